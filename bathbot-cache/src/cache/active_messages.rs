@@ -0,0 +1,93 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bb8_redis::redis::AsyncCommands;
+use eyre::{Result, WrapErr};
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, MessageMarker},
+};
+
+use crate::{Cache, key::RedisKey};
+
+impl Cache {
+    /// Register an active message so that its components can be removed in
+    /// case the bot restarts before the message times out.
+    ///
+    /// Registering an already registered message refreshes its expiration.
+    pub async fn add_active_message(
+        &self,
+        channel: Id<ChannelMarker>,
+        msg: Id<MessageMarker>,
+        expire_seconds: u64,
+    ) -> Result<()> {
+        let now = unix_timestamp();
+        let mut conn = self.connection().await?;
+        let key = RedisKey::active_messages();
+
+        conn.zadd::<_, _, _, ()>(&key, member(channel, msg), now + expire_seconds)
+            .await
+            .wrap_err("Failed to add active message")?;
+
+        conn.zrembyscore::<_, _, _, ()>(&key, 0, now)
+            .await
+            .wrap_err("Failed to remove expired active messages")?;
+
+        Ok(())
+    }
+
+    /// Unregister an active message that ended normally.
+    pub async fn remove_active_message(
+        &self,
+        channel: Id<ChannelMarker>,
+        msg: Id<MessageMarker>,
+    ) -> Result<()> {
+        self.connection()
+            .await?
+            .zrem::<_, _, ()>(RedisKey::active_messages(), member(channel, msg))
+            .await
+            .wrap_err("Failed to remove active message")
+    }
+
+    /// Remove all registered active messages and return at most `limit` of
+    /// them that did not expire yet.
+    pub async fn take_active_messages(
+        &self,
+        limit: usize,
+    ) -> Result<Vec<(Id<ChannelMarker>, Id<MessageMarker>)>> {
+        let mut conn = self.connection().await?;
+        let key = RedisKey::active_messages();
+
+        let members: Vec<String> = conn
+            .zrangebyscore_limit(&key, unix_timestamp(), "+inf", 0, limit as isize)
+            .await
+            .wrap_err("Failed to get active messages")?;
+
+        conn.del::<_, ()>(&key)
+            .await
+            .wrap_err("Failed to delete active messages")?;
+
+        let entries = members
+            .iter()
+            .filter_map(|member| {
+                let (channel, msg) = member.split_once(':')?;
+
+                Some((
+                    Id::new_checked(channel.parse().ok()?)?,
+                    Id::new_checked(msg.parse().ok()?)?,
+                ))
+            })
+            .collect();
+
+        Ok(entries)
+    }
+}
+
+fn member(channel: Id<ChannelMarker>, msg: Id<MessageMarker>) -> String {
+    format!("{channel}:{msg}")
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
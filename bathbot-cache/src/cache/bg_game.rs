@@ -0,0 +1,32 @@
+use bb8_redis::redis::AsyncCommands;
+use eyre::{Result, WrapErr};
+use twilight_model::id::{Id, marker::MessageMarker};
+
+use crate::{Cache, key::RedisKey};
+
+/// Round messages can only have their mapset tags edited for one day.
+const ROUND_EXPIRE_SECONDS: u64 = 24 * 60 * 60;
+
+impl Cache {
+    /// Remember which mapset a background game round message resolved.
+    pub async fn store_bg_game_round(&self, msg: Id<MessageMarker>, mapset_id: u32) -> Result<()> {
+        self.connection()
+            .await?
+            .set_ex::<_, _, ()>(
+                RedisKey::bg_game_round(msg),
+                mapset_id,
+                ROUND_EXPIRE_SECONDS,
+            )
+            .await
+            .wrap_err("Failed to store bg game round")
+    }
+
+    /// The mapset that a background game round message resolved, if any.
+    pub async fn bg_game_round(&self, msg: Id<MessageMarker>) -> Result<Option<u32>> {
+        self.connection()
+            .await?
+            .get(RedisKey::bg_game_round(msg))
+            .await
+            .wrap_err("Failed to get bg game round")
+    }
+}
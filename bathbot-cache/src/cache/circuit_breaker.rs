@@ -0,0 +1,202 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use metrics::{counter, describe_counter};
+use tracing::{info, warn};
+
+const CIRCUIT_BREAKER_TRANSITIONS: &str = "redis_circuit_breaker_transitions";
+
+/// Stops connection attempts to redis for a while after repeated failures so
+/// that callers fail fast instead of waiting for a timeout each time.
+pub(super) struct CircuitBreaker {
+    state: Mutex<BreakerState>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum BreakerState {
+    /// Connections are attempted; counts consecutive failures.
+    Closed { failures: u32 },
+    /// Connections are not attempted until the instant is reached.
+    Open { until: Instant },
+    /// The open period passed; the next outcome decides the state.
+    HalfOpen,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) enum Transition {
+    Opened,
+    HalfOpened,
+    Closed,
+}
+
+impl CircuitBreaker {
+    /// Consecutive failures after which the breaker opens.
+    const FAILURE_THRESHOLD: u32 = 3;
+
+    /// How long the breaker stays open before connections are attempted again.
+    const OPEN_DURATION: Duration = Duration::from_secs(5);
+
+    pub(super) fn new() -> Self {
+        describe_counter!(
+            CIRCUIT_BREAKER_TRANSITIONS,
+            "Number of state transitions of the redis circuit breaker"
+        );
+
+        Self {
+            state: Mutex::new(BreakerState::Closed { failures: 0 }),
+        }
+    }
+
+    /// Whether a connection should be attempted.
+    pub(super) fn allow(&self, now: Instant) -> bool {
+        let mut state = self.state.lock().unwrap();
+
+        match *state {
+            BreakerState::Closed { .. } | BreakerState::HalfOpen => true,
+            BreakerState::Open { until } if now < until => false,
+            BreakerState::Open { .. } => {
+                *state = BreakerState::HalfOpen;
+                Self::record(Transition::HalfOpened);
+
+                true
+            }
+        }
+    }
+
+    pub(super) fn on_success(&self) -> Option<Transition> {
+        let mut state = self.state.lock().unwrap();
+
+        let transition = match *state {
+            BreakerState::Closed { failures: 0 } => None,
+            BreakerState::Closed { .. } => {
+                *state = BreakerState::Closed { failures: 0 };
+
+                None
+            }
+            BreakerState::Open { .. } | BreakerState::HalfOpen => {
+                *state = BreakerState::Closed { failures: 0 };
+
+                Some(Transition::Closed)
+            }
+        };
+
+        transition.inspect(|transition| Self::record(*transition))
+    }
+
+    pub(super) fn on_failure(&self, now: Instant) -> Option<Transition> {
+        let mut state = self.state.lock().unwrap();
+        let open = BreakerState::Open {
+            until: now + Self::OPEN_DURATION,
+        };
+
+        let transition = match *state {
+            BreakerState::Closed { failures } if failures + 1 >= Self::FAILURE_THRESHOLD => {
+                *state = open;
+
+                Some(Transition::Opened)
+            }
+            BreakerState::Closed { failures } => {
+                *state = BreakerState::Closed {
+                    failures: failures + 1,
+                };
+
+                None
+            }
+            BreakerState::HalfOpen => {
+                *state = open;
+
+                Some(Transition::Opened)
+            }
+            // A connection attempt that started before the breaker opened
+            BreakerState::Open { .. } => None,
+        };
+
+        transition.inspect(|transition| Self::record(*transition))
+    }
+
+    fn record(transition: Transition) {
+        let state = match transition {
+            Transition::Opened => {
+                warn!("Redis circuit breaker opened, skipping redis for a while");
+
+                "open"
+            }
+            Transition::HalfOpened => "half_open",
+            Transition::Closed => {
+                info!("Redis circuit breaker closed again");
+
+                "closed"
+            }
+        };
+
+        counter!(CIRCUIT_BREAKER_TRANSITIONS, "state" => state).increment(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opens_after_consecutive_failures() {
+        let breaker = CircuitBreaker::new();
+        let now = Instant::now();
+
+        assert_eq!(breaker.on_failure(now), None);
+        assert_eq!(breaker.on_failure(now), None);
+        assert!(breaker.allow(now));
+        assert_eq!(breaker.on_failure(now), Some(Transition::Opened));
+        assert!(!breaker.allow(now));
+        assert!(!breaker.allow(now + Duration::from_secs(4)));
+    }
+
+    #[test]
+    fn success_resets_failures() {
+        let breaker = CircuitBreaker::new();
+        let now = Instant::now();
+
+        assert_eq!(breaker.on_failure(now), None);
+        assert_eq!(breaker.on_failure(now), None);
+        assert_eq!(breaker.on_success(), None);
+        assert_eq!(breaker.on_failure(now), None);
+        assert_eq!(breaker.on_failure(now), None);
+        assert!(breaker.allow(now));
+    }
+
+    #[test]
+    fn half_open_after_open_duration() {
+        let breaker = CircuitBreaker::new();
+        let now = Instant::now();
+
+        for _ in 0..CircuitBreaker::FAILURE_THRESHOLD {
+            let _ = breaker.on_failure(now);
+        }
+
+        let later = now + CircuitBreaker::OPEN_DURATION;
+        assert!(breaker.allow(later));
+
+        // Failing while half-open reopens immediately
+        assert_eq!(breaker.on_failure(later), Some(Transition::Opened));
+        assert!(!breaker.allow(later));
+
+        let even_later = later + CircuitBreaker::OPEN_DURATION;
+        assert!(breaker.allow(even_later));
+        assert_eq!(breaker.on_success(), Some(Transition::Closed));
+        assert!(breaker.allow(even_later));
+    }
+
+    #[test]
+    fn late_failures_keep_open_period() {
+        let breaker = CircuitBreaker::new();
+        let now = Instant::now();
+
+        for _ in 0..CircuitBreaker::FAILURE_THRESHOLD {
+            let _ = breaker.on_failure(now);
+        }
+
+        assert_eq!(breaker.on_failure(now + Duration::from_secs(3)), None);
+        assert!(breaker.allow(now + CircuitBreaker::OPEN_DURATION));
+    }
+}
@@ -0,0 +1,93 @@
+use bb8_redis::redis::{self, AsyncCommands};
+use eyre::{Result, WrapErr};
+
+use crate::{Cache, key::RedisKey};
+
+/// Amount of stored values at which all of them are cleared.
+const CAPACITY: usize = 100_000;
+
+impl Cache {
+    /// Values of converted maps for the given entries.
+    ///
+    /// Each lookup counts as a hit of the entry.
+    pub async fn convert_values(&self, entries: &[String]) -> Result<Vec<Option<String>>> {
+        if entries.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = self.connection().await?;
+        let mut pipe = redis::pipe();
+
+        pipe.cmd("HMGET")
+            .arg(RedisKey::convert_values())
+            .arg(entries);
+
+        for entry in entries {
+            pipe.zincr(RedisKey::convert_hits(), entry, 1).ignore();
+        }
+
+        let (values,) = pipe
+            .query_async::<(Vec<Option<String>>,)>(&mut *conn)
+            .await
+            .wrap_err("Failed to get convert values")?;
+
+        Ok(values)
+    }
+
+    /// Store values of converted maps for the given entries.
+    pub async fn store_convert_values(&self, values: &[(String, String)]) -> Result<()> {
+        if values.is_empty() {
+            return Ok(());
+        }
+
+        let mut conn = self.connection().await?;
+
+        let len: usize = conn
+            .hlen(RedisKey::convert_values())
+            .await
+            .wrap_err("Failed to get amount of convert values")?;
+
+        let mut pipe = redis::pipe();
+
+        if len >= CAPACITY {
+            pipe.del(&[RedisKey::convert_values(), RedisKey::convert_hits()])
+                .ignore();
+        }
+
+        pipe.hset_multiple(RedisKey::convert_values(), values)
+            .ignore();
+
+        // Ensure that each stored entry appears among the hits
+        for (entry, _) in values {
+            pipe.zincr(RedisKey::convert_hits(), entry, 0).ignore();
+        }
+
+        pipe.query_async::<()>(&mut *conn)
+            .await
+            .wrap_err("Failed to store convert values")
+    }
+
+    /// All stored entries of converted maps alongside their amount of hits.
+    pub async fn convert_hits(&self) -> Result<Vec<(String, u32)>> {
+        self.connection()
+            .await?
+            .zrange_withscores(RedisKey::convert_hits(), 0, -1)
+            .await
+            .wrap_err("Failed to get convert hits")
+    }
+
+    pub async fn remove_convert_values(&self, entries: &[String]) -> Result<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        redis::pipe()
+            .hdel(RedisKey::convert_values(), entries)
+            .ignore()
+            .zrem(RedisKey::convert_hits(), entries)
+            .ignore()
+            .query_async::<()>(&mut *self.connection().await?)
+            .await
+            .wrap_err("Failed to remove convert values")
+    }
+}
@@ -0,0 +1,40 @@
+use bb8_redis::redis::AsyncCommands;
+use eyre::{Result, WrapErr};
+
+use crate::{Cache, key::RedisKey};
+
+impl Cache {
+    /// Disable the feature with the given name.
+    ///
+    /// The entry expires on its own so a forgotten flag can't keep the
+    /// feature disabled indefinitely.
+    pub async fn disable_feature(&self, name: &'static str, expire_seconds: u64) -> Result<()> {
+        self.connection()
+            .await?
+            .set_ex::<_, _, ()>(RedisKey::feature_disabled(name), true, expire_seconds)
+            .await
+            .wrap_err("Failed to store disabled feature")
+    }
+
+    /// Whether the feature with the given name is currently disabled.
+    pub async fn feature_disabled(&self, name: &'static str) -> Result<bool> {
+        self.connection()
+            .await?
+            .exists(RedisKey::feature_disabled(name))
+            .await
+            .wrap_err("Failed to check disabled feature")
+    }
+
+    /// Enable the feature with the given name and return whether it was
+    /// disabled.
+    pub async fn enable_feature(&self, name: &'static str) -> Result<bool> {
+        let removed: usize = self
+            .connection()
+            .await?
+            .del(RedisKey::feature_disabled(name))
+            .await
+            .wrap_err("Failed to delete disabled feature")?;
+
+        Ok(removed > 0)
+    }
+}
@@ -3,10 +3,7 @@ use bathbot_model::twilight::{
     guild::{ArchivedCachedGuild, ArchivedCachedMember, ArchivedCachedRole},
     user::{ArchivedCachedCurrentUser, ArchivedCachedUser},
 };
-use bb8_redis::{
-    bb8::RunError,
-    redis::{AsyncCommands, RedisError},
-};
+use bb8_redis::redis::{AsyncCommands, RedisError};
 use eyre::{Report, WrapErr};
 use rkyv::{Portable, bytecheck::CheckBytes, rancor::BoxedError};
 use thiserror::Error as ThisError;
@@ -15,6 +12,7 @@ use twilight_model::id::{
     marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
 };
 
+use super::ConnectionError;
 use crate::{
     Cache,
     key::{RedisKey, ToCacheKey},
@@ -96,6 +94,16 @@ impl Cache {
             .wrap_err("Failed to get member ids")
     }
 
+    pub async fn guild_roles(&self, guild: Id<GuildMarker>) -> Result<Vec<u64>, Report> {
+        self.connection()
+            .await
+            .map_err(FetchError::Connection)
+            .map_err(Report::new)?
+            .smembers(RedisKey::guild_roles(guild))
+            .await
+            .wrap_err("Failed to get role ids")
+    }
+
     pub async fn member(
         &self,
         guild: Id<GuildMarker>,
@@ -142,7 +150,7 @@ impl Cache {
 #[derive(Debug, ThisError)]
 pub enum FetchError {
     #[error("Failed to acquire connection")]
-    Connection(#[from] RunError<RedisError>),
+    Connection(#[from] ConnectionError),
     #[error("Redis error")]
     Redis(#[from] RedisError),
     #[error("Validation error")]
@@ -0,0 +1,57 @@
+use bb8_redis::redis::AsyncCommands;
+use eyre::{Result, WrapErr};
+
+use crate::{Cache, key::RedisKey};
+
+/// Strikes only count if they happen within a day of each other.
+const STRIKE_EXPIRE_SECONDS: i64 = 24 * 60 * 60;
+
+impl Cache {
+    /// Add a strike for an osu! user that could not be found and return the
+    /// new amount of strikes.
+    pub async fn add_link_strike(&self, osu_id: u32) -> Result<u32> {
+        let mut conn = self.connection().await?;
+        let key = RedisKey::link_strikes(osu_id);
+
+        let strikes: u32 = conn
+            .incr(&key, 1)
+            .await
+            .wrap_err("Failed to increase link strikes")?;
+
+        conn.expire::<_, ()>(&key, STRIKE_EXPIRE_SECONDS)
+            .await
+            .wrap_err("Failed to set expiration of link strikes")?;
+
+        Ok(strikes)
+    }
+
+    /// Keep the strikes around until they're cleared explicitly.
+    pub async fn persist_link_strikes(&self, osu_id: u32) -> Result<()> {
+        self.connection()
+            .await?
+            .persist::<_, ()>(RedisKey::link_strikes(osu_id))
+            .await
+            .wrap_err("Failed to persist link strikes")
+    }
+
+    /// Whether an osu! user currently has any strikes.
+    pub async fn has_link_strikes(&self, osu_id: u32) -> Result<bool> {
+        self.connection()
+            .await?
+            .exists(RedisKey::link_strikes(osu_id))
+            .await
+            .wrap_err("Failed to check link strikes")
+    }
+
+    /// Remove all strikes of an osu! user and return whether there were any.
+    pub async fn clear_link_strikes(&self, osu_id: u32) -> Result<bool> {
+        let removed: usize = self
+            .connection()
+            .await?
+            .del(RedisKey::link_strikes(osu_id))
+            .await
+            .wrap_err("Failed to delete link strikes")?;
+
+        Ok(removed > 0)
+    }
+}
@@ -0,0 +1,48 @@
+use bb8_redis::redis::{self, AsyncCommands};
+use eyre::{Result, WrapErr};
+
+use crate::{Cache, key::RedisKey};
+
+impl Cache {
+    /// Enable maintenance mode with the given message.
+    ///
+    /// The entry expires on its own so maintenance mode can't outlive a
+    /// deploy that forgot to turn it off.
+    pub async fn store_maintenance(&self, message: &str, expire_seconds: u64) -> Result<()> {
+        self.connection()
+            .await?
+            .set_ex::<_, _, ()>(RedisKey::maintenance(), message, expire_seconds)
+            .await
+            .wrap_err("Failed to store maintenance")
+    }
+
+    /// The maintenance message and the amount of seconds until maintenance
+    /// mode ends on its own, if it's currently active.
+    pub async fn maintenance(&self) -> Result<Option<(String, u64)>> {
+        let mut conn = self.connection().await?;
+        let key = RedisKey::maintenance();
+
+        let (message, ttl): (Option<String>, i64) = redis::pipe()
+            .get(&key)
+            .ttl(&key)
+            .query_async(&mut *conn)
+            .await
+            .wrap_err("Failed to get maintenance")?;
+
+        // A negative TTL means the key is missing or has no expiration, the
+        // latter of which is never stored
+        Ok(message.zip(u64::try_from(ttl).ok()))
+    }
+
+    /// Disable maintenance mode and return whether it was active.
+    pub async fn clear_maintenance(&self) -> Result<bool> {
+        let removed: usize = self
+            .connection()
+            .await?
+            .del(RedisKey::maintenance())
+            .await
+            .wrap_err("Failed to delete maintenance")?;
+
+        Ok(removed > 0)
+    }
+}
@@ -1,4 +1,4 @@
-use std::fmt::Display;
+use std::{fmt::Display, time::Instant};
 
 use bb8_redis::{
     RedisConnectionManager,
@@ -6,23 +6,43 @@ use bb8_redis::{
     redis::RedisError,
 };
 use eyre::{Result, WrapErr};
+use thiserror::Error as ThisError;
 use tracing::error;
 use twilight_gateway::Event;
 use twilight_model::{
     application::interaction::InteractionData, gateway::payload::incoming::GuildCreate,
 };
 
-pub use self::fetch::FetchError;
+use self::circuit_breaker::CircuitBreaker;
+pub use self::{
+    fetch::FetchError,
+    reconcile::{ReconcileReport, SetReport},
+    size::{CacheSize, EntitySize, Extrapolation},
+};
 use crate::model::{CacheChange, CacheStats, CacheStatsInternal};
 
+mod active_messages;
+mod bg_game;
+mod circuit_breaker;
 mod cold_resume;
+mod convert_values;
 mod delete;
+mod feature_flags;
 mod fetch;
+mod link_strikes;
+mod maintenance;
+mod osutrack_update;
+mod recalc;
+mod reconcile;
+mod size;
+mod stats_refresh;
 mod store;
+mod tracking_digest;
 
 pub struct Cache {
     redis: Pool<RedisConnectionManager>,
     stats: CacheStatsInternal,
+    breaker: CircuitBreaker,
 }
 
 impl Cache {
@@ -42,7 +62,11 @@ impl Cache {
             .await
             .wrap_err("Failed to create cache stats")?;
 
-        Ok(Self { redis, stats })
+        Ok(Self {
+            redis,
+            stats,
+            breaker: CircuitBreaker::new(),
+        })
     }
 
     pub async fn update(&self, event: &Event) -> Option<CacheChange> {
@@ -154,9 +178,34 @@ impl Cache {
         self.stats.get()
     }
 
+    /// Acquire a connection from the pool unless the circuit breaker is open
+    /// due to previous failures.
     pub(crate) async fn connection(
         &self,
-    ) -> Result<PooledConnection<'_, RedisConnectionManager>, RunError<RedisError>> {
-        self.redis.get().await
+    ) -> Result<PooledConnection<'_, RedisConnectionManager>, ConnectionError> {
+        if !self.breaker.allow(Instant::now()) {
+            return Err(ConnectionError::CircuitOpen);
+        }
+
+        match self.redis.get().await {
+            Ok(conn) => {
+                let _ = self.breaker.on_success();
+
+                Ok(conn)
+            }
+            Err(err) => {
+                let _ = self.breaker.on_failure(Instant::now());
+
+                Err(ConnectionError::Pool(err))
+            }
+        }
     }
 }
+
+#[derive(Debug, ThisError)]
+pub enum ConnectionError {
+    #[error("Redis circuit breaker is open")]
+    CircuitOpen,
+    #[error("Failed to acquire connection from pool")]
+    Pool(#[from] RunError<RedisError>),
+}
@@ -0,0 +1,34 @@
+use bb8_redis::redis;
+use eyre::{Result, WrapErr};
+
+use crate::{Cache, key::RedisKey};
+
+impl Cache {
+    /// Remember that an osu!track update is triggered for the user unless
+    /// one is still stored.
+    ///
+    /// The entry expires after the given amount of seconds. Returns whether
+    /// the entry was stored i.e. whether no other update was triggered in the
+    /// meanwhile.
+    pub async fn claim_osutrack_update(
+        &self,
+        user_id: u32,
+        mode: u8,
+        timestamp: i64,
+        expire_seconds: u64,
+    ) -> Result<bool> {
+        let mut conn = self.connection().await?;
+
+        let stored: Option<String> = redis::cmd("SET")
+            .arg(RedisKey::osutrack_update(user_id, mode))
+            .arg(timestamp)
+            .arg("NX")
+            .arg("EX")
+            .arg(expire_seconds)
+            .query_async(&mut *conn)
+            .await
+            .wrap_err("Failed to claim osutrack update")?;
+
+        Ok(stored.is_some())
+    }
+}
@@ -0,0 +1,55 @@
+use bb8_redis::redis::{self, AsyncCommands};
+use eyre::{Result, WrapErr};
+
+use crate::{Cache, key::RedisKey};
+
+impl Cache {
+    /// Replace the pending recalculation job and reset its cursor.
+    pub async fn store_recalc_job(&self, entries: &[String]) -> Result<()> {
+        let mut conn = self.connection().await?;
+        let mut pipe = redis::pipe();
+
+        pipe.del(RedisKey::recalc_job()).ignore();
+
+        if !entries.is_empty() {
+            pipe.rpush(RedisKey::recalc_job(), entries).ignore();
+        }
+
+        pipe.set(RedisKey::recalc_cursor(), 0)
+            .ignore()
+            .query_async::<()>(&mut *conn)
+            .await
+            .wrap_err("Failed to store recalc job")
+    }
+
+    /// Entries of the pending recalculation job and the amount of entries
+    /// that were processed already.
+    pub async fn recalc_job(&self) -> Result<(Vec<String>, usize)> {
+        let mut conn = self.connection().await?;
+
+        let (entries, cursor) = redis::pipe()
+            .lrange(RedisKey::recalc_job(), 0, -1)
+            .get(RedisKey::recalc_cursor())
+            .query_async::<(Vec<String>, Option<usize>)>(&mut *conn)
+            .await
+            .wrap_err("Failed to get recalc job")?;
+
+        Ok((entries, cursor.unwrap_or(0)))
+    }
+
+    pub async fn store_recalc_cursor(&self, cursor: usize) -> Result<()> {
+        self.connection()
+            .await?
+            .set::<_, _, ()>(RedisKey::recalc_cursor(), cursor)
+            .await
+            .wrap_err("Failed to store recalc cursor")
+    }
+
+    pub async fn clear_recalc_job(&self) -> Result<()> {
+        self.connection()
+            .await?
+            .del::<_, ()>(&[RedisKey::recalc_job(), RedisKey::recalc_cursor()])
+            .await
+            .wrap_err("Failed to clear recalc job")
+    }
+}
@@ -0,0 +1,470 @@
+use std::{
+    cmp::Reverse,
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::AddAssign,
+};
+
+use bb8_redis::redis::{self, AsyncCommands, aio::ConnectionLike};
+use eyre::{Result, WrapErr};
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use crate::{Cache, key::RedisKey, model::CacheChange};
+
+/// Amount of ids that are checked per roundtrip.
+const BATCH_SIZE: usize = 500;
+
+/// Amount of dangling ids that were removed from a guild's sets.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReconcileReport {
+    pub members: SetReport,
+    pub channels: SetReport,
+    pub roles: SetReport,
+}
+
+impl ReconcileReport {
+    pub fn removed(&self) -> usize {
+        self.members.removed + self.channels.removed + self.roles.removed
+    }
+}
+
+impl AddAssign for ReconcileReport {
+    fn add_assign(&mut self, rhs: Self) {
+        self.members += rhs.members;
+        self.channels += rhs.channels;
+        self.roles += rhs.roles;
+    }
+}
+
+impl Display for ReconcileReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "members: {}, channels: {}, roles: {}",
+            self.members, self.channels, self.roles
+        )
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SetReport {
+    /// Ids that were checked; may count ids more than once since SSCAN can
+    /// return duplicates
+    pub checked: usize,
+    /// Ids that were removed because their entity key is missing
+    pub removed: usize,
+}
+
+impl AddAssign for SetReport {
+    fn add_assign(&mut self, rhs: Self) {
+        self.checked += rhs.checked;
+        self.removed += rhs.removed;
+    }
+}
+
+impl Display for SetReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}/{} removed", self.removed, self.checked)
+    }
+}
+
+impl Cache {
+    /// Remove ids from a guild's member, channel, and role sets whose entity
+    /// entries no longer exist.
+    pub async fn reconcile_guild(&self, guild: Id<GuildMarker>) -> Result<ReconcileReport> {
+        let mut conn = self.connection().await?;
+        let (report, change) = reconcile_guild(&mut *conn, guild).await?;
+        self.stats.update(&change);
+
+        Ok(report)
+    }
+
+    /// Ids of the guilds with the most cached members, largest first.
+    pub async fn largest_guilds(&self, amount: usize) -> Result<Vec<Id<GuildMarker>>> {
+        let mut conn = self.connection().await?;
+
+        let guilds: Vec<u64> = conn
+            .smembers(RedisKey::guilds())
+            .await
+            .wrap_err("Failed to get guild ids")?;
+
+        let mut sizes = Vec::with_capacity(guilds.len());
+
+        for chunk in guilds.chunks(BATCH_SIZE) {
+            let mut pipe = redis::pipe();
+
+            for &guild in chunk {
+                pipe.scard(RedisKey::guild_members(Id::new(guild)));
+            }
+
+            let counts: Vec<usize> = pipe
+                .query_async(&mut *conn)
+                .await
+                .wrap_err("Failed to get member counts")?;
+
+            sizes.extend(chunk.iter().copied().zip(counts));
+        }
+
+        Ok(largest(sizes, amount))
+    }
+
+    /// Ids of all cached guilds.
+    pub async fn guild_ids(&self) -> Result<Vec<Id<GuildMarker>>> {
+        let guilds: Vec<u64> = self
+            .connection()
+            .await?
+            .smembers(RedisKey::guilds())
+            .await
+            .wrap_err("Failed to get guild ids")?;
+
+        Ok(guilds.into_iter().filter_map(Id::new_checked).collect())
+    }
+}
+
+fn largest(mut sizes: Vec<(u64, usize)>, amount: usize) -> Vec<Id<GuildMarker>> {
+    sizes.sort_unstable_by_key(|(_, size)| Reverse(*size));
+
+    sizes
+        .into_iter()
+        .take(amount)
+        .filter_map(|(guild, _)| Id::new_checked(guild))
+        .collect()
+}
+
+async fn reconcile_guild<C>(
+    conn: &mut C,
+    guild: Id<GuildMarker>,
+) -> Result<(ReconcileReport, CacheChange)>
+where
+    C: ConnectionLike + Send + Sync,
+{
+    let mut change = CacheChange::default();
+
+    let (members, _) = reconcile_set(conn, RedisKey::guild_members(guild), None, |user| {
+        RedisKey::member(guild, Id::new(user))
+    })
+    .await
+    .wrap_err("Failed to reconcile guild members")?;
+
+    let (channels, removed) = reconcile_set(
+        conn,
+        RedisKey::guild_channels(guild),
+        Some(RedisKey::channels()),
+        |channel| RedisKey::channel(Some(guild), Id::new(channel)),
+    )
+    .await
+    .wrap_err("Failed to reconcile guild channels")?;
+
+    change.channels -= removed;
+
+    let (roles, removed) = reconcile_set(
+        conn,
+        RedisKey::guild_roles(guild),
+        Some(RedisKey::roles()),
+        |role| RedisKey::role(guild, Id::new(role)),
+    )
+    .await
+    .wrap_err("Failed to reconcile guild roles")?;
+
+    change.roles -= removed;
+
+    let report = ReconcileReport {
+        members,
+        channels,
+        roles,
+    };
+
+    Ok((report, change))
+}
+
+/// Scan through the set in batches and remove ids whose entity key is
+/// missing.
+///
+/// Dangling ids are also removed from the global id set, if any. Returns the
+/// report and how many ids were removed from the global id set.
+async fn reconcile_set<C, K>(
+    conn: &mut C,
+    set_key: RedisKey<'static>,
+    id_set_key: Option<RedisKey<'static>>,
+    entity_key: K,
+) -> Result<(SetReport, isize)>
+where
+    C: ConnectionLike + Send + Sync,
+    K: Fn(u64) -> RedisKey<'static>,
+{
+    let mut report = SetReport::default();
+    let mut removed_ids = 0;
+    let mut cursor = 0_u64;
+
+    loop {
+        let (next, ids): (u64, Vec<u64>) = redis::cmd("SSCAN")
+            .arg(&set_key)
+            .arg(cursor)
+            .arg("COUNT")
+            .arg(BATCH_SIZE)
+            .query_async(conn)
+            .await
+            .wrap_err("Failed sscan")?;
+
+        if !ids.is_empty() {
+            let mut pipe = redis::pipe();
+
+            for &id in ids.iter() {
+                pipe.exists(entity_key(id));
+            }
+
+            let exists: Vec<bool> = pipe.query_async(conn).await.wrap_err("Failed exists")?;
+
+            let dangling: Vec<u64> = ids
+                .iter()
+                .zip(exists)
+                .filter_map(|(&id, exists)| (!exists).then_some(id))
+                .collect();
+
+            report.checked += ids.len();
+
+            if !dangling.is_empty() {
+                let removed: usize = conn
+                    .srem(&set_key, &dangling)
+                    .await
+                    .wrap_err("Failed srem")?;
+
+                report.removed += removed;
+
+                if let Some(ref id_set_key) = id_set_key {
+                    let removed: isize = conn
+                        .srem(id_set_key, &dangling)
+                        .await
+                        .wrap_err("Failed srem of ids")?;
+
+                    removed_ids += removed;
+                }
+            }
+        }
+
+        if next == 0 {
+            break;
+        }
+
+        cursor = next;
+    }
+
+    Ok((report, removed_ids))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashMap, HashSet};
+
+    use bb8_redis::redis::{Arg, Cmd, Pipeline, RedisFuture, ToRedisArgs, Value};
+
+    use super::*;
+
+    const GUILD: Id<GuildMarker> = Id::new(1);
+
+    /// Understands just enough commands to reconcile sets.
+    #[derive(Default)]
+    struct MockConnection {
+        sets: HashMap<Vec<u8>, BTreeSet<u64>>,
+        keys: HashSet<Vec<u8>>,
+        /// SSCAN returns at most this many ids per call
+        page_size: usize,
+    }
+
+    impl MockConnection {
+        fn new(page_size: usize) -> Self {
+            Self {
+                page_size,
+                ..Default::default()
+            }
+        }
+
+        fn key(key: RedisKey<'_>) -> Vec<u8> {
+            key.to_redis_args().remove(0)
+        }
+
+        fn seed(
+            &mut self,
+            set: RedisKey<'_>,
+            ids: &[u64],
+            existing: impl Fn(u64) -> RedisKey<'static>,
+            missing: &[u64],
+        ) {
+            let set = self.sets.entry(Self::key(set)).or_default();
+            set.extend(ids);
+
+            for &id in ids.iter().filter(|id| !missing.contains(id)) {
+                self.keys.insert(Self::key(existing(id)));
+            }
+        }
+
+        fn set(&self, key: RedisKey<'_>) -> Vec<u64> {
+            self.sets
+                .get(&Self::key(key))
+                .map_or_else(Vec::new, |set| set.iter().copied().collect())
+        }
+
+        fn execute(&mut self, cmd: &Cmd) -> Value {
+            let args: Vec<&[u8]> = cmd
+                .args_iter()
+                .map(|arg| match arg {
+                    Arg::Simple(bytes) => bytes,
+                    Arg::Cursor => b"0",
+                })
+                .collect();
+
+            let parse =
+                |bytes: &[u8]| -> u64 { std::str::from_utf8(bytes).unwrap().parse().unwrap() };
+            let bulk = |n: u64| Value::BulkString(n.to_string().into_bytes());
+
+            match args[0] {
+                // The cursor is the last returned id so that removals
+                // during the scan don't skip any ids
+                b"SSCAN" => {
+                    let cursor = parse(args[2]);
+                    let set = self.sets.get(args[1]).cloned().unwrap_or_default();
+
+                    let page: Vec<_> = set
+                        .range(cursor + 1..)
+                        .take(self.page_size)
+                        .copied()
+                        .collect();
+
+                    let next = match page.last() {
+                        Some(&last) if set.range(last + 1..).next().is_some() => last,
+                        _ => 0,
+                    };
+
+                    let page = page.into_iter().map(bulk).collect();
+
+                    Value::Array(vec![bulk(next), Value::Array(page)])
+                }
+                b"EXISTS" => Value::Int(self.keys.contains(args[1]) as i64),
+                b"SREM" => {
+                    let set = self.sets.entry(args[1].to_vec()).or_default();
+                    let removed = args[2..].iter().filter(|id| set.remove(&parse(id))).count();
+
+                    Value::Int(removed as i64)
+                }
+                b"SCARD" => {
+                    let len = self.sets.get(args[1]).map_or(0, BTreeSet::len);
+
+                    Value::Int(len as i64)
+                }
+                cmd => panic!("unexpected command {}", String::from_utf8_lossy(cmd)),
+            }
+        }
+    }
+
+    impl ConnectionLike for MockConnection {
+        fn req_packed_command<'a>(&'a mut self, cmd: &'a Cmd) -> RedisFuture<'a, Value> {
+            let value = self.execute(cmd);
+
+            Box::pin(async move { Ok(value) })
+        }
+
+        fn req_packed_commands<'a>(
+            &'a mut self,
+            pipeline: &'a Pipeline,
+            offset: usize,
+            count: usize,
+        ) -> RedisFuture<'a, Vec<Value>> {
+            let values: Vec<_> = pipeline.cmd_iter().map(|cmd| self.execute(cmd)).collect();
+            let values = values.into_iter().skip(offset).take(count).collect();
+
+            Box::pin(async move { Ok(values) })
+        }
+
+        fn get_db(&self) -> i64 {
+            0
+        }
+    }
+
+    fn member(user: u64) -> RedisKey<'static> {
+        RedisKey::member(GUILD, Id::new(user))
+    }
+
+    fn channel(channel: u64) -> RedisKey<'static> {
+        RedisKey::channel(Some(GUILD), Id::new(channel))
+    }
+
+    fn role(role: u64) -> RedisKey<'static> {
+        RedisKey::role(GUILD, Id::new(role))
+    }
+
+    #[tokio::test]
+    async fn removes_dangling_ids() {
+        let mut conn = MockConnection::new(3);
+
+        let members: Vec<u64> = (1..=10).collect();
+        conn.seed(RedisKey::guild_members(GUILD), &members, member, &[2, 3, 9]);
+        conn.seed(RedisKey::guild_channels(GUILD), &[11, 12], channel, &[12]);
+        conn.seed(RedisKey::channels(), &[11, 12, 13], channel, &[]);
+        conn.seed(RedisKey::guild_roles(GUILD), &[21, 22], role, &[]);
+        conn.seed(RedisKey::roles(), &[21, 22], role, &[]);
+
+        let (report, change) = reconcile_guild(&mut conn, GUILD).await.unwrap();
+
+        let expected = ReconcileReport {
+            members: SetReport {
+                checked: 10,
+                removed: 3,
+            },
+            channels: SetReport {
+                checked: 2,
+                removed: 1,
+            },
+            roles: SetReport {
+                checked: 2,
+                removed: 0,
+            },
+        };
+
+        assert_eq!(report, expected);
+        assert_eq!(report.removed(), 4);
+        assert_eq!(change.channels, -1);
+        assert_eq!(change.roles, 0);
+
+        assert_eq!(
+            conn.set(RedisKey::guild_members(GUILD)),
+            [1, 4, 5, 6, 7, 8, 10]
+        );
+        assert_eq!(conn.set(RedisKey::guild_channels(GUILD)), [11]);
+        assert_eq!(conn.set(RedisKey::channels()), [11, 13]);
+        assert_eq!(conn.set(RedisKey::guild_roles(GUILD)), [21, 22]);
+    }
+
+    #[tokio::test]
+    async fn second_run_is_a_noop() {
+        let mut conn = MockConnection::new(2);
+
+        conn.seed(
+            RedisKey::guild_members(GUILD),
+            &[1, 2, 3, 4, 5],
+            member,
+            &[1, 5],
+        );
+
+        let (first, _) = reconcile_guild(&mut conn, GUILD).await.unwrap();
+        assert_eq!(first.members.removed, 2);
+
+        let (second, _) = reconcile_guild(&mut conn, GUILD).await.unwrap();
+        assert_eq!(second.members.checked, 3);
+        assert_eq!(second.removed(), 0);
+    }
+
+    #[tokio::test]
+    async fn empty_guild() {
+        let mut conn = MockConnection::new(BATCH_SIZE);
+        let (report, _) = reconcile_guild(&mut conn, GUILD).await.unwrap();
+
+        assert_eq!(report, ReconcileReport::default());
+    }
+
+    #[test]
+    fn largest_first() {
+        let sizes = vec![(1, 10), (2, 300), (3, 0), (4, 42)];
+
+        assert_eq!(largest(sizes.clone(), 2), [Id::new(2), Id::new(4)]);
+        assert_eq!(largest(sizes, 10).len(), 4);
+    }
+}
@@ -0,0 +1,349 @@
+use bb8_redis::redis::{self, AsyncCommands, ErrorKind, aio::ConnectionLike};
+use eyre::{Result, WrapErr};
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use crate::{Cache, key::RedisKey};
+
+/// Maximum amount of sampled entries per entity class so that estimates
+/// stay fast on huge caches.
+const MAX_SAMPLES: usize = 50;
+
+/// Amount of sampled entries per entity class that are also checked via
+/// `MEMORY USAGE`.
+const MEMORY_USAGE_SAMPLES: usize = 5;
+
+/// Amount of sets whose cardinality is requested per roundtrip.
+const BATCH_SIZE: usize = 500;
+
+/// Estimated size of the cached discord entities.
+#[derive(Clone, Debug, Default)]
+pub struct CacheSize {
+    pub guilds: EntitySize,
+    pub channels: EntitySize,
+    pub users: EntitySize,
+    pub members: EntitySize,
+    pub roles: EntitySize,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct EntitySize {
+    /// Amount of cached entries
+    pub count: usize,
+    /// Amount of sampled entries whose key still existed
+    pub sampled: usize,
+    /// Extrapolated bytes of all values
+    pub value_bytes: Option<Extrapolation>,
+    /// Extrapolated bytes according to redis' `MEMORY USAGE`, if available
+    pub memory_usage: Option<Extrapolation>,
+}
+
+/// Total size extrapolated from a sample.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Extrapolation {
+    pub total: u64,
+    /// Half-width of the ~95% confidence interval around `total`
+    pub margin: u64,
+}
+
+impl Extrapolation {
+    /// Extrapolate the total size of `count` entries from the sizes of a
+    /// uniform sample.
+    ///
+    /// Returns `None` if the sample is empty.
+    pub fn new(count: usize, sample: &[u64]) -> Option<Self> {
+        if sample.is_empty() {
+            return None;
+        }
+
+        let len = sample.len() as f64;
+        let mean = sample.iter().sum::<u64>() as f64 / len;
+
+        // A single sample says nothing about the spread
+        let margin = if sample.len() > 1 {
+            let variance = sample
+                .iter()
+                .map(|&size| (size as f64 - mean).powi(2))
+                .sum::<f64>()
+                / (len - 1.0);
+
+            1.96 * (variance / len).sqrt() * count as f64
+        } else {
+            mean * count as f64
+        };
+
+        Some(Self {
+            total: (mean * count as f64).round() as u64,
+            margin: margin.round() as u64,
+        })
+    }
+}
+
+impl Cache {
+    /// Estimate the size of the cached entities by sampling at most
+    /// [`MAX_SAMPLES`] entries of each kind.
+    ///
+    /// Channels, roles, and members are sampled through random guilds so
+    /// they're only uniform across guilds, not across entries.
+    pub async fn estimate_size(&self) -> Result<CacheSize> {
+        let mut conn = self.connection().await?;
+        let conn = &mut *conn;
+
+        let guilds = srandmember(conn, RedisKey::guilds(), MAX_SAMPLES)
+            .await
+            .wrap_err("Failed to sample guilds")?;
+
+        let guilds: Vec<Id<GuildMarker>> = guilds.into_iter().filter_map(Id::new_checked).collect();
+
+        let users = srandmember(conn, RedisKey::users(), MAX_SAMPLES)
+            .await
+            .wrap_err("Failed to sample users")?;
+
+        // One random channel, role, and member of each sampled guild
+        let mut pipe = redis::pipe();
+
+        for &guild in guilds.iter() {
+            pipe.srandmember_multiple(RedisKey::guild_channels(guild), 1)
+                .srandmember_multiple(RedisKey::guild_roles(guild), 1)
+                .srandmember_multiple(RedisKey::guild_members(guild), 1);
+        }
+
+        let samples: Vec<Vec<u64>> = if guilds.is_empty() {
+            Vec::new()
+        } else {
+            pipe.query_async(conn)
+                .await
+                .wrap_err("Failed to sample guild entries")?
+        };
+
+        let mut channel_keys = Vec::with_capacity(guilds.len());
+        let mut role_keys = Vec::with_capacity(guilds.len());
+        let mut member_keys = Vec::with_capacity(guilds.len());
+
+        for (&guild, sample) in guilds.iter().zip(samples.chunks_exact(3)) {
+            let [channels, roles, members] = sample else {
+                unreachable!()
+            };
+
+            channel_keys.extend(
+                channels
+                    .iter()
+                    .map(|&channel| RedisKey::channel(Some(guild), Id::new(channel))),
+            );
+            role_keys.extend(
+                roles
+                    .iter()
+                    .map(|&role| RedisKey::role(guild, Id::new(role))),
+            );
+            member_keys.extend(
+                members
+                    .iter()
+                    .map(|&user| RedisKey::member(guild, Id::new(user))),
+            );
+        }
+
+        let guild_keys: Vec<_> = guilds.iter().map(|&guild| RedisKey::guild(guild)).collect();
+
+        let user_keys: Vec<_> = users
+            .into_iter()
+            .map(|user| RedisKey::user(Id::new(user)))
+            .collect();
+
+        let guild_count = scard(conn, RedisKey::guilds()).await?;
+        let channel_count = scard(conn, RedisKey::channels()).await?;
+        let user_count = scard(conn, RedisKey::users()).await?;
+        let member_count = member_count(conn).await?;
+        let role_count = scard(conn, RedisKey::roles()).await?;
+
+        Ok(CacheSize {
+            guilds: entity_size(conn, guild_count, &guild_keys).await?,
+            channels: entity_size(conn, channel_count, &channel_keys).await?,
+            users: entity_size(conn, user_count, &user_keys).await?,
+            members: entity_size(conn, member_count, &member_keys).await?,
+            roles: entity_size(conn, role_count, &role_keys).await?,
+        })
+    }
+}
+
+/// Measure the sampled keys and extrapolate to `count` many entries.
+async fn entity_size<C>(
+    conn: &mut C,
+    count: usize,
+    keys: &[RedisKey<'static>],
+) -> Result<EntitySize>
+where
+    C: ConnectionLike + Send + Sync,
+{
+    // Missing keys are dangling ids
+    let sample: Vec<u64> = strlen(conn, keys)
+        .await?
+        .into_iter()
+        .filter(|&len| len > 0)
+        .collect();
+
+    let mut usages = Vec::with_capacity(MEMORY_USAGE_SAMPLES);
+    let mut usage_available = true;
+
+    for key in keys.iter().take(MEMORY_USAGE_SAMPLES) {
+        match memory_usage(conn, key).await? {
+            MemoryUsage::Bytes(bytes) => usages.push(bytes),
+            MemoryUsage::Missing => {}
+            MemoryUsage::Unavailable => {
+                usage_available = false;
+
+                break;
+            }
+        }
+    }
+
+    let memory_usage = if usage_available {
+        Extrapolation::new(count, &usages)
+    } else {
+        None
+    };
+
+    Ok(EntitySize {
+        count,
+        sampled: sample.len(),
+        value_bytes: Extrapolation::new(count, &sample),
+        memory_usage,
+    })
+}
+
+/// Sum of the cardinalities of all guild member sets.
+async fn member_count<C>(conn: &mut C) -> Result<usize>
+where
+    C: ConnectionLike + Send + Sync,
+{
+    let guilds: Vec<u64> = conn
+        .smembers(RedisKey::guilds())
+        .await
+        .wrap_err("Failed to get guild ids")?;
+
+    let mut total = 0;
+
+    for chunk in guilds.chunks(BATCH_SIZE) {
+        let mut pipe = redis::pipe();
+
+        for &guild in chunk {
+            pipe.scard(RedisKey::guild_members(Id::new(guild)));
+        }
+
+        let counts: Vec<usize> = pipe
+            .query_async(conn)
+            .await
+            .wrap_err("Failed to get member counts")?;
+
+        total += counts.into_iter().sum::<usize>();
+    }
+
+    Ok(total)
+}
+
+async fn scard<C>(conn: &mut C, key: RedisKey<'static>) -> Result<usize>
+where
+    C: ConnectionLike + Send + Sync,
+{
+    conn.scard(key).await.wrap_err("Failed scard")
+}
+
+async fn srandmember<C>(conn: &mut C, key: RedisKey<'static>, count: usize) -> Result<Vec<u64>>
+where
+    C: ConnectionLike + Send + Sync,
+{
+    conn.srandmember_multiple(key, count)
+        .await
+        .wrap_err("Failed srandmember")
+}
+
+async fn strlen<C>(conn: &mut C, keys: &[RedisKey<'static>]) -> Result<Vec<u64>>
+where
+    C: ConnectionLike + Send + Sync,
+{
+    if keys.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut pipe = redis::pipe();
+
+    for key in keys {
+        pipe.strlen(key);
+    }
+
+    pipe.query_async(conn).await.wrap_err("Failed strlen")
+}
+
+enum MemoryUsage {
+    Bytes(u64),
+    Missing,
+    /// The command is not available e.g. because it's disabled or the
+    /// server is not redis
+    Unavailable,
+}
+
+async fn memory_usage<C>(conn: &mut C, key: &RedisKey<'static>) -> Result<MemoryUsage>
+where
+    C: ConnectionLike + Send + Sync,
+{
+    let res: Result<Option<u64>, _> = redis::cmd("MEMORY")
+        .arg("USAGE")
+        .arg(key)
+        .query_async(conn)
+        .await;
+
+    match res {
+        Ok(Some(bytes)) => Ok(MemoryUsage::Bytes(bytes)),
+        Ok(None) => Ok(MemoryUsage::Missing),
+        Err(err) if err.kind() == ErrorKind::ResponseError => Ok(MemoryUsage::Unavailable),
+        Err(err) => Err(err).wrap_err("Failed memory usage"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extrapolate_uniform_sample() {
+        let extrapolation = Extrapolation::new(1000, &[100; 20]).unwrap();
+
+        assert_eq!(
+            extrapolation,
+            Extrapolation {
+                total: 100_000,
+                margin: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn extrapolate_mean_and_margin() {
+        // Mean 20, sample variance 200 / 3
+        let extrapolation = Extrapolation::new(100, &[10, 10, 30, 30]).unwrap();
+
+        assert_eq!(extrapolation.total, 2000);
+
+        let expected_margin = 1.96 * (200.0_f64 / 3.0 / 4.0).sqrt() * 100.0;
+        assert_eq!(extrapolation.margin, expected_margin.round() as u64);
+    }
+
+    #[test]
+    fn extrapolate_single_sample() {
+        let extrapolation = Extrapolation::new(10, &[50]).unwrap();
+
+        // Without spread, the whole estimate is uncertain
+        assert_eq!(extrapolation.total, 500);
+        assert_eq!(extrapolation.margin, 500);
+    }
+
+    #[test]
+    fn extrapolate_without_sample() {
+        assert_eq!(Extrapolation::new(10, &[]), None);
+        assert_eq!(
+            Extrapolation::new(0, &[50, 60]),
+            Some(Extrapolation {
+                total: 0,
+                margin: 0
+            })
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use bb8_redis::redis::AsyncCommands;
+use eyre::{Result, WrapErr};
+
+use crate::{Cache, key::RedisKey};
+
+/// Spent amounts are only relevant for the current day but keeping them a
+/// little longer doesn't hurt.
+const SPENT_EXPIRE_SECONDS: i64 = 2 * 24 * 60 * 60;
+
+impl Cache {
+    /// Amount of requests the stats refresher spent on the given julian day.
+    pub async fn stats_refresh_spent(&self, day: i32) -> Result<u32> {
+        let spent: Option<u32> = self
+            .connection()
+            .await?
+            .get(RedisKey::stats_refresh_spent(day))
+            .await
+            .wrap_err("Failed to get spent stats refresh requests")?;
+
+        Ok(spent.unwrap_or(0))
+    }
+
+    /// Add to the amount of requests the stats refresher spent on the given
+    /// julian day and return the new total.
+    pub async fn add_stats_refresh_spent(&self, day: i32, amount: u32) -> Result<u32> {
+        let mut conn = self.connection().await?;
+        let key = RedisKey::stats_refresh_spent(day);
+
+        let spent: u32 = conn
+            .incr(&key, amount)
+            .await
+            .wrap_err("Failed to increase spent stats refresh requests")?;
+
+        conn.expire::<_, ()>(&key, SPENT_EXPIRE_SECONDS)
+            .await
+            .wrap_err("Failed to set expiration of spent stats refresh requests")?;
+
+        Ok(spent)
+    }
+}
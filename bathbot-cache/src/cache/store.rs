@@ -3,7 +3,7 @@ use bathbot_model::twilight::{
     guild::{CachedGuild, CachedMember, CachedRole},
     user::{CachedCurrentUser, CachedUser},
 };
-use bb8_redis::redis::AsyncCommands;
+use bb8_redis::redis::{self, AsyncCommands};
 use eyre::{Report, Result, WrapErr};
 use rkyv::{
     rancor::{BoxedError, Strategy},
@@ -11,6 +11,7 @@ use rkyv::{
     util::AlignedVec,
     with::{ArchiveWith, SerializeWith, With},
 };
+use tracing::warn;
 use twilight_model::{
     application::interaction::InteractionMember,
     channel::Channel,
@@ -67,6 +68,31 @@ impl Cache {
         conn.set(key, bytes).await.map_err(Report::new)
     }
 
+    /// Reset the expiration of a key to `expire_seconds` if less than a
+    /// quarter of it remains.
+    ///
+    /// Returns whether the expiration was reset.
+    pub async fn refresh_ttl<K>(&self, key: &K, expire_seconds: u64) -> Result<bool>
+    where
+        K: ToCacheKey + ?Sized,
+    {
+        let mut conn = self.connection().await?;
+        let key = RedisKey::from(key);
+
+        let remaining: i64 = conn
+            .ttl(&key)
+            .await
+            .wrap_err("Failed to get remaining ttl")?;
+
+        if !should_refresh_ttl(remaining, expire_seconds) {
+            return Ok(false);
+        }
+
+        conn.expire(&key, expire_seconds as i64)
+            .await
+            .wrap_err("Failed to refresh ttl")
+    }
+
     /// Insert a value into a set.
     ///
     /// Returns whether the value was newly inserted. That is:
@@ -134,29 +160,32 @@ impl Cache {
         let (channels, channel_ids) = rkyv::util::with_arena(|arena| {
             let mut serializer = Serializer::new(AlignedVec::<8>::new(), arena.acquire(), ());
 
-            channels
-                .iter()
-                .map(move |channel| {
-                    let bytes = {
-                        let strategy = Strategy::<_, BoxedError>::wrap(&mut serializer);
-                        let with = With::<_, CachedChannel>::cast(channel);
-                        rkyv::api::serialize_using(with, strategy)
-                            .wrap_err("Failed to serialize channel")?;
-
-                        let bytes = serializer.writer.as_slice().to_vec();
-                        serializer.writer.clear();
+            collect_tolerant::<_, _, Zipped<Vec<_>, Vec<_>>>(
+                channels,
+                "channel",
+                |channel| channel.id.get(),
+                |channel| {
+                    let strategy = Strategy::<_, BoxedError>::wrap(&mut serializer);
+                    let with = With::<_, CachedChannel>::cast(channel);
+                    let res = rkyv::api::serialize_using(with, strategy)
+                        .wrap_err("Failed to serialize channel");
 
-                        bytes
-                    };
+                    let bytes = serializer.writer.as_slice().to_vec();
+                    serializer.writer.clear();
+                    res?;
 
                     let key = RedisKey::from(channel);
 
-                    Ok::<_, Report>(((key, bytes), channel.id.get()))
-                })
-                .collect::<Result<Zipped<Vec<_>, Vec<_>>, _>>()
-        })?
+                    Ok(((key, bytes), channel.id.get()))
+                },
+            )
+        })
         .into_parts();
 
+        if channels.is_empty() {
+            return Ok(CacheChange::default());
+        }
+
         let mut conn = self.connection().await?;
 
         conn.mset::<_, _, ()>(&channels)
@@ -167,7 +196,7 @@ impl Cache {
 
         conn.sadd::<_, _, ()>(guild_key, &channel_ids)
             .await
-            .wrap_err("Failed to add users as guild members")?;
+            .wrap_err("Failed to add channels as guild channels")?;
 
         let added: isize = conn
             .sadd(RedisKey::channels(), &channel_ids)
@@ -342,71 +371,66 @@ impl Cache {
         let (zipped_members, users) = rkyv::util::with_arena(|arena| {
             let mut serializer = Serializer::new(AlignedVec::<8>::new(), arena.acquire(), ());
 
-            members
-                .iter()
-                .map(move |member| {
+            collect_tolerant::<_, _, Zipped<Zipped<Vec<_>, Vec<_>>, Vec<_>>>(
+                members,
+                "member",
+                |member| member.user.id.get(),
+                |member| {
                     let user_id = member.user.id;
 
                     let user = {
                         let strategy = Strategy::<_, BoxedError>::wrap(&mut serializer.writer);
                         let with = With::<_, CachedUser>::cast(&member.user);
+                        let res = rkyv::api::serialize_using(with, strategy)
+                            .wrap_err("Failed to serialize user");
 
-                        rkyv::api::serialize_using(with, strategy)
-                            .wrap_err("Failed to serialize user")
-                            .map(|_| {
-                                let bytes = serializer.writer.as_slice().to_vec();
-                                serializer.writer.clear();
+                        let bytes = serializer.writer.as_slice().to_vec();
+                        serializer.writer.clear();
+                        res?;
 
-                                (RedisKey::from(&member.user), bytes)
-                            })
+                        (RedisKey::from(&member.user), bytes)
                     };
 
                     let member = {
                         let strategy = Strategy::<_, BoxedError>::wrap(&mut serializer);
                         let with = With::<_, CachedMember>::cast(member);
+                        let res = rkyv::api::serialize_using(with, strategy)
+                            .wrap_err("Failed to serialize member");
 
-                        rkyv::api::serialize_using(with, strategy)
-                            .wrap_err("Failed to serialize member")
-                            .map(|_| {
-                                let bytes = serializer.writer.as_slice().to_vec();
-                                serializer.writer.clear();
-                                let key = RedisKey::member(guild, member.user.id);
+                        let bytes = serializer.writer.as_slice().to_vec();
+                        serializer.writer.clear();
+                        res?;
 
-                                (key, bytes)
-                            })
+                        (RedisKey::member(guild, user_id), bytes)
                     };
 
-                    match (member, user) {
-                        (Ok(member), Ok(user)) => Ok(((member, user_id.get()), user)),
-                        (Err(e), _) | (_, Err(e)) => Err(e),
-                    }
-                })
-                .collect::<Result<Zipped<Zipped<Vec<_>, Vec<_>>, Vec<_>>>>()
-        })?
+                    Ok(((member, user_id.get()), user))
+                },
+            )
+        })
         .into_parts();
 
         let (members, member_ids) = zipped_members.into_parts();
 
-        let mut conn = self.connection().await?;
-
-        conn.mset::<_, _, ()>(&members)
-            .await
-            .wrap_err("Failed to store members bytes")?;
-
-        conn.mset::<_, _, ()>(&users)
-            .await
-            .wrap_err("Failed to store users bytes")?;
-
-        let guild_key = RedisKey::guild_members(guild);
+        if members.is_empty() {
+            return Ok(CacheChange::default());
+        }
 
-        conn.sadd::<_, _, ()>(guild_key, &member_ids)
-            .await
-            .wrap_err("Failed to add users as guild members")?;
+        let mut conn = self.connection().await?;
 
-        let added: isize = conn
+        // Large guilds have many members so all commands are sent in a single
+        // round trip
+        let (added,): (isize,) = redis::pipe()
+            .mset(&members)
+            .ignore()
+            .mset(&users)
+            .ignore()
+            .sadd(RedisKey::guild_members(guild), &member_ids)
+            .ignore()
             .sadd(RedisKey::users(), &member_ids)
+            .query_async(&mut *conn)
             .await
-            .wrap_err("Failed to add users as user ids")?;
+            .wrap_err("Failed to store members")?;
 
         Ok(CacheChange {
             users: added,
@@ -505,9 +529,11 @@ impl Cache {
     where
         I: IntoIterator<Item = &'r Role>,
     {
-        let (roles, role_ids) = roles
-            .into_iter()
-            .map(|role| {
+        let (roles, role_ids) = collect_tolerant::<_, _, Zipped<Vec<_>, Vec<_>>>(
+            roles,
+            "role",
+            |role| role.id.get(),
+            |role| {
                 let bytes = {
                     let mut serializer = AlignedVec::<8>::new();
                     let strategy = Strategy::<_, BoxedError>::wrap(&mut serializer);
@@ -520,10 +546,10 @@ impl Cache {
 
                 let key = RedisKey::role(guild, role.id);
 
-                Ok::<_, Report>(((key, AlignedVecRedisArgs(bytes)), role.id.get()))
-            })
-            .collect::<Result<Zipped<Vec<_>, Vec<_>>, _>>()?
-            .into_parts();
+                Ok(((key, AlignedVecRedisArgs(bytes)), role.id.get()))
+            },
+        )
+        .into_parts();
 
         if roles.is_empty() {
             return Ok(CacheChange::default());
@@ -625,3 +651,114 @@ impl Cache {
         })
     }
 }
+
+/// Applies `f` to each item and collects the successful outputs.
+///
+/// Items for which `f` fails are logged and skipped so that a single faulty
+/// entity does not prevent all other entities from being cached.
+fn collect_tolerant<'i, T, O, C>(
+    items: impl IntoIterator<Item = &'i T>,
+    kind: &str,
+    id: impl Fn(&T) -> u64,
+    mut f: impl FnMut(&T) -> Result<O>,
+) -> C
+where
+    T: 'i,
+    C: FromIterator<O>,
+{
+    items
+        .into_iter()
+        .filter_map(|item| match f(item) {
+            Ok(output) => Some(output),
+            Err(err) => {
+                warn!(
+                    id = id(item),
+                    ?err,
+                    "Skipping {kind} that failed to serialize"
+                );
+
+                None
+            }
+        })
+        .collect()
+}
+
+/// Whether a key with `remaining` seconds until expiration should have its
+/// original expiration of `expire_seconds` restored.
+///
+/// Negative values denote that the key is either missing or has no
+/// expiration in the first place.
+fn should_refresh_ttl(remaining: i64, expire_seconds: u64) -> bool {
+    u64::try_from(remaining).is_ok_and(|remaining| remaining * 4 < expire_seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use eyre::eyre;
+
+    use super::*;
+
+    struct Entity {
+        id: u64,
+        valid: bool,
+    }
+
+    fn serialize(entity: &Entity) -> Result<(Vec<u8>, u64)> {
+        if entity.valid {
+            Ok((entity.id.to_le_bytes().to_vec(), entity.id))
+        } else {
+            Err(eyre!("unserializable entity"))
+        }
+    }
+
+    #[test]
+    fn skips_failed_entities() {
+        let entities: Vec<_> = (1..=5).map(|id| Entity { id, valid: id != 3 }).collect();
+
+        let (bytes, ids) = collect_tolerant::<_, _, Zipped<Vec<_>, Vec<_>>>(
+            &entities,
+            "entity",
+            |e| e.id,
+            serialize,
+        )
+        .into_parts();
+
+        assert_eq!(ids, [1, 2, 4, 5]);
+        assert_eq!(bytes.len(), 4);
+        assert_eq!(bytes[2], 4_u64.to_le_bytes());
+    }
+
+    #[test]
+    fn all_failed_entities_yield_nothing() {
+        let entities = [
+            Entity {
+                id: 1,
+                valid: false,
+            },
+            Entity {
+                id: 2,
+                valid: false,
+            },
+        ];
+
+        let serialized: Vec<_> =
+            collect_tolerant::<_, _, Vec<_>>(&entities, "entity", |e| e.id, serialize);
+
+        assert!(serialized.is_empty());
+    }
+
+    #[test]
+    fn refresh_ttl_below_quarter() {
+        assert!(should_refresh_ttl(0, 1800));
+        assert!(should_refresh_ttl(449, 1800));
+        assert!(!should_refresh_ttl(450, 1800));
+        assert!(!should_refresh_ttl(1800, 1800));
+    }
+
+    #[test]
+    fn refresh_ttl_skips_missing_or_persistent() {
+        // -2: key does not exist; -1: key has no expiration
+        assert!(!should_refresh_ttl(-2, 1800));
+        assert!(!should_refresh_ttl(-1, 1800));
+    }
+}
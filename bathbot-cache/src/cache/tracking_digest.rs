@@ -0,0 +1,135 @@
+use bb8_redis::redis::{self, AsyncCommands};
+use eyre::{Result, WrapErr};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use crate::{Cache, key::RedisKey};
+
+/// Only the most recent entries are kept if a user's digest can't be sent for
+/// a while.
+const MAX_PENDING: isize = 100;
+
+/// Pending entries are flushed daily so anything older is stale.
+const DIGEST_EXPIRE_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// Failures only count as consecutive if the next one happens within two days.
+const FAILURES_EXPIRE_SECONDS: i64 = 2 * 24 * 60 * 60;
+
+impl Cache {
+    /// Append a line to the user's pending tracking digest.
+    pub async fn push_tracking_digest(&self, user: Id<UserMarker>, line: &str) -> Result<()> {
+        let mut conn = self.connection().await?;
+        let key = RedisKey::tracking_digest(user);
+
+        redis::pipe()
+            .rpush(&key, line)
+            .ignore()
+            .ltrim(&key, -MAX_PENDING, -1)
+            .ignore()
+            .expire(&key, DIGEST_EXPIRE_SECONDS)
+            .ignore()
+            .sadd(RedisKey::tracking_digest_users(), user.get())
+            .ignore()
+            .query_async::<()>(&mut *conn)
+            .await
+            .wrap_err("Failed to push tracking digest entry")
+    }
+
+    /// Users with a pending tracking digest.
+    pub async fn tracking_digest_users(&self) -> Result<Vec<Id<UserMarker>>> {
+        let users: Vec<u64> = self
+            .connection()
+            .await?
+            .smembers(RedisKey::tracking_digest_users())
+            .await
+            .wrap_err("Failed to get tracking digest users")?;
+
+        Ok(users.into_iter().filter_map(Id::new_checked).collect())
+    }
+
+    /// All pending lines of the user's tracking digest, oldest first.
+    pub async fn tracking_digest(&self, user: Id<UserMarker>) -> Result<Vec<String>> {
+        self.connection()
+            .await?
+            .lrange(RedisKey::tracking_digest(user), 0, -1)
+            .await
+            .wrap_err("Failed to get tracking digest")
+    }
+
+    /// Remove the oldest `amount` lines of the user's tracking digest.
+    ///
+    /// Lines that were pushed in the meantime are kept for the next digest.
+    pub async fn remove_tracking_digest(&self, user: Id<UserMarker>, amount: usize) -> Result<()> {
+        let mut conn = self.connection().await?;
+        let key = RedisKey::tracking_digest(user);
+
+        let remaining: usize = redis::pipe()
+            .ltrim(&key, amount as isize, -1)
+            .ignore()
+            .llen(&key)
+            .query_async::<(usize,)>(&mut *conn)
+            .await
+            .map(|(remaining,)| remaining)
+            .wrap_err("Failed to trim tracking digest")?;
+
+        if remaining == 0 {
+            conn.srem::<_, _, ()>(RedisKey::tracking_digest_users(), user.get())
+                .await
+                .wrap_err("Failed to remove tracking digest user")?;
+        }
+
+        Ok(())
+    }
+
+    /// Drop the user's tracking digest entirely.
+    pub async fn clear_tracking_digest(&self, user: Id<UserMarker>) -> Result<()> {
+        redis::pipe()
+            .del(RedisKey::tracking_digest(user))
+            .ignore()
+            .del(RedisKey::tracking_digest_failures(user))
+            .ignore()
+            .srem(RedisKey::tracking_digest_users(), user.get())
+            .ignore()
+            .query_async::<()>(&mut *self.connection().await?)
+            .await
+            .wrap_err("Failed to clear tracking digest")
+    }
+
+    /// Amount of consecutive failed attempts at sending the user's tracking
+    /// digest.
+    pub async fn tracking_digest_failures(&self, user: Id<UserMarker>) -> Result<u32> {
+        let failures: Option<u32> = self
+            .connection()
+            .await?
+            .get(RedisKey::tracking_digest_failures(user))
+            .await
+            .wrap_err("Failed to get tracking digest failures")?;
+
+        Ok(failures.unwrap_or(0))
+    }
+
+    /// Add a failed attempt at sending the user's tracking digest and return
+    /// the new amount of consecutive failures.
+    pub async fn add_tracking_digest_failure(&self, user: Id<UserMarker>) -> Result<u32> {
+        let mut conn = self.connection().await?;
+        let key = RedisKey::tracking_digest_failures(user);
+
+        let failures: u32 = conn
+            .incr(&key, 1)
+            .await
+            .wrap_err("Failed to increase tracking digest failures")?;
+
+        conn.expire::<_, ()>(&key, FAILURES_EXPIRE_SECONDS)
+            .await
+            .wrap_err("Failed to set expiration of tracking digest failures")?;
+
+        Ok(failures)
+    }
+
+    pub async fn clear_tracking_digest_failures(&self, user: Id<UserMarker>) -> Result<()> {
+        self.connection()
+            .await?
+            .del::<_, ()>(RedisKey::tracking_digest_failures(user))
+            .await
+            .wrap_err("Failed to delete tracking digest failures")
+    }
+}
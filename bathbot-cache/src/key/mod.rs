@@ -6,7 +6,7 @@ use twilight_model::{
     guild::{Guild, Member, Role},
     id::{
         Id,
-        marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
+        marker::{ChannelMarker, GuildMarker, MessageMarker, RoleMarker, UserMarker},
     },
     user::User,
 };
@@ -26,6 +26,14 @@ pub(crate) enum RedisKey<'a> {
 }
 
 impl RedisKey<'_> {
+    pub(crate) const fn active_messages() -> Self {
+        Self::Set(SetEntry::ActiveMessages)
+    }
+
+    pub(crate) fn bg_game_round(msg: Id<MessageMarker>) -> Self {
+        Self::Single(SingleEntry::BgGameRound { msg })
+    }
+
     pub(crate) fn channel(guild: Option<Id<GuildMarker>>, channel: Id<ChannelMarker>) -> Self {
         Self::Single(SingleEntry::Channel { guild, channel })
     }
@@ -34,10 +42,22 @@ impl RedisKey<'_> {
         Self::Set(SetEntry::Channels)
     }
 
+    pub(crate) const fn convert_hits() -> Self {
+        Self::Single(SingleEntry::ConvertHits)
+    }
+
+    pub(crate) const fn convert_values() -> Self {
+        Self::Single(SingleEntry::ConvertValues)
+    }
+
     pub(crate) const fn current_user() -> Self {
         Self::Single(SingleEntry::CurrentUser)
     }
 
+    pub(crate) const fn feature_disabled(name: &'static str) -> Self {
+        Self::Single(SingleEntry::FeatureDisabled { name })
+    }
+
     pub(crate) fn guild(guild: Id<GuildMarker>) -> Self {
         Self::Single(SingleEntry::Guild { guild })
     }
@@ -58,10 +78,30 @@ impl RedisKey<'_> {
         Self::Set(SetEntry::GuildRoles { guild })
     }
 
+    pub(crate) const fn link_strikes(osu_id: u32) -> Self {
+        Self::Single(SingleEntry::LinkStrikes { osu_id })
+    }
+
+    pub(crate) const fn maintenance() -> Self {
+        Self::Single(SingleEntry::Maintenance)
+    }
+
     pub(crate) fn member(guild: Id<GuildMarker>, user: Id<UserMarker>) -> Self {
         Self::Single(SingleEntry::Member { guild, user })
     }
 
+    pub(crate) const fn osutrack_update(user_id: u32, mode: u8) -> Self {
+        Self::Single(SingleEntry::OsuTrackUpdate { user_id, mode })
+    }
+
+    pub(crate) const fn recalc_cursor() -> Self {
+        Self::Single(SingleEntry::RecalcCursor)
+    }
+
+    pub(crate) const fn recalc_job() -> Self {
+        Self::Single(SingleEntry::RecalcJob)
+    }
+
     pub(crate) const fn resume_data() -> Self {
         Self::Single(SingleEntry::ResumeData)
     }
@@ -74,6 +114,22 @@ impl RedisKey<'_> {
         Self::Set(SetEntry::Roles)
     }
 
+    pub(crate) const fn stats_refresh_spent(day: i32) -> Self {
+        Self::Single(SingleEntry::StatsRefreshSpent { day })
+    }
+
+    pub(crate) fn tracking_digest(user: Id<UserMarker>) -> Self {
+        Self::Single(SingleEntry::TrackingDigest { user })
+    }
+
+    pub(crate) fn tracking_digest_failures(user: Id<UserMarker>) -> Self {
+        Self::Single(SingleEntry::TrackingDigestFailures { user })
+    }
+
+    pub(crate) const fn tracking_digest_users() -> Self {
+        Self::Set(SetEntry::TrackingDigestUsers)
+    }
+
     pub(crate) const fn unavailable_guilds() -> Self {
         Self::Set(SetEntry::UnavailableGuilds)
     }
@@ -5,12 +5,14 @@ use twilight_model::id::{Id, marker::GuildMarker};
 
 #[derive(Clone, Debug)]
 pub(crate) enum SetEntry {
+    ActiveMessages,
     Channels,
     Guilds,
     GuildChannels { guild: Id<GuildMarker> },
     GuildMembers { guild: Id<GuildMarker> },
     GuildRoles { guild: Id<GuildMarker> },
     Roles,
+    TrackingDigestUsers,
     UnavailableGuilds,
     Users,
 }
@@ -24,6 +26,7 @@ impl SetEntry {
         }
 
         match self {
+            SetEntry::ActiveMessages => res = Cow::Borrowed(b"ACTIVE_MESSAGES"),
             SetEntry::Channels => res = Cow::Borrowed(b"CHANNEL_IDS"),
             SetEntry::Guilds => res = Cow::Borrowed(b"GUILD_IDS"),
             SetEntry::GuildChannels { guild } => {
@@ -48,6 +51,7 @@ impl SetEntry {
                 push(res, buf.format(guild.get()));
             }
             SetEntry::Roles => res = Cow::Borrowed(b"ROLE_IDS"),
+            SetEntry::TrackingDigestUsers => res = Cow::Borrowed(b"TRACKING_DIGEST_USER_IDS"),
             SetEntry::UnavailableGuilds => res = Cow::Borrowed(b"UNAVAILABLE_GUILD_IDS"),
             SetEntry::Users => res = Cow::Borrowed(b"USER_IDS"),
         }
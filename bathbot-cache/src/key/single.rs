@@ -3,28 +3,55 @@ use std::borrow::Cow;
 use itoa::Buffer;
 use twilight_model::id::{
     Id,
-    marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
+    marker::{ChannelMarker, GuildMarker, MessageMarker, RoleMarker, UserMarker},
 };
 
 #[derive(Clone, Debug)]
 pub(crate) enum SingleEntry {
+    BgGameRound {
+        msg: Id<MessageMarker>,
+    },
+    ConvertHits,
+    ConvertValues,
     CurrentUser,
     Channel {
         guild: Option<Id<GuildMarker>>,
         channel: Id<ChannelMarker>,
     },
+    FeatureDisabled {
+        name: &'static str,
+    },
     Guild {
         guild: Id<GuildMarker>,
     },
+    LinkStrikes {
+        osu_id: u32,
+    },
+    Maintenance,
     Member {
         guild: Id<GuildMarker>,
         user: Id<UserMarker>,
     },
+    OsuTrackUpdate {
+        user_id: u32,
+        mode: u8,
+    },
+    RecalcCursor,
+    RecalcJob,
     ResumeData,
     Role {
         guild: Id<GuildMarker>,
         role: Id<RoleMarker>,
     },
+    StatsRefreshSpent {
+        day: i32,
+    },
+    TrackingDigest {
+        user: Id<UserMarker>,
+    },
+    TrackingDigestFailures {
+        user: Id<UserMarker>,
+    },
     User {
         user: Id<UserMarker>,
     },
@@ -39,6 +66,15 @@ impl SingleEntry {
         }
 
         match self {
+            Self::BgGameRound { msg } => {
+                let mut buf = Buffer::new();
+                let res = res.to_mut();
+
+                push(res, "BG_GAME_ROUND:");
+                push(res, buf.format(msg.get()));
+            }
+            Self::ConvertHits => res = Cow::Borrowed(b"CONVERT_HITS"),
+            Self::ConvertValues => res = Cow::Borrowed(b"CONVERT_VALUES"),
             Self::CurrentUser => res = Cow::Borrowed(b"CURRENT_USER"),
             Self::Channel { guild, channel } => {
                 let mut buf = Buffer::new();
@@ -55,6 +91,12 @@ impl SingleEntry {
                     None => push(res, buf.format(channel.get())),
                 }
             }
+            Self::FeatureDisabled { name } => {
+                let res = res.to_mut();
+
+                push(res, "FEATURE_DISABLED:");
+                push(res, name);
+            }
             Self::Guild { guild } => {
                 let mut buf = Buffer::new();
                 let res = res.to_mut();
@@ -62,6 +104,14 @@ impl SingleEntry {
                 push(res, "GUILD:");
                 push(res, buf.format(guild.get()));
             }
+            Self::LinkStrikes { osu_id } => {
+                let mut buf = Buffer::new();
+                let res = res.to_mut();
+
+                push(res, "LINK_STRIKES:");
+                push(res, buf.format(*osu_id));
+            }
+            Self::Maintenance => res = Cow::Borrowed(b"MAINTENANCE"),
             Self::Member { guild, user } => {
                 let mut buf = Buffer::new();
                 let res = res.to_mut();
@@ -71,6 +121,17 @@ impl SingleEntry {
                 res.push(b':');
                 push(res, buf.format(user.get()));
             }
+            Self::OsuTrackUpdate { user_id, mode } => {
+                let mut buf = Buffer::new();
+                let res = res.to_mut();
+
+                push(res, "OSUTRACK_UPDATE:");
+                push(res, buf.format(*user_id));
+                res.push(b':');
+                push(res, buf.format(*mode));
+            }
+            Self::RecalcCursor => res = Cow::Borrowed(b"RECALC_CURSOR"),
+            Self::RecalcJob => res = Cow::Borrowed(b"RECALC_JOB"),
             Self::ResumeData => res = Cow::Borrowed(b"RESUME_DATA"),
             Self::Role { guild, role } => {
                 let mut buf = Buffer::new();
@@ -81,6 +142,27 @@ impl SingleEntry {
                 res.push(b':');
                 push(res, buf.format(role.get()));
             }
+            Self::StatsRefreshSpent { day } => {
+                let mut buf = Buffer::new();
+                let res = res.to_mut();
+
+                push(res, "STATS_REFRESH_SPENT:");
+                push(res, buf.format(*day));
+            }
+            Self::TrackingDigest { user } => {
+                let mut buf = Buffer::new();
+                let res = res.to_mut();
+
+                push(res, "TRACKING_DIGEST:");
+                push(res, buf.format(user.get()));
+            }
+            Self::TrackingDigestFailures { user } => {
+                let mut buf = Buffer::new();
+                let res = res.to_mut();
+
+                push(res, "TRACKING_DIGEST_FAILURES:");
+                push(res, buf.format(user.get()));
+            }
             Self::User { user } => {
                 let mut buf = Buffer::new();
                 let res = res.to_mut();
@@ -1,5 +1,8 @@
 pub use self::{
-    cache::{Cache, FetchError},
+    cache::{
+        Cache, CacheSize, ConnectionError, EntitySize, Extrapolation, FetchError, ReconcileReport,
+        SetReport,
+    },
     key::ToCacheKey,
 };
 
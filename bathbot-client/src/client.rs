@@ -1,4 +1,4 @@
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use bytes::Bytes;
 use eyre::{Result, WrapErr};
@@ -6,7 +6,7 @@ use http_body_util::{BodyExt, Collected, Full};
 use hyper::{
     Method, Request, Response,
     body::Incoming,
-    header::{AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
+    header::{ACCEPT, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
 };
 use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
 use hyper_util::{
@@ -15,7 +15,11 @@ use hyper_util::{
 };
 
 use crate::{
-    ClientError, MY_USER_AGENT, Ratelimiters, Site, metrics::ClientMetrics, multipart::Multipart,
+    ClientError, MY_USER_AGENT, Ratelimiters, Site,
+    github::GithubQuota,
+    metrics::ClientMetrics,
+    multipart::Multipart,
+    retry::{Attempt, RetryOverride, RetryPolicies},
 };
 
 pub(crate) type InnerClient = HyperClient<HttpsConnector<HttpConnector>, Body>;
@@ -27,12 +31,14 @@ pub struct Client {
     twitch: bathbot_model::TwitchData,
     github_auth: Box<str>,
     ratelimiters: Ratelimiters,
+    retry_policies: RetryPolicies,
 }
 
 impl Client {
     pub async fn new(
         #[cfg(feature = "twitch")] (twitch_client_id, twitch_token): (&str, &str),
         github_token: &str,
+        retry_overrides: &[RetryOverride],
     ) -> Result<Self> {
         ClientMetrics::init();
 
@@ -56,6 +62,7 @@ impl Client {
         Ok(Self {
             client,
             ratelimiters: Ratelimiters::new(),
+            retry_policies: RetryPolicies::new(retry_overrides),
             #[cfg(feature = "twitch")]
             twitch,
             github_auth: format!("Bearer {github_token}").into_boxed_str(),
@@ -114,6 +121,46 @@ impl Client {
         bytes_res
     }
 
+    /// Unauthenticated GET request to github's REST api.
+    ///
+    /// Also returns the remaining quota as provided by the response headers.
+    pub(crate) async fn make_github_get_request(
+        &self,
+        url: impl AsRef<str>,
+    ) -> Result<(Bytes, Option<GithubQuota>), ClientError> {
+        let url = url.as_ref();
+        trace!("GET request to url {url}");
+
+        let req = Request::builder()
+            .uri(url)
+            .method(Method::GET)
+            .header(USER_AGENT, MY_USER_AGENT)
+            .header(ACCEPT, "application/vnd.github+json")
+            .body(Body::default())
+            .wrap_err("Failed to build GET request")?;
+
+        let (response, start) = self
+            .send_request(req, Site::Github)
+            .await
+            .wrap_err("Failed to receive GET response")?;
+
+        let status = response.status();
+        let quota = GithubQuota::from_headers(response.headers());
+
+        // Github responds with 403 instead of 429 once the quota is exhausted
+        let bytes_res = match (status.as_u16(), quota) {
+            (403 | 429, Some(quota)) if quota.remaining == 0 => {
+                Err(ClientError::GithubQuotaExhausted { reset: quota.reset })
+            }
+            _ => Self::error_for_status(response, url).await,
+        };
+
+        let latency = start.elapsed();
+        ClientMetrics::observe(Site::Github, status, latency);
+
+        bytes_res.map(|bytes| (bytes, quota))
+    }
+
     pub(crate) async fn make_multipart_post_request(
         &self,
         url: impl AsRef<str>,
@@ -212,23 +259,149 @@ impl Client {
         }
     }
 
+    /// Send the request and retry it according to the site's retry policy.
+    ///
+    /// The returned instant denotes the start of the last attempt.
     async fn send_request(
         &self,
         req: Request<Body>,
         site: Site,
     ) -> Result<(Response<Incoming>, Instant), HyperError> {
-        self.ratelimit(site).await;
+        let policy = self.retry_policies.get(site);
+        let mut retries = 0;
+        let mut first_failure: Option<Instant> = None;
+
+        loop {
+            self.ratelimit(site).await;
+
+            let start = Instant::now();
+            let res = self.client.request(clone_request(&req)).await;
+
+            let attempt = match res {
+                Ok(ref response) => Attempt::Status(response.status()),
+                Err(ref err) if err.is_connect() => Attempt::Connection,
+                Err(_) => Attempt::Other,
+            };
+
+            let spent = first_failure.map_or(Duration::ZERO, |instant| instant.elapsed());
 
-        let start = Instant::now();
-        let response_fut = self.client.request(req);
+            if let Some(delay) = policy.next_delay(attempt, retries, spent, rand::random()) {
+                debug!(?site, ?attempt, ?delay, "Retrying request");
 
-        match response_fut.await {
-            Ok(res) => Ok((res, start)),
-            Err(err) => {
-                ClientMetrics::internal_error(site);
+                first_failure.get_or_insert_with(Instant::now);
+                retries += 1;
+                tokio::time::sleep(delay).await;
 
-                Err(err)
+                continue;
             }
+
+            if retries > 0 {
+                let success = matches!(res, Ok(ref response) if response.status().is_success());
+                ClientMetrics::retries(site, success, retries);
+            }
+
+            return match res {
+                Ok(response) => Ok((response, start)),
+                Err(err) => {
+                    ClientMetrics::internal_error(site);
+
+                    Err(err)
+                }
+            };
         }
     }
 }
+
+/// Requests can't be cloned so we build a new one with the same content.
+fn clone_request(req: &Request<Body>) -> Request<Body> {
+    let mut clone = Request::new(req.body().clone());
+    *clone.method_mut() = req.method().clone();
+    *clone.uri_mut() = req.uri().clone();
+    *clone.version_mut() = req.version();
+    *clone.headers_mut() = req.headers().clone();
+
+    clone
+}
+
+#[cfg(all(test, not(feature = "twitch")))]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    /// Serve the given responses in order, one per connection.
+    async fn mock_server(responses: &'static [&'static str]) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut buf = [0; 1024];
+                let _ = stream.read(&mut buf).await.unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+                stream.write_all(response.as_bytes()).await.unwrap();
+                stream.shutdown().await.unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    const UNAVAILABLE: &str = "HTTP/1.1 503 Service Unavailable\r\n\
+        Content-Length: 0\r\n\
+        Connection: close\r\n\r\n";
+
+    const OK: &str = "HTTP/1.1 200 OK\r\n\
+        Content-Length: 2\r\n\
+        Connection: close\r\n\r\n\
+        ok";
+
+    const NOT_FOUND: &str = "HTTP/1.1 404 Not Found\r\n\
+        Content-Length: 0\r\n\
+        Connection: close\r\n\r\n";
+
+    #[tokio::test]
+    async fn retry_unavailable_service() {
+        let (url, requests) = mock_server(&[UNAVAILABLE, OK]).await;
+        let client = Client::new("", &[]).await.unwrap();
+
+        let bytes = client.make_get_request(url, Site::OsuStats).await.unwrap();
+
+        assert_eq!(bytes.as_ref(), b"ok");
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn no_retry_on_not_found() {
+        let (url, requests) = mock_server(&[NOT_FOUND, OK]).await;
+        let client = Client::new("", &[]).await.unwrap();
+
+        let res = client.make_get_request(url, Site::OsuStats).await;
+
+        assert!(matches!(res, Err(ClientError::NotFound)));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn give_up_after_max_retries() {
+        let (url, requests) = mock_server(&[UNAVAILABLE, UNAVAILABLE, OK]).await;
+        let overrides = ["OsuStats=1:10".parse().unwrap()];
+        let client = Client::new("", &overrides).await.unwrap();
+
+        let res = client.make_get_request(url, Site::OsuStats).await;
+
+        assert!(matches!(res, Err(ClientError::Report(_))));
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+}
@@ -1,5 +1,6 @@
 use eyre::Report;
 use thiserror::Error;
+use time::OffsetDateTime;
 
 #[derive(Debug, Error)]
 pub enum ClientError {
@@ -9,6 +10,8 @@ pub enum ClientError {
     NotFound,
     #[error("status code 429 - ratelimited")]
     Ratelimited,
+    #[error("github quota exhausted until {reset}")]
+    GithubQuotaExhausted { reset: OffsetDateTime },
     #[error(transparent)]
     Report(#[from] Report),
 }
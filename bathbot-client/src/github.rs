@@ -1,10 +1,15 @@
-use bathbot_model::{GraphQLResponse, OnlyPullRequests, PullRequests, PullRequestsAndTags};
+use bathbot_model::{
+    GraphQLResponse, OnlyPullRequests, PullRequests, PullRequestsAndTags, Release,
+};
 use eyre::{Result, WrapErr};
+use http::HeaderMap;
 use serde::Serialize;
+use time::OffsetDateTime;
 
-use crate::{Client, site::Site};
+use crate::{Client, ClientError, site::Site};
 
 const URL: &str = "https://api.github.com/graphql";
+const RELEASES_URL: &str = "https://api.github.com/repos/MaxOhn/Bathbot/releases";
 
 impl Client {
     pub async fn github_pull_requests_and_tags(&self) -> Result<PullRequestsAndTags> {
@@ -122,6 +127,45 @@ query ($cursor: String!) {
 
         Ok(data)
     }
+
+    /// Request the most recent releases.
+    pub async fn github_releases(
+        &self,
+        amount: usize,
+    ) -> Result<(Vec<Release>, Option<GithubQuota>), ClientError> {
+        let url = format!("{RELEASES_URL}?per_page={amount}");
+        let (bytes, quota) = self.make_github_get_request(url).await?;
+
+        let releases = serde_json::from_slice(&bytes).wrap_err_with(|| {
+            let body = String::from_utf8_lossy(&bytes);
+
+            format!("Failed to deserialize github releases: {body}")
+        })?;
+
+        Ok((releases, quota))
+    }
+}
+
+/// Remaining requests for github's REST api.
+#[derive(Copy, Clone, Debug)]
+pub struct GithubQuota {
+    pub remaining: u32,
+    /// When the quota resets
+    pub reset: OffsetDateTime,
+}
+
+impl GithubQuota {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        fn parse_header<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+            headers.get(name)?.to_str().ok()?.parse().ok()
+        }
+
+        let remaining = parse_header(headers, "x-ratelimit-remaining")?;
+        let reset = parse_header(headers, "x-ratelimit-reset")?;
+        let reset = OffsetDateTime::from_unix_timestamp(reset).ok()?;
+
+        Some(Self { remaining, reset })
+    }
 }
 
 #[derive(Serialize)]
@@ -17,11 +17,12 @@ mod osustats;
 mod osutrack;
 mod relax;
 mod respektive;
+mod retry;
 mod site;
 mod snipe;
 mod twitch;
 
 use self::site::{Ratelimiters, Site};
-pub use self::{client::Client, error::ClientError};
+pub use self::{client::Client, error::ClientError, github::GithubQuota, retry::RetryOverride};
 
 static MY_USER_AGENT: &str = env!("CARGO_PKG_NAME");
@@ -7,6 +7,7 @@ use crate::site::Site;
 
 const CLIENT_RESPONSE_TIME: &str = "client_response_time";
 const CLIENT_INTERNAL_ERRORS: &str = "client_internal_errors";
+const CLIENT_RETRIES: &str = "client_retries";
 
 pub(crate) struct ClientMetrics;
 
@@ -21,6 +22,11 @@ impl ClientMetrics {
             CLIENT_INTERNAL_ERRORS,
             "Number of times an internal error occurred"
         );
+
+        describe_counter!(
+            CLIENT_RETRIES,
+            "Number of request retries by whether the request eventually succeeded"
+        );
     }
 
     pub(crate) fn observe(site: Site, status: StatusCode, latency: Duration) {
@@ -35,4 +41,11 @@ impl ClientMetrics {
     pub(crate) fn internal_error(site: Site) {
         counter!(CLIENT_INTERNAL_ERRORS, "site" => site.as_str()).increment(1);
     }
+
+    pub(crate) fn retries(site: Site, success: bool, retries: u8) {
+        let outcome = if success { "success" } else { "failure" };
+
+        counter!(CLIENT_RETRIES, "site" => site.as_str(), "outcome" => outcome)
+            .increment(u64::from(retries));
+    }
 }
@@ -1,6 +1,6 @@
 use std::time::Instant;
 
-use bathbot_model::{OsuTrackHistoryEntry, RankAccPeaks};
+use bathbot_model::{OsuTrackHistoryEntry, OsuTrackUpdate, RankAccPeaks};
 use eyre::{Report, Result, WrapErr};
 use hyper::{Request, header::USER_AGENT};
 use rosu_v2::model::GameMode;
@@ -56,6 +56,15 @@ impl Client {
         user_id: u32,
         mode: GameMode,
     ) -> Result<(), ClientError> {
+        self.osutrack_update(user_id, mode).await.map(|_| ())
+    }
+
+    /// Let osu!track record a new datapoint for the user.
+    pub async fn osutrack_update(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+    ) -> Result<OsuTrackUpdate, ClientError> {
         let url = format!(
             "https://osutrack-api.ameo.dev/update?user={user_id}&mode={mode}",
             mode = mode as u8
@@ -77,22 +86,16 @@ impl Client {
         })?;
 
         let status = response.status();
-
-        match status.as_u16() {
-            200..=299 => {}
-            400 => return Err(ClientError::BadRequest),
-            404 => return Err(ClientError::NotFound),
-            429 => return Err(ClientError::Ratelimited),
-            _ => {
-                let err = eyre!("Failed with status code {status} when requesting url {url}");
-
-                return Err(err.into());
-            }
-        };
+        let bytes = Self::error_for_status(response, &url).await?;
 
         let latency = start.elapsed();
         ClientMetrics::observe(Site::OsuTrack, status, latency);
 
-        Ok(())
+        serde_json::from_slice(&bytes).map_err(|err| {
+            let body = String::from_utf8_lossy(&bytes);
+            let wrap = format!("Failed to deserialize osutrack update: {body}");
+
+            ClientError::Report(Report::new(err).wrap_err(wrap))
+        })
     }
 }
@@ -0,0 +1,253 @@
+use std::{str::FromStr, time::Duration};
+
+use hyper::StatusCode;
+
+use crate::site::Site;
+
+/// Maximum amount of additional time spent on retries for a single request so
+/// that interactive commands don't hang for too long.
+const RETRY_BUDGET: Duration = Duration::from_secs(4);
+
+const DEFAULT_RETRY_STATUSES: &[u16] = &[502, 503, 504];
+
+/// How requests to a site are retried.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct RetryPolicy {
+    pub max_retries: u8,
+    /// Delay before the first retry, doubled for each further retry.
+    pub backoff_base: Duration,
+    pub retry_statuses: &'static [u16],
+}
+
+/// Outcome of a single request attempt.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum Attempt {
+    Status(StatusCode),
+    /// The connection could not be established or timed out.
+    Connection,
+    /// Any other failure that is not worth retrying.
+    Other,
+}
+
+impl RetryPolicy {
+    const fn new(max_retries: u8, backoff_base_ms: u64) -> Self {
+        Self {
+            max_retries,
+            backoff_base: Duration::from_millis(backoff_base_ms),
+            retry_statuses: DEFAULT_RETRY_STATUSES,
+        }
+    }
+
+    /// Default policy of each site.
+    const fn default_for(site: Site) -> Self {
+        match site {
+            // Comparatively slow to recover so give them more time
+            Site::OsuStats | Site::OsuTrack | Site::Respektive => Self::new(2, 500),
+            // Quota-limited; don't waste requests
+            Site::Github => Self::new(1, 250),
+            // Uploads that shouldn't be sent twice
+            Site::DiscordAttachment | Site::MissAnalyzer => Self::new(0, 0),
+            _ => Self::new(2, 250),
+        }
+    }
+
+    /// Decide whether an attempt should be retried and if so, how long to
+    /// wait beforehand.
+    ///
+    /// - `retries`: amount of retries that have already been done
+    /// - `spent`: time spent on retries so far
+    /// - `jitter`: random value in `0.0..1.0`
+    pub(crate) fn next_delay(
+        &self,
+        attempt: Attempt,
+        retries: u8,
+        spent: Duration,
+        jitter: f64,
+    ) -> Option<Duration> {
+        let retryable = match attempt {
+            Attempt::Status(status) => self.retry_statuses.contains(&status.as_u16()),
+            Attempt::Connection => true,
+            Attempt::Other => false,
+        };
+
+        if !retryable || retries >= self.max_retries {
+            return None;
+        }
+
+        let backoff = self.backoff_base.saturating_mul(1 << retries.min(16));
+
+        // Jitter between 50% and 100% of the backoff to spread out retries
+        let delay = backoff.mul_f64(0.5 + jitter.clamp(0.0, 1.0) / 2.0);
+
+        (spent + delay <= RETRY_BUDGET).then_some(delay)
+    }
+}
+
+/// Retry policy of each site with configured overrides applied.
+pub(crate) struct RetryPolicies {
+    inner: Box<[RetryPolicy]>,
+}
+
+impl RetryPolicies {
+    pub(crate) fn new(overrides: &[RetryOverride]) -> Self {
+        let mut inner: Box<[_]> = Site::ALL
+            .iter()
+            .map(|&site| RetryPolicy::default_for(site))
+            .collect();
+
+        for &RetryOverride {
+            site,
+            max_retries,
+            backoff_base,
+        } in overrides
+        {
+            let policy = &mut inner[site as usize];
+            policy.max_retries = max_retries;
+
+            if let Some(backoff_base) = backoff_base {
+                policy.backoff_base = backoff_base;
+            }
+        }
+
+        Self { inner }
+    }
+
+    pub(crate) fn get(&self, site: Site) -> &RetryPolicy {
+        &self.inner[site as usize]
+    }
+}
+
+/// Configured deviation from a site's default retry policy.
+///
+/// Parsed from `{site}={max retries}` or
+/// `{site}={max retries}:{backoff base in ms}` e.g. `OsuStats=3:400`.
+#[derive(Copy, Clone, Debug)]
+pub struct RetryOverride {
+    site: Site,
+    max_retries: u8,
+    backoff_base: Option<Duration>,
+}
+
+impl FromStr for RetryOverride {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (site, policy) = s.trim().split_once('=').ok_or(())?;
+        let site = site.trim().parse()?;
+
+        let (max_retries, backoff_base) = match policy.split_once(':') {
+            Some((max_retries, backoff_base)) => {
+                let backoff_base = backoff_base.trim().parse().map_err(|_| ())?;
+
+                (max_retries, Some(Duration::from_millis(backoff_base)))
+            }
+            None => (policy, None),
+        };
+
+        Ok(Self {
+            site,
+            max_retries: max_retries.trim().parse().map_err(|_| ())?,
+            backoff_base,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POLICY: RetryPolicy = RetryPolicy::new(2, 250);
+
+    fn status(code: u16) -> Attempt {
+        Attempt::Status(StatusCode::from_u16(code).unwrap())
+    }
+
+    #[test]
+    fn retry_on_server_errors() {
+        for code in [502, 503, 504] {
+            let delay = POLICY.next_delay(status(code), 0, Duration::ZERO, 1.0);
+            assert_eq!(delay, Some(Duration::from_millis(250)));
+        }
+
+        let delay = POLICY.next_delay(Attempt::Connection, 0, Duration::ZERO, 1.0);
+        assert_eq!(delay, Some(Duration::from_millis(250)));
+    }
+
+    #[test]
+    fn no_retry_on_client_errors() {
+        for code in [400, 401, 403, 404, 429] {
+            assert_eq!(
+                POLICY.next_delay(status(code), 0, Duration::ZERO, 0.0),
+                None
+            );
+        }
+
+        assert_eq!(POLICY.next_delay(status(500), 0, Duration::ZERO, 0.0), None);
+        assert_eq!(
+            POLICY.next_delay(Attempt::Other, 0, Duration::ZERO, 0.0),
+            None
+        );
+    }
+
+    #[test]
+    fn exponential_backoff_with_jitter() {
+        let delay =
+            |retries, jitter| POLICY.next_delay(status(503), retries, Duration::ZERO, jitter);
+
+        assert_eq!(delay(0, 0.0), Some(Duration::from_millis(125)));
+        assert_eq!(delay(1, 0.0), Some(Duration::from_millis(250)));
+        assert_eq!(delay(1, 1.0), Some(Duration::from_millis(500)));
+        assert_eq!(delay(1, 0.5), Some(Duration::from_millis(375)));
+    }
+
+    #[test]
+    fn stop_after_max_retries() {
+        let last_retry = POLICY.next_delay(status(503), 1, Duration::ZERO, 0.0);
+        assert!(last_retry.is_some());
+
+        let exceeded = POLICY.next_delay(status(503), 2, Duration::ZERO, 0.0);
+        assert_eq!(exceeded, None);
+
+        let never = RetryPolicy::new(0, 250);
+        assert_eq!(never.next_delay(status(503), 0, Duration::ZERO, 0.0), None);
+    }
+
+    #[test]
+    fn respect_retry_budget() {
+        let spent = RETRY_BUDGET - Duration::from_millis(200);
+        assert_eq!(POLICY.next_delay(status(503), 0, spent, 1.0), None);
+
+        let delay = POLICY.next_delay(status(503), 0, spent, 0.0);
+        assert_eq!(delay, Some(Duration::from_millis(125)));
+    }
+
+    #[test]
+    fn apply_overrides() {
+        let overrides = [
+            "OsuStats=3:400".parse().unwrap(),
+            " Github = 0 ".parse().unwrap(),
+        ];
+
+        let policies = RetryPolicies::new(&overrides);
+
+        let osustats = policies.get(Site::OsuStats);
+        assert_eq!(osustats.max_retries, 3);
+        assert_eq!(osustats.backoff_base, Duration::from_millis(400));
+
+        let github = policies.get(Site::Github);
+        assert_eq!(github.max_retries, 0);
+        assert_eq!(github.backoff_base, Duration::from_millis(250));
+
+        assert_eq!(
+            *policies.get(Site::Osekai),
+            RetryPolicy::default_for(Site::Osekai)
+        );
+    }
+
+    #[test]
+    fn parse_invalid_overrides() {
+        for s in ["OsuStats", "Unknown=2", "OsuStats=-1", "OsuStats=2:fast"] {
+            assert!(s.parse::<RetryOverride>().is_err(), "{s}");
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{str::FromStr, time::Duration};
 
 use leaky_bucket_lite::LeakyBucket;
 
@@ -28,6 +28,8 @@ macro_rules! sites {
         }
 
         impl Site {
+            pub const ALL: &[Self] = &[ $( Self::$variant, )* ];
+
             pub fn as_str(self) -> &'static str {
                 match self {
                     $( Self::$variant => stringify!($variant), )*
@@ -35,6 +37,20 @@ macro_rules! sites {
             }
         }
 
+        impl FromStr for Site {
+            type Err = ();
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $(
+                    if s.eq_ignore_ascii_case(stringify!($variant)) {
+                        return Ok(Self::$variant);
+                    }
+                )*
+
+                Err(())
+            }
+        }
+
         fn make_buckets() -> Box<[LeakyBucket]> {
             let make_bucket = |per_second| {
                 LeakyBucket::builder()
@@ -1,8 +1,9 @@
 use std::{collections::BTreeMap, fmt::Write};
 
 use bathbot_model::{
-    SnipeCountries, SnipeCountryListOrder, SnipeCountryPlayer, SnipeCountryStatistics, SnipePlayer,
-    SnipePlayerHistory, SnipeRecent, SnipeScore, SnipeScoreParams,
+    SnipeCountries, SnipeCountryListOrder, SnipeCountryPlayer, SnipeCountryRankingPlayer,
+    SnipeCountryStatistics, SnipePlayer, SnipePlayerHistory, SnipeRecent, SnipeScore,
+    SnipeScoreParams,
 };
 use bathbot_util::{
     constants::HUISMETBENEN,
@@ -69,6 +70,25 @@ pub async fn get_snipe_country(
     })
 }
 
+pub async fn get_snipe_country_ranking(
+    client: &Client,
+    country: &str,
+    sort: &str,
+) -> Result<Vec<SnipeCountryRankingPlayer>> {
+    let url = format!(
+        "{HUISMETBENEN}rankings/{country}/{sort}?changes=30",
+        country = country.to_lowercase(),
+    );
+
+    let bytes = client.make_get_request(url, Site::Huismetbenen).await?;
+
+    serde_json::from_slice(&bytes).wrap_err_with(|| {
+        let body = String::from_utf8_lossy(&bytes);
+
+        format!("Failed to deserialize huismetbenen country player ranking: {body}")
+    })
+}
+
 pub async fn get_country_statistics(
     client: &Client,
     country: &str,
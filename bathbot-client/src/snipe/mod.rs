@@ -5,8 +5,9 @@ use std::{
 };
 
 use bathbot_model::{
-    SnipeCountries, SnipeCountryListOrder, SnipeCountryPlayer, SnipeCountryStatistics, SnipePlayer,
-    SnipePlayerListOrder, SnipeRecent, SnipeScore, SnipeScoreParams, SnipedPlayer, SnipedWeek,
+    SnipeCountries, SnipeCountryListOrder, SnipeCountryPlayer, SnipeCountryPlayersOrder,
+    SnipeCountryRankingPlayer, SnipeCountryStatistics, SnipePlayer, SnipePlayerListOrder,
+    SnipeRecent, SnipeScore, SnipeScoreParams, SnipedPlayer, SnipedWeek,
 };
 use bathbot_util::IntHasher;
 use eyre::Result;
@@ -145,6 +146,20 @@ impl Client {
         }
     }
 
+    /// Players of a country ranked by their national #1s, including the
+    /// amount of #1s gained and lost within the last 30 days.
+    ///
+    /// Only available for osu!standard.
+    pub async fn get_snipe_country_ranking(
+        &self,
+        country_code: &str,
+        sort: SnipeCountryPlayersOrder,
+    ) -> Result<Vec<SnipeCountryRankingPlayer>> {
+        let sort = sort.as_huismetbenen_str().unwrap_or("count");
+
+        huismetbenen::get_snipe_country_ranking(self, country_code, sort).await
+    }
+
     pub async fn get_country_statistics(
         &self,
         country_code: &str,
@@ -17,7 +17,11 @@ mod util;
 /// Make sure there is a function in scope with the signature
 /// `async fn slash_{lowercased_name}(Arc<Context>, InteractionCommand) ->
 /// Result<()>`
-#[proc_macro_derive(SlashCommand, attributes(bucket, command, flags))]
+///
+/// Subcommands that only display data can be listed by their path through
+/// `#[read_only("list", "group list")]` so that auditable commands don't
+/// record them.
+#[proc_macro_derive(SlashCommand, attributes(bucket, command, flags, read_only))]
 pub fn slash_command(input: TokenStream) -> TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
 
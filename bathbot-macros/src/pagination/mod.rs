@@ -104,12 +104,14 @@ pub(super) fn impl_derive(input: DeriveInput) -> Result<TokenStream, Error> {
             pub fn builder() -> #builder_name {
                 #builder_name {
                     #( #assigned_fields ,)*
+                    start_page: None,
                 }
             }
         }
 
         pub struct #builder_name {
             #( #builder_fields ,)*
+            start_page: ::core::option::Option<usize>,
         }
 
         impl #builder_name {
@@ -117,13 +119,25 @@ pub(super) fn impl_derive(input: DeriveInput) -> Result<TokenStream, Error> {
                 #( #finalized_vars ;)*
 
                 let pages_len: usize = #pages_len;
+                let mut pages = crate::active::pagination::Pages::new( #per_page, pages_len);
+
+                if let Some(page) = self.start_page.take() {
+                    pages.set_page(page);
+                }
 
                 #ident {
-                    pages: crate::active::pagination::Pages::new( #per_page, pages_len),
+                    pages,
                     #( #finalized_fields ,)*
                 }
             }
 
+            /// Page to show initially, clamped to the available pages.
+            pub fn start_page(&mut self, start_page: Option<usize>) -> &mut Self {
+                self.start_page = start_page;
+
+                self
+            }
+
             #( #builder_methods )*
         }
     };
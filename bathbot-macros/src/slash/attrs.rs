@@ -1,16 +1,17 @@
 use proc_macro2::Span;
-use syn::{Attribute, Error, LitBool, LitStr, Result};
+use syn::{Attribute, Error, LitBool, LitStr, Result, Token};
 
 use crate::{
     bucket::Bucket,
     flags::Flags,
-    util::{AsOption, LitOrConst},
+    util::{AsOption, LitOrConst, PunctuatedExt},
 };
 
 pub(super) struct CommandAttrs {
     pub(super) bucket: AsOption<Bucket>,
     pub(super) flags: Flags,
     pub(super) name_lit: LitStr,
+    pub(super) read_only: Vec<LitStr>,
 }
 
 impl CommandAttrs {
@@ -18,12 +19,15 @@ impl CommandAttrs {
         let mut bucket = None;
         let mut flags = None;
         let mut name_lit = None;
+        let mut read_only = Vec::new();
 
         for attr in attrs {
             if attr.path().is_ident("bucket") {
                 bucket = Some(attr.parse_args()?);
             } else if attr.path().is_ident("flags") {
                 flags = Some(attr.parse_args()?);
+            } else if attr.path().is_ident("read_only") {
+                read_only = attr.parse_args_with(Vec::parse_terminated::<Token![,]>)?;
             } else if attr.path().is_ident("command") {
                 attr.parse_nested_meta(|meta| {
                     if meta.path.is_ident("name") {
@@ -50,6 +54,7 @@ impl CommandAttrs {
             name_lit: name_lit.ok_or_else(|| {
                 Error::new(Span::call_site(), "missing #[command(name = \"...\")]")
             })?,
+            read_only,
         })
     }
 }
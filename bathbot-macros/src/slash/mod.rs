@@ -16,6 +16,7 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream> {
         bucket,
         flags,
         name_lit,
+        read_only,
     } = CommandAttrs::parse_attrs(&input.attrs)?;
 
     let name = input.ident;
@@ -33,6 +34,7 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream> {
             exec: #exec,
             flags: #flags,
             name: #name_lit,
+            read_only: &[#(#read_only),*],
             id: std::sync::OnceLock::new(),
         };
 
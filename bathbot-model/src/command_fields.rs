@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use bathbot_util::i18n::Lang;
 use rosu_v2::prelude::{GameMode, Grade};
 use time::UtcOffset;
 use twilight_interactions::command::{CommandOption, CreateOption};
@@ -40,6 +41,18 @@ pub enum GameModeOption {
     Mania,
 }
 
+impl GameModeOption {
+    /// The option's value as it's specified in slash commands.
+    pub fn value(self) -> &'static str {
+        match self {
+            Self::Osu => "osu",
+            Self::Taiko => "taiko",
+            Self::Catch => "ctb",
+            Self::Mania => "mania",
+        }
+    }
+}
+
 impl From<GameModeOption> for GameMode {
     #[inline]
     fn from(mode: GameModeOption) -> Self {
@@ -64,6 +77,24 @@ impl From<GameMode> for GameModeOption {
     }
 }
 
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum LangOption {
+    #[option(name = "English", value = "en")]
+    En,
+    #[option(name = "Deutsch", value = "de")]
+    De,
+}
+
+impl From<LangOption> for Lang {
+    #[inline]
+    fn from(lang: LangOption) -> Self {
+        match lang {
+            LangOption::En => Self::En,
+            LangOption::De => Self::De,
+        }
+    }
+}
+
 #[derive(Copy, Clone, CommandOption, CreateOption)]
 pub enum GradeOption {
     #[option(name = "SS", value = "ss")]
@@ -6,10 +6,79 @@ use std::{
 
 use bathbot_util::CowUtils;
 use once_cell::sync::OnceCell;
+use rosu_v2::prelude::CountryCode;
 use time::UtcOffset;
 
 static COUNTRIES: OnceCell<Countries> = OnceCell::new();
 
+/// Names and codes that aren't part of the main table, e.g. historical codes,
+/// local names, or common misspellings. Keys must be lowercase.
+const ALIASES: &[(&str, &str)] = &[
+    // Unofficial or historical codes
+    ("uk", "GB"),
+    ("su", "RU"),
+    ("yu", "RS"),
+    ("cs", "RS"),
+    ("zr", "CD"),
+    ("tp", "TL"),
+    ("bu", "MM"),
+    ("dd", "DE"),
+    ("an", "CW"),
+    // Local names
+    ("deutschland", "DE"),
+    ("österreich", "AT"),
+    ("osterreich", "AT"),
+    ("schweiz", "CH"),
+    ("suisse", "CH"),
+    ("españa", "ES"),
+    ("espana", "ES"),
+    ("italia", "IT"),
+    ("nederland", "NL"),
+    ("holland", "NL"),
+    ("belgië", "BE"),
+    ("belgique", "BE"),
+    ("polska", "PL"),
+    ("česko", "CZ"),
+    ("cesko", "CZ"),
+    ("suomi", "FI"),
+    ("sverige", "SE"),
+    ("norge", "NO"),
+    ("danmark", "DK"),
+    ("brasil", "BR"),
+    ("méxico", "MX"),
+    ("nippon", "JP"),
+    ("nihon", "JP"),
+    ("rossiya", "RU"),
+    ("türkiye", "TR"),
+    ("turkiye", "TR"),
+    ("korea", "KR"),
+    ("viet nam", "VN"),
+    ("pilipinas", "PH"),
+    // Common misspellings
+    ("phillipines", "PH"),
+    ("philipines", "PH"),
+    ("phillippines", "PH"),
+    ("columbia", "CO"),
+    ("argentinia", "AR"),
+    ("austrailia", "AU"),
+    ("australija", "AU"),
+    ("new zeland", "NZ"),
+    ("sweeden", "SE"),
+    ("finnland", "FI"),
+    ("swizerland", "CH"),
+    ("portugual", "PT"),
+    ("netherland", "NL"),
+    ("untied states", "US"),
+];
+
+/// Subdivision pseudo-codes that osu! shows on some profiles.
+const SUBDIVISIONS: &[(&str, &str)] = &[
+    ("GB-ENG", "england"),
+    ("GB-NIR", "northern ireland"),
+    ("GB-SCT", "scotland"),
+    ("GB-WLS", "wales"),
+];
+
 pub struct Countries {
     name_to_code: HashMap<&'static str, &'static str>,
     code_to_name: HashMap<&'static str, &'static str>,
@@ -25,6 +94,60 @@ impl Countries {
         Name(country_name)
     }
 
+    /// Resolve user input to a country or subdivision.
+    ///
+    /// Accepts country codes, subdivision pseudo-codes such as `GB-SCT`,
+    /// country and subdivision names, as well as aliases like `UK` or
+    /// `Deutschland`. Any other two ASCII letters are passed through as is.
+    pub fn resolve(input: &str) -> Option<CountryResolution> {
+        let countries = unsafe { COUNTRIES.get_unchecked() };
+
+        let input = input.trim();
+        let upper = input.cow_to_ascii_uppercase();
+
+        if let Some((code, name)) = countries.code_to_name.get_key_value(upper.as_ref()) {
+            return Some(CountryResolution::country(*code, *name));
+        }
+
+        let lower = input.to_lowercase();
+
+        let subdivision = SUBDIVISIONS
+            .iter()
+            .find(|(code, name)| *code == upper.as_ref() || *name == lower);
+
+        if let Some(&(code, name)) = subdivision {
+            return Some(CountryResolution {
+                code: Cow::Borrowed(code),
+                display_name: CountryName(name).to_string(),
+                is_subdivision: true,
+            });
+        }
+
+        let country = countries
+            .name_to_code
+            .get(lower.as_str())
+            .or_else(|| {
+                ALIASES
+                    .iter()
+                    .find_map(|(alias, code)| (*alias == lower).then_some(code))
+            })
+            .and_then(|code| countries.code_to_name.get_key_value(code));
+
+        if let Some((code, name)) = country {
+            return Some(CountryResolution::country(*code, *name));
+        }
+
+        if input.len() == 2 && input.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Some(CountryResolution {
+                display_name: upper.as_ref().to_owned(),
+                code: Cow::Owned(upper.into_owned()),
+                is_subdivision: false,
+            });
+        }
+
+        None
+    }
+
     pub fn init() {
         let mut countries = Countries {
             name_to_code: HashMap::with_capacity(256),
@@ -353,6 +476,64 @@ impl<'a> Code<'a> {
     }
 }
 
+/// The result of [`Countries::resolve`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CountryResolution {
+    /// Uppercase country code or subdivision pseudo-code such as `GB-SCT`.
+    pub code: Cow<'static, str>,
+    pub display_name: String,
+    pub is_subdivision: bool,
+}
+
+impl CountryResolution {
+    fn country(code: &'static str, name: &'static str) -> Self {
+        Self {
+            code: Cow::Borrowed(code),
+            display_name: CountryName(name).to_string(),
+            is_subdivision: false,
+        }
+    }
+
+    /// The code of the country itself, i.e. the parent country's code for
+    /// subdivisions.
+    ///
+    /// This is the code that osu! rankings and leaderboards work with.
+    pub fn country_code(&self) -> &str {
+        self.code
+            .split_once('-')
+            .map_or(self.code.as_ref(), |(code, _)| code)
+    }
+
+    pub fn to_country_code(&self) -> CountryCode {
+        CountryCode::from(self.country_code())
+    }
+
+    /// Name of the country itself, i.e. the parent country's name for
+    /// subdivisions.
+    pub fn country_name(&self) -> Cow<'_, str> {
+        if !self.is_subdivision {
+            return Cow::Borrowed(&self.display_name);
+        }
+
+        match Countries::code(self.country_code()).to_name() {
+            Some(name) => Cow::Owned(name.to_string()),
+            None => Cow::Borrowed(self.country_code()),
+        }
+    }
+
+    /// Explains what a subdivision was matched to, e.g. that Scotland is
+    /// ranked as part of the United Kingdom.
+    pub fn note(&self) -> Option<String> {
+        self.is_subdivision.then(|| {
+            format!(
+                "{} is ranked as part of {}",
+                self.display_name,
+                self.country_name()
+            )
+        })
+    }
+}
+
 pub struct CountryName(&'static str);
 
 impl CountryName {
@@ -405,3 +586,98 @@ impl<'a> Name<'a> {
         country_name.cow_to_ascii_lowercase()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Once;
+
+    use super::*;
+
+    fn resolve(input: &str) -> Option<CountryResolution> {
+        static INIT: Once = Once::new();
+        INIT.call_once(Countries::init);
+
+        Countries::resolve(input)
+    }
+
+    #[test]
+    fn resolve_code() {
+        let resolved = resolve("de").unwrap();
+
+        assert_eq!(resolved.code, "DE");
+        assert_eq!(resolved.display_name, "Germany");
+        assert!(!resolved.is_subdivision);
+    }
+
+    #[test]
+    fn resolve_name() {
+        let resolved = resolve(" United States of America ").unwrap();
+
+        assert_eq!(resolved.code, "US");
+        assert_eq!(resolved.display_name, "United States Of America");
+    }
+
+    #[test]
+    fn resolve_aliases() {
+        for (input, code) in [
+            ("UK", "GB"),
+            ("Deutschland", "DE"),
+            ("Österreich", "AT"),
+            ("phillipines", "PH"),
+            ("YU", "RS"),
+        ] {
+            let resolved = resolve(input).unwrap_or_else(|| panic!("failed to resolve {input}"));
+            assert_eq!(resolved.code, code, "{input}");
+            assert!(!resolved.is_subdivision, "{input}");
+        }
+    }
+
+    #[test]
+    fn aliases_resolve_to_known_codes() {
+        for (alias, code) in ALIASES {
+            assert_eq!(*alias, alias.to_lowercase(), "alias must be lowercase");
+            assert!(
+                resolve(code).is_some_and(|resolved| resolved.display_name != *code),
+                "unknown code {code} for alias {alias}"
+            );
+        }
+    }
+
+    #[test]
+    fn resolve_subdivision() {
+        for input in ["GB-SCT", "gb-sct", "Scotland"] {
+            let resolved = resolve(input).unwrap();
+
+            assert_eq!(resolved.code, "GB-SCT");
+            assert_eq!(resolved.display_name, "Scotland");
+            assert!(resolved.is_subdivision);
+            assert_eq!(resolved.country_code(), "GB");
+        }
+    }
+
+    #[test]
+    fn subdivision_note() {
+        let resolved = resolve("Scotland").unwrap();
+
+        assert_eq!(resolved.country_name(), "United Kingdom");
+        assert_eq!(
+            resolved.note().as_deref(),
+            Some("Scotland is ranked as part of United Kingdom")
+        );
+
+        let resolved = resolve("UK").unwrap();
+
+        assert_eq!(resolved.country_name(), "United Kingdom");
+        assert!(resolved.note().is_none());
+    }
+
+    #[test]
+    fn resolve_unknown() {
+        assert!(resolve("atlantis").is_none());
+        assert!(resolve("G1").is_none());
+
+        let resolved = resolve("xk").unwrap();
+        assert_eq!(resolved.code, "XK");
+        assert_eq!(resolved.country_code(), "XK");
+    }
+}
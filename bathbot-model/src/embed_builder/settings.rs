@@ -1,11 +1,14 @@
+use std::mem;
+
 use serde::{Deserialize, Serialize};
+use serde_json::{Error as JsonError, Map, Value as JsonValue};
 
 use super::{SettingValue, Value};
 use crate::deser::bool_as_u8;
 
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub struct ScoreEmbedSettings {
-    #[serde(rename = "v")]
+    #[serde(rename = "v", default = "ScoreEmbedSettings::default_values")]
     pub values: Vec<SettingValue>,
     #[serde(
         rename = "a",
@@ -21,9 +24,9 @@ pub struct ScoreEmbedSettings {
         skip_serializing_if = "super::is_true"
     )]
     pub show_sr_in_title: bool,
-    #[serde(rename = "i")]
+    #[serde(rename = "i", default)]
     pub image: SettingsImage,
-    #[serde(rename = "b")]
+    #[serde(rename = "b", default)]
     pub buttons: SettingsButtons,
 }
 
@@ -35,77 +38,92 @@ impl ScoreEmbedSettings {
     fn default_show_sr_in_title() -> bool {
         true
     }
+
+    fn default_values() -> Vec<SettingValue> {
+        vec![
+            SettingValue {
+                inner: Value::Grade,
+                y: 0,
+            },
+            SettingValue {
+                inner: Value::Mods,
+                y: 0,
+            },
+            SettingValue {
+                inner: Value::Score,
+                y: 0,
+            },
+            SettingValue {
+                inner: Value::Accuracy,
+                y: 0,
+            },
+            SettingValue {
+                inner: Value::ScoreDate,
+                y: 0,
+            },
+            SettingValue {
+                inner: Value::Pp(Default::default()),
+                y: 1,
+            },
+            SettingValue {
+                inner: Value::Combo(Default::default()),
+                y: 1,
+            },
+            SettingValue {
+                inner: Value::Hitresults(Default::default()),
+                y: 1,
+            },
+            SettingValue {
+                inner: Value::Length,
+                y: 2,
+            },
+            SettingValue {
+                inner: Value::Cs,
+                y: 2,
+            },
+            SettingValue {
+                inner: Value::Ar,
+                y: 2,
+            },
+            SettingValue {
+                inner: Value::Od,
+                y: 2,
+            },
+            SettingValue {
+                inner: Value::Hp,
+                y: 2,
+            },
+            SettingValue {
+                inner: Value::Bpm(Default::default()),
+                y: 2,
+            },
+            SettingValue {
+                inner: Value::Mapper(Default::default()),
+                y: SettingValue::FOOTER_Y,
+            },
+            SettingValue {
+                inner: Value::MapRankedDate,
+                y: SettingValue::FOOTER_Y,
+            },
+        ]
+    }
+
+    /// Whether every kind of value is contained at most once.
+    pub fn is_valid(&self) -> bool {
+        self.values.iter().enumerate().all(|(i, value)| {
+            let kind = mem::discriminant(&value.inner);
+
+            self.values[i + 1..]
+                .iter()
+                .all(|other| mem::discriminant(&other.inner) != kind)
+        })
+    }
 }
 
 impl Default for ScoreEmbedSettings {
     fn default() -> Self {
         Self {
-            values: vec![
-                SettingValue {
-                    inner: Value::Grade,
-                    y: 0,
-                },
-                SettingValue {
-                    inner: Value::Mods,
-                    y: 0,
-                },
-                SettingValue {
-                    inner: Value::Score,
-                    y: 0,
-                },
-                SettingValue {
-                    inner: Value::Accuracy,
-                    y: 0,
-                },
-                SettingValue {
-                    inner: Value::ScoreDate,
-                    y: 0,
-                },
-                SettingValue {
-                    inner: Value::Pp(Default::default()),
-                    y: 1,
-                },
-                SettingValue {
-                    inner: Value::Combo(Default::default()),
-                    y: 1,
-                },
-                SettingValue {
-                    inner: Value::Hitresults(Default::default()),
-                    y: 1,
-                },
-                SettingValue {
-                    inner: Value::Length,
-                    y: 2,
-                },
-                SettingValue {
-                    inner: Value::Cs,
-                    y: 2,
-                },
-                SettingValue {
-                    inner: Value::Ar,
-                    y: 2,
-                },
-                SettingValue {
-                    inner: Value::Od,
-                    y: 2,
-                },
-                SettingValue {
-                    inner: Value::Hp,
-                    y: 2,
-                },
-                SettingValue {
-                    inner: Value::Bpm(Default::default()),
-                    y: 2,
-                },
-                SettingValue {
-                    inner: Value::Mapper(Default::default()),
-                    y: SettingValue::FOOTER_Y,
-                },
-                SettingValue {
-                    inner: Value::MapRankedDate,
-                    y: SettingValue::FOOTER_Y,
-                },
-            ],
+            values: Self::default_values(),
             show_artist: Self::default_show_artist(),
             show_sr_in_title: Self::default_show_sr_in_title(),
             image: SettingsImage::default(),
@@ -170,3 +188,302 @@ impl Default for SettingsButtons {
         }
     }
 }
+
+/// Key of the values within serialized [`ScoreEmbedSettings`].
+const VALUES: &str = "v";
+
+/// Changed top-level fields of [`ScoreEmbedSettings`] in their serialized
+/// form.
+///
+/// Fields that map to `null` are removed so that they fall back to their
+/// default. If values were only edited but not added, removed, or moved, the
+/// values field maps to an object with the edited values as `"e"` and the
+/// default values including the edits as `"d"` so that only the edited
+/// values are replaced. The database merges patches the same way.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct ScoreEmbedPatch(Map<String, JsonValue>);
+
+impl ScoreEmbedPatch {
+    /// The fields that need to change to turn `old` into `new`.
+    pub fn diff(old: &ScoreEmbedSettings, new: &ScoreEmbedSettings) -> Self {
+        let mut old = fields(old);
+        let mut new = fields(new);
+        let mut patch = Map::new();
+
+        if let (Some(old_values), Some(new_values)) = (old.remove(VALUES), new.remove(VALUES)) {
+            if let Some(values) = values_patch(old_values, new_values) {
+                patch.insert(VALUES.to_owned(), values);
+            }
+        }
+
+        for (key, value) in new.iter() {
+            if old.get(key) != Some(value) {
+                patch.insert(key.to_owned(), value.to_owned());
+            }
+        }
+
+        for (key, _) in old {
+            if !new.contains_key(&key) {
+                patch.insert(key, JsonValue::Null);
+            }
+        }
+
+        Self(patch)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Replay the patch on top of `settings`.
+    pub fn apply(&self, settings: &ScoreEmbedSettings) -> Result<ScoreEmbedSettings, JsonError> {
+        let mut fields = fields(settings);
+
+        for (key, value) in self.0.iter() {
+            match value {
+                JsonValue::Null => {
+                    fields.remove(key);
+                }
+                JsonValue::Object(values) if key == VALUES => {
+                    let current = fields.get_mut(VALUES);
+
+                    if let (Some(JsonValue::Array(current)), Some(JsonValue::Array(edited))) =
+                        (current, values.get("e"))
+                    {
+                        edit_values(current, edited);
+                    }
+                }
+                _ => {
+                    fields.insert(key.to_owned(), value.to_owned());
+                }
+            }
+        }
+
+        serde_json::from_value(JsonValue::Object(fields))
+    }
+}
+
+fn fields(settings: &ScoreEmbedSettings) -> Map<String, JsonValue> {
+    match serde_json::to_value(settings) {
+        Ok(JsonValue::Object(fields)) => fields,
+        _ => unreachable!(),
+    }
+}
+
+/// Patch of the serialized values.
+///
+/// Only the edited values are included if no values were added, removed, or
+/// moved. Otherwise all values are included.
+fn values_patch(old: JsonValue, new: JsonValue) -> Option<JsonValue> {
+    if old == new {
+        return None;
+    }
+
+    let (old, new) = match (old, new) {
+        (JsonValue::Array(old), JsonValue::Array(new)) => (old, new),
+        (_, new) => return Some(new),
+    };
+
+    let same_kinds = old.len() == new.len()
+        && old
+            .iter()
+            .zip(new.iter())
+            .all(|(old, new)| value_kind(old) == value_kind(new));
+
+    if !same_kinds {
+        return Some(JsonValue::Array(new));
+    }
+
+    let edited: Vec<_> = old
+        .iter()
+        .zip(new.iter())
+        .filter(|(old, new)| old != new)
+        .map(|(_, new)| new.to_owned())
+        .collect();
+
+    // Stored settings without values use the defaults
+    let mut defaults = match fields(&ScoreEmbedSettings::default()).remove(VALUES) {
+        Some(JsonValue::Array(defaults)) => defaults,
+        _ => unreachable!(),
+    };
+
+    edit_values(&mut defaults, &edited);
+
+    let mut patch = Map::new();
+    patch.insert("e".to_owned(), JsonValue::Array(edited));
+    patch.insert("d".to_owned(), JsonValue::Array(defaults));
+
+    Some(JsonValue::Object(patch))
+}
+
+/// Replace values with the edited values of the same kind.
+fn edit_values(values: &mut [JsonValue], edited: &[JsonValue]) {
+    for edit in edited {
+        let kind = value_kind(edit);
+
+        if let Some(value) = values.iter_mut().find(|value| value_kind(value) == kind) {
+            edit.clone_into(value);
+        }
+    }
+}
+
+/// The kind of a serialized [`SettingValue`] e.g. `"grade"` or `"pp"`.
+fn value_kind(value: &JsonValue) -> Option<&str> {
+    match value.get("i")? {
+        JsonValue::String(kind) => Some(kind),
+        JsonValue::Object(inner) => inner.keys().next().map(String::as_str),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::embed_builder::{ComboValue, PpValue};
+
+    #[test]
+    fn skin_roundtrip() {
+        let mut settings = ScoreEmbedSettings::default();
+
+        settings.values.push(SettingValue {
+            inner: Value::Skin,
+            y: SettingValue::FOOTER_Y,
+        });
+
+        let json = serde_json::to_string(&settings).unwrap();
+        assert!(json.contains(r#"{"i":"skin","y":255}"#));
+
+        let deserialized: ScoreEmbedSettings = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, settings);
+    }
+
+    #[test]
+    fn deserialize_without_skin() {
+        let json = r#"{"v":[{"i":"grade","y":0},{"i":"ranked_date","y":255}],"i":0,"b":{}}"#;
+        let settings: ScoreEmbedSettings = serde_json::from_str(json).unwrap();
+
+        assert_eq!(settings.values.len(), 2);
+        assert!(
+            settings
+                .values
+                .iter()
+                .all(|value| value.inner != Value::Skin)
+        );
+    }
+
+    #[test]
+    fn reject_duplicate_values() {
+        let mut settings = ScoreEmbedSettings::default();
+        assert!(settings.is_valid());
+
+        settings.values.push(SettingValue {
+            inner: Value::Skin,
+            y: SettingValue::FOOTER_Y,
+        });
+        assert!(settings.is_valid());
+
+        settings.values.push(SettingValue {
+            inner: Value::Skin,
+            y: SettingValue::FOOTER_Y,
+        });
+        assert!(!settings.is_valid());
+    }
+
+    #[test]
+    fn deserialize_sparse_fields() {
+        let settings: ScoreEmbedSettings = serde_json::from_str(r#"{"ver":1,"i":2}"#).unwrap();
+
+        let expected = ScoreEmbedSettings {
+            image: SettingsImage::Hide,
+            ..Default::default()
+        };
+
+        assert_eq!(settings, expected);
+    }
+
+    #[test]
+    fn diff_only_changed_fields() {
+        let old = ScoreEmbedSettings::default();
+
+        let mut new = old.clone();
+        new.show_artist = false;
+        new.image = SettingsImage::Image;
+
+        let patch = ScoreEmbedPatch::diff(&old, &new);
+        assert_eq!(serde_json::to_string(&patch).unwrap(), r#"{"a":0,"i":1}"#);
+        assert_eq!(patch.apply(&old).unwrap(), new);
+
+        // Defaults are not serialized so they're removed instead
+        let patch = ScoreEmbedPatch::diff(&new, &old);
+        assert_eq!(
+            serde_json::to_string(&patch).unwrap(),
+            r#"{"a":null,"i":0}"#
+        );
+        assert_eq!(patch.apply(&new).unwrap(), old);
+
+        assert!(ScoreEmbedPatch::diff(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn replay_patch_on_other_changes() {
+        let base = ScoreEmbedSettings::default();
+
+        let mut first = base.clone();
+        first.show_sr_in_title = false;
+
+        let mut second = base.clone();
+        second.values.push(SettingValue {
+            inner: Value::Skin,
+            y: SettingValue::FOOTER_Y,
+        });
+
+        let replayed = ScoreEmbedPatch::diff(&base, &second).apply(&first).unwrap();
+
+        assert!(!replayed.show_sr_in_title);
+        assert_eq!(replayed.values, second.values);
+    }
+
+    #[test]
+    fn patch_only_edited_values() {
+        let base = ScoreEmbedSettings::default();
+
+        let mut pp_edit = base.clone();
+        pp_edit.values[5].inner = Value::Pp(PpValue {
+            max: false,
+            ..Default::default()
+        });
+
+        let mut combo_edit = base.clone();
+        combo_edit.values[6].inner = Value::Combo(ComboValue { max: false });
+
+        let pp_patch = ScoreEmbedPatch::diff(&base, &pp_edit);
+        let json = serde_json::to_value(&pp_patch).unwrap();
+        assert_eq!(
+            json["v"]["e"],
+            serde_json::json!([{ "i": { "pp": { "max": 0 } }, "y": 1 }])
+        );
+
+        // Both edits survive when replayed on top of each other
+        let merged = pp_patch.apply(&combo_edit).unwrap();
+        assert_eq!(merged.values[5], pp_edit.values[5]);
+        assert_eq!(merged.values[6], combo_edit.values[6]);
+
+        // The defaults include the edit for stored settings without values
+        let defaults: Vec<SettingValue> = serde_json::from_value(json["v"]["d"].clone()).unwrap();
+        assert_eq!(defaults, pp_edit.values);
+    }
+
+    #[test]
+    fn patch_all_moved_values() {
+        let base = ScoreEmbedSettings::default();
+
+        let mut moved = base.clone();
+        moved.values.swap(0, 1);
+
+        let patch = ScoreEmbedPatch::diff(&base, &moved);
+        let json = serde_json::to_value(&patch).unwrap();
+        assert!(json["v"].is_array());
+        assert_eq!(patch.apply(&base).unwrap(), moved);
+    }
+}
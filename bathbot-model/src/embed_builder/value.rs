@@ -45,6 +45,7 @@ pub enum Value {
     #[serde(rename = "ranked_date")]
     MapRankedDate,
     Mapper(MapperValue),
+    Skin,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
@@ -22,6 +22,8 @@ pub struct HlGameScore {
 pub enum HlVersion {
     #[option(name = "Score PP", value = "score_pp")]
     ScorePp = 0,
+    #[option(name = "Player rank", value = "score_rank")]
+    ScoreRank = 1,
 }
 
 bitflags::bitflags! {
@@ -76,6 +78,36 @@ impl FromStr for MapsetTags {
     }
 }
 
+impl MapsetTags {
+    /// Names of all tags as accepted by [`MapsetTags::from_str`].
+    pub const NAMES: [&str; 13] = [
+        "farm",
+        "streams",
+        "alternate",
+        "old",
+        "meme",
+        "hardname",
+        "easy",
+        "hard",
+        "tech",
+        "weeb",
+        "bluesky",
+        "english",
+        "kpop",
+    ];
+
+    /// Parse a comma-separated list of tag names, ignoring case and
+    /// surrounding whitespace.
+    ///
+    /// On failure, the first invalid name is returned.
+    pub fn parse_list(list: &str) -> Result<Self, String> {
+        list.split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .try_fold(Self::empty(), |tags, name| Ok(tags | name.parse::<Self>()?))
+    }
+}
+
 bitflags::bitflags! {
     #[derive(Copy, Clone)]
     pub struct Effects: u8 {
@@ -114,3 +146,32 @@ macro_rules! bitflag_impls {
 
 bitflag_impls!(MapsetTags, 32);
 bitflag_impls!(Effects, 8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_tag_list() {
+        let tags = MapsetTags::parse_list("Weeb, streams,,TECH ").unwrap();
+
+        assert_eq!(
+            tags.bits(),
+            (MapsetTags::Weeb | MapsetTags::Streams | MapsetTags::Tech).bits()
+        );
+    }
+
+    #[test]
+    fn parse_tag_list_invalid() {
+        let err = MapsetTags::parse_list("easy,tv size").unwrap_err();
+
+        assert_eq!(err, "tv size");
+    }
+
+    #[test]
+    fn all_tag_names_parse() {
+        let tags = MapsetTags::parse_list(&MapsetTags::NAMES.join(",")).unwrap();
+
+        assert_eq!(tags.bits(), MapsetTags::all().bits());
+    }
+}
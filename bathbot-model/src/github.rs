@@ -10,7 +10,10 @@ use serde::{
 };
 use time::OffsetDateTime;
 
-use crate::{deser::Datetime, rkyv_util::time::DateTimeRkyv};
+use crate::{
+    deser::{Datetime, datetime_rfc3339},
+    rkyv_util::time::DateTimeRkyv,
+};
 
 pub struct GraphQLResponse<T>(pub T);
 
@@ -630,6 +633,27 @@ impl<'de, T: Deserialize<'de>> Visitor<'de> for NodesSeqVisitor<'_, T> {
     }
 }
 
+#[derive(Archive, Deserialize, rkyv::Deserialize, Serialize)]
+pub struct Release {
+    #[serde(rename = "tag_name")]
+    pub tag: Box<str>,
+    pub name: Option<Box<str>>,
+    pub body: Option<Box<str>>,
+    pub html_url: Box<str>,
+    #[serde(with = "datetime_rfc3339")]
+    #[rkyv(with = DateTimeRkyv)]
+    pub published_at: OffsetDateTime,
+}
+
+/// Releases alongside the time they were requested so that they can still be
+/// served after going stale.
+#[derive(Archive, rkyv::Deserialize, Serialize)]
+pub struct Releases {
+    pub inner: Vec<Release>,
+    #[rkyv(with = DateTimeRkyv)]
+    pub fetched_at: OffsetDateTime,
+}
+
 pub enum GithubUrlDisplay {
     Issue(u64),
     PullRequest(u64),
@@ -119,6 +119,33 @@ impl SnipeCountryListOrder {
     }
 }
 
+#[derive(Copy, Clone, CommandOption, CreateOption, Debug, Default, Eq, PartialEq)]
+pub enum SnipeCountryPlayersOrder {
+    #[default]
+    #[option(name = "Count", value = "count")]
+    Count,
+    #[option(name = "Gained", value = "gained")]
+    Gained,
+    #[option(name = "Lost", value = "lost")]
+    Lost,
+    #[option(name = "Weighted PP", value = "weighted")]
+    Weighted,
+}
+
+impl SnipeCountryPlayersOrder {
+    /// The order as supported by huismetbenen's ranking endpoint.
+    ///
+    /// Orders without server-side support are fetched by count and have to be
+    /// sorted afterwards.
+    pub fn as_huismetbenen_str(self) -> Option<&'static str> {
+        match self {
+            Self::Count => Some("count"),
+            Self::Weighted => Some("pp/weighted"),
+            Self::Gained | Self::Lost => None,
+        }
+    }
+}
+
 #[derive(Copy, Clone, CommandOption, CreateOption, Debug, Default, Eq, PartialEq)]
 pub enum SnipePlayerListOrder {
     #[option(name = "Accuracy", value = "acc")]
@@ -237,6 +264,22 @@ pub struct SnipeCountryPlayer {
     pub count_first: u32,
 }
 
+#[derive(Debug, Deserialize, Archive, RkyvSerialize)]
+pub struct SnipeCountryRankingPlayer {
+    #[rkyv(with = DerefAsString)]
+    pub username: Username,
+    pub user_id: u32,
+    #[serde(rename = "count_total")]
+    pub count_first: u32,
+    pub weighted_pp: f32,
+    /// National #1s gained within the last 30 days
+    #[serde(rename = "count_gained", default)]
+    pub gained: u32,
+    /// National #1s lost within the last 30 days
+    #[serde(rename = "count_lost", default)]
+    pub lost: u32,
+}
+
 #[derive(Debug)]
 pub struct SnipeRecent {
     pub map_id: u32,
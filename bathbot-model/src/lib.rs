@@ -5,6 +5,7 @@ mod games;
 mod github;
 mod huismetbenen;
 mod kittenroleplay;
+mod map_completion;
 mod osekai;
 mod osu;
 mod osu_stats;
@@ -26,7 +27,7 @@ pub mod rkyv_util;
 
 pub use self::{
     country_code::*, deser::ModeAsSeed, either::Either, games::*, github::*, huismetbenen::*,
-    kittenroleplay::*, osekai::*, osu::*, osu_stats::*, osutrack::*,
+    kittenroleplay::*, map_completion::*, osekai::*, osu::*, osu_stats::*, osutrack::*,
     personal_best::PersonalBestIndex, ranking_entries::*, relax::*, respektive::*, score_slim::*,
     twitch::*, user_stats::*,
 };
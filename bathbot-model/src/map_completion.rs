@@ -0,0 +1,13 @@
+use rkyv::{Archive, Deserialize, Serialize};
+use rosu_v2::model::Grade;
+
+use crate::rosu_v2::grade::GradeRkyv;
+
+/// A user's best score on a map as far as map completion is concerned.
+#[derive(Copy, Clone, Debug, Archive, Serialize, Deserialize)]
+pub struct MapCompletionScore {
+    #[rkyv(with = GradeRkyv)]
+    pub grade: Grade,
+    pub accuracy: f32,
+    pub pp: Option<f32>,
+}
@@ -139,3 +139,63 @@ impl ArchivedOsuTrackHistoryEntry {
         self.timestamp.try_deserialize::<BoxedError>().unwrap()
     }
 }
+
+/// Response of osu!track after recording a new datapoint for a user.
+///
+/// Numeric values are the changes since the user's previous datapoint.
+#[derive(Debug, Deserialize)]
+pub struct OsuTrackUpdate {
+    pub username: Box<str>,
+    /// Whether this was the first datapoint of the user
+    #[serde(default)]
+    pub first: bool,
+    #[serde(default)]
+    pub levelup: bool,
+    #[serde(default)]
+    pub playcount: i32,
+    #[serde(default)]
+    pub pp_rank: i32,
+    #[serde(default, rename = "pp_raw")]
+    pub pp: f32,
+    #[serde(default)]
+    pub accuracy: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_update() {
+        let json = r#"{
+            "username": "Badewanne3",
+            "mode": 0,
+            "playcount": 12,
+            "pp_rank": -153,
+            "pp_raw": 4.25,
+            "accuracy": 0.0123,
+            "total_score": "123456789",
+            "levelup": false,
+            "first": false,
+            "exists": true,
+            "newhs": []
+        }"#;
+
+        let update: OsuTrackUpdate = serde_json::from_str(json).unwrap();
+
+        assert_eq!(update.username.as_ref(), "Badewanne3");
+        assert_eq!(update.playcount, 12);
+        assert_eq!(update.pp_rank, -153);
+        assert!(!update.first);
+    }
+
+    #[test]
+    fn deserialize_first_update() {
+        let json = r#"{"username": "Badewanne3", "first": true}"#;
+
+        let update: OsuTrackUpdate = serde_json::from_str(json).unwrap();
+
+        assert!(update.first);
+        assert_eq!(update.pp_rank, 0);
+    }
+}
@@ -253,6 +253,7 @@ impl RankingKind {
             Self::HlScores { version, .. } => {
                 let text = match version {
                     HlVersion::ScorePp => "Server leaderboard for Higherlower (Score PP)",
+                    HlVersion::ScoreRank => "Server leaderboard for Higherlower (Player Rank)",
                 };
 
                 EmbedHeader::Author(AuthorBuilder::new(text))
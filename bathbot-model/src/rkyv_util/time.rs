@@ -4,7 +4,7 @@ use rkyv::{
     munge::munge,
     niche::niching::Niching,
     rancor::{Fallible, Source},
-    rend::u64_le,
+    rend::{i64_le, u64_le},
     traits::NoUndef,
     with::{ArchiveWith, DeserializeWith, SerializeWith},
 };
@@ -84,6 +84,56 @@ impl Niching<ArchivedDateTime> for DateTimeRkyv {
     }
 }
 
+/// Archives an [`OffsetDateTime`] with second precision, taking up half the
+/// space of [`DateTimeRkyv`].
+pub struct DateTimeSecsRkyv;
+
+#[derive(Copy, Clone, CheckBytes, Portable, PartialEq, Eq, PartialOrd, Ord)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[repr(transparent)]
+pub struct ArchivedDateTimeSecs(i64_le);
+
+unsafe impl NoUndef for ArchivedDateTimeSecs {}
+
+impl ArchivedDateTimeSecs {
+    pub const fn new(datetime: OffsetDateTime) -> Self {
+        Self(i64_le::from_native(datetime.unix_timestamp()))
+    }
+
+    pub fn try_deserialize<E: Source>(&self) -> Result<OffsetDateTime, E> {
+        OffsetDateTime::from_unix_timestamp(self.0.to_native()).map_err(Source::new)
+    }
+}
+
+impl ArchiveWith<OffsetDateTime> for DateTimeSecsRkyv {
+    type Archived = ArchivedDateTimeSecs;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve_with(field: &OffsetDateTime, _: Self::Resolver, out: Place<Self::Archived>) {
+        out.write(ArchivedDateTimeSecs::new(*field));
+    }
+}
+
+impl<S: Fallible + ?Sized> SerializeWith<OffsetDateTime, S> for DateTimeSecsRkyv {
+    #[inline]
+    fn serialize_with(_: &OffsetDateTime, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible<Error: Source>> DeserializeWith<ArchivedDateTimeSecs, OffsetDateTime, D>
+    for DateTimeSecsRkyv
+{
+    #[inline]
+    fn deserialize_with(
+        archived: &ArchivedDateTimeSecs,
+        _: &mut D,
+    ) -> Result<OffsetDateTime, D::Error> {
+        archived.try_deserialize()
+    }
+}
+
 pub struct DateRkyv;
 
 impl DateRkyv {
@@ -2,4 +2,5 @@ pub mod grade;
 pub mod mode;
 pub mod multiplayer;
 pub mod ranking;
+pub mod score;
 pub mod user;
@@ -0,0 +1,105 @@
+use bathbot_util::ScoreHasEndedAt;
+use rkyv::{Archive, Deserialize, Serialize};
+use rosu_v2::prelude::{GameMode, GameMods, Grade, Score, ScoreStatistics, Username};
+use time::OffsetDateTime;
+
+use super::grade::GradeRkyv;
+use crate::rkyv_util::{DerefAsString, time::DateTimeRkyv};
+
+/// The parts of a map leaderboard score that are needed to display it.
+#[derive(Archive, Serialize, Deserialize)]
+pub struct MapLeaderboardScore {
+    pub score_id: u64,
+    pub user_id: u32,
+    #[rkyv(with = DerefAsString)]
+    pub username: Username,
+    pub avatar_url: Option<String>,
+    pub accuracy: f32,
+    #[rkyv(with = DateTimeRkyv)]
+    pub ended_at: OffsetDateTime,
+    #[rkyv(with = GradeRkyv)]
+    pub grade: Grade,
+    pub passed: bool,
+    pub max_combo: u32,
+    pub mode: GameMode,
+    pub mods: GameMods,
+    pub score: u32,
+    pub classic_score: u64,
+    pub is_legacy: bool,
+    pub set_on_lazer: bool,
+    /// The pp as provided by the osu!api
+    pub pp: Option<f32>,
+    #[rkyv(with = ScoreStatisticsRkyv)]
+    pub statistics: ScoreStatistics,
+}
+
+impl MapLeaderboardScore {
+    pub fn new(score: Score) -> Self {
+        let is_legacy = score.is_legacy();
+
+        let (username, avatar_url) = match score.user {
+            Some(user) => (user.username, Some(user.avatar_url)),
+            None => (format!("<user {}>", score.user_id).into(), None),
+        };
+
+        Self {
+            score_id: score.id,
+            user_id: score.user_id,
+            username,
+            avatar_url,
+            accuracy: score.accuracy,
+            ended_at: score.ended_at,
+            grade: score.grade,
+            passed: score.passed,
+            max_combo: score.max_combo,
+            mode: score.mode,
+            mods: score.mods,
+            score: score.score,
+            classic_score: score.classic_score,
+            is_legacy,
+            set_on_lazer: score.set_on_lazer,
+            pp: score.pp,
+            statistics: score.statistics,
+        }
+    }
+}
+
+#[rustfmt::skip]
+impl ScoreHasEndedAt for MapLeaderboardScore {
+    #[inline] fn ended_at(&self) -> OffsetDateTime { self.ended_at }
+}
+
+#[derive(Archive, Serialize, Deserialize)]
+#[rkyv(remote = ScoreStatistics, archived = ArchivedScoreStatistics)]
+pub struct ScoreStatisticsRkyv {
+    pub miss: u32,
+    pub meh: u32,
+    pub ok: u32,
+    pub good: u32,
+    pub great: u32,
+    pub perfect: u32,
+    pub small_tick_miss: u32,
+    pub small_tick_hit: u32,
+    pub large_tick_miss: u32,
+    pub large_tick_hit: u32,
+    pub slider_tail_hit: u32,
+}
+
+impl From<ScoreStatisticsRkyv> for ScoreStatistics {
+    fn from(stats: ScoreStatisticsRkyv) -> Self {
+        Self {
+            miss: stats.miss,
+            meh: stats.meh,
+            ok: stats.ok,
+            good: stats.good,
+            great: stats.great,
+            perfect: stats.perfect,
+            small_tick_miss: stats.small_tick_miss,
+            small_tick_hit: stats.small_tick_hit,
+            large_tick_miss: stats.large_tick_miss,
+            large_tick_hit: stats.large_tick_hit,
+            slider_tail_hit: stats.slider_tail_hit,
+            ..Default::default()
+        }
+    }
+}
@@ -20,10 +20,11 @@ use crate::{
     Either,
     rkyv_util::{
         DerefAsString, MapUnwrapOrDefault, UnwrapOrDefault,
-        time::{DateRkyv, DateTimeRkyv},
+        time::{DateRkyv, DateTimeRkyv, DateTimeSecsRkyv},
     },
 };
 
+/// The badge's `url` is never read and thus not archived.
 #[derive(Archive, Serialize)]
 #[rkyv(remote = Badge, archived = ArchivedBadge)]
 pub struct BadgeRkyv {
@@ -31,7 +32,6 @@ pub struct BadgeRkyv {
     pub awarded_at: OffsetDateTime,
     pub description: String,
     pub image_url: String,
-    pub url: String,
 }
 
 #[derive(Archive, Serialize, Deserialize)]
@@ -59,7 +59,7 @@ impl From<GradeCountsRkyv> for GradeCounts {
 #[derive(Archive, Serialize, Deserialize)]
 #[rkyv(remote = MedalCompact, archived = ArchivedMedalCompact)]
 pub struct MedalCompactRkyv {
-    #[rkyv(with = DateTimeRkyv)]
+    #[rkyv(with = DateTimeSecsRkyv)]
     pub achieved_at: OffsetDateTime,
     pub medal_id: u32,
 }
@@ -73,6 +73,7 @@ impl From<MedalCompactRkyv> for MedalCompact {
     }
 }
 
+/// Only archives fields that are displayed in the daily challenge command.
 #[derive(Archive, Serialize)]
 #[rkyv(
     remote = DailyChallengeUserStatistics,
@@ -83,12 +84,9 @@ pub struct DailyChallengeUserStatisticsRkyv {
     pub daily_streak_current: u32,
     #[rkyv(with = MapNiche<DateTimeRkyv, DateTimeRkyv>)]
     pub last_update: Option<OffsetDateTime>,
-    #[rkyv(with = MapNiche<DateTimeRkyv, DateTimeRkyv>)]
-    pub last_weekly_streak: Option<OffsetDateTime>,
     pub playcount: u32,
     pub top_10p_placements: u32,
     pub top_50p_placements: u32,
-    pub user_id: u32,
     pub weekly_streak_best: u32,
     pub weekly_streak_current: u32,
 }
@@ -129,6 +127,10 @@ impl Niching<ArchivedTeam> for TeamRkyv {
     }
 }
 
+/// Cached subset of [`UserExtended`].
+///
+/// Only fields that are read somewhere are archived. When adding a field,
+/// bump the cache key version of osu! users.
 #[derive(Clone, Archive, Serialize)]
 pub struct User {
     #[rkyv(with = DerefAsString)]
@@ -725,3 +727,192 @@ impl UserStats for StatsWrapper<'_> {
         self.playcount()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rkyv::{rancor::Panic, with::With};
+
+    use super::*;
+
+    #[derive(Archive, Serialize)]
+    #[rkyv(remote = Badge)]
+    struct LegacyBadge {
+        #[rkyv(with = DateTimeRkyv)]
+        awarded_at: OffsetDateTime,
+        description: String,
+        image_url: String,
+        url: String,
+    }
+
+    #[derive(Archive, Serialize)]
+    #[rkyv(remote = MedalCompact)]
+    struct LegacyMedal {
+        #[rkyv(with = DateTimeRkyv)]
+        achieved_at: OffsetDateTime,
+        medal_id: u32,
+    }
+
+    #[derive(Archive, Serialize)]
+    #[rkyv(remote = DailyChallengeUserStatistics)]
+    struct LegacyDailyChallenge {
+        daily_streak_best: u32,
+        daily_streak_current: u32,
+        #[rkyv(with = MapNiche<DateTimeRkyv, DateTimeRkyv>)]
+        last_update: Option<OffsetDateTime>,
+        #[rkyv(with = MapNiche<DateTimeRkyv, DateTimeRkyv>)]
+        last_weekly_streak: Option<OffsetDateTime>,
+        playcount: u32,
+        top_10p_placements: u32,
+        top_50p_placements: u32,
+        user_id: u32,
+        weekly_streak_best: u32,
+        weekly_streak_current: u32,
+    }
+
+    /// [`User`] archived the way it used to be before slimming.
+    #[derive(Archive, Serialize)]
+    struct LegacyUser {
+        #[rkyv(with = DerefAsString)]
+        avatar_url: Box<str>,
+        #[rkyv(with = DerefAsString)]
+        country_code: CountryCode,
+        #[rkyv(with = DateTimeRkyv)]
+        join_date: OffsetDateTime,
+        #[rkyv(with = UserKudosuRkyv)]
+        kudosu: UserKudosu,
+        #[rkyv(with = MapNiche<DateTimeRkyv, DateTimeRkyv>)]
+        last_visit: Option<OffsetDateTime>,
+        mode: GameMode,
+        user_id: u32,
+        #[rkyv(with = DerefAsString)]
+        username: Username,
+
+        #[rkyv(with = Map<LegacyBadge>)]
+        badges: Vec<Badge>,
+        follower_count: u32,
+        graveyard_mapset_count: u32,
+        guest_mapset_count: u32,
+        #[rkyv(with = MapNiche<UserHighestRankRkyv, DateTimeRkyv>)]
+        highest_rank: Option<UserHighestRank>,
+        loved_mapset_count: u32,
+        mapping_follower_count: u32,
+        #[rkyv(with = Map<MonthlyCountRkyv>)]
+        monthly_playcounts: Vec<MonthlyCount>,
+        rank_history: Box<[u32]>,
+        ranked_mapset_count: u32,
+        #[rkyv(with = Map<MonthlyCountRkyv>)]
+        replays_watched_counts: Vec<MonthlyCount>,
+        scores_first_count: u32,
+        #[rkyv(with = MapNiche<UserStatisticsRkyv, NaN>)]
+        statistics: Option<UserStatistics>,
+        pending_mapset_count: u32,
+        #[rkyv(with = Map<LegacyMedal>)]
+        medals: Vec<MedalCompact>,
+        #[rkyv(with = LegacyDailyChallenge)]
+        daily_challenge: DailyChallengeUserStatistics,
+        #[rkyv(with = MapNiche<TeamRkyv, TeamRkyv>)]
+        team: Option<Team>,
+    }
+
+    impl From<User> for LegacyUser {
+        fn from(user: User) -> Self {
+            let User {
+                avatar_url,
+                country_code,
+                join_date,
+                kudosu,
+                last_visit,
+                mode,
+                user_id,
+                username,
+                badges,
+                follower_count,
+                graveyard_mapset_count,
+                guest_mapset_count,
+                highest_rank,
+                loved_mapset_count,
+                mapping_follower_count,
+                monthly_playcounts,
+                rank_history,
+                ranked_mapset_count,
+                replays_watched_counts,
+                scores_first_count,
+                statistics,
+                pending_mapset_count,
+                medals,
+                daily_challenge,
+                team,
+            } = user;
+
+            Self {
+                avatar_url,
+                country_code,
+                join_date,
+                kudosu,
+                last_visit,
+                mode,
+                user_id,
+                username,
+                badges,
+                follower_count,
+                graveyard_mapset_count,
+                guest_mapset_count,
+                highest_rank,
+                loved_mapset_count,
+                mapping_follower_count,
+                monthly_playcounts,
+                rank_history,
+                ranked_mapset_count,
+                replays_watched_counts,
+                scores_first_count,
+                statistics,
+                pending_mapset_count,
+                medals,
+                daily_challenge,
+                team,
+            }
+        }
+    }
+
+    /// Response of the osu!api for a long-time player's osu!standard
+    /// profile.
+    fn fixture() -> User {
+        let json = include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/user.json"
+        ));
+
+        let user: UserExtended = serde_json::from_str(json).unwrap();
+
+        User::from(user)
+    }
+
+    #[test]
+    fn slimmed_user_is_smaller() {
+        let user = fixture();
+
+        let after = rkyv::to_bytes::<Panic>(&user).unwrap().len();
+        let legacy = LegacyUser::from(user);
+        let before = rkyv::to_bytes::<Panic>(&legacy).unwrap().len();
+
+        assert!(
+            after * 5 <= before * 4,
+            "expected at least 20% reduction; before={before}B after={after}B"
+        );
+    }
+
+    #[test]
+    fn medal_timestamps_roundtrip() {
+        let user = fixture();
+        let bytes = rkyv::to_bytes::<Panic>(&user).unwrap();
+        let archived = rkyv::access::<ArchivedUser, Panic>(&bytes).unwrap();
+
+        let medals: Vec<MedalCompact> = rkyv::api::deserialize_using::<_, _, Panic>(
+            With::<_, Map<MedalCompactRkyv>>::cast(&archived.medals),
+            &mut (),
+        )
+        .unwrap();
+
+        assert_eq!(medals, user.medals);
+    }
+}
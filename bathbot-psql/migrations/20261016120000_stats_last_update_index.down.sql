@@ -0,0 +1 @@
+DROP INDEX osu_user_stats_last_update_index;
@@ -0,0 +1 @@
+CREATE INDEX osu_user_stats_last_update_index ON osu_user_stats (last_update);
@@ -0,0 +1 @@
+DROP TABLE map_tag_edits;
@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS map_tag_edits (
+    edit_id    INT4 GENERATED ALWAYS AS IDENTITY,
+    mapset_id  INT4 NOT NULL,
+    -- discord user id of the editor
+    editor_id  INT8 NOT NULL,
+    -- `MapsetTags` bits
+    old_tags   INT4 NOT NULL,
+    new_tags   INT4 NOT NULL,
+    edited_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (edit_id)
+);
+
+CREATE INDEX map_tag_edits_mapset_index ON map_tag_edits (mapset_id);
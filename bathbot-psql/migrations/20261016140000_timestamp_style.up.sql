@@ -0,0 +1 @@
+ALTER TABLE user_configs ADD COLUMN timestamp_style INT2;
@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS mapset_watches (
+    discord_id  INT8 NOT NULL,
+    mapset_id   INT4 NOT NULL,
+    -- "artist - title" at the time of watching
+    name        VARCHAR(256) NOT NULL,
+    -- last known `RankStatus`
+    rank_status INT2 NOT NULL,
+    -- NULL if the user is notified via DM
+    channel_id  INT8,
+    insert_date TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- set once the mapset ranked
+    expires_at  TIMESTAMPTZ,
+    PRIMARY KEY (discord_id, mapset_id)
+);
+
+CREATE INDEX mapset_watches_mapset_index ON mapset_watches (mapset_id);
@@ -0,0 +1 @@
+ALTER TABLE user_configs ADD COLUMN recent_includes_fails BOOL;
@@ -0,0 +1,4 @@
+ALTER TABLE osu_users_100th_pp
+    DROP COLUMN playcount_milestone,
+    DROP COLUMN ranked_score_milestone,
+    DROP COLUMN pp_milestone;
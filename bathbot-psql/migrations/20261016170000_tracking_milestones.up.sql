@@ -0,0 +1,4 @@
+ALTER TABLE osu_users_100th_pp
+    ADD COLUMN playcount_milestone INT8,
+    ADD COLUMN ranked_score_milestone INT8,
+    ADD COLUMN pp_milestone INT8;
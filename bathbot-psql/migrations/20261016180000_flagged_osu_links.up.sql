@@ -0,0 +1,5 @@
+CREATE TABLE IF NOT EXISTS flagged_osu_links (
+    osu_id     INT4 NOT NULL,
+    flagged_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (osu_id)
+);
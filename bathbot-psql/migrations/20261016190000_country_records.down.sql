@@ -0,0 +1 @@
+DROP TABLE IF EXISTS osu_country_records;
@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS osu_country_records (
+    country_code VARCHAR(2) NOT NULL,
+    gamemode     INT2 NOT NULL,
+    record_pp    FLOAT4 NOT NULL,
+    score_id     INT8 NOT NULL,
+    user_id      INT4 NOT NULL,
+    updated_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (country_code, gamemode)
+);
@@ -0,0 +1 @@
+ALTER TABLE user_configs ADD COLUMN graph_theme INT2;
@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN auto_score_embed_channels;
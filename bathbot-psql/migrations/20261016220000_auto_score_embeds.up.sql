@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN auto_score_embed_channels INT8[];
@@ -0,0 +1 @@
+ALTER TABLE user_configs ADD COLUMN blacklisted_mods VARCHAR(64);
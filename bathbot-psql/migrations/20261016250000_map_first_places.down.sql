@@ -0,0 +1 @@
+DROP TABLE IF EXISTS osu_map_first_places;
@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS osu_map_first_places (
+    map_id      INT4 NOT NULL,
+    gamemode    INT2 NOT NULL,
+    score_id    INT8 NOT NULL,
+    user_id     INT4 NOT NULL,
+    pp          FLOAT4,
+    observed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (map_id, score_id)
+);
+
+CREATE INDEX IF NOT EXISTS osu_map_first_places_map_observed_index
+    ON osu_map_first_places (map_id, gamemode, observed_at);
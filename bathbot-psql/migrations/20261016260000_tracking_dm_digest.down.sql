@@ -0,0 +1 @@
+ALTER TABLE user_configs DROP COLUMN tracking_dm_digest;
@@ -0,0 +1 @@
+ALTER TABLE user_configs ADD COLUMN tracking_dm_digest BOOL;
@@ -0,0 +1 @@
+ALTER TABLE user_configs DROP COLUMN skin_display_name, DROP COLUMN skin_display_url;
@@ -0,0 +1 @@
+ALTER TABLE user_configs ADD COLUMN skin_display_name VARCHAR(50), ADD COLUMN skin_display_url TEXT;
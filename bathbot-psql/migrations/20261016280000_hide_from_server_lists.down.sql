@@ -0,0 +1 @@
+ALTER TABLE user_configs DROP COLUMN hide_from_server_lists;
@@ -0,0 +1 @@
+ALTER TABLE user_configs ADD COLUMN hide_from_server_lists BOOL;
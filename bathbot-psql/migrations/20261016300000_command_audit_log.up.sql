@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS command_audit_log (
+    guild_id  INT8 NOT NULL,
+    user_id   INT8 NOT NULL,
+    -- command name with its group and subcommand e.g. "serverconfig edit"
+    command   VARCHAR(64) NOT NULL,
+    -- truncated summary of the given options
+    options   VARCHAR(256) NOT NULL,
+    success   BOOL NOT NULL,
+    timestamp TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX command_audit_log_guild_index ON command_audit_log (guild_id, timestamp DESC);
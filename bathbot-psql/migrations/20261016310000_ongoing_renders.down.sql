@@ -0,0 +1 @@
+DROP TABLE ongoing_renders;
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS ongoing_renders (
+    render_id    INT8 NOT NULL,
+    score_id     INT8,
+    -- channel in which the video url will be sent
+    channel_id   INT8 NOT NULL,
+    -- message that the video url will reply to
+    message_id   INT8 NOT NULL,
+    requester_id INT8 NOT NULL,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (render_id)
+);
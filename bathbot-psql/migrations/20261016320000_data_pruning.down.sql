@@ -0,0 +1,5 @@
+DROP TABLE prune_reports;
+
+DROP INDEX user_configs_last_seen_index;
+
+ALTER TABLE user_configs DROP COLUMN last_seen_at;
@@ -0,0 +1,15 @@
+ALTER TABLE user_configs ADD COLUMN last_seen_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+
+CREATE INDEX user_configs_last_seen_index ON user_configs (last_seen_at);
+
+CREATE TABLE IF NOT EXISTS prune_reports (
+    report_id   INT4 GENERATED ALWAYS AS IDENTITY,
+    -- discord users whose configs have been inactive
+    discord_ids INT8[] NOT NULL,
+    -- tracking channels that the bot can no longer see
+    channel_ids INT8[] NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- set once the owner confirmed the report
+    applied_at  TIMESTAMPTZ,
+    PRIMARY KEY (report_id)
+);
@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN score_anomalies BOOLEAN;
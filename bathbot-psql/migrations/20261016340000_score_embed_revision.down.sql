@@ -0,0 +1,34 @@
+DROP FUNCTION merge_score_embed;
+DROP FUNCTION merge_score_embed_values;
+DROP FUNCTION score_embed_value_kind;
+
+ALTER TABLE user_configs DROP COLUMN score_embed_revision;
+
+-- Previous versions require the values, image, and buttons fields so sparse
+-- settings receive the defaults for missing ones
+UPDATE user_configs 
+SET 
+  score_embed = '{
+  "v": [
+    {"i": "grade", "y": 0},
+    {"i": "mods", "y": 0},
+    {"i": "score", "y": 0},
+    {"i": "acc", "y": 0},
+    {"i": "score_date", "y": 0},
+    {"i": {"pp": {}}, "y": 1},
+    {"i": {"combo": {}}, "y": 1},
+    {"i": {"hitresults": 1}, "y": 1},
+    {"i": "len", "y": 2},
+    {"i": "cs", "y": 2},
+    {"i": "ar", "y": 2},
+    {"i": "od", "y": 2},
+    {"i": "hp", "y": 2},
+    {"i": {"bpm": 0}, "y": 2},
+    {"i": {"mapper": {}}, "y": 255},
+    {"i": "ranked_date", "y": 255}
+  ],
+  "i": 0,
+  "b": {}
+}' :: JSONB || (score_embed - 'ver') 
+WHERE 
+  score_embed IS NOT NULL;
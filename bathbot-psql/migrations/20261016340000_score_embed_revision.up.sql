@@ -0,0 +1,69 @@
+-- Settings only keep explicitly set fields so that missing ones fall back to
+-- their current default, "ver" denotes this format
+UPDATE user_configs 
+SET 
+  score_embed = jsonb_set(
+    CASE WHEN score_embed -> 'b' = '{}' THEN score_embed - 'b' ELSE score_embed END, 
+    '{ver}', '1'
+  ) 
+WHERE 
+  score_embed IS NOT NULL;
+
+ALTER TABLE user_configs ADD COLUMN score_embed_revision INT4 NOT NULL DEFAULT 0;
+
+-- The kind of a value e.g. "grade" or "pp"
+CREATE FUNCTION score_embed_value_kind(value JSONB) RETURNS TEXT AS $$
+  SELECT 
+    CASE WHEN jsonb_typeof(value -> 'i') = 'string' THEN value ->> 'i' ELSE (
+      SELECT jsonb_object_keys(value -> 'i') LIMIT 1
+    ) END
+$$ LANGUAGE sql IMMUTABLE;
+
+-- Replace the values of the same kind as the edited ones, settings without
+-- values receive the patch's defaults which already include the edits
+CREATE FUNCTION merge_score_embed_values(settings JSONB, patch JSONB) RETURNS JSONB AS $$
+DECLARE
+  edit JSONB;
+  idx INT4;
+  merged JSONB := settings;
+BEGIN
+  IF NOT merged ? 'v' THEN
+    RETURN jsonb_set(merged, '{v}', patch -> 'd');
+  END IF;
+
+  FOR edit IN SELECT value FROM jsonb_array_elements(patch -> 'e') LOOP
+    SELECT 
+      ord - 1 INTO idx 
+    FROM 
+      jsonb_array_elements(merged -> 'v') WITH ORDINALITY AS elems(value, ord) 
+    WHERE 
+      score_embed_value_kind(elems.value) = score_embed_value_kind(edit);
+
+    IF idx IS NOT NULL THEN
+      merged := jsonb_set(merged, ARRAY['v', idx :: TEXT], edit);
+    END IF;
+  END LOOP;
+
+  RETURN merged;
+END;
+$$ LANGUAGE plpgsql IMMUTABLE;
+
+-- Set each field of the patch or remove it if its value is null
+CREATE FUNCTION merge_score_embed(settings JSONB, patch JSONB) RETURNS JSONB AS $$
+DECLARE
+  field RECORD;
+  merged JSONB := COALESCE(settings, '{"ver": 1}');
+BEGIN
+  FOR field IN SELECT key, value FROM jsonb_each(patch) LOOP
+    IF jsonb_typeof(field.value) = 'null' THEN
+      merged := merged - field.key;
+    ELSIF field.key = 'v' AND jsonb_typeof(field.value) = 'object' THEN
+      merged := merge_score_embed_values(merged, field.value);
+    ELSE
+      merged := jsonb_set(merged, ARRAY[field.key], field.value);
+    END IF;
+  END LOOP;
+
+  RETURN merged;
+END;
+$$ LANGUAGE plpgsql IMMUTABLE;
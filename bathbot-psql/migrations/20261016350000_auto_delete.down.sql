@@ -0,0 +1,4 @@
+ALTER TABLE guild_configs
+  DROP COLUMN auto_delete_secs,
+  DROP COLUMN auto_delete_groups,
+  DROP COLUMN auto_delete_attachments;
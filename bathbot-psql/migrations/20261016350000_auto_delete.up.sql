@@ -0,0 +1,4 @@
+ALTER TABLE guild_configs
+  ADD COLUMN auto_delete_secs INT2,
+  ADD COLUMN auto_delete_groups INT2,
+  ADD COLUMN auto_delete_attachments INT2;
@@ -0,0 +1,14 @@
+DELETE FROM osu_maps WHERE partial;
+DELETE FROM osu_mapsets WHERE partial;
+
+ALTER TABLE osu_maps
+  DROP COLUMN partial,
+  DROP COLUMN last_updated;
+
+ALTER TABLE osu_mapsets
+  DROP COLUMN partial,
+  DROP COLUMN last_updated,
+  ALTER COLUMN tags DROP DEFAULT,
+  ALTER COLUMN storyboard DROP DEFAULT,
+  ALTER COLUMN genre_id DROP DEFAULT,
+  ALTER COLUMN language_id DROP DEFAULT;
@@ -0,0 +1,11 @@
+ALTER TABLE osu_maps
+  ADD COLUMN partial BOOL NOT NULL DEFAULT FALSE,
+  ADD COLUMN last_updated TIMESTAMPTZ;
+
+ALTER TABLE osu_mapsets
+  ADD COLUMN partial BOOL NOT NULL DEFAULT FALSE,
+  ADD COLUMN last_updated TIMESTAMPTZ,
+  ALTER COLUMN tags SET DEFAULT '',
+  ALTER COLUMN storyboard SET DEFAULT FALSE,
+  ALTER COLUMN genre_id SET DEFAULT 0,
+  ALTER COLUMN language_id SET DEFAULT 0;
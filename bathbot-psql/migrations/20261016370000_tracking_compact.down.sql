@@ -0,0 +1,2 @@
+ALTER TABLE tracked_osu_users
+  DROP COLUMN compact;
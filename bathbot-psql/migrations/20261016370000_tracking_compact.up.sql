@@ -0,0 +1,2 @@
+ALTER TABLE tracked_osu_users
+  ADD COLUMN compact BOOL NOT NULL DEFAULT FALSE;
@@ -0,0 +1 @@
+DROP INDEX IF EXISTS command_audit_log_timestamp_index;
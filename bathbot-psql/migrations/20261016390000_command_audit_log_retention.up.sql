@@ -0,0 +1,2 @@
+-- old entries are deleted periodically across all guilds
+CREATE INDEX command_audit_log_timestamp_index ON command_audit_log (timestamp);
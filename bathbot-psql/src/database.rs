@@ -22,6 +22,20 @@ impl Database {
         Ok(Self { pool })
     }
 
+    /// Checks whether the database can be reached.
+    pub async fn ping(&self) -> Result<()> {
+        sqlx::query("SELECT 1").execute(&self.pool).await?;
+
+        Ok(())
+    }
+
+    /// Runs all pending migrations.
+    pub async fn migrate(&self) -> Result<()> {
+        sqlx::migrate!("./migrations").run(&self.pool).await?;
+
+        Ok(())
+    }
+
     /// Retrieves a connection from the pool.
     pub(crate) async fn acquire(&self) -> Result<PoolConnection<Postgres>, SqlxError> {
         self.pool.acquire().await
@@ -0,0 +1,211 @@
+use eyre::{Result, WrapErr};
+use sqlx::{Executor, Postgres};
+use time::OffsetDateTime;
+use twilight_model::id::{
+    Id,
+    marker::{GuildMarker, UserMarker},
+};
+
+use crate::{Database, model::audit_log::AuditLogEntry};
+
+impl Database {
+    pub async fn insert_audit_log_entry(&self, entry: &AuditLogEntry) -> Result<()> {
+        Self::insert_audit_log_entry_(self, entry).await
+    }
+
+    async fn insert_audit_log_entry_<'c, E>(executor: E, entry: &AuditLogEntry) -> Result<()>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO command_audit_log (
+  guild_id, user_id, command, options,
+  success, timestamp
+)
+VALUES
+  ($1, $2, $3, $4, $5, $6)"#,
+            entry.guild_id.get() as i64,
+            entry.user_id.get() as i64,
+            entry.command.as_ref(),
+            entry.options.as_ref(),
+            entry.success,
+            entry.timestamp,
+        );
+
+        query
+            .execute(executor)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// The most recent entries of a guild, optionally only for a user and
+    /// commands starting with the given name.
+    pub async fn select_audit_log(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Option<Id<UserMarker>>,
+        command: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<AuditLogEntry>> {
+        Self::select_audit_log_(self, guild_id, user_id, command, limit).await
+    }
+
+    async fn select_audit_log_<'c, E>(
+        executor: E,
+        guild_id: Id<GuildMarker>,
+        user_id: Option<Id<UserMarker>>,
+        command: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<AuditLogEntry>>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  user_id,
+  command,
+  options,
+  success,
+  timestamp
+FROM
+  command_audit_log
+WHERE
+  guild_id = $1
+  AND (
+    $2 :: INT8 IS NULL
+    OR user_id = $2
+  )
+  AND (
+    $3 :: VARCHAR IS NULL
+    OR starts_with(command, $3)
+  )
+ORDER BY
+  timestamp DESC
+LIMIT
+  $4"#,
+            guild_id.get() as i64,
+            user_id.map(|id| id.get() as i64),
+            command,
+            limit as i64,
+        );
+
+        let rows = query
+            .fetch_all(executor)
+            .await
+            .wrap_err("Failed to fetch all")?;
+
+        let entries = rows
+            .into_iter()
+            .map(|row| AuditLogEntry {
+                guild_id,
+                user_id: Id::new(row.user_id as u64),
+                command: row.command.into_boxed_str(),
+                options: row.options.into_boxed_str(),
+                success: row.success,
+                timestamp: row.timestamp,
+            })
+            .collect();
+
+        Ok(entries)
+    }
+
+    /// Delete all entries older than the cutoff and return how many there
+    /// were.
+    pub async fn delete_audit_log_entries_before(&self, cutoff: OffsetDateTime) -> Result<u64> {
+        Self::delete_audit_log_entries_before_(self, cutoff).await
+    }
+
+    async fn delete_audit_log_entries_before_<'c, E>(
+        executor: E,
+        cutoff: OffsetDateTime,
+    ) -> Result<u64>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  command_audit_log
+WHERE
+  timestamp < $1"#,
+            cutoff,
+        );
+
+        let res = query
+            .execute(executor)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    use super::*;
+    use crate::test_util::begin_transaction;
+
+    #[tokio::test]
+    #[ignore = "requires a database"]
+    async fn filter_audit_log() -> Result<()> {
+        let mut tx = begin_transaction().await?;
+
+        let guild_id = Id::new(24680);
+        let alice = Id::new(1);
+        let bob = Id::new(2);
+        let now = OffsetDateTime::now_utc();
+
+        let entries = [
+            (alice, "serverconfig edit", now - Duration::minutes(2)),
+            (bob, "track osu", now - Duration::minutes(1)),
+            (alice, "track osu", now),
+        ];
+
+        for (user_id, command, timestamp) in entries {
+            let entry = AuditLogEntry {
+                guild_id,
+                user_id,
+                command: command.into(),
+                options: "".into(),
+                success: true,
+                timestamp,
+            };
+
+            Database::insert_audit_log_entry_(&mut *tx, &entry).await?;
+        }
+
+        let all = Database::select_audit_log_(&mut *tx, guild_id, None, None, 100).await?;
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[0].user_id, alice);
+        assert_eq!(&*all[0].command, "track osu");
+
+        let by_user =
+            Database::select_audit_log_(&mut *tx, guild_id, Some(alice), None, 100).await?;
+        assert_eq!(by_user.len(), 2);
+
+        let by_command =
+            Database::select_audit_log_(&mut *tx, guild_id, None, Some("track"), 100).await?;
+        assert_eq!(by_command.len(), 2);
+
+        let limited = Database::select_audit_log_(&mut *tx, guild_id, None, None, 1).await?;
+        assert_eq!(limited.len(), 1);
+
+        let cutoff = now - Duration::seconds(30);
+        let deleted = Database::delete_audit_log_entries_before_(&mut *tx, cutoff).await?;
+        assert!(deleted >= 2);
+
+        let remaining = Database::select_audit_log_(&mut *tx, guild_id, None, None, 100).await?;
+        assert_eq!(remaining.len(), 1);
+
+        // Don't leave the test entries behind
+        tx.rollback().await?;
+
+        Ok(())
+    }
+}
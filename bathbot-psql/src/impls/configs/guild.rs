@@ -31,7 +31,14 @@ SELECT
   render_button, 
   allow_custom_skins, 
   hide_medal_solution, 
-  score_data 
+  score_data, 
+  delete_invocation, 
+  score_anomalies, 
+  auto_delete_secs, 
+  auto_delete_groups, 
+  auto_delete_attachments, 
+  auto_score_embed_channels, 
+  lang 
 FROM 
   guild_configs"#
         );
@@ -63,6 +70,13 @@ FROM
             allow_custom_skins,
             hide_medal_solution,
             score_data,
+            delete_invocation,
+            score_anomalies,
+            auto_delete_secs,
+            auto_delete_groups,
+            auto_delete_attachments,
+            auto_score_embed_channels,
+            lang,
         } = config;
 
         let authorities = rkyv::util::with_arena(|arena| {
@@ -74,16 +88,28 @@ FROM
             Ok::<_, Report>(writer)
         })?;
 
+        let auto_score_embed_channels: Vec<_> = auto_score_embed_channels
+            .iter()
+            .map(|channel_id| channel_id.get() as i64)
+            .collect();
+
         let query = sqlx::query!(
             r#"
 INSERT INTO guild_configs (
   guild_id, authorities, prefixes, allow_songs, 
   retries, list_size, 
   render_button, allow_custom_skins, 
-  hide_medal_solution, score_data
+  hide_medal_solution, score_data, 
+  delete_invocation, score_anomalies, 
+  auto_delete_secs, auto_delete_groups, 
+  auto_delete_attachments, auto_score_embed_channels, 
+  lang
 ) 
 VALUES 
-  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+  (
+    $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, 
+    $12, $13, $14, $15, $16, $17
+  )
 ON CONFLICT
   (guild_id)
 DO 
@@ -97,7 +123,14 @@ SET
   render_button = $7, 
   allow_custom_skins = $8, 
   hide_medal_solution = $9, 
-  score_data = $10"#,
+  score_data = $10, 
+  delete_invocation = $11, 
+  score_anomalies = $12, 
+  auto_delete_secs = $13, 
+  auto_delete_groups = $14, 
+  auto_delete_attachments = $15, 
+  auto_score_embed_channels = $16, 
+  lang = $17"#,
             guild_id.get() as i64,
             &authorities as &[u8],
             Json(prefixes) as _,
@@ -108,6 +141,13 @@ SET
             *allow_custom_skins,
             hide_medal_solution.map(i16::from),
             score_data.map(i16::from),
+            *delete_invocation,
+            *score_anomalies,
+            auto_delete_secs.map(|secs| secs as i16),
+            auto_delete_groups.map(|bits| bits as i16),
+            auto_delete_attachments.map(|bits| bits as i16),
+            &auto_score_embed_channels as &[i64],
+            lang.map(i16::from),
         );
 
         query
@@ -1,16 +1,22 @@
-use bathbot_model::embed_builder::ScoreEmbedSettings;
-use eyre::{Result, WrapErr};
+use bathbot_model::embed_builder::{ScoreEmbedPatch, ScoreEmbedSettings};
+use eyre::{ContextCompat, Result, WrapErr, bail};
 use futures::StreamExt;
-use rosu_v2::prelude::GameMode;
-use sqlx::types::Json;
+use rosu_v2::prelude::{GameMode, GameModsIntermode};
+use sqlx::{Executor, PgConnection, Postgres, types::Json};
 use time::UtcOffset;
 use twilight_model::id::{Id, marker::UserMarker};
 
 use crate::{
     Database,
-    model::configs::{DbSkinEntry, DbUserConfig, OsuUserId, SkinEntry, UserConfig},
+    model::configs::{
+        DbSkinEntry, DbUserConfig, GraphTheme, OsuUserId, RevisedScoreEmbed, SkinEntry, UserConfig,
+    },
 };
 
+/// How often a score embed patch is replayed on concurrent changes before
+/// giving up.
+const PATCH_ATTEMPTS: usize = 3;
+
 impl Database {
     pub async fn select_user_config_with_osu_id_by_discord_id(
         &self,
@@ -28,7 +34,17 @@ SELECT
   twitch_id, 
   timezone_seconds, 
   render_button, 
-  score_data 
+  score_data, 
+  timestamp_style, 
+  recent_includes_fails, 
+  graph_theme, 
+  blacklisted_mods, 
+  extended_profile, 
+  tracking_dm_digest, 
+  skin_display_name, 
+  skin_display_url, 
+  hide_from_server_lists, 
+  osutrack_auto_update 
 FROM 
   user_configs 
 WHERE 
@@ -48,6 +64,16 @@ WHERE
         &self,
         user_id: Id<UserMarker>,
     ) -> Result<Option<u32>> {
+        Self::select_osu_id_by_discord_id_(self, user_id).await
+    }
+
+    async fn select_osu_id_by_discord_id_<'c, E>(
+        executor: E,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<u32>>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
         let query = sqlx::query!(
             r#"
 SELECT 
@@ -60,7 +86,7 @@ WHERE
         );
 
         let osu_id = query
-            .fetch_optional(self)
+            .fetch_optional(executor)
             .await
             .wrap_err("failed to fetch optional")?
             .and_then(|row| row.osu_id);
@@ -247,11 +273,26 @@ FROM
         Ok(twitch_id.map(|id| id as u64))
     }
 
+    /// Insert or update the config.
+    ///
+    /// Score embed settings are only inserted, updates go through
+    /// [`Database::patch_score_embed_settings`].
     pub async fn upsert_user_config(
         &self,
         user_id: Id<UserMarker>,
         config: &UserConfig<OsuUserId>,
     ) -> Result<()> {
+        Self::upsert_user_config_(self, user_id, config).await
+    }
+
+    async fn upsert_user_config_<'c, E>(
+        executor: E,
+        user_id: Id<UserMarker>,
+        config: &UserConfig<OsuUserId>,
+    ) -> Result<()>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
         let UserConfig {
             list_size,
             score_embed,
@@ -262,6 +303,16 @@ FROM
             timezone,
             render_button,
             score_data,
+            timestamp_style,
+            recent_includes_fails,
+            graph_theme,
+            blacklisted_mods,
+            extended_profile,
+            tracking_dm_digest,
+            skin_display_name,
+            skin_display_url,
+            hide_from_server_lists,
+            osutrack_auto_update,
         } = config;
 
         let query = sqlx::query!(
@@ -269,21 +320,38 @@ FROM
 INSERT INTO user_configs (
   discord_id, osu_id, gamemode, twitch_id, 
   retries, score_embed, list_size, 
-  timezone_seconds, render_button, score_data
+  timezone_seconds, render_button, score_data, 
+  timestamp_style, recent_includes_fails, 
+  graph_theme, blacklisted_mods, extended_profile, 
+  tracking_dm_digest, skin_display_name, 
+  skin_display_url, hide_from_server_lists, 
+  osutrack_auto_update
 ) 
 VALUES 
-  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) ON CONFLICT (discord_id) DO 
+  (
+    $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, 
+    $12, $13, $14, $15, $16, $17, $18, $19, $20
+  ) ON CONFLICT (discord_id) DO 
 UPDATE 
 SET 
   osu_id = $2, 
   gamemode = $3, 
   twitch_id = $4, 
   retries = $5, 
-  score_embed = $6, 
   list_size = $7, 
   timezone_seconds = $8, 
   render_button = $9, 
-  score_data = $10"#,
+  score_data = $10, 
+  timestamp_style = $11, 
+  recent_includes_fails = $12, 
+  graph_theme = $13, 
+  blacklisted_mods = $14, 
+  extended_profile = $15, 
+  tracking_dm_digest = $16, 
+  skin_display_name = $17, 
+  skin_display_url = $18, 
+  hide_from_server_lists = $19, 
+  osutrack_auto_update = $20"#,
             user_id.get() as i64,
             osu.map(|id| id as i32),
             mode.map(|mode| mode as i16) as Option<i16>,
@@ -294,10 +362,20 @@ SET
             timezone.map(UtcOffset::whole_seconds),
             *render_button,
             score_data.map(i16::from),
+            timestamp_style.map(i16::from),
+            *recent_includes_fails,
+            graph_theme.map(i16::from),
+            blacklisted_mods.as_ref().map(ToString::to_string),
+            *extended_profile,
+            *tracking_dm_digest,
+            skin_display_name.as_deref(),
+            skin_display_url.as_deref(),
+            *hide_from_server_lists,
+            *osutrack_auto_update,
         );
 
         query
-            .execute(self)
+            .execute(executor)
             .await
             .wrap_err("failed to execute query")?;
 
@@ -306,33 +384,142 @@ SET
         Ok(())
     }
 
-    pub async fn update_score_embed_settings(
+    pub async fn select_score_embed_settings(
         &self,
         user_id: Id<UserMarker>,
-        settings: &ScoreEmbedSettings,
-    ) -> Result<()> {
+    ) -> Result<Option<RevisedScoreEmbed>> {
+        Self::select_score_embed_settings_(self, user_id).await
+    }
+
+    async fn select_score_embed_settings_<'c, E>(
+        executor: E,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<RevisedScoreEmbed>>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
         let query = sqlx::query!(
             r#"
-UPDATE user_configs
-SET
-  score_embed = $2
-WHERE
+SELECT 
+  score_embed as "score_embed: Json<ScoreEmbedSettings>", 
+  score_embed_revision 
+FROM 
+  user_configs 
+WHERE 
   discord_id = $1"#,
-            user_id.get() as i64,
-            Json(settings) as Json<_>,
+            user_id.get() as i64
         );
 
-        query
-            .execute(self)
+        let row_opt = query
+            .fetch_optional(executor)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt.map(|row| RevisedScoreEmbed {
+            settings: row.score_embed.map(|Json(settings)| settings),
+            revision: row.score_embed_revision,
+        }))
+    }
+
+    /// Apply the patch on the stored score embed settings.
+    ///
+    /// If the settings changed since `revision`, they're reloaded and the
+    /// patch is replayed on top of them.
+    pub async fn patch_score_embed_settings(
+        &self,
+        user_id: Id<UserMarker>,
+        patch: &ScoreEmbedPatch,
+        revision: i32,
+    ) -> Result<RevisedScoreEmbed> {
+        let mut conn = self
+            .acquire()
             .await
-            .wrap_err("Failed to execute query")?;
+            .wrap_err("Failed to acquire connection")?;
+
+        Self::patch_score_embed_settings_(&mut conn, user_id, patch, revision).await
+    }
+
+    async fn patch_score_embed_settings_(
+        conn: &mut PgConnection,
+        user_id: Id<UserMarker>,
+        patch: &ScoreEmbedPatch,
+        mut revision: i32,
+    ) -> Result<RevisedScoreEmbed> {
+        for _ in 0..PATCH_ATTEMPTS {
+            let update_fut =
+                Self::update_score_embed_settings_(&mut *conn, user_id, patch, revision);
+
+            if let Some(revised) = update_fut.await? {
+                debug!(
+                    user_id = user_id.get(),
+                    revision = revised.revision,
+                    "Patched score embed settings"
+                );
+
+                return Ok(revised);
+            }
+
+            let stored = Self::select_score_embed_settings_(&mut *conn, user_id)
+                .await?
+                .wrap_err("Missing user config")?;
+
+            let current = stored.settings.clone().unwrap_or_default();
+
+            let replayed = patch
+                .apply(&current)
+                .wrap_err("Failed to replay score embed patch")?;
+
+            // The concurrent change already did the same
+            if replayed == current {
+                return Ok(stored);
+            }
+
+            revision = stored.revision;
+        }
+
+        bail!("Score embed settings kept changing concurrently")
+    }
 
-        debug!(
-            user_id = user_id.get(),
-            "Inserted score embed settings into DB"
+    /// Merge the patch into the stored settings if they're still at
+    /// `revision`.
+    ///
+    /// Returns `None` if the settings were changed in the meantime.
+    async fn update_score_embed_settings_<'c, E>(
+        executor: E,
+        user_id: Id<UserMarker>,
+        patch: &ScoreEmbedPatch,
+        revision: i32,
+    ) -> Result<Option<RevisedScoreEmbed>>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  user_configs 
+SET 
+  score_embed = merge_score_embed(score_embed, $2), 
+  score_embed_revision = score_embed_revision + 1 
+WHERE 
+  discord_id = $1 
+  AND score_embed_revision = $3 
+RETURNING 
+  score_embed as "score_embed: Json<ScoreEmbedSettings>", 
+  score_embed_revision"#,
+            user_id.get() as i64,
+            Json(patch) as Json<_>,
+            revision,
         );
 
-        Ok(())
+        let row_opt = query
+            .fetch_optional(executor)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt.map(|row| RevisedScoreEmbed {
+            settings: row.score_embed.map(|Json(settings)| settings),
+            revision: row.score_embed_revision,
+        }))
     }
 
     pub async fn update_skin_url(
@@ -383,6 +570,151 @@ WHERE
         Ok(row_opt.map(|row| Id::new(row.discord_id as u64)))
     }
 
+    /// Discord users that are linked to the osu! user and opted into the
+    /// tracking DM digest.
+    pub async fn select_tracking_dm_digest_discord_ids(
+        &self,
+        user_id: u32,
+    ) -> Result<Vec<Id<UserMarker>>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  discord_id 
+FROM 
+  user_configs 
+WHERE 
+  osu_id = $1 
+  AND tracking_dm_digest"#,
+            user_id as i32
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("failed to fetch all")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Id::new(row.discord_id as u64))
+            .collect())
+    }
+
+    /// Filter the given discord users down to those that may appear in server
+    /// leaderboards i.e. those that did not hide themselves from server lists.
+    ///
+    /// Users without a config are kept.
+    pub async fn select_visible_linked_users(&self, discord_ids: &[i64]) -> Result<Vec<i64>> {
+        Self::select_visible_linked_users_(self, discord_ids).await
+    }
+
+    async fn select_visible_linked_users_<'c, E>(
+        executor: E,
+        discord_ids: &[i64],
+    ) -> Result<Vec<i64>>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  members.discord_id AS "discord_id!" 
+FROM 
+  UNNEST($1 :: INT8[]) AS members(discord_id) 
+  LEFT JOIN user_configs AS configs ON members.discord_id = configs.discord_id 
+WHERE 
+  configs.hide_from_server_lists IS NOT TRUE"#,
+            discord_ids
+        );
+
+        let rows = query
+            .fetch_all(executor)
+            .await
+            .wrap_err("failed to fetch all")?;
+
+        Ok(rows.into_iter().map(|row| row.discord_id).collect())
+    }
+
+    pub async fn update_tracking_dm_digest(
+        &self,
+        user_id: Id<UserMarker>,
+        tracking_dm_digest: bool,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  user_configs 
+SET 
+  tracking_dm_digest = $2 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64,
+            tracking_dm_digest
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Flag linked osu! users as possibly restricted.
+    pub async fn upsert_flagged_osu_link(&self, user_id: u32) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO flagged_osu_links (osu_id) 
+VALUES 
+  ($1) ON CONFLICT (osu_id) DO NOTHING"#,
+            user_id as i32
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_flagged_osu_link(&self, user_id: u32) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  flagged_osu_links 
+WHERE 
+  osu_id = $1"#,
+            user_id as i32
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn select_osu_link_flagged(&self, user_id: u32) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  1 AS flagged 
+FROM 
+  flagged_osu_links 
+WHERE 
+  osu_id = $1"#,
+            user_id as i32
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt.is_some())
+    }
+
     pub async fn select_user_mode(&self, user_id: Id<UserMarker>) -> Result<Option<GameMode>> {
         let query = sqlx::query!(
             r#"
@@ -402,4 +734,214 @@ WHERE
 
         Ok(row_opt.and_then(|row| row.gamemode.map(|mode| GameMode::from(mode as u8))))
     }
+
+    pub async fn select_user_graph_theme(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<GraphTheme>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  graph_theme 
+FROM 
+  user_configs 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt
+            .and_then(|row| row.graph_theme)
+            .map(GraphTheme::try_from)
+            .and_then(Result::ok))
+    }
+
+    pub async fn select_user_blacklisted_mods(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<GameModsIntermode>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  blacklisted_mods 
+FROM 
+  user_configs 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt
+            .and_then(|row| row.blacklisted_mods)
+            .as_deref()
+            .and_then(GameModsIntermode::try_from_acronyms))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bathbot_model::embed_builder::{ComboValue, PpValue, SettingsImage, Value};
+
+    use super::*;
+    use crate::test_util::begin_transaction;
+
+    #[tokio::test]
+    #[ignore = "requires a database"]
+    async fn hidden_users_are_excluded_from_server_lists() -> Result<()> {
+        let mut tx = begin_transaction().await?;
+
+        let hidden = Id::new(11111);
+        let visible = Id::new(22222);
+        let unconfigured = Id::new(33333);
+
+        let hidden_config = UserConfig {
+            osu: Some(1),
+            hide_from_server_lists: Some(true),
+            ..Default::default()
+        };
+
+        let visible_config = UserConfig {
+            osu: Some(2),
+            hide_from_server_lists: Some(false),
+            ..Default::default()
+        };
+
+        Database::upsert_user_config_(&mut *tx, hidden, &hidden_config).await?;
+        Database::upsert_user_config_(&mut *tx, visible, &visible_config).await?;
+
+        let members = [hidden, visible, unconfigured].map(|id| id.get() as i64);
+
+        let mut visible_members =
+            Database::select_visible_linked_users_(&mut *tx, &members).await?;
+        visible_members.sort_unstable();
+        assert_eq!(
+            visible_members,
+            [visible.get() as i64, unconfigured.get() as i64]
+        );
+
+        // Direct lookups are unaffected
+        let osu_id = Database::select_osu_id_by_discord_id_(&mut *tx, hidden).await?;
+        assert_eq!(osu_id, Some(1));
+
+        tx.rollback().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a database"]
+    async fn concurrent_score_embed_patches() -> Result<()> {
+        let mut tx = begin_transaction().await?;
+
+        let user_id = Id::new(44444);
+        Database::upsert_user_config_(&mut *tx, user_id, &UserConfig::default()).await?;
+
+        let stored = Database::select_score_embed_settings_(&mut *tx, user_id).await?;
+        assert_eq!(
+            stored,
+            Some(RevisedScoreEmbed {
+                settings: None,
+                revision: 0
+            })
+        );
+
+        // Two builders that were opened at the same revision
+        let base = ScoreEmbedSettings::default();
+
+        let mut hide_artist = base.clone();
+        hide_artist.show_artist = false;
+        let artist_patch = ScoreEmbedPatch::diff(&base, &hide_artist);
+
+        let mut hide_image = base.clone();
+        hide_image.image = SettingsImage::Hide;
+        let image_patch = ScoreEmbedPatch::diff(&base, &hide_image);
+
+        let first =
+            Database::patch_score_embed_settings_(&mut tx, user_id, &artist_patch, 0).await?;
+        assert_eq!(first.revision, 1);
+        assert_eq!(first.settings.as_ref(), Some(&hide_artist));
+
+        // The stale revision is rejected...
+        let stale =
+            Database::update_score_embed_settings_(&mut *tx, user_id, &image_patch, 0).await?;
+        assert!(stale.is_none());
+
+        // ... but replayed on top of the concurrent change
+        let second =
+            Database::patch_score_embed_settings_(&mut tx, user_id, &image_patch, 0).await?;
+        assert_eq!(second.revision, 2);
+
+        let merged = second.settings.unwrap();
+        assert!(!merged.show_artist);
+        assert_eq!(merged.image, SettingsImage::Hide);
+
+        // The database merges the same way as the replay
+        assert_eq!(merged, image_patch.apply(&hide_artist)?);
+
+        // Replaying a change that was already made does not bump the revision
+        let same = Database::patch_score_embed_settings_(&mut tx, user_id, &image_patch, 1).await?;
+        assert_eq!(same.revision, 2);
+
+        // Resetting a field removes it so it follows the default
+        let reset_patch = ScoreEmbedPatch::diff(&hide_artist, &base);
+        let reset =
+            Database::patch_score_embed_settings_(&mut tx, user_id, &reset_patch, 2).await?;
+        assert_eq!(reset.revision, 3);
+        assert!(reset.settings.unwrap().show_artist);
+
+        tx.rollback().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a database"]
+    async fn concurrent_score_embed_value_edits() -> Result<()> {
+        let mut tx = begin_transaction().await?;
+
+        let user_id = Id::new(55555);
+        Database::upsert_user_config_(&mut *tx, user_id, &UserConfig::default()).await?;
+
+        // Two builders that edit different values at the same revision
+        let base = ScoreEmbedSettings::default();
+
+        let mut pp_edit = base.clone();
+        pp_edit.values[5].inner = Value::Pp(PpValue {
+            max: false,
+            ..Default::default()
+        });
+        let pp_patch = ScoreEmbedPatch::diff(&base, &pp_edit);
+
+        let mut combo_edit = base.clone();
+        combo_edit.values[6].inner = Value::Combo(ComboValue { max: false });
+        let combo_patch = ScoreEmbedPatch::diff(&base, &combo_edit);
+
+        // Stored settings without values receive the patch's defaults
+        let first = Database::patch_score_embed_settings_(&mut tx, user_id, &pp_patch, 0).await?;
+        assert_eq!(first.settings.as_ref(), Some(&pp_edit));
+
+        let second =
+            Database::patch_score_embed_settings_(&mut tx, user_id, &combo_patch, 0).await?;
+        assert_eq!(second.revision, 2);
+
+        // Only the edited value was replaced so both edits are kept
+        let merged = second.settings.unwrap();
+        assert_eq!(merged.values[5], pp_edit.values[5]);
+        assert_eq!(merged.values[6], combo_edit.values[6]);
+        assert_eq!(merged, combo_patch.apply(&pp_edit)?);
+
+        tx.rollback().await?;
+
+        Ok(())
+    }
 }
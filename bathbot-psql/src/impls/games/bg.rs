@@ -1,12 +1,14 @@
 use std::mem;
 
-use bathbot_model::BgGameScore;
+use bathbot_model::{BgGameScore, MapsetTags};
 use eyre::{Result, WrapErr};
 use rosu_v2::prelude::GameMode;
+use sqlx::{Postgres, Transaction};
+use twilight_model::id::{Id, marker::UserMarker};
 
 use crate::{
     Database,
-    model::games::{DbBgGameScore, DbMapTagEntry, DbMapTagsParams},
+    model::games::{DbBgGameScore, DbMapTagEntry, DbMapTagsParams, DbMapsetTags},
 };
 
 impl Database {
@@ -90,4 +92,208 @@ SET
             .await
             .wrap_err("failed to fetch all")
     }
+
+    pub async fn select_mapset_tags(&self, mapset_id: u32) -> Result<Option<MapsetTags>> {
+        let query = sqlx::query_as!(
+            DbMapsetTags,
+            r#"
+SELECT 
+  farm, 
+  streams, 
+  alternate, 
+  old, 
+  meme, 
+  hardname, 
+  easy, 
+  hard, 
+  tech, 
+  weeb, 
+  bluesky, 
+  english, 
+  kpop 
+FROM 
+  map_tags 
+WHERE 
+  mapset_id = $1"#,
+            mapset_id as i32
+        );
+
+        let tags = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(tags.map(MapsetTags::from))
+    }
+
+    /// Overwrite the tags of a mapset and add an entry to the edit log.
+    ///
+    /// Returns the previous tags or `None` if the mapset is not stored.
+    pub async fn update_mapset_tags(
+        &self,
+        mapset_id: u32,
+        tags: MapsetTags,
+        editor: Id<UserMarker>,
+    ) -> Result<Option<MapsetTags>> {
+        let mut tx = self.begin().await.wrap_err("failed to begin transaction")?;
+
+        let query = sqlx::query_as!(
+            DbMapsetTags,
+            r#"
+SELECT 
+  farm, 
+  streams, 
+  alternate, 
+  old, 
+  meme, 
+  hardname, 
+  easy, 
+  hard, 
+  tech, 
+  weeb, 
+  bluesky, 
+  english, 
+  kpop 
+FROM 
+  map_tags 
+WHERE 
+  mapset_id = $1 FOR 
+UPDATE"#,
+            mapset_id as i32
+        );
+
+        let old_tags = query
+            .fetch_optional(&mut *tx)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        let Some(old_tags) = old_tags.map(MapsetTags::from) else {
+            return Ok(None);
+        };
+
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  map_tags 
+SET 
+  farm = $2, 
+  streams = $3, 
+  alternate = $4, 
+  old = $5, 
+  meme = $6, 
+  hardname = $7, 
+  easy = $8, 
+  hard = $9, 
+  tech = $10, 
+  weeb = $11, 
+  bluesky = $12, 
+  english = $13, 
+  kpop = $14 
+WHERE 
+  mapset_id = $1"#,
+            mapset_id as i32,
+            tags.contains(MapsetTags::Farm),
+            tags.contains(MapsetTags::Streams),
+            tags.contains(MapsetTags::Alternate),
+            tags.contains(MapsetTags::Old),
+            tags.contains(MapsetTags::Meme),
+            tags.contains(MapsetTags::HardName),
+            tags.contains(MapsetTags::Easy),
+            tags.contains(MapsetTags::Hard),
+            tags.contains(MapsetTags::Tech),
+            tags.contains(MapsetTags::Weeb),
+            tags.contains(MapsetTags::BlueSky),
+            tags.contains(MapsetTags::English),
+            tags.contains(MapsetTags::Kpop),
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Self::insert_map_tags_edit(&mut tx, mapset_id, editor, old_tags, tags)
+            .await
+            .wrap_err("failed to insert edit")?;
+
+        tx.commit().await.wrap_err("failed to commit transaction")?;
+
+        Ok(Some(old_tags))
+    }
+
+    async fn insert_map_tags_edit(
+        tx: &mut Transaction<'_, Postgres>,
+        mapset_id: u32,
+        editor: Id<UserMarker>,
+        old_tags: MapsetTags,
+        new_tags: MapsetTags,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO map_tag_edits (
+  mapset_id, editor_id, old_tags, new_tags
+) 
+VALUES 
+  ($1, $2, $3, $4)"#,
+            mapset_id as i32,
+            editor.get() as i64,
+            old_tags.bits() as i32,
+            new_tags.bits() as i32,
+        );
+
+        query
+            .execute(&mut **tx)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::begin_transaction;
+
+    #[tokio::test]
+    #[ignore = "requires a database"]
+    async fn insert_map_tags_edit_entry() -> Result<()> {
+        let mut tx = begin_transaction().await?;
+
+        let mapset_id = 12345;
+        let editor = Id::new(67890);
+        let old_tags = MapsetTags::Weeb | MapsetTags::Streams;
+        let new_tags = MapsetTags::Weeb | MapsetTags::Tech;
+
+        Database::insert_map_tags_edit(&mut tx, mapset_id, editor, old_tags, new_tags).await?;
+
+        let row = sqlx::query!(
+            r#"
+SELECT 
+  editor_id, 
+  old_tags, 
+  new_tags, 
+  edited_at 
+FROM 
+  map_tag_edits 
+WHERE 
+  mapset_id = $1 
+ORDER BY 
+  edit_id DESC 
+LIMIT 
+  1"#,
+            mapset_id as i32
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        assert_eq!(row.editor_id, editor.get() as i64);
+        assert_eq!(row.old_tags, old_tags.bits() as i32);
+        assert_eq!(row.new_tags, new_tags.bits() as i32);
+
+        // Don't leave the test entry behind
+        tx.rollback().await?;
+
+        Ok(())
+    }
 }
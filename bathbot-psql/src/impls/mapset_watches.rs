@@ -0,0 +1,314 @@
+use eyre::{Result, WrapErr};
+use futures::StreamExt;
+use rosu_v2::prelude::RankStatus;
+use sqlx::{Executor, Postgres};
+use time::OffsetDateTime;
+use twilight_model::id::{Id, marker::UserMarker};
+
+use crate::{Database, model::osu::MapsetWatch, util::parse_status};
+
+impl Database {
+    pub async fn select_mapset_watches(&self) -> Result<Vec<MapsetWatch>> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  discord_id,
+  mapset_id,
+  name,
+  rank_status,
+  channel_id,
+  expires_at
+FROM
+  mapset_watches"#
+        );
+
+        let mut rows = query.fetch(self);
+        let mut watches = Vec::new();
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("Failed to fetch next")?;
+
+            watches.push(MapsetWatch {
+                user_id: Id::new(row.discord_id as u64),
+                mapset_id: row.mapset_id as u32,
+                name: row.name.into_boxed_str(),
+                status: parse_status(row.rank_status),
+                channel_id: row.channel_id.map(|id| Id::new(id as u64)),
+                expires_at: row.expires_at,
+            });
+        }
+
+        Ok(watches)
+    }
+
+    pub async fn select_user_mapset_watches(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Result<Vec<MapsetWatch>> {
+        Self::select_user_mapset_watches_(self, user_id).await
+    }
+
+    async fn select_user_mapset_watches_<'c, E>(
+        executor: E,
+        user_id: Id<UserMarker>,
+    ) -> Result<Vec<MapsetWatch>>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  mapset_id,
+  name,
+  rank_status,
+  channel_id,
+  expires_at
+FROM
+  mapset_watches
+WHERE
+  discord_id = $1
+ORDER BY
+  insert_date DESC"#,
+            user_id.get() as i64
+        );
+
+        let rows = query
+            .fetch_all(executor)
+            .await
+            .wrap_err("Failed to fetch all")?;
+
+        let watches = rows
+            .into_iter()
+            .map(|row| MapsetWatch {
+                user_id,
+                mapset_id: row.mapset_id as u32,
+                name: row.name.into_boxed_str(),
+                status: parse_status(row.rank_status),
+                channel_id: row.channel_id.map(|id| Id::new(id as u64)),
+                expires_at: row.expires_at,
+            })
+            .collect();
+
+        Ok(watches)
+    }
+
+    pub async fn upsert_mapset_watch(&self, watch: &MapsetWatch) -> Result<()> {
+        Self::upsert_mapset_watch_(self, watch).await
+    }
+
+    async fn upsert_mapset_watch_<'c, E>(executor: E, watch: &MapsetWatch) -> Result<()>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO mapset_watches (
+  discord_id, mapset_id, name, rank_status,
+  channel_id, expires_at
+)
+VALUES
+  ($1, $2, $3, $4, $5, $6) ON CONFLICT (discord_id, mapset_id) DO
+UPDATE
+SET
+  name = $3,
+  rank_status = $4,
+  channel_id = $5,
+  expires_at = $6"#,
+            watch.user_id.get() as i64,
+            watch.mapset_id as i32,
+            watch.name.as_ref(),
+            watch.status as i16,
+            watch.channel_id.map(|id| id.get() as i64),
+            watch.expires_at,
+        );
+
+        query
+            .execute(executor)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Store the new status of a mapset for all of its watches.
+    pub async fn update_mapset_watch_status(
+        &self,
+        mapset_id: u32,
+        status: RankStatus,
+        expires_at: Option<OffsetDateTime>,
+    ) -> Result<()> {
+        Self::update_mapset_watch_status_(self, mapset_id, status, expires_at).await
+    }
+
+    async fn update_mapset_watch_status_<'c, E>(
+        executor: E,
+        mapset_id: u32,
+        status: RankStatus,
+        expires_at: Option<OffsetDateTime>,
+    ) -> Result<()>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+UPDATE
+  mapset_watches
+SET
+  rank_status = $2,
+  expires_at = $3
+WHERE
+  mapset_id = $1"#,
+            mapset_id as i32,
+            status as i16,
+            expires_at,
+        );
+
+        query
+            .execute(executor)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether the watch existed.
+    pub async fn delete_mapset_watch(
+        &self,
+        user_id: Id<UserMarker>,
+        mapset_id: u32,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  mapset_watches
+WHERE
+  discord_id = $1
+  AND mapset_id = $2"#,
+            user_id.get() as i64,
+            mapset_id as i32
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Delete the watches of all users on a mapset.
+    pub async fn delete_mapset_watches(&self, mapset_id: u32) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  mapset_watches
+WHERE
+  mapset_id = $1"#,
+            mapset_id as i32
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Delete all watches that expired before `now`.
+    ///
+    /// Returns the amount of deleted watches.
+    pub async fn delete_expired_mapset_watches(&self, now: OffsetDateTime) -> Result<u64> {
+        Self::delete_expired_mapset_watches_(self, now).await
+    }
+
+    async fn delete_expired_mapset_watches_<'c, E>(executor: E, now: OffsetDateTime) -> Result<u64>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  mapset_watches
+WHERE
+  expires_at <= $1"#,
+            now
+        );
+
+        let res = query
+            .execute(executor)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    use super::*;
+    use crate::test_util::begin_transaction;
+
+    #[tokio::test]
+    #[ignore = "requires a database"]
+    async fn store_and_expire_mapset_watches() -> Result<()> {
+        let mut tx = begin_transaction().await?;
+
+        let user_id = Id::new(67890);
+        let mapset_id = 12345;
+
+        let mut watch = MapsetWatch {
+            user_id,
+            mapset_id,
+            name: "Artist - Title".into(),
+            status: RankStatus::Qualified,
+            channel_id: None,
+            expires_at: None,
+        };
+
+        Database::upsert_mapset_watch_(&mut *tx, &watch).await?;
+
+        // Watching the same mapset again only updates the entry
+        watch.channel_id = Some(Id::new(13579));
+        Database::upsert_mapset_watch_(&mut *tx, &watch).await?;
+
+        let watches = Database::select_user_mapset_watches_(&mut *tx, user_id).await?;
+        assert_eq!(watches.len(), 1);
+        assert_eq!(watches[0].mapset_id, mapset_id);
+        assert_eq!(watches[0].status, RankStatus::Qualified);
+        assert_eq!(watches[0].channel_id, watch.channel_id);
+        assert!(watches[0].expires_at.is_none());
+
+        let now = OffsetDateTime::now_utc();
+        let expires_at = now + Duration::days(60);
+
+        Database::update_mapset_watch_status_(
+            &mut *tx,
+            mapset_id,
+            RankStatus::Ranked,
+            Some(expires_at),
+        )
+        .await?;
+
+        // Not expired yet
+        Database::delete_expired_mapset_watches_(&mut *tx, now).await?;
+        let watches = Database::select_user_mapset_watches_(&mut *tx, user_id).await?;
+        assert_eq!(watches.len(), 1);
+        assert_eq!(watches[0].status, RankStatus::Ranked);
+
+        let deleted =
+            Database::delete_expired_mapset_watches_(&mut *tx, expires_at + Duration::days(1))
+                .await?;
+        assert!(deleted >= 1);
+
+        let watches = Database::select_user_mapset_watches_(&mut *tx, user_id).await?;
+        assert!(watches.is_empty());
+
+        // Don't leave the test entries behind
+        tx.rollback().await?;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,9 @@
+mod audit_log;
 mod bookmarks;
 mod configs;
 mod games;
+mod mapset_watches;
+mod ongoing_renders;
 mod osu;
+mod prune;
 mod tracked_streams;
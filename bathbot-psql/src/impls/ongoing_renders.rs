@@ -0,0 +1,169 @@
+use eyre::{Result, WrapErr};
+use sqlx::{Executor, Postgres};
+use twilight_model::id::Id;
+
+use crate::{Database, model::render::DbOngoingRender};
+
+impl Database {
+    pub async fn insert_ongoing_render(&self, render: &DbOngoingRender) -> Result<()> {
+        Self::insert_ongoing_render_(self, render).await
+    }
+
+    async fn insert_ongoing_render_<'c, E>(executor: E, render: &DbOngoingRender) -> Result<()>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO ongoing_renders (
+  render_id, score_id, channel_id, message_id,
+  requester_id, created_at
+)
+VALUES
+  ($1, $2, $3, $4, $5, $6) ON CONFLICT (render_id) DO NOTHING"#,
+            render.render_id as i64,
+            render.score_id.map(|score_id| score_id as i64),
+            render.channel_id.get() as i64,
+            render.message_id.get() as i64,
+            render.requester_id.get() as i64,
+            render.created_at,
+        );
+
+        query
+            .execute(executor)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_ongoing_render(&self, render_id: u32) -> Result<()> {
+        Self::delete_ongoing_render_(self, render_id).await
+    }
+
+    async fn delete_ongoing_render_<'c, E>(executor: E, render_id: u32) -> Result<()>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  ongoing_renders
+WHERE
+  render_id = $1"#,
+            render_id as i64
+        );
+
+        query
+            .execute(executor)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn select_ongoing_renders(&self) -> Result<Vec<DbOngoingRender>> {
+        Self::select_ongoing_renders_(self).await
+    }
+
+    async fn select_ongoing_renders_<'c, E>(executor: E) -> Result<Vec<DbOngoingRender>>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  render_id,
+  score_id,
+  channel_id,
+  message_id,
+  requester_id,
+  created_at
+FROM
+  ongoing_renders
+ORDER BY
+  created_at"#
+        );
+
+        let rows = query
+            .fetch_all(executor)
+            .await
+            .wrap_err("Failed to fetch all")?;
+
+        let renders = rows
+            .into_iter()
+            .map(|row| DbOngoingRender {
+                render_id: row.render_id as u32,
+                score_id: row.score_id.map(|score_id| score_id as u64),
+                channel_id: Id::new(row.channel_id as u64),
+                message_id: Id::new(row.message_id as u64),
+                requester_id: Id::new(row.requester_id as u64),
+                created_at: row.created_at,
+            })
+            .collect();
+
+        Ok(renders)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Duration, OffsetDateTime};
+
+    use super::*;
+    use crate::test_util::begin_transaction;
+
+    #[tokio::test]
+    #[ignore = "requires a database"]
+    async fn ongoing_render_lifecycle() -> Result<()> {
+        let mut tx = begin_transaction().await?;
+
+        let now = OffsetDateTime::now_utc();
+
+        let render = |render_id, score_id, created_at| DbOngoingRender {
+            render_id,
+            score_id,
+            channel_id: Id::new(1),
+            message_id: Id::new(2),
+            requester_id: Id::new(3),
+            created_at,
+        };
+
+        let old = render(u32::MAX, Some(123), now - Duration::hours(3));
+        let new = render(u32::MAX - 1, None, now);
+
+        Database::insert_ongoing_render_(&mut *tx, &new).await?;
+        Database::insert_ongoing_render_(&mut *tx, &old).await?;
+
+        // Inserting the same render again is a no-op
+        Database::insert_ongoing_render_(&mut *tx, &new).await?;
+
+        let renders = Database::select_ongoing_renders_(&mut *tx).await?;
+        let ids: Vec<_> = renders
+            .iter()
+            .map(|render| render.render_id)
+            .filter(|id| *id >= u32::MAX - 1)
+            .collect();
+
+        // Oldest first and render ids don't overflow
+        assert_eq!(ids, [u32::MAX, u32::MAX - 1]);
+
+        let stored = renders.iter().find(|render| render.render_id == u32::MAX);
+        assert_eq!(stored.and_then(|render| render.score_id), Some(123));
+
+        Database::delete_ongoing_render_(&mut *tx, u32::MAX).await?;
+
+        let renders = Database::select_ongoing_renders_(&mut *tx).await?;
+        assert!(renders.iter().all(|render| render.render_id != u32::MAX));
+        assert!(
+            renders
+                .iter()
+                .any(|render| render.render_id == u32::MAX - 1)
+        );
+
+        // Don't leave the test entries behind
+        tx.rollback().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,367 @@
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use sqlx::{Executor, Postgres};
+
+use crate::{Database, model::osu::CountryRecord, util::parse_mode};
+
+impl Database {
+    pub async fn select_country_record(
+        &self,
+        country_code: &str,
+        mode: GameMode,
+    ) -> Result<Option<CountryRecord>> {
+        Self::select_country_record_(self, country_code, mode).await
+    }
+
+    async fn select_country_record_<'c, E>(
+        executor: E,
+        country_code: &str,
+        mode: GameMode,
+    ) -> Result<Option<CountryRecord>>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  country_code,
+  gamemode,
+  record_pp,
+  score_id,
+  user_id,
+  updated_at
+FROM
+  osu_country_records
+WHERE
+  country_code = $1
+  AND gamemode = $2"#,
+            country_code,
+            mode as i16
+        );
+
+        let row_opt = query
+            .fetch_optional(executor)
+            .await
+            .wrap_err("Failed to fetch optional")?;
+
+        Ok(row_opt.map(|row| CountryRecord {
+            country_code: row.country_code.into_boxed_str(),
+            mode: parse_mode(row.gamemode),
+            pp: row.record_pp,
+            score_id: row.score_id as u64,
+            user_id: row.user_id as u32,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    /// Ids of at most `limit` users that are tracked in the given mode and
+    /// whose stored country matches, highest pp first.
+    pub async fn select_tracked_osu_user_ids_by_country(
+        &self,
+        country_code: &str,
+        mode: GameMode,
+        limit: usize,
+    ) -> Result<Vec<u32>> {
+        Self::select_tracked_osu_user_ids_by_country_(self, country_code, mode, limit).await
+    }
+
+    async fn select_tracked_osu_user_ids_by_country_<'c, E>(
+        executor: E,
+        country_code: &str,
+        mode: GameMode,
+        limit: usize,
+    ) -> Result<Vec<u32>>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  tracked.user_id
+FROM
+  tracked_osu_users AS tracked
+JOIN
+  osu_user_stats AS stats
+ON
+  tracked.user_id = stats.user_id
+LEFT JOIN
+  osu_user_mode_stats AS mode_stats
+ON
+  tracked.user_id = mode_stats.user_id
+  AND tracked.gamemode = mode_stats.gamemode
+WHERE
+  tracked.gamemode = $1
+  AND stats.country_code = $2
+GROUP BY
+  tracked.user_id,
+  mode_stats.pp
+ORDER BY
+  mode_stats.pp DESC NULLS LAST
+LIMIT
+  $3"#,
+            mode as i16,
+            country_code,
+            limit as i64
+        );
+
+        let rows = query
+            .fetch_all(executor)
+            .await
+            .wrap_err("Failed to fetch all")?;
+
+        Ok(rows.into_iter().map(|row| row.user_id as u32).collect())
+    }
+
+    /// Seed the record of a country.
+    ///
+    /// Returns `false` if the country already has a record in which case it
+    /// remains untouched.
+    pub async fn insert_country_record(
+        &self,
+        country_code: &str,
+        mode: GameMode,
+        pp: f32,
+        score_id: u64,
+        user_id: u32,
+    ) -> Result<bool> {
+        Self::insert_country_record_(self, country_code, mode, pp, score_id, user_id).await
+    }
+
+    async fn insert_country_record_<'c, E>(
+        executor: E,
+        country_code: &str,
+        mode: GameMode,
+        pp: f32,
+        score_id: u64,
+        user_id: u32,
+    ) -> Result<bool>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_country_records (
+  country_code, gamemode, record_pp, score_id, user_id
+)
+VALUES
+  ($1, $2, $3, $4, $5) ON CONFLICT (country_code, gamemode) DO NOTHING"#,
+            country_code,
+            mode as i16,
+            pp,
+            score_id as i64,
+            user_id as i32
+        );
+
+        let res = query
+            .execute(executor)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Replace the record of a country if the given pp value is higher.
+    ///
+    /// The comparison happens within the query so concurrent updates can't
+    /// overwrite a higher record. Returns whether the record was replaced;
+    /// countries without a record are never updated so they must be seeded
+    /// beforehand.
+    pub async fn update_country_record(
+        &self,
+        country_code: &str,
+        mode: GameMode,
+        pp: f32,
+        score_id: u64,
+        user_id: u32,
+    ) -> Result<bool> {
+        Self::update_country_record_(self, country_code, mode, pp, score_id, user_id).await
+    }
+
+    async fn update_country_record_<'c, E>(
+        executor: E,
+        country_code: &str,
+        mode: GameMode,
+        pp: f32,
+        score_id: u64,
+        user_id: u32,
+    ) -> Result<bool>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+UPDATE
+  osu_country_records
+SET
+  record_pp = $3,
+  score_id = $4,
+  user_id = $5,
+  updated_at = NOW()
+WHERE
+  country_code = $1
+  AND gamemode = $2
+  AND record_pp < $3
+RETURNING
+  score_id"#,
+            country_code,
+            mode as i16,
+            pp,
+            score_id as i64,
+            user_id as i32
+        );
+
+        let row_opt = query
+            .fetch_optional(executor)
+            .await
+            .wrap_err("Failed to fetch optional")?;
+
+        Ok(row_opt.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::begin_transaction;
+
+    #[tokio::test]
+    #[ignore = "requires a database"]
+    async fn seed_candidates_are_capped_by_pp() -> Result<()> {
+        let mut tx = begin_transaction().await?;
+
+        // User 900004 is from another country and 900005 is tracked in
+        // another mode
+        sqlx::query!(
+            r#"
+INSERT INTO tracked_osu_users (user_id, gamemode, channel_id)
+VALUES
+  (900001, 1, 1),
+  (900001, 1, 2),
+  (900002, 1, 1),
+  (900003, 1, 1),
+  (900004, 1, 1),
+  (900005, 0, 1)"#
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+INSERT INTO osu_user_stats (
+  user_id, country_code, join_date, comment_count,
+  kudosu_total, kudosu_available, forum_post_count, badges,
+  played_maps, followers, graveyard_mapset_count, loved_mapset_count,
+  mapping_followers, previous_usernames_count, ranked_mapset_count,
+  medals
+)
+SELECT
+  user_id,
+  country_code,
+  NOW(),
+  0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0
+FROM
+  (
+    VALUES
+      (900001, 'XX'),
+      (900002, 'XX'),
+      (900003, 'XX'),
+      (900004, 'YY'),
+      (900005, 'XX')
+  ) AS users (user_id, country_code)"#
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // User 900003 has no stats for the mode so comes last
+        sqlx::query!(
+            r#"
+INSERT INTO osu_user_mode_stats (
+  user_id, gamemode, pp, accuracy, country_rank,
+  global_rank, count_ss, count_ssh, count_s,
+  count_sh, count_a, user_level, max_combo,
+  playcount, playtime, ranked_score, replays_watched,
+  total_hits, total_score, scores_first
+)
+SELECT
+  user_id,
+  1,
+  pp,
+  0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0
+FROM
+  (
+    VALUES
+      (900001, 1000.0::FLOAT4),
+      (900002, 2000.0::FLOAT4),
+      (900004, 3000.0::FLOAT4)
+  ) AS users (user_id, pp)"#
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let mode = GameMode::Taiko;
+
+        let user_ids =
+            Database::select_tracked_osu_user_ids_by_country_(&mut *tx, "XX", mode, 10).await?;
+        assert_eq!(user_ids, [900002, 900001, 900003]);
+
+        let user_ids =
+            Database::select_tracked_osu_user_ids_by_country_(&mut *tx, "XX", mode, 2).await?;
+        assert_eq!(user_ids, [900002, 900001]);
+
+        tx.rollback().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a database"]
+    async fn seed_and_update_country_record() -> Result<()> {
+        let mut tx = begin_transaction().await?;
+
+        let country = "XX";
+        let mode = GameMode::Taiko;
+
+        // Countries must be seeded before they can be updated
+        let updated =
+            Database::update_country_record_(&mut *tx, country, mode, 700.0, 1, 10).await?;
+        assert!(!updated);
+        assert!(
+            Database::select_country_record_(&mut *tx, country, mode)
+                .await?
+                .is_none()
+        );
+
+        let seeded =
+            Database::insert_country_record_(&mut *tx, country, mode, 500.0, 2, 20).await?;
+        assert!(seeded);
+
+        // Seeding again does not overwrite the existing record
+        let seeded =
+            Database::insert_country_record_(&mut *tx, country, mode, 900.0, 3, 30).await?;
+        assert!(!seeded);
+
+        let record = Database::select_country_record_(&mut *tx, country, mode)
+            .await?
+            .unwrap();
+        assert_eq!((record.pp, record.score_id, record.user_id), (500.0, 2, 20));
+
+        // Lower or equal pp don't replace the record
+        assert!(!Database::update_country_record_(&mut *tx, country, mode, 450.0, 4, 40).await?);
+        assert!(!Database::update_country_record_(&mut *tx, country, mode, 500.0, 5, 50).await?);
+
+        assert!(Database::update_country_record_(&mut *tx, country, mode, 550.0, 6, 60).await?);
+
+        let record = Database::select_country_record_(&mut *tx, country, mode)
+            .await?
+            .unwrap();
+        assert_eq!((record.pp, record.score_id, record.user_id), (550.0, 6, 60));
+
+        // Other modes are separate
+        let other = Database::select_country_record_(&mut *tx, country, GameMode::Osu).await?;
+        assert!(other.is_none());
+
+        tx.rollback().await?;
+
+        Ok(())
+    }
+}
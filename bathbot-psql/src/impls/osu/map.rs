@@ -1,13 +1,13 @@
-use std::{collections::HashMap, hash::BuildHasher};
+use std::{cmp::Reverse, collections::HashMap, hash::BuildHasher};
 
 use eyre::{Result, WrapErr};
 use futures::StreamExt;
 use rosu_v2::prelude::BeatmapExtended;
-use sqlx::{Postgres, Transaction};
+use sqlx::{PgConnection, Postgres, Transaction};
 
 use crate::{
     Database,
-    model::osu::{DbBeatmap, DbBeatmapset, DbMapContent, MapVersion},
+    model::osu::{DbBeatmap, DbBeatmapset, DbMapContent, DbMapMetadata, MapVersion, PartialMap},
 };
 
 impl Database {
@@ -51,7 +51,8 @@ FROM
     FROM 
       osu_maps 
     WHERE 
-      map_id = $1
+      map_id = $1 
+      AND NOT partial
   ) AS map 
   JOIN (
     SELECT 
@@ -64,7 +65,9 @@ FROM
       thumbnail, 
       cover 
     FROM 
-      osu_mapsets
+      osu_mapsets 
+    WHERE 
+      NOT partial
   ) AS mapset ON map.mapset_id = mapset.mapset_id"#,
             map_id as i32
         );
@@ -149,7 +152,8 @@ FROM
     FROM 
       osu_maps 
     WHERE 
-      map_id = ANY($1)
+      map_id = ANY($1) 
+      AND NOT partial
   ) AS map 
   JOIN (
     SELECT 
@@ -162,7 +166,9 @@ FROM
       thumbnail, 
       cover 
     FROM 
-      osu_mapsets
+      osu_mapsets 
+    WHERE 
+      NOT partial
   ) AS mapset ON map.mapset_id = mapset.mapset_id 
   LEFT JOIN (
     SELECT 
@@ -240,6 +246,68 @@ FROM
             .map(|row_opt| row_opt.map(|row| row.content))
     }
 
+    /// Hex-encoded MD5 hash of the stored .osu file.
+    pub async fn select_beatmap_file_checksum(&self, map_id: u32) -> Result<Option<String>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  MD5(content) AS "checksum!" 
+FROM 
+  osu_map_file_content 
+WHERE 
+  map_id = $1"#,
+            map_id as i32
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("Failed to fetch optional")
+            .map(|row_opt| row_opt.map(|row| row.checksum))
+    }
+
+    /// Select the metadata of a map, including maps that were only stored
+    /// partially.
+    pub async fn select_map_metadata(&self, map_id: u32) -> Result<Option<DbMapMetadata>> {
+        let mut conn = self
+            .acquire()
+            .await
+            .wrap_err("Failed to acquire connection")?;
+
+        Self::select_map_metadata_(&mut conn, map_id).await
+    }
+
+    async fn select_map_metadata_(
+        conn: &mut PgConnection,
+        map_id: u32,
+    ) -> Result<Option<DbMapMetadata>> {
+        let query = sqlx::query_as!(
+            DbMapMetadata,
+            r#"
+SELECT 
+  map.map_id, 
+  map.mapset_id, 
+  mapset.artist, 
+  mapset.title, 
+  map.map_version AS version, 
+  map.gamemode, 
+  mapset.thumbnail 
+FROM 
+  osu_maps AS map 
+  JOIN osu_mapsets AS mapset ON map.mapset_id = mapset.mapset_id 
+WHERE 
+  map.map_id = $1"#,
+            map_id as i32
+        );
+
+        query
+            .fetch_optional(conn)
+            .await
+            .wrap_err("Failed to fetch optional")
+    }
+
+    /// Partially stored mapsets may lack some of their maps so they're
+    /// skipped.
     pub async fn select_map_versions_by_map_id(&self, map_id: u32) -> Result<Vec<MapVersion>> {
         let query = sqlx::query_as!(
             MapVersion,
@@ -263,13 +331,23 @@ FROM
       osu_maps 
     WHERE 
       map_id = $1
-  ) AS mapset ON maps.mapset_id = mapset.mapset_id"#,
+  ) AS mapset ON maps.mapset_id = mapset.mapset_id 
+  JOIN (
+    SELECT 
+      mapset_id 
+    FROM 
+      osu_mapsets 
+    WHERE 
+      NOT partial
+  ) AS full_mapset ON maps.mapset_id = full_mapset.mapset_id"#,
             map_id as i32
         );
 
         query.fetch_all(self).await.wrap_err("failed to fetch all")
     }
 
+    /// Partially stored mapsets may lack some of their maps so they're
+    /// skipped.
     pub async fn select_map_versions_by_mapset_id(
         &self,
         mapset_id: u32,
@@ -284,6 +362,14 @@ FROM
   osu_maps 
 WHERE 
   mapset_id = $1 
+  AND mapset_id IN (
+    SELECT 
+      mapset_id 
+    FROM 
+      osu_mapsets 
+    WHERE 
+      NOT partial
+  ) 
 ORDER BY 
   version, 
   last_update DESC"#,
@@ -361,12 +447,12 @@ INSERT INTO osu_maps (
   map_id, mapset_id, user_id, checksum, 
   map_version, seconds_total, seconds_drain, 
   count_circles, count_sliders, count_spinners, 
-  hp, cs, od, ar, bpm, gamemode
+  hp, cs, od, ar, bpm, gamemode, last_updated
 ) 
 VALUES 
   (
     $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, 
-    $11, $12, $13, $14, $15, $16
+    $11, $12, $13, $14, $15, $16, $17
   ) ON CONFLICT (map_id) DO NOTHING"#,
             map.map_id as i32,
             map.mapset_id as i32,
@@ -384,6 +470,7 @@ VALUES
             map.ar,
             map.bpm,
             map.mode as i16,
+            map.last_updated,
         );
 
         query
@@ -409,4 +496,388 @@ WHERE
 
         Ok(())
     }
+
+    /// Insert the metadata of maps and their mapsets or update rows whose
+    /// `last_updated` is older.
+    ///
+    /// New rows are flagged as partial while full rows stay full.
+    pub async fn upsert_partial_maps(&self, maps: &[PartialMap<'_>]) -> Result<()> {
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+
+        for chunk in maps.chunks(100) {
+            Self::upsert_partial_maps_(&mut tx, chunk).await?;
+        }
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(())
+    }
+
+    async fn upsert_partial_maps_(conn: &mut PgConnection, maps: &[PartialMap<'_>]) -> Result<()> {
+        // A row may only be affected once per statement so duplicates are
+        // removed, keeping the most recent entry.
+        let mut maps: Vec<_> = maps.iter().collect();
+        maps.sort_unstable_by_key(|map| (map.map_id, Reverse(map.last_updated)));
+        maps.dedup_by_key(|map| map.map_id);
+
+        let mut mapsets = maps.clone();
+        mapsets.sort_unstable_by_key(|map| (map.mapset_id, Reverse(map.last_updated)));
+        mapsets.dedup_by_key(|map| map.mapset_id);
+
+        let len = mapsets.len();
+        let mut vec_mapset_id = Vec::with_capacity(len);
+        let mut vec_creator_id = Vec::with_capacity(len);
+        let mut vec_artist = Vec::with_capacity(len);
+        let mut vec_title = Vec::with_capacity(len);
+        let mut vec_creator = Vec::with_capacity(len);
+        let mut vec_source = Vec::with_capacity(len);
+        let mut vec_video = Vec::with_capacity(len);
+        let mut vec_mapset_bpm = Vec::with_capacity(len);
+        let mut vec_status = Vec::with_capacity(len);
+        let mut vec_thumbnail = Vec::with_capacity(len);
+        let mut vec_cover = Vec::with_capacity(len);
+        let mut vec_mapset_last_updated = Vec::with_capacity(len);
+
+        for mapset in mapsets {
+            vec_mapset_id.push(mapset.mapset_id);
+            vec_creator_id.push(mapset.creator_id);
+            vec_artist.push(mapset.artist);
+            vec_title.push(mapset.title);
+            vec_creator.push(mapset.creator);
+            vec_source.push(mapset.source);
+            vec_video.push(mapset.video);
+            vec_mapset_bpm.push(mapset.bpm);
+            vec_status.push(mapset.rank_status);
+            vec_thumbnail.push(mapset.thumbnail);
+            vec_cover.push(mapset.cover);
+            vec_mapset_last_updated.push(mapset.last_updated);
+        }
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_mapsets (
+  mapset_id, user_id, artist, title, creator, 
+  source, video, bpm, rank_status, thumbnail, 
+  cover, last_updated, partial
+) 
+SELECT 
+  *, 
+  TRUE 
+FROM 
+  UNNEST(
+    $1::INT4[], $2::INT4[], $3::VARCHAR[], $4::VARCHAR[], 
+    $5::VARCHAR[], $6::VARCHAR[], $7::BOOL[], $8::FLOAT4[], 
+    $9::INT2[], $10::VARCHAR[], $11::VARCHAR[], $12::TIMESTAMPTZ[]
+  ) ON CONFLICT (mapset_id) DO 
+UPDATE 
+SET 
+  title = excluded.title, 
+  rank_status = excluded.rank_status, 
+  last_updated = excluded.last_updated, 
+  last_update = NOW() 
+WHERE 
+  osu_mapsets.last_updated IS NULL 
+  OR osu_mapsets.last_updated < excluded.last_updated"#,
+            &vec_mapset_id,
+            &vec_creator_id,
+            &vec_artist as _,
+            &vec_title as _,
+            &vec_creator as _,
+            &vec_source as _,
+            &vec_video,
+            &vec_mapset_bpm,
+            &vec_status,
+            &vec_thumbnail as _,
+            &vec_cover as _,
+            &vec_mapset_last_updated,
+        );
+
+        query
+            .execute(&mut *conn)
+            .await
+            .wrap_err("Failed to upsert mapsets")?;
+
+        let len = maps.len();
+        let mut vec_map_id = Vec::with_capacity(len);
+        let mut vec_mapset_id = Vec::with_capacity(len);
+        let mut vec_mapper_id = Vec::with_capacity(len);
+        let mut vec_checksum = Vec::with_capacity(len);
+        let mut vec_version = Vec::with_capacity(len);
+        let mut vec_seconds_total = Vec::with_capacity(len);
+        let mut vec_seconds_drain = Vec::with_capacity(len);
+        let mut vec_circles = Vec::with_capacity(len);
+        let mut vec_sliders = Vec::with_capacity(len);
+        let mut vec_spinners = Vec::with_capacity(len);
+        let mut vec_hp = Vec::with_capacity(len);
+        let mut vec_cs = Vec::with_capacity(len);
+        let mut vec_od = Vec::with_capacity(len);
+        let mut vec_ar = Vec::with_capacity(len);
+        let mut vec_bpm = Vec::with_capacity(len);
+        let mut vec_mode = Vec::with_capacity(len);
+        let mut vec_last_updated = Vec::with_capacity(len);
+
+        for map in maps {
+            vec_map_id.push(map.map_id);
+            vec_mapset_id.push(map.mapset_id);
+            vec_mapper_id.push(map.mapper_id);
+            vec_checksum.push(map.checksum);
+            vec_version.push(map.map_version);
+            vec_seconds_total.push(map.seconds_total);
+            vec_seconds_drain.push(map.seconds_drain);
+            vec_circles.push(map.count_circles);
+            vec_sliders.push(map.count_sliders);
+            vec_spinners.push(map.count_spinners);
+            vec_hp.push(map.hp);
+            vec_cs.push(map.cs);
+            vec_od.push(map.od);
+            vec_ar.push(map.ar);
+            vec_bpm.push(map.bpm);
+            vec_mode.push(map.gamemode);
+            vec_last_updated.push(map.last_updated);
+        }
+
+        // The checksum is not updated so that outdated map file content is
+        // still detected when the map is fetched fully.
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_maps (
+  map_id, mapset_id, user_id, checksum, 
+  map_version, seconds_total, seconds_drain, 
+  count_circles, count_sliders, count_spinners, 
+  hp, cs, od, ar, bpm, gamemode, last_updated, 
+  partial
+) 
+SELECT 
+  *, 
+  TRUE 
+FROM 
+  UNNEST(
+    $1::INT4[], $2::INT4[], $3::INT4[], $4::VARCHAR[], 
+    $5::VARCHAR[], $6::INT4[], $7::INT4[], $8::INT4[], 
+    $9::INT4[], $10::INT4[], $11::FLOAT4[], $12::FLOAT4[], 
+    $13::FLOAT4[], $14::FLOAT4[], $15::FLOAT4[], $16::INT2[], 
+    $17::TIMESTAMPTZ[]
+  ) ON CONFLICT (map_id) DO 
+UPDATE 
+SET 
+  map_version = excluded.map_version, 
+  last_updated = excluded.last_updated, 
+  last_update = NOW() 
+WHERE 
+  osu_maps.last_updated IS NULL 
+  OR osu_maps.last_updated < excluded.last_updated"#,
+            &vec_map_id,
+            &vec_mapset_id,
+            &vec_mapper_id,
+            &vec_checksum as _,
+            &vec_version as _,
+            &vec_seconds_total,
+            &vec_seconds_drain,
+            &vec_circles,
+            &vec_sliders,
+            &vec_spinners,
+            &vec_hp,
+            &vec_cs,
+            &vec_od,
+            &vec_ar,
+            &vec_bpm,
+            &vec_mode,
+            &vec_last_updated,
+        );
+
+        query
+            .execute(&mut *conn)
+            .await
+            .wrap_err("Failed to upsert maps")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Duration, OffsetDateTime};
+
+    use super::*;
+    use crate::test_util::begin_transaction;
+
+    const MAP_ID: i32 = -1;
+    const MAPSET_ID: i32 = -1;
+
+    fn partial_map(
+        version: &'static str,
+        title: &'static str,
+        last_updated: OffsetDateTime,
+    ) -> PartialMap<'static> {
+        PartialMap {
+            map_id: MAP_ID,
+            mapset_id: MAPSET_ID,
+            mapper_id: 2,
+            checksum: "0123456789abcdef0123456789abcdef",
+            map_version: version,
+            seconds_total: 120,
+            seconds_drain: 110,
+            count_circles: 300,
+            count_sliders: 200,
+            count_spinners: 1,
+            hp: 5.0,
+            cs: 4.0,
+            od: 8.0,
+            ar: 9.0,
+            bpm: 180.0,
+            gamemode: 0,
+            last_updated,
+            creator_id: 2,
+            artist: "artist",
+            title,
+            creator: "creator",
+            source: "",
+            video: false,
+            rank_status: 1,
+            thumbnail: "",
+            cover: "",
+        }
+    }
+
+    async fn select_stored(conn: &mut PgConnection) -> Result<(String, String, bool, bool)> {
+        let row = sqlx::query!(
+            r#"
+SELECT 
+  map.map_version, 
+  map.partial AS map_partial, 
+  mapset.title, 
+  mapset.partial AS mapset_partial 
+FROM 
+  osu_maps AS map 
+  JOIN osu_mapsets AS mapset ON map.mapset_id = mapset.mapset_id 
+WHERE 
+  map.map_id = $1"#,
+            MAP_ID
+        )
+        .fetch_one(conn)
+        .await?;
+
+        Ok((
+            row.map_version,
+            row.title,
+            row.map_partial,
+            row.mapset_partial,
+        ))
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a database"]
+    async fn partial_rows_only_update_when_newer() -> Result<()> {
+        let mut tx = begin_transaction().await?;
+
+        let now = OffsetDateTime::now_utc();
+
+        // Duplicates within a batch keep the most recent entry
+        let maps = [
+            partial_map("Insane", "Title", now),
+            partial_map("Hard", "Old title", now - Duration::days(2)),
+        ];
+        Database::upsert_partial_maps_(&mut tx, &maps).await?;
+
+        let stored = select_stored(&mut tx).await?;
+        assert_eq!(
+            stored,
+            ("Insane".to_owned(), "Title".to_owned(), true, true)
+        );
+
+        // Older metadata is ignored
+        let older = [partial_map("Hard", "Old title", now - Duration::days(1))];
+        Database::upsert_partial_maps_(&mut tx, &older).await?;
+
+        let stored = select_stored(&mut tx).await?;
+        assert_eq!(
+            stored,
+            ("Insane".to_owned(), "Title".to_owned(), true, true)
+        );
+
+        // Newer metadata is applied
+        let newer = [partial_map("Extra", "New title", now + Duration::days(1))];
+        Database::upsert_partial_maps_(&mut tx, &newer).await?;
+
+        let stored = select_stored(&mut tx).await?;
+        assert_eq!(
+            stored,
+            ("Extra".to_owned(), "New title".to_owned(), true, true)
+        );
+
+        tx.rollback().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a database"]
+    async fn full_rows_stay_full() -> Result<()> {
+        let mut tx = begin_transaction().await?;
+
+        let now = OffsetDateTime::now_utc();
+
+        Database::upsert_partial_maps_(&mut tx, &[partial_map("Insane", "Title", now)]).await?;
+
+        // Pretend the map has been fetched fully in the meantime
+        sqlx::query!(
+            "UPDATE osu_maps SET partial = FALSE WHERE map_id = $1",
+            MAP_ID
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE osu_mapsets SET partial = FALSE WHERE mapset_id = $1",
+            MAPSET_ID
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let newer = [partial_map("Extra", "New title", now + Duration::days(1))];
+        Database::upsert_partial_maps_(&mut tx, &newer).await?;
+
+        // Metadata is updated but the rows are not marked as partial
+        let stored = select_stored(&mut tx).await?;
+        assert_eq!(
+            stored,
+            ("Extra".to_owned(), "New title".to_owned(), false, false)
+        );
+
+        tx.rollback().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a database"]
+    async fn metadata_of_partial_maps() -> Result<()> {
+        let mut tx = begin_transaction().await?;
+
+        let now = OffsetDateTime::now_utc();
+
+        let metadata = Database::select_map_metadata_(&mut tx, MAP_ID as u32).await?;
+        assert_eq!(metadata, None);
+
+        let maps = [partial_map("Insane", "Title", now)];
+        Database::upsert_partial_maps_(&mut tx, &maps).await?;
+
+        let expected = DbMapMetadata {
+            map_id: MAP_ID,
+            mapset_id: MAPSET_ID,
+            artist: "artist".to_owned(),
+            title: "Title".to_owned(),
+            version: "Insane".to_owned(),
+            gamemode: 0,
+            thumbnail: String::new(),
+        };
+
+        // Partial rows are readable as metadata but not as full maps
+        let metadata = Database::select_map_metadata_(&mut tx, MAP_ID as u32).await?;
+        assert_eq!(metadata, Some(expected));
+
+        tx.rollback().await?;
+
+        Ok(())
+    }
 }
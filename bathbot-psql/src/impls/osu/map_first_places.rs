@@ -0,0 +1,297 @@
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use sqlx::{Executor, Postgres};
+use time::OffsetDateTime;
+
+use crate::{Database, model::osu::MapFirstPlace, util::parse_mode};
+
+/// Amount of first places that are kept per map and mode.
+const FIRST_PLACE_RETENTION: i64 = 20;
+
+impl Database {
+    /// First places of a map that the bot observed, oldest first.
+    pub async fn select_map_first_places(
+        &self,
+        map_id: u32,
+        mode: GameMode,
+    ) -> Result<Vec<MapFirstPlace>> {
+        Self::select_map_first_places_(self, map_id, mode).await
+    }
+
+    async fn select_map_first_places_<'c, E>(
+        executor: E,
+        map_id: u32,
+        mode: GameMode,
+    ) -> Result<Vec<MapFirstPlace>>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  map_id,
+  gamemode,
+  score_id,
+  user_id,
+  pp,
+  observed_at
+FROM
+  osu_map_first_places
+WHERE
+  map_id = $1
+  AND gamemode = $2
+ORDER BY
+  observed_at ASC"#,
+            map_id as i32,
+            mode as i16
+        );
+
+        let rows = query
+            .fetch_all(executor)
+            .await
+            .wrap_err("Failed to fetch all")?;
+
+        let first_places = rows
+            .into_iter()
+            .map(|row| MapFirstPlace {
+                map_id: row.map_id as u32,
+                mode: parse_mode(row.gamemode),
+                score_id: row.score_id as u64,
+                user_id: row.user_id as u32,
+                pp: row.pp,
+                observed_at: row.observed_at,
+            })
+            .collect();
+
+        Ok(first_places)
+    }
+
+    /// Store the current first place of a map.
+    ///
+    /// Nothing is stored if the score is already known or if the latest
+    /// stored first place belongs to the same user. Only the most recent
+    /// first places of the map are kept.
+    ///
+    /// Returns whether the score was stored.
+    pub async fn insert_map_first_place(
+        &self,
+        map_id: u32,
+        mode: GameMode,
+        score_id: u64,
+        user_id: u32,
+        pp: Option<f32>,
+    ) -> Result<bool> {
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+        let now = OffsetDateTime::now_utc();
+
+        let inserted =
+            Self::insert_map_first_place_(&mut *tx, map_id, mode, score_id, user_id, pp, now)
+                .await?;
+
+        if inserted {
+            Self::prune_map_first_places_(&mut *tx, map_id, mode, FIRST_PLACE_RETENTION).await?;
+        }
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(inserted)
+    }
+
+    async fn insert_map_first_place_<'c, E>(
+        executor: E,
+        map_id: u32,
+        mode: GameMode,
+        score_id: u64,
+        user_id: u32,
+        pp: Option<f32>,
+        observed_at: OffsetDateTime,
+    ) -> Result<bool>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_map_first_places (
+  map_id, gamemode, score_id, user_id, pp, observed_at
+)
+SELECT
+  $1::INT4, $2::INT2, $3::INT8, $4::INT4, $5::FLOAT4, $6::TIMESTAMPTZ
+WHERE
+  NOT EXISTS (
+    SELECT
+      1
+    FROM
+      (
+        SELECT
+          user_id
+        FROM
+          osu_map_first_places
+        WHERE
+          map_id = $1
+          AND gamemode = $2
+        ORDER BY
+          observed_at DESC
+        LIMIT
+          1
+      ) AS latest
+    WHERE
+      latest.user_id = $4
+  ) ON CONFLICT (map_id, score_id) DO NOTHING"#,
+            map_id as i32,
+            mode as i16,
+            score_id as i64,
+            user_id as i32,
+            pp,
+            observed_at
+        );
+
+        let res = query
+            .execute(executor)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn prune_map_first_places_<'c, E>(
+        executor: E,
+        map_id: u32,
+        mode: GameMode,
+        keep: i64,
+    ) -> Result<()>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  osu_map_first_places
+WHERE
+  map_id = $1
+  AND gamemode = $2
+  AND score_id NOT IN (
+    SELECT
+      score_id
+    FROM
+      osu_map_first_places
+    WHERE
+      map_id = $1
+      AND gamemode = $2
+    ORDER BY
+      observed_at DESC
+    LIMIT
+      $3
+  )"#,
+            map_id as i32,
+            mode as i16,
+            keep
+        );
+
+        query
+            .execute(executor)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    use super::*;
+    use crate::test_util::begin_transaction;
+
+    #[tokio::test]
+    #[ignore = "requires a database"]
+    async fn only_store_first_place_changes() -> Result<()> {
+        let mut tx = begin_transaction().await?;
+
+        let map_id = 0;
+        let mode = GameMode::Osu;
+        let start = OffsetDateTime::now_utc();
+        let at = |secs: i64| start + Duration::seconds(secs);
+
+        let inserted =
+            Database::insert_map_first_place_(&mut *tx, map_id, mode, 1, 10, Some(500.0), at(0))
+                .await?;
+        assert!(inserted);
+
+        // The same score is only stored once
+        let inserted =
+            Database::insert_map_first_place_(&mut *tx, map_id, mode, 1, 10, Some(500.0), at(1))
+                .await?;
+        assert!(!inserted);
+
+        // A new score of the same user doesn't change the first place holder
+        let inserted =
+            Database::insert_map_first_place_(&mut *tx, map_id, mode, 2, 10, Some(510.0), at(2))
+                .await?;
+        assert!(!inserted);
+
+        let inserted =
+            Database::insert_map_first_place_(&mut *tx, map_id, mode, 3, 20, None, at(3)).await?;
+        assert!(inserted);
+
+        // The previous holder may take the first place back
+        let inserted =
+            Database::insert_map_first_place_(&mut *tx, map_id, mode, 4, 10, Some(520.0), at(4))
+                .await?;
+        assert!(inserted);
+
+        let first_places = Database::select_map_first_places_(&mut *tx, map_id, mode).await?;
+        let score_ids: Vec<_> = first_places.iter().map(|first| first.score_id).collect();
+        assert_eq!(score_ids, [1, 3, 4]);
+
+        // Other modes are separate
+        let other = Database::select_map_first_places_(&mut *tx, map_id, GameMode::Taiko).await?;
+        assert!(other.is_empty());
+
+        tx.rollback().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a database"]
+    async fn prune_old_first_places() -> Result<()> {
+        let mut tx = begin_transaction().await?;
+
+        let map_id = 0;
+        let mode = GameMode::Mania;
+        let start = OffsetDateTime::now_utc();
+
+        for i in 0..25 {
+            let observed_at = start + Duration::seconds(i);
+            let user_id = (i % 2) as u32 + 1;
+
+            let inserted = Database::insert_map_first_place_(
+                &mut *tx,
+                map_id,
+                mode,
+                i as u64,
+                user_id,
+                None,
+                observed_at,
+            )
+            .await?;
+            assert!(inserted);
+
+            Database::prune_map_first_places_(&mut *tx, map_id, mode, FIRST_PLACE_RETENTION)
+                .await?;
+        }
+
+        let first_places = Database::select_map_first_places_(&mut *tx, map_id, mode).await?;
+        assert_eq!(first_places.len(), FIRST_PLACE_RETENTION as usize);
+
+        // Only the oldest entries were removed
+        let score_ids: Vec<_> = first_places.iter().map(|first| first.score_id).collect();
+        let expected: Vec<_> = (5..25).collect();
+        assert_eq!(score_ids, expected);
+
+        tx.rollback().await?;
+
+        Ok(())
+    }
+}
@@ -27,7 +27,8 @@ SELECT
 FROM 
   osu_mapsets 
 WHERE 
-  mapset_id = $1"#,
+  mapset_id = $1 
+  AND NOT partial"#,
             mapset_id as i32
         );
 
@@ -66,12 +67,12 @@ INSERT INTO osu_mapsets (
   mapset_id, user_id, artist, title, 
   creator, source, tags, video, storyboard, 
   bpm, rank_status, ranked_date, genre_id, 
-  language_id, thumbnail, cover
+  language_id, thumbnail, cover, last_updated
 ) 
 VALUES 
   (
     $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, 
-    $11, $12, $13, $14, $15, $16
+    $11, $12, $13, $14, $15, $16, $17
   ) ON CONFLICT (mapset_id) DO 
 UPDATE 
 SET 
@@ -90,6 +91,8 @@ SET
   language_id = $14, 
   thumbnail = $15, 
   cover = $16, 
+  last_updated = $17, 
+  partial = FALSE, 
   last_update = NOW()"#,
             mapset.mapset_id as i32,
             mapset.creator_id as i32,
@@ -107,6 +110,7 @@ SET
             mapset.language.map(|language| language as i16),
             mapset.covers.list,
             mapset.covers.cover,
+            mapset.last_updated,
         );
 
         query
@@ -1,4 +1,6 @@
+pub mod country_records;
 pub mod map;
+pub mod map_first_places;
 pub mod mapset;
 pub mod name;
 pub mod rank_pp;
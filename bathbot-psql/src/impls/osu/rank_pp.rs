@@ -2,6 +2,7 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use eyre::{Result, WrapErr};
 use rosu_v2::prelude::GameMode;
+use sqlx::{Executor, Postgres};
 use time::OffsetDateTime;
 
 use crate::database::Database;
@@ -118,6 +119,90 @@ FROM
         }
     }
 
+    /// Deepest global rank for which there is recent pp data.
+    pub async fn select_max_rank_approx(&self, mode: GameMode) -> Result<Option<u32>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  MAX(global_rank) AS max_rank 
+FROM 
+  osu_user_mode_stats 
+WHERE 
+  gamemode = $1 
+  AND global_rank > 0 
+  AND NOW() - last_update < interval '2 days'"#,
+            mode as i16,
+        );
+
+        let row = query
+            .fetch_one(self)
+            .await
+            .wrap_err("Failed to fetch one")?;
+
+        Ok(row.max_rank.map(|rank| rank as u32))
+    }
+
+    /// Average rank and pp of recently updated users for each rank bucket.
+    ///
+    /// The thresholds must be sorted. Ranks below the first threshold are
+    /// ignored and the last bucket contains all ranks from the last threshold
+    /// onwards. Buckets without users are skipped and the result is sorted by
+    /// rank.
+    pub async fn select_pp_approx_by_rank_buckets(
+        &self,
+        thresholds: &[u32],
+        mode: GameMode,
+    ) -> Result<Vec<(u32, f32)>> {
+        Self::select_pp_approx_by_rank_buckets_(self, thresholds, mode).await
+    }
+
+    async fn select_pp_approx_by_rank_buckets_<'c, E>(
+        executor: E,
+        thresholds: &[u32],
+        mode: GameMode,
+    ) -> Result<Vec<(u32, f32)>>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let Some(&min_rank) = thresholds.first() else {
+            return Ok(Vec::new());
+        };
+
+        let thresholds: Vec<_> = thresholds.iter().map(|&rank| rank as i32).collect();
+
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  AVG(global_rank) :: FLOAT8 AS "rank!", 
+  AVG(pp) :: FLOAT4 AS "pp!" 
+FROM 
+  osu_user_mode_stats 
+WHERE 
+  gamemode = $1 
+  AND global_rank >= $2 
+  AND NOW() - last_update < interval '2 days' 
+GROUP BY 
+  width_bucket(global_rank, $3 :: INT4[]) 
+ORDER BY 
+  1"#,
+            mode as i16,
+            min_rank as i32,
+            &thresholds,
+        );
+
+        let rows = query
+            .fetch_all(executor)
+            .await
+            .wrap_err("Failed to fetch all")?;
+
+        let buckets = rows
+            .into_iter()
+            .map(|row| (row.rank.round() as u32, row.pp))
+            .collect();
+
+        Ok(buckets)
+    }
+
     pub async fn select_pp_approx_by_rank(&self, rank: u32, mode: GameMode) -> Result<f32> {
         let query = sqlx::query_as!(
             DbEntry,
@@ -336,3 +421,57 @@ impl Display for EntryFormatter<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::begin_transaction;
+
+    #[tokio::test]
+    #[ignore = "requires a database"]
+    async fn rank_buckets_are_averaged() -> Result<()> {
+        let mut tx = begin_transaction().await?;
+
+        // Rank 5000 is below the first threshold and the outdated rank 13000
+        // is ignored
+        sqlx::query!(
+            r#"
+INSERT INTO osu_user_mode_stats (
+  user_id, gamemode, pp, accuracy, country_rank, global_rank,
+  count_ss, count_ssh, count_s, count_sh, count_a, user_level, max_combo,
+  playcount, playtime, ranked_score, replays_watched, total_hits,
+  total_score, scores_first, last_update
+)
+SELECT
+  900000 + global_rank, 2, pp, 0, 0, global_rank,
+  0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, last_update
+FROM
+  (
+    VALUES
+      (5000, 9000.0, NOW()),
+      (10000, 6000.0, NOW()),
+      (13000, 9000.0, NOW() - interval '3 days'),
+      (14000, 5000.0, NOW()),
+      (16000, 4500.0, NOW()),
+      (20000, 3500.0, NOW()),
+      (30000, 2000.0, NOW())
+  ) AS entries (global_rank, pp, last_update)"#
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let buckets = Database::select_pp_approx_by_rank_buckets_(
+            &mut *tx,
+            &[10_000, 15_000, 22_000, 30_000],
+            GameMode::Catch,
+        )
+        .await?;
+
+        assert_eq!(
+            buckets,
+            [(12_000, 5500.0), (18_000, 4000.0), (30_000, 2000.0)]
+        );
+
+        Ok(())
+    }
+}
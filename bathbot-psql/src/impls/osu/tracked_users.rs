@@ -17,7 +17,10 @@ WITH pps AS (
     user_id,
     gamemode,
     pp as last_pp,
-    last_updated
+    last_updated,
+    playcount_milestone,
+    ranked_score_milestone,
+    pp_milestone
   FROM
     osu_users_100th_pp
   AS
@@ -50,7 +53,8 @@ SELECT
   min_pp,
   max_pp,
   min_combo_percent,
-  max_combo_percent
+  max_combo_percent,
+  compact
 FROM
   tracked_osu_users
 WHERE
@@ -70,10 +74,10 @@ WHERE
             r#"
 INSERT INTO tracked_osu_users (
   user_id, gamemode, channel_id, min_index, max_index,
-  min_pp, max_pp, min_combo_percent, max_combo_percent
+  min_pp, max_pp, min_combo_percent, max_combo_percent, compact
 )
 VALUES
-  ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
 ON CONFLICT
   (user_id, gamemode, channel_id)
 DO
@@ -84,7 +88,8 @@ SET
     min_pp = $6,
     max_pp = $7,
     min_combo_percent = $8,
-    max_combo_percent = $9"#,
+    max_combo_percent = $9,
+    compact = $10"#,
             user.user_id,
             user.gamemode,
             channel_id as i64,
@@ -94,6 +99,7 @@ SET
             user.max_pp,
             user.min_combo_percent,
             user.max_combo_percent,
+            user.compact,
         );
 
         query
@@ -138,6 +144,40 @@ SET
         Ok(())
     }
 
+    pub async fn update_tracked_milestones(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        playcount: Option<u64>,
+        ranked_score: Option<u64>,
+        pp: Option<u64>,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE
+  osu_users_100th_pp
+SET
+  playcount_milestone = $3,
+  ranked_score_milestone = $4,
+  pp_milestone = $5
+WHERE
+  user_id = $1
+  AND gamemode = $2"#,
+            user_id as i32,
+            mode as i16,
+            playcount.map(|n| n as i64),
+            ranked_score.map(|n| n as i64),
+            pp.map(|n| n as i64),
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
     pub async fn delete_tracked_osu_user(
         &self,
         user_id: u32,
@@ -8,7 +8,7 @@ use time::OffsetDateTime;
 
 use crate::{
     Database,
-    model::osu::{DbUserStatsEntry, OsuUserStatsColumnName},
+    model::osu::{DbUserStatsEntry, OsuUserStatsColumnName, StaleOsuUser},
 };
 
 fn convert_entries<V>(entries: Vec<DbUserStatsEntry<V>>) -> Vec<UserStatsEntry<V>> {
@@ -684,6 +684,55 @@ WHERE
         Ok(names)
     }
 
+    /// Select linked osu! users whose stored stats were updated least
+    /// recently.
+    ///
+    /// Users without stored stats are not considered.
+    pub async fn select_stalest_linked_osu_users(&self, limit: usize) -> Result<Vec<StaleOsuUser>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  configs.osu_id AS "osu_id!", 
+  configs.gamemode, 
+  stats.last_update 
+FROM 
+  (
+    SELECT 
+      osu_id, 
+      MIN(gamemode) AS gamemode 
+    FROM 
+      user_configs 
+    WHERE 
+      osu_id IS NOT NULL 
+    GROUP BY 
+      osu_id
+  ) AS configs 
+  JOIN osu_user_stats AS stats ON configs.osu_id = stats.user_id 
+ORDER BY 
+  stats.last_update ASC 
+LIMIT 
+  $1"#,
+            limit as i64
+        );
+
+        let mut rows = query.fetch(self);
+        let mut users = Vec::with_capacity(limit);
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("failed to fetch next")?;
+
+            users.push(StaleOsuUser {
+                user_id: row.osu_id as u32,
+                mode: row
+                    .gamemode
+                    .map_or(GameMode::Osu, |mode| GameMode::from(mode as u8)),
+                last_update: row.last_update,
+            });
+        }
+
+        Ok(users)
+    }
+
     pub async fn upsert_osu_user(&self, user: &UserExtended, mode: GameMode) -> Result<()> {
         let mut tx = self.begin().await.wrap_err("failed to begin transaction")?;
 
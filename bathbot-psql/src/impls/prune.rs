@@ -0,0 +1,448 @@
+use eyre::{Result, WrapErr};
+use sqlx::{Executor, PgConnection, Postgres};
+use time::OffsetDateTime;
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, UserMarker},
+};
+
+use crate::{
+    Database,
+    model::prune::{InactiveUserConfig, PruneReport},
+};
+
+impl Database {
+    /// Set the user's `last_seen_at` to `now` unless the stored value is less
+    /// than a day old.
+    pub async fn update_user_last_seen(
+        &self,
+        user_id: Id<UserMarker>,
+        now: OffsetDateTime,
+    ) -> Result<()> {
+        Self::update_user_last_seen_(self, user_id, now).await
+    }
+
+    async fn update_user_last_seen_<'c, E>(
+        executor: E,
+        user_id: Id<UserMarker>,
+        now: OffsetDateTime,
+    ) -> Result<()>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+UPDATE
+  user_configs
+SET
+  last_seen_at = $2
+WHERE
+  discord_id = $1
+  AND last_seen_at < $2::TIMESTAMPTZ - INTERVAL '1 day'"#,
+            user_id.get() as i64,
+            now,
+        );
+
+        query
+            .execute(executor)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// User configs that were not seen since `cutoff`.
+    pub async fn select_inactive_user_configs(
+        &self,
+        cutoff: OffsetDateTime,
+    ) -> Result<Vec<InactiveUserConfig>> {
+        Self::select_inactive_user_configs_(self, cutoff).await
+    }
+
+    async fn select_inactive_user_configs_<'c, E>(
+        executor: E,
+        cutoff: OffsetDateTime,
+    ) -> Result<Vec<InactiveUserConfig>>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  discord_id,
+  osu_id
+FROM
+  user_configs
+WHERE
+  last_seen_at < $1
+ORDER BY
+  discord_id"#,
+            cutoff
+        );
+
+        let rows = query
+            .fetch_all(executor)
+            .await
+            .wrap_err("Failed to fetch all")?;
+
+        let configs = rows
+            .into_iter()
+            .map(|row| InactiveUserConfig {
+                discord_id: Id::new(row.discord_id as u64),
+                osu_id: row.osu_id.map(|osu_id| osu_id as u32),
+            })
+            .collect();
+
+        Ok(configs)
+    }
+
+    /// All channels in which osu! users or twitch streams are tracked.
+    pub async fn select_tracked_channels(&self) -> Result<Vec<Id<ChannelMarker>>> {
+        Self::select_tracked_channels_(self).await
+    }
+
+    async fn select_tracked_channels_<'c, E>(executor: E) -> Result<Vec<Id<ChannelMarker>>>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  channel_id AS "channel_id!"
+FROM
+  tracked_osu_users
+UNION
+SELECT
+  channel_id
+FROM
+  tracked_twitch_streams
+ORDER BY
+  1"#
+        );
+
+        let rows = query
+            .fetch_all(executor)
+            .await
+            .wrap_err("Failed to fetch all")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Id::new(row.channel_id as u64))
+            .collect())
+    }
+
+    /// Store the candidates and return the report's id.
+    pub async fn insert_prune_report(
+        &self,
+        discord_ids: &[Id<UserMarker>],
+        channel_ids: &[Id<ChannelMarker>],
+    ) -> Result<i32> {
+        Self::insert_prune_report_(self, discord_ids, channel_ids).await
+    }
+
+    async fn insert_prune_report_<'c, E>(
+        executor: E,
+        discord_ids: &[Id<UserMarker>],
+        channel_ids: &[Id<ChannelMarker>],
+    ) -> Result<i32>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let discord_ids: Vec<_> = discord_ids.iter().map(|id| id.get() as i64).collect();
+        let channel_ids: Vec<_> = channel_ids.iter().map(|id| id.get() as i64).collect();
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO prune_reports (discord_ids, channel_ids)
+VALUES
+  ($1, $2) RETURNING report_id"#,
+            &discord_ids,
+            &channel_ids,
+        );
+
+        let row = query
+            .fetch_one(executor)
+            .await
+            .wrap_err("Failed to fetch one")?;
+
+        Ok(row.report_id)
+    }
+
+    /// The report unless there is no such report or it was applied already.
+    pub async fn select_pending_prune_report(&self, report_id: i32) -> Result<Option<PruneReport>> {
+        Self::select_pending_prune_report_(self, report_id).await
+    }
+
+    /// Locks the report's row until the end of the transaction.
+    async fn select_pending_prune_report_<'c, E>(
+        executor: E,
+        report_id: i32,
+    ) -> Result<Option<PruneReport>>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  discord_ids,
+  channel_ids,
+  created_at
+FROM
+  prune_reports
+WHERE
+  report_id = $1
+  AND applied_at IS NULL
+FOR UPDATE"#,
+            report_id
+        );
+
+        let row_opt = query
+            .fetch_optional(executor)
+            .await
+            .wrap_err("Failed to fetch optional")?;
+
+        let report = row_opt.map(|row| PruneReport {
+            report_id,
+            discord_ids: row
+                .discord_ids
+                .into_iter()
+                .map(|id| Id::new(id as u64))
+                .collect(),
+            channel_ids: row
+                .channel_ids
+                .into_iter()
+                .map(|id| Id::new(id as u64))
+                .collect(),
+            created_at: row.created_at,
+        });
+
+        Ok(report)
+    }
+
+    /// Delete the report's user configs that still were not seen since
+    /// `cutoff` and mark the report as applied within one transaction.
+    ///
+    /// Returns the amount of deleted configs or `None` if there is no such
+    /// report or it was applied already.
+    pub async fn claim_prune_report(
+        &self,
+        report_id: i32,
+        cutoff: OffsetDateTime,
+    ) -> Result<Option<u64>> {
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+
+        let Some(deleted) = Self::claim_prune_report_(&mut tx, report_id, cutoff).await? else {
+            return Ok(None);
+        };
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(Some(deleted))
+    }
+
+    async fn claim_prune_report_(
+        conn: &mut PgConnection,
+        report_id: i32,
+        cutoff: OffsetDateTime,
+    ) -> Result<Option<u64>> {
+        let Some(report) = Self::select_pending_prune_report_(&mut *conn, report_id).await? else {
+            return Ok(None);
+        };
+
+        let deleted =
+            Self::delete_inactive_user_configs_(&mut *conn, &report.discord_ids, cutoff).await?;
+
+        let query = sqlx::query!(
+            r#"
+UPDATE
+  prune_reports
+SET
+  applied_at = NOW()
+WHERE
+  report_id = $1"#,
+            report_id
+        );
+
+        query
+            .execute(&mut *conn)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(Some(deleted))
+    }
+
+    /// Delete the configs of the given users if they still were not seen
+    /// since `cutoff`.
+    ///
+    /// Returns the amount of deleted configs.
+    async fn delete_inactive_user_configs_<'c, E>(
+        executor: E,
+        discord_ids: &[Id<UserMarker>],
+        cutoff: OffsetDateTime,
+    ) -> Result<u64>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let discord_ids: Vec<_> = discord_ids.iter().map(|id| id.get() as i64).collect();
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  user_configs
+WHERE
+  discord_id = ANY($1)
+  AND last_seen_at < $2"#,
+            &discord_ids,
+            cutoff,
+        );
+
+        let res = query
+            .execute(executor)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    use super::*;
+    use crate::test_util::begin_transaction;
+
+    async fn seed_user<'c, E>(executor: E, discord_id: u64, last_seen_at: OffsetDateTime)
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO user_configs (discord_id, osu_id, last_seen_at)
+VALUES
+  ($1, $2, $3)"#,
+            discord_id as i64,
+            (discord_id % 2 == 0).then_some(discord_id as i32),
+            last_seen_at,
+        );
+
+        query.execute(executor).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a database"]
+    async fn prune_candidates() -> Result<()> {
+        let mut tx = begin_transaction().await?;
+
+        // Far in the past so that real rows don't interfere
+        let now = OffsetDateTime::UNIX_EPOCH + Duration::days(10 * 365);
+        let cutoff = now - Duration::days(18 * 30);
+
+        let active = 100_001;
+        let inactive = 100_002;
+        let inactive_unlinked = 100_003;
+        let throttled = 100_004;
+
+        seed_user(&mut *tx, active, now - Duration::days(30)).await;
+        seed_user(&mut *tx, inactive, cutoff - Duration::days(1)).await;
+        seed_user(&mut *tx, inactive_unlinked, cutoff - Duration::days(365)).await;
+        seed_user(&mut *tx, throttled, now - Duration::hours(12)).await;
+
+        let candidates = Database::select_inactive_user_configs_(&mut *tx, cutoff).await?;
+        let candidates: Vec<_> = candidates
+            .into_iter()
+            .filter(|config| (100_001..=100_004).contains(&config.discord_id.get()))
+            .map(|config| (config.discord_id.get(), config.osu_id))
+            .collect();
+
+        assert_eq!(
+            candidates,
+            [(inactive, Some(inactive as u32)), (inactive_unlinked, None)]
+        );
+
+        // Seen within the last day so it's not updated
+        let later = now - Duration::hours(1);
+        Database::update_user_last_seen_(&mut *tx, Id::new(throttled), later).await?;
+        let unchanged = Database::select_inactive_user_configs_(&mut *tx, now - Duration::hours(6))
+            .await?
+            .into_iter()
+            .any(|config| config.discord_id.get() == throttled);
+        assert!(unchanged);
+
+        // Inactive users that are seen again are no longer candidates
+        Database::update_user_last_seen_(&mut *tx, Id::new(inactive), now).await?;
+
+        let report_id = Database::insert_prune_report_(
+            &mut *tx,
+            &[Id::new(inactive), Id::new(inactive_unlinked)],
+            &[Id::new(42)],
+        )
+        .await?;
+
+        let report = Database::select_pending_prune_report_(&mut *tx, report_id)
+            .await?
+            .expect("missing report");
+
+        assert_eq!(report.discord_ids.len(), 2);
+        assert_eq!(report.channel_ids, [Id::new(42)]);
+
+        let deleted = Database::claim_prune_report_(&mut tx, report_id, cutoff).await?;
+        assert_eq!(deleted, Some(1));
+
+        // Reports can only be applied once
+        assert!(
+            Database::select_pending_prune_report_(&mut *tx, report_id)
+                .await?
+                .is_none()
+        );
+        assert!(
+            Database::claim_prune_report_(&mut tx, report_id, cutoff)
+                .await?
+                .is_none()
+        );
+
+        tx.rollback().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a database"]
+    async fn tracked_channels_are_deduplicated() -> Result<()> {
+        let mut tx = begin_transaction().await?;
+
+        sqlx::query!(
+            r#"
+INSERT INTO tracked_osu_users (user_id, gamemode, channel_id)
+VALUES
+  (1, 0, 900001),
+  (2, 0, 900001),
+  (1, 1, 900002)"#
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+INSERT INTO tracked_twitch_streams (channel_id, user_id)
+VALUES
+  (900002, 1),
+  (900003, 1)"#
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let channels: Vec<_> = Database::select_tracked_channels_(&mut *tx)
+            .await?
+            .into_iter()
+            .map(Id::get)
+            .filter(|id| (900_001..=900_003).contains(id))
+            .collect();
+
+        assert_eq!(channels, [900_001, 900_002, 900_003]);
+
+        tx.rollback().await?;
+
+        Ok(())
+    }
+}
@@ -10,4 +10,7 @@ mod database;
 mod impls;
 mod util;
 
+#[cfg(test)]
+mod test_util;
+
 pub mod model;
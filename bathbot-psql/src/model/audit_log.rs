@@ -0,0 +1,16 @@
+use time::OffsetDateTime;
+use twilight_model::id::{
+    Id,
+    marker::{GuildMarker, UserMarker},
+};
+
+pub struct AuditLogEntry {
+    pub guild_id: Id<GuildMarker>,
+    pub user_id: Id<UserMarker>,
+    /// Command name with its group and subcommand e.g. "serverconfig edit"
+    pub command: Box<str>,
+    /// Truncated summary of the given options
+    pub options: Box<str>,
+    pub success: bool,
+    pub timestamp: OffsetDateTime,
+}
@@ -0,0 +1,35 @@
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum GraphTheme {
+    #[default]
+    #[option(name = "Default", value = "default")]
+    Default = 0,
+    #[option(name = "Colorblind", value = "colorblind")]
+    Colorblind = 1,
+    #[option(name = "Light", value = "light")]
+    Light = 2,
+    #[option(name = "Dark", value = "dark")]
+    Dark = 3,
+}
+
+impl From<GraphTheme> for i16 {
+    fn from(theme: GraphTheme) -> Self {
+        theme as Self
+    }
+}
+
+impl TryFrom<i16> for GraphTheme {
+    type Error = ();
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Default),
+            1 => Ok(Self::Colorblind),
+            2 => Ok(Self::Light),
+            3 => Ok(Self::Dark),
+            _ => Err(()),
+        }
+    }
+}
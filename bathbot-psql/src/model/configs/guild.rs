@@ -1,4 +1,6 @@
+use bathbot_util::i18n::Lang;
 use sqlx::types::JsonValue;
+use twilight_model::id::{Id, marker::ChannelMarker};
 
 use super::{Authorities, HideSolutions, Retries, ScoreData, list_size::ListSize};
 
@@ -13,6 +15,13 @@ pub struct DbGuildConfig {
     pub allow_custom_skins: Option<bool>,
     pub hide_medal_solution: Option<i16>,
     pub score_data: Option<i16>,
+    pub delete_invocation: Option<bool>,
+    pub score_anomalies: Option<bool>,
+    pub auto_delete_secs: Option<i16>,
+    pub auto_delete_groups: Option<i16>,
+    pub auto_delete_attachments: Option<i16>,
+    pub auto_score_embed_channels: Option<Vec<i64>>,
+    pub lang: Option<i16>,
 }
 
 #[derive(Clone)]
@@ -26,6 +35,17 @@ pub struct GuildConfig {
     pub allow_custom_skins: Option<bool>,
     pub hide_medal_solution: Option<HideSolutions>,
     pub score_data: Option<ScoreData>,
+    pub delete_invocation: Option<bool>,
+    pub score_anomalies: Option<bool>,
+    /// Seconds after which responses to prefix commands are deleted
+    pub auto_delete_secs: Option<u16>,
+    /// Bit set of the prefix command groups whose responses are deleted
+    pub auto_delete_groups: Option<u16>,
+    /// Bit set of the prefix command groups whose responses are deleted even
+    /// if they contain an attachment
+    pub auto_delete_attachments: Option<u16>,
+    pub auto_score_embed_channels: Vec<Id<ChannelMarker>>,
+    pub lang: Option<Lang>,
 }
 
 impl GuildConfig {
@@ -44,6 +64,13 @@ impl Default for GuildConfig {
             allow_custom_skins: Default::default(),
             hide_medal_solution: Default::default(),
             score_data: Default::default(),
+            delete_invocation: Default::default(),
+            score_anomalies: Default::default(),
+            auto_delete_secs: Default::default(),
+            auto_delete_groups: Default::default(),
+            auto_delete_attachments: Default::default(),
+            auto_score_embed_channels: Default::default(),
+            lang: Default::default(),
         }
     }
 }
@@ -62,6 +89,13 @@ impl From<DbGuildConfig> for GuildConfig {
             allow_custom_skins,
             hide_medal_solution,
             score_data,
+            delete_invocation,
+            score_anomalies,
+            auto_delete_secs,
+            auto_delete_groups,
+            auto_delete_attachments,
+            auto_score_embed_channels,
+            lang,
         } = config;
 
         let authorities = Authorities::deserialize(&authorities);
@@ -90,6 +124,17 @@ impl From<DbGuildConfig> for GuildConfig {
                 .map(HideSolutions::try_from)
                 .and_then(Result::ok),
             score_data: score_data.map(ScoreData::try_from).and_then(Result::ok),
+            delete_invocation,
+            score_anomalies,
+            auto_delete_secs: auto_delete_secs.map(|secs| secs as u16),
+            auto_delete_groups: auto_delete_groups.map(|bits| bits as u16),
+            auto_delete_attachments: auto_delete_attachments.map(|bits| bits as u16),
+            auto_score_embed_channels: auto_score_embed_channels
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|channel_id| Id::new_checked(channel_id as u64))
+                .collect(),
+            lang: lang.map(Lang::try_from).and_then(Result::ok),
         }
     }
 }
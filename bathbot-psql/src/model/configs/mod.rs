@@ -1,19 +1,24 @@
 pub use self::{
     authorities::{Authorities, Authority},
+    graph_theme::GraphTheme,
     guild::{DbGuildConfig, GuildConfig},
     hide_solutions::HideSolutions,
     list_size::ListSize,
     retries::Retries,
     score_data::ScoreData,
     skin::{DbSkinEntry, SkinEntry},
-    user::{DbUserConfig, OsuId, OsuUserId, OsuUsername, UserConfig},
+    timestamp_style::TimestampStyle,
+    user::{DbUserConfig, OsuId, OsuUserId, OsuUsername, RevisedScoreEmbed, UserConfig},
 };
 
 mod authorities;
+mod graph_theme;
 mod guild;
 mod hide_solutions;
 mod list_size;
+pub mod resolve;
 mod retries;
 mod score_data;
 mod skin;
+mod timestamp_style;
 mod user;
@@ -0,0 +1,115 @@
+//! Resolution of settings that can be specified through command arguments,
+//! the user config, and the guild config.
+//!
+//! Commands as well as `/config list` should resolve settings through these
+//! functions so that the displayed values can't diverge from actual command
+//! behavior.
+
+use rosu_v2::prelude::GameMode;
+
+use super::{ListSize, Retries, ScoreData};
+
+/// Command arguments take precedence over the user config. Defaults to
+/// [`GameMode::Osu`].
+pub fn mode(args: Option<GameMode>, user: Option<GameMode>) -> GameMode {
+    args.or(user).unwrap_or(GameMode::Osu)
+}
+
+/// Command arguments take precedence over the user config, which takes
+/// precedence over the guild config. Defaults to [`ScoreData::Lazer`].
+pub fn score_data(
+    args: Option<ScoreData>,
+    user: Option<ScoreData>,
+    guild: Option<ScoreData>,
+) -> ScoreData {
+    args.or(user).or(guild).unwrap_or_default()
+}
+
+/// Command arguments take precedence over the user config, which takes
+/// precedence over the guild config. Defaults to [`ListSize::Condensed`].
+pub fn list_size(
+    args: Option<ListSize>,
+    user: Option<ListSize>,
+    guild: Option<ListSize>,
+) -> ListSize {
+    args.or(user).or(guild).unwrap_or_default()
+}
+
+/// The user config takes precedence over the guild config. Defaults to
+/// [`Retries::ConsiderMods`].
+pub fn retries(user: Option<Retries>, guild: Option<Retries>) -> Retries {
+    user.or(guild).unwrap_or(Retries::ConsiderMods)
+}
+
+/// A guild that disabled the render button overrules the user config.
+/// Otherwise the user config decides and defaults to showing the button.
+pub fn render_button(user: Option<bool>, guild: Option<bool>) -> bool {
+    match (guild, user) {
+        (None | Some(true), None) => true,
+        (None | Some(true), Some(with_render)) => with_render,
+        (Some(false), _) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mode_precedence() {
+        let taiko = Some(GameMode::Taiko);
+        let mania = Some(GameMode::Mania);
+
+        assert_eq!(mode(taiko, mania), GameMode::Taiko);
+        assert_eq!(mode(None, mania), GameMode::Mania);
+        assert_eq!(mode(None, None), GameMode::Osu);
+    }
+
+    #[test]
+    fn score_data_precedence() {
+        let stable = Some(ScoreData::Stable);
+        let classic = Some(ScoreData::LazerWithClassicScoring);
+        let lazer = Some(ScoreData::Lazer);
+
+        assert_eq!(score_data(stable, classic, lazer), ScoreData::Stable);
+        assert_eq!(
+            score_data(None, classic, stable),
+            ScoreData::LazerWithClassicScoring
+        );
+        assert_eq!(score_data(None, None, stable), ScoreData::Stable);
+        assert_eq!(score_data(None, None, None), ScoreData::Lazer);
+    }
+
+    #[test]
+    fn list_size_precedence() {
+        let single = Some(ListSize::Single);
+        let detailed = Some(ListSize::Detailed);
+        let condensed = Some(ListSize::Condensed);
+
+        assert_eq!(list_size(single, detailed, condensed), ListSize::Single);
+        assert_eq!(list_size(None, detailed, single), ListSize::Detailed);
+        assert_eq!(list_size(None, None, single), ListSize::Single);
+        assert_eq!(list_size(None, None, None), ListSize::Condensed);
+    }
+
+    #[test]
+    fn retries_precedence() {
+        let hide = Some(Retries::Hide);
+        let ignore_mods = Some(Retries::IgnoreMods);
+
+        assert_eq!(retries(hide, ignore_mods), Retries::Hide);
+        assert_eq!(retries(None, ignore_mods), Retries::IgnoreMods);
+        assert_eq!(retries(None, None), Retries::ConsiderMods);
+    }
+
+    #[test]
+    fn render_button_precedence() {
+        assert!(!render_button(Some(true), Some(false)));
+        assert!(!render_button(None, Some(false)));
+        assert!(!render_button(Some(false), Some(true)));
+        assert!(!render_button(Some(false), None));
+        assert!(render_button(Some(true), None));
+        assert!(render_button(None, Some(true)));
+        assert!(render_button(None, None));
+    }
+}
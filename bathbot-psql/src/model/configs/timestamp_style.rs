@@ -0,0 +1,32 @@
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum TimestampStyle {
+    #[default]
+    #[option(name = "Relative", value = "relative")]
+    Relative = 0,
+    #[option(name = "Local time", value = "local_absolute")]
+    LocalAbsolute = 1,
+    #[option(name = "UTC text", value = "utc_text")]
+    UtcText = 2,
+}
+
+impl From<TimestampStyle> for i16 {
+    fn from(style: TimestampStyle) -> Self {
+        style as Self
+    }
+}
+
+impl TryFrom<i16> for TimestampStyle {
+    type Error = ();
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Relative),
+            1 => Ok(Self::LocalAbsolute),
+            2 => Ok(Self::UtcText),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,9 +1,9 @@
 use bathbot_model::embed_builder::ScoreEmbedSettings;
-use rosu_v2::prelude::{GameMode, Username};
+use rosu_v2::prelude::{GameMode, GameModsIntermode, Username};
 use sqlx::types::Json;
 use time::UtcOffset;
 
-use super::{Retries, ScoreData, list_size::ListSize};
+use super::{GraphTheme, Retries, ScoreData, TimestampStyle, list_size::ListSize};
 
 pub struct DbUserConfig {
     pub list_size: Option<i16>,
@@ -15,6 +15,23 @@ pub struct DbUserConfig {
     pub timezone_seconds: Option<i32>,
     pub render_button: Option<bool>,
     pub score_data: Option<i16>,
+    pub timestamp_style: Option<i16>,
+    pub recent_includes_fails: Option<bool>,
+    pub graph_theme: Option<i16>,
+    pub blacklisted_mods: Option<String>,
+    pub extended_profile: Option<bool>,
+    pub tracking_dm_digest: Option<bool>,
+    pub skin_display_name: Option<String>,
+    pub skin_display_url: Option<String>,
+    pub hide_from_server_lists: Option<bool>,
+    pub osutrack_auto_update: Option<bool>,
+}
+
+/// Score embed settings alongside the revision they were read at.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RevisedScoreEmbed {
+    pub settings: Option<ScoreEmbedSettings>,
+    pub revision: i32,
 }
 
 pub trait OsuId {
@@ -46,6 +63,16 @@ pub struct UserConfig<O: OsuId> {
     pub timezone: Option<UtcOffset>,
     pub render_button: Option<bool>,
     pub score_data: Option<ScoreData>,
+    pub timestamp_style: Option<TimestampStyle>,
+    pub recent_includes_fails: Option<bool>,
+    pub graph_theme: Option<GraphTheme>,
+    pub blacklisted_mods: Option<GameModsIntermode>,
+    pub extended_profile: Option<bool>,
+    pub tracking_dm_digest: Option<bool>,
+    pub skin_display_name: Option<String>,
+    pub skin_display_url: Option<String>,
+    pub hide_from_server_lists: Option<bool>,
+    pub osutrack_auto_update: Option<bool>,
 }
 
 impl<O: OsuId> Default for UserConfig<O> {
@@ -61,6 +88,16 @@ impl<O: OsuId> Default for UserConfig<O> {
             timezone: None,
             render_button: None,
             score_data: None,
+            timestamp_style: None,
+            recent_includes_fails: None,
+            graph_theme: None,
+            blacklisted_mods: None,
+            extended_profile: None,
+            tracking_dm_digest: None,
+            skin_display_name: None,
+            skin_display_url: None,
+            hide_from_server_lists: None,
+            osutrack_auto_update: None,
         }
     }
 }
@@ -78,6 +115,16 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
             timezone_seconds,
             render_button,
             score_data,
+            timestamp_style,
+            recent_includes_fails,
+            graph_theme,
+            blacklisted_mods,
+            extended_profile,
+            tracking_dm_digest,
+            skin_display_name,
+            skin_display_url,
+            hide_from_server_lists,
+            osutrack_auto_update,
         } = config;
 
         Self {
@@ -92,6 +139,20 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
                 .map(Result::unwrap),
             render_button,
             score_data: score_data.map(ScoreData::try_from).and_then(Result::ok),
+            timestamp_style: timestamp_style
+                .map(TimestampStyle::try_from)
+                .and_then(Result::ok),
+            recent_includes_fails,
+            graph_theme: graph_theme.map(GraphTheme::try_from).and_then(Result::ok),
+            blacklisted_mods: blacklisted_mods
+                .as_deref()
+                .and_then(GameModsIntermode::try_from_acronyms),
+            extended_profile,
+            tracking_dm_digest,
+            skin_display_name,
+            skin_display_url,
+            hide_from_server_lists,
+            osutrack_auto_update,
         }
     }
 }
@@ -1,4 +1,4 @@
-use std::fmt::Write;
+use std::{collections::HashSet, fmt::Write};
 
 use bathbot_model::MapsetTags;
 use rosu_v2::prelude::GameMode;
@@ -15,12 +15,85 @@ pub struct MapsetTagsEntries {
     pub tags: Vec<DbMapTagEntry>,
 }
 
+impl MapsetTagsEntries {
+    /// Minimum amount of mapsets required for a game with custom tags.
+    pub const MIN_POOL_SIZE: usize = 20;
+
+    /// Amount of distinct mapsets.
+    pub fn mapset_count(&self) -> usize {
+        self.tags
+            .iter()
+            .map(|entry| entry.mapset_id)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// Whether there are enough mapsets for a game with custom tags.
+    pub fn is_pool_sufficient(&self) -> bool {
+        self.mapset_count() >= Self::MIN_POOL_SIZE
+    }
+}
+
 #[derive(FromRow)]
 pub struct DbMapTagEntry {
     pub mapset_id: i32,
     pub image_filename: String,
 }
 
+pub struct DbMapsetTags {
+    pub farm: bool,
+    pub streams: bool,
+    pub alternate: bool,
+    pub old: bool,
+    pub meme: bool,
+    pub hardname: bool,
+    pub easy: bool,
+    pub hard: bool,
+    pub tech: bool,
+    pub weeb: bool,
+    pub bluesky: bool,
+    pub english: bool,
+    pub kpop: bool,
+}
+
+impl From<DbMapsetTags> for MapsetTags {
+    fn from(tags: DbMapsetTags) -> Self {
+        let DbMapsetTags {
+            farm,
+            streams,
+            alternate,
+            old,
+            meme,
+            hardname,
+            easy,
+            hard,
+            tech,
+            weeb,
+            bluesky,
+            english,
+            kpop,
+        } = tags;
+
+        let mut res = Self::empty();
+
+        res.set(Self::Farm, farm);
+        res.set(Self::Streams, streams);
+        res.set(Self::Alternate, alternate);
+        res.set(Self::Old, old);
+        res.set(Self::Meme, meme);
+        res.set(Self::HardName, hardname);
+        res.set(Self::Easy, easy);
+        res.set(Self::Hard, hard);
+        res.set(Self::Tech, tech);
+        res.set(Self::Weeb, weeb);
+        res.set(Self::BlueSky, bluesky);
+        res.set(Self::English, english);
+        res.set(Self::Kpop, kpop);
+
+        res
+    }
+}
+
 macro_rules! define_map_tags {
     ( $( $column:ident ,)* ) => {
         pub struct DbMapTagsParams {
@@ -131,3 +204,40 @@ define_map_tags! {
     easy,
     hard,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(mapset_ids: impl IntoIterator<Item = i32>) -> MapsetTagsEntries {
+        let tags = mapset_ids
+            .into_iter()
+            .map(|mapset_id| DbMapTagEntry {
+                mapset_id,
+                image_filename: format!("{mapset_id}.png"),
+            })
+            .collect();
+
+        MapsetTagsEntries {
+            mode: GameMode::Osu,
+            tags,
+        }
+    }
+
+    #[test]
+    fn pool_size_counts_distinct_mapsets() {
+        let entries = entries((0..15).chain(0..15));
+
+        assert_eq!(entries.mapset_count(), 15);
+        assert!(!entries.is_pool_sufficient());
+    }
+
+    #[test]
+    fn pool_size_threshold() {
+        let too_small = entries(0..MapsetTagsEntries::MIN_POOL_SIZE as i32 - 1);
+        assert!(!too_small.is_pool_sufficient());
+
+        let enough = entries(0..MapsetTagsEntries::MIN_POOL_SIZE as i32);
+        assert!(enough.is_pool_sufficient());
+    }
+}
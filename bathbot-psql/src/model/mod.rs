@@ -1,4 +1,6 @@
+pub mod audit_log;
 pub mod configs;
 pub mod games;
 pub mod osu;
+pub mod prune;
 pub mod render;
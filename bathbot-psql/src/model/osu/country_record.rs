@@ -0,0 +1,12 @@
+use rosu_v2::prelude::GameMode;
+use time::OffsetDateTime;
+
+/// Highest pp score of a country that the bot has seen.
+pub struct CountryRecord {
+    pub country_code: Box<str>,
+    pub mode: GameMode,
+    pub pp: f32,
+    pub score_id: u64,
+    pub user_id: u32,
+    pub updated_at: OffsetDateTime,
+}
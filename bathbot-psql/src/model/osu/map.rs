@@ -3,6 +3,8 @@ use bathbot_util::{
     query::{FilterCriteria, RegularCriteria, Searchable},
 };
 use rkyv::{Archive, Deserialize, Serialize};
+use rosu_v2::prelude::{BeatmapExtended, Beatmapset};
+use time::OffsetDateTime;
 
 #[derive(Clone)]
 pub struct DbBeatmap {
@@ -46,3 +48,82 @@ pub struct MapVersion {
     pub map_id: i32,
     pub version: String,
 }
+
+/// Metadata of a map which is also available for partially stored maps.
+#[derive(Debug, PartialEq)]
+pub struct DbMapMetadata {
+    pub map_id: i32,
+    pub mapset_id: i32,
+    pub artist: String,
+    pub title: String,
+    pub version: String,
+    pub gamemode: i16,
+    pub thumbnail: String,
+}
+
+/// Map and mapset metadata as embedded in score responses.
+///
+/// Such summaries lack some fields of a full mapset so the rows they create
+/// are flagged as partial and are not considered fully fetched.
+pub struct PartialMap<'a> {
+    pub map_id: i32,
+    pub mapset_id: i32,
+    pub mapper_id: i32,
+    pub checksum: &'a str,
+    pub map_version: &'a str,
+    pub seconds_total: i32,
+    pub seconds_drain: i32,
+    pub count_circles: i32,
+    pub count_sliders: i32,
+    pub count_spinners: i32,
+    pub hp: f32,
+    pub cs: f32,
+    pub od: f32,
+    pub ar: f32,
+    pub bpm: f32,
+    pub gamemode: i16,
+    pub last_updated: OffsetDateTime,
+    pub creator_id: i32,
+    pub artist: &'a str,
+    pub title: &'a str,
+    pub creator: &'a str,
+    pub source: &'a str,
+    pub video: bool,
+    pub rank_status: i16,
+    pub thumbnail: &'a str,
+    pub cover: &'a str,
+}
+
+impl<'a> PartialMap<'a> {
+    /// Returns `None` if the map has no checksum.
+    pub fn new(map: &'a BeatmapExtended, mapset: &'a Beatmapset) -> Option<Self> {
+        Some(Self {
+            map_id: map.map_id as i32,
+            mapset_id: map.mapset_id as i32,
+            mapper_id: map.creator_id as i32,
+            checksum: map.checksum.as_deref()?,
+            map_version: &map.version,
+            seconds_total: map.seconds_total as i32,
+            seconds_drain: map.seconds_drain as i32,
+            count_circles: map.count_circles as i32,
+            count_sliders: map.count_sliders as i32,
+            count_spinners: map.count_spinners as i32,
+            hp: map.hp,
+            cs: map.cs,
+            od: map.od,
+            ar: map.ar,
+            bpm: map.bpm,
+            gamemode: map.mode as i16,
+            last_updated: map.last_updated,
+            creator_id: mapset.creator_id as i32,
+            artist: &mapset.artist,
+            title: &mapset.title,
+            creator: mapset.creator_name.as_str(),
+            source: &mapset.source,
+            video: mapset.video,
+            rank_status: mapset.status as i16,
+            thumbnail: &mapset.covers.list,
+            cover: &mapset.covers.cover,
+        })
+    }
+}
@@ -0,0 +1,12 @@
+use rosu_v2::prelude::GameMode;
+use time::OffsetDateTime;
+
+/// Score that the bot observed as first place of a map's leaderboard.
+pub struct MapFirstPlace {
+    pub map_id: u32,
+    pub mode: GameMode,
+    pub score_id: u64,
+    pub user_id: u32,
+    pub pp: Option<f32>,
+    pub observed_at: OffsetDateTime,
+}
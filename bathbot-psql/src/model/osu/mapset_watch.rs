@@ -0,0 +1,18 @@
+use rosu_v2::prelude::RankStatus;
+use time::OffsetDateTime;
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, UserMarker},
+};
+
+pub struct MapsetWatch {
+    pub user_id: Id<UserMarker>,
+    pub mapset_id: u32,
+    /// "artist - title"
+    pub name: Box<str>,
+    /// The last known status of the mapset
+    pub status: RankStatus,
+    /// `None` if the user should be notified via DM
+    pub channel_id: Option<Id<ChannelMarker>>,
+    pub expires_at: Option<OffsetDateTime>,
+}
@@ -1,7 +1,13 @@
-pub use self::{bookmark::*, map::*, mapset::*, tracked_user::*, user::*};
+pub use self::{
+    bookmark::*, country_record::*, map::*, map_first_place::*, mapset::*, mapset_watch::*,
+    tracked_user::*, user::*,
+};
 
 mod bookmark;
+mod country_record;
 mod map;
+mod map_first_place;
 mod mapset;
+mod mapset_watch;
 mod tracked_user;
 mod user;
@@ -10,8 +10,12 @@ pub struct DbTrackedOsuUser {
     pub max_pp: Option<f32>,
     pub min_combo_percent: Option<f32>,
     pub max_combo_percent: Option<f32>,
+    pub compact: bool,
     pub last_pp: f32,
     pub last_updated: OffsetDateTime,
+    pub playcount_milestone: Option<i64>,
+    pub ranked_score_milestone: Option<i64>,
+    pub pp_milestone: Option<i64>,
 }
 
 pub struct DbTrackedOsuUserInChannel {
@@ -23,4 +27,5 @@ pub struct DbTrackedOsuUserInChannel {
     pub max_pp: Option<f32>,
     pub min_combo_percent: Option<f32>,
     pub max_combo_percent: Option<f32>,
+    pub compact: bool,
 }
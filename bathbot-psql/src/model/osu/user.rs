@@ -1,5 +1,7 @@
 use bathbot_model::{UserModeStatsColumn, UserStatsColumn};
+use rosu_v2::prelude::GameMode;
 use sqlx::{Database, Decode, FromRow, Postgres, Type, error::BoxDynError, postgres::PgTypeInfo};
+use time::OffsetDateTime;
 
 struct DbCountryCode {
     inner: [u8; 2],
@@ -42,6 +44,14 @@ pub struct DbUserStatsEntry<V> {
     pub value: V,
 }
 
+/// A linked osu! user alongside the time their stats were last updated.
+pub struct StaleOsuUser {
+    pub user_id: u32,
+    /// The mode of the user's config, defaulting to osu!standard.
+    pub mode: GameMode,
+    pub last_update: OffsetDateTime,
+}
+
 pub trait OsuUserStatsColumn {
     type Stats;
     type Value;
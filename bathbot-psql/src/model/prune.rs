@@ -0,0 +1,19 @@
+use time::OffsetDateTime;
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, UserMarker},
+};
+
+/// User config that was not used for a long time.
+pub struct InactiveUserConfig {
+    pub discord_id: Id<UserMarker>,
+    pub osu_id: Option<u32>,
+}
+
+/// Candidates for pruning that are only removed once the owner confirmed them.
+pub struct PruneReport {
+    pub report_id: i32,
+    pub discord_ids: Vec<Id<UserMarker>>,
+    pub channel_ids: Vec<Id<ChannelMarker>>,
+    pub created_at: OffsetDateTime,
+}
@@ -1,3 +1,9 @@
+use time::OffsetDateTime;
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, MessageMarker, UserMarker},
+};
+
 pub struct DbRenderOptions {
     pub official_skin_name: String,
     pub official_skin_display_name: String,
@@ -53,3 +59,13 @@ pub struct DbRenderOptions {
     pub show_slider_breaks: bool,
     pub ignore_fail: bool,
 }
+
+/// Render that was commissioned but whose video has not been delivered yet.
+pub struct DbOngoingRender {
+    pub render_id: u32,
+    pub score_id: Option<u64>,
+    pub channel_id: Id<ChannelMarker>,
+    pub message_id: Id<MessageMarker>,
+    pub requester_id: Id<UserMarker>,
+    pub created_at: OffsetDateTime,
+}
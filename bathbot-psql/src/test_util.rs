@@ -0,0 +1,25 @@
+use std::env;
+
+use eyre::{Result, WrapErr};
+use sqlx::{Postgres, Transaction};
+
+use crate::Database;
+
+/// Begin a transaction on the database specified by `DATABASE_URL`.
+///
+/// Tests should roll it back at the end so that nothing is persisted.
+pub(crate) async fn begin_transaction() -> Result<Transaction<'static, Postgres>> {
+    let _ = dotenvy::dotenv();
+    let url = env::var("DATABASE_URL").wrap_err("missing env variable `DATABASE_URL`")?;
+    let psql = Database::new(&url)?;
+
+    psql.begin().await.wrap_err("Failed to begin transaction")
+}
+
+#[tokio::test]
+#[ignore = "requires a database"]
+async fn begin_and_roll_back() -> Result<()> {
+    begin_transaction().await?.rollback().await?;
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use time::OffsetDateTime;
 
 use crate::IntHasher;
 
-pub struct Buckets([Mutex<Bucket>; 8]);
+pub struct Buckets([Mutex<Bucket>; 10]);
 
 impl Buckets {
     #[allow(clippy::new_without_default)]
@@ -27,6 +27,8 @@ impl Buckets {
             make_bucket(5, 900, 3),  // MatchLive
             make_bucket(60, 720, 2), // Render
             make_bucket(20, 0, 1),   // Songs
+            make_bucket(10, 0, 1),   // AutoScoreEmbed
+            make_bucket(0, 10, 5),   // Roll
         ])
     }
 
@@ -40,6 +42,8 @@ impl Buckets {
             BucketName::MatchLive => &self.0[5],
             BucketName::Render => &self.0[6],
             BucketName::Songs => &self.0[7],
+            BucketName::AutoScoreEmbed => &self.0[8],
+            BucketName::Roll => &self.0[9],
         }
     }
 }
@@ -116,4 +120,41 @@ pub enum BucketName {
     MatchLive,
     Render,
     Songs,
+    /// Keyed by channel id instead of user id
+    AutoScoreEmbed,
+    /// Keyed by channel id instead of user id
+    Roll,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_score_embed_once_per_channel() {
+        let buckets = Buckets::new();
+        let mut bucket = buckets.get(BucketName::AutoScoreEmbed).lock().unwrap();
+
+        assert_eq!(bucket.take(1), 0);
+
+        let cooldown = bucket.take(1);
+        assert!((1..=10).contains(&cooldown), "cooldown={cooldown}");
+
+        // Other channels are unaffected
+        assert_eq!(bucket.take(2), 0);
+    }
+
+    #[test]
+    fn auto_score_embed_after_delay() {
+        let buckets = Buckets::new();
+        let mut bucket = buckets.get(BucketName::AutoScoreEmbed).lock().unwrap();
+
+        assert_eq!(bucket.take(1), 0);
+
+        // Pretend the last embed was sent 10 seconds ago
+        bucket.users.get_mut(&1).unwrap().last_time -= 10;
+
+        assert_eq!(bucket.take(1), 0);
+        assert!(bucket.take(1) > 0);
+    }
 }
@@ -2,10 +2,15 @@ use std::{borrow::Cow, slice};
 
 use twilight_model::{
     channel::message::{Component, embed::Embed},
+    guild::Permissions,
     http::attachment::Attachment,
 };
 
 use super::EmbedBuilder;
+use crate::markdown::truncate_lines;
+
+/// Maximum length of a message's content.
+const CONTENT_LIMIT: usize = 2000;
 
 #[derive(Default)]
 pub struct MessageBuilder<'c> {
@@ -13,6 +18,7 @@ pub struct MessageBuilder<'c> {
     pub embed: EmbedOption,
     pub attachment: Option<Attachment>,
     pub components: Option<Vec<Component>>,
+    pub plain_fallback: bool,
 }
 
 // essentially an extension to Option<EmbedBuilder> which will be Some most of
@@ -92,6 +98,41 @@ impl<'c> MessageBuilder<'c> {
 
         self
     }
+
+    /// Send the embed as plain text instead if embeds can't be displayed.
+    pub fn plain_fallback(mut self, plain_fallback: bool) -> Self {
+        self.plain_fallback = plain_fallback;
+
+        self
+    }
+
+    /// Replace the embed with its plain text representation if the builder
+    /// opted into [`MessageBuilder::plain_fallback`] and the permissions
+    /// lack [`Permissions::EMBED_LINKS`].
+    pub fn resolve_plain_fallback(mut self, permissions: Option<Permissions>) -> Self {
+        let can_embed =
+            permissions.is_none_or(|permissions| permissions.contains(Permissions::EMBED_LINKS));
+
+        if can_embed || !self.plain_fallback {
+            return self;
+        }
+
+        let EmbedOption::Include(ref embed) = self.embed else {
+            return self;
+        };
+
+        let plain = embed.to_plain_text();
+
+        let content = match self.content.take() {
+            Some(content) if !content.is_empty() => format!("{content}\n{plain}"),
+            Some(_) | None => plain,
+        };
+
+        self.content = Some(Cow::Owned(truncate_lines(&content, CONTENT_LIMIT)));
+        self.embed = EmbedOption::Clear;
+
+        self
+    }
 }
 
 impl From<EmbedBuilder> for MessageBuilder<'_> {
@@ -140,3 +181,53 @@ impl IntoEmbed for Option<EmbedBuilder> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builder() -> MessageBuilder<'static> {
+        let embed = EmbedBuilder::new()
+            .title("Title")
+            .description("a\n".repeat(1500));
+
+        MessageBuilder::new().embed(embed)
+    }
+
+    #[test]
+    fn fallback_requires_opt_in() {
+        let no_embeds = Some(Permissions::SEND_MESSAGES);
+        let builder = builder().resolve_plain_fallback(no_embeds);
+
+        assert!(builder.content.is_none());
+        assert!(matches!(builder.embed, EmbedOption::Include(_)));
+    }
+
+    #[test]
+    fn fallback_only_without_embed_permission() {
+        let permissions = Some(Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS);
+
+        for permissions in [None, permissions] {
+            let builder = builder()
+                .plain_fallback(true)
+                .resolve_plain_fallback(permissions);
+
+            assert!(builder.content.is_none());
+            assert!(matches!(builder.embed, EmbedOption::Include(_)));
+        }
+    }
+
+    #[test]
+    fn fallback_replaces_embed() {
+        let builder = builder()
+            .content("Top score")
+            .plain_fallback(true)
+            .resolve_plain_fallback(Some(Permissions::SEND_MESSAGES));
+
+        let content = builder.content.unwrap();
+
+        assert!(content.starts_with("Top score\n**Title**\na\n"));
+        assert!(content.chars().count() <= CONTENT_LIMIT);
+        assert!(matches!(builder.embed, EmbedOption::Clear));
+    }
+}
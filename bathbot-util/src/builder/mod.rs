@@ -9,5 +9,6 @@ mod author;
 mod embed;
 mod footer;
 mod message;
+mod plain_text;
 
 pub mod modal;
@@ -0,0 +1,146 @@
+use std::{borrow::Cow, fmt::Write};
+
+use super::EmbedBuilder;
+
+impl EmbedBuilder {
+    /// Compact plain text representation of the embed for channels in which
+    /// embeds can't be displayed.
+    ///
+    /// Masked links outside of the description are turned into raw urls.
+    pub fn to_plain_text(&self) -> String {
+        let mut text = String::new();
+
+        if let Some(ref author) = self.author {
+            let _ = writeln!(text, "{}", unmask_links(&author.name));
+        }
+
+        if let Some(ref title) = self.title {
+            let _ = writeln!(text, "**{}**", unmask_links(title));
+        }
+
+        if let Some(ref url) = self.url {
+            let _ = writeln!(text, "<{url}>");
+        }
+
+        if let Some(ref description) = self.description {
+            let _ = writeln!(text, "{}", description.trim_end());
+        }
+
+        for field in self.fields.iter() {
+            let name = unmask_links(&field.name);
+            let value = unmask_links(&field.value);
+            let _ = writeln!(text, "**{}**\n{}", name.trim(), value.trim_end());
+        }
+
+        if let Some(ref footer) = self.footer {
+            let _ = writeln!(text, "*{}*", unmask_links(&footer.text));
+        }
+
+        text.truncate(text.trim_end().len());
+
+        text
+    }
+}
+
+/// Replace masked links `[text](url)` with `text (<url>)`.
+fn unmask_links(text: &str) -> Cow<'_, str> {
+    if !text.contains("](") {
+        return Cow::Borrowed(text);
+    }
+
+    let mut output = String::with_capacity(text.len() + 8);
+    let mut rest = text;
+
+    while let Some(open) = rest.find('[') {
+        let after_open = &rest[open + 1..];
+
+        let Some(close) = after_open.find("](") else {
+            break;
+        };
+
+        let label = &after_open[..close];
+        let after_label = &after_open[close + 2..];
+
+        let Some(end) = after_label.find(')') else {
+            break;
+        };
+
+        let url = &after_label[..end];
+
+        // Not a masked link, keep the bracket and look for the next one
+        if label.contains('[') || !url.starts_with("http") {
+            output.push_str(&rest[..=open]);
+            rest = after_open;
+
+            continue;
+        }
+
+        output.push_str(&rest[..open]);
+        let _ = write!(output, "{label} (<{url}>)");
+        rest = &after_label[end + 1..];
+    }
+
+    output.push_str(rest);
+
+    Cow::Owned(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::channel::message::embed::EmbedField;
+
+    use super::*;
+    use crate::{AuthorBuilder, FooterBuilder};
+
+    #[test]
+    fn unmask_single_link() {
+        let text = "[Player](https://osu.ppy.sh/u/2)";
+
+        assert_eq!(unmask_links(text), "Player (<https://osu.ppy.sh/u/2>)");
+    }
+
+    #[test]
+    fn unmask_keeps_other_brackets() {
+        assert_eq!(unmask_links("Map [Insane]"), "Map [Insane]");
+        assert_eq!(unmask_links("[a](b) [c]"), "[a](b) [c]");
+
+        assert_eq!(
+            unmask_links("Map [Insane] by [Mapper](https://osu.ppy.sh/u/3)!"),
+            "Map [Insane] by Mapper (<https://osu.ppy.sh/u/3>)!"
+        );
+    }
+
+    #[test]
+    fn plain_text_unmasks_outside_description() {
+        let fields = vec![EmbedField {
+            inline: false,
+            name: "[Top score](https://osu.ppy.sh/scores/1)".to_owned(),
+            value: "by [Player](https://osu.ppy.sh/u/2)".to_owned(),
+        }];
+
+        let embed = EmbedBuilder::new()
+            .author(AuthorBuilder::new("Player: 1,234pp"))
+            .title("Artist - Title [Insane]")
+            .url("https://osu.ppy.sh/b/4")
+            .description("Played by [Player](https://osu.ppy.sh/u/2)\n")
+            .fields(fields)
+            .footer(FooterBuilder::new(
+                "Mapset by [Mapper](https://osu.ppy.sh/u/3)",
+            ));
+
+        let expected = "Player: 1,234pp\n\
+            **Artist - Title [Insane]**\n\
+            <https://osu.ppy.sh/b/4>\n\
+            Played by [Player](https://osu.ppy.sh/u/2)\n\
+            **Top score (<https://osu.ppy.sh/scores/1>)**\n\
+            by Player (<https://osu.ppy.sh/u/2>)\n\
+            *Mapset by Mapper (<https://osu.ppy.sh/u/3>)*";
+
+        assert_eq!(embed.to_plain_text(), expected);
+    }
+
+    #[test]
+    fn plain_text_of_empty_embed() {
+        assert_eq!(EmbedBuilder::new().to_plain_text(), "");
+    }
+}
@@ -28,6 +28,8 @@ pub const TWITCH_OAUTH: &str = "https://id.twitch.tv/oauth2/token";
 
 // Error messages
 pub const GENERAL_ISSUE: &str = "Something went wrong, blame bade";
+pub const DATABASE_UNAVAILABLE: &str =
+    "The database is temporarily unavailable, please try again later";
 pub const OSU_API_ISSUE: &str = "Some issue with the osu api, blame bade";
 pub const ORDR_ISSUE: &str = "Some issue with the o!rdr api, blame bade";
 pub const OSEKAI_ISSUE: &str = "Some issue with the osekai api, blame bade";
@@ -36,11 +38,14 @@ pub const TWITCH_API_ISSUE: &str = "Some issue with the twitch api, blame bade";
 pub const THREADS_UNAVAILABLE: &str = "Cannot start new thread from here";
 
 // Discord error codes
+pub const MISSING_ACCESS: u64 = 50001;
 pub const CANNOT_DM_USER: u64 = 50007;
 pub const INVALID_ACTION_FOR_CHANNEL_TYPE: u64 = 50024;
 pub const MESSAGE_TOO_OLD_TO_BULK_DELETE: u64 = 50034;
+pub const THREAD_ARCHIVED: u64 = 50083;
 
 pub const UNKNOWN_CHANNEL: u64 = 10003;
+pub const UNKNOWN_MESSAGE: u64 = 10008;
 
 // Misc
 pub const INVITE_LINK: &str = "https://discord.com/api/oauth2/authorize?client_id=297073686916366336&permissions=309238025216&scope=bot%20applications.commands";
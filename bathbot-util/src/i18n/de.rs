@@ -0,0 +1,134 @@
+//! German strings.
+
+pub(super) static STRINGS: &[(&str, &str)] = &[
+    // Errors
+    (
+        "error.general",
+        "Etwas ist schiefgelaufen, beschwer dich bei bade",
+    ),
+    (
+        "error.osu_api",
+        "Es gibt Probleme mit der osu!api, beschwer dich bei bade",
+    ),
+    (
+        "link.required",
+        "Gib entweder einen osu! Benutzernamen an oder verknüpfe dich über {link} \
+        mit einem osu! Profil",
+    ),
+    (
+        "link.required_message",
+        "Verknüpfe dich über {link} mit einem osu! Profil, um deinen Score auf der \
+        Map zu vergleichen",
+    ),
+    (
+        "cooldown",
+        "Der Befehl ist noch im Cooldown, versuch es in {seconds} Sekunden erneut",
+    ),
+    // General
+    ("ordinal", "{n}."),
+    // Rank pp
+    (
+        "rank_pp.title.rank",
+        "Wie viele pp fehlen {username} bis Rang {rank}?",
+    ),
+    (
+        "rank_pp.title.holder",
+        "Wie viele pp fehlen {username} bis zum Rang #{rank} von {holder}?",
+    ),
+    (
+        "rank_pp.prefix.held",
+        "Rang {rank} gehört aktuell {holder} mit **{pp}pp**",
+    ),
+    (
+        "rank_pp.prefix.holder",
+        "{holder} ist auf Rang {rank} mit **{pp}pp**",
+    ),
+    (
+        "rank_pp.prefix.approx",
+        "Rang #{rank} erfordert aktuell ca. **{pp}pp**",
+    ),
+    (
+        "rank_pp.prefix.holder_over_10k",
+        "Der Rang #{rank} von {holder} erfordert aktuell **{pp}pp**",
+    ),
+    (
+        "rank_pp.reached",
+        "{username} ist bereits auf Rang #{rank}.",
+    ),
+    (
+        "rank_pp.above",
+        "{prefix}, also ist {username} mit **{pp}pp** bereits darüber.",
+    ),
+    (
+        "rank_pp.single",
+        "{prefix}, also fehlen {username} **{missing}** rohe pp, \
+        erreichbar mit einem einzigen Score mit **{pp}pp**.",
+    ),
+    (
+        "rank_pp.single_top",
+        "{prefix}, also fehlen {username} **{missing}** rohe pp, \
+        erreichbar mit einem einzigen Score mit **{pp}pp**, der zum {ordinal} Top-Play würde.",
+    ),
+    (
+        "rank_pp.amount",
+        "{prefix}, also fehlen {username} **{missing}** rohe pp. \
+        Um mit {amount} Scores aufzuholen, muss jeder **{pp}pp** wert sein und damit \
+        {placement} landen.",
+    ),
+    (
+        "rank_pp.placement",
+        "auf den persönlichen Bestleistungen #{from} bis #{to}",
+    ),
+    ("rank_pp.placement.outside", "außerhalb der Top 200"),
+    (
+        "rank_pp.each.too_low",
+        "{prefix}, also fehlen {username} **{missing}** rohe pp.\n\
+        Ein neuer Top-200-Score erfordert mindestens **{last_pp}pp**, \
+        also sind {target} pp mit {each}pp Scores nicht erreichbar.",
+    ),
+    (
+        "rank_pp.each.single",
+        "{prefix}, also fehlen {username} **{missing}** rohe pp.\n\
+        Um mit einem weiteren Score {target}pp zu erreichen, braucht {username} einen \
+        **{required}pp** Score, der zum {approx}{ordinal} Top-Play würde",
+    ),
+    (
+        "rank_pp.each.unreachable.one",
+        "{prefix}, also fehlen {username} **{missing}** rohe pp.\n\
+        Die Top-Scores von {username} mit {amount} neuen {each}pp Score aufzufüllen \
+        ergäbe nur {approx}**{top}pp**, was immer noch weniger als {target}pp ist.",
+    ),
+    (
+        "rank_pp.each.unreachable.other",
+        "{prefix}, also fehlen {username} **{missing}** rohe pp.\n\
+        Die Top-Scores von {username} mit {amount} neuen {each}pp Scores aufzufüllen \
+        ergäbe nur {approx}**{top}pp**, was immer noch weniger als {target}pp ist.",
+    ),
+    ("rank_pp.each.roughly", "ungefähr "),
+    (
+        "rank_pp.each.required.one",
+        "{prefix}, also fehlen {username} **{missing}** rohe pp.\n\
+        Um {target}pp zu erreichen, braucht {username} **{n_each}** weiteren {each}pp Score \
+        und einen **{required}pp** Score.",
+    ),
+    (
+        "rank_pp.each.required.other",
+        "{prefix}, also fehlen {username} **{missing}** rohe pp.\n\
+        Um {target}pp zu erreichen, braucht {username} **{n_each}** weitere {each}pp Scores \
+        und einen **{required}pp** Score.",
+    ),
+    (
+        "rank_pp.exclude.one",
+        "Angenommen, der schwächste Top-Play von {username} wird ersetzt, \
+        liegt die Ausgangsbasis bei **{pp}pp**.",
+    ),
+    (
+        "rank_pp.exclude.other",
+        "Angenommen, die {exclude} schwächsten Top-Plays von {username} werden ersetzt, \
+        liegt die Ausgangsbasis bei **{pp}pp**.",
+    ),
+    (
+        "rank_pp.blacklisted",
+        "Ein {pp}pp Play erfordert vermutlich {blacklist}-Scores, die du ausgeschlossen hast.",
+    ),
+];
@@ -0,0 +1,119 @@
+//! English strings which every other language falls back to.
+
+pub(super) static STRINGS: &[(&str, &str)] = &[
+    // Errors
+    ("error.general", "Something went wrong, blame bade"),
+    ("error.osu_api", "Some issue with the osu api, blame bade"),
+    (
+        "link.required",
+        "Either specify an osu! username or link yourself to an osu! profile via {link}",
+    ),
+    (
+        "link.required_message",
+        "Link yourself to an osu! profile via {link} to compare your score on the map",
+    ),
+    (
+        "cooldown",
+        "Command on cooldown, try again in {seconds} seconds",
+    ),
+    // General
+    ("ordinal", "{n}{suffix}"),
+    // Rank pp
+    (
+        "rank_pp.title.rank",
+        "How many pp is {username} missing to reach rank {rank}?",
+    ),
+    (
+        "rank_pp.title.holder",
+        "How many pp is {username} missing to reach {holder}'{genitiv} rank #{rank}?",
+    ),
+    (
+        "rank_pp.prefix.held",
+        "Rank {rank} is currently held by {holder} with **{pp}pp**",
+    ),
+    (
+        "rank_pp.prefix.holder",
+        "{holder} is rank {rank} with **{pp}pp**",
+    ),
+    (
+        "rank_pp.prefix.approx",
+        "Rank #{rank} currently requires approx. **{pp}pp**",
+    ),
+    (
+        "rank_pp.prefix.holder_over_10k",
+        "Reaching {holder}'{genitiv} rank #{rank} currently requires **{pp}pp**",
+    ),
+    ("rank_pp.reached", "{username} is already at rank #{rank}."),
+    (
+        "rank_pp.above",
+        "{prefix}, so {username} is already above that with **{pp}pp**.",
+    ),
+    (
+        "rank_pp.single",
+        "{prefix}, so {username} is missing **{missing}** raw pp, \
+        achievable with a single score worth **{pp}pp**.",
+    ),
+    (
+        "rank_pp.single_top",
+        "{prefix}, so {username} is missing **{missing}** raw pp, \
+        achievable with a single score worth **{pp}pp** which would be their {ordinal} top play.",
+    ),
+    (
+        "rank_pp.amount",
+        "{prefix}, so {username} is missing **{missing}** raw pp. \
+        To catch up with {amount} scores, each one must be worth **{pp}pp**, placing them {placement}.",
+    ),
+    ("rank_pp.placement", "at personal bests #{from} to #{to}"),
+    ("rank_pp.placement.outside", "outside of the top 200"),
+    (
+        "rank_pp.each.too_low",
+        "{prefix}, so {username} is missing **{missing}** raw pp.\n\
+        A new top200 score requires at least **{last_pp}pp** \
+        so {target} total pp can't be reached with {each}pp scores.",
+    ),
+    (
+        "rank_pp.each.single",
+        "{prefix}, so {username} is missing **{missing}** raw pp.\n\
+        To reach {target}pp with one additional score, {username} needs to perform a \
+        **{required}pp** score which would be their {approx}{ordinal} top play",
+    ),
+    (
+        "rank_pp.each.unreachable.one",
+        "{prefix}, so {username} is missing **{missing}** raw pp.\n\
+        Filling up {username}'{genitiv} top scores with {amount} new {each}pp score \
+        would only lead to {approx}**{top}pp** which is still less than {target}pp.",
+    ),
+    (
+        "rank_pp.each.unreachable.other",
+        "{prefix}, so {username} is missing **{missing}** raw pp.\n\
+        Filling up {username}'{genitiv} top scores with {amount} new {each}pp scores \
+        would only lead to {approx}**{top}pp** which is still less than {target}pp.",
+    ),
+    ("rank_pp.each.roughly", "roughly "),
+    (
+        "rank_pp.each.required.one",
+        "{prefix}, so {username} is missing **{missing}** raw pp.\n\
+        To reach {target}pp, {username} needs to perform **{n_each}** more {each}pp score \
+        and one **{required}pp** score.",
+    ),
+    (
+        "rank_pp.each.required.other",
+        "{prefix}, so {username} is missing **{missing}** raw pp.\n\
+        To reach {target}pp, {username} needs to perform **{n_each}** more {each}pp scores \
+        and one **{required}pp** score.",
+    ),
+    (
+        "rank_pp.exclude.one",
+        "Assuming {username}'s {exclude} weakest top play is replaced, \
+        their baseline is **{pp}pp**.",
+    ),
+    (
+        "rank_pp.exclude.other",
+        "Assuming {username}'s {exclude} weakest top plays are replaced, \
+        their baseline is **{pp}pp**.",
+    ),
+    (
+        "rank_pp.blacklisted",
+        "A {pp}pp play likely requires {blacklist}-level scores, which you've blacklisted.",
+    ),
+];
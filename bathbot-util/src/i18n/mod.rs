@@ -0,0 +1,267 @@
+//! Minimal localization of user-facing strings.
+//!
+//! Every language provides a static table that maps keys to templates.
+//! Templates may contain `{name}` placeholders which are substituted through
+//! [`tr!`](crate::tr), and `{{` or `}}` for literal braces. Keys that are
+//! missing in a language's table fall back to English.
+//!
+//! Adding a language only requires a new table module, a [`Lang`] variant
+//! pointing to it, and a choice for it in the option that selects languages.
+
+use std::fmt::{Display, Write};
+
+mod de;
+mod en;
+
+type Table = &'static [(&'static str, &'static str)];
+
+/// Language of user-facing strings.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[repr(u8)]
+pub enum Lang {
+    #[default]
+    En = 0,
+    De = 1,
+}
+
+impl Lang {
+    pub const ALL: [Self; 2] = [Self::En, Self::De];
+
+    /// Name of the language in the language itself.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::En => "English",
+            Self::De => "Deutsch",
+        }
+    }
+
+    const fn table(self) -> Table {
+        match self {
+            Self::En => en::STRINGS,
+            Self::De => de::STRINGS,
+        }
+    }
+
+    /// The template for the given key.
+    ///
+    /// Falls back to English if the key is not translated and to the key
+    /// itself if it's not even available in English.
+    pub fn template(self, key: &str) -> &str {
+        template_in(self.table(), key)
+    }
+}
+
+/// Generic errors that are shared by many commands.
+///
+/// Commands pass these explicitly so that the message can be translated
+/// through its key instead of its English text.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKey {
+    /// [`GENERAL_ISSUE`](crate::constants::GENERAL_ISSUE)
+    General,
+    /// [`OSU_API_ISSUE`](crate::constants::OSU_API_ISSUE)
+    OsuApi,
+}
+
+impl ErrorKey {
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::General => "error.general",
+            Self::OsuApi => "error.osu_api",
+        }
+    }
+}
+
+impl From<Lang> for i16 {
+    fn from(lang: Lang) -> Self {
+        lang as Self
+    }
+}
+
+impl TryFrom<i16> for Lang {
+    type Error = ();
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::En),
+            1 => Ok(Self::De),
+            _ => Err(()),
+        }
+    }
+}
+
+fn lookup(table: Table, key: &str) -> Option<&'static str> {
+    table
+        .iter()
+        .find_map(|(k, template)| (*k == key).then_some(*template))
+}
+
+fn template_in(table: Table, key: &str) -> &str {
+    lookup(table, key)
+        .or_else(|| lookup(en::STRINGS, key))
+        .unwrap_or(key)
+}
+
+/// Look up the key's template in the given language and substitute its
+/// placeholders.
+///
+/// Use [`tr!`](crate::tr) instead of calling this directly.
+pub fn translate(lang: Lang, key: &str, args: &[(&str, &dyn Display)]) -> String {
+    format_template(lang.template(key), args)
+}
+
+/// Substitute `{name}` placeholders of a template.
+///
+/// Placeholders without a matching argument are kept as they are.
+pub fn format_template(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut formatted = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(idx) = rest.find(['{', '}']) {
+        formatted.push_str(&rest[..idx]);
+        let brace = rest.as_bytes()[idx];
+        rest = &rest[idx + 1..];
+
+        // Escaped brace
+        if rest.as_bytes().first() == Some(&brace) {
+            formatted.push(brace as char);
+            rest = &rest[1..];
+
+            continue;
+        }
+
+        let placeholder = (brace == b'{')
+            .then(|| rest.split_once('}'))
+            .flatten()
+            .and_then(|(name, suffix)| {
+                args.iter()
+                    .find(|(arg, _)| *arg == name)
+                    .map(|(_, value)| (*value, suffix))
+            });
+
+        match placeholder {
+            Some((value, suffix)) => {
+                let _ = write!(formatted, "{value}");
+                rest = suffix;
+            }
+            None => formatted.push(brace as char),
+        }
+    }
+
+    formatted.push_str(rest);
+
+    formatted
+}
+
+/// Translate a key into the given [`Lang`](crate::i18n::Lang).
+///
+/// ```ignore
+/// let content = tr!("cooldown", lang, seconds = cooldown);
+/// ```
+#[macro_export]
+macro_rules! tr {
+    ($key:expr, $lang:expr $(,)?) => {
+        $crate::i18n::translate($lang, $key, &[])
+    };
+    ($key:expr, $lang:expr, $( $name:ident = $value:expr ),+ $(,)?) => {
+        $crate::i18n::translate(
+            $lang,
+            $key,
+            &[$( (stringify!($name), &$value as &dyn ::std::fmt::Display) ),+],
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{GENERAL_ISSUE, OSU_API_ISSUE};
+
+    fn placeholders(template: &str) -> Vec<&str> {
+        template
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}'))
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    #[test]
+    fn substitution() {
+        let formatted = format_template("{a} and {b}, {a}!", &[("a", &1), ("b", &"two")]);
+        assert_eq!(formatted, "1 and two, 1!");
+
+        let formatted = format_template("no placeholders", &[("a", &1)]);
+        assert_eq!(formatted, "no placeholders");
+
+        let formatted = format_template("{pp}pp", &[("pp", &1.5)]);
+        assert_eq!(formatted, "1.5pp");
+    }
+
+    #[test]
+    fn substitution_edge_cases() {
+        assert_eq!(format_template("{{a}} {a}", &[("a", &1)]), "{a} 1");
+        assert_eq!(format_template("{missing}", &[("a", &1)]), "{missing}");
+        assert_eq!(format_template("unclosed {a", &[("a", &1)]), "unclosed {a");
+        assert_eq!(format_template("stray } brace", &[]), "stray } brace");
+        assert_eq!(format_template("ü{a}ß", &[("a", &"ö")]), "üöß");
+    }
+
+    #[test]
+    fn macro_args() {
+        let cooldown = 3;
+
+        assert_eq!(
+            tr!("cooldown", Lang::En, seconds = cooldown),
+            "Command on cooldown, try again in 3 seconds"
+        );
+        assert_eq!(tr!(ErrorKey::General.key(), Lang::En), GENERAL_ISSUE);
+        assert_eq!(tr!(ErrorKey::OsuApi.key(), Lang::En), OSU_API_ISSUE);
+    }
+
+    #[test]
+    fn fallback() {
+        let partial: Table = &[("cooldown", "translated {seconds}")];
+
+        assert_eq!(template_in(partial, "cooldown"), "translated {seconds}");
+        assert_eq!(template_in(partial, "error.general"), GENERAL_ISSUE);
+        assert_eq!(template_in(&[], "error.osu_api"), OSU_API_ISSUE);
+        assert_eq!(template_in(partial, "unknown.key"), "unknown.key");
+
+        assert_ne!(Lang::De.template("cooldown"), Lang::En.template("cooldown"));
+    }
+
+    #[test]
+    fn tables_are_consistent() {
+        for lang in Lang::ALL {
+            let table = lang.table();
+
+            for (i, (key, template)) in table.iter().enumerate() {
+                assert!(
+                    table[..i].iter().all(|(other, _)| other != key),
+                    "duplicate key `{key}` for {lang:?}"
+                );
+
+                let Some(english) = lookup(en::STRINGS, key) else {
+                    panic!("key `{key}` of {lang:?} is missing in English");
+                };
+
+                for name in placeholders(template) {
+                    assert!(
+                        placeholders(english).contains(&name),
+                        "unknown placeholder `{name}` in `{key}` for {lang:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn i16_roundtrip() {
+        for lang in Lang::ALL {
+            assert_eq!(Lang::try_from(i16::from(lang)), Ok(lang));
+        }
+
+        assert!(Lang::try_from(-1).is_err());
+    }
+}
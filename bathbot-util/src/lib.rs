@@ -8,12 +8,17 @@ mod macros;
 mod matrix;
 mod metrics;
 mod mods_fmt;
+mod mods_parse;
+mod monotone_curve;
 mod msg_origin;
+mod seeded_rng;
 mod tourney_badges;
 
 pub mod constants;
 pub mod datetime;
 pub mod html;
+pub mod i18n;
+pub mod markdown;
 pub mod matcher;
 pub mod numbers;
 pub mod osu;
@@ -30,6 +35,9 @@ pub use self::{
     matrix::Matrix,
     metrics::MetricsReader,
     mods_fmt::ModsFormatter,
+    mods_parse::{ParsedMods, UnknownMod},
+    monotone_curve::MonotoneCurve,
     msg_origin::MessageOrigin,
+    seeded_rng::SeededRng,
     tourney_badges::TourneyBadges,
 };
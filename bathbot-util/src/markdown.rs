@@ -0,0 +1,181 @@
+use std::fmt::Write;
+
+/// Convert github flavored markdown into something that discord displays
+/// properly.
+///
+/// Headings become bold text, `*` and `+` bullets become `-`, lists longer
+/// than `max_list_items` are cut short, and consecutive empty lines are
+/// collapsed.
+pub fn discord_markdown(input: &str, max_list_items: usize) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut list_len = 0;
+    let mut blank = true;
+
+    for line in input.lines() {
+        let line = line.trim_end();
+        let trimmed = line.trim_start();
+
+        if trimmed.starts_with("<!--") && trimmed.ends_with("-->") {
+            continue;
+        }
+
+        if let Some(item) = list_item(trimmed) {
+            list_len += 1;
+
+            if list_len <= max_list_items {
+                let indent = &line[..line.len() - trimmed.len()];
+                let _ = writeln!(output, "{indent}{item}");
+            }
+
+            blank = false;
+
+            continue;
+        }
+
+        end_list(&mut output, &mut list_len, max_list_items);
+
+        if trimmed.is_empty() {
+            if !blank {
+                output.push('\n');
+                blank = true;
+            }
+
+            continue;
+        }
+
+        blank = false;
+
+        match heading(trimmed) {
+            Some("") => blank = true,
+            Some(heading) => {
+                let _ = writeln!(output, "**{heading}**");
+            }
+            None => {
+                output.push_str(line);
+                output.push('\n');
+            }
+        }
+    }
+
+    end_list(&mut output, &mut list_len, max_list_items);
+
+    let len = output.trim_end().len();
+    output.truncate(len);
+
+    output
+}
+
+/// Shorten the text to at most `max_len` bytes, preferably by omitting whole
+/// lines so that formatting stays intact.
+pub fn truncate_lines(text: &str, max_len: usize) -> String {
+    const ELLIPSIS: char = '…';
+
+    if text.len() <= max_len {
+        return text.to_owned();
+    }
+
+    let max_len = max_len.saturating_sub(ELLIPSIS.len_utf8());
+
+    let end = match text.as_bytes()[..=max_len]
+        .iter()
+        .rposition(|&byte| byte == b'\n')
+    {
+        Some(idx) => idx,
+        None => {
+            let mut end = max_len;
+
+            while !text.is_char_boundary(end) {
+                end -= 1;
+            }
+
+            end
+        }
+    };
+
+    let mut truncated = text[..end].trim_end().to_owned();
+    truncated.push(ELLIPSIS);
+
+    truncated
+}
+
+fn list_item(line: &str) -> Option<String> {
+    if let Some(item) = ["- ", "* ", "+ "]
+        .into_iter()
+        .find_map(|bullet| line.strip_prefix(bullet))
+    {
+        return Some(format!("- {}", item.trim_start()));
+    }
+
+    let digits = line.bytes().take_while(u8::is_ascii_digit).count();
+
+    (digits > 0 && line[digits..].starts_with(". ")).then(|| line.to_owned())
+}
+
+fn heading(line: &str) -> Option<&str> {
+    let level = line.bytes().take_while(|&byte| byte == b'#').count();
+
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+
+    let rest = &line[level..];
+
+    if !(rest.is_empty() || rest.starts_with(' ')) {
+        return None;
+    }
+
+    Some(rest.trim_end_matches('#').trim())
+}
+
+fn end_list(output: &mut String, list_len: &mut usize, max_list_items: usize) {
+    if *list_len > max_list_items {
+        let _ = writeln!(output, "- *…and {} more*", *list_len - max_list_items);
+    }
+
+    *list_len = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn headings_become_bold() {
+        let input = "# v3.2.0\n\n## Features ##\n#not a heading\n####### neither";
+
+        assert_eq!(
+            discord_markdown(input, 10),
+            "**v3.2.0**\n\n**Features**\n#not a heading\n####### neither"
+        );
+    }
+
+    #[test]
+    fn long_lists_collapse() {
+        let input = "* a\n+ b\n- c\n  - nested\n1. d\n\nafter";
+
+        assert_eq!(
+            discord_markdown(input, 3),
+            "- a\n- b\n- c\n- *…and 2 more*\n\nafter"
+        );
+        assert_eq!(
+            discord_markdown(input, 10),
+            "- a\n- b\n- c\n  - nested\n1. d\n\nafter"
+        );
+    }
+
+    #[test]
+    fn blank_lines_and_comments() {
+        let input = "\r\n<!-- template -->\nfirst\r\n\r\n\r\n\nsecond\n\n";
+
+        assert_eq!(discord_markdown(input, 10), "first\n\nsecond");
+    }
+
+    #[test]
+    fn truncate_at_line_end() {
+        let text = "**Features**\n- first\n- second";
+
+        assert_eq!(truncate_lines(text, 100), text);
+        assert_eq!(truncate_lines(text, 25), "**Features**\n- first…");
+        assert_eq!(truncate_lines("äöüäöü", 8), "äö…");
+    }
+}
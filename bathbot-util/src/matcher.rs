@@ -1,15 +1,13 @@
 use std::{borrow::Cow, sync::LazyLock};
 
 use regex::Regex;
-use rosu_v2::prelude::{
-    Acronym, GameModIntermode, GameMode, GameModsIntermode, UserId as OsuUserId,
-};
+use rosu_v2::prelude::{GameMode, UserId as OsuUserId};
 use twilight_model::id::{
     Id,
     marker::{RoleMarker, UserMarker},
 };
 
-use super::{CowUtils, osu::ModSelection};
+use super::{ParsedMods, osu::ModSelection};
 
 pub fn is_approved_skin_site(url: &str) -> bool {
     APPROVED_SKIN_SITE.is_match(url)
@@ -127,82 +125,57 @@ pub fn get_osu_match_id(msg: &str) -> Option<u32> {
 }
 
 pub fn get_mods(msg: &str) -> Option<ModSelection> {
-    if let Some(captures) = MOD_PLUS_MATCHER.captures(msg) {
-        let mods = GameModsIntermode::try_from_acronyms(captures.get(1)?.as_str())?;
+    try_get_mods(msg).and_then(Result::ok)
+}
 
-        Some(if msg.ends_with('!') {
-            ModSelection::Exact(mods)
-        } else {
-            ModSelection::Include(mods)
-        })
-    } else if let Some(captures) = MOD_MINUS_MATCHER.captures(msg) {
-        /// Manual `GameModsIntermode::try_from_acronyms` to account for `NM`
-        fn try_from_acronyms(s: &str) -> Option<ModSelection> {
-            fn split_prefix<const N: usize>(s: &str) -> (&str, &str) {
-                let end_idx = s
-                    .char_indices()
-                    .nth(N - 1)
-                    .map_or_else(|| s.len(), |(idx, c)| idx + c.len_utf8());
-
-                s.split_at(end_idx)
-            }
-
-            // There is no gamemod with an acronym of length one
-            if s.len() == 1 {
-                return None;
-            }
-
-            let uppercased = s.cow_to_ascii_uppercase();
-            let mut nomod = false;
-
-            let mut remaining = uppercased.as_ref();
-            let mut mods = GameModsIntermode::new();
-
-            while !remaining.is_empty() {
-                // Split off the first two characters and check if it's an acronym
-                let (candidate, rest) = split_prefix::<2>(remaining);
-
-                // SAFETY: `candidate` is guaranteed to be of length 2 and has been capitalized
-                let acronym = unsafe { Acronym::from_str_unchecked(candidate) };
-                let gamemod = GameModIntermode::from_acronym(acronym);
-
-                if !matches!(gamemod, GameModIntermode::Unknown(_)) && rest.len() != 1 {
-                    mods.insert(gamemod);
-                    remaining = rest;
-
-                    continue;
-                } else if candidate == "NM" {
-                    // This branch would be incorrect if there was a valid
-                    // acronym of length 3 that stars with "NM"
-                    nomod = true;
-                    remaining = rest;
-
-                    continue;
-                }
-
-                // Repeat for the first three characters
-                let (candidate, rest) = split_prefix::<3>(remaining);
-
-                // SAFETY: `candidate` is guaranteed to be of length 3 (or 2)
-                // and has been capitalized
-                let acronym = unsafe { Acronym::from_str_unchecked(candidate) };
-                let gamemod = GameModIntermode::from_acronym(acronym);
-
-                if matches!(gamemod, GameModIntermode::Unknown(_)) {
-                    return None;
-                }
-
-                mods.insert(gamemod);
-                remaining = rest;
-            }
-
-            Some(ModSelection::Exclude { mods, nomod })
-        }
-
-        try_from_acronyms(captures.get(1)?.as_str())
-    } else {
-        None
+/// Parses the `+mods`, `+mods!`, and `-mods!` syntax.
+///
+/// Returns `None` if the syntax does not match and an error describing
+/// unknown mods if the syntax matches but some mods could not be recognized.
+pub fn try_get_mods(msg: &str) -> Option<Result<ModSelection, String>> {
+    let msg = msg.trim();
+
+    let (include, rest) = match msg.strip_prefix('+') {
+        Some(rest) => (true, rest),
+        None => (false, msg.strip_prefix('-')?),
+    };
+
+    let (rest, exact) = match rest.strip_suffix('!') {
+        Some(rest) => (rest, true),
+        None => (rest, false),
+    };
+
+    // Excluding mods requires the `!` suffix
+    if !include && !exact {
+        return None;
+    }
+
+    let parsed = ParsedMods::parse(rest);
+
+    if let Some(content) = parsed.unknown_message() {
+        return Some(Err(content));
+    } else if !parsed.is_valid() {
+        return None;
     }
+
+    let ParsedMods { mods, nomod, .. } = parsed;
+
+    let selection = if !include {
+        ModSelection::Exclude { mods, nomod }
+    } else if exact {
+        ModSelection::Exact(mods)
+    } else {
+        ModSelection::Include(mods)
+    };
+
+    Some(Ok(selection))
+}
+
+/// Parses a page argument of the form `p7`.
+pub fn get_page(msg: &str) -> Option<usize> {
+    msg.strip_prefix(['p', 'P'])
+        .filter(|page| !page.is_empty() && page.bytes().all(|byte| byte.is_ascii_digit()))
+        .and_then(|page| page.parse().ok())
 }
 
 #[allow(dead_code)]
@@ -235,9 +208,6 @@ define_regex! {
 
     OSU_URL_MATCH_MATCHER: r"https://osu\.ppy\.sh/(?:community/matches|mp)/(\d+)";
 
-    MOD_PLUS_MATCHER: r"^\+(\w+)!?$";
-    MOD_MINUS_MATCHER: r"^-(\w+)!$";
-
     HIT_RESULTS_MATCHER: r".*\{(\d+/){2,}\d+}.*";
 
     EMOJI_MATCHER: r"<(a?):([^:\n]+):(\d+)>";
@@ -3,8 +3,10 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 use rosu_v2::model::mods::{
     GameMod, GameMods,
     generated_mods::{
-        DaycoreCatch, DaycoreMania, DaycoreOsu, DaycoreTaiko, DoubleTimeCatch, DoubleTimeMania,
-        DoubleTimeOsu, DoubleTimeTaiko, HalfTimeCatch, HalfTimeMania, HalfTimeOsu, HalfTimeTaiko,
+        DaycoreCatch, DaycoreMania, DaycoreOsu, DaycoreTaiko, DifficultyAdjustCatch,
+        DifficultyAdjustMania, DifficultyAdjustOsu, DifficultyAdjustTaiko, DoubleTimeCatch,
+        DoubleTimeMania, DoubleTimeOsu, DoubleTimeTaiko, FlashlightCatch, FlashlightMania,
+        FlashlightOsu, FlashlightTaiko, HalfTimeCatch, HalfTimeMania, HalfTimeOsu, HalfTimeTaiko,
         NightcoreCatch, NightcoreMania, NightcoreOsu, NightcoreTaiko,
     },
 };
@@ -12,17 +14,36 @@ use rosu_v2::model::mods::{
 pub struct ModsFormatter<'a> {
     mods: &'a GameMods,
     legacy_order: bool,
+    detailed: bool,
 }
 
 impl<'a> ModsFormatter<'a> {
     pub fn new(mods: &'a GameMods, legacy_order: bool) -> Self {
-        Self { mods, legacy_order }
+        Self {
+            mods,
+            legacy_order,
+            detailed: false,
+        }
+    }
+
+    /// Append settings that differ from the default e.g. `DT(1.3x)` or
+    /// `DA(AR10,OD9)`.
+    pub fn detailed(mut self, detailed: bool) -> Self {
+        self.detailed = detailed;
+
+        self
     }
 
     fn format_mods(&self, f: &mut Formatter<'_>) -> FmtResult {
         for gamemod in self.mods.iter() {
             f.write_str(gamemod.acronym().as_str())?;
 
+            if self.detailed {
+                write_settings(f, gamemod)?;
+
+                continue;
+            }
+
             match gamemod {
                 GameMod::HalfTimeOsu(HalfTimeOsu { speed_change, .. })
                 | GameMod::DaycoreOsu(DaycoreOsu { speed_change, .. })
@@ -41,7 +62,7 @@ impl<'a> ModsFormatter<'a> {
                 | GameMod::DoubleTimeMania(DoubleTimeMania { speed_change, .. })
                 | GameMod::NightcoreMania(NightcoreMania { speed_change, .. }) => {
                     if let Some(speed_change) = speed_change {
-                        write!(f, "({}x)", (*speed_change * 100.0).round() / 100.0)?
+                        write!(f, "({}x)", round(*speed_change))?
                     }
                 }
                 _ => {}
@@ -56,7 +77,11 @@ impl<'a> ModsFormatter<'a> {
         mods.sort_unstable_by_key(|m| m.bits());
 
         for m in mods {
-            f.write_str(m.acronym().as_str())?
+            f.write_str(m.acronym().as_str())?;
+
+            if self.detailed {
+                write_settings(f, m)?;
+            }
         }
 
         Ok(())
@@ -76,3 +101,334 @@ impl Display for ModsFormatter<'_> {
         }
     }
 }
+
+const DEFAULT_SLOWER_RATE: f64 = 0.75;
+const DEFAULT_FASTER_RATE: f64 = 1.5;
+const DEFAULT_FL_SIZE: f64 = 1.0;
+const DEFAULT_FL_FOLLOW_DELAY: f64 = 120.0;
+
+/// A single non-default setting of a mod.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum ModSetting {
+    Rate(f64),
+    CircleSize(f64),
+    ApproachRate(f64),
+    DrainRate(f64),
+    OverallDifficulty(f64),
+    ScrollSpeed(f64),
+    SizeMultiplier(f64),
+    FollowDelay(f64),
+}
+
+impl Display for ModSetting {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Rate(rate) => write!(f, "{}x", round(*rate)),
+            Self::CircleSize(cs) => write!(f, "CS{}", round(*cs)),
+            Self::ApproachRate(ar) => write!(f, "AR{}", round(*ar)),
+            Self::DrainRate(hp) => write!(f, "HP{}", round(*hp)),
+            Self::OverallDifficulty(od) => write!(f, "OD{}", round(*od)),
+            Self::ScrollSpeed(speed) => write!(f, "SV{}x", round(*speed)),
+            Self::SizeMultiplier(size) => write!(f, "{}x", round(*size)),
+            Self::FollowDelay(delay) => write!(f, "{}ms", round(*delay)),
+        }
+    }
+}
+
+fn write_settings(f: &mut Formatter<'_>, gamemod: &GameMod) -> FmtResult {
+    let settings = mod_settings(gamemod);
+    let mut iter = settings.iter();
+
+    let Some(first) = iter.next() else {
+        return Ok(());
+    };
+
+    write!(f, "({first}")?;
+
+    for setting in iter {
+        write!(f, ",{setting}")?;
+    }
+
+    f.write_str(")")
+}
+
+/// Settings of the mod that differ from their default.
+fn mod_settings(gamemod: &GameMod) -> Vec<ModSetting> {
+    let mut settings = Vec::new();
+
+    let mut push = |setting: Option<f64>, default: Option<f64>, f: fn(f64) -> ModSetting| {
+        if let Some(value) = setting
+            && default.is_none_or(|default| (value - default).abs() >= 0.005)
+        {
+            settings.push(f(value));
+        }
+    };
+
+    match gamemod {
+        GameMod::HalfTimeOsu(HalfTimeOsu { speed_change, .. })
+        | GameMod::DaycoreOsu(DaycoreOsu { speed_change, .. })
+        | GameMod::HalfTimeTaiko(HalfTimeTaiko { speed_change, .. })
+        | GameMod::DaycoreTaiko(DaycoreTaiko { speed_change, .. })
+        | GameMod::HalfTimeCatch(HalfTimeCatch { speed_change, .. })
+        | GameMod::DaycoreCatch(DaycoreCatch { speed_change, .. })
+        | GameMod::HalfTimeMania(HalfTimeMania { speed_change, .. })
+        | GameMod::DaycoreMania(DaycoreMania { speed_change, .. }) => {
+            push(*speed_change, Some(DEFAULT_SLOWER_RATE), ModSetting::Rate);
+        }
+        GameMod::DoubleTimeOsu(DoubleTimeOsu { speed_change, .. })
+        | GameMod::NightcoreOsu(NightcoreOsu { speed_change, .. })
+        | GameMod::DoubleTimeTaiko(DoubleTimeTaiko { speed_change, .. })
+        | GameMod::NightcoreTaiko(NightcoreTaiko { speed_change, .. })
+        | GameMod::DoubleTimeCatch(DoubleTimeCatch { speed_change, .. })
+        | GameMod::NightcoreCatch(NightcoreCatch { speed_change, .. })
+        | GameMod::DoubleTimeMania(DoubleTimeMania { speed_change, .. })
+        | GameMod::NightcoreMania(NightcoreMania { speed_change, .. }) => {
+            push(*speed_change, Some(DEFAULT_FASTER_RATE), ModSetting::Rate);
+        }
+        // Not using `..` so that new settings are noticed
+        GameMod::DifficultyAdjustOsu(DifficultyAdjustOsu {
+            circle_size,
+            approach_rate,
+            drain_rate,
+            overall_difficulty,
+            extended_limits: _,
+        }) => {
+            push(*approach_rate, None, ModSetting::ApproachRate);
+            push(*overall_difficulty, None, ModSetting::OverallDifficulty);
+            push(*circle_size, None, ModSetting::CircleSize);
+            push(*drain_rate, None, ModSetting::DrainRate);
+        }
+        GameMod::DifficultyAdjustTaiko(DifficultyAdjustTaiko {
+            scroll_speed,
+            drain_rate,
+            overall_difficulty,
+            extended_limits: _,
+        }) => {
+            push(*overall_difficulty, None, ModSetting::OverallDifficulty);
+            push(*drain_rate, None, ModSetting::DrainRate);
+            push(*scroll_speed, Some(1.0), ModSetting::ScrollSpeed);
+        }
+        GameMod::DifficultyAdjustCatch(DifficultyAdjustCatch {
+            circle_size,
+            approach_rate,
+            hard_rock_offsets: _,
+            drain_rate,
+            overall_difficulty,
+            extended_limits: _,
+        }) => {
+            push(*approach_rate, None, ModSetting::ApproachRate);
+            push(*overall_difficulty, None, ModSetting::OverallDifficulty);
+            push(*circle_size, None, ModSetting::CircleSize);
+            push(*drain_rate, None, ModSetting::DrainRate);
+        }
+        GameMod::DifficultyAdjustMania(DifficultyAdjustMania {
+            overall_difficulty,
+            drain_rate,
+            extended_limits: _,
+        }) => {
+            push(*overall_difficulty, None, ModSetting::OverallDifficulty);
+            push(*drain_rate, None, ModSetting::DrainRate);
+        }
+        GameMod::FlashlightOsu(FlashlightOsu {
+            follow_delay,
+            size_multiplier,
+            ..
+        }) => {
+            push(
+                *size_multiplier,
+                Some(DEFAULT_FL_SIZE),
+                ModSetting::SizeMultiplier,
+            );
+            push(
+                *follow_delay,
+                Some(DEFAULT_FL_FOLLOW_DELAY),
+                ModSetting::FollowDelay,
+            );
+        }
+        GameMod::FlashlightTaiko(FlashlightTaiko {
+            size_multiplier, ..
+        })
+        | GameMod::FlashlightCatch(FlashlightCatch {
+            size_multiplier, ..
+        })
+        | GameMod::FlashlightMania(FlashlightMania {
+            size_multiplier, ..
+        }) => {
+            push(
+                *size_multiplier,
+                Some(DEFAULT_FL_SIZE),
+                ModSetting::SizeMultiplier,
+            );
+        }
+        _ => {}
+    }
+
+    settings
+}
+
+fn round(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::model::GameMode;
+
+    use super::*;
+
+    fn detailed(mods: &GameMods) -> String {
+        ModsFormatter::new(mods, false).detailed(true).to_string()
+    }
+
+    fn single(acronym: &str, mode: GameMode, f: impl FnOnce(&mut GameMod)) -> GameMods {
+        let mut gamemod = GameMod::new(acronym, mode);
+        f(&mut gamemod);
+
+        [gamemod].into_iter().collect()
+    }
+
+    #[test]
+    fn rate_changes() {
+        let dt = single("DT", GameMode::Osu, |m| {
+            if let GameMod::DoubleTimeOsu(m) = m {
+                m.speed_change = Some(1.3);
+            }
+        });
+
+        assert_eq!(detailed(&dt), "DT(1.3x)");
+
+        let ht = single("HT", GameMode::Mania, |m| {
+            if let GameMod::HalfTimeMania(m) = m {
+                m.speed_change = Some(0.6);
+            }
+        });
+
+        assert_eq!(detailed(&ht), "HT(0.6x)");
+
+        let nc = single("NC", GameMode::Taiko, |m| {
+            if let GameMod::NightcoreTaiko(m) = m {
+                m.speed_change = Some(1.25);
+            }
+        });
+
+        assert_eq!(detailed(&nc), "NC(1.25x)");
+    }
+
+    #[test]
+    fn default_rate_is_suppressed() {
+        let dt = single("DT", GameMode::Osu, |m| {
+            if let GameMod::DoubleTimeOsu(m) = m {
+                m.speed_change = Some(1.5);
+            }
+        });
+
+        assert_eq!(detailed(&dt), "DT");
+
+        let dc = single("DC", GameMode::Catch, |m| {
+            if let GameMod::DaycoreCatch(m) = m {
+                m.speed_change = Some(0.75);
+            }
+        });
+
+        assert_eq!(detailed(&dc), "DC");
+
+        let unset = single("DT", GameMode::Osu, |_| {});
+        assert_eq!(detailed(&unset), "DT");
+
+        // Compact mode keeps showing explicit rates
+        assert_eq!(ModsFormatter::new(&dt, false).to_string(), "DT(1.5x)");
+    }
+
+    #[test]
+    fn difficulty_adjust() {
+        let osu = single("DA", GameMode::Osu, |m| {
+            if let GameMod::DifficultyAdjustOsu(m) = m {
+                m.approach_rate = Some(10.0);
+                m.overall_difficulty = Some(9.0);
+            }
+        });
+
+        assert_eq!(detailed(&osu), "DA(AR10,OD9)");
+
+        let taiko = single("DA", GameMode::Taiko, |m| {
+            if let GameMod::DifficultyAdjustTaiko(m) = m {
+                m.drain_rate = Some(4.5);
+                m.scroll_speed = Some(1.2);
+            }
+        });
+
+        assert_eq!(detailed(&taiko), "DA(HP4.5,SV1.2x)");
+
+        let catch = single("DA", GameMode::Catch, |m| {
+            if let GameMod::DifficultyAdjustCatch(m) = m {
+                m.circle_size = Some(5.0);
+                m.hard_rock_offsets = Some(true);
+            }
+        });
+
+        assert_eq!(detailed(&catch), "DA(CS5)");
+
+        let mania = single("DA", GameMode::Mania, |m| {
+            if let GameMod::DifficultyAdjustMania(m) = m {
+                m.overall_difficulty = Some(8.333);
+            }
+        });
+
+        assert_eq!(detailed(&mania), "DA(OD8.33)");
+
+        let unset = single("DA", GameMode::Osu, |_| {});
+        assert_eq!(detailed(&unset), "DA");
+    }
+
+    #[test]
+    fn flashlight() {
+        let size = single("FL", GameMode::Osu, |m| {
+            if let GameMod::FlashlightOsu(m) = m {
+                m.size_multiplier = Some(0.5);
+            }
+        });
+
+        assert_eq!(detailed(&size), "FL(0.5x)");
+
+        let delay = single("FL", GameMode::Osu, |m| {
+            if let GameMod::FlashlightOsu(m) = m {
+                m.follow_delay = Some(200.0);
+                m.size_multiplier = Some(1.0);
+            }
+        });
+
+        assert_eq!(detailed(&delay), "FL(200ms)");
+
+        let mania = single("FL", GameMode::Mania, |m| {
+            if let GameMod::FlashlightMania(m) = m {
+                m.size_multiplier = Some(1.5);
+            }
+        });
+
+        assert_eq!(detailed(&mania), "FL(1.5x)");
+    }
+
+    #[test]
+    fn combined_and_legacy_order() {
+        let mods: GameMods = [
+            GameMod::new("HD", GameMode::Osu),
+            GameMod::new("DT", GameMode::Osu),
+        ]
+        .into_iter()
+        .map(|mut m| {
+            if let GameMod::DoubleTimeOsu(ref mut m) = m {
+                m.speed_change = Some(1.3);
+            }
+
+            m
+        })
+        .collect();
+
+        assert!(detailed(&mods).contains("DT(1.3x)"));
+        assert_eq!(
+            ModsFormatter::new(&mods, true).detailed(true).to_string(),
+            "HDDT(1.3x)"
+        );
+        assert_eq!(detailed(&GameMods::new()), "NM");
+    }
+}
@@ -0,0 +1,448 @@
+use std::fmt::Write;
+
+use rosu_v2::prelude::{Acronym, GameModIntermode, GameModsIntermode};
+
+use crate::string_cmp::levenshtein_distance;
+
+/// Full mod names and their acronym.
+///
+/// Everything is lowercase and names contain no whitespace, underscores, or
+/// dashes.
+const MOD_NAMES: &[(&str, &str)] = &[
+    ("easy", "ez"),
+    ("nofail", "nf"),
+    ("halftime", "ht"),
+    ("daycore", "dc"),
+    ("hardrock", "hr"),
+    ("suddendeath", "sd"),
+    ("perfect", "pf"),
+    ("doubletime", "dt"),
+    ("nightcore", "nc"),
+    ("hidden", "hd"),
+    ("fadein", "fi"),
+    ("flashlight", "fl"),
+    ("blinds", "bl"),
+    ("stricttracking", "st"),
+    ("accuracychallenge", "ac"),
+    ("targetpractice", "tp"),
+    ("difficultyadjust", "da"),
+    ("classic", "cl"),
+    ("random", "rd"),
+    ("mirror", "mr"),
+    ("alternate", "al"),
+    ("singletap", "sg"),
+    ("autoplay", "at"),
+    ("cinema", "cn"),
+    ("relax", "rx"),
+    ("autopilot", "ap"),
+    ("spunout", "so"),
+    ("transform", "tr"),
+    ("wiggle", "wg"),
+    ("spinin", "si"),
+    ("grow", "gr"),
+    ("deflate", "df"),
+    ("windup", "wu"),
+    ("winddown", "wd"),
+    ("traceable", "tc"),
+    ("barrelroll", "br"),
+    ("approachdifferent", "ad"),
+    ("muted", "mu"),
+    ("noscope", "ns"),
+    ("magnetised", "mg"),
+    ("magnetized", "mg"),
+    ("repel", "rp"),
+    ("adaptivespeed", "as"),
+    ("freezeframe", "fr"),
+    ("bubbles", "bu"),
+    ("synesthesia", "sy"),
+    ("depth", "dp"),
+    ("bloom", "bm"),
+    ("touchdevice", "td"),
+    ("scorev2", "sv2"),
+    ("v2", "sv2"),
+    ("invert", "in"),
+    ("constantspeed", "cs"),
+    ("holdoff", "ho"),
+    ("dualstages", "ds"),
+    ("coop", "cp"),
+    ("swap", "sw"),
+    ("floatingfruits", "ff"),
+    ("cover", "co"),
+];
+
+/// Words that denote the absence of mods
+const NOMOD_NAMES: &[&str] = &["nm", "nomod", "nomods", "none"];
+
+/// Maximum amount of suggestions for an unknown token
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Mods parsed from user input that may contain full mod names, acronyms
+/// separated by whitespace or commas, or concatenated acronyms.
+///
+/// Prefixes and suffixes such as `+`, `-`, or `!` must be handled by the
+/// caller.
+#[derive(Debug, Default)]
+pub struct ParsedMods {
+    pub mods: GameModsIntermode,
+    /// Whether `nomod` or `nm` was specified
+    pub nomod: bool,
+    /// Tokens that could not be mapped to a mod
+    pub unknown: Vec<UnknownMod>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UnknownMod {
+    pub token: String,
+    /// Lowercase acronyms that are closest to the token
+    pub suggestions: Vec<&'static str>,
+}
+
+impl ParsedMods {
+    pub fn parse(input: &str) -> Self {
+        let tokens: Vec<_> = input
+            .split(|c: char| c.is_whitespace() || matches!(c, ',' | '+' | '/' | '|'))
+            .map(normalize)
+            .filter(|token| !token.is_empty())
+            .collect();
+
+        let mut parsed = Self::default();
+        let mut i = 0;
+
+        while i < tokens.len() {
+            let token = tokens[i].as_str();
+
+            if parsed.add_token(token) {
+                i += 1;
+
+                continue;
+            }
+
+            // Names might have been split by whitespace e.g. "hard rock"
+            if let Some(next) = tokens.get(i + 1) {
+                let joined = format!("{token}{next}");
+
+                if parsed.add_name(&joined) {
+                    i += 2;
+
+                    continue;
+                }
+            }
+
+            parsed.unknown.push(UnknownMod {
+                token: token.to_owned(),
+                suggestions: suggestions(token),
+            });
+
+            i += 1;
+        }
+
+        parsed
+    }
+
+    /// Whether all tokens were recognized and at least one mod or nomod was
+    /// specified.
+    pub fn is_valid(&self) -> bool {
+        self.unknown.is_empty() && (self.nomod || !self.mods.is_empty())
+    }
+
+    /// Describes all unknown tokens, e.g. "Unknown mod `hdr`, did you mean
+    /// `hr` or `hd`?"
+    pub fn unknown_message(&self) -> Option<String> {
+        if self.unknown.is_empty() {
+            return None;
+        }
+
+        let mut content = String::new();
+
+        for (i, unknown) in self.unknown.iter().enumerate() {
+            if i > 0 {
+                content.push('\n');
+            }
+
+            let _ = write!(content, "Unknown mod `{}`", unknown.token);
+
+            let len = unknown.suggestions.len();
+
+            for (j, suggestion) in unknown.suggestions.iter().enumerate() {
+                let sep = match j {
+                    0 => ", did you mean ",
+                    _ if j == len - 1 && len == 2 => " or ",
+                    _ if j == len - 1 => ", or ",
+                    _ => ", ",
+                };
+
+                let _ = write!(content, "{sep}`{suggestion}`");
+            }
+
+            if len > 0 {
+                content.push('?');
+            }
+        }
+
+        Some(content)
+    }
+
+    fn add_token(&mut self, token: &str) -> bool {
+        if self.add_name(token) {
+            return true;
+        }
+
+        let upper = token.to_ascii_uppercase();
+
+        if let Some(mods) = GameModsIntermode::try_from_acronyms(&upper) {
+            self.mods.extend(mods);
+
+            return true;
+        }
+
+        // Concatenated acronyms might contain `NM` at the start or end
+        let without_nomod = upper
+            .strip_prefix("NM")
+            .or_else(|| upper.strip_suffix("NM"))
+            .filter(|rest| !rest.is_empty());
+
+        match without_nomod.and_then(GameModsIntermode::try_from_acronyms) {
+            Some(mods) => {
+                self.mods.extend(mods);
+                self.nomod = true;
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn add_name(&mut self, name: &str) -> bool {
+        if NOMOD_NAMES.contains(&name) {
+            self.nomod = true;
+
+            return true;
+        }
+
+        if let Some(gamemod) = key_mod(name) {
+            self.mods.insert(gamemod);
+
+            return true;
+        }
+
+        let acronym = MOD_NAMES
+            .iter()
+            .find_map(|(mod_name, acronym)| (*mod_name == name).then_some(*acronym));
+
+        match acronym.map(|acronym| mod_from_acronym(&acronym.to_ascii_uppercase())) {
+            Some(gamemod) => {
+                self.mods.insert(gamemod);
+
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Lowercase the token and remove underscores and dashes.
+fn normalize(token: &str) -> String {
+    token
+        .chars()
+        .filter(|c| !matches!(c, '_' | '-'))
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Parses mania key mods such as "4key" or "7keys".
+fn key_mod(name: &str) -> Option<GameModIntermode> {
+    let count = name
+        .strip_suffix("keys")
+        .or_else(|| name.strip_suffix("key"))?;
+
+    let count: u8 = count.parse().ok().filter(|n| (1..=10).contains(n))?;
+    let acronym = format!("{count}K");
+
+    Some(mod_from_acronym(&acronym))
+}
+
+fn mod_from_acronym(acronym: &str) -> GameModIntermode {
+    // SAFETY: acronyms are uppercased and of length 2 or 3
+    let acronym = unsafe { Acronym::from_str_unchecked(acronym) };
+
+    GameModIntermode::from_acronym(acronym)
+}
+
+/// Acronyms whose name or acronym is closest to the token.
+fn suggestions(token: &str) -> Vec<&'static str> {
+    let threshold = if token.len() <= 3 { 1 } else { 2 };
+
+    let mut candidates: Vec<(usize, &'static str)> = MOD_NAMES
+        .iter()
+        .map(|(name, acronym)| {
+            let (acronym_dist, _) = levenshtein_distance(token, acronym);
+            let (name_dist, _) = levenshtein_distance(token, name);
+
+            (acronym_dist.min(name_dist), *acronym)
+        })
+        .filter(|(dist, _)| *dist <= threshold)
+        .collect();
+
+    // Stable sort to keep the order of `MOD_NAMES` for equal distances
+    candidates.sort_by_key(|(dist, _)| *dist);
+
+    let mut suggestions: Vec<&'static str> = Vec::with_capacity(MAX_SUGGESTIONS);
+
+    for (_, acronym) in candidates {
+        if !suggestions.contains(&acronym) {
+            suggestions.push(acronym);
+        }
+
+        if suggestions.len() == MAX_SUGGESTIONS {
+            break;
+        }
+    }
+
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::prelude::mods;
+
+    use super::*;
+
+    fn parse_valid(input: &str) -> GameModsIntermode {
+        let parsed = ParsedMods::parse(input);
+        assert!(parsed.is_valid(), "{input}: {parsed:?}");
+
+        parsed.mods
+    }
+
+    #[test]
+    fn concatenated_acronyms() {
+        assert_eq!(parse_valid("hdhr"), mods!(HD HR));
+        assert_eq!(parse_valid("HDDTHR"), mods!(HD DT HR));
+        assert_eq!(parse_valid("HdDt"), mods!(HD DT));
+    }
+
+    #[test]
+    fn separated_acronyms() {
+        assert_eq!(parse_valid("dt hr"), mods!(DT HR));
+        assert_eq!(parse_valid("  hd ,  dt  "), mods!(HD DT));
+        assert_eq!(parse_valid("hd,dt,hr"), mods!(HD DT HR));
+        assert_eq!(parse_valid("hd+dt"), mods!(HD DT));
+        assert_eq!(parse_valid("hd/fl"), mods!(HD FL));
+        assert_eq!(parse_valid("hddt fl"), mods!(HD DT FL));
+    }
+
+    #[test]
+    fn full_names() {
+        assert_eq!(parse_valid("hidden"), mods!(HD));
+        assert_eq!(parse_valid("Hidden DoubleTime"), mods!(HD DT));
+        assert_eq!(parse_valid("hard_rock"), mods!(HR));
+        assert_eq!(parse_valid("hard-rock"), mods!(HR));
+        assert_eq!(parse_valid("hard rock"), mods!(HR));
+        assert_eq!(parse_valid("double time, hidden"), mods!(DT HD));
+        assert_eq!(parse_valid("flashlight hr"), mods!(FL HR));
+        assert_eq!(parse_valid("magnetized"), mods!(MG));
+        assert_eq!(parse_valid("magnetised"), mods!(MG));
+        assert_eq!(parse_valid("score v2"), mods!(SV2));
+    }
+
+    #[test]
+    fn mania_keys() {
+        let keys = |acronym| GameModsIntermode::try_from_acronyms(acronym).unwrap();
+
+        assert_eq!(parse_valid("4k"), keys("4K"));
+        assert_eq!(parse_valid("7keys"), keys("7K"));
+        assert_eq!(parse_valid("10key"), keys("10K"));
+        assert!(!ParsedMods::parse("11keys").is_valid());
+    }
+
+    #[test]
+    fn nomod() {
+        for input in ["nm", "nomod", "NoMod", "no mod", "none"] {
+            let parsed = ParsedMods::parse(input);
+
+            assert!(parsed.is_valid(), "{input}");
+            assert!(parsed.nomod, "{input}");
+            assert!(parsed.mods.is_empty(), "{input}");
+        }
+    }
+
+    #[test]
+    fn concatenated_nomod() {
+        let parsed = ParsedMods::parse("nmhd");
+        assert!(parsed.is_valid());
+        assert!(parsed.nomod);
+        assert_eq!(parsed.mods, mods!(HD));
+
+        let parsed = ParsedMods::parse("HDDTNM");
+        assert!(parsed.is_valid());
+        assert!(parsed.nomod);
+        assert_eq!(parsed.mods, mods!(HD DT));
+    }
+
+    #[test]
+    fn empty_input() {
+        for input in ["", "   ", ",,", "+"] {
+            let parsed = ParsedMods::parse(input);
+
+            assert!(!parsed.is_valid(), "{input}");
+            assert!(parsed.unknown.is_empty(), "{input}");
+        }
+    }
+
+    #[test]
+    fn unknown_tokens() {
+        let parsed = ParsedMods::parse("hd hdr");
+
+        assert!(!parsed.is_valid());
+        assert_eq!(parsed.mods, mods!(HD));
+        assert_eq!(
+            parsed.unknown,
+            [UnknownMod {
+                token: "hdr".to_owned(),
+                suggestions: vec!["hr", "hd"],
+            }]
+        );
+    }
+
+    #[test]
+    fn misspelled_names() {
+        let parsed = ParsedMods::parse("hiddn");
+        assert_eq!(parsed.unknown[0].suggestions, ["hd"]);
+
+        let parsed = ParsedMods::parse("flashlght");
+        assert_eq!(parsed.unknown[0].suggestions, ["fl"]);
+
+        let parsed = ParsedMods::parse("qwertyuiop");
+        assert!(parsed.unknown[0].suggestions.is_empty());
+    }
+
+    #[test]
+    fn unknown_message() {
+        let parsed = ParsedMods::parse("hdr");
+
+        assert_eq!(
+            parsed.unknown_message().as_deref(),
+            Some("Unknown mod `hdr`, did you mean `hr` or `hd`?")
+        );
+
+        let parsed = ParsedMods::parse("qwertyuiop hiddn");
+
+        assert_eq!(
+            parsed.unknown_message().as_deref(),
+            Some("Unknown mod `qwertyuiop`\nUnknown mod `hiddn`, did you mean `hd`?")
+        );
+
+        assert!(ParsedMods::parse("hdhr").unknown_message().is_none());
+    }
+
+    #[test]
+    fn three_suggestions() {
+        let parsed = ParsedMods::parse("xd");
+        let suggestions = &parsed.unknown[0].suggestions;
+
+        assert_eq!(suggestions.len(), MAX_SUGGESTIONS);
+
+        let message = parsed.unknown_message().unwrap();
+        assert!(message.contains(", or "), "{message}");
+    }
+}
@@ -0,0 +1,235 @@
+/// Curve through a set of sample points that is interpolated with a monotone
+/// cubic Hermite spline (Fritsch-Carlson).
+///
+/// Between two samples the curve never overshoots, i.e. if the samples are
+/// monotone then so is the curve. Outside of the sample range the curve is
+/// extrapolated linearly along the first or last segment.
+#[derive(Clone, Debug)]
+pub struct MonotoneCurve {
+    xs: Box<[f64]>,
+    ys: Box<[f64]>,
+    tangents: Box<[f64]>,
+}
+
+impl MonotoneCurve {
+    /// Create a new curve from the given points.
+    ///
+    /// Returns `None` if there are no points, if x-values are not strictly
+    /// increasing, or if any value is not finite.
+    pub fn new(points: &[(f64, f64)]) -> Option<Self> {
+        let valid = !points.is_empty()
+            && points.iter().all(|(x, y)| x.is_finite() && y.is_finite())
+            && points.windows(2).all(|window| window[0].0 < window[1].0);
+
+        if !valid {
+            return None;
+        }
+
+        let xs: Box<[f64]> = points.iter().map(|(x, _)| *x).collect();
+        let ys: Box<[f64]> = points.iter().map(|(_, y)| *y).collect();
+
+        let secants: Vec<f64> = (0..xs.len().saturating_sub(1))
+            .map(|i| (ys[i + 1] - ys[i]) / (xs[i + 1] - xs[i]))
+            .collect();
+
+        let mut tangents = vec![0.0; xs.len()];
+
+        if let (Some(first), Some(last)) = (secants.first(), secants.last()) {
+            tangents[0] = *first;
+            tangents[xs.len() - 1] = *last;
+        }
+
+        for i in 1..secants.len() {
+            let (prev, next) = (secants[i - 1], secants[i]);
+
+            // Local extrema get a flat tangent
+            tangents[i] = if prev * next <= 0.0 {
+                0.0
+            } else {
+                (prev + next) / 2.0
+            };
+        }
+
+        // Restrict tangents so that no segment overshoots
+        for (i, secant) in secants.iter().copied().enumerate() {
+            if secant == 0.0 {
+                tangents[i] = 0.0;
+                tangents[i + 1] = 0.0;
+
+                continue;
+            }
+
+            let alpha = tangents[i] / secant;
+            let beta = tangents[i + 1] / secant;
+            let sum = alpha * alpha + beta * beta;
+
+            if sum > 9.0 {
+                let tau = 3.0 / sum.sqrt();
+                tangents[i] = tau * alpha * secant;
+                tangents[i + 1] = tau * beta * secant;
+            }
+        }
+
+        Some(Self {
+            xs,
+            ys,
+            tangents: tangents.into_boxed_slice(),
+        })
+    }
+
+    /// Evaluate the curve at the given x-value.
+    pub fn eval(&self, x: f64) -> f64 {
+        let last = self.xs.len() - 1;
+
+        if last == 0 {
+            return self.ys[0];
+        }
+
+        if x <= self.xs[0] {
+            return self.ys[0] + self.secant(0) * (x - self.xs[0]);
+        } else if x >= self.xs[last] {
+            return self.ys[last] + self.secant(last - 1) * (x - self.xs[last]);
+        }
+
+        // Index of the segment that contains x
+        let i = self.xs.partition_point(|&sample| sample <= x) - 1;
+
+        let h = self.xs[i + 1] - self.xs[i];
+        let t = (x - self.xs[i]) / h;
+        let t2 = t * t;
+        let t3 = t2 * t;
+
+        let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+        let h10 = t3 - 2.0 * t2 + t;
+        let h01 = -2.0 * t3 + 3.0 * t2;
+        let h11 = t3 - t2;
+
+        h00 * self.ys[i]
+            + h10 * h * self.tangents[i]
+            + h01 * self.ys[i + 1]
+            + h11 * h * self.tangents[i + 1]
+    }
+
+    /// The first and last x-value of the samples.
+    pub fn domain(&self) -> (f64, f64) {
+        (self.xs[0], self.xs[self.xs.len() - 1])
+    }
+
+    fn secant(&self, i: usize) -> f64 {
+        (self.ys[i + 1] - self.ys[i]) / (self.xs[i + 1] - self.xs[i])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    fn dense(curve: &MonotoneCurve, from: f64, to: f64) -> Vec<f64> {
+        (0..=1000)
+            .map(|i| curve.eval(from + (to - from) * i as f64 / 1000.0))
+            .collect()
+    }
+
+    #[test]
+    fn invalid_points() {
+        assert!(MonotoneCurve::new(&[]).is_none());
+        assert!(MonotoneCurve::new(&[(1.0, 1.0), (1.0, 2.0)]).is_none());
+        assert!(MonotoneCurve::new(&[(2.0, 1.0), (1.0, 2.0)]).is_none());
+        assert!(MonotoneCurve::new(&[(1.0, f64::NAN), (2.0, 2.0)]).is_none());
+    }
+
+    #[test]
+    fn single_point_is_constant() {
+        let curve = MonotoneCurve::new(&[(5.0, 42.0)]).unwrap();
+
+        assert_eq!(curve.eval(0.0), 42.0);
+        assert_eq!(curve.eval(100.0), 42.0);
+    }
+
+    #[test]
+    fn passes_through_samples() {
+        let points = [(0.0, 10.0), (1.0, 7.0), (2.5, 6.5), (4.0, 1.0), (7.0, 0.5)];
+        let curve = MonotoneCurve::new(&points).unwrap();
+
+        for (x, y) in points {
+            assert!((curve.eval(x) - y).abs() < EPSILON, "x={x}");
+        }
+    }
+
+    #[test]
+    fn linear_samples_stay_linear() {
+        let points: Vec<_> = (0..6).map(|i| (i as f64, 100.0 - 3.0 * i as f64)).collect();
+        let curve = MonotoneCurve::new(&points).unwrap();
+
+        for i in 0..=50 {
+            let x = i as f64 / 10.0;
+            assert!((curve.eval(x) - (100.0 - 3.0 * x)).abs() < EPSILON, "x={x}");
+        }
+    }
+
+    #[test]
+    fn monotone_without_overshoot() {
+        // Sharp drop that a regular cubic spline would overshoot on
+        let points = [
+            (0.0, 1000.0),
+            (1.0, 999.0),
+            (2.0, 998.0),
+            (3.0, 500.0),
+            (4.0, 499.0),
+            (5.0, 498.0),
+        ];
+        let curve = MonotoneCurve::new(&points).unwrap();
+        let values = dense(&curve, 0.0, 5.0);
+
+        assert!(values.windows(2).all(|w| w[1] <= w[0] + EPSILON));
+        assert!(
+            values
+                .iter()
+                .all(|&y| (498.0 - EPSILON..=1000.0 + EPSILON).contains(&y))
+        );
+
+        for window in points.windows(2) {
+            let [(x0, y0), (x1, y1)] = [window[0], window[1]];
+
+            for y in dense(&curve, x0, x1) {
+                assert!((y1 - EPSILON..=y0 + EPSILON).contains(&y));
+            }
+        }
+    }
+
+    #[test]
+    fn flat_segment_stays_flat() {
+        let points = [(0.0, 10.0), (1.0, 5.0), (2.0, 5.0), (3.0, 1.0)];
+        let curve = MonotoneCurve::new(&points).unwrap();
+
+        for y in dense(&curve, 1.0, 2.0) {
+            assert!((y - 5.0).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn approximates_smooth_curve() {
+        let f = |x: f64| 8000.0 * (-0.4 * x).exp();
+        let points: Vec<_> = (0..=10).map(|i| (i as f64, f(i as f64))).collect();
+        let curve = MonotoneCurve::new(&points).unwrap();
+
+        for i in 0..=100 {
+            let x = i as f64 / 10.0;
+            let rel_err = (curve.eval(x) - f(x)).abs() / f(x);
+            assert!(rel_err < 0.02, "x={x} rel_err={rel_err}");
+        }
+    }
+
+    #[test]
+    fn linear_extrapolation() {
+        let points = [(0.0, 10.0), (1.0, 6.0), (2.0, 4.0), (3.0, 3.0)];
+        let curve = MonotoneCurve::new(&points).unwrap();
+
+        assert!((curve.eval(5.0) - 1.0).abs() < EPSILON);
+        assert!((curve.eval(10.0) - -4.0).abs() < EPSILON);
+        assert!((curve.eval(-1.0) - 14.0).abs() < EPSILON);
+        assert_eq!(curve.domain(), (0.0, 3.0));
+    }
+}
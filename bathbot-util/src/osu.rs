@@ -5,12 +5,21 @@ use std::{
     slice::Iter,
 };
 
-use rosu_v2::prelude::{
-    GameMod, GameModIntermode, GameMode, GameMods, GameModsIntermode, Grade, LegacyScoreStatistics,
-    Score, ScoreStatistics,
+use rosu_v2::{
+    model::mods::generated_mods::{
+        DaycoreCatch, DaycoreMania, DaycoreOsu, DaycoreTaiko, DoubleTimeCatch, DoubleTimeMania,
+        DoubleTimeOsu, DoubleTimeTaiko, HalfTimeCatch, HalfTimeMania, HalfTimeOsu, HalfTimeTaiko,
+        NightcoreCatch, NightcoreMania, NightcoreOsu, NightcoreTaiko,
+    },
+    prelude::{
+        GameMod, GameModIntermode, GameMode, GameMods, GameModsIntermode, Grade,
+        LegacyScoreStatistics, RankStatus, Score, ScoreStatistics,
+    },
 };
 
-use crate::{constants::OSU_BASE, matcher, numbers::round};
+use crate::{ParsedMods, constants::OSU_BASE, matcher, numbers::round};
+
+pub mod derived_attributes;
 
 // <https://github.com/ppy/osu-queue-score-statistics/blob/45cd68bb1ec974ee433a9cb649e412a3376b130e/osu.Server.Queues.ScoreStatisticsProcessor/Processors/TotalScoreProcessor.cs#L91-L116>
 const TO_NEXT_LEVEL: [u64; 123] = [
@@ -201,7 +210,33 @@ impl ModSelection {
             return ModsResult::Mods(ModSelection::Exact(mods));
         };
 
-        matcher::get_mods(mods).map_or(ModsResult::Invalid, ModsResult::Mods)
+        if let Some(selection) = matcher::get_mods(mods) {
+            return ModsResult::Mods(selection);
+        }
+
+        // Prefixed mods must adhere to the syntax
+        if mods.starts_with(['+', '-']) {
+            return ModsResult::Invalid;
+        }
+
+        // Full mod names or separated acronyms without prefix
+        let parsed = ParsedMods::parse(mods);
+
+        if parsed.is_valid() {
+            ModsResult::Mods(ModSelection::Exact(parsed.mods))
+        } else {
+            ModsResult::Invalid
+        }
+    }
+
+    /// Describes the mods of the input that could not be recognized, e.g.
+    /// "Unknown mod `hdr`, did you mean `hr` or `hd`?"
+    pub fn unknown_mods_message(mods: &str) -> Option<String> {
+        match matcher::try_get_mods(mods) {
+            Some(Ok(_)) => None,
+            Some(Err(content)) => Some(content),
+            None => ParsedMods::parse(mods).unknown_message(),
+        }
     }
 
     /// Returns `true` if the score's mods coincide with this [`ModSelection`]
@@ -241,10 +276,10 @@ impl ModSelection {
     }
 
     pub fn filter_include(selection: &GameModsIntermode, mods: &GameMods) -> bool {
-        selection.iter().all(|gamemod| match gamemod {
-            Self::DT => mods.contains_intermode(Self::DT) || mods.contains_intermode(Self::NC),
-            Self::SD => mods.contains_intermode(Self::SD) || mods.contains_intermode(Self::PF),
-            _ => mods.contains_intermode(gamemod),
+        selection.iter().all(|gamemod| {
+            mods.iter()
+                .map(GameMod::intermode)
+                .any(|intermode| intermode == gamemod || Self::equivalent(intermode) == gamemod)
         })
     }
 
@@ -254,9 +289,7 @@ impl ModSelection {
             .filter(|m| {
                 let intermode = m.intermode();
 
-                !((intermode == Self::NC && selection.contains(Self::DT))
-                    || (intermode == Self::PF && selection.contains(Self::SD))
-                    || selection.contains(intermode))
+                !(selection.contains(intermode) || selection.contains(Self::equivalent(intermode)))
             })
             .count();
 
@@ -271,6 +304,24 @@ impl ModSelection {
         mods.iter().map(GameMod::intermode).eq(selection.iter())
     }
 
+    /// Convert the mods into their intermode counterpart while treating `NC`
+    /// as `DT` and `PF` as `SD`, sharing the equivalences of the filters.
+    pub fn merge_equivalent(mods: &GameMods) -> GameModsIntermode {
+        mods.iter()
+            .map(|gamemod| Self::equivalent(gamemod.intermode()))
+            .collect()
+    }
+
+    /// The mod that the given mod counts as when filtering i.e. `NC` counts
+    /// as `DT` and `PF` as `SD`.
+    fn equivalent(gamemod: GameModIntermode) -> GameModIntermode {
+        match gamemod {
+            Self::NC => Self::DT,
+            Self::PF => Self::SD,
+            gamemod => gamemod,
+        }
+    }
+
     /// Make sure included or exact mods don't exclude each other e.g. EZHR
     pub fn validate(self, mode: GameMode) -> Result<(), &'static str> {
         let mods = match self {
@@ -291,19 +342,58 @@ impl ModSelection {
     }
 }
 
+/// Subdivision pseudo-codes that osu! shows on some profiles and for which
+/// dedicated flags are available.
+const SUBDIVISION_FLAGS: [&str; 4] = ["GB-ENG", "GB-NIR", "GB-SCT", "GB-WLS"];
+
+/// Widths in which subdivision flags are available.
+const SUBDIVISION_FLAG_WIDTHS: [u32; 8] = [20, 40, 80, 160, 320, 640, 1280, 2560];
+
 pub fn flag_url(country_code: &str) -> String {
     // format!("{OSU_BASE}/images/flags/{country_code}.png") // from osu itself but
     // outdated
     flag_url_size(country_code, 256)
 }
 
+/// Also handles subdivision pseudo-codes such as `GB-SCT`.
+///
+/// If there is no flag for the subdivision, the parent country's flag is used.
 pub fn flag_url_size(country_code: &str, size: u32) -> String {
-    format!("https://osuflags.omkserver.nl/{country_code}-{size}.png") // kelderman
+    let Some((parent, _)) = country_code.split_once('-') else {
+        return format!("https://osuflags.omkserver.nl/{country_code}-{size}.png"); // kelderman
+    };
+
+    let subdivision = SUBDIVISION_FLAGS
+        .iter()
+        .find(|code| code.eq_ignore_ascii_case(country_code));
+
+    match subdivision {
+        Some(code) => {
+            let width = SUBDIVISION_FLAG_WIDTHS
+                .iter()
+                .copied()
+                .find(|&width| width >= size)
+                .unwrap_or(SUBDIVISION_FLAG_WIDTHS[SUBDIVISION_FLAG_WIDTHS.len() - 1]);
+
+            format!(
+                "https://flagcdn.com/w{width}/{code}.png",
+                code = code.to_ascii_lowercase()
+            )
+        }
+        None => flag_url_size(parent, size),
+    }
 }
 
+/// The svg flag of the country code's emoji as hosted by osu!.
+///
+/// Subdivisions have no such emoji so the parent country's flag is used.
 pub fn flag_url_svg(country_code: &str) -> String {
     const OFFSET: u32 = 0x1F1A5;
 
+    let country_code = country_code
+        .split_once('-')
+        .map_or(country_code, |(country_code, _)| country_code);
+
     let [byte0, byte1] = country_code.as_bytes() else {
         panic!("country code `{country_code}` is invalid");
     };
@@ -317,6 +407,74 @@ pub fn flag_url_svg(country_code: &str) -> String {
     url
 }
 
+/// Mods whose scores never award pp, regardless of the map.
+const UNRANKED_MODS: [GameModIntermode; 7] = [
+    GameModIntermode::Relax,
+    GameModIntermode::Autopilot,
+    GameModIntermode::Autoplay,
+    GameModIntermode::Cinema,
+    GameModIntermode::ScoreV2,
+    GameModIntermode::TargetPractice,
+    GameModIntermode::DifficultyAdjust,
+];
+
+/// Whether a score's pp value does *not* count towards the user's profile.
+///
+/// The api still provides pp for scores on loved maps or with unranked mods
+/// so those need to be identified manually. The score's `ranked` flag takes
+/// precedence if the api flagged it as unranked.
+///
+/// Returns `None` if the map status is unknown and neither the flag nor the
+/// mods decide it.
+pub fn is_unranked_pp(
+    status: Option<RankStatus>,
+    mods: &GameMods,
+    ranked: Option<bool>,
+) -> Option<bool> {
+    if ranked == Some(false) || !mods.iter().all(is_ranked_mod) {
+        return Some(true);
+    }
+
+    match status {
+        Some(status) => Some(!matches!(status, RankStatus::Ranked | RankStatus::Approved)),
+        None if ranked == Some(true) => Some(false),
+        None => None,
+    }
+}
+
+/// Whether the mod awards pp with its settings.
+///
+/// Rate changing mods are only ranked at their default rate.
+fn is_ranked_mod(gamemod: &GameMod) -> bool {
+    const DT_RATE: f64 = 1.5;
+    const HT_RATE: f64 = 0.75;
+
+    macro_rules! is_ranked_mod {
+        ( $( $dt:ident, $nc:ident, $ht:ident, $dc:ident; )* ) => {
+            match gamemod {
+                $(
+                    GameMod::$dt($dt { speed_change, .. })
+                    | GameMod::$nc($nc { speed_change, .. }) => {
+                        speed_change.is_none_or(|rate| rate == DT_RATE)
+                    }
+                    GameMod::$ht($ht { speed_change, .. })
+                    | GameMod::$dc($dc { speed_change, .. }) => {
+                        speed_change.is_none_or(|rate| rate == HT_RATE)
+                    }
+                )*
+                _ => !UNRANKED_MODS.contains(&gamemod.intermode()),
+            }
+        };
+    }
+
+    is_ranked_mod! {
+        DoubleTimeOsu, NightcoreOsu, HalfTimeOsu, DaycoreOsu;
+        DoubleTimeTaiko, NightcoreTaiko, HalfTimeTaiko, DaycoreTaiko;
+        DoubleTimeCatch, NightcoreCatch, HalfTimeCatch, DaycoreCatch;
+        DoubleTimeMania, NightcoreMania, HalfTimeMania, DaycoreMania;
+    }
+}
+
 pub trait ExtractablePp {
     fn extract_pp(&self) -> Vec<f32>;
 }
@@ -341,6 +499,218 @@ impl PpListUtil for [f32] {
     }
 }
 
+/// Recalculate a user's total pp after the pp values of their top scores
+/// changed while preserving the bonus pp contained in the original total.
+///
+/// Both `old_pps` and `new_pps` are expected to be sorted in descending order.
+/// The result is rounded to two decimal places.
+pub fn adjusted_total_pp(total_pp: f32, old_pps: &[f32], new_pps: &[f32]) -> f32 {
+    let bonus_pp = f64::from(total_pp) - old_pps.accum_weighted();
+    let adjusted = new_pps.accum_weighted() + bonus_pp;
+
+    ((100.0 * adjusted).round() / 100.0) as f32
+}
+
+/// Recalculate a user's total pp after replacing the pp values of some of
+/// their top scores while preserving the bonus pp contained in the original
+/// total.
+///
+/// `pps` is expected to be sorted in descending order and `replacements`
+/// contains indices into `pps` alongside their new pp value. The values are
+/// re-sorted before weighting them.
+pub fn total_pp_with_replacements(
+    total_pp: f32,
+    pps: &[f32],
+    replacements: &[(usize, f32)],
+) -> f32 {
+    let mut new_pps = pps.to_owned();
+
+    for &(idx, pp) in replacements {
+        if let Some(old_pp) = new_pps.get_mut(idx) {
+            *old_pp = pp;
+        }
+    }
+
+    new_pps.sort_unstable_by(|a, b| b.total_cmp(a));
+
+    adjusted_total_pp(total_pp, pps, &new_pps)
+}
+
+/// Recalculate a user's total pp as if their `exclude` lowest top scores did
+/// not exist while preserving the bonus pp contained in the original total.
+///
+/// `pps` is expected to be sorted in descending order and `exclude` is
+/// clamped to its length.
+pub fn total_pp_without_bottom(total_pp: f32, pps: &[f32], exclude: usize) -> f32 {
+    let kept = pps.len().saturating_sub(exclude);
+
+    adjusted_total_pp(total_pp, pps, &pps[..kept])
+}
+
+/// Factor by which a required score has to exceed a user's best play without
+/// blacklisted mods to assume that it requires those mods.
+const BLACKLIST_PP_FACTOR: f32 = 1.3;
+
+/// Whether a single score worth `required_pp` likely requires mods of the
+/// blacklist, i.e. whether it exceeds the user's best play without any of
+/// those mods by a large factor.
+///
+/// `scores` yields the pp and mods of the user's top scores.
+pub fn requires_blacklisted_mods<'m, I>(
+    required_pp: f32,
+    scores: I,
+    blacklist: &GameModsIntermode,
+) -> bool
+where
+    I: IntoIterator<Item = (f32, &'m GameMods)>,
+{
+    if blacklist.is_empty() {
+        return false;
+    }
+
+    let mut any_scores = false;
+
+    let best_allowed = scores
+        .into_iter()
+        .inspect(|_| any_scores = true)
+        .filter(|(_, mods)| !mods.contains_any(blacklist.iter()))
+        .fold(0.0, |best, (pp, _)| f32::max(best, pp));
+
+    any_scores && required_pp > BLACKLIST_PP_FACTOR * best_allowed
+}
+
+/// Minimum amount of top plays to suggest what a pp value corresponds to.
+const SUGGESTION_MIN_PLAYS: usize = 10;
+
+/// Plays whose pp are within this relative distance of the target pp are
+/// considered similar.
+const SUGGESTION_PP_MARGIN: f32 = 0.1;
+
+/// What a play worth some pp roughly looks like for a user.
+#[derive(Debug, PartialEq)]
+pub struct PlaySuggestion {
+    pub stars: f32,
+    /// Most common mods, `NC` and `PF` are merged into `DT` and `SD`.
+    pub mods: GameModsIntermode,
+    /// Whether the stars were extrapolated because no play came close to
+    /// the target pp.
+    pub extrapolated: bool,
+}
+
+/// Estimate the star rating and mods of a play worth `target_pp` based on the
+/// user's top plays.
+///
+/// `plays` yields the pp, star rating, and mods of the user's top plays. Uses
+/// the median star rating of plays within 10% of the target pp, or
+/// extrapolates linearly from all plays if there are none. Returns `None` if
+/// there are fewer than 10 plays or no trend can be determined.
+pub fn suggest_play<'m, I>(target_pp: f32, plays: I) -> Option<PlaySuggestion>
+where
+    I: IntoIterator<Item = (f32, f32, &'m GameMods)>,
+{
+    let plays: Vec<_> = plays.into_iter().collect();
+
+    if plays.len() < SUGGESTION_MIN_PLAYS {
+        return None;
+    }
+
+    let margin = target_pp * SUGGESTION_PP_MARGIN;
+
+    let mut similar: Vec<_> = plays
+        .iter()
+        .filter(|(pp, ..)| (pp - target_pp).abs() <= margin)
+        .collect();
+
+    if !similar.is_empty() {
+        similar.sort_unstable_by(|(.., a_stars, _), (.., b_stars, _)| a_stars.total_cmp(b_stars));
+
+        let mid = similar.len() / 2;
+
+        let stars = if similar.len() % 2 == 0 {
+            (similar[mid - 1].1 + similar[mid].1) / 2.0
+        } else {
+            similar[mid].1
+        };
+
+        return Some(PlaySuggestion {
+            stars,
+            mods: most_common_mods(similar.iter().map(|(_, _, mods)| *mods))?,
+            extrapolated: false,
+        });
+    }
+
+    let stars = extrapolate_stars(target_pp, &plays)?;
+
+    // Without similar plays, the mods of the plays closest in pp are the best
+    // guess
+    let mut closest: Vec<_> = plays.iter().collect();
+    closest.sort_unstable_by(|(a, ..), (b, ..)| {
+        (a - target_pp).abs().total_cmp(&(b - target_pp).abs())
+    });
+
+    let mods = closest
+        .into_iter()
+        .take(SUGGESTION_MIN_PLAYS)
+        .map(|(_, _, mods)| *mods);
+
+    Some(PlaySuggestion {
+        stars,
+        mods: most_common_mods(mods)?,
+        extrapolated: true,
+    })
+}
+
+/// Least squares fit of the star rating over pp, evaluated at `target_pp`.
+fn extrapolate_stars(target_pp: f32, plays: &[(f32, f32, &GameMods)]) -> Option<f32> {
+    let len = plays.len() as f64;
+
+    let mean_pp = plays.iter().map(|(pp, ..)| *pp as f64).sum::<f64>() / len;
+    let mean_stars = plays.iter().map(|(_, stars, _)| *stars as f64).sum::<f64>() / len;
+
+    let (covariance, variance) =
+        plays
+            .iter()
+            .fold((0.0, 0.0), |(covariance, variance), (pp, stars, _)| {
+                let pp_delta = *pp as f64 - mean_pp;
+                let stars_delta = *stars as f64 - mean_stars;
+
+                (
+                    covariance + pp_delta * stars_delta,
+                    variance + pp_delta * pp_delta,
+                )
+            });
+
+    if variance <= f64::EPSILON {
+        return None;
+    }
+
+    let slope = covariance / variance;
+    let stars = mean_stars + slope * (target_pp as f64 - mean_pp);
+
+    (stars > 0.0).then_some(stars as f32)
+}
+
+/// The most common mod combination, ties are resolved by first occurrence.
+fn most_common_mods<'m>(mods: impl Iterator<Item = &'m GameMods>) -> Option<GameModsIntermode> {
+    let mut counts: Vec<(GameModsIntermode, usize)> = Vec::new();
+
+    for mods in mods {
+        let mods = ModSelection::merge_equivalent(mods);
+
+        match counts.iter_mut().find(|(entry, _)| *entry == mods) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((mods, 1)),
+        }
+    }
+
+    // `max_by_key` would prefer the last maximum
+    counts
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, count)| *count)
+        .map(|(mods, _)| mods)
+}
+
 pub trait IntoPpIter {
     type Inner: Iterator<Item = f32> + DoubleEndedIterator + ExactSizeIterator;
 
@@ -570,6 +940,173 @@ pub enum AttributeKind {
     Od,
 }
 
+/// A hitresult whose count can be extracted from [`ScoreStatistics`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum HitResultKind {
+    Perfect,
+    Great,
+    Good,
+    Ok,
+    Meh,
+    Miss,
+    LargeTickHit,
+    SmallTickHit,
+    SmallTickMiss,
+}
+
+impl HitResultKind {
+    /// Count of this hitresult within the statistics.
+    pub const fn value(self, stats: &ScoreStatistics) -> u32 {
+        match self {
+            Self::Perfect => stats.perfect,
+            Self::Great => stats.great,
+            Self::Good => stats.good,
+            Self::Ok => stats.ok,
+            Self::Meh => stats.meh,
+            Self::Miss => stats.miss,
+            Self::LargeTickHit => stats.large_tick_hit,
+            Self::SmallTickHit => stats.small_tick_hit,
+            Self::SmallTickMiss => stats.small_tick_miss,
+        }
+    }
+
+    /// Whether scores of the given mode can contain this hitresult.
+    pub const fn is_valid_for(self, mode: GameMode) -> bool {
+        match self {
+            Self::Great | Self::Miss => true,
+            Self::Perfect | Self::Good => matches!(mode, GameMode::Mania),
+            Self::Ok => !matches!(mode, GameMode::Catch),
+            Self::Meh => matches!(mode, GameMode::Osu | GameMode::Mania),
+            Self::LargeTickHit | Self::SmallTickHit | Self::SmallTickMiss => {
+                matches!(mode, GameMode::Catch)
+            }
+        }
+    }
+
+    /// The name of this hitresult as players of the given mode know it.
+    pub const fn name(self, mode: GameMode) -> &'static str {
+        match (self, mode) {
+            (Self::Perfect, _) => "320",
+            (Self::Great, GameMode::Catch) => "Fruits",
+            (Self::Great, _) => "300",
+            (Self::Good, _) => "200",
+            (Self::Ok, _) => "100",
+            (Self::Meh, _) => "50",
+            (Self::Miss, _) => "Miss",
+            (Self::LargeTickHit, _) => "Droplets",
+            (Self::SmallTickHit, _) => "Droplets hit",
+            (Self::SmallTickMiss, _) => "Droplets missed",
+        }
+    }
+
+    /// The hitresults whose ratio is compared by default for the mode.
+    pub const fn default_ratio(mode: GameMode) -> (Self, Self) {
+        match mode {
+            GameMode::Osu | GameMode::Taiko => (Self::Great, Self::Ok),
+            GameMode::Catch => (Self::SmallTickHit, Self::SmallTickMiss),
+            GameMode::Mania => (Self::Perfect, Self::Great),
+        }
+    }
+}
+
+/// Ratio between two hitresult counts.
+///
+/// Returns `None` if both counts are zero and `f32::INFINITY` if only the
+/// denominator is zero.
+pub fn hitresult_ratio(numerator: u32, denominator: u32) -> Option<f32> {
+    match (numerator, denominator) {
+        (0, 0) => None,
+        (_, 0) => Some(f32::INFINITY),
+        (n, d) => Some(n as f32 / d as f32),
+    }
+}
+
+/// Hitresult counts of a catch score.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct CatchHitresults {
+    pub fruits: u32,
+    /// Large ticks that were caught
+    pub droplets: u32,
+    /// Small ticks that were caught
+    pub tiny_droplets: u32,
+    /// Small ticks that were missed
+    pub tiny_droplet_misses: u32,
+    /// Missed fruits and large ticks
+    pub misses: u32,
+}
+
+impl CatchHitresults {
+    /// Extract the hitresults from [`ScoreStatistics`].
+    ///
+    /// Lazer statistics store ticks in their own fields whereas statistics
+    /// converted from legacy scores put them into `ok`, `meh`, and `good`.
+    pub fn new(stats: &ScoreStatistics) -> Self {
+        let is_lazer = stats.large_tick_hit > 0
+            || stats.large_tick_miss > 0
+            || stats.small_tick_hit > 0
+            || stats.small_tick_miss > 0;
+
+        if is_lazer {
+            Self {
+                fruits: stats.great,
+                droplets: stats.large_tick_hit,
+                tiny_droplets: stats.small_tick_hit,
+                tiny_droplet_misses: stats.small_tick_miss,
+                misses: stats.miss + stats.large_tick_miss,
+            }
+        } else {
+            Self::from_legacy(stats)
+        }
+    }
+
+    /// Extract the hitresults from legacy statistics.
+    pub fn from_legacy(stats: &(impl LegacyStatistics + ?Sized)) -> Self {
+        Self {
+            fruits: stats.n300(),
+            droplets: stats.n100(),
+            tiny_droplets: stats.n50(),
+            tiny_droplet_misses: stats.n_katu(),
+            misses: stats.n_miss(),
+        }
+    }
+
+    pub const fn total_hits(&self) -> u32 {
+        self.fruits + self.droplets + self.tiny_droplets
+    }
+
+    pub const fn total(&self) -> u32 {
+        self.total_hits() + self.tiny_droplet_misses + self.misses
+    }
+
+    /// Accuracy as percentage i.e. between 0.0 and 100.0
+    pub fn accuracy(&self) -> f32 {
+        accuracy_percent(self.total_hits(), self.total())
+    }
+}
+
+fn accuracy_percent(hits: u32, total: u32) -> f32 {
+    if total == 0 {
+        return 100.0;
+    }
+
+    (100.0 * f64::from(hits) / f64::from(total)) as f32
+}
+
+/// Accuracy of the statistics as percentage.
+///
+/// If `max_stats` are unavailable, the legacy accuracy is calculated instead.
+pub fn calculate_accuracy(
+    mode: GameMode,
+    stats: &ScoreStatistics,
+    max_stats: Option<&ScoreStatistics>,
+) -> f32 {
+    match (mode, max_stats) {
+        (GameMode::Catch, _) => CatchHitresults::new(stats).accuracy(),
+        (_, Some(max_stats)) => stats.accuracy(mode, max_stats),
+        (_, None) => stats.legacy_accuracy(mode),
+    }
+}
+
 pub trait GradeGameMods {
     fn hd(&self) -> bool;
     fn fl(&self) -> bool;
@@ -635,7 +1172,7 @@ pub fn calculate_grade(
     match mode {
         GameMode::Osu => osu_grade(mods, stats, max_stats),
         GameMode::Taiko => taiko_grade(mods, stats, max_stats),
-        GameMode::Catch => catch_grade_legacy(mods, stats),
+        GameMode::Catch => catch_grade(mods, stats, max_stats),
         GameMode::Mania => mania_grade_legacy(mods, stats, stats.total_hits(GameMode::Mania)),
     }
 }
@@ -731,6 +1268,10 @@ pub trait LegacyStatistics {
     fn n_miss(&self) -> u32;
     fn passed_objects(&self, mode: GameMode) -> u32;
     fn accuracy(&self, mode: GameMode) -> f32;
+
+    fn catch_hitresults(&self) -> CatchHitresults {
+        CatchHitresults::from_legacy(self)
+    }
 }
 
 #[rustfmt::skip]
@@ -743,6 +1284,7 @@ impl LegacyStatistics for ScoreStatistics {
     fn n_miss(&self) -> u32 { self.miss }
     fn passed_objects(&self, mode: GameMode) -> u32 { self.total_hits(mode) }
     fn accuracy(&self, mode: GameMode) -> f32 { self.legacy_accuracy(mode) }
+    fn catch_hitresults(&self) -> CatchHitresults { CatchHitresults::new(self) }
 }
 
 #[rustfmt::skip]
@@ -822,8 +1364,44 @@ fn taiko_grade_legacy(
     }
 }
 
+fn catch_grade(
+    mods: GradeGameModsData,
+    stats: &ScoreStatistics,
+    max_stats: &ScoreStatistics,
+) -> Grade {
+    let hitresults = CatchHitresults::new(stats);
+    let max_hitresults = CatchHitresults::new(max_stats);
+
+    // Failed scores did not get to judge all objects so the total is based on
+    // the maximum statistics if available
+    let total = cmp::max(hitresults.total(), max_hitresults.total_hits());
+    let acc = accuracy_percent(hitresults.total_hits(), total);
+
+    if hitresults.total_hits() == total {
+        if mods.hd || mods.fl {
+            Grade::XH
+        } else {
+            Grade::X
+        }
+    } else if acc >= 98.0 {
+        if mods.hd || mods.fl {
+            Grade::SH
+        } else {
+            Grade::S
+        }
+    } else if acc >= 94.0 {
+        Grade::A
+    } else if acc >= 90.0 {
+        Grade::B
+    } else if acc >= 85.0 {
+        Grade::C
+    } else {
+        Grade::D
+    }
+}
+
 fn catch_grade_legacy(mods: GradeGameModsData, stats: &impl LegacyStatistics) -> Grade {
-    let acc = f64::from(stats.accuracy(GameMode::Catch));
+    let acc = f64::from(stats.catch_hitresults().accuracy());
 
     if (100.0 - acc).abs() <= f64::EPSILON {
         if mods.hd || mods.fl {
@@ -884,6 +1462,129 @@ fn mania_grade_legacy(
 mod tests {
     use super::*;
 
+    fn mods(acronyms: &[&str], mode: GameMode) -> GameMods {
+        acronyms
+            .iter()
+            .map(|acronym| GameMod::new(acronym, mode))
+            .collect()
+    }
+
+    #[test]
+    fn unranked_pp_map_status() {
+        let nomod = GameMods::new();
+
+        let unranked = |status| is_unranked_pp(Some(status), &nomod, None);
+
+        assert_eq!(
+            is_unranked_pp(Some(RankStatus::Ranked), &nomod, Some(true)),
+            Some(false)
+        );
+        assert_eq!(unranked(RankStatus::Approved), Some(false));
+        assert_eq!(unranked(RankStatus::Loved), Some(true));
+        assert_eq!(unranked(RankStatus::Qualified), Some(true));
+        assert_eq!(unranked(RankStatus::Graveyard), Some(true));
+
+        // The api's flag takes precedence
+        assert_eq!(
+            is_unranked_pp(Some(RankStatus::Ranked), &nomod, Some(false)),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn unranked_pp_unknown_status() {
+        let nomod = GameMods::new();
+
+        assert_eq!(is_unranked_pp(None, &nomod, None), None);
+        assert_eq!(is_unranked_pp(None, &nomod, Some(true)), Some(false));
+        assert_eq!(is_unranked_pp(None, &nomod, Some(false)), Some(true));
+        assert_eq!(
+            is_unranked_pp(None, &mods(&["RX"], GameMode::Osu), None),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn unranked_pp_mods() {
+        let ranked = |acronyms: &[&str], mode| {
+            is_unranked_pp(Some(RankStatus::Ranked), &mods(acronyms, mode), None) == Some(false)
+        };
+
+        assert!(ranked(&["HD", "DT", "HR"], GameMode::Osu));
+        assert!(ranked(&["EZ", "FL", "CL"], GameMode::Catch));
+        assert!(ranked(&["4K", "FI"], GameMode::Mania));
+
+        assert!(!ranked(&["RX"], GameMode::Osu));
+        assert!(!ranked(&["HD", "RX"], GameMode::Taiko));
+        assert!(!ranked(&["RX"], GameMode::Catch));
+        assert!(!ranked(&["AP", "DT"], GameMode::Osu));
+        assert!(!ranked(&["HD", "SV2"], GameMode::Osu));
+        assert!(!ranked(&["SV2"], GameMode::Mania));
+        assert!(!ranked(&["AT"], GameMode::Osu));
+        assert!(!ranked(&["TP"], GameMode::Osu));
+        assert!(!ranked(&["DA"], GameMode::Osu));
+    }
+
+    #[test]
+    fn unranked_pp_mod_settings() {
+        let dt = |speed_change| -> GameMods {
+            [GameMod::DoubleTimeOsu(DoubleTimeOsu {
+                speed_change,
+                ..Default::default()
+            })]
+            .into_iter()
+            .collect()
+        };
+
+        let unranked = |mods: &GameMods| is_unranked_pp(Some(RankStatus::Ranked), mods, None);
+
+        assert_eq!(unranked(&dt(None)), Some(false));
+        assert_eq!(unranked(&dt(Some(1.5))), Some(false));
+        assert_eq!(unranked(&dt(Some(1.2))), Some(true));
+
+        let dc: GameMods = [GameMod::DaycoreMania(DaycoreMania {
+            speed_change: Some(0.6),
+            ..Default::default()
+        })]
+        .into_iter()
+        .collect();
+
+        assert_eq!(unranked(&dc), Some(true));
+    }
+
+    #[test]
+    fn flag_url_country() {
+        assert_eq!(flag_url("DE"), "https://osuflags.omkserver.nl/DE-256.png");
+        assert_eq!(
+            flag_url_svg("de"),
+            format!("{OSU_BASE}assets/images/flags/1f1e9-1f1ea.svg")
+        );
+    }
+
+    #[test]
+    fn flag_url_subdivision() {
+        assert_eq!(flag_url("GB-SCT"), "https://flagcdn.com/w320/gb-sct.png");
+        assert_eq!(
+            flag_url_size("gb-wls", 20),
+            "https://flagcdn.com/w20/gb-wls.png"
+        );
+        assert_eq!(
+            flag_url_size("GB-ENG", 5000),
+            "https://flagcdn.com/w2560/gb-eng.png"
+        );
+
+        // Unknown subdivisions fall back to the parent country
+        assert_eq!(
+            flag_url("ES-CT"),
+            "https://osuflags.omkserver.nl/ES-256.png"
+        );
+    }
+
+    #[test]
+    fn flag_url_svg_subdivision_falls_back() {
+        assert_eq!(flag_url_svg("GB-SCT"), flag_url_svg("GB"));
+    }
+
     #[test]
     fn mod_selection_filter_exclude() {
         let hdnc: GameMods = [
@@ -907,4 +1608,482 @@ mod tests {
         assert!(!ModSelection::filter_exclude(&selection, false, &hdnc)); // -hddt!
         assert!(!ModSelection::filter_exclude(&selection, true, &hdnc)); // -hddtnm!
     }
+
+    #[test]
+    fn mod_selection_merge_equivalent() {
+        let merged = ModSelection::merge_equivalent(&mods(&["HD", "NC", "PF"], GameMode::Osu));
+        let expected: GameModsIntermode = [
+            GameModIntermode::Hidden,
+            GameModIntermode::DoubleTime,
+            GameModIntermode::SuddenDeath,
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(merged, expected);
+    }
+
+    #[test]
+    fn mod_selection_merge_agrees_with_filters() {
+        let hdncpf = mods(&["HD", "NC", "PF"], GameMode::Osu);
+        let merged = ModSelection::merge_equivalent(&hdncpf);
+
+        assert!(ModSelection::filter_include(&merged, &hdncpf));
+        assert!(!ModSelection::filter_exclude(&merged, false, &hdncpf));
+        assert!(!ModSelection::filter_exclude(&merged, true, &hdncpf));
+    }
+
+    fn plays(entries: &[(f32, f32, &'static [&'static str])]) -> Vec<(f32, f32, GameMods)> {
+        entries
+            .iter()
+            .map(|(pp, stars, acronyms)| (*pp, *stars, mods(acronyms, GameMode::Osu)))
+            .collect()
+    }
+
+    fn suggest(target_pp: f32, plays: &[(f32, f32, GameMods)]) -> Option<PlaySuggestion> {
+        suggest_play(
+            target_pp,
+            plays.iter().map(|(pp, stars, mods)| (*pp, *stars, mods)),
+        )
+    }
+
+    #[test]
+    fn suggestion_requires_enough_plays() {
+        let plays = plays(&[(400.0, 7.0, &["HD", "DT"]); 9]);
+
+        assert_eq!(suggest(400.0, &plays), None);
+    }
+
+    #[test]
+    fn suggestion_median_of_similar_plays() {
+        let plays = plays(&[
+            (440.0, 7.4, &["HD", "DT"]),
+            (420.0, 7.0, &["HD", "NC"]),
+            (410.0, 7.2, &["HD", "DT"]),
+            (400.0, 6.0, &["HR"]),
+            (380.0, 6.8, &["HD", "DT"]),
+            (300.0, 6.0, &[]),
+            (290.0, 5.9, &[]),
+            (280.0, 5.8, &[]),
+            (270.0, 5.7, &[]),
+            (260.0, 5.6, &[]),
+        ]);
+
+        let suggestion = suggest(412.0, &plays).unwrap();
+
+        // Stars of the plays within 10%: 6.0, 6.8, 7.0, 7.2, 7.4
+        assert_eq!(suggestion.stars, 7.0);
+        let hddt: GameModsIntermode = [GameModIntermode::Hidden, GameModIntermode::DoubleTime]
+            .into_iter()
+            .collect();
+        assert_eq!(suggestion.mods, hddt);
+        assert!(!suggestion.extrapolated);
+
+        // Even amount of similar plays
+        let suggestion = suggest(432.0, &plays).unwrap();
+
+        // Stars of the plays within 10%: 6.0, 7.0, 7.2, 7.4
+        assert!((suggestion.stars - 7.1).abs() < 1e-6);
+    }
+
+    #[test]
+    fn suggestion_extrapolates_trend() {
+        // One additional star for every 100pp
+        let plays: Vec<_> = (0..20)
+            .map(|i| {
+                let pp = 100.0 + 10.0 * i as f32;
+
+                (pp, pp / 100.0 + 2.0, mods(&["HR"], GameMode::Osu))
+            })
+            .collect();
+
+        let suggestion = suggest(500.0, &plays).unwrap();
+
+        assert!((suggestion.stars - 7.0).abs() < 1e-4);
+        let hr: GameModsIntermode = [GameModIntermode::HardRock].into_iter().collect();
+        assert_eq!(suggestion.mods, hr);
+        assert!(suggestion.extrapolated);
+    }
+
+    #[test]
+    fn suggestion_without_trend() {
+        let plays = plays(&[(100.0, 5.0, &[]); 10]);
+
+        assert_eq!(suggest(1000.0, &plays), None);
+    }
+
+    #[test]
+    fn hitresult_kind_value() {
+        let stats = ScoreStatistics {
+            perfect: 1,
+            great: 2,
+            good: 3,
+            ok: 4,
+            meh: 5,
+            miss: 6,
+            large_tick_hit: 7,
+            small_tick_hit: 8,
+            small_tick_miss: 9,
+            ..Default::default()
+        };
+
+        let kinds = [
+            HitResultKind::Perfect,
+            HitResultKind::Great,
+            HitResultKind::Good,
+            HitResultKind::Ok,
+            HitResultKind::Meh,
+            HitResultKind::Miss,
+            HitResultKind::LargeTickHit,
+            HitResultKind::SmallTickHit,
+            HitResultKind::SmallTickMiss,
+        ];
+
+        for (expected, kind) in (1..).zip(kinds) {
+            assert_eq!(kind.value(&stats), expected, "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn hitresult_kind_validity() {
+        use HitResultKind as K;
+
+        assert!(K::Perfect.is_valid_for(GameMode::Mania));
+        assert!(!K::Perfect.is_valid_for(GameMode::Osu));
+        assert!(K::Ok.is_valid_for(GameMode::Taiko));
+        assert!(!K::Ok.is_valid_for(GameMode::Catch));
+        assert!(!K::Meh.is_valid_for(GameMode::Taiko));
+        assert!(K::SmallTickHit.is_valid_for(GameMode::Catch));
+        assert!(!K::LargeTickHit.is_valid_for(GameMode::Mania));
+
+        for mode in [
+            GameMode::Osu,
+            GameMode::Taiko,
+            GameMode::Catch,
+            GameMode::Mania,
+        ] {
+            assert!(K::Great.is_valid_for(mode));
+            assert!(K::Miss.is_valid_for(mode));
+
+            let (numerator, denominator) = K::default_ratio(mode);
+            assert!(numerator.is_valid_for(mode), "{mode:?}");
+            assert!(denominator.is_valid_for(mode), "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn hitresult_ratio_zero_denominator() {
+        assert_eq!(hitresult_ratio(0, 0), None);
+        assert_eq!(hitresult_ratio(5, 0), Some(f32::INFINITY));
+        assert_eq!(hitresult_ratio(0, 5), Some(0.0));
+        assert_eq!(hitresult_ratio(6, 4), Some(1.5));
+    }
+
+    fn catch_stats(
+        great: u32,
+        droplets: u32,
+        tiny: u32,
+        tiny_miss: u32,
+        miss: u32,
+    ) -> ScoreStatistics {
+        ScoreStatistics {
+            great,
+            large_tick_hit: droplets,
+            small_tick_hit: tiny,
+            small_tick_miss: tiny_miss,
+            miss,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn catch_hitresults_shapes() {
+        let lazer = ScoreStatistics {
+            large_tick_miss: 1,
+            ..catch_stats(1210, 311, 2044, 41, 2)
+        };
+
+        let legacy = ScoreStatistics {
+            great: 1210,
+            ok: 311,
+            meh: 2044,
+            good: 41,
+            miss: 3,
+            ..Default::default()
+        };
+
+        let expected = CatchHitresults {
+            fruits: 1210,
+            droplets: 311,
+            tiny_droplets: 2044,
+            tiny_droplet_misses: 41,
+            misses: 3,
+        };
+
+        assert_eq!(CatchHitresults::new(&lazer), expected);
+        assert_eq!(CatchHitresults::new(&legacy), expected);
+        assert_eq!(legacy.catch_hitresults(), expected);
+    }
+
+    #[test]
+    fn catch_accuracy() {
+        let stats = ScoreStatistics {
+            large_tick_miss: 1,
+            ..catch_stats(1210, 311, 2044, 41, 2)
+        };
+
+        let acc = calculate_accuracy(GameMode::Catch, &stats, None);
+        assert!((acc - 98.78083).abs() < 0.0001, "{acc}");
+
+        let stats = ScoreStatistics {
+            great: 602,
+            ok: 88,
+            meh: 930,
+            good: 74,
+            miss: 5,
+            ..Default::default()
+        };
+
+        let acc = calculate_accuracy(GameMode::Catch, &stats, Some(&stats));
+        assert!((acc - 95.35021).abs() < 0.0001, "{acc}");
+
+        let acc = calculate_accuracy(GameMode::Catch, &ScoreStatistics::default(), None);
+        assert_eq!(acc, 100.0);
+    }
+
+    #[test]
+    fn catch_grades() {
+        let nomod = GameModsIntermode::new();
+        let hd: GameModsIntermode = [GameModIntermode::Hidden].into_iter().collect();
+
+        let fc = catch_stats(812, 120, 1380, 0, 0);
+        let grade = calculate_grade(GameMode::Catch, &hd, &fc, Some(&fc));
+        assert_eq!(grade, Grade::XH);
+
+        let stats = ScoreStatistics {
+            large_tick_miss: 1,
+            ..catch_stats(1210, 311, 2044, 41, 2)
+        };
+        let max_stats = catch_stats(1212, 312, 2085, 0, 0);
+        let grade = calculate_grade(GameMode::Catch, &nomod, &stats, Some(&max_stats));
+        assert_eq!(grade, Grade::S);
+
+        let stats = ScoreStatistics {
+            great: 602,
+            ok: 88,
+            meh: 930,
+            good: 74,
+            miss: 5,
+            ..Default::default()
+        };
+        assert_eq!(
+            calculate_legacy_grade(GameMode::Catch, &nomod, &stats),
+            Grade::A
+        );
+
+        // Lazer thresholds are inclusive, legacy ones are not
+        let stats = catch_stats(400, 50, 40, 10, 0);
+        let max_stats = catch_stats(400, 50, 50, 0, 0);
+        let grade = calculate_grade(GameMode::Catch, &nomod, &stats, Some(&max_stats));
+        assert_eq!(grade, Grade::S);
+        assert_eq!(
+            calculate_legacy_grade(GameMode::Catch, &nomod, &stats),
+            Grade::A
+        );
+    }
+
+    #[test]
+    fn adjusted_total_pp_unchanged() {
+        let pps = [400.0, 350.0, 300.0];
+        let total = 1000.0;
+
+        assert!((adjusted_total_pp(total, &pps, &pps) - total).abs() < 0.01);
+    }
+
+    #[test]
+    fn adjusted_total_pp_preserves_bonus() {
+        let old_pps = [400.0, 350.0, 300.0];
+        let weighted = old_pps.accum_weighted() as f32;
+        let bonus = 200.0;
+
+        // The last score got improved so much that it's now the top score
+        let new_pps = [500.0, 400.0, 350.0];
+        let expected = new_pps.accum_weighted() as f32 + bonus;
+        let adjusted = adjusted_total_pp(weighted + bonus, &old_pps, &new_pps);
+
+        assert!(
+            (adjusted - expected).abs() < 0.01,
+            "{adjusted} vs {expected}"
+        );
+    }
+
+    #[test]
+    fn adjusted_total_pp_rounds() {
+        let adjusted = adjusted_total_pp(100.004, &[100.0], &[100.0]);
+
+        assert_eq!(adjusted, 100.0);
+    }
+
+    #[test]
+    fn total_pp_with_replacements_resorts() {
+        let pps = [400.0, 350.0, 300.0, 100.0];
+        let bonus = 200.0;
+        let total = pps.accum_weighted() as f32 + bonus;
+
+        // The last score overtakes the second one
+        let replaced = total_pp_with_replacements(total, &pps, &[(3, 380.0)]);
+        let expected = [400.0, 380.0, 350.0, 300.0].accum_weighted() as f32 + bonus;
+
+        assert!(
+            (replaced - expected).abs() < 0.01,
+            "{replaced} vs {expected}"
+        );
+    }
+
+    #[test]
+    fn total_pp_with_multiple_replacements() {
+        let pps = [400.0, 350.0, 300.0, 100.0];
+        let bonus = 200.0;
+        let total = pps.accum_weighted() as f32 + bonus;
+
+        let replacements = [(2, 450.0), (3, 360.0), (9, 1000.0)];
+        let replaced = total_pp_with_replacements(total, &pps, &replacements);
+        let expected = [450.0, 400.0, 360.0, 350.0].accum_weighted() as f32 + bonus;
+
+        assert!(
+            (replaced - expected).abs() < 0.01,
+            "{replaced} vs {expected}"
+        );
+
+        let unchanged = total_pp_with_replacements(total, &pps, &[]);
+        assert!((unchanged - total).abs() < 0.01);
+    }
+
+    #[test]
+    fn total_pp_without_bottom_removes_weakest() {
+        let pps = [400.0, 350.0, 300.0, 100.0];
+        let bonus = 200.0;
+        let total = pps.accum_weighted() as f32 + bonus;
+
+        let expected = pps[..2].accum_weighted() as f32 + bonus;
+        let adjusted = total_pp_without_bottom(total, &pps, 2);
+
+        assert!(
+            (adjusted - expected).abs() < 0.01,
+            "{adjusted} vs {expected}"
+        );
+    }
+
+    #[test]
+    fn total_pp_without_bottom_edge_cases() {
+        let pps = [400.0, 350.0];
+        let total = pps.accum_weighted() as f32 + 100.0;
+
+        assert!((total_pp_without_bottom(total, &pps, 0) - total).abs() < 0.01);
+
+        // Only the bonus pp remain
+        assert!((total_pp_without_bottom(total, &pps, 5) - 100.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn blacklisted_mods_required() {
+        let dt = mods(&["DT"], GameMode::Osu);
+        let hddt = mods(&["HD", "DT"], GameMode::Osu);
+        let hd = mods(&["HD"], GameMode::Osu);
+        let scores = [(500.0, &dt), (450.0, &hddt), (300.0, &hd)];
+        let blacklist = GameModsIntermode::try_from_acronyms("DT").unwrap();
+
+        // Best play without DT is 300pp
+        assert!(!requires_blacklisted_mods(390.0, scores, &blacklist));
+        assert!(requires_blacklisted_mods(391.0, scores, &blacklist));
+    }
+
+    #[test]
+    fn blacklisted_mods_edge_cases() {
+        let dt = mods(&["DT"], GameMode::Osu);
+        let nomod = GameMods::new();
+        let blacklist = GameModsIntermode::try_from_acronyms("DT").unwrap();
+
+        // Empty blacklist never requires anything
+        let empty = GameModsIntermode::new();
+        assert!(!requires_blacklisted_mods(
+            1000.0,
+            [(100.0, &nomod)],
+            &empty
+        ));
+
+        // No scores at all
+        assert!(!requires_blacklisted_mods(1000.0, [], &blacklist));
+
+        // Only blacklisted plays
+        assert!(requires_blacklisted_mods(1.0, [(500.0, &dt)], &blacklist));
+    }
+
+    fn parse_selection(mods: &str) -> Option<ModSelection> {
+        match ModSelection::parse(Some(mods)) {
+            ModsResult::Mods(selection) => Some(selection),
+            ModsResult::None | ModsResult::Invalid => None,
+        }
+    }
+
+    #[test]
+    fn mod_selection_syntax() {
+        let hdhr: GameModsIntermode = [GameModIntermode::Hidden, GameModIntermode::HardRock]
+            .into_iter()
+            .collect();
+
+        assert_eq!(
+            parse_selection("+HDHR"),
+            Some(ModSelection::Include(hdhr.clone()))
+        );
+        assert_eq!(
+            parse_selection("+hd hr!"),
+            Some(ModSelection::Exact(hdhr.clone()))
+        );
+        assert_eq!(
+            parse_selection("-hidden,hardrock!"),
+            Some(ModSelection::Exclude {
+                mods: hdhr.clone(),
+                nomod: false
+            })
+        );
+        assert_eq!(
+            parse_selection("-nmhd!"),
+            Some(ModSelection::Exclude {
+                mods: [GameModIntermode::Hidden].into_iter().collect(),
+                nomod: true
+            })
+        );
+        assert_eq!(
+            parse_selection("-nm hd!"),
+            Some(ModSelection::Exclude {
+                mods: [GameModIntermode::Hidden].into_iter().collect(),
+                nomod: true
+            })
+        );
+        assert_eq!(
+            parse_selection("hidden hard rock"),
+            Some(ModSelection::Exact(hdhr))
+        );
+        assert_eq!(
+            parse_selection("+nomod"),
+            Some(ModSelection::Include(GameModsIntermode::new()))
+        );
+
+        assert_eq!(parse_selection("-hdhr"), None);
+        assert_eq!(parse_selection("+hdr"), None);
+    }
+
+    #[test]
+    fn unknown_mods_message() {
+        assert_eq!(
+            ModSelection::unknown_mods_message("+hdr!").as_deref(),
+            Some("Unknown mod `hdr`, did you mean `hr` or `hd`?")
+        );
+        assert_eq!(
+            ModSelection::unknown_mods_message("hd hdr").as_deref(),
+            Some("Unknown mod `hdr`, did you mean `hr` or `hd`?")
+        );
+        assert!(ModSelection::unknown_mods_message("+hdhr").is_none());
+    }
 }
@@ -0,0 +1,181 @@
+//! Values derived from a map's difficulty attributes.
+//!
+//! All functions expect attributes that are already adjusted by difficulty
+//! mods such as HR or EZ but *not* by the clock rate. Times are in
+//! milliseconds and scaled by the given clock rate so that e.g. DT shrinks hit
+//! windows.
+
+/// Map a difficulty value onto a range.
+///
+/// <https://github.com/ppy/osu/blob/master/osu.Game/Beatmaps/IBeatmapDifficultyInfo.cs>
+pub fn difficulty_range(difficulty: f64, min: f64, mid: f64, max: f64) -> f64 {
+    if difficulty > 5.0 {
+        mid + (max - mid) * (difficulty - 5.0) / 5.0
+    } else if difficulty < 5.0 {
+        mid - (mid - min) * (5.0 - difficulty) / 5.0
+    } else {
+        mid
+    }
+}
+
+/// Time in which a hit object is visible before it must be hit.
+///
+/// Also used as the fall time of catch fruits.
+pub fn preempt(ar: f64, clock_rate: f64) -> f64 {
+    difficulty_range(ar, 1800.0, 1200.0, 450.0) / clock_rate
+}
+
+/// Time in which an osu!standard hit object fades in.
+pub fn fade_in(ar: f64, clock_rate: f64) -> f64 {
+    let preempt = difficulty_range(ar, 1800.0, 1200.0, 450.0);
+
+    400.0 * (preempt / 450.0).min(1.0) / clock_rate
+}
+
+/// Hit windows of osu!standard.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OsuHitWindows {
+    pub great: f64,
+    pub ok: f64,
+    pub meh: f64,
+}
+
+impl OsuHitWindows {
+    pub fn new(od: f64, clock_rate: f64) -> Self {
+        Self {
+            great: difficulty_range(od, 80.0, 50.0, 20.0) / clock_rate,
+            ok: difficulty_range(od, 140.0, 100.0, 60.0) / clock_rate,
+            meh: difficulty_range(od, 200.0, 150.0, 100.0) / clock_rate,
+        }
+    }
+}
+
+/// Hit windows of osu!taiko.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TaikoHitWindows {
+    pub great: f64,
+    pub ok: f64,
+    pub miss: f64,
+}
+
+impl TaikoHitWindows {
+    pub fn new(od: f64, clock_rate: f64) -> Self {
+        Self {
+            great: difficulty_range(od, 50.0, 35.0, 20.0) / clock_rate,
+            ok: difficulty_range(od, 120.0, 80.0, 50.0) / clock_rate,
+            miss: difficulty_range(od, 135.0, 95.0, 70.0) / clock_rate,
+        }
+    }
+}
+
+/// Hit windows of osu!mania as used by osu!stable.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ManiaHitWindows {
+    pub perfect: f64,
+    pub great: f64,
+    pub good: f64,
+    pub ok: f64,
+    pub meh: f64,
+    pub miss: f64,
+}
+
+impl ManiaHitWindows {
+    pub fn new(od: f64, clock_rate: f64) -> Self {
+        Self {
+            perfect: 16.0 / clock_rate,
+            great: (64.0 - 3.0 * od) / clock_rate,
+            good: (97.0 - 3.0 * od) / clock_rate,
+            ok: (127.0 - 3.0 * od) / clock_rate,
+            meh: (151.0 - 3.0 * od) / clock_rate,
+            miss: (188.0 - 3.0 * od) / clock_rate,
+        }
+    }
+}
+
+/// Width of the osu!catch catcher in osu!pixels.
+///
+/// <https://github.com/ppy/osu/blob/master/osu.Game.Rulesets.Catch/UI/Catcher.cs>
+pub fn catcher_width(cs: f64) -> f64 {
+    const BASE_SIZE: f64 = 106.75;
+    const ALLOWED_CATCH_RANGE: f64 = 0.8;
+
+    let scale = 1.0 - 0.7 * (cs - 5.0) / 5.0;
+
+    BASE_SIZE * scale.abs() * ALLOWED_CATCH_RANGE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_approx(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-3,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn range_boundaries() {
+        assert_approx(difficulty_range(0.0, 1800.0, 1200.0, 450.0), 1800.0);
+        assert_approx(difficulty_range(5.0, 1800.0, 1200.0, 450.0), 1200.0);
+        assert_approx(difficulty_range(10.0, 1800.0, 1200.0, 450.0), 450.0);
+    }
+
+    #[test]
+    fn osu_preempt_and_fade_in() {
+        assert_approx(preempt(9.0, 1.0), 600.0);
+        assert_approx(preempt(9.0, 1.5), 400.0);
+        assert_approx(preempt(10.0, 1.0), 450.0);
+
+        assert_approx(fade_in(9.0, 1.0), 400.0);
+        assert_approx(fade_in(10.0, 1.0), 400.0);
+        assert_approx(fade_in(11.0, 1.0), 400.0 * 300.0 / 450.0);
+        assert_approx(fade_in(9.0, 1.5), 400.0 / 1.5);
+    }
+
+    #[test]
+    fn osu_windows() {
+        let windows = OsuHitWindows::new(8.0, 1.0);
+        assert_approx(windows.great, 32.0);
+        assert_approx(windows.ok, 76.0);
+        assert_approx(windows.meh, 120.0);
+    }
+
+    #[test]
+    fn taiko_windows() {
+        let windows = TaikoHitWindows::new(10.0, 1.0);
+        assert_approx(windows.great, 20.0);
+        assert_approx(windows.ok, 50.0);
+        assert_approx(windows.miss, 70.0);
+
+        let windows = TaikoHitWindows::new(10.0, 1.5);
+        assert_approx(windows.great, 20.0 / 1.5);
+        assert_approx(windows.ok, 50.0 / 1.5);
+
+        let windows = TaikoHitWindows::new(5.0, 1.0);
+        assert_approx(windows.great, 35.0);
+        assert_approx(windows.ok, 80.0);
+    }
+
+    #[test]
+    fn mania_windows() {
+        let windows = ManiaHitWindows::new(8.0, 1.0);
+        assert_approx(windows.perfect, 16.0);
+        assert_approx(windows.great, 40.0);
+        assert_approx(windows.good, 73.0);
+        assert_approx(windows.ok, 103.0);
+        assert_approx(windows.meh, 127.0);
+        assert_approx(windows.miss, 164.0);
+
+        let windows = ManiaHitWindows::new(8.0, 0.75);
+        assert_approx(windows.great, 40.0 / 0.75);
+    }
+
+    #[test]
+    fn catch_catcher_width() {
+        assert_approx(catcher_width(5.0), 85.4);
+        assert_approx(catcher_width(0.0), 145.18);
+        assert_approx(catcher_width(10.0), 25.62);
+    }
+}
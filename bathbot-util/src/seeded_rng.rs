@@ -0,0 +1,126 @@
+use std::ops::RangeInclusive;
+
+/// Deterministic random number generator based on SplitMix64.
+///
+/// The same seed components always produce the same sequence so that results
+/// can be reproduced and verified by others.
+#[derive(Clone, Debug)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    const GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+    /// Seed the generator by mixing all components in order.
+    pub fn new(components: &[u64]) -> Self {
+        let mut state = 0_u64;
+
+        for &component in components {
+            state = mix(state.wrapping_add(Self::GAMMA) ^ component);
+        }
+
+        Self { state }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(Self::GAMMA);
+
+        mix(self.state)
+    }
+
+    /// Uniformly distributed number within the range.
+    ///
+    /// Panics if the range is empty.
+    pub fn gen_range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range");
+
+        let Some(span) = (end - start).checked_add(1) else {
+            return self.next_u64();
+        };
+
+        // Reject values from the incomplete last chunk to avoid modulo bias
+        let zone = u64::MAX - (u64::MAX - span + 1) % span;
+
+        loop {
+            let n = self.next_u64();
+
+            if n <= zone {
+                return start + n % span;
+            }
+        }
+    }
+}
+
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic() {
+        let mut a = SeededRng::new(&[123, 456, 789]);
+        let mut b = SeededRng::new(&[123, 456, 789]);
+
+        for _ in 0..100 {
+            assert_eq!(a.gen_range(1..=100), b.gen_range(1..=100));
+        }
+    }
+
+    #[test]
+    fn components_matter() {
+        let first = |components: &[u64]| SeededRng::new(components).next_u64();
+
+        let base = first(&[1, 2, 3]);
+
+        assert_ne!(base, first(&[1, 2, 4]));
+        assert_ne!(base, first(&[3, 2, 1]));
+        assert_ne!(base, first(&[1, 2]));
+        assert_ne!(first(&[0]), first(&[0, 0]));
+    }
+
+    #[test]
+    fn range_bounds() {
+        let mut rng = SeededRng::new(&[42]);
+
+        for _ in 0..1000 {
+            let n = rng.gen_range(1..=6);
+            assert!((1..=6).contains(&n));
+        }
+
+        assert_eq!(rng.gen_range(7..=7), 7);
+
+        // Full range must not overflow
+        let _ = rng.gen_range(0..=u64::MAX);
+    }
+
+    #[test]
+    fn roughly_uniform() {
+        const SIDES: usize = 10;
+        const ROLLS: usize = 100_000;
+
+        let mut rng = SeededRng::new(&[2024, 10, 16]);
+        let mut counts = [0_usize; SIDES];
+
+        for _ in 0..ROLLS {
+            counts[rng.gen_range(0..=SIDES as u64 - 1) as usize] += 1;
+        }
+
+        let expected = ROLLS / SIDES;
+
+        // Each side is within 5% of its expected count
+        for count in counts {
+            assert!(
+                count.abs_diff(expected) < expected / 20,
+                "counts={counts:?}"
+            );
+        }
+    }
+}
@@ -58,6 +58,39 @@ pub fn levenshtein_distance<'w>(mut word_a: &'w str, mut word_b: &'w str) -> (us
     (get!(costs[n]) as usize, n)
 }
 
+/// Pick up to `limit` candidates that contain `query`, ignoring ASCII case.
+///
+/// Candidates starting with `query` come before those only containing it;
+/// within both groups the original order is kept.
+pub fn autocomplete_matches<'c, I>(query: &str, candidates: I, limit: usize) -> Vec<&'c str>
+where
+    I: IntoIterator<Item = &'c str>,
+{
+    let query = query.to_ascii_lowercase();
+
+    let mut prefixed = Vec::with_capacity(limit);
+    let mut contained = Vec::new();
+
+    for candidate in candidates {
+        let lowercase = candidate.to_ascii_lowercase();
+
+        if lowercase.starts_with(&query) {
+            prefixed.push(candidate);
+
+            if prefixed.len() == limit {
+                break;
+            }
+        } else if contained.len() < limit && lowercase.contains(&query) {
+            contained.push(candidate);
+        }
+    }
+
+    let remaining = limit - prefixed.len();
+    prefixed.extend(contained.into_iter().take(remaining));
+
+    prefixed
+}
+
 /// Consider the length of the longest common substring, then repeat recursively
 /// for the remaining left and right parts of the words
 pub fn gestalt_pattern_matching(word_a: &str, word_b: &str) -> f32 {
@@ -192,3 +225,40 @@ struct SubstringResult {
     start_b: usize,
     len: usize,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MEDALS: [&str; 5] = [
+        "Quick Maths",
+        "Mad Hatter",
+        "Hatrick",
+        "Jack of All Trades",
+        "Rising Star",
+    ];
+
+    #[test]
+    fn autocomplete_prefix_before_substring() {
+        let matches = autocomplete_matches("hat", MEDALS, 25);
+
+        assert_eq!(matches, ["Hatrick", "Mad Hatter"]);
+    }
+
+    #[test]
+    fn autocomplete_ignores_case() {
+        let matches = autocomplete_matches("QUICK", MEDALS, 25);
+
+        assert_eq!(matches, ["Quick Maths"]);
+    }
+
+    #[test]
+    fn autocomplete_respects_limit() {
+        let matches = autocomplete_matches("a", MEDALS, 2);
+        assert_eq!(matches, ["Quick Maths", "Mad Hatter"]);
+
+        // Prefix matches fill up the limit before substring matches
+        let matches = autocomplete_matches("r", MEDALS, 1);
+        assert_eq!(matches, ["Rising Star"]);
+    }
+}
@@ -0,0 +1,44 @@
+use std::{env, fs, path::PathBuf};
+
+/// Expose the locked rosu-pp version and git revision so that debug embeds
+/// show what pp values are actually calculated with.
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("missing CARGO_MANIFEST_DIR");
+    let lock_path = PathBuf::from(manifest_dir).join("../Cargo.lock");
+
+    println!("cargo:rerun-if-changed={}", lock_path.display());
+
+    let lock = fs::read_to_string(&lock_path).unwrap_or_default();
+    let (version, revision) = locked_package(&lock, "rosu-pp").unwrap_or(("unknown", ""));
+
+    println!("cargo:rustc-env=ROSU_PP_VERSION={version}");
+    println!("cargo:rustc-env=ROSU_PP_REVISION={revision}");
+}
+
+/// Version and, for git dependencies, the abbreviated revision of a package
+/// in `Cargo.lock`.
+fn locked_package<'l>(lock: &'l str, name: &str) -> Option<(&'l str, &'l str)> {
+    let package = lock
+        .split("[[package]]")
+        .find(|package| field(package, "name") == Some(name))?;
+
+    let version = field(package, "version")?;
+
+    let revision = field(package, "source")
+        .filter(|source| source.starts_with("git+"))
+        .and_then(|source| source.rsplit_once('#'))
+        .map_or("", |(_, rev)| &rev[..rev.len().min(7)]);
+
+    Some((version, revision))
+}
+
+fn field<'p>(package: &'p str, key: &str) -> Option<&'p str> {
+    package.lines().find_map(|line| {
+        line.strip_prefix(key)?
+            .trim_start()
+            .strip_prefix('=')?
+            .trim()
+            .strip_prefix('"')?
+            .strip_suffix('"')
+    })
+}
@@ -7,17 +7,32 @@ use tokio::{
     time::sleep,
 };
 
+use twilight_model::{
+    guild::Permissions,
+    id::{
+        Id,
+        marker::{ChannelMarker, MessageMarker},
+    },
+};
+
 use super::{
-    ActiveMessage, BuildPage, FullActiveMessage, IActiveMessage,
+    ActiveMessage, ActiveMessages, BuildPage, FullActiveMessage, IActiveMessage,
     origin::{ActiveMessageOrigin, ActiveMessageOriginError},
     response::ActiveResponse,
+    thread::{self, ArchivedDecision, ArchivedRetry},
 };
 use crate::core::Context;
 
+/// Additional seconds that an active message stays registered in redis after
+/// its timeout so that the registry entry does not vanish before the message
+/// times out.
+const REGISTRY_EXPIRE_MARGIN: u64 = 30;
+
 pub struct ActiveMessagesBuilder {
     inner: ActiveMessage,
     attachment: Option<(String, Vec<u8>)>,
     start_by_update: Option<bool>,
+    plain_fallback: bool,
 }
 
 impl ActiveMessagesBuilder {
@@ -26,6 +41,7 @@ impl ActiveMessagesBuilder {
             inner: active_msg.into(),
             attachment: None,
             start_by_update: None,
+            plain_fallback: false,
         }
     }
 
@@ -48,6 +64,7 @@ impl ActiveMessagesBuilder {
                 inner: mut active_msg,
                 attachment,
                 start_by_update,
+                plain_fallback,
             } = builder;
 
             let BuildPage {
@@ -57,11 +74,15 @@ impl ActiveMessagesBuilder {
             } = active_msg
                 .build_page()
                 .await
-                .wrap_err("Failed to build page")?;
+                .wrap_err("Failed to build page")?
+                .with_invocation(&active_msg);
 
             let components = active_msg.build_components();
 
-            let mut builder = MessageBuilder::new().embed(embed).components(components);
+            let mut builder = MessageBuilder::new()
+                .embed(embed)
+                .components(components)
+                .plain_fallback(plain_fallback);
 
             if let Some(ref content) = content {
                 builder = builder.content(content.as_ref());
@@ -83,15 +104,37 @@ impl ActiveMessagesBuilder {
                 .wrap_err("Failed to deserialize response")?;
 
             let msg = response.id;
+            let channel = response.channel_id;
             let response = ActiveResponse::new(&orig, &response);
+            let permissions = orig.permissions();
             let (activity_tx, activity_rx) = watch::channel(());
 
             if let Some(until_timeout) = active_msg.until_timeout() {
-                ActiveMessagesBuilder::spawn_timeout(activity_rx, response, until_timeout);
+                if let Some(archive_duration) = orig.thread_archive() {
+                    thread::bump_auto_archive(
+                        channel,
+                        archive_duration,
+                        until_timeout,
+                        permissions,
+                    )
+                    .await;
+                }
+
+                ActiveMessagesBuilder::register(channel, msg, until_timeout).await;
+
+                ActiveMessagesBuilder::spawn_timeout(
+                    activity_rx,
+                    channel,
+                    permissions,
+                    response,
+                    until_timeout,
+                );
 
                 let full = FullActiveMessage {
                     active_msg,
                     activity_tx,
+                    channel,
+                    plain_fallback,
                 };
 
                 Context::get().active_msgs.insert(msg, full).await;
@@ -114,11 +157,66 @@ impl ActiveMessagesBuilder {
         }
     }
 
-    fn spawn_timeout(mut rx: Receiver<()>, response: ActiveResponse, until_timeout: Duration) {
+    /// Send the pages as plain text in channels where embeds can't be shown.
+    pub fn plain_fallback(self, plain_fallback: bool) -> Self {
+        Self {
+            plain_fallback,
+            ..self
+        }
+    }
+
+    /// Keep track of the active message in redis so its components can be
+    /// removed after a restart.
+    async fn register(channel: Id<ChannelMarker>, msg: Id<MessageMarker>, until_timeout: Duration) {
+        let expire_seconds = until_timeout.as_secs() + REGISTRY_EXPIRE_MARGIN;
+
+        let register_fut = Context::cache().add_active_message(channel, msg, expire_seconds);
+
+        if let Err(err) = register_fut.await {
+            warn!(?err, "Failed to register active message");
+        }
+    }
+
+    /// Run the active message's timeout, unarchiving its thread if
+    /// necessary.
+    async fn timeout(
+        active_msg: &mut ActiveMessage,
+        channel: Id<ChannelMarker>,
+        permissions: Option<Permissions>,
+        response: ActiveResponse,
+    ) {
+        let mut retry = ArchivedRetry::new(channel, permissions);
+
+        loop {
+            let Err(err) = active_msg.on_timeout(response.clone()).await else {
+                return;
+            };
+
+            match retry.handle(thread::report_is_thread_archived(&err)).await {
+                ArchivedDecision::Propagate => {
+                    return warn!(?err, "Failed to timeout active message");
+                }
+                ArchivedDecision::UnarchiveAndRetry => {}
+                ArchivedDecision::Terminate => {
+                    return debug!(%channel, "Skipping timeout in archived thread");
+                }
+            }
+        }
+    }
+
+    fn spawn_timeout(
+        mut rx: Receiver<()>,
+        channel: Id<ChannelMarker>,
+        permissions: Option<Permissions>,
+        response: ActiveResponse,
+        until_timeout: Duration,
+    ) {
         tokio::spawn(async move {
             loop {
                 tokio::select! {
                     res = rx.changed() => if res.is_ok() {
+                        ActiveMessagesBuilder::register(channel, response.msg, until_timeout).await;
+
                         continue
                     } else {
                         return
@@ -126,10 +224,18 @@ impl ActiveMessagesBuilder {
                     _ = sleep(until_timeout) => {
                         let active_msg = Context::get().active_msgs.remove_full(response.msg).await;
 
-                        if let Some(FullActiveMessage { mut active_msg, .. }) = active_msg
-                            && let Err(err) = active_msg.on_timeout(response).await {
-                                warn!(?err, "Failed to timeout active message");
-                            }
+                        if let Some(FullActiveMessage { mut active_msg, .. }) = active_msg {
+                            ActiveMessages::unregister(channel, response.msg).await;
+
+                            let timeout_fut = ActiveMessagesBuilder::timeout(
+                                &mut active_msg,
+                                channel,
+                                permissions,
+                                response,
+                            );
+
+                            timeout_fut.await;
+                        }
 
                         return;
                     },
@@ -0,0 +1,88 @@
+use std::fmt::Write;
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_psql::model::audit_log::AuditLogEntry;
+use bathbot_util::{CowUtils, EmbedBuilder, FooterBuilder};
+use eyre::Result;
+use twilight_model::{
+    channel::message::Component,
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    active::{
+        BuildPage, ComponentResult, IActiveMessage,
+        pagination::{Pages, handle_pagination_component, handle_pagination_modal},
+    },
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+#[derive(PaginationBuilder)]
+pub struct AuditLogPagination {
+    #[pagination(per_page = 10)]
+    entries: Box<[AuditLogEntry]>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for AuditLogPagination {
+    async fn build_page(&mut self) -> Result<BuildPage> {
+        let pages = &self.pages;
+        let end_idx = self.entries.len().min(pages.index() + pages.per_page());
+        let entries = &self.entries[pages.index()..end_idx];
+
+        let mut description = String::with_capacity(entries.len() * 100);
+
+        for entry in entries {
+            let AuditLogEntry {
+                user_id,
+                command,
+                options,
+                success,
+                timestamp,
+                ..
+            } = entry;
+
+            let _ = write!(
+                description,
+                "<t:{timestamp}:R> <@{user_id}> `{command}` {result}",
+                timestamp = timestamp.unix_timestamp(),
+                result = if *success { "✅" } else { "❌" },
+            );
+
+            if !options.is_empty() {
+                let _ = write!(description, "\n{}", options.cow_escape_markdown());
+            }
+
+            description.push('\n');
+        }
+
+        if description.is_empty() {
+            description.push_str("No entries");
+        }
+
+        let page = pages.curr_page();
+        let pages = pages.last_page();
+
+        let footer_text = format!("Page {page}/{pages} • Entries: {}", self.entries.len());
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(FooterBuilder::new(footer_text))
+            .title("Recent uses of authority commands:");
+
+        Ok(BuildPage::new(embed, false))
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        handle_pagination_component(component, self.msg_owner, false, &mut self.pages).await
+    }
+
+    async fn handle_modal(&mut self, modal: &mut InteractionModal) -> Result<()> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages).await
+    }
+}
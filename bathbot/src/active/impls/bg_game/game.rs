@@ -18,7 +18,7 @@ use twilight_model::id::{
 use twilight_standby::future::WaitForMessageStream;
 
 use super::{hints::Hints, img_reveal::ImageReveal, mapset::GameMapset, util};
-use crate::{Context, commands::fun::GameDifficulty, core::BotConfig, util::ChannelExt};
+use crate::{Context, commands::fun::GameDifficulty, core::BotConfig};
 
 pub struct Game {
     pub mapset: GameMapset,
@@ -197,7 +197,9 @@ pub async fn game_loop(
                 );
 
                 // Send message
-                if let Err(err) = channel.plain_message(&content).await {
+                let result_fut = util::send_round_result(channel, &content, game.mapset_id());
+
+                if let Err(err) = result_fut.await {
                     warn!(?err, "Error while sending msg for winner");
                 }
 
@@ -20,7 +20,10 @@ use twilight_model::{
     id::{Id, marker::ChannelMarker},
 };
 
-use super::game::{Game, LoopResult, game_loop};
+use super::{
+    game::{Game, LoopResult, game_loop},
+    util,
+};
 use crate::{Context, commands::fun::GameDifficulty, util::ChannelExt};
 
 const GAME_LEN: Duration = Duration::from_secs(180);
@@ -81,7 +84,9 @@ impl BackgroundGame {
                             Full background: https://catboy.best/preview/background/{mapset_id}/set"
                         );
 
-                        if let Err(err) = channel.plain_message(&content).await {
+                        let result_fut = util::send_round_result(channel, &content, mapset_id);
+
+                        if let Err(err) = result_fut.await {
                             warn!(?err, "Failed to show resolve for bg game restart");
                         }
                     }
@@ -95,7 +100,9 @@ impl BackgroundGame {
                             End of game, see you next time o/"
                         );
 
-                        if let Err(err) = channel.plain_message(&content).await {
+                        let result_fut = util::send_round_result(channel, &content, mapset_id);
+
+                        if let Err(err) = result_fut.await {
                             warn!(?err, "Failed to show resolve for bg game stop");
                         }
 
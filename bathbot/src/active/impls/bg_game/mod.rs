@@ -16,7 +16,7 @@ use twilight_model::{
     },
 };
 
-pub use self::game_wrapper::BackgroundGame;
+pub use self::{game_wrapper::BackgroundGame, tag_editor::BackgroundGameTagEditor};
 use crate::{
     active::{BuildPage, ComponentResult, IActiveMessage},
     commands::fun::GameDifficulty,
@@ -29,6 +29,7 @@ mod game_wrapper;
 mod hints;
 mod img_reveal;
 mod mapset;
+mod tag_editor;
 mod util;
 
 pub struct BackgroundGameSetup {
@@ -77,103 +78,7 @@ impl IActiveMessage for BackgroundGameSetup {
             return Vec::new();
         }
 
-        let tag_options = |tags: MapsetTags| {
-            vec![
-                SelectMenuOption {
-                    default: tags.contains(MapsetTags::Easy),
-                    description: None,
-                    emoji: None,
-                    label: "Easy".to_owned(),
-                    value: "easy".to_owned(),
-                },
-                SelectMenuOption {
-                    default: tags.contains(MapsetTags::Hard),
-                    description: None,
-                    emoji: None,
-                    label: "Hard".to_owned(),
-                    value: "hard".to_owned(),
-                },
-                SelectMenuOption {
-                    default: tags.contains(MapsetTags::Meme),
-                    description: None,
-                    emoji: None,
-                    label: "Meme".to_owned(),
-                    value: "meme".to_owned(),
-                },
-                SelectMenuOption {
-                    default: tags.contains(MapsetTags::Weeb),
-                    description: None,
-                    emoji: None,
-                    label: "Weeb".to_owned(),
-                    value: "weeb".to_owned(),
-                },
-                SelectMenuOption {
-                    default: tags.contains(MapsetTags::Kpop),
-                    description: None,
-                    emoji: None,
-                    label: "K-Pop".to_owned(),
-                    value: "kpop".to_owned(),
-                },
-                SelectMenuOption {
-                    default: tags.contains(MapsetTags::Farm),
-                    description: None,
-                    emoji: None,
-                    label: "Farm".to_owned(),
-                    value: "farm".to_owned(),
-                },
-                SelectMenuOption {
-                    default: tags.contains(MapsetTags::HardName),
-                    description: None,
-                    emoji: None,
-                    label: "Hard name".to_owned(),
-                    value: "hardname".to_owned(),
-                },
-                SelectMenuOption {
-                    default: tags.contains(MapsetTags::Alternate),
-                    description: None,
-                    emoji: None,
-                    label: "Alternate".to_owned(),
-                    value: "alt".to_owned(),
-                },
-                SelectMenuOption {
-                    default: tags.contains(MapsetTags::BlueSky),
-                    description: None,
-                    emoji: None,
-                    label: "Blue sky".to_owned(),
-                    value: "bluesky".to_owned(),
-                },
-                SelectMenuOption {
-                    default: tags.contains(MapsetTags::English),
-                    description: None,
-                    emoji: None,
-                    label: "English".to_owned(),
-                    value: "english".to_owned(),
-                },
-                SelectMenuOption {
-                    default: tags.contains(MapsetTags::Streams),
-                    description: None,
-                    emoji: None,
-                    label: "Streams".to_owned(),
-                    value: "streams".to_owned(),
-                },
-                SelectMenuOption {
-                    default: tags.contains(MapsetTags::Old),
-                    description: None,
-                    emoji: None,
-                    label: "Old".to_owned(),
-                    value: "old".to_owned(),
-                },
-                SelectMenuOption {
-                    default: tags.contains(MapsetTags::Tech),
-                    description: None,
-                    emoji: None,
-                    label: "Tech".to_owned(),
-                    value: "tech".to_owned(),
-                },
-            ]
-        };
-
-        let include_options = tag_options(self.included);
+        let include_options = mapset_tag_options(self.included);
 
         let include_menu = SelectMenu {
             custom_id: "bg_setup_include".to_owned(),
@@ -191,7 +96,7 @@ impl IActiveMessage for BackgroundGameSetup {
             components: vec![Component::SelectMenu(include_menu)],
         };
 
-        let exclude_options = tag_options(self.excluded);
+        let exclude_options = mapset_tag_options(self.excluded);
 
         let exclude_menu = SelectMenu {
             custom_id: "bg_setup_exclude".to_owned(),
@@ -440,32 +345,114 @@ impl BackgroundGameSetup {
     }
 }
 
+fn mapset_tag_options(tags: MapsetTags) -> Vec<SelectMenuOption> {
+    vec![
+        SelectMenuOption {
+            default: tags.contains(MapsetTags::Easy),
+            description: None,
+            emoji: None,
+            label: "Easy".to_owned(),
+            value: "easy".to_owned(),
+        },
+        SelectMenuOption {
+            default: tags.contains(MapsetTags::Hard),
+            description: None,
+            emoji: None,
+            label: "Hard".to_owned(),
+            value: "hard".to_owned(),
+        },
+        SelectMenuOption {
+            default: tags.contains(MapsetTags::Meme),
+            description: None,
+            emoji: None,
+            label: "Meme".to_owned(),
+            value: "meme".to_owned(),
+        },
+        SelectMenuOption {
+            default: tags.contains(MapsetTags::Weeb),
+            description: None,
+            emoji: None,
+            label: "Weeb".to_owned(),
+            value: "weeb".to_owned(),
+        },
+        SelectMenuOption {
+            default: tags.contains(MapsetTags::Kpop),
+            description: None,
+            emoji: None,
+            label: "K-Pop".to_owned(),
+            value: "kpop".to_owned(),
+        },
+        SelectMenuOption {
+            default: tags.contains(MapsetTags::Farm),
+            description: None,
+            emoji: None,
+            label: "Farm".to_owned(),
+            value: "farm".to_owned(),
+        },
+        SelectMenuOption {
+            default: tags.contains(MapsetTags::HardName),
+            description: None,
+            emoji: None,
+            label: "Hard name".to_owned(),
+            value: "hardname".to_owned(),
+        },
+        SelectMenuOption {
+            default: tags.contains(MapsetTags::Alternate),
+            description: None,
+            emoji: None,
+            label: "Alternate".to_owned(),
+            value: "alt".to_owned(),
+        },
+        SelectMenuOption {
+            default: tags.contains(MapsetTags::BlueSky),
+            description: None,
+            emoji: None,
+            label: "Blue sky".to_owned(),
+            value: "bluesky".to_owned(),
+        },
+        SelectMenuOption {
+            default: tags.contains(MapsetTags::English),
+            description: None,
+            emoji: None,
+            label: "English".to_owned(),
+            value: "english".to_owned(),
+        },
+        SelectMenuOption {
+            default: tags.contains(MapsetTags::Streams),
+            description: None,
+            emoji: None,
+            label: "Streams".to_owned(),
+            value: "streams".to_owned(),
+        },
+        SelectMenuOption {
+            default: tags.contains(MapsetTags::Old),
+            description: None,
+            emoji: None,
+            label: "Old".to_owned(),
+            value: "old".to_owned(),
+        },
+        SelectMenuOption {
+            default: tags.contains(MapsetTags::Tech),
+            description: None,
+            emoji: None,
+            label: "Tech".to_owned(),
+            value: "tech".to_owned(),
+        },
+    ]
+}
+
 impl From<&InteractionComponent> for MapsetTags {
     fn from(component: &InteractionComponent) -> Self {
         component
             .data
             .values
             .iter()
-            .fold(Self::empty(), |tags, value| {
-                tags | match value.as_str() {
-                    "easy" => Self::Easy,
-                    "hard" => Self::Hard,
-                    "meme" => Self::Meme,
-                    "weeb" => Self::Weeb,
-                    "kpop" => Self::Kpop,
-                    "farm" => Self::Farm,
-                    "hardname" => Self::HardName,
-                    "alt" => Self::Alternate,
-                    "bluesky" => Self::BlueSky,
-                    "english" => Self::English,
-                    "streams" => Self::Streams,
-                    "old" => Self::Old,
-                    "tech" => Self::Tech,
-                    _ => {
-                        warn!(%value, "Unknown mapset tag");
-
-                        return tags;
-                    }
+            .fold(Self::empty(), |tags, value| match value.parse::<Self>() {
+                Ok(tag) => tags | tag,
+                Err(_) => {
+                    warn!(%value, "Unknown mapset tag");
+
+                    tags
                 }
             })
     }
@@ -0,0 +1,134 @@
+use bathbot_model::MapsetTags;
+use bathbot_util::{Authored, EmbedBuilder, FooterBuilder, constants::OSU_BASE};
+use eyre::{Result, WrapErr};
+use twilight_model::{
+    channel::message::{
+        Component,
+        component::{ActionRow, SelectMenu, SelectMenuType},
+    },
+    id::{Id, marker::UserMarker},
+};
+
+use super::mapset_tag_options;
+use crate::{
+    active::{BuildPage, ComponentResult, IActiveMessage},
+    commands::fun::can_edit_mapset_tags,
+    core::Context,
+    util::{ComponentExt, interaction::InteractionComponent},
+};
+
+pub struct BackgroundGameTagEditor {
+    mapset_id: u32,
+    tags: MapsetTags,
+    msg_owner: Id<UserMarker>,
+    status: Option<&'static str>,
+}
+
+impl IActiveMessage for BackgroundGameTagEditor {
+    async fn build_page(&mut self) -> Result<BuildPage> {
+        let mapset_id = self.mapset_id;
+
+        let mut embed = EmbedBuilder::new()
+            .title(format!("Tags of mapset {mapset_id}"))
+            .url(format!("{OSU_BASE}beatmapsets/{mapset_id}"))
+            .description(self.tags.join(", "));
+
+        if let Some(status) = self.status {
+            embed = embed.footer(FooterBuilder::new(status));
+        }
+
+        Ok(BuildPage::new(embed, true))
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        let options = mapset_tag_options(self.tags);
+
+        let menu = SelectMenu {
+            custom_id: "bg_tags_edit".to_owned(),
+            disabled: false,
+            max_values: Some(options.len() as u8),
+            min_values: Some(0),
+            options: Some(options),
+            placeholder: Some("Select the tags of the mapset".to_owned()),
+            channel_types: None,
+            default_values: None,
+            kind: SelectMenuType::Text,
+        };
+
+        let row = ActionRow {
+            components: vec![Component::SelectMenu(menu)],
+        };
+
+        vec![Component::ActionRow(row)]
+    }
+
+    async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        match self.edit_tags(component).await {
+            Ok(res) => res,
+            Err(err) => ComponentResult::Err(err),
+        }
+    }
+}
+
+impl BackgroundGameTagEditor {
+    pub fn new(mapset_id: u32, tags: MapsetTags, msg_owner: Id<UserMarker>) -> Self {
+        Self {
+            mapset_id,
+            tags,
+            msg_owner,
+            status: None,
+        }
+    }
+
+    async fn edit_tags(&mut self, component: &mut InteractionComponent) -> Result<ComponentResult> {
+        let user_id = component.user_id()?;
+
+        if user_id != self.msg_owner {
+            return Ok(ComponentResult::Ignore);
+        }
+
+        if component.data.custom_id != "bg_tags_edit" {
+            warn!(name = %component.data.custom_id, ?component, "Unknown tag editor component");
+
+            return Ok(ComponentResult::Ignore);
+        }
+
+        component
+            .defer()
+            .await
+            .wrap_err("Failed to defer component")?;
+
+        // Permissions might have been revoked since the editor was opened
+        if !can_edit_mapset_tags(user_id, component.guild_id).await? {
+            self.status = Some("You are no longer allowed to edit tags");
+
+            return Ok(ComponentResult::BuildPage);
+        }
+
+        let tags = MapsetTags::from(&*component);
+
+        if tags.bits() == self.tags.bits() {
+            return Ok(ComponentResult::BuildPage);
+        }
+
+        let update_fut = Context::games().bggame_update_mapset_tags(self.mapset_id, tags, user_id);
+
+        match update_fut.await? {
+            Some(old_tags) => {
+                info!(
+                    mapset_id = self.mapset_id,
+                    old = old_tags.join(','),
+                    new = tags.join(','),
+                    editor = %user_id,
+                    "Edited mapset tags",
+                );
+
+                self.tags = tags;
+                self.status = Some("Tags updated");
+            }
+            None => self.status = Some("The mapset is no longer stored"),
+        }
+
+        Ok(ComponentResult::BuildPage)
+    }
+}
@@ -1,6 +1,10 @@
 use std::collections::VecDeque;
 
 use bathbot_psql::model::games::{DbMapTagEntry, MapsetTagsEntries};
+use eyre::{Result, WrapErr};
+use twilight_model::id::{Id, marker::ChannelMarker};
+
+use crate::{core::Context, util::ChannelExt};
 
 pub fn get_random_mapset<'m>(
     entries: &'m MapsetTagsEntries,
@@ -23,3 +27,37 @@ pub fn get_random_mapset<'m>(
         }
     }
 }
+
+/// Send the message that resolves a round and remember its mapset so that the
+/// mapset's tags can be edited through the message.
+///
+/// Only fails if the message could not be sent; failing to remember the
+/// mapset is logged instead.
+pub async fn send_round_result(
+    channel: Id<ChannelMarker>,
+    content: &str,
+    mapset_id: u32,
+) -> Result<()> {
+    let response = channel
+        .plain_message(content)
+        .await
+        .wrap_err("Failed to send message")?;
+
+    let msg = match response.model().await {
+        Ok(msg) => msg,
+        Err(err) => {
+            warn!(?err, mapset_id, "Failed to deserialize round result");
+
+            return Ok(());
+        }
+    };
+
+    if let Err(err) = Context::cache()
+        .store_bg_game_round(msg.id, mapset_id)
+        .await
+    {
+        warn!(?err, mapset_id, "Failed to store bg game round");
+    }
+
+    Ok(())
+}
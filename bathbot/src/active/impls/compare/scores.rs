@@ -2,7 +2,7 @@ use std::fmt::{Display, Formatter, Result as FmtResult, Write};
 
 use bathbot_macros::PaginationBuilder;
 use bathbot_model::{ScoreSlim, embed_builder::ScoreEmbedSettings};
-use bathbot_psql::model::configs::ScoreData;
+use bathbot_psql::model::configs::{ScoreData, TimestampStyle};
 use bathbot_util::{
     CowUtils, EmbedBuilder, FooterBuilder, ModsFormatter, ScoreExt, constants::OSU_BASE,
     datetime::HowLongAgoDynamic, numbers::round,
@@ -24,7 +24,7 @@ use crate::{
     core::BotConfig,
     manager::{OsuMap, redis::osu::CachedUser},
     util::{
-        CachedUserExt, Emote,
+        CachedUserExt, EffectiveMode, Emote,
         interaction::{InteractionComponent, InteractionModal},
         osu::GradeFormatter,
     },
@@ -40,6 +40,8 @@ pub struct CompareScoresPagination {
     pinned: Box<[Score]>,
     pp_idx: usize,
     score_data: ScoreData,
+    timestamp_style: Option<TimestampStyle>,
+    mode: EffectiveMode,
     msg_owner: Id<UserMarker>,
     pages: Pages,
 }
@@ -57,9 +59,10 @@ impl IActiveMessage for CompareScoresPagination {
         let pp_idx = (page == self.pp_idx / 10 + 1).then_some(self.pp_idx % 10);
 
         let footer_text = format!(
-            "Page {page}/{pages} • {status:?} mapset by {creator}",
+            "Page {page}/{pages} • {status:?} mapset by {creator} • {mode}",
             status = self.map.status(),
             creator = self.map.creator(),
+            mode = self.mode,
         );
         let footer_icon = Emote::from(self.map.mode()).url();
         let footer = FooterBuilder::new(footer_text).icon_url(footer_icon);
@@ -88,6 +91,8 @@ impl IActiveMessage for CompareScoresPagination {
                 &self.settings,
                 entry,
                 self.score_data,
+                self.timestamp_style,
+                None,
                 MarkIndex::Skip,
             );
 
@@ -163,7 +168,8 @@ impl IActiveMessage for CompareScoresPagination {
                 } else {
                     embed = embed.fields(applied_settings.fields);
 
-                    if let Some(footer) = applied_settings.footer {
+                    if let Some(mut footer) = applied_settings.footer {
+                        let _ = write!(footer.text, " • {}", self.mode);
                         embed = embed.footer(footer);
                     }
                 }
@@ -1,10 +1,10 @@
 use std::cmp::{self, Ordering};
 
 use bathbot_model::embed_builder::{
-    ComboValue, EmoteTextValue, HitresultsValue, MapperValue, PpValue, ScoreEmbedSettings,
-    SettingValue, SettingsButtons, SettingsImage, Value,
+    ComboValue, EmoteTextValue, HitresultsValue, MapperValue, PpValue, ScoreEmbedPatch,
+    ScoreEmbedSettings, SettingValue, SettingsButtons, SettingsImage, Value,
 };
-use bathbot_psql::model::configs::ScoreData;
+use bathbot_psql::model::configs::{RevisedScoreEmbed, ScoreData};
 use bathbot_util::{Authored, MessageBuilder};
 use eyre::{ContextCompat, Result, WrapErr};
 use twilight_model::{
@@ -15,7 +15,7 @@ use twilight_model::{
     id::{Id, marker::UserMarker},
 };
 
-use super::{SingleScoreContent, SingleScorePagination, single_score::MarkIndex};
+use super::{ScoreSkin, SingleScoreContent, SingleScorePagination, single_score::MarkIndex};
 use crate::{
     active::{BuildPage, ComponentResult, IActiveMessage, response::ActiveResponse},
     commands::utility::ScoreEmbedDataWrap,
@@ -29,6 +29,8 @@ pub struct ScoreEmbedBuilderActive {
     content: ContentStatus,
     section: EmbedSection,
     value_kind: ValueKind,
+    /// Revision of the stored settings that the builder is based on
+    revision: i32,
     msg_owner: Id<UserMarker>,
 }
 
@@ -36,24 +38,28 @@ impl ScoreEmbedBuilderActive {
     pub fn new(
         user: &CachedUser,
         data: ScoreEmbedDataWrap,
-        settings: ScoreEmbedSettings,
+        settings: RevisedScoreEmbed,
         score_data: ScoreData,
+        skin: Option<ScoreSkin>,
         msg_owner: Id<UserMarker>,
     ) -> Self {
-        let inner = SingleScorePagination::new(
+        let mut inner = SingleScorePagination::new(
             user,
             Box::from([data]),
-            settings,
+            settings.settings.unwrap_or_default(),
             score_data,
             msg_owner,
             SingleScoreContent::None,
         );
 
+        inner.set_skin(skin);
+
         Self {
             inner,
             content: ContentStatus::Preview,
             section: EmbedSection::None,
             value_kind: ValueKind::None,
+            revision: settings.revision,
             msg_owner,
         }
     }
@@ -166,6 +172,16 @@ impl IActiveMessage for ScoreEmbedBuilderActive {
                                 value: "ranked_date".to_owned(),
                             },
                             kind_option!("Mapper", "mapper", Mapper),
+                            SelectMenuOption {
+                                default: matches!(self.value_kind, ValueKind::Skin),
+                                description: Some(
+                                    "Note: Only shows on your own scores, see `/config skin`"
+                                        .to_owned(),
+                                ),
+                                emoji: None,
+                                label: "Skin".to_owned(),
+                                value: "skin".to_owned(),
+                            },
                         ]),
                         placeholder: Some("Select a value to display".to_owned()),
                         channel_types: None,
@@ -757,7 +773,7 @@ impl IActiveMessage for ScoreEmbedBuilderActive {
 
                         components.push(arrow_row(idx));
                     }
-                    ValueKind::MapRankedDate => {
+                    ValueKind::MapRankedDate | ValueKind::Skin => {
                         components.push(show_hide_row(idx));
                         components.push(arrow_row(idx));
                     }
@@ -864,7 +880,8 @@ impl IActiveMessage for ScoreEmbedBuilderActive {
                     SelectMenuOption {
                         default: self.inner.settings.buttons.render,
                         description: Some(
-                            "Note: Doesn't work if `/config` score data set to `Stable`".to_owned(),
+                            "Note: Doesn't work if `/config edit` score data set to `Stable`"
+                                .to_owned(),
                         ),
                         emoji: None,
                         label: "Render".to_owned(),
@@ -873,7 +890,8 @@ impl IActiveMessage for ScoreEmbedBuilderActive {
                     SelectMenuOption {
                         default: self.inner.settings.buttons.miss_analyzer,
                         description: Some(
-                            "Note: Doesn't work if `/config` score data set to `Stable`".to_owned(),
+                            "Note: Doesn't work if `/config edit` score data set to `Stable`"
+                                .to_owned(),
                         ),
                         emoji: None,
                         label: "Miss analyzer".to_owned(),
@@ -910,6 +928,8 @@ impl IActiveMessage for ScoreEmbedBuilderActive {
             return ComponentResult::Ignore;
         }
 
+        let prev_settings = self.inner.settings.clone();
+
         match component.data.custom_id.as_str() {
             "embed_builder_section" => {
                 let Some(value) = component.data.values.first() else {
@@ -963,6 +983,7 @@ impl IActiveMessage for ScoreEmbedBuilderActive {
                     "n_spinners" => ValueKind::CountSpinners,
                     "ranked_date" => ValueKind::MapRankedDate,
                     "mapper" => ValueKind::Mapper,
+                    "skin" => ValueKind::Skin,
                     _ => {
                         return ComponentResult::Err(eyre!(
                             "Invalid value `{value}` for builder component `{}`",
@@ -1506,11 +1527,32 @@ impl IActiveMessage for ScoreEmbedBuilderActive {
             debug!(values = ?self.inner.settings.values, "Wrong setting values order");
         }
 
-        let store_fut =
-            Context::user_config().store_score_embed_settings(self.msg_owner, &self.inner.settings);
+        if !self.inner.settings.is_valid() {
+            warn!(values = ?self.inner.settings.values, "Invalid score embed settings");
+            self.content = ContentStatus::Error;
+
+            return ComponentResult::BuildPage;
+        }
+
+        let patch = ScoreEmbedPatch::diff(&prev_settings, &self.inner.settings);
+
+        if patch.is_empty() {
+            return ComponentResult::BuildPage;
+        }
+
+        let store_fut = Context::user_config().patch_score_embed_settings(
+            self.msg_owner,
+            &patch,
+            self.revision,
+        );
 
         match store_fut.await {
-            Ok(_) => self.content = ContentStatus::Preview,
+            Ok(RevisedScoreEmbed { settings, revision }) => {
+                // Includes changes of other builders that were made meanwhile
+                self.inner.settings = settings.unwrap_or_default();
+                self.revision = revision;
+                self.content = ContentStatus::Preview;
+            }
             Err(err) => {
                 self.content = ContentStatus::Error;
                 warn!(?err);
@@ -1589,6 +1631,7 @@ pub enum ValueKind {
     CountSpinners,
     MapRankedDate,
     Mapper,
+    Skin,
 }
 
 impl ValueKind {
@@ -1616,6 +1659,7 @@ impl ValueKind {
             Value::CountSpinners(_) => ValueKind::CountSpinners,
             Value::MapRankedDate => ValueKind::MapRankedDate,
             Value::Mapper(_) => ValueKind::Mapper,
+            Value::Skin => ValueKind::Skin,
         }
     }
 }
@@ -1645,6 +1689,7 @@ impl From<ValueKind> for Value {
             ValueKind::CountSpinners => Self::CountSpinners(Default::default()),
             ValueKind::MapRankedDate => Self::MapRankedDate,
             ValueKind::Mapper => Self::Mapper(Default::default()),
+            ValueKind::Skin => Self::Skin,
             ValueKind::Artist | ValueKind::None => unreachable!(),
         }
     }
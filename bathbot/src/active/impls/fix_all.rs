@@ -0,0 +1,120 @@
+use std::fmt::Write;
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_util::{CowUtils, EmbedBuilder, FooterBuilder, ModsFormatter, constants::OSU_BASE};
+use eyre::Result;
+use twilight_model::{
+    channel::message::Component,
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    active::{
+        BuildPage, ComponentResult, IActiveMessage,
+        pagination::{Pages, handle_pagination_component, handle_pagination_modal},
+    },
+    commands::osu::FixAllEntry,
+    manager::redis::osu::CachedUser,
+    util::{
+        CachedUserExt, Emote,
+        interaction::{InteractionComponent, InteractionModal},
+    },
+};
+
+#[derive(PaginationBuilder)]
+pub struct FixAllPagination {
+    user: CachedUser,
+    #[pagination(per_page = 5)]
+    entries: Box<[FixAllEntry]>,
+    /// Total pp if all entries were unchoked
+    fixed_pp: f32,
+    miss_limit: u32,
+    content: Box<str>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for FixAllPagination {
+    async fn build_page(&mut self) -> Result<BuildPage> {
+        let pages = &self.pages;
+        let end_idx = self.entries.len().min(pages.index() + pages.per_page());
+        let entries = &self.entries[pages.index()..end_idx];
+
+        let pp_raw = self
+            .user
+            .statistics
+            .as_ref()
+            .expect("missing stats")
+            .pp
+            .to_native();
+
+        let pp_diff = (100.0 * (self.fixed_pp - pp_raw)).round() / 100.0;
+        let mut description = String::with_capacity(512);
+
+        for entry in entries {
+            let FixAllEntry {
+                original_idx,
+                new_idx,
+                score,
+                map,
+                if_fc_pp,
+                gain,
+            } = entry;
+
+            let _ = write!(description, "**#{}", original_idx + 1);
+
+            if new_idx < original_idx {
+                let _ = write!(description, " → #{}", new_idx + 1);
+            }
+
+            let _ = writeln!(
+                description,
+                " [{title} [{version}]]({OSU_BASE}b/{id}) +{mods}**\n\
+                {old_pp:.2} → **{if_fc_pp:.2}pp** • {misses}{miss} • \
+                Total: **+{gain:.2}pp**",
+                title = map.title().cow_escape_markdown(),
+                version = map.version().cow_escape_markdown(),
+                id = map.map_id(),
+                mods = ModsFormatter::new(&score.mods, score.is_legacy),
+                old_pp = score.pp,
+                misses = score.statistics.miss,
+                miss = Emote::Miss,
+            );
+        }
+
+        let title = format!(
+            "Total pp: {pp_raw} → **{fixed_pp}pp** (+{pp_diff})",
+            fixed_pp = self.fixed_pp
+        );
+
+        let page = pages.curr_page();
+        let pages = pages.last_page();
+
+        let footer_text = format!(
+            "Page {page}/{pages} • Skipped scores with more than {limit} miss{plural}",
+            limit = self.miss_limit,
+            plural = if self.miss_limit == 1 { "" } else { "es" },
+        );
+
+        let embed = EmbedBuilder::new()
+            .author(self.user.author_builder(false))
+            .description(description)
+            .footer(FooterBuilder::new(footer_text))
+            .thumbnail(self.user.avatar_url.as_ref())
+            .title(title);
+
+        Ok(BuildPage::new(embed, false).content(self.content.clone()))
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        handle_pagination_component(component, self.msg_owner, false, &mut self.pages).await
+    }
+
+    async fn handle_modal(&mut self, modal: &mut InteractionModal) -> Result<()> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages).await
+    }
+}
@@ -257,10 +257,7 @@ impl HelpPrefixMenu {
             },
         );
 
-        let link = InteractionCommands::get_command("link").map_or_else(
-            || "`/link`".to_owned(),
-            |cmd| cmd.mention("link").to_string(),
-        );
+        let link = InteractionCommands::mention("link");
 
         let description = format!(
             ":fire: **Slash commands are supported!** Type `/` to check them out :fire:\n\n\
@@ -189,9 +189,13 @@ impl IActiveMessage for HigherLowerGame {
 }
 
 impl HigherLowerGame {
-    pub async fn new_score_pp(mode: GameMode, msg_owner: Id<UserMarker>) -> Result<Self> {
-        let game_fut = HigherLowerState::start_score_pp(mode);
-        let highscore_fut = Context::games().higherlower_highscore(msg_owner, HlVersion::ScorePp);
+    pub async fn new(
+        version: HlVersion,
+        mode: GameMode,
+        msg_owner: Id<UserMarker>,
+    ) -> Result<Self> {
+        let game_fut = HigherLowerState::start_score(version, mode);
+        let highscore_fut = Context::games().higherlower_highscore(msg_owner, version);
 
         let ((state, rx), highscore) = tokio::try_join!(game_fut, highscore_fut)?;
 
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use bathbot_model::rosu_v2::ranking::ArchivedRankingsUser;
+use bathbot_model::{HlVersion, rosu_v2::ranking::ArchivedRankingsUser};
 use bathbot_util::{
     EmbedBuilder, ModsFormatter,
     constants::OSU_BASE,
@@ -13,7 +13,7 @@ use rosu_v2::prelude::{CountryCode, GameMode, GameMods, Grade, Score, User as Us
 use twilight_model::channel::message::embed::EmbedField;
 
 use crate::{
-    active::impls::higherlower::state::{H, HigherLowerState, W, mapset_cover},
+    active::impls::higherlower::state::{H, HigherLowerState, HlValue, W, mapset_cover},
     core::Context,
     manager::OsuMapSlim,
     util::{Emote, osu::grade_emote},
@@ -27,7 +27,8 @@ pub(super) struct ScorePp {
     pub mapset_id: u32,
     user_id: u32,
     map_id: u32,
-    player_string: Box<str>,
+    global_rank: u32,
+    player_name: Box<str>,
     map_string: Box<str>,
     mods: GameMods,
     combo: u32,
@@ -183,6 +184,32 @@ impl ScorePp {
         HigherLowerState::upload_image(blipped.as_raw(), content).await
     }
 
+    pub(super) fn value(&self) -> HlValue {
+        HlValue {
+            pp: self.pp,
+            rank: self.global_rank,
+        }
+    }
+
+    /// Whether `next` must be re-rolled because it's too similar to
+    /// `previous`.
+    ///
+    /// Comparing ranks of the same player would always be a tie.
+    pub(super) fn is_repeat(version: HlVersion, previous: &Self, next: &Self) -> bool {
+        match version {
+            HlVersion::ScorePp => previous == next,
+            HlVersion::ScoreRank => previous.user_id == next.user_id,
+        }
+    }
+
+    fn player_string(&self, rank_visible: bool) -> String {
+        if rank_visible {
+            format!("{} (#{})", self.player_name, self.global_rank)
+        } else {
+            format!("{} (#???)", self.player_name)
+        }
+    }
+
     pub(super) fn play_string(&self, pp_visible: bool) -> String {
         format!(
             "**{map} +{mods}**\n{grade} {score} • **{acc}%** • **{combo}x**{max_combo} {miss}• **{pp}pp**",
@@ -212,20 +239,34 @@ impl ScorePp {
     }
 
     pub(super) fn log(game1: &Self, game2: &Self) {
-        debug!("{}pp vs {}pp", game1.pp, game2.pp);
+        debug!(
+            "{}pp (#{}) vs {}pp (#{})",
+            game1.pp, game1.global_rank, game2.pp, game2.global_rank
+        );
     }
 
-    pub(super) fn to_embed(previous: &Self, next: &Self, revealed: bool) -> EmbedBuilder {
+    pub(super) fn to_embed(
+        version: HlVersion,
+        previous: &Self,
+        next: &Self,
+        revealed: bool,
+    ) -> EmbedBuilder {
+        // Only the guessed value of the next round is hidden
+        let (pp_visible, rank_visible) = match version {
+            HlVersion::ScorePp => (revealed, true),
+            HlVersion::ScoreRank => (true, revealed),
+        };
+
         let fields = vec![
             EmbedField {
                 inline: false,
-                name: format!("__Previous:__ {}", previous.player_string),
+                name: format!("__Previous:__ {}", previous.player_string(true)),
                 value: previous.play_string(true),
             },
             EmbedField {
                 inline: false,
-                name: format!("__Next:__ {}", next.player_string),
-                value: next.play_string(revealed),
+                name: format!("__Next:__ {}", next.player_string(rank_visible)),
+                value: next.play_string(pp_visible),
             },
         ];
 
@@ -248,8 +289,8 @@ impl ScorePp {
             avatar_url,
             map_id: map.map_id(),
             mapset_id: map.mapset_id(),
-            player_string: format!(":flag_{country_code}: {username} (#{global_rank})")
-                .into_boxed_str(),
+            global_rank,
+            player_name: format!(":flag_{country_code}: {username}").into_boxed_str(),
             map_string: format!(
                 "[{artist} - {title} [{version}]]({OSU_BASE}b/{map_id})",
                 artist = map.artist(),
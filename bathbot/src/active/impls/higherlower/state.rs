@@ -6,6 +6,7 @@ use eyre::{ContextCompat, Result, WrapErr};
 use image::{ColorType, ImageEncoder, codecs::png::PngEncoder};
 use rosu_v2::prelude::GameMode;
 use tokio::sync::oneshot::{self, Receiver};
+use tracing::Instrument;
 
 use super::{HlGuess, score_pp::ScorePp};
 use crate::{core::BotConfig, util::ChannelExt};
@@ -28,7 +29,8 @@ pub(super) enum ButtonState {
 // seems to be a false alarm by clippy
 #[allow(clippy::large_enum_variant)]
 pub(super) enum HigherLowerState {
-    ScorePp {
+    Score {
+        version: HlVersion,
         mode: GameMode,
         previous: ScorePp,
         next: ScorePp,
@@ -36,14 +38,17 @@ pub(super) enum HigherLowerState {
 }
 
 impl HigherLowerState {
-    pub(super) async fn start_score_pp(mode: GameMode) -> Result<(Self, Receiver<String>)> {
+    pub(super) async fn start_score(
+        version: HlVersion,
+        mode: GameMode,
+    ) -> Result<(Self, Receiver<String>)> {
         let (previous, mut next) = tokio::try_join!(
             ScorePp::random(mode, None, 0),
             ScorePp::random(mode, None, 0)
         )
         .wrap_err("Failed to create score pp entry")?;
 
-        while next == previous {
+        while ScorePp::is_repeat(version, &previous, &next) {
             next = ScorePp::random(mode, None, 0)
                 .await
                 .wrap_err("Failed to create score pp entry")?;
@@ -70,7 +75,8 @@ impl HigherLowerState {
 
         let _ = tx.send(url);
 
-        let inner = Self::ScorePp {
+        let inner = Self::Score {
+            version,
             mode,
             previous,
             next,
@@ -81,17 +87,19 @@ impl HigherLowerState {
 
     pub(super) async fn restart(&mut self) -> Result<(Self, Receiver<String>)> {
         match self {
-            Self::ScorePp { mode, .. } => Self::start_score_pp(*mode).await,
+            Self::Score { version, mode, .. } => Self::start_score(*version, *mode).await,
         }
     }
 
     pub(super) async fn next(&mut self, curr_score: u32) -> Result<Receiver<String>> {
         let rx = match self {
-            Self::ScorePp {
+            Self::Score {
+                version,
                 mode,
                 previous,
                 next,
             } => {
+                let version = *version;
                 let mode = *mode;
                 mem::swap(previous, next);
 
@@ -99,7 +107,7 @@ impl HigherLowerState {
                     .await
                     .wrap_err("Failed to create score pp entry")?;
 
-                while previous == next {
+                while ScorePp::is_repeat(version, previous, next) {
                     *next = ScorePp::random(mode, Some(&*previous), curr_score)
                         .await
                         .wrap_err("Failed to create score pp entry")?;
@@ -118,18 +126,21 @@ impl HigherLowerState {
                 let (tx, rx) = oneshot::channel();
 
                 // Create the image in the background so it's available when needed later
-                tokio::spawn(async move {
-                    let url = match ScorePp::image(&pfp1, &pfp2, mapset_id1, mapset_id2).await {
-                        Ok(url) => url,
-                        Err(err) => {
-                            warn!(?err, "Failed to create image");
-
-                            String::new()
-                        }
-                    };
-
-                    let _ = tx.send(url);
-                });
+                tokio::spawn(
+                    async move {
+                        let url = match ScorePp::image(&pfp1, &pfp2, mapset_id1, mapset_id2).await {
+                            Ok(url) => url,
+                            Err(err) => {
+                                warn!(?err, "Failed to create image");
+
+                                String::new()
+                            }
+                        };
+
+                        let _ = tx.send(url);
+                    }
+                    .in_current_span(),
+                );
 
                 rx
             }
@@ -169,12 +180,16 @@ impl HigherLowerState {
         let mut title = "Higher or Lower: ".to_owned();
 
         let builder = match self {
-            HigherLowerState::ScorePp {
+            HigherLowerState::Score {
+                version,
                 mode,
                 previous,
                 next,
             } => {
-                title.push_str("Score PP");
+                match version {
+                    HlVersion::ScorePp => title.push_str("Score PP"),
+                    HlVersion::ScoreRank => title.push_str("Player Rank"),
+                }
 
                 match mode {
                     GameMode::Osu => {}
@@ -183,7 +198,7 @@ impl HigherLowerState {
                     GameMode::Mania => title.push_str(" (mania)"),
                 }
 
-                ScorePp::to_embed(previous, next, revealed)
+                ScorePp::to_embed(*version, previous, next, revealed)
             }
         };
 
@@ -192,20 +207,129 @@ impl HigherLowerState {
 
     pub(super) fn check_guess(&self, guess: HlGuess) -> bool {
         match self {
-            Self::ScorePp { previous, next, .. } => match guess {
-                HlGuess::Higher => next.pp >= previous.pp,
-                HlGuess::Lower => next.pp <= previous.pp,
-            },
+            Self::Score {
+                version,
+                previous,
+                next,
+                ..
+            } => check_guess(*version, previous.value(), next.value(), guess),
         }
     }
 
     pub(super) fn version(&self) -> HlVersion {
         match self {
-            Self::ScorePp { .. } => HlVersion::ScorePp,
+            Self::Score { version, .. } => *version,
         }
     }
 }
 
+/// The values of a round that can be guessed.
+#[derive(Copy, Clone)]
+pub(super) struct HlValue {
+    pub pp: f32,
+    pub rank: u32,
+}
+
+/// Whether `guess` is correct for the `next` value compared to the
+/// `previous` one.
+///
+/// A numerically lower rank counts as a *higher* rank. Equal values are
+/// correct for both guesses.
+fn check_guess(version: HlVersion, previous: HlValue, next: HlValue, guess: HlGuess) -> bool {
+    let ordering = match version {
+        HlVersion::ScorePp => next.pp.total_cmp(&previous.pp),
+        HlVersion::ScoreRank => previous.rank.cmp(&next.rank),
+    };
+
+    match guess {
+        HlGuess::Higher => ordering.is_ge(),
+        HlGuess::Lower => ordering.is_le(),
+    }
+}
+
 pub(super) fn mapset_cover(mapset_id: u32) -> String {
     format!("https://assets.ppy.sh/beatmaps/{mapset_id}/covers/cover.jpg")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(pp: f32, rank: u32) -> HlValue {
+        HlValue { pp, rank }
+    }
+
+    #[test]
+    fn score_pp_guess() {
+        let previous = value(400.0, 1000);
+        let next = value(500.0, 2000);
+
+        assert!(check_guess(
+            HlVersion::ScorePp,
+            previous,
+            next,
+            HlGuess::Higher
+        ));
+        assert!(!check_guess(
+            HlVersion::ScorePp,
+            previous,
+            next,
+            HlGuess::Lower
+        ));
+        assert!(check_guess(
+            HlVersion::ScorePp,
+            next,
+            previous,
+            HlGuess::Lower
+        ));
+        assert!(!check_guess(
+            HlVersion::ScorePp,
+            next,
+            previous,
+            HlGuess::Higher
+        ));
+    }
+
+    #[test]
+    fn score_rank_guess_is_inverted() {
+        // Rank #1000 is higher than rank #2000
+        let previous = value(400.0, 2000);
+        let next = value(300.0, 1000);
+
+        assert!(check_guess(
+            HlVersion::ScoreRank,
+            previous,
+            next,
+            HlGuess::Higher
+        ));
+        assert!(!check_guess(
+            HlVersion::ScoreRank,
+            previous,
+            next,
+            HlGuess::Lower
+        ));
+        assert!(check_guess(
+            HlVersion::ScoreRank,
+            next,
+            previous,
+            HlGuess::Lower
+        ));
+        assert!(!check_guess(
+            HlVersion::ScoreRank,
+            next,
+            previous,
+            HlGuess::Higher
+        ));
+    }
+
+    #[test]
+    fn ties_are_correct() {
+        let previous = value(400.0, 1000);
+        let next = value(400.0, 1000);
+
+        for version in [HlVersion::ScorePp, HlVersion::ScoreRank] {
+            assert!(check_guess(version, previous, next, HlGuess::Higher));
+            assert!(check_guess(version, previous, next, HlGuess::Lower));
+        }
+    }
+}
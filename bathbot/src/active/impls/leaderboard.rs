@@ -38,6 +38,7 @@ pub struct LeaderboardPagination {
     author_data: Option<LeaderboardUserScore>,
     first_place_icon: Option<Box<str>>,
     score_data: ScoreData,
+    anomalies: bool,
     content: Box<str>,
     msg_owner: Id<UserMarker>,
     pages: Pages,
@@ -113,20 +114,33 @@ impl IActiveMessage for LeaderboardPagination {
         let page = self.pages.curr_page();
         let pages = self.pages.last_page();
 
-        let footer_text = format!(
+        let mut footer_text = format!(
             "Page {page}/{pages} • {status:?} mapset of {creator}",
             status = self.map.status(),
             creator = self.map.creator(),
         );
 
+        if self.anomalies {
+            footer_text
+                .push_str("\n⚠: Set before ranking, full accuracy despite misses, or pp far off");
+        }
+
         let footer_icon = Emote::from(self.map.mode()).url();
         let footer = FooterBuilder::new(footer_text).icon_url(footer_icon);
 
+        // Label the scores so that screenshots are unambiguous
+        let title = match self.score_data {
+            ScoreData::Stable => "Stable leaderboard",
+            ScoreData::Lazer => "Lazer leaderboard",
+            ScoreData::LazerWithClassicScoring => "Lazer leaderboard (classic scoring)",
+        };
+
         let embed = EmbedBuilder::new()
             .author(author)
             .description(description)
             .footer(footer)
-            .thumbnail(self.map.thumbnail());
+            .thumbnail(self.map.thumbnail())
+            .title(title);
 
         Ok(BuildPage::new(embed, true).content(self.content.clone()))
     }
@@ -235,7 +249,7 @@ impl Display for ScoreFormatter<'_> {
         writeln!(
             f,
             "**#{i}** {underline}**[{username}]({OSU_BASE}users/{user_id})**{underline}: {score} [ {combo} ] **+{mods}**\n\
-            {grade} {pp} • {acc:.2}% • {miss}{ago}",
+            {grade} {pp} • {acc:.2}% • {miss}{ago}{anomaly}",
             i = self.score.pos,
             underline = if self.found_author { "__" } else { "" },
             username = self.score.username,
@@ -252,6 +266,7 @@ impl Display for ScoreFormatter<'_> {
             acc = self.score.accuracy,
             miss = MissFormat(self.score.statistics.miss),
             ago = HowLongAgoDynamic::new(&self.score.ended_at),
+            anomaly = if self.score.anomalous { " ⚠" } else { "" },
         )
     }
 }
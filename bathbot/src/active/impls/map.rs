@@ -31,7 +31,7 @@ use crate::{
     core::Context,
     manager::redis::osu::UserArgs,
     util::{
-        Emote, ModalExt,
+        Emote, ModalExt, density_sparkline,
         interaction::{InteractionComponent, InteractionModal},
     },
 };
@@ -43,6 +43,7 @@ pub struct MapPagination {
     maps: Box<[BeatmapExtended]>,
     mods: GameModsIntermode,
     attrs: CustomAttrs,
+    density: bool,
     origin: MessageOrigin,
     content: Box<str>,
     msg_owner: Id<UserMarker>,
@@ -225,6 +226,14 @@ impl IActiveMessage for MapPagination {
             map.count_spinners,
         );
 
+        if self.density {
+            let timestamps = rosu_map.hit_objects.iter().map(|h| h.start_time);
+
+            if let Some(sparkline) = density_sparkline(timestamps, 10_000.0, clock_rate) {
+                let _ = write!(info_value, "\n```\n{sparkline}\n```");
+            }
+        }
+
         let mut info_name = format!(
             "{mode} __[{version}]__",
             mode = Emote::from(map.mode),
@@ -29,6 +29,7 @@ pub struct MedalsListPagination {
     medals: Box<[MedalEntryList]>,
     content: Box<str>,
     msg_owner: Id<UserMarker>,
+    command: Option<String>,
     pages: Pages,
 }
 
@@ -57,11 +58,23 @@ impl IActiveMessage for MedalsListPagination {
 
             let url = url.cow_replace("%25", "%");
 
+            let _ = write!(
+                description,
+                "**#{i} [{medal}]({url})**",
+                medal = entry.name()
+            );
+
+            if let Some(ref variants) = entry.variants {
+                let _ = write!(
+                    description,
+                    " • {}/{} modes",
+                    variants.achieved, variants.total
+                );
+            }
+
             let _ = writeln!(
                 description,
-                "**#{i} [{medal}]({url})**\n\
-                `{rarity:>5.2}%` • <t:{timestamp}:d> • {group}",
-                medal = entry.medal.name,
+                "\n`{rarity:>5.2}%` • <t:{timestamp}:d> • {group}",
                 rarity = entry.rarity,
                 timestamp = entry.achieved.unix_timestamp(),
                 group = entry.medal.grouping,
@@ -106,4 +119,10 @@ impl IActiveMessage for MedalsListPagination {
     async fn handle_modal(&mut self, modal: &mut InteractionModal) -> Result<()> {
         handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages).await
     }
+
+    fn invocation(&self) -> Option<String> {
+        self.command
+            .as_deref()
+            .map(|command| self.pages.invocation(command))
+    }
 }
@@ -19,7 +19,7 @@ use crate::{
         pagination::{Pages, handle_pagination_component, handle_pagination_modal},
     },
     commands::osu::{MedalAchieved, MedalEmbed},
-    manager::redis::osu::CachedUser,
+    manager::redis::{CacheMeta, osu::CachedUser},
     util::interaction::{InteractionComponent, InteractionModal},
 };
 
@@ -27,6 +27,7 @@ use crate::{
 pub struct MedalsRecentPagination {
     user: CachedUser,
     medals: CachedArchive<ArchivedVec<ArchivedOsekaiMedal>>,
+    medals_meta: CacheMeta,
     #[pagination(per_page = 1)]
     achieved_medals: Box<[MedalCompact]>,
     embeds: HashMap<usize, MedalEmbed, IntHasher>,
@@ -40,6 +41,9 @@ impl IActiveMessage for MedalsRecentPagination {
     async fn build_page(&mut self) -> Result<BuildPage> {
         let idx = self.pages.index();
 
+        // Keep the medals cached while they're being browsed
+        self.medals_meta.refresh().await;
+
         let embed = match self.embeds.entry(idx) {
             Entry::Occupied(e) => e.get().to_owned(),
             Entry::Vacant(e) => {
@@ -2,13 +2,15 @@
 #[allow(unused)]
 pub use self::single_score::MarkIndex;
 pub use self::{
+    audit_log::AuditLogPagination,
     badges::BadgesPagination,
-    bg_game::{BackgroundGame, BackgroundGameSetup},
+    bg_game::{BackgroundGame, BackgroundGameSetup, BackgroundGameTagEditor},
     bookmarks::BookmarksPagination,
     changelog::ChangelogPagination,
     compare::{CompareMostPlayedPagination, CompareScoresPagination, CompareTopPagination},
     daily_challenge::DailyChallengeTodayPagination,
     embed_builder::ScoreEmbedBuilderActive,
+    fix_all::FixAllPagination,
     help::{HelpInteractionCommand, HelpPrefixMenu},
     higherlower::HigherLowerGame,
     leaderboard::LeaderboardPagination,
@@ -23,22 +25,31 @@ pub use self::{
     most_played::MostPlayedPagination,
     nochoke::NoChokePagination,
     osekai::{MedalCountPagination, MedalRarityPagination},
-    osustats::{OsuStatsBestPagination, OsuStatsPlayersPagination, OsuStatsScoresPagination},
+    osustats::{
+        OsuStatsBestPagination, OsuStatsBestUser, OsuStatsPlayersPagination,
+        OsuStatsScoresPagination,
+    },
     profile::ProfileMenu,
     ranking::RankingPagination,
     ranking_countries::RankingCountriesPagination,
     recent_list::RecentListPagination,
     render::{CachedRender, RenderSettingsActive, SettingsImport},
+    score_debug::ScoreDebugActive,
+    server_config_import::ServerConfigImportActive,
     simulate::{SimulateAttributes, SimulateComponents, SimulateData, SimulateMap, TopOldVersion},
-    single_score::{SingleScoreContent, SingleScorePagination},
+    single_score::{ScoreSkin, SingleScoreContent, SingleScorePagination},
     skins::SkinsPagination,
     slash_commands::SlashCommandsPagination,
-    snipe::{SnipeCountryListPagination, SnipeDifferencePagination, SnipePlayerListPagination},
-    top::TopPagination,
+    snipe::{
+        SnipeCountryListPagination, SnipeCountryPlayersPagination, SnipeDifferencePagination,
+        SnipePlayerListPagination, country_player_position, sort_country_players,
+    },
+    top::{MapFormat, TopPagination},
     top_if::TopIfPagination,
     track_list::TrackListPagination,
 };
 
+mod audit_log;
 mod badges;
 mod bg_game;
 mod bookmarks;
@@ -46,6 +57,7 @@ mod changelog;
 mod compare;
 mod daily_challenge;
 mod embed_builder;
+mod fix_all;
 mod help;
 mod higherlower;
 mod leaderboard;
@@ -64,6 +76,8 @@ mod ranking_countries;
 mod recent_list;
 pub mod relax;
 mod render;
+mod score_debug;
+mod server_config_import;
 mod simulate;
 mod single_score;
 mod skins;
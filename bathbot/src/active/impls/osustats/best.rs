@@ -23,6 +23,7 @@ use crate::{
     commands::osu::OsuStatsBestSort,
     core::BotConfig,
     embeds::ComboFormatter,
+    manager::redis::osu::CachedUser,
     util::{
         Emote,
         interaction::{InteractionComponent, InteractionModal},
@@ -33,6 +34,7 @@ use crate::{
 pub struct OsuStatsBestPagination {
     #[pagination(per_page = 10, len = "scores.scores.len()")]
     scores: OsuStatsBestScores,
+    user: Option<OsuStatsBestUser>,
     mode: GameMode,
     sort: OsuStatsBestSort,
     msg_owner: Id<UserMarker>,
@@ -49,19 +51,34 @@ impl IActiveMessage for OsuStatsBestPagination {
             ref scores,
         } = self.scores;
 
-        let author_text = format!(
-            "Top {mode} scores between {start} and {end}:",
-            mode = match self.mode {
-                GameMode::Osu => "osu!",
-                GameMode::Taiko => "taiko",
-                GameMode::Catch => "ctb",
-                GameMode::Mania => "mania",
-            },
-            start = start_date.format(DATE_FORMAT).unwrap(),
-            end = end_date.format(DATE_FORMAT).unwrap(),
-        );
+        let mode = match self.mode {
+            GameMode::Osu => "osu!",
+            GameMode::Taiko => "taiko",
+            GameMode::Catch => "ctb",
+            GameMode::Mania => "mania",
+        };
+
+        let start = start_date.format(DATE_FORMAT).unwrap();
+        let end = end_date.format(DATE_FORMAT).unwrap();
+
+        let author = match self.user {
+            Some(ref user) => {
+                let author_text = format!(
+                    "{name}'s placements in the top {total} {mode} scores between {start} and {end}:",
+                    name = user.user.username,
+                    total = user.total,
+                );
+
+                AuthorBuilder::new(author_text)
+                    .url("https://osustats.ppy.sh/")
+                    .icon_url(user.user.avatar_url.as_ref())
+            }
+            None => {
+                let author_text = format!("Top {mode} scores between {start} and {end}:");
 
-        let author = AuthorBuilder::new(author_text).url("https://osustats.ppy.sh/");
+                AuthorBuilder::new(author_text).url("https://osustats.ppy.sh/")
+            }
+        };
 
         let footer_text = format!(
             "Page {page}/{pages} • Sorted by {sort}",
@@ -89,9 +106,10 @@ impl IActiveMessage for OsuStatsBestPagination {
         for (score, i) in scores.iter().zip(idx + 1..) {
             let _ = writeln!(
                 description,
-                "**#{i} [{artist} - {title} [{version}]]({OSU_BASE}b/{map_id}) +{mods}**\n\
+                "**#{placement} [{artist} - {title} [{version}]]({OSU_BASE}b/{map_id}) +{mods}**\n\
                 by __[{user}]({OSU_BASE}u/{user_id})__ {grade} **{pp}pp** \
                 • {acc}% • [ {combo} ] {appendix}",
+                placement = Placement::new(self.user.as_ref(), i),
                 artist = score.map.artist,
                 title = score.map.title,
                 version = score.map.version,
@@ -128,6 +146,48 @@ impl IActiveMessage for OsuStatsBestPagination {
     }
 }
 
+/// Scores of a single user within the full list of best scores.
+pub struct OsuStatsBestUser {
+    user: CachedUser,
+    /// 1-based positions within the full list
+    placements: Box<[usize]>,
+    total: usize,
+}
+
+impl OsuStatsBestUser {
+    pub fn new(user: CachedUser, placements: Box<[usize]>, total: usize) -> Self {
+        Self {
+            user,
+            placements,
+            total,
+        }
+    }
+}
+
+struct Placement<'u> {
+    user: Option<&'u OsuStatsBestUser>,
+    i: usize,
+}
+
+impl<'u> Placement<'u> {
+    fn new(user: Option<&'u OsuStatsBestUser>, i: usize) -> Self {
+        Self { user, i }
+    }
+}
+
+impl Display for Placement<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let placement = self
+            .user
+            .and_then(|user| Some((user.placements.get(self.i - 1)?, user.total)));
+
+        match placement {
+            Some((placement, total)) => write!(f, "{placement} of {total}"),
+            None => write!(f, "{}", self.i),
+        }
+    }
+}
+
 struct OrderAppendix<'s> {
     score: &'s OsuStatsBestScore,
     sort: OsuStatsBestSort,
@@ -1,5 +1,6 @@
 pub use self::{
-    best::OsuStatsBestPagination, players::OsuStatsPlayersPagination,
+    best::{OsuStatsBestPagination, OsuStatsBestUser},
+    players::OsuStatsPlayersPagination,
     scores::OsuStatsScoresPagination,
 };
 
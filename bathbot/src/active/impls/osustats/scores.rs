@@ -1,9 +1,13 @@
-use std::{collections::BTreeMap, fmt::Write};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+    ops::Range,
+};
 
 use bathbot_macros::PaginationBuilder;
 use bathbot_model::{OsuStatsParams, OsuStatsScoresRaw, ScoreSlim};
 use bathbot_util::{
-    CowUtils, EmbedBuilder, FooterBuilder, ModsFormatter,
+    CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder, ModsFormatter,
     constants::OSU_BASE,
     datetime::HowLongAgoDynamic,
     numbers::{WithComma, round},
@@ -25,119 +29,41 @@ use crate::{
     embeds::{ComboFormatter, HitResultFormatter, PpFormatter},
     manager::redis::osu::CachedUser,
     util::{
-        CachedUserExt,
+        CachedUserExt, ComponentExt, ModalExt,
         interaction::{InteractionComponent, InteractionModal},
         osu::grade_emote,
     },
 };
 
+/// Amount of scores per osustats page.
+const OSUSTATS_PAGE_SIZE: usize = 24;
+
+/// Scores past this amount are not browsable to bound the amount of requests.
+const MAX_ENTRIES: usize = 500;
+
 #[derive(PaginationBuilder)]
 pub struct OsuStatsScoresPagination {
     user: CachedUser,
-    #[pagination(per_page = 5, len = "total")]
+    #[pagination(per_page = 5, len = "total.min(MAX_ENTRIES)")]
     entries: BTreeMap<usize, OsuStatsEntry>,
     total: usize,
     params: OsuStatsParams,
+    /// osustats pages whose scores are already in `entries`
+    loaded_pages: BTreeSet<usize>,
     content: Box<str>,
     msg_owner: Id<UserMarker>,
+    command: Option<String>,
     pages: Pages,
 }
 
 impl IActiveMessage for OsuStatsScoresPagination {
     async fn build_page(&mut self) -> Result<BuildPage> {
-        let pages = &self.pages;
-
-        let entries = self
-            .entries
-            .range(pages.index()..pages.index() + pages.per_page());
-        let count = entries.count();
-
-        if count < pages.per_page() && self.total - pages.index() > count {
-            let osustats_page = (pages.index() / 24) + 1;
-            self.params.page = osustats_page;
-            let scores_fut = Context::client().get_global_scores(&self.params);
-
-            let scores = match scores_fut.await.map(OsuStatsScoresRaw::into_scores) {
-                Ok(Ok(scores)) => scores.scores,
-                Err(err) | Ok(Err(err)) => return Err(err.wrap_err("Failed to get global scores")),
-            };
-
-            let maps_id_checksum = scores
-                .iter()
-                .map(|score| (score.map.map_id as i32, None))
-                .collect();
-
-            let mut maps = Context::osu_map().maps(&maps_id_checksum).await?;
-            let mode = self.params.mode;
-
-            for (score, i) in scores.into_iter().zip((osustats_page - 1) * 24..) {
-                let map_opt = maps.remove(&score.map.map_id);
-                let Some(map) = map_opt else { continue };
-
-                let mut calc = Context::pp(&map).mods(score.mods.clone()).mode(mode);
-                let attrs = calc.performance().await;
-
-                let pp = match score.pp {
-                    Some(pp) => pp,
-                    None => match calc.score(&score).performance().await {
-                        Some(attrs) => attrs.pp() as f32,
-                        None => 0.0,
-                    },
-                };
-
-                let mut max_pp = 0.0;
-                let mut stars = 0.0;
-                let mut max_combo = 0;
-
-                if let Some(attrs) = attrs {
-                    max_pp = attrs.pp() as f32;
-                    stars = attrs.stars() as f32;
-                    max_combo = attrs.max_combo();
-                }
-
-                if score.grade.eq_letter(Grade::X) && mode != GameMode::Mania && pp > 0.0 {
-                    max_pp = pp;
-                }
-
-                let rank = score.position;
-
-                let score = ScoreSlim {
-                    accuracy: score.accuracy,
-                    ended_at: score.ended_at,
-                    grade: score.grade,
-                    max_combo: score.max_combo,
-                    mode,
-                    mods: score.mods,
-                    pp,
-                    score: score.score,
-                    classic_score: 0,
-                    score_id: 0,
-                    statistics: ScoreStatistics {
-                        perfect: score.count_geki,
-                        great: score.count300,
-                        good: score.count_katu,
-                        ok: score.count100,
-                        meh: score.count50,
-                        miss: score.count_miss,
-                        ..Default::default()
-                    },
-                    set_on_lazer: false,
-                    is_legacy: true,
-                };
-
-                let entry = OsuStatsEntry {
-                    score,
-                    map,
-                    rank,
-                    max_pp,
-                    stars,
-                    max_combo,
-                };
-
-                self.entries.insert(i, entry);
-            }
+        for api_page in self.missing_pages() {
+            self.load_page(api_page).await?;
         }
 
+        let pages = &self.pages;
+
         if self.entries.is_empty() {
             let embed = EmbedBuilder::new()
                 .author(self.user.author_builder(false))
@@ -186,10 +112,13 @@ impl IActiveMessage for OsuStatsScoresPagination {
             );
         }
 
-        let footer = FooterBuilder::new(format!(
-            "Page {page}/{pages} • Total scores: {}",
-            self.total
-        ));
+        let mut footer_text = format!("Page {page}/{pages} • Total scores: {}", self.total);
+
+        if self.total > MAX_ENTRIES {
+            let _ = write!(footer_text, " (showing the first {MAX_ENTRIES})");
+        }
+
+        let footer = FooterBuilder::new(footer_text);
 
         let embed = EmbedBuilder::new()
             .author(self.user.author_builder(false))
@@ -205,10 +134,222 @@ impl IActiveMessage for OsuStatsScoresPagination {
     }
 
     async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
-        handle_pagination_component(component, self.msg_owner, true, &mut self.pages).await
+        let res =
+            handle_pagination_component(component, self.msg_owner, true, &mut self.pages).await;
+
+        // The component was deferred so the message can be updated while the
+        // scores are being fetched
+        if matches!(res, ComponentResult::BuildPage)
+            && let Some(builder) = self.loading_message()
+            && let Err(err) = component.update(builder).await
+        {
+            warn!(?err, "Failed to show loading page");
+        }
+
+        res
     }
 
     async fn handle_modal(&mut self, modal: &mut InteractionModal) -> Result<()> {
-        handle_pagination_modal(modal, self.msg_owner, true, &mut self.pages).await
+        let index = self.pages.index();
+        handle_pagination_modal(modal, self.msg_owner, true, &mut self.pages).await?;
+
+        // The modal is only deferred if the page changed
+        if self.pages.index() != index
+            && let Some(builder) = self.loading_message()
+            && let Err(err) = modal.update(builder).await
+        {
+            warn!(?err, "Failed to show loading page");
+        }
+
+        Ok(())
+    }
+
+    fn invocation(&self) -> Option<String> {
+        self.command
+            .as_deref()
+            .map(|command| self.pages.invocation(command))
+    }
+}
+
+impl OsuStatsScoresPagination {
+    /// The osustats pages of the current embed page that were not fetched
+    /// yet.
+    fn missing_pages(&self) -> Vec<usize> {
+        let len = self.total.min(MAX_ENTRIES);
+
+        api_pages(self.pages.index(), self.pages.per_page(), len)
+            .filter(|api_page| !self.loaded_pages.contains(api_page))
+            .collect()
+    }
+
+    /// A placeholder for the current embed page while its scores are still
+    /// being fetched, `None` if all of them are available already.
+    fn loading_message(&self) -> Option<MessageBuilder<'static>> {
+        if self.missing_pages().is_empty() {
+            return None;
+        }
+
+        let footer = FooterBuilder::new(format!(
+            "Page {}/{} • Total scores: {}",
+            self.pages.curr_page(),
+            self.pages.last_page(),
+            self.total
+        ));
+
+        let embed = EmbedBuilder::new()
+            .author(self.user.author_builder(false))
+            .description("Loading more scores from osustats...")
+            .footer(footer)
+            .thumbnail(self.user.avatar_url.as_ref());
+
+        let builder = MessageBuilder::new()
+            .embed(embed)
+            .content(self.content.to_string())
+            .components(Vec::new());
+
+        Some(builder)
+    }
+
+    /// Fetch the given osustats page and insert its scores into the entries.
+    async fn load_page(&mut self, api_page: usize) -> Result<()> {
+        self.params.page = api_page;
+        let scores_fut = Context::client().get_global_scores(&self.params);
+
+        let scores = match scores_fut.await.map(OsuStatsScoresRaw::into_scores) {
+            Ok(Ok(scores)) => scores.scores,
+            Err(err) | Ok(Err(err)) => return Err(err.wrap_err("Failed to get global scores")),
+        };
+
+        let maps_id_checksum = scores
+            .iter()
+            .map(|score| (score.map.map_id as i32, None))
+            .collect();
+
+        let mut maps = Context::osu_map().maps(&maps_id_checksum).await?;
+        let mode = self.params.mode;
+
+        for (score, i) in scores
+            .into_iter()
+            .zip((api_page - 1) * OSUSTATS_PAGE_SIZE..)
+        {
+            let map_opt = maps.remove(&score.map.map_id);
+            let Some(map) = map_opt else { continue };
+
+            let mut calc = Context::pp(&map).mods(score.mods.clone()).mode(mode);
+            let attrs = calc.performance().await;
+
+            let pp = match score.pp {
+                Some(pp) => pp,
+                None => match calc.score(&score).performance().await {
+                    Some(attrs) => attrs.pp() as f32,
+                    None => 0.0,
+                },
+            };
+
+            let mut max_pp = 0.0;
+            let mut stars = 0.0;
+            let mut max_combo = 0;
+
+            if let Some(attrs) = attrs {
+                max_pp = attrs.pp() as f32;
+                stars = attrs.stars() as f32;
+                max_combo = attrs.max_combo();
+            }
+
+            if score.grade.eq_letter(Grade::X) && mode != GameMode::Mania && pp > 0.0 {
+                max_pp = pp;
+            }
+
+            let rank = score.position;
+
+            let score = ScoreSlim {
+                accuracy: score.accuracy,
+                ended_at: score.ended_at,
+                grade: score.grade,
+                max_combo: score.max_combo,
+                mode,
+                mods: score.mods,
+                pp,
+                score: score.score,
+                classic_score: 0,
+                score_id: 0,
+                statistics: ScoreStatistics {
+                    perfect: score.count_geki,
+                    great: score.count300,
+                    good: score.count_katu,
+                    ok: score.count100,
+                    meh: score.count50,
+                    miss: score.count_miss,
+                    ..Default::default()
+                },
+                set_on_lazer: false,
+                is_legacy: true,
+            };
+
+            let entry = OsuStatsEntry {
+                score,
+                map,
+                rank,
+                max_pp,
+                stars,
+                max_combo,
+            };
+
+            self.entries.insert(i, entry);
+        }
+
+        self.loaded_pages.insert(api_page);
+
+        Ok(())
+    }
+}
+
+/// The 1-based osustats pages that contain the entries of the embed page
+/// starting at `index`.
+fn api_pages(index: usize, per_page: usize, len: usize) -> Range<usize> {
+    let end = (index + per_page).min(len);
+
+    if index >= end {
+        return 0..0;
+    }
+
+    index / OSUSTATS_PAGE_SIZE + 1..(end - 1) / OSUSTATS_PAGE_SIZE + 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_page_within_first_api_page() {
+        assert_eq!(api_pages(0, 5, 200), 1..2);
+        assert_eq!(api_pages(15, 5, 200), 1..2);
+    }
+
+    #[test]
+    fn page_spanning_two_api_pages() {
+        // Entries 20..25 where entry 24 is the first of the second api page
+        assert_eq!(api_pages(20, 5, 200), 1..3);
+        assert_eq!(api_pages(45, 5, 200), 2..4);
+    }
+
+    #[test]
+    fn page_ending_on_api_page_boundary() {
+        // Entries 115..120 with 120 being the start of the sixth api page
+        assert_eq!(api_pages(115, 5, 200), 5..6);
+        assert_eq!(api_pages(120, 5, 200), 6..7);
+    }
+
+    #[test]
+    fn last_page_is_clamped_to_len() {
+        // Only entries 22 and 23 exist on the last embed page
+        assert_eq!(api_pages(20, 5, 24), 1..2);
+        assert_eq!(api_pages(495, 5, MAX_ENTRIES), 21..22);
+    }
+
+    #[test]
+    fn no_entries() {
+        assert!(api_pages(0, 5, 0).is_empty());
+        assert!(api_pages(25, 5, 25).is_empty());
     }
 }
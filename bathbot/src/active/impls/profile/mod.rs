@@ -2,7 +2,7 @@ use std::fmt::{Display, Write};
 
 use bathbot_model::RankAccPeaks;
 use bathbot_util::{
-    Authored, EmbedBuilder, FooterBuilder, MessageOrigin,
+    Authored, EmbedBuilder, FooterBuilder, MessageOrigin, attachment,
     constants::OSU_BASE,
     datetime::{HowLongAgoText, NAIVE_DATETIME_FORMAT, SecToMinSec},
     fields,
@@ -32,7 +32,7 @@ use self::{
 };
 use crate::{
     active::{BuildPage, ComponentResult, IActiveMessage},
-    commands::osu::ProfileKind,
+    commands::osu::{ProfileKind, SKILL_RADAR_IMAGE, SkillRadarStatus},
     manager::redis::osu::CachedUser,
     util::{
         CachedUserExt, ComponentExt, Emote, interaction::InteractionComponent, osu::grade_emote,
@@ -52,9 +52,14 @@ pub struct ProfileMenu {
     skin_url: Availability<SkinUrl>,
     scores: Availability<Box<[Score]>>,
     score_rank: Availability<ScoreData>,
+    /// Respektive score rank to show on the compact page
+    compact_score_rank: Option<u32>,
     osutrack_peaks: Option<RankAccPeaks>,
     top100stats: Option<Top100Stats>,
     mapper_names: Availability<MapperNames>,
+    skill_radar: SkillRadarStatus,
+    /// Whether an osu!track update was triggered for this lookup
+    osutrack_updated: bool,
     kind: ProfileKind,
     origin: MessageOrigin,
     msg_owner: Id<UserMarker>,
@@ -62,13 +67,22 @@ pub struct ProfileMenu {
 
 impl IActiveMessage for ProfileMenu {
     async fn build_page(&mut self) -> Result<BuildPage> {
-        match self.kind {
-            ProfileKind::Compact => self.compact().await,
-            ProfileKind::UserStats => self.user_stats().await,
-            ProfileKind::Top100Stats => self.top100_stats().await,
-            ProfileKind::Top100Mods => self.top100_mods().await,
-            ProfileKind::Top100Mappers => self.top100_mappers().await,
-            ProfileKind::MapperStats => self.mapper_stats().await,
+        let page = match self.kind {
+            ProfileKind::Compact => self.compact().await?,
+            ProfileKind::UserStats => self.user_stats().await?,
+            ProfileKind::Top100Stats => self.top100_stats().await?,
+            ProfileKind::Top100Mods => self.top100_mods().await?,
+            ProfileKind::Top100Mappers => self.top100_mappers().await?,
+            ProfileKind::MapperStats => self.mapper_stats().await?,
+        };
+
+        // The radar is attached to the message so every page needs to show
+        // it, otherwise it would dangle as a plain file
+        match self.skill_radar {
+            SkillRadarStatus::Attached(_) => {
+                Ok(page.map_embed(|embed| embed.image(attachment(SKILL_RADAR_IMAGE))))
+            }
+            SkillRadarStatus::UnsupportedMode | SkillRadarStatus::NotRequested => Ok(page),
         }
     }
 
@@ -175,7 +189,9 @@ impl ProfileMenu {
         discord_id: Option<Id<UserMarker>>,
         tz: Option<UtcOffset>,
         osutrack_peaks: Option<RankAccPeaks>,
+        compact_score_rank: Option<u32>,
         legacy_scores: bool,
+        skill_radar: SkillRadarStatus,
         kind: ProfileKind,
         origin: MessageOrigin,
         msg_owner: Id<UserMarker>,
@@ -185,7 +201,9 @@ impl ProfileMenu {
             discord_id,
             tz,
             osutrack_peaks,
+            compact_score_rank,
             legacy_scores,
+            skill_radar,
             kind,
             msg_owner,
             skin_url: Availability::NotRequested,
@@ -194,9 +212,14 @@ impl ProfileMenu {
             mapper_names: Availability::NotRequested,
             origin,
             top100stats: None,
+            osutrack_updated: false,
         }
     }
 
+    pub fn set_osutrack_updated(&mut self, updated: bool) {
+        self.osutrack_updated = updated;
+    }
+
     async fn compact(&mut self) -> Result<BuildPage> {
         let user_id = self.user.user_id.to_native();
 
@@ -244,6 +267,10 @@ impl ProfileMenu {
             );
         }
 
+        if let Some(rank) = self.compact_score_rank {
+            let _ = write!(description, "\nScore rank: `#{}`", WithComma::new(rank));
+        }
+
         if let Some(peak) = highest_rank {
             let _ = write!(
                 description,
@@ -253,6 +280,10 @@ impl ProfileMenu {
             );
         }
 
+        if let SkillRadarStatus::UnsupportedMode = self.skill_radar {
+            description.push_str("\n*The skill radar is only available for osu!standard*");
+        }
+
         let embed = EmbedBuilder::new()
             .author(self.user.author_builder(true))
             .description(description)
@@ -426,7 +457,7 @@ impl ProfileMenu {
             }
         };
 
-        let fields = fields![
+        let mut fields = fields![
             "Peak rank", peak_rank, true;
             "Top score PP", top_score_pp, true;
             "Level", level, true;
@@ -451,6 +482,10 @@ impl ProfileMenu {
             "Followers", WithComma::new(follower_count.to_native()).to_string(), true;
         ];
 
+        if let SkillRadarStatus::Attached(ref radar) = self.skill_radar {
+            fields![fields { "Skill radar", radar.summary(), false }];
+        }
+
         let embed = EmbedBuilder::new()
             .author(self.user.author_builder(true))
             .description(description)
@@ -932,12 +967,16 @@ impl ProfileMenu {
             join_date = join_date.to_offset(tz);
         }
 
-        let text = format!(
+        let mut text = format!(
             "Joined osu! {} ({})",
             join_date.format(NAIVE_DATETIME_FORMAT).unwrap(),
             HowLongAgoText::new(&join_date),
         );
 
+        if self.osutrack_updated {
+            text.push_str(" • osu!track datapoint recorded");
+        }
+
         FooterBuilder::new(text).icon_url(Emote::from(self.user.mode).url())
     }
 }
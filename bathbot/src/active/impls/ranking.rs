@@ -1,19 +1,25 @@
 use std::{
     collections::{
-        BTreeMap,
+        BTreeMap, VecDeque,
         btree_map::{Entry, Range},
     },
     fmt::{Display, Formatter, Result as FmtResult, Write},
 };
 
+use bathbot_cache::model::CachedArchive;
 use bathbot_macros::PaginationBuilder;
-use bathbot_model::{BgGameScore, EmbedHeader, RankingEntries, RankingEntry, RankingKind};
+use bathbot_model::{
+    BgGameScore, EmbedHeader, RankingEntries, RankingEntry, RankingKind,
+    rosu_v2::ranking::ArchivedRankings,
+};
 use bathbot_util::{
     EmbedBuilder,
     numbers::{WithComma, round},
 };
 use eyre::{Result, WrapErr};
+use rosu_v2::prelude::{CountryCode, GameMode};
 use time::OffsetDateTime;
+use tokio::task::JoinHandle;
 use twilight_model::{
     channel::message::Component,
     id::{Id, marker::UserMarker},
@@ -25,9 +31,13 @@ use crate::{
         pagination::{Pages, handle_pagination_component, handle_pagination_modal},
     },
     core::Context,
+    manager::redis::{CacheMeta, RedisError},
     util::interaction::{InteractionComponent, InteractionModal},
 };
 
+/// Amount of entries per page of the osu!api rankings.
+const API_PAGE_SIZE: usize = 50;
+
 #[derive(PaginationBuilder)]
 pub struct RankingPagination {
     #[pagination(per_page = 20, len = "total")]
@@ -37,6 +47,9 @@ pub struct RankingPagination {
     kind: RankingKind,
     defer: bool,
     msg_owner: Id<UserMarker>,
+    command: Option<String>,
+    // Only used for pp rankings; initialized lazily
+    page_cache: Option<RankingPageCache>,
     pages: Pages,
 }
 
@@ -52,6 +65,8 @@ impl IActiveMessage for RankingPagination {
         // at once
         self.assure_present_users(page + 1).await?;
 
+        self.prefetch_next_pages();
+
         let idx = self.pages.index();
 
         let mut buf = String::new();
@@ -117,6 +132,12 @@ impl IActiveMessage for RankingPagination {
     async fn handle_modal(&mut self, modal: &mut InteractionModal) -> Result<()> {
         handle_pagination_modal(modal, self.msg_owner, self.defer(), &mut self.pages).await
     }
+
+    fn invocation(&self) -> Option<String> {
+        self.command
+            .as_deref()
+            .map(|command| self.pages.invocation(command))
+    }
 }
 
 impl RankingPagination {
@@ -189,6 +210,7 @@ impl RankingPagination {
         let count = self.entries.entry_count(range);
 
         if count < pages.per_page() && count < self.total - pages.index() {
+            let pinned = view_pages(pages.index(), pages.per_page(), self.total);
             let offset = page - 1;
             let page = page as u32;
             let kind = &self.kind;
@@ -238,68 +260,32 @@ impl RankingPagination {
                     }
                 }
                 RankingKind::PpCountry {
-                    mode,
-                    country_code: country,
-                    ..
+                    mode, country_code, ..
                 } => {
-                    let ranking = Context::redis()
-                        .pp_ranking(*mode, page, Some(country.as_str()))
-                        .await
-                        .wrap_err("Failed to get ranking page")?;
-
                     let RankingEntries::PpU32(ref mut entries) = self.entries else {
                         unreachable!()
                     };
 
-                    let iter = ranking.ranking.iter().enumerate().map(|(i, user)| {
-                        let country = user.country_code.as_str().into();
-
-                        let pp = user
-                            .statistics
-                            .as_ref()
-                            .map(|stats| stats.pp.to_native().round())
-                            .expect("missing stats");
-
-                        let entry = RankingEntry {
-                            country: Some(country),
-                            name: user.username.as_str().into(),
-                            value: pp as u32,
-                        };
+                    let ranking = self
+                        .page_cache
+                        .get_or_insert_with(RankingPageCache::default)
+                        .get(page as usize, *mode, Some(country_code), entries, &pinned)
+                        .await?;
 
-                        (offset * 50 + i, entry)
-                    });
-
-                    entries.extend(iter);
+                    extend_pp_entries(entries, ranking, offset);
                 }
                 RankingKind::PpGlobal { mode } => {
-                    let ranking = Context::redis()
-                        .pp_ranking(*mode, page, None)
-                        .await
-                        .wrap_err("failed to get ranking page")?;
-
                     let RankingEntries::PpU32(ref mut entries) = self.entries else {
                         unreachable!()
                     };
 
-                    let iter = ranking.ranking.iter().enumerate().map(|(i, user)| {
-                        let country = user.country_code.as_str().into();
-
-                        let pp = user
-                            .statistics
-                            .as_ref()
-                            .map(|stats| stats.pp.to_native().round())
-                            .expect("missing stats");
-
-                        let entry = RankingEntry {
-                            country: Some(country),
-                            name: user.username.as_str().into(),
-                            value: pp as u32,
-                        };
-
-                        (offset * 50 + i, entry)
-                    });
+                    let ranking = self
+                        .page_cache
+                        .get_or_insert_with(RankingPageCache::default)
+                        .get(page as usize, *mode, None, entries, &pinned)
+                        .await?;
 
-                    entries.extend(iter);
+                    extend_pp_entries(entries, ranking, offset);
                 }
                 RankingKind::RankedScore { mode } => {
                     let ranking = Context::osu()
@@ -330,6 +316,228 @@ impl RankingPagination {
 
         Ok(())
     }
+
+    /// Fetch the osu!api pages of the next view in the background so that
+    /// flipping forward does not have to wait for the api.
+    fn prefetch_next_pages(&mut self) {
+        let (mode, country_code) = match self.kind {
+            RankingKind::PpCountry {
+                mode,
+                ref country_code,
+                ..
+            } => (mode, Some(country_code)),
+            RankingKind::PpGlobal { mode } => (mode, None),
+            _ => return,
+        };
+
+        let RankingEntries::PpU32(ref entries) = self.entries else {
+            return;
+        };
+
+        let cache = self
+            .page_cache
+            .get_or_insert_with(RankingPageCache::default);
+
+        // Api pages are always inserted as a whole so checking their first
+        // entry suffices
+        let fetched: Vec<_> = (1..=self.total.div_ceil(API_PAGE_SIZE))
+            .filter(|&page| {
+                cache.contains(page) || entries.contains_key(&((page - 1) * API_PAGE_SIZE))
+            })
+            .collect();
+
+        let pages = pages_to_prefetch(
+            self.pages.index(),
+            self.pages.per_page(),
+            self.total,
+            &fetched,
+        );
+
+        cache.prefetch(pages, mode, country_code);
+    }
+}
+
+type RankingPage = (CachedArchive<ArchivedRankings>, CacheMeta);
+
+type PrefetchHandle = JoinHandle<Result<RankingPage, RedisError>>;
+
+/// Pages of the osu!api pp rankings that were fetched during the lifetime of
+/// the message so that flipping back and forth does not refetch them.
+#[derive(Default)]
+pub struct RankingPageCache {
+    /// Least recently used first
+    pages: VecDeque<(usize, RankingPage)>,
+    prefetching: Vec<(usize, PrefetchHandle)>,
+}
+
+impl RankingPageCache {
+    /// Maximum amount of pages that are kept around to bound memory.
+    const CAPACITY: usize = 10;
+
+    fn contains(&self, page: usize) -> bool {
+        self.pages.iter().any(|(cached, _)| *cached == page)
+    }
+
+    /// Get the given api page, either from the cache, a pending prefetch, or
+    /// by requesting it.
+    ///
+    /// Entries of pages that are evicted from the cache are removed as well.
+    /// Pinned pages, i.e. the ones of the current view, are never evicted.
+    ///
+    /// The page's redis entry is kept alive so that it's still cached when
+    /// the page is requested again after being evicted.
+    async fn get(
+        &mut self,
+        page: usize,
+        mode: GameMode,
+        country_code: Option<&CountryCode>,
+        entries: &mut BTreeMap<usize, RankingEntry<u32>>,
+        pinned: &[usize],
+    ) -> Result<&ArchivedRankings> {
+        if let Some(idx) = self.pages.iter().position(|(cached, _)| *cached == page) {
+            let cached = self.pages.remove(idx).expect("valid index");
+            self.pages.push_back(cached);
+        } else {
+            let ranking = match self.take_prefetched(page).await {
+                Some(ranking) => ranking,
+                None => Context::redis()
+                    .pp_ranking_with_meta(mode, page as u32, country_code.map(CountryCode::as_str))
+                    .await
+                    .wrap_err("Failed to get ranking page")?,
+            };
+
+            // Pages of the current view must stay so that their entries
+            // are still present when the view is built
+            if self.pages.len() >= Self::CAPACITY
+                && let Some(idx) = eviction_candidate(&self.pages, &self.prefetching, pinned)
+                && let Some((evicted, _)) = self.pages.remove(idx)
+            {
+                let start = (evicted - 1) * API_PAGE_SIZE;
+                let evicted_range = start..start + API_PAGE_SIZE;
+                entries.retain(|idx, _| !evicted_range.contains(idx));
+            }
+
+            self.pages.push_back((page, ranking));
+        }
+
+        let (_, (ranking, meta)) = self.pages.back().expect("just pushed");
+
+        let meta = meta.clone();
+        tokio::spawn(async move { meta.refresh().await });
+
+        Ok(ranking)
+    }
+
+    async fn take_prefetched(&mut self, page: usize) -> Option<RankingPage> {
+        let idx = self
+            .prefetching
+            .iter()
+            .position(|(pending, _)| *pending == page)?;
+
+        let (_, handle) = self.prefetching.swap_remove(idx);
+
+        match handle.await {
+            Ok(Ok(ranking)) => Some(ranking),
+            Ok(Err(err)) => {
+                warn!(?err, page, "Failed to prefetch ranking page");
+
+                None
+            }
+            Err(err) => {
+                warn!(?err, page, "Ranking page prefetch panicked");
+
+                None
+            }
+        }
+    }
+
+    fn prefetch(&mut self, pages: Vec<usize>, mode: GameMode, country_code: Option<&CountryCode>) {
+        // Prefetches that are no longer relevant still finish in the
+        // background and end up in redis
+        self.prefetching
+            .retain(|(pending, _)| pages.contains(pending));
+
+        for page in pages {
+            if self.prefetching.iter().any(|(pending, _)| *pending == page) {
+                continue;
+            }
+
+            let country_code = country_code.cloned();
+
+            let handle = tokio::spawn(async move {
+                Context::redis()
+                    .pp_ranking_with_meta(
+                        mode,
+                        page as u32,
+                        country_code.as_ref().map(CountryCode::as_str),
+                    )
+                    .await
+            });
+
+            self.prefetching.push((page, handle));
+        }
+    }
+}
+
+/// Api pages that contain the entries of the view starting at `index`.
+fn view_pages(index: usize, per_page: usize, total: usize) -> Vec<usize> {
+    let end = (index + per_page).min(total);
+
+    if index >= end {
+        return Vec::new();
+    }
+
+    let first = index / API_PAGE_SIZE + 1;
+    let last = (end - 1) / API_PAGE_SIZE + 1;
+
+    (first..=last).collect()
+}
+
+/// Position of the least recently used page that is neither pinned nor
+/// currently being prefetched.
+fn eviction_candidate<T, H>(
+    pages: &VecDeque<(usize, T)>,
+    prefetching: &[(usize, H)],
+    pinned: &[usize],
+) -> Option<usize> {
+    pages.iter().position(|(page, _)| {
+        !pinned.contains(page) && prefetching.iter().all(|(pending, _)| pending != page)
+    })
+}
+
+/// Api pages that are required for the view after the current one but have
+/// not been fetched yet.
+fn pages_to_prefetch(index: usize, per_page: usize, total: usize, fetched: &[usize]) -> Vec<usize> {
+    let mut pages = view_pages(index + per_page, per_page, total);
+    pages.retain(|page| !fetched.contains(page));
+
+    pages
+}
+
+fn extend_pp_entries(
+    entries: &mut BTreeMap<usize, RankingEntry<u32>>,
+    ranking: &ArchivedRankings,
+    offset: usize,
+) {
+    let iter = ranking.ranking.iter().enumerate().map(|(i, user)| {
+        let country = user.country_code.as_str().into();
+
+        let pp = user
+            .statistics
+            .as_ref()
+            .map(|stats| stats.pp.to_native().round())
+            .expect("missing stats");
+
+        let entry = RankingEntry {
+            country: Some(country),
+            name: user.username.as_str().into(),
+            value: pp as u32,
+        };
+
+        (offset * API_PAGE_SIZE + i, entry)
+    });
+
+    entries.extend(iter);
 }
 
 struct Lengths {
@@ -495,3 +703,61 @@ impl Display for Rank<'_> {
         write!(f, "#{}", WithComma::new(*self.inner))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefetch_next_api_page() {
+        // Next view (entries 20..40) is still within the first api page
+        assert!(pages_to_prefetch(0, 20, 10_000, &[1]).is_empty());
+
+        // Next view (entries 40..60) overlaps with the second api page
+        assert_eq!(pages_to_prefetch(20, 20, 10_000, &[1]), [2]);
+        assert!(pages_to_prefetch(20, 20, 10_000, &[1, 2]).is_empty());
+
+        // Jumped ahead so only the jumped-to page is fetched
+        assert_eq!(pages_to_prefetch(580, 20, 10_000, &[1, 12]), [13]);
+        assert_eq!(pages_to_prefetch(560, 20, 10_000, &[1]), [12, 13]);
+    }
+
+    #[test]
+    fn prefetch_respects_total() {
+        assert!(pages_to_prefetch(9980, 20, 10_000, &[200]).is_empty());
+        assert!(pages_to_prefetch(40, 20, 55, &[1, 2]).is_empty());
+        assert_eq!(pages_to_prefetch(20, 20, 55, &[1]), [2]);
+    }
+
+    #[test]
+    fn pages_of_view() {
+        assert_eq!(view_pages(0, 20, 10_000), [1]);
+        assert_eq!(view_pages(40, 20, 10_000), [1, 2]);
+        assert_eq!(view_pages(140, 20, 151), [3, 4]);
+        assert!(view_pages(60, 20, 60).is_empty());
+    }
+
+    #[test]
+    fn eviction_keeps_pages_of_view() {
+        // At capacity with the view's first page being the least recently
+        // used; the view crosses into the page that is being fetched
+        let pages: VecDeque<_> = [3, 1, 2, 5, 6, 7, 8, 9, 10, 11]
+            .into_iter()
+            .map(|page| (page, ()))
+            .collect();
+
+        assert_eq!(pages.len(), RankingPageCache::CAPACITY);
+
+        let pinned = view_pages(140, 20, 10_000);
+        assert_eq!(pinned, [3, 4]);
+        assert_eq!(eviction_candidate::<_, ()>(&pages, &[], &pinned), Some(1));
+    }
+
+    #[test]
+    fn eviction_skips_pending_prefetch() {
+        let pages: VecDeque<_> = [1, 2].into_iter().map(|page| (page, ())).collect();
+
+        assert_eq!(eviction_candidate(&pages, &[(1, ())], &[]), Some(1));
+        assert_eq!(eviction_candidate(&pages, &[(1, ())], &[2]), None);
+    }
+}
@@ -7,6 +7,7 @@ use bathbot_util::{
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use rosu_render::{ClientError as OrdrError, client::error::ApiError as OrdrApiError};
 use rosu_v2::error::OsuError;
+use tracing::Instrument;
 use twilight_model::{
     channel::message::{
         Component,
@@ -224,7 +225,7 @@ impl CachedRender {
             owner,
         );
 
-        tokio::spawn(ongoing_fut.await.await_render_url());
+        tokio::spawn(ongoing_fut.await.await_render_url().in_current_span());
 
         Ok(())
     }
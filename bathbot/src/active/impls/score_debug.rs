@@ -0,0 +1,319 @@
+use bathbot_util::{EmbedBuilder, FooterBuilder, ModsFormatter, constants::OSU_BASE, fields};
+use eyre::{Report, Result, WrapErr};
+use rosu_v2::prelude::Score;
+use twilight_model::{
+    channel::message::{
+        Component,
+        component::{ActionRow, Button, ButtonStyle},
+    },
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    active::{BuildPage, ComponentResult, IActiveMessage},
+    core::Context,
+    manager::PpManager,
+    util::{ComponentExt, interaction::InteractionComponent},
+};
+
+/// Version of rosu-pp that pp values are calculated with, read from
+/// `Cargo.lock` by the build script.
+const ROSU_PP_VERSION: &str = env!("ROSU_PP_VERSION");
+
+/// Abbreviated git revision of rosu-pp; empty if it's not a git dependency.
+const ROSU_PP_REVISION: &str = env!("ROSU_PP_REVISION");
+
+pub struct ScoreDebugActive {
+    score: Box<Score>,
+    diagnostic: ScoreDiagnostic,
+    refresh: RefreshStatus,
+    msg_owner: Id<UserMarker>,
+}
+
+#[derive(Copy, Clone)]
+enum RefreshStatus {
+    None,
+    Refreshed,
+    Failed,
+}
+
+impl ScoreDebugActive {
+    pub async fn new(score: Score, msg_owner: Id<UserMarker>) -> Result<Self> {
+        let diagnostic = ScoreDiagnostic::new(&score).await?;
+
+        Ok(Self {
+            score: Box::new(score),
+            diagnostic,
+            refresh: RefreshStatus::None,
+            msg_owner,
+        })
+    }
+
+    async fn handle_refresh(&mut self, component: &InteractionComponent) -> ComponentResult {
+        if let Err(err) = component.defer().await {
+            return ComponentResult::Err(Report::new(err).wrap_err("Failed to defer component"));
+        }
+
+        let map_id = self.score.map_id;
+
+        self.refresh = match Context::osu_map().refresh_file(map_id).await {
+            Ok(_) => match ScoreDiagnostic::new(&self.score).await {
+                Ok(diagnostic) => {
+                    self.diagnostic = diagnostic;
+
+                    RefreshStatus::Refreshed
+                }
+                Err(err) => {
+                    warn!(?err, "Failed to recalculate score diagnostic");
+
+                    RefreshStatus::Failed
+                }
+            },
+            Err(err) => {
+                warn!(?err, map_id, "Failed to refresh map file");
+
+                RefreshStatus::Failed
+            }
+        };
+
+        ComponentResult::BuildPage
+    }
+}
+
+impl IActiveMessage for ScoreDebugActive {
+    async fn build_page(&mut self) -> Result<BuildPage> {
+        let ScoreDiagnostic {
+            api_pp,
+            local_pp,
+            ref api_checksum,
+            ref file_checksum,
+            checksum,
+        } = self.diagnostic;
+
+        let score = &self.score;
+
+        let api_pp_value = match api_pp {
+            Some(pp) => format!("{pp:.2}"),
+            None => "Not provided".to_owned(),
+        };
+
+        let local_pp_value = match local_pp {
+            Some(pp) => format!("{pp:.2}"),
+            None => "Map too suspicious".to_owned(),
+        };
+
+        let difference = match (api_pp, local_pp) {
+            (Some(api), Some(local)) => format!("{:+.2}", local - api as f64),
+            _ => "-".to_owned(),
+        };
+
+        let mods = ModsFormatter::new(&score.mods, false).detailed(true);
+
+        let client = if score.set_on_lazer {
+            "lazer"
+        } else {
+            "stable"
+        };
+        let mut settings = format!("`{mods}` • {client}");
+
+        if !score.passed {
+            settings.push_str(" • failed, only passed objects count");
+        }
+
+        let file_value = match (checksum, file_checksum, api_checksum) {
+            (ChecksumStatus::Match, Some(file), _) => {
+                format!("`{file}` matches the osu!api")
+            }
+            (ChecksumStatus::Mismatch, Some(file), Some(api)) => format!(
+                "Stored `{file}` but the osu!api reports `{api}`.\n\
+                The stored file is outdated which likely explains the difference."
+            ),
+            (ChecksumStatus::Unknown, Some(file), _) => {
+                format!("`{file}`, the osu!api provided no checksum to compare")
+            }
+            _ => "No file stored".to_owned(),
+        };
+
+        let fields = fields![
+            "API pp", api_pp_value, true;
+            "Local pp", local_pp_value, true;
+            "Difference", difference, true;
+            "Mods & settings", settings, false;
+            "rosu-pp", rosu_pp_version(), true;
+            "Difficulty attributes", "Calculated for this request, not cached".to_owned(), true;
+            "Map file MD5", file_value, false;
+        ];
+
+        let footer = match (self.refresh, checksum) {
+            (RefreshStatus::Refreshed, _) => Some("Refreshed the map file and recalculated"),
+            (RefreshStatus::Failed, _) => Some("Failed to refresh the map file, try again later"),
+            (RefreshStatus::None, ChecksumStatus::Mismatch) => {
+                Some("Refresh the map file to recalculate with its current version")
+            }
+            (RefreshStatus::None, _) => None,
+        };
+
+        let mut embed = EmbedBuilder::new()
+            .title(format!("PP diagnostic for score {}", score.id))
+            .url(format!("{OSU_BASE}scores/{}", score.id))
+            .fields(fields);
+
+        if let Some(footer) = footer {
+            embed = embed.footer(FooterBuilder::new(footer));
+        }
+
+        let defer = !matches!(self.refresh, RefreshStatus::None);
+
+        Ok(BuildPage::new(embed, defer))
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        if self.diagnostic.checksum != ChecksumStatus::Mismatch {
+            return Vec::new();
+        }
+
+        let refresh = Button {
+            custom_id: Some("score_debug_refresh".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Refresh map file".to_owned()),
+            style: ButtonStyle::Danger,
+            url: None,
+            sku_id: None,
+        };
+
+        let components = vec![Component::Button(refresh)];
+
+        vec![Component::ActionRow(ActionRow { components })]
+    }
+
+    async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        let owner = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if owner != self.msg_owner {
+            return ComponentResult::Ignore;
+        }
+
+        match component.data.custom_id.as_str() {
+            "score_debug_refresh" => self.handle_refresh(component).await,
+            other => {
+                warn!(name = %other, ?component, "Unknown score debug component");
+
+                ComponentResult::Ignore
+            }
+        }
+    }
+}
+
+struct ScoreDiagnostic {
+    api_pp: Option<f32>,
+    /// `None` if the map is too suspicious
+    local_pp: Option<f64>,
+    api_checksum: Option<String>,
+    file_checksum: Option<String>,
+    checksum: ChecksumStatus,
+}
+
+impl ScoreDiagnostic {
+    async fn new(score: &Score) -> Result<Self> {
+        let map = Context::osu_map()
+            .pp_map(score.map_id)
+            .await
+            .wrap_err("Failed to get pp map")?;
+
+        let local_pp = PpManager::from_parsed(&map)
+            .score(score)
+            .performance()
+            .await
+            .map(|attrs| attrs.pp());
+
+        // Hashing after preparing the pp map in case the file was missing and
+        // just got downloaded
+        let file_checksum = Context::osu_map()
+            .file_checksum(score.map_id)
+            .await
+            .wrap_err("Failed to get map file checksum")?;
+
+        let api_checksum = score.map.as_ref().and_then(|map| map.checksum.clone());
+        let checksum = ChecksumStatus::new(file_checksum.as_deref(), api_checksum.as_deref());
+
+        Ok(Self {
+            api_pp: score.pp,
+            local_pp,
+            api_checksum,
+            file_checksum,
+            checksum,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ChecksumStatus {
+    /// The stored file is the map's current version
+    Match,
+    /// The stored file belongs to a different version of the map
+    Mismatch,
+    MissingFile,
+    /// The osu!api did not provide a checksum
+    Unknown,
+}
+
+impl ChecksumStatus {
+    fn new(file: Option<&str>, api: Option<&str>) -> Self {
+        match (file, api) {
+            (None, _) => Self::MissingFile,
+            (Some(_), None) => Self::Unknown,
+            (Some(file), Some(api)) if file.eq_ignore_ascii_case(api) => Self::Match,
+            (Some(_), Some(_)) => Self::Mismatch,
+        }
+    }
+}
+
+fn rosu_pp_version() -> String {
+    if ROSU_PP_REVISION.is_empty() {
+        format!("v{ROSU_PP_VERSION}")
+    } else {
+        format!("v{ROSU_PP_VERSION} (`{ROSU_PP_REVISION}`)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHECKSUM: &str = "f0e1d2c3b4a5968778695a4b3c2d1e0f";
+
+    #[test]
+    fn matching_checksums() {
+        let status = ChecksumStatus::new(Some(CHECKSUM), Some(CHECKSUM));
+        assert_eq!(status, ChecksumStatus::Match);
+
+        // Hex digits may differ in case between postgres and the osu!api
+        let upper = CHECKSUM.to_ascii_uppercase();
+        let status = ChecksumStatus::new(Some(CHECKSUM), Some(&upper));
+        assert_eq!(status, ChecksumStatus::Match);
+    }
+
+    #[test]
+    fn outdated_file_is_mismatch() {
+        let outdated = "00000000000000000000000000000000";
+        let status = ChecksumStatus::new(Some(outdated), Some(CHECKSUM));
+        assert_eq!(status, ChecksumStatus::Mismatch);
+    }
+
+    #[test]
+    fn missing_checksums() {
+        assert_eq!(
+            ChecksumStatus::new(None, Some(CHECKSUM)),
+            ChecksumStatus::MissingFile
+        );
+        assert_eq!(
+            ChecksumStatus::new(Some(CHECKSUM), None),
+            ChecksumStatus::Unknown
+        );
+        assert_eq!(ChecksumStatus::new(None, None), ChecksumStatus::MissingFile);
+    }
+}
@@ -0,0 +1,173 @@
+use bathbot_psql::model::configs::{Authorities, GuildConfig};
+use bathbot_util::{Authored, EmbedBuilder, FooterBuilder};
+use eyre::{Report, Result};
+use twilight_model::{
+    channel::message::{
+        Component,
+        component::{ActionRow, Button, ButtonStyle},
+    },
+    id::{
+        Id,
+        marker::{GuildMarker, UserMarker},
+    },
+};
+
+use crate::{
+    active::{BuildPage, ComponentResult, IActiveMessage},
+    commands::utility::GuildConfigExport,
+    core::Context,
+    util::{ComponentExt, interaction::InteractionComponent},
+};
+
+pub struct ServerConfigImportActive {
+    guild_id: Id<GuildMarker>,
+    /// The export and matched authority roles, `None` once the import was
+    /// applied or canceled
+    import: Option<(GuildConfigExport, Authorities)>,
+    preview: String,
+    status: ImportStatus,
+    msg_owner: Id<UserMarker>,
+}
+
+#[derive(Copy, Clone)]
+enum ImportStatus {
+    Pending,
+    Applied,
+    Canceled,
+    Failed,
+}
+
+impl ServerConfigImportActive {
+    pub fn new(
+        guild_id: Id<GuildMarker>,
+        export: GuildConfigExport,
+        authorities: Authorities,
+        preview: String,
+        msg_owner: Id<UserMarker>,
+    ) -> Self {
+        Self {
+            guild_id,
+            import: Some((export, authorities)),
+            preview,
+            status: ImportStatus::Pending,
+            msg_owner,
+        }
+    }
+
+    async fn handle_confirm(&mut self, component: &InteractionComponent) -> ComponentResult {
+        let Some((export, authorities)) = self.import.take() else {
+            return ComponentResult::Ignore;
+        };
+
+        if let Err(err) = component.defer().await {
+            return ComponentResult::Err(Report::new(err).wrap_err("Failed to defer component"));
+        }
+
+        // Settings that are not part of the export may have changed since the
+        // preview so the export is applied on top of the current config
+        let current = Context::guild_config()
+            .peek(self.guild_id, GuildConfig::to_owned)
+            .await;
+
+        let config = match export.to_config(&current, authorities) {
+            Ok(config) => config,
+            Err(err) => {
+                warn!(?err, "Failed to convert imported guild config");
+                self.status = ImportStatus::Failed;
+
+                return ComponentResult::BuildPage;
+            }
+        };
+
+        self.status = match Context::guild_config().replace(self.guild_id, config).await {
+            Ok(_) => ImportStatus::Applied,
+            Err(err) => {
+                warn!(?err, "Failed to apply imported guild config");
+
+                ImportStatus::Failed
+            }
+        };
+
+        ComponentResult::BuildPage
+    }
+}
+
+impl IActiveMessage for ServerConfigImportActive {
+    async fn build_page(&mut self) -> Result<BuildPage> {
+        let (footer, defer) = match self.status {
+            ImportStatus::Pending => ("Confirm to apply these changes", false),
+            ImportStatus::Applied => ("Applied the imported settings", true),
+            ImportStatus::Canceled => ("Canceled the import", false),
+            ImportStatus::Failed => ("Failed to apply the settings, try again later", true),
+        };
+
+        let mut embed = EmbedBuilder::new()
+            .title("Server config import")
+            .description(&self.preview)
+            .footer(FooterBuilder::new(footer));
+
+        match self.status {
+            ImportStatus::Applied => embed = embed.color_green(),
+            ImportStatus::Failed => embed = embed.color_red(),
+            ImportStatus::Pending | ImportStatus::Canceled => {}
+        }
+
+        Ok(BuildPage::new(embed, defer))
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        if !matches!(self.status, ImportStatus::Pending) {
+            return Vec::new();
+        }
+
+        let confirm = Button {
+            custom_id: Some("serverconfig_import_confirm".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Confirm".to_owned()),
+            style: ButtonStyle::Success,
+            url: None,
+            sku_id: None,
+        };
+
+        let cancel = Button {
+            custom_id: Some("serverconfig_import_cancel".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Cancel".to_owned()),
+            style: ButtonStyle::Danger,
+            url: None,
+            sku_id: None,
+        };
+
+        let components = vec![Component::Button(confirm), Component::Button(cancel)];
+
+        vec![Component::ActionRow(ActionRow { components })]
+    }
+
+    async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        let owner = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if owner != self.msg_owner {
+            return ComponentResult::Ignore;
+        }
+
+        match component.data.custom_id.as_str() {
+            "serverconfig_import_confirm" => self.handle_confirm(component).await,
+            "serverconfig_import_cancel" => {
+                self.import = None;
+                self.status = ImportStatus::Canceled;
+
+                ComponentResult::BuildPage
+            }
+            other => {
+                warn!(name = %other, ?component, "Unknown server config import component");
+
+                ComponentResult::Ignore
+            }
+        }
+    }
+}
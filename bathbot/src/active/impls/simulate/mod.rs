@@ -12,7 +12,7 @@ use bathbot_util::{
     fields,
     modal::{ModalBuilder, TextInputBuilder},
     numbers::{WithComma, round},
-    osu::calculate_grade,
+    osu::{calculate_accuracy, calculate_grade},
 };
 use eyre::{ContextCompat, Report, Result};
 use rosu_pp::{
@@ -48,7 +48,7 @@ use crate::{
     embeds::{ComboFormatter, HitResultFormatter, KeyFormatter, PpFormatter},
     manager::OsuMap,
     util::{
-        ComponentExt, Emote, ModalExt,
+        ComponentExt, EffectiveMode, Emote, ModalExt,
         interaction::{InteractionComponent, InteractionModal},
         osu::{GradeCompletionFormatter, MapInfo},
     },
@@ -62,6 +62,7 @@ mod top_old;
 pub struct SimulateComponents {
     map: SimulateMap,
     data: SimulateData,
+    mode: EffectiveMode,
     defer: bool,
     msg_owner: Id<UserMarker>,
 }
@@ -97,7 +98,7 @@ impl IActiveMessage for SimulateComponents {
                     map.version().cow_escape_markdown(),
                 )
             }
-            SimulateMap::Attached(ref map) => map.filename.as_ref().to_owned(),
+            SimulateMap::Attached(ref map) => map.metadata.to_string(),
         };
 
         if matches!(self.data.version, TopOldVersion::Mania(_)) {
@@ -111,13 +112,24 @@ impl IActiveMessage for SimulateComponents {
         let footer_text = match self.map {
             SimulateMap::Full(ref map) => {
                 format!(
-                    "{:?} mapset of {} • {version}",
+                    "{:?} mapset of {} • {version} • {mode}",
                     map.status(),
                     map.creator(),
-                    version = self.data.version
+                    version = self.data.version,
+                    mode = self.mode,
+                )
+            }
+            SimulateMap::Attached(ref map) if map.metadata.creator.is_empty() => {
+                format!("Unsubmitted map • {} • {}", self.data.version, self.mode)
+            }
+            SimulateMap::Attached(ref map) => {
+                format!(
+                    "Unsubmitted map by {} • {version} • {mode}",
+                    map.metadata.creator,
+                    version = self.data.version,
+                    mode = self.mode,
                 )
             }
-            SimulateMap::Attached(_) => self.data.version.to_string(),
         };
 
         let mut footer = FooterBuilder::new(footer_text);
@@ -179,11 +191,7 @@ impl IActiveMessage for SimulateComponents {
                 let max_stats_opt = self.data.set_on_lazer.then_some(&max_stats);
                 grade = calculate_grade(mode, mods, &stats, max_stats_opt);
 
-                let acc = if self.data.set_on_lazer {
-                    stats.accuracy(mode, &max_stats)
-                } else {
-                    stats.legacy_accuracy(mode)
-                };
+                let acc = calculate_accuracy(mode, &stats, max_stats_opt);
 
                 let acc = EmbedField {
                     inline: true,
@@ -648,10 +656,16 @@ impl IActiveMessage for SimulateComponents {
 }
 
 impl SimulateComponents {
-    pub fn new(map: SimulateMap, data: SimulateData, msg_owner: Id<UserMarker>) -> Self {
+    pub fn new(
+        map: SimulateMap,
+        data: SimulateData,
+        mode: EffectiveMode,
+        msg_owner: Id<UserMarker>,
+    ) -> Self {
         Self {
             map,
             data,
+            mode,
             msg_owner,
             defer: true,
         }
@@ -723,6 +737,17 @@ impl SimulateMap {
         }
     }
 
+    pub fn convert_mut(&mut self, mode: GameMode) {
+        if self.mode() == mode {
+            return;
+        }
+
+        match self {
+            Self::Full(map) => map.convert_mut(mode),
+            Self::Attached(map) => map.convert_mut(mode),
+        }
+    }
+
     pub fn pp_map(&self) -> &Beatmap {
         match self {
             Self::Full(map) => &map.pp_map,
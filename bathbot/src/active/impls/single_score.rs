@@ -9,12 +9,12 @@ use bathbot_model::embed_builder::{
     EmoteTextValue, HitresultsValue, MapperValue, ScoreEmbedSettings, SettingValue, SettingsImage,
     Value,
 };
-use bathbot_psql::model::configs::ScoreData;
+use bathbot_psql::model::configs::{OsuUserId, ScoreData, TimestampStyle, UserConfig};
 use bathbot_util::{
     AuthorBuilder, Authored, BucketName, CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder,
     ModsFormatter, attachment,
     constants::{GENERAL_ISSUE, ORDR_ISSUE, OSU_API_ISSUE, OSU_BASE},
-    datetime::{HowLongAgoDynamic, HowLongAgoText, SHORT_NAIVE_DATETIME_FORMAT, SecToMinSec},
+    datetime::SecToMinSec,
     fields,
     numbers::round,
 };
@@ -27,6 +27,7 @@ use rosu_v2::{
     prelude::{GameMod, GameMods, RankStatus},
 };
 use time::OffsetDateTime;
+use tracing::Instrument;
 use twilight_model::{
     channel::message::{
         Component, EmojiReactionType,
@@ -53,7 +54,7 @@ use crate::{
     embeds::HitResultFormatter,
     manager::{ReplayError, redis::osu::CachedUser},
     util::{
-        CachedUserExt, Emote, MessageExt,
+        CachedUserExt, Emote, MessageExt, TimestampFormatter, TimestampPosition,
         interaction::{InteractionComponent, InteractionModal},
         osu::{GradeFormatter, ScoreFormatter},
     },
@@ -63,6 +64,8 @@ pub struct SingleScorePagination {
     pub settings: ScoreEmbedSettings,
     scores: Box<[ScoreEmbedDataWrap]>,
     score_data: ScoreData,
+    timestamp_style: Option<TimestampStyle>,
+    skin: Option<ScoreSkin>,
     msg_owner: Id<UserMarker>,
     pages: Pages,
 
@@ -89,6 +92,8 @@ impl SingleScorePagination {
             settings,
             scores,
             score_data,
+            timestamp_style: None,
+            skin: None,
             msg_owner,
             pages,
             author: user.author_builder(false),
@@ -100,6 +105,14 @@ impl SingleScorePagination {
         self.pages.set_index(idx);
     }
 
+    pub fn set_timestamp_style(&mut self, timestamp_style: Option<TimestampStyle>) {
+        self.timestamp_style = timestamp_style;
+    }
+
+    pub fn set_skin(&mut self, skin: Option<ScoreSkin>) {
+        self.skin = skin;
+    }
+
     // refactored into a pub method so it's usable from elsewhere
     pub async fn async_build_page(
         &mut self,
@@ -108,7 +121,14 @@ impl SingleScorePagination {
     ) -> Result<BuildPage> {
         let score = &*self.scores[self.pages.index()].get_mut().await?;
 
-        let embed = Self::apply_settings(&self.settings, score, self.score_data, mark_idx);
+        let embed = Self::apply_settings(
+            &self.settings,
+            score,
+            self.score_data,
+            self.timestamp_style,
+            self.skin.as_ref(),
+            mark_idx,
+        );
 
         let url = format!("{OSU_BASE}b/{}", score.map.map_id());
 
@@ -158,9 +178,11 @@ impl SingleScorePagination {
         settings: &ScoreEmbedSettings,
         data: &ScoreEmbedData,
         score_data: ScoreData,
+        timestamp_style: Option<TimestampStyle>,
+        skin: Option<&ScoreSkin>,
         mark_idx: MarkIndex,
     ) -> EmbedBuilder {
-        apply_settings(settings, data, score_data, mark_idx)
+        apply_settings(settings, data, score_data, timestamp_style, skin, mark_idx)
     }
 
     async fn handle_miss_analyzer_button(
@@ -219,14 +241,17 @@ impl SingleScorePagination {
                 let channel_id = component.message.channel_id;
 
                 // Spawn in new task so that we're sure to callback the component in time
-                tokio::spawn(async move {
-                    let cached = CachedRender::new(score_id, video_url, true, owner);
-                    let begin_fut = ActiveMessages::builder(cached).begin(channel_id);
-
-                    if let Err(err) = begin_fut.await {
-                        error!(?err, "Failed to begin cached render message");
+                tokio::spawn(
+                    async move {
+                        let cached = CachedRender::new(score_id, video_url, true, owner);
+                        let begin_fut = ActiveMessages::builder(cached).begin(channel_id);
+
+                        if let Err(err) = begin_fut.await {
+                            error!(?err, "Failed to begin cached render message");
+                        }
                     }
-                });
+                    .in_current_span(),
+                );
 
                 return ComponentResult::BuildPage;
             }
@@ -241,13 +266,15 @@ impl SingleScorePagination {
             return self.render_cooldown_response(component, cooldown).await;
         }
 
-        tokio::spawn(Self::render_response(
+        let render_fut = Self::render_response(
             (component.message.id, component.message.channel_id),
             component.permissions,
             score_id,
             owner,
             component.guild_id,
-        ));
+        );
+
+        tokio::spawn(render_fut.in_current_span());
 
         ComponentResult::BuildPage
     }
@@ -543,6 +570,30 @@ pub enum SingleScoreContent {
     None,
 }
 
+/// Skin that a user specified through `/config skin`.
+pub struct ScoreSkin {
+    osu_id: Option<u32>,
+    name: Box<str>,
+    url: Option<Box<str>>,
+}
+
+impl ScoreSkin {
+    pub fn from_config(config: &UserConfig<OsuUserId>) -> Option<Self> {
+        let name = config.skin_display_name.as_deref()?;
+
+        Some(Self {
+            osu_id: config.osu,
+            name: Box::from(name),
+            url: config.skin_display_url.as_deref().map(Box::from),
+        })
+    }
+
+    /// The skin should only be shown on scores of its owner.
+    fn for_score(skin: Option<&Self>, score_user_id: u32) -> Option<&Self> {
+        skin.filter(|skin| skin.osu_id == Some(score_user_id))
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub enum MarkIndex {
     /// Don't mark anything
@@ -557,6 +608,8 @@ fn apply_settings(
     settings: &ScoreEmbedSettings,
     data: &ScoreEmbedData,
     score_data: ScoreData,
+    timestamp_style: Option<TimestampStyle>,
+    skin: Option<&ScoreSkin>,
     mark_idx: MarkIndex,
 ) -> EmbedBuilder {
     const SEP_NAME: &str = "\t";
@@ -585,6 +638,14 @@ fn apply_settings(
 
     let hide_ranked_date = || data.map.ranked_date().is_none();
 
+    // The builder previews the skin regardless of the score's user
+    let skin = match mark_idx {
+        MarkIndex::Skip => ScoreSkin::for_score(skin, data.user_id),
+        MarkIndex::Some(_) | MarkIndex::None => skin,
+    };
+
+    let hide_skin = || skin.is_none() && mark_idx == MarkIndex::Skip;
+
     for (i, curr) in settings.values.iter().enumerate() {
         let prev = i.checked_sub(1).and_then(|i| settings.values.get(i));
         let next = settings.values.get(i + 1);
@@ -598,7 +659,15 @@ fn apply_settings(
                     writer.push_str("__");
                 }
 
-                write_value(curr, data, &map_attrs, score_data, writer);
+                write_value(
+                    curr,
+                    data,
+                    &map_attrs,
+                    score_data,
+                    timestamp_style,
+                    skin,
+                    writer,
+                );
 
                 if mark_idx == MarkIndex::Some(i) {
                     writer.push_str("__");
@@ -708,6 +777,7 @@ fn apply_settings(
             }
             (_, Value::Ratio, _) if hide_ratio() => {}
             (_, Value::MapRankedDate, _) if hide_ranked_date() => {}
+            (_, Value::Skin, _) if hide_skin() => {}
             _ => {
                 let mut value = Cow::Borrowed(curr);
 
@@ -728,7 +798,8 @@ fn apply_settings(
                     .take_while(|prev| prev.y == curr.y)
                     .any(|prev| {
                         !((prev.inner == Value::Ratio && hide_ratio())
-                            || (prev.inner == Value::MapRankedDate && hide_ranked_date()))
+                            || (prev.inner == Value::MapRankedDate && hide_ranked_date())
+                            || (prev.inner == Value::Skin && hide_skin()))
                     });
 
                 if need_sep {
@@ -758,7 +829,15 @@ fn apply_settings(
                     writer.push_str(mark);
                 }
 
-                write_value(&value, data, &map_attrs, score_data, writer);
+                write_value(
+                    &value,
+                    data,
+                    &map_attrs,
+                    score_data,
+                    timestamp_style,
+                    skin,
+                    writer,
+                );
 
                 if mark_idx == MarkIndex::Some(i) {
                     writer.push_str(mark);
@@ -813,8 +892,16 @@ fn write_value(
     data: &ScoreEmbedData,
     map_attrs: &BeatmapAttributes,
     score_data: ScoreData,
+    timestamp_style: Option<TimestampStyle>,
+    skin: Option<&ScoreSkin>,
     writer: &mut String,
 ) {
+    let position = if value.y == SettingValue::FOOTER_Y {
+        TimestampPosition::Footer
+    } else {
+        TimestampPosition::Body
+    };
+
     match &value.inner {
         Value::Grade => {
             let _ = if value.y == SettingValue::NAME_Y {
@@ -851,11 +938,12 @@ fn write_value(
             }
         }
         Value::Mods => {
-            let _ = write!(
-                writer,
-                "+{}",
-                ModsFormatter::new(&data.score.mods, data.score.is_legacy)
-            );
+            // Settings only fit into the field area
+            let detailed = value.y != SettingValue::NAME_Y && value.y != SettingValue::FOOTER_Y;
+            let mods =
+                ModsFormatter::new(&data.score.mods, data.score.is_legacy).detailed(detailed);
+
+            let _ = write!(writer, "+{mods}");
         }
         Value::Score => {
             let _ = write!(writer, "{}", ScoreFormatter::new(&data.score, score_data));
@@ -864,20 +952,13 @@ fn write_value(
             let _ = write!(writer, "{}%", round(data.score.accuracy));
         }
         Value::ScoreDate => {
-            let score_date = data.score.ended_at;
-
-            if value.y == SettingValue::FOOTER_Y {
+            if position == TimestampPosition::Footer {
                 writer.push_str("Played ");
-
-                if OffsetDateTime::now_utc() < score_date + DAY {
-                    let _ = write!(writer, "{}", HowLongAgoText::new(&score_date));
-                } else {
-                    writer.push_str(&score_date.format(&SHORT_NAIVE_DATETIME_FORMAT).unwrap());
-                    writer.push_str(" UTC");
-                }
-            } else {
-                let _ = write!(writer, "{}", HowLongAgoDynamic::new(&score_date));
             }
+
+            let style = timestamp_style.unwrap_or_default();
+            let fmt = TimestampFormatter::new(data.score.ended_at, style, position);
+            let _ = write!(writer, "{fmt}");
         }
         Value::Pp(pp) => {
             let bold = if value.y < SettingValue::FOOTER_Y {
@@ -891,6 +972,7 @@ fn write_value(
                 ""
             };
 
+            let start = writer.len();
             let _ = write!(writer, "{bold}{:.2}", data.score.pp);
 
             let _ = match (pp.max, data.if_fc_pp.filter(|_| pp.if_fc), pp.max_if_fc) {
@@ -909,6 +991,12 @@ fn write_value(
                 }
                 (false, None, false) => write!(writer, "pp{bold}"),
             };
+
+            // Make it clear that the pp won't count towards the profile
+            if data.unranked_pp {
+                writer.insert(start, '[');
+                writer.push_str("] (not ranked)");
+            }
         }
         Value::Combo(combo) => {
             if value.y < SettingValue::FOOTER_Y {
@@ -1078,20 +1166,18 @@ fn write_value(
         }
         Value::MapRankedDate => {
             if let Some(ranked_date) = data.map.ranked_date() {
-                let _ = write!(writer, "{:?} ", data.map.status());
-
-                if OffsetDateTime::now_utc() < ranked_date + DAY {
-                    let _ = if value.y == SettingValue::FOOTER_Y {
-                        write!(writer, "{}", HowLongAgoText::new(&ranked_date))
+                // Without an explicit style, recently ranked maps are shown
+                // relatively and older ones as absolute date
+                let style = timestamp_style.unwrap_or_else(|| {
+                    if OffsetDateTime::now_utc() < ranked_date + DAY {
+                        TimestampStyle::Relative
                     } else {
-                        write!(writer, "{}", HowLongAgoDynamic::new(&ranked_date))
-                    };
-                } else if value.y == SettingValue::FOOTER_Y {
-                    writer.push_str(&ranked_date.format(&SHORT_NAIVE_DATETIME_FORMAT).unwrap());
-                    writer.push_str(" UTC");
-                } else {
-                    let _ = write!(writer, "<t:{}:f>", ranked_date.unix_timestamp());
-                }
+                        TimestampStyle::LocalAbsolute
+                    }
+                });
+
+                let fmt = TimestampFormatter::new(ranked_date, style, position);
+                let _ = write!(writer, "{:?} {fmt}", data.map.status());
             }
         }
         Value::Mapper(mapper) => {
@@ -1103,6 +1189,26 @@ fn write_value(
                 write!(writer, "Mapset by {creator}")
             };
         }
+        Value::Skin => {
+            let Some(skin) = skin else {
+                // Only reachable in the builder
+                writer.push_str("Skin: None");
+
+                return;
+            };
+
+            let name = skin.name.cow_escape_markdown();
+
+            // Links are only rendered within field values
+            let _ = match skin.url {
+                Some(ref url)
+                    if value.y > SettingValue::NAME_Y && value.y < SettingValue::FOOTER_Y =>
+                {
+                    write!(writer, "Skin: [{name}]({url})")
+                }
+                Some(_) | None => write!(writer, "Skin: {name}"),
+            };
+        }
     }
 }
 
@@ -1240,3 +1346,32 @@ impl_is_difficulty_adjusted! {
         OD: overall_difficulty,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn skin(osu_id: Option<u32>) -> ScoreSkin {
+        ScoreSkin {
+            osu_id,
+            name: Box::from("skin"),
+            url: None,
+        }
+    }
+
+    #[test]
+    fn skin_only_on_own_scores() {
+        let skin = skin(Some(2));
+
+        assert!(ScoreSkin::for_score(Some(&skin), 2).is_some());
+        assert!(ScoreSkin::for_score(Some(&skin), 3).is_none());
+        assert!(ScoreSkin::for_score(None, 2).is_none());
+    }
+
+    #[test]
+    fn skin_requires_linked_owner() {
+        let skin = skin(None);
+
+        assert!(ScoreSkin::for_score(Some(&skin), 2).is_none());
+    }
+}
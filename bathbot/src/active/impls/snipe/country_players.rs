@@ -0,0 +1,219 @@
+use std::{cmp::Reverse, fmt::Write};
+
+use bathbot_cache::model::CachedArchive;
+use bathbot_macros::PaginationBuilder;
+use bathbot_model::{ArchivedSnipeCountryRankingPlayer, CountryName, SnipeCountryPlayersOrder};
+use bathbot_util::{
+    CowUtils, EmbedBuilder, FooterBuilder, constants::OSU_BASE, numbers::WithComma, osu::flag_url,
+};
+use eyre::Result;
+use rkyv::vec::ArchivedVec;
+use rosu_v2::prelude::CountryCode;
+use twilight_model::{
+    channel::message::Component,
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    active::{
+        BuildPage, ComponentResult, IActiveMessage,
+        pagination::{Pages, handle_pagination_component, handle_pagination_modal},
+    },
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+#[derive(PaginationBuilder)]
+pub struct SnipeCountryPlayersPagination {
+    players: CachedArchive<ArchivedVec<ArchivedSnipeCountryRankingPlayer>>,
+    /// Indices into `players` in the order in which they're displayed
+    #[pagination(per_page = 15)]
+    order: Box<[usize]>,
+    country: (CountryName, CountryCode),
+    sort: SnipeCountryPlayersOrder,
+    /// Position within `order` of the author's osu! user
+    author_pos: Option<usize>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for SnipeCountryPlayersPagination {
+    async fn build_page(&mut self) -> Result<BuildPage> {
+        let pages = &self.pages;
+        let idx = pages.index();
+        let limit = self.order.len().min(idx + pages.per_page());
+
+        let order_text = match self.sort {
+            SnipeCountryPlayersOrder::Count => "#1 count",
+            SnipeCountryPlayersOrder::Gained => "#1s gained",
+            SnipeCountryPlayersOrder::Lost => "#1s lost",
+            SnipeCountryPlayersOrder::Weighted => "weighted pp from #1s",
+        };
+
+        let (country, code) = &self.country;
+
+        let title = format!(
+            "{country}{} #1 players, sorted by {order_text}",
+            if country.ends_with('s') { "'" } else { "'s" },
+        );
+
+        let underline = |sort| if self.sort == sort { "__" } else { "" };
+
+        let mut description = String::with_capacity(1024);
+
+        for (&player_idx, pos) in self.order[idx..limit].iter().zip(idx..) {
+            let player = &self.players[player_idx];
+
+            // Quote the author's entry so it stands out
+            let quote = if self.author_pos == Some(pos) {
+                "> "
+            } else {
+                ""
+            };
+
+            let _ = writeln!(
+                description,
+                "{quote}**#{rank} [{name}]({OSU_BASE}users/{id})**: \
+                {c}Count: {count}{c} • {w}Weighted pp: {weighted}{w}\n\
+                {quote}30 days: {g}+{gained}{g} / {l}-{lost}{l}",
+                rank = pos + 1,
+                name = player.username.cow_escape_markdown(),
+                id = player.user_id.to_native(),
+                c = underline(SnipeCountryPlayersOrder::Count),
+                count = WithComma::new(player.count_first.to_native()),
+                w = underline(SnipeCountryPlayersOrder::Weighted),
+                weighted = WithComma::new(player.weighted_pp.to_native()),
+                g = underline(SnipeCountryPlayersOrder::Gained),
+                gained = player.gained.to_native(),
+                l = underline(SnipeCountryPlayersOrder::Lost),
+                lost = player.lost.to_native(),
+            );
+        }
+
+        description.pop();
+
+        let page = pages.curr_page();
+        let last_page = pages.last_page();
+        let mut footer_text = format!("Page {page}/{last_page}");
+
+        if let Some(pos) = self.author_pos {
+            let _ = write!(footer_text, " • Your position: {}", pos + 1);
+        }
+
+        if self.sort.as_huismetbenen_str().is_none() {
+            footer_text.push_str(" • Sorted by the bot, ties keep #1 count order");
+        }
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(FooterBuilder::new(footer_text))
+            .thumbnail(flag_url(code.as_str()))
+            .title(title);
+
+        Ok(BuildPage::new(embed, false))
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        handle_pagination_component(component, self.msg_owner, false, &mut self.pages).await
+    }
+
+    async fn handle_modal(&mut self, modal: &mut InteractionModal) -> Result<()> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages).await
+    }
+}
+
+/// Indices of `players` in display order.
+///
+/// Orders that huismetbenen can't sort by are sorted here, keeping the
+/// fetched order for ties.
+pub fn sort_country_players(
+    players: &[ArchivedSnipeCountryRankingPlayer],
+    sort: SnipeCountryPlayersOrder,
+) -> Box<[usize]> {
+    let mut order: Box<[usize]> = (0..players.len()).collect();
+
+    match sort {
+        SnipeCountryPlayersOrder::Count | SnipeCountryPlayersOrder::Weighted => {}
+        SnipeCountryPlayersOrder::Gained => {
+            order.sort_by_key(|&idx| Reverse(players[idx].gained.to_native()))
+        }
+        SnipeCountryPlayersOrder::Lost => {
+            order.sort_by_key(|&idx| Reverse(players[idx].lost.to_native()))
+        }
+    }
+
+    order
+}
+
+/// Position within `order` of the given user.
+pub fn country_player_position(
+    players: &[ArchivedSnipeCountryRankingPlayer],
+    order: &[usize],
+    user_id: u32,
+) -> Option<usize> {
+    order
+        .iter()
+        .position(|&idx| players[idx].user_id.to_native() == user_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use bathbot_cache::util::serialize::serialize_using_arena;
+    use bathbot_model::SnipeCountryRankingPlayer;
+
+    use super::*;
+
+    fn players() -> CachedArchive<ArchivedVec<ArchivedSnipeCountryRankingPlayer>> {
+        let player = |user_id, count_first, gained, lost| SnipeCountryRankingPlayer {
+            username: format!("user{user_id}").into(),
+            user_id,
+            count_first,
+            weighted_pp: 0.0,
+            gained,
+            lost,
+        };
+
+        // Fetched in #1 count order
+        let players = vec![
+            player(1, 500, 2, 30),
+            player(2, 400, 10, 0),
+            player(3, 300, 10, 5),
+            player(4, 200, 0, 5),
+        ];
+
+        let bytes = serialize_using_arena(&players).unwrap();
+
+        CachedArchive::new(bytes).unwrap()
+    }
+
+    #[test]
+    fn keep_server_order() {
+        let players = players();
+
+        let order = sort_country_players(&players, SnipeCountryPlayersOrder::Count);
+        assert_eq!(order.as_ref(), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn sort_by_gained_and_lost() {
+        let players = players();
+
+        let order = sort_country_players(&players, SnipeCountryPlayersOrder::Gained);
+        assert_eq!(order.as_ref(), [1, 2, 0, 3]);
+
+        let order = sort_country_players(&players, SnipeCountryPlayersOrder::Lost);
+        assert_eq!(order.as_ref(), [0, 2, 3, 1]);
+    }
+
+    #[test]
+    fn author_position() {
+        let players = players();
+        let order = sort_country_players(&players, SnipeCountryPlayersOrder::Lost);
+
+        assert_eq!(country_player_position(&players, &order, 2), Some(3));
+        assert_eq!(country_player_position(&players, &order, 5), None);
+    }
+}
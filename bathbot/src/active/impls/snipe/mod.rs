@@ -1,8 +1,13 @@
 pub use self::{
-    country_list::SnipeCountryListPagination, difference::SnipeDifferencePagination,
+    country_list::SnipeCountryListPagination,
+    country_players::{
+        SnipeCountryPlayersPagination, country_player_position, sort_country_players,
+    },
+    difference::SnipeDifferencePagination,
     player_list::SnipePlayerListPagination,
 };
 
 mod country_list;
+mod country_players;
 mod difference;
 mod player_list;
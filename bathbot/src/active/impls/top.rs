@@ -21,7 +21,7 @@ use crate::{
         pagination::{Pages, handle_pagination_component, handle_pagination_modal},
     },
     commands::{
-        osu::TopScoreOrder,
+        osu::{TopChanges, TopMovement, TopScoreOrder},
         utility::{ScoreEmbedDataHalf, ScoreEmbedDataWrap},
     },
     embeds::{ComboFormatter, HitResultFormatter, PpFormatter},
@@ -41,7 +41,11 @@ pub struct TopPagination {
     condensed_list: bool,
     score_data: ScoreData,
     content: Box<str>,
+    changes: Option<TopChanges>,
     msg_owner: Id<UserMarker>,
+    command: Option<String>,
+    /// Whether an osu!track update was triggered for this lookup
+    osutrack_updated: bool,
     pages: Pages,
 }
 
@@ -55,7 +59,11 @@ impl TopPagination {
             condensed_list: None,
             score_data: None,
             content: None,
+            changes: None,
             msg_owner: None,
+            command: None,
+            osutrack_updated: false,
+            start_page: None,
         }
     }
 
@@ -71,12 +79,7 @@ impl TopPagination {
             self.condensed_description(scores)
         };
 
-        let footer_text = format!(
-            "Page {}/{} • Mode: {}",
-            self.pages.curr_page(),
-            self.pages.last_page(),
-            mode_str(self.mode)
-        );
+        let footer_text = self.footer_text();
 
         let embed = EmbedBuilder::new()
             .author(self.user.author_builder(false))
@@ -87,6 +90,21 @@ impl TopPagination {
         BuildPage::new(embed, false).content(self.content.clone())
     }
 
+    fn footer_text(&self) -> String {
+        let mut text = format!(
+            "Page {}/{} • Mode: {}",
+            self.pages.curr_page(),
+            self.pages.last_page(),
+            mode_str(self.mode)
+        );
+
+        if self.osutrack_updated {
+            text.push_str(" • osu!track datapoint recorded");
+        }
+
+        text
+    }
+
     fn condensed_description(&self, entries: &[ScoreEmbedDataWrap]) -> String {
         let mut description = String::with_capacity(1024);
         let legacy_mods_order = self.score_data.is_legacy();
@@ -106,7 +124,7 @@ impl TopPagination {
 
             let _ = writeln!(
                 description,
-                "**#{idx} [{map}]({OSU_BASE}b/{map_id})** [{stars}★]\n\
+                "**#{idx} [{map}]({OSU_BASE}b/{map_id})** [{stars}★]{movement}\n\
                 {grade} **{pp}pp** ({acc}%) [**{combo}x**/{max_combo}x] {miss}**+{mods}** {appendix}",
                 idx = original_idx
                     .or(pb_idx.as_ref().and_then(|idx| idx.idx))
@@ -115,6 +133,7 @@ impl TopPagination {
                 map = MapFormat::from(map),
                 map_id = map.map_id(),
                 stars = round(*stars),
+                movement = self.movement(score.score_id),
                 grade = GradeFormatter::new(score.grade, Some(score.score_id), score.is_legacy()),
                 pp = round(score.pp),
                 acc = if self.sort_by == TopScoreOrder::Acc {
@@ -158,7 +177,7 @@ impl TopPagination {
 
             let _ = writeln!(
                 description,
-                "**#{idx} [{map}]({OSU_BASE}b/{map_id})** [{stars}★]\n\
+                "**#{idx} [{map}]({OSU_BASE}b/{map_id})** [{stars}★]{movement}\n\
                 {grade} **{pp}pp** {acc}% `{score}` {{{n320}/{n300}/../{miss}}} **+{mods}** {appendix}",
                 idx = original_idx
                     .or(pb_idx.as_ref().and_then(|idx| idx.idx))
@@ -167,6 +186,7 @@ impl TopPagination {
                 map = MapFormat::from(map),
                 map_id = map.map_id(),
                 stars = round(*stars),
+                movement = self.movement(score.score_id),
                 grade = GradeFormatter::new(score.grade, Some(score.score_id), score.is_legacy()),
                 pp = round(score.pp),
                 acc = if self.sort_by == TopScoreOrder::Acc {
@@ -193,6 +213,12 @@ impl TopPagination {
         description
     }
 
+    fn movement(&self, score_id: u64) -> TopMovement {
+        self.changes
+            .as_ref()
+            .map_or(TopMovement::Same, |changes| changes.movement(score_id))
+    }
+
     fn build_detailed(&self) -> BuildPage {
         let pages = &self.pages;
         let end_idx = self.entries.len().min(pages.index() + pages.per_page());
@@ -217,7 +243,7 @@ impl TopPagination {
 
             let _ = writeln!(
                 description,
-                "**#{idx} [{title} [{version}]]({OSU_BASE}b/{id}) +{mods}** [{stars:.2}★]\n\
+                "**#{idx} [{title} [{version}]]({OSU_BASE}b/{id}) +{mods}** [{stars:.2}★]{movement}\n\
                 {grade} {pp} • {acc}% • {score}\n[ {combo} ] • {hits} • {appendix}",
                 idx = original_idx
                     .or(pb_idx.as_ref().and_then(|idx| idx.idx))
@@ -227,6 +253,7 @@ impl TopPagination {
                 version = map.version().cow_escape_markdown(),
                 id = map.map_id(),
                 mods = ModsFormatter::new(&score.mods, legacy_mods_order),
+                movement = self.movement(score.score_id),
                 grade = GradeFormatter::new(score.grade, Some(score.score_id), score.is_legacy()),
                 pp = PpFormatter::new(Some(score.pp), Some(*max_pp)),
                 acc = if self.sort_by == TopScoreOrder::Acc {
@@ -249,12 +276,7 @@ impl TopPagination {
 
         description.pop();
 
-        let footer_text = format!(
-            "Page {}/{} • Mode: {}",
-            self.pages.curr_page(),
-            self.pages.last_page(),
-            mode_str(self.mode)
-        );
+        let footer_text = self.footer_text();
 
         let embed = EmbedBuilder::new()
             .author(self.user.author_builder(false))
@@ -286,6 +308,12 @@ impl IActiveMessage for TopPagination {
     async fn handle_modal(&mut self, modal: &mut InteractionModal) -> Result<()> {
         handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages).await
     }
+
+    fn invocation(&self) -> Option<String> {
+        self.command
+            .as_deref()
+            .map(|command| self.pages.invocation(command))
+    }
 }
 
 pub struct TopPaginationBuilder {
@@ -296,7 +324,11 @@ pub struct TopPaginationBuilder {
     condensed_list: Option<bool>,
     score_data: Option<ScoreData>,
     content: Option<Box<str>>,
+    changes: Option<TopChanges>,
     msg_owner: Option<Id<UserMarker>>,
+    command: Option<String>,
+    osutrack_updated: bool,
+    start_page: Option<usize>,
 }
 
 impl TopPaginationBuilder {
@@ -308,14 +340,21 @@ impl TopPaginationBuilder {
         let condensed_list = self.condensed_list.expect("missing condensed_list");
         let score_data = self.score_data.expect("missing score_data");
         let content = self.content.take().expect("missing content");
+        let changes = self.changes.take();
         let msg_owner = self.msg_owner.expect("missing msg_owner");
+        let command = self.command.take();
+        let osutrack_updated = self.osutrack_updated;
 
-        let pages = if condensed_list {
+        let mut pages = if condensed_list {
             Pages::new(10, entries.len())
         } else {
             Pages::new(5, entries.len())
         };
 
+        if let Some(page) = self.start_page.take() {
+            pages.set_page(page);
+        }
+
         TopPagination {
             user,
             mode,
@@ -324,7 +363,10 @@ impl TopPaginationBuilder {
             condensed_list,
             score_data,
             content,
+            changes,
             msg_owner,
+            command,
+            osutrack_updated,
             pages,
         }
     }
@@ -371,11 +413,35 @@ impl TopPaginationBuilder {
         self
     }
 
+    pub fn changes(&mut self, changes: Option<TopChanges>) -> &mut Self {
+        self.changes = changes;
+
+        self
+    }
+
     pub fn msg_owner(&mut self, msg_owner: Id<UserMarker>) -> &mut Self {
         self.msg_owner = Some(msg_owner);
 
         self
     }
+
+    pub fn command(&mut self, command: Option<String>) -> &mut Self {
+        self.command = command;
+
+        self
+    }
+
+    pub fn osutrack_updated(&mut self, osutrack_updated: bool) -> &mut Self {
+        self.osutrack_updated = osutrack_updated;
+
+        self
+    }
+
+    pub fn start_page(&mut self, start_page: Option<usize>) -> &mut Self {
+        self.start_page = start_page;
+
+        self
+    }
 }
 
 fn mode_str(mode: GameMode) -> &'static str {
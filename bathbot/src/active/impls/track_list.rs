@@ -44,7 +44,7 @@ impl IActiveMessage for TrackListPagination {
                 params,
             } = entry;
 
-            let _ = writeln!(
+            let _ = write!(
                 description,
                 "[`{name}`]({OSU_BASE}u/{user_id}) {mode}: \
                 `Index: {index}` • `PP: {pp}` • `Combo percent: {combo_percent}%`",
@@ -53,6 +53,12 @@ impl IActiveMessage for TrackListPagination {
                 pp = params.pp(),
                 combo_percent = params.combo_percent(),
             );
+
+            if params.compact() {
+                description.push_str(" • `Compact`");
+            }
+
+            description.push('\n');
         }
 
         if description.is_empty() {
@@ -8,28 +8,32 @@ use impls::relax::top::RelaxTopPagination;
 use tokio::sync::watch::Sender;
 use twilight_model::{
     channel::message::Component,
-    id::{Id, marker::MessageMarker},
+    id::{
+        Id,
+        marker::{ChannelMarker, MessageMarker},
+    },
 };
 
 pub use self::origin::ActiveMessageOriginError;
 use self::{
     builder::ActiveMessagesBuilder,
     impls::{
-        BackgroundGameSetup, BadgesPagination, BookmarksPagination, CachedRender,
-        ChangelogPagination, CompareMostPlayedPagination, CompareScoresPagination,
-        CompareTopPagination, DailyChallengeTodayPagination, HelpInteractionCommand,
-        HelpPrefixMenu, HigherLowerGame, LeaderboardPagination, MapPagination, MapSearchPagination,
-        MatchComparePagination, MatchCostPagination, MedalCountPagination, MedalRarityPagination,
-        MedalsCommonPagination, MedalsListPagination, MedalsMissingPagination,
-        MedalsRecentPagination, MostPlayedPagination, NoChokePagination, OsuStatsBestPagination,
-        OsuStatsPlayersPagination, OsuStatsScoresPagination, ProfileMenu,
+        AuditLogPagination, BackgroundGameSetup, BackgroundGameTagEditor, BadgesPagination,
+        BookmarksPagination, CachedRender, ChangelogPagination, CompareMostPlayedPagination,
+        CompareScoresPagination, CompareTopPagination, DailyChallengeTodayPagination,
+        HelpInteractionCommand, HelpPrefixMenu, HigherLowerGame, LeaderboardPagination,
+        MapPagination, MapSearchPagination, MatchComparePagination, MatchCostPagination,
+        MedalCountPagination, MedalRarityPagination, MedalsCommonPagination, MedalsListPagination,
+        MedalsMissingPagination, MedalsRecentPagination, MostPlayedPagination, NoChokePagination,
+        OsuStatsBestPagination, OsuStatsPlayersPagination, OsuStatsScoresPagination, ProfileMenu,
         RankingCountriesPagination, RankingPagination, RecentListPagination, RenderSettingsActive,
-        ScoreEmbedBuilderActive, SettingsImport, SimulateComponents, SingleScorePagination,
-        SkinsPagination, SlashCommandsPagination, SnipeCountryListPagination,
-        SnipeDifferencePagination, SnipePlayerListPagination, TopIfPagination, TopPagination,
-        TrackListPagination,
+        ScoreDebugActive, ScoreEmbedBuilderActive, ServerConfigImportActive, SettingsImport,
+        SimulateComponents, SingleScorePagination, SkinsPagination, SlashCommandsPagination,
+        SnipeCountryListPagination, SnipeDifferencePagination, SnipePlayerListPagination,
+        TopIfPagination, TopPagination, TrackListPagination,
     },
     response::ActiveResponse,
+    thread::{ArchivedDecision, ArchivedRetry},
 };
 use crate::{
     core::{BotMetrics, Context, EventKind},
@@ -45,10 +49,13 @@ mod builder;
 mod origin;
 mod pagination;
 mod response;
+mod thread;
 
 #[enum_dispatch(IActiveMessage)]
 pub enum ActiveMessage {
+    AuditLogPagination,
     BackgroundGameSetup,
+    BackgroundGameTagEditor,
     BadgesPagination,
     BookmarksPagination,
     CachedRender,
@@ -82,7 +89,9 @@ pub enum ActiveMessage {
     RecentListPagination,
     RelaxTopPagination,
     RenderSettingsActive,
+    ScoreDebugActive,
     ScoreEmbedBuilderActive,
+    ServerConfigImportActive,
     SettingsImport,
     SimulateComponents,
     SingleScorePagination,
@@ -99,6 +108,8 @@ pub enum ActiveMessage {
 struct FullActiveMessage {
     active_msg: ActiveMessage,
     activity_tx: Sender<()>,
+    channel: Id<ChannelMarker>,
+    plain_fallback: bool,
 }
 
 pub struct ActiveMessages {
@@ -135,6 +146,8 @@ impl ActiveMessages {
         let Some(FullActiveMessage {
             active_msg,
             activity_tx,
+            plain_fallback,
+            ..
         }) = guard.get_mut()
         else {
             return error!(
@@ -144,46 +157,68 @@ impl ActiveMessages {
             );
         };
 
+        /// Returns `false` if the message's thread is archived and the active
+        /// message should stop.
         async fn handle_component_inner(
             active_msg: &mut ActiveMessage,
             activity_tx: &Sender<()>,
+            plain_fallback: bool,
             component: &mut InteractionComponent,
-        ) {
+        ) -> bool {
             match active_msg.handle_component(component).await {
-                ComponentResult::BuildPage => match active_msg.build_page().await {
+                ComponentResult::BuildPage => match active_msg
+                    .build_page()
+                    .await
+                    .map(|build| build.with_invocation(active_msg))
+                {
                     Ok(build) => {
-                        let mut builder = MessageBuilder::new()
-                            .embed(build.embed)
-                            .components(active_msg.build_components());
+                        let components = active_msg.build_components();
 
-                        if let Some(ref content) = build.content {
-                            builder = builder.content(content.as_ref());
-                        }
+                        let builder = || {
+                            let mut builder = MessageBuilder::new()
+                                .embed(build.embed.clone())
+                                .components(components.clone())
+                                .plain_fallback(plain_fallback);
 
-                        if build.defer {
-                            if let Err(err) = component.update(builder).await {
-                                BotMetrics::inc_command_error(
-                                    "component",
-                                    component.data.custom_id.clone(),
-                                );
-
-                                return error!(
-                                    name = %component.data.custom_id,
-                                    ?err,
-                                    "Failed to update component",
-                                );
+                            if let Some(ref content) = build.content {
+                                builder = builder.content(content.as_ref());
                             }
-                        } else if let Err(err) = component.callback(builder).await {
-                            BotMetrics::inc_command_error(
-                                "component",
-                                component.data.custom_id.clone(),
-                            );
 
-                            return error!(
-                                name = %component.data.custom_id,
-                                ?err,
-                                "Failed to callback component",
-                            );
+                            builder
+                        };
+
+                        let mut retry =
+                            ArchivedRetry::new(component.channel_id, component.permissions);
+
+                        loop {
+                            let res = if build.defer {
+                                component.update(builder()).await.map(|_| ())
+                            } else {
+                                component.callback(builder()).await.map(|_| ())
+                            };
+
+                            let Err(err) = res else { break };
+
+                            match retry.handle(thread::is_thread_archived(&err)).await {
+                                ArchivedDecision::Propagate => {
+                                    BotMetrics::inc_command_error(
+                                        "component",
+                                        component.data.custom_id.clone(),
+                                    );
+
+                                    let action = if build.defer { "update" } else { "callback" };
+
+                                    error!(
+                                        name = %component.data.custom_id,
+                                        ?err,
+                                        "Failed to {action} component",
+                                    );
+
+                                    return true;
+                                }
+                                ArchivedDecision::UnarchiveAndRetry => {}
+                                ArchivedDecision::Terminate => return false,
+                            }
                         }
 
                         let _ = activity_tx.send(());
@@ -208,7 +243,9 @@ impl ActiveMessages {
                             component.data.custom_id.clone(),
                         );
 
-                        return error!(?err, "Failed to create modal");
+                        error!(?err, "Failed to create modal");
+
+                        return true;
                     }
 
                     let _ = activity_tx.send(());
@@ -224,9 +261,21 @@ impl ActiveMessages {
                 }
                 ComponentResult::Ignore => {}
             }
+
+            true
         }
 
-        handle_component_inner(active_msg, activity_tx, &mut component).await;
+        let keep_active =
+            handle_component_inner(active_msg, activity_tx, *plain_fallback, &mut component).await;
+
+        if !keep_active {
+            // Dropping the activity sender ends the timeout task without
+            // attempting to remove components
+            if let Some(full) = guard.remove() {
+                debug!(msg = %msg_id, "Terminating active message in archived thread");
+                Self::unregister(full.channel, msg_id).await;
+            }
+        }
 
         let elapsed = start.elapsed();
         BotMetrics::observe_command("component", component.data.custom_id, elapsed);
@@ -245,6 +294,8 @@ impl ActiveMessages {
         let Some(FullActiveMessage {
             active_msg,
             activity_tx,
+            plain_fallback,
+            ..
         }) = guard.get_mut()
         else {
             return error!(name = %modal.data.custom_id, ?modal, "Unknown modal");
@@ -253,6 +304,7 @@ impl ActiveMessages {
         async fn handle_modal_inner(
             active_msg: &mut ActiveMessage,
             activity_tx: &Sender<()>,
+            plain_fallback: bool,
             modal: &mut InteractionModal,
         ) {
             if let Err(err) = active_msg.handle_modal(modal).await {
@@ -261,11 +313,17 @@ impl ActiveMessages {
                 return error!(name = %modal.data.custom_id, ?err, "Failed to process modal");
             }
 
-            match active_msg.build_page().await {
+            let build_res = active_msg
+                .build_page()
+                .await
+                .map(|build| build.with_invocation(active_msg));
+
+            match build_res {
                 Ok(build) => {
                     let mut builder = MessageBuilder::new()
                         .embed(build.embed)
-                        .components(active_msg.build_components());
+                        .components(active_msg.build_components())
+                        .plain_fallback(plain_fallback);
 
                     if let Some(ref content) = build.content {
                         builder = builder.content(content.as_ref());
@@ -305,7 +363,7 @@ impl ActiveMessages {
             }
         }
 
-        handle_modal_inner(active_msg, activity_tx, &mut modal).await;
+        handle_modal_inner(active_msg, activity_tx, *plain_fallback, &mut modal).await;
 
         let elapsed = start.elapsed();
         BotMetrics::observe_command("modal", modal.data.custom_id, elapsed);
@@ -316,7 +374,59 @@ impl ActiveMessages {
     }
 
     pub async fn remove(&self, msg: Id<MessageMarker>) {
-        self.remove_full(msg).await;
+        if let Some(full) = self.remove_full(msg).await {
+            Self::unregister(full.channel, msg).await;
+        }
+    }
+
+    async fn unregister(channel: Id<ChannelMarker>, msg: Id<MessageMarker>) {
+        if let Err(err) = Context::cache().remove_active_message(channel, msg).await {
+            warn!(?err, "Failed to unregister active message");
+        }
+    }
+
+    /// Remove the components of active messages that were still registered
+    /// when the bot shut down.
+    ///
+    /// Errors are ignored since the messages might have been deleted or
+    /// permissions might have changed in the meanwhile.
+    pub async fn clean_orphaned(orphaned: Vec<(Id<ChannelMarker>, Id<MessageMarker>)>) {
+        // Delay between message edits so that a large amount of orphans
+        // doesn't cause a burst of requests
+        const INTERVAL: Duration = Duration::from_millis(500);
+
+        if orphaned.is_empty() {
+            return;
+        }
+
+        info!(
+            count = orphaned.len(),
+            "Cleaning up orphaned active messages..."
+        );
+
+        let mut interval = tokio::time::interval(INTERVAL);
+        let mut cleaned = 0;
+
+        for (channel, msg) in orphaned {
+            interval.tick().await;
+
+            let update_fut = Context::http()
+                .update_message(channel, msg)
+                .components(Some(&[]));
+
+            match update_fut.await {
+                Ok(_) => {
+                    BotMetrics::inc_orphaned_active_message(true);
+                    cleaned += 1;
+                }
+                Err(err) => {
+                    BotMetrics::inc_orphaned_active_message(false);
+                    debug!(%channel, %msg, ?err, "Failed to clean orphaned active message");
+                }
+            }
+        }
+
+        info!(cleaned, "Finished cleaning up orphaned active messages");
     }
 
     async fn remove_full(&self, msg: Id<MessageMarker>) -> Option<FullActiveMessage> {
@@ -374,6 +484,14 @@ pub trait IActiveMessage {
         Ok(())
     }
 
+    /// Command invocation that reproduces the current page, shown in the
+    /// footer so that users can share it.
+    ///
+    /// Defaults to no invocation.
+    fn invocation(&self) -> Option<String> {
+        None
+    }
+
     /// Duration until the message is no longer active.
     /// On `None` the message will immediatly be considered as inactive.
     ///
@@ -408,6 +526,23 @@ impl BuildPage {
     pub fn into_embed(self) -> EmbedBuilder {
         self.embed
     }
+
+    /// Modify the embed while keeping the content and defer behavior.
+    pub fn map_embed(mut self, f: impl FnOnce(EmbedBuilder) -> EmbedBuilder) -> Self {
+        self.embed = f(self.embed);
+
+        self
+    }
+
+    /// Add the invocation of the active message to the footer.
+    fn with_invocation(mut self, active_msg: &ActiveMessage) -> Self {
+        if let Some(invocation) = active_msg.invocation() {
+            let footer = self.embed.footer.take();
+            self.embed.footer = Some(pagination::footer_with_invocation(footer, &invocation));
+        }
+
+        self
+    }
 }
 
 pub enum ComponentResult {
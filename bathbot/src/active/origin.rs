@@ -6,13 +6,14 @@ use twilight_http::{
     error::ErrorType,
 };
 use twilight_model::{
-    channel::Message,
+    channel::{Message, thread::AutoArchiveDuration},
+    guild::Permissions,
     id::{Id, marker::ChannelMarker},
 };
 
 use crate::{
     core::commands::CommandOrigin,
-    util::{ChannelExt, interaction::InteractionCommand},
+    util::{ChannelExt, CheckPermissions, interaction::InteractionCommand},
 };
 
 pub enum ActiveMessageOrigin<'d> {
@@ -36,7 +37,7 @@ impl ActiveMessageOrigin<'_> {
                 }
             }),
             Self::Command(orig) => orig
-                .create_message(builder)
+                .create_message_unnoted(builder)
                 .await
                 .map_err(ActiveMessageOriginError::Report),
         }
@@ -57,11 +58,26 @@ impl ActiveMessageOrigin<'_> {
                 }
             }),
             Self::Command(orig) => orig
-                .callback_with_response(builder)
+                .callback_with_response_unnoted(builder)
                 .await
                 .map_err(ActiveMessageOriginError::Report),
         }
     }
+
+    pub(super) fn permissions(&self) -> Option<Permissions> {
+        match self {
+            Self::Channel(_) => None,
+            Self::Command(orig) => orig.permissions(),
+        }
+    }
+
+    /// Auto-archive duration of the thread that the origin is in.
+    pub(super) fn thread_archive(&self) -> Option<AutoArchiveDuration> {
+        match self {
+            Self::Command(CommandOrigin::Interaction { command }) => command.thread_archive,
+            Self::Channel(_) | Self::Command(CommandOrigin::Message { .. }) => None,
+        }
+    }
 }
 
 impl<'d> From<CommandOrigin<'d>> for ActiveMessageOrigin<'d> {
@@ -1,5 +1,5 @@
 use bathbot_util::{
-    Authored,
+    Authored, FooterBuilder,
     modal::{ModalBuilder, TextInputBuilder},
     numbers::last_multiple,
 };
@@ -62,6 +62,18 @@ impl Pages {
         self.index = self.last_index.min(new_index);
     }
 
+    /// Set the current page, clamped between the first and last page.
+    ///
+    /// Pages are 1-based.
+    pub fn set_page(&mut self, page: usize) {
+        self.set_index(page.saturating_sub(1).saturating_mul(self.per_page));
+    }
+
+    /// Appends the current page to the given command invocation.
+    pub fn invocation(&self, base: &str) -> String {
+        format!("{base} page:{}", self.curr_page())
+    }
+
     /// Returns pagination components based on the current [`Pages`]
     pub fn components(&self) -> Vec<Component> {
         if self.last_index == 0 {
@@ -130,6 +142,25 @@ impl Pages {
     }
 }
 
+/// Adds the invocation of the current page to the footer so that it can be
+/// shared.
+pub fn footer_with_invocation(footer: Option<FooterBuilder>, invocation: &str) -> FooterBuilder {
+    match footer {
+        Some(mut footer) if !footer.text.is_empty() => {
+            footer.text.push_str(" • ");
+            footer.text.push_str(invocation);
+
+            footer
+        }
+        Some(mut footer) => {
+            footer.text.push_str(invocation);
+
+            footer
+        }
+        None => FooterBuilder::new(invocation),
+    }
+}
+
 pub async fn handle_pagination_component<'a>(
     component: &'a mut InteractionComponent,
     msg_owner: Id<UserMarker>,
@@ -254,3 +285,45 @@ pub async fn handle_pagination_modal<'a>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_page_clamps() {
+        let mut pages = Pages::new(10, 95);
+
+        pages.set_page(3);
+        assert_eq!(pages.index(), 20);
+        assert_eq!(pages.curr_page(), 3);
+
+        pages.set_page(0);
+        assert_eq!(pages.curr_page(), 1);
+
+        pages.set_page(42);
+        assert_eq!(pages.curr_page(), 10);
+        assert_eq!(pages.index(), pages.last_index());
+    }
+
+    #[test]
+    fn invocation_uses_current_page() {
+        let mut pages = Pages::new(20, 10_000);
+        pages.set_page(7);
+
+        let invocation = pages.invocation("/ranking pp country:FR");
+        assert_eq!(invocation, "/ranking pp country:FR page:7");
+    }
+
+    #[test]
+    fn footer_appends_invocation() {
+        let footer = FooterBuilder::new("Page 7/500").icon_url("https://osu.ppy.sh/icon.png");
+        let footer = footer_with_invocation(Some(footer), "/ranking pp page:7");
+
+        assert_eq!(footer.text, "Page 7/500 • /ranking pp page:7");
+        assert!(footer.icon_url.is_some());
+
+        let footer = footer_with_invocation(None, "/ranking pp page:7");
+        assert_eq!(footer.text, "/ranking pp page:7");
+    }
+}
@@ -14,11 +14,13 @@ use crate::{
     util::{InteractionToken, MessageExt},
 };
 
+#[derive(Clone)]
 pub struct ActiveResponse {
     pub msg: Id<MessageMarker>,
     pub inner: ActiveResponseInner,
 }
 
+#[derive(Clone)]
 pub enum ActiveResponseInner {
     Message { channel: Id<ChannelMarker> },
     Interaction { token: InteractionToken<'static> },
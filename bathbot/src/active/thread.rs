@@ -0,0 +1,246 @@
+use std::time::Duration;
+
+use bathbot_util::constants::THREAD_ARCHIVED;
+use eyre::Report;
+use twilight_http::{
+    Error,
+    api_error::{ApiError, GeneralApiError},
+    error::ErrorType,
+};
+use twilight_model::{
+    channel::thread::AutoArchiveDuration,
+    guild::Permissions,
+    id::{Id, marker::ChannelMarker},
+};
+
+use crate::core::Context;
+
+/// How to proceed after a request for an active message failed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) enum ArchivedDecision {
+    /// The failure is unrelated to archived threads.
+    Propagate,
+    /// Unarchive the thread and retry the request.
+    UnarchiveAndRetry,
+    /// The thread stays archived so the active message should stop without
+    /// further requests.
+    Terminate,
+}
+
+impl ArchivedDecision {
+    /// `retried` denotes whether the thread was already unarchived for this
+    /// request.
+    pub(super) fn new(archived: bool, retried: bool, permissions: Option<Permissions>) -> Self {
+        if !archived {
+            Self::Propagate
+        } else if !retried && can_unarchive(permissions) {
+            Self::UnarchiveAndRetry
+        } else {
+            Self::Terminate
+        }
+    }
+}
+
+/// Unarchiving requires sending messages in the thread, or managing threads if
+/// the thread is locked.
+fn can_unarchive(permissions: Option<Permissions>) -> bool {
+    permissions.is_some_and(|permissions| {
+        permissions.intersects(Permissions::SEND_MESSAGES_IN_THREADS | Permissions::MANAGE_THREADS)
+    })
+}
+
+/// Whether the request failed because it targeted an archived thread.
+pub(super) fn is_thread_archived(err: &Error) -> bool {
+    matches!(
+        err.kind(),
+        ErrorType::Response {
+            error: ApiError::General(GeneralApiError {
+                code: THREAD_ARCHIVED,
+                ..
+            }),
+            ..
+        }
+    )
+}
+
+/// Same as [`is_thread_archived`] but for any error within the report's
+/// chain.
+pub(super) fn report_is_thread_archived(err: &Report) -> bool {
+    err.chain()
+        .filter_map(|err| err.downcast_ref::<Error>())
+        .any(is_thread_archived)
+}
+
+/// Keeps track of unarchiving attempts while retrying a request.
+pub(super) struct ArchivedRetry {
+    channel: Id<ChannelMarker>,
+    permissions: Option<Permissions>,
+    retried: bool,
+}
+
+impl ArchivedRetry {
+    pub(super) fn new(channel: Id<ChannelMarker>, permissions: Option<Permissions>) -> Self {
+        Self {
+            channel,
+            permissions,
+            retried: false,
+        }
+    }
+
+    /// Decide how to proceed after a failed request.
+    ///
+    /// On [`ArchivedDecision::UnarchiveAndRetry`] the thread has been
+    /// unarchived already and the request should be sent again.
+    pub(super) async fn handle(&mut self, archived: bool) -> ArchivedDecision {
+        let decision = ArchivedDecision::new(archived, self.retried, self.permissions);
+
+        if decision != ArchivedDecision::UnarchiveAndRetry {
+            return decision;
+        }
+
+        self.retried = true;
+        let channel = self.channel;
+
+        let unarchive_fut = Context::http().update_thread(channel).archived(false);
+
+        match unarchive_fut.await {
+            Ok(_) => decision,
+            Err(err) => {
+                debug!(%channel, ?err, "Failed to unarchive thread");
+
+                ArchivedDecision::Terminate
+            }
+        }
+    }
+}
+
+/// The auto-archive duration that the thread should be bumped to so that it
+/// doesn't archive before the active message times out.
+fn archive_bump(
+    current: AutoArchiveDuration,
+    until_timeout: Duration,
+    permissions: Option<Permissions>,
+) -> Option<AutoArchiveDuration> {
+    const DURATIONS: [AutoArchiveDuration; 4] = [
+        AutoArchiveDuration::Hour,
+        AutoArchiveDuration::Day,
+        AutoArchiveDuration::ThreeDays,
+        AutoArchiveDuration::Week,
+    ];
+
+    let minutes = |duration: AutoArchiveDuration| u64::from(duration.number());
+    let timeout_minutes = until_timeout.as_secs().div_ceil(60);
+
+    if minutes(current) >= timeout_minutes
+        || !permissions.is_some_and(|permissions| permissions.contains(Permissions::MANAGE_THREADS))
+    {
+        return None;
+    }
+
+    let bump = DURATIONS
+        .into_iter()
+        .find(|&duration| minutes(duration) >= timeout_minutes)
+        .unwrap_or(AutoArchiveDuration::Week);
+
+    (minutes(bump) > minutes(current)).then_some(bump)
+}
+
+/// Increase the thread's auto-archive duration if it's shorter than the
+/// active message's timeout.
+pub(super) async fn bump_auto_archive(
+    channel: Id<ChannelMarker>,
+    current: AutoArchiveDuration,
+    until_timeout: Duration,
+    permissions: Option<Permissions>,
+) {
+    let Some(duration) = archive_bump(current, until_timeout, permissions) else {
+        return;
+    };
+
+    let update_fut = Context::http()
+        .update_thread(channel)
+        .auto_archive_duration(duration);
+
+    if let Err(err) = update_fut.await {
+        debug!(%channel, ?err, "Failed to bump thread auto-archive duration");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEND: Option<Permissions> = Some(Permissions::SEND_MESSAGES_IN_THREADS);
+    const MANAGE: Option<Permissions> = Some(Permissions::MANAGE_THREADS);
+    const NONE: Option<Permissions> = Some(Permissions::empty());
+
+    #[test]
+    fn unrelated_errors_propagate() {
+        assert_eq!(
+            ArchivedDecision::new(false, false, SEND),
+            ArchivedDecision::Propagate
+        );
+        assert_eq!(
+            ArchivedDecision::new(false, true, NONE),
+            ArchivedDecision::Propagate
+        );
+    }
+
+    #[test]
+    fn unarchive_once_if_permitted() {
+        assert_eq!(
+            ArchivedDecision::new(true, false, SEND),
+            ArchivedDecision::UnarchiveAndRetry
+        );
+        assert_eq!(
+            ArchivedDecision::new(true, false, MANAGE),
+            ArchivedDecision::UnarchiveAndRetry
+        );
+        assert_eq!(
+            ArchivedDecision::new(true, true, MANAGE),
+            ArchivedDecision::Terminate
+        );
+    }
+
+    #[test]
+    fn terminate_without_permission() {
+        assert_eq!(
+            ArchivedDecision::new(true, false, NONE),
+            ArchivedDecision::Terminate
+        );
+        assert_eq!(
+            ArchivedDecision::new(true, false, None),
+            ArchivedDecision::Terminate
+        );
+    }
+
+    #[test]
+    fn bump_short_archive_duration() {
+        let two_hours = Duration::from_secs(2 * 60 * 60);
+
+        assert_eq!(
+            archive_bump(AutoArchiveDuration::Hour, two_hours, MANAGE),
+            Some(AutoArchiveDuration::Day)
+        );
+        assert_eq!(
+            archive_bump(AutoArchiveDuration::Day, two_hours, MANAGE),
+            None
+        );
+        assert_eq!(
+            archive_bump(AutoArchiveDuration::Hour, two_hours, SEND),
+            None
+        );
+        assert_eq!(
+            archive_bump(AutoArchiveDuration::Hour, Duration::from_secs(60), MANAGE),
+            None
+        );
+        assert_eq!(
+            archive_bump(
+                AutoArchiveDuration::ThreeDays,
+                Duration::from_secs(30 * 24 * 60 * 60),
+                MANAGE
+            ),
+            Some(AutoArchiveDuration::Week)
+        );
+    }
+}
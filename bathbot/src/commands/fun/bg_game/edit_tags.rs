@@ -0,0 +1,112 @@
+use bathbot_macros::msg_command;
+use bathbot_util::{Authored, i18n::ErrorKey};
+use eyre::Result;
+use twilight_model::id::{
+    Id,
+    marker::{GuildMarker, UserMarker},
+};
+
+use crate::{
+    active::{ActiveMessages, impls::BackgroundGameTagEditor},
+    core::{BotConfig, Context, commands::checks::check_authority},
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+#[msg_command(name = "Edit mapset tags", flags(EPHEMERAL))]
+async fn edit_mapset_tags(mut command: InteractionCommand) -> Result<()> {
+    let msg_opt = command
+        .data
+        .resolved
+        .as_ref()
+        .and_then(|resolved| resolved.messages.values().next());
+
+    let Some(msg) = msg_opt else {
+        let _ = command.error_tr(ErrorKey::General).await;
+
+        bail!("Missing resolved message");
+    };
+
+    let msg_id = msg.id;
+    let user_id = command.user_id()?;
+
+    match can_edit_mapset_tags(user_id, command.guild_id).await {
+        Ok(true) => {}
+        Ok(false) => {
+            let content = "You are not allowed to edit mapset tags";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    }
+
+    let mapset_id = match Context::cache().bg_game_round(msg_id).await {
+        Ok(Some(mapset_id)) => mapset_id,
+        Ok(None) => {
+            let content = "This message is not the result of a background game round \
+                or it is older than a day";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err.wrap_err("Failed to get bg game round"));
+        }
+    };
+
+    let tags = match Context::games().bggame_mapset_tags(mapset_id).await {
+        Ok(Some(tags)) => tags,
+        Ok(None) => {
+            let content = format!("Mapset {mapset_id} is not stored for the background game");
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let editor = BackgroundGameTagEditor::new(mapset_id, tags, user_id);
+
+    ActiveMessages::builder(editor).begin(&mut command).await
+}
+
+/// Whether the user may edit the tags of background game mapsets.
+///
+/// Besides the bot owner, members of the guild that either have the
+/// configured editor role or are authorities of the guild are allowed to.
+pub async fn can_edit_mapset_tags(
+    user: Id<UserMarker>,
+    guild: Option<Id<GuildMarker>>,
+) -> Result<bool> {
+    let config = BotConfig::get();
+
+    if user == config.owner {
+        return Ok(true);
+    }
+
+    let Some(guild) = guild else {
+        return Ok(false);
+    };
+
+    if let Some(role) = config.bg_tag_editor_role {
+        let member = Context::cache().member(guild, user).await?;
+
+        if member.is_some_and(|member| member.roles.iter().any(|id| Id::from(*id) == role)) {
+            return Ok(true);
+        }
+    }
+
+    check_authority(user, Some(guild))
+        .await
+        .map(|content| content.is_none())
+}
@@ -1,8 +1,12 @@
-use bathbot_util::{BucketName, MessageBuilder, constants::GENERAL_ISSUE};
+use bathbot_util::{BucketName, MessageBuilder, i18n::ErrorKey};
 use eyre::Result;
 use twilight_model::{channel::Message, guild::Permissions};
 
-use crate::{Context, util::ChannelExt};
+use crate::{
+    Context,
+    core::commands::{interaction::InteractionCommands, localize_error},
+    util::ChannelExt,
+};
 
 pub async fn hint(msg: &Message, permissions: Option<Permissions>) -> Result<()> {
     let ratelimit = Context::check_ratelimit(msg.author.id, BucketName::BgHint);
@@ -23,13 +27,17 @@ pub async fn hint(msg: &Message, permissions: Option<Permissions>) -> Result<()>
                 msg.create_message(builder, permissions).await?;
             }
             Err(err) => {
-                let _ = msg.error(GENERAL_ISSUE).await;
+                let content = localize_error(msg.guild_id, ErrorKey::General).await;
+                let _ = msg.error(content).await;
 
                 return Err(err.wrap_err("Failed to get hint"));
             }
         },
         None => {
-            let content = "No running game in this channel. Start one with `/bg`.";
+            let content = format!(
+                "No running game in this channel. Start one with {}.",
+                InteractionCommands::mention("bg")
+            );
             msg.error(content).await?;
         }
     }
@@ -1,11 +1,12 @@
 use std::ops::ControlFlow;
 
 use bathbot_macros::{SlashCommand, command};
-use bathbot_model::{Effects, command_fields::ThreadChannel};
-use bathbot_psql::model::games::DbMapTagsParams;
+use bathbot_model::{Effects, MapsetTags, command_fields::ThreadChannel};
+use bathbot_psql::model::games::{DbMapTagsParams, MapsetTagsEntries};
 use bathbot_util::{
     Authored, CowUtils, MessageBuilder,
-    constants::{GENERAL_ISSUE, INVALID_ACTION_FOR_CHANNEL_TYPE, THREADS_UNAVAILABLE},
+    constants::{INVALID_ACTION_FOR_CHANNEL_TYPE, THREADS_UNAVAILABLE},
+    i18n::ErrorKey,
 };
 use eyre::{Report, Result};
 use rosu_v2::prelude::GameMode;
@@ -17,6 +18,7 @@ use twilight_model::{
     guild::Permissions,
 };
 
+pub use self::edit_tags::can_edit_mapset_tags;
 use self::{bigger::*, hint::*, rankings::*, skip::*, stop::*};
 use crate::{
     Context,
@@ -29,6 +31,7 @@ use crate::{
 };
 
 mod bigger;
+mod edit_tags;
 mod hint;
 mod rankings;
 mod skip;
@@ -49,7 +52,8 @@ pub async fn prefix_backgroundgame(
 
     match arg.as_ref().map(|arg| arg.as_ref()) {
         None | Some("help") => {
-            let content = "Use `/bg` to start a new background guessing game.\n\
+            let content = format!(
+                "Use {bg} to start a new background guessing game.\n\
                 Given part of a map's background, try to guess the **title** of the map's song.\n\
                 You don't need to guess content in parentheses `(...)` or content after `ft.` or `feat.`.\n\n\
                 Use these prefix commands to initiate with the game:\n\
@@ -58,9 +62,12 @@ pub async fn prefix_backgroundgame(
                 • `<bg h[int]`: Receive a hint (can be used multiple times).\n\
                 • `<bg b[igger]`: Increase the radius of the displayed image (can be used multiple times).\n\
                 • `<bg stop`: Resolve the current background and stop the game.
+                • `<bg tags`: List all tags that can be used for the `tags` and `exclude_tags` options (prefix-only).
                 • `<bg l[eaderboard] s[erver]`: Check out the global leaderboard for \
                 amount of correct guesses. If `server` or `s` is added at the end, \
-                I will only show members of this server.";
+                I will only show members of this server.",
+                bg = InteractionCommands::mention("bg"),
+            );
 
             let builder = MessageBuilder::new().embed(content);
             msg.create_message(builder, permissions).await?;
@@ -71,6 +78,21 @@ pub async fn prefix_backgroundgame(
         Some("h" | "hint") => hint(msg, permissions).await,
         Some("b" | "bigger" | "enhance") => bigger(msg, permissions).await,
         Some("stop" | "end" | "quit") => stop(msg).await,
+        Some("tags") => {
+            let content = format!(
+                "Valid tags for the `tags` and `exclude_tags` options of `/bg`:\n{}",
+                MapsetTags::NAMES
+                    .iter()
+                    .map(|name| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+
+            let builder = MessageBuilder::new().embed(content);
+            msg.create_message(builder, permissions).await?;
+
+            Ok(())
+        }
         Some("l" | "lb" | "leaderboard") => {
             let arg = args.next();
 
@@ -105,6 +127,7 @@ pub async fn prefix_backgroundgame(
     • `<bg h[int]`: Receive a hint (can be used multiple times).\n\
     • `<bg b[igger]`: Increase the radius of the displayed image (can be used multiple times).\n\
     • `<bg stop`: Resolve the current background and stop the game.
+    • `<bg tags`: List all tags that can be used for the `tags` and `exclude_tags` options (prefix-only).
     • `<bg l[eaderboard] s[erver]`: Check out the global leaderboard for \
     amount of correct guesses. If `server` or `s` is added at the end, \
     I will only show members of this server."
@@ -123,6 +146,20 @@ pub struct Bg {
         desc = "Choose if a new thread should be started, defaults to staying in the channel"
     )]
     thread: Option<ThreadChannel>,
+    #[command(
+        desc = "Comma-separated tags that maps must have",
+        help = "Comma-separated tags that maps must have e.g. `weeb, streams`.\n\
+        If this or `exclude_tags` is specified, the setup is skipped and the game starts right away.\n\
+        Use the prefix command `<bg tags` to list all valid tags, there is no slash equivalent."
+    )]
+    tags: Option<String>,
+    #[command(
+        desc = "Comma-separated tags that maps must not have",
+        help = "Comma-separated tags that maps must not have e.g. `meme, old`.\n\
+        If this or `tags` is specified, the setup is skipped and the game starts right away.\n\
+        Use the prefix command `<bg tags` to list all valid tags, there is no slash equivalent."
+    )]
+    exclude_tags: Option<String>,
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -251,8 +288,25 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
         difficulty,
         mode,
         thread,
+        tags,
+        exclude_tags,
     } = Bg::from_interaction(command.input_data())?;
 
+    let parse_tags =
+        |tags: Option<&str>| tags.map_or(Ok(MapsetTags::empty()), MapsetTags::parse_list);
+
+    let (included, excluded) = match parse_tags(tags.as_deref())
+        .and_then(|included| Ok((included, parse_tags(exclude_tags.as_deref())?)))
+    {
+        Ok(tags) => tags,
+        Err(name) => {
+            let content = format!("Unknown tag `{name}`, use `<bg tags` to list all valid tags");
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+    };
+
     let can_view_channel = command
         .permissions
         .is_none_or(|permissions| permissions.contains(Permissions::VIEW_CHANNEL));
@@ -330,7 +384,7 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
                         return Ok(());
                     }
                     None => {
-                        let _ = command.error_callback(GENERAL_ISSUE).await;
+                        let _ = command.error_callback_tr(ErrorKey::General).await;
 
                         return Err(Report::new(err).wrap_err("failed to create thread"));
                     }
@@ -358,55 +412,78 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
 
     let difficulty = difficulty.unwrap_or_default();
 
-    match mode {
-        Some(BgGameMode::Osu) | None => {
-            let setup = BackgroundGameSetup::new(difficulty, author);
+    let mode = match mode {
+        Some(BgGameMode::Osu) | None => GameMode::Osu,
+        Some(BgGameMode::Mania) => GameMode::Mania,
+    };
 
-            if matches!(thread, Some(ThreadChannel::Thread)) {
-                let res_builder = MessageBuilder::new().embed("Starting new thread...");
-                command.callback(res_builder, true).await?;
+    let custom_tags = !(included.is_empty() && excluded.is_empty());
 
-                ActiveMessages::builder(setup).begin(channel).await
-            } else {
-                ActiveMessages::builder(setup).begin(&mut command).await
-            }
-        }
-        Some(BgGameMode::Mania) => {
-            let params = DbMapTagsParams::new(GameMode::Mania);
+    if mode == GameMode::Osu && !custom_tags {
+        let setup = BackgroundGameSetup::new(difficulty, author);
 
-            let entries = match Context::games().bggame_tags(params).await {
-                Ok(entries) => entries,
-                Err(err) => {
-                    let _ = command.error(GENERAL_ISSUE).await;
+        return if matches!(thread, Some(ThreadChannel::Thread)) {
+            let res_builder = MessageBuilder::new().embed("Starting new thread...");
+            command.callback(res_builder, true).await?;
 
-                    return Err(err.wrap_err("failed to get all tagged mania mapsets"));
-                }
-            };
+            ActiveMessages::builder(setup).begin(channel).await
+        } else {
+            ActiveMessages::builder(setup).begin(&mut command).await
+        };
+    }
 
-            let content = format!(
-                "Starting mania background guessing game with {} different backgrounds",
-                entries.tags.len()
-            );
+    let mut params = DbMapTagsParams::new(mode);
+    params.include(included);
+    params.exclude(excluded);
 
-            let builder = MessageBuilder::new().embed(content);
+    let entries = match Context::games().bggame_tags(params).await {
+        Ok(entries) => entries,
+        Err(err) => {
+            let _ = command.error_callback_tr(ErrorKey::General).await;
 
-            if matches!(thread, Some(ThreadChannel::Thread)) {
-                let res_builder = MessageBuilder::new().embed("Starting new thread...");
-                command.callback(res_builder, true).await?;
+            return Err(err.wrap_err("failed to get tagged mapsets"));
+        }
+    };
 
-                channel.create_message(builder, None).await?;
-            } else {
-                command.callback(builder, false).await?;
-            }
+    if custom_tags && !entries.is_pool_sufficient() {
+        let content = format!(
+            "Only {} mapsets match the given tags but at least {} are required, \
+            try including fewer or excluding fewer tags",
+            entries.mapset_count(),
+            MapsetTagsEntries::MIN_POOL_SIZE,
+        );
 
-            let game_fut = BackgroundGame::new(channel, entries, Effects::empty(), difficulty);
+        command.error_callback(content).await?;
 
-            Context::bg_games()
-                .own(channel)
-                .await
-                .insert(game_fut.await);
+        return Ok(());
+    }
 
-            Ok(())
-        }
+    let content = format!(
+        "Starting {mode}background guessing game with {} different backgrounds",
+        entries.tags.len(),
+        mode = match mode {
+            GameMode::Mania => "mania ",
+            _ => "",
+        },
+    );
+
+    let builder = MessageBuilder::new().embed(content);
+
+    if matches!(thread, Some(ThreadChannel::Thread)) {
+        let res_builder = MessageBuilder::new().embed("Starting new thread...");
+        command.callback(res_builder, true).await?;
+
+        channel.create_message(builder, None).await?;
+    } else {
+        command.callback(builder, false).await?;
     }
+
+    let game_fut = BackgroundGame::new(channel, entries, Effects::empty(), difficulty);
+
+    Context::bg_games()
+        .own(channel)
+        .await
+        .insert(game_fut.await);
+
+    Ok(())
 }
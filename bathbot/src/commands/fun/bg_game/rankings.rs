@@ -1,13 +1,14 @@
 use std::collections::{BTreeMap, HashSet};
 
 use bathbot_model::{RankingEntries, RankingEntry, RankingKind};
-use bathbot_util::{IntHasher, constants::GENERAL_ISSUE};
+use bathbot_util::{IntHasher, i18n::ErrorKey};
 use eyre::Result;
 use twilight_model::{channel::Message, id::Id};
 
 use crate::{
     Context,
     active::{ActiveMessages, impls::RankingPagination},
+    core::commands::localize_error,
     util::ChannelExt,
 };
 
@@ -17,7 +18,8 @@ pub async fn leaderboard(msg: &Message, global: bool) -> Result<()> {
     let mut scores = match Context::games().bggame_leaderboard().await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = msg.error(GENERAL_ISSUE).await;
+            let content = localize_error(msg.guild_id, ErrorKey::General).await;
+            let _ = msg.error(content).await;
 
             return Err(err.wrap_err("failed to get bggame scores"));
         }
@@ -26,11 +28,10 @@ pub async fn leaderboard(msg: &Message, global: bool) -> Result<()> {
     let guild = msg.guild_id;
 
     if let Some(guild) = guild.filter(|_| !global) {
-        let members: HashSet<_, IntHasher> = cache
-            .members(guild)
+        let members: HashSet<_, IntHasher> = Context::user_config()
+            .visible_linked_users(guild)
             .await?
             .into_iter()
-            .map(|id| id as i64)
             .collect();
 
         scores.retain(|row| members.contains(&row.discord_id));
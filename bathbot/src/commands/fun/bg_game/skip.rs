@@ -1,11 +1,12 @@
-use bathbot_util::{
-    BucketName,
-    constants::{GENERAL_ISSUE, INVITE_LINK},
-};
+use bathbot_util::{BucketName, constants::INVITE_LINK, i18n::ErrorKey, tr};
 use eyre::Result;
 use twilight_model::channel::Message;
 
-use crate::{Context, util::ChannelExt};
+use crate::{
+    Context,
+    core::commands::{interaction::InteractionCommands, localize_error},
+    util::ChannelExt,
+};
 
 pub async fn skip(msg: &Message) -> Result<()> {
     if let Some(cooldown) = Context::check_ratelimit(msg.author.id, BucketName::BgSkip) {
@@ -14,7 +15,8 @@ pub async fn skip(msg: &Message) -> Result<()> {
             msg.author.id
         );
 
-        let content = format!("Command on cooldown, try again in {cooldown} seconds");
+        let lang = Context::guild_config().lang(msg.guild_id).await;
+        let content = tr!("cooldown", lang, seconds = cooldown);
         msg.error(content).await?;
 
         return Ok(());
@@ -26,16 +28,18 @@ pub async fn skip(msg: &Message) -> Result<()> {
         Some(game) => match game.restart() {
             Ok(_) => {}
             Err(err) => {
-                let _ = msg.error(GENERAL_ISSUE).await;
+                let content = localize_error(msg.guild_id, ErrorKey::General).await;
+                let _ = msg.error(content).await;
 
                 return Err(err.wrap_err("Failed to restart game"));
             }
         },
         None => {
             let content = format!(
-                "The background guessing game must be started with `/bg`.\n\
+                "The background guessing game must be started with {bg}.\n\
                 If slash commands are not available in your server, \
-                try [re-inviting the bot]({INVITE_LINK})."
+                try [re-inviting the bot]({INVITE_LINK}).",
+                bg = InteractionCommands::mention("bg"),
             );
 
             msg.error(content).await?;
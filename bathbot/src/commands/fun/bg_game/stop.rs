@@ -1,7 +1,7 @@
 use eyre::Result;
 use twilight_model::channel::Message;
 
-use crate::{Context, util::ChannelExt};
+use crate::{Context, core::commands::interaction::InteractionCommands, util::ChannelExt};
 
 pub async fn stop(msg: &Message) -> Result<()> {
     match Context::bg_games().read(&msg.channel_id).await.get() {
@@ -14,7 +14,10 @@ pub async fn stop(msg: &Message) -> Result<()> {
             }
         },
         None => {
-            let content = "No running game in this channel. Start one with `/bg`.";
+            let content = format!(
+                "No running game in this channel. Start one with {}.",
+                InteractionCommands::mention("bg")
+            );
             msg.error(content).await?;
         }
     }
@@ -4,7 +4,7 @@ use bathbot_macros::SlashCommand;
 use bathbot_model::{
     HlVersion, RankingEntries, RankingEntry, RankingKind, command_fields::GameModeOption,
 };
-use bathbot_util::{Authored, IntHasher, constants::GENERAL_ISSUE};
+use bathbot_util::{Authored, IntHasher, i18n::ErrorKey};
 use eyre::Result;
 use rosu_v2::prelude::GameMode;
 use twilight_interactions::command::{CommandModel, CreateCommand};
@@ -24,6 +24,8 @@ use crate::{
 pub enum HigherLower {
     #[command(name = "pp")]
     ScorePp(HigherLowerScorePp),
+    #[command(name = "rank")]
+    ScoreRank(HigherLowerScoreRank),
     #[command(name = "leaderboard")]
     Leaderboard(HigherLowerLeaderboard),
 }
@@ -42,34 +44,54 @@ pub struct HigherLowerScorePp {
     mode: Option<GameModeOption>,
 }
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "rank",
+    desc = "Is the player's global rank higher or lower?",
+    help = "Is the player's global rank higher or lower?\n\
+    The players are chosen randomly from the top 5,000 and one of their top scores \
+    is shown. Guess whether the next player's global rank is higher or lower than \
+    the previous one's. Note that #1 is a higher rank than #2."
+)]
+pub struct HigherLowerScoreRank {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+}
+
 #[derive(CommandModel, CreateCommand)]
 #[command(
     name = "leaderboard",
     desc = "Get the server leaderboard for higherlower highscores"
 )]
-pub struct HigherLowerLeaderboard;
+pub struct HigherLowerLeaderboard {
+    #[command(desc = "Specify a game version, defaults to pp")]
+    version: Option<HlVersion>,
+}
 
 async fn slash_higherlower(mut command: InteractionCommand) -> Result<()> {
     let args = HigherLower::from_interaction(command.input_data())?;
     let user = command.user_id()?;
 
-    let game_res = match args {
-        HigherLower::ScorePp(args) => {
-            let mode = match args.mode.map(GameMode::from) {
-                Some(mode) => mode,
-                None => Context::user_config()
-                    .mode(user)
-                    .await?
-                    .unwrap_or(GameMode::Osu),
-            };
-
-            HigherLowerGame::new_score_pp(mode, user).await
-        }
-        HigherLower::Leaderboard(_) => {
-            return higherlower_leaderboard(command, HlVersion::ScorePp).await;
+    let (version, mode) = match args {
+        HigherLower::ScorePp(args) => (HlVersion::ScorePp, args.mode),
+        HigherLower::ScoreRank(args) => (HlVersion::ScoreRank, args.mode),
+        HigherLower::Leaderboard(args) => {
+            let version = args.version.unwrap_or(HlVersion::ScorePp);
+
+            return higherlower_leaderboard(command, version).await;
         }
     };
 
+    let mode = match mode.map(GameMode::from) {
+        Some(mode) => mode,
+        None => Context::user_config()
+            .mode(user)
+            .await?
+            .unwrap_or(GameMode::Osu),
+    };
+
+    let game_res = HigherLowerGame::new(version, mode, user).await;
+
     match game_res {
         Ok(game) => {
             ActiveMessages::builder(game)
@@ -78,7 +100,7 @@ async fn slash_higherlower(mut command: InteractionCommand) -> Result<()> {
                 .await
         }
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             Err(err)
         }
@@ -102,17 +124,16 @@ async fn higherlower_leaderboard(
     let mut scores = match Context::games().higherlower_leaderboard(version).await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
     };
 
-    let members: HashSet<_, IntHasher> = Context::cache()
-        .members(guild)
+    let members: HashSet<_, IntHasher> = Context::user_config()
+        .visible_linked_users(guild)
         .await?
         .into_iter()
-        .map(|id| id as i64)
         .collect();
 
     scores.retain(|row| members.contains(&row.discord_id));
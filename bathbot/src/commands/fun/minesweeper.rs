@@ -18,7 +18,7 @@ use crate::{
     help = "Play a game of minesweeper.\n\
     In case you don't know how it works: Each number indicates the amount of neighboring bombs."
 )]
-#[flags(SKIP_DEFER)]
+#[flags(SKIP_DEFER, NO_DATABASE)]
 pub struct Minesweeper {
     #[command(desc = "Choose a difficulty")]
     difficulty: Difficulty,
@@ -52,7 +52,7 @@ pub async fn slash_minesweeper(mut command: InteractionCommand) -> Result<()> {
     - `hard`: 9x11 grid"
 )]
 #[usage("[easy / medium / hard]")]
-#[flags(SKIP_DEFER)]
+#[flags(SKIP_DEFER, NO_DATABASE)]
 #[group(Games)]
 async fn prefix_minesweeper(msg: &Message, mut args: Args<'_>) -> Result<()> {
     let difficulty = match Difficulty::args(&mut args) {
@@ -1,15 +1,19 @@
 use std::borrow::Cow;
 
 use bathbot_macros::{SlashCommand, command};
+use bathbot_model::command_fields::GameModeOption;
 use bathbot_util::{
     MessageBuilder, matcher,
     osu::{AttributeKind, ModSelection},
 };
 use eyre::Result;
+use rosu_pp::model::mode::GameMode as Mode;
 use rosu_v2::{model::mods::GameModsIntermode, prelude::GameMode};
 use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::Attachment;
 
 use crate::{
+    commands::osu::parsed_map::AttachedSimulateMap,
     core::commands::{CommandOrigin, prefix::Args},
     embeds::{AttributesEmbed, EmbedData},
     util::{ChannelExt, InteractionCommandExt, interaction::InteractionCommand},
@@ -33,6 +37,15 @@ pub enum Attributes<'a> {
 }
 
 impl<'a> Attributes<'a> {
+    fn take_file(&mut self) -> Option<Attachment> {
+        match self {
+            Self::Ar(args) => args.file.take(),
+            Self::Cs(args) => args.file.take(),
+            Self::Hp(args) => args.file.take(),
+            Self::Od(args) => args.file.take(),
+        }
+    }
+
     fn args(kind: AttributeKind, mut args: Args<'a>) -> Result<Self, &'static str> {
         let number: f32 = args
             .next()
@@ -47,24 +60,32 @@ impl<'a> Attributes<'a> {
 
         let this = match kind {
             AttributeKind::Ar => Self::Ar(AttributesAr {
-                number: number.clamp(AR_MIN, AR_MAX),
+                number: Some(number.clamp(AR_MIN, AR_MAX)),
                 mods,
                 clock_rate: None,
+                mode: None,
+                file: None,
             }),
             AttributeKind::Cs => Self::Cs(AttributesCs {
-                number: number.clamp(CS_MIN, CS_MAX),
+                number: Some(number.clamp(CS_MIN, CS_MAX)),
                 mods,
                 clock_rate: None,
+                mode: None,
+                file: None,
             }),
             AttributeKind::Hp => Self::Hp(AttributesHp {
-                number: number.clamp(HP_MIN, HP_MAX),
+                number: Some(number.clamp(HP_MIN, HP_MAX)),
                 mods,
                 clock_rate: None,
+                mode: None,
+                file: None,
             }),
             AttributeKind::Od => Self::Od(AttributesOd {
-                number: number.clamp(OD_MIN, OD_MAX),
+                number: Some(number.clamp(OD_MIN, OD_MAX)),
                 mods,
                 clock_rate: None,
+                mode: None,
+                file: None,
             }),
         };
 
@@ -83,9 +104,9 @@ pub struct AttributesAr<'a> {
         rename = "value",
         min_value = AR_MIN = f32,
         max_value = AR_MAX = f32,
-        desc = "Specify an AR value"
+        desc = "Specify an AR value, defaults to the file's AR"
     )]
-    number: f32,
+    number: Option<f32>,
     #[command(
         desc = "Specify mods e.g. hdhr or nm",
         help = "Specify mods either directly or through the explicit `+mod!` / `+mod` syntax, \
@@ -94,6 +115,10 @@ pub struct AttributesAr<'a> {
     mods: Cow<'a, str>,
     #[command(desc = "Specify a custom clock rate that overwrites mods")]
     clock_rate: Option<f32>,
+    #[command(desc = "Specify a gamemode for mode-specific values, defaults to osu!standard")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a .osu file to use its value, e.g. of an unsubmitted map")]
+    file: Option<Attachment>,
 }
 
 const CS_DESC: &str = "Check how mods influence the circle size attribute";
@@ -107,9 +132,9 @@ pub struct AttributesCs<'a> {
         rename = "value",
         min_value = CS_MIN = f32,
         max_value = CS_MAX = f32,
-        desc = "Specify a CS value"
+        desc = "Specify a CS value, defaults to the file's CS"
     )]
-    number: f32,
+    number: Option<f32>,
     #[command(
         desc = "Specify mods e.g. hdhr or nm",
         help = "Specify mods either directly or through the explicit `+mod!` / `+mod` syntax, \
@@ -118,6 +143,10 @@ pub struct AttributesCs<'a> {
     mods: Cow<'a, str>,
     #[command(desc = "Specify a custom clock rate that overwrites mods")]
     clock_rate: Option<f32>,
+    #[command(desc = "Specify a gamemode for mode-specific values, defaults to osu!standard")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a .osu file to use its value, e.g. of an unsubmitted map")]
+    file: Option<Attachment>,
 }
 
 const HP_DESC: &str = "Check how mods influence the drain rate attribute";
@@ -131,9 +160,9 @@ pub struct AttributesHp<'a> {
         rename = "value",
         min_value = HP_MIN = f32,
         max_value = HP_MAX = f32,
-        desc = "Specify an HP value"
+        desc = "Specify an HP value, defaults to the file's HP"
     )]
-    number: f32,
+    number: Option<f32>,
     #[command(
         desc = "Specify mods e.g. hdhr or nm",
         help = "Specify mods either directly or through the explicit `+mod!` / `+mod` syntax, \
@@ -142,6 +171,10 @@ pub struct AttributesHp<'a> {
     mods: Cow<'a, str>,
     #[command(desc = "Specify a custom clock rate that overwrites mods")]
     clock_rate: Option<f32>,
+    #[command(desc = "Specify a gamemode for mode-specific values, defaults to osu!standard")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a .osu file to use its value, e.g. of an unsubmitted map")]
+    file: Option<Attachment>,
 }
 
 const OD_DESC: &str = "Check how mods influence the overall difficulty attribute";
@@ -155,9 +188,9 @@ pub struct AttributesOd<'a> {
         rename = "value",
         min_value = OD_MIN = f32,
         max_value = OD_MAX = f32,
-        desc = "Specify an OD value"
+        desc = "Specify an OD value, defaults to the file's OD"
     )]
-    number: f32,
+    number: Option<f32>,
     #[command(
         desc = "Specify mods e.g. hdhr or nm",
         help = "Specify mods either directly or through the explicit `+mod!` / `+mod` syntax, \
@@ -166,12 +199,28 @@ pub struct AttributesOd<'a> {
     mods: Cow<'a, str>,
     #[command(desc = "Specify a custom clock rate that overwrites mods")]
     clock_rate: Option<f32>,
+    #[command(desc = "Specify a gamemode for mode-specific values, defaults to osu!standard")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a .osu file to use its value, e.g. of an unsubmitted map")]
+    file: Option<Attachment>,
 }
 
 async fn slash_attributes(mut command: InteractionCommand) -> Result<()> {
-    let attrs = Attributes::from_interaction(command.input_data())?;
+    let mut attrs = Attributes::from_interaction(command.input_data())?;
+
+    let Some(file) = attrs.take_file() else {
+        return attributes((&mut command).into(), attrs, None).await;
+    };
 
-    attributes((&mut command).into(), attrs).await
+    // Downloading and parsing the file might take longer than an initial
+    // response is allowed to
+    command.defer(false).await?;
+    let orig = CommandOrigin::from(&mut command);
+
+    match AttachedSimulateMap::new(&orig, Box::new(file)).await? {
+        Some(map) => attributes(orig, attrs, Some(map)).await,
+        None => Ok(()),
+    }
 }
 
 #[command]
@@ -182,7 +231,7 @@ async fn slash_attributes(mut command: InteractionCommand) -> Result<()> {
 #[group(AllModes)]
 async fn prefix_ar(msg: &Message, args: Args<'_>) -> Result<()> {
     match Attributes::args(AttributeKind::Ar, args) {
-        Ok(args) => attributes(msg.into(), args).await,
+        Ok(args) => attributes(msg.into(), args, None).await,
         Err(err) => {
             msg.error(err).await?;
 
@@ -199,7 +248,7 @@ async fn prefix_ar(msg: &Message, args: Args<'_>) -> Result<()> {
 #[group(AllModes)]
 async fn prefix_cs(msg: &Message, args: Args<'_>) -> Result<()> {
     match Attributes::args(AttributeKind::Cs, args) {
-        Ok(args) => attributes(msg.into(), args).await,
+        Ok(args) => attributes(msg.into(), args, None).await,
         Err(err) => {
             msg.error(err).await?;
 
@@ -216,7 +265,7 @@ async fn prefix_cs(msg: &Message, args: Args<'_>) -> Result<()> {
 #[group(AllModes)]
 async fn prefix_hp(msg: &Message, args: Args<'_>) -> Result<()> {
     match Attributes::args(AttributeKind::Hp, args) {
-        Ok(args) => attributes(msg.into(), args).await,
+        Ok(args) => attributes(msg.into(), args, None).await,
         Err(err) => {
             msg.error(err).await?;
 
@@ -233,7 +282,7 @@ async fn prefix_hp(msg: &Message, args: Args<'_>) -> Result<()> {
 #[group(AllModes)]
 async fn prefix_od(msg: &Message, args: Args<'_>) -> Result<()> {
     match Attributes::args(AttributeKind::Od, args) {
-        Ok(args) => attributes(msg.into(), args).await,
+        Ok(args) => attributes(msg.into(), args, None).await,
         Err(err) => {
             msg.error(err).await?;
 
@@ -242,12 +291,69 @@ async fn prefix_od(msg: &Message, args: Args<'_>) -> Result<()> {
     }
 }
 
-async fn attributes(orig: CommandOrigin<'_>, args: Attributes<'_>) -> Result<()> {
-    let (kind, value, mods, clock_rate) = match args {
-        Attributes::Ar(args) => (AttributeKind::Ar, args.number, args.mods, args.clock_rate),
-        Attributes::Cs(args) => (AttributeKind::Cs, args.number, args.mods, args.clock_rate),
-        Attributes::Hp(args) => (AttributeKind::Hp, args.number, args.mods, args.clock_rate),
-        Attributes::Od(args) => (AttributeKind::Od, args.number, args.mods, args.clock_rate),
+async fn attributes(
+    orig: CommandOrigin<'_>,
+    args: Attributes<'_>,
+    map: Option<AttachedSimulateMap>,
+) -> Result<()> {
+    let (kind, value, mods, clock_rate, mode) = match args {
+        Attributes::Ar(args) => (
+            AttributeKind::Ar,
+            args.number,
+            args.mods,
+            args.clock_rate,
+            args.mode,
+        ),
+        Attributes::Cs(args) => (
+            AttributeKind::Cs,
+            args.number,
+            args.mods,
+            args.clock_rate,
+            args.mode,
+        ),
+        Attributes::Hp(args) => (
+            AttributeKind::Hp,
+            args.number,
+            args.mods,
+            args.clock_rate,
+            args.mode,
+        ),
+        Attributes::Od(args) => (
+            AttributeKind::Od,
+            args.number,
+            args.mods,
+            args.clock_rate,
+            args.mode,
+        ),
+    };
+
+    // The interaction was deferred if a map was attached
+    let deferred = map.is_some();
+
+    let mode = match (mode, map.as_ref()) {
+        (Some(mode), _) => GameMode::from(mode),
+        (None, Some(map)) => match map.pp_map.mode {
+            Mode::Osu => GameMode::Osu,
+            Mode::Taiko => GameMode::Taiko,
+            Mode::Catch => GameMode::Catch,
+            Mode::Mania => GameMode::Mania,
+        },
+        (None, None) => GameMode::Osu,
+    };
+
+    let value = match (value, map.as_ref()) {
+        (Some(value), _) => value,
+        (None, Some(map)) => match kind {
+            AttributeKind::Ar => map.pp_map.ar,
+            AttributeKind::Cs => map.pp_map.cs,
+            AttributeKind::Hp => map.pp_map.hp,
+            AttributeKind::Od => map.pp_map.od,
+        },
+        (None, None) => {
+            let content = "Either specify a value or attach a .osu file";
+
+            return respond_error(&orig, deferred, content).await;
+        }
     };
 
     let mods = if let Some(mods) = GameModsIntermode::try_from_acronyms(&mods) {
@@ -258,15 +364,13 @@ async fn attributes(orig: CommandOrigin<'_>, args: Attributes<'_>) -> Result<()>
             None => {
                 let content =
                     "Failed to parse mods. Be sure to specify a valid mod combination e.g. `hrdt`.";
-                orig.error_callback(content).await?;
 
-                return Ok(());
+                return respond_error(&orig, deferred, content).await;
             }
             Some(ModSelection::Exclude { .. }) => {
                 let content = "Excluding mods does not work for this command";
-                orig.error_callback(content).await?;
 
-                return Ok(());
+                return respond_error(&orig, deferred, content).await;
             }
         }
     };
@@ -283,14 +387,27 @@ async fn attributes(orig: CommandOrigin<'_>, args: Attributes<'_>) -> Result<()>
     if !valid_mods {
         let content = "Looks like either some of these mods are incompatible with each other \
             or those mods don't fit to any gamemode.";
-        orig.error_callback(content).await?;
 
-        return Ok(());
+        return respond_error(&orig, deferred, content).await;
     }
 
-    let embed = AttributesEmbed::new(kind, value, mods, clock_rate).build();
+    let metadata = map.as_ref().map(|map| &map.metadata);
+    let embed = AttributesEmbed::new(kind, value, mods, clock_rate, mode, metadata).build();
     let builder = MessageBuilder::new().embed(embed);
-    orig.callback(builder).await?;
+
+    if deferred {
+        orig.create_message(builder).await?;
+    } else {
+        orig.callback(builder).await?;
+    }
 
     Ok(())
 }
+
+async fn respond_error(orig: &CommandOrigin<'_>, deferred: bool, content: &str) -> Result<()> {
+    if deferred {
+        orig.error(content).await
+    } else {
+        orig.error_callback(content).await
+    }
+}
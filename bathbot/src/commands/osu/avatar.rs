@@ -2,9 +2,7 @@ use std::borrow::Cow;
 
 use bathbot_macros::{HasName, SlashCommand, command};
 use bathbot_util::{
-    AuthorBuilder, EmbedBuilder, MessageBuilder,
-    constants::{GENERAL_ISSUE, OSU_BASE},
-    matcher,
+    AuthorBuilder, EmbedBuilder, MessageBuilder, constants::OSU_BASE, i18n::ErrorKey, matcher,
     osu::flag_url,
 };
 use eyre::{Report, Result};
@@ -71,7 +69,7 @@ async fn avatar(orig: CommandOrigin<'_>, args: Avatar<'_>) -> Result<()> {
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&orig).await,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -88,7 +86,7 @@ async fn avatar(orig: CommandOrigin<'_>, args: Avatar<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -3,7 +3,8 @@ use std::collections::BTreeMap;
 use bathbot_macros::command;
 use bathbot_util::{
     MessageBuilder,
-    constants::{AVATAR_URL, GENERAL_ISSUE, OSEKAI_ISSUE},
+    constants::{AVATAR_URL, OSEKAI_ISSUE},
+    i18n::ErrorKey,
     matcher,
 };
 use eyre::{Report, Result};
@@ -73,7 +74,7 @@ pub(super) async fn user(orig: CommandOrigin<'_>, args: BadgesUser<'_>) -> Resul
             Ok(Some(id)) => UserId::Id(id),
             Ok(None) => return require_link(&orig).await,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err.wrap_err("failed to get user id"));
             }
@@ -97,7 +98,7 @@ pub(super) async fn user(orig: CommandOrigin<'_>, args: BadgesUser<'_>) -> Resul
             return orig.error(content).await;
         }
         UserArgs::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
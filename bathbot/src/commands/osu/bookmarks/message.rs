@@ -2,9 +2,10 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use bathbot_macros::msg_command;
 use bathbot_psql::model::configs::ListSize;
-use bathbot_util::{Authored, MessageOrigin, constants::GENERAL_ISSUE, osu::MapIdType};
+use bathbot_util::{Authored, MessageOrigin, i18n::ErrorKey, osu::MapIdType};
 use eyre::{Report, Result};
 use rosu_v2::prelude::OsuError;
+use tracing::Instrument;
 use twilight_model::channel::Message;
 
 use crate::{
@@ -22,7 +23,7 @@ async fn bookmark_map(mut command: InteractionCommand) -> Result<()> {
         .and_then(|resolved| resolved.messages.values().next());
 
     let Some(msg) = msg_opt else {
-        let _ = command.error(GENERAL_ISSUE).await;
+        let _ = command.error_tr(ErrorKey::General).await;
 
         bail!("Missing resolved message");
     };
@@ -79,14 +80,14 @@ async fn bookmark_map(mut command: InteractionCommand) -> Result<()> {
             return Ok(());
         }
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get mapset"));
         }
     };
 
     let mapset_clone = mapset.clone();
-    tokio::spawn(async move { Context::osu_map().store(&mapset_clone).await });
+    tokio::spawn(async move { Context::osu_map().store(&mapset_clone).await }.in_current_span());
 
     let map_opt = mapset
         .maps
@@ -110,7 +111,7 @@ async fn bookmark_map(mut command: InteractionCommand) -> Result<()> {
     let bookmarks = match Context::bookmarks().get(user_id).await {
         Ok(bookmarks) => bookmarks,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await?;
+            let _ = command.error_tr(ErrorKey::General).await?;
 
             return Err(err);
         }
@@ -120,7 +121,7 @@ async fn bookmark_map(mut command: InteractionCommand) -> Result<()> {
         Some(index) => index,
         None => {
             if let Err(err) = Context::bookmarks().add(user_id, map_id).await {
-                let _ = command.error(GENERAL_ISSUE).await;
+                let _ = command.error_tr(ErrorKey::General).await;
 
                 return Err(err);
             } else {
@@ -134,7 +135,7 @@ async fn bookmark_map(mut command: InteractionCommand) -> Result<()> {
     let bookmarks = match Context::bookmarks().get(user_id).await {
         Ok(bookmarks) => bookmarks,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await?;
+            let _ = command.error_tr(ErrorKey::General).await?;
 
             return Err(err);
         }
@@ -2,10 +2,13 @@ use std::{borrow::Cow, fmt::Write};
 
 use bathbot_macros::{SlashCommand, command};
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_psql::model::{configs::ListSize, osu::MapBookmark};
+use bathbot_psql::model::{
+    configs::{ListSize, resolve},
+    osu::MapBookmark,
+};
 use bathbot_util::{
     CowUtils, MessageOrigin,
-    constants::GENERAL_ISSUE,
+    i18n::ErrorKey,
     query::{BookmarkCriteria, FilterCriteria, IFilterCriteria},
 };
 use eyre::Result;
@@ -48,7 +51,7 @@ pub struct Bookmarks<'a> {
         desc = "Size of the embed",
         help = "Size of the embed.\n\
         `Condensed` shows 10 entries, `Detailed` shows 5, and `Single` shows 1.\n\
-        The default can be set with the `/config` command."
+        The default can be set with the `/config edit` command."
     )]
     size: Option<ListSize>,
 }
@@ -131,25 +134,21 @@ async fn bookmarks(orig: CommandOrigin<'_>, args: Bookmarks<'_>) -> Result<()> {
     let (config_res, guild_list_size, bookmarks_res) =
         tokio::join!(config_fut, guild_config_fut, bookmarks_fut);
 
-    let list_size = match args.size {
-        Some(size) => size,
-        None => match config_res {
-            Ok(config) => match config.list_size {
-                Some(size) => size,
-                None => guild_list_size.unwrap_or_default(),
-            },
-            Err(err) => {
-                warn!(?err, "Failed to fetch user config");
-
-                guild_list_size.unwrap_or_default()
-            }
-        },
+    let user_list_size = match config_res {
+        Ok(config) => config.list_size,
+        Err(err) => {
+            warn!(?err, "Failed to fetch user config");
+
+            None
+        }
     };
 
+    let list_size = resolve::list_size(args.size, user_list_size, guild_list_size);
+
     let mut bookmarks = match bookmarks_res {
         Ok(bookmarks) => bookmarks,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
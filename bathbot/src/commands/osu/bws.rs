@@ -7,7 +7,7 @@ use std::{
 
 use bathbot_macros::{HasName, SlashCommand, command};
 use bathbot_util::{
-    EmbedBuilder, IntHasher, MessageBuilder, TourneyBadges, constants::GENERAL_ISSUE, matcher,
+    EmbedBuilder, IntHasher, MessageBuilder, TourneyBadges, i18n::ErrorKey, matcher,
     numbers::WithComma,
 };
 use eyre::{Report, Result};
@@ -70,10 +70,9 @@ impl<'m> Bws<'m> {
         let mut rank = None;
         let mut badges = None;
 
-        for arg in args.take(3) {
-            if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
-                let key = &arg[..idx];
-                let value = arg[idx + 1..].trim_end();
+        for arg in args.into_args().take(3) {
+            if let Some((key, value)) = arg.key_value() {
+                let value = value.trim_end();
 
                 match key {
                     "rank" | "r" => match value.parse::<u32>() {
@@ -100,10 +99,10 @@ impl<'m> Bws<'m> {
                         return Err(content.into());
                     }
                 }
-            } else if let Some(id) = matcher::get_mention_user(arg) {
+            } else if let Some(id) = matcher::get_mention_user(&arg.value) {
                 discord = Some(id);
             } else {
-                name = Some(arg.into());
+                name = Some(arg.value);
             }
         }
 
@@ -156,7 +155,7 @@ async fn bws(orig: CommandOrigin<'_>, args: Bws<'_>) -> Result<()> {
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&orig).await,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -177,7 +176,7 @@ async fn bws(orig: CommandOrigin<'_>, args: Bws<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -418,3 +417,34 @@ fn bws_value(rank: u32, badges: usize) -> u64 {
 
     rank.powf(0.9937_f64.powi(badges * badges)).round() as u64
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bws_without_badges_is_rank() {
+        assert_eq!(bws_value(1000, 0), 1000);
+        assert_eq!(bws_value(1, 10), 1);
+    }
+
+    #[test]
+    fn bws_reference_values() {
+        assert_eq!(bws_value(1000, 1), 957);
+        assert_eq!(bws_value(10_000, 3), 6009);
+        assert_eq!(bws_value(50_000, 5), 10_285);
+        assert_eq!(bws_value(123_456, 20), 3);
+    }
+
+    #[test]
+    fn more_badges_never_worsen_bws() {
+        for rank in [1, 50, 2500, 100_000] {
+            let values: Vec<_> = (0..10).map(|badges| bws_value(rank, badges)).collect();
+
+            assert!(
+                values.windows(2).all(|pair| pair[1] <= pair[0]),
+                "{values:?}"
+            );
+        }
+    }
+}
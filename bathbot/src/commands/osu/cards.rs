@@ -3,13 +3,9 @@ use std::{borrow::Cow, collections::HashMap};
 use bathbot_cards::{BathbotCard, RequiredAttributes};
 use bathbot_macros::{HasName, SlashCommand, command};
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
-    EmbedBuilder, IntHasher, MessageBuilder, attachment,
-    constants::{GENERAL_ISSUE, OSEKAI_ISSUE},
-    datetime::DATE_FORMAT,
-    matcher,
-    osu::flag_url_size,
+    EmbedBuilder, IntHasher, MessageBuilder, attachment, constants::OSEKAI_ISSUE,
+    datetime::DATE_FORMAT, i18n::ErrorKey, matcher, osu::flag_url_size,
 };
 use eyre::{Report, Result, WrapErr};
 use futures::{TryStreamExt, stream::FuturesUnordered};
@@ -183,16 +179,10 @@ async fn card(orig: CommandOrigin<'_>, args: Card<'_>) -> Result<()> {
         .or(config.mode)
         .unwrap_or(GameMode::Osu);
 
-    let legacy_scores = match config.score_data {
-        Some(score_data) => score_data.is_legacy(),
-        None => match orig.guild_id() {
-            Some(guild_id) => Context::guild_config()
-                .peek(guild_id, |config| config.score_data)
-                .await
-                .is_some_and(ScoreData::is_legacy),
-            None => false,
-        },
-    };
+    let legacy_scores = Context::guild_config()
+        .resolve_score_data(orig.guild_id(), None, config.score_data)
+        .await
+        .is_legacy();
 
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
     let scores_fut = Context::osu_scores()
@@ -209,7 +199,7 @@ async fn card(orig: CommandOrigin<'_>, args: Card<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         (Err(err), _) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -263,12 +253,12 @@ async fn card(orig: CommandOrigin<'_>, args: Card<'_>) -> Result<()> {
     let (pfp, flag) = match tokio::join!(pfp_fut, flag_fut) {
         (Ok(pfp), Ok(flag)) => (pfp, flag),
         (Err(err), _) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to acquire card avatar"));
         }
         (_, Err(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to acquire card flag"));
         }
@@ -6,10 +6,7 @@ use bathbot_model::{
     rkyv_util::time::DateRkyv,
     rosu_v2::user::{User, UserStatisticsRkyv},
 };
-use bathbot_util::{
-    MessageBuilder,
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-};
+use bathbot_util::{MessageBuilder, i18n::ErrorKey};
 use eyre::{Report, Result};
 use futures::{TryStreamExt, future, stream::FuturesUnordered};
 use once_cell::sync::OnceCell;
@@ -133,7 +130,7 @@ async fn claimname(orig: CommandOrigin<'_>, args: ClaimName<'_>) -> Result<()> {
             return Ok(());
         }
         UserArgs::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -223,7 +220,7 @@ async fn claimname(orig: CommandOrigin<'_>, args: ClaimName<'_>) -> Result<()> {
     let user = match user_fut.await {
         Ok(user) => user.unwrap(),
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -2,7 +2,7 @@ use std::{borrow::Cow, cmp::Ordering, collections::HashMap, fmt::Write, iter};
 
 use bathbot_macros::{SlashCommand, command};
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_util::{IntHasher, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{IntHasher, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use rosu_v2::{
     prelude::{GameMode, OsuError, Score, Username},
@@ -20,7 +20,7 @@ use crate::{
     Context,
     active::{ActiveMessages, impls::CompareTopPagination},
     commands::osu::{UserExtraction, user_not_found},
-    core::commands::{CommandOrigin, prefix::Args},
+    core::commands::{CommandOrigin, interaction::InteractionCommands, prefix::Args},
     manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
     util::{InteractionCommandExt, interaction::InteractionCommand, osu::get_combined_thumbnail},
 };
@@ -146,7 +146,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, mut args: CompareTop<'_>) -> Re
     let user_id1 = match extract_user_id(&mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -157,7 +157,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, mut args: CompareTop<'_>) -> Re
     let user_id2 = match extract_user_id(&mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -165,13 +165,15 @@ pub(super) async fn top(orig: CommandOrigin<'_>, mut args: CompareTop<'_>) -> Re
         UserExtraction::None => match Context::user_config().osu_id(owner).await {
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => {
-                let content =
-                    "Since you're not linked with the `/link` command, you must specify two names.";
+                let content = format!(
+                    "Since you're not linked with the {} command, you must specify two names.",
+                    InteractionCommands::mention("link")
+                );
 
                 return orig.error(content).await;
             }
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -187,7 +189,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, mut args: CompareTop<'_>) -> Re
         None => match Context::user_config().mode(owner).await {
             Ok(mode) => mode.unwrap_or(GameMode::Osu),
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -210,7 +212,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, mut args: CompareTop<'_>) -> Re
             return orig.error(content).await;
         }
         (Err(err), _) | (_, Err(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get scores");
 
             return Err(err);
@@ -0,0 +1,252 @@
+use bathbot_macros::msg_command;
+use bathbot_util::{EmbedBuilder, MessageBuilder, i18n::ErrorKey, tr};
+use eyre::{Result, WrapErr};
+use twilight_model::{
+    channel::Message,
+    id::{Id, marker::MessageMarker},
+};
+
+use super::score::{CompareScoreArgs, score};
+use crate::{
+    core::{
+        Context,
+        commands::{
+            CommandOrigin, interaction::InteractionCommands, localize_error,
+            outcome::note_error_response,
+        },
+    },
+    util::{InteractionCommandExt, interaction::InteractionCommand, osu::MapOrScore},
+};
+
+const NO_MAP_FOUND: &str = "Could not find a map in this message.\n\
+    Be sure either:\n\
+    - the message content is a map or score url\n\
+    - the embed author url is a map url\n\
+    - the embed url is a map url\n\
+    - a message before it in the channel contains a map";
+
+#[msg_command(name = "Compare score", flags(SKIP_DEFER))]
+async fn compare_score(mut command: InteractionCommand) -> Result<()> {
+    let msg_opt = command
+        .data
+        .resolved
+        .as_ref()
+        .and_then(|resolved| resolved.messages.values().next());
+
+    let Some(msg) = msg_opt else {
+        let content = localize_error(command.guild_id, ErrorKey::General).await;
+        let _ = ephemeral_error(&command, content).await;
+
+        bail!("Missing resolved message");
+    };
+
+    let map = match find_map(msg).await {
+        Ok(map) => map,
+        Err(content) => return ephemeral_error(&command, content).await,
+    };
+
+    let owner = command.user_id()?;
+
+    match Context::user_config().osu_id(owner).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            let link = InteractionCommands::mention_in(command.guild_id, "link");
+            let lang = Context::guild_config().lang(command.guild_id).await;
+            let content = tr!("link.required_message", lang, link = link);
+
+            return ephemeral_error(&command, content).await;
+        }
+        Err(err) => {
+            let content = localize_error(command.guild_id, ErrorKey::General).await;
+            let _ = ephemeral_error(&command, content).await;
+
+            return Err(err.wrap_err("Failed to get osu id"));
+        }
+    }
+
+    command.defer(false).await.wrap_err("Failed to defer")?;
+
+    score(
+        CommandOrigin::from_interaction(&mut command),
+        CompareScoreArgs::from_map(map),
+    )
+    .await
+}
+
+async fn find_map(msg: &Message) -> Result<MapOrScore, &'static str> {
+    if let Some(map) = MapOrScore::find_in_msg(msg).await {
+        return Ok(map);
+    }
+
+    let history = Context::retrieve_channel_history(msg.channel_id)
+        .await
+        .map_err(|_| NO_MAP_FOUND)?;
+
+    find_map_in_history(msg.id, &history).await
+}
+
+/// Searches the channel history, starting at the target message, for the
+/// most recent map.
+async fn find_map_in_history(
+    msg_id: Id<MessageMarker>,
+    history: &[Message],
+) -> Result<MapOrScore, &'static str> {
+    let Some(idx) = history.iter().position(|msg| msg.id == msg_id) else {
+        return Err(NO_MAP_FOUND);
+    };
+
+    Context::find_map_id_in_msgs(&history[idx..], 0)
+        .await
+        .map(MapOrScore::Map)
+        .ok_or(NO_MAP_FOUND)
+}
+
+/// Respond with an error only visible to the invoking user.
+async fn ephemeral_error(command: &InteractionCommand, content: impl Into<String>) -> Result<()> {
+    note_error_response();
+
+    let embed = EmbedBuilder::new().description(content).color_red();
+    let builder = MessageBuilder::new().embed(embed);
+
+    command
+        .callback(builder, true)
+        .await
+        .map(|_| ())
+        .wrap_err("Failed to callback with error")
+}
+
+#[cfg(test)]
+mod tests {
+    use bathbot_util::osu::MapIdType;
+    use serde_json::{Value, json};
+
+    use super::*;
+
+    fn message(content: &str, embeds: Value) -> Message {
+        let msg = json!({
+            "id": "2",
+            "channel_id": "1",
+            "author": {
+                "id": "3",
+                "username": "bathbot",
+                "discriminator": "0",
+                "avatar": null,
+                "bot": true
+            },
+            "content": content,
+            "timestamp": "2024-10-16T12:00:00.000000+00:00",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "mention_channels": [],
+            "attachments": [],
+            "components": [],
+            "embeds": embeds,
+            "reactions": [],
+            "sticker_items": [],
+            "pinned": false,
+            "type": 0
+        });
+
+        serde_json::from_value(msg).unwrap()
+    }
+
+    fn embed(fields: Value) -> Value {
+        let mut embed = json!({ "type": "rich", "fields": [] });
+        embed
+            .as_object_mut()
+            .unwrap()
+            .extend(fields.as_object().unwrap().clone());
+
+        embed
+    }
+
+    #[tokio::test]
+    async fn map_in_content() {
+        let msg = message("check out https://osu.ppy.sh/b/123", json!([]));
+
+        let map = find_map(&msg).await;
+        assert!(matches!(map, Ok(MapOrScore::Map(MapIdType::Map(123)))));
+    }
+
+    #[tokio::test]
+    async fn score_in_content() {
+        let msg = message("https://osu.ppy.sh/scores/456", json!([]));
+
+        let map = find_map(&msg).await;
+        assert!(matches!(map, Ok(MapOrScore::Score { id: 456, .. })));
+    }
+
+    #[tokio::test]
+    async fn content_before_embeds() {
+        let embeds = json!([embed(json!({ "url": "https://osu.ppy.sh/b/2" }))]);
+        let msg = message("https://osu.ppy.sh/b/1", embeds);
+
+        let map = find_map(&msg).await;
+        assert!(matches!(map, Ok(MapOrScore::Map(MapIdType::Map(1)))));
+    }
+
+    #[tokio::test]
+    async fn embed_url() {
+        let embeds = json!([embed(json!({ "url": "https://osu.ppy.sh/b/789" }))]);
+        let msg = message("", embeds);
+
+        let map = find_map(&msg).await;
+        assert!(matches!(map, Ok(MapOrScore::Map(MapIdType::Map(789)))));
+    }
+
+    #[tokio::test]
+    async fn embed_author_url_before_embed_url() {
+        let embeds = json!([embed(json!({
+            "author": { "name": "Some map", "url": "https://osu.ppy.sh/b/10" },
+            "url": "https://osu.ppy.sh/b/20"
+        }))]);
+        let msg = message("", embeds);
+
+        let map = find_map(&msg).await;
+        assert!(matches!(map, Ok(MapOrScore::Map(MapIdType::Map(10)))));
+    }
+
+    #[tokio::test]
+    async fn embed_description() {
+        let embeds = json!([embed(json!({
+            "description": "**[Some map](https://osu.ppy.sh/b/321)**"
+        }))]);
+        let msg = message("", embeds);
+
+        let map = find_map(&msg).await;
+        assert!(matches!(map, Ok(MapOrScore::Map(MapIdType::Map(321)))));
+    }
+
+    #[tokio::test]
+    async fn history_from_target() {
+        let mut newer = message("https://osu.ppy.sh/b/1", json!([]));
+        newer.id = Id::new(3);
+        let target = message("gg", json!([]));
+        let mut older = message("https://osu.ppy.sh/b/2", json!([]));
+        older.id = Id::new(1);
+
+        let history = [newer, target, older];
+
+        let map = find_map_in_history(Id::new(2), &history).await;
+        assert!(matches!(map, Ok(MapOrScore::Map(MapIdType::Map(2)))));
+    }
+
+    #[tokio::test]
+    async fn no_map_found() {
+        let embeds = json!([embed(json!({ "description": "nothing to see here" }))]);
+        let msg = message("just chatting", embeds);
+
+        assert!(MapOrScore::find_in_msg(&msg).await.is_none());
+
+        let history = [msg];
+        let map = find_map_in_history(Id::new(2), &history).await;
+        assert!(matches!(map, Err(NO_MAP_FOUND)));
+
+        // The target message is no longer within the channel history
+        let map = find_map_in_history(Id::new(4), &history).await;
+        assert!(matches!(map, Err(NO_MAP_FOUND)));
+    }
+}
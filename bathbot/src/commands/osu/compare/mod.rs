@@ -24,6 +24,7 @@ use crate::{
 };
 
 mod common;
+mod message;
 mod most_played;
 mod profile;
 mod score;
@@ -68,6 +69,12 @@ pub enum CompareAutocomplete<'a> {
 pub struct CompareScore<'a> {
     #[command(desc = "Specify a username")]
     name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Compare against another user's best score on the map",
+        help = "Specify a second username to show both users' best scores \
+        on the map side by side instead of listing the scores of a single user."
+    )]
+    vs: Option<Cow<'a, str>>,
     #[command(
         desc = "Specify a map url or map id",
         help = "Specify a map either by map url or map id.\n\
@@ -116,6 +123,7 @@ pub struct CompareScore<'a> {
 #[command(autocomplete = true)]
 pub struct CompareScoreAutocomplete<'a> {
     pub name: Option<Cow<'a, str>>,
+    pub vs: Option<Cow<'a, str>>,
     pub map: Option<Cow<'a, str>>,
     pub difficulty: AutocompleteValue<String>,
     pub mode: Option<GameModeOption>,
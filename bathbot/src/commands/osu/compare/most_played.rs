@@ -1,7 +1,7 @@
 use std::{cmp::Reverse, collections::HashMap, fmt::Write};
 
 use bathbot_macros::command;
-use bathbot_util::{IntHasher, MessageBuilder, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{IntHasher, MessageBuilder, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use rosu_v2::{
     model::GameMode,
@@ -14,7 +14,7 @@ use crate::{
     Context,
     active::{ActiveMessages, impls::CompareMostPlayedPagination},
     commands::osu::{UserExtraction, user_not_found},
-    core::commands::CommandOrigin,
+    core::commands::{CommandOrigin, interaction::InteractionCommands},
     manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
 };
 
@@ -73,7 +73,7 @@ pub(super) async fn mostplayed(
     let user_id1 = match extract_user_id(&mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -84,7 +84,7 @@ pub(super) async fn mostplayed(
     let user_id2 = match extract_user_id(&mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -92,13 +92,15 @@ pub(super) async fn mostplayed(
         UserExtraction::None => match Context::user_config().osu_id(owner).await {
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => {
-                let content =
-                    "Since you're not linked with the `/link` command, you must specify two names.";
+                let content = format!(
+                    "Since you're not linked with the {} command, you must specify two names.",
+                    InteractionCommands::mention("link")
+                );
 
                 return orig.error(content).await;
             }
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -121,7 +123,7 @@ pub(super) async fn mostplayed(
             return orig.error(content).await;
         }
         (Err(err), _) | (_, Err(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get scores");
 
             return Err(err);
@@ -4,7 +4,7 @@ use bathbot_macros::{SlashCommand, command};
 use bathbot_model::{RankAccPeaks, RespektiveUser, command_fields::GameModeOption};
 use bathbot_util::{
     MessageBuilder,
-    constants::GENERAL_ISSUE,
+    i18n::ErrorKey,
     matcher,
     numbers::MinMaxAvg,
     osu::{BonusPP, UserStats},
@@ -30,7 +30,7 @@ use super::{AT_LEAST_ONE, CompareProfile};
 use crate::{
     Context,
     commands::osu::UserExtraction,
-    core::commands::{CommandOrigin, prefix::Args},
+    core::commands::{CommandOrigin, interaction::InteractionCommands, prefix::Args},
     embeds::{EmbedData, ProfileCompareEmbed},
     manager::redis::osu::{UserArgs, UserArgsError},
     util::{InteractionCommandExt, interaction::InteractionCommand},
@@ -90,7 +90,7 @@ pub(super) async fn profile(orig: CommandOrigin<'_>, mut args: CompareProfile<'_
     let user_id1 = match extract_user_id(&mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -101,7 +101,7 @@ pub(super) async fn profile(orig: CommandOrigin<'_>, mut args: CompareProfile<'_
     let user_id2 = match extract_user_id(&mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -109,13 +109,15 @@ pub(super) async fn profile(orig: CommandOrigin<'_>, mut args: CompareProfile<'_
         UserExtraction::None => match Context::user_config().osu_id(orig.user_id()?).await {
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => {
-                let content =
-                    "Since you're not linked with the `/link` command, you must specify two names.";
+                let content = format!(
+                    "Since you're not linked with the {} command, you must specify two names.",
+                    InteractionCommands::mention("link")
+                );
 
                 return orig.error(content).await;
             }
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -131,7 +133,7 @@ pub(super) async fn profile(orig: CommandOrigin<'_>, mut args: CompareProfile<'_
         None => match Context::user_config().mode(orig.user_id()?).await {
             Ok(mode) => mode.unwrap_or(GameMode::Osu),
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -154,7 +156,7 @@ pub(super) async fn profile(orig: CommandOrigin<'_>, mut args: CompareProfile<'_
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user and scores");
 
             return Err(err);
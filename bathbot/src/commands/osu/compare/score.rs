@@ -8,13 +8,17 @@ use bathbot_model::{
     PersonalBestIndex, ScoreSlim,
     command_fields::{GameModeOption, GradeOption},
     embed_builder::{ScoreEmbedSettings, SettingsImage},
+    rosu_v2::score::MapLeaderboardScore,
+};
+use bathbot_psql::model::{
+    configs::{ScoreData, TimestampStyle, resolve},
+    osu::ArchivedMapVersion,
 };
-use bathbot_psql::model::{configs::ScoreData, osu::ArchivedMapVersion};
 use bathbot_util::{
-    CowUtils, MessageOrigin,
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    CowUtils, MessageBuilder, MessageOrigin,
+    i18n::ErrorKey,
     matcher,
-    osu::MapIdType,
+    osu::{MapIdType, ModSelection, is_unranked_pp},
 };
 use eyre::{Report, Result};
 use rosu_v2::{
@@ -41,19 +45,20 @@ use crate::{
     },
     commands::{
         DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
-        osu::{HasMods, ModsResult, map_strains_graph, require_link, user_not_found},
+        osu::{HasMods, ModsResult, Theme, map_strains_graph, require_link, user_not_found},
         utility::{SCORE_DATA_DESC, SCORE_DATA_HELP, ScoreEmbedData, ScoreEmbedDataPersonalBest},
     },
     core::commands::{
         CommandOrigin,
         prefix::{Args, ArgsNum},
     },
+    embeds::{EmbedData, ScoreVersusEmbed},
     manager::{
         MapError, OsuMap,
-        redis::osu::{UserArgs, UserArgsError, UserArgsSlim},
+        redis::osu::{CachedUser, UserArgs, UserArgsError, UserArgsSlim},
     },
     util::{
-        InteractionCommandExt,
+        EffectiveMode, InteractionCommandExt,
         interaction::InteractionCommand,
         osu::{IfFc, MapOrScore},
     },
@@ -69,6 +74,12 @@ use crate::{
 pub struct Cs<'a> {
     #[command(desc = "Specify a username")]
     name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Compare against another user's best score on the map",
+        help = "Specify a second username to show both users' best scores \
+        on the map side by side instead of listing the scores of a single user."
+    )]
+    vs: Option<Cow<'a, str>>,
     #[command(
         desc = "Specify a map url or map id",
         help = "Specify a map either by map url or map id.\n\
@@ -124,6 +135,12 @@ pub struct Cs<'a> {
 pub struct CompareScore_<'a> {
     #[command(desc = "Specify a username")]
     name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Compare against another user's best score on the map",
+        help = "Specify a second username to show both users' best scores \
+        on the map side by side instead of listing the scores of a single user."
+    )]
+    vs: Option<Cow<'a, str>>,
     #[command(
         desc = "Specify a map url or map id",
         help = "Specify a map either by map url or map id.\n\
@@ -171,6 +188,7 @@ pub struct CompareScore_<'a> {
 #[derive(HasMods, HasName)]
 pub(super) struct CompareScoreArgs<'a> {
     name: Option<Cow<'a, str>>,
+    vs: Option<Cow<'a, str>>,
     map: Option<MapOrScore>,
     difficulty: Option<String>,
     mode: Option<GameMode>,
@@ -209,6 +227,7 @@ impl<'m> CompareScoreArgs<'m> {
 
         Self {
             name,
+            vs: None,
             map,
             difficulty: None,
             mode,
@@ -223,6 +242,23 @@ impl<'m> CompareScoreArgs<'m> {
             score_data: None,
         }
     }
+
+    /// Compare the invoking user's scores on the given map.
+    pub(super) fn from_map(map: MapOrScore) -> Self {
+        Self {
+            name: None,
+            vs: None,
+            map: Some(map),
+            difficulty: None,
+            mode: None,
+            sort: None,
+            mods: None,
+            discord: None,
+            index: None,
+            grade: None,
+            score_data: None,
+        }
+    }
 }
 
 impl<'a> TryFrom<CompareScoreAutocomplete<'a>> for CompareScoreArgs<'a> {
@@ -254,6 +290,7 @@ impl<'a> TryFrom<CompareScoreAutocomplete<'a>> for CompareScoreArgs<'a> {
 
         Ok(Self {
             name: args.name,
+            vs: args.vs,
             map,
             difficulty,
             mode: args.mode.map(GameMode::from),
@@ -453,21 +490,25 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
         },
     };
 
-    let score_data = match args.score_data.or(config.score_data) {
-        Some(score_data) => score_data,
-        None => match orig.guild_id() {
-            Some(guild_id) => Context::guild_config()
+    let guild_score_data = match orig.guild_id() {
+        Some(guild_id) => {
+            Context::guild_config()
                 .peek(guild_id, |config| config.score_data)
                 .await
-                .unwrap_or_default(),
-            None => Default::default(),
-        },
+        }
+        None => None,
     };
 
+    // Same resolution as the leaderboard command so that global ranks match
+    let score_data = resolve::score_data(args.score_data, config.score_data, guild_score_data);
+
     let legacy_scores = score_data.is_legacy();
     let settings = config.score_embed.unwrap_or_default();
+    let timestamp_style = config.timestamp_style;
+    let theme = Theme::get(config.graph_theme);
 
     let CompareScoreArgs {
+        vs,
         sort,
         map,
         index,
@@ -477,6 +518,14 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
         ..
     } = args;
 
+    // Each side only shows its best score
+    if vs.is_some() && (sort.is_some() || index.is_some()) {
+        let content = "The `vs` option compares each user's best score \
+            so it can't be combined with `sort` or `index`";
+
+        return orig.error(content).await;
+    }
+
     let map_id = if let Some(Ok(map_id)) = difficulty.as_deref().map(str::parse) {
         map_id
     } else {
@@ -488,7 +537,16 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
                 return orig.error(content).await;
             }
             Some(MapOrScore::Score { id, mode }) => {
-                return compare_from_score(orig, id, mode, settings, score_data).await;
+                return compare_from_score(
+                    orig,
+                    id,
+                    mode,
+                    settings,
+                    score_data,
+                    timestamp_style,
+                    theme,
+                )
+                .await;
             }
             None => {
                 let idx = match index {
@@ -536,14 +594,8 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
     };
 
     // Retrieving the beatmap
-    let map = match Context::osu_map().map(map_id, None).await {
-        Ok(mut map) => {
-            if let Some(mode) = mode {
-                map.convert_mut(mode);
-            }
-
-            map
-        }
+    let mut map = match Context::osu_map().map(map_id, None).await {
+        Ok(map) => map,
         Err(MapError::NotFound) => {
             let content = format!(
                 "Could not find beatmap with id `{map_id}`. \
@@ -553,12 +605,24 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
             return orig.error(content).await;
         }
         Err(MapError::Report(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
     };
 
+    let effective_mode = EffectiveMode::infer(map.mode(), mode, config.mode);
+
+    if effective_mode.mode != map.mode() {
+        map.convert_mut(effective_mode.mode);
+    }
+
+    if let Some(vs) = vs {
+        let vs = UserId::Name(vs.as_ref().into());
+
+        return versus(&orig, &map, &user_id, &vs, mods.as_ref(), grade, score_data).await;
+    }
+
     let mode = map.mode();
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
@@ -599,13 +663,13 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
             return orig.error(content).await;
         }
         (Err(err), _) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
         }
         (_, Err(err)) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get scores");
 
             return Err(err);
@@ -620,14 +684,12 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
         .limit(100)
         .exec(user_args);
 
-    let scores_manager_clone = scores_manager.clone();
-
     let global_fut = async {
         if matches!(
             map.status(),
             RankStatus::Ranked | RankStatus::Loved | RankStatus::Approved
         ) {
-            let fut = scores_manager_clone.map_leaderboard(map_id, mode, None, 50, legacy_scores);
+            let fut = Context::redis().map_leaderboard(map_id, mode, None, 50, legacy_scores);
 
             Some(fut.await)
         } else {
@@ -704,7 +766,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
     let entries = match process_fut.await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to process scores"));
         }
@@ -724,7 +786,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
 
     let graph = match entries.first() {
         Some(entry) if matches!(settings.image, SettingsImage::ImageWithStrains) => {
-            prepare_graph(entry).await
+            prepare_graph(entry, theme).await
         }
         Some(_) | None => None,
     };
@@ -737,6 +799,8 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
         .pinned(pinned.into_boxed_slice())
         .pp_idx(pp_idx)
         .score_data(score_data)
+        .timestamp_style(timestamp_style)
+        .mode(effective_mode)
         .msg_owner(owner)
         .build();
 
@@ -753,7 +817,7 @@ async fn process_scores(
     user_id: u32,
     scores: Vec<Score>,
     top100: Option<&[Score]>,
-    globals: Option<&[Score]>,
+    globals: Option<&[MapLeaderboardScore]>,
     sort: ScoreOrder,
     score_data: ScoreData,
     origin: &MessageOrigin,
@@ -791,6 +855,8 @@ async fn process_scores(
             },
         };
 
+        let unranked_pp =
+            is_unranked_pp(Some(map.status()), &score.mods, score.ranked) == Some(true);
         let score = ScoreSlim::new(score, pp);
         let if_fc_pp = IfFc::new(&score, map).await.map(|if_fc| if_fc.pp);
 
@@ -808,6 +874,7 @@ async fn process_scores(
         });
 
         let entry = ScoreEmbedData {
+            user_id,
             score,
             map: map.clone(),
             stars,
@@ -818,6 +885,7 @@ async fn process_scores(
             pb_idx,
             global_idx,
             if_fc_pp,
+            unranked_pp,
             #[cfg(feature = "twitch")]
             twitch: None,
         };
@@ -881,6 +949,8 @@ async fn compare_from_score(
     mode: Option<GameMode>,
     settings: ScoreEmbedSettings,
     score_data: ScoreData,
+    timestamp_style: Option<TimestampStyle>,
+    theme: &Theme,
 ) -> Result<()> {
     let mut score_fut = Context::osu().score(score_id);
 
@@ -905,7 +975,7 @@ async fn compare_from_score(
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get score");
 
             return Err(err);
@@ -939,7 +1009,7 @@ async fn compare_from_score(
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -951,7 +1021,7 @@ async fn compare_from_score(
     let map = match map_res {
         Ok(map) => map,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(Report::new(err));
         }
@@ -966,10 +1036,8 @@ async fn compare_from_score(
         }
     };
 
-    let scores_manager_clone = scores_manager.clone();
-
     let globals = if matches!(map.status(), Ranked | Loved | Approved) {
-        let fut = scores_manager_clone.map_leaderboard(map.map_id(), mode, None, 50, legacy_scores);
+        let fut = Context::redis().map_leaderboard(map.map_id(), mode, None, 50, legacy_scores);
 
         match fut.await {
             Ok(globals) => Some(globals),
@@ -1026,6 +1094,7 @@ async fn compare_from_score(
         },
     };
 
+    let unranked_pp = is_unranked_pp(Some(map.status()), &score.mods, score.ranked) == Some(true);
     let score = ScoreSlim::new(score, pp);
     let if_fc_pp = IfFc::new(&score, &map).await.map(|if_fc| if_fc.pp);
     let origin = MessageOrigin::new(orig.guild_id(), orig.channel_id());
@@ -1044,6 +1113,7 @@ async fn compare_from_score(
     });
 
     let entry = ScoreEmbedData {
+        user_id,
         score,
         map: map.clone(),
         stars,
@@ -1054,12 +1124,13 @@ async fn compare_from_score(
         pb_idx,
         global_idx,
         if_fc_pp,
+        unranked_pp,
         #[cfg(feature = "twitch")]
         twitch: None,
     };
 
     let graph = if matches!(settings.image, SettingsImage::ImageWithStrains) {
-        prepare_graph(&entry).await
+        prepare_graph(&entry, theme).await
     } else {
         None
     };
@@ -1072,6 +1143,8 @@ async fn compare_from_score(
         .pinned(pinned.into_boxed_slice())
         .pp_idx(0)
         .score_data(score_data)
+        .timestamp_style(timestamp_style)
+        .mode(EffectiveMode::score(mode))
         .msg_owner(orig.user_id()?)
         .build();
 
@@ -1082,6 +1155,161 @@ async fn compare_from_score(
         .await
 }
 
+/// Both users' best scores on the map side by side.
+async fn versus(
+    orig: &CommandOrigin<'_>,
+    map: &OsuMap,
+    user_id: &UserId,
+    vs: &UserId,
+    mods: Option<&ModSelection>,
+    grade: Option<Grade>,
+    score_data: ScoreData,
+) -> Result<()> {
+    let map_id = map.map_id();
+    let mode = map.mode();
+    let legacy_scores = score_data.is_legacy();
+
+    let (res1, res2) = tokio::join!(
+        user_and_scores(user_id, map_id, mode, legacy_scores),
+        user_and_scores(vs, map_id, mode, legacy_scores),
+    );
+
+    let Some((user1, mut scores1)) = handle_user_and_scores(orig, user_id, res1).await? else {
+        return Ok(());
+    };
+
+    let Some((user2, mut scores2)) = handle_user_and_scores(orig, vs, res2).await? else {
+        return Ok(());
+    };
+
+    for scores in [&mut scores1, &mut scores2] {
+        if let Some(grade) = grade {
+            scores.retain(|score| score.grade.eq_letter(grade));
+        }
+
+        if let Some(selection) = mods {
+            selection.filter_scores(scores);
+        }
+    }
+
+    let origin = MessageOrigin::new(orig.guild_id(), orig.channel_id());
+    let sort = ScoreOrder::Pp;
+
+    let (entries1, entries2) = tokio::join!(
+        process_scores(
+            map,
+            user1.user_id.to_native(),
+            scores1,
+            None,
+            None,
+            sort,
+            score_data,
+            &origin,
+        ),
+        process_scores(
+            map,
+            user2.user_id.to_native(),
+            scores2,
+            None,
+            None,
+            sort,
+            score_data,
+            &origin,
+        ),
+    );
+
+    let (entries1, entries2) = match (entries1, entries2) {
+        (Ok(entries1), Ok(entries2)) => (entries1, entries2),
+        (Err(err), _) | (_, Err(err)) => {
+            let _ = orig.error_tr(ErrorKey::General).await;
+
+            return Err(err.wrap_err("Failed to process scores"));
+        }
+    };
+
+    let embed = ScoreVersusEmbed::new(
+        map,
+        &user1,
+        entries1.first(),
+        &user2,
+        entries2.first(),
+        mods.is_some() || grade.is_some(),
+    );
+
+    let builder = MessageBuilder::new().embed(embed.build());
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+async fn user_and_scores(
+    user_id: &UserId,
+    map_id: u32,
+    mode: GameMode,
+    legacy_scores: bool,
+) -> (
+    Result<CachedUser, UserArgsError>,
+    Result<Vec<Score>, OsuError>,
+) {
+    match UserArgs::rosu_id(user_id, mode).await {
+        UserArgs::Args(args) => {
+            let user_fut = Context::redis().osu_user_from_args(args);
+            let score_fut = Context::osu_scores()
+                .user_on_map(map_id, legacy_scores)
+                .exec(args);
+
+            tokio::join!(user_fut, score_fut)
+        }
+        UserArgs::User { user, mode } => {
+            let args = UserArgsSlim::user_id(user.user_id.to_native()).mode(mode);
+            let score_res = Context::osu_scores()
+                .user_on_map(map_id, legacy_scores)
+                .exec(args)
+                .await;
+
+            (Ok(user), score_res)
+        }
+        UserArgs::Err(err) => (Err(err), Ok(Vec::new())),
+    }
+}
+
+/// Respond with an error if either request failed.
+async fn handle_user_and_scores(
+    orig: &CommandOrigin<'_>,
+    user_id: &UserId,
+    (user_res, score_res): (
+        Result<CachedUser, UserArgsError>,
+        Result<Vec<Score>, OsuError>,
+    ),
+) -> Result<Option<(CachedUser, Vec<Score>)>> {
+    match (user_res, score_res) {
+        (Ok(user), Ok(scores)) => Ok(Some((user, scores))),
+        (Err(UserArgsError::Osu(OsuError::NotFound)), _) => {
+            let content = match user_id {
+                UserId::Id(user_id) => format!("User with id {user_id} was not found"),
+                UserId::Name(name) => format!("User `{name}` was not found"),
+            };
+
+            orig.error(content).await.map(|_| None)
+        }
+        (_, Err(OsuError::NotFound)) => {
+            let content = "Beatmap was not found. Maybe unranked?";
+
+            orig.error(content).await.map(|_| None)
+        }
+        (Err(err), _) => {
+            let _ = orig.error_tr(ErrorKey::General).await;
+
+            Err(Report::new(err).wrap_err("Failed to get user"))
+        }
+        (_, Err(err)) => {
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
+
+            Err(Report::new(err).wrap_err("Failed to get scores"))
+        }
+    }
+}
+
 async fn handle_autocomplete(
     command: &InteractionCommand,
     difficulty: Option<String>,
@@ -1123,13 +1351,14 @@ async fn handle_autocomplete(
     Ok(())
 }
 
-async fn prepare_graph(entry: &ScoreEmbedData) -> Option<(String, Vec<u8>)> {
+async fn prepare_graph(entry: &ScoreEmbedData, theme: &Theme) -> Option<(String, Vec<u8>)> {
     let fut = map_strains_graph(
         &entry.map.pp_map,
         entry.score.mods.clone(),
         entry.map.cover(),
         SingleScorePagination::IMAGE_W,
         SingleScorePagination::IMAGE_H,
+        theme,
     );
 
     match fut.await {
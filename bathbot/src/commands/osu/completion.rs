@@ -0,0 +1,542 @@
+use std::fmt::Write;
+
+use bathbot_macros::SlashCommand;
+use bathbot_model::MapCompletionScore;
+use bathbot_util::{
+    CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder, constants::OSU_BASE, i18n::ErrorKey,
+    markdown, matcher, numbers::round,
+};
+use eyre::{Report, Result};
+use futures::stream::{self, StreamExt};
+use rosu_v2::{
+    prelude::{BeatmapsetExtended, GameMode, OsuError},
+    request::UserId,
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use super::{require_link, user_not_found};
+use crate::{
+    Context,
+    core::commands::CommandOrigin,
+    manager::redis::osu::{UserArgs, UserArgsError},
+    util::{CachedUserExt, interaction::InteractionCommand, osu::GradeFormatter},
+};
+
+/// Maximum amount of difficulties that are checked.
+const MAX_DIFFS: usize = 50;
+
+/// Amount of score lookups that run at the same time.
+const CONCURRENCY: usize = 8;
+
+/// Above this amount of difficulties, only counts per mapset are shown.
+const COLLAPSE_THRESHOLD: usize = 25;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "completion",
+    desc = "Check which difficulties of a mapper or mapset you have a score on",
+    help = "Check which difficulties of a mapper or mapset you have a score on.\n\
+    For a mapper, only the difficulties of their 50 most recently ranked maps are considered.\n\
+    Converted difficulties are not considered."
+)]
+pub struct Completion {
+    #[command(desc = "Specify a mapper username")]
+    mapper: Option<String>,
+    #[command(desc = "Specify a mapset url or mapset id")]
+    mapset: Option<String>,
+}
+
+async fn slash_completion(mut command: InteractionCommand) -> Result<()> {
+    let args = Completion::from_interaction(command.input_data())?;
+
+    completion((&mut command).into(), args).await
+}
+
+async fn completion(orig: CommandOrigin<'_>, args: Completion) -> Result<()> {
+    let target = match (args.mapper, args.mapset) {
+        (Some(mapper), None) => CompletionTarget::Mapper(mapper),
+        (None, Some(mapset)) => match matcher::get_osu_mapset_id(&mapset) {
+            Some(mapset_id) => CompletionTarget::Mapset(mapset_id),
+            None => {
+                let content = "Failed to parse mapset url or id.\n\
+                    Be sure it's a valid mapset url or id.";
+
+                return orig.error(content).await;
+            }
+        },
+        _ => {
+            let content = "You must specify either a mapper or a mapset";
+
+            return orig.error(content).await;
+        }
+    };
+
+    let owner = orig.user_id()?;
+    let config = Context::user_config().with_osu_id(owner).await?;
+
+    let Some(user_id) = config.osu else {
+        return require_link(&orig).await;
+    };
+
+    let legacy_scores = Context::guild_config()
+        .resolve_score_data(orig.guild_id(), None, config.score_data)
+        .await
+        .is_legacy();
+
+    let user_args = UserArgs::rosu_id(&UserId::Id(user_id), GameMode::Osu).await;
+
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(UserId::Id(user_id)).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error_tr(ErrorKey::General).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let (title, url, mapsets) = match target {
+        CompletionTarget::Mapper(name) => {
+            let mapper_id = UserId::Name(name.as_str().into());
+            let mapper_args = UserArgs::rosu_id(&mapper_id, GameMode::Osu).await;
+
+            let mapper = match Context::redis().osu_user(mapper_args).await {
+                Ok(mapper) => mapper,
+                Err(UserArgsError::Osu(OsuError::NotFound)) => {
+                    let content = user_not_found(mapper_id).await;
+
+                    return orig.error(content).await;
+                }
+                Err(err) => {
+                    let _ = orig.error_tr(ErrorKey::General).await;
+                    let err = Report::new(err).wrap_err("Failed to get mapper");
+
+                    return Err(err);
+                }
+            };
+
+            let mapper_id = mapper.user_id.to_native();
+
+            let mapsets_fut = Context::osu()
+                .user_beatmapsets(mapper_id)
+                .ranked()
+                .limit(MAX_DIFFS);
+
+            let mapsets = match mapsets_fut.await {
+                Ok(mapsets) => mapsets,
+                Err(err) => {
+                    let _ = orig.error_tr(ErrorKey::General).await;
+                    let err = Report::new(err).wrap_err("Failed to get mapper's mapsets");
+
+                    return Err(err);
+                }
+            };
+
+            let title = format!("Completion of {}'s ranked maps", mapper.username.as_str());
+            let url = format!("{OSU_BASE}users/{mapper_id}");
+
+            (title, url, mapsets)
+        }
+        CompletionTarget::Mapset(mapset_id) => {
+            let mapset = match Context::osu().beatmapset(mapset_id).await {
+                Ok(mapset) => mapset,
+                Err(OsuError::NotFound) => {
+                    let content = format!("Could not find mapset with id `{mapset_id}`");
+
+                    return orig.error(content).await;
+                }
+                Err(err) => {
+                    let _ = orig.error_tr(ErrorKey::General).await;
+                    let err = Report::new(err).wrap_err("Failed to get mapset");
+
+                    return Err(err);
+                }
+            };
+
+            let title = format!("Completion of {} - {}", mapset.artist, mapset.title);
+            let url = format!("{OSU_BASE}beatmapsets/{mapset_id}");
+
+            (title, url, vec![mapset])
+        }
+    };
+
+    let mut sets = CompletionSet::from_mapsets(mapsets);
+
+    if sets.is_empty() {
+        let content = "There are no difficulties to check";
+
+        return orig.error(content).await;
+    }
+
+    lookup_scores(&mut sets, user_id, legacy_scores).await;
+
+    let counts = CompletionCounts::new(sets.iter().flat_map(|set| set.diffs.iter()));
+
+    let mut description = format!(
+        "**Completed {passed}/{known} difficulties ({percent}%)**\n\n",
+        passed = counts.passed,
+        known = counts.known(),
+        percent = round(counts.percent()),
+    );
+
+    if collapse(counts.total()) {
+        write_collapsed(&mut description, &sets);
+    } else {
+        write_checklist(&mut description, &sets);
+    }
+
+    let mut footer = String::from("Converted difficulties are not considered");
+
+    if counts.unknown > 0 {
+        let _ = write!(
+            footer,
+            " • {} difficulties could not be checked",
+            counts.unknown
+        );
+    }
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder(false))
+        .title(title)
+        .url(url)
+        .description(markdown::truncate_lines(&description, 4096))
+        .footer(FooterBuilder::new(footer));
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+enum CompletionTarget {
+    Mapper(String),
+    Mapset(u32),
+}
+
+struct CompletionSet {
+    mapset_id: u32,
+    artist: String,
+    title: String,
+    diffs: Vec<CompletionDiff>,
+}
+
+struct CompletionDiff {
+    map_id: u32,
+    mode: GameMode,
+    version: String,
+    stars: f32,
+    state: CompletionState,
+}
+
+#[derive(Copy, Clone)]
+enum CompletionState {
+    Score(MapCompletionScore),
+    Missing,
+    /// The lookup failed
+    Unknown,
+}
+
+impl CompletionSet {
+    /// Collect the difficulties of the mapsets while keeping at most
+    /// [`MAX_DIFFS`] of them.
+    fn from_mapsets(mapsets: Vec<BeatmapsetExtended>) -> Vec<Self> {
+        let mut remaining = MAX_DIFFS;
+        let mut sets = Vec::with_capacity(mapsets.len());
+
+        for mapset in mapsets {
+            if remaining == 0 {
+                break;
+            }
+
+            // Converts are provided separately so these are only the
+            // mapset's own difficulties
+            let mut diffs: Vec<_> = mapset
+                .maps
+                .unwrap_or_default()
+                .into_iter()
+                .map(|map| CompletionDiff {
+                    map_id: map.map_id,
+                    mode: map.mode,
+                    version: map.version,
+                    stars: map.stars,
+                    state: CompletionState::Unknown,
+                })
+                .collect();
+
+            if diffs.is_empty() {
+                continue;
+            }
+
+            diffs.sort_unstable_by(|a, b| {
+                (a.mode as u8)
+                    .cmp(&(b.mode as u8))
+                    .then_with(|| a.stars.total_cmp(&b.stars))
+            });
+            diffs.truncate(remaining);
+            remaining -= diffs.len();
+
+            sets.push(Self {
+                mapset_id: mapset.mapset_id,
+                artist: mapset.artist,
+                title: mapset.title,
+                diffs,
+            });
+        }
+
+        sets
+    }
+}
+
+/// Check which difficulties the user has a score on.
+///
+/// Lookups that fail leave the difficulty as [`CompletionState::Unknown`].
+async fn lookup_scores(sets: &mut [CompletionSet], user_id: u32, legacy_scores: bool) {
+    let diffs: Vec<_> = sets
+        .iter()
+        .flat_map(|set| set.diffs.iter())
+        .map(|diff| (diff.map_id, diff.mode))
+        .collect();
+
+    let states: Vec<_> = stream::iter(diffs)
+        .map(|(map_id, mode)| async move {
+            let score_fut =
+                Context::redis().map_completion_score(user_id, map_id, mode, legacy_scores);
+
+            match score_fut.await {
+                Ok(Some(score)) => CompletionState::Score(score),
+                Ok(None) => CompletionState::Missing,
+                Err(err) => {
+                    warn!(?err, map_id, "Failed to get map completion score");
+
+                    CompletionState::Unknown
+                }
+            }
+        })
+        .buffered(CONCURRENCY)
+        .collect()
+        .await;
+
+    let diffs = sets.iter_mut().flat_map(|set| set.diffs.iter_mut());
+
+    for (diff, state) in diffs.zip(states) {
+        diff.state = state;
+    }
+}
+
+/// Whether the checklist should only show counts per mapset.
+fn collapse(diff_count: usize) -> bool {
+    diff_count > COLLAPSE_THRESHOLD
+}
+
+fn write_checklist(description: &mut String, sets: &[CompletionSet]) {
+    for set in sets {
+        let _ = writeln!(
+            description,
+            "**[{artist} - {title}]({OSU_BASE}beatmapsets/{mapset_id})**",
+            artist = set.artist.cow_escape_markdown(),
+            title = set.title.cow_escape_markdown(),
+            mapset_id = set.mapset_id,
+        );
+
+        for diff in set.diffs.iter() {
+            let _ = write!(
+                description,
+                "{mark} [{version}]({OSU_BASE}b/{map_id}) [{stars}★]",
+                mark = mark(&diff.state),
+                version = diff.version.cow_escape_markdown(),
+                map_id = diff.map_id,
+                stars = round(diff.stars),
+            );
+
+            let _ = match diff.state {
+                CompletionState::Score(score) => writeln!(
+                    description,
+                    " {grade} {acc}%",
+                    grade = GradeFormatter::new(score.grade, None, false),
+                    acc = round(score.accuracy),
+                ),
+                CompletionState::Missing => writeln!(description),
+                CompletionState::Unknown => writeln!(description, " • could not check"),
+            };
+        }
+    }
+}
+
+fn write_collapsed(description: &mut String, sets: &[CompletionSet]) {
+    for set in sets {
+        let counts = CompletionCounts::new(set.diffs.iter());
+
+        let mark = if counts.passed == counts.total() {
+            "✅"
+        } else if counts.unknown > 0 {
+            "❔"
+        } else {
+            "❌"
+        };
+
+        let _ = write!(
+            description,
+            "{mark} [{artist} - {title}]({OSU_BASE}beatmapsets/{mapset_id}): `{passed}/{total}`",
+            artist = set.artist.cow_escape_markdown(),
+            title = set.title.cow_escape_markdown(),
+            mapset_id = set.mapset_id,
+            passed = counts.passed,
+            total = counts.total(),
+        );
+
+        if counts.unknown > 0 {
+            let _ = write!(description, " ({} unknown)", counts.unknown);
+        }
+
+        description.push('\n');
+    }
+}
+
+fn mark(state: &CompletionState) -> &'static str {
+    match state {
+        CompletionState::Score(_) => "✅",
+        CompletionState::Missing => "❌",
+        CompletionState::Unknown => "❔",
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+struct CompletionCounts {
+    passed: usize,
+    missing: usize,
+    unknown: usize,
+}
+
+impl CompletionCounts {
+    fn new<'a>(diffs: impl IntoIterator<Item = &'a CompletionDiff>) -> Self {
+        let mut counts = Self::default();
+
+        for diff in diffs {
+            match diff.state {
+                CompletionState::Score(_) => counts.passed += 1,
+                CompletionState::Missing => counts.missing += 1,
+                CompletionState::Unknown => counts.unknown += 1,
+            }
+        }
+
+        counts
+    }
+
+    fn total(&self) -> usize {
+        self.passed + self.missing + self.unknown
+    }
+
+    /// Amount of difficulties that could be checked.
+    fn known(&self) -> usize {
+        self.passed + self.missing
+    }
+
+    /// Percentage of checked difficulties that have a score.
+    fn percent(&self) -> f32 {
+        if self.known() == 0 {
+            0.0
+        } else {
+            100.0 * self.passed as f32 / self.known() as f32
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::prelude::Grade;
+
+    use super::*;
+
+    fn diff(map_id: u32, state: CompletionState) -> CompletionDiff {
+        CompletionDiff {
+            map_id,
+            mode: GameMode::Osu,
+            version: format!("Diff {map_id}"),
+            stars: 5.0,
+            state,
+        }
+    }
+
+    fn passed() -> CompletionState {
+        CompletionState::Score(MapCompletionScore {
+            grade: Grade::A,
+            accuracy: 95.0,
+            pp: None,
+        })
+    }
+
+    fn set(mapset_id: u32, diffs: Vec<CompletionDiff>) -> CompletionSet {
+        CompletionSet {
+            mapset_id,
+            artist: "Artist".to_owned(),
+            title: format!("Title {mapset_id}"),
+            diffs,
+        }
+    }
+
+    #[test]
+    fn collapse_above_threshold() {
+        assert!(!collapse(1));
+        assert!(!collapse(COLLAPSE_THRESHOLD));
+        assert!(collapse(COLLAPSE_THRESHOLD + 1));
+        assert!(collapse(MAX_DIFFS));
+    }
+
+    #[test]
+    fn count_states() {
+        let diffs = [
+            diff(1, passed()),
+            diff(2, CompletionState::Missing),
+            diff(3, CompletionState::Unknown),
+            diff(4, passed()),
+        ];
+
+        let counts = CompletionCounts::new(diffs.iter());
+
+        let expected = CompletionCounts {
+            passed: 2,
+            missing: 1,
+            unknown: 1,
+        };
+
+        assert_eq!(counts, expected);
+        assert_eq!(counts.total(), 4);
+        assert_eq!(counts.known(), 3);
+        assert!((counts.percent() - 66.666_67).abs() < 0.001);
+    }
+
+    #[test]
+    fn percent_without_known_diffs() {
+        let counts = CompletionCounts::new([diff(1, CompletionState::Unknown)].iter());
+
+        assert_eq!(counts.percent(), 0.0);
+    }
+
+    #[test]
+    fn collapsed_counts_per_mapset() {
+        let sets = [
+            set(1, vec![diff(1, passed()), diff(2, passed())]),
+            set(
+                2,
+                vec![diff(3, passed()), diff(4, CompletionState::Missing)],
+            ),
+            set(3, vec![diff(5, CompletionState::Unknown)]),
+        ];
+
+        let mut description = String::new();
+        write_collapsed(&mut description, &sets);
+
+        let expected = format!(
+            "✅ [Artist - Title 1]({OSU_BASE}beatmapsets/1): `2/2`\n\
+            ❌ [Artist - Title 2]({OSU_BASE}beatmapsets/2): `1/2`\n\
+            ❔ [Artist - Title 3]({OSU_BASE}beatmapsets/3): `0/1` (1 unknown)\n"
+        );
+
+        assert_eq!(description, expected);
+    }
+}
@@ -1,9 +1,7 @@
 use std::{collections::HashMap, fmt::Write};
 
 use bathbot_macros::command;
-use bathbot_util::{
-    AuthorBuilder, FooterBuilder, IntHasher, ModsFormatter, constants::GENERAL_ISSUE,
-};
+use bathbot_util::{AuthorBuilder, FooterBuilder, IntHasher, ModsFormatter, i18n::ErrorKey};
 use eyre::{Report, Result};
 use rkyv::{
     Deserialize,
@@ -51,7 +49,7 @@ pub(super) async fn today(orig: CommandOrigin<'_>) -> Result<()> {
     let today = match DailyChallengeDay::new(osu_id, UtcDateTime::now().date()).await {
         Ok(day) => day,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
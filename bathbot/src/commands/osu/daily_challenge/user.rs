@@ -1,7 +1,5 @@
 use bathbot_macros::command;
-use bathbot_util::{
-    EmbedBuilder, FooterBuilder, MessageBuilder, constants::GENERAL_ISSUE, fields, matcher,
-};
+use bathbot_util::{EmbedBuilder, FooterBuilder, MessageBuilder, fields, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use rkyv::rancor::{Panic, ResultExt};
 use rosu_v2::{error::OsuError, model::GameMode, request::UserId};
@@ -62,7 +60,7 @@ pub(super) async fn user(orig: CommandOrigin<'_>, user: DailyChallengeUser<'_>)
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&orig).await,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -79,7 +77,7 @@ pub(super) async fn user(orig: CommandOrigin<'_>, user: DailyChallengeUser<'_>)
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
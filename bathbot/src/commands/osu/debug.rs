@@ -0,0 +1,96 @@
+use bathbot_macros::SlashCommand;
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_util::{Authored, i18n::ErrorKey};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{GameMode, OsuError};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use super::score::{INVALID_ARG, ScoreArgs, fetch_score, not_found_content};
+use crate::{
+    active::{ActiveMessages, impls::ScoreDebugActive},
+    core::Context,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(name = "debug", desc = "Find out why the bot shows different values")]
+pub enum Debug {
+    #[command(name = "score")]
+    Score(DebugScore),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "score",
+    desc = "Explain why a score's pp differ from the website",
+    help = "Explain why a score's pp differ from the website.\n\
+    The score's pp are recalculated and compared to the pp provided by the osu!api, \
+    alongside the mods and settings used for calculation.\n\
+    Most differences are caused by an outdated map file which is detected by comparing \
+    the stored file's MD5 hash with the map's checksum. If they differ, the map file \
+    can be refreshed to recalculate with the map's current version."
+)]
+pub struct DebugScore {
+    #[command(desc = "Specify a score url or score id")]
+    score: String,
+    #[command(desc = "Specify a gamemode for legacy score ids")]
+    mode: Option<GameModeOption>,
+}
+
+async fn slash_debug(mut command: InteractionCommand) -> Result<()> {
+    match Debug::from_interaction(command.input_data())? {
+        Debug::Score(args) => score(command, args).await,
+    }
+}
+
+async fn score(mut command: InteractionCommand, args: DebugScore) -> Result<()> {
+    let Some(args) = ScoreArgs::parse(&args.score, args.mode.map(GameMode::from)) else {
+        command.error(INVALID_ARG).await?;
+
+        return Ok(());
+    };
+
+    let owner = command.user_id()?;
+
+    let fallback_mode = match args.mode {
+        Some(mode) => mode,
+        None => match Context::user_config().mode(owner).await {
+            Ok(mode) => mode.unwrap_or(GameMode::Osu),
+            Err(err) => {
+                let _ = command.error_tr(ErrorKey::General).await;
+
+                return Err(err);
+            }
+        },
+    };
+
+    let score = match fetch_score(&args, fallback_mode).await {
+        Ok(score) => score,
+        Err(OsuError::NotFound) => {
+            command
+                .error(not_found_content(&args, fallback_mode))
+                .await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::OsuApi).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get score"));
+        }
+    };
+
+    let active_msg = match ScoreDebugActive::new(score, owner).await {
+        Ok(active_msg) => active_msg,
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err.wrap_err("Failed to diagnose score"));
+        }
+    };
+
+    ActiveMessages::builder(active_msg)
+        .start_by_update(true)
+        .begin(&mut command)
+        .await
+}
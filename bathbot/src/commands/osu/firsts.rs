@@ -0,0 +1,158 @@
+use std::fmt::Write;
+
+use bathbot_macros::SlashCommand;
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_util::{
+    EmbedBuilder, FooterBuilder, MessageBuilder, constants::OSU_BASE, i18n::ErrorKey, matcher,
+    numbers::round,
+};
+use eyre::Result;
+use rosu_v2::prelude::GameMode;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    core::Context,
+    manager::MapError,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(name = "firsts", desc = "First place related commands")]
+pub enum Firsts {
+    #[command(name = "history")]
+    History(FirstsHistory),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "history",
+    desc = "Show who held the first place of a map",
+    help = "Show who held the first place of a map.\n\
+    The history only contains first places that the bot noticed while \
+    displaying the map's global leaderboard e.g. through `/leaderboard` or \
+    `/compare score` so it may miss short-lived first places.\n\
+    Only the latest 20 first places of a map are kept."
+)]
+pub struct FirstsHistory {
+    #[command(desc = "Specify a map url or map id")]
+    map: String,
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+}
+
+pub async fn slash_firsts(mut command: InteractionCommand) -> Result<()> {
+    match Firsts::from_interaction(command.input_data())? {
+        Firsts::History(args) => history(&command, args).await,
+    }
+}
+
+async fn history(command: &InteractionCommand, args: FirstsHistory) -> Result<()> {
+    let Some(map_id) = matcher::get_osu_map_id(&args.map) else {
+        let content = "Failed to parse map url or id.\n\
+            Be sure it's a valid map url or id.";
+        command.error(content).await?;
+
+        return Ok(());
+    };
+
+    let map = match Context::osu_map().metadata(map_id).await {
+        Ok(map) => map,
+        Err(MapError::NotFound) => {
+            let content = format!(
+                "Could not find beatmap with id `{map_id}`. \
+                Did you give me a mapset id instead of a map id?",
+            );
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(MapError::Report(err)) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let mode = args
+        .mode
+        .map_or_else(|| (map.gamemode as u8).into(), GameMode::from);
+
+    let first_places = match Context::osu_scores().map_first_places(map_id, mode).await {
+        Ok(first_places) => first_places,
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let title = format!("{} - {} [{}]", map.artist, map.title, map.version);
+
+    let footer = FooterBuilder::new("First places as observed by the bot");
+
+    let mut embed = EmbedBuilder::new()
+        .title(title)
+        .url(format!("{OSU_BASE}b/{map_id}"))
+        .thumbnail(map.thumbnail.as_str())
+        .footer(footer);
+
+    if first_places.is_empty() {
+        let description = "The bot has not observed any first place on this map yet.\n\
+            Check the map's leaderboard to record its current first place.";
+        embed = embed.description(description);
+
+        let builder = MessageBuilder::new().embed(embed);
+        command.update(builder).await?;
+
+        return Ok(());
+    }
+
+    let user_ids: Vec<_> = first_places
+        .iter()
+        .map(|first_place| first_place.user_id as i32)
+        .collect();
+
+    let names = match Context::osu_user().names(&user_ids).await {
+        Ok(names) => names,
+        Err(err) => {
+            warn!(?err, "Failed to get usernames");
+
+            Default::default()
+        }
+    };
+
+    let mut description = String::with_capacity(first_places.len() * 80);
+
+    // Most recent first place at the top
+    for (i, first_place) in first_places.iter().enumerate().rev() {
+        let user_id = first_place.user_id;
+
+        let _ = write!(description, "`{}.` ", i + 1);
+
+        match names.get(&user_id) {
+            Some(name) => {
+                let _ = write!(description, "[{name}]({OSU_BASE}u/{user_id})");
+            }
+            None => {
+                let _ = write!(description, "[<user {user_id}>]({OSU_BASE}u/{user_id})");
+            }
+        }
+
+        if let Some(pp) = first_place.pp {
+            let _ = write!(description, " • {}pp", round(pp));
+        }
+
+        let _ = writeln!(
+            description,
+            " • since <t:{}:d>",
+            first_place.observed_at.unix_timestamp()
+        );
+    }
+
+    embed = embed.description(description);
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
@@ -0,0 +1,415 @@
+use std::borrow::Cow;
+
+use bathbot_macros::HasName;
+use bathbot_model::ScoreSlim;
+use bathbot_psql::model::configs::ScoreData;
+use bathbot_util::{
+    MessageBuilder,
+    i18n::ErrorKey,
+    osu::{ExtractablePp, total_pp_with_replacements},
+};
+use eyre::{Report, Result, WrapErr};
+use futures::stream::{self, StreamExt};
+use rosu_v2::{
+    prelude::{GameMode, OsuError, Score},
+    request::UserId,
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use crate::{
+    Context,
+    active::{ActiveMessages, impls::FixAllPagination},
+    commands::{
+        DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
+        osu::{NochokeGameMode, require_link, user_not_found},
+        utility::{SCORE_DATA_DESC, SCORE_DATA_HELP},
+    },
+    core::commands::CommandOrigin,
+    manager::{
+        OsuMap,
+        redis::osu::{UserArgs, UserArgsError},
+    },
+    util::{InteractionCommandExt, interaction::InteractionCommand, osu::IfFc},
+};
+
+/// Scores with more misses are considered unrealistic to fix.
+const DEFAULT_MISS_LIMIT: u32 = 20;
+
+/// Amount of scores whose pp are calculated concurrently
+const CONCURRENT_CALCS: usize = 8;
+
+/// After how many processed scores the response shows the progress
+const PROGRESS_INTERVAL: usize = 40;
+
+#[derive(CommandModel, CreateCommand, HasName)]
+#[command(
+    name = "all",
+    desc = "List all top scores that would gain pp if they were unchoked",
+    help = "Calculate the if-FC pp of every top200 score that isn't a full combo \
+    and list the ones that would gain pp, sorted by how much total pp the user would gain.\n\
+    Scores with more than 20 misses are skipped unless a different miss limit is specified."
+)]
+pub struct FixAll<'a> {
+    #[command(
+        desc = "Specify a gamemode",
+        help = "Specify a gamemode. \
+        Since combo does not matter in mania, its scores can't be unchoked."
+    )]
+    mode: Option<NochokeGameMode>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(
+        min_value = 0,
+        desc = "Skip scores with more misses than this, defaults to 20"
+    )]
+    miss_limit: Option<u32>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+    #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
+    score_data: Option<ScoreData>,
+}
+
+pub(super) async fn fix_all(mut command: InteractionCommand, args: FixAll<'_>) -> Result<()> {
+    let orig = CommandOrigin::from(&mut command);
+    let owner = orig.user_id()?;
+    let config = Context::user_config().with_osu_id(owner).await?;
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match config.osu {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
+    let mode = match args.mode.map(GameMode::from).or(config.mode) {
+        None | Some(GameMode::Mania) => GameMode::Osu,
+        Some(mode) => mode,
+    };
+
+    let legacy_scores = Context::guild_config()
+        .resolve_score_data(orig.guild_id(), args.score_data, config.score_data)
+        .await
+        .is_legacy();
+
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+    let scores_fut = Context::osu_scores()
+        .top(200, legacy_scores)
+        .exec_with_user(user_args);
+
+    let (user, scores) = match scores_fut.await {
+        Ok((user, scores)) => (user, scores),
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error_tr(ErrorKey::General).await;
+            let err = Report::new(err).wrap_err("Failed to get user or scores");
+
+            return Err(err);
+        }
+    };
+
+    let miss_limit = args.miss_limit.unwrap_or(DEFAULT_MISS_LIMIT);
+    let pps = scores.extract_pp();
+
+    let processed = match process_scores(&command, scores, miss_limit).await {
+        Ok(processed) => processed,
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err.wrap_err("Failed to process scores"));
+        }
+    };
+
+    let total_pp = user
+        .statistics
+        .as_ref()
+        .expect("missing stats")
+        .pp
+        .to_native();
+
+    let mut if_fc_pps = vec![None; pps.len()];
+    let mut processed_by_idx: Vec<_> = pps.iter().map(|_| None).collect();
+
+    for score in processed {
+        let idx = score.idx;
+        if_fc_pps[idx] = Some(score.if_fc_pp);
+        processed_by_idx[idx] = Some(score);
+    }
+
+    let candidates = select_candidates(total_pp, &pps, &if_fc_pps);
+
+    let replacements: Vec<_> = candidates
+        .iter()
+        .map(|candidate| (candidate.idx, candidate.if_fc_pp))
+        .collect();
+
+    let fixed_pp = total_pp_with_replacements(total_pp, &pps, &replacements);
+
+    let entries: Box<[FixAllEntry]> = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let ProcessedScore { score, map, .. } = processed_by_idx[candidate.idx].take()?;
+
+            Some(FixAllEntry {
+                original_idx: candidate.idx,
+                new_idx: candidate.new_idx,
+                score,
+                map,
+                if_fc_pp: candidate.if_fc_pp,
+                gain: candidate.gain,
+            })
+        })
+        .collect();
+
+    let orig = CommandOrigin::from(&mut command);
+
+    if entries.is_empty() {
+        let content = format!(
+            "None of `{name}`'s top scores with at most {miss_limit} miss{plural} \
+            would gain pp from being unchoked",
+            name = user.username.as_str(),
+            plural = if miss_limit == 1 { "" } else { "es" },
+        );
+
+        return orig.error(content).await;
+    }
+
+    let content = format!(
+        "Top {mode}scores of `{name}` that would gain pp from being unchoked:",
+        mode = match mode {
+            GameMode::Osu => "",
+            GameMode::Taiko => "taiko ",
+            GameMode::Catch => "ctb ",
+            GameMode::Mania => "mania ",
+        },
+        name = user.username.as_str(),
+    );
+
+    let pagination = FixAllPagination::builder()
+        .user(user)
+        .entries(entries)
+        .fixed_pp(fixed_pp)
+        .miss_limit(miss_limit)
+        .content(content.into_boxed_str())
+        .msg_owner(owner)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+}
+
+pub struct FixAllEntry {
+    pub original_idx: usize,
+    /// Position within the top scores if only this score was unchoked
+    pub new_idx: usize,
+    pub score: ScoreSlim,
+    pub map: OsuMap,
+    pub if_fc_pp: f32,
+    /// Total pp the user would gain if only this score was unchoked
+    pub gain: f32,
+}
+
+struct ProcessedScore {
+    idx: usize,
+    score: ScoreSlim,
+    map: OsuMap,
+    if_fc_pp: f32,
+}
+
+async fn process_scores(
+    command: &InteractionCommand,
+    scores: Vec<Score>,
+    miss_limit: u32,
+) -> Result<Vec<ProcessedScore>> {
+    let scores: Vec<_> = scores
+        .into_iter()
+        .enumerate()
+        .filter(|(_, score)| score.statistics.miss <= miss_limit)
+        .collect();
+
+    let maps_id_checksum = scores
+        .iter()
+        .filter_map(|(_, score)| score.map.as_ref())
+        .map(|map| (map.map_id as i32, map.checksum.as_deref()))
+        .collect();
+
+    let mut maps = Context::osu_map().maps(&maps_id_checksum).await?;
+
+    let scores_with_maps: Vec<_> = scores
+        .into_iter()
+        .filter_map(|(i, score)| {
+            let map = maps.remove(&score.map_id)?;
+
+            Some((i, score, map))
+        })
+        .collect();
+
+    let total = scores_with_maps.len();
+    let mut processed = Vec::new();
+
+    let mut stream = stream::iter(scores_with_maps)
+        .map(|(i, score, map)| tokio::spawn(process_score(i, score, map)))
+        .buffered(CONCURRENT_CALCS)
+        .enumerate();
+
+    while let Some((i, res)) = stream.next().await {
+        if let Some(score) = res.wrap_err("Failed to join score processing task")? {
+            processed.push(score);
+        }
+
+        let done = i + 1;
+
+        if done % PROGRESS_INTERVAL == 0 && done < total {
+            let content = format!("Calculating if-FC pp... ({done}/{total} scores)");
+            let builder = MessageBuilder::new().content(content);
+
+            if let Err(err) = command.update(builder).await {
+                warn!(?err, "Failed to update progress");
+            }
+        }
+    }
+
+    Ok(processed)
+}
+
+async fn process_score(idx: usize, score: Score, map: OsuMap) -> Option<ProcessedScore> {
+    let map = map.convert(score.mode);
+
+    let mut calc = Context::pp(&map)
+        .lazer(score.set_on_lazer)
+        .mode(score.mode)
+        .mods(score.mods.clone());
+
+    let pp = score.pp.unwrap_or(0.0);
+    let score = ScoreSlim::new(score, pp);
+
+    // Re-use the difficulty attributes for the if-FC calculation
+    let attrs = calc.difficulty().await?;
+    let if_fc = IfFc::with_difficulty(&score, &map, attrs)?;
+
+    Some(ProcessedScore {
+        idx,
+        score,
+        map,
+        if_fc_pp: if_fc.pp,
+    })
+}
+
+#[derive(Debug, PartialEq)]
+struct Candidate {
+    idx: usize,
+    new_idx: usize,
+    if_fc_pp: f32,
+    gain: f32,
+}
+
+/// Select the scores whose if-FC pp exceed their current pp, sorted by the
+/// total pp that unchoking each of them would gain.
+///
+/// `pps` is expected to be sorted in descending order and `if_fc_pps` must
+/// contain the if-FC pp for each of those scores, if any.
+fn select_candidates(total_pp: f32, pps: &[f32], if_fc_pps: &[Option<f32>]) -> Vec<Candidate> {
+    let mut candidates: Vec<_> = if_fc_pps
+        .iter()
+        .zip(pps)
+        .enumerate()
+        .filter_map(|(idx, (if_fc_pp, &pp))| {
+            let if_fc_pp = if_fc_pp.filter(|&if_fc_pp| if_fc_pp > pp)?;
+
+            // Amount of other scores that are still worth more
+            let new_idx = pps
+                .iter()
+                .enumerate()
+                .filter(|&(i, &other)| i != idx && other > if_fc_pp)
+                .count();
+
+            let gain = total_pp_with_replacements(total_pp, pps, &[(idx, if_fc_pp)]) - total_pp;
+
+            Some(Candidate {
+                idx,
+                new_idx,
+                if_fc_pp,
+                gain,
+            })
+        })
+        .collect();
+
+    candidates.sort_unstable_by(|a, b| b.gain.total_cmp(&a.gain).then(a.idx.cmp(&b.idx)));
+
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use bathbot_util::osu::PpListUtil;
+
+    use super::*;
+
+    const PPS: [f32; 5] = [500.0, 450.0, 400.0, 300.0, 200.0];
+    const BONUS: f32 = 400.0;
+
+    fn total_pp() -> f32 {
+        PPS.accum_weighted() as f32 + BONUS
+    }
+
+    #[test]
+    fn skip_scores_without_gain() {
+        let if_fc_pps = [None, Some(440.0), Some(400.0), None, None];
+        let candidates = select_candidates(total_pp(), &PPS, &if_fc_pps);
+
+        assert!(candidates.is_empty());
+    }
+
+    #[test]
+    fn sort_by_gain() {
+        let if_fc_pps = [Some(510.0), None, Some(420.0), None, Some(480.0)];
+        let candidates = select_candidates(total_pp(), &PPS, &if_fc_pps);
+
+        let indices: Vec<_> = candidates.iter().map(|c| c.idx).collect();
+        assert_eq!(indices, [4, 2, 0]);
+
+        let new_indices: Vec<_> = candidates.iter().map(|c| c.new_idx).collect();
+        assert_eq!(new_indices, [1, 2, 0]);
+
+        assert!(candidates.iter().all(|c| c.gain > 0.0));
+    }
+
+    #[test]
+    fn gain_reweights_scores() {
+        let total = total_pp();
+        let if_fc_pps = [None, None, None, None, Some(480.0)];
+        let candidates = select_candidates(total, &PPS, &if_fc_pps);
+
+        let expected = [500.0, 480.0, 450.0, 400.0, 300.0].accum_weighted() as f32 + BONUS;
+        let gain = candidates[0].gain;
+
+        assert!(
+            (total + gain - expected).abs() < 0.01,
+            "{} vs {expected}",
+            total + gain
+        );
+    }
+
+    #[test]
+    fn combined_gain_exceeds_single_gains() {
+        let total = total_pp();
+        let if_fc_pps = [None, Some(470.0), None, Some(350.0), Some(250.0)];
+        let candidates = select_candidates(total, &PPS, &if_fc_pps);
+
+        let replacements: Vec<_> = candidates.iter().map(|c| (c.idx, c.if_fc_pp)).collect();
+        let fixed = total_pp_with_replacements(total, &PPS, &replacements);
+
+        let max_single = candidates.iter().map(|c| c.gain).fold(0.0, f32::max);
+        assert!(fixed - total > max_single);
+
+        let expected = [500.0, 470.0, 400.0, 350.0, 250.0].accum_weighted() as f32 + BONUS;
+        assert!((fixed - expected).abs() < 0.01, "{fixed} vs {expected}");
+    }
+}
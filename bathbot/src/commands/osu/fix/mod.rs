@@ -2,9 +2,8 @@ use std::borrow::Cow;
 
 use bathbot_macros::{HasMods, HasName, SlashCommand, command};
 use bathbot_model::ScoreSlim;
-use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    i18n::ErrorKey,
     matcher,
     osu::{MapIdType, ModSelection},
 };
@@ -20,6 +19,8 @@ use twilight_model::{
     id::{Id, marker::UserMarker},
 };
 
+use self::all::fix_all;
+pub use self::all::{FixAll, FixAllEntry};
 use super::{HasMods, ModsResult, require_link, user_not_found};
 use crate::{
     Context,
@@ -37,12 +38,26 @@ use crate::{
     },
 };
 
+mod all;
+
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(
     name = "fix",
+    desc = "Display a user's pp after unchoking their scores"
+)]
+pub enum Fix<'a> {
+    #[command(name = "score")]
+    Score(FixSingle<'a>),
+    #[command(name = "all")]
+    All(FixAll<'a>),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "score",
     desc = "Display a user's pp after unchoking their score on a map"
 )]
-pub struct Fix<'a> {
+pub struct FixSingle<'a> {
     #[command(desc = "Specify a username")]
     name: Option<Cow<'a, str>>,
     #[command(
@@ -104,10 +119,10 @@ impl<'m> FixArgs<'m> {
     }
 }
 
-impl<'a> TryFrom<Fix<'a>> for FixArgs<'a> {
+impl<'a> TryFrom<FixSingle<'a>> for FixArgs<'a> {
     type Error = &'static str;
 
-    fn try_from(args: Fix<'a>) -> Result<Self, Self::Error> {
+    fn try_from(args: FixSingle<'a>) -> Result<Self, Self::Error> {
         let id = match args.map {
             Some(map) => {
                 if let Some(id) = matcher::get_osu_map_id(&map)
@@ -136,7 +151,10 @@ impl<'a> TryFrom<Fix<'a>> for FixArgs<'a> {
 }
 
 async fn slash_fix(mut command: InteractionCommand) -> Result<()> {
-    let args = Fix::from_interaction(command.input_data())?;
+    let args = match Fix::from_interaction(command.input_data())? {
+        Fix::Score(args) => args,
+        Fix::All(args) => return fix_all(command, args).await,
+    };
 
     match FixArgs::try_from(args) {
         Ok(args) => fix((&mut command).into(), args).await,
@@ -194,16 +212,10 @@ async fn fix(orig: CommandOrigin<'_>, args: FixArgs<'_>) -> Result<()> {
         }
     };
 
-    let legacy_scores = match config.score_data {
-        Some(score_data) => score_data.is_legacy(),
-        None => match orig.guild_id() {
-            Some(guild_id) => Context::guild_config()
-                .peek(guild_id, |config| config.score_data)
-                .await
-                .is_some_and(ScoreData::is_legacy),
-            None => false,
-        },
-    };
+    let legacy_scores = Context::guild_config()
+        .resolve_score_data(orig.guild_id(), None, config.score_data)
+        .await
+        .is_legacy();
 
     let mods = match mods {
         None | Some(ModSelection::Exclude { .. }) => None,
@@ -304,7 +316,7 @@ async fn request_by_map(
             };
         }
         Err(MapError::Report(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return ScoreResult::Error(err);
         }
@@ -342,7 +354,7 @@ async fn request_by_map(
             };
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let wrap = "Failed to get user";
 
             return ScoreResult::Error(Report::new(err).wrap_err(wrap));
@@ -363,7 +375,7 @@ async fn request_by_map(
             }),
         },
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
             let wrap = "Failed to get scores";
 
             return ScoreResult::Error(Report::new(err).wrap_err(wrap));
@@ -393,7 +405,7 @@ async fn request_by_map(
             let top = match top_res {
                 Ok(scores) => scores,
                 Err(err) => {
-                    let _ = orig.error(OSU_API_ISSUE).await;
+                    let _ = orig.error_tr(ErrorKey::OsuApi).await;
                     let wrap = "failed to get top scores";
 
                     return ScoreResult::Error(Report::new(err).wrap_err(wrap));
@@ -447,7 +459,7 @@ async fn request_by_score(
             };
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get score");
 
             return ScoreResult::Error(err);
@@ -485,18 +497,18 @@ async fn request_by_score(
             };
         }
         (_, Err(MapError::Report(err)), _) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return ScoreResult::Error(err);
         }
         (Err(err), ..) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return ScoreResult::Error(err);
         }
         (.., Err(err)) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get top scores");
 
             return ScoreResult::Error(err);
@@ -7,7 +7,7 @@ use plotters::{
     chart::ChartBuilder,
     prelude::{DrawingArea, Rectangle},
     series::LineSeries,
-    style::{BLACK, Color, FontDesc, RGBColor, WHITE},
+    style::{Color, FontDesc},
 };
 use plotters_backend::{FontFamily, FontStyle};
 use plotters_skia::SkiaBackend;
@@ -22,7 +22,7 @@ use rosu_v2::prelude::GameMods;
 use skia_safe::{EncodedImageFormat, surfaces};
 use twilight_model::{channel::Message, guild::Permissions};
 
-use super::{BitMapElement, Graph, H, W, get_map_cover};
+use super::{BitMapElement, Graph, H, Theme, W, get_map_cover};
 use crate::{
     commands::osu::{GraphMapBpm, graphs::GRAPH_BPM_DESC},
     core::commands::{CommandOrigin, prefix::Args},
@@ -88,7 +88,12 @@ async fn prefix_graphbpm(msg: &Message, args: Args<'_>, perms: Option<Permission
     super::graph(orig, Graph::MapBpm(args)).await
 }
 
-pub async fn map_bpm_graph(map: &Beatmap, mods: GameMods, cover_url: &str) -> Result<Vec<u8>> {
+pub async fn map_bpm_graph(
+    map: &Beatmap,
+    mods: GameMods,
+    cover_url: &str,
+    theme: &Theme,
+) -> Result<Vec<u8>> {
     let mut start_timestamp = map
         .hit_objects
         .first()
@@ -184,14 +189,17 @@ pub async fn map_bpm_graph(map: &Beatmap, mods: GameMods, cover_url: &str) -> Re
                 let elem = BitMapElement::new(background, (0, 0));
                 root.draw(&elem).wrap_err("Failed to draw background")?;
 
-                let rect = Rectangle::new([(0, 0), (W as i32, H as i32)], BLACK.mix(0.75).filled());
+                let rect = Rectangle::new(
+                    [(0, 0), (W as i32, H as i32)],
+                    theme.overlay.mix(0.75).filled(),
+                );
                 root.draw(&rect)
                     .wrap_err("Failed to draw darkening rectangle")?;
             }
             Err(err) => {
                 warn!(?err, "Failed to get mapset cover");
 
-                root.fill(&RGBColor(19, 43, 33))
+                root.fill(&theme.background)
                     .wrap_err("Failed to fill background")?;
             }
         }
@@ -203,18 +211,19 @@ pub async fn map_bpm_graph(map: &Beatmap, mods: GameMods, cover_url: &str) -> Re
             .build_cartesian_2d(start_timestamp..last_timestamp, lower_limit..upper_limit)
             .wrap_err("Failed to build chart")?;
 
-        let text_style = FontDesc::new(FontFamily::SansSerif, 18.0, FontStyle::Bold).color(&WHITE);
+        let text_style =
+            FontDesc::new(FontFamily::SansSerif, 18.0, FontStyle::Bold).color(&theme.text);
 
         chart
             .configure_mesh()
             .disable_x_mesh()
             .set_all_tick_mark_size(3_i32)
-            .light_line_style(WHITE.mix(0.0)) // hide
-            .bold_line_style(WHITE.mix(0.75))
+            .light_line_style(theme.grid.mix(0.0)) // hide
+            .bold_line_style(theme.grid.mix(0.75))
             .x_labels(10)
             .x_label_style(text_style.clone())
             .y_label_style(text_style.clone())
-            .axis_style(WHITE)
+            .axis_style(theme.text)
             .x_label_formatter(&|timestamp| {
                 if timestamp.abs() < 0.0 {
                     return String::new();
@@ -231,15 +240,12 @@ pub async fn map_bpm_graph(map: &Beatmap, mods: GameMods, cover_url: &str) -> Re
             .draw()
             .wrap_err("Failed to draw mesh")?;
 
-        let series = LineSeries::new(points.iter().copied(), WHITE.mix(0.3).stroke_width(6));
+        let series = LineSeries::new(points.iter().copied(), theme.text.mix(0.3).stroke_width(6));
         chart
             .draw_series(series)
             .wrap_err("Failed to draw white series")?;
 
-        let series = LineSeries::new(
-            points.iter().copied(),
-            RGBColor(0, 208, 138).stroke_width(2),
-        );
+        let series = LineSeries::new(points.iter().copied(), theme.border.stroke_width(2));
         chart
             .draw_series(series)
             .wrap_err("Failed to draw green series")?;
@@ -18,7 +18,7 @@ use rosu_v2::prelude::GameMods;
 use skia_safe::{BlendMode, EncodedImageFormat, surfaces};
 use twilight_model::{channel::Message, guild::Permissions};
 
-use super::{BitMapElement, Graph, GraphMapStrains, get_map_cover};
+use super::{BitMapElement, Graph, GraphMapStrains, Theme, get_map_cover};
 use crate::{
     core::commands::{CommandOrigin, prefix::Args},
     util::{ChannelExt, osu::MapOrScore},
@@ -166,6 +166,7 @@ pub async fn map_strains_graph(
     cover_url: &str,
     w: u32,
     h: u32,
+    theme: &Theme,
 ) -> Result<Vec<u8>> {
     let strains = GraphStrains::new(map, mods)?;
     let cover_res = get_map_cover(cover_url, w, h).await;
@@ -230,14 +231,17 @@ pub async fn map_strains_graph(
                 let elem = BitMapElement::new(background, (0, 0));
                 root.draw(&elem).wrap_err("Failed to draw background")?;
 
-                let rect = Rectangle::new([(0, 0), (w as i32, h as i32)], BLACK.mix(0.75).filled());
+                let rect = Rectangle::new(
+                    [(0, 0), (w as i32, h as i32)],
+                    theme.overlay.mix(0.75).filled(),
+                );
                 root.draw(&rect)
                     .wrap_err("Failed to draw darkening rectangle")?;
             }
             Err(err) => {
                 warn!(?err, "Failed to get mapset cover");
 
-                root.fill(&RGBColor(19, 43, 33))
+                root.fill(&theme.background)
                     .wrap_err("Failed to fill background")?;
             }
         }
@@ -250,18 +254,19 @@ pub async fn map_strains_graph(
             .wrap_err("Failed to build chart")?;
 
         // Mesh and labels
-        let text_style = FontDesc::new(FontFamily::SansSerif, 14.0, FontStyle::Bold).color(&WHITE);
+        let text_style =
+            FontDesc::new(FontFamily::SansSerif, 14.0, FontStyle::Bold).color(&theme.text);
 
         chart
             .configure_mesh()
             .disable_y_mesh()
             .disable_y_axis()
             .set_all_tick_mark_size(3_i32)
-            .light_line_style(WHITE.mix(0.0)) // hide
-            .bold_line_style(WHITE.mix(0.75))
+            .light_line_style(theme.grid.mix(0.0)) // hide
+            .bold_line_style(theme.grid.mix(0.75))
             .x_labels(10)
             .x_label_style(text_style.clone())
-            .axis_style(WHITE)
+            .axis_style(theme.text)
             .x_label_formatter(&|timestamp| {
                 if timestamp.abs() < f64::EPSILON {
                     return String::new();
@@ -276,7 +281,14 @@ pub async fn map_strains_graph(
             .draw()
             .wrap_err("Failed to draw mesh")?;
 
-        draw_mode_strains(&backend, &mut chart, strains, &legend_area, &text_style)?;
+        draw_mode_strains(
+            &backend,
+            &mut chart,
+            strains,
+            &legend_area,
+            &text_style,
+            theme,
+        )?;
     }
 
     let png_bytes = surface
@@ -294,6 +306,7 @@ fn draw_mode_strains(
     strains: GraphStrains,
     legend_area: &DrawingArea<SkiaBackend<'_>, Shift>,
     text_style: &TextStyle<'_>,
+    theme: &Theme,
 ) -> Result<()> {
     let GraphStrains {
         strains,
@@ -315,9 +328,20 @@ fn draw_mode_strains(
 
     let factor = section_len * orig_count / new_count;
 
+    // Overlapping strains should stand out from the background
+    let blend_mode = if theme.is_light() {
+        BlendMode::Darken
+    } else {
+        BlendMode::Lighten
+    };
+
+    let [c0, c1, c2, c3, c4, c5] = theme.strains;
+
     macro_rules! draw_line {
         ( $label:literal, $strains:expr, $color:ident ) => {{
-            draw_series(backend, chart, &$strains, $label, factor, $color)?;
+            draw_series(
+                backend, chart, &$strains, $label, factor, $color, blend_mode,
+            )?;
             draw_line(legend_area, $label, $color, text_style, &mut legend_x)?;
         }};
     }
@@ -329,10 +353,9 @@ fn draw_mode_strains(
         label: &str,
         factor: f64,
         color: RGBColor,
+        blend_mode: BlendMode,
     ) -> Result<()> {
-        backend
-            .borrow_mut()
-            .set_blend_mode(Some(BlendMode::Lighten));
+        backend.borrow_mut().set_blend_mode(Some(blend_mode));
 
         let timestamp_iter = strains
             .iter()
@@ -393,20 +416,20 @@ fn draw_mode_strains(
 
     match strains {
         Strains::Osu(strains) => {
-            draw_line!("Aim", strains.aim, CYAN);
-            draw_line!("Aim (Sliders)", strains.aim_no_sliders, GREEN);
-            draw_line!("Speed", strains.speed, RED);
-            draw_line!("Flashlight", strains.flashlight, MAGENTA);
+            draw_line!("Aim", strains.aim, c0);
+            draw_line!("Aim (Sliders)", strains.aim_no_sliders, c1);
+            draw_line!("Speed", strains.speed, c2);
+            draw_line!("Flashlight", strains.flashlight, c3);
         }
         Strains::Taiko(strains) => {
-            draw_line!("Stamina", strains.stamina, RED);
-            draw_line!("Stamina (Single color)", strains.single_color_stamina, BLUE);
-            draw_line!("Color", strains.color, YELLOW);
-            draw_line!("Rhythm", strains.rhythm, CYAN);
-            draw_line!("Reading", strains.reading, GREEN);
+            draw_line!("Stamina", strains.stamina, c2);
+            draw_line!("Stamina (Single color)", strains.single_color_stamina, c4);
+            draw_line!("Color", strains.color, c5);
+            draw_line!("Rhythm", strains.rhythm, c0);
+            draw_line!("Reading", strains.reading, c1);
         }
-        Strains::Catch(strains) => draw_line!("Movement", strains.movement, CYAN),
-        Strains::Mania(strains) => draw_line!("Strain", strains.strains, MAGENTA),
+        Strains::Catch(strains) => draw_line!("Movement", strains.movement, c0),
+        Strains::Mania(strains) => draw_line!("Strain", strains.strains, c3),
     }
 
     Ok(())
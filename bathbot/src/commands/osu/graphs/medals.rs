@@ -1,6 +1,6 @@
 use bathbot_macros::command;
 use bathbot_model::rosu_v2::user::MedalCompactRkyv;
-use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{MessageBuilder, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use rkyv::{
     rancor::{Panic, ResultExt},
@@ -9,7 +9,7 @@ use rkyv::{
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 use twilight_model::guild::Permissions;
 
-use super::{Graph, GraphMedals, H, W};
+use super::{Graph, GraphMedals, H, Theme, W};
 use crate::{
     commands::osu::{graphs::GRAPH_MEDALS_DESC, medals::stats as medals_stats, user_not_found},
     core::{
@@ -55,6 +55,7 @@ async fn prefix_graphmedals(
 pub async fn medals_graph(
     orig: &CommandOrigin<'_>,
     user_id: UserId,
+    theme: &Theme,
 ) -> Result<Option<(CachedUser, Vec<u8>)>> {
     let user_args = UserArgs::rosu_id(&user_id, GameMode::Osu).await;
 
@@ -67,7 +68,7 @@ pub async fn medals_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let report = Report::new(err).wrap_err("Failed to get user");
 
             return Err(report);
@@ -82,7 +83,7 @@ pub async fn medals_graph(
 
     medals.sort_unstable_by_key(|medal| medal.achieved_at);
 
-    let bytes = match medals_stats::graph(&medals, W, H) {
+    let bytes = match medals_stats::graph(&medals, W, H, theme) {
         Ok(Some(graph)) => graph,
         Ok(None) => {
             let content = format!("`{}` does not have any medals", user.username.as_str());
@@ -92,7 +93,7 @@ pub async fn medals_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             warn!(?err, "Failed to create medals graph");
 
             return Ok(None);
@@ -5,10 +5,10 @@ use bathbot_model::{
     Countries,
     command_fields::{GameModeOption, ShowHideOption, TimezoneOption},
 };
-use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
     AuthorBuilder, CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder, attachment,
-    constants::{GENERAL_ISSUE, OSU_BASE},
+    constants::OSU_BASE,
+    i18n::ErrorKey,
     matcher,
     osu::{MapIdType, ModSelection, ModsResult},
 };
@@ -28,7 +28,6 @@ use twilight_model::id::{
     marker::{ChannelMarker, UserMarker},
 };
 
-pub use self::map_strains::map_strains_graph;
 use self::{
     bpm::map_bpm_graph,
     medals::medals_graph,
@@ -42,6 +41,7 @@ use self::{
     top_index::top_graph_index,
     top_time::{top_graph_time_day, top_graph_time_hour},
 };
+pub use self::{map_strains::map_strains_graph, skill_radar::skill_radar_graph, theme::Theme};
 use super::{SnipeGameMode, UserIdResult, require_link, user_not_found};
 use crate::{
     commands::{
@@ -63,8 +63,10 @@ mod osutrack;
 mod playcount_replays;
 mod rank;
 mod score_rank;
+mod skill_radar;
 mod snipe_count;
 mod sniped;
+mod theme;
 mod top_date;
 mod top_index;
 mod top_time;
@@ -354,13 +356,15 @@ async fn slash_graph(mut command: InteractionCommand) -> Result<()> {
 }
 
 async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
+    let theme = Theme::of_user(orig.user_id()?).await;
+
     let mut author_fn: fn(CachedUser) -> AuthorBuilder =
         |user: CachedUser| user.author_builder(false);
     let mut footer = None;
 
     let tuple_option = match args {
         Graph::MapBpm(args) => {
-            return match map_bpm(&orig, args).await {
+            return match map_bpm(&orig, args, theme).await {
                 Ok(ControlFlow::Continue(map)) => {
                     orig.create_message(map.into()).await?;
 
@@ -371,7 +375,7 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
             };
         }
         Graph::MapStrains(args) => {
-            return match map_strains(&orig, args).await {
+            return match map_strains(&orig, args, theme).await {
                 Ok(ControlFlow::Continue(map)) => {
                     orig.create_message(map.into()).await?;
 
@@ -388,14 +392,14 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
                     Ok(Some(user_id)) => UserId::Id(user_id),
                     Ok(None) => return require_link(&orig).await,
                     Err(err) => {
-                        let _ = orig.error(GENERAL_ISSUE).await;
+                        let _ = orig.error_tr(ErrorKey::General).await;
 
                         return Err(err);
                     }
                 },
             };
 
-            medals_graph(&orig, user_id)
+            medals_graph(&orig, user_id, theme)
                 .await
                 .wrap_err("failed to create medals graph")?
         }
@@ -453,7 +457,7 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
 
             footer = Some(FooterBuilder::new("Data provided by ameobea.me/osutrack"));
 
-            osutrack_graph(&orig, user_id, mode, args)
+            osutrack_graph(&orig, user_id, mode, args, theme)
                 .await
                 .wrap_err("Failed to create osutrack graph")?
         }
@@ -464,7 +468,7 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
                     Ok(Some(user_id)) => UserId::Id(user_id),
                     Ok(None) => return require_link(&orig).await,
                     Err(err) => {
-                        let _ = orig.error(GENERAL_ISSUE).await;
+                        let _ = orig.error_tr(ErrorKey::General).await;
 
                         return Err(err);
                     }
@@ -489,7 +493,7 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
                 return orig.error(":clown:").await;
             }
 
-            playcount_replays_graph(&orig, user_id, flags)
+            playcount_replays_graph(&orig, user_id, flags, theme)
                 .await
                 .wrap_err("failed to create profile graph")?
         }
@@ -497,14 +501,14 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
             let (user_id, mode) = user_id_mode!(orig, args);
             let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
-            rank_graph(&orig, user_id, user_args, args.from, args.until)
+            rank_graph(&orig, user_id, user_args, args.from, args.until, theme)
                 .await
                 .wrap_err("Failed to create rank graph")?
         }
         Graph::ScoreRank(args) => {
             let (user_id, mode) = user_id_mode!(orig, args);
 
-            let tuple_option = score_rank_graph(&orig, user_id, mode, args.from, args.until)
+            let tuple_option = score_rank_graph(&orig, user_id, mode, args.from, args.until, theme)
                 .await
                 .wrap_err("Failed to create score rank graph")?;
 
@@ -528,7 +532,7 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
             let (user_id, mode) = user_id_mode!(orig, args);
             footer = Some(FooterBuilder::new("Data provided by snipe.huismetbenen.nl"));
 
-            sniped_graph(&orig, user_id, mode)
+            sniped_graph(&orig, user_id, mode, theme)
                 .await
                 .wrap_err("failed to create snipe graph")?
         }
@@ -536,7 +540,7 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
             let (user_id, mode) = user_id_mode!(orig, args);
             footer = Some(FooterBuilder::new("Data provided by snipe.huismetbenen.nl"));
 
-            snipe_count_graph(&orig, user_id, mode)
+            snipe_count_graph(&orig, user_id, mode, theme)
                 .await
                 .wrap_err("failed to create snipe count graph")?
         }
@@ -546,7 +550,7 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
             let config = match Context::user_config().with_osu_id(owner).await {
                 Ok(config) => config,
                 Err(err) => {
-                    let _ = orig.error(GENERAL_ISSUE).await;
+                    let _ = orig.error_tr(ErrorKey::General).await;
 
                     return Err(err.wrap_err("failed to get user config"));
                 }
@@ -573,20 +577,22 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
                 .map(UtcOffset::from)
                 .or_else(|| no_user_specified.then_some(config.timezone).flatten());
 
-            let legacy_scores = match config.score_data {
-                Some(score_data) => score_data.is_legacy(),
-                None => match orig.guild_id() {
-                    Some(guild_id) => Context::guild_config()
-                        .peek(guild_id, |config| config.score_data)
-                        .await
-                        .is_some_and(ScoreData::is_legacy),
-                    None => false,
-                },
-            };
-
-            top_graph(&orig, user_id, user_args, args.order, tz, legacy_scores)
+            let legacy_scores = Context::guild_config()
+                .resolve_score_data(orig.guild_id(), None, config.score_data)
                 .await
-                .wrap_err("failed to create top graph")?
+                .is_legacy();
+
+            top_graph(
+                &orig,
+                user_id,
+                user_args,
+                args.order,
+                tz,
+                legacy_scores,
+                theme,
+            )
+            .await
+            .wrap_err("failed to create top graph")?
         }
     };
 
@@ -688,6 +694,7 @@ async fn get_map_id(map: Option<&str>, channel_id: Id<ChannelMarker>) -> Result<
 async fn map_bpm(
     orig: &CommandOrigin<'_>,
     args: GraphMapBpm<'_>,
+    theme: &Theme,
 ) -> Result<ControlFlow<(), MapResult>> {
     let mods_res = args.mods();
 
@@ -707,7 +714,7 @@ async fn map_bpm(
             return orig.error(content).await.map(ControlFlow::Break);
         }
         Err(MapError::Report(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -748,7 +755,7 @@ async fn map_bpm(
         }
     };
 
-    let bytes = map_bpm_graph(&map.pp_map, mods, map.cover()).await?;
+    let bytes = map_bpm_graph(&map.pp_map, mods, map.cover(), theme).await?;
 
     Ok(ControlFlow::Continue(MapResult::new(&map, bytes)))
 }
@@ -756,6 +763,7 @@ async fn map_bpm(
 async fn map_strains(
     orig: &CommandOrigin<'_>,
     args: GraphMapStrains<'_>,
+    theme: &Theme,
 ) -> Result<ControlFlow<(), MapResult>> {
     let mods_res = args.mods();
 
@@ -783,7 +791,7 @@ async fn map_strains(
             return orig.error(content).await.map(ControlFlow::Break);
         }
         Err(MapError::Report(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -821,7 +829,7 @@ async fn map_strains(
         }
     };
 
-    let bytes = map_strains_graph(&map.pp_map, mods, map.cover(), W, H).await?;
+    let bytes = map_strains_graph(&map.pp_map, mods, map.cover(), W, H, theme).await?;
 
     Ok(ControlFlow::Continue(MapResult::new(&map, bytes)))
 }
@@ -833,6 +841,7 @@ async fn top_graph(
     order: GraphTopOrder,
     tz: Option<UtcOffset>,
     legacy_scores: bool,
+    theme: &Theme,
 ) -> Result<Option<(CachedUser, Vec<u8>)>> {
     let scores_fut = Context::osu_scores()
         .top(200, legacy_scores)
@@ -847,7 +856,7 @@ async fn top_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user or scores");
 
             return Err(err);
@@ -879,16 +888,16 @@ async fn top_graph(
     let tz = tz.unwrap_or_else(|| Countries::code(country_code).to_timezone());
 
     let graph_result = match order {
-        GraphTopOrder::Date => top_graph_date(caption, &mut scores)
+        GraphTopOrder::Date => top_graph_date(caption, &mut scores, theme)
             .await
             .wrap_err("Failed to create top date graph"),
-        GraphTopOrder::Index => top_graph_index(caption, &scores)
+        GraphTopOrder::Index => top_graph_index(caption, &scores, theme)
             .await
             .wrap_err("Failed to create top index graph"),
-        GraphTopOrder::TimeByHour => top_graph_time_hour(caption, &mut scores, tz)
+        GraphTopOrder::TimeByHour => top_graph_time_hour(caption, &mut scores, tz, theme)
             .await
             .wrap_err("Failed to create top time hour graph"),
-        GraphTopOrder::TimeByDay => top_graph_time_day(caption, &mut scores, tz)
+        GraphTopOrder::TimeByDay => top_graph_time_day(caption, &mut scores, tz, theme)
             .await
             .wrap_err("Failed to create top time day graph"),
     };
@@ -896,7 +905,7 @@ async fn top_graph(
     let bytes = match graph_result {
         Ok(graph) => graph,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             warn!("{err:?}");
 
             return Ok(None);
@@ -6,7 +6,7 @@ use plotters::{
     chart::{ChartBuilder, SeriesLabelPosition},
     prelude::{Circle, IntoDrawingArea},
     series::AreaSeries,
-    style::{Color, GREEN, RED, RGBColor, TextStyle, WHITE},
+    style::{Color, TextStyle},
 };
 use plotters_backend::FontStyle;
 use plotters_skia::SkiaBackend;
@@ -14,11 +14,11 @@ use skia_safe::{EncodedImageFormat, surfaces};
 use time::OffsetDateTime;
 
 use crate::{
-    commands::osu::graphs::{H, W},
+    commands::osu::graphs::{H, Theme, W},
     util::Monthly,
 };
 
-pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>> {
+pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry], theme: &Theme) -> Result<Vec<u8>> {
     let mut min_acc: f32 = 100.0;
     let mut max_acc: f32 = 0.0;
 
@@ -50,11 +50,12 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
     {
         let mut root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = theme.background;
         root.fill(&background)
             .wrap_err("Failed to fill background")?;
 
-        let title_style = TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&WHITE);
+        let title_style =
+            TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&theme.text);
         root = root
             .titled("Accuracy", title_style)
             .wrap_err("Failed to draw title")?;
@@ -69,12 +70,12 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
         chart
             .configure_mesh()
             .disable_x_mesh()
-            .bold_line_style(WHITE.mix(0.3))
-            .light_line_style(WHITE.mix(0.0)) // hide
+            .bold_line_style(theme.grid.mix(0.3))
+            .light_line_style(theme.grid.mix(0.0)) // hide
             .y_label_formatter(&f32::to_string)
-            .label_style(("sans-serif", 20_i32, &WHITE))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 20_i32, &theme.text))
+            .axis_style(theme.axis)
+            .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &theme.text))
             .draw()
             .wrap_err("Failed to draw mesh")?;
 
@@ -82,37 +83,37 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
             .iter()
             .map(|entry| (entry.timestamp(), entry.accuracy.to_native()));
 
-        let area_style = RGBColor(2, 186, 213).mix(0.7).filled();
-        let border_style = RGBColor(0, 208, 138).stroke_width(3);
+        let area_style = theme.area.mix(0.7).filled();
+        let border_style = theme.border.stroke_width(3);
 
         let series = AreaSeries::new(data, min_acc, area_style).border_style(border_style);
         chart.draw_series(series).wrap_err("Failed to draw area")?;
 
         let max_coords = (max_datetime, max_acc);
-        let circle = Circle::new(max_coords, 9_u32, GREEN.stroke_width(2));
+        let circle = Circle::new(max_coords, 9_u32, theme.positive.stroke_width(2));
 
         chart
             .draw_series(iter::once(circle))
             .wrap_err("Failed to draw max circle")?
             .label(format!("Peak: {max_acc:.3}%"))
-            .legend(|(x, y)| Circle::new((x, y), 5_u32, GREEN.stroke_width(2)));
+            .legend(|(x, y)| Circle::new((x, y), 5_u32, theme.positive.stroke_width(2)));
 
         let min_coords = (min_datetime, min_acc);
-        let circle = Circle::new(min_coords, 9_u32, RED.stroke_width(2));
+        let circle = Circle::new(min_coords, 9_u32, theme.negative.stroke_width(2));
 
         chart
             .draw_series(iter::once(circle))
             .wrap_err("Failed to draw min circle")?
             .label(format!("Worst: {min_acc:.3}%"))
-            .legend(|(x, y)| Circle::new((x, y), 5_u32, RED.stroke_width(2)));
+            .legend(|(x, y)| Circle::new((x, y), 5_u32, theme.negative.stroke_width(2)));
 
         // Legend
         chart
             .configure_series_labels()
-            .background_style(RGBColor(7, 23, 17))
+            .background_style(theme.legend_background)
             .position(SeriesLabelPosition::UpperLeft)
             .legend_area_size(15_i32)
-            .label_font(("sans-serif", 20_i32, &WHITE))
+            .label_font(("sans-serif", 20_i32, &theme.text))
             .draw()
             .wrap_err("Failed to draw legend")?;
     }
@@ -5,18 +5,18 @@ use plotters::{
     chart::{ChartBuilder, SeriesLabelPosition},
     prelude::{IntoDrawingArea, PathElement},
     series::LineSeries,
-    style::{Color, RGBColor, TextStyle, WHITE},
+    style::{Color, TextStyle},
 };
 use plotters_backend::FontStyle;
 use plotters_skia::SkiaBackend;
 use skia_safe::{EncodedImageFormat, surfaces};
 
 use crate::{
-    commands::osu::graphs::{H, W},
+    commands::osu::graphs::{H, Theme, W},
     util::Monthly,
 };
 
-pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>> {
+pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry], theme: &Theme) -> Result<Vec<u8>> {
     // The caller already checked that `history` is not empty so indexing here
     // can't panic.
     let start = history[0].timestamp();
@@ -42,11 +42,12 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
     {
         let mut root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = theme.background;
         root.fill(&background)
             .wrap_err("Failed to fill background")?;
 
-        let title_style = TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&WHITE);
+        let title_style =
+            TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&theme.text);
         root = root
             .titled("Grades", title_style)
             .wrap_err("Failed to draw title")?;
@@ -62,12 +63,12 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
         chart
             .configure_mesh()
             .disable_x_mesh()
-            .bold_line_style(WHITE.mix(0.3))
-            .light_line_style(WHITE.mix(0.0)) // hide
+            .bold_line_style(theme.grid.mix(0.3))
+            .light_line_style(theme.grid.mix(0.0)) // hide
             .y_label_formatter(&|y| WithComma::new(*y).to_string())
-            .label_style(("sans-serif", 20_i32, &WHITE))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 20_i32, &theme.text))
+            .axis_style(theme.axis)
+            .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &theme.text))
             .draw()
             .wrap_err("Failed to draw mesh")?;
 
@@ -76,7 +77,7 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
             .iter()
             .map(|entry| (entry.timestamp(), entry.count_a.to_native()));
 
-        let a_style = RGBColor(0, 235, 180).stroke_width(2);
+        let a_style = theme.series[1].stroke_width(2);
         let a_series = LineSeries::new(a_data, a_style);
 
         chart
@@ -89,7 +90,7 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
             .iter()
             .map(|entry| (entry.timestamp(), entry.count_s.to_native()));
 
-        let s_style = RGBColor(0, 116, 193).stroke_width(2);
+        let s_style = theme.series[0].stroke_width(2);
         let s_series = LineSeries::new(s_data, s_style);
 
         chart
@@ -102,7 +103,7 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
             .iter()
             .map(|entry| (entry.timestamp(), entry.count_ss.to_native()));
 
-        let ss_style = RGBColor(255, 255, 255).stroke_width(2);
+        let ss_style = theme.series[2].stroke_width(2);
         let ss_series = LineSeries::new(ss_data, ss_style);
 
         chart
@@ -114,10 +115,10 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
         // Legend
         chart
             .configure_series_labels()
-            .background_style(RGBColor(7, 23, 17))
+            .background_style(theme.legend_background)
             .position(SeriesLabelPosition::UpperLeft)
             .legend_area_size(40_i32)
-            .label_font(("sans-serif", 20_i32, &WHITE))
+            .label_font(("sans-serif", 20_i32, &theme.text))
             .draw()
             .wrap_err("Failed to draw legend")?;
     }
@@ -4,7 +4,7 @@ use plotters::{
     chart::ChartBuilder,
     prelude::IntoDrawingArea,
     series::LineSeries,
-    style::{Color, RGBColor, TextStyle, WHITE},
+    style::{Color, TextStyle},
 };
 use plotters_backend::FontStyle;
 use plotters_skia::SkiaBackend;
@@ -12,11 +12,15 @@ use rosu_v2::model::GameMode;
 use skia_safe::{EncodedImageFormat, surfaces};
 
 use crate::{
-    commands::osu::graphs::{H, W},
+    commands::osu::graphs::{H, Theme, W},
     util::Monthly,
 };
 
-pub(super) fn graph(mode: GameMode, history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>> {
+pub(super) fn graph(
+    mode: GameMode,
+    history: &[ArchivedOsuTrackHistoryEntry],
+    theme: &Theme,
+) -> Result<Vec<u8>> {
     let mut min_300: f32 = 100.0;
     let mut max_300: f32 = 0.0;
 
@@ -48,11 +52,12 @@ pub(super) fn graph(mode: GameMode, history: &[ArchivedOsuTrackHistoryEntry]) ->
     {
         let mut root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = theme.background;
         root.fill(&background)
             .wrap_err("Failed to fill background")?;
 
-        let title_style = TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&WHITE);
+        let title_style =
+            TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&theme.text);
         root = root
             .titled("Hit Ratios", title_style)
             .wrap_err("Failed to draw title")?;
@@ -75,13 +80,13 @@ pub(super) fn graph(mode: GameMode, history: &[ArchivedOsuTrackHistoryEntry]) ->
         chart_300
             .configure_mesh()
             .disable_x_axis()
-            .bold_line_style(WHITE.mix(0.3))
-            .light_line_style(WHITE.mix(0.0)) // hide
+            .bold_line_style(theme.grid.mix(0.3))
+            .light_line_style(theme.grid.mix(0.0)) // hide
             .y_label_formatter(&f32::to_string)
             .y_desc("% 300")
-            .label_style(("sans-serif", 20_i32, &WHITE))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 20_i32, &theme.text))
+            .axis_style(theme.axis)
+            .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &theme.text))
             .draw()
             .wrap_err("Failed to draw first mesh")?;
 
@@ -90,7 +95,7 @@ pub(super) fn graph(mode: GameMode, history: &[ArchivedOsuTrackHistoryEntry]) ->
             .iter()
             .map(|entry| (entry.timestamp(), entry.ratio_count300()));
 
-        let count300_style = RGBColor(0, 116, 193).stroke_width(2);
+        let count300_style = theme.series[0].stroke_width(2);
         let count300_series = LineSeries::new(count300_data, count300_style);
 
         chart_300
@@ -118,13 +123,13 @@ pub(super) fn graph(mode: GameMode, history: &[ArchivedOsuTrackHistoryEntry]) ->
         let mut chart_100_mesh = chart_100.configure_mesh();
 
         chart_100_mesh
-            .bold_line_style(WHITE.mix(0.3))
-            .light_line_style(WHITE.mix(0.0)) // hide
+            .bold_line_style(theme.grid.mix(0.3))
+            .light_line_style(theme.grid.mix(0.0)) // hide
             .y_label_formatter(&f32::to_string)
             .y_desc("% 100")
-            .label_style(("sans-serif", 20_i32, &WHITE))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &WHITE));
+            .label_style(("sans-serif", 20_i32, &theme.text))
+            .axis_style(theme.axis)
+            .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &theme.text));
 
         if mode != GameMode::Taiko {
             chart_100_mesh.disable_x_axis();
@@ -139,7 +144,7 @@ pub(super) fn graph(mode: GameMode, history: &[ArchivedOsuTrackHistoryEntry]) ->
             .iter()
             .map(|entry| (entry.timestamp(), entry.ratio_count100()));
 
-        let count100_style = RGBColor(0, 235, 180).stroke_width(2);
+        let count100_style = theme.series[1].stroke_width(2);
         let count100_series = LineSeries::new(count100_data, count100_style);
 
         chart_100
@@ -159,13 +164,13 @@ pub(super) fn graph(mode: GameMode, history: &[ArchivedOsuTrackHistoryEntry]) ->
             // Mesh and axes
             chart_50
                 .configure_mesh()
-                .bold_line_style(WHITE.mix(0.3))
-                .light_line_style(WHITE.mix(0.0)) // hide
+                .bold_line_style(theme.grid.mix(0.3))
+                .light_line_style(theme.grid.mix(0.0)) // hide
                 .y_label_formatter(&f32::to_string)
                 .y_desc("% 50")
-                .label_style(("sans-serif", 20_i32, &WHITE))
-                .axis_style(RGBColor(7, 18, 14))
-                .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &WHITE))
+                .label_style(("sans-serif", 20_i32, &theme.text))
+                .axis_style(theme.axis)
+                .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &theme.text))
                 .draw()
                 .wrap_err("Failed to draw third mesh")?;
 
@@ -174,7 +179,7 @@ pub(super) fn graph(mode: GameMode, history: &[ArchivedOsuTrackHistoryEntry]) ->
                 .iter()
                 .map(|entry| (entry.timestamp(), entry.ratio_count50()));
 
-            let count50_style = RGBColor(255, 255, 255).stroke_width(2);
+            let count50_style = theme.series[2].stroke_width(2);
             let count50_series = LineSeries::new(count50_data, count50_style);
 
             chart_50
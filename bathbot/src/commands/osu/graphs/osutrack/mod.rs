@@ -1,8 +1,8 @@
-use bathbot_util::constants::GENERAL_ISSUE;
+use bathbot_util::i18n::ErrorKey;
 use eyre::{Report, Result};
 use rosu_v2::{error::OsuError, model::GameMode, request::UserId};
 
-use super::GraphOsuTrack;
+use super::{GraphOsuTrack, Theme};
 use crate::{
     commands::osu::user_not_found,
     core::{Context, commands::CommandOrigin},
@@ -21,6 +21,7 @@ pub async fn osutrack_graph(
     user_id: UserId,
     mode: GameMode,
     args: GraphOsuTrack,
+    theme: &Theme,
 ) -> Result<Option<(CachedUser, Vec<u8>)>> {
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
@@ -33,7 +34,7 @@ pub async fn osutrack_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -55,7 +56,7 @@ pub async fn osutrack_graph(
         }
         Ok(history) => history,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get osutrack history");
 
             return Err(err);
@@ -63,12 +64,12 @@ pub async fn osutrack_graph(
     };
 
     let res = match args {
-        GraphOsuTrack::PpRank(_) => pp_rank::graph(&history),
-        GraphOsuTrack::Score(_) => score::graph(&history),
-        GraphOsuTrack::HitRatios(_) => hit_ratios::graph(mode, &history),
-        GraphOsuTrack::Playcount(_) => playcount::graph(&history),
-        GraphOsuTrack::Accuracy(_) => accuracy::graph(&history),
-        GraphOsuTrack::Grades(_) => grades::graph(&history),
+        GraphOsuTrack::PpRank(_) => pp_rank::graph(&history, theme),
+        GraphOsuTrack::Score(_) => score::graph(&history, theme),
+        GraphOsuTrack::HitRatios(_) => hit_ratios::graph(mode, &history, theme),
+        GraphOsuTrack::Playcount(_) => playcount::graph(&history, theme),
+        GraphOsuTrack::Accuracy(_) => accuracy::graph(&history, theme),
+        GraphOsuTrack::Grades(_) => grades::graph(&history, theme),
     };
 
     Ok(Some((user, res?)))
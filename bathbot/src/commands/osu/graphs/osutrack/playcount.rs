@@ -7,7 +7,7 @@ use plotters::{
     chart::ChartBuilder,
     prelude::{IntoDrawingArea, IntoSegmentedCoord, Rectangle, SegmentValue},
     series::LineSeries,
-    style::{Color, RGBColor, TextStyle, WHITE},
+    style::{Color, RGBColor, TextStyle},
 };
 use plotters_backend::FontStyle;
 use plotters_skia::SkiaBackend;
@@ -15,11 +15,11 @@ use skia_safe::{EncodedImageFormat, surfaces};
 use time::{Date, OffsetDateTime, Time};
 
 use crate::{
-    commands::osu::graphs::{H, W},
+    commands::osu::graphs::{H, Theme, W},
     util::Monthly,
 };
 
-pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>> {
+pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry], theme: &Theme) -> Result<Vec<u8>> {
     // The caller already checked that `history` is not empty so indexing here
     // can't panic.
     let start = history[0].timestamp();
@@ -61,11 +61,12 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
     {
         let mut root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = theme.background;
         root.fill(&background)
             .wrap_err("Failed to fill background")?;
 
-        let title_style = TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&WHITE);
+        let title_style =
+            TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&theme.text);
         root = root
             .titled("Playcount", title_style)
             .wrap_err("Failed to draw title")?;
@@ -83,15 +84,15 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
             );
 
         // Mesh and axes
-        let label_style = ("sans-serif", 20_i32, &WHITE);
-        let axis_style = RGBColor(7, 18, 14);
-        let axis_desc_style = ("sans-serif", 20_i32, FontStyle::Bold, &WHITE);
+        let label_style = ("sans-serif", 20_i32, &theme.text);
+        let axis_style = theme.axis;
+        let axis_desc_style = ("sans-serif", 20_i32, FontStyle::Bold, &theme.text);
 
         chart
             .configure_mesh()
             .disable_x_mesh()
-            .bold_line_style(WHITE.mix(0.3))
-            .light_line_style(WHITE.mix(0.0)) // hide
+            .bold_line_style(theme.grid.mix(0.3))
+            .light_line_style(theme.grid.mix(0.0)) // hide
             .y_desc("Total")
             .y_label_formatter(&|y| WithComma::new(*y).to_string())
             .label_style(label_style)
@@ -111,7 +112,7 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
             .wrap_err("Failed to draw secondary mesh")?;
 
         // Series
-        let bars = YearCountBars::new(year_counts, first_playcount);
+        let bars = YearCountBars::new(year_counts, first_playcount, theme.area);
         chart
             .draw_secondary_series(bars)
             .wrap_err("Failed to draw bars")?;
@@ -120,7 +121,7 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
             .iter()
             .map(|entry| (entry.timestamp(), entry.playcount.to_native()));
 
-        let series_style = RGBColor(0, 246, 193).stroke_width(2);
+        let series_style = theme.series[1].stroke_width(2);
         let line = LineSeries::new(data, series_style);
 
         chart.draw_series(line).wrap_err("Failed to draw series")?;
@@ -149,13 +150,15 @@ impl YearCountEntry {
 struct YearCountBars {
     inner: IntoIter<i32, YearCountEntry>,
     prev_max: u32,
+    color: RGBColor,
 }
 
 impl YearCountBars {
-    fn new(counts: BTreeMap<i32, YearCountEntry>, first_playcount: u32) -> Self {
+    fn new(counts: BTreeMap<i32, YearCountEntry>, first_playcount: u32, color: RGBColor) -> Self {
         Self {
             inner: counts.into_iter(),
             prev_max: first_playcount,
+            color,
         }
     }
 }
@@ -182,7 +185,7 @@ impl Iterator for YearCountBars {
         let bot_right = (right, 0);
 
         let mix = if value > 0 { 0.5 } else { 0.0 };
-        let style = RGBColor(0, 126, 153).mix(mix).filled();
+        let style = self.color.mix(mix).filled();
 
         let mut rect = Rectangle::new([top_left, bot_right], style);
         rect.set_margin(0, 1, 2, 2);
@@ -7,7 +7,7 @@ use plotters::{
     chart::{ChartBuilder, SeriesLabelPosition},
     prelude::{Circle, IntoDrawingArea, PathElement},
     series::LineSeries,
-    style::{Color, GREEN, RGBColor, TextStyle, WHITE},
+    style::{Color, TextStyle},
 };
 use plotters_backend::FontStyle;
 use plotters_skia::SkiaBackend;
@@ -15,11 +15,11 @@ use skia_safe::{EncodedImageFormat, surfaces};
 use time::OffsetDateTime;
 
 use crate::{
-    commands::osu::graphs::{H, W},
+    commands::osu::graphs::{H, Theme, W},
     util::Monthly,
 };
 
-pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>> {
+pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry], theme: &Theme) -> Result<Vec<u8>> {
     let mut min_rank = u32::MAX;
     let mut max_rank = 0_u32;
     let mut min_rank_datetime = OffsetDateTime::now_utc();
@@ -52,11 +52,12 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
     {
         let mut root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = theme.background;
         root.fill(&background)
             .wrap_err("Failed to fill background")?;
 
-        let title_style = TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&WHITE);
+        let title_style =
+            TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&theme.text);
         root = root
             .titled("Rank and Total PP", title_style)
             .wrap_err("Failed to draw title")?;
@@ -71,15 +72,15 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
             .set_secondary_coord(Monthly(start..end), min_pp..max_pp);
 
         // Mesh and axes
-        let label_style = ("sans-serif", 20_i32, &WHITE);
-        let axis_style = RGBColor(7, 18, 14);
-        let axis_desc_style = ("sans-serif", 20_i32, FontStyle::Bold, &WHITE);
+        let label_style = ("sans-serif", 20_i32, &theme.text);
+        let axis_style = theme.axis;
+        let axis_desc_style = ("sans-serif", 20_i32, FontStyle::Bold, &theme.text);
 
         chart
             .configure_mesh()
             .disable_x_mesh()
-            .bold_line_style(WHITE.mix(0.3))
-            .light_line_style(WHITE.mix(0.0)) // hide
+            .bold_line_style(theme.grid.mix(0.3))
+            .light_line_style(theme.grid.mix(0.0)) // hide
             .y_desc("Rank")
             .y_label_formatter(&|y| if *y == 0 { 1 } else { -*y }.to_string())
             .label_style(label_style)
@@ -103,7 +104,7 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
             .iter()
             .map(|entry| (entry.timestamp(), -(entry.pp_rank.to_native() as i32)));
 
-        let rank_style = RGBColor(0, 116, 193).stroke_width(2);
+        let rank_style = theme.series[0].stroke_width(2);
         let rank_series = LineSeries::new(rank_data, rank_style);
 
         chart
@@ -112,7 +113,7 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
             .label(format!("Rank (peak #{})", WithComma::new(-max_rank as u32)))
             .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], rank_style));
 
-        let peak_style = GREEN.stroke_width(2);
+        let peak_style = theme.positive.stroke_width(2);
         let circle = Circle::new((min_rank_datetime, max_rank), 9_u32, peak_style);
 
         chart
@@ -123,7 +124,7 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
             .iter()
             .map(|entry| (entry.timestamp(), entry.pp.to_native()));
 
-        let pp_style = RGBColor(0, 246, 193).stroke_width(2);
+        let pp_style = theme.series[1].stroke_width(2);
         let pp_series = LineSeries::new(pp_data, pp_style);
 
         chart
@@ -135,10 +136,10 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
         // Legend
         chart
             .configure_series_labels()
-            .background_style(RGBColor(7, 23, 17))
+            .background_style(theme.legend_background)
             .position(SeriesLabelPosition::UpperLeft)
             .legend_area_size(45_i32)
-            .label_font(("sans-serif", 20_i32, &WHITE))
+            .label_font(("sans-serif", 20_i32, &theme.text))
             .draw()
             .wrap_err("Failed to draw legend")?;
     }
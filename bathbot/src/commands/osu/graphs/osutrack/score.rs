@@ -4,18 +4,18 @@ use plotters::{
     chart::{ChartBuilder, SeriesLabelPosition},
     prelude::{IntoDrawingArea, PathElement},
     series::LineSeries,
-    style::{Color, RGBColor, TextStyle, WHITE},
+    style::{Color, TextStyle},
 };
 use plotters_backend::FontStyle;
 use plotters_skia::SkiaBackend;
 use skia_safe::{EncodedImageFormat, surfaces};
 
 use crate::{
-    commands::osu::graphs::{H, W},
+    commands::osu::graphs::{H, Theme, W},
     util::Monthly,
 };
 
-pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>> {
+pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry], theme: &Theme) -> Result<Vec<u8>> {
     let mut min_score = u64::MAX;
     let mut max_score = 0_u64;
 
@@ -45,11 +45,12 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
     {
         let mut root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = theme.background;
         root.fill(&background)
             .wrap_err("Failed to fill background")?;
 
-        let title_style = TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&WHITE);
+        let title_style =
+            TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&theme.text);
         root = root
             .titled("Total and ranked score", title_style)
             .wrap_err("Failed to draw title")?;
@@ -64,15 +65,15 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
             .set_secondary_coord(Monthly(start..end), min_level..max_level);
 
         // Mesh and axes
-        let label_style = ("sans-serif", 20_i32, &WHITE);
-        let axis_style = RGBColor(7, 18, 14);
-        let axis_desc_style = ("sans-serif", 20_i32, FontStyle::Bold, &WHITE);
+        let label_style = ("sans-serif", 20_i32, &theme.text);
+        let axis_style = theme.axis;
+        let axis_desc_style = ("sans-serif", 20_i32, FontStyle::Bold, &theme.text);
 
         chart
             .configure_mesh()
             .disable_x_mesh()
-            .bold_line_style(WHITE.mix(0.3))
-            .light_line_style(WHITE.mix(0.0)) // hide
+            .bold_line_style(theme.grid.mix(0.3))
+            .light_line_style(theme.grid.mix(0.0)) // hide
             .y_desc("Score")
             .y_label_formatter(&|y| {
                 if *y >= 1_000_000_000_000 {
@@ -108,7 +109,7 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
             .iter()
             .map(|entry| (entry.timestamp(), entry.total_score.to_native()));
 
-        let total_style = RGBColor(0, 116, 193).stroke_width(2);
+        let total_style = theme.series[0].stroke_width(2);
         let total_series = LineSeries::new(total_data, total_style);
 
         chart
@@ -121,7 +122,7 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
             .iter()
             .map(|entry| (entry.timestamp(), entry.ranked_score.to_native()));
 
-        let ranked_style = RGBColor(0, 235, 180).stroke_width(2);
+        let ranked_style = theme.series[1].stroke_width(2);
         let ranked_series = LineSeries::new(ranked_data, ranked_style);
 
         chart
@@ -134,7 +135,7 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
             .iter()
             .map(|entry| (entry.timestamp(), entry.level.to_native()));
 
-        let level_style = RGBColor(255, 255, 255).stroke_width(2);
+        let level_style = theme.series[2].stroke_width(2);
         let level_series = LineSeries::new(level_data, level_style);
 
         chart
@@ -146,10 +147,10 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
         // Legend
         chart
             .configure_series_labels()
-            .background_style(RGBColor(7, 23, 17))
+            .background_style(theme.legend_background)
             .position(SeriesLabelPosition::UpperLeft)
             .legend_area_size(45_i32)
-            .label_font(("sans-serif", 20_i32, &WHITE))
+            .label_font(("sans-serif", 20_i32, &theme.text))
             .draw()
             .wrap_err("Failed to draw legend")?;
     }
@@ -2,7 +2,7 @@ use std::iter;
 
 use bathbot_macros::command;
 use bathbot_model::rosu_v2::user::MonthlyCountRkyv;
-use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{MessageBuilder, i18n::ErrorKey, matcher};
 use bitflags::bitflags;
 use bytes::Bytes;
 use eyre::{ContextCompat, Report, Result, WrapErr};
@@ -15,7 +15,7 @@ use plotters::{
         SeriesLabelPosition,
     },
     series::AreaSeries,
-    style::{BLACK, Color, RGBColor, WHITE},
+    style::{Color, RGBColor},
 };
 use plotters_backend::FontStyle;
 use plotters_skia::SkiaBackend;
@@ -32,7 +32,7 @@ use skia_safe::{EncodedImageFormat, Surface, surfaces};
 use time::{Date, Month, OffsetDateTime};
 use twilight_model::guild::Permissions;
 
-use super::{BitMapElement, Graph, GraphPlaycountReplays, H, W};
+use super::{BitMapElement, Graph, GraphPlaycountReplays, H, Theme, W};
 use crate::{
     commands::osu::{graphs::GRAPH_PLAYCOUNT_DESC, user_not_found},
     core::{
@@ -86,6 +86,7 @@ pub async fn playcount_replays_graph(
     orig: &CommandOrigin<'_>,
     user_id: UserId,
     flags: ProfileGraphFlags,
+    theme: &Theme,
 ) -> Result<Option<(CachedUser, Vec<u8>)>> {
     let user_args = UserArgs::rosu_id(&user_id, GameMode::Osu).await;
 
@@ -98,7 +99,7 @@ pub async fn playcount_replays_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -108,7 +109,8 @@ pub async fn playcount_replays_graph(
     let params = ProfileGraphParams::new(&mut user)
         .width(W)
         .height(H)
-        .flags(flags);
+        .flags(flags)
+        .theme(theme);
 
     let bytes = match graphs(params).await {
         Ok(GraphResult::Ok(graph)) => graph,
@@ -131,7 +133,7 @@ pub async fn playcount_replays_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             warn!(?err, "Failed to create profile graph");
 
             return Ok(None);
@@ -176,6 +178,7 @@ pub struct ProfileGraphParams<'l> {
     w: u32,
     h: u32,
     flags: ProfileGraphFlags,
+    theme: &'l Theme,
 }
 
 impl<'l> ProfileGraphParams<'l> {
@@ -188,6 +191,7 @@ impl<'l> ProfileGraphParams<'l> {
             w: Self::W,
             h: Self::H,
             flags: ProfileGraphFlags::default(),
+            theme: &Theme::DEFAULT,
         }
     }
 
@@ -208,6 +212,12 @@ impl<'l> ProfileGraphParams<'l> {
 
         self
     }
+
+    pub fn theme(mut self, theme: &'l Theme) -> Self {
+        self.theme = theme;
+
+        self
+    }
 }
 
 type Area<'b> = DrawingArea<SkiaBackend<'b>, Shift>;
@@ -258,7 +268,13 @@ async fn graphs(params: ProfileGraphParams<'_>) -> Result<GraphResult> {
 }
 
 fn draw(surface: &mut Surface, params: ProfileGraphParams<'_>, badges: &[Bytes]) -> Result<bool> {
-    let ProfileGraphParams { user, w, h, flags } = params;
+    let ProfileGraphParams {
+        user,
+        w,
+        h,
+        flags,
+        theme,
+    } = params;
 
     let (playcounts, replays) = prepare_monthly_counts(user, flags);
 
@@ -268,28 +284,28 @@ fn draw(surface: &mut Surface, params: ProfileGraphParams<'_>, badges: &[Bytes])
 
     let canvas = if flags.badges() && !badges.is_empty() {
         // Needs to happen after .await since type is not Send
-        let root = create_root(surface, w, h)?;
+        let root = create_root(surface, w, h, theme)?;
 
         draw_badges(badges, root, w, h)?
     } else {
-        create_root(surface, w, h)?
+        create_root(surface, w, h, theme)?
     };
 
     if flags.playcount() && flags.replays() {
-        draw_both(&playcounts, &replays, &canvas)?;
+        draw_both(&playcounts, &replays, &canvas, theme)?;
     } else if flags.replays() {
-        draw_replays(&replays, &canvas)?;
+        draw_replays(&replays, &canvas, theme)?;
     } else if flags.playcount() {
-        draw_playcounts(&playcounts, &canvas)?;
+        draw_playcounts(&playcounts, &canvas, theme)?;
     }
 
     Ok(true)
 }
 
-fn create_root(surface: &mut Surface, w: u32, h: u32) -> Result<Area<'_>> {
+fn create_root<'a>(surface: &'a mut Surface, w: u32, h: u32, theme: &Theme) -> Result<Area<'a>> {
     let root = SkiaBackend::new(surface.canvas(), w, h).into_drawing_area();
 
-    let background = RGBColor(19, 43, 33);
+    let background = theme.background;
     root.fill(&background)
         .wrap_err("Failed to fill background")?;
 
@@ -357,10 +373,7 @@ fn draw_badges<'a>(badges: &[Bytes], area: Area<'a>, w: u32, h: u32) -> Result<A
     Ok(bottom)
 }
 
-const PLAYCOUNTS_AREA_COLOR: RGBColor = RGBColor(0, 116, 193);
-const PLAYCOUNTS_BORDER_COLOR: RGBColor = RGBColor(102, 174, 222);
-
-fn draw_playcounts(playcounts: &[MonthlyCount], canvas: &Area<'_>) -> Result<()> {
+fn draw_playcounts(playcounts: &[MonthlyCount], canvas: &Area<'_>, theme: &Theme) -> Result<()> {
     let (first, last, max) = first_last_max(playcounts);
 
     let mut chart = ChartBuilder::on(canvas)
@@ -372,23 +385,23 @@ fn draw_playcounts(playcounts: &[MonthlyCount], canvas: &Area<'_>) -> Result<()>
 
     chart
         .configure_mesh()
-        .light_line_style(BLACK.mix(0.0))
+        .light_line_style(theme.grid.mix(0.0))
         .disable_x_mesh()
         .x_labels(10)
         .x_label_formatter(&|d| format!("{}-{}", d.year(), d.month() as u8))
         .y_desc("Monthly playcount")
-        .label_style(("sans-serif", 20_i32, &WHITE))
-        .bold_line_style(WHITE.mix(0.3))
-        .axis_style(RGBColor(7, 18, 14))
-        .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &WHITE))
+        .label_style(("sans-serif", 20_i32, &theme.text))
+        .bold_line_style(theme.grid.mix(0.3))
+        .axis_style(theme.axis)
+        .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &theme.text))
         .draw()
         .wrap_err("Failed to draw playcounts mesh")?;
 
     draw_area(
         &mut chart,
-        PLAYCOUNTS_AREA_COLOR,
+        theme.series[0],
         0.5,
-        PLAYCOUNTS_BORDER_COLOR,
+        theme.series[0],
         0.6,
         playcounts,
         "Monthly playcount",
@@ -396,10 +409,7 @@ fn draw_playcounts(playcounts: &[MonthlyCount], canvas: &Area<'_>) -> Result<()>
     .wrap_err("Failed to draw playcount area")
 }
 
-const REPLAYS_AREA_COLOR: RGBColor = RGBColor(0, 246, 193);
-const REPLAYS_BORDER_COLOR: RGBColor = RGBColor(40, 246, 205);
-
-fn draw_replays(replays: &[MonthlyCount], canvas: &Area<'_>) -> Result<()> {
+fn draw_replays(replays: &[MonthlyCount], canvas: &Area<'_>, theme: &Theme) -> Result<()> {
     let (first, last, max) = first_last_max(replays);
     let label_area = replay_label_area(max);
 
@@ -412,23 +422,23 @@ fn draw_replays(replays: &[MonthlyCount], canvas: &Area<'_>) -> Result<()> {
 
     chart
         .configure_mesh()
-        .light_line_style(BLACK.mix(0.0))
+        .light_line_style(theme.grid.mix(0.0))
         .disable_x_mesh()
         .x_labels(10)
         .x_label_formatter(&|d| format!("{}-{}", d.year(), d.month() as u8))
         .y_desc("Replays watched")
-        .label_style(("sans-serif", 20_i32, &WHITE))
-        .bold_line_style(WHITE.mix(0.3))
-        .axis_style(RGBColor(7, 18, 14))
-        .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &WHITE))
+        .label_style(("sans-serif", 20_i32, &theme.text))
+        .bold_line_style(theme.grid.mix(0.3))
+        .axis_style(theme.axis)
+        .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &theme.text))
         .draw()
         .wrap_err("Failed to draw replay mesh")?;
 
     draw_area(
         &mut chart,
-        REPLAYS_AREA_COLOR,
+        theme.series[1],
         0.2,
-        REPLAYS_BORDER_COLOR,
+        theme.series[1],
         1.0,
         replays,
         "Replays watched",
@@ -440,6 +450,7 @@ fn draw_both(
     playcounts: &[MonthlyCount],
     replays: &[MonthlyCount],
     canvas: &Area<'_>,
+    theme: &Theme,
 ) -> Result<()> {
     let (left_first, left_last, left_max) = first_last_max(playcounts);
     let (right_first, right_last, right_max) = first_last_max(replays);
@@ -460,32 +471,32 @@ fn draw_both(
     // Mesh and labels
     chart
         .configure_mesh()
-        .light_line_style(BLACK.mix(0.0))
+        .light_line_style(theme.grid.mix(0.0))
         .disable_x_mesh()
         .x_labels(10)
         .x_label_formatter(&|d| format!("{}-{}", d.year(), d.month() as u8))
         .y_desc("Monthly playcount")
-        .label_style(("sans-serif", 20_i32, &WHITE))
-        .bold_line_style(WHITE.mix(0.3))
-        .axis_style(RGBColor(7, 18, 14))
-        .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &WHITE))
+        .label_style(("sans-serif", 20_i32, &theme.text))
+        .bold_line_style(theme.grid.mix(0.3))
+        .axis_style(theme.axis)
+        .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &theme.text))
         .draw()
         .wrap_err("Failed to draw primary mesh")?;
 
     chart
         .configure_secondary_axes()
         .y_desc("Replays watched")
-        .label_style(("sans-serif", 20_i32, &WHITE))
-        .axis_style(RGBColor(7, 18, 14))
-        .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &WHITE))
+        .label_style(("sans-serif", 20_i32, &theme.text))
+        .axis_style(theme.axis)
+        .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &theme.text))
         .draw()
         .wrap_err("Failed to draw secondary mesh")?;
 
     draw_area(
         &mut chart,
-        PLAYCOUNTS_AREA_COLOR,
+        theme.series[0],
         0.5,
-        PLAYCOUNTS_BORDER_COLOR,
+        theme.series[0],
         0.6,
         playcounts,
         "Monthly playcount",
@@ -498,8 +509,8 @@ fn draw_both(
         .iter()
         .map(|MonthlyCount { start_date, count }| (*start_date, *count));
 
-    let area_color = REPLAYS_AREA_COLOR;
-    let border_color = REPLAYS_BORDER_COLOR;
+    let area_color = theme.series[1];
+    let border_color = theme.series[1];
     let series = AreaSeries::new(iter, 0, area_color.mix(0.2).filled());
 
     chart
@@ -524,10 +535,10 @@ fn draw_both(
     // Legend
     chart
         .configure_series_labels()
-        .background_style(RGBColor(7, 23, 17))
+        .background_style(theme.legend_background)
         .position(SeriesLabelPosition::UpperLeft)
         .legend_area_size(45_i32)
-        .label_font(("sans-serif", 20_i32, &WHITE))
+        .label_font(("sans-serif", 20_i32, &theme.text))
         .draw()
         .wrap_err("Failed to draw legend")?;
 
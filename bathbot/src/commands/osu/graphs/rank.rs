@@ -2,12 +2,12 @@ use std::iter;
 
 use bathbot_macros::command;
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_util::{constants::GENERAL_ISSUE, matcher, numbers::WithComma};
+use bathbot_util::{i18n::ErrorKey, matcher, numbers::WithComma};
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use plotters::{
     prelude::{ChartBuilder, Circle, IntoDrawingArea, SeriesLabelPosition},
     series::AreaSeries,
-    style::{BLACK, Color, GREEN, RED, RGBColor, ShapeStyle, WHITE},
+    style::{Color, RGBColor, ShapeStyle},
 };
 use plotters_backend::FontStyle;
 use plotters_skia::SkiaBackend;
@@ -15,7 +15,7 @@ use rosu_v2::{prelude::OsuError, request::UserId};
 use skia_safe::{EncodedImageFormat, surfaces};
 use twilight_model::guild::Permissions;
 
-use super::{Graph, GraphRank};
+use super::{Graph, GraphRank, Theme};
 use crate::{
     commands::osu::{
         graphs::{GRAPH_RANK_DESC, H, W},
@@ -118,8 +118,14 @@ pub async fn rank_graph(
     user_args: UserArgs,
     from: Option<u8>,
     until: Option<u8>,
+    theme: &Theme,
 ) -> Result<Option<(CachedUser, Vec<u8>)>> {
-    fn draw_graph(user: &CachedUser, from: u8, until: u8) -> Result<Option<Vec<u8>>> {
+    fn draw_graph(
+        user: &CachedUser,
+        from: u8,
+        until: u8,
+        theme: &Theme,
+    ) -> Result<Option<Vec<u8>>> {
         if user.rank_history.len() < 90 - from as usize {
             return Ok(None);
         }
@@ -177,7 +183,7 @@ pub async fn rank_graph(
         {
             let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-            let background = RGBColor(19, 43, 33);
+            let background = theme.background;
             root.fill(&background)
                 .wrap_err("Failed to fill background")?;
 
@@ -203,10 +209,10 @@ pub async fn rank_graph(
                 .x_label_formatter(&|x| format!("{}", (until + from) as u32 - *x))
                 .y_label_formatter(&|y| format!("{}", -*y))
                 .y_desc("Rank")
-                .label_style(("sans-serif", 15, &WHITE))
-                .bold_line_style(WHITE.mix(0.3))
-                .axis_style(RGBColor(7, 18, 14))
-                .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &WHITE))
+                .label_style(("sans-serif", 15, &theme.text))
+                .bold_line_style(theme.grid.mix(0.3))
+                .axis_style(theme.axis)
+                .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &theme.text))
                 .draw()
                 .wrap_err("Failed to draw mesh")?;
 
@@ -215,28 +221,28 @@ pub async fn rank_graph(
                 .skip_while(|(_, rank)| *rank == 0)
                 .take_while(|(_, rank)| *rank != 0);
 
-            let area_style = RGBColor(2, 186, 213).mix(0.7).filled();
-            let border_style = style(RGBColor(0, 208, 138)).stroke_width(3);
+            let area_style = theme.area.mix(0.7).filled();
+            let border_style = style(theme.border).stroke_width(3);
             let series = AreaSeries::new(data, min, area_style).border_style(border_style);
             chart.draw_series(series).wrap_err("Failed to draw area")?;
 
             let max_coords = (min_idx as u32, max);
-            let circle = Circle::new(max_coords, 9_u32, style(GREEN).stroke_width(2));
+            let circle = Circle::new(max_coords, 9_u32, style(theme.positive).stroke_width(2));
 
             chart
                 .draw_series(iter::once(circle))
                 .wrap_err("Failed to draw max circle")?
                 .label(format!("Peak: #{}", WithComma::new(-max)))
-                .legend(|(x, y)| Circle::new((x, y), 5_u32, style(GREEN).stroke_width(2)));
+                .legend(|(x, y)| Circle::new((x, y), 5_u32, style(theme.positive).stroke_width(2)));
 
             let min_coords = (max_idx as u32, min);
-            let circle = Circle::new(min_coords, 9_u32, style(RED).stroke_width(2));
+            let circle = Circle::new(min_coords, 9_u32, style(theme.negative).stroke_width(2));
 
             chart
                 .draw_series(iter::once(circle))
                 .wrap_err("Failed to draw min circle")?
                 .label(format!("Worst: #{}", WithComma::new(-min)))
-                .legend(|(x, y)| Circle::new((x, y), 5_u32, style(RED).stroke_width(2)));
+                .legend(|(x, y)| Circle::new((x, y), 5_u32, style(theme.negative).stroke_width(2)));
 
             let limit = (until - from) / 2 + from;
 
@@ -248,11 +254,11 @@ pub async fn rank_graph(
 
             chart
                 .configure_series_labels()
-                .border_style(BLACK.stroke_width(2))
-                .background_style(RGBColor(192, 192, 192))
+                .border_style(theme.legend_border.mix(0.6).stroke_width(1))
+                .background_style(theme.legend_background)
                 .position(position)
                 .legend_area_size(13)
-                .label_font(("sans-serif", 15, FontStyle::Bold))
+                .label_font(("sans-serif", 15, FontStyle::Bold, &theme.text))
                 .draw()
                 .wrap_err("Failed to draw legend")?;
         }
@@ -275,7 +281,7 @@ pub async fn rank_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -285,7 +291,7 @@ pub async fn rank_graph(
     let from_unwrapped = from.unwrap_or(0);
     let until_unwrapped = u8::max(until.unwrap_or(90), u8::min(from_unwrapped + 2, 90));
 
-    let bytes = match draw_graph(&user, from_unwrapped, until_unwrapped) {
+    let bytes = match draw_graph(&user, from_unwrapped, until_unwrapped, theme) {
         Ok(Some(graph)) => graph,
         Ok(None) => {
             let mut content = format!(
@@ -302,7 +308,7 @@ pub async fn rank_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             warn!(?err, "Failed to draw rank graph");
 
             return Ok(None);
@@ -0,0 +1,95 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+use eyre::{Result, WrapErr};
+use plotters::{
+    prelude::*,
+    style::text_anchor::{HPos, Pos, VPos},
+};
+use plotters_skia::SkiaBackend;
+use skia_safe::{EncodedImageFormat, surfaces};
+
+use super::Theme;
+use crate::commands::osu::SkillRadar;
+
+/// Amount of concentric guide polygons.
+const RINGS: u32 = 4;
+
+pub fn skill_radar_graph(radar: &SkillRadar, w: u32, h: u32, theme: &Theme) -> Result<Vec<u8>> {
+    let mut surface =
+        surfaces::raster_n32_premul((w as i32, h as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let root = SkiaBackend::new(surface.canvas(), w, h).into_drawing_area();
+
+        root.fill(&theme.background)
+            .wrap_err("Failed to fill background")?;
+
+        let center = (w as f64 / 2.0, h as f64 / 2.0);
+        let radius = w.min(h) as f64 * 0.34;
+
+        // Corner of the `i`th axis at `scale` of the full radius
+        let corner = |i: usize, scale: f64| {
+            let angle = TAU * i as f64 / SkillRadar::LABELS.len() as f64 - FRAC_PI_2;
+
+            (
+                (center.0 + angle.cos() * radius * scale).round() as i32,
+                (center.1 + angle.sin() * radius * scale).round() as i32,
+            )
+        };
+
+        let axes = 0..SkillRadar::LABELS.len();
+
+        // Guide rings
+        for ring in 1..=RINGS {
+            let scale = ring as f64 / RINGS as f64;
+            let mut points: Vec<_> = axes.clone().map(|i| corner(i, scale)).collect();
+            points.push(points[0]);
+
+            let ring = PathElement::new(points, theme.grid.mix(0.3).stroke_width(1));
+            root.draw(&ring).wrap_err("Failed to draw guide ring")?;
+        }
+
+        // Axes
+        for i in axes.clone() {
+            let axis = PathElement::new(
+                vec![corner(i, 0.0), corner(i, 1.0)],
+                theme.grid.mix(0.3).stroke_width(1),
+            );
+            root.draw(&axis).wrap_err("Failed to draw axis")?;
+        }
+
+        // Skills
+        let values = radar.values();
+        let points: Vec<_> = axes
+            .clone()
+            .map(|i| corner(i, values[i].clamp(0.0, 1.0)))
+            .collect();
+
+        let area = Polygon::new(points.clone(), theme.area.mix(0.6).filled());
+        root.draw(&area).wrap_err("Failed to draw skill area")?;
+
+        let mut border = points;
+        border.push(border[0]);
+        let border = PathElement::new(border, theme.border.stroke_width(3));
+        root.draw(&border).wrap_err("Failed to draw skill border")?;
+
+        // Labels
+        let label_style = TextStyle::from(("sans-serif", 20_i32, FontStyle::Bold))
+            .color(&theme.text)
+            .pos(Pos::new(HPos::Center, VPos::Center));
+
+        for (i, label) in SkillRadar::LABELS.into_iter().enumerate() {
+            let text = format!("{label} ({:.0}%)", values[i] * 100.0);
+            let elem = Text::new(text, corner(i, 1.25), label_style.clone());
+            root.draw(&elem).wrap_err("Failed to draw label")?;
+        }
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(png_bytes)
+}
@@ -1,10 +1,10 @@
 use bathbot_macros::command;
-use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{MessageBuilder, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 use twilight_model::guild::Permissions;
 
-use super::{Graph, GraphSnipeCount, H, W};
+use super::{Graph, GraphSnipeCount, H, Theme, W};
 use crate::{
     commands::osu::{
         SnipeGameMode, graphs::GRAPH_SNIPE_COUNT_DESC, player_snipe_stats, user_not_found,
@@ -90,6 +90,7 @@ pub async fn snipe_count_graph(
     orig: &CommandOrigin<'_>,
     user_id: UserId,
     mode: GameMode,
+    theme: &Theme,
 ) -> Result<Option<(CachedUser, Vec<u8>)>> {
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
@@ -102,7 +103,7 @@ pub async fn snipe_count_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -140,7 +141,7 @@ pub async fn snipe_count_graph(
                 return Ok(None);
             }
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -153,12 +154,12 @@ pub async fn snipe_count_graph(
         return Ok(None);
     };
 
-    let graph_result = player_snipe_stats::graphs(&history, &player.count_sr_spread, W, H);
+    let graph_result = player_snipe_stats::graphs(&history, &player.count_sr_spread, W, H, theme);
 
     let bytes = match graph_result {
         Ok(graph) => graph,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             warn!(?err, "Failed to create snipe count graph");
 
             return Ok(None);
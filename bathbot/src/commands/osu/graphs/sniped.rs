@@ -1,10 +1,10 @@
 use bathbot_macros::command;
-use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{MessageBuilder, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 use twilight_model::guild::Permissions;
 
-use super::{Graph, GraphSniped, H, W};
+use super::{Graph, GraphSniped, H, Theme, W};
 use crate::{
     commands::osu::{SnipeGameMode, graphs::GRAPH_SNIPED_DESC, sniped, user_not_found},
     core::{
@@ -88,6 +88,7 @@ pub async fn sniped_graph(
     orig: &CommandOrigin<'_>,
     user_id: UserId,
     mode: GameMode,
+    theme: &Theme,
 ) -> Result<Option<(CachedUser, Vec<u8>)>> {
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
@@ -100,7 +101,7 @@ pub async fn sniped_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -123,7 +124,7 @@ pub async fn sniped_graph(
         match tokio::try_join!(sniper_fut, snipee_fut) {
             Ok(tuple) => tuple,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err.wrap_err("failed to get sniper or snipee"));
             }
@@ -135,7 +136,7 @@ pub async fn sniped_graph(
         return Ok(None);
     };
 
-    let bytes = match sniped::graphs(username, &mut sniper, &mut snipee, W, H) {
+    let bytes = match sniped::graphs(username, &mut sniper, &mut snipee, W, H, theme) {
         Ok(Some(graph)) => graph,
         Ok(None) => {
             let content = format!(
@@ -147,7 +148,7 @@ pub async fn sniped_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             warn!(?err, "Failed to create sniped graph");
 
             return Ok(None);
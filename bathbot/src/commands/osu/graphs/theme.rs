@@ -0,0 +1,417 @@
+use bathbot_psql::model::configs::GraphTheme;
+use plotters::style::RGBColor;
+use twilight_model::id::{Id, marker::UserMarker};
+
+use crate::core::Context;
+
+/// Colors used to draw graphs.
+pub struct Theme {
+    pub background: RGBColor,
+    /// Drawn on top of cover images to keep the graph readable
+    pub overlay: RGBColor,
+    pub text: RGBColor,
+    /// Mesh lines, drawn with transparency
+    pub grid: RGBColor,
+    pub axis: RGBColor,
+    pub legend_background: RGBColor,
+    pub legend_border: RGBColor,
+    /// Fill of area graphs, drawn with transparency
+    pub area: RGBColor,
+    /// Outline of area graphs
+    pub border: RGBColor,
+    /// Marker of maximum values
+    pub positive: RGBColor,
+    /// Marker of minimum values
+    pub negative: RGBColor,
+    /// Colors of graphs with multiple series
+    pub series: [RGBColor; 3],
+    /// Colors of the strain lines of a map
+    pub strains: [RGBColor; 6],
+}
+
+impl Theme {
+    pub const DEFAULT: Self = Self {
+        background: RGBColor(19, 43, 33),
+        overlay: RGBColor(0, 0, 0),
+        text: RGBColor(255, 255, 255),
+        grid: RGBColor(255, 255, 255),
+        axis: RGBColor(7, 18, 14),
+        legend_background: RGBColor(7, 23, 17),
+        legend_border: RGBColor(255, 255, 255),
+        area: RGBColor(2, 186, 213),
+        border: RGBColor(0, 208, 138),
+        positive: RGBColor(0, 255, 0),
+        negative: RGBColor(255, 0, 0),
+        series: [
+            RGBColor(0, 116, 193),
+            RGBColor(0, 235, 180),
+            RGBColor(255, 255, 255),
+        ],
+        strains: [
+            RGBColor(0, 255, 255),
+            RGBColor(0, 255, 0),
+            RGBColor(255, 0, 0),
+            RGBColor(255, 0, 255),
+            RGBColor(0, 0, 255),
+            RGBColor(255, 255, 0),
+        ],
+    };
+
+    /// Based on the Okabe-Ito palette which stays distinguishable for all
+    /// common types of color vision deficiency.
+    pub const COLORBLIND: Self = Self {
+        background: RGBColor(24, 24, 24),
+        overlay: RGBColor(0, 0, 0),
+        text: RGBColor(255, 255, 255),
+        grid: RGBColor(255, 255, 255),
+        axis: RGBColor(8, 8, 8),
+        legend_background: RGBColor(12, 12, 12),
+        legend_border: RGBColor(255, 255, 255),
+        area: RGBColor(86, 180, 233),
+        border: RGBColor(230, 159, 0),
+        positive: RGBColor(0, 158, 115),
+        negative: RGBColor(213, 94, 0),
+        series: [
+            RGBColor(86, 180, 233),
+            RGBColor(230, 159, 0),
+            RGBColor(204, 121, 167),
+        ],
+        strains: [
+            RGBColor(86, 180, 233),
+            RGBColor(0, 158, 115),
+            RGBColor(213, 94, 0),
+            RGBColor(204, 121, 167),
+            RGBColor(0, 114, 178),
+            RGBColor(240, 228, 66),
+        ],
+    };
+
+    pub const LIGHT: Self = Self {
+        background: RGBColor(250, 250, 250),
+        overlay: RGBColor(255, 255, 255),
+        text: RGBColor(20, 20, 20),
+        grid: RGBColor(0, 0, 0),
+        axis: RGBColor(90, 90, 90),
+        legend_background: RGBColor(235, 235, 235),
+        legend_border: RGBColor(0, 0, 0),
+        area: RGBColor(2, 186, 213),
+        border: RGBColor(0, 140, 95),
+        positive: RGBColor(0, 150, 0),
+        negative: RGBColor(200, 0, 0),
+        series: [
+            RGBColor(0, 116, 193),
+            RGBColor(0, 160, 120),
+            RGBColor(60, 60, 60),
+        ],
+        strains: [
+            RGBColor(0, 150, 200),
+            RGBColor(0, 150, 0),
+            RGBColor(200, 0, 0),
+            RGBColor(170, 0, 170),
+            RGBColor(0, 0, 200),
+            RGBColor(190, 150, 0),
+        ],
+    };
+
+    pub const DARK: Self = Self {
+        background: RGBColor(24, 24, 27),
+        overlay: RGBColor(0, 0, 0),
+        text: RGBColor(230, 230, 230),
+        grid: RGBColor(255, 255, 255),
+        axis: RGBColor(10, 10, 10),
+        legend_background: RGBColor(16, 16, 18),
+        legend_border: RGBColor(230, 230, 230),
+        area: RGBColor(2, 186, 213),
+        border: RGBColor(0, 208, 138),
+        positive: RGBColor(0, 255, 0),
+        negative: RGBColor(255, 0, 0),
+        series: [
+            RGBColor(0, 116, 193),
+            RGBColor(0, 235, 180),
+            RGBColor(230, 230, 230),
+        ],
+        strains: [
+            RGBColor(0, 255, 255),
+            RGBColor(0, 255, 0),
+            RGBColor(255, 0, 0),
+            RGBColor(255, 0, 255),
+            RGBColor(0, 0, 255),
+            RGBColor(255, 255, 0),
+        ],
+    };
+
+    /// Whether dark colors are drawn on a light background.
+    pub fn is_light(&self) -> bool {
+        let RGBColor(r, g, b) = self.background;
+
+        // Perceived brightness
+        299 * r as u32 + 587 * g as u32 + 114 * b as u32 > 127_500
+    }
+
+    /// The theme of a user's config, falling back to the default theme.
+    pub fn get(theme: Option<GraphTheme>) -> &'static Self {
+        match theme.unwrap_or_default() {
+            GraphTheme::Default => &Self::DEFAULT,
+            GraphTheme::Colorblind => &Self::COLORBLIND,
+            GraphTheme::Light => &Self::LIGHT,
+            GraphTheme::Dark => &Self::DARK,
+        }
+    }
+
+    /// The theme of a user's config, falling back to the default theme if
+    /// the config could not be retrieved.
+    pub async fn of_user(user_id: Id<UserMarker>) -> &'static Self {
+        match Context::user_config().graph_theme(user_id).await {
+            Ok(theme) => Self::get(theme),
+            Err(err) => {
+                warn!(?err, "Failed to get graph theme");
+
+                &Self::DEFAULT
+            }
+        }
+    }
+
+    /// Color for the `idx`-th of arbitrarily many series, cycling through
+    /// the strain colors.
+    pub fn categorical(&self, idx: usize) -> RGBColor {
+        self.strains[idx % self.strains.len()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashSet,
+        hash::{DefaultHasher, Hash, Hasher},
+    };
+
+    use plotters::{
+        prelude::{ChartBuilder, Circle, IntoDrawingArea},
+        series::AreaSeries,
+        style::Color,
+    };
+    use plotters_skia::SkiaBackend;
+    use skia_safe::{
+        AlphaType, Color, ColorType, EncodedImageFormat, IRect, ImageInfo, Paint, surfaces,
+    };
+
+    use super::*;
+
+    const THEMES: [GraphTheme; 4] = [
+        GraphTheme::Default,
+        GraphTheme::Colorblind,
+        GraphTheme::Light,
+        GraphTheme::Dark,
+    ];
+
+    /// Draws a fixed graph without text so that the output does not depend on
+    /// installed fonts.
+    fn synthetic_graph(theme: &Theme) -> Vec<u8> {
+        const W: u32 = 200;
+        const H: u32 = 100;
+
+        let mut surface = surfaces::raster_n32_premul((W as i32, H as i32)).unwrap();
+
+        {
+            let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
+            root.fill(&theme.background).unwrap();
+
+            let mut chart = ChartBuilder::on(&root)
+                .margin(5_i32)
+                .build_cartesian_2d(0..10_i32, 0..10_i32)
+                .unwrap();
+
+            chart
+                .configure_mesh()
+                .disable_x_axis()
+                .disable_y_axis()
+                .bold_line_style(theme.grid.mix(0.3))
+                .draw()
+                .unwrap();
+
+            let points = [(0, 2), (3, 7), (5, 4), (8, 9), (10, 6)];
+            let area = AreaSeries::new(points, 0, theme.area.mix(0.7).filled())
+                .border_style(theme.border.stroke_width(3));
+            chart.draw_series(area).unwrap();
+
+            let max = Circle::new((8, 9), 4_i32, theme.positive.stroke_width(2));
+            let min = Circle::new((0, 2), 4_i32, theme.negative.stroke_width(2));
+            chart.draw_series([max, min]).unwrap();
+        }
+
+        surface
+            .image_snapshot()
+            .encode(None, EncodedImageFormat::PNG, None)
+            .unwrap()
+            .to_vec()
+    }
+
+    /// Draws one band per palette color.
+    ///
+    /// The bands are pixel-aligned and drawn without anti-aliasing so the
+    /// resulting pixels are exact and their hash can be pinned.
+    fn swatch(theme: &Theme) -> Vec<u8> {
+        const W: i32 = 8;
+        const BAND: i32 = 2;
+
+        let colors: Vec<_> = [
+            theme.background,
+            theme.overlay,
+            theme.text,
+            theme.grid,
+            theme.axis,
+            theme.legend_background,
+            theme.legend_border,
+            theme.area,
+            theme.border,
+            theme.positive,
+            theme.negative,
+        ]
+        .into_iter()
+        .chain(theme.series)
+        .chain(theme.strains)
+        .collect();
+
+        let h = BAND * colors.len() as i32;
+        let mut surface = surfaces::raster_n32_premul((W, h)).unwrap();
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(false);
+
+        for (i, RGBColor(r, g, b)) in colors.into_iter().enumerate() {
+            paint.set_color(Color::from_rgb(r, g, b));
+            let rect = IRect::from_xywh(0, i as i32 * BAND, W, BAND);
+            surface.canvas().draw_irect(rect, &paint);
+        }
+
+        let info = ImageInfo::new((W, h), ColorType::RGBA8888, AlphaType::Unpremul, None);
+        let mut pixels = vec![0; (W * h * 4) as usize];
+        assert!(surface.read_pixels(&info, &mut pixels, W as usize * 4, (0, 0)));
+
+        pixels
+    }
+
+    /// FNV-1a which, unlike [`DefaultHasher`], is stable across Rust versions.
+    fn fnv1a(bytes: &[u8]) -> u64 {
+        bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        })
+    }
+
+    fn hash(bytes: &[u8]) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        bytes.hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    #[test]
+    fn light_backgrounds() {
+        assert!(Theme::LIGHT.is_light());
+        assert!(!Theme::DEFAULT.is_light());
+        assert!(!Theme::COLORBLIND.is_light());
+        assert!(!Theme::DARK.is_light());
+    }
+
+    #[test]
+    fn palette_per_theme() {
+        // (theme, background, text, axis, area, first series)
+        let cases = [
+            (
+                None,
+                RGBColor(19, 43, 33),
+                RGBColor(255, 255, 255),
+                RGBColor(7, 18, 14),
+                RGBColor(2, 186, 213),
+                RGBColor(0, 116, 193),
+            ),
+            (
+                Some(GraphTheme::Default),
+                RGBColor(19, 43, 33),
+                RGBColor(255, 255, 255),
+                RGBColor(7, 18, 14),
+                RGBColor(2, 186, 213),
+                RGBColor(0, 116, 193),
+            ),
+            (
+                Some(GraphTheme::Colorblind),
+                RGBColor(24, 24, 24),
+                RGBColor(255, 255, 255),
+                RGBColor(8, 8, 8),
+                RGBColor(86, 180, 233),
+                RGBColor(86, 180, 233),
+            ),
+            (
+                Some(GraphTheme::Light),
+                RGBColor(250, 250, 250),
+                RGBColor(20, 20, 20),
+                RGBColor(90, 90, 90),
+                RGBColor(2, 186, 213),
+                RGBColor(0, 116, 193),
+            ),
+            (
+                Some(GraphTheme::Dark),
+                RGBColor(24, 24, 27),
+                RGBColor(230, 230, 230),
+                RGBColor(10, 10, 10),
+                RGBColor(2, 186, 213),
+                RGBColor(0, 116, 193),
+            ),
+        ];
+
+        for (theme, background, text, axis, area, series) in cases {
+            let palette = Theme::get(theme);
+
+            assert_eq!(palette.background, background, "{theme:?}");
+            assert_eq!(palette.text, text, "{theme:?}");
+            assert_eq!(palette.axis, axis, "{theme:?}");
+            assert_eq!(palette.area, area, "{theme:?}");
+            assert_eq!(palette.series[0], series, "{theme:?}");
+        }
+    }
+
+    #[test]
+    fn categorical_cycles_through_strains() {
+        let theme = &Theme::COLORBLIND;
+
+        assert_eq!(theme.categorical(0), theme.strains[0]);
+        assert_eq!(theme.categorical(5), theme.strains[5]);
+        assert_eq!(theme.categorical(6), theme.strains[0]);
+        assert_eq!(theme.categorical(13), theme.strains[1]);
+    }
+
+    #[test]
+    fn themes_match_pinned_swatches() {
+        let cases = [
+            (GraphTheme::Default, 0x0c9c428995e36485),
+            (GraphTheme::Colorblind, 0xa6d76fd6e1588805),
+            (GraphTheme::Light, 0xda1e1fb888991ce5),
+            (GraphTheme::Dark, 0x7724628db3ff8965),
+        ];
+
+        for (theme, expected) in cases {
+            let swatch = swatch(Theme::get(Some(theme)));
+
+            assert_eq!(fnv1a(&swatch), expected, "{theme:?}");
+        }
+    }
+
+    #[test]
+    fn themes_render_deterministically() {
+        for theme in THEMES {
+            let theme = Theme::get(Some(theme));
+
+            assert_eq!(hash(&synthetic_graph(theme)), hash(&synthetic_graph(theme)));
+        }
+    }
+
+    #[test]
+    fn themes_render_distinctly() {
+        let hashes: HashSet<_> = THEMES
+            .into_iter()
+            .map(|theme| hash(&synthetic_graph(Theme::get(Some(theme)))))
+            .collect();
+
+        assert_eq!(hashes.len(), THEMES.len());
+    }
+}
@@ -2,17 +2,21 @@ use eyre::{ContextCompat, Result, WrapErr};
 use plotters::{
     prelude::{ChartBuilder, Circle, EmptyElement, IntoDrawingArea, SeriesLabelPosition},
     series::PointSeries,
-    style::{Color, RGBColor, WHITE},
+    style::Color,
 };
 use plotters_backend::FontStyle;
 use plotters_skia::SkiaBackend;
 use rosu_v2::prelude::Score;
 use skia_safe::{EncodedImageFormat, surfaces};
 
-use super::{H, W};
+use super::{H, Theme, W};
 use crate::util::Monthly;
 
-pub async fn top_graph_date(caption: String, scores: &mut [Score]) -> Result<Vec<u8>> {
+pub async fn top_graph_date(
+    caption: String,
+    scores: &mut [Score],
+    theme: &Theme,
+) -> Result<Vec<u8>> {
     let max = scores.first().and_then(|s| s.pp).unwrap_or(0.0);
     let max_adj = max + 5.0;
 
@@ -31,11 +35,11 @@ pub async fn top_graph_date(caption: String, scores: &mut [Score]) -> Result<Vec
     {
         let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = theme.background;
         root.fill(&background)
             .wrap_err("failed to fill background")?;
 
-        let caption_style = ("sans-serif", 25_i32, FontStyle::Bold, &WHITE);
+        let caption_style = ("sans-serif", 25_i32, FontStyle::Bold, &theme.text);
 
         let mut chart = ChartBuilder::on(&root)
             .x_label_area_size(40_i32)
@@ -51,15 +55,15 @@ pub async fn top_graph_date(caption: String, scores: &mut [Score]) -> Result<Vec
             .disable_x_mesh()
             .y_label_formatter(&|pp| format!("{pp:.0}pp"))
             .x_label_formatter(&|datetime| datetime.date().to_string())
-            .label_style(("sans-serif", 16_i32, &WHITE))
-            .bold_line_style(WHITE.mix(0.3))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 16_i32, &theme.text))
+            .bold_line_style(theme.grid.mix(0.3))
+            .axis_style(theme.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &theme.text))
             .draw()
             .wrap_err("failed to draw mesh")?;
 
-        let point_style = RGBColor(2, 186, 213).mix(0.7).filled();
-        let border_style = WHITE.mix(0.9).stroke_width(1);
+        let point_style = theme.area.mix(0.7).filled();
+        let border_style = theme.text.mix(0.9).stroke_width(1);
 
         let iter = scores.iter().filter_map(|s| Some((s.ended_at, s.pp?)));
 
@@ -87,11 +91,11 @@ pub async fn top_graph_date(caption: String, scores: &mut [Score]) -> Result<Vec
 
         chart
             .configure_series_labels()
-            .border_style(WHITE.mix(0.6).stroke_width(1))
-            .background_style(RGBColor(7, 23, 17))
+            .border_style(theme.legend_border.mix(0.6).stroke_width(1))
+            .background_style(theme.legend_background)
             .position(SeriesLabelPosition::MiddleLeft)
             .legend_area_size(0_i32)
-            .label_font(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .label_font(("sans-serif", 16_i32, FontStyle::Bold, &theme.text))
             .draw()
             .wrap_err("failed to draw legend")?;
     }
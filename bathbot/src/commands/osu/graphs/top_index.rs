@@ -2,16 +2,16 @@ use eyre::{ContextCompat, Result, WrapErr};
 use plotters::{
     prelude::{ChartBuilder, EmptyElement, IntoDrawingArea, SeriesLabelPosition},
     series::AreaSeries,
-    style::{Color, RGBColor, WHITE},
+    style::Color,
 };
 use plotters_backend::FontStyle;
 use plotters_skia::SkiaBackend;
 use rosu_v2::prelude::Score;
 use skia_safe::{EncodedImageFormat, surfaces};
 
-use super::{H, W};
+use super::{H, Theme, W};
 
-pub async fn top_graph_index(caption: String, scores: &[Score]) -> Result<Vec<u8>> {
+pub async fn top_graph_index(caption: String, scores: &[Score], theme: &Theme) -> Result<Vec<u8>> {
     let max = scores.first().and_then(|s| s.pp).unwrap_or(0.0);
     let max_adj = max + 5.0;
 
@@ -24,11 +24,11 @@ pub async fn top_graph_index(caption: String, scores: &[Score]) -> Result<Vec<u8
     {
         let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = theme.background;
         root.fill(&background)
             .wrap_err("failed to fill background")?;
 
-        let caption_style = ("sans-serif", 25_i32, FontStyle::Bold, &WHITE);
+        let caption_style = ("sans-serif", 25_i32, FontStyle::Bold, &theme.text);
 
         let mut chart = ChartBuilder::on(&root)
             .x_label_area_size(40_i32)
@@ -42,15 +42,15 @@ pub async fn top_graph_index(caption: String, scores: &[Score]) -> Result<Vec<u8
         chart
             .configure_mesh()
             .y_label_formatter(&|pp| format!("{pp:.0}pp"))
-            .label_style(("sans-serif", 16_i32, &WHITE))
-            .bold_line_style(WHITE.mix(0.3))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 16_i32, &theme.text))
+            .bold_line_style(theme.grid.mix(0.3))
+            .axis_style(theme.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &theme.text))
             .draw()
             .wrap_err("failed to draw mesh")?;
 
-        let area_style = RGBColor(2, 186, 213).mix(0.7).filled();
-        let border_style = RGBColor(0, 208, 138).stroke_width(3);
+        let area_style = theme.area.mix(0.7).filled();
+        let border_style = theme.border.stroke_width(3);
         let iter = (1..).zip(scores).filter_map(|(i, s)| Some((i, s.pp?)));
         let series = AreaSeries::new(iter, 0.0, area_style).border_style(border_style);
 
@@ -66,7 +66,7 @@ pub async fn top_graph_index(caption: String, scores: &[Score]) -> Result<Vec<u8
             .filter_map(|(i, s)| Some((i, s.pp?)))
             .take(0);
 
-        let series = AreaSeries::new(iter, 0.0, WHITE).border_style(WHITE);
+        let series = AreaSeries::new(iter, 0.0, theme.text).border_style(theme.text);
 
         chart
             .draw_series(series)
@@ -76,11 +76,11 @@ pub async fn top_graph_index(caption: String, scores: &[Score]) -> Result<Vec<u8
 
         chart
             .configure_series_labels()
-            .border_style(WHITE.mix(0.6).stroke_width(1))
-            .background_style(RGBColor(7, 23, 17))
+            .border_style(theme.legend_border.mix(0.6).stroke_width(1))
+            .background_style(theme.legend_background)
             .position(SeriesLabelPosition::UpperRight)
             .legend_area_size(0_i32)
-            .label_font(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .label_font(("sans-serif", 16_i32, FontStyle::Bold, &theme.text))
             .draw()
             .wrap_err("failed to draw legend")?;
     }
@@ -12,7 +12,7 @@ use plotters::{
         IntoSegmentedCoord, Ranged, Rectangle, SegmentValue, SeriesLabelPosition,
     },
     series::PointSeries,
-    style::{Color, RGBColor, TextStyle, WHITE},
+    style::{Color, RGBColor, TextStyle},
 };
 use plotters_backend::FontStyle;
 use plotters_skia::SkiaBackend;
@@ -20,12 +20,13 @@ use rosu_v2::prelude::Score;
 use skia_safe::{EncodedImageFormat, Surface, surfaces};
 use time::{Duration, OffsetDateTime, UtcOffset};
 
-use crate::commands::osu::graphs::{H, W};
+use crate::commands::osu::graphs::{H, Theme, W};
 
 pub async fn top_graph_time_hour(
     mut caption: String,
     scores: &mut [Score],
     tz: UtcOffset,
+    theme: &Theme,
 ) -> Result<Vec<u8>> {
     fn date_to_value(date: OffsetDateTime) -> u32 {
         date.hour() as u32 * 60 + date.minute() as u32
@@ -61,11 +62,12 @@ pub async fn top_graph_time_hour(
     {
         let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = theme.background;
         root.fill(&background)
             .wrap_err("Failed to fill background")?;
 
-        let caption_style = TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&WHITE);
+        let caption_style =
+            TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&theme.text);
 
         let x_label_area_size = 50;
         let y_label_area_size = 60;
@@ -94,22 +96,22 @@ pub async fn top_graph_time_hour(
             .disable_y_axis()
             .x_labels(24)
             .x_desc("Hour of the day")
-            .label_style(("sans-serif", 16_i32, &WHITE))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 16_i32, &theme.text))
+            .axis_style(theme.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &theme.text))
             .draw()
             .wrap_err("Failed to draw primary bar mesh")?;
 
         chart
             .configure_secondary_axes()
             .y_desc("#  of  plays  set")
-            .label_style(("sans-serif", 16_i32, &WHITE))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 16_i32, &theme.text))
+            .axis_style(theme.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &theme.text))
             .draw()
             .wrap_err("Failed to draw secondary mesh")?;
 
-        let counts = ScoreTimeCounts::<AxisByHour>::new(hours);
+        let counts = ScoreTimeCounts::<AxisByHour>::new(hours, theme.area);
         chart
             .draw_secondary_series(counts)
             .wrap_err("Failed to draw bars")?;
@@ -127,7 +129,7 @@ pub async fn top_graph_time_hour(
             .wrap_err("Failed to build point chart")?
             .set_secondary_coord(0_u32..24 * 60, min_adj..max_adj);
 
-        draw_point_mesh(&mut chart)?;
+        draw_point_mesh(&mut chart, theme)?;
 
         draw_points(
             &mut chart,
@@ -136,6 +138,7 @@ pub async fn top_graph_time_hour(
             max,
             min,
             (W as f32 / 4.5) as i32,
+            theme,
         )?;
     }
 
@@ -146,6 +149,7 @@ pub async fn top_graph_time_day(
     mut caption: String,
     scores: &mut [Score],
     tz: UtcOffset,
+    theme: &Theme,
 ) -> Result<Vec<u8>> {
     fn date_to_value(date: OffsetDateTime) -> u32 {
         date.weekday() as u32 * 24 * 60 + date.hour() as u32 * 60 + date.minute() as u32
@@ -181,11 +185,12 @@ pub async fn top_graph_time_day(
     {
         let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = theme.background;
         root.fill(&background)
             .wrap_err("Failed to fill background")?;
 
-        let caption_style = TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&WHITE);
+        let caption_style =
+            TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&theme.text);
 
         let x_label_area_size = 35;
         let y_label_area_size = 60;
@@ -213,22 +218,22 @@ pub async fn top_graph_time_day(
             .disable_y_mesh()
             .disable_y_axis()
             .x_labels(7)
-            .label_style(("sans-serif", 16_i32, &WHITE))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 16_i32, &theme.text))
+            .axis_style(theme.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &theme.text))
             .draw()
             .wrap_err("Failed to draw primary bar mesh")?;
 
         chart
             .configure_secondary_axes()
             .y_desc("#  of  plays  set")
-            .label_style(("sans-serif", 16_i32, &WHITE))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 16_i32, &theme.text))
+            .axis_style(theme.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &theme.text))
             .draw()
             .wrap_err("Failed to draw secondary mesh")?;
 
-        let counts = ScoreTimeCounts::<AxisByDay>::new(days);
+        let counts = ScoreTimeCounts::<AxisByDay>::new(days, theme.area);
         chart
             .draw_secondary_series(counts)
             .wrap_err("Failed to draw bars")?;
@@ -246,7 +251,7 @@ pub async fn top_graph_time_day(
             .wrap_err("Failed to build point chart")?
             .set_secondary_coord(0_u32..7 * 24 * 60, min_adj..max_adj);
 
-        draw_point_mesh(&mut chart)?;
+        draw_point_mesh(&mut chart, theme)?;
 
         draw_points(
             &mut chart,
@@ -255,6 +260,7 @@ pub async fn top_graph_time_day(
             max,
             min,
             (W as f32 / 6.5) as i32,
+            theme,
         )?;
     }
 
@@ -276,16 +282,16 @@ type Chart<'a> = DualCoordChartContext<
     Cartesian2d<RangedCoordu32, RangedCoordf32>,
 >;
 
-fn draw_point_mesh(chart: &mut Chart<'_>) -> Result<()> {
+fn draw_point_mesh(chart: &mut Chart<'_>, theme: &Theme) -> Result<()> {
     chart
         .configure_mesh()
         .disable_x_mesh()
         .disable_x_axis()
         .y_label_formatter(&|pp| format!("{pp:.0}pp"))
-        .label_style(("sans-serif", 16_i32, &WHITE))
-        .bold_line_style(WHITE.mix(0.3))
-        .axis_style(RGBColor(7, 18, 14))
-        .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+        .label_style(("sans-serif", 16_i32, &theme.text))
+        .bold_line_style(theme.grid.mix(0.3))
+        .axis_style(theme.axis)
+        .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &theme.text))
         .draw()
         .wrap_err("Failed to draw point mesh")
 }
@@ -297,18 +303,19 @@ fn draw_points(
     max_pp: f32,
     min_pp: f32,
     legend_pos: i32,
+    theme: &Theme,
 ) -> Result<()> {
     // Draw secondary axis just to hide its values so that
     // the left hand values aren't displayed instead
     chart
         .configure_secondary_axes()
-        .label_style(("", 16_i32, &WHITE.mix(0.0)))
-        .axis_style(WHITE.mix(0.0))
+        .label_style(("", 16_i32, &theme.text.mix(0.0)))
+        .axis_style(theme.text.mix(0.0))
         .draw()
         .wrap_err("Failed to draw secondary points")?;
 
-    let point_style = RGBColor(2, 186, 213).mix(0.7).filled();
-    let border_style = WHITE.mix(0.9).stroke_width(1);
+    let point_style = theme.area.mix(0.7).filled();
+    let border_style = theme.text.mix(0.9).stroke_width(1);
 
     let iter = scores
         .iter()
@@ -340,11 +347,11 @@ fn draw_points(
 
     chart
         .configure_series_labels()
-        .border_style(WHITE.mix(0.6).stroke_width(1))
-        .background_style(RGBColor(7, 23, 17))
+        .border_style(theme.legend_border.mix(0.6).stroke_width(1))
+        .background_style(theme.legend_background)
         .position(SeriesLabelPosition::Coordinate(legend_pos, 10))
         .legend_area_size(0_i32)
-        .label_font(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+        .label_font(("sans-serif", 16_i32, FontStyle::Bold, &theme.text))
         .draw()
         .wrap_err("Failed to draw legend")?;
 
@@ -410,14 +417,16 @@ impl TimeAxis for AxisByDay {
 struct ScoreTimeCounts<A: TimeAxis> {
     counts: A::Counts,
     idx: usize,
+    color: RGBColor,
     axis: PhantomData<A>,
 }
 
 impl<A: TimeAxis> ScoreTimeCounts<A> {
-    fn new(counts: A::Counts) -> Self {
+    fn new(counts: A::Counts, color: RGBColor) -> Self {
         Self {
             counts,
             idx: 0,
+            color,
             axis: PhantomData,
         }
     }
@@ -435,7 +444,7 @@ impl<A: TimeAxis> Iterator for ScoreTimeCounts<A> {
         let bot_right = (A::right_segment(value), 0);
 
         let mix = if count > 0 { 0.5 } else { 0.0 };
-        let style = RGBColor(0, 126, 153).mix(mix).filled();
+        let style = self.color.mix(mix).filled();
 
         let mut rect = Rectangle::new([top_left, bot_right], style);
         rect.set_margin(0, 1, 2, 2);
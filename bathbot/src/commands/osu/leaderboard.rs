@@ -1,17 +1,17 @@
 use std::{borrow::Cow, cmp::Reverse, collections::HashMap};
 
 use bathbot_macros::{HasMods, SlashCommand, command};
-use bathbot_model::command_fields::GameModeOption;
-use bathbot_psql::model::configs::ScoreData;
+use bathbot_model::{command_fields::GameModeOption, rosu_v2::score::MapLeaderboardScore};
+use bathbot_psql::model::configs::{ScoreData, resolve};
 use bathbot_util::{
     IntHasher, ScoreExt,
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    i18n::ErrorKey,
     matcher,
     osu::{MapIdType, ModSelection},
 };
 use eyre::{Report, Result};
 use rosu_v2::prelude::{
-    BeatmapUserScore, GameMode, GameMods, GameModsIntermode, Grade, OsuError, Score,
+    BeatmapUserScore, GameMode, GameMods, GameModsIntermode, Grade, OsuError, RankStatus, Score,
     ScoreStatistics, Username,
 };
 use time::OffsetDateTime;
@@ -19,7 +19,10 @@ use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand,
 use twilight_model::{
     channel::Message,
     guild::Permissions,
-    id::{Id, marker::UserMarker},
+    id::{
+        Id,
+        marker::{GuildMarker, UserMarker},
+    },
 };
 
 use super::{HasMods, ModsResult};
@@ -378,23 +381,22 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
             return orig.error(content).await;
         }
         Err(MapError::Report(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
     };
 
-    let score_data = match args.score_data.or(config.score_data) {
-        Some(score_data) => score_data,
-        None => match orig.guild_id() {
-            Some(guild_id) => Context::guild_config()
+    let guild_score_data = match orig.guild_id() {
+        Some(guild_id) => {
+            Context::guild_config()
                 .peek(guild_id, |config| config.score_data)
                 .await
-                .unwrap_or_default(),
-            None => Default::default(),
-        },
+        }
+        None => None,
     };
 
+    let mut score_data = resolve::score_data(args.score_data, config.score_data, guild_score_data);
     let legacy_scores = score_data.is_legacy();
 
     let specify_mods = match mods {
@@ -415,7 +417,7 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
 
     const SCORE_COUNT: usize = 100;
 
-    let scores_fut = Context::osu_scores().map_leaderboard(
+    let scores_fut = Context::redis().map_leaderboard(
         map_id,
         mode,
         specify_mods.clone(),
@@ -431,7 +433,23 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
         legacy_scores,
     );
 
-    let (scores_res, user_res, attrs) = tokio::join!(scores_fut, user_fut, attrs_fut);
+    let (mut scores_res, mut user_res, attrs) = tokio::join!(scores_fut, user_fut, attrs_fut);
+
+    let fallback = specify_mods.is_none()
+        && matches!(
+            scores_res,
+            Ok(ref scores) if falls_back_to_lazer(score_data, map.status(), !scores.is_empty())
+        );
+
+    if fallback {
+        score_data = ScoreData::Lazer;
+
+        let scores_fut =
+            Context::redis().map_leaderboard(map_id, mode, None, SCORE_COUNT as u32, false);
+        let user_fut = get_user_score(config.osu, map_id, mode, None, false);
+
+        (scores_res, user_res) = tokio::join!(scores_fut, user_fut);
+    }
 
     let mut avatar_urls = HashMap::with_capacity_and_hasher(SCORE_COUNT, IntHasher);
 
@@ -440,20 +458,15 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
             .into_iter()
             .enumerate()
             .map(|(i, mut score)| {
-                let username = match score.user.take() {
-                    Some(user) => {
-                        avatar_urls.insert(score.id, user.avatar_url.into_boxed_str());
-
-                        user.username
-                    }
-                    None => format!("<user {}>", score.user_id).into(),
-                };
+                if let Some(avatar_url) = score.avatar_url.take() {
+                    avatar_urls.insert(score.score_id, avatar_url.into_boxed_str());
+                }
 
-                LeaderboardScore::new(score.user_id, username, score, i + 1)
+                LeaderboardScore::from_map_leaderboard(score, i + 1)
             })
             .collect(),
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
 
             return Err(err.wrap_err("Failed to get leaderboard"));
         }
@@ -501,9 +514,15 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
         max_combo = attrs.max_combo();
     }
 
+    let anomalies = mark_anomalies(orig.guild_id(), &mut scores, &map).await;
+
     args.sort.sort(&mut scores, &map, score_data).await;
     args.sort.push_content(&mut content);
 
+    if fallback {
+        content.push_str("\nThis map has no stable leaderboard, showing lazer scores instead");
+    }
+
     let first_place_icon = scores.first().and_then(|s| avatar_urls.remove(&s.score_id));
 
     let pagination = LeaderboardPagination::builder()
@@ -514,6 +533,7 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
         .author_data(user_score)
         .first_place_icon(first_place_icon)
         .score_data(score_data)
+        .anomalies(anomalies)
         .content(content.into_boxed_str())
         .msg_owner(owner)
         .build();
@@ -552,6 +572,21 @@ async fn get_map_id(orig: &CommandOrigin<'_>, map: Option<MapIdType>) -> Result<
     }
 }
 
+/// Whether an empty stable leaderboard should be replaced by the lazer
+/// leaderboard.
+///
+/// Not every map with a leaderboard provides a stable variant of it.
+fn falls_back_to_lazer(score_data: ScoreData, status: RankStatus, found_scores: bool) -> bool {
+    score_data.is_legacy() && has_leaderboard(status) && !found_scores
+}
+
+fn has_leaderboard(status: RankStatus) -> bool {
+    matches!(
+        status,
+        RankStatus::Ranked | RankStatus::Approved | RankStatus::Qualified | RankStatus::Loved
+    )
+}
+
 async fn get_user_score(
     osu_id: Option<u32>,
     map_id: u32,
@@ -602,7 +637,11 @@ pub struct LeaderboardScore {
     pub score_id: u64,
     pub is_legacy: bool,
     pub set_on_lazer: bool,
+    /// The pp as provided by the osu!api
+    pub api_pp: Option<f32>,
     pub pps: Option<PpData>,
+    /// Whether the score's data looks suspicious
+    pub anomalous: bool,
 }
 
 impl LeaderboardScore {
@@ -623,7 +662,32 @@ impl LeaderboardScore {
             classic_score: score.classic_score,
             ended_at: score.ended_at,
             score_id: score.id,
+            api_pp: score.pp,
             pps: None,
+            anomalous: false,
+        }
+    }
+
+    pub fn from_map_leaderboard(score: MapLeaderboardScore, pos: usize) -> Self {
+        Self {
+            user_id: score.user_id,
+            username: score.username,
+            pos,
+            is_legacy: score.is_legacy,
+            set_on_lazer: score.set_on_lazer,
+            grade: if score.passed { score.grade } else { Grade::F },
+            accuracy: score.accuracy,
+            statistics: score.statistics,
+            mode: score.mode,
+            mods: score.mods,
+            combo: score.max_combo,
+            score: score.score,
+            classic_score: score.classic_score,
+            ended_at: score.ended_at,
+            score_id: score.score_id,
+            api_pp: score.pp,
+            pps: None,
+            anomalous: false,
         }
     }
 }
@@ -664,3 +728,178 @@ pub struct LeaderboardUserScore {
     pub discord_id: Id<UserMarker>,
     pub score: LeaderboardScore,
 }
+
+/// The pp of the osu!api and the recalculated pp may differ by at most this
+/// factor before a score is considered suspicious.
+const PP_DEVIATION_FACTOR: f32 = 3.0;
+
+/// Only scores up to this leaderboard position are checked for deviating pp.
+const PP_DEVIATION_POSITIONS: usize = 5;
+
+/// Mark scores whose data looks suspicious.
+///
+/// Skipped if the guild disabled it. Returns whether any score was marked.
+pub async fn mark_anomalies(
+    guild_id: Option<Id<GuildMarker>>,
+    scores: &mut [LeaderboardScore],
+    map: &OsuMap,
+) -> bool {
+    if let Some(guild_id) = guild_id {
+        let enabled = Context::guild_config()
+            .peek(guild_id, |config| config.score_anomalies.unwrap_or(true))
+            .await;
+
+        if !enabled {
+            return false;
+        }
+    }
+
+    let status = map.status();
+    let ranked_date = map.ranked_date();
+    let mut any = false;
+
+    for score in scores.iter_mut() {
+        let mut anomalous = predates_ranking(score.ended_at, status, ranked_date)
+            || full_acc_with_misses(score.accuracy, score.statistics.miss);
+
+        if !anomalous
+            && score.pos <= PP_DEVIATION_POSITIONS
+            && let Some(api_pp) = score.api_pp
+        {
+            anomalous = pp_deviates(api_pp, score.pp(map).await.pp);
+        }
+
+        score.anomalous = anomalous;
+        any |= anomalous;
+    }
+
+    any
+}
+
+/// Whether the score was set before the map got its leaderboard.
+///
+/// Only the ranked date of qualified and loved maps marks the opening of the
+/// leaderboard. Ranked maps keep the scores of their qualification so theirs
+/// comes too late, and the ranked date of maps without leaderboard is
+/// meaningless, e.g. because it is stale after the map got unranked.
+fn predates_ranking(
+    ended_at: OffsetDateTime,
+    status: RankStatus,
+    ranked_date: Option<OffsetDateTime>,
+) -> bool {
+    matches!(status, RankStatus::Qualified | RankStatus::Loved)
+        && ranked_date.is_some_and(|ranked_date| ended_at < ranked_date)
+}
+
+/// Whether the score has full accuracy despite misses.
+fn full_acc_with_misses(accuracy: f32, misses: u32) -> bool {
+    accuracy >= 100.0 && misses > 0
+}
+
+/// Whether the osu!api's pp and the recalculated pp differ by more than
+/// [`PP_DEVIATION_FACTOR`] in either direction.
+///
+/// Scores without pp e.g. on loved maps are not considered.
+fn pp_deviates(api_pp: f32, calculated_pp: f32) -> bool {
+    if api_pp <= 0.0 || calculated_pp <= 0.0 {
+        return false;
+    }
+
+    let ratio = api_pp / calculated_pp;
+
+    !(PP_DEVIATION_FACTOR.recip()..=PP_DEVIATION_FACTOR).contains(&ratio)
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn user_config_decides_leaderboard_variant() {
+        let stable = Some(ScoreData::Stable);
+        let lazer = Some(ScoreData::Lazer);
+
+        // Without an argument, the user's config decides which leaderboard is requested
+        assert!(resolve::score_data(None, stable, lazer).is_legacy());
+        assert!(!resolve::score_data(lazer, stable, None).is_legacy());
+        assert!(!resolve::score_data(None, None, None).is_legacy());
+    }
+
+    #[test]
+    fn fall_back_to_lazer_leaderboard() {
+        let stable = ScoreData::Stable;
+        let lazer = ScoreData::Lazer;
+
+        assert!(falls_back_to_lazer(stable, RankStatus::Ranked, false));
+        assert!(falls_back_to_lazer(stable, RankStatus::Loved, false));
+        assert!(!falls_back_to_lazer(stable, RankStatus::Ranked, true));
+        assert!(!falls_back_to_lazer(lazer, RankStatus::Ranked, false));
+
+        // Maps without any leaderboard have nothing to fall back to
+        assert!(!falls_back_to_lazer(stable, RankStatus::Graveyard, false));
+        assert!(!falls_back_to_lazer(stable, RankStatus::Pending, false));
+    }
+
+    #[test]
+    fn anomaly_before_ranking() {
+        let ranked = OffsetDateTime::from_unix_timestamp(1_577_836_800).unwrap();
+
+        let before = ranked - Duration::DAY;
+        let after = ranked + Duration::DAY;
+
+        assert!(predates_ranking(
+            before,
+            RankStatus::Qualified,
+            Some(ranked)
+        ));
+        assert!(!predates_ranking(
+            ranked,
+            RankStatus::Qualified,
+            Some(ranked)
+        ));
+        assert!(!predates_ranking(
+            after,
+            RankStatus::Qualified,
+            Some(ranked)
+        ));
+        assert!(predates_ranking(before, RankStatus::Loved, Some(ranked)));
+
+        // Scores from the qualification carry over to the ranked leaderboard
+        assert!(!predates_ranking(before, RankStatus::Ranked, Some(ranked)));
+        assert!(!predates_ranking(
+            before,
+            RankStatus::Approved,
+            Some(ranked)
+        ));
+
+        // Unranked maps have no date to compare against
+        assert!(!predates_ranking(ranked, RankStatus::Pending, None));
+
+        // Neither do maps whose ranked date is left over from before they
+        // lost their leaderboard
+        let graveyard = RankStatus::Graveyard;
+        assert!(!predates_ranking(before, graveyard, Some(ranked)));
+    }
+
+    #[test]
+    fn anomaly_full_acc_with_misses() {
+        assert!(full_acc_with_misses(100.0, 1));
+        assert!(!full_acc_with_misses(100.0, 0));
+        assert!(!full_acc_with_misses(99.5, 3));
+    }
+
+    #[test]
+    fn anomaly_pp_deviation() {
+        assert!(pp_deviates(1000.0, 300.0));
+        assert!(pp_deviates(100.0, 301.0));
+        assert!(!pp_deviates(300.0, 100.0));
+        assert!(!pp_deviates(100.0, 300.0));
+        assert!(!pp_deviates(420.0, 400.0));
+
+        // Missing pp are no indication
+        assert!(!pp_deviates(0.0, 500.0));
+        assert!(!pp_deviates(500.0, 0.0));
+    }
+}
@@ -4,7 +4,7 @@ use eyre::Result;
 use twilight_interactions::command::CreateCommand;
 
 use crate::{
-    commands::utility::{Config, ConfigLink, config},
+    commands::utility::{ConfigEdit, ConfigLink, config},
     util::{ChannelExt, interaction::InteractionCommand},
 };
 
@@ -13,14 +13,14 @@ use crate::{
     name = "link",
     desc = "Link your discord to an osu! profile",
     help = "Link your discord to an osu! profile.\n\
-    To unlink, use the `/config` command.\n\
-    To link your discord to a twitch account you can also use the `/config` command."
+    To unlink, use the `/config edit` command.\n\
+    To link your discord to a twitch account you can also use the `/config edit` command."
 )]
 #[flags(EPHEMERAL)]
 pub struct Link;
 
 async fn slash_link(command: InteractionCommand) -> Result<()> {
-    let mut args = Config::default();
+    let mut args = ConfigEdit::default();
     args.osu = Some(ConfigLink::Link);
 
     config(command, args).await
@@ -3,12 +3,13 @@ use std::{borrow::Cow, cmp::Ordering, fmt::Write};
 use bathbot_macros::{HasMods, SlashCommand, command};
 use bathbot_util::{
     MessageOrigin,
-    constants::OSU_API_ISSUE,
+    i18n::ErrorKey,
     matcher,
     osu::{MapIdType, ModSelection},
 };
 use eyre::{Report, Result};
 use rosu_v2::prelude::{GameMode, GameModsIntermode, OsuError};
+use tracing::Instrument;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::{channel::Message, guild::Permissions};
 
@@ -19,7 +20,7 @@ use crate::{
         ActiveMessages,
         impls::{MapPagination, SingleScorePagination},
     },
-    commands::osu::map_strains_graph,
+    commands::osu::{Theme, map_strains_graph},
     core::commands::{CommandOrigin, prefix::Args},
     util::{ChannelExt, InteractionCommandExt, interaction::InteractionCommand, osu::MapOrScore},
 };
@@ -57,6 +58,12 @@ pub struct Map<'a> {
     hp: Option<f64>,
     #[command(desc = "Specify a custom clock rate that overrides mods")]
     clock_rate: Option<f64>,
+    #[command(
+        desc = "Show a timeline of the map's object density",
+        help = "Show a sparkline of the amount of objects per 10 seconds across the map's length.\n\
+        Mods that change the clock rate are taken into account."
+    )]
+    density: Option<bool>,
 }
 
 #[derive(HasMods)]
@@ -64,6 +71,7 @@ struct MapArgs<'a> {
     map: Option<MapIdType>,
     mods: Option<Cow<'a, str>>,
     attrs: CustomAttrs,
+    density: bool,
 }
 
 #[derive(Default, Debug)]
@@ -169,6 +177,7 @@ impl<'m> MapArgs<'m> {
             map,
             mods,
             attrs: CustomAttrs::default(),
+            density: false,
         })
     }
 }
@@ -185,6 +194,7 @@ impl<'a> TryFrom<Map<'a>> for MapArgs<'a> {
             cs,
             hp,
             clock_rate,
+            density,
         } = args;
 
         let map = match map.map(|arg| {
@@ -211,7 +221,12 @@ impl<'a> TryFrom<Map<'a>> for MapArgs<'a> {
             ..Default::default()
         };
 
-        Ok(Self { map, mods, attrs })
+        Ok(Self {
+            map,
+            mods,
+            attrs,
+            density: density.unwrap_or(false),
+        })
     }
 }
 
@@ -264,7 +279,12 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
         }
     };
 
-    let MapArgs { map, attrs, .. } = args;
+    let MapArgs {
+        map,
+        attrs,
+        density,
+        ..
+    } = args;
 
     let map_id = if let Some(id) = map {
         id
@@ -315,14 +335,14 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("failed to get mapset"));
         }
     };
 
     let mapset_clone = mapset.clone();
-    tokio::spawn(async move { Context::osu_map().store(&mapset_clone).await });
+    tokio::spawn(async move { Context::osu_map().store(&mapset_clone).await }.in_current_span());
 
     let Some(mut maps) = mapset.maps.take().filter(|maps| !maps.is_empty()) else {
         return orig.error("The mapset has no maps").await;
@@ -370,12 +390,14 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
         }
     };
 
+    let theme = Theme::of_user(orig.user_id()?).await;
+
     let graph = match Context::osu_map().pp_map(map_id).await {
         Ok(map) => {
             let w = SingleScorePagination::IMAGE_W;
             let h = SingleScorePagination::IMAGE_H;
 
-            match map_strains_graph(&map, mods_with_mode, &mapset.covers.cover, w, h).await {
+            match map_strains_graph(&map, mods_with_mode, &mapset.covers.cover, w, h, theme).await {
                 Ok(graph) => Some(graph),
                 Err(err) => {
                     warn!(?err, "Failed to create graph");
@@ -400,6 +422,7 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
         .maps(maps.into_boxed_slice())
         .mods(mods)
         .attrs(attrs)
+        .density(density)
         .origin(origin)
         .content(content.unwrap_or_default().into_boxed_str())
         .msg_owner(orig.user_id()?)
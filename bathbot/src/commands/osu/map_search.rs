@@ -2,7 +2,7 @@ use std::{collections::BTreeMap, ops::Not};
 
 use bathbot_macros::{SlashCommand, command};
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_util::constants::OSU_API_ISSUE;
+use bathbot_util::i18n::ErrorKey;
 use eyre::{Report, Result};
 use rosu_v2::prelude::{
     BeatmapsetExtended, BeatmapsetSearchResult, BeatmapsetSearchSort, Genre, Language, Osu,
@@ -621,7 +621,7 @@ async fn search(orig: CommandOrigin<'_>, args: Search) -> Result<()> {
     let mut search_result = match args.request(Context::osu()).await {
         Ok(response) => response,
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("Failed to get search results"));
         }
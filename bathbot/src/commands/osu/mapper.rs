@@ -5,20 +5,17 @@ use std::{
 
 use bathbot_macros::{HasName, SlashCommand, command};
 use bathbot_model::{command_fields::GameModeOption, embed_builder::SettingsImage};
-use bathbot_psql::model::configs::{GuildConfig, ListSize, ScoreData};
-use bathbot_util::{CowUtils, constants::GENERAL_ISSUE, matcher};
+use bathbot_psql::model::configs::{GuildConfig, ListSize, ScoreData, resolve};
+use bathbot_util::{CowUtils, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
-use rosu_v2::{
-    prelude::{GameMode, OsuError, Score},
-    request::UserId,
-};
+use rosu_v2::prelude::{GameMode, OsuError, Score};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::{
     guild::Permissions,
     id::{Id, marker::UserMarker},
 };
 
-use super::{ScoreOrder, map_strains_graph, require_link, user_not_found};
+use super::{ScoreOrder, Theme, map_strains_graph, require_link, skin_and_user_id, user_not_found};
 use crate::{
     Context,
     active::{
@@ -63,7 +60,7 @@ pub struct Mapper<'a> {
         desc = "Size of the embed",
         help = "Size of the embed.\n\
         `Condensed` shows 10 scores, `Detailed` shows 5, and `Single` shows 1.\n\
-        The default can be set with the `/config` command."
+        The default can be set with the `/config edit` command."
     )]
     size: Option<ListSize>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
@@ -219,7 +216,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
     let mut config = match Context::user_config().with_osu_id(msg_owner).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -231,12 +228,11 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
         .or(config.mode)
         .unwrap_or(GameMode::Osu);
 
-    let user_id = match user_id!(orig, args) {
-        Some(user_id) => user_id,
-        None => match config.osu.take() {
-            Some(user_id) => UserId::Id(user_id),
-            None => return require_link(&orig).await,
-        },
+    let user_id = user_id!(orig, args);
+    let (skin, user_id) = skin_and_user_id(&mut config, user_id);
+
+    let Some(user_id) = user_id else {
+        return require_link(&orig).await;
     };
 
     let GuildValues {
@@ -252,11 +248,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
         None => GuildValues::default(),
     };
 
-    let score_data = args
-        .score_data
-        .or(config.score_data)
-        .or(guild_score_data)
-        .unwrap_or_default();
+    let score_data = resolve::score_data(args.score_data, config.score_data, guild_score_data);
 
     let legacy_scores = score_data.is_legacy();
 
@@ -284,7 +276,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         (Err(err), _) | (_, Err(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get mapper, user, or scores");
 
             return Err(err);
@@ -297,11 +289,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
     let username = user.username.as_str();
     let settings = config.score_embed.unwrap_or_default();
 
-    let mut with_render = match (guild_render_button, config.render_button) {
-        (None | Some(true), None) => true,
-        (None | Some(true), Some(with_render)) => with_render,
-        (Some(false), _) => false,
-    };
+    let mut with_render = resolve::render_button(config.render_button, guild_render_button);
 
     with_render &= settings.buttons.render
         && mode == GameMode::Osu
@@ -312,7 +300,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
         match process_scores(scores, mapper_id, args.sort, with_render, legacy_scores).await {
             Ok(entries) => entries,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err.wrap_err("failed to process scores"));
             }
@@ -357,11 +345,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
 
     let sort_by = args.sort.unwrap_or(ScoreOrder::Pp).into();
 
-    let list_size = args
-        .size
-        .or(config.list_size)
-        .or(guild_list_size)
-        .unwrap_or_default();
+    let list_size = resolve::list_size(args.size, config.list_size, guild_list_size);
 
     let entries = entries.into_boxed_slice();
 
@@ -381,6 +365,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
                         entry.map.cover(),
                         SingleScorePagination::IMAGE_W,
                         SingleScorePagination::IMAGE_H,
+                        Theme::get(config.graph_theme),
                     );
 
                     match fut.await {
@@ -395,10 +380,13 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
                 Some(_) | None => None,
             };
 
-            let pagination = SingleScorePagination::new(
+            let mut pagination = SingleScorePagination::new(
                 &user, entries, settings, score_data, msg_owner, content,
             );
 
+            pagination.set_timestamp_style(config.timestamp_style);
+            pagination.set_skin(skin);
+
             return ActiveMessages::builder(pagination)
                 .start_by_update(true)
                 .attachment(graph)
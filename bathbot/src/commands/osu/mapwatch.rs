@@ -0,0 +1,313 @@
+use std::{borrow::Cow, fmt::Write};
+
+use bathbot_macros::SlashCommand;
+use bathbot_psql::model::osu::MapsetWatch;
+use bathbot_util::{
+    Authored, CowUtils, EmbedBuilder, MessageBuilder, constants::OSU_BASE, i18n::ErrorKey, matcher,
+};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{OsuError, RankStatus};
+use twilight_interactions::command::{
+    AutocompleteValue, CommandModel, CommandOption, CreateCommand, CreateOption,
+};
+use twilight_model::application::command::{CommandOptionChoice, CommandOptionChoiceValue};
+
+use crate::{
+    core::{Context, commands::interaction::InteractionCommands},
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+/// Maximum amount of mapsets a single user can watch.
+const WATCH_LIMIT: usize = 25;
+
+#[derive(CreateCommand, SlashCommand)]
+#[command(
+    name = "mapwatch",
+    desc = "Get notified when a mapset changes its ranked status",
+    help = "Get notified when a mapset changes its ranked status, \
+    e.g. when it gets ranked or disqualified.\n\
+    Mapsets are checked once per hour.\n\
+    Watches are removed automatically 60 days after the mapset ranked \
+    or when the mapset is deleted."
+)]
+#[flags(EPHEMERAL)]
+#[allow(dead_code)]
+pub enum MapWatch {
+    #[command(name = "add")]
+    Add(MapWatchAdd),
+    #[command(name = "list")]
+    List(MapWatchList),
+    #[command(name = "remove")]
+    Remove(MapWatchRemove),
+}
+
+#[derive(CommandModel)]
+enum MapWatch_<'a> {
+    #[command(name = "add")]
+    Add(MapWatchAdd),
+    #[command(name = "list")]
+    List(MapWatchList),
+    #[command(name = "remove")]
+    Remove(MapWatchRemove_<'a>),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "add", desc = "Watch a mapset for ranked status changes")]
+pub struct MapWatchAdd {
+    #[command(desc = "Specify a mapset url or mapset id")]
+    mapset: String,
+    #[command(desc = "Choose where to be notified, defaults to DMs")]
+    notify: Option<MapWatchNotify>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption, Default)]
+pub enum MapWatchNotify {
+    #[option(name = "Direct message", value = "dm")]
+    #[default]
+    Dm,
+    #[option(name = "This channel", value = "channel")]
+    Channel,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List all mapsets you're watching")]
+pub struct MapWatchList;
+
+#[derive(CreateCommand)]
+#[command(name = "remove", desc = "Stop watching a mapset")]
+#[allow(dead_code)]
+pub struct MapWatchRemove {
+    #[command(autocomplete = true, desc = "Specify a watched mapset")]
+    mapset: String,
+}
+
+#[derive(CommandModel)]
+#[command(autocomplete = true)]
+struct MapWatchRemove_<'a> {
+    mapset: AutocompleteValue<Cow<'a, str>>,
+}
+
+pub async fn slash_mapwatch(mut command: InteractionCommand) -> Result<()> {
+    match MapWatch_::from_interaction(command.input_data())? {
+        MapWatch_::Add(args) => add(&command, args).await,
+        MapWatch_::List(_) => list(&command).await,
+        MapWatch_::Remove(args) => match args.mapset {
+            AutocompleteValue::None => remove_autocomplete(&command, String::new()).await,
+            AutocompleteValue::Focused(name) => remove_autocomplete(&command, name).await,
+            AutocompleteValue::Completed(mapset) => remove(&command, &mapset).await,
+        },
+    }
+}
+
+async fn add(command: &InteractionCommand, args: MapWatchAdd) -> Result<()> {
+    let Some(mapset_id) = matcher::get_osu_mapset_id(&args.mapset) else {
+        let content = "Failed to parse mapset url or id.\n\
+            Be sure it's a valid mapset url or id.";
+        command.error(content).await?;
+
+        return Ok(());
+    };
+
+    let user = command.user_id()?;
+
+    let watches = match Context::mapset_watches().get(user).await {
+        Ok(watches) => watches,
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let already_watching = watches.iter().any(|watch| watch.mapset_id == mapset_id);
+
+    if !already_watching && watches.len() >= WATCH_LIMIT {
+        let content = format!(
+            "You can watch at most {WATCH_LIMIT} mapsets, remove some with {} first",
+            InteractionCommands::mention("mapwatch remove")
+        );
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    let mapset = match Context::osu().beatmapset(mapset_id).await {
+        Ok(mapset) => mapset,
+        Err(OsuError::NotFound) => {
+            let content = format!("Mapset {mapset_id} was not found");
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::OsuApi).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get mapset"));
+        }
+    };
+
+    if matches!(
+        mapset.status,
+        RankStatus::Ranked | RankStatus::Approved | RankStatus::Loved
+    ) {
+        let content = format!("Mapset {mapset_id} is already {:?}", mapset.status);
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    // There are no DMs to fall back to in DMs
+    let channel_id = match args.notify.unwrap_or_default() {
+        MapWatchNotify::Channel if command.guild_id.is_some() => Some(command.channel_id),
+        MapWatchNotify::Channel | MapWatchNotify::Dm => None,
+    };
+
+    let watch = MapsetWatch {
+        user_id: user,
+        mapset_id,
+        name: format!("{} - {}", mapset.artist, mapset.title).into_boxed_str(),
+        status: mapset.status,
+        channel_id,
+        expires_at: None,
+    };
+
+    if let Err(err) = Context::mapset_watches().add(&watch).await {
+        let _ = command.error_tr(ErrorKey::General).await;
+
+        return Err(err);
+    }
+
+    let location = match channel_id {
+        Some(channel) => format!("in <#{channel}>"),
+        None => "via DM".to_owned(),
+    };
+
+    let description = format!(
+        "Watching [{name}]({OSU_BASE}beatmapsets/{mapset_id}) ({status:?}).\n\
+        You will be notified {location} when its status changes.",
+        name = watch.name,
+        status = watch.status,
+    );
+
+    let embed = EmbedBuilder::new().description(description);
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+async fn list(command: &InteractionCommand) -> Result<()> {
+    let user = command.user_id()?;
+
+    let watches = match Context::mapset_watches().get(user).await {
+        Ok(watches) => watches,
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    if watches.is_empty() {
+        let content = format!(
+            "You're not watching any mapsets, add some with {}",
+            InteractionCommands::mention("mapwatch add")
+        );
+        let builder = MessageBuilder::new().embed(content);
+        command.update(builder).await?;
+
+        return Ok(());
+    }
+
+    let mut description = String::with_capacity(watches.len() * 96);
+
+    for watch in watches.iter() {
+        let _ = write!(
+            description,
+            "• [{name}]({OSU_BASE}beatmapsets/{mapset_id}) ({status:?})",
+            name = watch.name,
+            mapset_id = watch.mapset_id,
+            status = watch.status,
+        );
+
+        match watch.channel_id {
+            Some(channel) => {
+                let _ = writeln!(description, " in <#{channel}>");
+            }
+            None => description.push('\n'),
+        }
+    }
+
+    let embed = EmbedBuilder::new()
+        .title(format!("Watched mapsets ({}/{WATCH_LIMIT})", watches.len()))
+        .description(description);
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+async fn remove(command: &InteractionCommand, mapset: &str) -> Result<()> {
+    let Some(mapset_id) = matcher::get_osu_mapset_id(mapset) else {
+        let content = "Failed to parse mapset url or id.\n\
+            Be sure to pick one of the suggested mapsets.";
+        command.error(content).await?;
+
+        return Ok(());
+    };
+
+    let user = command.user_id()?;
+
+    let content = match Context::mapset_watches().remove(user, mapset_id).await {
+        Ok(true) => format!("No longer watching mapset {mapset_id}"),
+        Ok(false) => format!("You were not watching mapset {mapset_id}"),
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+async fn remove_autocomplete(command: &InteractionCommand, name: String) -> Result<()> {
+    let user = command.user_id()?;
+    let watches = Context::mapset_watches().get(user).await?;
+    let name = name.cow_to_ascii_lowercase();
+
+    let choices = watches
+        .iter()
+        .filter(|watch| {
+            name.is_empty()
+                || watch.name.to_ascii_lowercase().contains(name.as_ref())
+                || watch.mapset_id.to_string().starts_with(name.as_ref())
+        })
+        .take(25)
+        .map(new_choice)
+        .collect();
+
+    command.autocomplete(choices).await?;
+
+    Ok(())
+}
+
+fn new_choice(watch: &MapsetWatch) -> CommandOptionChoice {
+    let mut name = format!("{} ({:?})", watch.name, watch.status);
+
+    // Choice names may be at most 100 characters long
+    if name.chars().count() > 100 {
+        name = name.chars().take(99).collect();
+        name.push('…');
+    }
+
+    CommandOptionChoice {
+        name,
+        name_localizations: None,
+        value: CommandOptionChoiceValue::String(watch.mapset_id.to_string()),
+    }
+}
@@ -1,7 +1,7 @@
 use std::time::Duration;
 
 use bathbot_macros::SlashCommand;
-use bathbot_util::{Authored, MessageBuilder, constants::OSU_API_ISSUE, matcher};
+use bathbot_util::{Authored, MessageBuilder, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use rosu_v2::prelude::OsuError;
 use tokio::time::interval;
@@ -103,7 +103,7 @@ async fn matchcompare(mut command: InteractionCommand, args: MatchCompare) -> Re
             let previous_fut_2 = retrieve_previous(&mut match2, Context::osu());
 
             if let Err(err) = tokio::try_join!(previous_fut_1, previous_fut_2) {
-                let _ = command.error(OSU_API_ISSUE).await;
+                let _ = command.error_tr(ErrorKey::OsuApi).await;
                 let err = Report::new(err)
                     .wrap_err("Failed to get history of at least one of the matches");
 
@@ -128,7 +128,7 @@ async fn matchcompare(mut command: InteractionCommand, args: MatchCompare) -> Re
             return Ok(());
         }
         Err(err) => {
-            let _ = command.error(OSU_API_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::OsuApi).await;
             let report = Report::new(err).wrap_err("failed to get at least one of the matches");
 
             return Err(report);
@@ -6,7 +6,7 @@ use std::{
 };
 
 use bathbot_macros::{SlashCommand, command};
-use bathbot_util::{IntHasher, constants::OSU_API_ISSUE, matcher};
+use bathbot_util::{IntHasher, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use rosu_v2::prelude::{
     GameMod, GameModIntermode, GameMods, GameModsIntermode, MatchGame, MatchTeam, Osu, OsuError,
@@ -199,7 +199,7 @@ async fn matchcosts(orig: CommandOrigin<'_>, args: MatchCost<'_>) -> Result<()>
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get match");
 
             return Err(err);
@@ -4,10 +4,8 @@ use bathbot_macros::{SlashCommand, command};
 use bathbot_model::command_fields::ThreadChannel;
 use bathbot_util::{
     MessageBuilder,
-    constants::{
-        GENERAL_ISSUE, INVALID_ACTION_FOR_CHANNEL_TYPE, OSU_API_ISSUE, OSU_BASE,
-        THREADS_UNAVAILABLE,
-    },
+    constants::{INVALID_ACTION_FOR_CHANNEL_TYPE, OSU_BASE, THREADS_UNAVAILABLE},
+    i18n::ErrorKey,
     matcher,
 };
 use eyre::{Report, Result, WrapErr};
@@ -29,7 +27,7 @@ use crate::{
     help = "Similar to what an mp link does, this command will \
     keep a channel up to date about events in a multiplayer match."
 )]
-#[flags(AUTHORITY)]
+#[flags(AUTHORITY, AUDITABLE)]
 pub enum Matchlive<'a> {
     #[command(name = "track")]
     Add(MatchliveAdd<'a>),
@@ -44,6 +42,8 @@ pub struct MatchliveAdd<'a> {
     match_url: Cow<'a, str>,
     #[command(desc = "Choose if a new thread should be started")]
     thread: ThreadChannel,
+    #[command(desc = "Only send a summary once the match is over")]
+    summary_only: Option<bool>,
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -66,20 +66,28 @@ async fn slash_matchlive(mut command: InteractionCommand) -> Result<()> {
     "Live track a multiplayer match in a channel.\n\
     Similar to what an mp link does, I will keep a channel up \
     to date about events in a match.\n\
+    Add `summary` after the match to only receive a summary once the match is over.\n\
     Use the `matchliveremove` command to stop tracking the match."
 )]
-#[usage("[match url / match id]")]
-#[examples("58320988", "https://osu.ppy.sh/community/matches/58320988")]
+#[usage("[match url / match id] [summary]")]
+#[examples(
+    "58320988",
+    "https://osu.ppy.sh/community/matches/58320988",
+    "58320988 summary"
+)]
 #[alias("mla", "matchliveadd", "mlt", "matchlivetrack")]
 #[bucket(MatchLive)]
-#[flags(AUTHORITY)]
+#[flags(AUTHORITY, AUDITABLE)]
 #[group(AllModes)]
 async fn prefix_matchlive(msg: &Message, mut args: Args<'_>) -> Result<()> {
     match args.next() {
         Some(arg) => {
+            let summary_only = args.next().map(|arg| arg.eq_ignore_ascii_case("summary"));
+
             let args = MatchliveAdd {
                 match_url: arg.into(),
                 thread: ThreadChannel::Channel,
+                summary_only,
             };
 
             matchlive(msg.into(), args).await
@@ -103,7 +111,7 @@ async fn prefix_matchlive(msg: &Message, mut args: Args<'_>) -> Result<()> {
 #[usage("[match url / match id]")]
 #[examples("58320988", "https://osu.ppy.sh/community/matches/58320988")]
 #[alias("mlr")]
-#[flags(AUTHORITY)]
+#[flags(AUTHORITY, AUDITABLE)]
 #[group(AllModes)]
 async fn prefix_matchliveremove(msg: &Message, mut args: Args<'_>) -> Result<()> {
     let args = match args.next() {
@@ -136,7 +144,13 @@ fn parse_match_id(match_url: &str) -> Result<u32, &'static str> {
 }
 
 async fn matchlive(orig: CommandOrigin<'_>, args: MatchliveAdd<'_>) -> Result<()> {
-    let MatchliveAdd { match_url, thread } = args;
+    let MatchliveAdd {
+        match_url,
+        thread,
+        summary_only,
+    } = args;
+
+    let summary_only = summary_only.unwrap_or(false);
 
     let match_id = match parse_match_id(&match_url) {
         Ok(id) => id,
@@ -181,7 +195,7 @@ async fn matchlive(orig: CommandOrigin<'_>, args: MatchliveAdd<'_>) -> Result<()
                 match content {
                     Some(content) => return orig.error(content).await,
                     None => {
-                        let _ = orig.error(GENERAL_ISSUE).await;
+                        let _ = orig.error_tr(ErrorKey::General).await;
                         let report = Report::new(err).wrap_err("failed to create thread");
 
                         return Err(report);
@@ -191,7 +205,18 @@ async fn matchlive(orig: CommandOrigin<'_>, args: MatchliveAdd<'_>) -> Result<()
         }
     }
 
-    let content: &str = match Context::add_match_track(channel, match_id).await {
+    let content: &str = match Context::add_match_track(channel, match_id, summary_only).await {
+        MatchTrackResult::Added if summary_only => {
+            let content = format!(
+                "Tracking [the match]({OSU_BASE}community/matches/{match_id}), \
+                a summary will be sent once it's over"
+            );
+
+            let builder = MessageBuilder::new().embed(content);
+            orig.create_message(builder).await?;
+
+            return Ok(());
+        }
         MatchTrackResult::Added => match orig {
             CommandOrigin::Message { .. } => return Ok(()),
             CommandOrigin::Interaction { command } => {
@@ -205,7 +230,7 @@ async fn matchlive(orig: CommandOrigin<'_>, args: MatchliveAdd<'_>) -> Result<()
         },
         MatchTrackResult::Capped => "Channels can track at most three games at a time",
         MatchTrackResult::Duplicate => "That match is already being tracking in this channel",
-        MatchTrackResult::Error => OSU_API_ISSUE,
+        MatchTrackResult::Error => return orig.error_tr(ErrorKey::OsuApi).await,
         MatchTrackResult::NotFound => "The osu!api returned a 404 indicating an invalid match id",
         MatchTrackResult::Private => "The match can't be tracked because it is private",
     };
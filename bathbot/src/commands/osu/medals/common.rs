@@ -6,7 +6,7 @@ use std::{
 
 use bathbot_macros::command;
 use bathbot_model::{MedalGroup, OsekaiMedal, Rarity};
-use bathbot_util::{IntHasher, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{IntHasher, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use rkyv::rancor::{Panic, ResultExt};
 use rosu_v2::{
@@ -21,7 +21,7 @@ use crate::{
     Context,
     active::{ActiveMessages, impls::MedalsCommonPagination},
     commands::osu::UserExtraction,
-    core::commands::CommandOrigin,
+    core::commands::{CommandOrigin, interaction::InteractionCommands},
     manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
     util::osu::get_combined_thumbnail,
 };
@@ -77,7 +77,7 @@ pub(super) async fn common(orig: CommandOrigin<'_>, mut args: MedalCommon<'_>) -
     let user_id1 = match extract_user_id(&mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -93,7 +93,7 @@ pub(super) async fn common(orig: CommandOrigin<'_>, mut args: MedalCommon<'_>) -
     let user_id2 = match extract_user_id(&mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -101,13 +101,15 @@ pub(super) async fn common(orig: CommandOrigin<'_>, mut args: MedalCommon<'_>) -
         UserExtraction::None => match Context::user_config().osu_id(orig.user_id()?).await {
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => {
-                let content =
-                    "Since you're not linked with the `/link` command, you must specify two names.";
+                let content = format!(
+                    "Since you're not linked with the {} command, you must specify two names.",
+                    InteractionCommands::mention("link")
+                );
 
                 return orig.error(content).await;
             }
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -140,7 +142,7 @@ pub(super) async fn common(orig: CommandOrigin<'_>, mut args: MedalCommon<'_>) -
             return orig.error(content).await;
         }
         (Err(err), _) | (_, Err(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get user"));
         }
@@ -149,7 +151,7 @@ pub(super) async fn common(orig: CommandOrigin<'_>, mut args: MedalCommon<'_>) -
     let all_medals = match all_medals_res {
         Ok(medals) => medals,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get cached medals"));
         }
@@ -266,7 +268,7 @@ pub(super) async fn common(orig: CommandOrigin<'_>, mut args: MedalCommon<'_>) -
                         });
                     }
                     Err(err) => {
-                        let _ = orig.error(GENERAL_ISSUE).await;
+                        let _ = orig.error_tr(ErrorKey::General).await;
 
                         return Err(
                             Report::new(err).wrap_err("Failed to get cached rarity ranking")
@@ -1,18 +1,22 @@
 use std::{
     cmp::{Ordering, Reverse},
     collections::HashMap,
+    fmt::Write,
 };
 
 use bathbot_macros::command;
-use bathbot_model::{OsekaiMedal, Rarity};
-use bathbot_util::{IntHasher, constants::GENERAL_ISSUE, matcher};
+use bathbot_model::{ArchivedOsekaiMedal, MedalGroup, OsekaiMedal, Rarity};
+use bathbot_util::{IntHasher, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
-use rkyv::rancor::{Panic, ResultExt};
+use rkyv::{
+    rancor::{Panic, ResultExt},
+    vec::ArchivedVec,
+};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 use time::OffsetDateTime;
 use twilight_model::guild::Permissions;
 
-use super::{MedalList, MedalListOrder, icons_image::draw_icons_image};
+use super::{MedalList, MedalListOrder, icons_image::draw_icons_image, variants::medal_variants};
 use crate::{
     Context,
     active::{ActiveMessages, impls::MedalsListPagination},
@@ -40,6 +44,8 @@ impl<'m> MedalList<'m> {
             sort: None,
             group: None,
             reverse: None,
+            grouped: None,
+            page: None,
         }
     }
 }
@@ -70,7 +76,7 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: MedalList<'_>) -> Result
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&orig).await,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -81,6 +87,8 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: MedalList<'_>) -> Result
         sort,
         group,
         reverse,
+        grouped,
+        page,
         ..
     } = args;
 
@@ -97,13 +105,13 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: MedalList<'_>) -> Result
             return orig.error(content).await;
         }
         (Err(err), ..) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let report = Report::new(err).wrap_err("Failed to get user");
 
             return Err(report);
         }
         (_, Err(err), _) | (.., Err(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get cached rarity ranking"));
         }
@@ -140,6 +148,7 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: MedalList<'_>) -> Result
                         .get(&m.medal_id.to_native())
                         .copied()
                         .unwrap_or(100.0),
+                    variants: None,
                 };
 
                 Some(entry)
@@ -159,9 +168,20 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: MedalList<'_>) -> Result
         medals.retain(|entry| entry.medal.grouping == group);
     }
 
+    if grouped == Some(true) {
+        group_variants(&mut medals, &osekai_medals);
+    }
+
+    let sort_value = sort.as_ref().map(|sort| match sort {
+        MedalListOrder::Alphabet => "alphabet",
+        MedalListOrder::Date => "date",
+        MedalListOrder::MedalId => "medal_id",
+        MedalListOrder::Rarity => "rarity",
+    });
+
     let order_str = match sort.unwrap_or_default() {
         MedalListOrder::Alphabet => {
-            medals.sort_unstable_by(|a, b| a.medal.name.cmp(&b.medal.name));
+            medals.sort_unstable_by(|a, b| a.name().cmp(b.name()));
 
             "alphabet"
         }
@@ -220,6 +240,7 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: MedalList<'_>) -> Result
     };
 
     let name = user.username.as_str();
+    let command = invocation(name, sort_value, group, reverse, grouped);
 
     let content = match group {
         None => format!("All medals of `{name}` sorted by {reverse_str}{order_str}:",),
@@ -234,6 +255,8 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: MedalList<'_>) -> Result
         .medals(medals.into_boxed_slice())
         .content(content.into_boxed_str())
         .msg_owner(owner)
+        .command(Some(command))
+        .start_page(page.map(|page| page as usize))
         .build();
 
     ActiveMessages::builder(pagination)
@@ -243,8 +266,105 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: MedalList<'_>) -> Result
         .await
 }
 
+/// Slash command invocation that reproduces the listed medals.
+fn invocation(
+    name: &str,
+    sort: Option<&str>,
+    group: Option<MedalGroup>,
+    reverse: Option<bool>,
+    grouped: Option<bool>,
+) -> String {
+    let mut command = format!("/medal list name:{name}");
+
+    if let Some(sort) = sort {
+        let _ = write!(command, " sort:{sort}");
+    }
+
+    if let Some(group) = group {
+        let group = match group {
+            MedalGroup::SkillDedication => "skill_dedication",
+            MedalGroup::HushHush => "hush_hush",
+            MedalGroup::HushHushExpert => "hush_hush_expert",
+            MedalGroup::BeatmapPacks => "map_packs",
+            MedalGroup::BeatmapChallengePacks => "map_challenge_packs",
+            MedalGroup::SeasonalSpotlights => "seasonal_spotlights",
+            MedalGroup::BeatmapSpotlights => "map_spotlights",
+            MedalGroup::ModIntroduction => "mod_intro",
+        };
+
+        let _ = write!(command, " group:{group}");
+    }
+
+    if let Some(reverse) = reverse {
+        let _ = write!(command, " reverse:{reverse}");
+    }
+
+    if let Some(grouped) = grouped {
+        let _ = write!(command, " grouped:{grouped}");
+    }
+
+    command
+}
+
+/// Collapse the achieved mode variants of each medal into a single entry,
+/// represented by the most recently achieved variant.
+fn group_variants(
+    medals: &mut Vec<MedalEntryList>,
+    osekai_medals: &ArchivedVec<ArchivedOsekaiMedal>,
+) {
+    for variants in medal_variants(osekai_medals) {
+        let medal_ids: Vec<_> = variants
+            .indices
+            .iter()
+            .map(|&idx| osekai_medals[idx].medal_id.to_native())
+            .collect();
+
+        let is_variant = |entry: &MedalEntryList| medal_ids.contains(&entry.medal.medal_id);
+
+        let Some(newest) = medals
+            .iter()
+            .filter(|entry| is_variant(entry))
+            .max_by_key(|entry| entry.achieved)
+            .map(|entry| entry.medal.medal_id)
+        else {
+            continue;
+        };
+
+        let achieved = medals.iter().filter(|entry| is_variant(entry)).count();
+        medals.retain(|entry| !is_variant(entry) || entry.medal.medal_id == newest);
+
+        if let Some(entry) = medals
+            .iter_mut()
+            .find(|entry| entry.medal.medal_id == newest)
+        {
+            entry.variants = Some(GroupedVariants {
+                base_name: variants.base_name,
+                achieved,
+                total: medal_ids.len(),
+            });
+        }
+    }
+}
+
 pub struct MedalEntryList {
     pub medal: OsekaiMedal,
     pub achieved: OffsetDateTime,
     pub rarity: f32,
+    /// Only set if the entry represents all mode variants of the medal
+    pub variants: Option<GroupedVariants>,
+}
+
+impl MedalEntryList {
+    pub fn name(&self) -> &str {
+        match self.variants {
+            Some(ref variants) => &variants.base_name,
+            None => &self.medal.name,
+        }
+    }
+}
+
+pub struct GroupedVariants {
+    pub base_name: Box<str>,
+    pub achieved: usize,
+    pub total: usize,
 }
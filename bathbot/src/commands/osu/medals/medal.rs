@@ -2,6 +2,7 @@ use std::{
     borrow::Cow,
     cmp::{Ordering, Reverse},
     fmt::Write,
+    mem,
 };
 
 use bathbot_macros::command;
@@ -9,14 +10,15 @@ use bathbot_model::{ArchivedOsekaiMedal, MedalGroup, OsekaiComment, OsekaiMap};
 use bathbot_psql::model::configs::HideSolutions;
 use bathbot_util::{
     AuthorBuilder, CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder,
-    constants::{FIELD_VALUE_SIZE, GENERAL_ISSUE, OSEKAI_ISSUE, OSU_BASE},
+    constants::{FIELD_VALUE_SIZE, OSEKAI_ISSUE, OSU_BASE},
     fields,
+    i18n::ErrorKey,
     osu::flag_url,
-    string_cmp::levenshtein_similarity,
+    string_cmp::{autocomplete_matches, levenshtein_similarity},
 };
-use eyre::{Report, Result, WrapErr};
+use eyre::{Report, Result};
 use rkyv::{rend::f32_le, vec::ArchivedVec};
-use rosu_v2::prelude::GameMode;
+use rosu_v2::prelude::{GameMode, OsuError};
 use time::OffsetDateTime;
 use twilight_interactions::command::AutocompleteValue;
 use twilight_model::{
@@ -24,10 +26,15 @@ use twilight_model::{
     channel::message::embed::EmbedField,
 };
 
-use super::{MedalAchieved, MedalInfo_};
+use super::{
+    MedalAchieved, MedalInfo_,
+    variants::{MedalVariants, medal_variants, variants_embed},
+};
 use crate::{
     Context,
+    commands::osu::user_not_found,
     core::commands::CommandOrigin,
+    manager::redis::osu::{UserArgs, UserArgsError},
     util::{ChannelExt, InteractionCommandExt, interaction::InteractionCommand},
 };
 
@@ -53,22 +60,15 @@ async fn prefix_medal(msg: &Message, args: Args<'_>) -> Result<()> {
 
     let args = MedalInfo_ {
         name: AutocompleteValue::Completed(name.into()),
+        user: None,
+        discord: None,
     };
 
     info(msg.into(), args).await
 }
 
-pub(super) async fn info(orig: CommandOrigin<'_>, args: MedalInfo_<'_>) -> Result<()> {
-    let MedalInfo_ { name } = args;
-
-    let medals = match Context::redis().medals().await {
-        Ok(medals) => medals,
-        Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
-
-            return Err(Report::new(err).wrap_err("Failed to get cached medals"));
-        }
-    };
+pub(super) async fn info(orig: CommandOrigin<'_>, mut args: MedalInfo_<'_>) -> Result<()> {
+    let name = mem::replace(&mut args.name, AutocompleteValue::None);
 
     let name = match (name, &orig) {
         (AutocompleteValue::None, CommandOrigin::Interaction { command }) => {
@@ -81,12 +81,34 @@ pub(super) async fn info(orig: CommandOrigin<'_>, args: MedalInfo_<'_>) -> Resul
         _ => unreachable!(),
     };
 
+    let medals = match Context::redis().medals().await {
+        Ok(medals) => medals,
+        Err(err) => {
+            let _ = orig.error_tr(ErrorKey::General).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get cached medals"));
+        }
+    };
+
     let name = name.cow_to_ascii_lowercase();
 
-    let medal = match medals
+    let medal_idx = medals
         .iter()
-        .position(|m| m.name.to_ascii_lowercase() == name)
-    {
+        .position(|m| m.name.to_ascii_lowercase() == name);
+
+    // Variants can be requested by their own name or their shared name
+    let variants = medal_variants(&medals)
+        .into_iter()
+        .find(|variants| match medal_idx {
+            Some(idx) => variants.contains(idx),
+            None => variants.base_name.to_ascii_lowercase() == name,
+        });
+
+    if let Some(variants) = variants {
+        return info_variants(&orig, args, &medals, &variants).await;
+    }
+
+    let medal = match medal_idx {
         Some(idx) => &medals[idx],
         None => return no_medal(&orig, name.as_ref(), &medals).await,
     };
@@ -134,6 +156,40 @@ pub(super) async fn info(orig: CommandOrigin<'_>, args: MedalInfo_<'_>) -> Resul
     Ok(())
 }
 
+async fn info_variants(
+    orig: &CommandOrigin<'_>,
+    args: MedalInfo_<'_>,
+    medals: &ArchivedVec<ArchivedOsekaiMedal>,
+    variants: &MedalVariants,
+) -> Result<()> {
+    let user = match user_id!(orig, args) {
+        Some(user_id) => {
+            let user_args = UserArgs::rosu_id(&user_id, GameMode::Osu).await;
+
+            match Context::redis().osu_user(user_args).await {
+                Ok(user) => Some(user),
+                Err(UserArgsError::Osu(OsuError::NotFound)) => {
+                    let content = user_not_found(user_id).await;
+
+                    return orig.error(content).await;
+                }
+                Err(err) => {
+                    let _ = orig.error_tr(ErrorKey::General).await;
+
+                    return Err(Report::new(err).wrap_err("Failed to get user"));
+                }
+            }
+        }
+        None => None,
+    };
+
+    let embed = variants_embed(medals, variants, user.as_ref());
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
 const SIMILARITY_THRESHOLD: f32 = 0.6;
 
 async fn no_medal(
@@ -173,32 +229,26 @@ async fn no_medal(
 }
 
 pub async fn handle_autocomplete(command: &InteractionCommand, name: String) -> Result<()> {
-    let name = if name.is_empty() {
+    if name.is_empty() {
         command.autocomplete(Vec::new()).await?;
 
         return Ok(());
-    } else {
-        name.cow_to_ascii_lowercase()
-    };
-
-    let name = name.as_ref();
+    }
 
-    let medals = Context::redis()
-        .medals()
-        .await
-        .wrap_err("Failed to get cached medals")?;
+    // Fetching the medals could take longer than discord's response window
+    // so on a cache miss there won't be any suggestions this time around
+    let Some(medals) = Context::redis().cached_medals().await else {
+        command.autocomplete(Vec::new()).await?;
 
-    let mut choices = Vec::with_capacity(25);
+        return Ok(());
+    };
 
-    for medal in medals.iter() {
-        if medal.name.to_ascii_lowercase().starts_with(name) {
-            choices.push(new_choice(&medal.name));
+    let names = medals.iter().map(|medal| medal.name.as_str());
 
-            if choices.len() == 25 {
-                break;
-            }
-        }
-    }
+    let choices = autocomplete_matches(&name, names, 25)
+        .into_iter()
+        .map(new_choice)
+        .collect();
 
     command.autocomplete(choices).await?;
 
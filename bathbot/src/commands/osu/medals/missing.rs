@@ -2,7 +2,7 @@ use std::{borrow::Cow, cmp::Ordering, collections::HashSet};
 
 use bathbot_macros::command;
 use bathbot_model::{MEDAL_GROUPS, MedalGroup, OsekaiMedal};
-use bathbot_util::{IntHasher, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{IntHasher, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use rkyv::rancor::{Panic, ResultExt};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
@@ -51,7 +51,7 @@ pub(super) async fn missing(orig: CommandOrigin<'_>, args: MedalMissing<'_>) ->
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&orig).await,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -70,12 +70,12 @@ pub(super) async fn missing(orig: CommandOrigin<'_>, args: MedalMissing<'_>) ->
             return orig.error(content).await;
         }
         (_, Err(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get cached medals"));
         }
         (Err(err), _) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let report = Report::new(err).wrap_err("Failed to get user");
 
             return Err(report);
@@ -1,16 +1,19 @@
 use std::borrow::Cow;
 
 use bathbot_macros::{HasName, SlashCommand};
-use bathbot_model::MedalGroup;
+use bathbot_model::{MedalGroup, command_fields::GameModeOption};
 use eyre::Result;
 use twilight_interactions::command::{
     AutocompleteValue, CommandModel, CommandOption, CreateCommand, CreateOption,
 };
 use twilight_model::id::{Id, marker::UserMarker};
 
-pub use self::{common::*, list::*, medal::*, missing::*, recent::*, stats::*};
+pub use self::{common::*, list::*, medal::*, missing::*, recent::*, stats::*, suggest::*};
 use crate::{
-    commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP},
+    commands::{
+        DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
+        osu::{HasName, UserIdResult},
+    },
     util::{InteractionCommandExt, interaction::InteractionCommand},
 };
 
@@ -20,6 +23,8 @@ mod list;
 mod medal;
 mod missing;
 mod recent;
+mod suggest;
+mod variants;
 
 pub mod stats;
 
@@ -44,6 +49,8 @@ pub enum Medal<'a> {
     Recent(MedalRecent<'a>),
     #[command(name = "stats")]
     Stats(MedalStats<'a>),
+    #[command(name = "suggest")]
+    Suggest(MedalSuggest<'a>),
 }
 
 #[derive(CommandModel)]
@@ -60,6 +67,8 @@ enum Medal_<'a> {
     Recent(MedalRecent<'a>),
     #[command(name = "stats")]
     Stats(MedalStats<'a>),
+    #[command(name = "suggest")]
+    Suggest(MedalSuggest<'a>),
 }
 
 #[derive(CommandModel, CreateCommand, Default)]
@@ -140,15 +149,28 @@ pub struct MedalInfo {
         autocomplete = true,
         desc = "Specify the name of a medal",
         help = "Specify the name of a medal.\n\
-        Upper- and lowercase does not matter but punctuation is important."
+        Upper- and lowercase does not matter but punctuation is important.\n\
+        Medals that exist once per mode are shown together."
     )]
     name: String,
+    #[command(desc = "Specify a username to show which mode variants they own")]
+    user: Option<String>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
 }
 
 #[derive(CommandModel)]
 #[command(autocomplete = true)]
 struct MedalInfo_<'a> {
     name: AutocompleteValue<Cow<'a, str>>,
+    user: Option<Cow<'a, str>>,
+    discord: Option<Id<UserMarker>>,
+}
+
+impl HasName for MedalInfo_<'_> {
+    fn user_id(&self) -> UserIdResult {
+        UserIdResult::process(self.user.as_deref(), self.discord)
+    }
 }
 
 const MEDAL_LIST_DESC: &str = "List all achieved medals of a user";
@@ -164,8 +186,16 @@ pub struct MedalList<'a> {
     group: Option<MedalGroup>,
     #[command(desc = "Reverse the resulting medal list")]
     reverse: Option<bool>,
+    #[command(
+        desc = "Show medals that exist once per mode as a single entry",
+        help = "Show medals that exist once per mode as a single entry.\n\
+        The entry shows how many of the mode variants were achieved."
+    )]
+    grouped: Option<bool>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(min_value = 1, desc = "Specify a page to start on")]
+    page: Option<u32>,
 }
 
 #[derive(CommandOption, CreateOption, Default)]
@@ -239,6 +269,26 @@ pub struct MedalStats<'a> {
     discord: Option<Id<UserMarker>>,
 }
 
+#[derive(CommandModel, CreateCommand, HasName)]
+#[command(
+    name = "suggest",
+    desc = "Suggest missing medals that should be easy to get",
+    help = "Suggest missing medals that should be easy to get.\n\
+    Medals are ranked by how common they are, whether they match the mode, \
+    and their group. Skill medals above the star rating of the user's top plays \
+    are ranked lower. Medals without rarity data are not suggested."
+)]
+pub struct MedalSuggest<'a> {
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(desc = "Specify the user's main gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(min_value = 1, max_value = 10, desc = "Amount of suggested medals")]
+    count: Option<u8>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+}
+
 pub async fn slash_medal(mut command: InteractionCommand) -> Result<()> {
     match Medal_::from_interaction(command.input_data())? {
         Medal_::Common(args) => common((&mut command).into(), args).await,
@@ -247,5 +297,6 @@ pub async fn slash_medal(mut command: InteractionCommand) -> Result<()> {
         Medal_::Missing(args) => missing((&mut command).into(), args).await,
         Medal_::Recent(args) => recent((&mut command).into(), args).await,
         Medal_::Stats(args) => stats((&mut command).into(), args).await,
+        Medal_::Suggest(args) => suggest((&mut command).into(), args).await,
     }
 }
@@ -3,7 +3,7 @@ use std::{cmp::Reverse, collections::HashMap};
 use bathbot_macros::command;
 use bathbot_model::rosu_v2::user::MedalCompactRkyv;
 use bathbot_psql::model::configs::HideSolutions;
-use bathbot_util::{IntHasher, MessageBuilder, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{IntHasher, MessageBuilder, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use rand::{Rng, thread_rng};
 use rkyv::{
@@ -62,7 +62,7 @@ pub(super) async fn recent(orig: CommandOrigin<'_>, args: MedalRecent<'_>) -> Re
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&orig).await,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -71,9 +71,9 @@ pub(super) async fn recent(orig: CommandOrigin<'_>, args: MedalRecent<'_>) -> Re
 
     let user_args = UserArgs::rosu_id(&user_id, GameMode::Osu).await;
     let user_fut = Context::redis().osu_user(user_args);
-    let medals_fut = Context::redis().medals();
+    let medals_fut = Context::redis().medals_with_meta();
 
-    let (user, all_medals) = match tokio::join!(user_fut, medals_fut) {
+    let (user, (all_medals, medals_meta)) = match tokio::join!(user_fut, medals_fut) {
         (Ok(user), Ok(medals)) => (user, medals),
         (Err(UserArgsError::Osu(OsuError::NotFound)), _) => {
             let content = user_not_found(user_id).await;
@@ -81,13 +81,13 @@ pub(super) async fn recent(orig: CommandOrigin<'_>, args: MedalRecent<'_>) -> Re
             return orig.error(content).await;
         }
         (Err(err), _) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let report = Report::new(err).wrap_err("Failed to get user");
 
             return Err(report);
         }
         (_, Err(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get cached medals"));
         }
@@ -166,7 +166,7 @@ pub(super) async fn recent(orig: CommandOrigin<'_>, args: MedalRecent<'_>) -> Re
     {
         Ok(idx) => &all_medals[idx],
         Err(_) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             bail!("No medal with id `{medal_id}`");
         }
@@ -202,6 +202,7 @@ pub(super) async fn recent(orig: CommandOrigin<'_>, args: MedalRecent<'_>) -> Re
         .achieved_medals(user_medals.into_boxed_slice())
         .embeds(embeds)
         .medals(all_medals)
+        .medals_meta(medals_meta)
         .hide_solutions(hide_solutions)
         .content(content)
         .msg_owner(owner)
@@ -2,7 +2,7 @@ use std::{borrow::Cow, collections::HashMap};
 
 use bathbot_macros::command;
 use bathbot_model::rosu_v2::user::MedalCompactRkyv;
-use bathbot_util::{IntHasher, MessageBuilder, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{IntHasher, MessageBuilder, i18n::ErrorKey, matcher};
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use plotters::prelude::*;
 use plotters_skia::SkiaBackend;
@@ -22,7 +22,7 @@ use twilight_model::guild::Permissions;
 use super::MedalStats;
 use crate::{
     Context,
-    commands::osu::{require_link, user_not_found},
+    commands::osu::{Theme, require_link, user_not_found},
     core::commands::CommandOrigin,
     embeds::{EmbedData, MedalStatsEmbed, StatsMedal},
     manager::redis::osu::{UserArgs, UserArgsError},
@@ -64,7 +64,7 @@ pub(super) async fn stats(orig: CommandOrigin<'_>, args: MedalStats<'_>) -> Resu
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&orig).await,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -83,12 +83,12 @@ pub(super) async fn stats(orig: CommandOrigin<'_>, args: MedalStats<'_>) -> Resu
             return orig.error(content).await;
         }
         (_, Err(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get cached medals"));
         }
         (Err(err), _) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get user"));
         }
@@ -102,7 +102,9 @@ pub(super) async fn stats(orig: CommandOrigin<'_>, args: MedalStats<'_>) -> Resu
 
     medals.sort_unstable_by_key(|medal| medal.achieved_at);
 
-    let graph = match graph(&medals, W, H) {
+    let theme = Theme::of_user(orig.user_id()?).await;
+
+    let graph = match graph(&medals, W, H, theme) {
         Ok(bytes_option) => bytes_option,
         Err(err) => {
             warn!(?err, "Failed to create graph");
@@ -148,7 +150,7 @@ pub(super) async fn stats(orig: CommandOrigin<'_>, args: MedalStats<'_>) -> Resu
 const W: u32 = 1350;
 const H: u32 = 350;
 
-pub fn graph(medals: &[MedalCompact], w: u32, h: u32) -> Result<Option<Vec<u8>>> {
+pub fn graph(medals: &[MedalCompact], w: u32, h: u32, theme: &Theme) -> Result<Option<Vec<u8>>> {
     let (first, last) = match medals {
         [medal] => (medal.achieved_at, medal.achieved_at),
         [first, .., last] => (first.achieved_at, last.achieved_at),
@@ -161,11 +163,12 @@ pub fn graph(medals: &[MedalCompact], w: u32, h: u32) -> Result<Option<Vec<u8>>>
     {
         let mut root = SkiaBackend::new(surface.canvas(), w, h).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = theme.background;
         root.fill(&background)
             .wrap_err("Failed to fill background")?;
 
-        let title_style = TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&WHITE);
+        let title_style =
+            TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&theme.text);
         root = root
             .titled("Medal history", title_style)
             .wrap_err("Failed to draw title")?;
@@ -181,15 +184,15 @@ pub fn graph(medals: &[MedalCompact], w: u32, h: u32) -> Result<Option<Vec<u8>>>
         chart
             .configure_mesh()
             .disable_mesh()
-            .label_style(("sans-serif", 20, &WHITE))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 20, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 20, &theme.text))
+            .axis_style(theme.axis)
+            .axis_desc_style(("sans-serif", 20, FontStyle::Bold, &theme.text))
             .draw()
             .wrap_err("Failed to draw mesh and labels")?;
 
         // Draw area
-        let area_style = RGBColor(2, 186, 213).mix(0.6).filled();
-        let border_style = RGBColor(0, 208, 138).stroke_width(3);
+        let area_style = theme.area.mix(0.6).filled();
+        let border_style = theme.border.stroke_width(3);
         let counter = MedalCounter::new(medals);
         let series = AreaSeries::new(counter, 0, area_style).border_style(border_style);
         chart.draw_series(series).wrap_err("Failed to draw area")?;
@@ -0,0 +1,387 @@
+use std::{collections::HashSet, fmt::Write};
+
+use bathbot_model::{MedalGroup, OsekaiMedal};
+use bathbot_util::{
+    CowUtils, EmbedBuilder, FooterBuilder, IntHasher, MessageBuilder, i18n::ErrorKey,
+    markdown::truncate_lines,
+};
+use eyre::{Report, Result};
+use rkyv::rancor::{Panic, ResultExt};
+use rosu_v2::{
+    prelude::{GameMode, OsuError, Score},
+    request::UserId,
+};
+
+use super::MedalSuggest;
+use crate::{
+    Context,
+    commands::osu::{require_link, user_not_found},
+    core::commands::CommandOrigin,
+    manager::redis::osu::{UserArgs, UserArgsError},
+    util::CachedUserExt,
+};
+
+/// Maximum length of a medal's criteria text in the embed.
+const CRITERIA_LEN: usize = 150;
+
+/// How much a medal's rarity contributes to its attainability, i.e. a medal
+/// that every player owns adds the full weight.
+const RARITY_WEIGHT: f32 = 0.5;
+
+/// Added for medals of the user's main mode; medals available in all modes get
+/// half of it and medals of other modes get nothing.
+const MODE_WEIGHT: f32 = 0.2;
+
+/// Scaled by the [`group_factor`] of the medal's group.
+const GROUP_WEIGHT: f32 = 0.3;
+
+/// Subtracted for every star that a skill medal requires beyond the user's
+/// comfort zone.
+const SKILL_GAP_PENALTY: f32 = 0.15;
+
+/// How many stars above their top plays' average a user is still expected to
+/// be able to pass.
+const COMFORT_MARGIN: f32 = 0.5;
+
+pub(super) async fn suggest(orig: CommandOrigin<'_>, args: MedalSuggest<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let mut config = match Context::user_config().with_osu_id(owner).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let mode = args
+        .mode
+        .map(GameMode::from)
+        .or(config.mode)
+        .unwrap_or(GameMode::Osu);
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match config.osu.take() {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
+    let legacy_scores = config.score_data.unwrap_or_default().is_legacy();
+
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+    let scores_fut = Context::osu_scores()
+        .top(100, legacy_scores)
+        .exec_with_user(user_args);
+    let medals_fut = Context::redis().medals();
+
+    let (user, scores, all_medals) = match tokio::join!(scores_fut, medals_fut) {
+        (Ok((user, scores)), Ok(medals)) => (user, scores, medals),
+        (Err(UserArgsError::Osu(OsuError::NotFound)), _) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        (Err(err), _) => {
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
+            let err = Report::new(err).wrap_err("Failed to get user or scores");
+
+            return Err(err);
+        }
+        (_, Err(err)) => {
+            let _ = orig.error_tr(ErrorKey::General).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get cached medals"));
+        }
+    };
+
+    let owned: HashSet<_, IntHasher> = user
+        .medals
+        .iter()
+        .map(|medal| medal.medal_id.to_native())
+        .collect();
+
+    let candidates: Vec<OsekaiMedal> = all_medals
+        .iter()
+        .filter(|medal| !owned.contains(&medal.medal_id.to_native()))
+        .map(|entry| rkyv::api::deserialize_using::<_, _, Panic>(entry, &mut ()).always_ok())
+        .collect();
+
+    let summary = UserSummary {
+        mode,
+        comfort_stars: comfort_stars(&scores),
+    };
+
+    let count = args.count.map_or(5, usize::from);
+    let suggestions = suggest_medals(&candidates, &summary, count);
+
+    let mut description = String::with_capacity(count * 256);
+
+    if suggestions.is_empty() {
+        description.push_str("No medals to suggest, seems like you got all the easy ones!");
+    }
+
+    for (medal, i) in suggestions.iter().zip(1..) {
+        let url = match medal.url() {
+            Ok(url) => url,
+            Err(err) => {
+                warn!(?err);
+
+                medal.backup_url()
+            }
+        };
+
+        let _ = writeln!(
+            description,
+            "**{i}.** [{name}]({url}) • `{rarity:.2}%` • {group}\n{criteria}",
+            name = medal.name,
+            url = url.cow_replace("%25", "%"),
+            rarity = medal.rarity.unwrap_or(0.0),
+            group = medal.grouping,
+            criteria = truncate_lines(&medal.description, CRITERIA_LEN),
+        );
+    }
+
+    let footer = match summary.comfort_stars {
+        Some(stars) => format!(
+            "Comfort zone: ~{stars:.2}★ • Missing {} medals",
+            candidates.len()
+        ),
+        None => format!("Missing {} medals", candidates.len()),
+    };
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder(false))
+        .description(description)
+        .footer(FooterBuilder::new(footer))
+        .thumbnail(user.avatar_url.as_ref())
+        .title("Suggested medals");
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// What is known about the user when judging how attainable a medal is.
+struct UserSummary {
+    mode: GameMode,
+    /// Approximate star rating the user is comfortable playing at.
+    comfort_stars: Option<f32>,
+}
+
+/// Average star rating of the scores' maps.
+fn comfort_stars(scores: &[Score]) -> Option<f32> {
+    let (sum, count) = scores
+        .iter()
+        .filter_map(|score| score.map.as_ref())
+        .fold((0.0, 0), |(sum, count), map| (sum + map.stars, count + 1));
+
+    (count > 0).then(|| sum / count as f32)
+}
+
+/// The `count` most attainable medals, excluding medals without rarity data.
+fn suggest_medals<'m>(
+    medals: &'m [OsekaiMedal],
+    user: &UserSummary,
+    count: usize,
+) -> Vec<&'m OsekaiMedal> {
+    let mut scored: Vec<_> = medals
+        .iter()
+        .filter_map(|medal| attainability(medal, user).map(|score| (medal, score)))
+        .collect();
+
+    scored.sort_unstable_by(|(a_medal, a), (b_medal, b)| {
+        b.total_cmp(a)
+            .then_with(|| a_medal.medal_id.cmp(&b_medal.medal_id))
+    });
+    scored.truncate(count);
+
+    scored.into_iter().map(|(medal, _)| medal).collect()
+}
+
+/// Heuristic of how easy it would be for the user to acquire the medal; higher
+/// is easier.
+///
+/// Returns `None` if the medal has no rarity data.
+fn attainability(medal: &OsekaiMedal, user: &UserSummary) -> Option<f32> {
+    let rarity = medal.rarity?;
+
+    let mut score = RARITY_WEIGHT * (rarity / 100.0).clamp(0.0, 1.0);
+
+    score += match medal.mode {
+        Some(mode) if mode == user.mode => MODE_WEIGHT,
+        Some(_) => 0.0,
+        None => MODE_WEIGHT / 2.0,
+    };
+
+    score += GROUP_WEIGHT * group_factor(medal.grouping);
+
+    if medal.grouping == MedalGroup::SkillDedication {
+        let required = required_stars(&medal.name).or_else(|| required_stars(&medal.description));
+
+        if let (Some(required), Some(comfort)) = (required, user.comfort_stars) {
+            let gap = required - (comfort + COMFORT_MARGIN);
+
+            if gap > 0.0 {
+                score -= SKILL_GAP_PENALTY * gap;
+            }
+        }
+    }
+
+    Some(score)
+}
+
+/// How approachable medals of a group generally are, between 0 and 1.
+fn group_factor(group: MedalGroup) -> f32 {
+    match group {
+        MedalGroup::BeatmapPacks | MedalGroup::ModIntroduction => 1.0,
+        MedalGroup::SeasonalSpotlights | MedalGroup::BeatmapSpotlights => 0.8,
+        MedalGroup::SkillDedication => 0.7,
+        MedalGroup::BeatmapChallengePacks => 0.5,
+        MedalGroup::HushHush => 0.3,
+        MedalGroup::HushHushExpert => 0.0,
+    }
+}
+
+/// Parses the star rating out of texts such as "Pass a 5 star map" or
+/// "Achieve a 6-star FC".
+fn required_stars(text: &str) -> Option<f32> {
+    let lowercase = text.to_ascii_lowercase();
+
+    lowercase.match_indices("star").find_map(|(idx, _)| {
+        let prefix = lowercase[..idx].trim_end_matches([' ', '-']);
+        let start = prefix
+            .rfind(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .map_or(0, |idx| idx + 1);
+
+        prefix[start..].parse().ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn medal(
+        id: u32,
+        rarity: Option<f32>,
+        mode: Option<GameMode>,
+        group: MedalGroup,
+    ) -> OsekaiMedal {
+        medal_named(id, "Medal", rarity, mode, group)
+    }
+
+    fn medal_named(
+        id: u32,
+        description: &str,
+        rarity: Option<f32>,
+        mode: Option<GameMode>,
+        group: MedalGroup,
+    ) -> OsekaiMedal {
+        let json = serde_json::json!({
+            "Medal_ID": id.to_string(),
+            "Ordering": "0",
+            "Frequency": rarity.map(|rarity| rarity.to_string()),
+            "Name": format!("Medal {id}"),
+            "Link": "medal.png",
+            "Description": description,
+            "Gamemode": match mode {
+                Some(GameMode::Osu) => "osu",
+                Some(GameMode::Taiko) => "taiko",
+                Some(GameMode::Catch) => "fruits",
+                Some(GameMode::Mania) => "mania",
+                None => "NULL",
+            },
+            "Grouping": group.to_string(),
+            "Solution": null,
+            "Mods": null,
+            "Supports_Lazer": "1",
+            "Supports_Stable": "1",
+        });
+
+        // Some fields deserialize borrowed strings so a `Value` won't do
+        serde_json::from_str(&json.to_string()).unwrap()
+    }
+
+    fn ids(medals: &[&OsekaiMedal]) -> Vec<u32> {
+        medals.iter().map(|medal| medal.medal_id).collect()
+    }
+
+    const USER: UserSummary = UserSummary {
+        mode: GameMode::Osu,
+        comfort_stars: Some(5.0),
+    };
+
+    #[test]
+    fn common_medals_first() {
+        let medals = [
+            medal(1, Some(5.0), None, MedalGroup::BeatmapPacks),
+            medal(2, Some(60.0), None, MedalGroup::BeatmapPacks),
+            medal(3, Some(20.0), None, MedalGroup::BeatmapPacks),
+        ];
+
+        assert_eq!(ids(&suggest_medals(&medals, &USER, 3)), [2, 3, 1]);
+    }
+
+    #[test]
+    fn main_mode_preferred() {
+        let medals = [
+            medal(
+                1,
+                Some(10.0),
+                Some(GameMode::Mania),
+                MedalGroup::BeatmapPacks,
+            ),
+            medal(2, Some(10.0), None, MedalGroup::BeatmapPacks),
+            medal(3, Some(10.0), Some(GameMode::Osu), MedalGroup::BeatmapPacks),
+        ];
+
+        assert_eq!(ids(&suggest_medals(&medals, &USER, 3)), [3, 2, 1]);
+    }
+
+    #[test]
+    fn skill_medals_beyond_comfort_zone_deprioritized() {
+        let medals = [
+            medal_named(
+                1,
+                "Aiming for the stars! Pass a 9 star map.",
+                Some(12.0),
+                Some(GameMode::Osu),
+                MedalGroup::SkillDedication,
+            ),
+            medal_named(
+                2,
+                "Pass a 5-star map.",
+                Some(10.0),
+                Some(GameMode::Osu),
+                MedalGroup::SkillDedication,
+            ),
+            medal(3, Some(4.0), Some(GameMode::Osu), MedalGroup::HushHush),
+        ];
+
+        assert_eq!(ids(&suggest_medals(&medals, &USER, 3)), [2, 3, 1]);
+    }
+
+    #[test]
+    fn missing_rarity_excluded_and_count_respected() {
+        let medals = [
+            medal(1, None, None, MedalGroup::BeatmapPacks),
+            medal(2, Some(1.0), None, MedalGroup::HushHushExpert),
+            medal(3, Some(50.0), None, MedalGroup::BeatmapPacks),
+        ];
+
+        assert_eq!(ids(&suggest_medals(&medals, &USER, 1)), [3]);
+        assert_eq!(ids(&suggest_medals(&medals, &USER, 10)), [3, 2]);
+    }
+
+    #[test]
+    fn parse_required_stars() {
+        assert_eq!(required_stars("Pass a 5 star map"), Some(5.0));
+        assert_eq!(required_stars("Achieve a 6-star FC"), Some(6.0));
+        assert_eq!(required_stars("Rising Star: 1 Star"), Some(1.0));
+        assert_eq!(required_stars("Beat a map"), None);
+    }
+}
@@ -0,0 +1,417 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use bathbot_model::{ArchivedOsekaiMedal, MedalGroup};
+use bathbot_util::{AuthorBuilder, CowUtils, EmbedBuilder, FooterBuilder, constants::OSU_BASE};
+use rkyv::{rend::f32_le, vec::ArchivedVec};
+use rosu_v2::prelude::GameMode;
+
+use crate::manager::redis::osu::CachedUser;
+
+/// A medal that exists once per mode, e.g. a spotlight medal for each mode.
+pub struct MedalVariants {
+    /// Name of the medal without the mode
+    pub base_name: Box<str>,
+    /// Indices into the medal list, ordered by mode
+    pub indices: Vec<usize>,
+}
+
+impl MedalVariants {
+    pub fn contains(&self, idx: usize) -> bool {
+        self.indices.contains(&idx)
+    }
+}
+
+/// Cluster all medals that are variants of each other.
+pub fn medal_variants(medals: &ArchivedVec<ArchivedOsekaiMedal>) -> Vec<MedalVariants> {
+    let keys = medals.iter().map(|medal| MedalKey {
+        name: medal.name.as_str(),
+        mode: medal.mode.as_ref().copied(),
+        group: medal.grouping,
+        ordering: medal.ordering.to_native(),
+    });
+
+    cluster(keys)
+}
+
+struct MedalKey<'a> {
+    name: &'a str,
+    mode: Option<GameMode>,
+    group: MedalGroup,
+    ordering: u32,
+}
+
+/// Medals are variants of each other if they're in the same group and share
+/// the same name apart from a mention of their respective mode.
+fn cluster<'a>(medals: impl IntoIterator<Item = MedalKey<'a>>) -> Vec<MedalVariants> {
+    struct Variant<'a> {
+        idx: usize,
+        mode: GameMode,
+        ordering: u32,
+        base_name: &'a str,
+    }
+
+    let mut clusters: BTreeMap<_, Vec<Variant<'_>>> = BTreeMap::new();
+
+    for (idx, medal) in medals.into_iter().enumerate() {
+        let Some(mode) = medal.mode else { continue };
+        let Some(base_name) = base_name(medal.name, mode) else {
+            continue;
+        };
+
+        let variant = Variant {
+            idx,
+            mode,
+            ordering: medal.ordering,
+            base_name,
+        };
+
+        clusters
+            .entry((medal.group, base_name.to_ascii_lowercase()))
+            .or_default()
+            .push(variant);
+    }
+
+    let mut clusters: Vec<_> = clusters
+        .into_values()
+        .filter_map(|mut variants| {
+            variants.sort_unstable_by_key(|variant| (variant.mode as u8, variant.ordering));
+            variants.dedup_by_key(|variant| variant.mode);
+
+            (variants.len() > 1).then(|| MedalVariants {
+                base_name: variants[0].base_name.into(),
+                indices: variants.iter().map(|variant| variant.idx).collect(),
+            })
+        })
+        .collect();
+
+    clusters.sort_unstable_by_key(|variants| variants.indices[0]);
+
+    clusters
+}
+
+/// Ways the mode is mentioned in medal names.
+fn mode_names(mode: GameMode) -> &'static [&'static str] {
+    match mode {
+        GameMode::Osu => &["osu!standard", "osu!std", "osu!", "standard"],
+        GameMode::Taiko => &["osu!taiko", "taiko"],
+        GameMode::Catch => &["osu!catch", "osu!ctb", "catch the beat", "catch", "ctb"],
+        GameMode::Mania => &["osu!mania", "mania"],
+    }
+}
+
+/// Characters between the mode and the base name
+const SEPARATORS_BEFORE: &[char] = &[' ', '-', '–', ':', '(', '['];
+const SEPARATORS_AFTER: &[char] = &[' ', '-', '–', ':', ')', ']'];
+
+/// The medal name without the mention of its mode e.g.
+/// `"Spotlights: Winter 2023 (osu!taiko)"` becomes `"Spotlights: Winter 2023"`.
+///
+/// Returns `None` if the name does not mention the mode at its start or end.
+fn base_name(name: &str, mode: GameMode) -> Option<&str> {
+    // Lowercasing ASCII keeps byte offsets intact
+    let lowercase = name.to_ascii_lowercase();
+    let trimmed = lowercase.trim_end_matches([')', ']']);
+
+    for mode_name in mode_names(mode) {
+        if let Some(prefix) = trimmed.strip_suffix(mode_name) {
+            let base = prefix.trim_end_matches(SEPARATORS_BEFORE);
+
+            if !base.is_empty() && base.len() < prefix.len() {
+                return Some(&name[..base.len()]);
+            }
+        }
+    }
+
+    let trimmed = lowercase.trim_start_matches(['(', '[']);
+    let offset = lowercase.len() - trimmed.len();
+
+    for mode_name in mode_names(mode) {
+        if let Some(suffix) = trimmed.strip_prefix(mode_name) {
+            let base = suffix.trim_start_matches(SEPARATORS_AFTER);
+
+            if !base.is_empty() && base.len() < suffix.len() {
+                let start = offset + mode_name.len() + (suffix.len() - base.len());
+
+                return Some(&name[start..]);
+            }
+        }
+    }
+
+    None
+}
+
+/// Embed listing each mode variant of a medal, optionally with whether the
+/// user owns it.
+pub fn variants_embed(
+    medals: &ArchivedVec<ArchivedOsekaiMedal>,
+    variants: &MedalVariants,
+    user: Option<&CachedUser>,
+) -> EmbedBuilder {
+    let first = &medals[variants.indices[0]];
+
+    let owned = |medal: &ArchivedOsekaiMedal| {
+        user.map(|user| {
+            user.medals
+                .iter()
+                .any(|owned| owned.medal_id == medal.medal_id)
+        })
+    };
+
+    let mode_width = variants
+        .indices
+        .iter()
+        .filter_map(|&idx| medals[idx].mode.as_ref())
+        .map(|mode| mode_name(*mode).len())
+        .max()
+        .unwrap_or(0)
+        .max("Mode".len());
+
+    let mut description = String::with_capacity(256);
+    let _ = writeln!(description, "{}", first.description.as_str());
+    description.push_str("```\n");
+
+    let _ = write!(description, "{:<mode_width$} | Rarity ", "Mode");
+
+    if user.is_some() {
+        description.push_str("| Owned");
+    }
+
+    let _ = write!(description, "\n{:-<mode_width$}-+--------", "-");
+
+    if user.is_some() {
+        description.push_str("+------");
+    }
+
+    description.push('\n');
+
+    let mut owned_count = 0;
+
+    for &idx in variants.indices.iter() {
+        let medal = &medals[idx];
+        let mode = medal.mode.as_ref().map_or("Any", |mode| mode_name(*mode));
+
+        let rarity = medal
+            .rarity
+            .as_ref()
+            .copied()
+            .map_or(0.0, f32_le::to_native);
+
+        let _ = write!(description, "{mode:<mode_width$} | {rarity:>5.2}% ");
+
+        match owned(medal) {
+            Some(true) => {
+                owned_count += 1;
+                description.push_str("| ✓");
+            }
+            Some(false) => description.push_str("| ✗"),
+            None => {}
+        }
+
+        description.push('\n');
+    }
+
+    description.push_str("```");
+
+    let url = match first.url() {
+        Ok(url) => url,
+        Err(err) => {
+            warn!(?err);
+
+            first.backup_url()
+        }
+    };
+
+    let builder = EmbedBuilder::new()
+        .description(description)
+        .thumbnail(first.icon_url().to_string())
+        .title(variants.base_name.cow_escape_markdown().into_owned())
+        .url(url);
+
+    let Some(user) = user else {
+        let footer = format!("{} mode variants", variants.indices.len());
+
+        return builder.footer(FooterBuilder::new(footer));
+    };
+
+    let author = AuthorBuilder::new(user.username.as_str())
+        .url(format!("{OSU_BASE}users/{}", user.user_id.to_native()));
+
+    let footer = FooterBuilder::new(format!(
+        "Achieved {owned_count}/{} mode variants",
+        variants.indices.len()
+    ));
+
+    builder.author(author).footer(footer)
+}
+
+fn mode_name(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "osu!",
+        GameMode::Taiko => "osu!taiko",
+        GameMode::Catch => "osu!catch",
+        GameMode::Mania => "osu!mania",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `(medal_id, name, mode, group, ordering)` as listed by osekai.
+    type Medal = (u32, &'static str, Option<GameMode>, MedalGroup, u32);
+
+    const RISING_STAR: Medal = (
+        55,
+        "Rising Star",
+        Some(GameMode::Osu),
+        MedalGroup::SkillDedication,
+        1,
+    );
+
+    const MY_FIRST_DON: Medal = (
+        63,
+        "My First Don",
+        Some(GameMode::Taiko),
+        MedalGroup::SkillDedication,
+        1,
+    );
+
+    const MANIAC: Medal = (
+        86,
+        "Maniac",
+        Some(GameMode::Mania),
+        MedalGroup::SkillDedication,
+        8,
+    );
+
+    const COMBO_500: Medal = (
+        1,
+        "500 Combo",
+        Some(GameMode::Osu),
+        MedalGroup::SkillDedication,
+        1,
+    );
+
+    const WINTER_2023: [Medal; 4] = [
+        (
+            319,
+            "Beatmap Spotlights: Winter 2023 (osu!)",
+            Some(GameMode::Osu),
+            MedalGroup::SeasonalSpotlights,
+            1,
+        ),
+        (
+            320,
+            "Beatmap Spotlights: Winter 2023 (osu!taiko)",
+            Some(GameMode::Taiko),
+            MedalGroup::SeasonalSpotlights,
+            2,
+        ),
+        (
+            321,
+            "Beatmap Spotlights: Winter 2023 (osu!catch)",
+            Some(GameMode::Catch),
+            MedalGroup::SeasonalSpotlights,
+            3,
+        ),
+        (
+            322,
+            "Beatmap Spotlights: Winter 2023 (osu!mania)",
+            Some(GameMode::Mania),
+            MedalGroup::SeasonalSpotlights,
+            4,
+        ),
+    ];
+
+    fn keys(medals: &[Medal]) -> impl Iterator<Item = MedalKey<'static>> + '_ {
+        medals
+            .iter()
+            .map(|&(_, name, mode, group, ordering)| MedalKey {
+                name,
+                mode,
+                group,
+                ordering,
+            })
+    }
+
+    fn cluster_ids(medals: &[Medal]) -> Vec<Vec<u32>> {
+        cluster(keys(medals))
+            .iter()
+            .map(|variants| variants.indices.iter().map(|&idx| medals[idx].0).collect())
+            .collect()
+    }
+
+    #[test]
+    fn base_names() {
+        for (_, name, mode, ..) in WINTER_2023 {
+            let base = base_name(name, mode.unwrap());
+            assert_eq!(base, Some("Beatmap Spotlights: Winter 2023"), "{name}");
+        }
+
+        let cases = [
+            ("Rising Star", GameMode::Osu, None),
+            ("My First Don", GameMode::Taiko, None),
+            // Mode must match the medal's own mode
+            (WINTER_2023[1].1, GameMode::Mania, None),
+            // Mode as part of a word
+            ("Maniac", GameMode::Mania, None),
+        ];
+
+        for (name, mode, expected) in cases {
+            assert_eq!(base_name(name, mode), expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn clusters_mode_variants() {
+        let [osu, taiko, catch, mania] = WINTER_2023;
+        let medals = [
+            RISING_STAR,
+            mania,
+            osu,
+            MY_FIRST_DON,
+            taiko,
+            catch,
+            MANIAC,
+            COMBO_500,
+        ];
+
+        let clusters = cluster(keys(&medals));
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(&*clusters[0].base_name, "Beatmap Spotlights: Winter 2023");
+
+        assert_eq!(cluster_ids(&medals), [[319, 320, 321, 322]]);
+    }
+
+    #[test]
+    fn requires_matching_group_and_multiple_modes() {
+        let [osu, taiko, ..] = WINTER_2023;
+
+        // Only one mode
+        assert!(cluster_ids(&[osu, RISING_STAR]).is_empty());
+
+        // Same base name but different group
+        let other_group = (
+            taiko.0,
+            taiko.1,
+            taiko.2,
+            MedalGroup::BeatmapSpotlights,
+            taiko.4,
+        );
+        assert!(cluster_ids(&[osu, other_group]).is_empty());
+
+        // No mode at all
+        let no_mode = |(id, name, _, group, ordering): Medal| (id, name, None, group, ordering);
+        assert!(cluster_ids(&[no_mode(osu), no_mode(taiko)]).is_empty());
+    }
+
+    #[test]
+    fn duplicate_modes_keep_lowest_ordering() {
+        let [osu, _, _, mania] = WINTER_2023;
+
+        // The same medal listed twice, e.g. while osekai updates its ordering
+        let duplicate = (osu.0, osu.1, osu.2, osu.3, 7);
+
+        assert_eq!(cluster_ids(&[duplicate, osu, mania]), [[319, 322]]);
+    }
+}
@@ -12,8 +12,7 @@ macro_rules! user_id {
                     return $orig.error(content).await;
                 }
                 crate::commands::osu::UserIdFutureResult::Err(err) => {
-                    let content = bathbot_util::constants::GENERAL_ISSUE;
-                    let _ = $orig.error(content).await;
+                    let _ = $orig.error_tr(bathbot_util::i18n::ErrorKey::General).await;
 
                     return Err(err);
                 }
@@ -61,22 +60,29 @@ macro_rules! user_id_mode {
     }};
 }
 
-use std::{future::Future, pin::Pin};
+use std::{collections::HashMap, future::Future, pin::Pin};
 
-use bathbot_util::osu::ModsResult;
+use bathbot_psql::model::configs::{OsuUserId, UserConfig};
+use bathbot_util::{IntHasher, osu::ModsResult, tr};
 use eyre::{Report, Result, WrapErr};
-use rosu_v2::request::UserId;
+use rosu_v2::{
+    prelude::{GameMods, GameModsIntermode, Score},
+    request::UserId,
+};
 use twilight_interactions::command::{CommandOption, CreateOption};
 use twilight_model::id::{Id, marker::UserMarker};
 
 pub use self::{
-    badges::*, claim_name::*, compare::*, daily_challenge::*, fix::*, graphs::*, leaderboard::*,
-    map::*, map_search::*, match_compare::*, match_costs::*, medals::*, nochoke::*, osustats::*,
-    profile::*, recent::*, render::*, simulate::*, snipe::*, top::*, whatif::*,
+    badges::*, claim_name::*, compare::*, daily_challenge::*, firsts::*, fix::*, graphs::*,
+    leaderboard::*, map::*, map_search::*, mapwatch::*, match_compare::*, match_costs::*,
+    medals::*, nochoke::*, osustats::*, profile::*, recent::*, render::*, score::auto_score_embed,
+    simulate::*, snipe::*, top::*, whatif::*,
 };
 use crate::{
     Context,
+    active::impls::ScoreSkin,
     core::commands::{CommandOrigin, interaction::InteractionCommands},
+    manager::CachedAttributes,
 };
 
 mod attributes;
@@ -87,7 +93,10 @@ mod bws;
 mod cards;
 mod claim_name;
 mod compare;
+mod completion;
 mod daily_challenge;
+mod debug;
+mod firsts;
 mod fix;
 mod graphs;
 mod leaderboard;
@@ -96,6 +105,7 @@ mod link;
 mod map;
 mod map_search;
 mod mapper;
+mod mapwatch;
 mod match_compare;
 mod match_costs;
 mod medals;
@@ -110,8 +120,10 @@ mod rank;
 mod ranking;
 mod ratios;
 mod recent;
+mod record;
 pub(crate) mod relax;
 mod render;
+mod score;
 mod serverleaderboard;
 mod simulate;
 mod snipe;
@@ -164,20 +176,37 @@ impl UserIdFutureResult {
 }
 
 pub async fn require_link(orig: &CommandOrigin<'_>) -> Result<()> {
-    let link = InteractionCommands::get_command("link").map_or_else(
-        || "`/link`".to_owned(),
-        |cmd| cmd.mention("link").to_string(),
-    );
-
-    let content =
-        format!("Either specify an osu! username or link yourself to an osu! profile via {link}");
+    let link = InteractionCommands::mention_in(orig.guild_id(), "link");
+    let lang = Context::guild_config().lang(orig.guild_id()).await;
+    let content = tr!("link.required", lang, link = link);
 
     orig.error(content)
         .await
         .wrap_err("Failed to send require-link message")
 }
 
+/// Falls back to the linked user if no user id was given.
+///
+/// The skin is read beforehand because it belongs to the linked user.
+pub fn skin_and_user_id(
+    config: &mut UserConfig<OsuUserId>,
+    user_id: Option<UserId>,
+) -> (Option<ScoreSkin>, Option<UserId>) {
+    let skin = ScoreSkin::from_config(config);
+    let user_id = user_id.or_else(|| config.osu.take().map(UserId::Id));
+
+    (skin, user_id)
+}
+
 pub async fn user_not_found(user_id: UserId) -> String {
+    if let UserId::Id(user_id) = user_id {
+        match Context::osu_user().is_link_flagged(user_id).await {
+            Ok(true) => return user_not_found_content(UserId::Id(user_id), true),
+            Ok(false) => {}
+            Err(err) => warn!(?err, "Failed to check whether link is flagged"),
+        }
+    }
+
     let user_id = match user_id {
         user_id @ UserId::Name(_) => user_id,
         UserId::Id(user_id) => match Context::osu_user().name(user_id).await {
@@ -191,12 +220,80 @@ pub async fn user_not_found(user_id: UserId) -> String {
         },
     };
 
+    user_not_found_content(user_id, false)
+}
+
+fn user_not_found_content(user_id: UserId, flagged: bool) -> String {
     match user_id {
+        _ if flagged => "The linked osu! account appears to be restricted or renamed; \
+            use `/link` to re-link it"
+            .to_owned(),
         UserId::Name(name) => format!("User `{name}` was not found"),
         UserId::Id(user_id) => format!("User with id {user_id} was not found"),
     }
 }
 
+/// The author's blacklisted mods, only if the given osu! user is the author's
+/// own linked account.
+async fn own_blacklisted_mods(
+    orig: &CommandOrigin<'_>,
+    osu_user_id: u32,
+) -> Option<GameModsIntermode> {
+    let author_id = orig.user_id().ok()?;
+    let user_config = Context::user_config();
+
+    let (osu_id_res, mods_res) = tokio::join!(
+        user_config.osu_id(author_id),
+        user_config.blacklisted_mods(author_id)
+    );
+
+    match (osu_id_res, mods_res) {
+        (Ok(Some(osu_id)), Ok(mods)) if osu_id == osu_user_id => mods,
+        (Ok(_), Ok(_)) => None,
+        (Err(err), _) | (_, Err(err)) => {
+            warn!(?err, "Failed to get blacklisted mods");
+
+            None
+        }
+    }
+}
+
+/// The pp, star rating, and mods of each top score.
+///
+/// Scores whose map or difficulty is unavailable are skipped.
+async fn top_play_difficulties(scores: &[Score]) -> Result<Vec<(f32, f32, &GameMods)>> {
+    let maps_id_checksum: HashMap<_, _, IntHasher> = scores
+        .iter()
+        .map(|score| {
+            (
+                score.map_id as i32,
+                score.map.as_ref().and_then(|map| map.checksum.as_deref()),
+            )
+        })
+        .collect();
+
+    let mut maps = Context::osu_map().maps(&maps_id_checksum).await?;
+
+    if let Some(mode) = scores.first().map(|score| score.mode) {
+        maps.values_mut().for_each(|map| map.convert_mut(mode));
+    }
+
+    let mut cached_attrs = CachedAttributes::default();
+    let mut plays = Vec::with_capacity(scores.len());
+
+    for score in scores {
+        let (Some(pp), Some(map)) = (score.pp, maps.get(&score.map_id)) else {
+            continue;
+        };
+
+        if let Some(attrs) = cached_attrs.get(&map.pp_map, score).await {
+            plays.push((pp, attrs.stars() as f32, &score.mods));
+        }
+    }
+
+    Ok(plays)
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, CommandOption, CreateOption)]
 pub enum ScoreOrder {
     #[option(name = "Accuracy", value = "acc")]
@@ -236,3 +333,27 @@ enum UserExtraction {
     Content(String),
     None,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_not_found_content_flagged() {
+        let content = user_not_found_content(UserId::Id(2), true);
+
+        assert!(content.starts_with("The linked osu! account appears to be restricted"));
+    }
+
+    #[test]
+    fn user_not_found_content_unflagged() {
+        assert_eq!(
+            user_not_found_content(UserId::Name("peppy".into()), false),
+            "User `peppy` was not found"
+        );
+        assert_eq!(
+            user_not_found_content(UserId::Id(2), false),
+            "User with id 2 was not found"
+        );
+    }
+}
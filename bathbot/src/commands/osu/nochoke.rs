@@ -3,8 +3,13 @@ use std::borrow::Cow;
 use bathbot_macros::{HasName, SlashCommand, command};
 use bathbot_model::ScoreSlim;
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{constants::GENERAL_ISSUE, matcher, osu::calculate_grade};
-use eyre::{Report, Result};
+use bathbot_util::{
+    i18n::ErrorKey,
+    matcher,
+    osu::{adjusted_total_pp, calculate_accuracy, calculate_grade},
+};
+use eyre::{Report, Result, WrapErr};
+use futures::stream::{self, StreamExt};
 use rosu_pp::any::DifficultyAttributes;
 use rosu_v2::{
     prelude::{GameMode, GameMods, Grade, OsuError, Score, ScoreStatistics},
@@ -212,16 +217,10 @@ async fn nochoke(orig: CommandOrigin<'_>, args: Nochoke<'_>) -> Result<()> {
         Some(mode) => mode,
     };
 
-    let legacy_scores = match args.score_data.or(config.score_data) {
-        Some(score_data) => score_data.is_legacy(),
-        None => match orig.guild_id() {
-            Some(guild_id) => Context::guild_config()
-                .peek(guild_id, |config| config.score_data)
-                .await
-                .is_some_and(ScoreData::is_legacy),
-            None => false,
-        },
-    };
+    let legacy_scores = Context::guild_config()
+        .resolve_score_data(orig.guild_id(), args.score_data, config.score_data)
+        .await
+        .is_legacy();
 
     let Nochoke {
         miss_limit,
@@ -244,7 +243,7 @@ async fn nochoke(orig: CommandOrigin<'_>, args: Nochoke<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user or scores");
 
             return Err(err);
@@ -256,38 +255,31 @@ async fn nochoke(orig: CommandOrigin<'_>, args: Nochoke<'_>) -> Result<()> {
     let mut entries = match process_scores(scores, miss_limit, version).await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("failed to process scores"));
         }
     };
 
-    // Calculate bonus pp
-    let actual_pp: f32 = entries
+    let original_pps: Vec<_> = entries
         .iter()
         .map(|entry| entry.original_score.pp)
-        .zip(0..)
-        .fold(0.0, |sum, (pp, i)| sum + pp * 0.95_f32.powi(i));
-
-    let bonus_pp = user
-        .statistics
-        .as_ref()
-        .expect("missing stats")
-        .pp
-        .to_native()
-        - actual_pp;
+        .collect();
 
     // Sort by unchoked pp
     entries.sort_unstable_by(|a, b| b.unchoked_pp().total_cmp(&a.unchoked_pp()));
 
+    let unchoked_pps: Vec<_> = entries.iter().map(NochokeEntry::unchoked_pp).collect();
+
     // Calculate total user pp without chokes
-    let mut unchoked_pp: f32 = entries
-        .iter()
-        .map(NochokeEntry::unchoked_pp)
-        .zip(0..)
-        .fold(0.0, |sum, (pp, i)| sum + pp * 0.95_f32.powi(i));
+    let total_pp = user
+        .statistics
+        .as_ref()
+        .expect("missing stats")
+        .pp
+        .to_native();
 
-    unchoked_pp = (100.0 * (unchoked_pp + bonus_pp)).round() / 100.0;
+    let unchoked_pp = adjusted_total_pp(total_pp, &original_pps, &unchoked_pps);
 
     match filter {
         Some(NochokeFilter::OnlyChokes) => entries.retain(|entry| entry.unchoked.is_some()),
@@ -376,13 +368,12 @@ impl NochokeEntry {
     pub fn unchoked_accuracy(&self) -> f32 {
         self.unchoked
             .as_ref()
-            .map(|unchoked| match unchoked.max_statistics {
-                Some(ref max_stats) => unchoked
-                    .statistics
-                    .accuracy(self.original_score.mode, max_stats),
-                None => unchoked
-                    .statistics
-                    .legacy_accuracy(self.original_score.mode),
+            .map(|unchoked| {
+                calculate_accuracy(
+                    self.original_score.mode,
+                    &unchoked.statistics,
+                    unchoked.max_statistics.as_ref(),
+                )
             })
             .unwrap_or(self.original_score.accuracy)
     }
@@ -408,13 +399,14 @@ impl Unchoked {
     }
 }
 
+/// Amount of scores whose pp are calculated concurrently
+const CONCURRENT_CALCS: usize = 8;
+
 async fn process_scores(
     scores: Vec<Score>,
     miss_limit: Option<u32>,
     version: NochokeVersion,
 ) -> Result<Vec<NochokeEntry>> {
-    let mut entries = Vec::with_capacity(scores.len());
-
     let maps_id_checksum = scores
         .iter()
         .filter_map(|score| score.map.as_ref())
@@ -424,69 +416,94 @@ async fn process_scores(
     let mut maps = Context::osu_map().maps(&maps_id_checksum).await?;
     let miss_limit = miss_limit.unwrap_or(u32::MAX);
 
-    for (i, score) in scores.into_iter().enumerate() {
-        let Some(mut map) = maps.remove(&score.map_id) else {
-            continue;
-        };
-        map = map.convert(score.mode);
-
-        let attrs = Context::pp(&map)
-            .lazer(score.set_on_lazer)
-            .mode(score.mode)
-            .mods(score.mods.clone())
-            .performance()
-            .await;
-
-        let pp = score.pp.unwrap_or(0.0);
-
-        let mut max_pp = 0.0;
-        let mut stars = 0.0;
-        let mut max_combo = 0;
-
-        if let Some(attrs) = attrs {
-            max_pp = attrs.pp() as f32;
-            stars = attrs.stars() as f32;
-            max_combo = attrs.max_combo();
-        }
+    let scores_with_maps: Vec<_> = scores
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, score)| {
+            let map = maps.remove(&score.map_id)?;
 
-        if score.grade.eq_letter(Grade::X) && score.mode != GameMode::Mania && pp > 0.0 {
-            max_pp = pp;
-        }
+            Some((i, score, map))
+        })
+        .collect();
+
+    let mut entries = Vec::with_capacity(scores_with_maps.len());
+
+    let mut stream = stream::iter(scores_with_maps)
+        .map(|(i, score, map)| tokio::spawn(process_score(i, score, map, miss_limit, version)))
+        .buffered(CONCURRENT_CALCS);
 
-        let score = ScoreSlim::new(score, pp);
-        let too_many_misses = score.statistics.miss > miss_limit;
-
-        let unchoked = match version {
-            NochokeVersion::Unchoke if too_many_misses => None,
-            // Skip unchoking because it has too many misses or because its a convert
-            NochokeVersion::Unchoke => IfFc::new(&score, &map)
-                .await
-                .map(|if_fc| Unchoked::new(if_fc, &score.mods, score.mode)),
-            NochokeVersion::Perfect if too_many_misses => None,
-            NochokeVersion::Perfect => perfect_score(&score, &map).await,
-        };
-
-        let entry = NochokeEntry {
-            original_idx: i,
-            original_score: score,
-            unchoked,
-            map,
-            max_pp,
-            stars,
-            max_combo,
-        };
-
-        entries.push(entry);
+    while let Some(res) = stream.next().await {
+        entries.push(res.wrap_err("Failed to join score processing task")?);
     }
 
     Ok(entries)
 }
 
-/// Returns `None` if the map is too suspicious.
-async fn perfect_score(score: &ScoreSlim, map: &OsuMap) -> Option<Unchoked> {
+async fn process_score(
+    idx: usize,
+    score: Score,
+    map: OsuMap,
+    miss_limit: u32,
+    version: NochokeVersion,
+) -> NochokeEntry {
+    let map = map.convert(score.mode);
+
+    let mut calc = Context::pp(&map)
+        .lazer(score.set_on_lazer)
+        .mode(score.mode)
+        .mods(score.mods.clone());
+
+    let attrs = calc.performance().await;
+
+    // Already calculated for the performance so this is just a clone
+    let difficulty = calc.difficulty().await.cloned();
+
+    let pp = score.pp.unwrap_or(0.0);
+
+    let mut max_pp = 0.0;
+    let mut stars = 0.0;
+    let mut max_combo = 0;
+
+    if let Some(attrs) = attrs {
+        max_pp = attrs.pp() as f32;
+        stars = attrs.stars() as f32;
+        max_combo = attrs.max_combo();
+    }
+
+    if score.grade.eq_letter(Grade::X) && score.mode != GameMode::Mania && pp > 0.0 {
+        max_pp = pp;
+    }
+
+    let score = ScoreSlim::new(score, pp);
+    let too_many_misses = score.statistics.miss > miss_limit;
+
+    let unchoked = match (version, difficulty) {
+        // Skip unchoking because it has too many misses or because its a convert
+        (_, None) => None,
+        (NochokeVersion::Unchoke, _) if too_many_misses => None,
+        (NochokeVersion::Unchoke, Some(ref attrs)) => IfFc::with_difficulty(&score, &map, attrs)
+            .map(|if_fc| Unchoked::new(if_fc, &score.mods, score.mode)),
+        (NochokeVersion::Perfect, _) if too_many_misses => None,
+        (NochokeVersion::Perfect, Some(ref attrs)) => perfect_score(&score, &map, attrs),
+    };
+
+    NochokeEntry {
+        original_idx: idx,
+        original_score: score,
+        unchoked,
+        map,
+        max_pp,
+        stars,
+        max_combo,
+    }
+}
+
+fn perfect_score(
+    score: &ScoreSlim,
+    map: &OsuMap,
+    attrs: &DifficultyAttributes,
+) -> Option<Unchoked> {
     let total_hits = score.total_hits();
-    let mut calc = Context::pp(map).mode(score.mode).mods(score.mods.clone());
-    let attrs = calc.difficulty().await?;
 
     let stats = match attrs {
         DifficultyAttributes::Osu(attrs) if score.statistics.great != total_hits => {
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use bathbot_model::{Countries, MedalCount, OsekaiUserEntry};
-use bathbot_util::{Authored, constants::GENERAL_ISSUE};
+use bathbot_util::{Authored, i18n::ErrorKey};
 use eyre::{Report, Result};
 
 use super::OsekaiMedalCount;
@@ -42,7 +42,7 @@ pub(super) async fn medal_count(
     let mut ranking = match osekai_res {
         Ok(ranking) => ranking.try_deserialize::<Vec<OsekaiUserEntry>>().unwrap(),
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get cached medal count ranking"));
         }
@@ -1,5 +1,5 @@
 use bathbot_model::Rarity;
-use bathbot_util::{Authored, constants::GENERAL_ISSUE};
+use bathbot_util::{Authored, i18n::ErrorKey};
 use eyre::{Report, Result};
 
 use crate::{
@@ -12,7 +12,7 @@ pub(super) async fn rarity(mut command: InteractionCommand) -> Result<()> {
     let ranking = match Context::redis().osekai_ranking::<Rarity>().await {
         Ok(ranking) => ranking,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get cached rarity ranking"));
         }
@@ -4,7 +4,7 @@ use bathbot_model::{
     ArchivedOsekaiRankingEntry, Countries, OsekaiRanking, OsekaiRankingEntry, RankingEntries,
     RankingEntry, RankingKind,
 };
-use bathbot_util::{Authored, constants::GENERAL_ISSUE};
+use bathbot_util::{Authored, i18n::ErrorKey};
 use eyre::{Report, Result};
 use rkyv::{
     Archive,
@@ -54,7 +54,7 @@ where
     let ranking = match osekai_res {
         Ok(ranking) => ranking,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get cached osekai ranking"));
         }
@@ -109,7 +109,7 @@ where
     let ranking = match osekai_res {
         Ok(ranking) => ranking,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get cached osekai ranking"));
         }
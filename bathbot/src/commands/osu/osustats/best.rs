@@ -1,21 +1,67 @@
-use bathbot_util::constants::GENERAL_ISSUE;
-use eyre::Result;
-use rosu_v2::prelude::GameMode;
+use std::{cmp::Ordering, mem};
+
+use bathbot_model::{OsuStatsBestScore, OsuStatsBestTimeframe};
+use bathbot_util::i18n::ErrorKey;
+use eyre::{Report, Result};
+use rosu_v2::prelude::{GameMode, OsuError};
 
 use super::{OsuStatsBest, OsuStatsBestSort};
 use crate::{
-    active::{ActiveMessages, impls::OsuStatsBestPagination},
-    core::{Context, commands::CommandOrigin},
+    active::{
+        ActiveMessages,
+        impls::{OsuStatsBestPagination, OsuStatsBestUser},
+    },
+    commands::osu::user_not_found,
+    core::{Context, Service, commands::CommandOrigin},
+    manager::redis::osu::{UserArgs, UserArgsError},
 };
 
-pub(super) async fn recentbest(orig: CommandOrigin<'_>, args: OsuStatsBest) -> Result<()> {
+pub(super) async fn recentbest(orig: CommandOrigin<'_>, args: OsuStatsBest<'_>) -> Result<()> {
+    let notice_fut = Context::feature_flags().disabled_notice(Service::OsuStats);
+
+    if let Some(content) = notice_fut.await {
+        return orig.error(content).await;
+    }
+
     let mode = args.mode.map(GameMode::from).unwrap_or(GameMode::Osu);
+
+    let user_id = user_id!(orig, args);
+
+    let user_fut = async {
+        match user_id {
+            Some(ref user_id) => {
+                let user_args = UserArgs::rosu_id(user_id, mode).await;
+
+                Context::redis().osu_user(user_args).await.map(Some)
+            }
+            None => Ok(None),
+        }
+    };
+
     let scores_fut = Context::redis().osustats_best(args.timeframe, mode);
 
-    let mut scores = match scores_fut.await {
+    let (user_res, scores_res) = tokio::join!(user_fut, scores_fut);
+
+    let user = match user_res {
+        Ok(user) => user,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            // Only reachable if a user was specified
+            let content = user_not_found(user_id.unwrap()).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error_tr(ErrorKey::General).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let mut scores = match scores_res {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -23,41 +69,56 @@ pub(super) async fn recentbest(orig: CommandOrigin<'_>, args: OsuStatsBest) -> R
 
     let sort = args.sort.unwrap_or_default();
 
-    match sort {
-        OsuStatsBestSort::Accuracy => scores.scores.sort_unstable_by(|a, b| {
-            b.accuracy
-                .total_cmp(&a.accuracy)
-                .then_with(|| a.ended_at.cmp(&b.ended_at))
-        }),
-        OsuStatsBestSort::Combo => scores.scores.sort_unstable_by(|a, b| {
-            b.max_combo
-                .cmp(&a.max_combo)
-                .then_with(|| a.ended_at.cmp(&b.ended_at))
-        }),
-        OsuStatsBestSort::Date => scores.scores.sort_unstable_by_key(|score| score.ended_at),
-        OsuStatsBestSort::LeaderboardPosition => scores.scores.sort_unstable_by(|a, b| {
-            a.position
-                .cmp(&b.position)
-                .then_with(|| a.ended_at.cmp(&b.ended_at))
-        }),
-        OsuStatsBestSort::Misses => scores.scores.sort_unstable_by(|a, b| {
-            b.count_miss
-                .cmp(&a.count_miss)
-                .then_with(|| a.ended_at.cmp(&b.ended_at))
-        }),
-        OsuStatsBestSort::Pp => scores.scores.sort_unstable_by(|a, b| {
-            b.pp.total_cmp(&a.pp)
-                .then_with(|| a.ended_at.cmp(&b.ended_at))
-        }),
-        OsuStatsBestSort::Score => scores.scores.sort_unstable_by(|a, b| {
-            b.score
-                .cmp(&a.score)
-                .then_with(|| a.ended_at.cmp(&b.ended_at))
-        }),
-    }
+    // The cached scores are shared so the user filter is applied afterwards.
+    // Placements refer to the cached order so they're computed before sorting.
+    let user = match user {
+        Some(user) => {
+            let user_id = user.user_id.to_native();
+            let total = scores.scores.len();
+            let placements = placements(&scores.scores, |score| score.user.user_id == user_id);
+
+            if placements.is_empty() {
+                let content = format!(
+                    "None of {name}'s scores made the top {total} {timeframe}",
+                    name = user.username,
+                    timeframe = match args.timeframe {
+                        OsuStatsBestTimeframe::Yesterday => "yesterday",
+                        OsuStatsBestTimeframe::LastWeek => "last week",
+                        OsuStatsBestTimeframe::LastMonth => "last month",
+                    },
+                );
+
+                return orig.error(content).await;
+            }
+
+            let mut entries: Vec<_> = mem::take(&mut scores.scores)
+                .into_vec()
+                .into_iter()
+                .filter(|score| score.user.user_id == user_id)
+                .zip(placements)
+                .collect();
+
+            entries.sort_unstable_by(|(a, _), (b, _)| compare(sort, a, b));
+
+            let (filtered, placements): (Vec<_>, Vec<_>) = entries.into_iter().unzip();
+            scores.scores = filtered.into_boxed_slice();
+
+            Some(OsuStatsBestUser::new(
+                user,
+                placements.into_boxed_slice(),
+                total,
+            ))
+        }
+        None => {
+            scores.scores.sort_unstable_by(|a, b| compare(sort, a, b));
+
+            None
+        }
+    };
 
     let pagination = OsuStatsBestPagination::builder()
         .scores(scores)
+        .user(user)
         .mode(mode)
         .sort(sort)
         .msg_owner(orig.user_id()?)
@@ -68,3 +129,40 @@ pub(super) async fn recentbest(orig: CommandOrigin<'_>, args: OsuStatsBest) -> R
         .begin(orig)
         .await
 }
+
+fn compare(sort: OsuStatsBestSort, a: &OsuStatsBestScore, b: &OsuStatsBestScore) -> Ordering {
+    let by_date = || a.ended_at.cmp(&b.ended_at);
+
+    match sort {
+        OsuStatsBestSort::Accuracy => b.accuracy.total_cmp(&a.accuracy).then_with(by_date),
+        OsuStatsBestSort::Combo => b.max_combo.cmp(&a.max_combo).then_with(by_date),
+        OsuStatsBestSort::Date => by_date(),
+        OsuStatsBestSort::LeaderboardPosition => a.position.cmp(&b.position).then_with(by_date),
+        OsuStatsBestSort::Misses => b.count_miss.cmp(&a.count_miss).then_with(by_date),
+        OsuStatsBestSort::Pp => b.pp.total_cmp(&a.pp).then_with(by_date),
+        OsuStatsBestSort::Score => b.score.cmp(&a.score).then_with(by_date),
+    }
+}
+
+/// 1-based positions of all entries that match the predicate.
+fn placements<T>(scores: &[T], is_user: impl Fn(&T) -> bool) -> Box<[usize]> {
+    scores
+        .iter()
+        .zip(1..)
+        .filter_map(|(score, i)| is_user(score).then_some(i))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn placements_within_full_list() {
+        let user_ids = [5, 2, 7, 2, 2, 9, 1, 2];
+
+        assert_eq!(&*placements(&user_ids, |&id| id == 2), [2, 4, 5, 8]);
+        assert_eq!(&*placements(&user_ids, |&id| id == 9), [6]);
+        assert!(placements(&user_ids, |&id| id == 3).is_empty());
+    }
+}
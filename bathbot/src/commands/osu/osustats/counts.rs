@@ -2,11 +2,7 @@ use std::borrow::Cow;
 
 use bathbot_macros::{HasName, SlashCommand, command};
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_util::{
-    MessageBuilder,
-    constants::{GENERAL_ISSUE, OSUSTATS_API_ISSUE},
-    matcher,
-};
+use bathbot_util::{MessageBuilder, constants::OSUSTATS_API_ISSUE, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use rosu_v2::prelude::OsuError;
 use twilight_interactions::command::{CommandModel, CreateCommand};
@@ -16,7 +12,10 @@ use super::OsuStatsCount;
 use crate::{
     Context,
     commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP, osu::user_not_found},
-    core::commands::{CommandOrigin, prefix::Args},
+    core::{
+        Service,
+        commands::{CommandOrigin, prefix::Args},
+    },
     embeds::{EmbedData, OsuStatsCountsEmbed},
     manager::redis::osu::{UserArgs, UserArgsError},
     util::{InteractionCommandExt, interaction::InteractionCommand, osu::TopCounts},
@@ -148,6 +147,12 @@ async fn slash_osc(mut command: InteractionCommand) -> Result<()> {
 }
 
 pub(super) async fn count(orig: CommandOrigin<'_>, args: OsuStatsCount<'_>) -> Result<()> {
+    let notice_fut = Context::feature_flags().disabled_notice(Service::OsuStats);
+
+    if let Some(content) = notice_fut.await {
+        return orig.error(content).await;
+    }
+
     let (user_id, mode) = user_id_mode!(orig, args);
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
@@ -159,7 +164,7 @@ pub(super) async fn count(orig: CommandOrigin<'_>, args: OsuStatsCount<'_>) -> R
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -1,4 +1,9 @@
-use std::{borrow::Cow, collections::BTreeMap, fmt::Write, ops::Not};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+    ops::Not,
+};
 
 use bathbot_macros::command;
 use bathbot_model::{
@@ -6,10 +11,7 @@ use bathbot_model::{
     command_fields::GameModeOption,
 };
 use bathbot_util::{
-    CowUtils,
-    constants::{GENERAL_ISSUE, OSUSTATS_API_ISSUE},
-    matcher,
-    osu::ModSelection,
+    CowUtils, constants::OSUSTATS_API_ISSUE, i18n::ErrorKey, matcher, osu::ModSelection,
 };
 use eyre::{Report, Result};
 use rosu_v2::prelude::{GameModIntermode, GameMode, Grade, OsuError, ScoreStatistics, Username};
@@ -19,7 +21,10 @@ use crate::{
     Context,
     active::{ActiveMessages, impls::OsuStatsScoresPagination},
     commands::osu::{HasMods, ModsResult, user_not_found},
-    core::commands::{CommandOrigin, prefix::Args},
+    core::{
+        Service,
+        commands::{CommandOrigin, prefix::Args},
+    },
     manager::{
         OsuMap,
         redis::osu::{UserArgs, UserArgsError},
@@ -29,7 +34,7 @@ use crate::{
 
 const OSG_USAGE: &str = "[username] [mods] [acc=[number..]number] \
 [rank=[integer..]integer] [sort=acc/combo/date/misses/pp/rank/score] \
-[reverse=true/false]";
+[reverse=true/false] [p<page>]";
 
 #[command]
 #[desc("All scores of a player that are on a map's global leaderboard")]
@@ -42,6 +47,7 @@ const OSG_USAGE: &str = "[username] [mods] [acc=[number..]number] \
     - `rank`: single integer or two integers of the form `a..b` e.g. `rank=2..45`\n\
     - `sort`: `acc`, `combo`, `date` (default), `misses`, `pp`, `rank`, or `score`\n\
     - `reverse`: `true` or `false` (default)\n\
+    To start on a specific page, add e.g. `p3` as last argument.\n\
     Check https://osustats.ppy.sh/ for more info."
 )]
 #[usage(OSG_USAGE)]
@@ -73,6 +79,7 @@ async fn prefix_osustatsglobals(msg: &Message, args: Args<'_>) -> Result<()> {
     - `rank`: single integer or two integers of the form `a..b` e.g. `rank=2..45`\n\
     - `sort`: `acc`, `combo`, `date` (default), `misses`, `pp`, `rank`, or `score`\n\
     - `reverse`: `true` or `false` (default)\n\
+    To start on a specific page, add e.g. `p3` as last argument.\n\
     Check https://osustats.ppy.sh/ for more info."
 )]
 #[usage(OSG_USAGE)]
@@ -104,6 +111,7 @@ async fn prefix_osustatsglobalsmania(msg: &Message, args: Args<'_>) -> Result<()
     - `rank`: single integer or two integers of the form `a..b` e.g. `rank=2..45`\n\
     - `sort`: `acc`, `combo`, `date` (default), `misses`, `pp`, `rank`, or `score`\n\
     - `reverse`: `true` or `false` (default)\n\
+    To start on a specific page, add e.g. `p3` as last argument.\n\
     Check https://osustats.ppy.sh/ for more info."
 )]
 #[usage(OSG_USAGE)]
@@ -135,6 +143,7 @@ async fn prefix_osustatsglobalstaiko(msg: &Message, args: Args<'_>) -> Result<()
     - `rank`: single integer or two integers of the form `a..b` e.g. `rank=2..45`\n\
     - `sort`: `acc`, `combo`, `date` (default), `misses`, `pp`, `rank`, or `score`\n\
     - `reverse`: `true` or `false` (default)\n\
+    To start on a specific page, add e.g. `p3` as last argument.\n\
     Check https://osustats.ppy.sh/ for more info."
 )]
 #[usage(OSG_USAGE)]
@@ -156,10 +165,26 @@ async fn prefix_osustatsglobalsctb(msg: &Message, args: Args<'_>) -> Result<()>
 }
 
 pub(super) async fn scores(orig: CommandOrigin<'_>, args: OsuStatsScores<'_>) -> Result<()> {
+    let notice_fut = Context::feature_flags().disabled_notice(Service::OsuStats);
+
+    if let Some(content) = notice_fut.await {
+        return orig.error(content).await;
+    }
+
     let mods = match args.mods() {
         ModsResult::Mods(mods) => Some(mods),
         ModsResult::None => None,
-        ModsResult::Invalid => return orig.error(OsuStatsScores::ERR_PARSE_MODS).await,
+        ModsResult::Invalid => {
+            let content = args
+                .mods
+                .as_deref()
+                .and_then(ModSelection::unknown_mods_message);
+
+            return match content {
+                Some(content) => orig.error(content).await,
+                None => orig.error(OsuStatsScores::ERR_PARSE_MODS).await,
+            };
+        }
     };
 
     let (user_id, mode) = user_id_mode!(orig, args);
@@ -174,13 +199,15 @@ pub(super) async fn scores(orig: CommandOrigin<'_>, args: OsuStatsScores<'_>) ->
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
         }
     };
 
+    let page = args.page;
+    let command = args.invocation(user.username.as_str(), mode);
     let params = args.into_params(user.username.as_str().into(), mode, mods);
     let scores_fut = Context::client().get_global_scores(&params);
 
@@ -197,7 +224,7 @@ pub(super) async fn scores(orig: CommandOrigin<'_>, args: OsuStatsScores<'_>) ->
     let entries = match process_scores(scores, mode).await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to process scores"));
         }
@@ -250,8 +277,11 @@ pub(super) async fn scores(orig: CommandOrigin<'_>, args: OsuStatsScores<'_>) ->
         .entries(entries)
         .total(amount)
         .params(params)
+        .loaded_pages(BTreeSet::from([1]))
         .content(content.into_boxed_str())
         .msg_owner(orig.user_id()?)
+        .command(Some(command))
+        .start_page(page.map(|page| page as usize))
         .build();
 
     ActiveMessages::builder(pagination)
@@ -317,12 +347,28 @@ impl<'m> OsuStatsScores<'m> {
         let mut mods = None;
         let mut reverse = None;
 
-        for arg in args.map(|arg| arg.cow_to_ascii_lowercase()) {
-            if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
-                let key = &arg[..idx];
-                let value = arg[idx + 1..].trim_end();
+        let mut args: Vec<_> = args.into_args().collect();
 
-                match key {
+        // A trailing `p<number>` specifies the page to start on
+        let page = args
+            .last()
+            .and_then(|arg| matcher::get_page(&arg.value))
+            .map(|page| page as u32);
+
+        if page.is_some() {
+            args.pop();
+        }
+
+        for arg in args {
+            let key_value = arg.key_value();
+            let arg = arg.into_lowercase();
+
+            if let Some((key, value)) = key_value {
+                let key = key.cow_to_ascii_lowercase();
+                let value = value.cow_to_ascii_lowercase();
+                let value = value.trim_end();
+
+                match key.as_ref() {
                     "acc" | "accuracy" | "a" => match value.find("..") {
                         Some(idx) => {
                             let bot = &value[..idx];
@@ -406,9 +452,16 @@ impl<'m> OsuStatsScores<'m> {
                             return Err(content.into());
                         }
                     },
-                    "mods" => match matcher::get_mods(value) {
-                        Some(_) => mods = Some(format!("+{value}!").into()),
-                        None => return Err(Self::ERR_PARSE_MODS.into()),
+                    "mods" => match matcher::try_get_mods(value) {
+                        Some(Ok(_)) => mods = Some(value.to_owned().into()),
+                        Some(Err(content)) => return Err(content.into()),
+                        None => match ModSelection::unknown_mods_message(value) {
+                            Some(content) => return Err(content.into()),
+                            None if value.starts_with(['+', '-']) => {
+                                return Err(Self::ERR_PARSE_MODS.into());
+                            }
+                            None => mods = Some(format!("+{value}!").into()),
+                        },
                     },
                     _ => {
                         let content = format!(
@@ -419,7 +472,11 @@ impl<'m> OsuStatsScores<'m> {
                         return Err(content.into());
                     }
                 }
-            } else if matcher::get_mods(&arg).is_some() {
+            } else if let Some(mods_) = matcher::try_get_mods(&arg) {
+                if let Err(content) = mods_ {
+                    return Err(content.into());
+                }
+
                 mods = Some(arg);
             } else if let Some(id) = matcher::get_mention_user(&arg) {
                 discord = Some(id);
@@ -439,8 +496,55 @@ impl<'m> OsuStatsScores<'m> {
             max_acc,
             reverse,
             discord,
+            page,
         })
     }
+
+    /// Slash command invocation that reproduces the listed scores.
+    fn invocation(&self, username: &str, mode: GameMode) -> String {
+        let mode = GameModeOption::from(mode).value();
+        let mut command = format!("/osustats scores name:{username} mode:{mode}");
+
+        if let Some(sort) = self.sort {
+            let sort = match sort {
+                OsuStatsScoresOrder::Acc => "acc",
+                OsuStatsScoresOrder::Combo => "combo",
+                OsuStatsScoresOrder::Date => "date",
+                OsuStatsScoresOrder::Misses => "misses",
+                OsuStatsScoresOrder::Pp => "pp",
+                OsuStatsScoresOrder::Rank => "rank",
+                OsuStatsScoresOrder::Score => "score",
+            };
+
+            let _ = write!(command, " sort:{sort}");
+        }
+
+        if let Some(ref mods) = self.mods {
+            let _ = write!(command, " mods:{mods}");
+        }
+
+        if let Some(min_rank) = self.min_rank {
+            let _ = write!(command, " min_rank:{min_rank}");
+        }
+
+        if let Some(max_rank) = self.max_rank {
+            let _ = write!(command, " max_rank:{max_rank}");
+        }
+
+        if let Some(min_acc) = self.min_acc {
+            let _ = write!(command, " min_acc:{min_acc}");
+        }
+
+        if let Some(max_acc) = self.max_acc {
+            let _ = write!(command, " max_acc:{max_acc}");
+        }
+
+        if let Some(reverse) = self.reverse {
+            let _ = write!(command, " reverse:{reverse}");
+        }
+
+        command
+    }
 }
 
 pub struct OsuStatsEntry {
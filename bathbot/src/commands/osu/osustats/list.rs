@@ -4,10 +4,7 @@ use bathbot_macros::command;
 use bathbot_model::{
     Countries, OsuStatsPlayer, OsuStatsPlayersArgs, command_fields::GameModeOption,
 };
-use bathbot_util::{
-    CowUtils, IntHasher,
-    constants::{GENERAL_ISSUE, OSUSTATS_API_ISSUE},
-};
+use bathbot_util::{CowUtils, IntHasher, constants::OSUSTATS_API_ISSUE, i18n::ErrorKey};
 use eyre::Result;
 use rosu_v2::{model::GameMode, prelude::CountryCode};
 
@@ -15,7 +12,10 @@ use super::OsuStatsPlayers;
 use crate::{
     Context,
     active::{ActiveMessages, impls::OsuStatsPlayersPagination},
-    core::commands::{CommandOrigin, prefix::Args},
+    core::{
+        Service,
+        commands::{CommandOrigin, prefix::Args},
+    },
     util::ChannelExt,
 };
 
@@ -32,13 +32,19 @@ impl<'a> From<OsuStatsPlayers<'a>> for OsuStatsPlayersArgs {
 }
 
 pub(super) async fn players(orig: CommandOrigin<'_>, mut args: OsuStatsPlayers<'_>) -> Result<()> {
+    let notice_fut = Context::feature_flags().disabled_notice(Service::OsuStats);
+
+    if let Some(content) = notice_fut.await {
+        return orig.error(content).await;
+    }
+
     let owner = orig.user_id()?;
 
     if args.mode.is_none() {
         args.mode = match Context::user_config().mode(owner).await {
             Ok(mode) => mode.map(GameModeOption::from),
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -48,19 +54,14 @@ pub(super) async fn players(orig: CommandOrigin<'_>, mut args: OsuStatsPlayers<'
     let mut params = OsuStatsPlayersArgs::from(args);
 
     if let Some(country) = params.country.as_mut() {
-        if country.len() != 2 {
-            match Countries::name(&*country).to_code() {
-                Some(code) => *country = CountryCode::from(code),
-                None => {
-                    let content = format!(
-                        "Looks like `{country}` is neither a country name nor a country code"
-                    );
-
-                    return orig.error(content).await;
-                }
+        match Countries::resolve(country.as_str()) {
+            Some(resolved) => *country = resolved.to_country_code(),
+            None => {
+                let content =
+                    format!("Looks like `{country}` is neither a country name nor a country code");
+
+                return orig.error(content).await;
             }
-        } else {
-            *country = country.to_uppercase().into()
         }
     }
 
@@ -347,12 +348,16 @@ impl<'m> OsuStatsPlayers<'m> {
         let mut min_rank = None;
         let mut max_rank = None;
 
-        for arg in args.take(2).map(|arg| arg.cow_to_ascii_lowercase()) {
-            if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
-                let key = &arg[..idx];
-                let value = arg[idx + 1..].trim_end();
+        for arg in args.into_args().take(2) {
+            let key_value = arg.key_value();
+            let arg = arg.into_lowercase();
+
+            if let Some((key, value)) = key_value {
+                let key = key.cow_to_ascii_lowercase();
+                let value = value.cow_to_ascii_lowercase();
+                let value = value.trim_end();
 
-                match key {
+                match key.as_ref() {
                     "rank" | "r" => match value.find("..") {
                         Some(idx) => {
                             let bot = &value[..idx];
@@ -386,10 +391,9 @@ impl<'m> OsuStatsPlayers<'m> {
                         return Err(content.into());
                     }
                 }
-            } else if arg.len() == 2 && arg.is_ascii() {
+            } else if Countries::resolve(arg.as_ref()).is_some() {
+                // Resolved again once the arguments are converted into params
                 country = Some(arg);
-            } else if let Some(code) = Countries::name(arg.as_ref()).to_code() {
-                country = Some(code.into());
             } else {
                 let content = format!(
                     "Failed to parse `{arg}` as either rank or country.\n\
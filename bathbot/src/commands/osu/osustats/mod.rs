@@ -34,7 +34,7 @@ pub enum OsuStats<'a> {
     #[command(name = "scores")]
     Scores(OsuStatsScores<'a>),
     #[command(name = "best")]
-    Best(OsuStatsBest),
+    Best(OsuStatsBest<'a>),
 }
 
 #[derive(CommandModel, CreateCommand, Default, HasName)]
@@ -119,17 +119,28 @@ pub struct OsuStatsScores<'a> {
     reverse: Option<bool>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(min_value = 1, desc = "Specify a page to start on")]
+    page: Option<u32>,
 }
 
-#[derive(CommandModel, CreateCommand)]
-#[command(name = "best", desc = "Global top scores of a certain timeframe")]
-pub struct OsuStatsBest {
+#[derive(CommandModel, CreateCommand, HasName)]
+#[command(
+    name = "best",
+    desc = "Global top scores of a certain timeframe",
+    help = "Global top scores of a certain timeframe.\n\
+    If a user is specified, only their scores are shown alongside their placement in the full list."
+)]
+pub struct OsuStatsBest<'a> {
     #[command(desc = "Only show scores of this timeframe")]
     timeframe: OsuStatsBestTimeframe,
     #[command(desc = "Specify a gamemode")]
     mode: Option<GameModeOption>,
     #[command(desc = "Choose how the scores should be ordered")]
     sort: Option<OsuStatsBestSort>,
+    #[command(desc = "Only show scores of this user")]
+    name: Option<Cow<'a, str>>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
 }
 
 #[derive(Copy, Clone, CommandOption, CreateOption, Default)]
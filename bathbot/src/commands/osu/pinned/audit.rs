@@ -0,0 +1,448 @@
+use std::{borrow::Cow, collections::HashMap, fmt::Write};
+
+use bathbot_macros::HasName;
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_util::{
+    EmbedBuilder, FooterBuilder, IntHasher, MessageBuilder, constants::OSU_BASE, i18n::ErrorKey,
+};
+use eyre::{Report, Result};
+use rosu_v2::{
+    prelude::{GameMode, OsuError, RankStatus, Score},
+    request::UserId,
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use crate::{
+    Context,
+    commands::{
+        DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
+        osu::{require_link, user_not_found},
+    },
+    core::commands::CommandOrigin,
+    manager::{
+        OsuMap,
+        redis::osu::{UserArgs, UserArgsError, UserArgsSlim},
+    },
+    util::CachedUserExt,
+};
+
+/// Only list this many entries per issue to stay within embed limits.
+const MAX_LISTED: usize = 10;
+
+#[derive(CommandModel, CreateCommand, HasName)]
+#[command(
+    name = "audit",
+    desc = "Check the user's pinned scores for outdated or duplicate pins",
+    help = "Check the user's pinned scores for pins that should probably be adjusted.\n\
+    The check looks for pinned scores that are no longer in the top200, \
+    pinned scores on maps that are no longer ranked, and multiple pins on the same map."
+)]
+pub struct PinnedAudit<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+}
+
+pub(super) async fn audit(orig: CommandOrigin<'_>, args: PinnedAudit<'_>) -> Result<()> {
+    let msg_owner = orig.user_id()?;
+
+    let mut config = match Context::user_config().with_osu_id(msg_owner).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let mode = args
+        .mode
+        .map(GameMode::from)
+        .or(config.mode)
+        .unwrap_or(GameMode::Osu);
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match config.osu.take() {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+
+    let user = match user_args {
+        UserArgs::Args(args) => match Context::redis().osu_user_from_args(args).await {
+            Ok(user) => user,
+            Err(UserArgsError::Osu(OsuError::NotFound)) => {
+                let content = user_not_found(user_id).await;
+
+                return orig.error(content).await;
+            }
+            Err(err) => {
+                let _ = orig.error_tr(ErrorKey::General).await;
+                let err = Report::new(err).wrap_err("Failed to get user");
+
+                return Err(err);
+            }
+        },
+        UserArgs::User { user, .. } => user,
+        UserArgs::Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        UserArgs::Err(err) => {
+            let _ = orig.error_tr(ErrorKey::General).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let user_args = UserArgsSlim::user_id(user.user_id.to_native()).mode(mode);
+    let legacy_scores = config.score_data.unwrap_or_default().is_legacy();
+
+    let scores_manager = Context::osu_scores();
+
+    let pinned_fut = scores_manager
+        .clone()
+        .pinned(legacy_scores)
+        .limit(100)
+        .exec(user_args);
+
+    let top_fut = scores_manager.top(200, legacy_scores).exec(user_args);
+
+    let (pinned, top) = match tokio::try_join!(pinned_fut, top_fut) {
+        Ok(tuple) => tuple,
+        Err(OsuError::NotFound) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
+            let err = Report::new(err).wrap_err("Failed to get pinned or top scores");
+
+            return Err(err);
+        }
+    };
+
+    let maps_id_checksum = pinned
+        .iter()
+        .filter_map(|score| score.map.as_ref())
+        .map(|map| (map.map_id as i32, map.checksum.as_deref()))
+        .collect();
+
+    let maps = match Context::osu_map().maps(&maps_id_checksum).await {
+        Ok(maps) => maps,
+        Err(err) => {
+            let _ = orig.error_tr(ErrorKey::General).await;
+
+            return Err(err.wrap_err("Failed to get maps"));
+        }
+    };
+
+    let report = PinnedReport::new(&pinned, &top, |map_id| {
+        maps.get(&map_id).map(OsuMap::status)
+    });
+
+    let description = report.description(&pinned, &maps);
+
+    let footer = FooterBuilder::new(format!(
+        "Checked {} pinned scores against the top{}",
+        pinned.len(),
+        top.len()
+    ));
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder(false))
+        .description(description)
+        .footer(footer)
+        .thumbnail(user.avatar_url.as_ref());
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// Scores that can be audited.
+pub trait AuditScore {
+    fn score_id(&self) -> u64;
+    fn map_id(&self) -> u32;
+}
+
+impl AuditScore for Score {
+    fn score_id(&self) -> u64 {
+        self.id
+    }
+
+    fn map_id(&self) -> u32 {
+        self.map_id
+    }
+}
+
+/// Whether `pinned` corresponds to `top`.
+///
+/// Only score ids are compared. Both lists are fetched from the same
+/// endpoint so their ids are comparable, and a pinned score that was
+/// replaced by a newer score with the same mods must still be flagged.
+fn is_same_score<S: AuditScore>(pinned: &S, top: &S) -> bool {
+    pinned.score_id() == top.score_id()
+}
+
+/// Issues found in a list of pinned scores.
+///
+/// All contained indices refer to the pinned scores.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PinnedReport {
+    /// Pinned scores on ranked maps that are not part of the top scores
+    pub not_in_top: Vec<usize>,
+    /// Pinned scores on maps that are no longer ranked, approved, or loved
+    pub unranked: Vec<(usize, RankStatus)>,
+    /// Pinned scores sharing the same map; the first index is the original pin
+    pub duplicates: Vec<Vec<usize>>,
+}
+
+impl PinnedReport {
+    pub fn new<S, F>(pinned: &[S], top: &[S], status: F) -> Self
+    where
+        S: AuditScore,
+        F: Fn(u32) -> Option<RankStatus>,
+    {
+        let mut report = Self::default();
+        let mut by_map = HashMap::<u32, Vec<usize>, IntHasher>::default();
+        let mut map_order = Vec::new();
+
+        for (i, score) in pinned.iter().enumerate() {
+            let map_id = score.map_id();
+
+            by_map
+                .entry(map_id)
+                .or_insert_with(|| {
+                    map_order.push(map_id);
+
+                    Vec::new()
+                })
+                .push(i);
+
+            match status(map_id) {
+                Some(RankStatus::Ranked | RankStatus::Approved) | None => {
+                    if !top.iter().any(|top| is_same_score(score, top)) {
+                        report.not_in_top.push(i);
+                    }
+                }
+                Some(RankStatus::Loved) => {}
+                Some(status) => report.unranked.push((i, status)),
+            }
+        }
+
+        report.duplicates = map_order
+            .into_iter()
+            .filter_map(|map_id| by_map.remove(&map_id))
+            .filter(|indices| indices.len() > 1)
+            .collect();
+
+        report
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.not_in_top.is_empty() && self.unranked.is_empty() && self.duplicates.is_empty()
+    }
+
+    fn description(&self, pinned: &[Score], maps: &HashMap<u32, OsuMap, IntHasher>) -> String {
+        if pinned.is_empty() {
+            return "No pinned scores to check".to_owned();
+        } else if self.is_empty() {
+            return "All pinned scores look fine ✅".to_owned();
+        }
+
+        let mut description = String::with_capacity(512);
+
+        let write_score = |description: &mut String, idx: usize| {
+            let score = &pinned[idx];
+
+            let _ = match maps.get(&score.map_id) {
+                Some(map) => write!(
+                    description,
+                    "- #{i} [{artist} - {title} [{version}]]({OSU_BASE}b/{map_id}) +{mods}",
+                    i = idx + 1,
+                    artist = map.artist(),
+                    title = map.title(),
+                    version = map.version(),
+                    map_id = score.map_id,
+                    mods = score.mods,
+                ),
+                None => write!(
+                    description,
+                    "- #{i} [Map {map_id}]({OSU_BASE}b/{map_id}) +{mods}",
+                    i = idx + 1,
+                    map_id = score.map_id,
+                    mods = score.mods,
+                ),
+            };
+        };
+
+        if !self.not_in_top.is_empty() {
+            description.push_str("**No longer in the top scores:**\n");
+
+            for &idx in self.not_in_top.iter().take(MAX_LISTED) {
+                write_score(&mut description, idx);
+                description.push('\n');
+            }
+
+            write_remaining(&mut description, self.not_in_top.len());
+            description.push_str("→ Likely overwritten or deleted, consider unpinning them\n\n");
+        }
+
+        if !self.unranked.is_empty() {
+            description.push_str("**Map no longer ranked:**\n");
+
+            for &(idx, status) in self.unranked.iter().take(MAX_LISTED) {
+                write_score(&mut description, idx);
+                let _ = writeln!(description, " ({status:?})");
+            }
+
+            write_remaining(&mut description, self.unranked.len());
+            description.push_str("→ These scores no longer give pp\n\n");
+        }
+
+        if !self.duplicates.is_empty() {
+            description.push_str("**Multiple pins on the same map:**\n");
+
+            for indices in self.duplicates.iter().take(MAX_LISTED) {
+                write_score(&mut description, indices[0]);
+                let _ = write!(description, " (also pinned as ");
+
+                for (i, idx) in indices[1..].iter().enumerate() {
+                    if i > 0 {
+                        description.push_str(", ");
+                    }
+
+                    let _ = write!(description, "#{}", idx + 1);
+                }
+
+                description.push_str(")\n");
+            }
+
+            write_remaining(&mut description, self.duplicates.len());
+            description.push_str("→ Consider keeping only the best pin per map\n");
+        }
+
+        description
+    }
+}
+
+fn write_remaining(description: &mut String, total: usize) {
+    if total > MAX_LISTED {
+        let _ = writeln!(description, "- ... and {} more", total - MAX_LISTED);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestScore {
+        id: u64,
+        map_id: u32,
+    }
+
+    impl AuditScore for TestScore {
+        fn score_id(&self) -> u64 {
+            self.id
+        }
+
+        fn map_id(&self) -> u32 {
+            self.map_id
+        }
+    }
+
+    fn score(id: u64, map_id: u32) -> TestScore {
+        TestScore { id, map_id }
+    }
+
+    fn ranked(_: u32) -> Option<RankStatus> {
+        Some(RankStatus::Ranked)
+    }
+
+    #[test]
+    fn all_fine() {
+        let pinned = [score(1, 10), score(2, 20)];
+        let top = [score(2, 20), score(3, 30), score(1, 10)];
+
+        let report = PinnedReport::new(&pinned, &top, ranked);
+
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn not_in_top() {
+        let pinned = [score(1, 10), score(2, 20)];
+        let top = [score(1, 10)];
+
+        let report = PinnedReport::new(&pinned, &top, ranked);
+
+        assert_eq!(report.not_in_top, [1]);
+        assert!(report.unranked.is_empty());
+        assert!(report.duplicates.is_empty());
+    }
+
+    #[test]
+    fn replaced_score_on_same_map() {
+        // The pinned score on map 10 was replaced by a newer one
+        let pinned = [score(1, 10), score(2, 20)];
+        let top = [score(100, 10), score(2, 20)];
+
+        let report = PinnedReport::new(&pinned, &top, ranked);
+
+        assert_eq!(report.not_in_top, [0]);
+    }
+
+    #[test]
+    fn unranked_and_loved() {
+        let pinned = [score(1, 10), score(2, 20), score(3, 30)];
+        let top = [];
+
+        let status = |map_id| match map_id {
+            10 => Some(RankStatus::Graveyard),
+            20 => Some(RankStatus::Loved),
+            _ => Some(RankStatus::Ranked),
+        };
+
+        let report = PinnedReport::new(&pinned, &top, status);
+
+        assert_eq!(report.unranked, [(0, RankStatus::Graveyard)]);
+        assert_eq!(report.not_in_top, [2]);
+    }
+
+    #[test]
+    fn duplicates() {
+        let pinned = [
+            score(1, 10),
+            score(2, 20),
+            score(3, 10),
+            score(4, 30),
+            score(5, 20),
+            score(6, 10),
+        ];
+        let top = [
+            score(1, 10),
+            score(2, 20),
+            score(3, 10),
+            score(4, 30),
+            score(5, 20),
+            score(6, 10),
+        ];
+
+        let report = PinnedReport::new(&pinned, &top, ranked);
+
+        assert_eq!(report.duplicates, [vec![0, 2, 5], vec![1, 4]]);
+        assert!(report.not_in_top.is_empty());
+    }
+}
@@ -8,27 +8,27 @@ use bathbot_macros::{HasMods, HasName, SlashCommand, command};
 use bathbot_model::{
     PersonalBestIndex, command_fields::GameModeOption, embed_builder::SettingsImage,
 };
-use bathbot_psql::model::configs::{GuildConfig, ListSize, ScoreData};
+use bathbot_psql::model::configs::{GuildConfig, ListSize, ScoreData, resolve};
 use bathbot_util::{
     MessageOrigin,
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    i18n::ErrorKey,
     matcher,
     osu::ModSelection,
     query::{IFilterCriteria, Searchable, TopCriteria},
 };
 use eyre::{Report, Result};
 use rand::{Rng, thread_rng};
-use rosu_v2::{
-    prelude::{GameMode, OsuError, Score},
-    request::UserId,
-};
+use rosu_v2::prelude::{GameMode, OsuError, Score};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::{
     guild::Permissions,
     id::{Id, marker::UserMarker},
 };
 
-use super::{HasMods, ModsResult, ScoreOrder, map_strains_graph, require_link, user_not_found};
+use super::{
+    HasMods, ModsResult, ScoreOrder, Theme, map_strains_graph, require_link, skin_and_user_id,
+    user_not_found,
+};
 use crate::{
     Context,
     active::{
@@ -47,9 +47,28 @@ use crate::{
     util::{CheckPermissions, InteractionCommandExt, interaction::InteractionCommand},
 };
 
-#[derive(CommandModel, CreateCommand, HasMods, HasName, SlashCommand)]
-#[command(name = "pinned", desc = "Display the user's pinned scores")]
-pub struct Pinned<'a> {
+pub use self::audit::*;
+
+mod audit;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "pinned",
+    desc = "Display or check the user's pinned scores",
+    help = "Display or check the user's pinned scores.\n\
+    The list of pinned scores that used to be `/pinned` itself is now `/pinned scores` \
+    with the same options. The `<pinned` prefix command is unchanged."
+)]
+pub enum Pinned<'a> {
+    #[command(name = "scores")]
+    Scores(PinnedScores<'a>),
+    #[command(name = "audit")]
+    Audit(PinnedAudit<'a>),
+}
+
+#[derive(CommandModel, CreateCommand, HasMods, HasName)]
+#[command(name = "scores", desc = "Display the user's pinned scores")]
+pub struct PinnedScores<'a> {
     #[command(desc = "Specify a gamemode")]
     mode: Option<GameModeOption>,
     #[command(desc = "Specify a username")]
@@ -86,14 +105,14 @@ pub struct Pinned<'a> {
         desc = "Size of the embed",
         help = "Size of the embed.\n\
         `Condensed` shows 10 scores, `Detailed` shows 5, and `Single` shows 1.\n\
-        The default can be set with the `/config` command."
+        The default can be set with the `/config edit` command."
     )]
     size: Option<ListSize>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
 }
 
-impl<'m> Pinned<'m> {
+impl<'m> PinnedScores<'m> {
     fn args(mode: Option<GameModeOption>, args: Args<'m>) -> Self {
         let mut name = None;
         let mut discord = None;
@@ -129,7 +148,7 @@ impl<'m> Pinned<'m> {
 #[aliases("p", "pins")]
 #[group(Osu)]
 async fn prefix_pinned(msg: &Message, args: Args<'_>) -> Result<()> {
-    let args = Pinned::args(None, args);
+    let args = PinnedScores::args(None, args);
 
     pinned(msg.into(), args).await
 }
@@ -141,7 +160,7 @@ async fn prefix_pinned(msg: &Message, args: Args<'_>) -> Result<()> {
 #[aliases("ptaiko", "pinnedt", "pinstaiko", "pinst")]
 #[group(Taiko)]
 async fn prefix_pinnedtaiko(msg: &Message, args: Args<'_>) -> Result<()> {
-    let args = Pinned::args(Some(GameModeOption::Taiko), args);
+    let args = PinnedScores::args(Some(GameModeOption::Taiko), args);
 
     pinned(msg.into(), args).await
 }
@@ -161,7 +180,7 @@ async fn prefix_pinnedtaiko(msg: &Message, args: Args<'_>) -> Result<()> {
 )]
 #[group(Catch)]
 async fn prefix_pinnedctb(msg: &Message, args: Args<'_>) -> Result<()> {
-    let args = Pinned::args(Some(GameModeOption::Catch), args);
+    let args = PinnedScores::args(Some(GameModeOption::Catch), args);
 
     pinned(msg.into(), args).await
 }
@@ -173,18 +192,19 @@ async fn prefix_pinnedctb(msg: &Message, args: Args<'_>) -> Result<()> {
 #[aliases("pmania", "pinnedm", "pinsmania", "pinsm")]
 #[group(Mania)]
 async fn prefix_pinnedmania(msg: &Message, args: Args<'_>) -> Result<()> {
-    let args = Pinned::args(Some(GameModeOption::Mania), args);
+    let args = PinnedScores::args(Some(GameModeOption::Mania), args);
 
     pinned(msg.into(), args).await
 }
 
 async fn slash_pinned(mut command: InteractionCommand) -> Result<()> {
-    let args = Pinned::from_interaction(command.input_data())?;
-
-    pinned((&mut command).into(), args).await
+    match Pinned::from_interaction(command.input_data())? {
+        Pinned::Scores(args) => pinned((&mut command).into(), args).await,
+        Pinned::Audit(args) => audit((&mut command).into(), args).await,
+    }
 }
 
-async fn pinned(orig: CommandOrigin<'_>, args: Pinned<'_>) -> Result<()> {
+async fn pinned(orig: CommandOrigin<'_>, args: PinnedScores<'_>) -> Result<()> {
     let mods = match args.mods() {
         ModsResult::Mods(mods) => Some(mods),
         ModsResult::None => None,
@@ -203,7 +223,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned<'_>) -> Result<()> {
     let mut config = match Context::user_config().with_osu_id(msg_owner).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -230,18 +250,13 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned<'_>) -> Result<()> {
         None => GuildValues::default(),
     };
 
-    let list_size = args
-        .size
-        .or(config.list_size)
-        .or(guild_list_size)
-        .unwrap_or_default();
-
-    let user_id = match user_id!(orig, args) {
-        Some(user_id) => user_id,
-        None => match config.osu.take() {
-            Some(user_id) => UserId::Id(user_id),
-            None => return require_link(&orig).await,
-        },
+    let list_size = resolve::list_size(args.size, config.list_size, guild_list_size);
+
+    let user_id = user_id!(orig, args);
+    let (skin, user_id) = skin_and_user_id(&mut config, user_id);
+
+    let Some(user_id) = user_id else {
+        return require_link(&orig).await;
     };
 
     let (user_args, user_opt) = match UserArgs::rosu_id(&user_id, mode).await {
@@ -256,18 +271,14 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         UserArgs::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
         }
     };
 
-    let score_data = args
-        .score_data
-        .or(config.score_data)
-        .or(guild_score_data)
-        .unwrap_or_default();
+    let score_data = resolve::score_data(args.score_data, config.score_data, guild_score_data);
 
     let legacy_scores = score_data.is_legacy();
     let missing_user = user_opt.is_none();
@@ -314,13 +325,13 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         (Err(err), ..) | (_, Err(err), _) | (.., Err(UserArgsError::Osu(err))) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user or prepare scores");
 
             return Err(err);
         }
         (.., Err(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -329,11 +340,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned<'_>) -> Result<()> {
 
     let settings = config.score_embed.unwrap_or_default();
 
-    let mut with_render = match (guild_render_button, config.render_button) {
-        (None | Some(true), None) => true,
-        (None | Some(true), Some(with_render)) => with_render,
-        (Some(false), _) => false,
-    };
+    let mut with_render = resolve::render_button(config.render_button, guild_render_button);
 
     with_render &= settings.buttons.render
         && mode == GameMode::Osu
@@ -357,7 +364,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned<'_>) -> Result<()> {
     {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to process scores"));
         }
@@ -412,6 +419,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned<'_>) -> Result<()> {
                         entry.map.cover(),
                         SingleScorePagination::IMAGE_W,
                         SingleScorePagination::IMAGE_H,
+                        Theme::get(config.graph_theme),
                     );
 
                     match fut.await {
@@ -430,6 +438,9 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned<'_>) -> Result<()> {
                 &user, entries, settings, score_data, msg_owner, content,
             );
 
+            pagination.set_timestamp_style(config.timestamp_style);
+            pagination.set_skin(skin);
+
             if let Some(idx) = single_idx {
                 pagination.set_index(idx);
             }
@@ -463,7 +474,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned<'_>) -> Result<()> {
 
 async fn process_scores(
     pinned: Vec<Score>,
-    args: &Pinned<'_>,
+    args: &PinnedScores<'_>,
     mods: Option<&ModSelection>,
     top100: Option<&[Score]>,
     with_render: bool,
@@ -607,7 +618,7 @@ async fn process_scores(
 
 fn write_content(
     name: &str,
-    args: &Pinned,
+    args: &PinnedScores,
     amount: usize,
     mods: Option<&ModSelection>,
 ) -> Option<String> {
@@ -659,7 +670,11 @@ fn write_content(
     }
 }
 
-fn content_with_condition(args: &Pinned, amount: usize, mods: Option<&ModSelection>) -> String {
+fn content_with_condition(
+    args: &PinnedScores,
+    amount: usize,
+    mods: Option<&ModSelection>,
+) -> String {
     let mut content = String::with_capacity(64);
 
     match args.sort {
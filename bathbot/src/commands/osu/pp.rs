@@ -2,13 +2,13 @@ use std::borrow::Cow;
 
 use bathbot_macros::{HasName, SlashCommand, command};
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{MessageBuilder, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use rosu_v2::prelude::OsuError;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{Id, marker::UserMarker};
 
-use super::user_not_found;
+use super::{top_play_difficulties, user_not_found};
 use crate::{
     Context,
     commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP},
@@ -48,6 +48,13 @@ pub struct Pp<'a> {
         If `each` is set, this argument will be ignored"
     )]
     amount: Option<u8>,
+    #[command(
+        desc = "Suggest the stars and mods of such a play",
+        help = "Suggest the star rating and mods that a play of the required pp \
+        usually has for the user based on their top plays.\n\
+        Requires at least 10 top plays."
+    )]
+    suggest: Option<bool>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
 }
@@ -74,6 +81,7 @@ impl<'m> Pp<'m> {
             name,
             each: None,
             amount: None,
+            suggest: None,
             discord,
         })
     }
@@ -176,7 +184,11 @@ async fn pp(orig: CommandOrigin<'_>, args: Pp<'_>) -> Result<()> {
     let (user_id, mode) = user_id_mode!(orig, args);
 
     let Pp {
-        pp, each, amount, ..
+        pp,
+        each,
+        amount,
+        suggest,
+        ..
     } = args;
 
     let Some(pp) = PpValue::parse(pp.as_ref()) else {
@@ -207,7 +219,7 @@ async fn pp(orig: CommandOrigin<'_>, args: Pp<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user or scores");
 
             return Err(err);
@@ -231,8 +243,22 @@ async fn pp(orig: CommandOrigin<'_>, args: Pp<'_>) -> Result<()> {
         }
     };
 
+    let plays = if suggest == Some(true) {
+        match top_play_difficulties(&scores).await {
+            Ok(plays) => plays,
+            Err(err) => {
+                warn!(?err, "Failed to get top play difficulties");
+
+                Vec::new()
+            }
+        }
+    } else {
+        Vec::new()
+    };
+
     // Accumulate all necessary data
-    let embed_data = PpMissingEmbed::new(&user, &scores, target_pp as f64, rank, each, amount);
+    let embed_data =
+        PpMissingEmbed::new(&user, &scores, &plays, target_pp as f64, rank, each, amount);
 
     // Creating the embed
     let embed = embed_data.build();
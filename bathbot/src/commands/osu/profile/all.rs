@@ -0,0 +1,364 @@
+use std::{borrow::Cow, fmt::Write};
+
+use bathbot_macros::{HasName, command};
+use bathbot_util::{
+    AuthorBuilder, CowUtils, EmbedBuilder, MessageBuilder, constants::OSU_BASE, i18n::ErrorKey,
+    matcher, numbers::WithComma, osu::flag_url,
+};
+use eyre::{Report, Result};
+use rosu_v2::{
+    prelude::{GameMode, OsuError},
+    request::UserId,
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    channel::message::embed::EmbedField,
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    Context,
+    commands::{
+        DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
+        osu::{require_link, user_not_found},
+    },
+    core::commands::{CommandOrigin, prefix::Args},
+    manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
+};
+
+const MODES: [GameMode; 4] = [
+    GameMode::Osu,
+    GameMode::Taiko,
+    GameMode::Catch,
+    GameMode::Mania,
+];
+
+#[derive(CommandModel, CreateCommand, HasName)]
+#[command(
+    name = "all",
+    desc = "Display a summary of a user's statistics in all modes",
+    help = "Display a summary of a user's statistics in all modes.\n\
+    Modes without any plays are skipped. The strongest mode is the one in which \
+    the user's global rank is best relative to the mode's amount of ranked players."
+)]
+pub struct ProfileAll<'a> {
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+}
+
+impl<'m> ProfileAll<'m> {
+    fn args(args: Args<'m>) -> Self {
+        let mut name = None;
+        let mut discord = None;
+
+        for arg in args.map(|arg| arg.cow_to_ascii_lowercase()) {
+            if let Some(id) = matcher::get_mention_user(&arg) {
+                discord = Some(id);
+            } else {
+                name = Some(arg);
+            }
+        }
+
+        Self { name, discord }
+    }
+}
+
+#[command]
+#[desc("Display a summary of a user's statistics in all modes")]
+#[usage("[username]")]
+#[examples("badewanne3")]
+#[aliases("allmodes", "pall")]
+#[group(AllModes)]
+async fn prefix_profileall(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = ProfileAll::args(args);
+
+    profile_all(msg.into(), args).await
+}
+
+pub(super) async fn profile_all(orig: CommandOrigin<'_>, args: ProfileAll<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match Context::user_config().osu_id(owner).await {
+            Ok(Some(user_id)) => UserId::Id(user_id),
+            Ok(None) => return require_link(&orig).await,
+            Err(err) => {
+                let _ = orig.error_tr(ErrorKey::General).await;
+
+                return Err(err);
+            }
+        },
+    };
+
+    let mode_fut = |mode| {
+        let user_id = &user_id;
+
+        async move {
+            let user_args = UserArgs::rosu_id(user_id, mode).await;
+
+            Context::redis().osu_user(user_args).await
+        }
+    };
+
+    let (osu, taiko, catch, mania) = tokio::join!(
+        mode_fut(GameMode::Osu),
+        mode_fut(GameMode::Taiko),
+        mode_fut(GameMode::Catch),
+        mode_fut(GameMode::Mania),
+    );
+
+    let results = [osu, taiko, catch, mania];
+
+    // Only fail the command if no mode could be retrieved, otherwise the
+    // failing modes are shown as unavailable
+    let Some(user) = results.iter().find_map(|res| res.as_ref().ok()) else {
+        // Every mode failed so osu!standard's error is representative
+        let [Err(err), ..] = results else {
+            unreachable!()
+        };
+
+        return match err {
+            UserArgsError::Osu(OsuError::NotFound) => {
+                let content = user_not_found(user_id).await;
+
+                orig.error(content).await
+            }
+            err => {
+                let _ = orig.error_tr(ErrorKey::General).await;
+
+                Err(Report::new(err).wrap_err("Failed to get user"))
+            }
+        };
+    };
+
+    let entries = MODES.map(|mode| match &results[mode as usize] {
+        Ok(user) => ModeEntry::from_user(user),
+        Err(err) => {
+            warn!(?err, ?mode, "Failed to get user for all-modes profile");
+
+            ModeEntry::Unavailable
+        }
+    });
+
+    let embed = embed(user, &entries);
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+fn embed(user: &CachedUser, entries: &[ModeEntry; 4]) -> EmbedBuilder {
+    let user_id = user.user_id.to_native();
+    let country_code = user.country_code.as_str();
+
+    let author = AuthorBuilder::new(user.username.as_str())
+        .url(format!("{OSU_BASE}u/{user_id}"))
+        .icon_url(flag_url(country_code));
+
+    let visible = visible_modes(entries);
+
+    let description = match strongest_mode(entries) {
+        _ if visible.is_empty() => "No plays in any mode".to_owned(),
+        Some(mode) => format!("Strongest mode: **{}**", mode_name(mode)),
+        None => "Strongest mode: **-**".to_owned(),
+    };
+
+    let fields = visible.into_iter().map(|(mode, entry)| {
+        let mut value = String::with_capacity(128);
+
+        match entry {
+            ModeEntry::Stats(stats) => {
+                let _ = write!(value, "**{}pp**", WithComma::new(stats.pp));
+
+                match stats.global_rank {
+                    Some(rank) => {
+                        let _ = write!(value, " • #{}", WithComma::new(rank));
+                    }
+                    None => value.push_str(" • unranked"),
+                }
+
+                if let Some(rank) = stats.country_rank {
+                    let _ = write!(value, " ({country_code}#{})", WithComma::new(rank));
+                }
+
+                let _ = write!(
+                    value,
+                    "\nAccuracy: `{:.2}%` • Playcount: `{}`",
+                    stats.accuracy,
+                    WithComma::new(stats.playcount),
+                );
+            }
+            ModeEntry::Unavailable => value.push_str("unavailable"),
+        }
+
+        EmbedField {
+            inline: false,
+            name: mode_name(mode).to_owned(),
+            value,
+        }
+    });
+
+    EmbedBuilder::new()
+        .author(author)
+        .description(description)
+        .fields(fields.collect())
+        .thumbnail(user.avatar_url.as_ref())
+}
+
+fn mode_name(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "osu!",
+        GameMode::Taiko => "taiko",
+        GameMode::Catch => "catch",
+        GameMode::Mania => "mania",
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct ModeStats {
+    pp: f32,
+    global_rank: Option<u32>,
+    country_rank: Option<u32>,
+    accuracy: f32,
+    playcount: u32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum ModeEntry {
+    Stats(ModeStats),
+    Unavailable,
+}
+
+impl ModeEntry {
+    fn from_user(user: &CachedUser) -> Self {
+        let Some(stats) = user.statistics.as_ref() else {
+            return Self::Unavailable;
+        };
+
+        let global_rank = stats.global_rank.to_native();
+        let country_rank = stats.country_rank.to_native();
+
+        Self::Stats(ModeStats {
+            pp: stats.pp.to_native(),
+            global_rank: (global_rank > 0).then_some(global_rank),
+            country_rank: (country_rank > 0).then_some(country_rank),
+            accuracy: stats.accuracy.to_native(),
+            playcount: stats.playcount.to_native(),
+        })
+    }
+}
+
+/// Approximate amount of ranked players per mode.
+///
+/// The API does not expose the total amount of ranked players so these are
+/// hand-picked estimates of each mode's ranked player base. They're only used
+/// to compare ranks across modes so only their ratios matter; adjust them
+/// should a mode grow disproportionately.
+fn ranked_players(mode: GameMode) -> u32 {
+    match mode {
+        GameMode::Osu => 2_000_000,
+        GameMode::Taiko => 250_000,
+        GameMode::Catch => 200_000,
+        GameMode::Mania => 450_000,
+    }
+}
+
+/// Modes that should be shown, i.e. those with plays and those that could
+/// not be retrieved.
+fn visible_modes(entries: &[ModeEntry; 4]) -> Vec<(GameMode, ModeEntry)> {
+    MODES
+        .into_iter()
+        .zip(entries.iter().copied())
+        .filter(|(_, entry)| match entry {
+            ModeEntry::Stats(stats) => stats.playcount > 0,
+            ModeEntry::Unavailable => true,
+        })
+        .collect()
+}
+
+/// The mode with the best global rank relative to the mode's amount of
+/// ranked players.
+fn strongest_mode(entries: &[ModeEntry; 4]) -> Option<GameMode> {
+    MODES
+        .into_iter()
+        .zip(entries)
+        .filter_map(|(mode, entry)| match entry {
+            ModeEntry::Stats(ModeStats {
+                global_rank: Some(rank),
+                playcount: 1..,
+                ..
+            }) => Some((mode, *rank as f64 / ranked_players(mode) as f64)),
+            _ => None,
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(mode, _)| mode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(global_rank: u32, playcount: u32) -> ModeEntry {
+        ModeEntry::Stats(ModeStats {
+            pp: 1000.0,
+            global_rank: (global_rank > 0).then_some(global_rank),
+            country_rank: None,
+            accuracy: 98.0,
+            playcount,
+        })
+    }
+
+    #[test]
+    fn skips_modes_without_plays() {
+        let entries = [
+            stats(10_000, 500),
+            stats(0, 0),
+            ModeEntry::Unavailable,
+            stats(5_000, 20),
+        ];
+
+        let modes: Vec<_> = visible_modes(&entries)
+            .into_iter()
+            .map(|(mode, _)| mode)
+            .collect();
+
+        assert_eq!(modes, [GameMode::Osu, GameMode::Catch, GameMode::Mania]);
+    }
+
+    #[test]
+    fn strongest_by_normalized_rank() {
+        // 10k of 2M osu! players beats 5k of 450k mania players
+        let entries = [
+            stats(10_000, 500),
+            stats(0, 0),
+            stats(0, 3),
+            stats(5_000, 20),
+        ];
+
+        assert_eq!(strongest_mode(&entries), Some(GameMode::Osu));
+
+        let entries = [
+            stats(100_000, 500),
+            stats(1_000, 100),
+            stats(0, 0),
+            stats(5_000, 20),
+        ];
+
+        assert_eq!(strongest_mode(&entries), Some(GameMode::Taiko));
+    }
+
+    #[test]
+    fn no_strongest_without_ranks() {
+        let entries = [
+            ModeEntry::Unavailable,
+            stats(0, 10),
+            stats(0, 0),
+            ModeEntry::Unavailable,
+        ];
+
+        assert_eq!(strongest_mode(&entries), None);
+    }
+}
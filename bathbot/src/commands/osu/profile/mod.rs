@@ -1,9 +1,9 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, time::Duration};
 
 use bathbot_macros::{HasName, SlashCommand, command};
 use bathbot_model::command_fields::GameModeOption;
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{CowUtils, MessageOrigin, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{CowUtils, MessageOrigin, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use rosu_v2::{
     prelude::{GameMode, OsuError},
@@ -12,19 +12,40 @@ use rosu_v2::{
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::id::{Id, marker::UserMarker};
 
-use super::{require_link, user_not_found};
+use self::skills::skill_radar;
+use super::{Theme, require_link, user_not_found};
 use crate::{
     Context,
     active::{ActiveMessages, impls::ProfileMenu},
     commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP},
-    core::commands::{CommandOrigin, prefix::Args},
+    core::{
+        BotMetrics,
+        commands::{CommandOrigin, prefix::Args},
+    },
     manager::redis::osu::{UserArgs, UserArgsError},
-    util::{ChannelExt, InteractionCommandExt, interaction::InteractionCommand},
+    util::{self, ChannelExt, InteractionCommandExt, interaction::InteractionCommand},
 };
 
-#[derive(CommandModel, CreateCommand, SlashCommand, HasName)]
+pub use self::{
+    all::*,
+    skills::{SKILL_RADAR_IMAGE, SkillRadar, SkillRadarStatus},
+};
+
+mod all;
+mod skills;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "profile", desc = "Display statistics of a user")]
-pub struct Profile<'a> {
+pub enum Profile<'a> {
+    #[command(name = "user")]
+    User(ProfileUser<'a>),
+    #[command(name = "all")]
+    All(ProfileAll<'a>),
+}
+
+#[derive(CommandModel, CreateCommand, HasName)]
+#[command(name = "user", desc = "Display statistics of a user in one mode")]
+pub struct ProfileUser<'a> {
     #[command(desc = "Specify a gamemode")]
     mode: Option<GameModeOption>,
     #[command(desc = "Specify a username")]
@@ -33,6 +54,13 @@ pub struct Profile<'a> {
     embed: Option<ProfileKind>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(
+        desc = "Show the score rank on the compact embed",
+        help = "Show the score rank from [respektive](https://score.respektive.pw) \
+        on the compact embed.\n\
+        Overrides the `extended_profile` option of `/config edit`."
+    )]
+    score_rank: Option<bool>,
 }
 
 #[derive(Copy, Clone, CommandOption, CreateOption, Debug, Eq, PartialEq)]
@@ -58,7 +86,7 @@ impl Default for ProfileKind {
     }
 }
 
-impl<'m> Profile<'m> {
+impl<'m> ProfileUser<'m> {
     fn args(mode: GameModeOption, args: Args<'m>) -> Result<Self, String> {
         let mut name = None;
         let mut discord = None;
@@ -76,6 +104,7 @@ impl<'m> Profile<'m> {
             name,
             embed: None,
             discord,
+            score_rank: None,
         })
     }
 }
@@ -87,7 +116,7 @@ impl<'m> Profile<'m> {
 #[alias("profile")]
 #[group(Osu)]
 async fn prefix_osu(msg: &Message, args: Args<'_>) -> Result<()> {
-    match Profile::args(GameModeOption::Osu, args) {
+    match ProfileUser::args(GameModeOption::Osu, args) {
         Ok(args) => profile(msg.into(), args).await,
         Err(content) => {
             msg.error(content).await?;
@@ -104,7 +133,7 @@ async fn prefix_osu(msg: &Message, args: Args<'_>) -> Result<()> {
 #[aliases("profilemania", "maniaprofile", "profilem")]
 #[group(Mania)]
 async fn prefix_mania(msg: &Message, args: Args<'_>) -> Result<()> {
-    match Profile::args(GameModeOption::Mania, args) {
+    match ProfileUser::args(GameModeOption::Mania, args) {
         Ok(args) => profile(msg.into(), args).await,
         Err(content) => {
             msg.error(content).await?;
@@ -121,7 +150,7 @@ async fn prefix_mania(msg: &Message, args: Args<'_>) -> Result<()> {
 #[aliases("profiletaiko", "taikoprofile", "profilet")]
 #[group(Taiko)]
 async fn prefix_taiko(msg: &Message, args: Args<'_>) -> Result<()> {
-    match Profile::args(GameModeOption::Taiko, args) {
+    match ProfileUser::args(GameModeOption::Taiko, args) {
         Ok(args) => profile(msg.into(), args).await,
         Err(content) => {
             msg.error(content).await?;
@@ -147,7 +176,7 @@ async fn prefix_taiko(msg: &Message, args: Args<'_>) -> Result<()> {
 )]
 #[group(Catch)]
 async fn prefix_ctb(msg: &Message, args: Args<'_>) -> Result<()> {
-    match Profile::args(GameModeOption::Catch, args) {
+    match ProfileUser::args(GameModeOption::Catch, args) {
         Ok(args) => profile(msg.into(), args).await,
         Err(content) => {
             msg.error(content).await?;
@@ -158,18 +187,19 @@ async fn prefix_ctb(msg: &Message, args: Args<'_>) -> Result<()> {
 }
 
 async fn slash_profile(mut command: InteractionCommand) -> Result<()> {
-    let args = Profile::from_interaction(command.input_data())?;
-
-    profile((&mut command).into(), args).await
+    match Profile::from_interaction(command.input_data())? {
+        Profile::User(args) => profile((&mut command).into(), args).await,
+        Profile::All(args) => profile_all((&mut command).into(), args).await,
+    }
 }
 
-async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
+async fn profile(orig: CommandOrigin<'_>, args: ProfileUser<'_>) -> Result<()> {
     let owner = orig.user_id()?;
 
     let config = match Context::user_config().with_osu_id(owner).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to get user config"));
         }
@@ -205,10 +235,30 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
         },
     };
 
+    let extended_profile = config.extended_profile.unwrap_or(false);
+    let with_score_rank = args.score_rank.unwrap_or(extended_profile);
+
+    // If the user id is already known, the score rank can be fetched
+    // alongside the user
+    let early_score_rank_id = match user_id {
+        UserId::Id(user_id) if with_score_rank => Some(user_id),
+        _ => None,
+    };
+
+    let early_score_rank_fut = async {
+        match early_score_rank_id {
+            Some(user_id) => score_rank(user_id, mode).await,
+            None => None,
+        }
+    };
+
     // Retrieve the user and their top scores
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
+    let user_fut = Context::redis().osu_user(user_args);
 
-    let user = match Context::redis().osu_user(user_args).await {
+    let (user_res, early_score_rank) = tokio::join!(user_fut, early_score_rank_fut);
+
+    let user = match user_res {
         Ok(user) => user,
         Err(UserArgsError::Osu(OsuError::NotFound)) => {
             let content = user_not_found(user_id).await;
@@ -216,7 +266,7 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -227,7 +277,38 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
     let peaks_fut = Context::client().osu_user_rank_acc_peak(user_id, mode);
     let user_id_fut = Context::user_config().discord_from_osu_id(user_id);
 
-    let (peaks_res, user_id_res) = tokio::join!(peaks_fut, user_id_fut);
+    let late_score_rank_fut = async {
+        if with_score_rank && early_score_rank_id.is_none() {
+            score_rank(user_id, mode).await
+        } else {
+            early_score_rank
+        }
+    };
+
+    let skill_radar_fut = async {
+        if extended_profile && mode == GameMode::Osu {
+            skill_radar(user_id, legacy_scores, Theme::get(config.graph_theme)).await
+        } else {
+            None
+        }
+    };
+
+    let osutrack_fut = async {
+        // Only for the author's own linked account
+        if config.osutrack_auto_update == Some(true) && config.osu == Some(user_id) {
+            Context::osutrack_auto_update(user_id, mode).await
+        } else {
+            false
+        }
+    };
+
+    let (peaks_res, user_id_res, compact_score_rank, skill_radar_opt, osutrack_updated) = tokio::join!(
+        peaks_fut,
+        user_id_fut,
+        late_score_rank_fut,
+        skill_radar_fut,
+        osutrack_fut
+    );
 
     // Try to get the discord user id that is linked to the osu!user
     let discord_id = match user_id_res {
@@ -254,22 +335,61 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
         }
     };
 
+    let (skill_radar, attachment) = match skill_radar_opt {
+        Some((radar, bytes)) => (
+            SkillRadarStatus::Attached(radar),
+            Some((SKILL_RADAR_IMAGE.to_owned(), bytes)),
+        ),
+        None if extended_profile && mode != GameMode::Osu => {
+            (SkillRadarStatus::UnsupportedMode, None)
+        }
+        None => (SkillRadarStatus::NotRequested, None),
+    };
+
     let tz = no_user_specified.then_some(config.timezone).flatten();
     let origin = MessageOrigin::new(orig.guild_id(), orig.channel_id());
 
-    let pagination = ProfileMenu::new(
+    let mut pagination = ProfileMenu::new(
         user,
         discord_id,
         tz,
         peaks,
+        compact_score_rank,
         legacy_scores,
+        skill_radar,
         kind,
         origin,
         owner,
     );
 
+    pagination.set_osutrack_updated(osutrack_updated);
+
     ActiveMessages::builder(pagination)
+        .attachment(attachment)
         .start_by_update(true)
+        .plain_fallback(true)
         .begin(orig)
         .await
 }
+
+/// How long to wait for respektive before leaving out the score rank.
+const SCORE_RANK_TIMEOUT: Duration = Duration::from_millis(1500);
+
+async fn score_rank(user_id: u32, mode: GameMode) -> Option<u32> {
+    let rank_fut = Context::redis().respektive_score_rank(user_id, mode);
+
+    match util::within(rank_fut, SCORE_RANK_TIMEOUT).await {
+        Some(Ok(rank)) => rank,
+        Some(Err(err)) => {
+            BotMetrics::inc_respektive_error("request");
+            warn!(?err, "Failed to get respektive score rank");
+
+            None
+        }
+        None => {
+            BotMetrics::inc_respektive_error("timeout");
+
+            None
+        }
+    }
+}
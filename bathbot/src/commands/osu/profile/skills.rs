@@ -0,0 +1,372 @@
+use std::{collections::HashMap, fmt::Write};
+
+use bathbot_util::IntHasher;
+use eyre::{Result, WrapErr};
+use rosu_pp::any::DifficultyAttributes;
+use rosu_v2::prelude::{GameMode, Score};
+use tokio::task::spawn_blocking;
+
+use crate::{
+    commands::osu::{Theme, skill_radar_graph},
+    core::Context,
+    manager::{CachedAttributes, OsuMap, redis::osu::UserArgsSlim},
+};
+
+pub const SKILL_RADAR_IMAGE: &str = "skill_radar.png";
+
+const W: u32 = 800;
+const H: u32 = 500;
+
+/// Skill radar of the profile embed.
+#[derive(Copy, Clone)]
+pub enum SkillRadarStatus {
+    /// The radar is attached as [`SKILL_RADAR_IMAGE`]
+    Attached(SkillRadar),
+    /// The radar is requested but only available for osu!standard
+    UnsupportedMode,
+    NotRequested,
+}
+
+/// Calculate the skill radar of a user's top200 and draw it.
+pub(super) async fn skill_radar(
+    user_id: u32,
+    legacy_scores: bool,
+    theme: &Theme,
+) -> Option<(SkillRadar, Vec<u8>)> {
+    let user_args = UserArgsSlim::user_id(user_id).mode(GameMode::Osu);
+
+    let scores = match Context::osu_scores()
+        .top(200, legacy_scores)
+        .exec(user_args)
+        .await
+    {
+        Ok(scores) => scores,
+        Err(err) => {
+            warn!(?err, "Failed to get top scores for skill radar");
+
+            return None;
+        }
+    };
+
+    let radar = match SkillRadar::new(scores).await {
+        Ok(Some(radar)) => radar,
+        Ok(None) => return None,
+        Err(err) => {
+            warn!(?err, "Failed to calculate skill radar");
+
+            return None;
+        }
+    };
+
+    match skill_radar_graph(&radar, W, H, theme) {
+        Ok(bytes) => Some((radar, bytes)),
+        Err(err) => {
+            warn!(?err, "Failed to draw skill radar");
+
+            None
+        }
+    }
+}
+
+/// Heuristic skill profile derived from a user's top scores.
+///
+/// Every metric lies within `0.0..=1.0` so they can share one radar chart.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SkillRadar {
+    pub aim: f64,
+    pub speed: f64,
+    pub accuracy: f64,
+    pub consistency: f64,
+    pub stamina: f64,
+}
+
+impl SkillRadar {
+    pub const LABELS: [&'static str; 5] = ["Aim", "Speed", "Accuracy", "Consistency", "Stamina"];
+
+    /// Calculate the skills of osu!standard top scores.
+    ///
+    /// Returns `None` if no score could be evaluated.
+    pub async fn new(scores: Vec<Score>) -> Result<Option<Self>> {
+        let maps_id_checksum: HashMap<_, _, IntHasher> = scores
+            .iter()
+            .map(|score| {
+                (
+                    score.map_id as i32,
+                    score.map.as_ref().and_then(|map| map.checksum.as_deref()),
+                )
+            })
+            .collect();
+
+        let maps = Context::osu_map().maps(&maps_id_checksum).await?;
+
+        // Calculating difficulty attributes of up to 200 maps would block
+        // the runtime for too long
+        let skill_scores = spawn_blocking(move || skill_scores(&scores, &maps))
+            .await
+            .wrap_err("Failed to join skill score calculation")?;
+
+        Ok(Self::from_scores(&skill_scores))
+    }
+
+    fn from_scores(scores: &[SkillScore]) -> Option<Self> {
+        if scores.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            aim: aim(scores),
+            speed: speed(scores),
+            accuracy: accuracy(scores),
+            consistency: consistency(scores),
+            stamina: stamina(scores),
+        })
+    }
+
+    /// The skills as percentages, e.g. as alternative to the drawn radar.
+    pub fn summary(&self) -> String {
+        let mut summary = String::new();
+
+        for (i, (label, value)) in Self::LABELS.iter().zip(self.values()).enumerate() {
+            if i > 0 {
+                summary.push_str(" • ");
+            }
+
+            let _ = write!(summary, "{label}: `{:.0}%`", value * 100.0);
+        }
+
+        summary
+    }
+
+    pub fn values(&self) -> [f64; 5] {
+        [
+            self.aim,
+            self.speed,
+            self.accuracy,
+            self.consistency,
+            self.stamina,
+        ]
+    }
+}
+
+/// Evaluate the scores whose map is available.
+///
+/// Scores with equivalent mods on the same map share their difficulty
+/// attributes.
+fn skill_scores(scores: &[Score], maps: &HashMap<u32, OsuMap, IntHasher>) -> Vec<SkillScore> {
+    let mut cached_attrs = CachedAttributes::default();
+    let mut skill_scores = Vec::with_capacity(scores.len());
+
+    for (i, score) in scores.iter().enumerate() {
+        let Some(map) = maps.get(&score.map_id) else {
+            continue;
+        };
+
+        let Some(DifficultyAttributes::Osu(attrs)) = cached_attrs.get_blocking(&map.pp_map, score)
+        else {
+            continue;
+        };
+
+        let map_attrs = map.attributes().mods(score.mods.clone()).build();
+
+        skill_scores.push(SkillScore {
+            weight: score.weight.map_or_else(
+                || 0.95_f64.powi(i as i32),
+                |weight| weight.percentage as f64 / 100.0,
+            ),
+            aim: attrs.aim,
+            speed: attrs.speed,
+            od: map_attrs.od,
+            acc: score.accuracy as f64,
+            drain: map.seconds_drain() as f64 / map_attrs.clock_rate,
+            objects: map.n_objects(),
+        });
+    }
+
+    skill_scores
+}
+
+/// The data of a top score that the skills are derived from.
+#[derive(Copy, Clone, Debug)]
+struct SkillScore {
+    /// Weight of the score within the top scores
+    weight: f64,
+    /// Aim difficulty rating
+    aim: f64,
+    /// Speed difficulty rating
+    speed: f64,
+    /// Overall difficulty after mods
+    od: f64,
+    /// Accuracy in percent
+    acc: f64,
+    /// Drain time in seconds after mods
+    drain: f64,
+    objects: u32,
+}
+
+/// Drain time at which the length part of stamina saturates.
+const STAMINA_LENGTH_SECS: f64 = 300.0;
+
+/// Objects per second at which the density part of stamina saturates.
+const STAMINA_DENSITY: f64 = 8.0;
+
+/// Weighted mean of `f` over all scores.
+fn weighted_mean(scores: &[SkillScore], f: impl Fn(&SkillScore) -> f64) -> f64 {
+    let (sum, weights) = scores.iter().fold((0.0, 0.0), |(sum, weights), score| {
+        (sum + score.weight * f(score), weights + score.weight)
+    });
+
+    if weights <= f64::EPSILON {
+        0.0
+    } else {
+        sum / weights
+    }
+}
+
+/// Weighted mean of the aim rating's share of aim and speed.
+///
+/// Aim and speed add up to `1.0` so a balanced player has `0.5` each.
+fn aim(scores: &[SkillScore]) -> f64 {
+    weighted_mean(scores, |score| {
+        let total = score.aim + score.speed;
+
+        if total <= f64::EPSILON {
+            0.5
+        } else {
+            score.aim / total
+        }
+    })
+}
+
+/// Weighted mean of the speed rating's share of aim and speed.
+fn speed(scores: &[SkillScore]) -> f64 {
+    1.0 - aim(scores)
+}
+
+/// Weighted mean of accuracy scaled by overall difficulty.
+///
+/// Accuracy counts fully on OD 10 and above but only half on OD 0.
+fn accuracy(scores: &[SkillScore]) -> f64 {
+    weighted_mean(scores, |score| {
+        let od_factor = 0.5 + score.od.clamp(0.0, 10.0) / 20.0;
+
+        score.acc / 100.0 * od_factor
+    })
+}
+
+/// Inverse of the weighted accuracy variance, in percent points squared.
+///
+/// Identical accuracies yield `1.0`, a variance of one yields `0.5`.
+fn consistency(scores: &[SkillScore]) -> f64 {
+    let mean = weighted_mean(scores, |score| score.acc);
+    let variance = weighted_mean(scores, |score| (score.acc - mean).powi(2));
+
+    1.0 / (1.0 + variance)
+}
+
+/// Weighted mean of map length times object density.
+///
+/// Both parts are normalized and capped so that maps of at least
+/// [`STAMINA_LENGTH_SECS`] with [`STAMINA_DENSITY`] objects per second
+/// count fully.
+fn stamina(scores: &[SkillScore]) -> f64 {
+    weighted_mean(scores, |score| {
+        if score.drain <= f64::EPSILON {
+            return 0.0;
+        }
+
+        let length = (score.drain / STAMINA_LENGTH_SECS).min(1.0);
+        let density = (score.objects as f64 / score.drain / STAMINA_DENSITY).min(1.0);
+
+        length * density
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(aim: f64, speed: f64, od: f64, acc: f64, drain: f64, objects: u32) -> SkillScore {
+        SkillScore {
+            weight: 1.0,
+            aim,
+            speed,
+            od,
+            acc,
+            drain,
+            objects,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn aim_and_speed_shares() {
+        let scores = [
+            score(3.0, 1.0, 9.0, 98.0, 120.0, 500),
+            score(2.0, 2.0, 9.0, 98.0, 120.0, 500),
+        ];
+
+        assert_close(aim(&scores), 0.625);
+        assert_close(speed(&scores), 0.375);
+    }
+
+    #[test]
+    fn aim_respects_weights() {
+        let mut scores = [
+            score(4.0, 0.0, 9.0, 98.0, 120.0, 500),
+            score(0.0, 4.0, 9.0, 98.0, 120.0, 500),
+        ];
+
+        scores[1].weight = 0.0;
+
+        assert_close(aim(&scores), 1.0);
+    }
+
+    #[test]
+    fn accuracy_scales_with_od() {
+        let hard = [score(2.0, 2.0, 10.0, 100.0, 120.0, 500)];
+        let easy = [score(2.0, 2.0, 0.0, 100.0, 120.0, 500)];
+        let overcapped = [score(2.0, 2.0, 11.0, 90.0, 120.0, 500)];
+
+        assert_close(accuracy(&hard), 1.0);
+        assert_close(accuracy(&easy), 0.5);
+        assert_close(accuracy(&overcapped), 0.9);
+    }
+
+    #[test]
+    fn consistency_from_acc_variance() {
+        let steady = [
+            score(2.0, 2.0, 9.0, 97.0, 120.0, 500),
+            score(2.0, 2.0, 9.0, 97.0, 120.0, 500),
+        ];
+
+        let shaky = [
+            score(2.0, 2.0, 9.0, 96.0, 120.0, 500),
+            score(2.0, 2.0, 9.0, 98.0, 120.0, 500),
+        ];
+
+        assert_close(consistency(&steady), 1.0);
+        assert_close(consistency(&shaky), 0.5);
+    }
+
+    #[test]
+    fn stamina_from_length_and_density() {
+        let marathon = [score(2.0, 2.0, 9.0, 98.0, 600.0, 6000)];
+        let short = [score(2.0, 2.0, 9.0, 98.0, 60.0, 240)];
+        let empty = [score(2.0, 2.0, 9.0, 98.0, 0.0, 0)];
+
+        assert_close(stamina(&marathon), 1.0);
+        assert_close(stamina(&short), 0.2 * 0.5);
+        assert_close(stamina(&empty), 0.0);
+    }
+
+    #[test]
+    fn no_scores_no_radar() {
+        assert_eq!(SkillRadar::from_scores(&[]), None);
+    }
+}
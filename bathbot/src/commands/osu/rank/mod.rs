@@ -51,6 +51,14 @@ pub struct RankPp<'a> {
         If `each` is set, this argument will be ignored"
     )]
     amount: Option<u8>,
+    #[command(
+        min_value = 0,
+        max_value = 50,
+        desc = "Pretend your N lowest top scores don't exist",
+        help = "Pretend your N lowest top scores don't exist.\n\
+        The baseline total pp is recalculated without those scores, bonus pp is kept."
+    )]
+    exclude_top: Option<u8>,
     #[command(desc = "Specify a country (code)")]
     country: Option<Cow<'a, str>>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
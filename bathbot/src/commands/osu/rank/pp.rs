@@ -2,7 +2,7 @@ use std::{
     borrow::Cow,
     cmp,
     convert::identity,
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Display, Formatter, Result as FmtResult, Write},
     iter,
 };
 
@@ -10,10 +10,15 @@ use bathbot_macros::command;
 use bathbot_model::{Countries, command_fields::GameModeOption};
 use bathbot_util::{
     CowUtils, EmbedBuilder, MessageBuilder,
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    i18n::ErrorKey,
+    i18n::Lang,
     matcher,
     numbers::WithComma,
-    osu::{ExtractablePp, PpListUtil, pp_missing},
+    osu::{
+        ExtractablePp, PpListUtil, is_unranked_pp, pp_missing, requires_blacklisted_mods,
+        total_pp_without_bottom,
+    },
+    tr,
 };
 use eyre::{Report, Result};
 use rosu_v2::prelude::{CountryCode, OsuError, Score, UserId, Username};
@@ -21,9 +26,11 @@ use rosu_v2::prelude::{CountryCode, OsuError, Score, UserId, Username};
 use super::{RankPp, RankValue};
 use crate::{
     Context,
-    commands::osu::user_not_found,
-    core::commands::{CommandOrigin, prefix::Args},
-    embeds::PersonalBestIndexFormatter,
+    commands::osu::{own_blacklisted_mods, user_not_found},
+    core::commands::{
+        CommandOrigin,
+        prefix::{Arg, Args, strip_quotes},
+    },
     manager::redis::osu::{CachedUser, UserArgs, UserArgsError, UserArgsSlim},
     util::{CachedUserExt, ChannelExt},
 };
@@ -36,17 +43,17 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
         rank,
         each,
         amount,
+        exclude_top,
         ..
     } = args;
 
+    let exclude = exclude_top.map_or(0, usize::from);
+
     let rank_value = RankValue::parse(rank.as_ref());
 
     let country = match country {
-        Some(ref country) => match Countries::name(country).to_code() {
-            Some(code) => Some(CountryCode::from(code)),
-            None if country.len() == 2 => {
-                Some(CountryCode::from(country.cow_to_ascii_uppercase().as_ref()))
-            }
+        Some(ref country) => match Countries::resolve(country) {
+            Some(resolved) => Some(resolved.to_country_code()),
             None => {
                 let content =
                     format!("Looks like `{country}` is neither a country name nor a country code");
@@ -74,7 +81,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get user"));
         }
@@ -123,7 +130,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
                     return orig.error(content).await;
                 }
                 Err(err) => {
-                    let _ = orig.error(GENERAL_ISSUE).await;
+                    let _ = orig.error_tr(ErrorKey::General).await;
 
                     return Err(Report::new(err).wrap_err("Failed to get target user"));
                 }
@@ -154,7 +161,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
             let rankings = match rankings_fut.await {
                 Ok(rankings) => rankings,
                 Err(err) => {
-                    let _ = orig.error(GENERAL_ISSUE).await;
+                    let _ = orig.error_tr(ErrorKey::General).await;
 
                     return Err(Report::new(err).wrap_err("Failed to get user"));
                 }
@@ -193,7 +200,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
             let required_pp = match Context::approx().pp(rank, mode).await {
                 Ok(pp) => pp,
                 Err(err) => {
-                    let _ = orig.error(GENERAL_ISSUE).await;
+                    let _ = orig.error_tr(ErrorKey::General).await;
 
                     return Err(err);
                 }
@@ -212,7 +219,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
     };
 
     // Retrieve the user's top scores if required
-    let scores = if rank_data.with_scores() {
+    let mut scores = if rank_data.with_scores() || exclude > 0 {
         let user = rank_data.user();
 
         let user_args = UserArgsSlim::user_id(user.user_id.to_native()).mode(mode);
@@ -223,9 +230,19 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
             .exec(user_args);
 
         match scores_fut.await {
-            Ok(scores) => (!scores.is_empty()).then_some(scores),
+            Ok(mut scores) => {
+                // Scores whose pp don't count towards the profile shouldn't be weighted
+                // while those of unknown maps are assumed to count
+                scores.retain(|score| {
+                    let status = score.map.as_ref().map(|map| map.status);
+
+                    is_unranked_pp(status, &score.mods, score.ranked) != Some(true)
+                });
+
+                (!scores.is_empty()).then_some(scores)
+            }
             Err(err) => {
-                let _ = orig.error(OSU_API_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::OsuApi).await;
                 let err = Report::new(err).wrap_err("Failed to get scores");
 
                 return Err(err);
@@ -241,9 +258,75 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
         (None, Some(amount)) => RankMultipleScores::Amount(amount),
     };
 
-    let title = rank_data.title();
     let user = rank_data.user();
-    let description = rank_data.description(scores.as_deref(), multiple);
+
+    let mut user_pp = user
+        .statistics
+        .as_ref()
+        .expect("missing stats")
+        .pp
+        .to_native();
+
+    if exclude > 0 {
+        let Some(scores) = scores.as_mut().filter(|scores| exclude < scores.len()) else {
+            let content = format!(
+                "`exclude_top` must be smaller than the amount of top scores ({})",
+                scores.as_ref().map_or(0, Vec::len)
+            );
+
+            return orig.error(content).await;
+        };
+
+        user_pp = total_pp_without_bottom(user_pp, &scores.extract_pp(), exclude);
+        scores.truncate(scores.len() - exclude);
+    }
+
+    let lang = Context::guild_config().lang(orig.guild_id()).await;
+    let title = rank_data.title(lang);
+    let mut description = rank_data.description(user_pp as f64, scores.as_deref(), multiple, lang);
+
+    if exclude > 0 {
+        let key = if exclude == 1 {
+            "rank_pp.exclude.one"
+        } else {
+            "rank_pp.exclude.other"
+        };
+
+        let baseline = tr!(
+            key,
+            lang,
+            username = user.username.as_str().cow_escape_markdown(),
+            exclude = exclude,
+            pp = WithComma::new(user_pp),
+        );
+
+        description = format!("{baseline}\n{description}");
+    }
+
+    let target_pp = rank_data.target_pp();
+
+    if let (RankMultipleScores::Amount(1), Some(scores)) = (multiple, scores.as_deref())
+        && user_pp < target_pp
+        && let Some(blacklist) = own_blacklisted_mods(&orig, user.user_id.to_native()).await
+    {
+        let (required, _) = pp_missing(user_pp as f64, target_pp as f64, scores);
+        let required = required as f32;
+
+        let pps_and_mods = scores
+            .iter()
+            .map(|score| (score.pp.unwrap_or(0.0), &score.mods));
+
+        if requires_blacklisted_mods(required, pps_and_mods, &blacklist) {
+            let note = tr!(
+                "rank_pp.blacklisted",
+                lang,
+                pp = WithComma::new(required),
+                blacklist = blacklist,
+            );
+
+            let _ = write!(description, "\n{note}");
+        }
+    }
 
     let embed = EmbedBuilder::new()
         .author(user.author_builder(false))
@@ -251,7 +334,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
         .thumbnail(user.avatar_url.as_ref())
         .title(title);
 
-    let builder = MessageBuilder::new().embed(embed);
+    let builder = MessageBuilder::new().embed(embed).plain_fallback(true);
     orig.create_message(builder).await?;
 
     Ok(())
@@ -357,22 +440,27 @@ impl<'m> RankPp<'m> {
                 country_code: Option<Cow<'a, str>>,
                 prefixed: bool,
             },
-            Name(&'a str),
+            Name(Cow<'a, str>),
             None,
         }
 
         impl<'a> Prefixed<'a> {
-            fn parse(arg: &'a str) -> Prefixed<'a> {
-                let Some((key, value)) = arg.split_once('=') else {
-                    return Self::parse_rank(arg, false).unwrap_or(Self::None);
+            fn parse(arg: &Arg<'a>) -> Prefixed<'a> {
+                let Some((key, value)) = arg.key_value() else {
+                    return Self::parse_rank(strip_quotes(arg.raw), false).unwrap_or(Self::None);
                 };
 
                 match key {
-                    "rank" | "reach" | "r" => Self::parse_rank(value, true).unwrap_or(Self::Rank {
-                        value,
-                        country_code: None,
-                        prefixed: true,
-                    }),
+                    "rank" | "reach" | "r" => {
+                        let raw = arg.raw;
+                        let value = strip_quotes(&raw[key.len() + 1..]);
+
+                        Self::parse_rank(value, true).unwrap_or(Self::Rank {
+                            value,
+                            country_code: None,
+                            prefixed: true,
+                        })
+                    }
                     "user" | "u" | "name" | "n" => Self::Name(value),
                     _ => Self::None,
                 }
@@ -414,9 +502,9 @@ impl<'m> RankPp<'m> {
         let mut rank = None;
         let mut discord = None;
 
-        if let Some(first) = args.next() {
-            if let Some(second) = args.next() {
-                match (Prefixed::parse(first), Prefixed::parse(second)) {
+        if let Some(first) = args.next_arg() {
+            if let Some(second) = args.next_arg() {
+                match (Prefixed::parse(&first), Prefixed::parse(&second)) {
                     (
                         Prefixed::Rank {
                             value,
@@ -443,9 +531,9 @@ impl<'m> RankPp<'m> {
                         },
                         Prefixed::None,
                     ) => {
-                        rank = Some(value);
+                        rank = Some(Cow::Borrowed(value));
                         country = country_code;
-                        name = Some(Cow::Borrowed(second));
+                        name = Some(second.value);
                     }
                     (
                         Prefixed::Rank {
@@ -457,9 +545,9 @@ impl<'m> RankPp<'m> {
                             prefixed: true,
                         },
                     ) => {
-                        rank = Some(value);
+                        rank = Some(Cow::Borrowed(value));
                         country = country_code;
-                        name = Some(Cow::Borrowed(first));
+                        name = Some(first.value);
                     }
                     (
                         Prefixed::Rank {
@@ -469,9 +557,9 @@ impl<'m> RankPp<'m> {
                         },
                         Prefixed::Name(name_value),
                     ) => {
-                        rank = Some(value);
+                        rank = Some(Cow::Borrowed(value));
                         country = country_code;
-                        name = Some(Cow::Borrowed(name_value));
+                        name = Some(name_value);
                     }
                     (
                         Prefixed::None,
@@ -481,9 +569,9 @@ impl<'m> RankPp<'m> {
                             prefixed: _,
                         },
                     ) => {
-                        rank = Some(value);
+                        rank = Some(Cow::Borrowed(value));
                         country = country_code;
-                        name = Some(Cow::Borrowed(first));
+                        name = Some(first.value);
                     }
                     (
                         Prefixed::Name(name_value),
@@ -493,39 +581,39 @@ impl<'m> RankPp<'m> {
                             prefixed: _,
                         },
                     ) => {
-                        rank = Some(value);
+                        rank = Some(Cow::Borrowed(value));
                         country = country_code;
-                        name = Some(Cow::Borrowed(name_value));
+                        name = Some(name_value);
                     }
                     (Prefixed::Name(rank_value), Prefixed::Name(name_value)) => {
                         rank = Some(rank_value);
-                        name = Some(Cow::Borrowed(name_value));
+                        name = Some(name_value);
                     }
                     (Prefixed::Name(value), Prefixed::None) => {
-                        rank = Some(second);
-                        name = Some(Cow::Borrowed(value));
+                        rank = Some(second.value);
+                        name = Some(value);
                     }
                     (Prefixed::None, Prefixed::Name(value)) => {
-                        rank = Some(first);
-                        name = Some(Cow::Borrowed(value));
+                        rank = Some(first.value);
+                        name = Some(value);
                     }
                     (Prefixed::None, Prefixed::None) => {
-                        rank = Some(first);
-                        name = Some(Cow::Borrowed(second));
+                        rank = Some(first.value);
+                        name = Some(second.value);
                     }
                 }
             } else {
-                match Prefixed::parse(first) {
+                match Prefixed::parse(&first) {
                     Prefixed::Rank {
                         value,
                         country_code,
                         prefixed: _,
                     } => {
-                        rank = Some(value);
+                        rank = Some(Cow::Borrowed(value));
                         country = country_code;
                     }
-                    Prefixed::Name(name_value) => name = Some(Cow::Borrowed(name_value)),
-                    Prefixed::None => name = Some(Cow::Borrowed(first)),
+                    Prefixed::Name(name_value) => name = Some(name_value),
+                    Prefixed::None => name = Some(first.value),
                 }
             }
         }
@@ -537,7 +625,7 @@ impl<'m> RankPp<'m> {
             name = None;
         }
 
-        let rank = rank.map(Cow::Borrowed).or_else(|| name.take()).ok_or(
+        let rank = rank.or_else(|| name.take()).ok_or(
             "Failed to parse `rank`. Provide it either as positive number \
             or as country acronym followed by a positive number e.g. `be10` \
             as one of the first two arguments.",
@@ -549,6 +637,7 @@ impl<'m> RankPp<'m> {
             name,
             each: None,
             amount: None,
+            exclude_top: None,
             country,
             discord,
         })
@@ -603,6 +692,25 @@ fn idx_suffix(idx: usize) -> &'static str {
     }
 }
 
+fn ordinal(idx: usize, lang: Lang) -> String {
+    tr!("ordinal", lang, n = idx, suffix = idx_suffix(idx))
+}
+
+/// Where `amount` many scores would be placed in the top scores if the first
+/// one is placed at `start_idx`.
+fn placement(start_idx: usize, amount: u8, lang: Lang) -> String {
+    if start_idx >= 200 {
+        tr!("rank_pp.placement.outside", lang)
+    } else {
+        tr!(
+            "rank_pp.placement",
+            lang,
+            from = start_idx + 1,
+            to = start_idx + usize::from(amount),
+        )
+    }
+}
+
 impl RankData {
     fn with_scores(&self) -> bool {
         let user_pp = |user: &CachedUser| {
@@ -625,6 +733,15 @@ impl RankData {
         }
     }
 
+    fn target_pp(&self) -> f32 {
+        match self {
+            Self::Sub10k { rank_holder, .. } => rank_holder.pp,
+            Self::Sub10kExact { rank_holder, .. } => rank_holder.pp,
+            Self::Over10kApprox { required_pp, .. } => *required_pp,
+            Self::Over10kExact { rank_holder, .. } => rank_holder.pp,
+        }
+    }
+
     fn user(&self) -> &CachedUser {
         match self {
             Self::Sub10k { user, .. } => user,
@@ -634,27 +751,30 @@ impl RankData {
         }
     }
 
-    fn title(&self) -> String {
+    fn title(&self, lang: Lang) -> String {
         match self {
             RankData::Sub10k {
                 user,
                 rank,
                 country,
                 ..
-            } => {
-                format!(
-                    "How many pp is {username} missing to reach rank {country}{rank}?",
-                    username = user.username.as_str().cow_escape_markdown(),
+            } => tr!(
+                "rank_pp.title.rank",
+                lang,
+                username = user.username.as_str().cow_escape_markdown(),
+                rank = format!(
+                    "{country}{rank}",
                     country = country.as_ref().map(|code| code.as_str()).unwrap_or("#"),
-                )
-            }
+                ),
+            ),
             RankData::Sub10kExact { user, rank_holder } => {
                 let holder_name = rank_holder.username.as_str();
 
-                format!(
-                    "How many pp is {username} missing to reach \
-                    {holder_name}'{genitiv} rank #{rank}?",
+                tr!(
+                    "rank_pp.title.holder",
+                    lang,
                     username = user.username.as_str().cow_escape_markdown(),
+                    holder = holder_name,
                     genitiv = if holder_name.ends_with('s') { "" } else { "s" },
                     rank = rank_holder.global_rank,
                 )
@@ -662,25 +782,31 @@ impl RankData {
             RankData::Over10kExact { user, rank_holder } => {
                 let holder_name = rank_holder.username.cow_escape_markdown();
 
-                format!(
-                    "How many pp is {username} missing to reach \
-                    {holder_name}'{genitiv} rank #{rank}?",
+                tr!(
+                    "rank_pp.title.holder",
+                    lang,
                     username = user.username.as_str().cow_escape_markdown(),
+                    holder = holder_name,
                     genitiv = if holder_name.ends_with('s') { "" } else { "s" },
                     rank = WithComma::new(rank_holder.global_rank),
                 )
             }
-            RankData::Over10kApprox { user, rank, .. } => {
-                format!(
-                    "How many pp is {username} missing to reach rank #{rank}?",
-                    username = user.username.as_str().cow_escape_markdown(),
-                    rank = WithComma::new(*rank),
-                )
-            }
+            RankData::Over10kApprox { user, rank, .. } => tr!(
+                "rank_pp.title.rank",
+                lang,
+                username = user.username.as_str().cow_escape_markdown(),
+                rank = format!("#{}", WithComma::new(*rank)),
+            ),
         }
     }
 
-    fn description(&self, scores: Option<&[Score]>, multiple: RankMultipleScores) -> String {
+    fn description(
+        &self,
+        user_pp: f64,
+        scores: Option<&[Score]>,
+        multiple: RankMultipleScores,
+        lang: Lang,
+    ) -> String {
         match self {
             RankData::Sub10k {
                 user,
@@ -688,55 +814,88 @@ impl RankData {
                 country,
                 rank_holder,
             } => {
-                let prefix = format!(
-                    "Rank {rank} is currently held by {name} with **{pp}pp**",
-                    name = rank_holder.username.cow_escape_markdown(),
+                let prefix = tr!(
+                    "rank_pp.prefix.held",
+                    lang,
+                    holder = rank_holder.username.cow_escape_markdown(),
                     rank = RankFormat::new(*rank, country.is_none(), rank_holder),
                     pp = WithComma::new(rank_holder.pp),
                 );
 
-                Self::description_sub_10k(user, &prefix, rank_holder, scores, multiple)
+                Self::description_sub_10k(
+                    user,
+                    user_pp,
+                    &prefix,
+                    rank_holder,
+                    scores,
+                    multiple,
+                    lang,
+                )
             }
             RankData::Sub10kExact { user, rank_holder } => {
-                let prefix = format!(
-                    "{name} is rank {rank} with **{pp}pp**",
-                    name = rank_holder.username.cow_escape_markdown(),
+                let prefix = tr!(
+                    "rank_pp.prefix.holder",
+                    lang,
+                    holder = rank_holder.username.cow_escape_markdown(),
                     rank = RankFormat::new(rank_holder.global_rank, true, rank_holder),
                     pp = WithComma::new(rank_holder.pp),
                 );
 
-                Self::description_sub_10k(user, &prefix, rank_holder, scores, multiple)
+                Self::description_sub_10k(
+                    user,
+                    user_pp,
+                    &prefix,
+                    rank_holder,
+                    scores,
+                    multiple,
+                    lang,
+                )
             }
             RankData::Over10kApprox {
                 user,
                 rank,
                 required_pp,
-            } => Self::description_over_10k(
-                user,
-                "Rank",
-                "approx. ",
-                *required_pp as f64,
-                *rank,
-                scores,
-                multiple,
-            ),
+            } => {
+                let required_pp = *required_pp as f64;
+
+                let prefix = tr!(
+                    "rank_pp.prefix.approx",
+                    lang,
+                    rank = WithComma::new(*rank),
+                    pp = WithComma::new(required_pp),
+                );
+
+                Self::description_missing(
+                    user,
+                    user_pp,
+                    &prefix,
+                    required_pp,
+                    scores,
+                    multiple,
+                    lang,
+                )
+            }
             RankData::Over10kExact { user, rank_holder } => {
                 let holder_name = rank_holder.username.as_str();
+                let required_pp = rank_holder.pp as f64;
 
-                let prefix = format!(
-                    "Reaching {holder_name}'{genitiv} rank",
-                    holder_name = holder_name.cow_escape_markdown(),
-                    genitiv = if holder_name.ends_with('s') { "" } else { "s" }
+                let prefix = tr!(
+                    "rank_pp.prefix.holder_over_10k",
+                    lang,
+                    holder = holder_name.cow_escape_markdown(),
+                    genitiv = if holder_name.ends_with('s') { "" } else { "s" },
+                    rank = WithComma::new(rank_holder.global_rank),
+                    pp = WithComma::new(required_pp),
                 );
 
-                Self::description_over_10k(
+                Self::description_missing(
                     user,
+                    user_pp,
                     &prefix,
-                    "",
-                    rank_holder.pp as f64,
-                    rank_holder.global_rank,
+                    required_pp,
                     scores,
                     multiple,
+                    lang,
                 )
             }
         }
@@ -744,254 +903,80 @@ impl RankData {
 
     fn description_sub_10k(
         user: &CachedUser,
+        user_pp: f64,
         prefix: &str,
         rank_holder: &RankHolder,
         scores: Option<&[Score]>,
         multiple: RankMultipleScores,
+        lang: Lang,
     ) -> String {
-        let username = user.username.as_str().cow_escape_markdown();
-        let user_id = user.user_id.to_native();
-        let user_pp = user
-            .statistics
-            .as_ref()
-            .expect("missing stats")
-            .pp
-            .to_native() as f64;
-        let rank = rank_holder.global_rank;
-        let rank_holder_pp = rank_holder.pp as f64;
-
-        if user_id == rank_holder.user_id {
-            return format!("{username} is already at rank #{rank}.");
-        } else if user_pp > rank_holder_pp {
-            return format!(
-                "{prefix}, so {username} is already above that with **{pp}pp**.",
-                pp = WithComma::new(user_pp)
+        if user.user_id.to_native() == rank_holder.user_id {
+            return tr!(
+                "rank_pp.reached",
+                lang,
+                username = user.username.as_str().cow_escape_markdown(),
+                rank = rank_holder.global_rank,
             );
         }
 
-        let Some(scores) = scores else {
-            return format!(
-                "{prefix}, so {username} is missing **{holder_pp}** raw pp, \
-                achievable with a single score worth **{holder_pp}pp**.",
-                holder_pp = WithComma::new(rank_holder_pp),
-            );
-        };
-
-        match multiple {
-            RankMultipleScores::Amount(1) => {
-                let (required, idx) = pp_missing(user_pp, rank_holder_pp, scores);
-                let idx = idx + 1;
-                let suffix = idx_suffix(idx);
-
-                format!(
-                    "{prefix}, so {username} is missing **{missing}** raw pp, achievable \
-                    with a single score worth **{pp}pp** which would be their {idx}{suffix} top play.",
-                    missing = WithComma::new(rank_holder_pp - user_pp),
-                    pp = WithComma::new(required),
-                )
-            }
-            RankMultipleScores::Amount(amount) => {
-                let pps = scores.extract_pp();
-
-                let raw_delta = rank_holder_pp - user_pp;
-                let weight_sum: f64 = (0..amount as i32).map(|exp| FACTOR.powi(exp)).sum();
-                let mid_goal = user_pp + (raw_delta / weight_sum);
-                let (required, _) = pp_missing(user_pp, mid_goal, pps.as_slice());
-                let mut required = required as f32;
-
-                let pb_start_idx = pps
-                    .binary_search_by(|probe| required.total_cmp(probe))
-                    .map_or_else(identity, |idx| idx + 1);
-
-                let pb_fmt = PersonalBestIndexFormatter::new(pb_start_idx, amount);
-
-                if scores.len() == 200 && required < *pps.last().unwrap() {
-                    required = (*pps.last().unwrap() - 0.01).max(0.0);
-                }
-
-                format!(
-                    "{prefix}, so {username} is missing **{missing}** raw pp. \
-                    To catch up with {amount} scores, each one must be worth \
-                    **{pp}pp**, placing them {pb_fmt}.",
-                    missing = WithComma::new(rank_holder_pp - user_pp),
-                    pp = WithComma::new(required),
-                )
-            }
-            RankMultipleScores::EachPp(each) => {
-                if let Some(last_pp) = scores.last().and_then(|s| s.pp)
-                    && each < last_pp
-                {
-                    return format!(
-                        "{prefix}, so {username} is missing **{missing}** raw pp.\n\
-                            A new top200 score requires at least **{last_pp}pp** \
-                            so {holder_pp} total pp can't be reached with {each}pp scores.",
-                        holder_pp = WithComma::new(rank_holder_pp),
-                        missing = WithComma::new(rank_holder_pp - user_pp),
-                        last_pp = WithComma::new(last_pp),
-                        each = WithComma::new(each),
-                    );
-                }
-
-                let mut pps = scores.extract_pp();
-
-                // Top10k players most definitely have a full top200 but let's
-                // ensure it regardless
-                if pps.len() < 200 {
-                    pps.extend(iter::repeat_n(0.0, 200 - pps.len()));
-                }
-
-                let (required, idx) = pp_missing(user_pp, rank_holder_pp, scores);
-                let required = required as f32;
-
-                if required < each {
-                    let suffix = idx_suffix(idx + 1);
-
-                    return format!(
-                        "{prefix}, so {username} is missing **{missing}** raw pp.\n\
-                        To reach {holder_pp}pp with one additional score, {username} needs to \
-                        perform a **{required}pp** score which would be their {approx}{idx}{suffix} top play",
-                        holder_pp = WithComma::new(rank_holder_pp),
-                        missing = WithComma::new(rank_holder_pp - user_pp),
-                        required = WithComma::new(required),
-                        approx = if idx >= 200 { "~" } else { "" },
-                        idx = idx + 1,
-                    );
-                }
-
-                let idx = pps.iter().position(|&pp| pp < each).unwrap_or(pps.len());
-
-                let mut iter = pps
-                    .iter()
-                    .zip(0..)
-                    .map(|(pp, i)| *pp as f64 * FACTOR.powi(i));
-
-                let mut top: f64 = (&mut iter).take(idx).sum();
-                let bot: f64 = iter.sum();
-
-                let bonus_pp = f64::max(user_pp - (top + bot), 0.0);
-                top += bonus_pp;
-                let len = pps.len();
-
-                let each_f64 = each as f64;
-                let mut n_each = len;
-
-                for i in idx..len {
-                    let bot = pps[idx..]
-                        .iter()
-                        .zip(i as i32 + 1..)
-                        .fold(0.0, |sum, (pp, i)| sum + *pp as f64 * FACTOR.powi(i));
-
-                    let factor = FACTOR.powi(i as i32);
-
-                    if top + factor * each_f64 + bot >= rank_holder_pp {
-                        // requires n_each many new scores of `each` many pp and one
-                        // additional score
-                        n_each = i - idx;
-                        break;
-                    }
-
-                    top += factor * each_f64;
-                }
+        let target_pp = rank_holder.pp as f64;
 
-                if n_each == len {
-                    return format!(
-                        "{prefix}, so {username} is missing **{missing}** raw pp.\n\
-                        Filling up {username}'{genitiv} top scores with {amount} new \
-                        {each}pp score{plural} would only lead to {approx}**{top}pp** which \
-                        is still less than {holder_pp}pp.",
-                        holder_pp = WithComma::new(rank_holder_pp),
-                        amount = len - idx,
-                        each = WithComma::new(each),
-                        missing = WithComma::new(rank_holder_pp - user_pp),
-                        plural = if len - idx != 1 { "s" } else { "" },
-                        genitiv = if idx != 1 { "s" } else { "" },
-                        approx = if idx >= 200 { "roughly " } else { "" },
-                        top = WithComma::new(top),
-                    );
-                }
-
-                pps.extend(iter::repeat_n(each, n_each));
-
-                pps.sort_unstable_by(|a, b| b.total_cmp(a));
-
-                let accum = pps.accum_weighted();
-
-                // Calculate the pp of the missing score after adding `n_each`
-                // many `each` pp scores
-                let total = accum + bonus_pp;
-                let (required, _) = pp_missing(total, rank_holder_pp, pps.as_slice());
-
-                format!(
-                    "{prefix}, so {username} is missing **{missing}** raw pp.\n\
-                    To reach {holder_pp}pp, {username} needs to perform **{n_each}** \
-                    more {each}pp score{plural} and one **{required}pp** score.",
-                    holder_pp = WithComma::new(rank_holder_pp),
-                    missing = WithComma::new(rank_holder_pp - user_pp),
-                    each = WithComma::new(each),
-                    plural = if n_each != 1 { "s" } else { "" },
-                    required = WithComma::new(required),
-                )
-            }
-        }
+        Self::description_missing(user, user_pp, prefix, target_pp, scores, multiple, lang)
     }
 
-    fn description_over_10k(
+    /// Describe how the user can reach the target pp.
+    ///
+    /// The prefix states which rank requires the target pp.
+    fn description_missing(
         user: &CachedUser,
+        user_pp: f64,
         prefix: &str,
-        maybe_approx: &str,
-        required_pp: f64,
-        rank: u32,
+        target_pp: f64,
         scores: Option<&[Score]>,
         multiple: RankMultipleScores,
+        lang: Lang,
     ) -> String {
         let username = user.username.as_str().cow_escape_markdown();
-        let user_pp = user
-            .statistics
-            .as_ref()
-            .expect("missing stats")
-            .pp
-            .to_native() as f64;
-
-        if user_pp > required_pp {
-            return format!(
-                "{prefix} #{rank} currently requires {maybe_approx}**{required_pp}pp**, \
-                so {username} is already above that with **{pp}pp**.",
-                rank = WithComma::new(rank),
-                required_pp = WithComma::new(required_pp),
-                pp = WithComma::new(user_pp)
+
+        if user_pp > target_pp {
+            return tr!(
+                "rank_pp.above",
+                lang,
+                prefix = prefix,
+                username = username,
+                pp = WithComma::new(user_pp),
             );
         }
 
         let Some(scores) = scores else {
-            return format!(
-                "{prefix} #{rank} currently requires {maybe_approx}**{required_pp}pp**, \
-                so {username} is missing **{required_pp}** raw pp, \
-                achievable with a single score worth **{required_pp}pp**.",
-                rank = WithComma::new(rank),
-                required_pp = WithComma::new(required_pp),
+            return tr!(
+                "rank_pp.single",
+                lang,
+                prefix = prefix,
+                username = username,
+                missing = WithComma::new(target_pp),
+                pp = WithComma::new(target_pp),
             );
         };
 
         match multiple {
             RankMultipleScores::Amount(1) => {
-                let (required, idx) = pp_missing(user_pp, required_pp, scores);
-                let idx = idx + 1;
-                let suffix = idx_suffix(idx);
-
-                format!(
-                    "{prefix} #{rank} currently requires {maybe_approx}**{required_pp}pp**, so \
-                    {username} is missing **{missing}** raw pp, achievable with a \
-                    single score worth **{pp}pp** which would be their {idx}{suffix} top play.",
-                    rank = WithComma::new(rank),
-                    required_pp = WithComma::new(required_pp),
-                    missing = WithComma::new(required_pp - user_pp),
+                let (required, idx) = pp_missing(user_pp, target_pp, scores);
+
+                tr!(
+                    "rank_pp.single_top",
+                    lang,
+                    prefix = prefix,
+                    username = username,
+                    missing = WithComma::new(target_pp - user_pp),
                     pp = WithComma::new(required),
+                    ordinal = ordinal(idx + 1, lang),
                 )
             }
             RankMultipleScores::Amount(amount) => {
                 let pps = scores.extract_pp();
 
-                let raw_delta = required_pp - user_pp;
+                let raw_delta = target_pp - user_pp;
                 let weight_sum: f64 = (0..amount as i32).map(|exp| FACTOR.powi(exp)).sum();
                 let mid_goal = user_pp + (raw_delta / weight_sum);
                 let (required, _) = pp_missing(user_pp, mid_goal, pps.as_slice());
@@ -1001,35 +986,33 @@ impl RankData {
                     .binary_search_by(|probe| required.total_cmp(probe))
                     .map_or_else(identity, |idx| idx + 1);
 
-                let pb_fmt = PersonalBestIndexFormatter::new(pb_start_idx, amount);
-
                 if scores.len() == 200 && required < *pps.last().unwrap() {
                     required = (*pps.last().unwrap() - 0.01).max(0.0);
                 }
 
-                format!(
-                    "{prefix} #{rank} currently requires {maybe_approx}**{required_pp}pp**, so \
-                    {username} is missing **{missing}** raw pp. To catch up \
-                    with {amount} scores, each one must be worth **{pp}pp**, \
-                    placing them {pb_fmt}.",
-                    rank = WithComma::new(rank),
-                    required_pp = WithComma::new(required_pp),
-                    missing = WithComma::new(required_pp - user_pp),
+                tr!(
+                    "rank_pp.amount",
+                    lang,
+                    prefix = prefix,
+                    username = username,
+                    missing = WithComma::new(target_pp - user_pp),
+                    amount = amount,
                     pp = WithComma::new(required),
+                    placement = placement(pb_start_idx, amount, lang),
                 )
             }
             RankMultipleScores::EachPp(each) => {
                 if let Some(last_pp) = scores.last().and_then(|s| s.pp)
                     && each < last_pp
                 {
-                    return format!(
-                        "{prefix} #{rank} currently requires {maybe_approx}**{required_pp}pp**, \
-                            so {username} is missing **{missing}** raw pp.\n\
-                            A new top200 score requires at least **{last_pp}pp** \
-                            so {required_pp} total pp can't be reached with {each}pp scores.",
-                        required_pp = WithComma::new(required_pp),
-                        missing = WithComma::new(required_pp - user_pp),
+                    return tr!(
+                        "rank_pp.each.too_low",
+                        lang,
+                        prefix = prefix,
+                        username = username,
+                        missing = WithComma::new(target_pp - user_pp),
                         last_pp = WithComma::new(last_pp),
+                        target = WithComma::new(target_pp),
                         each = WithComma::new(each),
                     );
                 }
@@ -1041,22 +1024,20 @@ impl RankData {
                     pps.extend(iter::repeat_n(0.0, 200 - pps.len()));
                 }
 
-                let (required, idx) = pp_missing(user_pp, required_pp, scores);
+                let (required, idx) = pp_missing(user_pp, target_pp, scores);
                 let required = required as f32;
 
                 if required < each {
-                    let suffix = idx_suffix(idx + 1);
-
-                    return format!(
-                        "{prefix} #{rank} currently requires {maybe_approx}**{required_pp}pp**, \
-                        so {username} is missing **{missing}** raw pp.\n\
-                        To reach {required_pp}pp with one additional score, {username} needs to \
-                        perform a **{required}pp** score which would be their {approx}{idx}{suffix} top play",
-                        required_pp = WithComma::new(required_pp),
-                        missing = WithComma::new(required_pp - user_pp),
+                    return tr!(
+                        "rank_pp.each.single",
+                        lang,
+                        prefix = prefix,
+                        username = username,
+                        missing = WithComma::new(target_pp - user_pp),
+                        target = WithComma::new(target_pp),
                         required = WithComma::new(required),
                         approx = if idx >= 200 { "~" } else { "" },
-                        idx = idx + 1,
+                        ordinal = ordinal(idx + 1, lang),
                     );
                 }
 
@@ -1085,7 +1066,7 @@ impl RankData {
 
                     let factor = FACTOR.powi(i as i32);
 
-                    if top + factor * each_f64 + bot >= required_pp {
+                    if top + factor * each_f64 + bot >= target_pp {
                         // requires n_each many new scores of `each` many pp and one
                         // additional score
                         n_each = i - idx;
@@ -1096,20 +1077,32 @@ impl RankData {
                 }
 
                 if n_each == len {
-                    return format!(
-                        "{prefix} #{rank} currently requires {maybe_approx}**{required_pp}pp**, \
-                        so {username} is missing **{missing}** raw pp.\n\
-                        Filling up {username}'{genitiv} top scores with {amount} new \
-                        {each}pp score{plural} would only lead to {approx}**{top}pp** which \
-                        is still less than {required_pp}pp.",
-                        required_pp = WithComma::new(required_pp),
-                        amount = len - idx,
-                        each = WithComma::new(each),
-                        missing = WithComma::new(required_pp - user_pp),
-                        plural = if len - idx != 1 { "s" } else { "" },
+                    let amount = len - idx;
+
+                    let key = if amount == 1 {
+                        "rank_pp.each.unreachable.one"
+                    } else {
+                        "rank_pp.each.unreachable.other"
+                    };
+
+                    let approx = if idx >= 200 {
+                        tr!("rank_pp.each.roughly", lang)
+                    } else {
+                        String::new()
+                    };
+
+                    return tr!(
+                        key,
+                        lang,
+                        prefix = prefix,
+                        username = username,
+                        missing = WithComma::new(target_pp - user_pp),
                         genitiv = if idx != 1 { "s" } else { "" },
-                        approx = if idx >= 200 { "roughly " } else { "" },
+                        amount = amount,
+                        each = WithComma::new(each),
+                        approx = approx,
                         top = WithComma::new(top),
+                        target = WithComma::new(target_pp),
                     );
                 }
 
@@ -1122,17 +1115,23 @@ impl RankData {
                 // Calculate the pp of the missing score after adding `n_each`
                 // many `each` pp scores
                 let total = accum + bonus_pp;
-                let (required, _) = pp_missing(total, required_pp, pps.as_slice());
-
-                format!(
-                    "{prefix} #{rank} currently requires {maybe_approx}**{required_pp}pp**, \
-                    so {username} is missing **{missing}** raw pp.\n\
-                    To reach {required_pp}pp, {username} needs to perform **{n_each}** \
-                    more {each}pp score{plural} and one **{required}pp** score.",
-                    required_pp = WithComma::new(required_pp),
-                    missing = WithComma::new(required_pp - user_pp),
+                let (required, _) = pp_missing(total, target_pp, pps.as_slice());
+
+                let key = if n_each == 1 {
+                    "rank_pp.each.required.one"
+                } else {
+                    "rank_pp.each.required.other"
+                };
+
+                tr!(
+                    key,
+                    lang,
+                    prefix = prefix,
+                    username = username,
+                    missing = WithComma::new(target_pp - user_pp),
+                    target = WithComma::new(target_pp),
+                    n_each = n_each,
                     each = WithComma::new(each),
-                    plural = if n_each != 1 { "s" } else { "" },
                     required = WithComma::new(required),
                 )
             }
@@ -1460,4 +1459,34 @@ mod tests {
         assert_eq!(args.name.as_deref(), Some("cd36"));
         assert_eq!(args.country.as_deref(), Some("be"));
     }
+
+    #[test]
+    fn quoted_name_rank() {
+        let args = Args::new("\"- Ares -\" 123", ArgsNum::None);
+        let args = RankPp::args(None, args).unwrap();
+
+        assert_eq!(args.rank, "123");
+        assert_eq!(args.name.as_deref(), Some("- Ares -"));
+        assert!(args.country.is_none());
+    }
+
+    #[test]
+    fn country_rank_prefixed_quoted_name() {
+        let args = Args::new("be50 user=\"- Ares -\"", ArgsNum::None);
+        let args = RankPp::args(None, args).unwrap();
+
+        assert_eq!(args.rank, "50");
+        assert_eq!(args.name.as_deref(), Some("- Ares -"));
+        assert_eq!(args.country.as_deref(), Some("be"));
+    }
+
+    #[test]
+    fn prefixed_quoted_rank() {
+        let args = Args::new("rank=\"123\" peppy", ArgsNum::None);
+        let args = RankPp::args(None, args).unwrap();
+
+        assert_eq!(args.rank, "123");
+        assert_eq!(args.name.as_deref(), Some("peppy"));
+        assert!(args.country.is_none());
+    }
 }
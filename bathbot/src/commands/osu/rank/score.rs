@@ -3,11 +3,8 @@ use std::{borrow::Cow, cmp, fmt::Write, iter};
 use bathbot_macros::command;
 use bathbot_model::{RespektiveUser, command_fields::GameModeOption};
 use bathbot_util::{
-    AuthorBuilder, CowUtils, EmbedBuilder, MessageBuilder,
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
-    matcher,
-    numbers::WithComma,
-    osu::flag_url,
+    AuthorBuilder, CowUtils, EmbedBuilder, MessageBuilder, constants::OSU_BASE, i18n::ErrorKey,
+    matcher, numbers::WithComma, osu::flag_url,
 };
 use eyre::{Report, Result};
 use rosu_v2::prelude::{OsuError, UserId, Username};
@@ -164,7 +161,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankScore<'_>) -> Resul
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get user"));
         }
@@ -227,7 +224,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankScore<'_>) -> Resul
                     return orig.error(content).await;
                 }
                 Err(err) => {
-                    let _ = orig.error(GENERAL_ISSUE).await;
+                    let _ = orig.error_tr(ErrorKey::General).await;
 
                     return Err(Report::new(err).wrap_err("Failed to get target user"));
                 }
@@ -238,7 +235,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankScore<'_>) -> Resul
             let rank_opt = match user_fut.await {
                 Ok(mut users) => users.next().flatten().and_then(|user| user.rank),
                 Err(err) => {
-                    let _ = orig.error(GENERAL_ISSUE).await;
+                    let _ = orig.error_tr(ErrorKey::General).await;
 
                     return Err(err.wrap_err("Failed to get respektive user"));
                 }
@@ -306,7 +303,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankScore<'_>) -> Resul
                 return orig.error(content).await;
             }
             Err(err) => {
-                let _ = orig.error(OSU_API_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::OsuApi).await;
                 let err = Report::new(err).wrap_err("Failed to get user");
 
                 return Err(err);
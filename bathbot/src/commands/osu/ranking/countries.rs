@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 
 use bathbot_macros::command;
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_util::constants::{GENERAL_ISSUE, OSU_API_ISSUE};
+use bathbot_util::i18n::ErrorKey;
 use eyre::{Report, Result};
 use rosu_v2::prelude::GameMode;
 
@@ -53,7 +53,7 @@ pub(super) async fn country(orig: CommandOrigin<'_>, args: RankingCountry) -> Re
         None => match Context::user_config().mode(owner).await {
             Ok(mode) => mode.unwrap_or(GameMode::Osu),
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err);
             }
@@ -63,7 +63,7 @@ pub(super) async fn country(orig: CommandOrigin<'_>, args: RankingCountry) -> Re
     let mut ranking = match Context::osu().country_rankings(mode).await {
         Ok(ranking) => ranking,
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get country ranking");
 
             return Err(err);
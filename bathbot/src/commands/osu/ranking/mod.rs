@@ -36,6 +36,8 @@ pub struct RankingPp<'a> {
     mode: Option<GameModeOption>,
     #[command(desc = "Specify a country (code)")]
     country: Option<Cow<'a, str>>,
+    #[command(min_value = 1, desc = "Specify a page to start on")]
+    page: Option<u32>,
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -49,6 +51,8 @@ pub struct RankingScore<'a> {
     mode: Option<GameModeOption>,
     #[command(desc = "Specify a country (code)")]
     country: Option<Cow<'a, str>>,
+    #[command(min_value = 1, desc = "Specify a page to start on")]
+    page: Option<u32>,
 }
 
 impl From<Option<GameModeOption>> for RankingScore<'_> {
@@ -56,6 +60,7 @@ impl From<Option<GameModeOption>> for RankingScore<'_> {
         Self {
             mode,
             country: None,
+            page: None,
         }
     }
 }
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, iter};
+use std::{borrow::Cow, fmt::Write, iter};
 
 use bathbot_cache::model::CachedArchive;
 use bathbot_macros::command;
@@ -6,7 +6,7 @@ use bathbot_model::{
     Countries, RankingEntries, RankingEntry, RankingKind, command_fields::GameModeOption,
     rosu_v2::ranking::ArchivedRankings,
 };
-use bathbot_util::constants::GENERAL_ISSUE;
+use bathbot_util::i18n::ErrorKey;
 use eyre::{Report, Result};
 use rosu_v2::prelude::{CountryCode, GameMode, Rankings};
 
@@ -19,19 +19,18 @@ use crate::{
     util::ChannelExt,
 };
 
-// TODO: this sucks
 fn check_country(arg: &str) -> Result<CountryCode, &'static str> {
-    if arg.len() == 2 && arg.is_ascii() {
-        Ok(arg.into())
-    } else if let Some(code) = Countries::name(arg).to_code() {
-        Ok(code.into())
-    } else {
-        Err("The given argument must be a valid country or country code of two ASCII letters")
-    }
+    Countries::resolve(arg)
+        .map(|resolved| resolved.to_country_code())
+        .ok_or("The given argument must be a valid country or country code of two ASCII letters")
 }
 
 pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankingPp<'_>) -> Result<()> {
-    let RankingPp { country, mode } = args;
+    let RankingPp {
+        country,
+        mode,
+        page,
+    } = args;
     let owner = orig.user_id()?;
 
     let (mode, author_id) = match mode.map(GameMode::from) {
@@ -46,7 +45,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankingPp<'_>) -> Result<(
         None => match Context::user_config().with_osu_id(owner).await {
             Ok(config) => (config.mode.unwrap_or(GameMode::Osu), config.osu),
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err.wrap_err("Failed to get user config"));
             }
@@ -54,9 +53,8 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankingPp<'_>) -> Result<(
     };
 
     let country = match country.as_deref() {
-        Some(country) if country.len() == 2 => Some(country.to_uppercase().into()),
-        Some(country) => match Countries::name(country).to_code() {
-            Some(code) => Some(CountryCode::from(code)),
+        Some(country) => match Countries::resolve(country) {
+            Some(resolved) => Some(resolved.to_country_code()),
             None => {
                 let content =
                     format!("Looks like `{country}` is neither a country name nor a country code");
@@ -73,8 +71,9 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankingPp<'_>) -> Result<(
 
     let (ranking_res, author_idx) = tokio::join!(ranking_fut, author_idx_fut);
     let ranking_res = ranking_res.map(Ranking::Performance);
+    let command = invocation("pp", mode, country.as_ref());
 
-    ranking(orig, mode, country, author_idx, ranking_res).await
+    ranking(orig, mode, country, author_idx, ranking_res, command, page).await
 }
 
 async fn pp_author_idx(
@@ -121,7 +120,11 @@ async fn pp_author_idx(
 }
 
 pub(super) async fn score(orig: CommandOrigin<'_>, args: RankingScore<'_>) -> Result<()> {
-    let RankingScore { country, mode } = args;
+    let RankingScore {
+        country,
+        mode,
+        page,
+    } = args;
     let owner = orig.user_id()?;
 
     let (mode, author_id) = match mode.map(GameMode::from) {
@@ -136,7 +139,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankingScore<'_>) -> Re
         None => match Context::user_config().with_osu_id(owner).await {
             Ok(config) => (config.mode.unwrap_or(GameMode::Osu), config.osu),
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err.wrap_err("Failed to get user config"));
             }
@@ -144,9 +147,8 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankingScore<'_>) -> Re
     };
 
     let country = match country.as_deref() {
-        Some(country) if country.len() == 2 => Some(country.to_uppercase().into()),
-        Some(country) => match Countries::name(country).to_code() {
-            Some(code) => Some(CountryCode::from(code)),
+        Some(country) => match Countries::resolve(country) {
+            Some(resolved) => Some(resolved.to_country_code()),
             None => {
                 let content =
                     format!("Looks like `{country}` is neither a country name nor a country code");
@@ -172,7 +174,9 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankingScore<'_>) -> Re
         .map_err(Report::new)
         .map_err(RedisError::Acquire);
 
-    ranking(orig, mode, country, author_idx, ranking_res).await
+    let command = invocation("score", mode, country.as_ref());
+
+    ranking(orig, mode, country, author_idx, ranking_res, command, page).await
 }
 
 async fn score_author_idx(
@@ -205,11 +209,13 @@ async fn ranking(
     country: Option<CountryCode>,
     author_idx: Option<usize>,
     result: Result<Ranking, RedisError>,
+    command: String,
+    page: Option<u32>,
 ) -> Result<()> {
     let ranking = match result {
         Ok(ranking) => ranking,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get ranking"));
         }
@@ -244,6 +250,8 @@ async fn ranking(
         .kind(ranking_kind)
         .defer(true)
         .msg_owner(orig.user_id()?)
+        .command(Some(command))
+        .start_page(page.map(|page| page as usize))
         .build();
 
     ActiveMessages::builder(pagination)
@@ -252,6 +260,17 @@ async fn ranking(
         .await
 }
 
+fn invocation(kind: &str, mode: GameMode, country: Option<&CountryCode>) -> String {
+    let mode = GameModeOption::from(mode).value();
+    let mut command = format!("/ranking {kind} mode:{mode}");
+
+    if let Some(country) = country {
+        let _ = write!(command, " country:{}", country.as_str());
+    }
+
+    command
+}
+
 enum Ranking {
     Performance(CachedArchive<ArchivedRankings>),
     Score(Rankings),
@@ -356,6 +375,7 @@ pub async fn prefix_ppranking(msg: &Message, mut args: Args<'_>) -> Result<()> {
     let args = RankingPp {
         mode: None,
         country: country.map(CountryCode::into_string).map(Cow::Owned),
+        page: None,
     };
 
     pp(msg.into(), args).await
@@ -386,6 +406,7 @@ pub async fn prefix_pprankingmania(msg: &Message, mut args: Args<'_>) -> Result<
     let args = RankingPp {
         mode: Some(GameModeOption::Mania),
         country: country.map(CountryCode::into_string).map(Cow::Owned),
+        page: None,
     };
 
     pp(msg.into(), args).await
@@ -416,6 +437,7 @@ pub async fn prefix_pprankingtaiko(msg: &Message, mut args: Args<'_>) -> Result<
     let args = RankingPp {
         mode: Some(GameModeOption::Taiko),
         country: country.map(CountryCode::into_string).map(Cow::Owned),
+        page: None,
     };
 
     pp(msg.into(), args).await
@@ -446,6 +468,7 @@ pub async fn prefix_pprankingctb(msg: &Message, mut args: Args<'_>) -> Result<()
     let args = RankingPp {
         mode: Some(GameModeOption::Catch),
         country: country.map(CountryCode::into_string).map(Cow::Owned),
+        page: None,
     };
 
     pp(msg.into(), args).await
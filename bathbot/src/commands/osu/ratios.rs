@@ -1,14 +1,14 @@
 use std::borrow::Cow;
 
 use bathbot_macros::{HasName, SlashCommand, command};
-use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE, matcher};
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_util::{MessageBuilder, i18n::ErrorKey, matcher, osu::HitResultKind};
 use eyre::{Report, Result};
 use rosu_v2::{
     prelude::{GameMode, OsuError},
     request::UserId,
 };
-use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::id::{Id, marker::UserMarker};
 
 use super::{require_link, user_not_found};
@@ -21,32 +21,81 @@ use crate::{
     util::{InteractionCommandExt, interaction::InteractionCommand},
 };
 
-const RATIOS_DESC: &str = "Ratio related stats about a user's mania top100";
+const RATIOS_DESC: &str = "Ratio related stats about a user's top200";
 
 #[derive(CommandModel, CreateCommand, Default, HasName, SlashCommand)]
 #[command(
     name = "ratios",
     desc = RATIOS_DESC,
     help = "The \"ratio\" of a mania score is generally considered to be `n320/n300` \
-    (or sometimes `n320/everything else`).\n\n\
+    (or sometimes `n320/everything else`).\n\
+    Other ratios such as `n300/n100` in taiko can be chosen via the \
+    `numerator` and `denominator` options.\n\n\
     How to read the embed:\n\
     The first column defines how the top scores are split up based on their accuracy.\n\
     E.g. `>90%` will only include top scores that have more than 90% accuracy.\n\
     The second column tells how many scores are in the corresponding accuracy row.\n\
-    For the third column, it calculates the ratio of all scores in that row and displays their average.\n\
-    The fourth column shows the average percentual miss amount for scores in the corresponding row."
+    For the third column, it calculates the ratio of each score in that row and displays their average.\n\
+    The fourth column shows the ratio of the summed up hitresults of all scores in that row.\n\
+    The fifth column shows the average percentual miss amount for scores in the corresponding row.\n\
+    Ratios without any hits in the denominator are shown as `inf`, or `-` if there are no hits at all."
 )]
 pub struct Ratios<'a> {
     #[command(desc = "Specify a username")]
     name: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a gamemode, defaults to mania")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify the hitresult of the ratio's numerator")]
+    numerator: Option<RatioHitResult>,
+    #[command(desc = "Specify the hitresult of the ratio's denominator")]
+    denominator: Option<RatioHitResult>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
 }
 
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum RatioHitResult {
+    #[option(name = "320 / Perfect", value = "perfect")]
+    Perfect,
+    #[option(name = "300 / Great / Fruits", value = "great")]
+    Great,
+    #[option(name = "200 / Good", value = "good")]
+    Good,
+    #[option(name = "100 / Ok", value = "ok")]
+    Ok,
+    #[option(name = "50 / Meh", value = "meh")]
+    Meh,
+    #[option(name = "Miss", value = "miss")]
+    Miss,
+    #[option(name = "Droplets (ctb)", value = "large_tick_hit")]
+    LargeTickHit,
+    #[option(name = "Droplets hit (ctb)", value = "small_tick_hit")]
+    SmallTickHit,
+    #[option(name = "Droplets missed (ctb)", value = "small_tick_miss")]
+    SmallTickMiss,
+}
+
+impl From<RatioHitResult> for HitResultKind {
+    #[inline]
+    fn from(hitresult: RatioHitResult) -> Self {
+        match hitresult {
+            RatioHitResult::Perfect => Self::Perfect,
+            RatioHitResult::Great => Self::Great,
+            RatioHitResult::Good => Self::Good,
+            RatioHitResult::Ok => Self::Ok,
+            RatioHitResult::Meh => Self::Meh,
+            RatioHitResult::Miss => Self::Miss,
+            RatioHitResult::LargeTickHit => Self::LargeTickHit,
+            RatioHitResult::SmallTickHit => Self::SmallTickHit,
+            RatioHitResult::SmallTickMiss => Self::SmallTickMiss,
+        }
+    }
+}
+
 #[command]
 #[desc(RATIOS_DESC)]
 #[help(
-    "Calculate the average ratios of a user's top100.\n\
+    "Calculate the average 320:300 ratios of a user's mania top200.\n\
     If the command was used before on the given osu name, \
     I will also compare the current results with the ones from last time \
     if they've changed since."
@@ -59,12 +108,12 @@ async fn prefix_ratios(msg: &Message, mut args: Args<'_>) -> Result<()> {
     let args = match args.next() {
         Some(arg) => match matcher::get_mention_user(arg) {
             Some(id) => Ratios {
-                name: None,
                 discord: Some(id),
+                ..Default::default()
             },
             None => Ratios {
                 name: Some(Cow::Borrowed(arg)),
-                discord: None,
+                ..Default::default()
             },
         },
         None => Ratios::default(),
@@ -80,6 +129,28 @@ async fn slash_ratios(mut command: InteractionCommand) -> Result<()> {
 }
 
 async fn ratios(orig: CommandOrigin<'_>, args: Ratios<'_>) -> Result<()> {
+    let mode = args.mode.map_or(GameMode::Mania, GameMode::from);
+    let (default_numerator, default_denominator) = HitResultKind::default_ratio(mode);
+    let numerator = args
+        .numerator
+        .map_or(default_numerator, HitResultKind::from);
+    let denominator = args
+        .denominator
+        .map_or(default_denominator, HitResultKind::from);
+
+    if let Some(invalid) = [numerator, denominator]
+        .into_iter()
+        .find(|hitresult| !hitresult.is_valid_for(mode))
+    {
+        let content = format!(
+            "Scores of mode {mode} don't have `{}` hitresults",
+            invalid.name(mode),
+            mode = mode_name(mode),
+        );
+
+        return orig.error(content).await;
+    }
+
     let owner = orig.user_id()?;
     let config = Context::user_config().with_osu_id(owner).await?;
 
@@ -91,22 +162,16 @@ async fn ratios(orig: CommandOrigin<'_>, args: Ratios<'_>) -> Result<()> {
         },
     };
 
-    let legacy_scores = match config.score_data {
-        Some(score_data) => score_data.is_legacy(),
-        None => match orig.guild_id() {
-            Some(guild_id) => Context::guild_config()
-                .peek(guild_id, |config| config.score_data)
-                .await
-                .is_some_and(ScoreData::is_legacy),
-            None => false,
-        },
-    };
+    let legacy_scores = Context::guild_config()
+        .resolve_score_data(orig.guild_id(), None, config.score_data)
+        .await
+        .is_legacy();
 
     // Retrieve the user and their top scores
-    let user_args = UserArgs::rosu_id(&user_id, GameMode::Mania).await;
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
     let scores_fut = Context::osu_scores()
-        .top(100, legacy_scores)
+        .top(200, legacy_scores)
         .exec_with_user(user_args);
 
     let (user, scores) = match scores_fut.await {
@@ -117,7 +182,7 @@ async fn ratios(orig: CommandOrigin<'_>, args: Ratios<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user or scores");
 
             return Err(err);
@@ -125,11 +190,14 @@ async fn ratios(orig: CommandOrigin<'_>, args: Ratios<'_>) -> Result<()> {
     };
 
     // Accumulate all necessary data
-    let embed_data = RatioEmbed::new(&user, scores);
+    let embed_data = RatioEmbed::new(&user, scores, mode, numerator, denominator);
 
     let content = format!(
-        "Average ratios of `{}`'s top 100 in mania:",
-        user.username.as_str()
+        "Average {}:{} ratios of `{}`'s top 200 in {}:",
+        numerator.name(mode),
+        denominator.name(mode),
+        user.username.as_str(),
+        mode_name(mode),
     );
 
     // Creating the embed
@@ -139,3 +207,12 @@ async fn ratios(orig: CommandOrigin<'_>, args: Ratios<'_>) -> Result<()> {
 
     Ok(())
 }
+
+fn mode_name(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "osu!",
+        GameMode::Taiko => "taiko",
+        GameMode::Catch => "ctb",
+        GameMode::Mania => "mania",
+    }
+}
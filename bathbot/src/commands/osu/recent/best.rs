@@ -0,0 +1,636 @@
+use std::{borrow::Cow, fmt::Write, sync::Arc};
+
+use bathbot_macros::command;
+use bathbot_model::{command_fields::GameModeOption, embed_builder::SettingsImage};
+use bathbot_psql::model::configs::resolve;
+use bathbot_util::{CowUtils, MessageOrigin, i18n::ErrorKey, matcher};
+use eyre::{Report, Result};
+use rosu_v2::{
+    prelude::{GameMode, OsuError, Score},
+    request::UserId,
+};
+use time::{Duration, OffsetDateTime};
+use twilight_model::guild::Permissions;
+
+use super::RecentBest;
+use crate::{
+    Context,
+    active::{
+        ActiveMessages,
+        impls::{ScoreSkin, SingleScoreContent, SingleScorePagination},
+    },
+    commands::{
+        osu::{Theme, map_strains_graph, require_link, user_not_found},
+        utility::{MissAnalyzerCheck, ScoreEmbedDataWrap},
+    },
+    core::commands::{
+        CommandOrigin,
+        prefix::{Args, ArgsNum},
+    },
+    manager::redis::osu::{UserArgs, UserArgsError, UserArgsSlim},
+    util::{ChannelExt, CheckPermissions},
+};
+
+/// Amount of days that are considered if none are specified.
+const DEFAULT_DAYS: u8 = 7;
+
+const RECENT_BEST_USAGE: &str = "[username] [days=number]";
+
+#[command]
+#[desc("Display a user's best play of the last days")]
+#[help(
+    "Display a user's highest pp play that was set within the last few days.\n\
+    By default the last 7 days are considered, specify e.g. `days=30` to change that. \
+    The amount of days must be between 1 and 90.\n\
+    To get the second or third best play instead, you can add a number right after the command, \
+    e.g. `rb2 badewanne3`.\n\
+    If none of the top plays were set within that time, the recent plays of the last 24 hours \
+    are considered instead."
+)]
+#[usage(RECENT_BEST_USAGE)]
+#[examples("badewanne3", "days=30", "whitecat days=3")]
+#[alias(
+    "rb",
+    "rsb",
+    "rbo",
+    "rbs",
+    "recentbestosu",
+    "recentbeststd",
+    "recentbeststandard"
+)]
+#[group(Osu)]
+async fn prefix_recentbest(msg: &Message, args: Args<'_>) -> Result<()> {
+    match RecentBest::args(None, args) {
+        Ok(args) => best(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+#[command]
+#[desc("Display a user's best mania play of the last days")]
+#[help(
+    "Display a user's highest pp mania play that was set within the last few days.\n\
+    By default the last 7 days are considered, specify e.g. `days=30` to change that. \
+    The amount of days must be between 1 and 90.\n\
+    To get the second or third best play instead, you can add a number right after the command, \
+    e.g. `rbm2 badewanne3`.\n\
+    If none of the top plays were set within that time, the recent plays of the last 24 hours \
+    are considered instead."
+)]
+#[usage(RECENT_BEST_USAGE)]
+#[examples("badewanne3", "days=30", "whitecat days=3")]
+#[alias("rbm", "rsbm")]
+#[group(Mania)]
+async fn prefix_recentbestmania(msg: &Message, args: Args<'_>) -> Result<()> {
+    match RecentBest::args(Some(GameModeOption::Mania), args) {
+        Ok(args) => best(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+#[command]
+#[desc("Display a user's best taiko play of the last days")]
+#[help(
+    "Display a user's highest pp taiko play that was set within the last few days.\n\
+    By default the last 7 days are considered, specify e.g. `days=30` to change that. \
+    The amount of days must be between 1 and 90.\n\
+    To get the second or third best play instead, you can add a number right after the command, \
+    e.g. `rbt2 badewanne3`.\n\
+    If none of the top plays were set within that time, the recent plays of the last 24 hours \
+    are considered instead."
+)]
+#[usage(RECENT_BEST_USAGE)]
+#[examples("badewanne3", "days=30", "whitecat days=3")]
+#[alias("rbt", "rsbt")]
+#[group(Taiko)]
+async fn prefix_recentbesttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
+    match RecentBest::args(Some(GameModeOption::Taiko), args) {
+        Ok(args) => best(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+#[command]
+#[desc("Display a user's best ctb play of the last days")]
+#[help(
+    "Display a user's highest pp ctb play that was set within the last few days.\n\
+    By default the last 7 days are considered, specify e.g. `days=30` to change that. \
+    The amount of days must be between 1 and 90.\n\
+    To get the second or third best play instead, you can add a number right after the command, \
+    e.g. `rbc2 badewanne3`.\n\
+    If none of the top plays were set within that time, the recent plays of the last 24 hours \
+    are considered instead."
+)]
+#[usage(RECENT_BEST_USAGE)]
+#[examples("badewanne3", "days=30", "whitecat days=3")]
+#[alias("rbc", "rsbc", "recentbestcatch")]
+#[group(Catch)]
+async fn prefix_recentbestctb(msg: &Message, args: Args<'_>) -> Result<()> {
+    match RecentBest::args(Some(GameModeOption::Catch), args) {
+        Ok(args) => best(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+impl<'m> RecentBest<'m> {
+    fn args(mode: Option<GameModeOption>, args: Args<'m>) -> Result<Self, Cow<'static, str>> {
+        let mut name = None;
+        let mut discord = None;
+        let mut days = None;
+
+        let index = match args.num {
+            ArgsNum::Value(n) => Some(u8::try_from(n).unwrap_or(u8::MAX)),
+            ArgsNum::Random | ArgsNum::None => None,
+        };
+
+        for arg in args.into_args().take(2) {
+            let key_value = arg.key_value();
+            let arg = arg.into_lowercase();
+
+            if let Some((key, value)) = key_value {
+                let key = key.cow_to_ascii_lowercase();
+                let value = value.cow_to_ascii_lowercase();
+                let value = value.trim_end();
+
+                match key.as_ref() {
+                    "days" | "day" | "d" => match value.parse() {
+                        Ok(n @ 1..=90) => days = Some(n),
+                        _ => {
+                            let content =
+                                "Failed to parse `days`. Must be an integer between 1 and 90.";
+
+                            return Err(content.into());
+                        }
+                    },
+                    _ => {
+                        let content = format!(
+                            "Unrecognized option `{key}`.\n\
+                            Available options are: `days`."
+                        );
+
+                        return Err(content.into());
+                    }
+                }
+            } else if let Some(id) = matcher::get_mention_user(&arg) {
+                discord = Some(id);
+            } else {
+                name = Some(arg);
+            }
+        }
+
+        Ok(Self {
+            mode,
+            name,
+            days,
+            index,
+            discord,
+            score_data: None,
+        })
+    }
+}
+
+pub(super) async fn best(orig: CommandOrigin<'_>, args: RecentBest<'_>) -> Result<()> {
+    let author = orig.user_id()?;
+
+    let config = match Context::user_config().with_osu_id(author).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error_tr(ErrorKey::General).await;
+
+            return Err(err.wrap_err("Failed to get user config"));
+        }
+    };
+
+    let mode = resolve::mode(args.mode.map(GameMode::from), config.mode);
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match config.osu {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
+    let (guild_render_button, guild_score_data) = match orig.guild_id() {
+        Some(guild_id) => {
+            Context::guild_config()
+                .peek(guild_id, |config| (config.render_button, config.score_data))
+                .await
+        }
+        None => (None, None),
+    };
+
+    let score_data = resolve::score_data(args.score_data, config.score_data, guild_score_data);
+    let legacy_scores = score_data.is_legacy();
+
+    let days = args.days.unwrap_or(DEFAULT_DAYS).clamp(1, 90);
+    let index = args
+        .index
+        .map_or(0, |idx| usize::from(idx).saturating_sub(1));
+    let since = OffsetDateTime::now_utc() - Duration::days(i64::from(days));
+
+    // Retrieve the user and their top scores
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+    let scores_fut = Context::osu_scores()
+        .top(200, legacy_scores)
+        .exec_with_user(user_args);
+
+    let (user, top) = match scores_fut.await {
+        Ok((user, scores)) => (user, scores),
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error_tr(ErrorKey::General).await;
+            let err = Report::new(err).wrap_err("Failed to get user or scores");
+
+            return Err(err);
+        }
+    };
+
+    let top_candidates: Vec<_> = top.iter().map(Candidate::from).collect();
+
+    // Only request recent scores if they're actually needed
+    let mut recent = if needs_recent_scan(&top_candidates, since) {
+        let user_args = UserArgsSlim::user_id(user.user_id.to_native()).mode(mode);
+
+        let recent_fut = Context::osu_scores()
+            .recent(legacy_scores)
+            .limit(100)
+            .include_fails(false)
+            .exec(user_args);
+
+        match recent_fut.await {
+            Ok(scores) => scores,
+            Err(err) => {
+                let _ = orig.error_tr(ErrorKey::OsuApi).await;
+                let err = Report::new(err).wrap_err("Failed to get recent scores");
+
+                return Err(err);
+            }
+        }
+    } else {
+        Vec::new()
+    };
+
+    let recent_candidates: Vec<_> = recent.iter().map(Candidate::from).collect();
+
+    let selection = match select_recent_best(&top_candidates, &recent_candidates, since, index) {
+        Ok(selection) => selection,
+        Err(err) => {
+            let content = err.content(user.username.as_str(), days);
+
+            return orig.error(content).await;
+        }
+    };
+
+    let score = match selection {
+        Selection::Top(idx) => top[idx].clone(),
+        Selection::Recent(idx) => recent.swap_remove(idx),
+    };
+
+    let settings = config.score_embed.unwrap_or_default();
+
+    let mut with_render = resolve::render_button(config.render_button, guild_render_button);
+
+    with_render &= settings.buttons.render
+        && mode == GameMode::Osu
+        && orig.has_permission_to(Permissions::SEND_MESSAGES)
+        && Context::ordr_available();
+
+    let origin = MessageOrigin::new(orig.guild_id(), orig.channel_id());
+
+    let entry = ScoreEmbedDataWrap::new_raw(
+        score,
+        legacy_scores,
+        with_render,
+        MissAnalyzerCheck::without(),
+        Some(Arc::from(top)),
+        #[cfg(feature = "twitch")]
+        None,
+        origin,
+    );
+
+    let mut entries: Box<[_]> = Box::new([entry]);
+
+    let graph = match entries.first_mut() {
+        Some(entry) if matches!(settings.image, SettingsImage::ImageWithStrains) => {
+            match entry.get_mut().await {
+                Ok(entry) => {
+                    let fut = map_strains_graph(
+                        &entry.map.pp_map,
+                        entry.score.mods.clone(),
+                        entry.map.cover(),
+                        SingleScorePagination::IMAGE_W,
+                        SingleScorePagination::IMAGE_H,
+                        Theme::get(config.graph_theme),
+                    );
+
+                    match fut.await {
+                        Ok(graph) => Some((SingleScorePagination::IMAGE_NAME.to_owned(), graph)),
+                        Err(err) => {
+                            warn!(?err, "Failed to create strain graph");
+
+                            None
+                        }
+                    }
+                }
+                Err(err) => {
+                    warn!(?err, "Failed to get score data");
+
+                    None
+                }
+            }
+        }
+        Some(_) | None => None,
+    };
+
+    let content = SingleScoreContent::SameForAll(window_content(index, days, selection));
+
+    let mut pagination =
+        SingleScorePagination::new(&user, entries, settings, score_data, author, content);
+
+    pagination.set_timestamp_style(config.timestamp_style);
+    pagination.set_skin(ScoreSkin::from_config(&config));
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .attachment(graph)
+        .begin(orig)
+        .await
+}
+
+/// The properties of a score that matter when picking the best one within a
+/// time window.
+#[derive(Copy, Clone)]
+struct Candidate {
+    pp: Option<f32>,
+    ended_at: OffsetDateTime,
+    passed: bool,
+}
+
+impl Candidate {
+    fn qualifies(&self, since: OffsetDateTime) -> bool {
+        self.passed && self.pp.is_some() && self.ended_at >= since
+    }
+}
+
+impl From<&Score> for Candidate {
+    fn from(score: &Score) -> Self {
+        Self {
+            pp: score.pp,
+            ended_at: score.ended_at,
+            passed: score.passed,
+        }
+    }
+}
+
+/// Index of the selected score within the list it was picked from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Selection {
+    Top(usize),
+    Recent(usize),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum SelectionError {
+    /// No score was set within the window
+    Empty,
+    /// The index exceeds the amount of scores within the window
+    TooFewScores(usize),
+}
+
+impl SelectionError {
+    fn content(self, username: &str, days: u8) -> String {
+        let window = if days == 1 {
+            Cow::Borrowed("day")
+        } else {
+            Cow::Owned(format!("{days} days"))
+        };
+
+        match self {
+            Self::Empty => {
+                format!("`{username}` has not set any pp plays within the last {window}")
+            }
+            Self::TooFewScores(count) => format!(
+                "`{username}` only has {count} pp play{plural} within the last {window}",
+                plural = if count == 1 { "" } else { "s" },
+            ),
+        }
+    }
+}
+
+/// Whether none of the top scores were set within the window so that the
+/// recent scores need to be scanned instead.
+fn needs_recent_scan(top: &[Candidate], since: OffsetDateTime) -> bool {
+    !top.iter().any(|candidate| candidate.qualifies(since))
+}
+
+/// Pick the score with the `index`-th highest pp that was set since the given
+/// date.
+///
+/// Top scores are considered first; only if none of them qualify, the recent
+/// scores are scanned instead.
+fn select_recent_best(
+    top: &[Candidate],
+    recent: &[Candidate],
+    since: OffsetDateTime,
+    index: usize,
+) -> Result<Selection, SelectionError> {
+    let (candidates, selection): (_, fn(usize) -> Selection) = if needs_recent_scan(top, since) {
+        (recent, Selection::Recent)
+    } else {
+        (top, Selection::Top)
+    };
+
+    let mut qualifying: Vec<_> = candidates
+        .iter()
+        .enumerate()
+        .filter(|(_, candidate)| candidate.qualifies(since))
+        .filter_map(|(idx, candidate)| candidate.pp.map(|pp| (idx, pp)))
+        .collect();
+
+    if qualifying.is_empty() {
+        return Err(SelectionError::Empty);
+    }
+
+    // Stable sort so that ties keep their original order
+    qualifying.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+    qualifying
+        .get(index)
+        .map(|&(idx, _)| selection(idx))
+        .ok_or(SelectionError::TooFewScores(qualifying.len()))
+}
+
+fn window_content(index: usize, days: u8, selection: Selection) -> String {
+    let mut content = if index == 0 {
+        "Best play".to_owned()
+    } else {
+        format!("#{} best play", index + 1)
+    };
+
+    if days == 1 {
+        content.push_str(" of the last day");
+    } else {
+        let _ = write!(content, " of the last {days} days");
+    }
+
+    if let Selection::Recent(_) = selection {
+        content.push_str(" (no top play in that time, picked from recent plays)");
+    }
+
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap()
+    }
+
+    fn candidate(pp: Option<f32>, days_ago: i64, passed: bool) -> Candidate {
+        Candidate {
+            pp,
+            ended_at: now() - Duration::days(days_ago),
+            passed,
+        }
+    }
+
+    fn since(days: i64) -> OffsetDateTime {
+        now() - Duration::days(days)
+    }
+
+    #[test]
+    fn picks_highest_pp_top_score_within_window() {
+        let top = [
+            candidate(Some(500.0), 30, true),
+            candidate(Some(400.0), 2, true),
+            candidate(Some(450.0), 10, true),
+            candidate(Some(300.0), 1, true),
+        ];
+
+        assert_eq!(
+            select_recent_best(&top, &[], since(7), 0),
+            Ok(Selection::Top(1))
+        );
+        assert_eq!(
+            select_recent_best(&top, &[], since(14), 0),
+            Ok(Selection::Top(2))
+        );
+    }
+
+    #[test]
+    fn ignores_recent_if_top_qualifies() {
+        let top = [candidate(Some(200.0), 3, true)];
+        let recent = [candidate(Some(150.0), 0, true)];
+
+        assert!(!needs_recent_scan(&top, since(7)));
+        assert_eq!(
+            select_recent_best(&top, &recent, since(7), 0),
+            Ok(Selection::Top(0))
+        );
+    }
+
+    #[test]
+    fn falls_back_to_recent_scores() {
+        let top = [candidate(Some(500.0), 30, true)];
+        let recent = [
+            candidate(Some(120.0), 0, false),
+            candidate(None, 0, true),
+            candidate(Some(80.0), 0, true),
+            candidate(Some(95.0), 0, true),
+        ];
+
+        assert!(needs_recent_scan(&top, since(7)));
+        assert_eq!(
+            select_recent_best(&top, &recent, since(7), 0),
+            Ok(Selection::Recent(3))
+        );
+        assert_eq!(
+            select_recent_best(&top, &recent, since(7), 1),
+            Ok(Selection::Recent(2))
+        );
+    }
+
+    #[test]
+    fn index_beyond_window() {
+        let top = [
+            candidate(Some(400.0), 2, true),
+            candidate(Some(300.0), 4, true),
+            candidate(Some(200.0), 20, true),
+        ];
+
+        assert_eq!(
+            select_recent_best(&top, &[], since(7), 1),
+            Ok(Selection::Top(1))
+        );
+        assert_eq!(
+            select_recent_best(&top, &[], since(7), 2),
+            Err(SelectionError::TooFewScores(2))
+        );
+    }
+
+    #[test]
+    fn empty_window() {
+        let top = [candidate(Some(400.0), 20, true)];
+        let recent = [candidate(Some(100.0), 0, false)];
+
+        assert_eq!(
+            select_recent_best(&top, &recent, since(7), 0),
+            Err(SelectionError::Empty)
+        );
+        assert_eq!(
+            select_recent_best(&[], &[], since(1), 0),
+            Err(SelectionError::Empty)
+        );
+    }
+
+    #[test]
+    fn error_content() {
+        assert_eq!(
+            SelectionError::Empty.content("peppy", 7),
+            "`peppy` has not set any pp plays within the last 7 days"
+        );
+        assert_eq!(
+            SelectionError::Empty.content("peppy", 1),
+            "`peppy` has not set any pp plays within the last day"
+        );
+        assert_eq!(
+            SelectionError::TooFewScores(1).content("peppy", 30),
+            "`peppy` only has 1 pp play within the last 30 days"
+        );
+    }
+
+    #[test]
+    fn content_states_window() {
+        assert_eq!(
+            window_content(0, 7, Selection::Top(0)),
+            "Best play of the last 7 days"
+        );
+        assert_eq!(
+            window_content(1, 1, Selection::Recent(0)),
+            "#2 best play of the last day (no top play in that time, picked from recent plays)"
+        );
+    }
+}
@@ -1,9 +1,6 @@
 use bathbot_model::ScoreSlim;
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{
-    MessageBuilder,
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-};
+use bathbot_util::{MessageBuilder, i18n::ErrorKey};
 use eyre::{Report, Result};
 use rand::{Rng, thread_rng};
 use rosu_v2::{
@@ -82,7 +79,7 @@ pub(super) async fn fix(orig: CommandOrigin<'_>, args: RecentFix) -> Result<()>
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user or scores");
 
             return Err(err);
@@ -121,12 +118,12 @@ pub(super) async fn fix(orig: CommandOrigin<'_>, args: RecentFix) -> Result<()>
             match tokio::join!(map_fut, best_fut) {
                 (Ok(map), Ok(best)) => (score, map, best),
                 (Err(err), _) => {
-                    let _ = orig.error(GENERAL_ISSUE).await;
+                    let _ = orig.error_tr(ErrorKey::General).await;
 
                     return Err(Report::new(err));
                 }
                 (_, Err(err)) => {
-                    let _ = orig.error(OSU_API_ISSUE).await;
+                    let _ = orig.error_tr(ErrorKey::OsuApi).await;
                     let err = Report::new(err).wrap_err("failed to get top scores");
 
                     return Err(err);
@@ -1,10 +1,6 @@
 use bathbot_macros::command;
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher,
-    osu::ModSelection,
-};
+use bathbot_util::{i18n::ErrorKey, matcher, osu::ModSelection};
 use eyre::{Report, Result};
 use rand::{Rng, thread_rng};
 use rosu_v2::{
@@ -17,7 +13,8 @@ use crate::{
     Context,
     active::{ActiveMessages, impls::LeaderboardPagination},
     commands::osu::{
-        HasMods, LeaderboardScore, LeaderboardUserScore, ModsResult, require_link, user_not_found,
+        HasMods, LeaderboardScore, LeaderboardUserScore, ModsResult, mark_anomalies, require_link,
+        user_not_found,
     },
     core::commands::{CommandOrigin, prefix::Args},
     manager::{
@@ -173,7 +170,7 @@ pub(super) async fn leaderboard(
     let config = match Context::user_config().with_osu_id(owner).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to get user config"));
         }
@@ -256,7 +253,7 @@ pub(super) async fn leaderboard(
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get scores");
 
             return Err(err);
@@ -293,7 +290,7 @@ pub(super) async fn leaderboard(
     let map = match map_res {
         Ok(map) => map,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(Report::new(err));
         }
@@ -318,7 +315,7 @@ pub(super) async fn leaderboard(
             })
             .collect(),
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
 
             return Err(err.wrap_err("Failed to get scores"));
         }
@@ -374,6 +371,8 @@ pub(super) async fn leaderboard(
         max_combo = attrs.max_combo();
     }
 
+    let anomalies = mark_anomalies(orig.guild_id(), &mut scores, &map).await;
+
     let order = args.sort.unwrap_or_default();
     order.sort(&mut scores, &map, score_data).await;
     order.push_content(&mut content);
@@ -388,6 +387,7 @@ pub(super) async fn leaderboard(
         .author_data(user_score)
         .first_place_icon(first_place_icon)
         .score_data(score_data)
+        .anomalies(anomalies)
         .content(content.into_boxed_str())
         .msg_owner(owner)
         .build();
@@ -10,18 +10,16 @@ use bathbot_model::{
     ScoreSlim,
     command_fields::{GameModeOption, GradeOption},
 };
-use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
     CowUtils, IntHasher,
-    constants::GENERAL_ISSUE,
+    i18n::ErrorKey,
     matcher,
     osu::ModSelection,
     query::{IFilterCriteria, Searchable},
 };
 use eyre::{Report, Result};
-use rosu_pp::{Beatmap, Difficulty, any::DifficultyAttributes};
 use rosu_v2::{
-    prelude::{GameMode, GameMods, Grade, OsuError, Score},
+    prelude::{GameMode, Grade, OsuError, Score},
     request::UserId,
 };
 
@@ -32,7 +30,7 @@ use crate::{
     commands::osu::{HasMods, ModsResult, ScoreOrder, require_link, user_not_found},
     core::commands::{CommandOrigin, prefix::Args},
     manager::{
-        OsuMap,
+        CachedAttributes, OsuMap,
         redis::osu::{UserArgs, UserArgsError},
     },
     util::{ChannelExt, NativeCriteria},
@@ -249,12 +247,16 @@ impl<'m> RecentList<'m> {
         let mut grade = None;
         let mut passes = None;
 
-        for arg in args.take(3).map(|arg| arg.cow_to_ascii_lowercase()) {
-            if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
-                let key = &arg[..idx];
-                let value = arg[idx + 1..].trim_end();
+        for arg in args.into_args().take(3) {
+            let key_value = arg.key_value();
+            let arg = arg.into_lowercase();
 
-                match key {
+            if let Some((key, value)) = key_value {
+                let key = key.cow_to_ascii_lowercase();
+                let value = value.cow_to_ascii_lowercase();
+                let value = value.trim_end();
+
+                match key.as_ref() {
                     "pass" | "p" | "passes" => match value {
                         "true" | "t" | "1" => passes = Some(true),
                         "false" | "f" | "0" => passes = Some(false),
@@ -344,16 +346,10 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: RecentList<'_>) -> Resul
         Some(mode) => mode,
     };
 
-    let legacy_scores = match args.score_data.or(config.score_data) {
-        Some(score_data) => score_data.is_legacy(),
-        None => match orig.guild_id() {
-            Some(guild_id) => Context::guild_config()
-                .peek(guild_id, |config| config.score_data)
-                .await
-                .is_some_and(ScoreData::is_legacy),
-            None => false,
-        },
-    };
+    let legacy_scores = Context::guild_config()
+        .resolve_score_data(orig.guild_id(), args.score_data, config.score_data)
+        .await
+        .is_legacy();
 
     let RecentList {
         query,
@@ -403,7 +399,7 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: RecentList<'_>) -> Resul
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user or scores");
 
             return Err(err);
@@ -413,7 +409,7 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: RecentList<'_>) -> Resul
     let (entries, maps) = match process_scores(scores, &args, mode, mods.as_ref()).await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to process scores"));
         }
@@ -745,54 +741,3 @@ async fn process_scores(
 
     Ok((entries, maps))
 }
-
-// It takes a long time to calculate attributes for maps like /b/5023039 with
-// 32k+ objects so we want to cache as much as possible in case users have
-// the same long map multiple times with the same mods in their recent plays.
-#[derive(Default)]
-struct CachedAttributes {
-    // `GameMods` implements neither `Hash` nor `Ord` so we cannot use
-    // `HashMap` or `BTreeMap` and need to use `Vec` instead.
-    entries: Vec<CachedEntry>,
-}
-
-impl CachedAttributes {
-    /// Returns `None` if the map is too suspicious.
-    async fn get(&mut self, map: &Beatmap, score: &Score) -> Option<DifficultyAttributes> {
-        let map_id = score.map_id;
-        let lazer = score.set_on_lazer;
-
-        let res = self.entries.iter().find(|entry| {
-            entry.map_id == map_id && entry.mods == score.mods && entry.lazer == lazer
-        });
-
-        if let Some(entry) = res {
-            return Some(entry.attrs.clone());
-        } else if map.check_suspicion().is_err() {
-            return None;
-        }
-
-        let mods = score.mods.clone();
-
-        let attrs = Difficulty::new()
-            .mods(mods.clone())
-            .lazer(lazer)
-            .calculate(map);
-
-        self.entries.push(CachedEntry {
-            map_id,
-            lazer,
-            mods,
-            attrs: attrs.clone(),
-        });
-
-        Some(attrs)
-    }
-}
-
-struct CachedEntry {
-    map_id: u32,
-    lazer: bool,
-    mods: GameMods,
-    attrs: DifficultyAttributes,
-}
@@ -2,24 +2,25 @@ use std::borrow::Cow;
 
 use bathbot_macros::{HasMods, HasName, SlashCommand};
 use bathbot_model::command_fields::{GameModeOption, GradeOption};
-use bathbot_psql::model::configs::{ListSize, ScoreData};
+use bathbot_psql::model::configs::ScoreData;
 use eyre::Result;
-use rosu_v2::prelude::{GameMode, Grade};
+use rosu_v2::prelude::GameMode;
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::id::{Id, marker::UserMarker};
 
-use self::fix::*;
+use self::{best::*, fix::*};
 pub use self::{leaderboard::*, list::*, score::*};
-use super::{HasMods, ModsResult, ScoreOrder, TopArgs, TopScoreOrder};
+use super::ScoreOrder;
 use crate::{
     commands::{
         DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
-        osu::{LeaderboardSort, top},
+        osu::LeaderboardSort,
         utility::{SCORE_DATA_DESC, SCORE_DATA_HELP},
     },
-    util::{InteractionCommandExt, interaction::InteractionCommand},
+    util::interaction::InteractionCommand,
 };
 
+mod best;
 mod fix;
 mod leaderboard;
 mod list;
@@ -36,7 +37,7 @@ pub enum Recent<'a> {
     #[command(name = "score")]
     Score(RecentScore<'a>),
     #[command(name = "best")]
-    Best(RecentBest),
+    Best(RecentBest<'a>),
     #[command(name = "leaderboard")]
     Leaderboard(RecentLeaderboard<'a>),
     #[command(name = "list")]
@@ -50,7 +51,8 @@ pub enum Recent<'a> {
     name = "score",
     desc = "Show a user's recent score (same as `/rs`)",
     help = "Show a user's recent score (same as `/rs`).\n\
-    To add a timestamp to a twitch VOD, be sure you linked yourself to a twitch account via `/config`."
+    To add a timestamp to a twitch VOD, be sure you linked yourself to a twitch account \
+    via `/config edit`."
 )]
 pub struct RecentScore<'a> {
     #[command(desc = "Specify a gamemode")]
@@ -69,103 +71,52 @@ pub struct RecentScore<'a> {
     grade: Option<GradeOption>,
     #[command(desc = "Specify whether only passes should be considered")]
     passes: Option<bool>,
+    #[command(
+        desc = "Specify whether fails count towards the index",
+        help = "Specify whether failed scores count towards the index.\n\
+        If disabled, e.g. `index:2` shows the second most recent pass.\n\
+        Overrides the `recent_fails` setting of `/config edit`."
+    )]
+    fails: Option<bool>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
 }
 
-#[derive(CommandModel, CreateCommand, HasMods, HasName)]
+#[derive(CommandModel, CreateCommand, HasName)]
 #[command(
     name = "best",
-    desc = "Display the user's current top200 sorted by date (same as `/rb`)"
+    desc = "Display a user's best play of the last days (same as `/rb`)",
+    help = "Display a user's highest pp play that was set within the last few days.\n\
+    If none of the top plays were set within that time, \
+    the recent plays of the last 24 hours are considered instead."
 )]
-pub struct RecentBest {
+pub struct RecentBest<'a> {
     #[command(desc = "Specify a gamemode")]
     mode: Option<GameModeOption>,
     #[command(desc = "Specify a username")]
-    name: Option<String>,
+    name: Option<Cow<'a, str>>,
     #[command(
-        desc = "Specify mods (`+mods` for included, `+mods!` for exact, `-mods!` for excluded)",
-        help = "Filter out all scores that don't match the specified mods.\n\
-        Mods must be given as `+mods` for included mods, `+mods!` for exact mods, \
-        or `-mods!` for excluded mods.\n\
-        Examples:\n\
-        - `+hd`: Scores must have at least `HD` but can also have more other mods\n\
-        - `+hdhr!`: Scores must have exactly `HDHR`\n\
-        - `-ezhd!`: Scores must have neither `EZ` nor `HD` e.g. `HDDT` would get filtered out\n\
-        - `-nm!`: Scores can not be nomod so there must be any other mod"
+        min_value = 1,
+        max_value = 90,
+        desc = "Consider plays of this many last days, defaults to 7"
     )]
-    mods: Option<String>,
+    days: Option<u8>,
     #[command(
-        desc = "Choose a specific score index or `random`",
-        help = "By default the command will show paginated embeds containing ten scores per page.\n\
-        However, if this index is specified, the command will only show the score at the given index.\n\
-        E.g. `index:1` will show the top score and \
-        `index:3` will show the score with the third highest pp amount.\n\
-        With `random` or `?` it'll choose a random index."
+        min_value = 1,
+        max_value = 100,
+        desc = "Choose the play with the n-th highest pp instead",
+        help = "By default the play with the highest pp within the time window is shown.\n\
+        E.g. `index:2` shows the second best play within that window instead."
     )]
-    index: Option<String>,
+    index: Option<u8>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
-    #[command(desc = "Reverse the resulting score list")]
-    reverse: Option<bool>,
-    #[command(
-        desc = "Specify a search query containing artist, difficulty, AR, BPM, ...",
-        help = "Filter out scores similarly as you filter maps in osu! itself.\n\
-        You can specify the artist, creator, difficulty, title, or limit values such as \
-        ar, cs, hp, od, bpm, length, or stars like for example `fdfd ar>10 od>=9`.\n\
-        While ar & co will be adjusted to mods, stars will not."
-    )]
-    query: Option<String>,
-    #[command(desc = "Consider only scores with this grade")]
-    grade: Option<GradeOption>,
-    #[command(desc = "Filter out all scores that don't have a perfect combo")]
-    perfect_combo: Option<bool>,
-    #[command(
-        desc = "Condense top plays",
-        help = "Size of the embed.\n\
-        `Condensed` shows 10 scores, `Detailed` shows 5, and `Single` shows 1.\n\
-        The default can be set with the `/config` command."
-    )]
-    size: Option<ListSize>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
 }
 
-impl TryFrom<RecentBest> for TopArgs<'_> {
-    type Error = &'static str;
-
-    fn try_from(args: RecentBest) -> Result<Self, Self::Error> {
-        let mods = match args.mods() {
-            ModsResult::Mods(mods) => Some(mods),
-            ModsResult::None => None,
-            ModsResult::Invalid => return Err(Self::ERR_PARSE_MODS),
-        };
-
-        Ok(Self {
-            name: args.name.map(Cow::Owned),
-            discord: args.discord,
-            mode: args.mode.map(GameMode::from),
-            mods,
-            min_acc: None,
-            max_acc: None,
-            min_combo: None,
-            max_combo: None,
-            grade: args.grade.map(Grade::from),
-            sort_by: TopScoreOrder::Date,
-            reverse: args.reverse.unwrap_or(false),
-            perfect_combo: args.perfect_combo,
-            index: args.index,
-            query: args.query,
-            size: args.size,
-            score_data: args.score_data,
-            has_dash_r: false,
-            has_dash_p_or_i: false,
-        })
-    }
-}
-
 #[derive(CommandModel, CreateCommand, HasMods, HasName)]
 #[command(
     name = "leaderboard",
@@ -298,90 +249,57 @@ impl From<RecentFixGameMode> for GameMode {
     }
 }
 
-#[allow(unused)] // fields are used through transmute in From impl
 #[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
-#[command(name = "rb", desc = "Display the user's current top200 sorted by date")]
-pub struct Rb {
+#[command(
+    name = "rb",
+    desc = "Display a user's best play of the last days",
+    help = "Display a user's highest pp play that was set within the last few days.\n\
+    If none of the top plays were set within that time, \
+    the recent plays of the last 24 hours are considered instead."
+)]
+pub struct Rb<'a> {
     #[command(desc = "Specify a gamemode")]
     mode: Option<GameModeOption>,
     #[command(desc = "Specify a username")]
-    name: Option<String>,
+    name: Option<Cow<'a, str>>,
     #[command(
-        desc = "Specify mods (`+mods` for included, `+mods!` for exact, `-mods!` for excluded)",
-        help = "Filter out all scores that don't match the specified mods.\n\
-        Mods must be given as `+mods` for included mods, `+mods!` for exact mods, \
-        or `-mods!` for excluded mods.\n\
-        Examples:\n\
-        - `+hd`: Scores must have at least `HD` but can also have more other mods\n\
-        - `+hdhr!`: Scores must have exactly `HDHR`\n\
-        - `-ezhd!`: Scores must have neither `EZ` nor `HD` e.g. `HDDT` would get filtered out\n\
-        - `-nm!`: Scores can not be nomod so there must be any other mod"
+        min_value = 1,
+        max_value = 90,
+        desc = "Consider plays of this many last days, defaults to 7"
     )]
-    mods: Option<String>,
+    days: Option<u8>,
     #[command(
-        desc = "Choose a specific score index or `random`",
-        help = "By default the command will show paginated embeds containing five scores per page.\n\
-        However, if this index is specified, the command will only show the score at the given index.\n\
-        E.g. `index:1` will show the top score and \
-        `index:3` will show the score with the third highest pp amount.\n\
-        With `random` or `?` it'll choose a random index."
+        min_value = 1,
+        max_value = 100,
+        desc = "Choose the play with the n-th highest pp instead",
+        help = "By default the play with the highest pp within the time window is shown.\n\
+        E.g. `index:2` shows the second best play within that window instead."
     )]
-    index: Option<String>,
+    index: Option<u8>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
-    #[command(desc = "Reverse the resulting score list")]
-    reverse: Option<bool>,
-    #[command(
-        desc = "Specify a search query containing artist, difficulty, AR, BPM, ...",
-        help = "Filter out scores similarly as you filter maps in osu! itself.\n\
-        You can specify the artist, creator, difficulty, title, or limit values such as \
-        ar, cs, hp, od, bpm, length, or stars like for example `fdfd ar>10 od>=9`.\n\
-        While ar & co will be adjusted to mods, stars will not."
-    )]
-    query: Option<String>,
-    #[command(desc = "Consider only scores with this grade")]
-    grade: Option<GradeOption>,
-    #[command(desc = "Filter out all scores that don't have a perfect combo")]
-    perfect_combo: Option<bool>,
-    #[command(
-        desc = "Condense top plays",
-        help = "Size of the embed.\n\
-        `Condensed` shows 10 scores, `Detailed` shows 5, and `Single` shows 1.\n\
-        The default can be set with the `/config` command."
-    )]
-    size: Option<ListSize>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
 }
 
-impl From<Rb> for RecentBest {
+impl<'a> From<Rb<'a>> for RecentBest<'a> {
     #[inline]
-    fn from(args: Rb) -> Self {
+    fn from(args: Rb<'a>) -> Self {
         let Rb {
             mode,
             name,
-            mods,
+            days,
             index,
             discord,
-            reverse,
-            query,
-            grade,
-            perfect_combo,
-            size,
             score_data,
         } = args;
 
         Self {
             mode,
             name,
-            mods,
+            days,
             index,
             discord,
-            reverse,
-            query,
-            grade,
-            perfect_combo,
-            size,
             score_data,
         }
     }
@@ -390,14 +308,7 @@ impl From<Rb> for RecentBest {
 async fn slash_recent(mut command: InteractionCommand) -> Result<()> {
     match Recent::from_interaction(command.input_data())? {
         Recent::Score(args) => score((&mut command).into(), args).await,
-        Recent::Best(args) => match TopArgs::try_from(args) {
-            Ok(args) => top((&mut command).into(), args).await,
-            Err(content) => {
-                command.error(content).await?;
-
-                Ok(())
-            }
-        },
+        Recent::Best(args) => best((&mut command).into(), args).await,
         Recent::Leaderboard(args) => leaderboard((&mut command).into(), args).await,
         Recent::List(args) => list((&mut command).into(), args).await,
         Recent::Fix(args) => fix((&mut command).into(), args).await,
@@ -407,12 +318,5 @@ async fn slash_recent(mut command: InteractionCommand) -> Result<()> {
 async fn slash_rb(mut command: InteractionCommand) -> Result<()> {
     let args = Rb::from_interaction(command.input_data())?;
 
-    match TopArgs::try_from(RecentBest::from(args)) {
-        Ok(args) => top((&mut command).into(), args).await,
-        Err(content) => {
-            command.error(content).await?;
-
-            Ok(())
-        }
-    }
+    best((&mut command).into(), args.into()).await
 }
@@ -5,8 +5,8 @@ use bathbot_model::{
     command_fields::{GameModeOption, GradeOption},
     embed_builder::SettingsImage,
 };
-use bathbot_psql::model::configs::{GuildConfig, Retries, ScoreData};
-use bathbot_util::{CowUtils, MessageOrigin, constants::GENERAL_ISSUE, matcher};
+use bathbot_psql::model::configs::{GuildConfig, Retries, ScoreData, resolve};
+use bathbot_util::{CowUtils, MessageOrigin, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use rand::{Rng, thread_rng};
 use rosu_v2::{
@@ -24,11 +24,11 @@ use crate::{
     Context,
     active::{
         ActiveMessages,
-        impls::{SingleScoreContent, SingleScorePagination},
+        impls::{ScoreSkin, SingleScoreContent, SingleScorePagination},
     },
     commands::{
         DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
-        osu::{map_strains_graph, require_link, user_not_found},
+        osu::{Theme, map_strains_graph, require_link, user_not_found},
         utility::{MissAnalyzerCheck, SCORE_DATA_DESC, SCORE_DATA_HELP, ScoreEmbedDataWrap},
     },
     core::commands::{CommandOrigin, interaction::InteractionCommands, prefix::Args},
@@ -274,12 +274,16 @@ impl<'m> RecentScore<'m> {
         let mut passes = None;
         let num = args.num;
 
-        for arg in args.take(3).map(|arg| arg.cow_to_ascii_lowercase()) {
-            if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
-                let key = &arg[..idx];
-                let value = arg[idx + 1..].trim_end();
+        for arg in args.into_args().take(3) {
+            let key_value = arg.key_value();
+            let arg = arg.into_lowercase();
 
-                match key {
+            if let Some((key, value)) = key_value {
+                let key = key.cow_to_ascii_lowercase();
+                let value = value.cow_to_ascii_lowercase();
+                let value = value.trim_end();
+
+                match key.as_ref() {
                     "pass" | "p" | "passes" => match value {
                         "true" | "t" | "1" => passes = Some(true),
                         "false" | "f" | "0" => passes = Some(false),
@@ -330,6 +334,7 @@ impl<'m> RecentScore<'m> {
             index: num.to_string_opt().map(Cow::Owned),
             grade,
             passes,
+            fails: None,
             discord,
             score_data: None,
         })
@@ -347,7 +352,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
     let config = match user_config_res {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to get user config"));
         }
@@ -359,11 +364,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         score_data: guild_score_data,
     } = guild_values;
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
+    let mode = resolve::mode(args.mode.map(GameMode::from), config.mode);
 
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
@@ -376,6 +377,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
     let RecentScore {
         grade,
         passes,
+        fails,
         index,
         score_data,
         ..
@@ -389,14 +391,12 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
     let include_fails = match (grade, passes) {
         (Some(Grade::F), Some(true)) => return orig.error(":clown:").await,
         (_, Some(passes)) => !passes,
-        (Some(Grade::F), _) | (None, None) => true,
+        (Some(Grade::F), _) => true,
+        (None, None) => fails.or(config.recent_includes_fails).unwrap_or(true),
         _ => false,
     };
 
-    let score_data = score_data
-        .or(config.score_data)
-        .or(guild_score_data)
-        .unwrap_or_default();
+    let score_data = resolve::score_data(score_data, config.score_data, guild_score_data);
 
     let legacy_scores = score_data.is_legacy();
 
@@ -435,7 +435,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user or scores");
 
             return Err(err);
@@ -462,11 +462,20 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         scores.retain(|score| passed == score.passed);
     }
 
+    let passed: Vec<_> = scores.iter().map(|score| score.passed).collect();
+
     let num = match index.as_deref() {
-        Some("random" | "?") => match scores.is_empty() {
-            false => thread_rng().gen_range(0..scores.len()),
-            true => 0,
-        },
+        Some("random" | "?") => {
+            let qualifying = passed
+                .iter()
+                .filter(|&&passed| include_fails || passed)
+                .count();
+
+            match qualifying {
+                0 => 0,
+                _ => thread_rng().gen_range(0..qualifying),
+            }
+        }
         Some(n) => match n.parse::<usize>() {
             Ok(n) => n.saturating_sub(1),
             Err(_) => {
@@ -479,23 +488,27 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         None => 0,
     };
 
-    let retries = config
-        .retries
-        .or(guild_retries)
-        .unwrap_or(Retries::ConsiderMods);
+    let num = match recent_index(&passed, include_fails, num) {
+        Ok(num) => num,
+        Err(len) => {
+            let username = user.username.as_str();
+
+            let content = format!(
+                "There {verb} only {len} {kind}score{plural} in `{username}`'{genitive} recent history.",
+                verb = if len != 1 { "are" } else { "is" },
+                kind = if include_fails { "" } else { "passed " },
+                plural = if len != 1 { "s" } else { "" },
+                genitive = if username.ends_with('s') { "" } else { "s" }
+            );
 
-    let Some([score, prev_scores @ ..]) = scores.get(num..) else {
-        let len = scores.len();
-        let username = user.username.as_str();
+            return orig.error(content).await;
+        }
+    };
 
-        let content = format!(
-            "There {verb} only {len} score{plural} in `{username}`'{genitive} recent history.",
-            verb = if len != 1 { "are" } else { "is" },
-            plural = if len != 1 { "s" } else { "" },
-            genitive = if username.ends_with('s') { "" } else { "s" }
-        );
+    let retries = resolve::retries(config.retries, guild_retries);
 
-        return orig.error(content).await;
+    let [score, prev_scores @ ..] = &scores[num..] else {
+        unreachable!("index was resolved to an existing score");
     };
 
     let map_id = score.map_id;
@@ -566,11 +579,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         .as_ref()
         .is_some_and(Context::has_miss_analyzer);
 
-    let mut with_render = match (guild_render_button, config.render_button) {
-        (None | Some(true), None) => true,
-        (None | Some(true), Some(with_render)) => with_render,
-        (Some(false), _) => false,
-    };
+    let mut with_render = resolve::render_button(config.render_button, guild_render_button);
 
     let (settings, missing_settings) = match config.score_embed {
         Some(settings) => (settings, false),
@@ -665,10 +674,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         if let Some(notices) = add_content_notices {
             debug!(user = %author, notices, "Adding builder notice");
 
-            let builder = InteractionCommands::get_command("builder").map_or_else(
-                || "`/builder edit`".to_owned(),
-                |cmd| cmd.mention("builder edit").to_string(),
-            );
+            let builder = InteractionCommands::mention("builder edit");
 
             let mut new_content =
                 format!("✨ NEW: You can now use {builder} to customize your score format! ✨");
@@ -697,6 +703,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
                         entry.map.cover(),
                         SingleScorePagination::IMAGE_W,
                         SingleScorePagination::IMAGE_H,
+                        Theme::get(config.graph_theme),
                     );
 
                     match fut.await {
@@ -722,14 +729,34 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         SingleScorePagination::new(&user, entries, settings, score_data, author, content);
 
     pagination.set_index(num);
+    pagination.set_timestamp_style(config.timestamp_style);
+    pagination.set_skin(ScoreSkin::from_config(&config));
 
     ActiveMessages::builder(pagination)
         .start_by_update(true)
         .attachment(graph)
+        .plain_fallback(true)
         .begin(orig)
         .await
 }
 
+/// Resolve the requested index of a recent score.
+///
+/// If fails are not included, only passes count towards the index. Returns
+/// the index within all scores, or the amount of scores that could have been
+/// chosen if the index is too large.
+fn recent_index(passed: &[bool], include_fails: bool, index: usize) -> Result<usize, usize> {
+    let qualifies = |passed: &bool| include_fails || *passed;
+
+    passed
+        .iter()
+        .enumerate()
+        .filter(|(_, passed)| qualifies(passed))
+        .nth(index)
+        .map(|(idx, _)| idx)
+        .ok_or_else(|| passed.iter().filter(|passed| qualifies(passed)).count())
+}
+
 fn process_scores(
     scores: Vec<Score>,
     top100: Option<Vec<Score>>,
@@ -798,7 +825,8 @@ async fn twitch_data(user_id: u64) -> Option<crate::commands::utility::TwitchDat
     name = "rs",
     desc = "Show a user's recent score",
     help = "Show a user's recent score.\n\
-    To add a timestamp to a twitch VOD, be sure you linked yourself to a twitch account via `/config`."
+    To add a timestamp to a twitch VOD, be sure you linked yourself to a twitch account \
+    via `/config edit`."
 )]
 pub struct Rs<'a> {
     #[command(desc = "Specify a gamemode")]
@@ -817,6 +845,13 @@ pub struct Rs<'a> {
     grade: Option<GradeOption>,
     #[command(desc = "Specify whether only passes should be considered")]
     passes: Option<bool>,
+    #[command(
+        desc = "Specify whether fails count towards the index",
+        help = "Specify whether failed scores count towards the index.\n\
+        If disabled, e.g. `index:2` shows the second most recent pass.\n\
+        Overrides the `recent_fails` setting of `/config edit`."
+    )]
+    fails: Option<bool>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
@@ -832,6 +867,7 @@ impl<'a> From<Rs<'a>> for RecentScore<'a> {
             index,
             grade,
             passes,
+            fails,
             discord,
             score_data,
         } = args;
@@ -842,6 +878,7 @@ impl<'a> From<Rs<'a>> for RecentScore<'a> {
             index,
             grade,
             passes,
+            fails,
             discord,
             score_data,
         }
@@ -881,3 +918,28 @@ async fn get_guild_values(orig: &CommandOrigin<'_>) -> GuildValues {
         None => GuildValues::default(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCORES: [bool; 6] = [false, true, false, false, true, true];
+
+    #[test]
+    fn index_with_fails() {
+        assert_eq!(recent_index(&SCORES, true, 0), Ok(0));
+        assert_eq!(recent_index(&SCORES, true, 3), Ok(3));
+        assert_eq!(recent_index(&SCORES, true, 5), Ok(5));
+        assert_eq!(recent_index(&SCORES, true, 6), Err(6));
+    }
+
+    #[test]
+    fn index_without_fails() {
+        assert_eq!(recent_index(&SCORES, false, 0), Ok(1));
+        assert_eq!(recent_index(&SCORES, false, 1), Ok(4));
+        assert_eq!(recent_index(&SCORES, false, 2), Ok(5));
+        assert_eq!(recent_index(&SCORES, false, 3), Err(3));
+        assert_eq!(recent_index(&[false, false], false, 0), Err(0));
+        assert_eq!(recent_index(&[], true, 0), Err(0));
+    }
+}
@@ -0,0 +1,130 @@
+use std::borrow::Cow;
+
+use bathbot_macros::SlashCommand;
+use bathbot_model::{Countries, command_fields::GameModeOption};
+use bathbot_util::{
+    AuthorBuilder, EmbedBuilder, FooterBuilder, MessageBuilder, constants::OSU_BASE,
+    i18n::ErrorKey, numbers::WithComma, osu::flag_url,
+};
+use eyre::Result;
+use rosu_v2::prelude::GameMode;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    core::Context,
+    tracking::OsuTracking,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "record",
+    desc = "Display the highest pp play of a country that the bot has seen",
+    help = "Display the highest pp play of a country that the bot has seen.\n\
+    Records are only updated through osu!tracking so they only reflect scores \
+    of tracked users and may very well be beaten by untracked players."
+)]
+pub struct Record<'a> {
+    #[command(desc = "Specify a country (code)")]
+    country: Cow<'a, str>,
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+}
+
+async fn slash_record(mut command: InteractionCommand) -> Result<()> {
+    let args = Record::from_interaction(command.input_data())?;
+
+    let Some(country) = Countries::resolve(&args.country) else {
+        let content = format!(
+            "Looks like `{}` is neither a country name nor a country code",
+            args.country
+        );
+
+        return command.error(content).await;
+    };
+
+    let mode = match args.mode.map(GameMode::from) {
+        Some(mode) => mode,
+        None => Context::user_config()
+            .mode(command.user_id()?)
+            .await?
+            .unwrap_or(GameMode::Osu),
+    };
+
+    let country_code = country.country_code();
+
+    let record = match OsuTracking::country_record(country_code, mode).await {
+        Ok(record) => record,
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let mode_str = match mode {
+        GameMode::Osu => "osu!",
+        GameMode::Taiko => "taiko",
+        GameMode::Catch => "catch",
+        GameMode::Mania => "mania",
+    };
+
+    let country_name = country.country_name();
+    let note = country.note();
+
+    let Some(record) = record else {
+        let mut content =
+            format!("The bot has not seen any {mode_str} top play from {country_name} yet");
+
+        if let Some(note) = note {
+            content.push('\n');
+            content.push_str(&note);
+        }
+
+        let builder = MessageBuilder::new().embed(content);
+        command.update(builder).await?;
+
+        return Ok(());
+    };
+
+    let username = match Context::osu_user().name(record.user_id).await {
+        Ok(Some(name)) => name.to_string(),
+        Ok(None) => format!("User {}", record.user_id),
+        Err(err) => {
+            warn!(?err, "Failed to get username");
+
+            format!("User {}", record.user_id)
+        }
+    };
+
+    let author = AuthorBuilder::new(format!(
+        "Best {mode_str} play from {country_name} seen by the bot"
+    ))
+    .icon_url(flag_url(country_code));
+
+    let description = format!(
+        "**{pp}pp** by [{username}]({OSU_BASE}u/{user_id})\n\
+        [Go to score]({OSU_BASE}scores/{score_id})",
+        pp = WithComma::new(record.pp),
+        user_id = record.user_id,
+        score_id = record.score_id,
+    );
+
+    let footer = match note {
+        Some(note) => format!("{note} • Only reflects scores of tracked users"),
+        None => "Only reflects scores of tracked users".to_owned(),
+    };
+
+    let footer = FooterBuilder::new(footer);
+
+    let embed = EmbedBuilder::new()
+        .author(author)
+        .description(description)
+        .footer(footer)
+        .timestamp(record.updated_at);
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
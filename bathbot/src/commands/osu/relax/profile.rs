@@ -4,9 +4,7 @@ use bathbot_macros::command;
 use bathbot_model::RelaxPlayersDataResponse;
 use bathbot_util::{
     EmbedBuilder, FooterBuilder, MessageBuilder, MessageOrigin, attachment,
-    constants::{GENERAL_ISSUE, RELAX_ICON_URL},
-    datetime::NAIVE_DATETIME_FORMAT,
-    fields, matcher,
+    constants::RELAX_ICON_URL, datetime::NAIVE_DATETIME_FORMAT, fields, i18n::ErrorKey, matcher,
     numbers::WithComma,
 };
 use eyre::{Context as _, ContextCompat, Report, Result};
@@ -15,7 +13,7 @@ use plotters::{
     coord::{Shift, types::RangedCoordi32},
     prelude::{Cartesian2d, Circle, DrawingArea, IntoDrawingArea, PathElement},
     series::AreaSeries,
-    style::{BLACK, Color, RGBColor, WHITE},
+    style::{Color, RGBColor},
 };
 use plotters_backend::FontStyle;
 use plotters_skia::SkiaBackend;
@@ -34,6 +32,7 @@ use twilight_model::{
 
 use crate::{
     commands::osu::{
+        Theme,
         relax::{RX_PROFILE_DESC, RX_PROFILE_HELP, RelaxProfile, relax_author_builder},
         require_link,
     },
@@ -85,7 +84,7 @@ pub(super) async fn relax_profile(orig: CommandOrigin<'_>, args: RelaxProfile<'_
     let config = match Context::user_config().with_osu_id(msg_owner).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -112,7 +111,7 @@ pub(super) async fn relax_profile(orig: CommandOrigin<'_>, args: RelaxProfile<'_
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -153,7 +152,7 @@ pub(super) async fn relax_profile(orig: CommandOrigin<'_>, args: RelaxProfile<'_
         Err(err) => {
             warn!(?err, "Failed to fetch user relax player info");
 
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -161,11 +160,12 @@ pub(super) async fn relax_profile(orig: CommandOrigin<'_>, args: RelaxProfile<'_
 
     let origin = MessageOrigin::new(orig.guild_id(), orig.channel_id());
     let pagination = RelaxProfileArgs::new(user, discord_id, info_res, origin);
+    let theme = Theme::get(config.graph_theme);
 
-    let graph = match relax_playcount_graph(&pagination) {
+    let graph = match relax_playcount_graph(&pagination, theme) {
         Ok(graph) => graph,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to draw a relax playcount graph"));
         }
@@ -256,10 +256,10 @@ fn relax_footer_builder(args: &RelaxProfileArgs) -> FooterBuilder {
 // use that or something
 const W: u32 = 590;
 const H: u32 = 170;
-fn relax_playcount_graph(args: &RelaxProfileArgs) -> Result<Vec<u8>> {
+fn relax_playcount_graph(args: &RelaxProfileArgs, theme: &Theme) -> Result<Vec<u8>> {
     let mut surface =
         surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
-    let root = create_root(&mut surface, W, H)?;
+    let root = create_root(&mut surface, W, H, theme)?;
     let playcounts: Vec<MonthlyCount> = args
         .info
         .playcounts_per_month
@@ -269,7 +269,7 @@ fn relax_playcount_graph(args: &RelaxProfileArgs) -> Result<Vec<u8>> {
             count: playcount.playcount as i32,
         })
         .collect();
-    draw_playcounts(&playcounts, &root)?;
+    draw_playcounts(&playcounts, &root, theme)?;
     let canvas: Vec<u8> = surface
         .image_snapshot()
         .encode(None, EncodedImageFormat::PNG, None)
@@ -277,21 +277,19 @@ fn relax_playcount_graph(args: &RelaxProfileArgs) -> Result<Vec<u8>> {
         .to_vec();
     Ok(canvas)
 }
-const PLAYCOUNTS_AREA_COLOR: RGBColor = RGBColor(0, 116, 193);
-const PLAYCOUNTS_BORDER_COLOR: RGBColor = RGBColor(102, 174, 222);
 type Area<'b> = DrawingArea<SkiaBackend<'b>, Shift>;
 type Chart<'a, 'b> = ChartContext<'a, SkiaBackend<'b>, Cartesian2d<Monthly<Date>, RangedCoordi32>>;
 
-fn create_root(surface: &mut Surface, w: u32, h: u32) -> Result<Area<'_>> {
+fn create_root<'a>(surface: &'a mut Surface, w: u32, h: u32, theme: &Theme) -> Result<Area<'a>> {
     let root = SkiaBackend::new(surface.canvas(), w, h).into_drawing_area();
 
-    let background = RGBColor(19, 43, 33);
+    let background = theme.background;
     root.fill(&background)
         .wrap_err("Failed to fill background")?;
 
     Ok(root)
 }
-fn draw_playcounts(playcounts: &[MonthlyCount], canvas: &Area<'_>) -> Result<()> {
+fn draw_playcounts(playcounts: &[MonthlyCount], canvas: &Area<'_>, theme: &Theme) -> Result<()> {
     let (first, last, max) = first_last_max(playcounts);
 
     let mut chart = ChartBuilder::on(canvas)
@@ -303,23 +301,23 @@ fn draw_playcounts(playcounts: &[MonthlyCount], canvas: &Area<'_>) -> Result<()>
 
     chart
         .configure_mesh()
-        .light_line_style(BLACK.mix(0.0))
+        .light_line_style(theme.grid.mix(0.0))
         .disable_x_mesh()
         .x_labels(10)
         .x_label_formatter(&|d| format!("{}-{}", d.year(), d.month() as u8))
         .y_desc("Monthly playcount")
-        .label_style(("sans-serif", 14_i32, &WHITE))
-        .bold_line_style(WHITE.mix(0.3))
-        .axis_style(RGBColor(7, 18, 14))
-        .axis_desc_style(("sans-serif", 14_i32, FontStyle::Bold, &WHITE))
+        .label_style(("sans-serif", 14_i32, &theme.text))
+        .bold_line_style(theme.grid.mix(0.3))
+        .axis_style(theme.axis)
+        .axis_desc_style(("sans-serif", 14_i32, FontStyle::Bold, &theme.text))
         .draw()
         .wrap_err("Failed to draw playcounts mesh")?;
 
     draw_area(
         &mut chart,
-        PLAYCOUNTS_AREA_COLOR,
+        theme.series[0],
         0.5,
-        PLAYCOUNTS_BORDER_COLOR,
+        theme.series[0],
         0.6,
         playcounts,
         "Monthly playcount",
@@ -1,7 +1,7 @@
 use std::{cmp::Ordering, collections::HashMap};
 
 use bathbot_macros::command;
-use bathbot_util::{constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use rosu_v2::{error::OsuError, model::GameMode, request::UserId};
 use twilight_model::guild::Permissions;
@@ -71,7 +71,7 @@ async fn top(orig: CommandOrigin<'_>, args: RelaxTop<'_>) -> Result<()> {
     let mut config = match Context::user_config().with_osu_id(msg_owner).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -98,7 +98,7 @@ async fn top(orig: CommandOrigin<'_>, args: RelaxTop<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -1,13 +1,13 @@
 use std::{
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Display, Formatter, Result as FmtResult, Write},
     time::{Duration, Instant},
 };
 
 use bathbot_macros::SlashCommand;
+use bathbot_psql::model::render::DbOngoingRender;
 use bathbot_util::{
-    Authored, BucketName, EmbedBuilder, MessageBuilder,
-    constants::{GENERAL_ISSUE, ORDR_ISSUE, OSU_API_ISSUE},
-    matcher,
+    Authored, BucketName, EmbedBuilder, MessageBuilder, constants::ORDR_ISSUE, i18n::ErrorKey,
+    matcher, tr,
 };
 use eyre::{Report, Result, WrapErr};
 use rosu_render::{
@@ -15,6 +15,9 @@ use rosu_render::{
     model::RenderDone,
 };
 use rosu_v2::error::OsuError;
+use time::OffsetDateTime;
+use tokio::time::{Interval, MissedTickBehavior, interval_at};
+use tracing::Instrument;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::{
     channel::{Attachment, Message},
@@ -30,7 +33,7 @@ use crate::{
         ActiveMessages,
         impls::{CachedRender, RenderSettingsActive, SettingsImport},
     },
-    core::{Context, commands::OwnedCommandOrigin},
+    core::{Context, Service, commands::OwnedCommandOrigin},
     manager::{ReplayError, ReplaySettings},
     tracking::OrdrReceivers,
     util::{InteractionCommandExt, MessageExt, interaction::InteractionCommand},
@@ -119,6 +122,14 @@ pub async fn slash_render(mut command: InteractionCommand) -> Result<()> {
         return Ok(());
     };
 
+    let notice_fut = Context::feature_flags().disabled_notice(Service::Ordr);
+
+    if let Some(content) = notice_fut.await {
+        command.error_callback(content).await?;
+
+        return Ok(());
+    }
+
     match Render::from_interaction(command.input_data())? {
         Render::Replay(args) => render_replay(command, args).await,
         Render::Score(args) => render_score(command, args).await,
@@ -137,7 +148,8 @@ async fn render_replay(command: InteractionCommand, replay: RenderReplay) -> Res
     if let Some(cooldown) = Context::check_ratelimit(owner, BucketName::Render) {
         trace!("Ratelimiting user {owner} on bucket `Render` for {cooldown} seconds");
 
-        let content = format!("Command on cooldown, try again in {cooldown} seconds");
+        let lang = Context::guild_config().lang(command.guild_id).await;
+        let content = tr!("cooldown", lang, seconds = cooldown);
         command.error_callback(content).await?;
 
         return Ok(());
@@ -158,7 +170,7 @@ async fn render_replay(command: InteractionCommand, replay: RenderReplay) -> Res
     let settings = match Context::replay().get_settings(owner).await {
         Ok(settings) => settings,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -238,7 +250,7 @@ async fn render_replay(command: InteractionCommand, replay: RenderReplay) -> Res
     )
     .await;
 
-    tokio::spawn(ongoing.await_render_url());
+    tokio::spawn(ongoing.await_render_url().in_current_span());
 
     Ok(())
 }
@@ -280,7 +292,8 @@ async fn render_score(mut command: InteractionCommand, score: RenderScore) -> Re
     if let Some(cooldown) = Context::check_ratelimit(owner, BucketName::Render) {
         trace!("Ratelimiting user {owner} on bucket `Render` for {cooldown} seconds");
 
-        let content = format!("Command on cooldown, try again in {cooldown} seconds");
+        let lang = Context::guild_config().lang(command.guild_id).await;
+        let content = tr!("cooldown", lang, seconds = cooldown);
         command.error(content).await?;
 
         return Ok(());
@@ -314,12 +327,12 @@ async fn render_score(mut command: InteractionCommand, score: RenderScore) -> Re
             return Ok(());
         }
         Err(ReplayError::Osu(err)) => {
-            let _ = command.error(OSU_API_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("Failed to get replay"));
         }
         Err(ReplayError::AlreadyRequestedCheck(err)) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err(ReplayError::ALREADY_REQUESTED_TEXT));
         }
@@ -328,7 +341,7 @@ async fn render_score(mut command: InteractionCommand, score: RenderScore) -> Re
     let settings = match settings_res {
         Ok(settings) => settings,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -399,7 +412,7 @@ async fn render_score(mut command: InteractionCommand, score: RenderScore) -> Re
         owner,
     );
 
-    tokio::spawn(ongoing_fut.await.await_render_url());
+    tokio::spawn(ongoing_fut.await.await_render_url().in_current_span());
 
     Ok(())
 }
@@ -589,8 +602,33 @@ impl OngoingRender {
         score_id: Option<u64>,
         msg_owner: Id<UserMarker>,
     ) -> Self {
+        let orig = orig.into();
+
+        // The message that the video url will reply to
+        let target = match (&orig, &response) {
+            (OwnedCommandOrigin::Message { msg, channel, .. }, _) => Some((*msg, *channel)),
+            (OwnedCommandOrigin::Interaction { .. }, Some(response)) => Some(response.get()),
+            (OwnedCommandOrigin::Interaction { .. }, None) => None,
+        };
+
+        // Persist the render so that it can be recovered after a restart
+        if let Some((message_id, channel_id)) = target {
+            let render = DbOngoingRender {
+                render_id,
+                score_id,
+                channel_id,
+                message_id,
+                requester_id: msg_owner,
+                created_at: OffsetDateTime::now_utc(),
+            };
+
+            if let Err(err) = Context::psql().insert_ongoing_render(&render).await {
+                warn!(?err, render_id, "Failed to store ongoing render");
+            }
+        }
+
         Self {
-            orig: orig.into(),
+            orig,
             response,
             render_id,
             receivers: Context::ordr().subscribe_render_id(render_id).await,
@@ -602,39 +640,46 @@ impl OngoingRender {
 
     pub async fn await_render_url(mut self) {
         const TIMEOUT_DURATION: Duration = Duration::from_secs(60 * 60 * 24);
-        const INTERVAL: Duration = Duration::from_secs(5);
+        const MIN_POLL_INTERVAL: Duration = Duration::from_secs(10);
+        const MAX_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+        let timeout = tokio::time::sleep(TIMEOUT_DURATION);
+        tokio::pin!(timeout);
 
-        let mut last_update = Instant::now();
+        let mut progress = RenderProgressState::default();
+        let mut poll_period = MIN_POLL_INTERVAL;
+        let mut poll = poll_interval(poll_period);
 
         loop {
             tokio::select! {
-                progress = self.receivers.progress.recv() => {
-                    let now = Instant::now();
-
-                    debug!("Got progress: {progress:?}");
-
-                    if last_update + INTERVAL > now {
-                        continue;
-                    }
+                received = self.receivers.progress.recv() => {
+                    debug!("Got progress: {received:?}");
 
-                    last_update = now;
-
-                    let Some(progress) = progress else {
+                    let Some(received) = received else {
                         return warn!("progress channel was closed");
                     };
 
-                    self.status.set(RenderStatusInner::Rendering(progress.progress));
-                    let builder = self.status.as_message();
+                    if let Some(content) = self.handle_progress(&mut progress, &received.progress).await {
+                        return self.fail(content).await;
+                    }
+                },
+                _ = poll.tick() => {
+                    let list_fut = Context::ordr().client().render_list().render_id(self.render_id);
 
-                    if let Some(ref response) = self.response {
-                        let perms = response.permissions;
+                    match list_fut.await {
+                        Ok(list) => {
+                            set_poll_period(&mut poll, &mut poll_period, MIN_POLL_INTERVAL);
 
-                        if let Some(update_fut) = response.get().update(builder, perms) {
-                            if let Err(err) = update_fut.await {
-                                warn!(?err, "Failed to update message");
+                            let Some(render) = list.renders.first() else { continue };
+
+                            if let Some(content) = self.handle_progress(&mut progress, &render.progress).await {
+                                return self.fail(content).await;
                             }
-                        } else {
-                            warn!("Lacking permissions to update message");
+                        }
+                        Err(err) => {
+                            let backoff = (poll_period * 2).min(MAX_POLL_INTERVAL);
+                            set_poll_period(&mut poll, &mut poll_period, backoff);
+                            debug!(?err, "Failed to poll render status");
                         }
                     }
                 },
@@ -683,6 +728,7 @@ impl OngoingRender {
                     }
 
                     Context::ordr().unsubscribe_render_id(render_id).await;
+                    remove_ongoing_render(render_id).await;
 
                     return;
                 },
@@ -693,71 +739,355 @@ impl OngoingRender {
 
                     warn!(?failed, "Received error from o!rdr");
 
-                    if let Err(err) = self.orig.reply_error(failed.error_message).await {
+                    return self.fail(failed.error_message).await;
+                },
+                _ = &mut timeout => {
+                    let content = "Timeout while waiting for o!rdr updates, \
+                        there was probably a network issue.";
+
+                    return self.fail(content).await;
+                },
+            }
+        }
+    }
+
+    /// Update the status message with the received progress unless it was
+    /// updated recently.
+    ///
+    /// Returns o!rdr's error text if the progress shows that the render failed.
+    async fn handle_progress(
+        &mut self,
+        state: &mut RenderProgressState,
+        progress: &str,
+    ) -> Option<String> {
+        const UPDATE_INTERVAL: Duration = Duration::from_secs(10);
+
+        let now = Instant::now();
+
+        let phase = match RenderPhase::parse(progress) {
+            RenderPhase::Failed(error) => return Some(error),
+            // The done event will update the message
+            RenderPhase::Done => return None,
+            phase => phase,
+        };
+
+        if let RenderPhase::Queued(Some(position)) = phase {
+            state.eta.observe(now, position);
+        }
+
+        if state
+            .last_update
+            .is_some_and(|last_update| last_update + UPDATE_INTERVAL > now)
+        {
+            return None;
+        }
+
+        state.last_update = Some(now);
+
+        let text = phase.describe(&state.eta, progress);
+        self.status.set(RenderStatusInner::Rendering(text.into()));
+        let builder = self.status.as_message();
+
+        if let Some(ref response) = self.response {
+            let perms = response.permissions;
+
+            if let Some(update_fut) = response.get().update(builder, perms) {
+                if let Err(err) = update_fut.await {
+                    warn!(?err, "Failed to update message");
+                }
+            } else {
+                warn!("Lacking permissions to update message");
+            }
+        }
+
+        None
+    }
+
+    /// Reply with the error and stop waiting for the render.
+    async fn fail(self, content: impl Into<String>) {
+        if let Err(err) = self.orig.reply_error(content).await {
+            warn!(?err, "Failed to update message");
+        } else if let Some(ref response) = self.response {
+            if response.delete {
+                if let Err(err) = response.get().delete().await {
+                    warn!(?err, "Failed to delete response");
+                }
+            } else {
+                let embed = EmbedBuilder::new().color_red().description("Render failed");
+                let builder = MessageBuilder::new().embed(embed);
+                let perms = response.permissions;
+
+                if let Some(update_fut) = response.get().update(builder, perms) {
+                    if let Err(err) = update_fut.await {
                         warn!(?err, "Failed to update message");
-                    } else if let Some(ref response) = self.response {
-                        if response.delete {
-                            if let Err(err) = response.get().delete().await {
-                                warn!(?err, "Failed to delete response");
-                            }
-                        } else {
-                            let embed = EmbedBuilder::new()
-                                .color_red()
-                                .description("Render failed");
-                            let builder = MessageBuilder::new().embed(embed);
-                            let perms = response.permissions;
+                    }
+                } else {
+                    warn!("Lacking permissions to update message");
+                }
+            }
+        }
 
-                            if let Some(update_fut) = response.get().update(builder, perms) {
-                                if let Err(err) = update_fut.await {
-                                    warn!(?err, "Failed to update message");
-                                }
-                            } else {
-                                warn!("Lacking permissions to update message");
-                            }
+        Context::ordr().unsubscribe_render_id(self.render_id).await;
+        remove_ongoing_render(self.render_id).await;
+    }
+}
+
+async fn remove_ongoing_render(render_id: u32) {
+    if let Err(err) = Context::psql().delete_ongoing_render(render_id).await {
+        warn!(?err, render_id, "Failed to remove ongoing render");
+    }
+}
+
+/// Renders that are older than this are not recovered anymore.
+const RECOVERY_MAX_AGE: Duration = Duration::from_secs(2 * 60 * 60);
+
+/// Deliver the videos of renders that were still ongoing during the last
+/// shutdown.
+///
+/// The interactions of those renders expired in the meanwhile so the video url
+/// is sent as a new message instead.
+pub async fn recover_ongoing_renders() {
+    if !Context::ordr_available() {
+        return;
+    }
+
+    let renders = match Context::psql().select_ongoing_renders().await {
+        Ok(renders) => renders,
+        Err(err) => return warn!(?err, "Failed to get ongoing renders"),
+    };
+
+    let now = OffsetDateTime::now_utc();
+
+    for render in renders {
+        match remaining_recovery_time(render.created_at, now) {
+            Some(remaining) => {
+                debug!(render_id = render.render_id, "Recovering ongoing render");
+
+                tokio::spawn(recover_render(render, remaining));
+            }
+            None => {
+                debug!(render_id = render.render_id, "Discarding outdated render");
+
+                remove_ongoing_render(render.render_id).await;
+            }
+        }
+    }
+}
+
+/// Ticks every `period`, starting one period from now.
+fn poll_interval(period: Duration) -> Interval {
+    let mut interval = interval_at(tokio::time::Instant::now() + period, period);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    interval
+}
+
+/// Restarts the interval if the period changed.
+fn set_poll_period(poll: &mut Interval, poll_period: &mut Duration, period: Duration) {
+    if *poll_period != period {
+        *poll_period = period;
+        *poll = poll_interval(period);
+    }
+}
+
+/// Returns `None` if the render is too old to be recovered.
+fn remaining_recovery_time(created_at: OffsetDateTime, now: OffsetDateTime) -> Option<Duration> {
+    let age = Duration::try_from(now - created_at).unwrap_or_default();
+
+    RECOVERY_MAX_AGE
+        .checked_sub(age)
+        .filter(|remaining| !remaining.is_zero())
+}
+
+async fn recover_render(render: DbOngoingRender, remaining: Duration) {
+    const MIN_POLL_INTERVAL: Duration = Duration::from_secs(10);
+    const MAX_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+    let render_id = render.render_id;
+    let deadline = Instant::now() + remaining;
+    let mut poll_period = MIN_POLL_INTERVAL;
+    let mut poll = poll_interval(poll_period);
+
+    loop {
+        match Context::ordr()
+            .client()
+            .render_list()
+            .render_id(render_id)
+            .await
+        {
+            Ok(list) => {
+                set_poll_period(&mut poll, &mut poll_period, MIN_POLL_INTERVAL);
+
+                if let Some(entry) = list.renders.first() {
+                    match RenderPhase::parse(&entry.progress) {
+                        RenderPhase::Done if !entry.video_url.is_empty() => {
+                            deliver_recovered_render(&render, &entry.video_url).await;
+
+                            break;
+                        }
+                        RenderPhase::Failed(error) => {
+                            debug!(render_id, %error, "Recovered render failed");
+
+                            break;
                         }
+                        _ => {}
                     }
+                }
+            }
+            Err(err) => {
+                let backoff = (poll_period * 2).min(MAX_POLL_INTERVAL);
+                set_poll_period(&mut poll, &mut poll_period, backoff);
+                debug!(?err, render_id, "Failed to poll render status");
+            }
+        }
 
-                    Context::ordr().unsubscribe_render_id(failed.render_id).await;
+        if Instant::now() + poll_period > deadline {
+            debug!(render_id, "Giving up on recovered render");
 
-                    return;
-                },
-                _ = tokio::time::sleep(TIMEOUT_DURATION) => {
-                    let content = "Timeout while waiting for o!rdr updates, \
-                        there was probably a network issue.";
+            break;
+        }
 
-                    if let Err(err) = self.orig.reply_error(content).await {
-                        warn!(?err, "Failed to update message");
-                    } else if let Some(ref response) = self.response {
-                        if response.delete {
-                            if let Err(err) = response.get().delete().await {
-                                warn!(?err, "Failed to delete response");
-                            }
-                        } else {
-                            let embed = EmbedBuilder::new()
-                                .color_red()
-                                .description("Render failed");
-                            let builder = MessageBuilder::new().embed(embed);
-                            let perms = response.permissions;
+        poll.tick().await;
+    }
 
-                            if let Some(update_fut) = response.get().update(builder, perms) {
-                                if let Err(err) = update_fut.await {
-                                    warn!(?err, "Failed to update message");
-                                }
-                            } else {
-                                warn!("Lacking permissions to update message");
-                            }
-                        }
+    remove_ongoing_render(render_id).await;
+}
+
+async fn deliver_recovered_render(render: &DbOngoingRender, video_url: &str) {
+    if let Some(score_id) = render.score_id {
+        let store_fut = Context::replay().store_video_url(score_id, video_url);
+
+        if let Err(err) = store_fut.await {
+            warn!(?err, score_id, video_url, "Failed to store video url");
+        }
+    }
+
+    let content = format!("{video_url} <@{}>", render.requester_id);
+
+    // The message might have been deleted in the meanwhile
+    let create_fut = Context::http()
+        .create_message(render.channel_id)
+        .content(&content)
+        .reply(render.message_id)
+        .fail_if_not_exists(false);
+
+    if let Err(err) = create_fut.await {
+        warn!(
+            err = ?Report::new(err),
+            render_id = render.render_id,
+            "Failed to deliver recovered render"
+        );
+    }
+}
+
+#[derive(Default)]
+struct RenderProgressState {
+    last_update: Option<Instant>,
+    eta: QueueEta,
+}
+
+/// Phase of a render as described by o!rdr's progress text.
+#[derive(Debug, PartialEq)]
+enum RenderPhase {
+    Queued(Option<u32>),
+    Rendering(Option<u32>),
+    Done,
+    Failed(String),
+    Other,
+}
+
+impl RenderPhase {
+    fn parse(progress: &str) -> Self {
+        let lowercase = progress.trim().to_ascii_lowercase();
+
+        if lowercase.starts_with("error") {
+            Self::Failed(progress.trim().to_owned())
+        } else if lowercase.starts_with("done") {
+            Self::Done
+        } else if lowercase.contains("queue") {
+            Self::Queued(first_number(&lowercase))
+        } else if let Some(idx) = lowercase.find('%') {
+            let start = lowercase[..idx]
+                .rfind(|c: char| !c.is_ascii_digit())
+                .map_or(0, |idx| idx + 1);
+
+            Self::Rendering(lowercase[start..idx].parse().ok())
+        } else {
+            Self::Other
+        }
+    }
+
+    fn describe(&self, eta: &QueueEta, progress: &str) -> String {
+        match self {
+            Self::Queued(Some(position)) => {
+                let mut text = match eta.first_position() {
+                    Some(first) if first > *position => {
+                        format!("Queue position {first} → {position}")
                     }
+                    _ => format!("Queue position {position}"),
+                };
 
-                    Context::ordr().unsubscribe_render_id(self.render_id).await;
+                if let Some(eta) = eta.remaining() {
+                    let secs = eta.as_secs();
+                    let _ = write!(text, " (ETA ~{}m{:02}s)", secs / 60, secs % 60);
+                }
 
-                    return;
-                },
+                text
+            }
+            Self::Queued(None) => "In queue".to_owned(),
+            Self::Rendering(Some(percent)) => format!("Rendering {percent}%"),
+            Self::Rendering(None) | Self::Done | Self::Failed(_) | Self::Other => {
+                progress.trim().to_owned()
             }
         }
     }
 }
 
+fn first_number(s: &str) -> Option<u32> {
+    let start = s.find(|c: char| c.is_ascii_digit())?;
+    let len = s[start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(s.len() - start);
+
+    s[start..start + len].parse().ok()
+}
+
+/// Estimates the remaining time in the render queue based on how fast the
+/// queue position decreased so far.
+#[derive(Default)]
+struct QueueEta {
+    first: Option<(Instant, u32)>,
+    last: Option<(Instant, u32)>,
+}
+
+impl QueueEta {
+    fn observe(&mut self, now: Instant, position: u32) {
+        match self.last {
+            // Moving back in the queue invalidates the observed rate
+            Some((_, last)) if position > last => self.first = Some((now, position)),
+            None => self.first = Some((now, position)),
+            Some(_) => {}
+        }
+
+        self.last = Some((now, position));
+    }
+
+    fn first_position(&self) -> Option<u32> {
+        self.first.map(|(_, position)| position)
+    }
+
+    fn remaining(&self) -> Option<Duration> {
+        let (first_at, first_pos) = self.first?;
+        let (last_at, last_pos) = self.last?;
+
+        let dequeued = first_pos.checked_sub(last_pos).filter(|&n| n > 0)?;
+        let elapsed = last_at.duration_since(first_at);
+        let per_position = elapsed.as_secs_f64() / f64::from(dequeued);
+
+        Some(Duration::from_secs_f64(per_position * f64::from(last_pos)))
+    }
+}
+
 async fn render_settings_modify(command: &mut InteractionCommand) -> Result<()> {
     command
         .defer(false)
@@ -769,7 +1099,7 @@ async fn render_settings_modify(command: &mut InteractionCommand) -> Result<()>
     let settings = match Context::replay().get_settings(owner).await {
         Ok(settings) => settings,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -804,14 +1134,14 @@ async fn render_settings_copy(
     let settings = match replay_manager.get_settings(args.user).await {
         Ok(settings) => settings,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
     };
 
     if let Err(err) = replay_manager.set_settings(owner, &settings).await {
-        let _ = command.error(GENERAL_ISSUE).await;
+        let _ = command.error_tr(ErrorKey::General).await;
 
         return Err(err);
     }
@@ -836,7 +1166,7 @@ async fn render_settings_default(command: &mut InteractionCommand) -> Result<()>
     let settings = ReplaySettings::new_default(owner);
 
     if let Err(err) = replay_manager.set_settings(owner, &settings).await {
-        let _ = command.error(GENERAL_ISSUE).await;
+        let _ = command.error_tr(ErrorKey::General).await;
 
         return Err(err);
     }
@@ -849,3 +1179,89 @@ async fn render_settings_default(command: &mut InteractionCommand) -> Result<()>
         .begin(command)
         .await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recovery_time() {
+        let now = OffsetDateTime::now_utc();
+        let remaining = |age| remaining_recovery_time(now - age, now);
+
+        assert_eq!(remaining(time::Duration::ZERO), Some(RECOVERY_MAX_AGE));
+        assert_eq!(
+            remaining(time::Duration::minutes(90)),
+            Some(Duration::from_secs(30 * 60))
+        );
+        assert_eq!(remaining(time::Duration::hours(2)), None);
+        assert_eq!(remaining(time::Duration::hours(5)), None);
+
+        // Clock skew must not extend the recovery
+        assert_eq!(
+            remaining(time::Duration::minutes(-5)),
+            Some(RECOVERY_MAX_AGE)
+        );
+    }
+
+    #[test]
+    fn parse_phases() {
+        assert_eq!(
+            RenderPhase::parse("In queue (position 12)"),
+            RenderPhase::Queued(Some(12))
+        );
+        assert_eq!(RenderPhase::parse("In queue..."), RenderPhase::Queued(None));
+        assert_eq!(
+            RenderPhase::parse("Rendering: 34% (ETA: 1m 20s)"),
+            RenderPhase::Rendering(Some(34))
+        );
+        assert_eq!(RenderPhase::parse("Finalizing..."), RenderPhase::Other);
+        assert_eq!(RenderPhase::parse("Done."), RenderPhase::Done);
+        assert_eq!(
+            RenderPhase::parse("Error: the replay file is corrupted"),
+            RenderPhase::Failed("Error: the replay file is corrupted".to_owned())
+        );
+    }
+
+    #[test]
+    fn eta_from_dequeue_rate() {
+        let start = Instant::now();
+        let mut eta = QueueEta::default();
+
+        eta.observe(start, 12);
+        assert_eq!(eta.remaining(), None);
+
+        // 7 positions in 70 seconds => 10 seconds per position
+        eta.observe(start + Duration::from_secs(70), 5);
+        assert_eq!(eta.remaining(), Some(Duration::from_secs(50)));
+        assert_eq!(eta.first_position(), Some(12));
+    }
+
+    #[test]
+    fn eta_resets_when_moving_back() {
+        let start = Instant::now();
+        let mut eta = QueueEta::default();
+
+        eta.observe(start, 5);
+        eta.observe(start + Duration::from_secs(10), 8);
+        assert_eq!(eta.remaining(), None);
+        assert_eq!(eta.first_position(), Some(8));
+
+        eta.observe(start + Duration::from_secs(30), 4);
+        assert_eq!(eta.remaining(), Some(Duration::from_secs(20)));
+    }
+
+    #[test]
+    fn describe_queue_progression() {
+        let start = Instant::now();
+        let mut eta = QueueEta::default();
+        eta.observe(start, 12);
+        eta.observe(start + Duration::from_secs(70), 5);
+
+        let text = RenderPhase::Queued(Some(5)).describe(&eta, "");
+        assert_eq!(text, "Queue position 12 → 5 (ETA ~0m50s)");
+
+        let text = RenderPhase::Rendering(Some(34)).describe(&eta, "");
+        assert_eq!(text, "Rendering 34%");
+    }
+}
@@ -0,0 +1,436 @@
+use std::{borrow::Cow, sync::Arc};
+
+use bathbot_macros::{SlashCommand, command};
+use bathbot_model::{command_fields::GameModeOption, embed_builder::SettingsImage};
+use bathbot_psql::model::configs::resolve;
+use bathbot_util::{BucketName, MessageOrigin, i18n::ErrorKey, matcher};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{GameMode, OsuError, Score};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{channel::Message, guild::Permissions};
+
+use crate::{
+    Context,
+    active::{
+        ActiveMessages,
+        impls::{ScoreSkin, SingleScoreContent, SingleScorePagination},
+    },
+    commands::{
+        osu::{Theme, map_strains_graph, user_not_found},
+        utility::{MissAnalyzerCheck, ScoreEmbedDataWrap},
+    },
+    core::commands::{CommandOrigin, checks::check_channel_permissions, prefix::Args},
+    manager::redis::osu::{UserArgs, UserArgsError, UserArgsSlim},
+    util::{ChannelExt, CheckPermissions, InteractionCommandExt, interaction::InteractionCommand},
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "score",
+    desc = "Display a score by its url or id",
+    help = "Display a score by its url or id.\n\
+    Score urls that contain a mode such as `https://osu.ppy.sh/scores/osu/123` refer to legacy \
+    scores. Urls without a mode and bare ids are looked up as lazer scores first and, \
+    if there is none, as legacy scores of the given mode."
+)]
+pub struct ShowScore<'a> {
+    #[command(desc = "Specify a score url or score id")]
+    id: Cow<'a, str>,
+    #[command(
+        desc = "Specify a gamemode for legacy score ids",
+        help = "Specify a gamemode for legacy score ids.\n\
+        Only relevant if the id does not belong to a lazer score and the url does not contain a mode."
+    )]
+    mode: Option<GameModeOption>,
+}
+
+pub(super) struct ScoreArgs {
+    id: u64,
+    pub(super) mode: Option<GameMode>,
+    /// Whether the mode was part of the score url
+    explicit_mode: bool,
+}
+
+impl ScoreArgs {
+    pub(super) fn parse(arg: &str, mode: Option<GameMode>) -> Option<Self> {
+        if let Some((id, url_mode)) = matcher::get_osu_score_id(arg) {
+            return Some(Self {
+                id,
+                mode: url_mode.or(mode),
+                explicit_mode: url_mode.is_some(),
+            });
+        }
+
+        arg.parse().ok().map(|id| Self {
+            id,
+            mode,
+            explicit_mode: false,
+        })
+    }
+}
+
+pub(super) const INVALID_ARG: &str = "Failed to parse score. \
+    Be sure to specify either a score url or a score id.";
+
+#[command]
+#[desc("Display a score by its url or id")]
+#[help(
+    "Display a score by its url or id.\n\
+    Score urls that contain a mode refer to legacy scores. \
+    Urls without a mode and bare ids are looked up as lazer scores first \
+    and, if there is none, as legacy scores."
+)]
+#[usage("[score url / score id]")]
+#[examples(
+    "https://osu.ppy.sh/scores/1234567890",
+    "https://osu.ppy.sh/scores/osu/4321"
+)]
+#[alias("scoreid")]
+#[group(AllModes)]
+async fn prefix_showscore(
+    msg: &Message,
+    mut args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    match args.next().and_then(|arg| ScoreArgs::parse(arg, None)) {
+        Some(args) => score(CommandOrigin::from_msg(msg, permissions), args).await,
+        None => {
+            msg.error(INVALID_ARG).await?;
+
+            Ok(())
+        }
+    }
+}
+
+async fn slash_showscore(mut command: InteractionCommand) -> Result<()> {
+    let args = ShowScore::from_interaction(command.input_data())?;
+
+    match ScoreArgs::parse(&args.id, args.mode.map(GameMode::from)) {
+        Some(args) => score((&mut command).into(), args).await,
+        None => {
+            command.error(INVALID_ARG).await?;
+
+            Ok(())
+        }
+    }
+}
+
+async fn score(orig: CommandOrigin<'_>, args: ScoreArgs) -> Result<()> {
+    match prepare_score(&orig, &args).await {
+        Ok(PreparedScore { pagination, graph }) => {
+            ActiveMessages::builder(pagination)
+                .start_by_update(true)
+                .attachment(graph)
+                .begin(orig)
+                .await
+        }
+        Err(PrepareScoreError::Content(content)) => orig.error(content).await,
+        Err(PrepareScoreError::Osu(err)) => {
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
+
+            Err(err)
+        }
+        Err(PrepareScoreError::General(err)) => {
+            let _ = orig.error_tr(ErrorKey::General).await;
+
+            Err(err)
+        }
+    }
+}
+
+/// Respond to a message containing a score url with the score's embed if
+/// the channel opted into automatic score embeds.
+///
+/// Failures are only logged; the message is skipped silently.
+pub async fn auto_score_embed(msg: &Message) {
+    let Some(guild_id) = msg.guild_id else {
+        return;
+    };
+
+    let enabled = Context::guild_config()
+        .peek(guild_id, |config| {
+            config.auto_score_embed_channels.contains(&msg.channel_id)
+        })
+        .await;
+
+    if !enabled {
+        return;
+    }
+
+    let Some((id, mode)) = matcher::get_osu_score_id(&msg.content) else {
+        return;
+    };
+
+    if Context::check_channel_ratelimit(msg.channel_id, BucketName::AutoScoreEmbed).is_some() {
+        return;
+    }
+
+    let permissions = match Context::cache().current_user().await {
+        Ok(Some(user)) => {
+            check_channel_permissions(user.id.to_native(), msg.channel_id, guild_id).await
+        }
+        Ok(None) => return,
+        Err(err) => return debug!(?err, "Failed to get current user for auto score embed"),
+    };
+
+    if !permissions.contains(Permissions::SEND_MESSAGES) {
+        return;
+    }
+
+    let args = ScoreArgs {
+        id,
+        mode,
+        explicit_mode: mode.is_some(),
+    };
+
+    let orig = CommandOrigin::from_msg(msg, Some(permissions));
+
+    let PreparedScore { pagination, graph } = match prepare_score(&orig, &args).await {
+        Ok(prepared) => prepared,
+        Err(PrepareScoreError::Content(_)) => return,
+        Err(PrepareScoreError::Osu(err) | PrepareScoreError::General(err)) => {
+            return debug!(?err, "Failed to prepare auto score embed");
+        }
+    };
+
+    let begin_fut = ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .attachment(graph)
+        .begin(orig);
+
+    if let Err(err) = begin_fut.await {
+        debug!(?err, "Failed to send auto score embed");
+    }
+}
+
+struct PreparedScore {
+    pagination: SingleScorePagination,
+    graph: Option<(String, Vec<u8>)>,
+}
+
+enum PrepareScoreError {
+    /// User-facing reason why the score cannot be shown
+    Content(String),
+    Osu(Report),
+    General(Report),
+}
+
+async fn prepare_score(
+    orig: &CommandOrigin<'_>,
+    args: &ScoreArgs,
+) -> Result<PreparedScore, PrepareScoreError> {
+    let author = orig.user_id().map_err(PrepareScoreError::General)?;
+
+    let config = match Context::user_config().with_osu_id(author).await {
+        Ok(config) => config,
+        Err(err) => {
+            return Err(PrepareScoreError::General(
+                err.wrap_err("Failed to get user config"),
+            ));
+        }
+    };
+
+    let (guild_render_button, guild_score_data) = match orig.guild_id() {
+        Some(guild_id) => {
+            Context::guild_config()
+                .peek(guild_id, |config| (config.render_button, config.score_data))
+                .await
+        }
+        None => (None, None),
+    };
+
+    let fallback_mode = args.mode.or(config.mode).unwrap_or(GameMode::Osu);
+
+    let score = match fetch_score(args, fallback_mode).await {
+        Ok(score) => score,
+        Err(OsuError::NotFound) => {
+            let content = not_found_content(args, fallback_mode);
+
+            return Err(PrepareScoreError::Content(content));
+        }
+        Err(err) => {
+            let err = Report::new(err).wrap_err("Failed to get score");
+
+            return Err(PrepareScoreError::Osu(err));
+        }
+    };
+
+    let mode = score.mode;
+    let score_data = resolve::score_data(None, config.score_data, guild_score_data);
+    let legacy_scores = score_data.is_legacy();
+
+    let user_args = UserArgs::user_id(score.user_id, mode);
+    let user_fut = Context::redis().osu_user(user_args);
+
+    let user_args = UserArgsSlim::user_id(score.user_id).mode(mode);
+    let top100_fut = Context::osu_scores()
+        .top(100, legacy_scores)
+        .exec(user_args);
+
+    let (user_res, top100_res) = tokio::join!(user_fut, top100_fut);
+
+    let user = match user_res {
+        Ok(user) => user,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(score.user_id.into()).await;
+
+            return Err(PrepareScoreError::Content(content));
+        }
+        Err(err) => {
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(PrepareScoreError::General(err));
+        }
+    };
+
+    let top100 = match top100_res {
+        Ok(scores) => Some(Arc::from(scores)),
+        Err(err) => {
+            warn!(?err, "Failed to get top100");
+
+            None
+        }
+    };
+
+    let settings = config.score_embed.unwrap_or_default();
+
+    let with_miss_analyzer = mode == GameMode::Osu
+        && orig
+            .guild_id()
+            .as_ref()
+            .is_some_and(Context::has_miss_analyzer);
+
+    let with_render = resolve::render_button(config.render_button, guild_render_button)
+        && settings.buttons.render
+        && mode == GameMode::Osu
+        && orig.has_permission_to(Permissions::SEND_MESSAGES)
+        && Context::ordr_available();
+
+    let guild_id = orig.guild_id();
+    let miss_analyzer = MissAnalyzerCheck::new(guild_id, with_miss_analyzer);
+    let origin = MessageOrigin::new(guild_id, orig.channel_id());
+
+    let entry = ScoreEmbedDataWrap::new_raw(
+        score,
+        legacy_scores,
+        with_render,
+        miss_analyzer,
+        top100,
+        #[cfg(feature = "twitch")]
+        None,
+        origin,
+    );
+
+    let mut entries: Box<[_]> = Box::from([entry]);
+
+    let graph = if matches!(settings.image, SettingsImage::ImageWithStrains) {
+        match entries[0].get_mut().await {
+            Ok(entry) => {
+                let fut = map_strains_graph(
+                    &entry.map.pp_map,
+                    entry.score.mods.clone(),
+                    entry.map.cover(),
+                    SingleScorePagination::IMAGE_W,
+                    SingleScorePagination::IMAGE_H,
+                    Theme::get(config.graph_theme),
+                );
+
+                match fut.await {
+                    Ok(graph) => Some((SingleScorePagination::IMAGE_NAME.to_owned(), graph)),
+                    Err(err) => {
+                        warn!(?err, "Failed to create strain graph");
+
+                        None
+                    }
+                }
+            }
+            Err(err) => {
+                warn!(?err, "Failed to get score data");
+
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let mut pagination = SingleScorePagination::new(
+        &user,
+        entries,
+        settings,
+        score_data,
+        author,
+        SingleScoreContent::None,
+    );
+
+    pagination.set_timestamp_style(config.timestamp_style);
+    pagination.set_skin(ScoreSkin::from_config(&config));
+
+    Ok(PreparedScore { pagination, graph })
+}
+
+/// Fetch a score by its id.
+///
+/// If the mode was part of the score url, the id refers to a legacy score.
+/// Otherwise the id is looked up as lazer score first and as legacy score of
+/// the fallback mode second.
+pub(super) async fn fetch_score(
+    args: &ScoreArgs,
+    fallback_mode: GameMode,
+) -> Result<Score, OsuError> {
+    if args.explicit_mode {
+        return Context::osu().score(args.id).mode(fallback_mode).await;
+    }
+
+    match Context::osu().score(args.id).await {
+        Err(OsuError::NotFound) => Context::osu().score(args.id).mode(fallback_mode).await,
+        res => res,
+    }
+}
+
+pub(super) fn not_found_content(args: &ScoreArgs, fallback_mode: GameMode) -> String {
+    let mode = match fallback_mode {
+        GameMode::Osu => "osu!",
+        GameMode::Taiko => "taiko",
+        GameMode::Catch => "catch",
+        GameMode::Mania => "mania",
+    };
+
+    if args.explicit_mode {
+        format!("There is no legacy {mode} score with id {}", args.id)
+    } else {
+        format!(
+            "There is neither a lazer score nor a legacy {mode} score with id {}",
+            args.id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_args() {
+        let args = ScoreArgs::parse("https://osu.ppy.sh/scores/taiko/4321", None).unwrap();
+        assert_eq!(
+            (args.id, args.mode, args.explicit_mode),
+            (4321, Some(GameMode::Taiko), true)
+        );
+
+        let args =
+            ScoreArgs::parse("https://osu.ppy.sh/scores/1234", Some(GameMode::Mania)).unwrap();
+        assert_eq!(
+            (args.id, args.mode, args.explicit_mode),
+            (1234, Some(GameMode::Mania), false)
+        );
+
+        let args = ScoreArgs::parse("1234", None).unwrap();
+        assert_eq!(
+            (args.id, args.mode, args.explicit_mode),
+            (1234, None, false)
+        );
+
+        assert!(ScoreArgs::parse("badewanne3", None).is_none());
+    }
+}
@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use bathbot_macros::SlashCommand;
 use bathbot_model::{Countries, RankingKind, UserModeStatsColumn, UserStatsColumn, UserStatsKind};
-use bathbot_util::{Authored, constants::GENERAL_ISSUE};
+use bathbot_util::{Authored, i18n::ErrorKey};
 use eyre::Result;
 use rosu_v2::prelude::GameMode;
 use twilight_interactions::command::{CommandModel, CreateCommand};
@@ -25,10 +25,11 @@ use crate::{
     which was cached through some command beforehand.\n\
     Since only the cached data is used, no values are guaranteed to be up-to-date. \
     They're just snapshots from the last time the user was retrieved through a command.\n\n\
-    There are three reasons why a user might be missing from the leaderboard:\n\
+    There are four reasons why a user might be missing from the leaderboard:\n\
     - They are not linked through the `/link` command\n\
+    - They hid themselves from server lists through `/config privacy`\n\
     - Their osu! user stats have not been cached yet. \
-    Try using any command that retrieves the user, e.g. `/profile`, in order to cache them.\n\
+    Try using any command that retrieves the user, e.g. `/profile user`, in order to cache them.\n\
     - Members of this server are not stored as such. Maybe let bade know :eyes:"
 )]
 #[flags(ONLY_GUILDS)]
@@ -148,10 +149,10 @@ async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()>
     let guild_id = command.guild_id.unwrap(); // command is only processed in guilds
     let cache = Context::cache();
 
-    let members: Vec<_> = match cache.members(guild_id).await {
-        Ok(members) => members.into_iter().map(|id| id as i64).collect(),
+    let members = match Context::user_config().visible_linked_users(guild_id).await {
+        Ok(members) => members,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -295,7 +296,7 @@ async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()>
     let entries = match entries_res {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -314,15 +315,8 @@ async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()>
         let content = if args.country().is_some() {
             "No user data found for members of this server from that country".to_owned()
         } else {
-            let link = InteractionCommands::get_command("link").map_or_else(
-                || "`/link`".to_owned(),
-                |cmd| cmd.mention("link").to_string(),
-            );
-
-            let profile = InteractionCommands::get_command("profile").map_or_else(
-                || "`/profile`".to_owned(),
-                |cmd| cmd.mention("profile").to_string(),
-            );
+            let link = InteractionCommands::mention("link");
+            let profile = InteractionCommands::mention("profile user");
 
             format!(
                 "No user data found for members of this server :(\n\
@@ -1,14 +1,13 @@
-use std::{borrow::Cow, str::FromStr};
+use std::borrow::Cow;
 
-use bathbot_util::CowUtils;
+use bathbot_util::{CowUtils, ParsedMods};
 use nom::{
-    Err as NomErr, IResult, Parser,
+    IResult, Parser,
     branch::alt,
     bytes::complete as by,
     character::complete as ch,
-    combinator::{all_consuming, map, map_parser, map_res, opt, recognize, success},
-    error::{Error as NomError, ErrorKind as NomErrorKind},
-    multi::many1_count,
+    combinator::{all_consuming, map, map_res, opt, recognize, success},
+    error::Error as NomError,
     number::complete as num,
     sequence::{delimited, preceded, terminated, tuple},
 };
@@ -90,7 +89,6 @@ fn parse_any(input: &str) -> Result<SimulateArg, ParseError> {
         enum ParseAny {
             Float(f32),
             Int(u32),
-            Mods(GameModsIntermode),
             Ar(f32),
             Cs(f32),
             Hp(f32),
@@ -99,12 +97,11 @@ fn parse_any(input: &str) -> Result<SimulateArg, ParseError> {
 
         let float = map(map_res(recognize_float, str::parse), ParseAny::Float);
         let int = map(ch::u32, ParseAny::Int);
-        let mods = map(parse_mods_force_prefix, ParseAny::Mods);
         let ar = map(preceded(by::tag("ar"), num::float), ParseAny::Ar);
         let cs = map(preceded(by::tag("cs"), num::float), ParseAny::Cs);
         let hp = map(preceded(by::tag("hp"), num::float), ParseAny::Hp);
         let od = map(preceded(by::tag("od"), num::float), ParseAny::Od);
-        let (rest, num) = alt((float, int, mods, ar, cs, hp, od))(input)?;
+        let (rest, num) = alt((float, int, ar, cs, hp, od))(input)?;
 
         match num {
             ParseAny::Float(n) => {
@@ -144,7 +141,6 @@ fn parse_any(input: &str) -> Result<SimulateArg, ParseError> {
 
                 all_consuming(alt(options))(rest)
             }
-            ParseAny::Mods(mods) => Ok((rest, SimulateArg::Mods(mods))),
             ParseAny::Ar(n) => Ok((rest, SimulateArg::Ar(n))),
             ParseAny::Cs(n) => Ok((rest, SimulateArg::Cs(n))),
             ParseAny::Hp(n) => Ok((rest, SimulateArg::Hp(n))),
@@ -152,6 +148,11 @@ fn parse_any(input: &str) -> Result<SimulateArg, ParseError> {
         }
     }
 
+    // Mods without key must be prefixed with `+`
+    if input.starts_with('+') {
+        return parse_mods(input).map(SimulateArg::Mods);
+    }
+
     inner(input)
         .map(|(_, val)| val)
         .map_err(|_| ParseError::nom(input))
@@ -246,41 +247,34 @@ fn parse_lazer(input: &str, err: ParseError) -> Result<bool, ParseError> {
     parse_bool(input).map(|(_, val)| val).map_err(|_| err)
 }
 
-fn is_some<T>(opt: Option<T>) -> bool {
-    opt.is_some()
-}
+fn parse_mods(input: &str) -> Result<GameModsIntermode, ParseError> {
+    let (prefixed, rest) = match input.strip_prefix('+') {
+        Some(rest) => (true, rest),
+        None => (false, input),
+    };
 
-fn parse_mods_force_prefix(input: &str) -> IResult<&str, GameModsIntermode> {
-    let (rest, (prefixed, mods, _)) = parse_mods_raw(input)?;
+    let rest = match rest.strip_suffix('!') {
+        Some(rest) if prefixed => rest,
+        Some(_) => return Err(ParseError::Mods),
+        None => rest,
+    };
 
-    if prefixed {
-        Ok((rest, mods))
-    } else {
-        Err(NomErr::Error(NomError::new(input, NomErrorKind::Char)))
+    // Excluding mods is not supported
+    if rest.starts_with('-') {
+        return Err(ParseError::Mods);
     }
-}
 
-fn parse_mods(input: &str) -> Result<GameModsIntermode, ParseError> {
-    let (_, (prefixed, mods, suffixed)) = parse_mods_raw(input).map_err(|_| ParseError::Mods)?;
+    let parsed = ParsedMods::parse(rest);
 
-    if prefixed || !suffixed {
-        Ok(mods)
+    if let Some(content) = parsed.unknown_message() {
+        Err(ParseError::UnknownMods(content))
+    } else if parsed.is_valid() {
+        Ok(parsed.mods)
     } else {
         Err(ParseError::Mods)
     }
 }
 
-fn parse_mods_raw(input: &str) -> IResult<&str, (bool, GameModsIntermode, bool)> {
-    let prefixed = map(opt(ch::char('+')), is_some);
-    let suffixed = map(opt(ch::char('!')), is_some);
-
-    let single_mod = map_parser(by::take(2_usize), all_consuming(ch::alpha1));
-    let mods_str = recognize(many1_count(single_mod));
-    let mods = map_res(mods_str, GameModsIntermode::from_str);
-
-    tuple((prefixed, mods, all_consuming(suffixed)))(input)
-}
-
 fn recognize_float(input: &str) -> IResult<&str, &str> {
     let comma = alt((ch::char('.'), ch::char(',')));
 
@@ -375,6 +369,7 @@ pub enum ParseError {
     Stable,
     Nom(String),
     Unknown(String),
+    UnknownMods(String),
 }
 
 impl ParseError {
@@ -412,7 +407,7 @@ impl ParseError {
             Self::SmallTicks => "Failed to parse small ticks, must be a number".into(),
             Self::Lazer => "Failed to parse lazer, must be a boolean".into(),
             Self::Stable => "Failed to parse stable, must be a boolean".into(),
-            Self::Nom(err) | Self::Unknown(err) => err.into(),
+            Self::Nom(err) | Self::Unknown(err) | Self::UnknownMods(err) => err.into(),
         }
     }
 }
@@ -653,7 +648,10 @@ mod tests {
         );
         assert_eq!(SimulateArg::parse("+hdhr"), Ok(SimulateArg::Mods(hdhr)));
 
-        assert_eq!(SimulateArg::parse("mods=+hdr!"), Err(ParseError::Mods));
+        assert!(matches!(
+            SimulateArg::parse("mods=+hdr!"),
+            Err(ParseError::UnknownMods(err)) if err.contains("`hdr`")
+        ));
         assert_eq!(SimulateArg::parse("mods=-hdhr!"), Err(ParseError::Mods));
         assert_eq!(SimulateArg::parse("mods=hdhr!"), Err(ParseError::Mods));
         assert!(matches!(
@@ -669,4 +667,31 @@ mod tests {
             Err(ParseError::Nom(err)) if err.contains("`hdhr!`")
         ));
     }
+
+    #[test]
+    fn tolerant_mods() {
+        assert_eq!(
+            SimulateArg::parse("mods=hidden,hardrock"),
+            Ok(SimulateArg::Mods(mods!(HD HR)))
+        );
+        assert_eq!(
+            SimulateArg::parse("+HD,DT"),
+            Ok(SimulateArg::Mods(mods!(HD DT)))
+        );
+        assert_eq!(
+            SimulateArg::parse("+DoubleTime!"),
+            Ok(SimulateArg::Mods(mods!(DT)))
+        );
+        assert_eq!(
+            SimulateArg::parse("mods=nomod"),
+            Ok(SimulateArg::Mods(GameModsIntermode::new()))
+        );
+        assert_eq!(
+            SimulateArg::parse("+hdr"),
+            Err(ParseError::UnknownMods(
+                "Unknown mod `hdr`, did you mean `hr` or `hd`?".to_owned()
+            ))
+        );
+        assert_eq!(SimulateArg::parse("mods="), Err(ParseError::Mods));
+    }
 }
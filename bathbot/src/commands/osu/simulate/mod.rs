@@ -5,9 +5,8 @@ use std::borrow::Cow;
 
 use bathbot_macros::{HasMods, SlashCommand, command};
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
-    constants::GENERAL_ISSUE,
+    i18n::ErrorKey,
     matcher,
     osu::{MapIdType, ModSelection},
 };
@@ -29,13 +28,15 @@ use crate::{
         ActiveMessages,
         impls::{SimulateAttributes, SimulateComponents, SimulateData, SimulateMap, TopOldVersion},
     },
-    commands::osu::parsed_map::AttachedSimulateMap,
+    commands::osu::parsed_map::{AttachedSimulateMap, MapSource},
     core::{
         Context,
         commands::{CommandOrigin, prefix::Args},
     },
     manager::MapError,
-    util::{InteractionCommandExt, interaction::InteractionCommand, osu::MapOrScore},
+    util::{
+        EffectiveMode, InteractionCommandExt, interaction::InteractionCommand, osu::MapOrScore,
+    },
 };
 
 #[derive(CreateCommand, CommandModel, Default, HasMods, SlashCommand)]
@@ -86,7 +87,7 @@ pub struct Simulate<'m> {
     hp: Option<f32>,
     #[command(desc = "Overwrite the map's overall difficulty")]
     od: Option<f32>,
-    #[command(desc = "Specify a .osu file")]
+    #[command(desc = "Specify a .osu file, e.g. of an unsubmitted map")]
     file: Option<Attachment>,
 }
 
@@ -105,12 +106,14 @@ async fn simulate(orig: CommandOrigin<'_>, mut args: SimulateArgs) -> Result<()>
     let config = Context::user_config().with_osu_id(owner).await?;
 
     let map = args.map.take();
-    let mode = args.mode.or(config.mode);
 
-    let Some(map) = prepare_map(&orig, map, mode).await? else {
+    let Some(mut map) = prepare_map(&orig, map).await? else {
         return Ok(());
     };
 
+    let effective_mode = EffectiveMode::infer(map.mode(), args.mode, config.mode);
+    map.convert_mut(effective_mode.mode);
+
     let mode = map.mode();
     debug!(?map, ?mode, "Processing simulate command...");
 
@@ -141,16 +144,10 @@ async fn simulate(orig: CommandOrigin<'_>, mut args: SimulateArgs) -> Result<()>
 
     let set_on_lazer = match args.set_on_lazer {
         Some(lazer) => lazer,
-        None => !match config.score_data {
-            Some(score_data) => score_data.is_legacy(),
-            None => match orig.guild_id() {
-                Some(guild_id) => Context::guild_config()
-                    .peek(guild_id, |config| config.score_data)
-                    .await
-                    .is_some_and(ScoreData::is_legacy),
-                None => false,
-            },
-        },
+        None => !Context::guild_config()
+            .resolve_score_data(orig.guild_id(), None, config.score_data)
+            .await
+            .is_legacy(),
     };
 
     let simulate_data = SimulateData {
@@ -179,7 +176,7 @@ async fn simulate(orig: CommandOrigin<'_>, mut args: SimulateArgs) -> Result<()>
         max_combo,
     };
 
-    let active = SimulateComponents::new(map, simulate_data, owner);
+    let active = SimulateComponents::new(map, simulate_data, effective_mode, owner);
 
     ActiveMessages::builder(active)
         .start_by_update(true)
@@ -362,18 +359,17 @@ async fn prefix_simulatemania(
 
 async fn prepare_map(
     orig: &CommandOrigin<'_>,
-    map: Option<SimulateMapArg>,
-    mode: Option<GameMode>,
+    map: Option<MapSource>,
 ) -> Result<Option<SimulateMap>> {
     let map_id = match map {
-        Some(SimulateMapArg::Id(MapIdType::Map(id))) => id,
-        Some(SimulateMapArg::Id(MapIdType::Set(_))) => {
+        Some(MapSource::Online(MapIdType::Map(id))) => id,
+        Some(MapSource::Online(MapIdType::Set(_))) => {
             let content = "Looks like you gave me a mapset id, I need a map id though";
 
             return orig.error(content).await.map(|_| None);
         }
-        Some(SimulateMapArg::Attachment(attachment)) => {
-            return AttachedSimulateMap::new(orig, attachment, mode)
+        Some(MapSource::Attachment(attachment)) => {
+            return AttachedSimulateMap::new(orig, attachment)
                 .await
                 .map(|opt| opt.map(SimulateMap::Attached));
         }
@@ -402,10 +398,7 @@ async fn prepare_map(
     };
 
     let map = match Context::osu_map().map(map_id, None).await {
-        Ok(map) => match mode {
-            Some(mode) => map.convert(mode),
-            None => map,
-        },
+        Ok(map) => map,
         Err(MapError::NotFound) => {
             let content = format!(
                 "Could not find beatmap with id `{map_id}`. \
@@ -415,7 +408,7 @@ async fn prepare_map(
             return orig.error(content).await.map(|_| None);
         }
         Err(MapError::Report(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -424,14 +417,9 @@ async fn prepare_map(
     Ok(Some(SimulateMap::Full(map)))
 }
 
-enum SimulateMapArg {
-    Id(MapIdType),
-    Attachment(Box<Attachment>),
-}
-
 #[derive(Default)]
 struct SimulateArgs {
-    map: Option<SimulateMapArg>,
+    map: Option<MapSource>,
     mode: Option<GameMode>,
     mods: Option<GameModsIntermode>,
     combo: Option<u32>,
@@ -460,7 +448,7 @@ impl SimulateArgs {
         args: Args<'_>,
     ) -> Result<Self, Cow<'static, str>> {
         let map = match MapOrScore::find_in_msg(msg).await {
-            Some(MapOrScore::Map(id)) => Some(SimulateMapArg::Id(id)),
+            Some(MapOrScore::Map(id)) => Some(MapSource::Online(id)),
             Some(MapOrScore::Score { .. }) => {
                 return Err(Cow::Borrowed(
                     "This command does not (yet) accept score urls as argument",
@@ -481,7 +469,7 @@ impl SimulateArgs {
                 .or_else(|| matcher::get_osu_mapset_id(arg).map(MapIdType::Set));
 
             if let Some(id) = id_opt {
-                simulate.map = Some(SimulateMapArg::Id(id));
+                simulate.map = Some(MapSource::Online(id));
 
                 continue;
             }
@@ -533,7 +521,7 @@ impl SimulateArgs {
         let mode = simulate.mode.map(GameMode::from);
 
         let map = match simulate.file {
-            Some(attachment) => Some(SimulateMapArg::Attachment(Box::new(attachment))),
+            Some(attachment) => Some(MapSource::Attachment(Box::new(attachment))),
             None => match simulate.map {
                 Some(map) => matcher::get_osu_map_id(&map)
                     .map(MapIdType::Map)
@@ -542,7 +530,7 @@ impl SimulateArgs {
                         "Failed to parse map url. \
                         Be sure you specify a valid map id or url to a map.",
                     )
-                    .map(SimulateMapArg::Id)
+                    .map(MapSource::Online)
                     .map(Some)?,
                 None => None,
             },
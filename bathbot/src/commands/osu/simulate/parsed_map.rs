@@ -1,22 +1,41 @@
-use bathbot_util::constants::GENERAL_ISSUE;
-use eyre::{Report, Result};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    time::Duration,
+};
+
+use bathbot_util::{CowUtils, i18n::ErrorKey, osu::MapIdType};
+use eyre::Result;
 use rosu_pp::{Beatmap, Difficulty};
 use rosu_v2::prelude::GameMode;
+use tokio::time::timeout;
 use twilight_model::channel::Attachment;
 
 use crate::core::{Context, commands::CommandOrigin};
 
+/// Attachments larger than this are rejected before downloading them.
+const MAX_ATTACHMENT_SIZE: u64 = 1024 * 1024;
+
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Where a command gets its map from.
+pub enum MapSource {
+    /// A map or mapset id of a submitted map
+    Online(MapIdType),
+    /// A .osu file, e.g. of an unsubmitted map
+    Attachment(Box<Attachment>),
+}
+
 pub struct AttachedSimulateMap {
     pub pp_map: Beatmap,
     pub max_combo: u32,
     pub filename: Box<str>,
+    pub metadata: OsuFileMetadata,
 }
 
 impl AttachedSimulateMap {
     pub async fn new(
         orig: &CommandOrigin<'_>,
         attachment: Box<Attachment>,
-        mode: Option<GameMode>,
     ) -> Result<Option<Self>> {
         if !attachment.filename.ends_with(".osu") {
             let content = "The attached file must be of type .osu";
@@ -25,42 +44,260 @@ impl AttachedSimulateMap {
             return Ok(None);
         }
 
-        let bytes = match Context::client().get_discord_attachment(&attachment).await {
-            Ok(bytes) => bytes,
-            Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+        if attachment.size > MAX_ATTACHMENT_SIZE {
+            let content = "The attached file must not be larger than 1MB";
+            orig.error(content).await?;
+
+            return Ok(None);
+        }
+
+        let download_fut = Context::client().get_discord_attachment(&attachment);
+
+        let bytes = match timeout(DOWNLOAD_TIMEOUT, download_fut).await {
+            Ok(Ok(bytes)) => bytes,
+            Ok(Err(err)) => {
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err.wrap_err("Failed to download attachment"));
             }
+            Err(_) => {
+                let content = "Timed out while downloading the attached file";
+                orig.error(content).await?;
+
+                return Ok(None);
+            }
         };
 
-        let mut pp_map = match Beatmap::from_bytes(&bytes) {
-            Ok(map) => map,
+        // The reported size can't be relied upon
+        if bytes.len() as u64 > MAX_ATTACHMENT_SIZE {
+            let content = "The attached file must not be larger than 1MB";
+            orig.error(content).await?;
+
+            return Ok(None);
+        }
+
+        let (pp_map, metadata) = match parse_osu_file(&bytes) {
+            Ok(parsed) => parsed,
             Err(err) => {
-                debug!(err = ?Report::new(err), "Failed to parse attachment as beatmap");
+                debug!(%err, "Failed to parse attachment as beatmap");
 
-                let content = "Failed to parse file. Be sure you provide a valid .osu file.";
+                let content =
+                    format!("Failed to parse file: {err}.\nBe sure you provide a valid .osu file.");
                 orig.error(content).await?;
 
                 return Ok(None);
             }
         };
 
-        if let Some(mode) = mode {
-            // TODO: use mods
-            let _ = pp_map.convert_mut((mode as u8).into(), &Default::default());
-        }
-
-        let max_combo = if pp_map.check_suspicion().is_ok() {
-            Difficulty::new().calculate(&pp_map).max_combo()
-        } else {
-            0
-        };
+        let max_combo = Self::max_combo(&pp_map);
 
         Ok(Some(Self {
             pp_map,
             max_combo,
             filename: attachment.filename.into(),
+            metadata,
         }))
     }
+
+    pub fn convert_mut(&mut self, mode: GameMode) {
+        // TODO: use mods
+        if self
+            .pp_map
+            .convert_mut((mode as u8).into(), &Default::default())
+            .is_ok()
+        {
+            self.max_combo = Self::max_combo(&self.pp_map);
+        }
+    }
+
+    fn max_combo(pp_map: &Beatmap) -> u32 {
+        if pp_map.check_suspicion().is_ok() {
+            Difficulty::new().calculate(pp_map).max_combo()
+        } else {
+            0
+        }
+    }
+}
+
+/// Metadata of a .osu file which rosu-pp does not keep around.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct OsuFileMetadata {
+    pub artist: Box<str>,
+    pub title: Box<str>,
+    pub version: Box<str>,
+    pub creator: Box<str>,
+}
+
+impl OsuFileMetadata {
+    fn parse(content: &str) -> Self {
+        let mut metadata = Self::default();
+        let mut in_section = false;
+
+        for line in content.lines().map(str::trim) {
+            if line.starts_with('[') {
+                if in_section {
+                    break;
+                }
+
+                in_section = line == "[Metadata]";
+
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once(':').filter(|_| in_section) else {
+                continue;
+            };
+
+            let field = match key.trim_end() {
+                "Artist" => &mut metadata.artist,
+                "Title" => &mut metadata.title,
+                "Version" => &mut metadata.version,
+                "Creator" => &mut metadata.creator,
+                _ => continue,
+            };
+
+            *field = value.trim_start().into();
+        }
+
+        metadata
+    }
+}
+
+impl Display for OsuFileMetadata {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} - {} [{}]",
+            self.artist.cow_escape_markdown(),
+            self.title.cow_escape_markdown(),
+            self.version.cow_escape_markdown(),
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum OsuFileError {
+    Empty,
+    MissingHeader,
+    NoHitObjects,
+    Decode(Box<str>),
+}
+
+impl Display for OsuFileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Empty => f.write_str("the file is empty"),
+            Self::MissingHeader => {
+                f.write_str("the file does not start with `osu file format v[version]`")
+            }
+            Self::NoHitObjects => f.write_str("the file contains no hit objects"),
+            Self::Decode(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+/// Parse the bytes of a .osu file in memory.
+pub fn parse_osu_file(bytes: &[u8]) -> Result<(Beatmap, OsuFileMetadata), OsuFileError> {
+    let content = String::from_utf8_lossy(bytes);
+    let content = content.trim_start_matches('\u{feff}');
+
+    let Some(first_line) = content.lines().map(str::trim).find(|line| !line.is_empty()) else {
+        return Err(OsuFileError::Empty);
+    };
+
+    if !first_line.starts_with("osu file format v") {
+        return Err(OsuFileError::MissingHeader);
+    }
+
+    let pp_map = Beatmap::from_bytes(bytes)
+        .map_err(|err| OsuFileError::Decode(summarize_error(&err.to_string())))?;
+
+    if pp_map.hit_objects.is_empty() {
+        return Err(OsuFileError::NoHitObjects);
+    }
+
+    Ok((pp_map, OsuFileMetadata::parse(content)))
+}
+
+/// Only keep the first line of an error message and limit its length so it
+/// fits into an error embed.
+fn summarize_error(err: &str) -> Box<str> {
+    const MAX_LEN: usize = 100;
+
+    let line = err.lines().next().unwrap_or_default().trim();
+
+    match line.char_indices().nth(MAX_LEN) {
+        Some((idx, _)) => format!("{}…", &line[..idx]).into_boxed_str(),
+        None => line.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! fixture {
+        ($name:literal) => {
+            include_bytes!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/",
+                $name
+            ))
+        };
+    }
+
+    #[test]
+    fn valid_file() {
+        let (pp_map, metadata) = parse_osu_file(fixture!("density.osu")).unwrap();
+
+        assert!(!pp_map.hit_objects.is_empty());
+
+        let expected = OsuFileMetadata {
+            artist: "bathbot".into(),
+            title: "density".into(),
+            version: "fixture".into(),
+            creator: "bathbot".into(),
+        };
+
+        assert_eq!(metadata, expected);
+    }
+
+    #[test]
+    fn empty_file() {
+        assert!(matches!(parse_osu_file(b""), Err(OsuFileError::Empty)));
+        assert!(matches!(
+            parse_osu_file(b" \n\r\n"),
+            Err(OsuFileError::Empty)
+        ));
+    }
+
+    #[test]
+    fn missing_header() {
+        let res = parse_osu_file(fixture!("malformed_header.osu"));
+        assert!(matches!(res, Err(OsuFileError::MissingHeader)));
+
+        // e.g. an image that was renamed to .osu
+        let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+        let res = parse_osu_file(png);
+        assert!(matches!(res, Err(OsuFileError::MissingHeader)));
+    }
+
+    #[test]
+    fn no_hit_objects() {
+        let res = parse_osu_file(fixture!("malformed_no_objects.osu"));
+        assert!(matches!(res, Err(OsuFileError::NoHitObjects)));
+    }
+
+    #[test]
+    fn summarized_error() {
+        assert_eq!(
+            summarize_error("invalid line\ncaused by: something").as_ref(),
+            "invalid line"
+        );
+
+        let long = "a".repeat(150);
+        let summary = summarize_error(&long);
+        assert_eq!(summary.chars().count(), 101);
+        assert!(summary.ends_with('…'));
+    }
 }
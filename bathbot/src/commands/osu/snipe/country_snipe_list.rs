@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use bathbot_macros::command;
 use bathbot_model::{Countries, SnipeCountryListOrder};
-use bathbot_util::{CowUtils, constants::GENERAL_ISSUE};
+use bathbot_util::{CowUtils, i18n::ErrorKey};
 use eyre::{Report, Result};
 use rosu_v2::{
     model::GameMode,
@@ -10,7 +10,7 @@ use rosu_v2::{
     request::UserId,
 };
 
-use super::{SnipeCountryList, SnipeGameMode};
+use super::{SnipeCountryList, SnipeGameMode, snipe_disabled_notice};
 use crate::{
     Context,
     active::{ActiveMessages, impls::SnipeCountryListPagination},
@@ -147,7 +147,7 @@ pub(super) async fn country_list(
                             return orig.error(content).await;
                         }
                         Err(err) => {
-                            let _ = orig.error(GENERAL_ISSUE).await;
+                            let _ = orig.error_tr(ErrorKey::General).await;
                             let err = Report::new(err).wrap_err("Failed to get user");
 
                             return Err(err);
@@ -164,6 +164,10 @@ pub(super) async fn country_list(
         }
     };
 
+    if let Some(content) = snipe_disabled_notice(mode).await {
+        return orig.error(content).await;
+    }
+
     let country_code = match country {
         Some(ref country) => match Countries::name(country).to_code() {
             Some(code) => CountryCode::from(code),
@@ -204,7 +208,7 @@ pub(super) async fn country_list(
     {
         Ok(players) => players,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("failed to get snipe country"));
         }
@@ -249,12 +253,16 @@ impl<'m> SnipeCountryList<'m> {
         let mut country = None;
         let mut sort = None;
 
-        for arg in args.take(2).map(CowUtils::cow_to_ascii_lowercase) {
-            if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
-                let key = &arg[..idx];
-                let value = arg[idx + 1..].trim_end();
+        for arg in args.into_args().take(2) {
+            let key_value = arg.key_value();
+            let arg = arg.into_lowercase();
+
+            if let Some((key, value)) = key_value {
+                let key = key.cow_to_ascii_lowercase();
+                let value = value.cow_to_ascii_lowercase();
+                let value = value.trim_end();
 
-                match key {
+                match key.as_ref() {
                     "sort" => {
                         sort = match value {
                             "count" => Some(SnipeCountryListOrder::Count),
@@ -0,0 +1,132 @@
+use bathbot_model::Countries;
+use bathbot_util::i18n::ErrorKey;
+use eyre::{Report, Result};
+use rosu_v2::{
+    model::GameMode,
+    prelude::{CountryCode, OsuError},
+    request::UserId,
+};
+
+use super::{SnipeCountryPlayers, snipe_disabled_notice};
+use crate::{
+    Context,
+    active::{
+        ActiveMessages,
+        impls::{SnipeCountryPlayersPagination, country_player_position, sort_country_players},
+    },
+    commands::osu::user_not_found,
+    core::commands::CommandOrigin,
+    manager::redis::osu::{UserArgs, UserArgsError},
+};
+
+pub(super) async fn country_players(
+    orig: CommandOrigin<'_>,
+    args: SnipeCountryPlayers<'_>,
+) -> Result<()> {
+    if let Some(content) = snipe_disabled_notice(GameMode::Osu).await {
+        return orig.error(content).await;
+    }
+
+    let author_id = orig.user_id()?;
+
+    let SnipeCountryPlayers { country, sort } = args;
+
+    let osu_id = match Context::user_config().osu_id(author_id).await {
+        Ok(osu_id) => osu_id,
+        Err(err) => {
+            warn!(?err);
+
+            None
+        }
+    };
+
+    let country_code = match country {
+        Some(ref country) => match Countries::name(country).to_code() {
+            Some(code) => CountryCode::from(code),
+            None if country.len() == 2 => CountryCode::from(country.as_ref()),
+            None => {
+                let content =
+                    format!("Looks like `{country}` is neither a country name nor a country code");
+
+                return orig.error(content).await;
+            }
+        },
+        None => match osu_id {
+            Some(user_id) => {
+                let user_args = UserArgs::user_id(user_id, GameMode::Osu);
+
+                match Context::redis().osu_user(user_args).await {
+                    Ok(user) => user.country_code.as_str().into(),
+                    Err(UserArgsError::Osu(OsuError::NotFound)) => {
+                        let content = user_not_found(UserId::Id(user_id)).await;
+
+                        return orig.error(content).await;
+                    }
+                    Err(err) => {
+                        let _ = orig.error_tr(ErrorKey::General).await;
+                        let err = Report::new(err).wrap_err("Failed to get user");
+
+                        return Err(err);
+                    }
+                }
+            }
+            None => {
+                let content = "Since you're not linked, you must specify a country (code)";
+
+                return orig.error(content).await;
+            }
+        },
+    };
+
+    if !Context::huismetbenen()
+        .is_supported(country_code.as_str(), GameMode::Osu)
+        .await
+    {
+        let content = format!("The country code `{country_code}` is not supported :(",);
+
+        return orig.error(content).await;
+    }
+
+    let sort = sort.unwrap_or_default();
+
+    let players = match Context::redis()
+        .snipe_country_ranking(&country_code, sort)
+        .await
+    {
+        Ok(players) => players,
+        Err(err) => {
+            let _ = orig.error_tr(ErrorKey::General).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get snipe country ranking"));
+        }
+    };
+
+    if players.is_empty() {
+        let content = format!("No national #1 players found for country code `{country_code}`");
+
+        return orig.error(content).await;
+    }
+
+    let order = sort_country_players(&players, sort);
+    let author_pos = osu_id.and_then(|user_id| country_player_position(&players, &order, user_id));
+
+    let Some(country_name) = Countries::code(&country_code).to_name() else {
+        let content = format!("Unknown country code `{country_code}`");
+
+        return orig.error(content).await;
+    };
+
+    let pagination = SnipeCountryPlayersPagination::builder()
+        .players(players)
+        .order(order)
+        .country((country_name, country_code))
+        .sort(sort)
+        .author_pos(author_pos)
+        .msg_owner(author_id)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+}
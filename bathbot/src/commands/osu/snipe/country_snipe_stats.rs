@@ -2,7 +2,7 @@ use std::{borrow::Cow, cmp::Ordering::Equal};
 
 use bathbot_macros::command;
 use bathbot_model::{Countries, SnipeCountryListOrder, SnipeCountryPlayer};
-use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE};
+use bathbot_util::{MessageBuilder, i18n::ErrorKey};
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use plotters::prelude::*;
 use plotters_skia::SkiaBackend;
@@ -14,10 +14,10 @@ use rosu_v2::{
 use skia_safe::{EncodedImageFormat, surfaces};
 use twilight_model::guild::Permissions;
 
-use super::{SnipeCountryStats, SnipeGameMode};
+use super::{SnipeCountryStats, SnipeGameMode, snipe_disabled_notice};
 use crate::{
     Context,
-    commands::osu::user_not_found,
+    commands::osu::{Theme, user_not_found},
     core::commands::CommandOrigin,
     embeds::{CountrySnipeStatsEmbed, EmbedData},
     manager::redis::osu::{UserArgs, UserArgsError},
@@ -108,7 +108,7 @@ pub(super) async fn country_stats(
     let config = match Context::user_config().with_osu_id(orig.user_id()?).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to get user config"));
         }
@@ -120,6 +120,10 @@ pub(super) async fn country_stats(
         .or(config.mode)
         .unwrap_or(GameMode::Osu);
 
+    if let Some(content) = snipe_disabled_notice(mode).await {
+        return orig.error(content).await;
+    }
+
     let country_code = match args.country {
         Some(ref country) => match Countries::name(country).to_code() {
             Some(code) => CountryCode::from(code),
@@ -143,7 +147,7 @@ pub(super) async fn country_stats(
                         return orig.error(content).await;
                     }
                     Err(err) => {
-                        let _ = orig.error(GENERAL_ISSUE).await;
+                        let _ = orig.error_tr(ErrorKey::General).await;
                         let err = Report::new(err).wrap_err("Failed to get user");
 
                         return Err(err);
@@ -180,14 +184,14 @@ pub(super) async fn country_stats(
         match tokio::try_join!(players_fut, stats_fut,) {
             Ok((players, statistics)) => (players, statistics),
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err.wrap_err("failed to get country data"));
             }
         }
     };
 
-    let graph = match graphs(&players) {
+    let graph = match graphs(&players, Theme::get(config.graph_theme)) {
         Ok(graph_option) => Some(graph_option),
         Err(err) => {
             warn!(?err, "Failed to create graph");
@@ -218,7 +222,7 @@ pub(super) async fn country_stats(
 const W: u32 = 1350;
 const H: u32 = 350;
 
-fn graphs(players: &[SnipeCountryPlayer]) -> Result<Vec<u8>> {
+fn graphs(players: &[SnipeCountryPlayer], theme: &Theme) -> Result<Vec<u8>> {
     let mut pp: Vec<_> = players
         .iter()
         .map(|player| (&player.username, player.pp))
@@ -251,7 +255,7 @@ fn graphs(players: &[SnipeCountryPlayer]) -> Result<Vec<u8>> {
     {
         let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = theme.background;
         root.fill(&background)
             .wrap_err("failed to fill background")?;
 
@@ -261,7 +265,7 @@ fn graphs(players: &[SnipeCountryPlayer]) -> Result<Vec<u8>> {
             .x_label_area_size(30)
             .y_label_area_size(60)
             .margin_right(15)
-            .caption("Weighted pp from #1s", ("sans-serif", 30, &WHITE))
+            .caption("Weighted pp from #1s", ("sans-serif", 30, &theme.text))
             .build_cartesian_2d(0..pp.len() - 1, 0.0..pp_max)
             .wrap_err("failed to build left chart")?;
 
@@ -270,7 +274,7 @@ fn graphs(players: &[SnipeCountryPlayer]) -> Result<Vec<u8>> {
             .configure_mesh()
             .disable_x_mesh()
             .x_label_offset(30)
-            .label_style(("sans-serif", 12, &WHITE))
+            .label_style(("sans-serif", 12, &theme.text))
             .x_label_formatter(&|idx| {
                 if *idx < 10 {
                     pp[*idx].0.to_string()
@@ -282,7 +286,7 @@ fn graphs(players: &[SnipeCountryPlayer]) -> Result<Vec<u8>> {
             .wrap_err("failed to draw left mesh")?;
 
         // Histogram bars
-        let area_style = RGBColor(2, 186, 213).mix(0.7).filled();
+        let area_style = theme.area.mix(0.7).filled();
 
         let iter = pp
             .iter()
@@ -299,7 +303,7 @@ fn graphs(players: &[SnipeCountryPlayer]) -> Result<Vec<u8>> {
             .x_label_area_size(30)
             .y_label_area_size(35)
             .margin_right(15)
-            .caption("#1 Count", ("sans-serif", 30, &WHITE))
+            .caption("#1 Count", ("sans-serif", 30, &theme.text))
             .build_cartesian_2d(0..count.len() - 1, 0..count_max)
             .wrap_err("failed to build right chart")?;
 
@@ -308,7 +312,7 @@ fn graphs(players: &[SnipeCountryPlayer]) -> Result<Vec<u8>> {
             .configure_mesh()
             .disable_x_mesh()
             .x_label_offset(30)
-            .label_style(("sans-serif", 12, &WHITE))
+            .label_style(("sans-serif", 12, &theme.text))
             .x_label_formatter(&|idx| {
                 if *idx < 10 {
                     count[*idx].0.to_string()
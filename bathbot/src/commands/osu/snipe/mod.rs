@@ -1,22 +1,24 @@
 use std::borrow::Cow;
 
 use bathbot_macros::{HasMods, HasName, SlashCommand};
-use bathbot_model::{SnipeCountryListOrder, SnipePlayerListOrder};
+use bathbot_model::{SnipeCountryListOrder, SnipeCountryPlayersOrder, SnipePlayerListOrder};
 use eyre::Result;
 use rosu_v2::model::GameMode;
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::id::{Id, marker::UserMarker};
 
 pub use self::{
-    country_snipe_list::*, country_snipe_stats::*, player_snipe_list::*, player_snipe_stats::*,
-    sniped::*, sniped_difference::*,
+    country_snipe_list::*, country_snipe_players::*, country_snipe_stats::*, player_snipe_list::*,
+    player_snipe_stats::*, sniped::*, sniped_difference::*,
 };
 use crate::{
     commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP},
+    core::{Context, Service},
     util::{InteractionCommandExt, interaction::InteractionCommand},
 };
 
 mod country_snipe_list;
+mod country_snipe_players;
 mod country_snipe_stats;
 mod player_snipe_list;
 mod sniped_difference;
@@ -36,6 +38,8 @@ pub mod sniped;
 pub enum Snipe<'a> {
     #[command(name = "country")]
     Country(SnipeCountry<'a>),
+    #[command(name = "countrylist")]
+    CountryList(SnipeCountryListGroup<'a>),
     #[command(name = "player")]
     Player(SnipePlayer<'a>),
 }
@@ -49,6 +53,13 @@ pub enum SnipeCountry<'a> {
     Stats(SnipeCountryStats<'a>),
 }
 
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "countrylist", desc = "Rankings of a country's snipers")]
+pub enum SnipeCountryListGroup<'a> {
+    #[command(name = "players")]
+    Players(SnipeCountryPlayers<'a>),
+}
+
 #[derive(Copy, Clone, CommandOption, CreateOption, Default)]
 pub enum SnipeGameMode {
     #[default]
@@ -111,6 +122,27 @@ pub struct SnipeCountryStats<'a> {
     country: Option<Cow<'a, str>>,
 }
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "players",
+    desc = "List a country's players by their national #1s",
+    help = "List a country's osu!standard players by their national #1s, \
+    including how many #1s they gained and lost within the last 30 days.\n\
+    Data is provided by [huismetbenen](https://snipe.huismetbenen.nl)."
+)]
+pub struct SnipeCountryPlayers<'a> {
+    #[command(desc = "Specify a country (code)")]
+    country: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify the order of players",
+        help = "Specify the order of players.\n\
+        Available orderings are `count` for the amount of #1 scores, `gained` and `lost` for \
+        the amount of #1s gained or lost within the last 30 days, and `weighted` for the total \
+        pp a user would have if only their #1s would count towards it."
+    )]
+    sort: Option<SnipeCountryPlayersOrder>,
+}
+
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "player", desc = "Player related snipe stats")]
 pub enum SnipePlayer<'a> {
@@ -209,6 +241,9 @@ async fn slash_snipe(mut command: InteractionCommand) -> Result<()> {
         Snipe::Country(SnipeCountry::Stats(args)) => {
             country_stats((&mut command).into(), args).await
         }
+        Snipe::CountryList(SnipeCountryListGroup::Players(args)) => {
+            country_players((&mut command).into(), args).await
+        }
         Snipe::Player(SnipePlayer::Gain(args)) => player_gain((&mut command).into(), args).await,
         Snipe::Player(SnipePlayer::List(args)) => player_list((&mut command).into(), args).await,
         Snipe::Player(SnipePlayer::Loss(args)) => player_loss((&mut command).into(), args).await,
@@ -219,6 +254,21 @@ async fn slash_snipe(mut command: InteractionCommand) -> Result<()> {
     }
 }
 
+/// The notice to respond with if the snipe data source for the mode is
+/// disabled.
+///
+/// Only osu!standard data comes from huismetbenen, the other modes are
+/// unaffected.
+async fn snipe_disabled_notice(mode: GameMode) -> Option<String> {
+    if mode != GameMode::Osu {
+        return None;
+    }
+
+    Context::feature_flags()
+        .disabled_notice(Service::Huismetbenen)
+        .await
+}
+
 async fn slash_snipeplayersniped(mut command: InteractionCommand) -> Result<()> {
     let args = SnipePlayerSniped::from_interaction(command.input_data())?;
 
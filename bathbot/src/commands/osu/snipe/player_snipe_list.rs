@@ -7,11 +7,11 @@ use std::{
 
 use bathbot_macros::command;
 use bathbot_model::SnipeScoreParams;
-use bathbot_util::{CowUtils, constants::GENERAL_ISSUE, matcher, osu::ModSelection};
+use bathbot_util::{CowUtils, i18n::ErrorKey, matcher, osu::ModSelection};
 use eyre::{Report, Result};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 
-use super::{SnipeGameMode, SnipePlayerList, SnipePlayerListOrder};
+use super::{SnipeGameMode, SnipePlayerList, SnipePlayerListOrder, snipe_disabled_notice};
 use crate::{
     Context,
     active::{ActiveMessages, impls::SnipePlayerListPagination},
@@ -129,6 +129,10 @@ pub(super) async fn player_list(orig: CommandOrigin<'_>, args: SnipePlayerList<'
     let owner = orig.user_id()?;
 
     let (user_id, mode) = user_id_mode!(orig, args);
+    if let Some(content) = snipe_disabled_notice(mode).await {
+        return orig.error(content).await;
+    }
+
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
     let user = match Context::redis().osu_user(user_args).await {
@@ -142,7 +146,7 @@ pub(super) async fn player_list(orig: CommandOrigin<'_>, args: SnipePlayerList<'
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let report = Report::new(err).wrap_err("Failed to get user");
 
             return Err(report);
@@ -180,7 +184,7 @@ pub(super) async fn player_list(orig: CommandOrigin<'_>, args: SnipePlayerList<'
             (scores, count)
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("failed to get scores or counts"));
         }
@@ -237,12 +241,16 @@ impl<'m> SnipePlayerList<'m> {
         let mut mods = None;
         let mut reverse = None;
 
-        for arg in args.take(4).map(CowUtils::cow_to_ascii_lowercase) {
-            if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
-                let key = &arg[..idx];
-                let value = arg[idx + 1..].trim_end();
+        for arg in args.into_args().take(4) {
+            let key_value = arg.key_value();
+            let arg = arg.into_lowercase();
+
+            if let Some((key, value)) = key_value {
+                let key = key.cow_to_ascii_lowercase();
+                let value = value.cow_to_ascii_lowercase();
+                let value = value.trim_end();
 
-                match key {
+                match key.as_ref() {
                     "sort" | "s" => {
                         sort = match value {
                             "acc" | "accuracy" | "a" => Some(SnipePlayerListOrder::Acc),
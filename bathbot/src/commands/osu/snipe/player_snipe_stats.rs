@@ -2,11 +2,7 @@ use std::collections::BTreeMap;
 
 use bathbot_macros::command;
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{
-    MessageBuilder,
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher,
-};
+use bathbot_util::{MessageBuilder, i18n::ErrorKey, matcher};
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use plotters::prelude::*;
 use plotters_skia::SkiaBackend;
@@ -15,10 +11,10 @@ use skia_safe::{EncodedImageFormat, surfaces};
 use time::Date;
 use twilight_model::guild::Permissions;
 
-use super::{SnipeGameMode, SnipePlayerStats};
+use super::{SnipeGameMode, SnipePlayerStats, snipe_disabled_notice};
 use crate::{
     Context,
-    commands::osu::require_link,
+    commands::osu::{Theme, require_link},
     core::commands::{CommandOrigin, prefix::Args},
     embeds::{EmbedData, PlayerSnipeStatsEmbed},
     manager::redis::osu::{UserArgs, UserArgsError},
@@ -109,6 +105,10 @@ pub(super) async fn player_stats(
         .or(config.mode)
         .unwrap_or(GameMode::Osu);
 
+    if let Some(content) = snipe_disabled_notice(mode).await {
+        return orig.error(content).await;
+    }
+
     let legacy_scores = match config.score_data {
         Some(score_data) => score_data.is_legacy(),
         None => match orig.guild_id() {
@@ -135,7 +135,7 @@ pub(super) async fn player_stats(
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let report = Report::new(err).wrap_err("Failed to get user");
 
             return Err(report);
@@ -180,13 +180,15 @@ pub(super) async fn player_stats(
             return Ok(());
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
     };
 
-    let graph = match graphs(&history, &player.count_sr_spread, W, H) {
+    let theme = Theme::get(config.graph_theme);
+
+    let graph = match graphs(&history, &player.count_sr_spread, W, H, theme) {
         Ok(graph) => Some(graph),
         Err(err) => {
             warn!(?err, "Failed to create graph");
@@ -204,12 +206,12 @@ pub(super) async fn player_stats(
         match tokio::join!(score_fut, map_fut) {
             (Ok(score), Ok(map)) => Some((score.score, map)),
             (Err(err), _) => {
-                let _ = orig.error(OSU_API_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::OsuApi).await;
 
                 return Err(Report::new(err).wrap_err("Failed to get oldest score"));
             }
             (_, Err(err)) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(Report::new(err).wrap_err("Failed to get map of oldest score"));
             }
@@ -241,6 +243,7 @@ pub fn graphs(
     stars: &BTreeMap<i8, u32>,
     w: u32,
     h: u32,
+    theme: &Theme,
 ) -> Result<Vec<u8>> {
     let mut surface =
         surfaces::raster_n32_premul((w as i32, h as i32)).wrap_err("Failed to create surface")?;
@@ -254,7 +257,7 @@ pub fn graphs(
     {
         let root = SkiaBackend::new(surface.canvas(), w, h).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = theme.background;
         root.fill(&background)
             .wrap_err("failed to fill background")?;
 
@@ -278,7 +281,7 @@ pub fn graphs(
 
             let mut chart = ChartBuilder::on(&left)
                 .margin(9)
-                .caption("National #1 Count History", ("sans-serif", 30, &WHITE))
+                .caption("National #1 Count History", ("sans-serif", 30, &theme.text))
                 .x_label_area_size(20)
                 .y_label_area_size(40)
                 .build_cartesian_2d(Monthly(first..last), min..max + 1)
@@ -290,17 +293,17 @@ pub fn graphs(
                 .disable_x_mesh()
                 .x_labels(8)
                 .x_label_formatter(&|d| format!("{}-{}", d.year(), d.month() as u8))
-                .label_style(("sans-serif", 15, &WHITE))
-                .bold_line_style(WHITE.mix(0.3))
-                .axis_style(RGBColor(7, 18, 14))
-                .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &WHITE))
+                .label_style(("sans-serif", 15, &theme.text))
+                .bold_line_style(theme.grid.mix(0.3))
+                .axis_style(theme.axis)
+                .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &theme.text))
                 .draw()
                 .wrap_err("failed to draw left mesh")?;
 
             // Draw area
             let iter = history.iter().map(|(date, n)| (*date, *n));
-            let area_style = RGBColor(2, 186, 213).mix(0.7).filled();
-            let border_style = style(RGBColor(0, 208, 138)).stroke_width(3);
+            let area_style = theme.area.mix(0.7).filled();
+            let border_style = style(theme.border).stroke_width(3);
             let series = AreaSeries::new(iter, 0, area_style).border_style(border_style);
             chart
                 .draw_series(series)
@@ -325,7 +328,7 @@ pub fn graphs(
             .x_label_area_size(30)
             .y_label_area_size(40)
             .margin_right(15)
-            .caption("Star rating spread", ("sans-serif", 30, &WHITE))
+            .caption("Star rating spread", ("sans-serif", 30, &theme.text))
             .build_cartesian_2d((first..last).into_segmented(), 0..max + 1)
             .wrap_err("failed to build right chart")?;
 
@@ -334,15 +337,15 @@ pub fn graphs(
             .configure_mesh()
             .disable_x_mesh()
             .x_labels(15)
-            .label_style(("sans-serif", 15, &WHITE))
-            .bold_line_style(WHITE.mix(0.3))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 15, &theme.text))
+            .bold_line_style(theme.grid.mix(0.3))
+            .axis_style(theme.axis)
+            .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &theme.text))
             .draw()
             .wrap_err("failed to draw right mesh")?;
 
         // Histogram bars
-        let area_style = RGBColor(2, 186, 213).mix(0.7).filled();
+        let area_style = theme.area.mix(0.7).filled();
 
         let iter = stars
             .iter()
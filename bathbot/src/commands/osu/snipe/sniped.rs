@@ -2,7 +2,7 @@ use std::ops;
 
 use bathbot_macros::command;
 use bathbot_model::SnipedWeek;
-use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE, datetime::DATE_FORMAT, matcher};
+use bathbot_util::{MessageBuilder, datetime::DATE_FORMAT, i18n::ErrorKey, matcher};
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use plotters::{
     coord::{
@@ -18,9 +18,10 @@ use skia_safe::{EncodedImageFormat, surfaces};
 use time::Date;
 use twilight_model::guild::Permissions;
 
-use super::{SnipeGameMode, SnipePlayerSniped};
+use super::{SnipeGameMode, SnipePlayerSniped, snipe_disabled_notice};
 use crate::{
     Context,
+    commands::osu::Theme,
     core::commands::{CommandOrigin, prefix::Args},
     embeds::{EmbedData, SnipedEmbed},
     manager::redis::osu::{UserArgs, UserArgsError},
@@ -94,6 +95,10 @@ pub(super) async fn player_sniped(
     args: SnipePlayerSniped<'_>,
 ) -> Result<()> {
     let (user_id, mode) = user_id_mode!(orig, args);
+    if let Some(content) = snipe_disabled_notice(mode).await {
+        return orig.error(content).await;
+    }
+
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
     let user = match Context::redis().osu_user(user_args).await {
@@ -107,7 +112,7 @@ pub(super) async fn player_sniped(
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -130,7 +135,7 @@ pub(super) async fn player_sniped(
         match tokio::try_join!(sniper_fut, snipee_fut) {
             Ok(tuple) => tuple,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error_tr(ErrorKey::General).await;
 
                 return Err(err.wrap_err("Failed to get sniper or snipee"));
             }
@@ -141,7 +146,9 @@ pub(super) async fn player_sniped(
         return orig.error(content).await;
     };
 
-    let graph = match graphs(username, &mut sniper, &mut snipee, W, H) {
+    let theme = Theme::of_user(orig.user_id()?).await;
+
+    let graph = match graphs(username, &mut sniper, &mut snipee, W, H, theme) {
         Ok(graph_option) => graph_option,
         Err(err) => {
             warn!(?err, "Failed to create graph");
@@ -179,6 +186,7 @@ pub fn graphs(
     snipee: &mut [SnipedWeek],
     w: u32,
     h: u32,
+    theme: &Theme,
 ) -> Result<Option<Vec<u8>>> {
     if sniper.is_empty() && snipee.is_empty() {
         return Ok(None);
@@ -193,17 +201,21 @@ pub fn graphs(
     {
         let root = SkiaBackend::new(surface.canvas(), w, h).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = theme.background;
         root.fill(&background)
             .wrap_err("failed to fill background")?;
 
         match (sniper.is_empty(), snipee.is_empty()) {
-            (false, true) => draw_sniper(&root, name, sniper).wrap_err("failed to draw sniper")?,
-            (true, false) => draw_snipee(&root, name, snipee).wrap_err("failed to draw snipee")?,
+            (false, true) => {
+                draw_sniper(&root, name, sniper, theme).wrap_err("failed to draw sniper")?
+            }
+            (true, false) => {
+                draw_snipee(&root, name, snipee, theme).wrap_err("failed to draw snipee")?
+            }
             (false, false) => {
                 let (left, right) = root.split_horizontally(w / 2);
-                draw_sniper(&left, name, sniper).wrap_err("failed to draw sniper")?;
-                draw_snipee(&right, name, snipee).wrap_err("failed to draw snipee")?
+                draw_sniper(&left, name, sniper, theme).wrap_err("failed to draw sniper")?;
+                draw_snipee(&right, name, snipee, theme).wrap_err("failed to draw snipee")?
             }
             (true, true) => unreachable!(),
         }
@@ -224,6 +236,7 @@ fn draw_sniper<DB: DrawingBackend>(
     root: &DrawingArea<DB, Shift>,
     name: &str,
     sniper: &[SnipedWeek],
+    theme: &Theme,
 ) -> Result<()> {
     let max = sniper[0].players[0].count;
 
@@ -231,14 +244,14 @@ fn draw_sniper<DB: DrawingBackend>(
         .x_label_area_size(30)
         .y_label_area_size(35)
         .margin_right(5)
-        .caption(format!("Sniped by {name}"), ("sans-serif", 25, &WHITE))
+        .caption(format!("Sniped by {name}"), ("sans-serif", 25, &theme.text))
         .build_cartesian_2d(SnipedWeeksCoord::new(sniper).into_segmented(), 0..max + 1)
         .map_err(|e| Report::msg(e.to_string()))
         .wrap_err("Failed to build chart")?;
 
-    draw_mesh(&mut chart)?;
-    draw_histogram_blocks(sniper, &mut chart).wrap_err("Failed to draw histogram blocks")?;
-    draw_legend(&mut chart)?;
+    draw_mesh(&mut chart, theme)?;
+    draw_histogram_blocks(sniper, &mut chart, theme).wrap_err("Failed to draw histogram blocks")?;
+    draw_legend(&mut chart, theme)?;
 
     Ok(())
 }
@@ -247,6 +260,7 @@ fn draw_snipee<DB: DrawingBackend>(
     root: &DrawingArea<DB, Shift>,
     name: &str,
     snipee: &[SnipedWeek],
+    theme: &Theme,
 ) -> Result<()> {
     let max = snipee[0].players[0].count;
 
@@ -254,19 +268,22 @@ fn draw_snipee<DB: DrawingBackend>(
         .x_label_area_size(30)
         .y_label_area_size(35)
         .margin_right(5)
-        .caption(format!("Sniped {name}"), ("sans-serif", 25, &WHITE))
+        .caption(format!("Sniped {name}"), ("sans-serif", 25, &theme.text))
         .build_cartesian_2d(SnipedWeeksCoord::new(snipee).into_segmented(), 0..max + 1)
         .map_err(|e| Report::msg(e.to_string()))
         .wrap_err("Failed to build chart")?;
 
-    draw_mesh(&mut chart)?;
-    draw_histogram_blocks(snipee, &mut chart).wrap_err("Failed to draw histogram blocks")?;
-    draw_legend(&mut chart)?;
+    draw_mesh(&mut chart, theme)?;
+    draw_histogram_blocks(snipee, &mut chart, theme).wrap_err("Failed to draw histogram blocks")?;
+    draw_legend(&mut chart, theme)?;
 
     Ok(())
 }
 
-fn draw_mesh<DB: DrawingBackend>(chart: &mut ChartContext<'_, DB, ContextType<'_>>) -> Result<()> {
+fn draw_mesh<DB: DrawingBackend>(
+    chart: &mut ChartContext<'_, DB, ContextType<'_>>,
+    theme: &Theme,
+) -> Result<()> {
     chart
         .configure_mesh()
         .disable_x_mesh()
@@ -276,10 +293,10 @@ fn draw_mesh<DB: DrawingBackend>(chart: &mut ChartContext<'_, DB, ContextType<'_
             }
             _ => unreachable!(),
         })
-        .label_style(("sans-serif", 15, &WHITE))
-        .bold_line_style(WHITE.mix(0.3))
-        .axis_style(RGBColor(7, 18, 14))
-        .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &WHITE))
+        .label_style(("sans-serif", 15, &theme.text))
+        .bold_line_style(theme.grid.mix(0.3))
+        .axis_style(theme.axis)
+        .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &theme.text))
         .draw()
         .map_err(|e| Report::msg(e.to_string()))
         .wrap_err("Failed to draw mesh")
@@ -288,6 +305,7 @@ fn draw_mesh<DB: DrawingBackend>(chart: &mut ChartContext<'_, DB, ContextType<'_
 fn draw_histogram_blocks<'a, DB: DrawingBackend + 'a>(
     weeks: &'a [SnipedWeek],
     chart: &mut ChartContext<'a, DB, ContextType<'a>>,
+    theme: &Theme,
 ) -> Result<()> {
     for (i, player) in weeks[0].players.iter().enumerate() {
         let count_iter = || {
@@ -301,9 +319,9 @@ fn draw_histogram_blocks<'a, DB: DrawingBackend + 'a>(
             })
         };
 
-        // Draw block
-        let color = HSLColor(i as f64 * 0.1, 0.5, 0.5);
+        let color = theme.categorical(i);
 
+        // Draw block
         let series = Histogram::vertical(chart)
             .data(count_iter())
             .style(color.mix(0.75).filled());
@@ -316,8 +334,6 @@ fn draw_histogram_blocks<'a, DB: DrawingBackend + 'a>(
             .legend(move |(x, y)| Circle::new((x, y), 4, color.filled()));
 
         // Draw border
-        let color = HSLColor(i as f64 * 0.1, 0.5, 0.3);
-
         let series = Histogram::vertical(chart).data(count_iter()).style(color);
 
         chart
@@ -331,14 +347,15 @@ fn draw_histogram_blocks<'a, DB: DrawingBackend + 'a>(
 
 fn draw_legend<'a, DB: DrawingBackend + 'a>(
     chart: &mut ChartContext<'a, DB, ContextType<'_>>,
+    theme: &Theme,
 ) -> Result<()> {
     chart
         .configure_series_labels()
-        .border_style(WHITE.mix(0.6).stroke_width(1))
-        .background_style(RGBColor(7, 23, 17))
+        .border_style(theme.legend_border.mix(0.6).stroke_width(1))
+        .background_style(theme.legend_background)
         .position(SeriesLabelPosition::UpperLeft)
         .legend_area_size(13)
-        .label_font(("sans-serif", 15, FontStyle::Bold, &WHITE))
+        .label_font(("sans-serif", 15, FontStyle::Bold, &theme.text))
         .draw()
         .map_err(|e| Report::msg(e.to_string()))
         .wrap_err("Failed to draw legend")
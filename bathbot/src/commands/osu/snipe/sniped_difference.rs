@@ -1,12 +1,12 @@
 use std::{cmp::Reverse, collections::HashMap};
 
 use bathbot_macros::command;
-use bathbot_util::{IntHasher, MessageBuilder, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{IntHasher, MessageBuilder, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 use time::{Duration, OffsetDateTime};
 
-use super::{SnipeGameMode, SnipePlayerGain, SnipePlayerLoss};
+use super::{SnipeGameMode, SnipePlayerGain, SnipePlayerLoss, snipe_disabled_notice};
 use crate::{
     Context,
     active::{ActiveMessages, impls::SnipeDifferencePagination},
@@ -167,6 +167,10 @@ async fn sniped_diff(
     user_id: UserId,
     mode: GameMode,
 ) -> Result<()> {
+    if let Some(content) = snipe_disabled_notice(mode).await {
+        return orig.error(content).await;
+    }
+
     let owner = orig.user_id()?;
 
     // Request the user
@@ -183,7 +187,7 @@ async fn sniped_diff(
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -216,7 +220,7 @@ async fn sniped_diff(
     let mut scores = match scores_fut.await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("failed to get snipes"));
         }
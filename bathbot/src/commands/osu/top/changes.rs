@@ -0,0 +1,147 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use bathbot_util::{IntHasher, datetime::HowLongAgoDynamic};
+use time::OffsetDateTime;
+
+use crate::manager::redis::TopSnapshot;
+
+/// How a top score moved compared to a previous snapshot of the top scores.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TopMovement {
+    New,
+    Up(usize),
+    Down(usize),
+    Same,
+}
+
+impl Display for TopMovement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::New => f.write_str(" `NEW`"),
+            Self::Up(n) => write!(f, " ▲{n}"),
+            Self::Down(n) => write!(f, " ▼{n}"),
+            Self::Same => Ok(()),
+        }
+    }
+}
+
+/// Movement of each score id in `new` compared to its position in `old`.
+///
+/// The returned movements have the same order as `new`.
+pub fn diff_positions(old: &[u64], new: &[u64]) -> Vec<TopMovement> {
+    let old_positions: HashMap<u64, usize, IntHasher> = old
+        .iter()
+        .enumerate()
+        .map(|(i, score_id)| (*score_id, i))
+        .collect();
+
+    new.iter()
+        .enumerate()
+        .map(|(i, score_id)| match old_positions.get(score_id) {
+            None => TopMovement::New,
+            Some(&j) if j > i => TopMovement::Up(j - i),
+            Some(&j) if j < i => TopMovement::Down(i - j),
+            Some(_) => TopMovement::Same,
+        })
+        .collect()
+}
+
+/// Changes of a user's top scores since they were last listed.
+pub struct TopChanges {
+    movements: HashMap<u64, TopMovement, IntHasher>,
+    new_count: usize,
+    since: OffsetDateTime,
+}
+
+impl TopChanges {
+    pub fn new(snapshot: &TopSnapshot, score_ids: &[u64]) -> Self {
+        let movements: HashMap<_, _, IntHasher> = score_ids
+            .iter()
+            .copied()
+            .zip(diff_positions(&snapshot.score_ids, score_ids))
+            .collect();
+
+        let new_count = movements
+            .values()
+            .filter(|movement| matches!(movement, TopMovement::New))
+            .count();
+
+        Self {
+            movements,
+            new_count,
+            since: snapshot.stored_at,
+        }
+    }
+
+    pub fn movement(&self, score_id: u64) -> TopMovement {
+        self.movements
+            .get(&score_id)
+            .copied()
+            .unwrap_or(TopMovement::Same)
+    }
+
+    /// Line summarizing the changes e.g. "2 new plays since 3 days ago".
+    pub fn summary(&self) -> String {
+        let since = HowLongAgoDynamic::new(&self.since);
+
+        match self.new_count {
+            0 => format!("No new plays since {since}"),
+            1 => format!("1 new play since {since}"),
+            n => format!("{n} new plays since {since}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use TopMovement::{Down, New, Same, Up};
+
+    #[test]
+    fn unchanged_order() {
+        assert_eq!(diff_positions(&[1, 2, 3], &[1, 2, 3]), [Same, Same, Same]);
+    }
+
+    #[test]
+    fn insertion_pushes_scores_down() {
+        assert_eq!(
+            diff_positions(&[1, 2, 3], &[1, 4, 2, 3]),
+            [Same, New, Down(1), Down(1)]
+        );
+    }
+
+    #[test]
+    fn drop_off_moves_scores_up() {
+        assert_eq!(
+            diff_positions(&[1, 2, 3, 4], &[1, 3, 4]),
+            [Same, Up(1), Up(1)]
+        );
+    }
+
+    #[test]
+    fn insertion_and_drop_off() {
+        // 5 and 6 are new plays and 2 dropped off
+        assert_eq!(
+            diff_positions(&[1, 2, 3, 4], &[5, 1, 4, 6, 3]),
+            [New, Down(1), Up(1), New, Down(2)]
+        );
+    }
+
+    #[test]
+    fn empty_snapshot_marks_everything_new() {
+        assert_eq!(diff_positions(&[], &[1, 2]), [New, New]);
+        assert!(diff_positions(&[1, 2], &[]).is_empty());
+    }
+
+    #[test]
+    fn format_markers() {
+        assert_eq!(Up(3).to_string(), " ▲3");
+        assert_eq!(Down(1).to_string(), " ▼1");
+        assert_eq!(New.to_string(), " `NEW`");
+        assert_eq!(Same.to_string(), "");
+    }
+}
@@ -2,10 +2,9 @@ use std::{borrow::Cow, fmt::Write};
 
 use bathbot_macros::{HasName, SlashCommand, command};
 use bathbot_model::{ScoreSlim, command_fields::GameModeOption};
-use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
     CowUtils,
-    constants::GENERAL_ISSUE,
+    i18n::ErrorKey,
     matcher,
     numbers::round,
     osu::ModSelection,
@@ -226,16 +225,10 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
         return orig.error(content).await;
     }
 
-    let legacy_scores = match config.score_data {
-        Some(score_data) => score_data.is_legacy(),
-        None => match orig.guild_id() {
-            Some(guild_id) => Context::guild_config()
-                .peek(guild_id, |config| config.score_data)
-                .await
-                .is_some_and(ScoreData::is_legacy),
-            None => false,
-        },
-    };
+    let legacy_scores = Context::guild_config()
+        .resolve_score_data(orig.guild_id(), None, config.score_data)
+        .await
+        .is_legacy();
 
     // Retrieve the user and their top scores
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
@@ -251,7 +244,7 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user or scores");
 
             return Err(err);
@@ -293,7 +286,7 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
     let mut entries = match process_scores(scores, mods, mode, sort, legacy_scores).await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("failed to modify scores"));
         }
@@ -1,14 +1,14 @@
-use std::{borrow::Cow, cmp::Reverse, fmt::Write, mem};
+use std::{borrow::Cow, cmp::Reverse, collections::HashMap, fmt::Write, mem};
 
 use bathbot_macros::{HasMods, HasName, SlashCommand, command};
 use bathbot_model::{
     command_fields::{GameModeOption, GradeOption},
     embed_builder::SettingsImage,
 };
-use bathbot_psql::model::configs::{GuildConfig, ListSize, ScoreData};
+use bathbot_psql::model::configs::{GuildConfig, ListSize, ScoreData, resolve};
 use bathbot_util::{
     CowUtils,
-    constants::GENERAL_ISSUE,
+    i18n::ErrorKey,
     matcher,
     numbers::round,
     osu::ModSelection,
@@ -16,18 +16,23 @@ use bathbot_util::{
 };
 use eyre::{Report, Result};
 use rand::{Rng, thread_rng};
-use rosu_v2::{
-    prelude::{GameMode, Grade, OsuError, Score},
-    request::UserId,
-};
+use rosu_v2::prelude::{GameMode, Grade, OsuError, Score};
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::{
     guild::Permissions,
     id::{Id, marker::UserMarker},
 };
 
-pub use self::{if_::*, old::*};
-use super::{HasMods, ModsResult, ScoreOrder, map_strains_graph, require_link, user_not_found};
+pub use self::{
+    changes::{TopChanges, TopMovement},
+    if_::*,
+    mods::*,
+    old::*,
+};
+use super::{
+    HasMods, ModsResult, ScoreOrder, Theme, map_strains_graph, require_link, skin_and_user_id,
+    user_not_found,
+};
 use crate::{
     Context,
     active::{
@@ -41,12 +46,17 @@ use crate::{
             ScoreEmbedDataPersonalBest, ScoreEmbedDataWrap,
         },
     },
-    core::commands::{CommandOrigin, prefix::Args},
+    core::{
+        ConvertKey,
+        commands::{CommandOrigin, prefix::Args},
+    },
     manager::redis::osu::{UserArgs, UserArgsError},
     util::{ChannelExt, CheckPermissions, InteractionCommandExt, interaction::InteractionCommand},
 };
 
+mod changes;
 mod if_;
+mod mods;
 mod old;
 
 #[derive(CommandModel, CreateCommand, HasMods, SlashCommand)]
@@ -95,11 +105,20 @@ pub struct Top {
         desc = "Size of the embed",
         help = "Size of the embed.\n\
         `Condensed` shows 10 scores, `Detailed` shows 5, and `Single` shows 1.\n\
-        The default can be set with the `/config` command."
+        The default can be set with the `/config edit` command."
     )]
     size: Option<ListSize>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
+    #[command(min_value = 1, desc = "Specify a page to start on")]
+    page: Option<u32>,
+    #[command(
+        desc = "Show how scores moved since the last time, defaults to true",
+        help = "Mark each score with how its position changed since the top scores \
+        were last listed e.g. `▲3` or `NEW`.\n\
+        The previous order is remembered for a week."
+    )]
+    changes: Option<bool>,
 }
 
 #[derive(Copy, Clone, Default, CommandOption, CreateOption, Eq, PartialEq)]
@@ -137,6 +156,28 @@ pub enum TopScoreOrder {
     Stars,
 }
 
+impl TopScoreOrder {
+    fn value(self) -> &'static str {
+        match self {
+            Self::Acc => "acc",
+            Self::Ar => "ar",
+            Self::Bpm => "bpm",
+            Self::Combo => "combo",
+            Self::Cs => "cs",
+            Self::Date => "date",
+            Self::Hp => "hp",
+            Self::Length => "len",
+            Self::RankedDate => "ranked_date",
+            Self::Misses => "miss",
+            Self::ModsCount => "mods_count",
+            Self::Od => "od",
+            Self::Pp => "pp",
+            Self::Score => "score",
+            Self::Stars => "stars",
+        }
+    }
+}
+
 impl From<ScoreOrder> for TopScoreOrder {
     #[inline]
     fn from(sort_by: ScoreOrder) -> Self {
@@ -158,7 +199,7 @@ impl From<ScoreOrder> for TopScoreOrder {
 
 const TOP_USAGE: &str = "[username] [mods] [acc=number[..number]] \
 [combo=integer[..integer]] [grade=SS/S/A/B/C/D] \
-[sort=acc/combo/date/length/position] [reverse=true/false]";
+[sort=acc/combo/date/length/position] [reverse=true/false] [p<page>]";
 
 #[command]
 #[desc("Display a user's top plays")]
@@ -170,8 +211,9 @@ const TOP_USAGE: &str = "[username] [mods] [acc=number[..number]] \
      - `acc`: single number or two numbers of the form `a..b` e.g. `acc=97.5..98`\n\
      - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
      - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
-     - `sort`: `acc`, `combo`, `date` (= `rb` command), `length`, or `position` (default)\n\
+     - `sort`: `acc`, `combo`, `date`, `length`, or `position` (default)\n\
      - `reverse`: `true` or `false` (default)\n\
+     To start on a specific page, add e.g. `p3` as last argument.\n\
      \n\
      Instead of showing the scores in a list, you can also __show a single score__ by \
      specifying a number right after the command, e.g. `<top2 badewanne3`."
@@ -205,8 +247,9 @@ async fn prefix_top(msg: &Message, args: Args<'_>) -> Result<()> {
     - `acc`: single number or two numbers of the form `a..b` e.g. `acc=97.5..98`\n\
     - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
-    - `sort`: `acc`, `combo`, `date` (= `rbm` command), `length`, or `position` (default)\n\
+    - `sort`: `acc`, `combo`, `date`, `length`, or `position` (default)\n\
     - `reverse`: `true` or `false` (default)\n\
+    To start on a specific page, add e.g. `p3` as last argument.\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<topm2 badewanne3`."
@@ -240,8 +283,9 @@ async fn prefix_topmania(msg: &Message, args: Args<'_>) -> Result<()> {
     - `acc`: single number or two numbers of the form `a..b` e.g. `acc=97.5..98`\n\
     - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
-    - `sort`: `acc`, `combo`, `date` (= `rbt` command), `length`, or `position` (default)\n\
+    - `sort`: `acc`, `combo`, `date`, `length`, or `position` (default)\n\
     - `reverse`: `true` or `false` (default)\n\
+    To start on a specific page, add e.g. `p3` as last argument.\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<topt2 badewanne3`."
@@ -275,8 +319,9 @@ async fn prefix_toptaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     - `acc`: single number or two numbers of the form `a..b` e.g. `acc=97.5..98`\n\
     - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
-    - `sort`: `acc`, `combo`, `date` (= `rbc` command), `length`, or `position` (default)\n\
+    - `sort`: `acc`, `combo`, `date`, `length`, or `position` (default)\n\
     - `reverse`: `true` or `false` (default)\n\
+    To start on a specific page, add e.g. `p3` as last argument.\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<topc2 badewanne3`."
@@ -300,168 +345,6 @@ async fn prefix_topctb(msg: &Message, args: Args<'_>) -> Result<()> {
     }
 }
 
-const RB_USAGE: &str = "[username] [mods] [acc=number[..number]] \
-[combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false]";
-
-#[command]
-#[desc("Sort a user's top plays by date")]
-#[help(
-    "Display a user's most recent top plays.\n\
-    Mods can be specified through the usual `+_`, `+_!`, `-_!` syntax.\n\
-    There are also multiple options you can set by specifying `key=value`.\n\
-    These are the keys with their values:\n\
-    - `acc`: single number or two numbers of the form `a..b` e.g. `acc=97.5..98`\n\
-    - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
-    - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
-    - `reverse`: `true` or `false` (default)\n\
-    \n\
-    Instead of showing the scores in a list, you can also __show a single score__ by \
-    specifying a number right after the command, e.g. `<rb2 badewanne3`."
-)]
-#[usage(RB_USAGE)]
-#[examples(
-    "badewanne3 acc=97.34..99.5 grade=A +hdhr",
-    "vaxei -dt! combo=1234",
-    "peppy combo=200..500 grade=B reverse=true"
-)]
-#[alias(
-    "rb",
-    "rbo",
-    "rbs",
-    "recentbestosu",
-    "recentbeststd",
-    "recentbeststandard"
-)]
-#[group(Osu)]
-async fn prefix_recentbest(msg: &Message, args: Args<'_>) -> Result<()> {
-    match TopArgs::args(None, args) {
-        Ok(mut args) => {
-            args.sort_by = TopScoreOrder::Date;
-
-            top(msg.into(), args).await
-        }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
-    }
-}
-
-#[command]
-#[desc("Sort a user's top mania plays by date")]
-#[help(
-    "Display a user's most recent top mania plays.\n\
-    Mods can be specified through the usual `+_`, `+_!`, `-_!` syntax.\n\
-    There are also multiple options you can set by specifying `key=value`.\n\
-    These are the keys with their values:\n\
-    - `acc`: single number or two numbers of the form `a..b` e.g. `acc=97.5..98`\n\
-    - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
-    - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
-    - `reverse`: `true` or `false` (default)\n\
-    \n\
-    Instead of showing the scores in a list, you can also __show a single score__ by \
-    specifying a number right after the command, e.g. `<rbm2 badewanne3`."
-)]
-#[usage(RB_USAGE)]
-#[examples(
-    "badewanne3 acc=97.34..99.5 grade=A +hdhr",
-    "vaxei -dt! combo=1234",
-    "peppy combo=200..500 grade=B reverse=true"
-)]
-#[alias("rbm")]
-#[group(Mania)]
-async fn prefix_recentbestmania(msg: &Message, args: Args<'_>) -> Result<()> {
-    match TopArgs::args(Some(GameMode::Mania), args) {
-        Ok(mut args) => {
-            args.sort_by = TopScoreOrder::Date;
-
-            top(msg.into(), args).await
-        }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
-    }
-}
-
-#[command]
-#[desc("Sort a user's top taiko plays by date")]
-#[help(
-    "Display a user's most recent top taiko plays.\n\
-    Mods can be specified through the usual `+_`, `+_!`, `-_!` syntax.\n\
-    There are also multiple options you can set by specifying `key=value`.\n\
-    These are the keys with their values:\n\
-    - `acc`: single number or two numbers of the form `a..b` e.g. `acc=97.5..98`\n\
-    - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
-    - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
-    - `reverse`: `true` or `false` (default)\n\
-    \n\
-    Instead of showing the scores in a list, you can also __show a single score__ by \
-    specifying a number right after the command, e.g. `<rbt2 badewanne3`."
-)]
-#[usage(RB_USAGE)]
-#[examples(
-    "badewanne3 acc=97.34..99.5 grade=A +hdhr",
-    "vaxei -dt! combo=1234",
-    "peppy combo=200..500 grade=B reverse=true"
-)]
-#[alias("rbt")]
-#[group(Taiko)]
-async fn prefix_recentbesttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
-    match TopArgs::args(Some(GameMode::Taiko), args) {
-        Ok(mut args) => {
-            args.sort_by = TopScoreOrder::Date;
-
-            top(msg.into(), args).await
-        }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
-    }
-}
-
-#[command]
-#[desc("Sort a user's top ctb plays by date")]
-#[help(
-    "Display a user's most recent top ctb plays.\n\
-    Mods can be specified through the usual `+_`, `+_!`, `-_!` syntax.\n\
-    There are also multiple options you can set by specifying `key=value`.\n\
-    These are the keys with their values:\n\
-    - `acc`: single number or two numbers of the form `a..b` e.g. `acc=97.5..98`\n\
-    - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
-    - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
-    - `reverse`: `true` or `false` (default)\n\
-    \n\
-    Instead of showing the scores in a list, you can also __show a single score__ by \
-    specifying a number right after the command, e.g. `<rbc2 badewanne3`."
-)]
-#[usage(RB_USAGE)]
-#[examples(
-    "badewanne3 acc=97.34..99.5 grade=A +hdhr",
-    "vaxei -dt! combo=1234",
-    "peppy combo=200..500 grade=B reverse=true"
-)]
-#[alias("rbc")]
-#[group(Catch)]
-async fn prefix_recentbestctb(msg: &Message, args: Args<'_>) -> Result<()> {
-    match TopArgs::args(Some(GameMode::Catch), args) {
-        Ok(mut args) => {
-            args.sort_by = TopScoreOrder::Date;
-
-            top(msg.into(), args).await
-        }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
-    }
-}
-
 async fn slash_top(mut command: InteractionCommand) -> Result<()> {
     let args = Top::from_interaction(command.input_data())?;
 
@@ -493,6 +376,8 @@ pub struct TopArgs<'a> {
     pub query: Option<String>,
     pub size: Option<ListSize>,
     pub score_data: Option<ScoreData>,
+    pub page: Option<usize>,
+    pub changes: bool,
     pub has_dash_r: bool,
     pub has_dash_p_or_i: bool,
 }
@@ -524,16 +409,29 @@ impl<'m> TopArgs<'m> {
         let mut has_dash_p_or_i = None;
         let num = args.num;
 
-        for arg in args.map(|arg| arg.cow_to_ascii_lowercase()) {
+        let mut args: Vec<_> = args.into_args().collect();
+
+        // A trailing `p<number>` specifies the page to start on
+        let page = args.last().and_then(|arg| matcher::get_page(&arg.value));
+
+        if page.is_some() {
+            args.pop();
+        }
+
+        for arg in args {
+            let key_value = arg.key_value();
+            let arg = arg.into_lowercase();
+
             if arg.as_ref() == "-r" {
                 has_dash_r = Some(true);
             } else if matches!(arg.as_ref(), "-p" | "-i") {
                 has_dash_p_or_i = Some(true);
-            } else if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
-                let key = &arg[..idx];
-                let value = arg[idx + 1..].trim_end();
+            } else if let Some((key, value)) = key_value {
+                let key = key.cow_to_ascii_lowercase();
+                let value = value.cow_to_ascii_lowercase();
+                let value = value.trim_end();
 
-                match key {
+                match key.as_ref() {
                     "acc" | "accuracy" | "a" => match value.find("..") {
                         Some(idx) => {
                             let bot = &value[..idx];
@@ -613,8 +511,9 @@ impl<'m> TopArgs<'m> {
                             return Err(content.into());
                         }
                     },
-                    "mods" => match matcher::get_mods(value) {
-                        Some(mods_) => mods = Some(mods_),
+                    "mods" => match matcher::try_get_mods(value) {
+                        Some(Ok(mods_)) => mods = Some(mods_),
+                        Some(Err(content)) => return Err(content.into()),
                         None => return Err(Self::ERR_PARSE_MODS.into()),
                     },
                     "reverse" | "r" => match value {
@@ -636,8 +535,8 @@ impl<'m> TopArgs<'m> {
                         return Err(content.into());
                     }
                 }
-            } else if let Some(mods_) = matcher::get_mods(arg.as_ref()) {
-                mods = Some(mods_);
+            } else if let Some(mods_) = matcher::try_get_mods(arg.as_ref()) {
+                mods = Some(mods_.map_err(Cow::Owned)?);
             } else {
                 match matcher::get_mention_user(arg.as_ref()) {
                     Some(id) => discord = Some(id),
@@ -663,6 +562,8 @@ impl<'m> TopArgs<'m> {
             query: None,
             size: None,
             score_data: None,
+            page,
+            changes: true,
             has_dash_r: has_dash_r.unwrap_or(false),
             has_dash_p_or_i: has_dash_p_or_i.unwrap_or(false),
         };
@@ -672,13 +573,21 @@ impl<'m> TopArgs<'m> {
 }
 
 impl TryFrom<Top> for TopArgs<'static> {
-    type Error = &'static str;
+    type Error = Cow<'static, str>;
 
     fn try_from(args: Top) -> Result<Self, Self::Error> {
         let mods = match args.mods() {
             ModsResult::Mods(mods) => Some(mods),
             ModsResult::None => None,
-            ModsResult::Invalid => return Err(Self::ERR_PARSE_MODS),
+            ModsResult::Invalid => {
+                let content = args
+                    .mods
+                    .as_deref()
+                    .and_then(ModSelection::unknown_mods_message)
+                    .map_or(Cow::Borrowed(Self::ERR_PARSE_MODS), Cow::Owned);
+
+                return Err(content);
+            }
         };
 
         Ok(Self {
@@ -698,25 +607,90 @@ impl TryFrom<Top> for TopArgs<'static> {
             query: args.query,
             size: args.size,
             score_data: args.score_data,
+            page: args.page.map(|page| page as usize),
+            changes: args.changes.unwrap_or(true),
             has_dash_r: false,
             has_dash_p_or_i: false,
         })
     }
 }
 
+impl TopArgs<'_> {
+    /// Slash command invocation that reproduces the listed scores.
+    fn invocation(&self, username: &str, mode: GameMode, condensed_list: bool) -> String {
+        let mode = GameModeOption::from(mode).value();
+        let mut command = format!("/top name:{username} mode:{mode}");
+
+        if self.sort_by != TopScoreOrder::Pp {
+            let _ = write!(command, " sort:{}", self.sort_by.value());
+        }
+
+        if let Some(ref mods) = self.mods {
+            let _ = match mods {
+                ModSelection::Include(mods) => write!(command, " mods:+{mods}"),
+                ModSelection::Exact(mods) => write!(command, " mods:+{mods}!"),
+                ModSelection::Exclude { mods, nomod: true } => {
+                    write!(command, " mods:-NM{mods}!")
+                }
+                ModSelection::Exclude { mods, nomod: false } => write!(command, " mods:-{mods}!"),
+            };
+        }
+
+        if let Some(grade) = self.grade {
+            let grade = match grade {
+                Grade::XH | Grade::X => "ss",
+                Grade::SH | Grade::S => "s",
+                Grade::A => "a",
+                Grade::B => "b",
+                Grade::C => "c",
+                Grade::D => "d",
+                Grade::F => "f",
+            };
+
+            let _ = write!(command, " grade:{grade}");
+        }
+
+        if let Some(ref query) = self.query {
+            let _ = write!(command, " query:{query}");
+        }
+
+        if let Some(perfect_combo) = self.perfect_combo {
+            let _ = write!(command, " perfect_combo:{perfect_combo}");
+        }
+
+        if self.reverse {
+            command.push_str(" reverse:true");
+        }
+
+        if !self.changes {
+            command.push_str(" changes:false");
+        }
+
+        let size = if condensed_list {
+            "condensed"
+        } else {
+            "detailed"
+        };
+
+        let _ = write!(command, " size:{size}");
+
+        command
+    }
+}
+
 pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()> {
     let msg_owner = orig.user_id()?;
 
     let mut config = match Context::user_config().with_osu_id(msg_owner).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
     };
 
-    let mode = args.mode.or(config.mode).unwrap_or(GameMode::Osu);
+    let mode = resolve::mode(args.mode, config.mode);
 
     if args.sort_by == TopScoreOrder::Pp && args.has_dash_r {
         let mode_long = mode_long(mode);
@@ -730,36 +704,32 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
         };
 
         let content = format!(
-            "`{prefix}top{mode_long} -r`? I think you meant `{prefix}recentbest{mode_long}` \
-            or `{prefix}rb{mode_short}` for short ;)",
+            "`{prefix}top{mode_long} -r`? I think you meant `{prefix}top{mode_long} sort=date`, \
+            or `{prefix}rb{mode_short}` for the best play of the last days ;)",
         );
 
         return orig.error(content).await;
     } else if args.has_dash_p_or_i {
-        let cmd = match args.sort_by {
-            TopScoreOrder::Date => "rb",
-            TopScoreOrder::Pp => "top",
-            _ => unreachable!(),
-        };
-
         let mode_long = mode_long(mode);
         let prefix = Context::guild_config().first_prefix(orig.guild_id()).await;
 
         let content = format!(
-            "`{prefix}{cmd}{mode_long} -i / -p`? \
+            "`{prefix}top{mode_long} -i / -p`? \
             Try putting the number right after the command, e.g. \
-            `{prefix}{cmd}{mode_long}42`, or use the pagination buttons.",
+            `{prefix}top{mode_long}42`, or use the pagination buttons.",
         );
 
         return orig.error(content).await;
     }
 
-    let user_id = match user_id!(orig, args) {
-        Some(user_id) => user_id,
-        None => match config.osu.take() {
-            Some(user_id) => UserId::Id(user_id),
-            None => return require_link(&orig).await,
-        },
+    // Before the linked user id is taken out of the config
+    let linked_user_id = config.osu;
+
+    let user_id = user_id!(orig, args);
+    let (skin, user_id) = skin_and_user_id(&mut config, user_id);
+
+    let Some(user_id) = user_id else {
+        return require_link(&orig).await;
     };
 
     let GuildValues {
@@ -775,11 +745,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
         None => GuildValues::default(),
     };
 
-    let score_data = args
-        .score_data
-        .or(config.score_data)
-        .or(guild_score_data)
-        .unwrap_or_default();
+    let score_data = resolve::score_data(args.score_data, config.score_data, guild_score_data);
 
     let legacy_scores = score_data.is_legacy();
 
@@ -797,20 +763,31 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user or scores");
 
             return Err(err);
         }
     };
 
+    let score_ids: Vec<_> = scores.iter().map(|score| score.score_id).collect();
+    let changes = top_changes(
+        user.user_id.to_native(),
+        mode,
+        legacy_scores,
+        &args,
+        score_ids,
+    )
+    .await;
+
+    // Only for the author's own linked account
+    let osutrack_updated = config.osutrack_auto_update == Some(true)
+        && linked_user_id == Some(user.user_id.to_native())
+        && Context::osutrack_auto_update(user.user_id.to_native(), mode).await;
+
     let settings = config.score_embed.unwrap_or_default();
 
-    let mut with_render = match (guild_render_button, config.render_button) {
-        (None | Some(true), None) => true,
-        (None | Some(true), Some(with_render)) => with_render,
-        (Some(false), _) => false,
-    };
+    let mut with_render = resolve::render_button(config.render_button, guild_render_button);
 
     with_render &= settings.buttons.render
         && mode == GameMode::Osu
@@ -822,7 +799,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
     let entries = match process_scores(scores, &args, with_render, score_data).await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("failed to process scores"));
         }
@@ -861,11 +838,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
     let entries = entries.into_boxed_slice();
     let content = write_content(username, &args, entries.len(), index);
 
-    let list_size = args
-        .size
-        .or(config.list_size)
-        .or(guild_list_size)
-        .unwrap_or_default();
+    let list_size = resolve::list_size(args.size, config.list_size, guild_list_size);
 
     let condensed_list = match (single_idx, list_size) {
         (Some(_), _) | (None, ListSize::Single) => {
@@ -885,6 +858,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
                         entry.map.cover(),
                         SingleScorePagination::IMAGE_W,
                         SingleScorePagination::IMAGE_H,
+                        Theme::get(config.graph_theme),
                     );
 
                     match fut.await {
@@ -903,6 +877,9 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
                 &user, entries, settings, score_data, msg_owner, content,
             );
 
+            pagination.set_timestamp_style(config.timestamp_style);
+            pagination.set_skin(skin);
+
             if let Some(idx) = single_idx {
                 pagination.set_index(idx);
             }
@@ -917,6 +894,18 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
         (None, ListSize::Detailed) => false,
     };
 
+    let command = args.invocation(username, mode, condensed_list);
+
+    let mut content = content.unwrap_or_default();
+
+    if let Some(ref changes) = changes {
+        if !content.is_empty() {
+            content.push('\n');
+        }
+
+        content.push_str(&changes.summary());
+    }
+
     let pagination = TopPagination::builder()
         .user(user)
         .mode(mode)
@@ -924,8 +913,12 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
         .sort_by(args.sort_by)
         .condensed_list(condensed_list)
         .score_data(score_data)
-        .content(content.unwrap_or_default().into_boxed_str())
+        .content(content.into_boxed_str())
+        .changes(changes)
         .msg_owner(msg_owner)
+        .command(Some(command))
+        .osutrack_updated(osutrack_updated)
+        .start_page(args.page)
         .build();
 
     ActiveMessages::builder(pagination)
@@ -934,6 +927,43 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
         .await
 }
 
+/// Compare the top scores with the previously stored order and store the
+/// current order in the background.
+async fn top_changes(
+    user_id: u32,
+    mode: GameMode,
+    legacy_scores: bool,
+    args: &TopArgs<'_>,
+    score_ids: Vec<u64>,
+) -> Option<TopChanges> {
+    let changes = if args.changes {
+        match Context::redis()
+            .top_snapshot(user_id, mode, legacy_scores)
+            .await
+        {
+            Ok(snapshot) => snapshot.map(|snapshot| TopChanges::new(&snapshot, &score_ids)),
+            Err(err) => {
+                warn!(?err, "Failed to get top snapshot");
+
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    tokio::spawn(async move {
+        let store_fut =
+            Context::redis().store_top_snapshot(user_id, mode, legacy_scores, score_ids);
+
+        if let Err(err) = store_fut.await {
+            warn!(?err, "Failed to store top snapshot");
+        }
+    });
+
+    changes
+}
+
 async fn process_scores(
     scores: Vec<Score>,
     args: &TopArgs<'_>,
@@ -987,24 +1017,73 @@ async fn process_scores(
 
     let mut maps = Context::osu_map().maps(&maps_id_checksum).await?;
 
-    for (i, score) in scores.into_iter().enumerate() {
-        let Some(mut map) = maps.remove(&score.map_id) else {
-            continue;
-        };
+    // Converts are recalculated for their mode so their values are stored
+    // for when their star ratings are relevant
+    let use_convert_values = args.sort_by == TopScoreOrder::Stars
+        || filter_criteria
+            .as_ref()
+            .is_some_and(|criteria| !criteria.stars.is_empty());
+
+    let scores: Vec<_> = scores
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, score)| {
+            let map = maps.remove(&score.map_id)?.convert(score.mode);
+            let convert_key = use_convert_values
+                .then(|| ConvertKey::new(&map, score.mode, &score.mods, score.set_on_lazer))
+                .flatten();
+
+            Some((i, score, map, convert_key))
+        })
+        .collect();
 
-        map = map.convert(score.mode);
+    let convert_keys: Vec<_> = scores
+        .iter()
+        .filter_map(|(.., convert_key)| convert_key.clone())
+        .collect();
 
+    let stored_values = if convert_keys.is_empty() {
+        HashMap::new()
+    } else {
+        Context::convert_values(&convert_keys).await
+    };
+
+    let mut calculated_values = Vec::new();
+
+    for (i, score, map, convert_key) in scores {
         let pb_idx = Some(ScoreEmbedDataPersonalBest::from_index(i));
 
-        let half = ScoreEmbedDataHalf::new(
-            score,
-            map,
-            pb_idx,
-            legacy_scores,
-            with_render,
-            MissAnalyzerCheck::without(),
-        )
-        .await;
+        let half = match convert_key {
+            Some(key) => {
+                let (half, calculated) = ScoreEmbedDataHalf::with_convert_values(
+                    score,
+                    map,
+                    pb_idx,
+                    legacy_scores,
+                    with_render,
+                    MissAnalyzerCheck::without(),
+                    stored_values.get(&key).copied(),
+                )
+                .await;
+
+                if let Some(values) = calculated {
+                    calculated_values.push((key, values));
+                }
+
+                half
+            }
+            None => {
+                ScoreEmbedDataHalf::new(
+                    score,
+                    map,
+                    pb_idx,
+                    legacy_scores,
+                    with_render,
+                    MissAnalyzerCheck::without(),
+                )
+                .await
+            }
+        };
 
         if let Some(ref criteria) = filter_criteria {
             if half.matches(criteria) {
@@ -1015,6 +1094,17 @@ async fn process_scores(
         }
     }
 
+    if !calculated_values.is_empty() {
+        info!(
+            recalculated = calculated_values.len(),
+            "Recalculated values of converts"
+        );
+
+        if let Err(err) = Context::store_convert_values(&calculated_values).await {
+            warn!(?err, "Failed to store convert values");
+        }
+    }
+
     if let Some(perfect_combo) = args.perfect_combo {
         entries.retain(|entry| {
             perfect_combo == (entry.get_half().max_combo == entry.get_half().score.max_combo)
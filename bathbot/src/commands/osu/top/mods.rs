@@ -0,0 +1,323 @@
+use std::{borrow::Cow, collections::HashMap, fmt::Write};
+
+use bathbot_macros::{HasName, SlashCommand};
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_util::{
+    EmbedBuilder, FooterBuilder, MessageBuilder, constants::OSU_BASE, i18n::ErrorKey,
+    osu::ModSelection,
+};
+use eyre::{Report, Result};
+use rosu_v2::{
+    prelude::{GameMod, GameMode, GameMods, GameModsIntermode, OsuError, Score},
+    request::UserId,
+};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use crate::{
+    Context,
+    commands::{
+        DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
+        osu::{require_link, user_not_found},
+    },
+    core::commands::CommandOrigin,
+    manager::redis::osu::{UserArgs, UserArgsError},
+    util::{CachedUserExt, InteractionCommandExt, interaction::InteractionCommand},
+};
+
+/// Maximum amount of mod combinations shown in the embed.
+const SHOWN_GROUPS: usize = 15;
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "topmods",
+    desc = "Break down the user's top200 by mod combination",
+    help = "Group the user's top200 by their mod combination and show for each combination \
+    how many scores it has, how much weighted pp it contributes, its average accuracy, \
+    and its best play.\n\
+    By default, `NC` counts as `DT` and `PF` counts as `SD`. \
+    Use the `strict` option to keep them apart."
+)]
+pub struct TopMods<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Choose how the mod combinations should be ordered",
+        help = "Choose how the mod combinations should be ordered, defaults to `count`."
+    )]
+    sort: Option<TopModsOrder>,
+    #[command(desc = "Whether NC and DT or PF and SD should be kept apart")]
+    strict: Option<bool>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+}
+
+#[derive(Copy, Clone, Default, CommandOption, CreateOption, Eq, PartialEq)]
+pub enum TopModsOrder {
+    #[default]
+    #[option(name = "Count", value = "count")]
+    Count,
+    #[option(name = "Weighted PP", value = "weighted_pp")]
+    WeightedPp,
+}
+
+async fn slash_topmods(mut command: InteractionCommand) -> Result<()> {
+    let args = TopMods::from_interaction(command.input_data())?;
+
+    topmods((&mut command).into(), args).await
+}
+
+async fn topmods(orig: CommandOrigin<'_>, args: TopMods<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+    let config = Context::user_config().with_osu_id(owner).await?;
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match config.osu {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
+    let mode = args
+        .mode
+        .map(GameMode::from)
+        .or(config.mode)
+        .unwrap_or(GameMode::Osu);
+
+    let legacy_scores = Context::guild_config()
+        .resolve_score_data(orig.guild_id(), None, config.score_data)
+        .await
+        .is_legacy();
+
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+    let scores_fut = Context::osu_scores()
+        .top(200, legacy_scores)
+        .exec_with_user(user_args);
+
+    let (user, scores) = match scores_fut.await {
+        Ok((user, scores)) => (user, scores),
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error_tr(ErrorKey::General).await;
+            let err = Report::new(err).wrap_err("Failed to get user or scores");
+
+            return Err(err);
+        }
+    };
+
+    let strict = args.strict.unwrap_or(false);
+    let mut groups = ModsGroup::from_scores(&scores, strict);
+    ModsGroup::sort(&mut groups, args.sort.unwrap_or_default());
+
+    let mut description = String::with_capacity(groups.len().min(SHOWN_GROUPS) * 80);
+
+    if groups.is_empty() {
+        description.push_str("No scores found");
+    } else {
+        let mods_len = groups
+            .iter()
+            .take(SHOWN_GROUPS)
+            .map(|group| group.mods_str().len())
+            .max()
+            .unwrap_or(0);
+
+        for group in groups.iter().take(SHOWN_GROUPS) {
+            let _ = writeln!(
+                description,
+                "`{mods:<mods_len$}` `{count:>3}x` • `{pp:>7.2}pp` • `{acc:>6.2}%` • \
+                [Best play]({OSU_BASE}scores/{score_id})",
+                mods = group.mods_str(),
+                count = group.count,
+                pp = group.weighted_pp,
+                acc = group.avg_acc(),
+                score_id = scores[group.best_idx].id,
+            );
+        }
+
+        if groups.len() > SHOWN_GROUPS {
+            let _ = write!(description, "*…and {} more*", groups.len() - SHOWN_GROUPS);
+        }
+    }
+
+    let footer = if strict {
+        "Strict mod combinations"
+    } else {
+        "NC counts as DT, PF counts as SD"
+    };
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder(false))
+        .description(description)
+        .footer(FooterBuilder::new(footer))
+        .thumbnail(user.avatar_url.as_ref().to_owned())
+        .title(format!(
+            "Mod combinations in the top{} of {}",
+            scores.len(),
+            user.username.as_str()
+        ));
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// Aggregated stats of all top scores with the same mod combination.
+struct ModsGroup {
+    mods: GameModsIntermode,
+    count: usize,
+    weighted_pp: f32,
+    acc_sum: f32,
+    best_pp: f32,
+    /// Index of the group's best score within the top scores
+    best_idx: usize,
+}
+
+impl ModsGroup {
+    fn from_scores(scores: &[Score], strict: bool) -> Vec<Self> {
+        let entries = scores.iter().zip(0..).map(|(score, i)| {
+            let pp = score.pp.unwrap_or(0.0);
+            let weighted_pp = score
+                .weight
+                .as_ref()
+                .map_or_else(|| pp * 0.95_f32.powi(i), |weight| weight.pp);
+
+            (&score.mods, pp, weighted_pp, score.accuracy)
+        });
+
+        Self::group(entries, strict)
+    }
+
+    /// Group the entries by their mods. Entries are given as their mods, pp,
+    /// weighted pp, and accuracy.
+    fn group<'m>(
+        entries: impl Iterator<Item = (&'m GameMods, f32, f32, f32)>,
+        strict: bool,
+    ) -> Vec<Self> {
+        let mut groups = HashMap::<GameModsIntermode, Self>::new();
+
+        for (idx, (mods, pp, weighted_pp, acc)) in entries.enumerate() {
+            let mods = if strict {
+                mods.iter().map(GameMod::intermode).collect()
+            } else {
+                ModSelection::merge_equivalent(mods)
+            };
+
+            let group = groups.entry(mods).or_insert_with_key(|mods| Self {
+                mods: mods.clone(),
+                count: 0,
+                weighted_pp: 0.0,
+                acc_sum: 0.0,
+                best_pp: pp,
+                best_idx: idx,
+            });
+
+            group.count += 1;
+            group.weighted_pp += weighted_pp;
+            group.acc_sum += acc;
+
+            if pp > group.best_pp {
+                group.best_pp = pp;
+                group.best_idx = idx;
+            }
+        }
+
+        groups.into_values().collect()
+    }
+
+    fn sort(groups: &mut [Self], order: TopModsOrder) {
+        match order {
+            TopModsOrder::Count => groups.sort_unstable_by(|a, b| {
+                b.count
+                    .cmp(&a.count)
+                    .then_with(|| b.weighted_pp.total_cmp(&a.weighted_pp))
+            }),
+            TopModsOrder::WeightedPp => {
+                groups.sort_unstable_by(|a, b| b.weighted_pp.total_cmp(&a.weighted_pp))
+            }
+        }
+    }
+
+    fn mods_str(&self) -> String {
+        if self.mods.is_empty() {
+            "NM".to_owned()
+        } else {
+            self.mods.to_string()
+        }
+    }
+
+    fn avg_acc(&self) -> f32 {
+        self.acc_sum / self.count as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mods(acronyms: &[&str]) -> GameMods {
+        acronyms
+            .iter()
+            .map(|acronym| GameMod::new(acronym, GameMode::Osu))
+            .collect()
+    }
+
+    fn groups(strict: bool) -> Vec<ModsGroup> {
+        let entries = [
+            (mods(&["HD", "DT"]), 500.0, 500.0, 98.0),
+            (mods(&["HD", "NC"]), 480.0, 456.0, 96.0),
+            (mods(&["HR", "PF"]), 450.0, 406.125, 100.0),
+            (mods(&["HR", "SD"]), 400.0, 342.95, 99.0),
+            (mods(&[]), 380.0, 309.51, 97.0),
+        ];
+
+        let iter = entries
+            .iter()
+            .map(|(mods, pp, weighted_pp, acc)| (mods, *pp, *weighted_pp, *acc));
+
+        let mut groups = ModsGroup::group(iter, strict);
+        ModsGroup::sort(&mut groups, TopModsOrder::WeightedPp);
+
+        groups
+    }
+
+    fn assert_mods(groups: &[ModsGroup], expected: &[&str]) {
+        let expected: Vec<_> = expected
+            .iter()
+            .map(|acronyms| GameModsIntermode::try_from_acronyms(acronyms).unwrap_or_default())
+            .collect();
+
+        let mods: Vec<_> = groups.iter().map(|group| group.mods.clone()).collect();
+        assert_eq!(mods, expected);
+    }
+
+    #[test]
+    fn group_merged() {
+        let groups = groups(false);
+
+        assert_mods(&groups, &["HDDT", "HRSD", ""]);
+
+        let counts: Vec<_> = groups.iter().map(|group| group.count).collect();
+        assert_eq!(counts, [2, 2, 1]);
+
+        assert_eq!(groups[0].best_idx, 0);
+        assert_eq!(groups[1].best_idx, 2);
+        assert!((groups[0].avg_acc() - 97.0).abs() < f32::EPSILON);
+        assert!((groups[1].weighted_pp - 749.075).abs() < 0.001);
+    }
+
+    #[test]
+    fn group_strict() {
+        let groups = groups(true);
+
+        assert_mods(&groups, &["HDDT", "HDNC", "HRPF", "HRSD", ""]);
+        assert!(groups.iter().all(|group| group.count == 1));
+    }
+}
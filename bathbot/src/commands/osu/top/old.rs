@@ -1,10 +1,9 @@
-use std::{borrow::Cow, cmp::Ordering};
+use std::{borrow::Cow, cmp::Ordering, fmt::Write};
 
 use bathbot_macros::{HasMods, HasName, SlashCommand, command};
 use bathbot_model::ScoreSlim;
-use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
-    constants::GENERAL_ISSUE,
+    i18n::ErrorKey,
     matcher,
     numbers::round,
     osu::ModSelection,
@@ -147,33 +146,49 @@ impl TryFrom<i32> for TopOldOsuVersion {
     type Error = &'static str;
 
     fn try_from(year: i32) -> Result<Self, Self::Error> {
-        match year {
-            2007..=2011 | 7..=11 => {
-                Err("Up until april 2012, ranked score was the skill metric.\n\
-                The first available pp system is from 2014.")
-            }
-            2012..=2013 | 12..=13 => Err(
+        match full_year(year) {
+            2007..=2011 => Err("Up until april 2012, ranked score was the skill metric.\n\
+                The first available pp system is from 2014."),
+            2012..=2013 => Err(
                 "April 2012 till january 2014 the ppv1 system was in place, \
                 which is unfortunately impossible to implement nowadays \
                 because of lacking data \\:(\n\
                 The first available pp system is from 2014.",
             ),
-            2014 | 14 => Ok(Self::May14July14),
-            2015 | 15 => Ok(Self::February15April15),
-            2016..=2017 | 16..=17 => Ok(Self::April15May18),
-            2018 | 18 => Ok(Self::May18February19),
-            2019..=2020 | 19..=20 => Ok(Self::February19January21),
-            2021 | 21 => Ok(Self::July21November21),
-            2022 | 22 => Ok(Self::November21September22),
-            2023 | 23 => Ok(Self::September22October24),
-            2024 | 24 => Ok(Self::October24March25),
             i32::MIN..=2006 => Err("osu! was not a thing until september 2007.\n\
                 The first available pp system is from 2014."),
-            _ => Ok(Self::March25Now),
+            year => Ok(from_year(year)),
         }
     }
 }
 
+impl YearVersion for TopOldOsuVersion {
+    const YEARS: &'static [(i32, Self, &'static str)] = &[
+        (2014, Self::May14July14, "May 2014 - July 2014"),
+        (2015, Self::February15April15, "February 2015 - April 2015"),
+        (2016, Self::April15May18, "April 2015 - May 2018"),
+        (2018, Self::May18February19, "May 2018 - February 2019"),
+        (
+            2019,
+            Self::February19January21,
+            "February 2019 - January 2021",
+        ),
+        (2021, Self::July21November21, "July 2021 - November 2021"),
+        (
+            2022,
+            Self::November21September22,
+            "November 2021 - September 2022",
+        ),
+        (
+            2023,
+            Self::September22October24,
+            "September 2022 - October 2024",
+        ),
+        (2024, Self::October24March25, "October 2024 - March 2025"),
+        (2025, Self::March25Now, "March 2025 - Now"),
+    ];
+}
+
 #[derive(CommandModel, CreateCommand, HasMods, HasName)]
 #[command(
     name = "taiko",
@@ -245,18 +260,36 @@ impl TryFrom<i32> for TopOldTaikoVersion {
     type Error = &'static str;
 
     fn try_from(year: i32) -> Result<Self, Self::Error> {
-        match year {
-            2014..=2019 | 14..=19 => Ok(Self::March14September20),
-            2020..=2022 | 20..=22 => Ok(Self::September20September22),
-            2023 | 23 => Ok(Self::September22October24),
-            2024 | 24 => Ok(Self::October24March25),
+        match full_year(year) {
             i32::MIN..=2013 => Err("taiko pp were not a thing until march 2014. \
                 I think? Don't quote me on that :^)"),
-            _ => Ok(Self::March25Now),
+            year => Ok(from_year(year)),
         }
     }
 }
 
+impl YearVersion for TopOldTaikoVersion {
+    const YEARS: &'static [(i32, Self, &'static str)] = &[
+        (
+            2014,
+            Self::March14September20,
+            "March 2014 - September 2020",
+        ),
+        (
+            2020,
+            Self::September20September22,
+            "September 2020 - September 2022",
+        ),
+        (
+            2023,
+            Self::September22October24,
+            "September 2022 - October 2024",
+        ),
+        (2024, Self::October24March25, "October 2024 - March 2025"),
+        (2025, Self::March25Now, "March 2025 - Now"),
+    ];
+}
+
 #[derive(CommandModel, CreateCommand, HasMods, HasName)]
 #[command(
     name = "ctb",
@@ -316,16 +349,22 @@ impl TryFrom<i32> for TopOldCatchVersion {
     type Error = &'static str;
 
     fn try_from(year: i32) -> Result<Self, Self::Error> {
-        match year {
-            2014..=2019 | 14..=19 => Ok(Self::March14May20),
-            2023 | 23 => Ok(Self::May20October24),
+        match full_year(year) {
             i32::MIN..=2013 => Err("ctb pp were not a thing until march 2014. \
                 I think? Don't quote me on that :^)"),
-            _ => Ok(Self::October24Now),
+            year => Ok(from_year(year)),
         }
     }
 }
 
+impl YearVersion for TopOldCatchVersion {
+    const YEARS: &'static [(i32, Self, &'static str)] = &[
+        (2014, Self::March14May20, "March 2014 - May 2020"),
+        (2020, Self::May20October24, "May 2020 - October 2024"),
+        (2024, Self::October24Now, "October 2024 - Now"),
+    ];
+}
+
 #[derive(CommandModel, CreateCommand, HasMods, HasName)]
 #[command(
     name = "mania",
@@ -388,17 +427,71 @@ impl TryFrom<i32> for TopOldManiaVersion {
     type Error = &'static str;
 
     fn try_from(year: i32) -> Result<Self, Self::Error> {
-        match year {
-            2014..=2018 | 14..=18 => Ok(Self::March14May18),
-            2019..=2022 | 19..=22 => Ok(Self::May18October22),
-            2023 | 23 => Ok(Self::October22October24),
+        match full_year(year) {
             i32::MIN..=2013 => Err("mania pp were not a thing until march 2014. \
                 I think? Don't quote me on that :^)"),
-            _ => Ok(Self::October24Now),
+            year => Ok(from_year(year)),
         }
     }
 }
 
+impl YearVersion for TopOldManiaVersion {
+    const YEARS: &'static [(i32, Self, &'static str)] = &[
+        (2014, Self::March14May18, "March 2014 - May 2018"),
+        (2019, Self::May18October22, "May 2018 - October 2022"),
+        (
+            2023,
+            Self::October22October24,
+            "October 2022 - October 2024",
+        ),
+        (2024, Self::October24Now, "October 2024 - Now"),
+    ];
+}
+
+/// A pp version that prefix commands select through a year.
+trait YearVersion: Copy + 'static {
+    /// The first year that selects each version alongside the dates it
+    /// covers, in ascending order.
+    const YEARS: &'static [(i32, Self, &'static str)];
+}
+
+/// Two-digit years are read as `20xx`.
+fn full_year(year: i32) -> i32 {
+    if (0..100).contains(&year) {
+        year + 2000
+    } else {
+        year
+    }
+}
+
+/// The latest version whose first year is not after the given year.
+///
+/// Years before the first version fall back to it.
+fn from_year<V: YearVersion>(year: i32) -> V {
+    V::YEARS
+        .iter()
+        .rfind(|(first, ..)| *first <= year)
+        .unwrap_or(&V::YEARS[0])
+        .1
+}
+
+/// Append the years that prefix commands accept to the error.
+fn invalid_year<V: YearVersion>(err: &str) -> String {
+    let mut content = format!("{err}\n\nValid years:");
+
+    for (i, (first, _, dates)) in V::YEARS.iter().enumerate() {
+        let _ = match V::YEARS.get(i + 1).map(|(next, ..)| next - 1) {
+            Some(last) if last > *first => write!(content, "\n- `{first}`-`{last}`: {dates}"),
+            Some(_) => write!(content, "\n- `{first}`: {dates}"),
+            None => write!(content, "\n- `{first}`+: {dates}"),
+        };
+    }
+
+    content.push_str("\nAll versions can be chosen through the slash command.");
+
+    content
+}
+
 pub async fn slash_topold(mut command: InteractionCommand) -> Result<()> {
     let args = TopOld::from_interaction(command.input_data())?;
 
@@ -523,7 +616,7 @@ async fn prefix_topoldctb(msg: &Message, args: Args<'_>) -> Result<()> {
 }
 
 impl<'m> TopOld<'m> {
-    fn args(mode: GameMode, args: Args<'m>) -> Result<Self, &'static str> {
+    fn args(mode: GameMode, args: Args<'m>) -> Result<Self, String> {
         let mut name = None;
         let mut discord = None;
         let mut year = None;
@@ -542,7 +635,8 @@ impl<'m> TopOld<'m> {
 
         let args = match mode {
             GameMode::Osu => {
-                let version = TopOldOsuVersion::try_from(year)?;
+                let version =
+                    TopOldOsuVersion::try_from(year).map_err(invalid_year::<TopOldOsuVersion>)?;
 
                 let osu = TopOldOsu {
                     version,
@@ -557,7 +651,8 @@ impl<'m> TopOld<'m> {
                 Self::Osu(osu)
             }
             GameMode::Taiko => {
-                let version = TopOldTaikoVersion::try_from(year)?;
+                let version = TopOldTaikoVersion::try_from(year)
+                    .map_err(invalid_year::<TopOldTaikoVersion>)?;
 
                 let taiko = TopOldTaiko {
                     version,
@@ -572,7 +667,8 @@ impl<'m> TopOld<'m> {
                 Self::Taiko(taiko)
             }
             GameMode::Catch => {
-                let version = TopOldCatchVersion::try_from(year)?;
+                let version = TopOldCatchVersion::try_from(year)
+                    .map_err(invalid_year::<TopOldCatchVersion>)?;
 
                 let catch = TopOldCatch {
                     version,
@@ -587,7 +683,8 @@ impl<'m> TopOld<'m> {
                 Self::Catch(catch)
             }
             GameMode::Mania => {
-                let version = TopOldManiaVersion::try_from(year)?;
+                let version = TopOldManiaVersion::try_from(year)
+                    .map_err(invalid_year::<TopOldManiaVersion>)?;
 
                 let mania = TopOldMania {
                     version,
@@ -895,8 +992,7 @@ macro_rules! user_id_ref {
                     return $orig.error(content).await;
                 }
                 crate::commands::osu::UserIdFutureResult::Err(err) => {
-                    let content = bathbot_util::constants::GENERAL_ISSUE;
-                    let _ = $orig.error(content).await;
+                    let _ = $orig.error_tr(bathbot_util::i18n::ErrorKey::General).await;
 
                     return Err(err);
                 }
@@ -934,16 +1030,10 @@ async fn topold(orig: CommandOrigin<'_>, args: TopOld<'_>) -> Result<()> {
         },
     };
 
-    let legacy_scores = match config.score_data {
-        Some(score_data) => score_data.is_legacy(),
-        None => match orig.guild_id() {
-            Some(guild_id) => Context::guild_config()
-                .peek(guild_id, |config| config.score_data)
-                .await
-                .is_some_and(ScoreData::is_legacy),
-            None => false,
-        },
-    };
+    let legacy_scores = Context::guild_config()
+        .resolve_score_data(orig.guild_id(), None, config.score_data)
+        .await
+        .is_legacy();
 
     // Retrieve the user and their top scores
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
@@ -959,7 +1049,7 @@ async fn topold(orig: CommandOrigin<'_>, args: TopOld<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user or scores");
 
             return Err(err);
@@ -984,7 +1074,7 @@ async fn topold(orig: CommandOrigin<'_>, args: TopOld<'_>) -> Result<()> {
     let mut entries = match process_scores(scores, &args).await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("failed to process scores"));
         }
@@ -1329,3 +1419,66 @@ impl_from! {
     TopOldCatch: Catch,
     TopOldMania: Mania,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catch_years() {
+        assert_eq!(
+            TopOldCatchVersion::try_from(2019),
+            Ok(TopOldCatchVersion::March14May20)
+        );
+        assert_eq!(
+            TopOldCatchVersion::try_from(21),
+            Ok(TopOldCatchVersion::May20October24)
+        );
+        assert_eq!(
+            TopOldCatchVersion::try_from(2024),
+            Ok(TopOldCatchVersion::October24Now)
+        );
+    }
+
+    #[test]
+    fn years_match_version_ranges() {
+        assert_eq!(
+            TopOldOsuVersion::try_from(17),
+            Ok(TopOldOsuVersion::April15May18)
+        );
+        assert_eq!(
+            TopOldOsuVersion::try_from(2020),
+            Ok(TopOldOsuVersion::February19January21)
+        );
+        assert_eq!(
+            TopOldOsuVersion::try_from(2099),
+            Ok(TopOldOsuVersion::March25Now)
+        );
+        assert!(TopOldOsuVersion::try_from(12).is_err());
+        assert_eq!(
+            TopOldTaikoVersion::try_from(2022),
+            Ok(TopOldTaikoVersion::September20September22)
+        );
+        assert_eq!(
+            TopOldManiaVersion::try_from(23),
+            Ok(TopOldManiaVersion::October22October24)
+        );
+        assert!(TopOldManiaVersion::try_from(2013).is_err());
+    }
+
+    #[test]
+    fn invalid_year_lists_valid_years() {
+        let err = TopOldManiaVersion::try_from(2010).unwrap_err();
+        let content = invalid_year::<TopOldManiaVersion>(err);
+
+        assert!(content.starts_with(err));
+        assert!(content.contains("`2014`-`2018`: March 2014 - May 2018"));
+        assert!(content.contains("`2024`+: October 2024 - Now"));
+
+        let err = TopOldOsuVersion::try_from(2012).unwrap_err();
+        let content = invalid_year::<TopOldOsuVersion>(err);
+        assert!(content.contains("- `2014`: May 2014 - July 2014\n"));
+        assert!(content.contains("- `2016`-`2017`: April 2015 - May 2018\n"));
+        assert!(content.contains("`2025`+: March 2025 - Now"));
+    }
+}
@@ -4,22 +4,25 @@ use bathbot_macros::{HasName, SlashCommand, command};
 use bathbot_model::command_fields::GameModeOption;
 use bathbot_util::{
     MessageBuilder,
-    constants::GENERAL_ISSUE,
+    i18n::ErrorKey,
     matcher,
-    osu::{ExtractablePp, PpListUtil},
+    osu::{
+        ExtractablePp, PlaySuggestion, PpListUtil, is_unranked_pp, requires_blacklisted_mods,
+        suggest_play, total_pp_without_bottom,
+    },
 };
 use eyre::{Report, Result};
-use rosu_v2::prelude::OsuError;
+use rosu_v2::prelude::{GameMode, OsuError};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{Id, marker::UserMarker};
 
-use super::user_not_found;
+use super::{own_blacklisted_mods, top_play_difficulties, user_not_found};
 use crate::{
     Context,
     commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP},
     core::commands::{CommandOrigin, prefix::Args},
     embeds::{EmbedData, WhatIfEmbed},
-    manager::redis::osu::{UserArgs, UserArgsError},
+    manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
     util::{ChannelExt, InteractionCommandExt, interaction::InteractionCommand},
 };
 
@@ -27,7 +30,7 @@ pub enum WhatIfData {
     NonTop200,
     NoScores {
         count: usize,
-        rank: Option<u32>,
+        rank: Option<ProjectedRank>,
     },
     Top200 {
         bonus_pp: f32,
@@ -35,10 +38,26 @@ pub enum WhatIfData {
         new_pp: f32,
         new_pos: usize,
         max_pp: f32,
-        rank: Option<u32>,
+        rank: Option<ProjectedRank>,
+        baseline: Option<ExcludedBaseline>,
+        suggestion: Option<PlaySuggestion>,
     },
 }
 
+/// The user's total pp if their weakest top scores did not exist.
+pub struct ExcludedBaseline {
+    pub excluded: usize,
+    pub pp: f32,
+}
+
+pub struct ProjectedRank {
+    pub global: u32,
+    /// Whether the global rank is only an approximation i.e. it's beyond the
+    /// cached rankings
+    pub approx: bool,
+    pub country: Option<u32>,
+}
+
 impl WhatIfData {
     pub fn count(&self) -> usize {
         match self {
@@ -67,6 +86,21 @@ pub struct WhatIf<'a> {
         desc = "Specify how many times a score should be added, defaults to 1"
     )]
     count: Option<usize>,
+    #[command(
+        min_value = 0,
+        max_value = 50,
+        desc = "Pretend your N lowest top scores don't exist",
+        help = "Pretend your N lowest top scores don't exist.\n\
+        The baseline total pp is recalculated without those scores, bonus pp is kept."
+    )]
+    exclude_top: Option<u8>,
+    #[command(
+        desc = "Suggest the stars and mods of such a play",
+        help = "Suggest the star rating and mods that a play of the required pp \
+        usually has for the user based on their top plays.\n\
+        Requires at least 10 top plays."
+    )]
+    suggest: Option<bool>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
 }
@@ -92,6 +126,8 @@ impl<'m> WhatIf<'m> {
             mode,
             name,
             count: None,
+            exclude_top: None,
+            suggest: None,
             discord,
         })
     }
@@ -190,7 +226,9 @@ async fn slash_whatif(mut command: InteractionCommand) -> Result<()> {
 async fn whatif(orig: CommandOrigin<'_>, args: WhatIf<'_>) -> Result<()> {
     let (user_id, mode) = user_id_mode!(orig, args);
     let count = args.count.unwrap_or(1);
+    let exclude = args.exclude_top.map_or(0, usize::from);
     let pp = args.pp;
+    let suggest = args.suggest == Some(true);
 
     if pp < 0.0 {
         return orig.error("The pp number must be non-negative").await;
@@ -204,7 +242,7 @@ async fn whatif(orig: CommandOrigin<'_>, args: WhatIf<'_>) -> Result<()> {
         .top(200, false)
         .exec_with_user(user_args);
 
-    let (user, scores) = match scores_fut.await {
+    let (user, mut scores) = match scores_fut.await {
         Ok((user, scores)) => (user, scores),
         Err(UserArgsError::Osu(OsuError::NotFound)) => {
             let content = user_not_found(user_id).await;
@@ -212,30 +250,52 @@ async fn whatif(orig: CommandOrigin<'_>, args: WhatIf<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get user or scores");
 
             return Err(err);
         }
     };
 
+    let full_top200 = scores.len() == 200;
+
+    // Scores whose pp don't count towards the profile shouldn't be weighted
+    // while those of unknown maps are assumed to count
+    scores.retain(|score| {
+        let status = score.map.as_ref().map(|map| map.status);
+
+        is_unranked_pp(status, &score.mods, score.ranked) != Some(true)
+    });
+
+    if exclude > 0 && exclude >= scores.len() {
+        let content = format!(
+            "`exclude_top` must be smaller than the amount of top scores ({})",
+            scores.len()
+        );
+
+        return orig.error(content).await;
+    }
+
+    let blacklisted = own_blacklisted_mods(&orig, user.user_id.to_native())
+        .await
+        .filter(|blacklist| {
+            let scores = scores
+                .iter()
+                .map(|score| (score.pp.unwrap_or(0.0), &score.mods));
+
+            requires_blacklisted_mods(pp, scores, blacklist)
+        });
+
     let whatif_data = if scores.is_empty() {
         let pp = iter::repeat(pp)
             .zip(0..)
             .take(count)
             .fold(0.0, |sum, (pp, i)| sum + pp as f64 * FACTOR.powi(i));
 
-        let rank = match Context::approx().rank(pp as f32, mode).await {
-            Ok(rank) => Some(rank),
-            Err(err) => {
-                warn!(?err, "Failed to get rank pp");
-
-                None
-            }
-        };
+        let rank = project_rank(&user, pp as f32, mode).await;
 
         WhatIfData::NoScores { count, rank }
-    } else if scores.len() == 200 && pp < scores.last().and_then(|s| s.pp).unwrap_or(0.0) {
+    } else if exclude == 0 && full_top200 && pp < scores.last().and_then(|s| s.pp).unwrap_or(0.0) {
         WhatIfData::NonTop200
     } else {
         let mut pps = scores.extract_pp();
@@ -249,26 +309,39 @@ async fn whatif(orig: CommandOrigin<'_>, args: WhatIf<'_>) -> Result<()> {
             .to_native() as f64;
         let bonus_pp = f64::max(total - actual, 0.0);
 
+        let baseline = (exclude > 0).then(|| {
+            let pp = total_pp_without_bottom(total as f32, &pps, exclude);
+            pps.truncate(pps.len() - exclude);
+
+            ExcludedBaseline {
+                excluded: exclude,
+                pp,
+            }
+        });
+
         let idx = pps
             .iter()
             .position(|&pp_| pp_ < pp)
-            .unwrap_or(scores.len() - 1);
+            .unwrap_or(pps.len().saturating_sub(1));
 
         pps.extend(iter::repeat_n(pp, count));
         pps.sort_unstable_by(|a, b| b.total_cmp(a));
 
         let new_pp = pps.accum_weighted();
 
-        let rank = match Context::approx()
-            .rank((new_pp + bonus_pp) as f32, mode)
-            .await
-        {
-            Ok(rank) => Some(rank),
-            Err(err) => {
-                warn!(?err, "Failed to get rank pp");
+        let rank = project_rank(&user, (new_pp + bonus_pp) as f32, mode).await;
 
-                None
+        let suggestion = if suggest {
+            match top_play_difficulties(&scores).await {
+                Ok(plays) => suggest_play(pp, plays),
+                Err(err) => {
+                    warn!(?err, "Failed to get top play difficulties");
+
+                    None
+                }
             }
+        } else {
+            None
         };
 
         WhatIfData::Top200 {
@@ -278,11 +351,13 @@ async fn whatif(orig: CommandOrigin<'_>, args: WhatIf<'_>) -> Result<()> {
             new_pos: idx + 1,
             max_pp,
             rank,
+            baseline,
+            suggestion,
         }
     };
 
     // Sending the embed
-    let embed = WhatIfEmbed::new(&user, pp, whatif_data);
+    let embed = WhatIfEmbed::new(&user, pp, whatif_data, blacklisted);
     let builder = MessageBuilder::new().embed(embed.build());
     orig.create_message(builder).await?;
 
@@ -290,3 +365,224 @@ async fn whatif(orig: CommandOrigin<'_>, args: WhatIf<'_>) -> Result<()> {
 }
 
 const FACTOR: f64 = 0.95;
+
+/// Amount of entries per page in the pp rankings
+const RANKING_PAGE_SIZE: u32 = 50;
+
+/// The last page of the pp rankings i.e. rank 10,000
+const RANKING_LAST_PAGE: u32 = 200;
+
+/// Maximum amount of ranking pages to fetch per projection
+const MAX_PAGE_FETCHES: usize = 3;
+
+/// Project the global and country rank a user would have with the given
+/// total pp.
+///
+/// Ranks within the top 10k are looked up in the pp rankings, the global rank
+/// beyond that is approximated.
+async fn project_rank(user: &CachedUser, total_pp: f32, mode: GameMode) -> Option<ProjectedRank> {
+    let estimate = match Context::approx().rank(total_pp, mode).await {
+        Ok(rank) => rank,
+        Err(err) => {
+            warn!(?err, "Failed to get rank pp");
+
+            return None;
+        }
+    };
+
+    let user_id = user.user_id.to_native();
+    let limit = RANKING_PAGE_SIZE * RANKING_LAST_PAGE;
+
+    let global = if estimate <= limit {
+        search_rankings(user_id, total_pp, mode, None, estimate).await
+    } else {
+        None
+    };
+
+    let country_rank = user
+        .statistics
+        .as_ref()
+        .expect("missing stats")
+        .country_rank
+        .to_native();
+
+    // The user can only climb so their current country rank is an upper bound
+    let country = if (1..=limit).contains(&country_rank) {
+        let country = Some(user.country_code.as_str());
+
+        search_rankings(user_id, total_pp, mode, country, country_rank).await
+    } else {
+        None
+    };
+
+    Some(ProjectedRank {
+        global: global.unwrap_or(estimate),
+        approx: global.is_none(),
+        country,
+    })
+}
+
+/// Look for the rank of the given pp in the cached rankings, starting on the
+/// page of the estimated rank.
+async fn search_rankings(
+    user_id: u32,
+    pp: f32,
+    mode: GameMode,
+    country: Option<&str>,
+    estimate: u32,
+) -> Option<u32> {
+    let mut search = RankingSearch::new(estimate);
+
+    for _ in 0..MAX_PAGE_FETCHES {
+        let rankings = match Context::redis()
+            .pp_ranking(mode, search.page, country)
+            .await
+        {
+            Ok(rankings) => rankings,
+            Err(err) => {
+                warn!(?err, page = search.page, "Failed to get pp ranking");
+
+                return None;
+            }
+        };
+
+        // Skipping the user themselves so they don't count as holder with
+        // more pp than their own projection
+        let pps: Vec<_> = rankings
+            .ranking
+            .iter()
+            .filter(|entry| entry.user_id.to_native() != user_id)
+            .map(|entry| {
+                entry
+                    .statistics
+                    .as_ref()
+                    .map_or(0.0, |stats| stats.pp.to_native())
+            })
+            .collect();
+
+        match search.step(&pps, pp) {
+            RankingSearchStep::Found(rank) => return Some(rank),
+            RankingSearchStep::Continue => {}
+            RankingSearchStep::Exhausted => return None,
+        }
+    }
+
+    None
+}
+
+/// Walks through the pages of the pp rankings to find the first holder with
+/// less pp than the target.
+struct RankingSearch {
+    page: u32,
+    /// Lowest page that may still contain the rank
+    lo: u32,
+    /// Highest page that may still contain the rank
+    hi: u32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum RankingSearchStep {
+    Found(u32),
+    Continue,
+    Exhausted,
+}
+
+impl RankingSearch {
+    fn new(estimate: u32) -> Self {
+        Self {
+            page: estimate
+                .div_ceil(RANKING_PAGE_SIZE)
+                .clamp(1, RANKING_LAST_PAGE),
+            lo: 1,
+            hi: RANKING_LAST_PAGE,
+        }
+    }
+
+    /// Process the pp values of the current page, sorted descendingly, and
+    /// either resolve the rank or move on to a neighbouring page.
+    fn step(&mut self, pps: &[f32], pp: f32) -> RankingSearchStep {
+        let idx = pps.partition_point(|&holder_pp| holder_pp >= pp);
+        let is_full = pps.len() >= RANKING_PAGE_SIZE as usize;
+
+        if idx == 0 && self.page > self.lo {
+            self.hi = self.page - 1;
+            self.page = self.hi;
+
+            RankingSearchStep::Continue
+        } else if idx == pps.len() && is_full && self.page < self.hi {
+            self.lo = self.page + 1;
+            self.page = self.lo;
+
+            RankingSearchStep::Continue
+        } else if idx == pps.len() && is_full && self.page == RANKING_LAST_PAGE {
+            RankingSearchStep::Exhausted
+        } else {
+            RankingSearchStep::Found((self.page - 1) * RANKING_PAGE_SIZE + idx as u32 + 1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rankings in which rank `n` has `20_000 - n` pp
+    fn ranking_page(page: u32) -> Vec<f32> {
+        let start = (page - 1) * RANKING_PAGE_SIZE + 1;
+
+        (start..start + RANKING_PAGE_SIZE)
+            .map(|rank| (20_000 - rank) as f32)
+            .collect()
+    }
+
+    fn search(pp: f32, estimate: u32) -> (Option<u32>, usize) {
+        let mut search = RankingSearch::new(estimate);
+
+        for fetched in 1..=MAX_PAGE_FETCHES {
+            match search.step(&ranking_page(search.page), pp) {
+                RankingSearchStep::Found(rank) => return (Some(rank), fetched),
+                RankingSearchStep::Continue => {}
+                RankingSearchStep::Exhausted => return (None, fetched),
+            }
+        }
+
+        (None, MAX_PAGE_FETCHES)
+    }
+
+    #[test]
+    fn found_on_estimated_page() {
+        assert_eq!(search(15_678.5, 4_321), (Some(4_322), 1));
+        assert_eq!(search(15_679.0, 4_321), (Some(4_322), 1));
+    }
+
+    #[test]
+    fn estimate_off_by_pages() {
+        // actual rank is on the next page
+        assert_eq!(search(15_678.5, 4_280), (Some(4_322), 2));
+        // actual rank is on the previous page
+        assert_eq!(search(15_678.5, 4_360), (Some(4_322), 2));
+        // too far off from the estimate
+        assert_eq!(search(15_678.5, 4_500), (None, 3));
+    }
+
+    #[test]
+    fn page_boundaries() {
+        // first entry of a page
+        assert_eq!(search(19_900.5, 100), (Some(100), 1));
+        assert_eq!(search(19_899.5, 100), (Some(101), 2));
+        assert_eq!(search(25_000.0, 1), (Some(1), 1));
+    }
+
+    #[test]
+    fn beyond_rankings() {
+        assert_eq!(search(1.0, 10_000), (None, 1));
+    }
+
+    #[test]
+    fn partial_page() {
+        let mut search = RankingSearch::new(1);
+        let pps = [300.0, 200.0, 100.0];
+
+        assert_eq!(search.step(&pps, 50.0), RankingSearchStep::Found(4));
+    }
+}
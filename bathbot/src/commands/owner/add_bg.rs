@@ -4,6 +4,7 @@ use bathbot_psql::model::osu::ArtistTitle;
 use bathbot_util::{
     MessageBuilder,
     constants::{GENERAL_ISSUE, OSU_BASE},
+    i18n::ErrorKey,
 };
 use eyre::{Report, Result};
 use rosu_v2::prelude::GameMode;
@@ -67,7 +68,7 @@ pub async fn addbg(command: InteractionCommand, bg: OwnerAddBg) -> Result<()> {
             let mut file = match File::create(&path).await {
                 Ok(file) => file,
                 Err(err) => {
-                    let _ = command.error(GENERAL_ISSUE).await;
+                    let _ = command.error_tr(ErrorKey::General).await;
                     let err = Report::new(err).wrap_err("failed to create file for new bg");
 
                     return Err(err);
@@ -76,7 +77,7 @@ pub async fn addbg(command: InteractionCommand, bg: OwnerAddBg) -> Result<()> {
 
             // Store in file
             if let Err(err) = file.write_all(&content).await {
-                let _ = command.error(GENERAL_ISSUE).await;
+                let _ = command.error_tr(ErrorKey::General).await;
                 let err = Report::new(err).wrap_err("failed writing to bg file");
 
                 return Err(err);
@@ -84,7 +85,7 @@ pub async fn addbg(command: InteractionCommand, bg: OwnerAddBg) -> Result<()> {
             path
         }
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("failed to get discord attachment"));
         }
@@ -0,0 +1,133 @@
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+
+use bathbot_cache::{EntitySize, Extrapolation};
+use bathbot_util::{
+    EmbedBuilder, FooterBuilder, MessageBuilder, i18n::ErrorKey, numbers::WithComma,
+};
+use eyre::Result;
+
+use crate::{
+    Context,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+pub async fn cache_size(command: InteractionCommand) -> Result<()> {
+    command.defer(false).await?;
+
+    let size = match Context::cache().estimate_size().await {
+        Ok(size) => size,
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err.wrap_err("Failed to estimate cache size"));
+        }
+    };
+
+    let entities = [
+        ("Guilds", &size.guilds),
+        ("Channels", &size.channels),
+        ("Users", &size.users),
+        ("Members", &size.members),
+        ("Roles", &size.roles),
+    ];
+
+    let mut description = String::new();
+    let mut total_values = 0;
+    let mut total_memory = Some(0);
+
+    for (name, entity) in entities {
+        let EntitySize {
+            count,
+            sampled,
+            value_bytes,
+            memory_usage,
+        } = entity;
+
+        let _ = write!(
+            description,
+            "**{name}**: {count} entries\n\
+            Values: {values} ({sampled} sampled)",
+            count = WithComma::new(*count),
+            values = ExtrapolationFormatter(value_bytes.as_ref()),
+        );
+
+        if let Some(memory_usage) = memory_usage {
+            let _ = write!(
+                description,
+                " • Memory usage: {}",
+                ExtrapolationFormatter(Some(memory_usage))
+            );
+        }
+
+        description.push('\n');
+
+        total_values += value_bytes.map_or(0, |bytes| bytes.total);
+        total_memory = total_memory
+            .zip(*memory_usage)
+            .map(|(sum, bytes)| sum + bytes.total);
+    }
+
+    let _ = write!(
+        description,
+        "\n**Total values**: ~{}",
+        ByteSize(total_values)
+    );
+
+    if let Some(total_memory) = total_memory {
+        let _ = write!(
+            description,
+            " • **Memory usage**: ~{}",
+            ByteSize(total_memory)
+        );
+    }
+
+    let footer = FooterBuilder::new(
+        "Extrapolated from random samples with ~95% confidence margins; \
+        channels, roles, and members are sampled per guild so large guilds are underrepresented",
+    );
+
+    let embed = EmbedBuilder::new()
+        .title("Cache size estimate")
+        .description(description)
+        .footer(footer);
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+struct ExtrapolationFormatter<'a>(Option<&'a Extrapolation>);
+
+impl Display for ExtrapolationFormatter<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.0 {
+            Some(Extrapolation { total, margin }) => {
+                write!(f, "~{} ± {}", ByteSize(*total), ByteSize(*margin))
+            }
+            None => f.write_str("-"),
+        }
+    }
+}
+
+struct ByteSize(u64);
+
+impl Display for ByteSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+        let mut size = self.0 as f64;
+        let mut unit = 0;
+
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+
+        if unit == 0 {
+            write!(f, "{}{}", self.0, UNITS[0])
+        } else {
+            write!(f, "{size:.2}{}", UNITS[unit])
+        }
+    }
+}
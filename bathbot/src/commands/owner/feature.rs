@@ -0,0 +1,55 @@
+use std::time::Instant;
+
+use bathbot_util::{MessageBuilder, i18n::ErrorKey};
+use eyre::Result;
+
+use super::{OwnerFeature, OwnerFeatureDisable, OwnerFeatureEnable};
+use crate::{
+    core::Context,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+/// Safety net so that a forgotten flag doesn't keep an integration disabled.
+const DISABLE_SECONDS: u64 = 24 * 60 * 60;
+
+pub async fn feature(command: InteractionCommand, args: OwnerFeature) -> Result<()> {
+    let (service, disabled) = match args {
+        OwnerFeature::Disable(OwnerFeatureDisable { name }) => {
+            let store_fut = Context::cache().disable_feature(name.key(), DISABLE_SECONDS);
+
+            if let Err(err) = store_fut.await {
+                let _ = command.error_callback_tr(ErrorKey::General).await;
+
+                return Err(err);
+            }
+
+            (name, true)
+        }
+        OwnerFeature::Enable(OwnerFeatureEnable { name }) => {
+            if let Err(err) = Context::cache().enable_feature(name.key()).await {
+                let _ = command.error_callback_tr(ErrorKey::General).await;
+
+                return Err(err);
+            }
+
+            (name, false)
+        }
+    };
+
+    // Apply right away instead of waiting for the memoized flag to expire
+    Context::feature_flags().set(service, disabled, Instant::now());
+
+    let content = if disabled {
+        format!(
+            "The {service} integration is disabled for the next {} hours",
+            DISABLE_SECONDS / 3600
+        )
+    } else {
+        format!("The {service} integration is enabled")
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
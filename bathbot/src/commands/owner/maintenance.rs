@@ -0,0 +1,49 @@
+use bathbot_util::{MessageBuilder, i18n::ErrorKey};
+use eyre::Result;
+use time::OffsetDateTime;
+
+use super::{OwnerMaintenance, OwnerMaintenanceOn};
+use crate::{
+    core::Context,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+pub async fn maintenance(command: InteractionCommand, args: OwnerMaintenance) -> Result<()> {
+    let state = match args {
+        OwnerMaintenance::On(OwnerMaintenanceOn { duration, message }) => {
+            let message = message.unwrap_or_default();
+            let seconds = duration as u64 * 60;
+
+            if let Err(err) = Context::cache().store_maintenance(&message, seconds).await {
+                let _ = command.error_callback_tr(ErrorKey::General).await;
+
+                return Err(err);
+            }
+
+            Some((message, seconds))
+        }
+        OwnerMaintenance::Off(_) => {
+            if let Err(err) = Context::cache().clear_maintenance().await {
+                let _ = command.error_callback_tr(ErrorKey::General).await;
+
+                return Err(err);
+            }
+
+            None
+        }
+    };
+
+    let content = match state {
+        Some((_, seconds)) => format!("Maintenance mode enabled for {} minutes", seconds / 60),
+        None => "Maintenance mode disabled".to_owned(),
+    };
+
+    // Apply right away instead of waiting for the watcher
+    let now = OffsetDateTime::now_utc().unix_timestamp();
+    Context::maintenance().update(state, now).update_presence();
+
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
@@ -1,18 +1,28 @@
 use bathbot_macros::SlashCommand;
 use bathbot_model::command_fields::GameModeOption;
 use eyre::Result;
-use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::channel::Attachment;
 
 pub use self::reshard::RESHARD_TX;
-use self::{add_bg::*, cache::*, request_members::*};
+use self::{
+    add_bg::*, cache::*, cache_size::*, feature::*, maintenance::*, prune::*, recalc::*,
+    reconcile::*, request_members::*,
+};
 use crate::{
     commands::owner::reshard::reshard,
+    core::Service,
     util::{InteractionCommandExt, interaction::InteractionCommand},
 };
 
 mod add_bg;
 mod cache;
+mod cache_size;
+mod feature;
+mod maintenance;
+mod prune;
+mod recalc;
+mod reconcile;
 mod request_members;
 mod reshard;
 mod tracking_stats;
@@ -26,6 +36,18 @@ pub enum Owner {
     AddBg(OwnerAddBg),
     #[command(name = "cache")]
     Cache(OwnerCache),
+    #[command(name = "cachesize")]
+    CacheSize(OwnerCacheSize),
+    #[command(name = "feature")]
+    Feature(OwnerFeature),
+    #[command(name = "maintenance")]
+    Maintenance(OwnerMaintenance),
+    #[command(name = "prune")]
+    Prune(OwnerPrune),
+    #[command(name = "recalc")]
+    Recalc(OwnerRecalc),
+    #[command(name = "reconcile")]
+    Reconcile(OwnerReconcile),
     #[command(name = "requestmembers")]
     RequestMembers(OwnerRequestMembers),
     #[command(name = "reshard")]
@@ -47,6 +69,156 @@ pub struct OwnerAddBg {
 #[command(name = "cache", desc = "Display stats about the internal cache")]
 pub struct OwnerCache;
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "cachesize",
+    desc = "Estimate the memory footprint of the cached discord entities"
+)]
+pub struct OwnerCacheSize;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "feature", desc = "Toggle upstream integrations at runtime")]
+pub enum OwnerFeature {
+    #[command(name = "disable")]
+    Disable(OwnerFeatureDisable),
+    #[command(name = "enable")]
+    Enable(OwnerFeatureEnable),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "disable",
+    desc = "Respond right away instead of sending requests to the service"
+)]
+pub struct OwnerFeatureDisable {
+    #[command(desc = "The service whose integration should be disabled")]
+    name: Service,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "enable", desc = "Re-enable a disabled integration")]
+pub struct OwnerFeatureEnable {
+    #[command(desc = "The service whose integration should be enabled")]
+    name: Service,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "maintenance",
+    desc = "Turn away non-owner commands during deploys"
+)]
+pub enum OwnerMaintenance {
+    #[command(name = "on")]
+    On(OwnerMaintenanceOn),
+    #[command(name = "off")]
+    Off(OwnerMaintenanceOff),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "on", desc = "Enable maintenance mode")]
+pub struct OwnerMaintenanceOn {
+    #[command(
+        min_value = 1,
+        max_value = 1440,
+        desc = "Minutes until maintenance mode ends on its own"
+    )]
+    duration: i64,
+    #[command(desc = "Shown to users alongside the remaining time")]
+    message: Option<String>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "off", desc = "Disable maintenance mode")]
+pub struct OwnerMaintenanceOff;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "prune", desc = "Remove long inactive data")]
+pub enum OwnerPrune {
+    #[command(name = "report")]
+    Report(OwnerPruneReport),
+    #[command(name = "confirm")]
+    Confirm(OwnerPruneConfirm),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "report",
+    desc = "Create a report of prunable data without deleting anything"
+)]
+pub struct OwnerPruneReport;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "confirm", desc = "Delete the data of a prune report")]
+pub struct OwnerPruneConfirm {
+    #[command(min_value = 1, desc = "The id of the report")]
+    report: i64,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "recalc",
+    desc = "Invalidate stored star ratings and max pp of converts"
+)]
+pub enum OwnerRecalc {
+    #[command(name = "start")]
+    Start(OwnerRecalcStart),
+    #[command(name = "resume")]
+    Resume(OwnerRecalcResume),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "start",
+    desc = "Invalidate attributes and recompute the most used ones"
+)]
+pub struct OwnerRecalcStart {
+    #[command(desc = "Which attributes should be invalidated")]
+    scope: RecalcScopeOption,
+    #[command(desc = "Specify the mode for the mode scope")]
+    mode: Option<GameModeOption>,
+    #[command(min_value = 1, desc = "Specify the mapset id for the mapset scope")]
+    mapset: Option<i64>,
+    #[command(
+        min_value = 0,
+        max_value = 1000,
+        desc = "Amount of the most used attributes to recompute right away"
+    )]
+    recompute: Option<i64>,
+}
+
+#[derive(CommandOption, CreateOption)]
+pub enum RecalcScopeOption {
+    #[option(name = "All cached attributes", value = "all")]
+    All,
+    #[option(name = "Attributes of a mode", value = "mode")]
+    Mode,
+    #[option(name = "Attributes of a mapset", value = "mapset")]
+    Mapset,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "resume",
+    desc = "Continue recomputing attributes of an interrupted recalculation"
+)]
+pub struct OwnerRecalcResume;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "reconcile",
+    desc = "Remove dangling entries from a guild's cached members, channels, and roles"
+)]
+pub struct OwnerReconcile {
+    #[command(desc = "The guild id of which entries should be reconciled")]
+    guild_id: Option<String>,
+    #[command(
+        min_value = 1,
+        max_value = 100,
+        desc = "Reconcile the guilds with the most cached members instead"
+    )]
+    largest: Option<i64>,
+}
+
 #[derive(CommandModel, CreateCommand)]
 #[command(
     name = "requestmembers",
@@ -76,6 +248,12 @@ async fn slash_owner(mut command: InteractionCommand) -> Result<()> {
     match Owner::from_interaction(command.input_data())? {
         Owner::AddBg(bg) => addbg(command, bg).await,
         Owner::Cache(_) => cache(command).await,
+        Owner::CacheSize(_) => cache_size(command).await,
+        Owner::Feature(args) => feature(command, args).await,
+        Owner::Maintenance(args) => maintenance(command, args).await,
+        Owner::Prune(args) => prune(command, args).await,
+        Owner::Recalc(args) => recalc(command, args).await,
+        Owner::Reconcile(args) => reconcile(command, args).await,
         Owner::RequestMembers(args) => request_members(command, &args.guild_id).await,
         Owner::Reshard(_) => reshard(command).await,
         Owner::Tracking(OwnerTracking::Stats(_)) => tracking_stats::trackingstats(command).await,
@@ -0,0 +1,77 @@
+use bathbot_util::{EmbedBuilder, MessageBuilder, i18n::ErrorKey};
+use eyre::Result;
+
+use super::{OwnerPrune, OwnerPruneConfirm};
+use crate::{
+    tracking::{apply_prune_report, create_prune_report},
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+pub async fn prune(command: InteractionCommand, args: OwnerPrune) -> Result<()> {
+    command.defer(false).await?;
+
+    match args {
+        OwnerPrune::Report(_) => report(command).await,
+        OwnerPrune::Confirm(OwnerPruneConfirm { report }) => confirm(command, report).await,
+    }
+}
+
+async fn report(command: InteractionCommand) -> Result<()> {
+    let embed = match create_prune_report().await {
+        Ok(Some((report_id, summary))) => summary.embed(report_id),
+        Ok(None) => EmbedBuilder::new().description("Nothing to prune"),
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+async fn confirm(command: InteractionCommand, report_id: i64) -> Result<()> {
+    let Ok(report_id) = i32::try_from(report_id) else {
+        command.error("Invalid report id").await?;
+
+        return Ok(());
+    };
+
+    let outcome = match apply_prune_report(report_id).await {
+        Ok(Some(outcome)) => outcome,
+        Ok(None) => {
+            let content = format!("Report #{report_id} does not exist or was applied already");
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let description = format!(
+        "Deleted user configs: {}\n\
+        Skipped because of recent activity: {}\n\
+        Untracked channels: {}\n\
+        Skipped because they are reachable again: {}",
+        outcome.deleted_configs,
+        outcome.skipped_configs,
+        outcome.untracked_channels,
+        outcome.skipped_channels,
+    );
+
+    let embed = EmbedBuilder::new()
+        .title(format!("Applied prune report #{report_id}"))
+        .description(description);
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
@@ -0,0 +1,273 @@
+use std::{
+    fmt::Write,
+    ops::Range,
+    time::{Duration, Instant},
+};
+
+use bathbot_util::{EmbedBuilder, MessageBuilder, i18n::ErrorKey};
+use eyre::{Result, WrapErr};
+use futures::future::join_all;
+
+use super::{OwnerRecalc, OwnerRecalcStart, RecalcScopeOption};
+use crate::{
+    core::{Context, ConvertKey, ConvertValues, RecalcScope},
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+/// Maximum amount of maps that are recomputed at the same time.
+const CONCURRENCY: usize = 4;
+
+/// How often the progress message is edited.
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(5);
+
+pub async fn recalc(command: InteractionCommand, args: OwnerRecalc) -> Result<()> {
+    match args {
+        OwnerRecalc::Start(args) => start(command, args).await,
+        OwnerRecalc::Resume(_) => resume(command).await,
+    }
+}
+
+async fn start(command: InteractionCommand, args: OwnerRecalcStart) -> Result<()> {
+    let scope = match (args.scope, args.mode, args.mapset) {
+        (RecalcScopeOption::All, ..) => RecalcScope::All,
+        (RecalcScopeOption::Mode, Some(mode), _) => RecalcScope::Mode(mode.into()),
+        (RecalcScopeOption::Mapset, _, Some(mapset_id)) => RecalcScope::Mapset(mapset_id as u32),
+        (RecalcScopeOption::Mode, None, _) => {
+            command.error_callback("Must specify a mode").await?;
+
+            return Ok(());
+        }
+        (RecalcScopeOption::Mapset, _, None) => {
+            command.error_callback("Must specify a mapset id").await?;
+
+            return Ok(());
+        }
+    };
+
+    command.defer(false).await?;
+
+    let recompute = args.recompute.map_or(0, |n| n as usize);
+    let (removed, keys) = match Context::invalidate_convert_values(scope, recompute).await {
+        Ok(invalidated) => invalidated,
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let entries: Vec<_> = keys.iter().map(ConvertKey::to_entry).collect();
+
+    if let Err(err) = Context::cache().store_recalc_job(&entries).await {
+        let _ = command.error_tr(ErrorKey::General).await;
+
+        return Err(err);
+    }
+
+    let header = format!("Invalidated {removed} stored values of converts");
+
+    run_job(&command, &header, keys, 0).await
+}
+
+async fn resume(command: InteractionCommand) -> Result<()> {
+    command.defer(false).await?;
+
+    let (entries, cursor) = match Context::cache().recalc_job().await {
+        Ok(job) => job,
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    if entries.is_empty() {
+        command.error("No pending recalculation").await?;
+
+        return Ok(());
+    }
+
+    let keys = entries
+        .iter()
+        .filter_map(|entry| {
+            let key = ConvertKey::from_entry(entry);
+
+            if key.is_none() {
+                warn!(%entry, "Invalid recalc job entry");
+            }
+
+            key
+        })
+        .collect();
+
+    run_job(&command, "Resumed recalculation", keys, cursor).await
+}
+
+async fn run_job(
+    command: &InteractionCommand,
+    header: &str,
+    keys: Vec<ConvertKey>,
+    cursor: usize,
+) -> Result<()> {
+    let mut cursor = RecalcCursor::new(cursor, keys.len());
+    let mut failed = 0;
+    let mut last_progress = Instant::now();
+
+    update_progress(command, header, &cursor, failed).await;
+
+    while let Some(chunk) = cursor.next_chunk() {
+        let results = join_all(keys[chunk.clone()].iter().map(recompute)).await;
+
+        for (key, res) in keys[chunk.clone()].iter().zip(results) {
+            if let Err(err) = res {
+                warn!(
+                    map_id = key.map_id(),
+                    ?err,
+                    "Failed to recompute attributes"
+                );
+                failed += 1;
+            }
+        }
+
+        cursor.finish_chunk(chunk);
+
+        if let Err(err) = Context::cache().store_recalc_cursor(cursor.next).await {
+            warn!(?err, "Failed to store recalc cursor");
+        }
+
+        if last_progress.elapsed() >= PROGRESS_INTERVAL {
+            update_progress(command, header, &cursor, failed).await;
+            last_progress = Instant::now();
+        }
+    }
+
+    if let Err(err) = Context::cache().clear_recalc_job().await {
+        warn!(?err, "Failed to clear recalc job");
+    }
+
+    update_progress(command, header, &cursor, failed).await;
+
+    Ok(())
+}
+
+async fn recompute(key: &ConvertKey) -> Result<()> {
+    let map = Context::osu_map()
+        .map(key.map_id(), None)
+        .await
+        .wrap_err("Failed to get map")?
+        .convert(key.mode());
+
+    let mut calc = Context::pp(&map)
+        .mode(key.mode())
+        .mods(key.mods().to_owned())
+        .lazer(key.lazer());
+
+    if let Some(attrs) = calc.performance().await {
+        let values = [(key.to_owned(), ConvertValues::new(&attrs))];
+        Context::store_convert_values(&values).await?;
+    }
+
+    Ok(())
+}
+
+async fn update_progress(
+    command: &InteractionCommand,
+    header: &str,
+    cursor: &RecalcCursor,
+    failed: usize,
+) {
+    let mut description = format!(
+        "{header}\nRecomputed {}/{} of the most used entries",
+        cursor.next, cursor.total
+    );
+
+    if failed > 0 {
+        let _ = write!(description, " ({failed} failed)");
+    }
+
+    if cursor.next_chunk().is_none() {
+        description.push_str("\n**Done**");
+    }
+
+    let embed = EmbedBuilder::new()
+        .title("Difficulty recalculation")
+        .description(description);
+
+    let builder = MessageBuilder::new().embed(embed);
+
+    if let Err(err) = command.update(builder).await {
+        warn!(?err, "Failed to update recalc progress");
+    }
+}
+
+/// Position within a persisted recalculation job.
+#[derive(Debug, PartialEq, Eq)]
+struct RecalcCursor {
+    /// Index of the next entry to process
+    next: usize,
+    total: usize,
+}
+
+impl RecalcCursor {
+    /// A stored cursor beyond the job's entries counts as finished.
+    fn new(stored: usize, total: usize) -> Self {
+        Self {
+            next: stored.min(total),
+            total,
+        }
+    }
+
+    /// Entries to process next, bounded by [`CONCURRENCY`].
+    fn next_chunk(&self) -> Option<Range<usize>> {
+        (self.next < self.total).then(|| self.next..(self.next + CONCURRENCY).min(self.total))
+    }
+
+    fn finish_chunk(&mut self, chunk: Range<usize>) {
+        self.next = chunk.end;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks(mut cursor: RecalcCursor) -> Vec<Range<usize>> {
+        let mut chunks = Vec::new();
+
+        while let Some(chunk) = cursor.next_chunk() {
+            chunks.push(chunk.clone());
+            cursor.finish_chunk(chunk);
+        }
+
+        chunks
+    }
+
+    #[test]
+    fn fresh_job() {
+        let cursor = RecalcCursor::new(0, 10);
+        assert_eq!(chunks(cursor), [0..4, 4..8, 8..10]);
+    }
+
+    #[test]
+    fn resume_within_job() {
+        let cursor = RecalcCursor::new(4, 10);
+        assert_eq!(chunks(cursor), [4..8, 8..10]);
+
+        // Stored after an interrupted chunk of a differently sized job
+        let cursor = RecalcCursor::new(6, 10);
+        assert_eq!(chunks(cursor), [6..10]);
+    }
+
+    #[test]
+    fn resume_finished_job() {
+        assert!(RecalcCursor::new(10, 10).next_chunk().is_none());
+        assert_eq!(
+            RecalcCursor::new(15, 10),
+            RecalcCursor {
+                next: 10,
+                total: 10
+            }
+        );
+        assert!(RecalcCursor::new(0, 0).next_chunk().is_none());
+    }
+}
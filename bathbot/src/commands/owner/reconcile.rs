@@ -0,0 +1,88 @@
+use std::fmt::Write;
+
+use bathbot_cache::ReconcileReport;
+use bathbot_util::{EmbedBuilder, MessageBuilder, i18n::ErrorKey};
+use eyre::Result;
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use super::OwnerReconcile;
+use crate::{
+    core::Context,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+enum Target {
+    Guild(Id<GuildMarker>),
+    Largest(usize),
+}
+
+pub async fn reconcile(command: InteractionCommand, args: OwnerReconcile) -> Result<()> {
+    let target = match (args.guild_id, args.largest) {
+        (Some(guild_id), None) => match guild_id.parse().map(Id::<GuildMarker>::new_checked) {
+            Ok(Some(guild)) => Target::Guild(guild),
+            _ => {
+                command
+                    .error_callback("Must provide a valid guild id")
+                    .await?;
+
+                return Ok(());
+            }
+        },
+        (None, Some(largest)) => Target::Largest(largest as usize),
+        _ => {
+            let content = "Must provide either a guild id or an amount of largest guilds";
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    command.defer(false).await?;
+
+    let guilds = match target {
+        Target::Guild(guild) => vec![guild],
+        Target::Largest(largest) => match Context::cache().largest_guilds(largest).await {
+            Ok(guilds) => guilds,
+            Err(err) => {
+                let _ = command.error_tr(ErrorKey::General).await;
+
+                return Err(err.wrap_err("Failed to get largest guilds"));
+            }
+        },
+    };
+
+    let mut total = ReconcileReport::default();
+    let mut description = String::new();
+
+    for guild in guilds {
+        let report = match Context::cache().reconcile_guild(guild).await {
+            Ok(report) => report,
+            Err(err) => {
+                let _ = command.error_tr(ErrorKey::General).await;
+
+                return Err(err.wrap_err(format!("Failed to reconcile guild {guild}")));
+            }
+        };
+
+        if report.removed() > 0 {
+            let _ = writeln!(description, "`{guild}`: {report}");
+        }
+
+        total += report;
+    }
+
+    if description.is_empty() {
+        description.push_str("No dangling entries found\n");
+    }
+
+    let _ = write!(description, "\n**Total**: {total}");
+
+    let embed = EmbedBuilder::new()
+        .title("Cache reconciliation")
+        .description(description);
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
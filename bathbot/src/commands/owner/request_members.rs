@@ -1,4 +1,4 @@
-use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE};
+use bathbot_util::{MessageBuilder, i18n::ErrorKey};
 use eyre::{Report, Result};
 use twilight_model::id::Id;
 
@@ -40,7 +40,7 @@ pub async fn request_members(command: InteractionCommand, guild_id: &str) -> Res
             Ok(())
         }
         Err(err) => {
-            let _ = command.error_callback(GENERAL_ISSUE).await;
+            let _ = command.error_callback_tr(ErrorKey::General).await;
 
             Err(Report::new(err).wrap_err("Failed to forward member request"))
         }
@@ -1,3 +1,5 @@
+use std::fmt::Write;
+
 use bathbot_util::{EmbedBuilder, MessageBuilder, fields};
 use eyre::Result;
 use metrics::Key;
@@ -61,9 +63,24 @@ pub async fn trackingstats(command: InteractionCommand) -> Result<()> {
         stats.count_mania,
     );
 
+    let mut restarts = String::new();
+
+    for (kind, count) in Context::watchdog().restarts() {
+        if !restarts.is_empty() {
+            restarts.push_str(" • ");
+        }
+
+        let _ = write!(restarts, "`{}: {count}`", kind.name());
+    }
+
+    if restarts.is_empty() {
+        restarts.push_str("None");
+    }
+
     let fields = fields![
         "Hits since reboot".to_owned(), hits, false;
         "Counts".to_owned(), counts, false;
+        "Background loop restarts".to_owned(), restarts, false;
     ];
 
     let embed = EmbedBuilder::new()
@@ -23,7 +23,7 @@ mod zenzenzense;
 use std::fmt::Write;
 
 use bathbot_macros::SlashCommand;
-use bathbot_util::{BucketName, MessageBuilder};
+use bathbot_util::{BucketName, MessageBuilder, tr};
 use eyre::{ContextCompat, Result};
 use tokio::time::{Duration, interval};
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
@@ -36,7 +36,7 @@ pub use self::{
 };
 use crate::{
     Context,
-    core::commands::CommandOrigin,
+    core::commands::{CommandOrigin, interaction::InteractionCommands, prefix::note_response},
     util::{CheckPermissions, InteractionCommandExt, MessageExt, interaction::InteractionCommand},
 };
 
@@ -63,7 +63,8 @@ async fn song(lyrics: &[&str], delay: u64, orig: CommandOrigin<'_>) -> Result<()
 
     // Same bucket for guilds
     if let Some(cooldown) = Context::check_ratelimit(id, BucketName::Songs) {
-        let content = format!("Command on cooldown, try again in {cooldown} seconds");
+        let lang = Context::guild_config().lang(orig.guild_id()).await;
+        let content = tr!("cooldown", lang, seconds = cooldown);
 
         return orig.error_callback(content).await;
     }
@@ -78,7 +79,7 @@ async fn song(lyrics: &[&str], delay: u64, orig: CommandOrigin<'_>) -> Result<()
 
         interval.tick().await;
 
-        let mut response = orig.callback_with_response(builder).await?.model().await?;
+        let mut response = orig.callback_with_response(builder).await?;
 
         for line in &lyrics[1..] {
             interval.tick().await;
@@ -93,9 +94,14 @@ async fn song(lyrics: &[&str], delay: u64, orig: CommandOrigin<'_>) -> Result<()
                 .model()
                 .await?;
         }
+
+        note_response(&response);
     } else {
-        let content = "The server's higher-ups have disabled song commands. \
-            Server authorities can re-enable them with the `/serverconfig` command";
+        let content = format!(
+            "The server's higher-ups have disabled song commands. \
+            Server authorities can re-enable them with the {} command",
+            InteractionCommands::mention("serverconfig edit")
+        );
 
         orig.error(content).await?;
     }
@@ -22,7 +22,8 @@ mod untrack_all;
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "track", desc = "Track top score updates for players")]
-#[flags(AUTHORITY)]
+#[flags(AUTHORITY, AUDITABLE)]
+#[read_only("list")]
 pub enum Track {
     #[command(name = "add")]
     Add(TrackAdd),
@@ -72,6 +73,8 @@ pub struct TrackAdd {
         desc = "Scores must have at most X max combo percent (0-100; default 100)"
     )]
     max_combo_percent: Option<f32>,
+    #[command(desc = "Send single-line notifications instead of full embeds (default false)")]
+    compact: Option<bool>,
     #[command(desc = "Specify a second username")]
     name2: Option<String>,
     #[command(desc = "Specify a third username")]
@@ -171,6 +174,7 @@ struct TrackArgs {
     max_pp: Option<f32>,
     min_combo_percent: Option<f32>,
     max_combo_percent: Option<f32>,
+    compact: Option<bool>,
     more_names: Vec<String>,
 }
 
@@ -184,12 +188,16 @@ impl TrackArgs {
             ArgsNum::Random | ArgsNum::None => None,
         };
 
-        for arg in args.map(CowUtils::cow_to_ascii_lowercase) {
-            if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
-                let key = &arg[..idx];
-                let value = arg[idx + 1..].trim_end();
+        for arg in args.into_args() {
+            let key_value = arg.key_value();
+            let arg = arg.into_lowercase();
 
-                match key {
+            if let Some((key, value)) = key_value {
+                let key = key.cow_to_ascii_lowercase();
+                let value = value.cow_to_ascii_lowercase();
+                let value = value.trim_end();
+
+                match key.as_ref() {
                     "limit" | "l" => match value.parse() {
                         Ok(num) => max_index = Some(num),
                         Err(_) => {
@@ -226,6 +234,7 @@ impl TrackArgs {
             max_pp: None,
             min_combo_percent: None,
             max_combo_percent: None,
+            compact: None,
             more_names,
             mode,
         };
@@ -245,6 +254,7 @@ impl From<TrackAdd> for TrackArgs {
             max_pp,
             min_combo_percent,
             max_combo_percent,
+            compact,
             name2,
             name3,
             name4,
@@ -279,6 +289,7 @@ impl From<TrackAdd> for TrackArgs {
             max_pp,
             min_combo_percent,
             max_combo_percent,
+            compact,
         }
     }
 }
@@ -297,6 +308,7 @@ impl From<TrackRemoveUser> for TrackArgs {
             max_pp: None,
             min_combo_percent: None,
             max_combo_percent: None,
+            compact: None,
         }
     }
 }
@@ -1,7 +1,7 @@
 use std::fmt::Write;
 
 use bathbot_macros::command;
-use bathbot_util::{EmbedBuilder, FooterBuilder, MessageBuilder, constants::GENERAL_ISSUE, fields};
+use bathbot_util::{EmbedBuilder, FooterBuilder, MessageBuilder, fields, i18n::ErrorKey};
 use eyre::{Report, Result};
 use rosu_v2::prelude::{GameMode, OsuError};
 
@@ -25,6 +25,7 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
         max_pp,
         min_combo_percent,
         max_combo_percent,
+        compact,
     } = args;
 
     more_names.push(name);
@@ -45,7 +46,7 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
             return orig.error(content).await;
         }
         Err((err, _)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("Failed to get names");
 
             return Err(err);
@@ -55,7 +56,8 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
     let params = TrackEntryParams::new()
         .with_index(min_index, max_index)
         .with_pp(min_pp, max_pp)
-        .with_combo_percent(min_combo_percent, max_combo_percent);
+        .with_combo_percent(min_combo_percent, max_combo_percent)
+        .with_compact(compact.unwrap_or(false));
 
     let channel = orig.channel_id();
     let mut success = Vec::with_capacity(users.len());
@@ -122,13 +124,17 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
         fields![fields { "Failed to track:".to_owned(), value, false }];
     }
 
-    let value = format!(
+    let mut value = format!(
         "`Index: {index}` | `PP: {pp}pp` | `Combo percent: {combo_percent}%`",
         index = params.index(),
         pp = params.pp(),
         combo_percent = params.combo_percent(),
     );
 
+    if params.compact() {
+        value.push_str(" | `Compact`");
+    }
+
     fields![fields { "Parameters:".to_owned(), value, false }];
 
     let footer = FooterBuilder::new("").icon_url(Emote::from(mode).url());
@@ -165,7 +171,7 @@ const TRACK_USAGE: &str = "[limit=number] [username1] [username2] ...";
     "limit=45 cookiezi whitecat",
     "\"freddie benson\""
 )]
-#[flags(AUTHORITY, ONLY_GUILDS)]
+#[flags(AUTHORITY, AUDITABLE, ONLY_GUILDS)]
 #[group(Tracking)]
 async fn prefix_track(msg: &Message, args: Args<'_>) -> Result<()> {
     match TrackArgs::args(Some(GameMode::Osu), args).await {
@@ -197,7 +203,7 @@ async fn prefix_track(msg: &Message, args: Args<'_>) -> Result<()> {
     "limit=45 cookiezi whitecat",
     "\"freddie benson\""
 )]
-#[flags(AUTHORITY, ONLY_GUILDS)]
+#[flags(AUTHORITY, AUDITABLE, ONLY_GUILDS)]
 #[group(Tracking)]
 pub async fn prefix_trackmania(msg: &Message, args: Args<'_>) -> Result<()> {
     match TrackArgs::args(Some(GameMode::Mania), args).await {
@@ -229,7 +235,7 @@ pub async fn prefix_trackmania(msg: &Message, args: Args<'_>) -> Result<()> {
     "limit=45 cookiezi whitecat",
     "\"freddie benson\""
 )]
-#[flags(AUTHORITY, ONLY_GUILDS)]
+#[flags(AUTHORITY, AUDITABLE, ONLY_GUILDS)]
 #[group(Tracking)]
 pub async fn prefix_tracktaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     match TrackArgs::args(Some(GameMode::Taiko), args).await {
@@ -261,7 +267,7 @@ pub async fn prefix_tracktaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     "limit=45 cookiezi whitecat",
     "\"freddie benson\""
 )]
-#[flags(AUTHORITY, ONLY_GUILDS)]
+#[flags(AUTHORITY, AUDITABLE, ONLY_GUILDS)]
 #[alias("trackingcatch")]
 #[group(Tracking)]
 pub async fn prefix_trackctb(msg: &Message, args: Args<'_>) -> Result<()> {
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use bathbot_macros::command;
-use bathbot_util::constants::{GENERAL_ISSUE, OSU_API_ISSUE};
+use bathbot_util::i18n::ErrorKey;
 use eyre::{Report, Result};
 use rosu_v2::prelude::{GameMode, OsuError, Username};
 use twilight_model::id::{Id, marker::ChannelMarker};
@@ -36,7 +36,7 @@ pub async fn tracklist(orig: CommandOrigin<'_>) -> Result<()> {
     let entries = match OsuTracking::tracked_users_in_channel(channel_id).await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to get tracked users"));
         }
@@ -45,7 +45,7 @@ pub async fn tracklist(orig: CommandOrigin<'_>) -> Result<()> {
     let mut users = match get_users(orig.channel_id(), entries).await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("failed to get users"));
         }
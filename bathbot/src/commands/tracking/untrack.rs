@@ -1,7 +1,7 @@
 use std::{collections::HashSet, fmt::Write};
 
 use bathbot_macros::command;
-use bathbot_util::{EmbedBuilder, MessageBuilder, constants::GENERAL_ISSUE};
+use bathbot_util::{EmbedBuilder, MessageBuilder, i18n::ErrorKey};
 use eyre::{Report, Result};
 use rosu_v2::prelude::{GameMode, OsuError};
 
@@ -20,7 +20,7 @@ use crate::{
 )]
 #[usage("[username1] [username2] ...")]
 #[example("badewanne3 cookiezi \"freddie benson\" peppy")]
-#[flags(AUTHORITY, ONLY_GUILDS)]
+#[flags(AUTHORITY, AUDITABLE, ONLY_GUILDS)]
 #[group(Tracking)]
 async fn prefix_untrack(msg: &Message, args: Args<'_>) -> Result<()> {
     match TrackArgs::args(None, args).await {
@@ -57,7 +57,7 @@ pub(super) async fn untrack(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<
             return orig.error(content).await;
         }
         Err((err, _)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
             let err = Report::new(err).wrap_err("failed to get names");
 
             return Err(err);
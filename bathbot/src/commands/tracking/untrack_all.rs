@@ -14,7 +14,7 @@ use crate::{core::commands::CommandOrigin, tracking::OsuTracking, util::ChannelE
 )]
 #[usage("[osu / mania / taiko / ctb]")]
 #[example("", "mania")]
-#[flags(AUTHORITY, ONLY_GUILDS, SKIP_DEFER)]
+#[flags(AUTHORITY, AUDITABLE, ONLY_GUILDS, SKIP_DEFER)]
 #[group(Tracking)]
 async fn prefix_untrackall(msg: &Message, mut args: Args<'_>) -> Result<()> {
     let mode = match args.next() {
@@ -1,14 +1,15 @@
 use bathbot_macros::command;
-use bathbot_util::{
-    CowUtils, MessageBuilder,
-    constants::{GENERAL_ISSUE, TWITCH_API_ISSUE},
-};
+use bathbot_util::{CowUtils, MessageBuilder, constants::TWITCH_API_ISSUE, i18n::ErrorKey};
 use eyre::Result;
 
-use crate::{Context, core::commands::CommandOrigin, util::ChannelExt};
+use crate::{
+    Context,
+    core::{Service, commands::CommandOrigin},
+    util::ChannelExt,
+};
 
 #[command]
-#[flags(AUTHORITY, ONLY_GUILDS)]
+#[flags(AUTHORITY, AUDITABLE, ONLY_GUILDS)]
 #[desc("Notifying a channel when a twitch stream comes online")]
 #[aliases("streamadd", "trackstream")]
 #[usage("[stream name]")]
@@ -29,6 +30,12 @@ async fn prefix_addstream(msg: &Message, mut args: Args<'_>) -> Result<()> {
 }
 
 pub async fn addstream(orig: CommandOrigin<'_>, name: &'_ str) -> Result<()> {
+    let notice_fut = Context::feature_flags().disabled_notice(Service::Twitch);
+
+    if let Some(content) = notice_fut.await {
+        return orig.error(content).await;
+    }
+
     let twitch_id = match Context::client().get_twitch_user(name).await {
         Ok(Some(user)) => user.user_id,
         Ok(None) => {
@@ -63,7 +70,7 @@ pub async fn addstream(orig: CommandOrigin<'_>, name: &'_ str) -> Result<()> {
             orig.error(content).await
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             Err(err.wrap_err("failed to add stream track"))
         }
@@ -16,7 +16,8 @@ pub mod tracked;
     help = "Track a twitch stream in this channel.\n\
     When the stream goes online, a notification will be send to this channel within a few minutes."
 )]
-#[flags(AUTHORITY)]
+#[flags(AUTHORITY, AUDITABLE)]
+#[read_only("list")]
 pub enum TrackStream {
     #[command(name = "add")]
     Add(TrackStreamAdd),
@@ -1,14 +1,15 @@
 use bathbot_macros::command;
-use bathbot_util::{
-    CowUtils, MessageBuilder,
-    constants::{GENERAL_ISSUE, TWITCH_API_ISSUE},
-};
+use bathbot_util::{CowUtils, MessageBuilder, constants::TWITCH_API_ISSUE, i18n::ErrorKey};
 use eyre::Result;
 
-use crate::{Context, core::commands::CommandOrigin, util::ChannelExt};
+use crate::{
+    Context,
+    core::{Service, commands::CommandOrigin},
+    util::ChannelExt,
+};
 
 #[command]
-#[flags(AUTHORITY, ONLY_GUILDS)]
+#[flags(AUTHORITY, AUDITABLE, ONLY_GUILDS)]
 #[desc("Stop tracking a twitch user in a channel")]
 #[aliases("streamremove", "untrackstream")]
 #[usage("[stream name]")]
@@ -29,6 +30,12 @@ async fn prefix_removestream(msg: &Message, mut args: Args<'_>) -> Result<()> {
 }
 
 pub async fn removestream(orig: CommandOrigin<'_>, name: &'_ str) -> Result<()> {
+    let notice_fut = Context::feature_flags().disabled_notice(Service::Twitch);
+
+    if let Some(content) = notice_fut.await {
+        return orig.error(content).await;
+    }
+
     let twitch_id = match Context::client().get_twitch_user(name).await {
         Ok(Some(user)) => user.user_id,
         Ok(None) => {
@@ -65,7 +72,7 @@ pub async fn removestream(orig: CommandOrigin<'_>, name: &'_ str) -> Result<()>
             orig.error(content).await
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             Err(err.wrap_err("failed to remove stream track"))
         }
@@ -1,10 +1,13 @@
 use std::fmt::Write;
 
 use bathbot_macros::command;
-use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE};
+use bathbot_util::{MessageBuilder, i18n::ErrorKey};
 use eyre::Result;
 
-use crate::{Context, core::commands::CommandOrigin};
+use crate::{
+    Context,
+    core::{Service, commands::CommandOrigin},
+};
 
 #[command]
 #[desc("List all streams that are tracked in a channel")]
@@ -15,12 +18,18 @@ async fn prefix_trackedstreams(msg: &Message) -> Result<()> {
 }
 
 pub async fn tracked(orig: CommandOrigin<'_>) -> Result<()> {
+    let notice_fut = Context::feature_flags().disabled_notice(Service::Twitch);
+
+    if let Some(content) = notice_fut.await {
+        return orig.error(content).await;
+    }
+
     let twitch_ids = Context::tracked_users_in(orig.channel_id());
 
     let mut twitch_users: Vec<_> = match Context::client().get_twitch_users(&twitch_ids).await {
         Ok(users) => users.into_iter().map(|user| user.display_name).collect(),
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("failed to get twitch users"));
         }
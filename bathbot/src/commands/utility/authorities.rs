@@ -3,7 +3,7 @@ use std::fmt::Write;
 use bathbot_macros::command;
 use bathbot_model::twilight::id::ArchivedId;
 use bathbot_psql::model::configs::{Authorities, GuildConfig};
-use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{MessageBuilder, i18n::ErrorKey, matcher};
 use eyre::{Report, Result};
 use twilight_model::{
     guild::Permissions,
@@ -31,7 +31,7 @@ use crate::{
 #[usage("[@role1] [id of role2] ...")]
 #[example("-show", "@Moderator @Mod 83794728403223 @BotCommander")]
 #[alias("authority")]
-#[flags(AUTHORITY, ONLY_GUILDS, SKIP_DEFER)]
+#[flags(AUTHORITY, AUDITABLE, ONLY_GUILDS, SKIP_DEFER)]
 #[group(Utility)]
 async fn prefix_authorities(msg: &Message, mut args: Args<'_>) -> Result<()> {
     match AuthorityCommandKind::args(&mut args) {
@@ -67,7 +67,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
             };
 
             if let Err(err) = Context::guild_config().update(guild_id, f).await {
-                let _ = orig.error_callback(GENERAL_ISSUE).await;
+                let _ = orig.error_callback_tr(ErrorKey::General).await;
 
                 return Err(err.wrap_err("failed to update guild config"));
             }
@@ -107,7 +107,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
                         .collect(),
                     Ok(None) => Vec::new(),
                     Err(err) => {
-                        let _ = orig.error_callback(GENERAL_ISSUE).await;
+                        let _ = orig.error_callback_tr(ErrorKey::General).await;
 
                         return Err(Report::new(err));
                     }
@@ -120,7 +120,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
                             || roles.iter().any(|&new| new == role.id && new != role_id)
                     }),
                     Err(err) => {
-                        let _ = orig.error_callback(GENERAL_ISSUE).await;
+                        let _ = orig.error_callback_tr(ErrorKey::General).await;
 
                         return Err(Report::new(err));
                     }
@@ -137,7 +137,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
             let f = |config: &mut GuildConfig| config.authorities.retain(|id| *id != role_id);
 
             if let Err(err) = Context::guild_config().update(guild_id, f).await {
-                let _ = orig.error_callback(GENERAL_ISSUE).await;
+                let _ = orig.error_callback_tr(ErrorKey::General).await;
 
                 return Err(err.wrap_err("failed to update guild config"));
             }
@@ -165,7 +165,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
                         .collect(),
                     Ok(None) => Vec::new(),
                     Err(err) => {
-                        let _ = orig.error_callback(GENERAL_ISSUE).await;
+                        let _ = orig.error_callback_tr(ErrorKey::General).await;
 
                         return Err(Report::new(err));
                     }
@@ -177,7 +177,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
                             .contains(Permissions::ADMINISTRATOR)
                     }),
                     Err(err) => {
-                        let _ = orig.error_callback(GENERAL_ISSUE).await;
+                        let _ = orig.error_callback_tr(ErrorKey::General).await;
 
                         return Err(Report::new(err));
                     }
@@ -194,7 +194,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
             let f = |config: &mut GuildConfig| config.authorities.clear();
 
             if let Err(err) = Context::guild_config().update(guild_id, f).await {
-                let _ = orig.error_callback(GENERAL_ISSUE).await;
+                let _ = orig.error_callback_tr(ErrorKey::General).await;
 
                 return Err(err.wrap_err("Failed to update guild config"));
             }
@@ -226,7 +226,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
                         .collect(),
                     Ok(None) => Vec::new(),
                     Err(err) => {
-                        let _ = orig.error_callback(GENERAL_ISSUE).await;
+                        let _ = orig.error_callback_tr(ErrorKey::General).await;
 
                         return Err(Report::new(err));
                     }
@@ -239,7 +239,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
                             || roles.iter().any(|&new| new == role.id)
                     }),
                     Err(err) => {
-                        let _ = orig.error_callback(GENERAL_ISSUE).await;
+                        let _ = orig.error_callback_tr(ErrorKey::General).await;
 
                         return Err(Report::new(err));
                     }
@@ -256,7 +256,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
             let f = |config: &mut GuildConfig| config.authorities = roles.into_iter().collect();
 
             if let Err(err) = Context::guild_config().update(guild_id, f).await {
-                let _ = orig.error_callback(GENERAL_ISSUE).await;
+                let _ = orig.error_callback_tr(ErrorKey::General).await;
 
                 return Err(err.wrap_err("failed to update guild config"));
             }
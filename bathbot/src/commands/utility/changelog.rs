@@ -2,7 +2,7 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use bathbot_macros::{SlashCommand, command};
 use bathbot_model::{PullRequests, PullRequestsAndTags, ReferencedIssue, Tag};
-use bathbot_util::constants::{FIELD_VALUE_SIZE, GENERAL_ISSUE};
+use bathbot_util::{constants::FIELD_VALUE_SIZE, i18n::ErrorKey};
 use eyre::{ContextCompat, Result, WrapErr};
 use time::OffsetDateTime;
 use twilight_interactions::command::{CommandModel, CreateCommand};
@@ -36,14 +36,14 @@ async fn changelog(orig: CommandOrigin<'_>) -> Result<()> {
     let mut data = match Context::github().tags_and_prs().await {
         Ok(res) => res,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
     };
 
     if data.tags.len() != 25 {
-        let _ = orig.error(GENERAL_ISSUE).await;
+        let _ = orig.error_tr(ErrorKey::General).await;
 
         bail!("Expected 25 tags, got {}", data.tags.len());
     }
@@ -78,7 +78,7 @@ async fn create_pages(
     match pages_fut.await {
         Ok(res) => Ok(res),
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_tr(ErrorKey::General).await;
 
             Err(err.wrap_err("Failed to build pages"))
         }
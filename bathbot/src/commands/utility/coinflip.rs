@@ -0,0 +1,42 @@
+use bathbot_macros::{SlashCommand, command};
+use bathbot_util::MessageBuilder;
+use eyre::Result;
+use rand::random;
+use twilight_interactions::command::CreateCommand;
+use twilight_model::guild::Permissions;
+
+use super::roll::channel_cooldown;
+use crate::{core::commands::CommandOrigin, util::interaction::InteractionCommand};
+
+#[derive(CreateCommand, SlashCommand)]
+#[command(name = "coinflip", desc = "Flip a coin")]
+#[flags(SKIP_DEFER, NO_DATABASE)]
+pub struct Coinflip;
+
+async fn slash_coinflip(mut command: InteractionCommand) -> Result<()> {
+    coinflip((&mut command).into()).await
+}
+
+#[command]
+#[desc("Flip a coin")]
+#[aliases("flip", "coin")]
+#[flags(SKIP_DEFER, NO_DATABASE)]
+#[group(Utility)]
+async fn prefix_coinflip(msg: &Message, permissions: Option<Permissions>) -> Result<()> {
+    coinflip(CommandOrigin::from_msg(msg, permissions)).await
+}
+
+async fn coinflip(orig: CommandOrigin<'_>) -> Result<()> {
+    if let Some(content) = channel_cooldown(&orig).await {
+        return orig.error_callback(content).await;
+    }
+
+    let author_id = orig.user_id()?;
+    let side = if random() { "heads" } else { "tails" };
+    let description = format!("<@{author_id}> flips a coin and gets **{side}** :coin:");
+
+    let builder = MessageBuilder::new().embed(description);
+    orig.callback(builder).await?;
+
+    Ok(())
+}
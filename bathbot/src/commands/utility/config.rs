@@ -1,14 +1,15 @@
 use ::time::UtcOffset;
 use bathbot_macros::SlashCommand;
-use bathbot_model::command_fields::{ShowHideOption, TimezoneOption};
+use bathbot_model::command_fields::{EnableDisable, ShowHideOption, TimezoneOption};
 use bathbot_psql::model::configs::{
-    ListSize, OsuUserId, OsuUsername, Retries, ScoreData, UserConfig,
+    GraphTheme, GuildConfig, ListSize, OsuUserId, OsuUsername, Retries, ScoreData, TimestampStyle,
+    UserConfig,
 };
 #[cfg(feature = "server")]
 use bathbot_server::AuthenticationStandbyError;
-use bathbot_util::{Authored, constants::GENERAL_ISSUE};
 #[cfg(feature = "server")]
-use bathbot_util::{EmbedBuilder, MessageBuilder};
+use bathbot_util::EmbedBuilder;
+use bathbot_util::{Authored, MessageBuilder, ParsedMods, i18n::ErrorKey};
 use eyre::{Report, Result};
 use rosu_v2::prelude::GameMode;
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
@@ -17,20 +18,36 @@ use twilight_model::id::{Id, marker::UserMarker};
 use super::{SkinValidation, ValidationStatus};
 use crate::{
     Context,
-    embeds::{ConfigEmbed, EmbedData},
+    embeds::{ConfigEmbed, ConfigListEmbed, EmbedData},
     util::{InteractionCommandExt, interaction::InteractionCommand},
 };
 #[cfg(feature = "server")]
 use crate::{core::BotConfig, util::Emote};
 
-#[cfg(feature = "server")]
-#[derive(CommandModel, CreateCommand, Default, SlashCommand)]
+#[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(
     name = "config",
-    desc = "Adjust your default configuration for commands"
+    desc = "Adjust or list your configuration for commands",
+    help = "Adjust or list your configuration for commands.\n\
+    Settings that used to be options of `/config` itself are now adjusted through \
+    `/config edit` with the same options."
 )]
 #[flags(EPHEMERAL)]
-pub struct Config {
+pub enum Config {
+    #[command(name = "edit")]
+    Edit(ConfigEdit),
+    #[command(name = "list")]
+    List(ConfigList),
+    #[command(name = "skin")]
+    Skin(ConfigSkin),
+    #[command(name = "privacy")]
+    Privacy(ConfigPrivacy),
+}
+
+#[cfg(feature = "server")]
+#[derive(CommandModel, CreateCommand, Default)]
+#[command(name = "edit", desc = "Adjust your default configuration for commands")]
+pub struct ConfigEdit {
     #[command(
         desc = "Specify whether you want to link to an osu! profile",
         help = "Most osu! commands require a specified username to work.\n\
@@ -60,7 +77,7 @@ pub struct Config {
     mode: Option<ConfigGameMode>,
     #[command(
         desc = "Adjust the amount of scores shown per page in top, rb, pinned, ...",
-        help = "Adjust the amount of scores shown per page in `/top`, `/rb`, `/pinned`, and `/mapper`.\n\
+        help = "Adjust the amount of scores shown per page in `/top`, `/pinned`, and `/mapper`.\n\
         `Condensed` shows 10 scores, `Detailed` shows 5, and `Single` shows 1."
     )]
     list_embeds: Option<ListSize>,
@@ -84,11 +101,26 @@ pub struct Config {
         desc = "Should the recent command include a render button?",
         help = "Should the `recent` command include a render button?\n\
         The button would be a shortcut for the `/render` command.\n\
-        In servers, this requires that the render button is not disabled in `/serverconfigs`."
+        In servers, this requires that the render button is not disabled \
+        in `/serverconfig edit`."
     )]
     render_button: Option<ShowHideOption>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
+    #[command(desc = TIMESTAMP_STYLE_DESC, help = TIMESTAMP_STYLE_HELP)]
+    timestamp_style: Option<TimestampStyle>,
+    #[command(desc = RECENT_FAILS_DESC, help = RECENT_FAILS_HELP)]
+    recent_fails: Option<ShowHideOption>,
+    #[command(desc = GRAPH_THEME_DESC, help = GRAPH_THEME_HELP)]
+    graph_theme: Option<GraphTheme>,
+    #[command(desc = BLACKLIST_MODS_DESC, help = BLACKLIST_MODS_HELP)]
+    blacklist_mods: Option<String>,
+    #[command(desc = EXTENDED_PROFILE_DESC, help = EXTENDED_PROFILE_HELP)]
+    extended_profile: Option<ShowHideOption>,
+    #[command(desc = TRACKING_DM_DIGEST_DESC, help = TRACKING_DM_DIGEST_HELP)]
+    tracking_dm_digest: Option<EnableDisable>,
+    #[command(desc = OSUTRACK_AUTO_UPDATE_DESC, help = OSUTRACK_AUTO_UPDATE_HELP)]
+    osutrack_auto_update: Option<EnableDisable>,
 }
 
 pub const SCORE_DATA_DESC: &str = "Whether scores should be requested as lazer or stable scores";
@@ -96,16 +128,61 @@ pub const SCORE_DATA_DESC: &str = "Whether scores should be requested as lazer o
 pub const SCORE_DATA_HELP: &str = "Whether scores should be requested as lazer or stable scores.\n\
 They have a different score and grade calculation and only lazer adds the new mods.";
 
+pub const TIMESTAMP_STYLE_DESC: &str = "How dates such as score or ranked dates should be shown";
+
+pub const TIMESTAMP_STYLE_HELP: &str = "How dates such as score or ranked dates should be shown.\n\
+`Relative` shows e.g. `3 hours ago`, `Local time` shows the date in your own timezone, \
+and `UTC text` shows the plain UTC date.\n\
+Since embed footers can't display discord timestamps, dates within them are always shown as UTC.\n\
+Tracking notifications are seen by the whole channel so they always use the default style.";
+
+pub const RECENT_FAILS_DESC: &str = "Should the recent command include failed scores by default?";
+
+pub const RECENT_FAILS_HELP: &str = "Should the `recent` command include failed scores by default?\n\
+If hidden, the recent index skips fails so e.g. `rs 2` shows the second most recent pass.\n\
+The `fails` option of `/rs` overrides this setting.";
+
+pub const GRAPH_THEME_DESC: &str = "Color scheme of graphs";
+
+pub const GRAPH_THEME_HELP: &str = "Color scheme of graphs.\n\
+`Colorblind` uses the Okabe-Ito palette whose colors stay distinguishable for all common \
+types of color vision deficiency.";
+
+pub const BLACKLIST_MODS_DESC: &str = "Mods you don't play, e.g. `DT`, or `none` to clear";
+
+pub const BLACKLIST_MODS_HELP: &str = "Mods you don't play, e.g. `DT` or `HDDT`.\n\
+If a command such as `/rank pp` or `/whatif` refers to a score that likely requires those mods, \
+it will point that out.\n\
+Specify `none` to clear the blacklist.";
+
+pub const EXTENDED_PROFILE_DESC: &str = "Show additional profile rows such as the score rank";
+
+pub const EXTENDED_PROFILE_HELP: &str = "Should profiles include additional rows by default?\n\
+Currently this adds the score rank from [respektive](https://score.respektive.pw) to the compact \
+profile page and, for osu!standard, a skill radar derived from the top200 scores.\n\
+The `score_rank` option of `/profile` overrides the score rank part of this setting.";
+
+pub const TRACKING_DM_DIGEST_DESC: &str = "Receive a daily DM with your tracked top plays";
+
+pub const TRACKING_DM_DIGEST_HELP: &str = "Receive a daily DM listing your new top plays \
+that were announced by osu! tracking in any server.\n\
+Requires a linked osu! profile.\n\
+If the bot fails to DM you on three consecutive days, the digest is disabled again.";
+
+pub const OSUTRACK_AUTO_UPDATE_DESC: &str =
+    "Record an osu!track datapoint when you look yourself up";
+
+pub const OSUTRACK_AUTO_UPDATE_HELP: &str = "Record an osu!track datapoint when you look yourself up.\n\
+[osu!track](https://ameobea.me/osutrack) only records your stats when someone triggers an \
+update. If enabled, using `profile` or `top` on your own linked account triggers such an update \
+in the background, at most once every six hours per mode.";
+
 // FIXME: Some attribute command does not register the #[cfg(feature = "")]
 // tag on fields so we need an entirely new struct for now
 #[cfg(not(feature = "server"))]
-#[derive(CommandModel, CreateCommand, Default, SlashCommand)]
-#[command(
-    name = "config",
-    desc = "Adjust your default configuration for commands"
-)]
-#[flags(EPHEMERAL)]
-pub struct Config {
+#[derive(CommandModel, CreateCommand, Default)]
+#[command(name = "edit", desc = "Adjust your default configuration for commands")]
+pub struct ConfigEdit {
     #[command(
         desc = "Specify a gamemode (NOTE: Only use for non-std modes if you NEVER use std commands)",
         help = "Always having to specify the `mode` option for any non-std \
@@ -139,13 +216,72 @@ pub struct Config {
         desc = "Should the recent command include a render button?",
         help = "Should the `recent` command include a render button?\n\
         The button would be a shortcut for the `/render` command.\n\
-        In servers, this requires that the render button is not disabled in `/serverconfigs`."
+        In servers, this requires that the render button is not disabled \
+        in `/serverconfig edit`."
     )]
     render_button: Option<ShowHideOption>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
+    #[command(desc = TIMESTAMP_STYLE_DESC, help = TIMESTAMP_STYLE_HELP)]
+    timestamp_style: Option<TimestampStyle>,
+    #[command(desc = RECENT_FAILS_DESC, help = RECENT_FAILS_HELP)]
+    recent_fails: Option<ShowHideOption>,
+    #[command(desc = GRAPH_THEME_DESC, help = GRAPH_THEME_HELP)]
+    graph_theme: Option<GraphTheme>,
+    #[command(desc = BLACKLIST_MODS_DESC, help = BLACKLIST_MODS_HELP)]
+    blacklist_mods: Option<String>,
+    #[command(desc = EXTENDED_PROFILE_DESC, help = EXTENDED_PROFILE_HELP)]
+    extended_profile: Option<ShowHideOption>,
+    #[command(desc = TRACKING_DM_DIGEST_DESC, help = TRACKING_DM_DIGEST_HELP)]
+    tracking_dm_digest: Option<EnableDisable>,
+    #[command(desc = OSUTRACK_AUTO_UPDATE_DESC, help = OSUTRACK_AUTO_UPDATE_HELP)]
+    osutrack_auto_update: Option<EnableDisable>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "list",
+    desc = "List your settings and the values that commands end up using",
+    help = "List your settings and the values that commands end up using.\n\
+    Some settings can also be configured by the server through `/serverconfig`. \
+    The `Effective` column shows which value is used if a command does not specify \
+    the option itself."
+)]
+pub struct ConfigList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "skin",
+    desc = "Specify the skin that is shown on your scores",
+    help = "Specify the skin that is shown on your scores.\n\
+    If your score embed contains the `Skin` value (see `/builder`), the skin's name \
+    will be shown on your own scores, linked to the given URL if there is one.\n\
+    Use `none` as name to remove your skin."
+)]
+pub struct ConfigSkin {
+    #[command(desc = "Name of the skin (max 50 characters) or `none` to remove it")]
+    name: String,
+    #[command(desc = "Link to download the skin")]
+    url: Option<String>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "privacy",
+    desc = "Adjust where you show up",
+    help = "Adjust where you show up.\n\
+    Hiding yourself from server lists excludes you from server-wide leaderboards \
+    such as `/serverleaderboard` or the leaderboards of `/higherlower` and the bg game. \
+    Commands that look you up directly are not affected."
+)]
+pub struct ConfigPrivacy {
+    #[command(desc = "Should you be hidden from server leaderboards?")]
+    hide_from_server_lists: bool,
 }
 
+/// Maximum amount of characters of a skin name.
+const SKIN_NAME_MAX_LEN: usize = 50;
+
 #[cfg(feature = "server")]
 #[derive(CommandOption, CreateOption)]
 pub enum ConfigLink {
@@ -182,13 +318,162 @@ impl From<ConfigGameMode> for Option<GameMode> {
 }
 
 async fn slash_config(mut command: InteractionCommand) -> Result<()> {
-    let args = Config::from_interaction(command.input_data())?;
+    match Config::from_interaction(command.input_data())? {
+        Config::Edit(args) => config(command, args).await,
+        Config::List(_) => config_list(command).await,
+        Config::Skin(args) => config_skin(command, args).await,
+        Config::Privacy(args) => config_privacy(command, args).await,
+    }
+}
+
+async fn config_privacy(command: InteractionCommand, args: ConfigPrivacy) -> Result<()> {
+    let author = command.user()?;
+
+    let mut config = match Context::user_config().with_osu_id(author.id).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    config.hide_from_server_lists = Some(args.hide_from_server_lists);
+
+    if let Err(err) = Context::user_config().store(author.id, &config).await {
+        let _ = command.error_tr(ErrorKey::General).await;
 
-    config(command, args).await
+        return Err(err);
+    }
+
+    let content = if args.hide_from_server_lists {
+        "You will no longer show up in server leaderboards"
+    } else {
+        "You will show up in server leaderboards again"
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
 }
 
-pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
-    let Config {
+async fn config_skin(command: InteractionCommand, args: ConfigSkin) -> Result<()> {
+    let skin = match validate_skin_display(&args.name, args.url.as_deref()) {
+        Ok(skin) => skin,
+        Err(content) => {
+            command.error(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    let author = command.user()?;
+
+    let mut config = match Context::user_config().with_osu_id(author.id).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let content = match skin {
+        Some((name, url)) => {
+            let content = format!("Your scores will now show the skin `{name}`");
+            config.skin_display_name = Some(name.to_owned());
+            config.skin_display_url = url.map(str::to_owned);
+
+            content
+        }
+        None => {
+            config.skin_display_name = None;
+            config.skin_display_url = None;
+
+            "Your scores will no longer show a skin".to_owned()
+        }
+    };
+
+    if let Err(err) = Context::user_config().store(author.id, &config).await {
+        let _ = command.error_tr(ErrorKey::General).await;
+
+        return Err(err);
+    }
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+/// Validate the skin name and URL.
+///
+/// Returns `None` if the skin should be removed.
+fn validate_skin_display<'a>(
+    name: &'a str,
+    url: Option<&'a str>,
+) -> Result<Option<(&'a str, Option<&'a str>)>, String> {
+    let name = name.trim();
+
+    if name.eq_ignore_ascii_case("none") {
+        return Ok(None);
+    } else if name.is_empty() {
+        return Err("The skin name must not be empty".to_owned());
+    } else if name.chars().count() > SKIN_NAME_MAX_LEN {
+        return Err(format!(
+            "The skin name must be at most {SKIN_NAME_MAX_LEN} characters long"
+        ));
+    } else if name.contains(['\n', '`']) {
+        return Err("The skin name must not contain newlines or backticks".to_owned());
+    }
+
+    let url = url.map(str::trim).filter(|url| !url.is_empty());
+
+    if let Some(url) = url {
+        let valid = (url.starts_with("https://") || url.starts_with("http://"))
+            && !url.contains(char::is_whitespace);
+
+        if !valid {
+            return Err(
+                "The skin URL must be a link starting with `https://` or `http://`".to_owned(),
+            );
+        }
+    }
+
+    Ok(Some((name, url)))
+}
+
+async fn config_list(command: InteractionCommand) -> Result<()> {
+    let author = command.user()?;
+
+    let config = match Context::user_config().with_osu_id(author.id).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let guild_config = match command.guild_id {
+        Some(guild_id) => Some(
+            Context::guild_config()
+                .peek(guild_id, GuildConfig::clone)
+                .await,
+        ),
+        None => None,
+    };
+
+    let embed_data = ConfigListEmbed::new(author, &config, guild_config.as_ref());
+    let builder = embed_data.build().into();
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+pub async fn config(command: InteractionCommand, config: ConfigEdit) -> Result<()> {
+    let ConfigEdit {
         #[cfg(feature = "server")]
         osu,
         #[cfg(feature = "server")]
@@ -200,8 +485,33 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
         mut skin_url,
         render_button,
         score_data,
+        timestamp_style,
+        recent_fails,
+        graph_theme,
+        blacklist_mods,
+        extended_profile,
+        tracking_dm_digest,
+        osutrack_auto_update,
     } = config;
 
+    let blacklisted_mods = match blacklist_mods {
+        Some(input) if input.trim().eq_ignore_ascii_case("none") => Some(None),
+        Some(input) => match ParsedMods::parse(&input) {
+            parsed if parsed.is_valid() && !parsed.mods.is_empty() => Some(Some(parsed.mods)),
+            _ => {
+                let content = format!(
+                    "Failed to parse `{input}` as mods. \
+                    Specify acronyms such as `DT` or `HDDT`, or `none` to clear the blacklist."
+                );
+
+                command.error(content).await?;
+
+                return Ok(());
+            }
+        },
+        None => None,
+    };
+
     if let Some(ref skin_url) = skin_url {
         match SkinValidation::check(&command, skin_url).await? {
             ValidationStatus::Continue => {}
@@ -214,7 +524,7 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
     let mut config = match Context::user_config().with_osu_id(author.id).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -249,6 +559,34 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
         config.score_data = Some(score_data);
     }
 
+    if let Some(timestamp_style) = timestamp_style {
+        config.timestamp_style = Some(timestamp_style);
+    }
+
+    if let Some(recent_fails) = recent_fails {
+        config.recent_includes_fails = Some(matches!(recent_fails, ShowHideOption::Show));
+    }
+
+    if let Some(graph_theme) = graph_theme {
+        config.graph_theme = Some(graph_theme);
+    }
+
+    if let Some(blacklisted_mods) = blacklisted_mods {
+        config.blacklisted_mods = blacklisted_mods;
+    }
+
+    if let Some(extended_profile) = extended_profile {
+        config.extended_profile = Some(matches!(extended_profile, ShowHideOption::Show));
+    }
+
+    if let Some(tracking_dm_digest) = tracking_dm_digest {
+        config.tracking_dm_digest = Some(matches!(tracking_dm_digest, EnableDisable::Enable));
+    }
+
+    if let Some(osutrack_auto_update) = osutrack_auto_update {
+        config.osutrack_auto_update = Some(matches!(osutrack_auto_update, EnableDisable::Enable));
+    }
+
     #[cfg(feature = "server")]
     if let Some(ConfigLink::Unlink) = osu {
         config.osu.take();
@@ -280,7 +618,7 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
                 let update_fut = Context::user_config().update_skin(author.id, Some(skin_url));
 
                 if let Err(err) = update_fut.await {
-                    command.error(GENERAL_ISSUE).await?;
+                    command.error_tr(ErrorKey::General).await?;
 
                     return Err(err);
                 }
@@ -381,7 +719,7 @@ async fn handle_both_links(
     };
 
     if let Err(err) = Context::user_config().store(author.id, config).await {
-        let _ = command.error(GENERAL_ISSUE).await;
+        let _ = command.error_tr(ErrorKey::General).await;
 
         return HandleResult::Err(err);
     }
@@ -418,7 +756,7 @@ async fn handle_twitch_link(
     };
 
     if let Err(err) = Context::user_config().store(author.id, config).await {
-        let _ = command.error(GENERAL_ISSUE).await;
+        let _ = command.error_tr(ErrorKey::General).await;
 
         return HandleResult::Err(err);
     }
@@ -478,7 +816,7 @@ async fn handle_osu_link(
     }
 
     if let Err(err) = Context::user_config().store(author.id, config).await {
-        let _ = command.error(GENERAL_ISSUE).await;
+        let _ = command.error_tr(ErrorKey::General).await;
 
         return HandleResult::Err(err);
     }
@@ -496,13 +834,15 @@ async fn handle_ephemeral<T>(
         return Some(Err(eyre::Report::new(err)));
     }
 
-    let content = match fut.await {
+    let res = match fut.await {
         Ok(res) => return Some(Ok(res)),
-        Err(AuthenticationStandbyError::Timeout) => "You did not authenticate in time",
-        Err(AuthenticationStandbyError::Canceled) => GENERAL_ISSUE,
+        Err(AuthenticationStandbyError::Timeout) => {
+            command.error("You did not authenticate in time").await
+        }
+        Err(AuthenticationStandbyError::Canceled) => command.error_tr(ErrorKey::General).await,
     };
 
-    if let Err(err) = command.error(content).await {
+    if let Err(err) = res {
         return Some(Err(err.into()));
     }
 
@@ -544,7 +884,7 @@ async fn handle_no_links(
     }
 
     if let Err(err) = Context::user_config().store(author.id, config).await {
-        let _ = command.error(GENERAL_ISSUE).await;
+        let _ = command.error_tr(ErrorKey::General).await;
 
         return HandleResult::Err(err);
     }
@@ -580,6 +920,16 @@ async fn convert_config(
         timezone,
         render_button,
         score_data,
+        timestamp_style,
+        recent_includes_fails,
+        graph_theme,
+        blacklisted_mods,
+        extended_profile,
+        tracking_dm_digest,
+        skin_display_name,
+        skin_display_url,
+        hide_from_server_lists,
+        osutrack_auto_update,
     } = config;
 
     UserConfig {
@@ -592,6 +942,16 @@ async fn convert_config(
         timezone,
         render_button,
         score_data,
+        timestamp_style,
+        recent_includes_fails,
+        graph_theme,
+        blacklisted_mods,
+        extended_profile,
+        tracking_dm_digest,
+        skin_display_name,
+        skin_display_url,
+        hide_from_server_lists,
+        osutrack_auto_update,
     }
 }
 
@@ -601,3 +961,30 @@ enum HandleResult {
     Done,
     Err(Report),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_skin_display() {
+        assert_eq!(
+            validate_skin_display(" My Skin ", None),
+            Ok(Some(("My Skin", None)))
+        );
+        assert_eq!(
+            validate_skin_display("My Skin", Some("https://example.com/skin.osk")),
+            Ok(Some(("My Skin", Some("https://example.com/skin.osk"))))
+        );
+        assert_eq!(validate_skin_display("None", Some("https://a.b")), Ok(None));
+    }
+
+    #[test]
+    fn invalid_skin_display() {
+        assert!(validate_skin_display("  ", None).is_err());
+        assert!(validate_skin_display(&"a".repeat(51), None).is_err());
+        assert!(validate_skin_display(&"a".repeat(50), None).is_ok());
+        assert!(validate_skin_display("skin", Some("ftp://example.com")).is_err());
+        assert!(validate_skin_display("skin", Some("https://exa mple.com")).is_err());
+    }
+}
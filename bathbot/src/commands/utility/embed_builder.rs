@@ -1,11 +1,15 @@
 use std::{sync::Arc, time::Duration};
 
 use bathbot_macros::SlashCommand;
-use bathbot_model::{PersonalBestIndex, ScoreSlim, embed_builder::ScoreEmbedSettings};
-use bathbot_psql::model::configs::ScoreData;
+use bathbot_model::{
+    PersonalBestIndex, ScoreSlim,
+    embed_builder::{ScoreEmbedPatch, ScoreEmbedSettings},
+};
+use bathbot_psql::model::configs::{RevisedScoreEmbed, ScoreData};
 use bathbot_util::{
     Authored, CowUtils, MessageOrigin,
-    constants::GENERAL_ISSUE,
+    i18n::ErrorKey,
+    osu::is_unranked_pp,
     query::{FilterCriteria, Searchable, TopCriteria},
 };
 use eyre::{Report, Result};
@@ -22,8 +26,11 @@ use twilight_model::id::{
 };
 
 use crate::{
-    active::{ActiveMessages, impls::ScoreEmbedBuilderActive},
-    core::Context,
+    active::{
+        ActiveMessages,
+        impls::{ScoreEmbedBuilderActive, ScoreSkin},
+    },
+    core::{Context, ConvertValues},
     manager::{MapError, OsuMap, PpManager, redis::osu::UserArgsSlim},
     util::{InteractionCommandExt, interaction::InteractionCommand, osu::IfFc},
 };
@@ -75,10 +82,12 @@ pub async fn slash_scoreembedbuilder(mut command: InteractionCommand) -> Result<
 }
 
 async fn edit(command: &mut InteractionCommand) -> Result<()> {
-    let config = match Context::user_config().with_osu_id(command.user_id()?).await {
+    let author = command.user_id()?;
+
+    let config = match Context::user_config().with_osu_id(author).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to get user config"));
         }
@@ -95,9 +104,18 @@ async fn edit(command: &mut InteractionCommand) -> Result<()> {
         },
     };
 
-    let settings = config.score_embed.unwrap_or_default();
+    let settings = match Context::user_config().score_embed_settings(author).await {
+        Ok(settings) => settings,
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let skin = ScoreSkin::from_config(&config);
 
-    exec(command, settings, score_data).await
+    exec(command, settings, score_data, skin).await
 }
 
 async fn copy(command: &mut InteractionCommand, args: ScoreEmbedBuilderCopy) -> Result<()> {
@@ -109,7 +127,7 @@ async fn copy(command: &mut InteractionCommand, args: ScoreEmbedBuilderCopy) ->
     let (config1, config2) = match tokio::try_join!(config_fut1, config_fut2) {
         Ok(tuple) => tuple,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to get user config"));
         }
@@ -127,14 +145,10 @@ async fn copy(command: &mut InteractionCommand, args: ScoreEmbedBuilderCopy) ->
     };
 
     let settings = config2.score_embed.unwrap_or_default();
+    let settings = store_settings(author, settings).await;
+    let skin = ScoreSkin::from_config(&config1);
 
-    let store_fut = Context::user_config().store_score_embed_settings(author, &settings);
-
-    if let Err(err) = store_fut.await {
-        warn!(?err);
-    }
-
-    exec(command, settings, score_data).await
+    exec(command, settings, score_data, skin).await
 }
 
 async fn default(command: &mut InteractionCommand) -> Result<()> {
@@ -143,7 +157,7 @@ async fn default(command: &mut InteractionCommand) -> Result<()> {
     let config = match Context::user_config().with_osu_id(author).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(err.wrap_err("Failed to get user config"));
         }
@@ -160,21 +174,46 @@ async fn default(command: &mut InteractionCommand) -> Result<()> {
         },
     };
 
-    let settings = ScoreEmbedSettings::default();
+    let settings = store_settings(author, ScoreEmbedSettings::default()).await;
+    let skin = ScoreSkin::from_config(&config);
 
-    let store_fut = Context::user_config().store_score_embed_settings(author, &settings);
+    exec(command, settings, score_data, skin).await
+}
 
-    if let Err(err) = store_fut.await {
-        warn!(?err);
-    }
+/// Overwrite the stored settings with the given ones.
+async fn store_settings(
+    user_id: Id<UserMarker>,
+    settings: ScoreEmbedSettings,
+) -> RevisedScoreEmbed {
+    let store_fut = async {
+        let user_config = Context::user_config();
+        let current = user_config.score_embed_settings(user_id).await?;
+        let patch = ScoreEmbedPatch::diff(&current.settings.unwrap_or_default(), &settings);
+
+        user_config
+            .patch_score_embed_settings(user_id, &patch, current.revision)
+            .await
+    };
+
+    match store_fut.await {
+        Ok(revised) => revised,
+        Err(err) => {
+            warn!(?err, "Failed to store score embed settings");
 
-    exec(command, settings, score_data).await
+            // Changes in the builder are replayed on top of the stored settings
+            RevisedScoreEmbed {
+                settings: Some(settings),
+                revision: 0,
+            }
+        }
+    }
 }
 
 async fn exec(
     command: &mut InteractionCommand,
-    settings: ScoreEmbedSettings,
+    settings: RevisedScoreEmbed,
     score_data: ScoreData,
+    skin: Option<ScoreSkin>,
 ) -> Result<()> {
     let msg_owner = command.user_id()?;
     let legacy_scores = score_data.is_legacy();
@@ -189,7 +228,7 @@ async fn exec(
     let (user, score, map) = match tokio::join!(user_fut, score_fut, map_fut) {
         (Ok(user), Ok(score), Ok(map)) => (user, score.score, map),
         (user_res, score_res, map_res) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             let (err, wrap) = if let Err(err) = user_res {
                 (Report::new(err), "Failed to get user for builder")
@@ -212,7 +251,8 @@ async fn exec(
         data.score.statistics.perfect = 480;
     }
 
-    let active_msg = ScoreEmbedBuilderActive::new(&user, data, settings, score_data, msg_owner);
+    let active_msg =
+        ScoreEmbedBuilderActive::new(&user, data, settings, score_data, skin, msg_owner);
 
     ActiveMessages::builder(active_msg)
         .start_by_update(true)
@@ -304,12 +344,16 @@ impl ScoreEmbedDataWrap {
             },
         };
 
+        let unranked_pp =
+            is_unranked_pp(Some(map.status()), &score.mods, score.ranked) == Some(true);
+        let user_id = score.user_id;
         let score = ScoreSlim::new(score, pp);
 
         let if_fc_pp = IfFc::new(&score, &map).await.map(|if_fc| if_fc.pp);
 
         Self {
             inner: ScoreEmbedDataStatus::Full(ScoreEmbedData {
+                user_id,
                 score,
                 map,
                 stars,
@@ -320,6 +364,7 @@ impl ScoreEmbedDataWrap {
                 pb_idx: Some(ScoreEmbedDataPersonalBest::from_index(pb_idx)),
                 global_idx,
                 if_fc_pp,
+                unranked_pp,
                 #[cfg(feature = "twitch")]
                 twitch: None,
             }),
@@ -418,6 +463,7 @@ pub struct ScoreEmbedDataHalf {
     pub legacy_scores: bool,
     pub with_render: bool,
     pub has_replay: bool,
+    pub unranked_pp: bool,
     pub miss_analyzer_check: MissAnalyzerCheck,
     pub original_idx: Option<usize>,
 }
@@ -431,22 +477,53 @@ impl ScoreEmbedDataHalf {
         with_render: bool,
         miss_analyzer_check: MissAnalyzerCheck,
     ) -> Self {
+        let (half, _) = Self::with_convert_values(
+            score,
+            map,
+            pb_idx,
+            legacy_scores,
+            with_render,
+            miss_analyzer_check,
+            None,
+        )
+        .await;
+
+        half
+    }
+
+    /// Same as [`ScoreEmbedDataHalf::new`] but the values of a converted map
+    /// might be stored already.
+    ///
+    /// Also returns the values if they had to be calculated.
+    pub async fn with_convert_values(
+        score: Score,
+        map: OsuMap,
+        pb_idx: Option<ScoreEmbedDataPersonalBest>,
+        legacy_scores: bool,
+        with_render: bool,
+        miss_analyzer_check: MissAnalyzerCheck,
+        stored: Option<ConvertValues>,
+    ) -> (Self, Option<ConvertValues>) {
         let user_id = score.user_id;
+        let mut calc = PpAttrs::calculator(&map, score.mode, &score.mods, score.set_on_lazer);
+
+        let calculated = match stored {
+            Some(_) => None,
+            None => calc.performance().await.as_ref().map(ConvertValues::new),
+        };
 
         let PpAttrs {
             calc,
             stars,
             max_combo,
             max_pp,
-        } = PpAttrs::new(
-            &map,
+        } = PpAttrs::from_values(
+            calc,
+            stored.or(calculated),
             score.mode,
-            &score.mods,
             score.grade,
-            score.set_on_lazer,
             score.pp,
-        )
-        .await;
+        );
 
         let pp = match score.pp {
             Some(pp) => pp,
@@ -457,9 +534,11 @@ impl ScoreEmbedDataHalf {
         };
 
         let has_replay = score.has_replay;
+        let unranked_pp =
+            is_unranked_pp(Some(map.status()), &score.mods, score.ranked) == Some(true);
         let score = ScoreSlim::new(score, pp);
 
-        Self {
+        let half = Self {
             user_id,
             score,
             map,
@@ -470,9 +549,12 @@ impl ScoreEmbedDataHalf {
             legacy_scores,
             with_render,
             has_replay,
+            unranked_pp,
             miss_analyzer_check,
             original_idx: None,
-        }
+        };
+
+        (half, calculated)
     }
 
     async fn into_full(self) -> ScoreEmbedData {
@@ -550,6 +632,7 @@ impl ScoreEmbedDataHalf {
             .then_some(self.score.score_id);
 
         ScoreEmbedData {
+            user_id: self.user_id,
             score: self.score,
             map: self.map,
             stars: self.stars,
@@ -560,6 +643,7 @@ impl ScoreEmbedDataHalf {
             pb_idx: self.pb_idx,
             global_idx,
             if_fc_pp,
+            unranked_pp: self.unranked_pp,
             #[cfg(feature = "twitch")]
             twitch: None,
         }
@@ -587,6 +671,7 @@ impl ScoreEmbedDataHalf {
 }
 
 pub struct ScoreEmbedData {
+    pub user_id: u32,
     pub score: ScoreSlim,
     pub map: OsuMap,
     pub stars: f32,
@@ -597,6 +682,8 @@ pub struct ScoreEmbedData {
     pub pb_idx: Option<ScoreEmbedDataPersonalBest>,
     pub global_idx: Option<usize>,
     pub if_fc_pp: Option<f32>,
+    /// Whether the score's pp do not count towards the user's profile
+    pub unranked_pp: bool,
     #[cfg(feature = "twitch")]
     pub twitch: Option<Arc<TwitchData>>,
 }
@@ -743,6 +830,7 @@ pub struct ScoreEmbedDataRaw {
     pub is_legacy: bool,
     pub statistics: ScoreStatistics,
     pub has_replay: bool,
+    pub ranked: Option<bool>,
 }
 
 impl ScoreEmbedDataRaw {
@@ -779,6 +867,7 @@ impl ScoreEmbedDataRaw {
             is_legacy: score.legacy_score_id == Some(score.id),
             statistics: score.statistics,
             has_replay: score.replay,
+            ranked: score.ranked,
             set_on_lazer: score.set_on_lazer,
         }
     }
@@ -818,6 +907,8 @@ impl ScoreEmbedDataRaw {
             },
         };
 
+        let unranked_pp = is_unranked_pp(Some(map.status()), &self.mods, self.ranked) == Some(true);
+
         let score = ScoreSlim {
             accuracy: self.accuracy,
             ended_at: self.ended_at,
@@ -914,6 +1005,7 @@ impl ScoreEmbedDataRaw {
             .and_then(|pb_idx| ScoreEmbedDataPersonalBest::try_new(pb_idx, &self.origin));
 
         Ok(ScoreEmbedData {
+            user_id: self.user_id,
             score,
             map,
             stars,
@@ -924,6 +1016,7 @@ impl ScoreEmbedDataRaw {
             pb_idx,
             global_idx,
             if_fc_pp,
+            unranked_pp,
             #[cfg(feature = "twitch")]
             twitch: self.twitch,
         })
@@ -946,28 +1039,43 @@ impl<'m> PpAttrs<'m> {
         lazer: bool,
         pp: Option<f32>,
     ) -> Self {
-        let mut calc = Context::pp(map)
+        let mut calc = Self::calculator(map, mode, mods, lazer);
+        let values = calc.performance().await.as_ref().map(ConvertValues::new);
+
+        Self::from_values(calc, values, mode, grade, pp)
+    }
+
+    fn calculator(map: &'m OsuMap, mode: GameMode, mods: &GameMods, lazer: bool) -> PpManager<'m> {
+        Context::pp(map)
             .mode(mode)
             .mods(mods.to_owned())
-            .lazer(lazer);
-
-        let mut max_pp = 0.0;
-        let mut stars = 0.0;
-        let mut max_combo = 0;
+            .lazer(lazer)
+    }
 
-        if let Some(attrs) = calc.performance().await {
-            max_pp = pp
-                .filter(|_| grade.eq_letter(Grade::X) && mode != GameMode::Mania)
-                .unwrap_or(attrs.pp() as f32);
+    fn from_values(
+        calc: PpManager<'m>,
+        values: Option<ConvertValues>,
+        mode: GameMode,
+        grade: Grade,
+        pp: Option<f32>,
+    ) -> Self {
+        let Some(values) = values else {
+            return Self {
+                calc,
+                stars: 0.0,
+                max_combo: 0,
+                max_pp: 0.0,
+            };
+        };
 
-            stars = attrs.stars() as f32;
-            max_combo = attrs.max_combo();
-        }
+        let max_pp = pp
+            .filter(|_| grade.eq_letter(Grade::X) && mode != GameMode::Mania)
+            .unwrap_or(values.max_pp);
 
         Self {
             calc,
-            stars,
-            max_combo,
+            stars: values.stars,
+            max_combo: values.max_combo,
             max_pp,
         }
     }
@@ -10,13 +10,13 @@ use crate::{core::commands::CommandOrigin, util::interaction::InteractionCommand
 
 #[derive(CreateCommand, SlashCommand)]
 #[command(name = "invite", desc = "Invite me to your server")]
-#[flags(SKIP_DEFER)]
+#[flags(SKIP_DEFER, NO_DATABASE)]
 pub struct Invite;
 
 #[command]
 #[desc("Invite me to your server")]
 #[alias("inv")]
-#[flags(SKIP_DEFER)]
+#[flags(SKIP_DEFER, NO_DATABASE)]
 #[group(Utility)]
 async fn prefix_invite(msg: &Message) -> Result<()> {
     invite(msg.into()).await
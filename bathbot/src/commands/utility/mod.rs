@@ -1,14 +1,20 @@
 mod authorities;
 mod changelog;
+mod coinflip;
 mod commands;
 mod config;
 mod embed_builder;
 mod invite;
+mod pick;
 mod ping;
 mod prefix;
+mod releases;
 mod roll;
 mod server_config;
 mod skin;
 
 #[allow(unused_imports)]
-pub use self::{authorities::*, changelog::*, config::*, embed_builder::*, skin::*};
+pub use self::{
+    authorities::*, changelog::*, config::*, embed_builder::*, server_config::GuildConfigExport,
+    skin::*,
+};
@@ -0,0 +1,106 @@
+use bathbot_macros::{SlashCommand, command};
+use bathbot_util::{CowUtils, MessageBuilder};
+use eyre::Result;
+use rand::{seq::SliceRandom, thread_rng};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::guild::Permissions;
+
+use super::roll::channel_cooldown;
+use crate::{
+    core::commands::CommandOrigin,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(name = "pick", desc = "Pick one of multiple options")]
+#[flags(SKIP_DEFER, NO_DATABASE)]
+pub struct Pick {
+    #[command(desc = "Specify options separated by commas, e.g. `nm1, hd2, dt1`")]
+    options: String,
+}
+
+async fn slash_pick(mut command: InteractionCommand) -> Result<()> {
+    let args = Pick::from_interaction(command.input_data())?;
+
+    pick((&mut command).into(), &args.options).await
+}
+
+#[command]
+#[desc("Pick one of multiple options")]
+#[help(
+    "Pick one of multiple options.\n\
+    Options are separated by commas or, if there are no commas, by spaces."
+)]
+#[usage("[option1, option2, ...]")]
+#[examples("nm1, hd2, dt1", "red blue")]
+#[aliases("choose")]
+#[flags(SKIP_DEFER, NO_DATABASE)]
+#[group(Utility)]
+async fn prefix_pick(
+    msg: &Message,
+    args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    let options = args.rest();
+
+    pick(CommandOrigin::from_msg(msg, permissions), options).await
+}
+
+async fn pick(orig: CommandOrigin<'_>, options: &str) -> Result<()> {
+    let options = parse_options(options);
+
+    if options.len() < 2 {
+        let content = "You must specify at least two options, separated by commas";
+
+        return orig.error_callback(content).await;
+    }
+
+    if let Some(content) = channel_cooldown(&orig).await {
+        return orig.error_callback(content).await;
+    }
+
+    let author_id = orig.user_id()?;
+    let choice = options
+        .choose(&mut thread_rng())
+        .copied()
+        .unwrap_or_default();
+
+    let description = format!(
+        "<@{author_id}> gets **{}** out of {} options :crystal_ball:",
+        choice.cow_escape_markdown(),
+        options.len()
+    );
+
+    let builder = MessageBuilder::new().embed(description);
+    orig.callback(builder).await?;
+
+    Ok(())
+}
+
+/// Split by commas if there are any, otherwise by whitespace.
+fn parse_options(options: &str) -> Vec<&str> {
+    let options: Vec<_> = if options.contains(',') {
+        options.split(',').map(str::trim).collect()
+    } else {
+        options.split_whitespace().collect()
+    };
+
+    options
+        .into_iter()
+        .filter(|option| !option.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options() {
+        assert_eq!(parse_options("nm1, hd2,dt1"), ["nm1", "hd2", "dt1"]);
+        assert_eq!(parse_options("red blue"), ["red", "blue"]);
+        assert_eq!(parse_options("a b, c d"), ["a b", "c d"]);
+        assert_eq!(parse_options(" a,, ,b "), ["a", "b"]);
+        assert!(parse_options("   ").is_empty());
+    }
+}
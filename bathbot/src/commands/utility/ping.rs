@@ -16,7 +16,7 @@ receive a response from discord after sending a message.";
 
 #[derive(CreateCommand, SlashCommand)]
 #[command(name = "ping", desc = PING_DESC, help = PING_HELP)]
-#[flags(SKIP_DEFER)]
+#[flags(SKIP_DEFER, NO_DATABASE)]
 pub struct Ping;
 
 async fn slash_ping(mut command: InteractionCommand) -> Result<()> {
@@ -26,7 +26,7 @@ async fn slash_ping(mut command: InteractionCommand) -> Result<()> {
 #[command]
 #[desc(PING_DESC)]
 #[help(PING_HELP)]
-#[flags(SKIP_DEFER)]
+#[flags(SKIP_DEFER, NO_DATABASE)]
 #[group(Utility)]
 async fn prefix_ping(msg: &Message, permissions: Option<Permissions>) -> Result<()> {
     ping(CommandOrigin::from_msg(msg, permissions)).await
@@ -35,11 +35,9 @@ async fn prefix_ping(msg: &Message, permissions: Option<Permissions>) -> Result<
 async fn ping(orig: CommandOrigin<'_>) -> Result<()> {
     let builder = MessageBuilder::new().content("Pong");
     let start = Instant::now();
-    let response_raw = orig.callback_with_response(builder).await?;
+    let response = orig.callback_with_response(builder).await?;
     let elapsed = (Instant::now() - start).as_millis();
 
-    let response = response_raw.model().await?;
-
     let response = match orig {
         CommandOrigin::Message { permissions, .. } => {
             CommandOrigin::from_msg(&response, permissions)
@@ -3,12 +3,16 @@ use std::{cmp::Ordering, fmt::Write};
 use aho_corasick::{AhoCorasick, AhoCorasickBuilder};
 use bathbot_macros::command;
 use bathbot_psql::model::configs::GuildConfig;
-use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{MessageBuilder, i18n::ErrorKey, matcher};
 use eyre::Result;
 use once_cell::sync::OnceCell;
 use twilight_model::guild::Permissions;
 
-use crate::{Context, core::commands::checks::check_authority, util::ChannelExt};
+use crate::{
+    Context,
+    core::commands::{checks::check_authority, localize_error},
+    util::ChannelExt,
+};
 
 #[command]
 #[desc("Change my prefixes for a server")]
@@ -24,7 +28,7 @@ use crate::{Context, core::commands::checks::check_authority, util::ChannelExt};
 #[usage("[add / remove] [prefix]")]
 #[example("add $ 🍆 new_pref", "remove < !!")]
 #[alias("prefixes")]
-#[flags(ONLY_GUILDS, SKIP_DEFER)] // authority check is done manually
+#[flags(AUDITABLE, ONLY_GUILDS, SKIP_DEFER)] // authority check is done manually
 #[group(Utility)]
 async fn prefix_prefix(
     msg: &Message,
@@ -53,7 +57,8 @@ async fn prefix_prefix(
             return Ok(());
         }
         Err(err) => {
-            let _ = msg.error(GENERAL_ISSUE).await;
+            let content = localize_error(msg.guild_id, ErrorKey::General).await;
+            let _ = msg.error(content).await;
 
             return Err(err.wrap_err("Failed to check authority status"));
         }
@@ -157,7 +162,8 @@ async fn prefix_prefix(
             Ok(())
         }
         Err(err) => {
-            let _ = msg.error(GENERAL_ISSUE).await;
+            let content = localize_error(msg.guild_id, ErrorKey::General).await;
+            let _ = msg.error(content).await;
 
             Err(err.wrap_err("failed to update guild config"))
         }
@@ -212,4 +218,4 @@ impl PrefixValidator {
 
 static VALIDATOR: OnceCell<AhoCorasick> = OnceCell::new();
 
-const PREFIX_LIMIT: usize = 5;
+pub(super) const PREFIX_LIMIT: usize = 5;
@@ -0,0 +1,149 @@
+use std::fmt::Write;
+
+use bathbot_macros::SlashCommand;
+use bathbot_model::Release;
+use bathbot_util::{
+    EmbedBuilder, FooterBuilder, MessageBuilder,
+    constants::DESCRIPTION_SIZE,
+    i18n::ErrorKey,
+    markdown::{discord_markdown, truncate_lines},
+};
+use eyre::Result;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    core::Context,
+    manager::GithubReleases,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+const RELEASES_URL: &str = "https://github.com/MaxOhn/Bathbot/releases";
+
+/// Amount of releases shown when no version is specified.
+const SHOWN_RELEASES: usize = 5;
+
+/// Maximum length for the notes of a single release in the overview.
+const OVERVIEW_BODY_LEN: usize = 600;
+
+/// Maximum amount of list items per list before they're collapsed.
+const MAX_LIST_ITEMS: usize = 8;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "releases",
+    desc = "Show the release notes of recent bot versions",
+    help = "Show the release notes of the five most recent bot versions.\n\
+    For the list of all changes since the last version, use `/changelog`."
+)]
+pub struct Releases {
+    #[command(desc = "Specify a version, e.g. v3.2.0")]
+    version: Option<String>,
+}
+
+pub async fn slash_releases(mut command: InteractionCommand) -> Result<()> {
+    let args = Releases::from_interaction(command.input_data())?;
+
+    let data = match Context::github().releases().await {
+        Ok(Some(data)) => data,
+        Ok(None) => {
+            let content = "GitHub's ratelimit was reached, try again later";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error_tr(ErrorKey::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let GithubReleases { releases, stale } = data;
+
+    let embed = match args.version {
+        Some(version) => match find_release(&releases.inner, &version) {
+            Some(release) => single_embed(release),
+            None => {
+                let recent = releases
+                    .inner
+                    .iter()
+                    .take(SHOWN_RELEASES)
+                    .map(|release| format!("`{}`", release.tag))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                let content =
+                    format!("Found no release for version `{version}`, recent versions: {recent}");
+                command.error(content).await?;
+
+                return Ok(());
+            }
+        },
+        None => overview_embed(&releases.inner),
+    };
+
+    let embed = if stale {
+        embed.footer(FooterBuilder::new(
+            "GitHub's ratelimit was reached, releases might be outdated",
+        ))
+    } else {
+        embed
+    };
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+/// Find a release by its tag, ignoring a leading `v`.
+fn find_release<'r>(releases: &'r [Release], version: &str) -> Option<&'r Release> {
+    let version = version.trim();
+    let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+
+    releases.iter().find(|release| {
+        let tag = release.tag.strip_prefix(['v', 'V']).unwrap_or(&release.tag);
+
+        tag.eq_ignore_ascii_case(version)
+    })
+}
+
+fn release_notes(release: &Release) -> String {
+    match release.body.as_deref().map(str::trim) {
+        Some(body) if !body.is_empty() => discord_markdown(body, MAX_LIST_ITEMS),
+        _ => "*No release notes*".to_owned(),
+    }
+}
+
+fn overview_embed(releases: &[Release]) -> EmbedBuilder {
+    let mut description = String::with_capacity(DESCRIPTION_SIZE);
+
+    for release in releases.iter().take(SHOWN_RELEASES) {
+        let notes = truncate_lines(&release_notes(release), OVERVIEW_BODY_LEN);
+
+        let _ = write!(
+            description,
+            "**[{name}]({url})** • <t:{timestamp}:D>\n{notes}\n\n",
+            name = release.name.as_deref().unwrap_or(&release.tag),
+            url = release.html_url,
+            timestamp = release.published_at.unix_timestamp(),
+        );
+    }
+
+    if description.is_empty() {
+        description.push_str("No releases yet");
+    }
+
+    EmbedBuilder::new()
+        .title("Recent releases")
+        .url(RELEASES_URL)
+        .description(truncate_lines(description.trim_end(), DESCRIPTION_SIZE))
+}
+
+fn single_embed(release: &Release) -> EmbedBuilder {
+    EmbedBuilder::new()
+        .title(release.name.as_deref().unwrap_or(&release.tag))
+        .url(release.html_url.as_ref())
+        .description(truncate_lines(&release_notes(release), DESCRIPTION_SIZE))
+        .timestamp(release.published_at)
+}
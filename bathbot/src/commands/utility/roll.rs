@@ -1,11 +1,15 @@
 use bathbot_macros::{SlashCommand, command};
-use bathbot_util::MessageBuilder;
+use bathbot_util::{
+    BucketName, EmbedBuilder, FooterBuilder, MessageBuilder, SeededRng, matcher, tr,
+};
 use eyre::Result;
 use rand::{Rng, random, thread_rng};
+use time::OffsetDateTime;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::guild::Permissions;
 
 use crate::{
+    Context,
     core::commands::{CommandOrigin, prefix::ArgsNum},
     util::{InteractionCommandExt, interaction::InteractionCommand},
 };
@@ -14,10 +18,18 @@ const DEFAULT_LIMIT: u32 = 100;
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "roll", desc = "Roll a random number")]
-#[flags(SKIP_DEFER)]
+#[flags(SKIP_DEFER, NO_DATABASE)]
 pub struct Roll {
     #[command(desc = "Specify an upper limit or `random`, defaults to 100")]
     limit: Option<String>,
+    #[command(
+        desc = "Specify a map url or map id to make the roll verifiable",
+        help = "Specify a map url or map id to make the roll verifiable.\n\
+        The roll is seeded by the map, the channel, today's date, and the user \
+        so rolling again gives the same result. \
+        Useful for referees to check that a roll wasn't repeated."
+    )]
+    map: Option<String>,
 }
 
 async fn slash_roll(mut command: InteractionCommand) -> Result<()> {
@@ -29,7 +41,19 @@ async fn slash_roll(mut command: InteractionCommand) -> Result<()> {
         None => Some(DEFAULT_LIMIT),
     };
 
-    roll((&mut command).into(), limit).await
+    let map = match args.map.as_deref().map(matcher::get_osu_map_id) {
+        Some(Some(map_id)) => Some(map_id),
+        Some(None) => {
+            let content =
+                "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+        None => None,
+    };
+
+    roll((&mut command).into(), limit, map).await
 }
 
 #[command]
@@ -39,7 +63,7 @@ async fn slash_roll(mut command: InteractionCommand) -> Result<()> {
     If no upper limit is specified, it defaults to 100."
 )]
 #[usage("[upper limit]")]
-#[flags(SKIP_DEFER)]
+#[flags(SKIP_DEFER, NO_DATABASE)]
 #[group(Utility)]
 async fn prefix_roll(
     msg: &Message,
@@ -55,20 +79,101 @@ async fn prefix_roll(
         },
     };
 
-    roll(CommandOrigin::from_msg(msg, permissions), limit).await
+    roll(CommandOrigin::from_msg(msg, permissions), limit, None).await
+}
+
+#[command]
+#[desc("Get a random number seeded by a map")]
+#[help(
+    "Get a random number that is seeded by the map, the channel, today's date, \
+    and the user so rolling again gives the same result.\n\
+    Useful for referees to check that a roll wasn't repeated.\n\
+    If no upper limit is specified, it defaults to 100."
+)]
+#[usage("[map url / map id] [upper limit]")]
+#[examples("2240404", "https://osu.ppy.sh/beatmapsets/902425#osu/2240404 1000")]
+#[aliases("maproll")]
+#[flags(SKIP_DEFER, NO_DATABASE)]
+#[group(Utility)]
+async fn prefix_rollmap(
+    msg: &Message,
+    mut args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    let orig = CommandOrigin::from_msg(msg, permissions);
+
+    let Some(map) = args.next().and_then(matcher::get_osu_map_id) else {
+        let content = "You must specify a map url or map id to seed the roll with";
+
+        return orig.error(content).await;
+    };
+
+    let limit = match args.next().map(str::parse) {
+        Some(Ok(n)) => Some(n),
+        None | Some(Err(_)) => Some(DEFAULT_LIMIT),
+    };
+
+    roll(orig, limit, Some(map)).await
 }
 
-async fn roll(orig: CommandOrigin<'_>, limit: Option<u32>) -> Result<()> {
+async fn roll(orig: CommandOrigin<'_>, limit: Option<u32>, map: Option<u32>) -> Result<()> {
+    if let Some(content) = channel_cooldown(&orig).await {
+        return orig.error_callback(content).await;
+    }
+
     let author_id = orig.user_id()?;
-    let num = limit.map_or_else(random, |limit| thread_rng().gen_range(1..=limit.max(2)));
+
+    let (num, footer) = match map {
+        Some(map_id) => {
+            let channel_id = orig.channel_id();
+            let date = OffsetDateTime::now_utc().date();
+
+            let mut rng = SeededRng::new(&[
+                u64::from(map_id),
+                channel_id.get(),
+                date.to_julian_day() as u64,
+                author_id.get(),
+            ]);
+
+            let num = match limit {
+                Some(limit) => rng.gen_range(1..=u64::from(limit.max(2))) as u32,
+                None => rng.next_u64() as u32,
+            };
+
+            let footer =
+                format!("Seed: map {map_id} • channel {channel_id} • {date} • user {author_id}");
+
+            (num, Some(FooterBuilder::new(footer)))
+        }
+        None => {
+            let num = limit.map_or_else(random, |limit| thread_rng().gen_range(1..=limit.max(2)));
+
+            (num, None)
+        }
+    };
 
     let description = format!(
         "<@{author_id}> rolls {num} point{} :game_die:",
         if num == 1 { "" } else { "s" }
     );
 
-    let builder = MessageBuilder::new().embed(description);
+    let mut embed = EmbedBuilder::new().description(description);
+
+    if let Some(footer) = footer {
+        embed = embed.footer(footer);
+    }
+
+    let builder = MessageBuilder::new().embed(embed);
     orig.callback(builder).await?;
 
     Ok(())
 }
+
+/// Random commands share a ratelimit per channel so that lobbies don't get
+/// spammed.
+pub(super) async fn channel_cooldown(orig: &CommandOrigin<'_>) -> Option<String> {
+    let cooldown = Context::check_channel_ratelimit(orig.channel_id(), BucketName::Roll)?;
+    let lang = Context::guild_config().lang(orig.guild_id()).await;
+
+    Some(tr!("cooldown", lang, seconds = cooldown))
+}
@@ -0,0 +1,835 @@
+use std::fmt::Write;
+
+use bathbot_macros::{SlashCommand, command};
+use bathbot_model::command_fields::{EnableDisable, LangOption, ShowHideOption};
+use bathbot_psql::model::configs::{
+    Authorities, GuildConfig, HideSolutions, ListSize, Retries, ScoreData,
+};
+use bathbot_util::{MessageBuilder, i18n::ErrorKey};
+use eyre::{Report, Result};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::{
+    channel::Attachment,
+    guild::Permissions,
+    id::{
+        Id,
+        marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
+    },
+};
+
+pub use self::transfer::GuildConfigExport;
+use self::transfer::RoleMatches;
+use super::AuthorityCommandKind;
+use crate::{
+    Context,
+    active::{
+        ActiveMessages,
+        impls::{AuditLogPagination, ServerConfigImportActive},
+    },
+    core::commands::{
+        CommandOrigin,
+        prefix::{AutoDelete, AutoDeleteGroups, PrefixCommandGroup},
+    },
+    embeds::{EmbedData, ServerConfigEmbed},
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+mod transfer;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "serverconfig",
+    dm_permission = false,
+    desc = "Adjust configurations or authority roles for this server"
+)]
+#[flags(AUTHORITY, AUDITABLE, SKIP_DEFER, ONLY_GUILDS)]
+#[read_only("auditlog", "authorities list", "auto_score_embeds list", "export")]
+#[allow(clippy::large_enum_variant)]
+pub enum ServerConfig {
+    #[command(name = "authorities")]
+    Authorities(ServerConfigAuthorities),
+    #[command(name = "edit")]
+    Edit(ServerConfigEdit),
+    #[command(name = "auto_score_embeds")]
+    AutoScoreEmbeds(ServerConfigAutoScoreEmbeds),
+    #[command(name = "autodelete")]
+    AutoDelete(ServerConfigAutoDelete),
+    #[command(name = "export")]
+    Export(ServerConfigExport),
+    #[command(name = "import")]
+    Import(ServerConfigImport),
+    #[command(name = "auditlog")]
+    AuditLog(ServerConfigAuditLog),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "authorities",
+    desc = "Adjust authority roles for a server",
+    help = "To use certain commands, users require a special status.\n\
+    This command adjusts the authority status of roles.\n\
+    Any member with an authority role can use these higher commands.\n\n\
+    Authority commands: `matchlive`, `prune`, `roleassign`, \
+    `serverconfig`, `track`, `trackstream`."
+)]
+pub enum ServerConfigAuthorities {
+    #[command(name = "add")]
+    Add(ServerConfigAuthoritiesAdd),
+    #[command(name = "remove")]
+    Remove(ServerConfigAuthoritiesRemove),
+    #[command(name = "remove_all")]
+    RemoveAll(ServerConfigAuthoritiesRemoveAll),
+    #[command(name = "list")]
+    List(ServerConfigAuthoritiesList),
+}
+
+impl From<ServerConfigAuthorities> for AuthorityCommandKind {
+    #[inline]
+    fn from(args: ServerConfigAuthorities) -> Self {
+        match args {
+            ServerConfigAuthorities::Add(args) => Self::Add(args.role),
+            ServerConfigAuthorities::Remove(args) => Self::Remove(args.role),
+            ServerConfigAuthorities::RemoveAll(_) => Self::RemoveAll,
+            ServerConfigAuthorities::List(_) => Self::List,
+        }
+    }
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "add",
+    desc = "Add authority status to a role",
+    help = "Add authority status to a role.\n\
+    Servers can have at most 10 authority roles."
+)]
+pub struct ServerConfigAuthoritiesAdd {
+    #[command(desc = "Specify the role that should gain authority status")]
+    role: Id<RoleMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "remove",
+    desc = "Remove authority status from a role",
+    help = "Remove authority status from a role.\n\
+    You can only use this if the removed role would __not__ make you lose authority status yourself."
+)]
+pub struct ServerConfigAuthoritiesRemove {
+    #[command(desc = "Specify the role that should gain authority status")]
+    role: Id<RoleMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "remove_all",
+    desc = "Remove authority status from all roles",
+    help = "Remove authority status from all roles.\n\
+    You can only use this if you have the admin permission."
+)]
+pub struct ServerConfigAuthoritiesRemoveAll;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "Display all current authority roles")]
+pub struct ServerConfigAuthoritiesList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "auto_score_embeds",
+    desc = "Adjust channels in which score urls are embedded automatically",
+    help = "In the specified channels, the bot will respond to messages containing \
+    an osu! score url with the score's embed, just like the `/score` command.\n\
+    To prevent spam, there is at most one automatic embed per channel every ten seconds."
+)]
+pub enum ServerConfigAutoScoreEmbeds {
+    #[command(name = "add")]
+    Add(ServerConfigAutoScoreEmbedsAdd),
+    #[command(name = "remove")]
+    Remove(ServerConfigAutoScoreEmbedsRemove),
+    #[command(name = "list")]
+    List(ServerConfigAutoScoreEmbedsList),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "add",
+    desc = "Embed score urls in a channel automatically",
+    help = "Embed score urls in a channel automatically.\n\
+    Servers can have at most 10 such channels."
+)]
+pub struct ServerConfigAutoScoreEmbedsAdd {
+    #[command(desc = "Specify the channel")]
+    channel: Id<ChannelMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "remove",
+    desc = "Stop embedding score urls in a channel automatically"
+)]
+pub struct ServerConfigAutoScoreEmbedsRemove {
+    #[command(desc = "Specify the channel")]
+    channel: Id<ChannelMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "list",
+    desc = "Display all channels in which score urls are embedded automatically"
+)]
+pub struct ServerConfigAutoScoreEmbedsList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "autodelete",
+    desc = "Delete responses to prefix commands after a while",
+    help = "Delete the bot's responses to prefix commands after a while.\n\
+    Only plain responses are deleted. Responses with buttons or menus are always kept \
+    and so are responses with attachments unless `attachments` is enabled for the group.\n\
+    Tracking and twitch commands are never affected.\n\
+    All other command groups are affected until adjusted through `group` and `enabled`."
+)]
+pub struct ServerConfigAutoDelete {
+    #[command(
+        min_value = 0,
+        max_value = 3600,
+        desc = "Seconds until responses are deleted, 30 to 3600 or 0 to disable"
+    )]
+    seconds: Option<i64>,
+    #[command(desc = "Choose a command group to adjust")]
+    group: Option<AutoDeleteGroupOption>,
+    #[command(desc = "Should responses of the group be deleted?")]
+    enabled: Option<bool>,
+    #[command(
+        desc = "Should responses of the group be deleted even if they contain an attachment?"
+    )]
+    attachments: Option<bool>,
+}
+
+#[derive(CommandOption, CreateOption)]
+pub enum AutoDeleteGroupOption {
+    #[option(name = "All modes", value = "all_modes")]
+    AllModes,
+    #[option(name = "osu!standard", value = "osu")]
+    Osu,
+    #[option(name = "osu!taiko", value = "taiko")]
+    Taiko,
+    #[option(name = "osu!catch", value = "catch")]
+    Catch,
+    #[option(name = "osu!mania", value = "mania")]
+    Mania,
+    #[option(name = "Games", value = "games")]
+    Games,
+    #[option(name = "Utility", value = "utility")]
+    Utility,
+    #[option(name = "Songs", value = "songs")]
+    Songs,
+}
+
+impl From<AutoDeleteGroupOption> for PrefixCommandGroup {
+    fn from(group: AutoDeleteGroupOption) -> Self {
+        match group {
+            AutoDeleteGroupOption::AllModes => Self::AllModes,
+            AutoDeleteGroupOption::Osu => Self::Osu,
+            AutoDeleteGroupOption::Taiko => Self::Taiko,
+            AutoDeleteGroupOption::Catch => Self::Catch,
+            AutoDeleteGroupOption::Mania => Self::Mania,
+            AutoDeleteGroupOption::Games => Self::Games,
+            AutoDeleteGroupOption::Utility => Self::Utility,
+            AutoDeleteGroupOption::Songs => Self::Songs,
+        }
+    }
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "export",
+    desc = "Export the server's settings as a file",
+    help = "Export the server's settings as a file which can be imported \
+    in another server via `/serverconfig import`.\n\
+    The file contains the prefixes, the names of authority roles, and the settings of \
+    `/serverconfig edit`. Channel-bound settings such as automatic score embeds are not included."
+)]
+pub struct ServerConfigExport;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "import",
+    desc = "Import settings from a file of /serverconfig export",
+    help = "Import settings from a file that was created via `/serverconfig export`.\n\
+    Authority roles are matched by name against this server's roles, first exactly and then \
+    ignoring case. Roles without a match are skipped.\n\
+    The changes are previewed and only applied once you confirm them."
+)]
+pub struct ServerConfigImport {
+    #[command(desc = "Specify a file created by /serverconfig export")]
+    file: Attachment,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "auditlog",
+    desc = "Display recent uses of authority commands",
+    help = "Display the most recent 100 uses of authority commands in this server \
+    such as `track`, `matchlive`, or `serverconfig`.\n\
+    Each entry shows who used which command with what options and whether it succeeded."
+)]
+pub struct ServerConfigAuditLog {
+    #[command(desc = "Only show entries of this user")]
+    user: Option<Id<UserMarker>>,
+    #[command(desc = "Only show commands starting with this name e.g. track")]
+    command: Option<String>,
+}
+
+#[derive(CommandModel, CreateCommand, Default)]
+#[command(name = "edit", desc = "Adjust configurations for a server")]
+pub struct ServerConfigEdit {
+    #[command(desc = "Choose whether song commands can be used or not")]
+    song_commands: Option<EnableDisable>,
+    #[command(
+        desc = "Adjust the amount of scores shown per page in top, rb, pinned, ...",
+        help = "Adjust the amount of scores shown per page in top, rb, pinned, and mapper.\n\
+        `Condensed` shows 10 scores, `Detailed` shows 5, and `Single` shows 1.\n\
+        Applies only if the member has not specified a config for themselves."
+    )]
+    list_embeds: Option<ListSize>,
+    #[command(
+        desc = "Should the amount of retries be shown for the recent command?",
+        help = "Should the amount of retries be shown for the `recent` command?\n\
+        Applies only if the member has not specified a config for themselves."
+    )]
+    retries: Option<Retries>,
+    #[command(
+        desc = "Should the recent command include a render button?",
+        help = "Should the `recent` command include a render button?\n\
+        The button would be a shortcut for the `/render` command.\n\
+        If hidden, the button will never show. If shown, members \
+        will have the option to choose via `/config edit`."
+    )]
+    render_button: Option<ShowHideOption>,
+    #[command(
+        desc = "Are members allowed to use custom skins when rendering?",
+        help = "Are members allowed to use custom skins when rendering?\n\
+        Handy for disallowing potentially obscene skins."
+    )]
+    allow_custom_skins: Option<bool>,
+    #[command(desc = "Should medal solutions should be hidden behind spoiler tags?")]
+    hide_medal_solutions: Option<HideSolutions>,
+    #[command(
+        desc = "Whether scores should be requested as lazer or stable scores",
+        help = "Whether scores should be requested as lazer or stable scores.\n\
+        They have a different score and grade calculation and only lazer adds the new mods.\n\
+        Applies only if the member has not specified a config for themselves."
+    )]
+    score_data: Option<ScoreData>,
+    #[command(
+        desc = "Should invoking messages of prefix commands be deleted?",
+        help = "Should invoking messages of prefix commands be deleted after a successful response?\n\
+        Messages of commands that failed or responded with an error are kept.\n\
+        Requires the bot to have the `Manage Messages` permission in the channel."
+    )]
+    delete_invocation: Option<bool>,
+    #[command(
+        desc = "Should suspicious scores be marked on map leaderboards?",
+        help = "Should suspicious scores be marked on map leaderboards?\n\
+        Scores that were set before the map was ranked, have full accuracy despite misses, \
+        or whose pp are far off from the recalculated pp get a ⚠ marker."
+    )]
+    score_anomalies: Option<bool>,
+    #[command(
+        desc = "Language of the bot's responses",
+        help = "Language of the bot's responses.\n\
+        Only some responses are translated so far, the rest stays in English. \
+        Command names and options are always English."
+    )]
+    language: Option<LangOption>,
+}
+
+impl ServerConfigEdit {
+    fn any(&self) -> bool {
+        let Self {
+            song_commands,
+            list_embeds,
+            retries,
+            render_button,
+            allow_custom_skins,
+            hide_medal_solutions,
+            score_data,
+            delete_invocation,
+            score_anomalies,
+            language,
+        } = self;
+
+        song_commands.is_some()
+            || list_embeds.is_some()
+            || retries.is_some()
+            || render_button.is_some()
+            || allow_custom_skins.is_some()
+            || hide_medal_solutions.is_some()
+            || score_data.is_some()
+            || delete_invocation.is_some()
+            || score_anomalies.is_some()
+            || language.is_some()
+    }
+}
+
+async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
+    let args = ServerConfig::from_interaction(command.input_data())?;
+
+    serverconfig((&mut command).into(), args).await
+}
+
+#[command]
+#[desc("Check the current configurations for a server")]
+#[help(
+    "Check the current configurations for a server.\n\
+    Use `/serverconfig edit` to edit them."
+)]
+#[flags(SKIP_DEFER, ONLY_GUILDS)]
+#[group(Utility)]
+async fn prefix_serverconfig(msg: &Message, _: Args<'_>, perms: Option<Permissions>) -> Result<()> {
+    let args = ServerConfig::Edit(ServerConfigEdit::default());
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    serverconfig(orig, args).await
+}
+
+async fn serverconfig(orig: CommandOrigin<'_>, args: ServerConfig) -> Result<()> {
+    let guild_id = orig.guild_id().unwrap();
+
+    let guild = match Context::cache().guild(guild_id).await {
+        Ok(Some(guild)) => guild,
+        Ok(None) => {
+            warn!("Missing guild {guild_id} in cache");
+            orig.error_tr(ErrorKey::General).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = orig.error_tr(ErrorKey::General).await;
+
+            return Err(Report::new(err));
+        }
+    };
+
+    let args = match args {
+        ServerConfig::Authorities(args) => {
+            return super::authorities(orig, args.into()).await;
+        }
+        ServerConfig::Edit(edit) => edit,
+        ServerConfig::AutoScoreEmbeds(args) => return auto_score_embeds(orig, args).await,
+        ServerConfig::AutoDelete(args) => return auto_delete(orig, args).await,
+        ServerConfig::Export(_) => return export(orig).await,
+        ServerConfig::Import(args) => return import(orig, args).await,
+        ServerConfig::AuditLog(args) => return audit_log(orig, args).await,
+    };
+
+    if args.any() {
+        let f = |config: &mut GuildConfig| {
+            let ServerConfigEdit {
+                list_embeds,
+                retries,
+                song_commands,
+                render_button,
+                allow_custom_skins,
+                hide_medal_solutions,
+                score_data,
+                delete_invocation,
+                score_anomalies,
+                language,
+            } = args;
+
+            if let Some(list_embeds) = list_embeds {
+                config.list_size = Some(list_embeds);
+            }
+
+            if let Some(retries) = retries {
+                config.retries = Some(retries);
+            }
+
+            if let Some(with_lyrics) = song_commands {
+                config.allow_songs = Some(with_lyrics == EnableDisable::Enable);
+            }
+
+            if let Some(render_button) = render_button {
+                config.render_button = Some(render_button == ShowHideOption::Show);
+            }
+
+            if let Some(allow_custom_skins) = allow_custom_skins {
+                config.allow_custom_skins = Some(allow_custom_skins);
+            }
+
+            if let Some(hide_medal_solutions) = hide_medal_solutions {
+                config.hide_medal_solution = Some(hide_medal_solutions);
+            }
+
+            if let Some(score_data) = score_data {
+                config.score_data = Some(score_data);
+            }
+
+            if let Some(delete_invocation) = delete_invocation {
+                config.delete_invocation = Some(delete_invocation);
+            }
+
+            if let Some(score_anomalies) = score_anomalies {
+                config.score_anomalies = Some(score_anomalies);
+            }
+
+            if let Some(language) = language {
+                config.lang = Some(language.into());
+            }
+        };
+
+        if let Err(err) = Context::guild_config().update(guild_id, f).await {
+            let _ = orig.error_callback_tr(ErrorKey::General).await;
+
+            return Err(err.wrap_err("failed to update guild config"));
+        }
+    }
+
+    let config = Context::guild_config()
+        .peek(guild_id, GuildConfig::to_owned)
+        .await;
+
+    let authorities = authority_names(guild_id, &config.authorities).await;
+
+    let embed = ServerConfigEmbed::new(guild, config, &authorities);
+    let builder = embed.build().into();
+    orig.callback(builder).await?;
+
+    Ok(())
+}
+
+/// Servers can have at most this many channels with automatic score embeds.
+const MAX_AUTO_SCORE_EMBED_CHANNELS: usize = 10;
+
+async fn auto_score_embeds(
+    orig: CommandOrigin<'_>,
+    args: ServerConfigAutoScoreEmbeds,
+) -> Result<()> {
+    let guild_id = orig.guild_id().unwrap();
+
+    let mut content = match args {
+        ServerConfigAutoScoreEmbeds::Add(ServerConfigAutoScoreEmbedsAdd { channel }) => {
+            let channels = Context::guild_config()
+                .peek(guild_id, |config| config.auto_score_embed_channels.len())
+                .await;
+
+            if channels >= MAX_AUTO_SCORE_EMBED_CHANNELS {
+                let content = format!(
+                    "You can have at most {MAX_AUTO_SCORE_EMBED_CHANNELS} channels \
+                    per server with automatic score embeds."
+                );
+
+                return orig.error_callback(content).await;
+            }
+
+            let f = |config: &mut GuildConfig| {
+                if !config.auto_score_embed_channels.contains(&channel) {
+                    config.auto_score_embed_channels.push(channel);
+                }
+            };
+
+            if let Err(err) = Context::guild_config().update(guild_id, f).await {
+                let _ = orig.error_callback_tr(ErrorKey::General).await;
+
+                return Err(err.wrap_err("Failed to update guild config"));
+            }
+
+            "Score urls will now be embedded automatically in ".to_owned()
+        }
+        ServerConfigAutoScoreEmbeds::Remove(ServerConfigAutoScoreEmbedsRemove { channel }) => {
+            let f = |config: &mut GuildConfig| {
+                config.auto_score_embed_channels.retain(|id| *id != channel)
+            };
+
+            if let Err(err) = Context::guild_config().update(guild_id, f).await {
+                let _ = orig.error_callback_tr(ErrorKey::General).await;
+
+                return Err(err.wrap_err("Failed to update guild config"));
+            }
+
+            "Score urls will now be embedded automatically in ".to_owned()
+        }
+        ServerConfigAutoScoreEmbeds::List(_) => {
+            "Score urls are embedded automatically in ".to_owned()
+        }
+    };
+
+    let channels = Context::guild_config()
+        .peek(guild_id, |config| config.auto_score_embed_channels.clone())
+        .await;
+
+    let mut channels = channels.iter();
+
+    if let Some(channel) = channels.next() {
+        let _ = write!(content, "<#{channel}>");
+
+        for channel in channels {
+            let _ = write!(content, ", <#{channel}>");
+        }
+    } else {
+        content.push_str("no channels");
+    }
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.callback(builder).await?;
+
+    Ok(())
+}
+
+async fn auto_delete(orig: CommandOrigin<'_>, args: ServerConfigAutoDelete) -> Result<()> {
+    let guild_id = orig.guild_id().unwrap();
+
+    let ServerConfigAutoDelete {
+        seconds,
+        group,
+        enabled,
+        attachments,
+    } = args;
+
+    let valid_secs = i64::from(AutoDelete::MIN_SECS)..=i64::from(AutoDelete::MAX_SECS);
+
+    if seconds.is_some_and(|secs| secs != 0 && !valid_secs.contains(&secs)) {
+        let content = format!(
+            "The amount of seconds must be between {} and {} or 0 to disable",
+            AutoDelete::MIN_SECS,
+            AutoDelete::MAX_SECS
+        );
+
+        return orig.error_callback(content).await;
+    }
+
+    if group.is_none() && (enabled.is_some() || attachments.is_some()) {
+        let content = "You must specify a `group` to adjust";
+
+        return orig.error_callback(content).await;
+    }
+
+    if seconds.is_some() || enabled.is_some() || attachments.is_some() {
+        let f = |config: &mut GuildConfig| {
+            if let Some(secs) = seconds {
+                config.auto_delete_secs = (secs > 0).then_some(secs as u16);
+            }
+
+            let Some(group) = group.map(PrefixCommandGroup::from) else {
+                return;
+            };
+
+            if let Some(enabled) = enabled {
+                let mut groups = config
+                    .auto_delete_groups
+                    .map_or(AutoDeleteGroups::ALL, AutoDeleteGroups::from_bits);
+
+                groups.set(group, enabled);
+                config.auto_delete_groups = Some(groups.bits());
+            }
+
+            if let Some(attachments) = attachments {
+                let mut groups = config
+                    .auto_delete_attachments
+                    .map_or(AutoDeleteGroups::NONE, AutoDeleteGroups::from_bits);
+
+                groups.set(group, attachments);
+                config.auto_delete_attachments = Some(groups.bits());
+            }
+        };
+
+        if let Err(err) = Context::guild_config().update(guild_id, f).await {
+            let _ = orig.error_callback_tr(ErrorKey::General).await;
+
+            return Err(err.wrap_err("Failed to update guild config"));
+        }
+    }
+
+    let (secs, groups, attachments) = Context::guild_config()
+        .peek(guild_id, |config| {
+            (
+                AutoDelete::new(config).map(|auto_delete| auto_delete.secs),
+                config
+                    .auto_delete_groups
+                    .map_or(AutoDeleteGroups::ALL, AutoDeleteGroups::from_bits),
+                config
+                    .auto_delete_attachments
+                    .map_or(AutoDeleteGroups::NONE, AutoDeleteGroups::from_bits),
+            )
+        })
+        .await;
+
+    let mut content = match secs {
+        Some(secs) => format!("Responses to prefix commands are deleted after {secs} seconds\n"),
+        None => "Responses to prefix commands are not deleted automatically\n".to_owned(),
+    };
+
+    for group in AutoDeleteGroups::ELIGIBLE {
+        let action = match (groups.contains(group), attachments.contains(group)) {
+            (true, true) => "delete, even with attachments",
+            (true, false) => "delete",
+            (false, _) => "keep",
+        };
+
+        let _ = write!(content, "\n{} {}: {action}", group.emote(), group.name());
+    }
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.callback(builder).await?;
+
+    Ok(())
+}
+
+async fn authority_names(guild_id: Id<GuildMarker>, authorities: &Authorities) -> Vec<String> {
+    let mut names = Vec::with_capacity(authorities.len());
+
+    for &role in authorities.iter() {
+        if let Ok(Some(role)) = Context::cache().role(guild_id, role).await {
+            names.push(role.name.as_ref().to_owned());
+        }
+    }
+
+    names
+}
+
+async fn export(orig: CommandOrigin<'_>) -> Result<()> {
+    let guild_id = orig.guild_id().unwrap();
+
+    let config = Context::guild_config()
+        .peek(guild_id, GuildConfig::to_owned)
+        .await;
+
+    let authorities = authority_names(guild_id, &config.authorities).await;
+
+    let bytes = match GuildConfigExport::new(&config, authorities).to_json() {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = orig.error_callback_tr(ErrorKey::General).await;
+
+            return Err(Report::new(err).wrap_err("Failed to serialize guild config export"));
+        }
+    };
+
+    let content = "Use `/serverconfig import` with this file to copy the settings to another \
+        server.\nChannel-bound settings such as automatic score embeds are not included.";
+
+    let builder = MessageBuilder::new()
+        .content(content)
+        .attachment(format!("serverconfig_{guild_id}.json"), bytes);
+
+    orig.callback(builder).await?;
+
+    Ok(())
+}
+
+/// Exports are tiny so larger files can't be one.
+const MAX_IMPORT_SIZE: u64 = 16 * 1024;
+
+async fn import(orig: CommandOrigin<'_>, args: ServerConfigImport) -> Result<()> {
+    let guild_id = orig.guild_id().unwrap();
+    let owner = orig.user_id()?;
+    let ServerConfigImport { file } = args;
+
+    if file.size > MAX_IMPORT_SIZE {
+        let content = "The file is too large to be a config export";
+
+        return orig.error_callback(content).await;
+    }
+
+    let bytes = match Context::client().get_discord_attachment(&file).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = orig.error_callback_tr(ErrorKey::General).await;
+
+            return Err(err.wrap_err("Failed to get discord attachment"));
+        }
+    };
+
+    let export = match GuildConfigExport::parse(&bytes) {
+        Ok(export) => export,
+        Err(err) => return orig.error_callback(err.to_string()).await,
+    };
+
+    let roles = match guild_roles(guild_id).await {
+        Ok(roles) => roles,
+        Err(err) => {
+            let _ = orig.error_callback_tr(ErrorKey::General).await;
+
+            return Err(err.wrap_err("Failed to get guild roles"));
+        }
+    };
+
+    let RoleMatches { matched, unmatched } = transfer::match_roles(&export.authorities, &roles);
+    let authorities: Authorities = matched.iter().map(|(id, _)| *id).collect();
+    let imported_authorities: Vec<_> = matched.into_iter().map(|(_, name)| name).collect();
+
+    let current = Context::guild_config()
+        .peek(guild_id, GuildConfig::to_owned)
+        .await;
+
+    let current_authorities = authority_names(guild_id, &current.authorities).await;
+
+    let config = match export.to_config(&current, authorities.clone()) {
+        Ok(config) => config,
+        Err(err) => return orig.error_callback(err.to_string()).await,
+    };
+
+    let preview = transfer::preview(
+        &current,
+        &current_authorities,
+        &config,
+        &imported_authorities,
+        &unmatched,
+    );
+
+    // The config may change until the import is confirmed so the export is
+    // merged into the then current config
+    let active_msg = ServerConfigImportActive::new(guild_id, export, authorities, preview, owner);
+
+    ActiveMessages::builder(active_msg).begin(orig).await
+}
+
+/// Amount of most recent audit log entries that are shown.
+const AUDIT_LOG_LIMIT: usize = 100;
+
+async fn audit_log(orig: CommandOrigin<'_>, args: ServerConfigAuditLog) -> Result<()> {
+    let guild_id = orig.guild_id().unwrap();
+    let owner = orig.user_id()?;
+    let ServerConfigAuditLog { user, command } = args;
+
+    let command = command.map(|command| command.trim().to_lowercase());
+
+    let entries = match Context::psql()
+        .select_audit_log(guild_id, user, command.as_deref(), AUDIT_LOG_LIMIT)
+        .await
+    {
+        Ok(entries) => entries,
+        Err(err) => {
+            let _ = orig.error_callback_tr(ErrorKey::General).await;
+
+            return Err(err.wrap_err("Failed to get audit log"));
+        }
+    };
+
+    let pagination = AuditLogPagination::builder()
+        .entries(entries.into_boxed_slice())
+        .msg_owner(owner)
+        .build();
+
+    ActiveMessages::builder(pagination).begin(orig).await
+}
+
+async fn guild_roles(guild_id: Id<GuildMarker>) -> Result<Vec<(Id<RoleMarker>, String)>> {
+    let cache = Context::cache();
+
+    let role_ids = cache
+        .guild_roles(guild_id)
+        .await?
+        .into_iter()
+        .filter_map(Id::new_checked);
+
+    let roles = cache.roles(guild_id, role_ids).await?;
+
+    let roles = roles
+        .iter()
+        .map(|role| (role.id.to_native(), role.name.as_ref().to_owned()))
+        .collect();
+
+    Ok(roles)
+}
@@ -0,0 +1,455 @@
+use std::fmt::{Debug, Write};
+
+use bathbot_psql::model::configs::{
+    Authorities, GuildConfig, HideSolutions, ListSize, Retries, ScoreData,
+};
+use bathbot_util::i18n::Lang;
+use serde::{Deserialize, Serialize};
+use thiserror::Error as ThisError;
+use twilight_model::id::{Id, marker::RoleMarker};
+
+use crate::commands::utility::prefix::PREFIX_LIMIT;
+
+/// Version of the export format. Must be bumped on incompatible changes.
+pub const EXPORT_VERSION: u8 = 1;
+
+/// Servers can have at most this many authority roles.
+const MAX_AUTHORITIES: usize = 10;
+
+/// Settings of a guild that can be carried over to another guild.
+///
+/// Channel-bound settings are left out and authority roles are stored by name
+/// since their ids differ between guilds.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GuildConfigExport {
+    pub version: u8,
+    pub prefixes: Vec<String>,
+    pub authorities: Vec<String>,
+    pub list_size: Option<i16>,
+    pub retries: Option<i16>,
+    pub allow_songs: Option<bool>,
+    pub render_button: Option<bool>,
+    pub allow_custom_skins: Option<bool>,
+    pub hide_medal_solution: Option<i16>,
+    pub score_data: Option<i16>,
+    pub delete_invocation: Option<bool>,
+    pub score_anomalies: Option<bool>,
+    pub lang: Option<i16>,
+}
+
+#[derive(Deserialize)]
+struct ExportVersion {
+    version: u8,
+}
+
+#[derive(Debug, ThisError)]
+pub enum ImportError {
+    #[error("The file is not a valid config export")]
+    Json(#[from] serde_json::Error),
+    #[error(
+        "The file has export version {0} but only version {} is supported",
+        EXPORT_VERSION
+    )]
+    Version(u8),
+    #[error("The file contains an invalid value for `{0}`")]
+    InvalidValue(&'static str),
+    #[error("The file must contain between 1 and {} prefixes", PREFIX_LIMIT)]
+    Prefixes,
+    #[error("The file must contain at most {} authority roles", MAX_AUTHORITIES)]
+    Authorities,
+}
+
+impl GuildConfigExport {
+    pub fn new(config: &GuildConfig, authorities: Vec<String>) -> Self {
+        Self {
+            version: EXPORT_VERSION,
+            prefixes: config.prefixes.clone(),
+            authorities,
+            list_size: config.list_size.map(i16::from),
+            retries: config.retries.map(i16::from),
+            allow_songs: config.allow_songs,
+            render_button: config.render_button,
+            allow_custom_skins: config.allow_custom_skins,
+            hide_medal_solution: config.hide_medal_solution.map(i16::from),
+            score_data: config.score_data.map(i16::from),
+            delete_invocation: config.delete_invocation,
+            score_anomalies: config.score_anomalies,
+            lang: config.lang.map(i16::from),
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<Vec<u8>> {
+        serde_json::to_vec_pretty(self)
+    }
+
+    pub fn parse(bytes: &[u8]) -> Result<Self, ImportError> {
+        // Check the version first so that exports of another version are
+        // reported as such instead of as invalid file
+        let ExportVersion { version } = serde_json::from_slice(bytes)?;
+
+        if version != EXPORT_VERSION {
+            return Err(ImportError::Version(version));
+        }
+
+        let export: Self = serde_json::from_slice(bytes)?;
+
+        if export.prefixes.is_empty() || export.prefixes.len() > PREFIX_LIMIT {
+            return Err(ImportError::Prefixes);
+        }
+
+        if export.authorities.len() > MAX_AUTHORITIES {
+            return Err(ImportError::Authorities);
+        }
+
+        Ok(export)
+    }
+
+    /// Apply the exported settings on top of the current config.
+    ///
+    /// Settings that are not part of the export such as channels are kept.
+    pub fn to_config(
+        &self,
+        current: &GuildConfig,
+        authorities: Authorities,
+    ) -> Result<GuildConfig, ImportError> {
+        fn convert<T: TryFrom<i16>>(
+            value: Option<i16>,
+            name: &'static str,
+        ) -> Result<Option<T>, ImportError> {
+            value
+                .map(|value| T::try_from(value).map_err(|_| ImportError::InvalidValue(name)))
+                .transpose()
+        }
+
+        let Self {
+            version: _,
+            ref prefixes,
+            authorities: _,
+            list_size,
+            retries,
+            allow_songs,
+            render_button,
+            allow_custom_skins,
+            hide_medal_solution,
+            score_data,
+            delete_invocation,
+            score_anomalies,
+            lang,
+        } = *self;
+
+        Ok(GuildConfig {
+            authorities,
+            list_size: convert::<ListSize>(list_size, "list_size")?,
+            prefixes: prefixes.clone(),
+            retries: convert::<Retries>(retries, "retries")?,
+            allow_songs,
+            render_button,
+            allow_custom_skins,
+            hide_medal_solution: convert::<HideSolutions>(
+                hide_medal_solution,
+                "hide_medal_solution",
+            )?,
+            score_data: convert::<ScoreData>(score_data, "score_data")?,
+            delete_invocation,
+            score_anomalies,
+            auto_delete_secs: current.auto_delete_secs,
+            auto_delete_groups: current.auto_delete_groups,
+            auto_delete_attachments: current.auto_delete_attachments,
+            auto_score_embed_channels: current.auto_score_embed_channels.clone(),
+            lang: convert::<Lang>(lang, "lang")?,
+        })
+    }
+}
+
+pub struct RoleMatches {
+    /// Ids and names of the matched roles of the target guild
+    pub matched: Vec<(Id<RoleMarker>, String)>,
+    pub unmatched: Vec<String>,
+}
+
+/// Match exported role names against the roles of the target guild.
+///
+/// Exact matches are preferred, otherwise the name is matched
+/// case-insensitively.
+pub fn match_roles(names: &[String], roles: &[(Id<RoleMarker>, String)]) -> RoleMatches {
+    let mut matched: Vec<(Id<RoleMarker>, String)> = Vec::with_capacity(names.len());
+    let mut unmatched = Vec::new();
+
+    for name in names {
+        let lowercase = name.to_lowercase();
+
+        let role_opt = roles.iter().find(|(_, role)| role == name).or_else(|| {
+            roles
+                .iter()
+                .find(|(_, role)| role.to_lowercase() == lowercase)
+        });
+
+        match role_opt {
+            Some((id, _)) if matched.iter().any(|(matched_id, _)| matched_id == id) => {}
+            Some(role) => matched.push(role.to_owned()),
+            None => unmatched.push(name.to_owned()),
+        }
+    }
+
+    RoleMatches { matched, unmatched }
+}
+
+/// Describe the settings that an import would change.
+pub fn preview(
+    current: &GuildConfig,
+    current_authorities: &[String],
+    imported: &GuildConfig,
+    imported_authorities: &[String],
+    unmatched: &[String],
+) -> String {
+    let mut preview = String::new();
+
+    let mut push = |name: &str, old: String, new: String| {
+        if old != new {
+            let _ = writeln!(preview, "**{name}**: {old} → {new}");
+        }
+    };
+
+    push(
+        "Prefixes",
+        fmt_list(&current.prefixes),
+        fmt_list(&imported.prefixes),
+    );
+    push(
+        "Authority roles",
+        fmt_list(current_authorities),
+        fmt_list(imported_authorities),
+    );
+    push(
+        "List embeds",
+        fmt_opt(current.list_size),
+        fmt_opt(imported.list_size),
+    );
+    push(
+        "Retries",
+        fmt_opt(current.retries),
+        fmt_opt(imported.retries),
+    );
+    push(
+        "Song commands",
+        fmt_opt(current.allow_songs),
+        fmt_opt(imported.allow_songs),
+    );
+    push(
+        "Render button",
+        fmt_opt(current.render_button),
+        fmt_opt(imported.render_button),
+    );
+    push(
+        "Custom skins",
+        fmt_opt(current.allow_custom_skins),
+        fmt_opt(imported.allow_custom_skins),
+    );
+    push(
+        "Medal solutions",
+        fmt_opt(current.hide_medal_solution),
+        fmt_opt(imported.hide_medal_solution),
+    );
+    push(
+        "Score data",
+        fmt_opt(current.score_data),
+        fmt_opt(imported.score_data),
+    );
+    push(
+        "Delete invocations",
+        fmt_opt(current.delete_invocation),
+        fmt_opt(imported.delete_invocation),
+    );
+    push(
+        "Score anomalies",
+        fmt_opt(current.score_anomalies),
+        fmt_opt(imported.score_anomalies),
+    );
+    push("Language", fmt_opt(current.lang), fmt_opt(imported.lang));
+
+    if preview.is_empty() {
+        preview.push_str("The import would not change any settings\n");
+    }
+
+    if !unmatched.is_empty() {
+        let _ = write!(
+            preview,
+            "\nNo role matches the authority roles {}, they will be skipped",
+            fmt_list(unmatched)
+        );
+    }
+
+    preview
+}
+
+fn fmt_list(values: &[String]) -> String {
+    if values.is_empty() {
+        return "none".to_owned();
+    }
+
+    let mut list = String::new();
+
+    for value in values {
+        if !list.is_empty() {
+            list.push_str(", ");
+        }
+
+        let _ = write!(list, "`{value}`");
+    }
+
+    list
+}
+
+fn fmt_opt<T: Debug>(value: Option<T>) -> String {
+    match value {
+        Some(value) => format!("`{value:?}`"),
+        None => "`unset`".to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roles() -> Vec<(Id<RoleMarker>, String)> {
+        vec![
+            (Id::new(1), "Moderator".to_owned()),
+            (Id::new(2), "moderator".to_owned()),
+            (Id::new(3), "Bot Commander".to_owned()),
+        ]
+    }
+
+    fn config() -> GuildConfig {
+        GuildConfig {
+            prefixes: vec!["<".to_owned(), "!".to_owned()],
+            list_size: Some(ListSize::Detailed),
+            retries: Some(Retries::IgnoreMods),
+            allow_songs: Some(false),
+            hide_medal_solution: Some(HideSolutions::HideAll),
+            score_data: Some(ScoreData::Stable),
+            auto_score_embed_channels: vec![Id::new(42)],
+            lang: Some(Lang::De),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let export = GuildConfigExport::new(&config(), vec!["Moderator".to_owned()]);
+        let bytes = export.to_json().unwrap();
+        let parsed = GuildConfigExport::parse(&bytes).unwrap();
+
+        assert_eq!(parsed, export);
+
+        let imported = parsed
+            .to_config(&GuildConfig::default(), Authorities::default())
+            .unwrap();
+
+        assert_eq!(imported.prefixes, ["<", "!"]);
+        assert_eq!(imported.list_size, Some(ListSize::Detailed));
+        assert_eq!(imported.retries, Some(Retries::IgnoreMods));
+        assert_eq!(imported.allow_songs, Some(false));
+        assert_eq!(imported.hide_medal_solution, Some(HideSolutions::HideAll));
+        assert_eq!(imported.score_data, Some(ScoreData::Stable));
+        assert_eq!(imported.lang, Some(Lang::De));
+        assert!(imported.auto_score_embed_channels.is_empty());
+    }
+
+    #[test]
+    fn keeps_channels_of_target() {
+        let export = GuildConfigExport::new(&GuildConfig::default(), Vec::new());
+        let imported = export.to_config(&config(), Authorities::default()).unwrap();
+
+        assert_eq!(imported.auto_score_embed_channels, [Id::new(42)]);
+        assert_eq!(imported.prefixes, [GuildConfig::DEFAULT_PREFIX]);
+    }
+
+    #[test]
+    fn rejects_invalid_exports() {
+        let mut export = GuildConfigExport::new(&config(), Vec::new());
+        export.version = EXPORT_VERSION + 1;
+        let bytes = export.to_json().unwrap();
+
+        assert!(matches!(
+            GuildConfigExport::parse(&bytes),
+            Err(ImportError::Version(version)) if version == EXPORT_VERSION + 1
+        ));
+
+        let mut export = GuildConfigExport::new(&config(), Vec::new());
+        export.prefixes.clear();
+        let bytes = export.to_json().unwrap();
+
+        assert!(matches!(
+            GuildConfigExport::parse(&bytes),
+            Err(ImportError::Prefixes)
+        ));
+
+        assert!(matches!(
+            GuildConfigExport::parse(b"{\"prefixes\": []}"),
+            Err(ImportError::Json(_))
+        ));
+
+        let mut export = GuildConfigExport::new(&config(), Vec::new());
+        export.retries = Some(99);
+        let res = export.to_config(&GuildConfig::default(), Authorities::default());
+
+        assert!(matches!(res, Err(ImportError::InvalidValue("retries"))));
+    }
+
+    #[test]
+    fn matches_exact_names_first() {
+        let names = ["moderator".to_owned(), "Moderator".to_owned()];
+        let RoleMatches { matched, unmatched } = match_roles(&names, &roles());
+
+        assert_eq!(
+            matched,
+            [
+                (Id::new(2), "moderator".to_owned()),
+                (Id::new(1), "Moderator".to_owned())
+            ]
+        );
+        assert!(unmatched.is_empty());
+    }
+
+    #[test]
+    fn matches_case_insensitively() {
+        let names = ["BOT COMMANDER".to_owned(), "Admin".to_owned()];
+        let RoleMatches { matched, unmatched } = match_roles(&names, &roles());
+
+        assert_eq!(matched, [(Id::new(3), "Bot Commander".to_owned())]);
+        assert_eq!(unmatched, ["Admin"]);
+    }
+
+    #[test]
+    fn matches_roles_once() {
+        let names = ["bot commander".to_owned(), "Bot Commander".to_owned()];
+        let RoleMatches { matched, .. } = match_roles(&names, &roles());
+
+        assert_eq!(matched.len(), 1);
+    }
+
+    #[test]
+    fn preview_lists_changes() {
+        let current = GuildConfig::default();
+        let imported = config();
+
+        let text = preview(
+            &current,
+            &[],
+            &imported,
+            &["Moderator".to_owned()],
+            &["Admin".to_owned()],
+        );
+
+        assert!(text.contains("**Prefixes**: `<` → `<`, `!`\n"));
+        assert!(text.contains("**Authority roles**: none → `Moderator`\n"));
+        assert!(text.contains("**Retries**: `unset` → `IgnoreMods`\n"));
+        assert!(!text.contains("Render button"));
+        assert!(
+            text.ends_with("No role matches the authority roles `Admin`, they will be skipped")
+        );
+
+        let unchanged = preview(&current, &[], &current, &[], &[]);
+        assert_eq!(unchanged, "The import would not change any settings\n");
+    }
+}
@@ -4,7 +4,7 @@ use std::{
 };
 
 use bathbot_macros::{HasName, SlashCommand};
-use bathbot_util::{Authored, EmbedBuilder, MessageBuilder, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{Authored, EmbedBuilder, MessageBuilder, i18n::ErrorKey, matcher};
 use eyre::{Report, Result, WrapErr};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{Id, marker::UserMarker};
@@ -64,7 +64,7 @@ impl CheckSkin {
                     command.update(builder).await?;
                 }
                 Err(err) => {
-                    let _ = command.error(GENERAL_ISSUE).await;
+                    let _ = command.error_tr(ErrorKey::General).await;
 
                     return Err(err);
                 }
@@ -83,7 +83,7 @@ impl CheckSkin {
                     command.update(builder).await?;
                 }
                 Err(err) => {
-                    let _ = command.error(GENERAL_ISSUE).await;
+                    let _ = command.error_tr(ErrorKey::General).await;
 
                     return Err(err);
                 }
@@ -108,7 +108,7 @@ impl CheckSkin {
                     command.update(builder).await?;
                 }
                 Err(err) => {
-                    let _ = command.error(GENERAL_ISSUE).await;
+                    let _ = command.error_tr(ErrorKey::General).await;
 
                     return Err(err);
                 }
@@ -139,7 +139,7 @@ impl AllSkin {
                     .wrap_err("Failed to begin active message")
             }
             Err(err) => {
-                let _ = command.error(GENERAL_ISSUE).await;
+                let _ = command.error_tr(ErrorKey::General).await;
 
                 Err(err)
             }
@@ -183,7 +183,7 @@ impl SetSkin {
         let update_fut = Context::user_config().update_skin(command.user_id()?, Some(&url));
 
         if let Err(err) = update_fut.await {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -211,7 +211,7 @@ impl UnsetSkin {
         let update_fut = Context::user_config().update_skin(command.user_id()?, None);
 
         if let Err(err) = update_fut.await {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error_tr(ErrorKey::General).await;
 
             return Err(err);
         }
@@ -0,0 +1,234 @@
+use std::{
+    collections::VecDeque,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use eyre::Report;
+use rosu_v2::error::OsuError;
+use time::OffsetDateTime;
+
+use super::Context;
+
+/// Length in seconds of the sliding window over which errors are counted.
+const WINDOW_SECS: i64 = 5 * 60;
+
+/// Errors within the window at which the osu!api is considered degraded.
+const ENTER_THRESHOLD: usize = 25;
+
+/// Errors within the window below which a degraded osu!api is considered
+/// healthy again. Lower than [`ENTER_THRESHOLD`] so that the state doesn't
+/// flap around the threshold.
+const EXIT_THRESHOLD: usize = 8;
+
+/// Appended to osu!api error responses while the osu!api is degraded.
+pub const API_INCIDENT_BANNER: &str = "osu!api appears to be having issues — not just you";
+
+/// The kind of osu!api failure that hints at an upstream problem.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ApiErrorClass {
+    Timeout,
+    ServerError,
+    RateLimited,
+}
+
+impl ApiErrorClass {
+    /// Classify the first osu!api error within the report's chain.
+    fn from_report(err: &Report) -> Option<Self> {
+        err.chain()
+            .find_map(|err| match err.downcast_ref::<OsuError>()? {
+                OsuError::Request { .. } | OsuError::RequestTimeout { .. } => Some(Self::Timeout),
+                OsuError::ServiceUnavailable { .. } => Some(Self::ServerError),
+                OsuError::Response { status, .. } if status.as_u16() == 429 => {
+                    Some(Self::RateLimited)
+                }
+                OsuError::Response { status, .. } if status.is_server_error() => {
+                    Some(Self::ServerError)
+                }
+                _ => None,
+            })
+    }
+}
+
+/// Amount of osu!api errors per class within the window.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ApiErrorCounts {
+    pub timeout: usize,
+    pub server_error: usize,
+    pub rate_limited: usize,
+}
+
+impl ApiErrorCounts {
+    pub fn total(&self) -> usize {
+        self.timeout + self.server_error + self.rate_limited
+    }
+}
+
+/// How the incident state changed through [`ApiIncident::evaluate`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IncidentChange {
+    Unchanged,
+    Started,
+    Ended,
+}
+
+impl IncidentChange {
+    /// Let the bot's presence indicate whether the osu!api is degraded.
+    pub fn update_presence(self) {
+        if self != Self::Unchanged {
+            Context::update_presence();
+        }
+    }
+}
+
+/// Counts bot-wide osu!api errors to tell whether the osu!api itself is
+/// having issues.
+pub struct ApiIncident {
+    /// Unix timestamps in seconds of recent errors, oldest first
+    events: Mutex<VecDeque<(i64, ApiErrorClass)>>,
+    degraded: AtomicBool,
+}
+
+impl ApiIncident {
+    pub const fn new() -> Self {
+        Self {
+            events: Mutex::new(VecDeque::new()),
+            degraded: AtomicBool::new(false),
+        }
+    }
+
+    /// Record the command error if it was caused by the osu!api.
+    pub fn record_error(&self, err: &Report) {
+        if let Some(class) = ApiErrorClass::from_report(err) {
+            self.record(class, OffsetDateTime::now_utc().unix_timestamp());
+        }
+    }
+
+    fn record(&self, class: ApiErrorClass, now: i64) {
+        let mut events = self.events.lock().unwrap();
+        prune(&mut events, now);
+        events.push_back((now, class));
+    }
+
+    /// Whether the osu!api is currently considered degraded.
+    pub fn is_degraded(&self) -> bool {
+        self.degraded.load(Ordering::Relaxed)
+    }
+
+    /// Error counts within the window ending at `now`.
+    pub fn counts(&self, now: i64) -> ApiErrorCounts {
+        let mut events = self.events.lock().unwrap();
+        prune(&mut events, now);
+
+        let mut counts = ApiErrorCounts::default();
+
+        for (_, class) in events.iter() {
+            let count = match class {
+                ApiErrorClass::Timeout => &mut counts.timeout,
+                ApiErrorClass::ServerError => &mut counts.server_error,
+                ApiErrorClass::RateLimited => &mut counts.rate_limited,
+            };
+
+            *count += 1;
+        }
+
+        counts
+    }
+
+    /// Re-evaluate the degraded state based on the current window.
+    pub fn evaluate(&self, now: i64) -> IncidentChange {
+        let errors = self.counts(now).total();
+        let degraded = next_state(self.is_degraded(), errors);
+
+        match (self.degraded.swap(degraded, Ordering::Relaxed), degraded) {
+            (false, true) => IncidentChange::Started,
+            (true, false) => IncidentChange::Ended,
+            _ => IncidentChange::Unchanged,
+        }
+    }
+}
+
+fn prune(events: &mut VecDeque<(i64, ApiErrorClass)>, now: i64) {
+    while events
+        .front()
+        .is_some_and(|(timestamp, _)| now - *timestamp >= WINDOW_SECS)
+    {
+        events.pop_front();
+    }
+}
+
+/// Enter the degraded state at [`ENTER_THRESHOLD`] errors and only leave it
+/// again below [`EXIT_THRESHOLD`].
+fn next_state(degraded: bool, errors: usize) -> bool {
+    if degraded {
+        errors >= EXIT_THRESHOLD
+    } else {
+        errors >= ENTER_THRESHOLD
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn record_many(incident: &ApiIncident, amount: usize, class: ApiErrorClass, now: i64) {
+        for _ in 0..amount {
+            incident.record(class, now);
+        }
+    }
+
+    #[test]
+    fn hysteresis() {
+        assert!(!next_state(false, ENTER_THRESHOLD - 1));
+        assert!(next_state(false, ENTER_THRESHOLD));
+
+        // Between the thresholds the previous state is kept
+        assert!(next_state(true, ENTER_THRESHOLD - 1));
+        assert!(next_state(true, EXIT_THRESHOLD));
+        assert!(!next_state(false, EXIT_THRESHOLD));
+
+        assert!(!next_state(true, EXIT_THRESHOLD - 1));
+    }
+
+    #[test]
+    fn transitions() {
+        let incident = ApiIncident::new();
+        record_many(&incident, ENTER_THRESHOLD - 1, ApiErrorClass::Timeout, NOW);
+        assert_eq!(incident.evaluate(NOW), IncidentChange::Unchanged);
+
+        incident.record(ApiErrorClass::ServerError, NOW);
+        assert_eq!(incident.evaluate(NOW), IncidentChange::Started);
+        assert_eq!(incident.evaluate(NOW), IncidentChange::Unchanged);
+        assert!(incident.is_degraded());
+
+        // The burst leaves the window but errors keep trickling in
+        let later = NOW + WINDOW_SECS;
+        record_many(&incident, EXIT_THRESHOLD, ApiErrorClass::RateLimited, later);
+        assert_eq!(incident.evaluate(later), IncidentChange::Unchanged);
+        assert!(incident.is_degraded());
+
+        let much_later = later + WINDOW_SECS;
+        assert_eq!(incident.evaluate(much_later), IncidentChange::Ended);
+        assert!(!incident.is_degraded());
+    }
+
+    #[test]
+    fn counts_per_class() {
+        let incident = ApiIncident::new();
+        record_many(&incident, 3, ApiErrorClass::Timeout, NOW - WINDOW_SECS);
+        record_many(&incident, 2, ApiErrorClass::ServerError, NOW - 60);
+        incident.record(ApiErrorClass::RateLimited, NOW);
+
+        let expected = ApiErrorCounts {
+            timeout: 0,
+            server_error: 2,
+            rate_limited: 1,
+        };
+
+        assert_eq!(incident.counts(NOW), expected);
+    }
+}
@@ -0,0 +1,328 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::Mutex,
+};
+
+use time::OffsetDateTime;
+
+use super::commands::outcome::CommandOutcome;
+
+/// Length in seconds of the sliding window over which outcomes are counted.
+const WINDOW_SECS: i64 = 5 * 60;
+
+/// Commands with fewer invocations in a window are not evaluated.
+const MIN_INVOCATIONS: u32 = 20;
+
+/// Share of internal errors above which a command raises an alert.
+const MAX_INTERNAL_ERROR_RATE: f64 = 0.3;
+
+/// Keeps the command outcomes within a sliding window per command.
+pub struct CommandHealth {
+    /// Unix timestamps in seconds of recent outcomes, oldest first
+    window: Mutex<HashMap<Box<str>, VecDeque<(i64, CommandOutcome)>>>,
+    /// Commands that are above the error rate threshold and were already
+    /// alerted about
+    alerting: Mutex<HashSet<Box<str>>>,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct OutcomeCounts {
+    pub success: u32,
+    pub user_error: u32,
+    pub internal_error: u32,
+}
+
+impl OutcomeCounts {
+    fn record(&mut self, outcome: CommandOutcome) {
+        let count = match outcome {
+            CommandOutcome::Success => &mut self.success,
+            CommandOutcome::UserError => &mut self.user_error,
+            CommandOutcome::InternalError => &mut self.internal_error,
+        };
+
+        *count = count.saturating_add(1);
+    }
+
+    pub fn total(&self) -> u32 {
+        self.success + self.user_error + self.internal_error
+    }
+
+    pub fn internal_error_rate(&self) -> f64 {
+        match self.total() {
+            0 => 0.0,
+            total => self.internal_error as f64 / total as f64,
+        }
+    }
+}
+
+impl CommandHealth {
+    pub fn new() -> Self {
+        Self {
+            window: Mutex::new(HashMap::new()),
+            alerting: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub fn record(&self, name: &str, outcome: CommandOutcome) {
+        self.record_at(name, outcome, OffsetDateTime::now_utc().unix_timestamp());
+    }
+
+    fn record_at(&self, name: &str, outcome: CommandOutcome, now: i64) {
+        let mut window = self.window.lock().unwrap();
+
+        match window.get_mut(name) {
+            Some(events) => {
+                prune(events, now);
+                events.push_back((now, outcome));
+            }
+            None => {
+                window.insert(name.into(), VecDeque::from([(now, outcome)]));
+            }
+        }
+    }
+
+    /// Evaluate the window ending at `now`.
+    ///
+    /// Commands are only alerted about once until their error rate drops
+    /// below the threshold again.
+    pub fn evaluate_window(&self, now: i64) -> Vec<ErrorRateAlert> {
+        let alerts = evaluate(self.counts(now));
+        let mut alerting = self.alerting.lock().unwrap();
+
+        let new_alerts = alerts
+            .iter()
+            .filter(|alert| !alerting.contains(&alert.name))
+            .cloned()
+            .collect();
+
+        *alerting = alerts.into_iter().map(|alert| alert.name).collect();
+
+        new_alerts
+    }
+
+    /// Outcome counts per command within the window ending at `now`.
+    fn counts(&self, now: i64) -> HashMap<Box<str>, OutcomeCounts> {
+        let mut window = self.window.lock().unwrap();
+
+        window.retain(|_, events| {
+            prune(events, now);
+
+            !events.is_empty()
+        });
+
+        window
+            .iter()
+            .map(|(name, events)| {
+                let mut counts = OutcomeCounts::default();
+
+                for (_, outcome) in events.iter() {
+                    counts.record(*outcome);
+                }
+
+                (name.clone(), counts)
+            })
+            .collect()
+    }
+}
+
+fn prune(events: &mut VecDeque<(i64, CommandOutcome)>, now: i64) {
+    while events
+        .front()
+        .is_some_and(|(timestamp, _)| now - *timestamp >= WINDOW_SECS)
+    {
+        events.pop_front();
+    }
+}
+
+/// A command that failed too often within a window.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorRateAlert {
+    pub name: Box<str>,
+    pub counts: OutcomeCounts,
+}
+
+/// Find the commands that were invoked at least [`MIN_INVOCATIONS`] times and
+/// whose internal error rate exceeds [`MAX_INTERNAL_ERROR_RATE`].
+///
+/// Alerts are sorted by descending error rate.
+fn evaluate(window: HashMap<Box<str>, OutcomeCounts>) -> Vec<ErrorRateAlert> {
+    let mut alerts: Vec<_> = window
+        .into_iter()
+        .filter(|(_, counts)| {
+            counts.total() >= MIN_INVOCATIONS
+                && counts.internal_error_rate() > MAX_INTERNAL_ERROR_RATE
+        })
+        .map(|(name, counts)| ErrorRateAlert { name, counts })
+        .collect();
+
+    alerts.sort_unstable_by(|a, b| {
+        b.counts
+            .internal_error_rate()
+            .total_cmp(&a.counts.internal_error_rate())
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    alerts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn record_many(health: &CommandHealth, name: &str, outcome: CommandOutcome, n: u32) {
+        record_many_at(health, name, outcome, n, NOW);
+    }
+
+    fn record_many_at(
+        health: &CommandHealth,
+        name: &str,
+        outcome: CommandOutcome,
+        n: u32,
+        now: i64,
+    ) {
+        for _ in 0..n {
+            health.record_at(name, outcome, now);
+        }
+    }
+
+    #[test]
+    fn window_counts() {
+        let health = CommandHealth::new();
+
+        record_many(&health, "rs", CommandOutcome::Success, 3);
+        record_many(&health, "rs", CommandOutcome::UserError, 2);
+        record_many(&health, "rs", CommandOutcome::InternalError, 1);
+
+        let counts = health.counts(NOW);
+
+        assert_eq!(
+            counts["rs"],
+            OutcomeCounts {
+                success: 3,
+                user_error: 2,
+                internal_error: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn window_slides() {
+        let health = CommandHealth::new();
+
+        record_many_at(&health, "rs", CommandOutcome::InternalError, 4, NOW);
+        record_many_at(&health, "rs", CommandOutcome::Success, 2, NOW + 60);
+
+        // Only the older outcomes left the window
+        let counts = health.counts(NOW + WINDOW_SECS);
+
+        assert_eq!(
+            counts["rs"],
+            OutcomeCounts {
+                success: 2,
+                user_error: 0,
+                internal_error: 0,
+            }
+        );
+
+        assert!(health.counts(NOW + 60 + WINDOW_SECS).is_empty());
+    }
+
+    #[test]
+    fn errors_across_evaluations_alert() {
+        let health = CommandHealth::new();
+
+        // Errors straddle the previous evaluation but are within one window
+        record_many_at(&health, "profile", CommandOutcome::InternalError, 10, NOW);
+        assert!(health.evaluate_window(NOW + 60).is_empty());
+
+        record_many_at(
+            &health,
+            "profile",
+            CommandOutcome::InternalError,
+            10,
+            NOW + 120,
+        );
+        let alerts = health.evaluate_window(NOW + 180);
+
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].name.as_ref(), "profile");
+    }
+
+    #[test]
+    fn alert_once_until_recovered() {
+        let health = CommandHealth::new();
+
+        record_many(&health, "top", CommandOutcome::InternalError, 20);
+        assert_eq!(health.evaluate_window(NOW).len(), 1);
+        assert!(health.evaluate_window(NOW + 60).is_empty());
+
+        // The errors leave the window so the command recovers
+        assert!(health.evaluate_window(NOW + WINDOW_SECS).is_empty());
+
+        record_many_at(
+            &health,
+            "top",
+            CommandOutcome::InternalError,
+            20,
+            NOW + WINDOW_SECS,
+        );
+        assert_eq!(health.evaluate_window(NOW + WINDOW_SECS).len(), 1);
+    }
+
+    #[test]
+    fn alert_above_threshold() {
+        let health = CommandHealth::new();
+
+        // 7 of 20 = 35%
+        record_many(&health, "profile", CommandOutcome::Success, 13);
+        record_many(&health, "profile", CommandOutcome::InternalError, 7);
+
+        // 6 of 20 = 30% is not above the threshold
+        record_many(&health, "top", CommandOutcome::Success, 14);
+        record_many(&health, "top", CommandOutcome::InternalError, 6);
+
+        let alerts = evaluate(health.counts(NOW));
+
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].name.as_ref(), "profile");
+    }
+
+    #[test]
+    fn too_few_invocations() {
+        let health = CommandHealth::new();
+
+        record_many(
+            &health,
+            "recent",
+            CommandOutcome::InternalError,
+            MIN_INVOCATIONS - 1,
+        );
+
+        assert!(evaluate(health.counts(NOW)).is_empty());
+    }
+
+    #[test]
+    fn user_errors_dont_alert() {
+        let health = CommandHealth::new();
+
+        record_many(&health, "map", CommandOutcome::UserError, 30);
+
+        assert!(evaluate(health.counts(NOW)).is_empty());
+    }
+
+    #[test]
+    fn alerts_sorted_by_rate() {
+        let health = CommandHealth::new();
+
+        record_many(&health, "a", CommandOutcome::InternalError, 10);
+        record_many(&health, "a", CommandOutcome::Success, 10);
+        record_many(&health, "b", CommandOutcome::InternalError, 20);
+
+        let alerts = evaluate(health.counts(NOW));
+        let names: Vec<_> = alerts.iter().map(|alert| alert.name.as_ref()).collect();
+
+        assert_eq!(names, ["b", "a"]);
+    }
+}
@@ -0,0 +1,306 @@
+use std::{borrow::Cow, fmt::Write};
+
+use bathbot_psql::model::audit_log::AuditLogEntry;
+use time::OffsetDateTime;
+use twilight_model::{
+    application::interaction::application_command::{CommandDataOption, CommandOptionValue},
+    channel::Message,
+    id::{
+        Id,
+        marker::{GuildMarker, UserMarker},
+    },
+};
+
+use crate::{core::Context, util::interaction::InteractionCommand};
+
+/// Longer values of a single option are truncated.
+const MAX_VALUE_LEN: usize = 32;
+
+/// Must fit into the database column.
+const MAX_OPTIONS_LEN: usize = 256;
+
+/// Must fit into the database column.
+const MAX_COMMAND_LEN: usize = 64;
+
+/// Execution of an auditable command that is written to the guild's audit
+/// log once the command finished.
+pub struct AuditRecord {
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    command: String,
+    options: String,
+}
+
+impl AuditRecord {
+    /// Returns `None` if the command was not used in a guild or the
+    /// subcommand is one of the command's `read_only` paths.
+    pub fn interaction(
+        command: &InteractionCommand,
+        name: &str,
+        read_only: &[&str],
+    ) -> Option<Self> {
+        let (command_path, options) = summarize_options(name, &command.data.options);
+
+        if is_read_only_path(name, &command_path, read_only) {
+            return None;
+        }
+
+        Some(Self {
+            guild_id: command.guild_id?,
+            user_id: command.user_id().ok()?,
+            command: command_path,
+            options,
+        })
+    }
+
+    /// Returns `None` if the command was not used in a guild or only displays
+    /// data.
+    pub fn prefix(msg: &Message, name: &str, args: &str) -> Option<Self> {
+        if is_read_only_prefix(name, args.trim()) {
+            return None;
+        }
+
+        Some(Self {
+            guild_id: msg.guild_id?,
+            user_id: msg.author.id,
+            command: truncate(name, MAX_COMMAND_LEN).into_owned(),
+            options: truncate(args.trim(), MAX_OPTIONS_LEN).into_owned(),
+        })
+    }
+
+    /// Write the record without waiting for the database.
+    pub fn record(self, success: bool) {
+        let entry = AuditLogEntry {
+            guild_id: self.guild_id,
+            user_id: self.user_id,
+            command: self.command.into_boxed_str(),
+            options: self.options.into_boxed_str(),
+            success,
+            timestamp: OffsetDateTime::now_utc(),
+        };
+
+        tokio::spawn(async move {
+            if let Err(err) = Context::psql().insert_audit_log_entry(&entry).await {
+                warn!(?err, "Failed to insert audit log entry");
+            }
+        });
+    }
+}
+
+/// Returns the full command path including group and subcommand, and a
+/// summary of the options e.g. `("track osu", "name:peppy limit:50")`.
+fn summarize_options(name: &str, mut options: &[CommandDataOption]) -> (String, String) {
+    let mut path = name.to_owned();
+
+    while let [
+        CommandDataOption {
+            name: sub,
+            value:
+                CommandOptionValue::SubCommand(nested) | CommandOptionValue::SubCommandGroup(nested),
+        },
+    ] = options
+    {
+        path.push(' ');
+        path.push_str(sub);
+        options = nested.as_slice();
+    }
+
+    let mut summary = String::new();
+
+    for option in options {
+        if !summary.is_empty() {
+            summary.push(' ');
+        }
+
+        let _ = write!(summary, "{}:", option.name);
+
+        let _ = match option.value {
+            CommandOptionValue::Attachment(_) => write!(summary, "<attachment>"),
+            CommandOptionValue::Boolean(value) => write!(summary, "{value}"),
+            CommandOptionValue::Channel(id) => write!(summary, "<#{id}>"),
+            CommandOptionValue::Integer(value) => write!(summary, "{value}"),
+            CommandOptionValue::Mentionable(id) => write!(summary, "{id}"),
+            CommandOptionValue::Number(value) => write!(summary, "{value}"),
+            CommandOptionValue::Role(id) => write!(summary, "<@&{id}>"),
+            CommandOptionValue::String(ref value) => {
+                let value = truncate(value, MAX_VALUE_LEN);
+
+                if value.contains(char::is_whitespace) {
+                    write!(summary, "\"{value}\"")
+                } else {
+                    write!(summary, "{value}")
+                }
+            }
+            CommandOptionValue::User(id) => write!(summary, "<@{id}>"),
+            CommandOptionValue::Focused(..)
+            | CommandOptionValue::SubCommand(_)
+            | CommandOptionValue::SubCommandGroup(_) => write!(summary, "…"),
+        };
+    }
+
+    let path = truncate(&path, MAX_COMMAND_LEN).into_owned();
+    let summary = truncate(&summary, MAX_OPTIONS_LEN).into_owned();
+
+    (path, summary)
+}
+
+/// Whether the command path without the command's name is one of the
+/// `read_only` subcommand paths.
+fn is_read_only_path(name: &str, path: &str, read_only: &[&str]) -> bool {
+    path.strip_prefix(name)
+        .and_then(|sub| sub.strip_prefix(' '))
+        .is_some_and(|sub| read_only.contains(&sub))
+}
+
+/// Whether the prefix command's arguments only ask to display data.
+fn is_read_only_prefix(name: &str, args: &str) -> bool {
+    match name {
+        "authorities" => matches!(args, "-show" | "show"),
+        "prefix" => args.is_empty(),
+        _ => false,
+    }
+}
+
+/// Cut off the end if the value has more than `max` characters.
+fn truncate(value: &str, max: usize) -> Cow<'_, str> {
+    match value.char_indices().nth(max) {
+        None => Cow::Borrowed(value),
+        Some(_) => {
+            let end = value
+                .char_indices()
+                .nth(max - 1)
+                .map_or(value.len(), |(idx, _)| idx);
+
+            Cow::Owned(format!("{}…", &value[..end]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn option(name: &str, value: CommandOptionValue) -> CommandDataOption {
+        CommandDataOption {
+            name: name.to_owned(),
+            value,
+        }
+    }
+
+    #[test]
+    fn path_includes_subcommands() {
+        let options = [option(
+            "authorities",
+            CommandOptionValue::SubCommandGroup(vec![option(
+                "add",
+                CommandOptionValue::SubCommand(vec![option(
+                    "role",
+                    CommandOptionValue::Role(Id::new(123)),
+                )]),
+            )]),
+        )];
+
+        let (path, summary) = summarize_options("serverconfig", &options);
+
+        assert_eq!(path, "serverconfig authorities add");
+        assert_eq!(summary, "role:<@&123>");
+    }
+
+    #[test]
+    fn summarizes_values() {
+        let options = [
+            option("name", CommandOptionValue::String("peppy".to_owned())),
+            option("channel", CommandOptionValue::Channel(Id::new(456))),
+            option("limit", CommandOptionValue::Integer(50)),
+            option("min_pp", CommandOptionValue::Number(123.5)),
+            option("reverse", CommandOptionValue::Boolean(true)),
+            option("discord", CommandOptionValue::User(Id::new(789))),
+            option("file", CommandOptionValue::Attachment(Id::new(1))),
+            option("title", CommandOptionValue::String("two words".to_owned())),
+        ];
+
+        let (path, summary) = summarize_options("track", &options);
+
+        assert_eq!(path, "track");
+        assert_eq!(
+            summary,
+            "name:peppy channel:<#456> limit:50 min_pp:123.5 reverse:true \
+            discord:<@789> file:<attachment> title:\"two words\""
+        );
+    }
+
+    #[test]
+    fn no_options() {
+        let options = [option(
+            "remove_all",
+            CommandOptionValue::SubCommand(Vec::new()),
+        )];
+
+        let (path, summary) = summarize_options("serverconfig", &options);
+
+        assert_eq!(path, "serverconfig remove_all");
+        assert!(summary.is_empty());
+    }
+
+    #[test]
+    fn truncates_long_values() {
+        let long = "a".repeat(100);
+        let options = [option("name", CommandOptionValue::String(long))];
+
+        let (_, summary) = summarize_options("track", &options);
+
+        let expected = format!("name:{}…", "a".repeat(MAX_VALUE_LEN - 1));
+        assert_eq!(summary, expected);
+    }
+
+    #[test]
+    fn truncates_summary() {
+        let options: Vec<_> = (0..50)
+            .map(|i| option("name", CommandOptionValue::String(format!("user{i}"))))
+            .collect();
+
+        let (_, summary) = summarize_options("track", &options);
+
+        assert_eq!(summary.chars().count(), MAX_OPTIONS_LEN);
+        assert!(summary.ends_with('…'));
+    }
+
+    #[test]
+    fn read_only_views_are_skipped() {
+        let options = [option(
+            "authorities",
+            CommandOptionValue::SubCommandGroup(vec![option(
+                "list",
+                CommandOptionValue::SubCommand(Vec::new()),
+            )]),
+        )];
+
+        let (path, _) = summarize_options("serverconfig", &options);
+        let read_only = ["auditlog", "authorities list"];
+        assert!(is_read_only_path("serverconfig", &path, &read_only));
+        assert!(!is_read_only_path(
+            "serverconfig",
+            "serverconfig authorities add",
+            &read_only
+        ));
+        assert!(!is_read_only_path(
+            "serverconfig",
+            "serverconfig",
+            &read_only
+        ));
+        assert!(!is_read_only_path("track", "track list", &[]));
+
+        assert!(is_read_only_prefix("authorities", "-show"));
+        assert!(!is_read_only_prefix("authorities", "@Moderator"));
+        assert!(is_read_only_prefix("prefix", ""));
+        assert!(!is_read_only_prefix("prefix", "add !"));
+        assert!(!is_read_only_prefix("track", ""));
+    }
+
+    #[test]
+    fn truncate_respects_char_boundaries() {
+        assert_eq!(truncate("äöü", 3), "äöü");
+        assert_eq!(truncate("äöüß", 3), "äö…");
+        assert_eq!(truncate("", 3), "");
+    }
+}
@@ -17,7 +17,7 @@ use twilight_model::{
     },
 };
 
-use crate::core::{BotConfig, Context};
+use crate::core::{BotConfig, Context, commands::interaction::InteractionCommands};
 
 /// Is authority -> Ok(None)
 /// No authority -> Ok(Some(message to user))
@@ -40,10 +40,13 @@ pub async fn check_authority(
         .await;
 
     if auth_roles.is_empty() {
-        let content = "You need admin permissions to use this command.\n\
-            (`/serverconfig` to adjust authority status for this server)";
+        let content = format!(
+            "You need admin permissions to use this command.\n\
+            ({} to adjust authority status for this server)",
+            InteractionCommands::mention_in(Some(guild_id), "serverconfig authorities")
+        );
 
-        return Ok(Some(content.to_owned()));
+        return Ok(Some(content));
     }
 
     let member = match roles {
@@ -78,7 +81,11 @@ pub async fn check_authority(
             }
         }
 
-        content.push_str("\n(`/serverconfig` to adjust authority status for this server)");
+        let _ = write!(
+            content,
+            "\n({} to adjust authority status for this server)",
+            InteractionCommands::mention_in(Some(guild_id), "serverconfig authorities")
+        );
 
         return Ok(Some(content));
     }
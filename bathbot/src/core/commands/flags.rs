@@ -6,6 +6,8 @@ bitflags::bitflags! {
         const ONLY_GUILDS = 1 << 2;
         const ONLY_OWNER  = 1 << 3;
         const SKIP_DEFER  = 1 << 4;
+        const NO_DATABASE = 1 << 5;
+        const AUDITABLE   = 1 << 6;
     }
 }
 
@@ -18,6 +20,10 @@ impl CommandFlags {
         !self.contains(CommandFlags::SKIP_DEFER)
     }
 
+    pub fn needs_database(self) -> bool {
+        !self.contains(CommandFlags::NO_DATABASE)
+    }
+
     pub fn ephemeral(self) -> bool {
         self.contains(CommandFlags::EPHEMERAL)
     }
@@ -29,4 +35,9 @@ impl CommandFlags {
     pub fn only_owner(self) -> bool {
         self.contains(CommandFlags::ONLY_OWNER)
     }
+
+    /// Whether executions should be written to the guild's audit log.
+    pub fn auditable(self) -> bool {
+        self.contains(CommandFlags::AUDITABLE)
+    }
 }
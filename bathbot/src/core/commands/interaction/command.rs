@@ -27,6 +27,13 @@ impl InteractionCommandKind {
         }
     }
 
+    pub fn exec(&self) -> fn(InteractionCommand) -> CommandResult {
+        match self {
+            InteractionCommandKind::Chat(cmd) => cmd.exec,
+            InteractionCommandKind::Message(cmd) => cmd.exec,
+        }
+    }
+
     pub fn flags(&self) -> CommandFlags {
         match self {
             InteractionCommandKind::Chat(cmd) => cmd.flags,
@@ -34,10 +41,23 @@ impl InteractionCommandKind {
         }
     }
 
+    /// Paths of subcommands that only display data and are not audited.
+    pub fn read_only(&self) -> &'static [&'static str] {
+        match self {
+            InteractionCommandKind::Chat(cmd) => cmd.read_only,
+            InteractionCommandKind::Message(_) => &[],
+        }
+    }
+
     pub fn id(&self) -> Id<CommandMarker> {
+        self.try_id().expect("missing command id")
+    }
+
+    /// The command's id or `None` if it has not been registered yet.
+    pub fn try_id(&self) -> Option<Id<CommandMarker>> {
         match self {
-            InteractionCommandKind::Chat(cmd) => *cmd.id.get().expect("missing command id"),
-            InteractionCommandKind::Message(cmd) => *cmd.id.get().expect("missing command id"),
+            InteractionCommandKind::Chat(cmd) => cmd.id.get().copied(),
+            InteractionCommandKind::Message(cmd) => cmd.id.get().copied(),
         }
     }
 
@@ -55,6 +75,8 @@ pub struct SlashCommand {
     pub exec: fn(InteractionCommand) -> CommandResult,
     pub flags: CommandFlags,
     pub name: &'static str,
+    /// Paths of subcommands that only display data and are not audited
+    pub read_only: &'static [&'static str],
     pub id: OnceLock<Id<CommandMarker>>,
 }
 
@@ -0,0 +1,219 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use once_cell::sync::OnceCell;
+use twilight_model::{
+    application::command::Command,
+    id::{
+        Id,
+        marker::{CommandMarker, GuildMarker},
+    },
+};
+
+/// Registrations that may happen in a burst, e.g. when joining many guilds
+/// at once.
+const BUCKET_CAPACITY: u32 = 5;
+
+/// Time until another registration is allowed once the burst is used up.
+const BUCKET_REFILL: Duration = Duration::from_secs(10);
+
+static GUILD_COMMANDS: OnceCell<GuildCommands> = OnceCell::new();
+
+/// Command ids of guilds whose commands were registered individually.
+///
+/// Only used if commands are not registered globally.
+pub struct GuildCommands {
+    /// Guilds whose commands are registered or currently being registered
+    known: Mutex<HashSet<Id<GuildMarker>>>,
+    ids: Mutex<HashMap<Id<GuildMarker>, HashMap<String, Id<CommandMarker>>>>,
+    bucket: Mutex<TokenBucket>,
+}
+
+impl GuildCommands {
+    pub fn get() -> &'static Self {
+        GUILD_COMMANDS.get_or_init(|| Self {
+            known: Mutex::new(HashSet::new()),
+            ids: Mutex::new(HashMap::new()),
+            bucket: Mutex::new(TokenBucket::new(
+                BUCKET_CAPACITY,
+                BUCKET_REFILL,
+                Instant::now(),
+            )),
+        })
+    }
+
+    /// Wait until the rate limit allows another registration.
+    pub async fn acquire(&self) {
+        loop {
+            let res = self.bucket.lock().unwrap().try_acquire(Instant::now());
+
+            match res {
+                Ok(()) => return,
+                Err(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+    }
+
+    /// Mark the guild as known so that its commands are registered only
+    /// once.
+    ///
+    /// Returns `false` if the guild was already known.
+    pub fn insert_known(&self, guild: Id<GuildMarker>) -> bool {
+        self.known.lock().unwrap().insert(guild)
+    }
+
+    pub fn set_ids(&self, guild: Id<GuildMarker>, commands: &[Command]) {
+        let ids = commands
+            .iter()
+            .filter_map(|cmd| Some((cmd.name.clone(), cmd.id?)))
+            .collect();
+
+        self.known.lock().unwrap().insert(guild);
+        self.ids.lock().unwrap().insert(guild, ids);
+    }
+
+    /// Forget about the guild so that its commands are registered again
+    /// once it's joined.
+    pub fn forget(&self, guild: Id<GuildMarker>) {
+        self.known.lock().unwrap().remove(&guild);
+        self.ids.lock().unwrap().remove(&guild);
+    }
+
+    /// The id of the command in the guild, if it was registered for it.
+    pub fn id(&self, guild: Id<GuildMarker>, name: &str) -> Option<Id<CommandMarker>> {
+        self.ids.lock().unwrap().get(&guild)?.get(name).copied()
+    }
+}
+
+/// Whether a guild delete is due to the bot leaving the guild, as opposed to
+/// the guild becoming unavailable.
+pub fn is_leave(unavailable: Option<bool>) -> bool {
+    unavailable != Some(true)
+}
+
+/// Simple token bucket that refills one token at a time.
+struct TokenBucket {
+    capacity: u32,
+    tokens: u32,
+    refill: Duration,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: u32, refill: Duration, now: Instant) -> Self {
+        Self {
+            capacity,
+            tokens: capacity,
+            refill,
+            last_refill: now,
+        }
+    }
+
+    /// Take a token or return how long to wait until the next one is
+    /// available.
+    fn try_acquire(&mut self, now: Instant) -> Result<(), Duration> {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        let refilled = (elapsed.as_nanos() / self.refill.as_nanos()) as u32;
+
+        if refilled > 0 {
+            self.tokens = self.tokens.saturating_add(refilled).min(self.capacity);
+            self.last_refill += self.refill * refilled;
+        }
+
+        if self.tokens == self.capacity {
+            // A full bucket doesn't accumulate time towards the next token
+            self.last_refill = now;
+        }
+
+        if self.tokens > 0 {
+            self.tokens -= 1;
+
+            Ok(())
+        } else {
+            Err((self.last_refill + self.refill).saturating_duration_since(now))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REFILL: Duration = Duration::from_secs(10);
+
+    #[test]
+    fn bucket_allows_burst() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(3, REFILL, now);
+
+        for _ in 0..3 {
+            assert_eq!(bucket.try_acquire(now), Ok(()));
+        }
+
+        assert_eq!(bucket.try_acquire(now), Err(REFILL));
+
+        let later = now + Duration::from_secs(4);
+        assert_eq!(bucket.try_acquire(later), Err(Duration::from_secs(6)));
+    }
+
+    #[test]
+    fn bucket_refills() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(2, REFILL, now);
+
+        assert!(bucket.try_acquire(now).is_ok());
+        assert!(bucket.try_acquire(now).is_ok());
+        assert!(bucket.try_acquire(now).is_err());
+
+        let later = now + REFILL;
+        assert!(bucket.try_acquire(later).is_ok());
+        assert!(bucket.try_acquire(later).is_err());
+
+        // Partial progress towards the next token is kept
+        let even_later = later + REFILL * 3 / 2;
+        assert!(bucket.try_acquire(even_later).is_ok());
+        assert_eq!(bucket.try_acquire(even_later), Err(REFILL / 2));
+    }
+
+    #[test]
+    fn bucket_is_capped() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(2, REFILL, now);
+
+        let much_later = now + REFILL * 100;
+
+        assert!(bucket.try_acquire(much_later).is_ok());
+        assert!(bucket.try_acquire(much_later).is_ok());
+        assert!(bucket.try_acquire(much_later).is_err());
+    }
+
+    #[test]
+    fn known_guilds() {
+        let guild_commands = GuildCommands::get();
+        let guild = Id::new(3);
+
+        assert!(guild_commands.insert_known(guild));
+        assert!(!guild_commands.insert_known(guild));
+
+        guild_commands.forget(guild);
+        assert!(guild_commands.insert_known(guild));
+
+        // Registered guilds are known too
+        let other = Id::new(4);
+        guild_commands.set_ids(other, &[]);
+        assert!(!guild_commands.insert_known(other));
+
+        guild_commands.forget(guild);
+        guild_commands.forget(other);
+    }
+
+    #[test]
+    fn leave_or_outage() {
+        assert!(is_leave(None));
+        assert!(is_leave(Some(false)));
+        assert!(!is_leave(Some(true)));
+    }
+}
@@ -5,11 +5,21 @@ use futures::Future;
 use linkme::distributed_slice;
 use once_cell::sync::OnceCell;
 use radix_trie::{Trie, TrieCommon, iter::Keys};
-use twilight_model::application::command::Command;
-
-pub use self::command::{InteractionCommandKind, MessageCommand, SlashCommand};
+use twilight_model::{
+    application::command::Command,
+    id::{
+        Id,
+        marker::{CommandMarker, GuildMarker},
+    },
+};
+
+pub use self::{
+    command::{InteractionCommandKind, MessageCommand, SlashCommand},
+    guild::{GuildCommands, is_leave},
+};
 
 mod command;
+mod guild;
 
 #[distributed_slice]
 pub static __SLASH_COMMANDS: [SlashCommand] = [..];
@@ -50,6 +60,28 @@ impl InteractionCommands {
         Self::get().command(command)
     }
 
+    /// Clickable mention of a slash command, e.g. `"link"` or `"config edit"`.
+    ///
+    /// Falls back to the command name in backticks if the command's id is not
+    /// known, e.g. before the commands have been registered.
+    pub fn mention(full_name: &str) -> String {
+        let name = full_name.split(' ').next().unwrap_or(full_name);
+        let id = Self::get_command(name).and_then(|cmd| cmd.try_id());
+
+        mention_or_name(full_name, id)
+    }
+
+    /// Same as [`InteractionCommands::mention`] but prefers the command's id
+    /// in the guild if the guild's commands were registered individually.
+    pub fn mention_in(guild: Option<Id<GuildMarker>>, full_name: &str) -> String {
+        let name = full_name.split(' ').next().unwrap_or(full_name);
+
+        match guild.and_then(|guild| GuildCommands::get().id(guild, name)) {
+            Some(id) => mention_or_name(full_name, Some(id)),
+            None => Self::mention(full_name),
+        }
+    }
+
     pub fn collect(&self) -> Vec<Command> {
         self.0
             .values()
@@ -89,3 +121,62 @@ impl InteractionCommands {
         }
     }
 }
+
+fn mention_or_name(full_name: &str, id: Option<Id<CommandMarker>>) -> String {
+    match id {
+        Some(id) => format!("</{full_name}:{id}>"),
+        None => format!("`/{full_name}`"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mention_without_id() {
+        assert_eq!(
+            InteractionCommands::mention("config edit"),
+            "`/config edit`"
+        );
+        assert_eq!(InteractionCommands::mention("unknown"), "`/unknown`");
+    }
+
+    #[test]
+    fn mention_with_id() {
+        // Command ids can't be unset so they're not set on the global commands
+        assert_eq!(mention_or_name("link", Some(Id::new(42))), "</link:42>");
+        assert_eq!(
+            mention_or_name("config edit", Some(Id::new(42))),
+            "</config edit:42>"
+        );
+        assert_eq!(mention_or_name("link", None), "`/link`");
+    }
+
+    #[test]
+    fn mention_in_guild() {
+        let guild = Id::new(1);
+
+        let mut cmd = InteractionCommands::get_command("mapwatch")
+            .unwrap()
+            .create();
+        cmd.id = Some(Id::new(7));
+        GuildCommands::get().set_ids(guild, &[cmd]);
+
+        assert_eq!(
+            InteractionCommands::mention_in(Some(guild), "mapwatch add"),
+            "</mapwatch add:7>"
+        );
+        assert_eq!(
+            InteractionCommands::mention_in(Some(Id::new(2)), "mapwatch add"),
+            "`/mapwatch add`"
+        );
+
+        GuildCommands::get().forget(guild);
+
+        assert_eq!(
+            InteractionCommands::mention_in(Some(guild), "mapwatch add"),
+            "`/mapwatch add`"
+        );
+    }
+}
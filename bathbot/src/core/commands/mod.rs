@@ -1,11 +1,13 @@
 pub use self::{
     flags::CommandFlags,
-    origin::{CommandOrigin, OwnedCommandOrigin},
+    origin::{CommandOrigin, OwnedCommandOrigin, localize_error},
 };
 
 mod flags;
 mod origin;
 
+pub mod audit;
 pub mod checks;
 pub mod interaction;
+pub mod outcome;
 pub mod prefix;
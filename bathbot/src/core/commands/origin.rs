@@ -1,4 +1,4 @@
-use bathbot_util::{Authored, EmbedBuilder, MessageBuilder};
+use bathbot_util::{Authored, EmbedBuilder, MessageBuilder, i18n::ErrorKey, tr};
 use eyre::{ContextCompat, Result, WrapErr};
 use twilight_http::Response;
 use twilight_model::{
@@ -10,8 +10,9 @@ use twilight_model::{
     },
 };
 
+use super::{outcome::note_error_response, prefix::note_response};
 use crate::{
-    core::Context,
+    core::{API_INCIDENT_BANNER, Context},
     util::{
         ChannelExt, InteractionCommandExt, InteractionToken, MessageExt,
         interaction::{InteractionCommand, InteractionComponent},
@@ -52,16 +53,24 @@ impl CommandOrigin<'_> {
 
     /// Respond to something.
     ///
-    /// In case of a message, discard the response message created.
+    /// In case of a message, the response message created is only noted so
+    /// that it can be deleted after a while.
     ///
     /// In case of an interaction, the response will **not** be ephemeral.
     pub async fn callback(&self, builder: MessageBuilder<'_>) -> Result<()> {
         match self {
-            Self::Message { msg, permissions } => msg
-                .create_message(builder, *permissions)
-                .await
-                .map(unit)
-                .wrap_err("failed to create message to callback"),
+            Self::Message { msg, permissions } => {
+                let response = msg
+                    .create_message(builder, *permissions)
+                    .await
+                    .wrap_err("failed to create message to callback")?;
+
+                if let Ok(response) = response.model().await {
+                    note_response(&response);
+                }
+
+                Ok(())
+            }
             Self::Interaction { command } => command
                 .callback(builder, false)
                 .await
@@ -72,8 +81,26 @@ impl CommandOrigin<'_> {
 
     /// Respond to something and return the resulting response message.
     ///
+    /// In case of a message, the response message is noted so that it can be
+    /// deleted after a while.
+    ///
     /// In case of an interaction, the response will **not** be ephemeral.
-    pub async fn callback_with_response(
+    pub async fn callback_with_response(&self, builder: MessageBuilder<'_>) -> Result<Message> {
+        let response = self
+            .callback_with_response_unnoted(builder)
+            .await?
+            .model()
+            .await
+            .wrap_err("failed to deserialize response callback")?;
+
+        self.note(&response);
+
+        Ok(response)
+    }
+
+    /// Same as [`CommandOrigin::callback_with_response`] except that the
+    /// response is never deleted automatically, e.g. because it's paginated.
+    pub async fn callback_with_response_unnoted(
         &self,
         builder: MessageBuilder<'_>,
     ) -> Result<Response<Message>> {
@@ -122,11 +149,32 @@ impl CommandOrigin<'_> {
 
     /// Respond to something and return the resulting response message.
     ///
+    /// In case of a message, the response message is noted so that it can be
+    /// deleted after a while.
+    ///
     /// In case of an interaction, be sure you already called back the invoke,
     /// either through deferring or a previous initial response.
     /// Also be sure this is only called once.
     /// Afterwards, use the resulting response message instead.
-    pub async fn create_message(&self, builder: MessageBuilder<'_>) -> Result<Response<Message>> {
+    pub async fn create_message(&self, builder: MessageBuilder<'_>) -> Result<Message> {
+        let response = self
+            .create_message_unnoted(builder)
+            .await?
+            .model()
+            .await
+            .wrap_err("failed to deserialize created response")?;
+
+        self.note(&response);
+
+        Ok(response)
+    }
+
+    /// Same as [`CommandOrigin::create_message`] except that the response is
+    /// never deleted automatically, e.g. because it's paginated.
+    pub async fn create_message_unnoted(
+        &self,
+        builder: MessageBuilder<'_>,
+    ) -> Result<Response<Message>> {
         match self {
             Self::Message { msg, permissions } => msg
                 .create_message(builder, *permissions)
@@ -190,6 +238,30 @@ impl CommandOrigin<'_> {
                 .wrap_err("failed to callback with error"),
         }
     }
+
+    /// Same as [`CommandOrigin::error`] but with a generic error in the
+    /// guild's language.
+    pub async fn error_tr(&self, key: ErrorKey) -> Result<()> {
+        let content = localize_error(self.guild_id(), key).await;
+
+        self.error(content).await
+    }
+
+    /// Same as [`CommandOrigin::error_callback`] but with a generic error in
+    /// the guild's language.
+    pub async fn error_callback_tr(&self, key: ErrorKey) -> Result<()> {
+        let content = localize_error(self.guild_id(), key).await;
+
+        self.error_callback(content).await
+    }
+
+    /// Note the response of a prefix command so that it can be deleted after a
+    /// while. Interaction responses are never deleted.
+    fn note(&self, response: &Message) {
+        if let Self::Message { .. } = self {
+            note_response(response);
+        }
+    }
 }
 
 impl<'d> CommandOrigin<'d> {
@@ -214,6 +286,19 @@ impl<'d> From<&'d mut InteractionCommand> for CommandOrigin<'d> {
     }
 }
 
+/// Translate a generic error into the guild's language.
+pub async fn localize_error(guild_id: Option<Id<GuildMarker>>, key: ErrorKey) -> String {
+    let lang = Context::guild_config().lang(guild_id).await;
+    let mut content = tr!(key.key(), lang);
+
+    if key == ErrorKey::OsuApi && Context::api_incident().is_degraded() {
+        content.push('\n');
+        content.push_str(API_INCIDENT_BANNER);
+    }
+
+    content
+}
+
 pub enum OwnedCommandOrigin {
     Message {
         msg: Id<MessageMarker>,
@@ -260,11 +345,15 @@ impl OwnedCommandOrigin {
                 msg,
                 channel,
                 permissions,
-            } => (*msg, *channel)
-                .reply(builder, *permissions)
-                .await
-                .map(unit)
-                .wrap_err("Failed to reply with error"),
+            } => {
+                note_error_response();
+
+                (*msg, *channel)
+                    .reply(builder, *permissions)
+                    .await
+                    .map(unit)
+                    .wrap_err("Failed to reply with error")
+            }
             OwnedCommandOrigin::Interaction { token, permissions } => token
                 .update(builder, *permissions)
                 .await
@@ -313,3 +402,79 @@ impl From<&InteractionComponent> for OwnedCommandOrigin {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::*;
+    use crate::core::commands::prefix::track_response;
+
+    fn message(id: u64, components: Value) -> Message {
+        let msg = json!({
+            "id": id.to_string(),
+            "channel_id": "1",
+            "author": {
+                "id": "3",
+                "username": "bathbot",
+                "discriminator": "0",
+                "avatar": null,
+                "bot": true
+            },
+            "content": "",
+            "timestamp": "2026-10-16T12:00:00.000000+00:00",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "mention_channels": [],
+            "attachments": [],
+            "components": components,
+            "embeds": [],
+            "reactions": [],
+            "sticker_items": [],
+            "pinned": false,
+            "type": 0
+        });
+
+        serde_json::from_value(msg).unwrap()
+    }
+
+    #[tokio::test]
+    async fn created_message_is_noted() {
+        let invoke = message(1, json!([]));
+        let response = message(2, json!([]));
+        let orig = CommandOrigin::from_msg(&invoke, None);
+
+        let (_, tracked) = track_response(async { orig.note(&response) }).await;
+        let noted = tracked.response.expect("response was not noted");
+
+        assert_eq!(noted.message, response.id);
+        assert_eq!(noted.channel, response.channel_id);
+        assert!(!noted.components);
+    }
+
+    #[tokio::test]
+    async fn component_message_is_kept() {
+        let components = json!([{
+            "type": 1,
+            "components": [{
+                "type": 2,
+                "style": 1,
+                "label": "Next",
+                "custom_id": "pagination_next"
+            }]
+        }]);
+
+        let invoke = message(1, json!([]));
+        let response = message(2, components);
+        let orig = CommandOrigin::from_msg(&invoke, None);
+
+        let (_, tracked) = track_response(async { orig.note(&response) }).await;
+        let noted = tracked.response.expect("response was not noted");
+
+        // Noted responses with components are never auto-deleted
+        assert!(noted.components);
+    }
+}
@@ -0,0 +1,99 @@
+use std::cell::Cell;
+
+use futures::Future;
+
+use crate::core::{BotMetrics, Context};
+
+tokio::task_local! {
+    /// Whether the currently processed command responded with an error.
+    static ERROR_RESPONSE: Cell<bool>;
+}
+
+/// Note that the currently processed command, if any, responded with an
+/// error.
+pub fn note_error_response() {
+    let _ = ERROR_RESPONSE.try_with(|responded| responded.set(true));
+}
+
+/// Await a command and return its output alongside whether it responded with
+/// an error.
+pub async fn track_error_response<F: Future>(fut: F) -> (F::Output, bool) {
+    let fut = async {
+        let output = fut.await;
+
+        (output, ERROR_RESPONSE.with(Cell::get))
+    };
+
+    ERROR_RESPONSE.scope(Cell::new(false), fut).await
+}
+
+/// How the execution of a command ended.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CommandOutcome {
+    Success,
+    /// The command responded with an error but finished regularly e.g.
+    /// because the user provided invalid arguments
+    UserError,
+    /// The command returned an error
+    InternalError,
+}
+
+impl CommandOutcome {
+    pub fn new<T, E>(res: &Result<T, E>, error_response: bool) -> Self {
+        match res {
+            Err(_) => Self::InternalError,
+            Ok(_) if error_response => Self::UserError,
+            Ok(_) => Self::Success,
+        }
+    }
+
+    /// Count the outcome towards the metrics and the command's error rate.
+    pub fn record(self, kind: &'static str, name: &str) {
+        BotMetrics::inc_command_outcome(kind, name.to_owned(), self);
+        Context::command_health().record(name, self);
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::UserError => "user_error",
+            Self::InternalError => "internal_error",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify() {
+        let ok: Result<(), ()> = Ok(());
+        let err: Result<(), ()> = Err(());
+
+        assert_eq!(CommandOutcome::new(&ok, false), CommandOutcome::Success);
+        assert_eq!(CommandOutcome::new(&ok, true), CommandOutcome::UserError);
+        assert_eq!(
+            CommandOutcome::new(&err, false),
+            CommandOutcome::InternalError
+        );
+
+        // Commands commonly respond with a generic error before failing
+        assert_eq!(
+            CommandOutcome::new(&err, true),
+            CommandOutcome::InternalError
+        );
+    }
+
+    #[tokio::test]
+    async fn tracks_error_response() {
+        let (_, responded) = track_error_response(async { note_error_response() }).await;
+        assert!(responded);
+
+        let (_, responded) = track_error_response(async {}).await;
+        assert!(!responded);
+
+        // Outside of a tracked command noting is a no-op
+        note_error_response();
+    }
+}
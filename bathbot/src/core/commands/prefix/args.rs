@@ -1,16 +1,28 @@
+use std::{borrow::Cow, iter};
+
+use bathbot_util::CowUtils;
 use nom::{
     Err as NomErr, IResult,
-    branch::alt,
-    bytes::complete as by,
     character::complete as ch,
-    combinator::{ParserIterator, iterator, map_opt},
-    error::Error as NomError,
-    sequence::{delimited, terminated},
+    combinator::{ParserIterator, iterator},
+    error::{Error as NomError, ErrorKind},
 };
 
 type ItemError<'m> = NomError<&'m str>;
 type ItemFn<'m> = fn(&'m str) -> IResult<&'m str, &'m str, ItemError<'m>>;
 
+/// Opening and closing characters of quoted arguments.
+const QUOTES: [(char, char); 6] = [
+    ('"', '"'),
+    ('\'', '\''),
+    ('“', '“'),
+    ('«', '»'),
+    ('„', '“'),
+    ('“', '”'),
+];
+
+const ESCAPE: char = '\\';
+
 pub struct Args<'m> {
     iter: ParserIterator<&'m str, ItemError<'m>, ItemFn<'m>>,
     pub num: ArgsNum,
@@ -38,42 +50,201 @@ impl<'m> Args<'m> {
         }
     }
 
+    /// Like [`Iterator::next`] but provides both the raw and the unquoted
+    /// form of the argument.
+    pub fn next_arg(&mut self) -> Option<Arg<'m>> {
+        (&mut self.iter).next().map(Arg::new)
+    }
+
+    /// Iterate over the remaining arguments through [`Args::next_arg`].
+    pub fn into_args(mut self) -> impl Iterator<Item = Arg<'m>> {
+        iter::from_fn(move || self.next_arg())
+    }
+
+    /// Parses the raw form of the next argument.
+    ///
+    /// An argument is either
+    ///   - wrapped in quotes, preserving inner whitespace
+    ///   - a `key=value` pair whose value is wrapped in quotes
+    ///   - everything up to the next whitespace
+    ///
+    /// Unterminated quotes are treated as regular characters.
     fn next_item(input: &'m str) -> IResult<&'m str, &'m str, ItemError<'m>> {
-        let quote_delimited = |start: char, end: char| {
-            delimited(
-                ch::char(start),
-                by::take_till1(move |c| c == end),
-                ch::char(end),
-            )
-        };
-
-        let simple = map_opt(by::take_till(char::is_whitespace), |item: &str| {
-            (!item.is_empty()).then_some(item)
-        });
-
-        let options = (
-            quote_delimited('"', '"'),
-            quote_delimited('\'', '\''),
-            quote_delimited('“', '“'),
-            quote_delimited('«', '»'),
-            quote_delimited('„', '“'),
-            quote_delimited('“', '”'),
-            simple,
-        );
+        let len = Quoted::parse(input)
+            .map(|quoted| quoted.len)
+            .or_else(|| quoted_key_value_len(input))
+            .unwrap_or_else(|| input.find(char::is_whitespace).unwrap_or(input.len()));
+
+        if len == 0 {
+            return Err(NomErr::Error(NomError::new(input, ErrorKind::TakeTill1)));
+        }
+
+        let (item, rest) = input.split_at(len);
+        let (rest, _) = ch::space0(rest)?;
 
-        terminated(alt(options), ch::space0)(input)
+        Ok((rest, item))
     }
 }
 
 impl<'m> Iterator for Args<'m> {
     type Item = &'m str;
 
+    /// Returns the next argument without its surrounding quotes.
+    ///
+    /// Escaped characters are kept as is; use [`Args::next_arg`] to resolve
+    /// them.
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        (&mut self.iter).next()
+        (&mut self.iter).next().map(strip_quotes)
+    }
+}
+
+/// A single argument of a prefix command.
+pub struct Arg<'m> {
+    /// The argument as it was written, including quotes and escapes
+    pub raw: &'m str,
+    /// The argument without surrounding quotes and with escapes resolved
+    pub value: Cow<'m, str>,
+}
+
+impl<'m> Arg<'m> {
+    fn new(raw: &'m str) -> Self {
+        Self {
+            raw,
+            value: unquote(raw),
+        }
+    }
+
+    /// Split a `key=value` argument and unquote its value.
+    ///
+    /// Returns `None` if the argument is quoted as a whole or has no key
+    /// before its first `=`.
+    pub fn key_value(&self) -> Option<(&'m str, Cow<'m, str>)> {
+        if Quoted::parse(self.raw).is_some_and(|quoted| quoted.len == self.raw.len()) {
+            return None;
+        }
+
+        let (key, value) = self
+            .raw
+            .split_once('=')
+            .filter(|(key, _)| !key.is_empty())?;
+
+        Some((key, unquote(value)))
+    }
+
+    /// The unquoted value in ASCII lowercase.
+    pub fn into_lowercase(self) -> Cow<'m, str> {
+        match self.value {
+            Cow::Borrowed(value) => value.cow_to_ascii_lowercase(),
+            Cow::Owned(mut value) => {
+                value.make_ascii_lowercase();
+
+                Cow::Owned(value)
+            }
+        }
     }
 }
 
+/// Remove surrounding quotes without resolving escaped characters.
+///
+/// Arguments that are not quoted as a whole are returned unchanged.
+pub fn strip_quotes(arg: &str) -> &str {
+    match Quoted::parse(arg) {
+        Some(quoted) if quoted.len == arg.len() => quoted.inner(arg),
+        _ => arg,
+    }
+}
+
+/// Remove surrounding quotes and resolve escaped characters.
+///
+/// Arguments that are not quoted as a whole are returned unchanged.
+pub fn unquote(arg: &str) -> Cow<'_, str> {
+    let quoted = match Quoted::parse(arg) {
+        Some(quoted) if quoted.len == arg.len() => quoted,
+        _ => return Cow::Borrowed(arg),
+    };
+
+    let inner = quoted.inner(arg);
+
+    if !inner.contains(ESCAPE) {
+        return Cow::Borrowed(inner);
+    }
+
+    let mut unescaped = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        if c == ESCAPE {
+            match chars.clone().next() {
+                Some(next) if next == quoted.close || next == ESCAPE => {
+                    unescaped.push(next);
+                    chars.next();
+
+                    continue;
+                }
+                _ => {}
+            }
+        }
+
+        unescaped.push(c);
+    }
+
+    Cow::Owned(unescaped)
+}
+
+/// Location of a quoted argument at the start of some input.
+#[derive(Copy, Clone)]
+struct Quoted {
+    open: char,
+    close: char,
+    /// Byte length including both quotes
+    len: usize,
+}
+
+impl Quoted {
+    /// Find a non-empty quoted argument at the start of the input.
+    ///
+    /// A backslash escapes the closing quote and itself.
+    fn parse(input: &str) -> Option<Self> {
+        let first = input.chars().next()?;
+
+        QUOTES
+            .into_iter()
+            .filter(|&(open, _)| open == first)
+            .find_map(|(open, close)| {
+                let start = open.len_utf8();
+                let mut chars = input[start..].char_indices();
+
+                while let Some((i, c)) = chars.next() {
+                    if c == ESCAPE {
+                        chars.next();
+                    } else if c == close {
+                        return (i > 0).then_some(Self {
+                            open,
+                            close,
+                            len: start + i + close.len_utf8(),
+                        });
+                    }
+                }
+
+                None
+            })
+    }
+
+    fn inner<'a>(&self, arg: &'a str) -> &'a str {
+        &arg[self.open.len_utf8()..self.len - self.close.len_utf8()]
+    }
+}
+
+/// Byte length of a `key=value` argument whose value is quoted.
+fn quoted_key_value_len(input: &str) -> Option<usize> {
+    let word_len = input.find(char::is_whitespace).unwrap_or(input.len());
+    let eq_idx = input[..word_len].find('=').filter(|&idx| idx > 0)?;
+    let value_start = eq_idx + 1;
+
+    Quoted::parse(&input[value_start..]).map(|quoted| value_start + quoted.len)
+}
+
 #[derive(Copy, Clone)]
 pub enum ArgsNum {
     Value(u32),
@@ -90,3 +261,146 @@ impl ArgsNum {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(content: &str) -> Vec<&str> {
+        Args::new(content, ArgsNum::None).collect()
+    }
+
+    fn values(content: &str) -> Vec<String> {
+        Args::new(content, ArgsNum::None)
+            .into_args()
+            .map(|arg| arg.value.into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn whitespace_separated() {
+        assert_eq!(args("peppy  be50\tx"), ["peppy", "be50", "x"]);
+        assert!(args("").is_empty());
+    }
+
+    #[test]
+    fn newline_ends_args() {
+        let mut args = Args::new("a b\nc", ArgsNum::None);
+
+        assert_eq!(args.next(), Some("a"));
+        assert_eq!(args.next(), Some("b"));
+        assert_eq!(args.next(), None);
+        assert_eq!(args.rest(), "\nc");
+    }
+
+    #[test]
+    fn rest_after_quoted() {
+        let mut args = Args::new("\"- Ares -\" rest of it", ArgsNum::None);
+
+        assert_eq!(args.next(), Some("- Ares -"));
+        assert_eq!(args.rest(), "rest of it");
+    }
+
+    #[test]
+    fn quoted_keeps_spaces() {
+        assert_eq!(args("\"- Ares -\" 123"), ["- Ares -", "123"]);
+        assert_eq!(
+            args("'a b' «c d» „e f“ “g h”"),
+            ["a b", "c d", "e f", "g h"]
+        );
+    }
+
+    #[test]
+    fn escaped_quotes() {
+        let content = r#""say \"hi\"" "back\\slash" "c:\path""#;
+
+        assert_eq!(args(content), [r#"say \"hi\""#, r"back\\slash", r"c:\path"]);
+
+        assert_eq!(values(content), [r#"say "hi""#, r"back\slash", r"c:\path"]);
+    }
+
+    #[test]
+    fn nested_quotes() {
+        assert_eq!(args(r#""it's" 'say "hi"'"#), ["it's", r#"say "hi""#]);
+    }
+
+    #[test]
+    fn unterminated_quotes_are_literal() {
+        assert_eq!(args("\"- Ares - 123"), ["\"-", "Ares", "-", "123"]);
+        assert_eq!(args(r#""abc\""#), [r#""abc\""#]);
+        assert_eq!(args("don't stop"), ["don't", "stop"]);
+    }
+
+    #[test]
+    fn empty_quotes_are_literal() {
+        assert_eq!(args("\"\" ''"), ["\"\"", "''"]);
+    }
+
+    #[test]
+    fn quoted_key_value() {
+        assert_eq!(args("sort=\"play date\" x"), ["sort=\"play date\"", "x"]);
+
+        let mut args = Args::new("sort=\"play \\\"date\\\"\" mods=hd", ArgsNum::None);
+
+        let arg = args.next_arg().unwrap();
+        assert_eq!(arg.raw, r#"sort="play \"date\"""#);
+        let (key, value) = arg.key_value().unwrap();
+        assert_eq!(key, "sort");
+        assert_eq!(value, r#"play "date""#);
+
+        let arg = args.next_arg().unwrap();
+        assert_eq!(arg.key_value().unwrap(), ("mods", Cow::Borrowed("hd")));
+
+        assert!(args.next_arg().is_none());
+    }
+
+    #[test]
+    fn quote_before_equals() {
+        assert_eq!(args("\"a=b\" c"), ["a=b", "c"]);
+        assert!(Arg::new("\"a=b\"").key_value().is_none());
+        assert!(Arg::new("=b").key_value().is_none());
+        assert_eq!(args("=\"a b\""), ["=\"a", "b\""]);
+        assert_eq!(args("key=\"a b"), ["key=\"a", "b"]);
+    }
+
+    #[test]
+    fn lowercase_value() {
+        let mut args = Args::new(r#"PePPy "A \"B\"" x"#, ArgsNum::None).into_args();
+
+        let arg = args.next().unwrap().into_lowercase();
+        assert!(matches!(arg, Cow::Owned(s) if s == "peppy"));
+
+        let arg = args.next().unwrap().into_lowercase();
+        assert!(matches!(arg, Cow::Owned(s) if s == r#"a "b""#));
+
+        let arg = args.next().unwrap().into_lowercase();
+        assert!(matches!(arg, Cow::Borrowed("x")));
+    }
+
+    #[test]
+    fn multi_byte_characters() {
+        assert_eq!(
+            args("\"日本 語\" «ü ß» name=\"ö ä\""),
+            ["日本 語", "ü ß", "name=\"ö ä\""]
+        );
+
+        assert_eq!(values("name=\"ö ä\""), ["name=\"ö ä\""]);
+        assert_eq!(unquote("“日本\\”語”"), "日本”語");
+    }
+
+    #[test]
+    fn strip_and_unquote() {
+        assert_eq!(strip_quotes("\"a b\""), "a b");
+        assert_eq!(strip_quotes("\"a\" b"), "\"a\" b");
+        assert_eq!(strip_quotes("plain"), "plain");
+        assert!(matches!(unquote("\"a b\""), Cow::Borrowed("a b")));
+        assert!(matches!(unquote("\"a \\\" b\""), Cow::Owned(s) if s == "a \" b"));
+    }
+
+    #[test]
+    fn args_num_is_kept() {
+        let args = Args::new("\"a b\"", ArgsNum::Value(3));
+
+        assert_eq!(args.num.to_string_opt().as_deref(), Some("3"));
+    }
+}
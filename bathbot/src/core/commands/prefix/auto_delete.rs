@@ -0,0 +1,252 @@
+use std::time::Duration;
+
+use bathbot_psql::model::configs::GuildConfig;
+use twilight_model::{
+    channel::Message,
+    id::{
+        Id,
+        marker::{ChannelMarker, MessageMarker},
+    },
+};
+
+use super::PrefixCommandGroup;
+
+/// Settings of a guild to delete responses of prefix commands after a while.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AutoDelete {
+    pub secs: u16,
+    pub groups: AutoDeleteGroups,
+    /// Groups whose responses are deleted even if they contain an attachment
+    pub attachments: AutoDeleteGroups,
+}
+
+impl AutoDelete {
+    pub const MIN_SECS: u16 = 30;
+    pub const MAX_SECS: u16 = 3600;
+
+    /// Returns `None` if responses are not deleted in the guild.
+    pub fn new(config: &GuildConfig) -> Option<Self> {
+        let secs = config
+            .auto_delete_secs
+            .filter(|secs| (Self::MIN_SECS..=Self::MAX_SECS).contains(secs))?;
+
+        Some(Self {
+            secs,
+            groups: config
+                .auto_delete_groups
+                .map_or(AutoDeleteGroups::ALL, AutoDeleteGroups::from_bits),
+            attachments: config
+                .auto_delete_attachments
+                .map_or(AutoDeleteGroups::NONE, AutoDeleteGroups::from_bits),
+        })
+    }
+
+    /// How long to wait before deleting the response of a command.
+    ///
+    /// Returns `None` if the response should be kept. Responses with
+    /// components manage their own lifecycle and are always kept.
+    pub fn delay(&self, group: PrefixCommandGroup, response: &NotedResponse) -> Option<Duration> {
+        if response.components || !self.groups.contains(group) {
+            return None;
+        }
+
+        if response.attachment && !self.attachments.contains(group) {
+            return None;
+        }
+
+        Some(Duration::from_secs(u64::from(self.secs)))
+    }
+}
+
+/// Set of prefix command groups whose responses can be deleted.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AutoDeleteGroups(u16);
+
+impl AutoDeleteGroups {
+    pub const NONE: Self = Self(0);
+    pub const ALL: Self = Self((1 << Self::ELIGIBLE.len()) - 1);
+
+    /// Groups whose responses may be deleted. Tracking and twitch commands
+    /// are left out since their responses confirm configuration changes.
+    ///
+    /// The order determines the stored bits so it must not change.
+    pub const ELIGIBLE: [PrefixCommandGroup; 8] = [
+        PrefixCommandGroup::AllModes,
+        PrefixCommandGroup::Osu,
+        PrefixCommandGroup::Taiko,
+        PrefixCommandGroup::Catch,
+        PrefixCommandGroup::Mania,
+        PrefixCommandGroup::Games,
+        PrefixCommandGroup::Utility,
+        PrefixCommandGroup::Songs,
+    ];
+
+    /// Unknown bits are ignored.
+    pub fn from_bits(bits: u16) -> Self {
+        Self(bits & Self::ALL.0)
+    }
+
+    pub fn bits(self) -> u16 {
+        self.0
+    }
+
+    pub fn contains(self, group: PrefixCommandGroup) -> bool {
+        Self::bit(group).is_some_and(|bit| self.0 & bit != 0)
+    }
+
+    /// Add or remove the group.
+    ///
+    /// Returns `false` if the group is not eligible.
+    pub fn set(&mut self, group: PrefixCommandGroup, enabled: bool) -> bool {
+        let Some(bit) = Self::bit(group) else {
+            return false;
+        };
+
+        if enabled {
+            self.0 |= bit;
+        } else {
+            self.0 &= !bit;
+        }
+
+        true
+    }
+
+    fn bit(group: PrefixCommandGroup) -> Option<u16> {
+        Self::ELIGIBLE
+            .iter()
+            .position(|&eligible| eligible == group)
+            .map(|idx| 1 << idx)
+    }
+}
+
+/// Response of a prefix command that may be deleted after a while.
+#[derive(Copy, Clone, Debug)]
+pub struct NotedResponse {
+    pub message: Id<MessageMarker>,
+    pub channel: Id<ChannelMarker>,
+    pub components: bool,
+    pub attachment: bool,
+}
+
+impl From<&Message> for NotedResponse {
+    fn from(msg: &Message) -> Self {
+        Self {
+            message: msg.id,
+            channel: msg.channel_id,
+            components: !msg.components.is_empty(),
+            attachment: !msg.attachments.is_empty(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(components: bool, attachment: bool) -> NotedResponse {
+        NotedResponse {
+            message: Id::new(1),
+            channel: Id::new(2),
+            components,
+            attachment,
+        }
+    }
+
+    fn config(secs: u16, groups: Option<u16>, attachments: Option<u16>) -> GuildConfig {
+        GuildConfig {
+            auto_delete_secs: Some(secs),
+            auto_delete_groups: groups,
+            auto_delete_attachments: attachments,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn decision_matrix() {
+        let group = PrefixCommandGroup::Osu;
+        let enabled = AutoDeleteGroups::ALL;
+        let mut disabled = AutoDeleteGroups::ALL;
+        disabled.set(group, false);
+
+        let delay = Some(Duration::from_secs(60));
+
+        // (groups, attachment groups, components, attachment, expected)
+        let cases = [
+            (enabled, AutoDeleteGroups::NONE, false, false, delay),
+            (enabled, AutoDeleteGroups::NONE, false, true, None),
+            (enabled, AutoDeleteGroups::NONE, true, false, None),
+            (enabled, AutoDeleteGroups::NONE, true, true, None),
+            (enabled, enabled, false, false, delay),
+            (enabled, enabled, false, true, delay),
+            (enabled, enabled, true, false, None),
+            (enabled, enabled, true, true, None),
+            (disabled, AutoDeleteGroups::NONE, false, false, None),
+            (disabled, AutoDeleteGroups::NONE, false, true, None),
+            (disabled, enabled, false, false, None),
+            (disabled, enabled, false, true, None),
+            (disabled, enabled, true, true, None),
+        ];
+
+        for (groups, attachments, components, attachment, expected) in cases {
+            let auto_delete = AutoDelete {
+                secs: 60,
+                groups,
+                attachments,
+            };
+
+            assert_eq!(
+                auto_delete.delay(group, &response(components, attachment)),
+                expected,
+                "groups={groups:?} attachments={attachments:?} \
+                components={components} attachment={attachment}"
+            );
+        }
+    }
+
+    #[test]
+    fn ineligible_groups_are_kept() {
+        let auto_delete = AutoDelete {
+            secs: 60,
+            groups: AutoDeleteGroups::from_bits(u16::MAX),
+            attachments: AutoDeleteGroups::from_bits(u16::MAX),
+        };
+
+        for group in [PrefixCommandGroup::Tracking, PrefixCommandGroup::Twitch] {
+            let mut groups = AutoDeleteGroups::NONE;
+
+            assert_eq!(auto_delete.delay(group, &response(false, false)), None);
+            assert!(!AutoDeleteGroups::ALL.contains(group));
+            assert!(!groups.set(group, true));
+            assert_eq!(groups, AutoDeleteGroups::NONE);
+        }
+    }
+
+    #[test]
+    fn from_config() {
+        assert_eq!(AutoDelete::new(&GuildConfig::default()), None);
+        assert_eq!(AutoDelete::new(&config(29, None, None)), None);
+        assert_eq!(AutoDelete::new(&config(3601, None, None)), None);
+
+        let auto_delete = AutoDelete::new(&config(30, None, None)).unwrap();
+        assert_eq!(auto_delete.groups, AutoDeleteGroups::ALL);
+        assert_eq!(auto_delete.attachments, AutoDeleteGroups::NONE);
+
+        let auto_delete = AutoDelete::new(&config(3600, Some(0b10), Some(u16::MAX))).unwrap();
+        assert!(auto_delete.groups.contains(PrefixCommandGroup::Osu));
+        assert!(!auto_delete.groups.contains(PrefixCommandGroup::AllModes));
+        assert_eq!(auto_delete.attachments, AutoDeleteGroups::ALL);
+    }
+
+    #[test]
+    fn stable_bits() {
+        let mut groups = AutoDeleteGroups::NONE;
+
+        assert!(groups.set(PrefixCommandGroup::AllModes, true));
+        assert!(groups.set(PrefixCommandGroup::Songs, true));
+        assert_eq!(groups.bits(), 0b1000_0001);
+
+        assert!(groups.set(PrefixCommandGroup::AllModes, false));
+        assert_eq!(groups.bits(), 0b1000_0000);
+        assert_eq!(AutoDeleteGroups::ALL.bits(), 0b1111_1111);
+    }
+}
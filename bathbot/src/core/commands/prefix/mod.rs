@@ -1,4 +1,5 @@
 use std::{
+    cell::Cell,
     fmt::{Display, Formatter, Result as FmtResult},
     pin::Pin,
 };
@@ -8,14 +9,18 @@ use futures::Future;
 use linkme::distributed_slice;
 use once_cell::sync::OnceCell;
 use radix_trie::{Trie, TrieCommon};
+use twilight_model::channel::Message;
 
 pub use self::{
-    args::{Args, ArgsNum},
+    args::{Arg, Args, ArgsNum, strip_quotes},
+    auto_delete::{AutoDelete, AutoDeleteGroups, NotedResponse},
     command::PrefixCommand,
 };
+use super::outcome::track_error_response;
 use crate::util::Emote;
 
 mod args;
+mod auto_delete;
 mod command;
 
 #[distributed_slice]
@@ -23,6 +28,42 @@ pub static __PREFIX_COMMANDS: [PrefixCommand] = [..];
 
 static PREFIX_COMMANDS: OnceCell<PrefixCommands> = OnceCell::new();
 
+tokio::task_local! {
+    /// Response of the currently processed prefix command that may be deleted
+    /// after a while.
+    static RESPONSE: Cell<Option<NotedResponse>>;
+}
+
+/// Note the response of the currently processed prefix command, if any, so
+/// that it can be deleted after a while.
+///
+/// Only note responses that the command won't touch afterwards.
+pub fn note_response(response: &Message) {
+    let _ = RESPONSE.try_with(|noted| noted.set(Some(response.into())));
+}
+
+/// How a prefix command responded.
+pub struct TrackedResponse {
+    pub error: bool,
+    pub response: Option<NotedResponse>,
+}
+
+/// Await a prefix command and return its output alongside how it responded.
+pub async fn track_response<F: Future>(fut: F) -> (F::Output, TrackedResponse) {
+    let fut = async {
+        let (output, error) = track_error_response(fut).await;
+
+        let tracked = TrackedResponse {
+            error,
+            response: RESPONSE.with(Cell::get),
+        };
+
+        (output, tracked)
+    };
+
+    RESPONSE.scope(Cell::new(None), fut).await
+}
+
 pub type CommandResult<'fut> = Pin<Box<dyn Future<Output = Result<()>> + 'fut + Send>>;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -1,14 +1,18 @@
 use std::{env, fmt::Debug, mem::MaybeUninit, path::PathBuf, str::FromStr};
 
+use bathbot_client::RetryOverride;
 use eyre::Result;
 use once_cell::sync::OnceCell;
 use rosu_v2::model::Grade;
 use twilight_model::id::{
     Id,
-    marker::{ChannelMarker, GuildMarker, UserMarker},
+    marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
 };
 
-use crate::util::{CustomEmote, Emote};
+use crate::{
+    tracking::WarmResource,
+    util::{CustomEmote, Emote},
+};
 
 static CONFIG: OnceCell<BotConfig> = OnceCell::new();
 
@@ -28,6 +32,30 @@ pub struct BotConfig {
     pub owner: Id<UserMarker>,
     pub dev_guild: Id<GuildMarker>,
     pub hl_channel: Id<ChannelMarker>,
+    /// Role in the dev guild whose members may edit mapset tags of the
+    /// background game.
+    pub bg_tag_editor_role: Option<Id<RoleMarker>>,
+    /// Daily amount of osu!api requests for refreshing user stats in the
+    /// background.
+    #[cfg(feature = "statsrefresh")]
+    pub stats_refresh_budget: u32,
+    /// Whether to start without the database if it cannot be reached instead
+    /// of aborting.
+    pub degraded_mode: bool,
+    /// Which redis entries to fill on startup, if any.
+    pub cache_prewarm: Option<CachePrewarm>,
+    /// Deviations from the custom client's default retry policies.
+    pub client_retry_overrides: Box<[RetryOverride]>,
+    /// Whether to send the owner monthly reports of inactive data that may
+    /// be pruned.
+    pub prune_reports: bool,
+}
+
+#[derive(Debug)]
+pub struct CachePrewarm {
+    /// Only log which entries would be warmed.
+    pub dry_run: bool,
+    pub resources: Box<[WarmResource]>,
 }
 
 #[derive(Debug)]
@@ -91,6 +119,16 @@ impl BotConfig {
         ];
         let emotes = Self::parse_emotes::<Emote, _, 17>(emote_strs)?;
 
+        let cache_prewarm = if env_var_opt("CACHE_PREWARM")?.unwrap_or(false) {
+            Some(CachePrewarm {
+                dry_run: env_var_opt("CACHE_PREWARM_DRY_RUN")?.unwrap_or(false),
+                resources: env_var_opt("CACHE_PREWARM_RESOURCES")?
+                    .unwrap_or_else(|| Box::from(WarmResource::ALL)),
+            })
+        } else {
+            None
+        };
+
         let config = BotConfig {
             database_url: env_var("DATABASE_URL")?,
             tokens: Tokens {
@@ -125,6 +163,13 @@ impl BotConfig {
             owner: env_var("OWNER_USER_ID")?,
             dev_guild: env_var("DEV_GUILD_ID")?,
             hl_channel: env_var("HL_IMAGE_CHANNEL")?,
+            bg_tag_editor_role: env_var_opt("BG_TAG_EDITOR_ROLE_ID")?,
+            #[cfg(feature = "statsrefresh")]
+            stats_refresh_budget: env_var_opt("STATS_REFRESH_BUDGET")?.unwrap_or(2000),
+            degraded_mode: env_var_opt("DEGRADED_MODE")?.unwrap_or(false),
+            cache_prewarm,
+            client_retry_overrides: env_var_opt("CLIENT_RETRY_OVERRIDES")?.unwrap_or_default(),
+            prune_reports: env_var_opt("PRUNE_REPORTS")?.unwrap_or(false),
         };
 
         if CONFIG.set(config).is_err() {
@@ -189,13 +234,40 @@ macro_rules! env_kind {
 
 env_kind! {
     Box<str>: |s| { Ok(s.into_boxed_str()) },
+    bool: |s| { s.parse().map_err(|_| s) },
     u8: |s| { s.parse().map_err(|_| s) },
     u16: |s| { s.parse().map_err(|_| s) },
+    u32: |s| { s.parse().map_err(|_| s) },
     u64: |s| { s.parse().map_err(|_| s) },
     PathBuf: |s| { s.parse().map_err(|_| s) },
     Id<UserMarker>: |s| { s.parse().map(Id::new).map_err(|_| s) },
     Id<GuildMarker>: |s| { s.parse().map(Id::new).map_err(|_| s) },
     Id<ChannelMarker>: |s| { s.parse().map(Id::new).map_err(|_| s) },
+    Id<RoleMarker>: |s| { s.parse().map(Id::new).map_err(|_| s) },
+}
+
+impl EnvKind for Box<[WarmResource]> {
+    const EXPECTED: &'static str = "a comma-separated list of `pp_rankings`, `medals`, \
+        `badges`, or `snipe_countries`";
+
+    fn from_str(s: String) -> Result<Self, String> {
+        s.split(',')
+            .map(|resource| resource.trim().parse())
+            .collect::<Result<_, _>>()
+            .map_err(|_| s)
+    }
+}
+
+impl EnvKind for Box<[RetryOverride]> {
+    const EXPECTED: &'static str = "a comma-separated list of `{site}={max retries}` or \
+        `{site}={max retries}:{backoff base in ms}`";
+
+    fn from_str(s: String) -> Result<Self, String> {
+        s.split(',')
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|_| s)
+    }
 }
 
 impl EnvKind for CustomEmote {
@@ -224,6 +296,18 @@ fn env_var<T: EnvKind>(name: &str) -> Result<T> {
     })
 }
 
+fn env_var_opt<T: EnvKind>(name: &str) -> Result<Option<T>> {
+    match env::var(name) {
+        Ok(value) => T::from_str(value).map(Some).map_err(|value| {
+            eyre!(
+                "failed to parse env variable `{name}={value}`; expected {expected}",
+                expected = T::EXPECTED
+            )
+        }),
+        Err(_) => Ok(None),
+    }
+}
+
 trait AsUsize {
     fn to_usize(self) -> usize;
 }
@@ -0,0 +1,391 @@
+use std::{
+    collections::HashMap,
+    hash::{Hash, Hasher},
+};
+
+use eyre::{Result, WrapErr};
+use rosu_mods::serde::GameModsSeed;
+use rosu_pp::any::PerformanceAttributes;
+use rosu_v2::prelude::{GameMode, GameMods};
+use serde::de::DeserializeSeed;
+
+use super::Context;
+use crate::manager::{OsuMap, performance_mods};
+
+/// Values of a converted map that are shown in top lists.
+///
+/// They're stored so that converts don't have to be recalculated for their
+/// mode on every top list.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ConvertValues {
+    pub stars: f32,
+    pub max_combo: u32,
+    pub max_pp: f32,
+}
+
+impl ConvertValues {
+    pub fn new(attrs: &PerformanceAttributes) -> Self {
+        Self {
+            stars: attrs.stars() as f32,
+            max_combo: attrs.max_combo(),
+            max_pp: attrs.pp() as f32,
+        }
+    }
+
+    fn to_entry(self) -> String {
+        format!("{}:{}:{}", self.stars, self.max_combo, self.max_pp)
+    }
+
+    fn from_entry(entry: &str) -> Option<Self> {
+        let mut split = entry.split(':');
+
+        let values = Self {
+            stars: split.next()?.parse().ok()?,
+            max_combo: split.next()?.parse().ok()?,
+            max_pp: split.next()?.parse().ok()?,
+        };
+
+        split.next().is_none().then_some(values)
+    }
+}
+
+/// Identifies the stored values of a converted map.
+#[derive(Clone, Debug)]
+pub struct ConvertKey {
+    map_id: u32,
+    /// Determined by the map id so neither compared nor hashed
+    mapset_id: u32,
+    mode: GameMode,
+    /// Performance-relevant mods, including their settings
+    mods: GameMods,
+    lazer: bool,
+}
+
+impl ConvertKey {
+    /// Returns `None` if the map is not converted.
+    pub fn new(map: &OsuMap, mode: GameMode, mods: &GameMods, lazer: bool) -> Option<Self> {
+        Self::new_(
+            map.map_id(),
+            map.mapset_id(),
+            map.pp_map.is_convert,
+            mode,
+            mods,
+            lazer,
+        )
+    }
+
+    fn new_(
+        map_id: u32,
+        mapset_id: u32,
+        is_convert: bool,
+        mode: GameMode,
+        mods: &GameMods,
+        lazer: bool,
+    ) -> Option<Self> {
+        is_convert.then(|| Self {
+            map_id,
+            mapset_id,
+            mode,
+            mods: performance_mods(mods),
+            lazer,
+        })
+    }
+
+    pub fn map_id(&self) -> u32 {
+        self.map_id
+    }
+
+    pub fn mode(&self) -> GameMode {
+        self.mode
+    }
+
+    pub fn mods(&self) -> &GameMods {
+        &self.mods
+    }
+
+    pub fn lazer(&self) -> bool {
+        self.lazer
+    }
+
+    /// Serialize the key into an entry of the stored values or of a persisted
+    /// recalculation job.
+    pub fn to_entry(&self) -> String {
+        let mut entry = format!(
+            "{}:{}:{}:{}:",
+            self.map_id, self.mapset_id, self.mode as u8, self.lazer as u8
+        );
+
+        if let Ok(mods) = serde_json::to_string(&self.mods) {
+            entry.push_str(&mods);
+        }
+
+        entry
+    }
+
+    /// Deserialize an entry of the stored values or of a persisted
+    /// recalculation job.
+    pub fn from_entry(entry: &str) -> Option<Self> {
+        // The mods' JSON may contain colons itself
+        let mut split = entry.splitn(5, ':');
+
+        let map_id = split.next()?.parse().ok()?;
+        let mapset_id = split.next()?.parse().ok()?;
+        let mode = GameMode::from(split.next()?.parse::<u8>().ok()?);
+
+        let lazer = match split.next()? {
+            "0" => false,
+            "1" => true,
+            _ => return None,
+        };
+
+        let seed = GameModsSeed::Mode {
+            mode,
+            deny_unknown_fields: false,
+        };
+
+        let mut deserializer = serde_json::Deserializer::from_str(split.next()?);
+        let mods = seed.deserialize(&mut deserializer).ok()?;
+
+        Some(Self {
+            map_id,
+            mapset_id,
+            mode,
+            mods,
+            lazer,
+        })
+    }
+}
+
+/// Which stored values of converts to invalidate.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RecalcScope {
+    All,
+    Mode(GameMode),
+    Mapset(u32),
+}
+
+impl RecalcScope {
+    pub fn matches(self, key: &ConvertKey) -> bool {
+        match self {
+            Self::All => true,
+            Self::Mode(mode) => key.mode == mode,
+            Self::Mapset(mapset_id) => key.mapset_id == mapset_id,
+        }
+    }
+}
+
+impl PartialEq for ConvertKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.map_id == other.map_id
+            && self.mode == other.mode
+            && self.lazer == other.lazer
+            && self.mods == other.mods
+    }
+}
+
+impl Eq for ConvertKey {}
+
+impl Hash for ConvertKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.map_id.hash(state);
+        (self.mode as u8).hash(state);
+        self.lazer.hash(state);
+
+        // Settings are only compared, not hashed
+        for gamemod in self.mods.iter() {
+            gamemod.acronym().as_str().hash(state);
+        }
+    }
+}
+
+impl Context {
+    /// Stored values for the given keys.
+    ///
+    /// Values that could not be retrieved are treated as missing.
+    pub async fn convert_values(keys: &[ConvertKey]) -> HashMap<ConvertKey, ConvertValues> {
+        let entries: Vec<_> = keys.iter().map(ConvertKey::to_entry).collect();
+
+        let values = match Self::cache().convert_values(&entries).await {
+            Ok(values) => values,
+            Err(err) => {
+                warn!(?err, "Failed to get convert values");
+
+                return HashMap::new();
+            }
+        };
+
+        keys.iter()
+            .zip(values)
+            .filter_map(|(key, value)| {
+                let value = ConvertValues::from_entry(value.as_deref()?)?;
+
+                Some((key.to_owned(), value))
+            })
+            .collect()
+    }
+
+    pub async fn store_convert_values(values: &[(ConvertKey, ConvertValues)]) -> Result<()> {
+        let entries: Vec<_> = values
+            .iter()
+            .map(|(key, value)| (key.to_entry(), value.to_entry()))
+            .collect();
+
+        Self::cache().store_convert_values(&entries).await
+    }
+
+    /// Remove all stored values within the scope.
+    ///
+    /// Returns the amount of removed entries and the keys of the `most_used`
+    /// most frequently used ones among them.
+    pub async fn invalidate_convert_values(
+        scope: RecalcScope,
+        most_used: usize,
+    ) -> Result<(usize, Vec<ConvertKey>)> {
+        let hits = Self::cache()
+            .convert_hits()
+            .await
+            .wrap_err("Failed to get stored convert entries")?;
+
+        let mut entries = Vec::new();
+        let mut matching = Vec::new();
+
+        for (entry, hits) in hits {
+            match ConvertKey::from_entry(&entry) {
+                Some(key) if scope.matches(&key) => matching.push((key, hits)),
+                Some(_) => continue,
+                // Unreadable entries are stale regardless of the scope
+                None => {}
+            }
+
+            entries.push(entry);
+        }
+
+        Self::cache()
+            .remove_convert_values(&entries)
+            .await
+            .wrap_err("Failed to remove convert values")?;
+
+        Ok((entries.len(), most_used_keys(matching, most_used)))
+    }
+}
+
+fn most_used_keys(mut entries: Vec<(ConvertKey, u32)>, amount: usize) -> Vec<ConvertKey> {
+    entries.sort_unstable_by(|(_, a), (_, b)| b.cmp(a));
+
+    entries
+        .into_iter()
+        .take(amount)
+        .map(|(key, _)| key)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::hash_map::RandomState, hash::BuildHasher};
+
+    use rosu_v2::{model::mods::generated_mods::DoubleTimeTaiko, prelude::GameMod};
+
+    use super::*;
+
+    fn key(is_convert: bool, mode: GameMode, acronyms: &[&str]) -> Option<ConvertKey> {
+        let mods: GameMods = acronyms
+            .iter()
+            .map(|acronym| GameMod::new(acronym, mode))
+            .collect();
+
+        ConvertKey::new_(123, 456, is_convert, mode, &mods, true)
+    }
+
+    #[test]
+    fn only_converts() {
+        assert!(key(false, GameMode::Osu, &["HD", "DT"]).is_none());
+        assert!(key(false, GameMode::Mania, &["DT"]).is_none());
+        assert!(key(true, GameMode::Mania, &["DT"]).is_some());
+    }
+
+    #[test]
+    fn equivalent_mods_share_key() {
+        let hasher = RandomState::new();
+
+        let dt = key(true, GameMode::Taiko, &["DT"]).unwrap();
+        let nc = key(true, GameMode::Taiko, &["NC"]).unwrap();
+        assert!(dt == nc);
+        assert_eq!(hasher.hash_one(&dt), hasher.hash_one(&nc));
+
+        let hddt = key(true, GameMode::Taiko, &["HD", "DT"]).unwrap();
+        assert!(dt != hddt);
+
+        let catch = key(true, GameMode::Catch, &["DT"]).unwrap();
+        assert!(dt != catch);
+
+        let hr = key(true, GameMode::Taiko, &["HR"]).unwrap();
+        assert!(dt != hr);
+    }
+
+    #[test]
+    fn scope_matching() {
+        let key = key(true, GameMode::Catch, &["HR"]).unwrap();
+
+        assert!(RecalcScope::All.matches(&key));
+        assert!(RecalcScope::Mode(GameMode::Catch).matches(&key));
+        assert!(!RecalcScope::Mode(GameMode::Taiko).matches(&key));
+        assert!(RecalcScope::Mapset(456).matches(&key));
+        assert!(!RecalcScope::Mapset(123).matches(&key));
+    }
+
+    #[test]
+    fn most_used_first() {
+        let a = key(true, GameMode::Taiko, &[]).unwrap();
+        let b = key(true, GameMode::Catch, &[]).unwrap();
+        let c = key(true, GameMode::Mania, &[]).unwrap();
+
+        let keys = most_used_keys(vec![(a, 2), (b.clone(), 7), (c.clone(), 5)], 2);
+        assert_eq!(keys, [b, c]);
+    }
+
+    #[test]
+    fn entry_roundtrip() {
+        let dt = key(true, GameMode::Mania, &["HD", "DT"]).unwrap();
+        assert_eq!(ConvertKey::from_entry(&dt.to_entry()), Some(dt));
+
+        let nomod = key(true, GameMode::Taiko, &[]).unwrap();
+        assert_eq!(nomod.to_entry(), "123:456:1:1:[]");
+        assert_eq!(ConvertKey::from_entry(&nomod.to_entry()), Some(nomod));
+
+        assert!(ConvertKey::from_entry("123:456:3").is_none());
+        assert!(ConvertKey::from_entry("123:456:3:2:[]").is_none());
+        assert!(ConvertKey::from_entry("123:456:3:1:DT").is_none());
+    }
+
+    #[test]
+    fn entry_keeps_mod_settings() {
+        let mode = GameMode::Taiko;
+
+        let custom_dt: GameMods = [GameMod::DoubleTimeTaiko(DoubleTimeTaiko {
+            speed_change: Some(1.3),
+            ..Default::default()
+        })]
+        .into_iter()
+        .collect();
+
+        let custom = ConvertKey::new_(123, 456, true, mode, &custom_dt, true).unwrap();
+        let entry = custom.to_entry();
+        assert_eq!(ConvertKey::from_entry(&entry), Some(custom.clone()));
+
+        let default = key(true, mode, &["DT"]).unwrap();
+        assert!(ConvertKey::from_entry(&entry) != Some(default));
+    }
+
+    #[test]
+    fn values_roundtrip() {
+        let values = ConvertValues {
+            stars: 5.25,
+            max_combo: 1234,
+            max_pp: 456.75,
+        };
+
+        assert_eq!(ConvertValues::from_entry(&values.to_entry()), Some(values));
+        assert!(ConvertValues::from_entry("5.25:1234").is_none());
+        assert!(ConvertValues::from_entry("5.25:1234:456.75:1").is_none());
+    }
+}
@@ -1,17 +1,19 @@
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{collections::HashMap, future, sync::Arc, time::Duration};
 
 use bathbot_util::IntHasher;
 use eyre::{Report, Result, WrapErr};
 use tokio::{
     sync::mpsc::UnboundedReceiver,
-    time::{self, MissedTickBehavior},
+    time::{self, Instant, MissedTickBehavior},
 };
 use twilight_gateway::{ConfigBuilder, Intents, Session, Shard, ShardId};
 use twilight_http::Client;
 use twilight_model::{
     channel::message::AllowedMentions,
     gateway::{
-        payload::outgoing::{RequestGuildMembers, update_presence::UpdatePresencePayload},
+        payload::outgoing::{
+            RequestGuildMembers, UpdatePresence, update_presence::UpdatePresencePayload,
+        },
         presence::{ActivityType, MinimalActivity, Status},
     },
     id::{
@@ -20,7 +22,8 @@ use twilight_model::{
     },
 };
 
-use crate::core::{BotConfig, Context};
+use super::member_requests::{MemberRequestRetries, RetryDecision};
+use crate::core::{BotConfig, BotMetrics, Context};
 
 pub(super) async fn http(config: &BotConfig) -> Result<(Arc<Client>, Id<ApplicationMarker>)> {
     let mentions = AllowedMentions {
@@ -66,14 +69,9 @@ pub(super) async fn gateway(
         | Intents::DIRECT_MESSAGES
         | Intents::MESSAGE_CONTENT;
 
-    let activity = MinimalActivity {
-        kind: ActivityType::Playing,
-        name: "osu!".to_owned(),
-        url: None,
-    };
-
     let presence =
-        UpdatePresencePayload::new([activity.into()], false, None, Status::Online).unwrap();
+        UpdatePresencePayload::new([activity(false, false).into()], false, None, Status::Online)
+            .unwrap();
 
     let config = ConfigBuilder::new(config.tokens.discord.to_string(), intents)
         .presence(presence)
@@ -91,7 +89,40 @@ pub(super) async fn gateway(
         .wrap_err("Failed to create recommended shards")
 }
 
+/// The bot's activity, indicating whether maintenance mode is active or the
+/// osu!api is having issues.
+fn activity(maintenance: bool, api_degraded: bool) -> MinimalActivity {
+    let name = if maintenance {
+        "🔧 Maintenance"
+    } else if api_degraded {
+        "⚠️ osu!api issues"
+    } else {
+        "osu!"
+    };
+
+    MinimalActivity {
+        kind: ActivityType::Playing,
+        name: name.to_owned(),
+        url: None,
+    }
+}
+
 impl Context {
+    /// Update the presence of all shards based on the maintenance mode and
+    /// the osu!api's state.
+    pub fn update_presence() {
+        let maintenance = Context::maintenance().is_active();
+        let api_degraded = Context::api_incident().is_degraded();
+        let activity = activity(maintenance, api_degraded);
+        let presence = UpdatePresence::new([activity.into()], false, None, Status::Online).unwrap();
+
+        for (shard_id, sender) in Context::get().shard_senders.read().unwrap().iter() {
+            if let Err(err) = sender.command(&presence) {
+                warn!(shard_id, ?err, "Failed to update presence");
+            }
+        }
+    }
+
     pub async fn request_guild_members(mut member_rx: UnboundedReceiver<(Id<GuildMarker>, u32)>) {
         let ctx = Context::get();
 
@@ -99,24 +130,49 @@ impl Context {
         interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
         interval.tick().await;
         let mut counter = 1;
+        let mut retries = MemberRequestRetries::default();
         info!("Processing member request queue...");
 
-        while let Some((guild_id, shard_id)) = member_rx.recv().await {
-            let removed_opt = ctx
-                .member_requests
-                .pending_guilds
-                .lock()
-                .unwrap()
-                .remove(&guild_id);
-
-            // If a guild is in the channel twice, only process the first and ignore the
-            // second
-            if !removed_opt {
-                continue;
-            }
+        loop {
+            let deadline = retries.next_deadline();
+
+            let retry_due = async {
+                match deadline {
+                    Some(deadline) => time::sleep_until(deadline).await,
+                    None => future::pending().await,
+                }
+            };
+
+            let (guild_id, shard_id) = tokio::select! {
+                req = member_rx.recv() => {
+                    let Some((guild_id, shard_id)) = req else { break };
+
+                    let is_pending = ctx
+                        .member_requests
+                        .pending_guilds
+                        .lock()
+                        .unwrap()
+                        .contains(&guild_id);
+
+                    // If a guild is in the channel twice or is already waiting to be
+                    // retried, only process it once
+                    if !is_pending || retries.is_scheduled(guild_id) {
+                        continue;
+                    }
+
+                    (guild_id, shard_id)
+                }
+                _ = retry_due => match retries.pop_due(Instant::now()) {
+                    Some(entry) => entry,
+                    None => continue,
+                },
+            };
 
             interval.tick().await;
 
+            let span = info_span!("member_request", guild_id = guild_id.get(), shard_id);
+            let _entered = span.enter();
+
             let req = RequestGuildMembers::builder(guild_id).query("", None);
             trace!("Member request #{counter} for guild {guild_id}");
             counter += 1;
@@ -125,17 +181,37 @@ impl Context {
                 Some(sender) => sender.command(&req),
                 None => {
                     warn!("Missing sender for shard {shard_id}");
+                    retries.clear(guild_id);
+                    ctx.member_requests.remove_pending(guild_id);
 
                     continue;
                 }
             };
 
-            if let Err(err) = command_res {
-                let wrap = format!("Failed to request members for guild {guild_id}");
-                warn!("{:?}", Report::new(err).wrap_err(wrap));
+            let Err(err) = command_res else {
+                retries.clear(guild_id);
+                ctx.member_requests.remove_pending(guild_id);
+
+                continue;
+            };
+
+            let err = Report::new(err);
+
+            match retries.on_failure(guild_id, shard_id, Instant::now()) {
+                (RetryDecision::Retry(delay), attempts) => {
+                    warn!(
+                        ?err,
+                        ?delay,
+                        attempts,
+                        "Failed to request members, retrying"
+                    );
+                }
+                (RetryDecision::GiveUp, attempts) => {
+                    warn!(?err, attempts, "Failed to request members, giving up");
+                    BotMetrics::inc_member_request_give_up();
 
-                if let Err(err) = ctx.member_requests.tx.send((guild_id, shard_id)) {
-                    warn!("Failed to re-forward member request: {err}");
+                    // Allows a later guild event to start over
+                    ctx.member_requests.remove_pending(guild_id);
                 }
             }
         }
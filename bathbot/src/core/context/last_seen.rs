@@ -0,0 +1,74 @@
+use bathbot_util::IntHasher;
+use papaya::HashMap as PapayaMap;
+use time::{Duration, OffsetDateTime};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use super::Context;
+
+/// Mapping user ids to the last timestamp that their `last_seen_at` was
+/// written to the database.
+pub type UserLastSeenTimestamps = PapayaMap<Id<UserMarker>, OffsetDateTime, IntHasher>;
+
+/// The stored `last_seen_at` is only updated once per day.
+const UPDATE_INTERVAL: Duration = Duration::DAY;
+
+/// Amount of timestamps at which outdated ones are removed.
+const CAPACITY: usize = 100_000;
+
+impl Context {
+    /// Update the user's `last_seen_at` without waiting for the database.
+    ///
+    /// Most calls only check the in-memory timestamp so that commands don't
+    /// cause a write each.
+    pub fn mark_user_seen(user_id: Id<UserMarker>) {
+        if !Self::database_health().is_available() {
+            return;
+        }
+
+        let now = OffsetDateTime::now_utc();
+
+        {
+            let timestamps = Self::get().data.user_last_seen_timestamps.pin();
+
+            if !needs_update(timestamps.get(&user_id).copied(), now) {
+                return;
+            }
+
+            if timestamps.len() >= CAPACITY {
+                timestamps.retain(|_, last| !needs_update(Some(*last), now));
+
+                // The database only updates once per day regardless so
+                // forgetting timestamps merely causes redundant queries
+                if timestamps.len() >= CAPACITY {
+                    timestamps.clear();
+                }
+            }
+
+            timestamps.insert(user_id, now);
+        }
+
+        tokio::spawn(async move {
+            if let Err(err) = Self::psql().update_user_last_seen(user_id, now).await {
+                warn!(%user_id, ?err, "Failed to update last seen timestamp");
+            }
+        });
+    }
+}
+
+fn needs_update(last: Option<OffsetDateTime>, now: OffsetDateTime) -> bool {
+    last.is_none_or(|last| last < now - UPDATE_INTERVAL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_at_most_once_per_day() {
+        let now = OffsetDateTime::now_utc();
+
+        assert!(needs_update(None, now));
+        assert!(!needs_update(Some(now - Duration::hours(23)), now));
+        assert!(needs_update(Some(now - Duration::hours(25)), now));
+    }
+}
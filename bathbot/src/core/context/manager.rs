@@ -4,19 +4,23 @@ use rosu_v2::prelude::GameMode;
 use super::Context;
 use crate::manager::{
     ApproxManager, BookmarkManager, GameManager, GithubManager, GuildConfigManager,
-    HuismetbenenCountryManager, MapManager, OsuMap, OsuUserManager, PpManager, ReplayManager,
-    ScoresManager, UserConfigManager, redis::RedisManager,
+    HuismetbenenCountryManager, MapManager, MapsetWatchManager, OsuMap, OsuUserManager, PpManager,
+    ReplayManager, ScoresManager, UserConfigManager, redis::RedisManager,
 };
 
 impl Context {
     pub fn guild_config() -> GuildConfigManager {
         let ctx = Self::get();
 
-        GuildConfigManager::new(&ctx.clients.psql, &ctx.data.guild_configs)
+        GuildConfigManager::new(
+            &ctx.clients.psql,
+            &ctx.data.guild_configs,
+            &ctx.data.database_data_loaded,
+        )
     }
 
     pub fn user_config() -> UserConfigManager {
-        UserConfigManager::new()
+        UserConfigManager::new(&Self::get().data.user_links)
     }
 
     pub fn osu_user() -> OsuUserManager {
@@ -32,7 +36,7 @@ impl Context {
     }
 
     pub fn approx() -> ApproxManager {
-        ApproxManager::new()
+        ApproxManager::new(&Self::get().data.pp_curves)
     }
 
     pub fn games() -> GameManager {
@@ -48,6 +52,10 @@ impl Context {
         BookmarkManager::new()
     }
 
+    pub fn mapset_watches() -> MapsetWatchManager {
+        MapsetWatchManager::new()
+    }
+
     pub fn replay() -> ReplayManager {
         let ctx = Self::get();
 
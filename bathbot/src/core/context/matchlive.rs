@@ -6,11 +6,17 @@ use twilight_model::id::{Id, marker::ChannelMarker};
 
 use super::Context;
 use crate::{
+    core::BackgroundLoop,
     embeds::MatchLiveEmbed,
-    matchlive::{Channel, MatchEntry, MatchTrackResult, TrackedMatch, send_match_messages},
+    matchlive::{
+        Channel, MatchEntry, MatchProgress, MatchTrackResult, TrackedMatch, send_match_messages,
+    },
     util::ChannelExt,
 };
 
+/// How often tracked matches are updated.
+pub const TICK: Duration = Duration::from_secs(10);
+
 impl Context {
     /// In case the channel tracks exactly one match, returns the match's id
     pub async fn tracks_single_match(channel: Id<ChannelMarker>) -> Option<u32> {
@@ -30,7 +36,15 @@ impl Context {
             .map(|(key, _)| *key)
     }
 
-    pub async fn add_match_track(channel: Id<ChannelMarker>, match_id: u32) -> MatchTrackResult {
+    /// Start tracking the match in the channel.
+    ///
+    /// If `summary_only` is set, the channel only receives a summary once the
+    /// match is over.
+    pub async fn add_match_track(
+        channel: Id<ChannelMarker>,
+        match_id: u32,
+        summary_only: bool,
+    ) -> MatchTrackResult {
         let mut match_live = Context::get().data.matchlive.inner.lock().await;
 
         // Increment the track counter for the channel
@@ -54,14 +68,18 @@ impl Context {
                     return MatchTrackResult::Duplicate;
                 }
 
-                let embeds = &entry.tracked.embeds;
+                let channel = if summary_only {
+                    Channel::summary_only(channel)
+                } else {
+                    let embeds = &entry.tracked.embeds;
 
-                let channel = match send_match_messages(channel, embeds).await {
-                    Ok(msg) => Channel::new(channel, msg),
-                    Err(err) => {
-                        error!("{err:?}");
+                    match send_match_messages(channel, embeds).await {
+                        Ok(msg) => Channel::new(channel, msg),
+                        Err(err) => {
+                            error!("{err:?}");
 
-                        return MatchTrackResult::Error;
+                            return MatchTrackResult::Error;
+                        }
                     }
                 };
 
@@ -74,20 +92,38 @@ impl Context {
             // The match is not yet tracked -> request and store it
             Entry::Vacant(e) => match Context::osu().osu_match(match_id).await {
                 Ok(osu_match) => {
-                    let embeds = MatchLiveEmbed::new(&osu_match);
+                    let mut progress = MatchProgress::default();
+                    let embeds = MatchLiveEmbed::new(&osu_match, &mut progress);
+                    let disbanded =
+                        matches!(osu_match.events.last(), Some(MatchEvent::Disbanded { .. }));
+
+                    let channel = if summary_only {
+                        Channel::summary_only(channel)
+                    } else {
+                        match send_match_messages(channel, &embeds).await {
+                            Ok(msg) => Channel::new(channel, msg),
+                            Err(err) => {
+                                error!("{err:?}");
+
+                                return MatchTrackResult::Error;
+                            }
+                        }
+                    };
 
-                    let channel = match send_match_messages(channel, &embeds).await {
-                        Ok(msg) => Channel::new(channel, msg),
-                        Err(err) => {
+                    if disbanded {
+                        // The match is already over so send the summary right away
+                        let summary = MatchLiveEmbed::summary(&osu_match, &progress);
+
+                        if let Err(err) =
+                            send_match_messages(channel.id, slice::from_ref(&summary)).await
+                        {
                             error!("{err:?}");
 
                             return MatchTrackResult::Error;
                         }
-                    };
-
-                    // Only add to tracking if it's not already disbanded
-                    if !matches!(osu_match.events.last(), Some(MatchEvent::Disbanded { .. })) {
-                        let tracked_match = TrackedMatch::new(osu_match, embeds);
+                    } else {
+                        // Only add to tracking if it's not already disbanded
+                        let tracked_match = TrackedMatch::new(osu_match, embeds, progress);
                         let id = channel.id;
                         e.insert(MatchEntry::new(tracked_match, channel));
                         *match_live.channel_count.entry(id).or_insert(0) += 1;
@@ -156,8 +192,7 @@ impl Context {
     }
 
     pub async fn match_live_loop() {
-        // Update all matches every 10 seconds
-        let mut interval = interval(Duration::from_secs(10));
+        let mut interval = interval(TICK);
         interval.tick().await;
 
         // Match ids of matches that finished this iteration
@@ -168,6 +203,7 @@ impl Context {
 
         loop {
             interval.tick().await;
+            Context::watchdog().beat(BackgroundLoop::MatchLive);
 
             {
                 // Tight scope makes sure this lock is dropped ASAP
@@ -192,9 +228,11 @@ impl Context {
                         .embeds
                         .last_mut()
                         .expect("no last live embed")
-                        .update(&next_match);
+                        .update(&next_match, &mut tracked_match.progress);
+
+                    let ended = next_match.end_time.is_some();
 
-                    if next_match.end_time.is_some() {
+                    if ended {
                         remove.push(next_match.match_id);
                     }
 
@@ -206,6 +244,7 @@ impl Context {
 
                         // For every channel that's tracking the match
                         for Channel { id, msg_id } in entry.channels.iter() {
+                            let Some(msg_id) = msg_id else { continue };
                             let embed = Some(data.as_embed());
 
                             // Update the last message
@@ -222,8 +261,12 @@ impl Context {
                     // For all new embeds, send them to all channels
                     if let Some(embeds) = new_embeds {
                         for Channel { id, msg_id } in entry.channels.iter_mut() {
+                            if msg_id.is_none() {
+                                continue;
+                            }
+
                             match send_match_messages(*id, &embeds).await {
-                                Ok(msg) => *msg_id = msg,
+                                Ok(msg) => *msg_id = Some(msg),
                                 Err(err) => {
                                     error!(channel = id.get(), ?err, "Failed to send last msg")
                                 }
@@ -232,6 +275,22 @@ impl Context {
 
                         tracked_match.embeds.extend(embeds);
                     }
+
+                    // Send the summary to all channels once the match is over
+                    if ended {
+                        let summary = MatchLiveEmbed::summary(
+                            &tracked_match.osu_match,
+                            &tracked_match.progress,
+                        );
+
+                        for Channel { id, .. } in entry.channels.iter() {
+                            let summary_fut = send_match_messages(*id, slice::from_ref(&summary));
+
+                            if let Err(err) = summary_fut.await {
+                                error!(channel = id.get(), ?err, "Failed to send match summary");
+                            }
+                        }
+                    }
                 }
             }
 
@@ -0,0 +1,230 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    time::Duration,
+};
+
+use bathbot_util::IntHasher;
+use tokio::time::Instant;
+use twilight_model::id::{Id, marker::GuildMarker};
+
+/// How failed member requests are retried.
+#[derive(Copy, Clone, Debug)]
+pub(super) struct RetryPolicy {
+    /// Delay after the first failure, doubled for each further failure
+    base_delay: Duration,
+    max_delay: Duration,
+    /// Total amount of attempts before giving up
+    max_attempts: u32,
+}
+
+impl RetryPolicy {
+    pub(super) const fn new(base_delay: Duration, max_delay: Duration, max_attempts: u32) -> Self {
+        Self {
+            base_delay,
+            max_delay,
+            max_attempts,
+        }
+    }
+
+    /// Decide what to do after the given amount of attempts failed.
+    pub(super) fn decide(&self, failed_attempts: u32) -> RetryDecision {
+        if failed_attempts >= self.max_attempts {
+            return RetryDecision::GiveUp;
+        }
+
+        let exp = failed_attempts.saturating_sub(1).min(31);
+        let delay = self.base_delay.saturating_mul(1 << exp).min(self.max_delay);
+
+        RetryDecision::Retry(delay)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(600), Duration::from_secs(60), 5)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) enum RetryDecision {
+    Retry(Duration),
+    GiveUp,
+}
+
+type Scheduled = Reverse<(Instant, Id<GuildMarker>, u32)>;
+
+/// Member requests that failed and are waiting to be retried.
+#[derive(Default)]
+pub(super) struct MemberRequestRetries {
+    policy: RetryPolicy,
+    attempts: HashMap<Id<GuildMarker>, u32, IntHasher>,
+    queue: BinaryHeap<Scheduled>,
+}
+
+impl MemberRequestRetries {
+    /// Whether a retry is already scheduled for the guild.
+    pub(super) fn is_scheduled(&self, guild_id: Id<GuildMarker>) -> bool {
+        self.attempts.contains_key(&guild_id)
+    }
+
+    /// When the next retry is due.
+    pub(super) fn next_deadline(&self) -> Option<Instant> {
+        self.queue.peek().map(|Reverse((deadline, ..))| *deadline)
+    }
+
+    /// Take the next guild and shard id whose retry is due.
+    pub(super) fn pop_due(&mut self, now: Instant) -> Option<(Id<GuildMarker>, u32)> {
+        if self.next_deadline()? > now {
+            return None;
+        }
+
+        self.queue
+            .pop()
+            .map(|Reverse((_, guild_id, shard_id))| (guild_id, shard_id))
+    }
+
+    /// Record a failed request and schedule a retry if the policy allows it.
+    ///
+    /// Returns the decision alongside the total amount of failed attempts.
+    pub(super) fn on_failure(
+        &mut self,
+        guild_id: Id<GuildMarker>,
+        shard_id: u32,
+        now: Instant,
+    ) -> (RetryDecision, u32) {
+        let attempts = self.attempts.entry(guild_id).or_default();
+        *attempts += 1;
+        let attempts = *attempts;
+
+        let decision = self.policy.decide(attempts);
+
+        match decision {
+            RetryDecision::Retry(delay) => {
+                self.queue.push(Reverse((now + delay, guild_id, shard_id)))
+            }
+            RetryDecision::GiveUp => {
+                self.attempts.remove(&guild_id);
+            }
+        }
+
+        (decision, attempts)
+    }
+
+    /// Forget about previous failures of the guild.
+    pub(super) fn clear(&mut self, guild_id: Id<GuildMarker>) {
+        self.attempts.remove(&guild_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_doubles() {
+        let policy = RetryPolicy::default();
+
+        let delays: Vec<_> = (1..5).map(|attempts| policy.decide(attempts)).collect();
+
+        assert_eq!(
+            delays,
+            [
+                RetryDecision::Retry(Duration::from_millis(600)),
+                RetryDecision::Retry(Duration::from_millis(1200)),
+                RetryDecision::Retry(Duration::from_millis(2400)),
+                RetryDecision::Retry(Duration::from_millis(4800)),
+            ]
+        );
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let policy = RetryPolicy::default();
+
+        assert!(matches!(policy.decide(4), RetryDecision::Retry(_)));
+        assert_eq!(policy.decide(5), RetryDecision::GiveUp);
+        assert_eq!(policy.decide(6), RetryDecision::GiveUp);
+    }
+
+    #[test]
+    fn delay_is_capped() {
+        let max_delay = Duration::from_secs(60);
+        let policy = RetryPolicy::new(Duration::from_millis(600), max_delay, u32::MAX);
+
+        assert_eq!(
+            policy.decide(7),
+            RetryDecision::Retry(Duration::from_millis(38_400))
+        );
+        assert_eq!(policy.decide(8), RetryDecision::Retry(max_delay));
+        assert_eq!(policy.decide(1000), RetryDecision::Retry(max_delay));
+    }
+
+    #[test]
+    fn retries_are_scheduled() {
+        let mut retries = MemberRequestRetries::default();
+        let now = Instant::now();
+        let guild = Id::new(1);
+
+        assert!(!retries.is_scheduled(guild));
+        assert_eq!(retries.next_deadline(), None);
+
+        let (decision, attempts) = retries.on_failure(guild, 0, now);
+        assert_eq!(decision, RetryDecision::Retry(Duration::from_millis(600)));
+        assert_eq!(attempts, 1);
+        assert!(retries.is_scheduled(guild));
+
+        let deadline = now + Duration::from_millis(600);
+        assert_eq!(retries.next_deadline(), Some(deadline));
+        assert_eq!(retries.pop_due(now), None);
+        assert_eq!(retries.pop_due(deadline), Some((guild, 0)));
+        assert_eq!(retries.next_deadline(), None);
+
+        // Still considered scheduled until it succeeds or is given up on
+        assert!(retries.is_scheduled(guild));
+        retries.clear(guild);
+        assert!(!retries.is_scheduled(guild));
+    }
+
+    #[test]
+    fn earliest_retry_first() {
+        let mut retries = MemberRequestRetries::default();
+        let now = Instant::now();
+        let slow = Id::new(1);
+        let fast = Id::new(2);
+
+        // Retry after the second failure of `slow` is due 1.8s from now
+        retries.on_failure(slow, 0, now);
+        let first_retry = now + Duration::from_millis(600);
+        assert_eq!(retries.pop_due(first_retry), Some((slow, 0)));
+        retries.on_failure(slow, 0, first_retry);
+
+        // Retry after the first failure of `fast` is due 1.6s from now
+        retries.on_failure(fast, 1, now + Duration::from_secs(1));
+
+        let later = now + Duration::from_secs(60);
+        assert_eq!(retries.pop_due(later), Some((fast, 1)));
+        assert_eq!(retries.pop_due(later), Some((slow, 0)));
+        assert_eq!(retries.pop_due(later), None);
+    }
+
+    #[test]
+    fn give_up_forgets_guild() {
+        let mut retries = MemberRequestRetries::default();
+        let now = Instant::now();
+        let guild = Id::new(1);
+
+        for _ in 1..5 {
+            assert!(matches!(
+                retries.on_failure(guild, 0, now).0,
+                RetryDecision::Retry(_)
+            ));
+        }
+
+        assert_eq!(
+            retries.on_failure(guild, 0, now),
+            (RetryDecision::GiveUp, 5)
+        );
+        assert!(!retries.is_scheduled(guild));
+    }
+}
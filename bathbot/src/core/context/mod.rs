@@ -1,6 +1,9 @@
 use std::{
     collections::{HashMap, HashSet},
-    sync::{Arc, Mutex, OnceLock, RwLock},
+    sync::{
+        Arc, Mutex, OnceLock, RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
     time::Duration,
 };
 
@@ -26,16 +29,26 @@ use twilight_model::id::{
 };
 use twilight_standby::Standby;
 
-use self::osutrack::OsuTrackUserNotifTimestamps;
-use super::{BotConfig, BotMetrics};
+pub use self::convert_attrs::{ConvertKey, ConvertValues, RecalcScope};
+#[cfg(feature = "matchlive")]
+pub use self::matchlive::TICK as MATCH_LIVE_TICK;
+use self::{last_seen::UserLastSeenTimestamps, osutrack::OsuTrackUserNotifTimestamps};
+use super::{
+    ApiIncident, BotConfig, BotMetrics, CommandHealth, DatabaseHealth, FeatureFlags, Maintenance,
+    Watchdog,
+};
 use crate::{
     active::{ActiveMessages, impls::BackgroundGame},
+    manager::{PpCurves, UserLinks},
     tracking::{Ordr, OsuTracking, ScoresWebSocket, ScoresWebSocketDisconnect},
 };
 
+mod convert_attrs;
 mod discord;
 mod games;
+mod last_seen;
 mod manager;
+mod member_requests;
 mod messages;
 mod osutrack;
 mod set_commands;
@@ -56,6 +69,10 @@ type TrackedStreams = PapayaMap<u64, Vec<Id<ChannelMarker>>, IntHasher>;
 
 static CONTEXT: OnceLock<Box<Context>> = OnceLock::new();
 
+/// Maximum amount of active messages from before a restart whose components
+/// will be removed.
+const ORPHANED_ACTIVE_MSGS_CAP: usize = 200;
+
 pub struct Context {
     pub buckets: Buckets,
     pub shard_senders: RwLock<HashMap<u32, MessageSender, IntHasher>>,
@@ -65,6 +82,12 @@ pub struct Context {
     pub metrics: MetricsReader,
     data: ContextData,
     clients: Clients,
+    database_health: DatabaseHealth,
+    command_health: CommandHealth,
+    feature_flags: FeatureFlags,
+    maintenance: Maintenance,
+    api_incident: ApiIncident,
+    watchdog: Watchdog,
 
     /// Keeps track of the amount of times content was added to a usual bot
     /// response to remind users about the new /builder command.
@@ -97,6 +120,30 @@ impl Context {
         &Self::get().data.cache
     }
 
+    pub fn database_health() -> &'static DatabaseHealth {
+        &Self::get().database_health
+    }
+
+    pub fn command_health() -> &'static CommandHealth {
+        &Self::get().command_health
+    }
+
+    pub fn feature_flags() -> &'static FeatureFlags {
+        &Self::get().feature_flags
+    }
+
+    pub fn maintenance() -> &'static Maintenance {
+        &Self::get().maintenance
+    }
+
+    pub fn api_incident() -> &'static ApiIncident {
+        &Self::get().api_incident
+    }
+
+    pub fn watchdog() -> &'static Watchdog {
+        &Self::get().watchdog
+    }
+
     pub fn osu() -> &'static Osu {
         &Self::get().clients.osu
     }
@@ -127,6 +174,48 @@ impl Context {
         &Self::get().clients.psql
     }
 
+    /// Whether the database-backed data was loaded; `false` after starting
+    /// without a database until [`Context::reload_database_data`] succeeds.
+    pub fn database_data_loaded() -> bool {
+        Self::get()
+            .data
+            .database_data_loaded
+            .load(Ordering::Acquire)
+    }
+
+    /// Load all database-backed data that was skipped when starting without
+    /// a database.
+    pub async fn reload_database_data() -> Result<()> {
+        let ctx = Self::get();
+        let psql = &ctx.clients.psql;
+
+        ctx.data
+            .osu_tracking
+            .reload(psql)
+            .await
+            .wrap_err("Failed to reload osu! tracking")?;
+
+        #[cfg(feature = "twitchtracking")]
+        {
+            let streams = psql
+                .select_tracked_twitch_streams::<IntHasher>()
+                .await
+                .wrap_err("Failed to get tracked streams")?;
+
+            let tracked_streams = ctx.data.tracked_streams.pin();
+
+            for (twitch_id, channels) in streams {
+                tracked_streams.insert(twitch_id, channels);
+            }
+        }
+
+        // Marks the data as loaded so it must come last
+        Self::guild_config()
+            .reload()
+            .await
+            .wrap_err("Failed to reload guild configs")
+    }
+
     pub fn tracking() -> &'static OsuTracking {
         &Self::get().data.osu_tracking
     }
@@ -160,8 +249,35 @@ impl Context {
 
             let prometheus = metrics_exporter_prometheus::PrometheusBuilder::new()
                 .set_buckets(&DEFAULT_BUCKETS)
-                .expect("DEFAULT_BUCKETS is not empty")
-                .build_recorder();
+                .expect("DEFAULT_BUCKETS is not empty");
+
+            #[cfg(feature = "statsrefresh")]
+            let prometheus = {
+                const HOUR: f64 = 60.0 * 60.0;
+                const DAY: f64 = 24.0 * HOUR;
+
+                const STALENESS_BUCKETS: [f64; 9] = [
+                    HOUR,
+                    6.0 * HOUR,
+                    12.0 * HOUR,
+                    DAY,
+                    2.0 * DAY,
+                    4.0 * DAY,
+                    7.0 * DAY,
+                    14.0 * DAY,
+                    30.0 * DAY,
+                ];
+
+                let matcher = metrics_exporter_prometheus::Matcher::Suffix(
+                    "stats_refresh_staleness".to_owned(),
+                );
+
+                prometheus
+                    .set_buckets_for_metric(matcher, &STALENESS_BUCKETS)
+                    .expect("STALENESS_BUCKETS is not empty")
+            };
+
+            let prometheus = prometheus.build_recorder();
 
             let prometheus_handle = prometheus.handle();
 
@@ -188,6 +304,16 @@ impl Context {
         let psql =
             Database::new(&config.database_url).wrap_err("Failed to create database client")?;
 
+        let database_available = match Self::check_database(&psql).await {
+            Ok(()) => true,
+            Err(err) if config.degraded_mode => {
+                error!(?err, "Database unavailable, starting in degraded mode");
+
+                false
+            }
+            Err(err) => return Err(err.wrap_err("Database unavailable")),
+        };
+
         // Connect to discord API
         let (http, application_id) = discord::http(config)
             .await
@@ -204,7 +330,17 @@ impl Context {
             .await
             .wrap_err("Failed to create redis cache")?;
 
-        let data = ContextData::new(&psql, cache, application_id)
+        // Must happen before defrosting since that might flush redis
+        let orphaned_msgs = match cache.take_active_messages(ORPHANED_ACTIVE_MSGS_CAP).await {
+            Ok(orphaned_msgs) => orphaned_msgs,
+            Err(err) => {
+                warn!(?err, "Failed to take orphaned active messages");
+
+                Vec::new()
+            }
+        };
+
+        let data = ContextData::new(&psql, database_available, cache, application_id)
             .await
             .wrap_err("Failed to create context data")?;
 
@@ -221,6 +357,7 @@ impl Context {
             #[cfg(feature = "twitch")]
             (&config.tokens.twitch_client_id, &config.tokens.twitch_token),
             &config.tokens.github_token,
+            &config.client_retry_overrides,
         );
 
         let custom_client = client_fut
@@ -276,6 +413,12 @@ impl Context {
             clients,
             shard_senders,
             data,
+            database_health: DatabaseHealth::new(database_available),
+            command_health: CommandHealth::new(),
+            feature_flags: FeatureFlags::new(),
+            maintenance: Maintenance::new(),
+            api_incident: ApiIncident::new(),
+            watchdog: Watchdog::new(),
             buckets: Buckets::new(),
             member_requests: MemberRequests::new(tx),
             active_msgs: ActiveMessages::new(),
@@ -298,6 +441,8 @@ impl Context {
             Err(err) => warn!(?err, "Failed to connect scores websocket"),
         };
 
+        tokio::spawn(ActiveMessages::clean_orphaned(orphaned_msgs));
+
         Ok((
             shards,
             #[cfg(feature = "server")]
@@ -305,6 +450,36 @@ impl Context {
         ))
     }
 
+    /// Make sure the database is reachable and up to date before starting.
+    async fn check_database(psql: &Database) -> Result<()> {
+        const ATTEMPTS: u32 = 5;
+
+        let mut backoff = Duration::from_secs(1);
+        let mut attempt = 1;
+
+        loop {
+            let res = match psql.ping().await {
+                Ok(()) => psql.migrate().await.wrap_err("Failed to run migrations"),
+                Err(err) => Err(err.wrap_err("Failed to connect to the database")),
+            };
+
+            match res {
+                Ok(()) => return Ok(()),
+                Err(err) if attempt == ATTEMPTS => return Err(err),
+                Err(err) => {
+                    warn!(
+                        ?err,
+                        attempt, "Database check failed, retrying in {backoff:?}"
+                    );
+
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
     /// Acquire an entry for the user in the bucket and optionally return the
     /// cooldown in amount of seconds if acquiring the entry was ratelimitted.
     pub fn check_ratelimit(user_id: Id<UserMarker>, bucket: BucketName) -> Option<i64> {
@@ -318,6 +493,22 @@ impl Context {
         (ratelimit > 0).then_some(ratelimit)
     }
 
+    /// Same as [`Context::check_ratelimit`] but for buckets that are keyed by
+    /// channel instead of user.
+    pub fn check_channel_ratelimit(
+        channel_id: Id<ChannelMarker>,
+        bucket: BucketName,
+    ) -> Option<i64> {
+        let ratelimit = Self::get()
+            .buckets
+            .get(bucket)
+            .lock()
+            .unwrap()
+            .take(channel_id.get());
+
+        (ratelimit > 0).then_some(ratelimit)
+    }
+
     pub fn down_resumable(shards: &[Shard]) -> HashMap<u32, Session, IntHasher> {
         shards
             .iter()
@@ -385,6 +576,10 @@ impl MemberRequests {
             pending_guilds: Mutex::new(HashSet::default()),
         }
     }
+
+    fn remove_pending(&self, guild_id: Id<GuildMarker>) {
+        self.pending_guilds.lock().unwrap().remove(&guild_id);
+    }
 }
 
 struct Clients {
@@ -408,9 +603,15 @@ struct ContextData {
     tracked_streams: TrackedStreams,
     osu_tracking: OsuTracking,
     guild_configs: GuildConfigs,
+    /// Whether the database-backed data was loaded, `false` after starting
+    /// without a database
+    database_data_loaded: AtomicBool,
     guild_shards: GuildShards,
     miss_analyzer_guilds: MissAnalyzerGuilds,
     osutrack_user_notif_timestamps: OsuTrackUserNotifTimestamps,
+    user_last_seen_timestamps: UserLastSeenTimestamps,
+    pp_curves: PpCurves,
+    user_links: UserLinks,
     #[cfg(feature = "twitch")]
     online_twitch_streams: crate::tracking::OnlineTwitchStreams,
 }
@@ -418,9 +619,14 @@ struct ContextData {
 impl ContextData {
     async fn new(
         psql: &Database,
+        database_available: bool,
         cache: Cache,
         application_id: Id<ApplicationMarker>,
     ) -> Result<Self> {
+        if !database_available {
+            return Ok(Self::without_database(cache, application_id).await);
+        }
+
         #[cfg(feature = "twitchtracking")]
         let (
             guild_configs_res,
@@ -450,6 +656,7 @@ impl ContextData {
                 .wrap_err("Failed to get guild configs")?
                 .into_iter()
                 .collect(),
+            database_data_loaded: AtomicBool::new(true),
             #[cfg(feature = "twitchtracking")]
             tracked_streams: tracked_streams_res
                 .wrap_err("Failed to get tracked streams")?
@@ -463,11 +670,43 @@ impl ContextData {
             matchlive: crate::matchlive::MatchLiveChannels::new(),
             miss_analyzer_guilds,
             osutrack_user_notif_timestamps: OsuTrackUserNotifTimestamps::default(),
+            user_last_seen_timestamps: UserLastSeenTimestamps::default(),
+            pp_curves: PpCurves::default(),
+            user_links: UserLinks::default(),
             #[cfg(feature = "twitch")]
             online_twitch_streams: crate::tracking::OnlineTwitchStreams::default(),
         })
     }
 
+    /// Data for when the database is unavailable in degraded mode.
+    async fn without_database(cache: Cache, application_id: Id<ApplicationMarker>) -> Self {
+        let (guild_shards, miss_analyzer_guilds) = tokio::join!(
+            Self::fetch_guild_shards(&cache),
+            Self::fetch_miss_analyzer_guilds(&cache),
+        );
+
+        Self {
+            cache,
+            guild_configs: GuildConfigs::default(),
+            database_data_loaded: AtomicBool::new(false),
+            #[cfg(feature = "twitchtracking")]
+            tracked_streams: TrackedStreams::default(),
+            osu_tracking: OsuTracking::default(),
+            application_id,
+            games: Games::new(),
+            guild_shards,
+            #[cfg(feature = "matchlive")]
+            matchlive: crate::matchlive::MatchLiveChannels::new(),
+            miss_analyzer_guilds,
+            osutrack_user_notif_timestamps: OsuTrackUserNotifTimestamps::default(),
+            user_last_seen_timestamps: UserLastSeenTimestamps::default(),
+            pp_curves: PpCurves::default(),
+            user_links: UserLinks::default(),
+            #[cfg(feature = "twitch")]
+            online_twitch_streams: crate::tracking::OnlineTwitchStreams::default(),
+        }
+    }
+
     async fn fetch_guild_shards(cache: &Cache) -> GuildShards {
         let fetch_fut = cache
             .fetch::<_, <CacheGuildShards as ArchiveWith<[(Id<GuildMarker>, u32)]>>::Archived>(
@@ -3,6 +3,7 @@ use std::time::Duration;
 use papaya::HashMap as PapayaMap;
 use rosu_v2::model::GameMode;
 use time::OffsetDateTime;
+use tracing::Instrument;
 
 use super::Context;
 
@@ -10,6 +11,10 @@ use super::Context;
 /// user's activity.
 pub type OsuTrackUserNotifTimestamps = PapayaMap<(u32, GameMode), OffsetDateTime>;
 
+/// Minimum time between two osu!track updates that users triggered through
+/// their `osutrack_auto_update` config.
+const AUTO_UPDATE_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
 impl Context {
     pub async fn notify_osutrack_of_user_activity(&self, user_id: u32, mode: GameMode) {
         const DAY: Duration = Duration::from_secs(60 * 60 * 24);
@@ -44,4 +49,39 @@ impl Context {
             .pin()
             .insert(key, OffsetDateTime::now_utc());
     }
+
+    /// Triggers an osu!track update for the user in the background unless
+    /// one was already triggered within the last six hours.
+    ///
+    /// Returns whether the update was triggered. Errors are only logged so
+    /// that osu!track being unavailable never affects the command.
+    pub async fn osutrack_auto_update(user_id: u32, mode: GameMode) -> bool {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let interval = AUTO_UPDATE_INTERVAL.as_secs();
+
+        // Claiming and checking happen in one go so that concurrent commands
+        // can't both trigger an update
+        let claim_fut = Context::cache().claim_osutrack_update(user_id, mode as u8, now, interval);
+
+        match claim_fut.await {
+            Ok(true) => {}
+            Ok(false) => return false,
+            // Without storing the update we could not throttle so we skip it
+            Err(err) => {
+                warn!(?err, "Failed to claim osutrack update");
+
+                return false;
+            }
+        }
+
+        let update_fut = async move {
+            if let Err(err) = Context::client().osutrack_update(user_id, mode).await {
+                warn!(user_id, %mode, ?err, "Failed to trigger osutrack update");
+            }
+        };
+
+        tokio::spawn(update_fut.in_current_span());
+
+        true
+    }
 }
@@ -1,11 +1,12 @@
 use eyre::{Result, WrapErr};
 use twilight_model::{
     application::{command::Command, interaction::InteractionContextType},
+    id::{Id, marker::GuildMarker},
     oauth::ApplicationIntegrationType,
 };
 
 use super::Context;
-use crate::core::BotConfig;
+use crate::core::commands::interaction::{GuildCommands, InteractionCommands};
 
 impl Context {
     #[cold]
@@ -50,13 +51,64 @@ impl Context {
     }
 
     #[cold]
-    pub async fn set_guild_commands(cmds: Vec<Command>) -> Result<Vec<Command>> {
+    pub async fn set_guild_commands(
+        guild: Id<GuildMarker>,
+        cmds: Vec<Command>,
+    ) -> Result<Vec<Command>> {
         Context::interaction()
-            .set_guild_commands(BotConfig::get().dev_guild, &cmds)
+            .set_guild_commands(guild, &cmds)
             .await
             .wrap_err("Failed to set commands")?
             .models()
             .await
             .wrap_err("Failed to deserialize commands")
     }
+
+    /// Set the commands of a guild and store their ids for mentions.
+    ///
+    /// Used when commands are not registered globally.
+    pub async fn register_guild_commands(
+        guild: Id<GuildMarker>,
+        cmds: Vec<Command>,
+    ) -> Result<Vec<Command>> {
+        let cmds = Self::set_guild_commands(guild, cmds).await?;
+        GuildCommands::get().set_ids(guild, &cmds);
+
+        Ok(cmds)
+    }
+
+    /// Register the commands in the guild unless they're already registered.
+    pub async fn register_unknown_guild_commands(guild: Id<GuildMarker>) {
+        let guild_commands = GuildCommands::get();
+
+        if !guild_commands.insert_known(guild) {
+            return;
+        }
+
+        guild_commands.acquire().await;
+        let cmds = InteractionCommands::get().collect();
+
+        match Self::register_guild_commands(guild, cmds).await {
+            Ok(_) => info!(%guild, "Registered guild commands"),
+            Err(err) => {
+                // Try again on the next guild create
+                guild_commands.forget(guild);
+                warn!(?err, %guild, "Failed to register guild commands");
+            }
+        }
+    }
+
+    /// Register the commands in all cached guilds, including those that were
+    /// joined while the bot was offline.
+    #[cold]
+    pub async fn register_cached_guild_commands() {
+        let guilds = match Context::cache().guild_ids().await {
+            Ok(guilds) => guilds,
+            Err(err) => return warn!(?err, "Failed to get guild ids for commands"),
+        };
+
+        for guild in guilds {
+            Self::register_unknown_guild_commands(guild).await;
+        }
+    }
 }
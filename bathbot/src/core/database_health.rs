@@ -0,0 +1,63 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Keeps track of whether the database is currently reachable.
+pub struct DatabaseHealth {
+    available: AtomicBool,
+}
+
+/// How the availability changed through [`DatabaseHealth::update`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HealthChange {
+    Unchanged,
+    Lost,
+    Recovered,
+}
+
+impl DatabaseHealth {
+    pub const fn new(available: bool) -> Self {
+        Self {
+            available: AtomicBool::new(available),
+        }
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.available.load(Ordering::Relaxed)
+    }
+
+    /// Store the availability and return how it changed.
+    pub fn update(&self, available: bool) -> HealthChange {
+        match (self.available.swap(available, Ordering::Relaxed), available) {
+            (true, false) => HealthChange::Lost,
+            (false, true) => HealthChange::Recovered,
+            (true, true) | (false, false) => HealthChange::Unchanged,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transitions() {
+        let health = DatabaseHealth::new(true);
+        assert!(health.is_available());
+
+        assert_eq!(health.update(true), HealthChange::Unchanged);
+        assert_eq!(health.update(false), HealthChange::Lost);
+        assert!(!health.is_available());
+
+        assert_eq!(health.update(false), HealthChange::Unchanged);
+        assert_eq!(health.update(true), HealthChange::Recovered);
+        assert!(health.is_available());
+    }
+
+    #[test]
+    fn starts_unavailable() {
+        let health = DatabaseHealth::new(false);
+        assert!(!health.is_available());
+
+        assert_eq!(health.update(true), HealthChange::Recovered);
+        assert_eq!(health.update(true), HealthChange::Unchanged);
+    }
+}
@@ -3,7 +3,7 @@ use std::{mem, time::Instant};
 use crate::{
     commands::{
         help::slash_help,
-        osu::{slash_badges, slash_cs, slash_medal},
+        osu::{slash_badges, slash_cs, slash_mapwatch, slash_medal},
     },
     core::{BotMetrics, events::EventKind},
     util::interaction::InteractionCommand,
@@ -19,6 +19,7 @@ pub async fn handle_autocomplete(mut command: InteractionCommand) {
         "help" => slash_help(command).await,
         "badges" => slash_badges(command).await,
         "medal" => slash_medal(command).await,
+        "mapwatch" => slash_mapwatch(command).await,
         "cs" | "compare" | "score" => slash_cs(command).await,
         _ => return error!(name, "Unknown autocomplete command"),
     };
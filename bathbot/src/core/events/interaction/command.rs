@@ -1,21 +1,49 @@
 use std::{mem, time::Instant};
 
-use bathbot_util::Authored;
+use bathbot_util::{Authored, constants::DATABASE_UNAVAILABLE, tr};
 use eyre::Result;
+use time::OffsetDateTime;
+use tracing::Instrument;
 
+use super::retry::{RetryData, add_retry_button, is_transient};
 use crate::{
     core::{
         BotConfig, BotMetrics, Context,
         commands::{
+            audit::AuditRecord,
             checks::check_authority,
             interaction::{InteractionCommandKind, InteractionCommands, SlashCommand},
+            outcome::{CommandOutcome, track_error_response},
         },
         events::{EventKind, ProcessResult},
+        logging::command_span,
     },
     util::{InteractionCommandExt, interaction::InteractionCommand},
 };
 
-pub async fn handle_command(mut command: InteractionCommand) {
+pub async fn handle_command(command: InteractionCommand) {
+    handle_command_inner(command, true).await
+}
+
+/// Process a command without offering a retry button on transient failures.
+pub async fn handle_command_without_retry(command: InteractionCommand) {
+    handle_command_inner(command, false).await
+}
+
+async fn handle_command_inner(command: InteractionCommand, allow_retry: bool) {
+    let span = command_span(
+        &command.data.name,
+        command.guild_id,
+        command.channel_id,
+        command.user_id().ok(),
+    );
+
+    handle_command_in_span(command, allow_retry)
+        .instrument(span)
+        .await
+}
+
+async fn handle_command_in_span(mut command: InteractionCommand, allow_retry: bool) {
     let start = Instant::now();
 
     let name = mem::take(&mut command.data.name);
@@ -27,7 +55,13 @@ pub async fn handle_command(mut command: InteractionCommand) {
 
     let group_sub = command.group_sub();
 
-    match process_command(command, cmd).await {
+    // Options are consumed when executing so they need to be kept beforehand
+    let retry = allow_retry
+        .then(|| RetryData::new(&command, &name))
+        .flatten()
+        .map(|data| (data, command.id, command.token.clone()));
+
+    match process_command(command, cmd, &name).await {
         Ok(ProcessResult::Success) => info!(%name, "Processed interaction command"),
         Ok(reason) => info!(?reason, "Interaction command `{name}` was not processed"),
         Err(err) => {
@@ -36,6 +70,13 @@ pub async fn handle_command(mut command: InteractionCommand) {
                 None => BotMetrics::inc_command_error("message", name.clone()),
             }
 
+            if let Some((data, interaction_id, token)) = retry.filter(|_| is_transient(&err))
+                && let Err(err) = add_retry_button(data, interaction_id, token).await
+            {
+                warn!(?err, "Failed to add retry button");
+            }
+
+            Context::api_incident().record_error(&err);
             error!(name, ?err, "Failed to process interaction command");
         }
     }
@@ -51,27 +92,40 @@ pub async fn handle_command(mut command: InteractionCommand) {
 async fn process_command(
     command: InteractionCommand,
     cmd: InteractionCommandKind,
+    name: &str,
 ) -> Result<ProcessResult> {
-    match cmd {
-        InteractionCommandKind::Chat(cmd) => match pre_process_command(&command, cmd).await? {
-            Some(result) => return Ok(result),
-            None => {
-                if cmd.flags.defer() {
-                    command.defer(cmd.flags.ephemeral()).await?;
-                }
-
-                (cmd.exec)(command).await?;
-            }
-        },
-        InteractionCommandKind::Message(cmd) => {
-            if cmd.flags.defer() {
-                command.defer(cmd.flags.ephemeral()).await?;
-            }
+    if let InteractionCommandKind::Chat(slash) = cmd
+        && let Some(result) = pre_process_command(&command, slash).await?
+    {
+        return Ok(result);
+    }
 
-            (cmd.exec)(command).await?;
-        }
+    let flags = cmd.flags();
+
+    if flags.defer() {
+        command.defer(flags.ephemeral()).await?;
     }
 
+    // Options are consumed when executing so they need to be summarized beforehand
+    let audit = flags
+        .auditable()
+        .then(|| AuditRecord::interaction(&command, name, cmd.read_only()))
+        .flatten();
+
+    let kind = match cmd {
+        InteractionCommandKind::Chat(_) => "slash",
+        InteractionCommandKind::Message(_) => "message",
+    };
+
+    let (res, error_response) = track_error_response((cmd.exec())(command)).await;
+    CommandOutcome::new(&res, error_response).record(kind, name);
+
+    if let Some(audit) = audit {
+        audit.record(res.is_ok());
+    }
+
+    res?;
+
     Ok(ProcessResult::Success)
 }
 
@@ -89,6 +143,16 @@ async fn pre_process_command(
         return Ok(Some(ProcessResult::NoOwner));
     }
 
+    // Under maintenance?
+    let now = OffsetDateTime::now_utc().unix_timestamp();
+    let is_owner = user_id == BotConfig::get().owner;
+
+    if let Some(notice) = Context::maintenance().notice(is_owner, now) {
+        command.error_callback(notice.to_string()).await?;
+
+        return Ok(Some(ProcessResult::Maintenance));
+    }
+
     // Only in guilds?
     // Using `dm_permission = false` used to be sufficient but apparently
     // that's no longer the case.
@@ -99,13 +163,21 @@ async fn pre_process_command(
         return Ok(Some(ProcessResult::NoDM));
     }
 
+    // Database unavailable?
+    if slash.flags.needs_database() && !Context::database_health().is_available() {
+        command.error_callback(DATABASE_UNAVAILABLE).await?;
+
+        return Ok(Some(ProcessResult::NoDatabase));
+    }
+
     // Ratelimited?
     if let Some(bucket) = slash.bucket
         && let Some(cooldown) = Context::check_ratelimit(user_id, bucket)
     {
         trace!("Ratelimiting user {user_id} on bucket `{bucket:?}` for {cooldown} seconds");
 
-        let content = format!("Command on cooldown, try again in {cooldown} seconds");
+        let lang = Context::guild_config().lang(command.guild_id).await;
+        let content = tr!("cooldown", lang, seconds = cooldown);
         command.error_callback(content).await?;
 
         return Ok(Some(ProcessResult::Ratelimited(bucket)));
@@ -129,5 +201,7 @@ async fn pre_process_command(
         }
     }
 
+    Context::mark_user_seen(user_id);
+
     Ok(None)
 }
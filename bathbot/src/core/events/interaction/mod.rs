@@ -1,13 +1,21 @@
+use bathbot_util::Authored;
+use tracing::Instrument;
 use twilight_model::application::interaction::{Interaction, InteractionData, InteractionType};
 
-use self::{autocomplete::handle_autocomplete, command::handle_command};
+use self::{
+    autocomplete::handle_autocomplete,
+    command::handle_command,
+    retry::{RETRY_PREFIX, handle_retry},
+};
 use crate::{
     active::ActiveMessages,
+    core::logging::component_span,
     util::interaction::{InteractionCommand, InteractionComponent, InteractionModal},
 };
 
 mod autocomplete;
 mod command;
+mod retry;
 
 pub async fn handle_interaction(interaction: Interaction) {
     let Interaction {
@@ -24,10 +32,12 @@ pub async fn handle_interaction(interaction: Interaction) {
         ..
     } = interaction;
 
-    let Some(channel_id) = channel.map(|channel| channel.id) else {
+    let Some(channel) = channel else {
         return warn!(?kind, "No channel id for interaction");
     };
 
+    let channel_id = channel.id;
+
     match data {
         Some(InteractionData::ApplicationCommand(data)) => {
             let cmd = InteractionCommand {
@@ -37,6 +47,9 @@ pub async fn handle_interaction(interaction: Interaction) {
                 guild_id,
                 id,
                 member,
+                thread_archive: channel
+                    .thread_metadata
+                    .map(|metadata| metadata.auto_archive_duration),
                 token,
                 user,
             };
@@ -64,7 +77,20 @@ pub async fn handle_interaction(interaction: Interaction) {
                 user,
             };
 
-            ActiveMessages::handle_component(component).await
+            let span = component_span(
+                &component.data.custom_id,
+                component.guild_id,
+                component.channel_id,
+                component.user_id().ok(),
+            );
+
+            if component.data.custom_id.starts_with(RETRY_PREFIX) {
+                handle_retry(component).instrument(span).await
+            } else {
+                ActiveMessages::handle_component(component)
+                    .instrument(span)
+                    .await
+            }
         }
         Some(InteractionData::ModalSubmit(data)) => {
             let modal = InteractionModal {
@@ -0,0 +1,351 @@
+use std::borrow::Cow;
+
+use bathbot_util::{Authored, MessageBuilder};
+use eyre::{Report, Result, WrapErr};
+use rosu_v2::error::OsuError;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use tokio::time::error::Elapsed;
+use twilight_model::{
+    application::{
+        command::CommandType,
+        interaction::application_command::{CommandData, CommandDataOption},
+    },
+    channel::message::{
+        Component, EmojiReactionType,
+        component::{ActionRow, Button, ButtonStyle},
+    },
+    id::{
+        Id,
+        marker::{InteractionMarker, UserMarker},
+    },
+};
+
+use super::command::handle_command_without_retry;
+use crate::{
+    core::{Context, commands::interaction::InteractionCommands},
+    util::{
+        ComponentExt, InteractionToken,
+        interaction::{InteractionCommand, InteractionComponent},
+    },
+};
+
+/// Prefix of the custom id of retry buttons.
+pub const RETRY_PREFIX: &str = "retry:";
+
+/// Prefix of the custom id of retry buttons whose data is stored in redis.
+const STORED_PREFIX: &str = "retry:key:";
+
+/// Maximum length of a component's custom id.
+const CUSTOM_ID_LIMIT: usize = 100;
+
+/// How many seconds a retry button can be used.
+const RETRY_EXPIRE: u64 = 5 * 60;
+
+/// Everything needed to re-dispatch a failed command.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RetryData {
+    #[serde(rename = "n")]
+    pub name: String,
+    #[serde(rename = "o")]
+    pub options: Vec<CommandDataOption>,
+    #[serde(rename = "u")]
+    pub owner: Id<UserMarker>,
+    #[serde(rename = "t")]
+    pub created_at: i64,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RetryCustomId {
+    /// The data fits into the custom id.
+    Inline(String),
+    /// The data is too large and must be stored in redis.
+    Stored {
+        custom_id: String,
+        key: String,
+        payload: String,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RetryRef {
+    Inline(RetryData),
+    Stored { key: String },
+}
+
+impl RetryData {
+    pub fn new(command: &InteractionCommand, name: &str) -> Option<Self> {
+        // Attachments are not available anymore when retrying
+        let has_attachments = command
+            .data
+            .resolved
+            .as_ref()
+            .is_some_and(|resolved| !resolved.attachments.is_empty());
+
+        if has_attachments || command.data.kind != CommandType::ChatInput {
+            return None;
+        }
+
+        Some(Self {
+            name: name.to_owned(),
+            options: command.data.options.clone(),
+            owner: command.user_id().ok()?,
+            created_at: OffsetDateTime::now_utc().unix_timestamp(),
+        })
+    }
+
+    pub fn encode(&self, interaction_id: Id<InteractionMarker>) -> Result<RetryCustomId> {
+        let payload = serde_json::to_string(self).wrap_err("Failed to serialize retry data")?;
+        let inline = format!("{RETRY_PREFIX}{payload}");
+
+        if inline.len() <= CUSTOM_ID_LIMIT {
+            return Ok(RetryCustomId::Inline(inline));
+        }
+
+        Ok(RetryCustomId::Stored {
+            custom_id: format!("{STORED_PREFIX}{interaction_id}"),
+            key: stored_key(interaction_id.get()),
+            payload,
+        })
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now - self.created_at > RETRY_EXPIRE as i64
+    }
+}
+
+impl RetryRef {
+    pub fn decode(custom_id: &str) -> Option<Self> {
+        if let Some(interaction_id) = custom_id.strip_prefix(STORED_PREFIX) {
+            let interaction_id = interaction_id.parse().ok()?;
+
+            return Some(Self::Stored {
+                key: stored_key(interaction_id),
+            });
+        }
+
+        let payload = custom_id.strip_prefix(RETRY_PREFIX)?;
+
+        serde_json::from_str(payload).ok().map(Self::Inline)
+    }
+}
+
+fn stored_key(interaction_id: u64) -> String {
+    format!("retry_{interaction_id}")
+}
+
+/// Whether the error is likely to disappear when trying again.
+pub fn is_transient(err: &Report) -> bool {
+    err.chain().any(|err| {
+        if err.is::<Elapsed>() {
+            return true;
+        }
+
+        match err.downcast_ref::<OsuError>() {
+            Some(
+                OsuError::Request { .. }
+                | OsuError::RequestTimeout { .. }
+                | OsuError::ServiceUnavailable { .. },
+            ) => true,
+            Some(OsuError::Response { status, .. }) => status.is_server_error(),
+            _ => false,
+        }
+    })
+}
+
+/// Add a retry button to the error response of a failed command.
+pub async fn add_retry_button(
+    data: RetryData,
+    interaction_id: Id<InteractionMarker>,
+    token: String,
+) -> Result<()> {
+    let custom_id = match data.encode(interaction_id)? {
+        RetryCustomId::Inline(custom_id) => custom_id,
+        RetryCustomId::Stored {
+            custom_id,
+            key,
+            payload,
+        } => {
+            Context::cache()
+                .store_new(&key, payload.as_bytes(), RETRY_EXPIRE)
+                .await
+                .wrap_err("Failed to store retry data")?;
+
+            custom_id
+        }
+    };
+
+    let button = Button {
+        custom_id: Some(custom_id),
+        disabled: false,
+        emoji: Some(EmojiReactionType::Unicode {
+            name: "🔁".to_owned(),
+        }),
+        label: Some("Retry".to_owned()),
+        style: ButtonStyle::Secondary,
+        url: None,
+        sku_id: None,
+    };
+
+    let components = vec![Component::ActionRow(ActionRow {
+        components: vec![Component::Button(button)],
+    })];
+
+    let builder = MessageBuilder::new().components(components);
+
+    InteractionToken(Cow::Owned(token))
+        .update(builder, None)
+        .await
+        .wrap_err("Failed to add retry button")?;
+
+    Ok(())
+}
+
+/// Re-dispatch the command of a clicked retry button.
+pub async fn handle_retry(component: InteractionComponent) {
+    if let Err(err) = handle_retry_inner(component).await {
+        error!(?err, "Failed to handle retry button");
+    }
+}
+
+async fn handle_retry_inner(component: InteractionComponent) -> Result<()> {
+    let data = match RetryRef::decode(&component.data.custom_id) {
+        Some(RetryRef::Inline(data)) => Some(data),
+        Some(RetryRef::Stored { key }) => match Context::cache().fetch_raw(&key).await {
+            Ok(Ok(bytes)) => serde_json::from_slice(&bytes).ok(),
+            Ok(Err(_)) => None,
+            Err(err) => return Err(Report::new(err).wrap_err("Failed to fetch retry data")),
+        },
+        None => None,
+    };
+
+    let now = OffsetDateTime::now_utc().unix_timestamp();
+
+    let Some(data) = data.filter(|data| !data.is_expired(now)) else {
+        // Expired; remove the button
+        let builder = MessageBuilder::new().components(Vec::new());
+        component.callback(builder).await?;
+
+        return Ok(());
+    };
+
+    if component.user_id()? != data.owner {
+        component.defer().await?;
+
+        return Ok(());
+    }
+
+    // Prevent multiple retries through the same button
+    let _ = Context::http()
+        .update_message(component.channel_id, component.message.id)
+        .components(Some(&[]))
+        .await;
+
+    let Some(id) = InteractionCommands::get_command(&data.name).and_then(|cmd| cmd.try_id()) else {
+        return Ok(());
+    };
+
+    let InteractionComponent {
+        permissions,
+        channel_id,
+        guild_id,
+        id: interaction_id,
+        member,
+        thread_archive: None,
+        token,
+        user,
+        ..
+    } = component;
+
+    let command = InteractionCommand {
+        permissions,
+        channel_id,
+        data: Box::new(CommandData {
+            guild_id,
+            id,
+            name: data.name,
+            kind: CommandType::ChatInput,
+            options: data.options,
+            resolved: None,
+            target_id: None,
+        }),
+        guild_id,
+        id: interaction_id,
+        member,
+        token,
+        user,
+    };
+
+    handle_command_without_retry(command).await;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::application::interaction::application_command::CommandOptionValue;
+
+    use super::*;
+
+    fn data(name_len: usize) -> RetryData {
+        RetryData {
+            name: "rs".to_owned(),
+            options: vec![CommandDataOption {
+                name: "name".to_owned(),
+                value: CommandOptionValue::String("a".repeat(name_len)),
+            }],
+            owner: Id::new(123),
+            created_at: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn inline_custom_id() {
+        let data = data(5);
+
+        let RetryCustomId::Inline(custom_id) = data.encode(Id::new(1)).unwrap() else {
+            panic!("expected inline custom id");
+        };
+
+        assert!(custom_id.len() <= CUSTOM_ID_LIMIT);
+        assert_eq!(RetryRef::decode(&custom_id), Some(RetryRef::Inline(data)));
+    }
+
+    #[test]
+    fn stored_custom_id() {
+        let data = data(100);
+        let interaction_id = Id::new(987_654_321);
+
+        let RetryCustomId::Stored {
+            custom_id,
+            key,
+            payload,
+        } = data.encode(interaction_id).unwrap()
+        else {
+            panic!("expected stored custom id");
+        };
+
+        assert_eq!(custom_id, "retry:key:987654321");
+        assert_eq!(
+            RetryRef::decode(&custom_id),
+            Some(RetryRef::Stored { key: key.clone() })
+        );
+        assert_eq!(key, "retry_987654321");
+        assert_eq!(serde_json::from_str::<RetryData>(&payload).unwrap(), data);
+    }
+
+    #[test]
+    fn invalid_custom_id() {
+        assert_eq!(RetryRef::decode("retry:key:abc"), None);
+        assert_eq!(RetryRef::decode("retry:{"), None);
+        assert_eq!(RetryRef::decode("next_higherlower"), None);
+    }
+
+    #[test]
+    fn expiry() {
+        let data = data(5);
+
+        assert!(!data.is_expired(data.created_at + 300));
+        assert!(data.is_expired(data.created_at + 301));
+    }
+}
@@ -1,22 +1,45 @@
 use std::time::Instant;
 
 use bathbot_psql::model::configs::GuildConfig;
-use bathbot_util::BucketName;
+use bathbot_util::{
+    BucketName,
+    constants::{DATABASE_UNAVAILABLE, UNKNOWN_MESSAGE},
+    tr,
+};
 use eyre::Result;
 use nom::{
     bytes::complete as by,
     combinator::{opt, recognize},
 };
-use twilight_model::{channel::Message, guild::Permissions};
+use time::OffsetDateTime;
+use tokio::time::sleep;
+use tracing::Instrument;
+use twilight_http::{
+    Error as TwilightError,
+    api_error::{ApiError, GeneralApiError},
+    error::ErrorType,
+};
+use twilight_model::{
+    channel::Message,
+    guild::Permissions,
+    id::{Id, marker::GuildMarker},
+};
 
 use self::parse::*;
 use super::{EventKind, ProcessResult};
 use crate::{
+    commands::osu::auto_score_embed,
     core::{
-        BotMetrics, Context,
-        commands::checks::{check_authority, check_channel_permissions},
+        BotConfig, BotMetrics, Context,
+        commands::{
+            audit::AuditRecord,
+            checks::{check_authority, check_channel_permissions},
+            outcome::CommandOutcome,
+            prefix::{AutoDelete, NotedResponse, PrefixCommandGroup, track_response},
+        },
+        logging::command_span,
     },
-    util::ChannelExt,
+    util::{ChannelExt, MessageExt},
 };
 
 mod parse;
@@ -48,7 +71,7 @@ pub async fn handle_message(msg: Message) {
     };
 
     let Some((content, _)) = prefix_opt else {
-        return;
+        return auto_score_embed(&msg).await;
     };
 
     // Parse msg content for commands
@@ -57,23 +80,34 @@ pub async fn handle_message(msg: Message) {
     };
 
     let name = invoke.cmd.name();
-    EventKind::PrefixCommand.log(&msg, name).await;
-
-    match process_command(invoke, &msg).await {
-        Ok(ProcessResult::Success) => info!(%name, "Processed command"),
-        Ok(reason) => info!(?reason, "Command `{name}` was not processed"),
-        Err(err) => {
-            BotMetrics::inc_command_error("prefix", name);
-            error!(name, ?err, "Failed to process prefix command");
+    let span = command_span(name, msg.guild_id, msg.channel_id, Some(msg.author.id));
+
+    let fut = async {
+        EventKind::PrefixCommand.log(&msg, name).await;
+
+        match process_command(invoke, &msg).await {
+            Ok(ProcessResult::Success) => info!(%name, "Processed command"),
+            Ok(reason) => info!(?reason, "Command `{name}` was not processed"),
+            Err(err) => {
+                BotMetrics::inc_command_error("prefix", name);
+                Context::api_incident().record_error(&err);
+                error!(name, ?err, "Failed to process prefix command");
+            }
         }
-    }
+    };
+
+    fut.instrument(span).await;
 
     let elapsed = start.elapsed();
     BotMetrics::observe_command("prefix", name, elapsed);
 }
 
 async fn process_command<'m>(invoke: Invoke<'m>, msg: &'m Message) -> Result<ProcessResult> {
-    let Invoke { cmd, args } = invoke;
+    let Invoke {
+        cmd,
+        args,
+        raw_args,
+    } = invoke;
 
     // Only in guilds?
     if (cmd.flags.authority() || cmd.flags.only_guilds()) && msg.guild_id.is_none() {
@@ -102,6 +136,23 @@ async fn process_command<'m>(invoke: Invoke<'m>, msg: &'m Message) -> Result<Pro
         _ => None,
     };
 
+    // Under maintenance?
+    let now = OffsetDateTime::now_utc().unix_timestamp();
+    let is_owner = msg.author.id == BotConfig::get().owner;
+
+    if let Some(notice) = Context::maintenance().notice(is_owner, now) {
+        msg.error(notice.to_string()).await?;
+
+        return Ok(ProcessResult::Maintenance);
+    }
+
+    // Database unavailable?
+    if cmd.flags.needs_database() && !Context::database_health().is_available() {
+        msg.error(DATABASE_UNAVAILABLE).await?;
+
+        return Ok(ProcessResult::NoDatabase);
+    }
+
     // Ratelimited?
     if let Some(cooldown) = Context::check_ratelimit(msg.author.id, BucketName::All) {
         trace!("Ratelimiting user {} for {cooldown} seconds", msg.author.id);
@@ -117,7 +168,8 @@ async fn process_command<'m>(invoke: Invoke<'m>, msg: &'m Message) -> Result<Pro
             msg.author.id,
         );
 
-        let content = format!("Command on cooldown, try again in {cooldown} seconds");
+        let lang = Context::guild_config().lang(msg.guild_id).await;
+        let content = tr!("cooldown", lang, seconds = cooldown);
         msg.error(content).await?;
 
         return Ok(ProcessResult::Ratelimited(bucket));
@@ -141,13 +193,118 @@ async fn process_command<'m>(invoke: Invoke<'m>, msg: &'m Message) -> Result<Pro
         }
     }
 
+    Context::mark_user_seen(msg.author.id);
+
     // Broadcast typing event
     if cmd.flags.defer() {
         let _ = Context::http().create_typing_trigger(channel).await;
     }
 
+    let audit = cmd
+        .flags
+        .auditable()
+        .then(|| AuditRecord::prefix(msg, cmd.name(), raw_args))
+        .flatten();
+
     // Call command function
-    (cmd.exec)(msg, args, permissions).await?;
+    let (res, tracked) = track_response((cmd.exec)(msg, args, permissions)).await;
+    CommandOutcome::new(&res, tracked.error).record("prefix", cmd.name());
+
+    if let Some(audit) = audit {
+        audit.record(res.is_ok());
+    }
+
+    res?;
+
+    // Keep the invocation on error responses so that users can fix it
+    if !tracked.error {
+        delete_invocation(msg, permissions).await;
+    }
+
+    if let Some((guild_id, response)) = msg.guild_id.zip(tracked.response) {
+        auto_delete_response(guild_id, cmd.group, response).await;
+    }
 
     Ok(ProcessResult::Success)
 }
+
+/// Delete the invoking message if the guild opted in and the bot is allowed
+/// to.
+async fn delete_invocation(msg: &Message, permissions: Option<Permissions>) {
+    let Some(guild_id) = msg.guild_id else {
+        return;
+    };
+
+    let can_delete =
+        permissions.is_some_and(|permissions| permissions.contains(Permissions::MANAGE_MESSAGES));
+
+    if !can_delete {
+        return;
+    }
+
+    let delete = Context::guild_config()
+        .peek(guild_id, |config| config.delete_invocation.unwrap_or(false))
+        .await;
+
+    if !delete {
+        return;
+    }
+
+    let delete_fut = msg.delete();
+
+    tokio::spawn(
+        async move {
+            if let Err(err) = delete_fut.await {
+                warn!(?err, "Failed to delete invoking message");
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+/// Delete the response after a while if the guild opted in for the command's
+/// group.
+async fn auto_delete_response(
+    guild_id: Id<GuildMarker>,
+    group: PrefixCommandGroup,
+    response: NotedResponse,
+) {
+    let delay = Context::guild_config()
+        .peek(guild_id, |config| {
+            AutoDelete::new(config).and_then(|auto_delete| auto_delete.delay(group, &response))
+        })
+        .await;
+
+    let Some(delay) = delay else {
+        return;
+    };
+
+    tokio::spawn(
+        async move {
+            sleep(delay).await;
+
+            let delete_fut = (response.message, response.channel).delete();
+
+            match delete_fut.await {
+                Ok(_) => {}
+                // Deleted in the meanwhile
+                Err(err) if is_unknown_message(&err) => {}
+                Err(err) => warn!(?err, "Failed to delete response after delay"),
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+fn is_unknown_message(err: &TwilightError) -> bool {
+    matches!(
+        err.kind(),
+        ErrorType::Response {
+            error: ApiError::General(GeneralApiError {
+                code: UNKNOWN_MESSAGE,
+                ..
+            }),
+            ..
+        }
+    )
+}
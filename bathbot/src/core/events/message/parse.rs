@@ -11,6 +11,8 @@ use crate::core::commands::prefix::{Args, ArgsNum, PrefixCommand, PrefixCommands
 pub struct Invoke<'i> {
     pub cmd: &'static PrefixCommand,
     pub args: Args<'i>,
+    /// Unparsed arguments
+    pub raw_args: &'i str,
 }
 
 impl<'i> Invoke<'i> {
@@ -45,6 +47,10 @@ impl<'i> Invoke<'i> {
         let (rest, (cmd, num)) = parse(input).ok()?;
         let args = Args::new(rest, num);
 
-        Some(Self { cmd, args })
+        Some(Self {
+            cmd,
+            args,
+            raw_args: rest,
+        })
     }
 }
@@ -12,10 +12,13 @@ use tokio::{
     task::JoinSet,
 };
 use twilight_gateway::{Event, EventTypeFlags, Shard, StreamExt as _};
-use twilight_model::user::User;
+use twilight_model::{gateway::payload::incoming::GuildCreate, user::User};
 
 use self::{interaction::handle_interaction, message::handle_message};
-use super::{BotMetrics, Context};
+use super::{
+    BotMetrics, Context,
+    commands::interaction::{GuildCommands, is_leave},
+};
 
 mod interaction;
 mod message;
@@ -31,6 +34,8 @@ enum ProcessResult {
     ),
     NoOwner,
     NoAuthority,
+    NoDatabase,
+    Maintenance,
 }
 
 pub enum EventKind {
@@ -228,6 +233,16 @@ async fn handle_event(event: Event, shard_id: u32) {
                 if let Err(err) = ctx.member_requests.tx.send((guild_id, shard_id)) {
                     warn!(?err, "Failed to forward member request");
                 }
+
+                // Guilds that only became available again already have their commands
+                if !cfg!(feature = "global_slash") && matches!(*e, GuildCreate::Available(_)) {
+                    Context::register_unknown_guild_commands(guild_id).await;
+                }
+            }
+            Event::GuildDelete(e) => {
+                if !cfg!(feature = "global_slash") && is_leave(e.unavailable) {
+                    GuildCommands::get().forget(e.id);
+                }
             }
             Event::InteractionCreate(e) => handle_interaction(e.0).await,
             Event::MemberAdd(e) if e.member.user.id == MISS_ANALYZER_ID => {
@@ -0,0 +1,214 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    future::Future,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use eyre::Result;
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+use super::Context;
+
+/// How long a flag read from redis is trusted before it's fetched again.
+const MEMO_DURATION: Duration = Duration::from_secs(10);
+
+/// Upstream integrations that can be disabled at runtime.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, CommandOption, CreateOption)]
+pub enum Service {
+    #[option(name = "huismetbenen", value = "huismetbenen")]
+    Huismetbenen,
+    #[option(name = "osustats", value = "osustats")]
+    OsuStats,
+    #[option(name = "ordr", value = "ordr")]
+    Ordr,
+    #[option(name = "twitch", value = "twitch")]
+    Twitch,
+}
+
+impl Service {
+    const COUNT: usize = 4;
+
+    /// Name of the service's flag in redis.
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::Huismetbenen => "huismetbenen",
+            Self::OsuStats => "osustats",
+            Self::Ordr => "ordr",
+            Self::Twitch => "twitch",
+        }
+    }
+
+    const fn idx(self) -> usize {
+        self as usize
+    }
+}
+
+impl Display for Service {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let name = match self {
+            Self::Huismetbenen => "huismetbenen",
+            Self::OsuStats => "osu!stats",
+            Self::Ordr => "o!rdr",
+            Self::Twitch => "twitch",
+        };
+
+        f.write_str(name)
+    }
+}
+
+#[derive(Copy, Clone)]
+struct CachedFlag {
+    disabled: bool,
+    fetched_at: Instant,
+}
+
+/// Memoizes the kill-switches stored in redis so that command entry points
+/// only do a redis roundtrip every few seconds.
+pub struct FeatureFlags {
+    flags: [Mutex<Option<CachedFlag>>; Service::COUNT],
+}
+
+impl FeatureFlags {
+    pub const fn new() -> Self {
+        Self {
+            flags: [const { Mutex::new(None) }; Service::COUNT],
+        }
+    }
+
+    /// The notice to respond with if the service's integration is disabled.
+    pub async fn disabled_notice(&self, service: Service) -> Option<String> {
+        let disabled = self
+            .is_disabled(service, Instant::now(), |service| {
+                Context::cache().feature_disabled(service.key())
+            })
+            .await;
+
+        disabled.then(|| format!("The {service} integration is temporarily disabled"))
+    }
+
+    /// Overwrite the memoized flag so that a toggle applies right away.
+    pub fn set(&self, service: Service, disabled: bool, now: Instant) {
+        *self.flags[service.idx()].lock().unwrap() = Some(CachedFlag {
+            disabled,
+            fetched_at: now,
+        });
+    }
+
+    /// Whether the service is disabled, only calling `fetch` if the memoized
+    /// flag is outdated.
+    ///
+    /// If fetching fails, the service is considered enabled so that a redis
+    /// hiccup doesn't take down the commands.
+    async fn is_disabled<F, Fut>(&self, service: Service, now: Instant, fetch: F) -> bool
+    where
+        F: FnOnce(Service) -> Fut,
+        Fut: Future<Output = Result<bool>>,
+    {
+        let cached = *self.flags[service.idx()].lock().unwrap();
+
+        if let Some(flag) = cached.filter(|flag| !is_outdated(flag.fetched_at, now)) {
+            return flag.disabled;
+        }
+
+        let disabled = match fetch(service).await {
+            Ok(disabled) => disabled,
+            Err(err) => {
+                warn!(?err, %service, "Failed to check feature flag");
+
+                false
+            }
+        };
+
+        self.set(service, disabled, now);
+
+        disabled
+    }
+}
+
+fn is_outdated(fetched_at: Instant, now: Instant) -> bool {
+    now.saturating_duration_since(fetched_at) >= MEMO_DURATION
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use eyre::eyre;
+
+    use super::*;
+
+    async fn check(
+        flags: &FeatureFlags,
+        now: Instant,
+        calls: &AtomicUsize,
+        response: Result<bool>,
+    ) -> bool {
+        flags
+            .is_disabled(Service::OsuStats, now, |_| async {
+                calls.fetch_add(1, Ordering::Relaxed);
+
+                response
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn memoizes_within_duration() {
+        let flags = FeatureFlags::new();
+        let calls = AtomicUsize::new(0);
+        let start = Instant::now();
+
+        assert!(check(&flags, start, &calls, Ok(true)).await);
+
+        let later = start + MEMO_DURATION - Duration::from_secs(1);
+        assert!(check(&flags, later, &calls, Ok(false)).await);
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn refetches_after_duration() {
+        let flags = FeatureFlags::new();
+        let calls = AtomicUsize::new(0);
+        let start = Instant::now();
+
+        assert!(check(&flags, start, &calls, Ok(true)).await);
+
+        // e.g. the flag expired in redis in the meantime
+        let later = start + MEMO_DURATION;
+        assert!(!check(&flags, later, &calls, Ok(false)).await);
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn set_applies_immediately() {
+        let flags = FeatureFlags::new();
+        let calls = AtomicUsize::new(0);
+        let start = Instant::now();
+
+        assert!(!check(&flags, start, &calls, Ok(false)).await);
+        flags.set(Service::OsuStats, true, start);
+        assert!(check(&flags, start, &calls, Ok(false)).await);
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn services_are_independent() {
+        let flags = FeatureFlags::new();
+        let now = Instant::now();
+        flags.set(Service::Twitch, true, now);
+
+        let calls = AtomicUsize::new(0);
+        assert!(!check(&flags, now, &calls, Ok(false)).await);
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn fetch_error_fails_open() {
+        let flags = FeatureFlags::new();
+        let calls = AtomicUsize::new(0);
+        let now = Instant::now();
+
+        assert!(!check(&flags, now, &calls, Err(eyre!("redis down"))).await);
+    }
+}
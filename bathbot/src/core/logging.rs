@@ -2,7 +2,7 @@ use std::fmt::Result as FmtResult;
 
 use bathbot_util::datetime::NAIVE_DATETIME_FORMAT;
 use time::format_description::FormatItem;
-use tracing::{Event, Subscriber, level_filters::LevelFilter};
+use tracing::{Event, Span, Subscriber, level_filters::LevelFilter};
 use tracing_appender::{
     non_blocking::{NonBlocking, WorkerGuard},
     rolling::{RollingFileAppender, Rotation},
@@ -11,7 +11,7 @@ use tracing_subscriber::{
     EnvFilter, Layer as _,
     filter::Targets,
     fmt::{
-        FmtContext, FormatEvent, FormatFields, Layer,
+        FmtContext, FormatEvent, FormatFields, FormattedFields, Layer,
         format::Writer,
         time::{FormatTime, UtcTime},
     },
@@ -19,6 +19,10 @@ use tracing_subscriber::{
     registry::LookupSpan,
     util::SubscriberInitExt,
 };
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+};
 
 pub fn init() -> Box<[WorkerGuard]> {
     let stdout_filter: EnvFilter = "bathbot=debug,sqlx=warn,tracking=off,info".parse().unwrap();
@@ -91,6 +95,68 @@ pub fn init() -> Box<[WorkerGuard]> {
     vec![file_guard, tracking_guard].into_boxed_slice()
 }
 
+/// Span to wrap the processing of a command in.
+///
+/// Events within the span, including those of spawned tasks that are
+/// instrumented with it, will be logged alongside the span's fields.
+pub fn command_span(
+    command: &str,
+    guild_id: Option<Id<GuildMarker>>,
+    channel_id: Id<ChannelMarker>,
+    user_id: Option<Id<UserMarker>>,
+) -> Span {
+    info_span!(
+        "command",
+        command,
+        guild_id = guild_id.map(Id::get),
+        channel_id = channel_id.get(),
+        user_id = user_id.map(Id::get),
+    )
+}
+
+/// Span to wrap the processing of a message component in.
+pub fn component_span(
+    custom_id: &str,
+    guild_id: Option<Id<GuildMarker>>,
+    channel_id: Id<ChannelMarker>,
+    user_id: Option<Id<UserMarker>>,
+) -> Span {
+    info_span!(
+        "component",
+        custom_id,
+        guild_id = guild_id.map(Id::get),
+        channel_id = channel_id.get(),
+        user_id = user_id.map(Id::get),
+    )
+}
+
+/// Write the fields of all of our own spans that contain the event.
+fn write_spans<S, N>(ctx: &FmtContext<'_, S, N>, writer: &mut Writer<'_>) -> FmtResult
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    let Some(scope) = ctx.event_scope() else {
+        return Ok(());
+    };
+
+    // Spans of dependencies would only clutter the logs
+    let spans = scope
+        .from_root()
+        .filter(|span| span.metadata().target().starts_with("bathbot"));
+
+    for span in spans {
+        let extensions = span.extensions();
+
+        match extensions.get::<FormattedFields<N>>() {
+            Some(fields) if !fields.is_empty() => write!(writer, "{}{{{fields}}}: ", span.name())?,
+            Some(_) | None => write!(writer, "{}: ", span.name())?,
+        }
+    }
+
+    Ok(())
+}
+
 struct StdoutEventFormat {
     timer: UtcTime<&'static [FormatItem<'static>]>,
 }
@@ -118,6 +184,7 @@ where
         let metadata = event.metadata();
 
         write!(writer, " {:>5} ", metadata.level(),)?;
+        write_spans(ctx, &mut writer)?;
 
         ctx.field_format().format_fields(writer.by_ref(), event)?;
 
@@ -162,8 +229,107 @@ where
             }
         }
 
+        write_spans(ctx, &mut writer)?;
+
         ctx.field_format().format_fields(writer.by_ref(), event)?;
 
         writeln!(writer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Result as IoResult, Write},
+        sync::{Arc, Mutex},
+    };
+
+    use tracing::Instrument;
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Buffer {
+        fn logs(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> IoResult<()> {
+            Ok(())
+        }
+    }
+
+    fn subscriber(buf: &Buffer) -> impl Subscriber + Send + Sync + use<> {
+        let writer = buf.clone();
+
+        let layer = Layer::default()
+            .event_format(FileEventFormat::<true>::default())
+            .with_writer(move || writer.clone());
+
+        tracing_subscriber::registry().with(layer)
+    }
+
+    #[test]
+    fn nested_event_contains_command_fields() {
+        let buf = Buffer::default();
+
+        tracing::subscriber::with_default(subscriber(&buf), || {
+            let span = command_span("rs", Some(Id::new(1)), Id::new(2), Some(Id::new(3)));
+            let _entered = span.enter();
+
+            info_span!("nested").in_scope(|| warn!("Failed to get user"));
+        });
+
+        let logs = buf.logs();
+
+        assert!(logs.contains("Failed to get user"), "{logs}");
+        assert!(logs.contains(r#"command="rs""#), "{logs}");
+        assert!(logs.contains("guild_id=1"), "{logs}");
+        assert!(logs.contains("channel_id=2"), "{logs}");
+        assert!(logs.contains("user_id=3"), "{logs}");
+    }
+
+    #[test]
+    fn missing_fields_are_omitted() {
+        let buf = Buffer::default();
+
+        tracing::subscriber::with_default(subscriber(&buf), || {
+            command_span("help", None, Id::new(2), None).in_scope(|| warn!("In DMs"));
+        });
+
+        let logs = buf.logs();
+
+        assert!(logs.contains("channel_id=2"), "{logs}");
+        assert!(!logs.contains("guild_id"), "{logs}");
+        assert!(!logs.contains("user_id"), "{logs}");
+    }
+
+    #[tokio::test]
+    async fn spawned_task_keeps_command_fields() {
+        let buf = Buffer::default();
+        let _guard = tracing::subscriber::set_default(subscriber(&buf));
+
+        let span = command_span("render", None, Id::new(2), Some(Id::new(3)));
+
+        let handle = async { tokio::spawn(async { warn!("Failed to render") }.in_current_span()) }
+            .instrument(span)
+            .await;
+
+        handle.await.unwrap();
+
+        let logs = buf.logs();
+
+        assert!(logs.contains("Failed to render"), "{logs}");
+        assert!(logs.contains(r#"command="render""#), "{logs}");
+    }
+}
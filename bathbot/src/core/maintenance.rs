@@ -0,0 +1,171 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::{
+        RwLock,
+        atomic::{AtomicI64, Ordering},
+    },
+};
+
+use super::Context;
+
+/// Mirrors the maintenance mode that is stored in redis so that commands can
+/// be checked without a redis roundtrip.
+pub struct Maintenance {
+    /// Unix timestamp in seconds at which maintenance mode ends, `0` if it's
+    /// not active
+    ends_at: AtomicI64,
+    message: RwLock<Option<Box<str>>>,
+}
+
+/// How maintenance mode changed through [`Maintenance::update`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MaintenanceChange {
+    Unchanged,
+    Started,
+    Ended,
+}
+
+impl MaintenanceChange {
+    /// Let the bot's presence indicate whether maintenance mode is active.
+    pub fn update_presence(self) {
+        if self != Self::Unchanged {
+            Context::update_presence();
+        }
+    }
+}
+
+impl Maintenance {
+    pub const fn new() -> Self {
+        Self {
+            ends_at: AtomicI64::new(0),
+            message: RwLock::new(None),
+        }
+    }
+
+    /// Store the state fetched from redis i.e. the message and the remaining
+    /// seconds, and return how it changed.
+    pub fn update(&self, state: Option<(String, u64)>, now: i64) -> MaintenanceChange {
+        let ends_at = match state {
+            Some((message, remaining)) => {
+                let message = (!message.is_empty()).then(|| message.into_boxed_str());
+                *self.message.write().unwrap() = message;
+
+                now + remaining as i64
+            }
+            None => {
+                *self.message.write().unwrap() = None;
+
+                0
+            }
+        };
+
+        match (self.ends_at.swap(ends_at, Ordering::Relaxed), ends_at) {
+            (0, 0) => MaintenanceChange::Unchanged,
+            (0, _) => MaintenanceChange::Started,
+            (_, 0) => MaintenanceChange::Ended,
+            (_, _) => MaintenanceChange::Unchanged,
+        }
+    }
+
+    /// Whether maintenance mode is currently active.
+    pub fn is_active(&self) -> bool {
+        self.ends_at.load(Ordering::Relaxed) != 0
+    }
+
+    /// The notice to respond with if a command of the user should be turned
+    /// away.
+    pub fn notice(&self, is_owner: bool, now: i64) -> Option<MaintenanceNotice> {
+        let ends_at = self.ends_at.load(Ordering::Relaxed);
+
+        if !is_blocked(ends_at, now, is_owner) {
+            return None;
+        }
+
+        Some(MaintenanceNotice {
+            message: self.message.read().unwrap().clone(),
+            remaining_minutes: remaining_minutes(ends_at, now),
+        })
+    }
+}
+
+/// The bot owner can still use commands, e.g. to end maintenance mode early.
+fn is_blocked(ends_at: i64, now: i64, is_owner: bool) -> bool {
+    ends_at > now && !is_owner
+}
+
+/// Rounded up so that a few remaining seconds don't show as zero minutes.
+fn remaining_minutes(ends_at: i64, now: i64) -> u64 {
+    (ends_at.saturating_sub(now).max(0) as u64).div_ceil(60)
+}
+
+pub struct MaintenanceNotice {
+    message: Option<Box<str>>,
+    remaining_minutes: u64,
+}
+
+impl Display for MaintenanceNotice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "🔧 Undergoing maintenance, back in ~{} minute{}",
+            self.remaining_minutes,
+            if self.remaining_minutes == 1 { "" } else { "s" }
+        )?;
+
+        if let Some(ref message) = self.message {
+            write!(f, "\n{message}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    #[test]
+    fn gate_decision() {
+        assert!(is_blocked(NOW + 60, NOW, false));
+        assert!(!is_blocked(NOW + 60, NOW, true));
+
+        // Expired but not yet cleared by the watcher
+        assert!(!is_blocked(NOW - 1, NOW, false));
+        assert!(!is_blocked(0, NOW, false));
+    }
+
+    #[test]
+    fn remaining_time() {
+        assert_eq!(remaining_minutes(NOW + 600, NOW), 10);
+        assert_eq!(remaining_minutes(NOW + 601, NOW), 11);
+        assert_eq!(remaining_minutes(NOW + 1, NOW), 1);
+        assert_eq!(remaining_minutes(NOW, NOW), 0);
+        assert_eq!(remaining_minutes(NOW - 30, NOW), 0);
+    }
+
+    #[test]
+    fn transitions() {
+        let maintenance = Maintenance::new();
+        assert!(maintenance.notice(false, NOW).is_none());
+
+        let state = Some(("Deploying".to_owned(), 90));
+        assert_eq!(
+            maintenance.update(state.clone(), NOW),
+            MaintenanceChange::Started
+        );
+        assert_eq!(maintenance.update(state, NOW), MaintenanceChange::Unchanged);
+
+        let notice = maintenance.notice(false, NOW).unwrap();
+        assert_eq!(
+            notice.to_string(),
+            "🔧 Undergoing maintenance, back in ~2 minutes\nDeploying"
+        );
+        assert!(maintenance.notice(true, NOW).is_none());
+
+        assert_eq!(maintenance.update(None, NOW), MaintenanceChange::Ended);
+        assert_eq!(maintenance.update(None, NOW), MaintenanceChange::Unchanged);
+        assert!(maintenance.notice(false, NOW).is_none());
+    }
+}
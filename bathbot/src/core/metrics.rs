@@ -8,12 +8,25 @@ use metrics::{
 use rosu_v2::model::GameMode;
 use twilight_gateway::Event;
 
+use super::commands::outcome::CommandOutcome;
+
 const GATEWAY_EVENTS: &str = "gateway_events";
 const COMMANDS_PROCESS_TIME: &str = "commands_process_time";
 const COMMAND_ERRORS: &str = "command_errors";
+const COMMAND_OUTCOMES: &str = "command_outcomes";
 const CACHE_ENTRIES: &str = "cache_entries";
 const REDIS_CACHE_HITS: &str = "redis_cache_hits";
+const DIFFICULTY_CACHE_HITS: &str = "difficulty_cache_hits";
 const OSU_TRACKING_HIT: &str = "osu_tracking_hit";
+const OSU_TRACKING_FETCHES_SAVED: &str = "osu_tracking_fetches_saved";
+const ORPHANED_ACTIVE_MESSAGES: &str = "orphaned_active_messages";
+const STATS_REFRESHED: &str = "stats_refreshed";
+const STATS_REFRESH_PAUSED: &str = "stats_refresh_paused";
+const STATS_REFRESH_STALENESS: &str = "stats_refresh_staleness";
+const APPROX_CURVE_AGE: &str = "approx_curve_age";
+const BACKGROUND_LOOP_RESTARTS: &str = "background_loop_restarts";
+const RESPEKTIVE_ERRORS: &str = "respektive_errors";
+const MEMBER_REQUESTS_ABANDONED: &str = "member_requests_abandoned";
 
 pub struct BotMetrics;
 
@@ -30,12 +43,69 @@ impl BotMetrics {
             Unit::Count,
             "Number of times a command failed"
         );
+        describe_counter!(
+            COMMAND_OUTCOMES,
+            Unit::Count,
+            "Number of executed commands by whether they succeeded, responded with an error, \
+            or failed internally"
+        );
         describe_gauge!(CACHE_ENTRIES, Unit::Count, "Number of cache entries");
+        describe_counter!(
+            ORPHANED_ACTIVE_MESSAGES,
+            Unit::Count,
+            "Number of active messages from before a restart whose components were removed"
+        );
+        describe_counter!(
+            STATS_REFRESHED,
+            Unit::Count,
+            "Number of users whose stats were refreshed in the background"
+        );
+        describe_gauge!(
+            STATS_REFRESH_PAUSED,
+            "Whether the background stats refresh is paused due to API pressure"
+        );
+        describe_histogram!(
+            STATS_REFRESH_STALENESS,
+            Unit::Seconds,
+            "Age of user stats at the time they were refreshed in the background"
+        );
+        describe_gauge!(
+            APPROX_CURVE_AGE,
+            Unit::Seconds,
+            "Age of the sampled rank-pp curve used to approximate pp"
+        );
+        describe_counter!(
+            BACKGROUND_LOOP_RESTARTS,
+            Unit::Count,
+            "Number of times a background loop was restarted by the watchdog"
+        );
+        describe_counter!(
+            RESPEKTIVE_ERRORS,
+            Unit::Count,
+            "Number of failed or timed out respektive requests"
+        );
+        describe_counter!(
+            MEMBER_REQUESTS_ABANDONED,
+            Unit::Count,
+            "Number of guilds whose member request was given up on after repeated failures"
+        );
+        describe_counter!(
+            OSU_TRACKING_FETCHES_SAVED,
+            Unit::Count,
+            "Number of osu!api fetches saved by processing a tracked score once for all \
+            subscribed channels"
+        );
         describe_counter!(
             REDIS_CACHE_HITS,
             Unit::Count,
             "Number of times redis contained a cached value"
         );
+        describe_counter!(
+            DIFFICULTY_CACHE_HITS,
+            Unit::Count,
+            "Number of reused difficulty attributes, split by whether the mods matched exactly \
+            or only after canonicalization"
+        );
 
         let stats = cache.stats();
 
@@ -65,6 +135,19 @@ impl BotMetrics {
         .increment(1);
     }
 
+    pub fn inc_command_outcome(
+        kind: &'static str,
+        name: impl Into<SharedString>,
+        outcome: CommandOutcome,
+    ) {
+        counter!(COMMAND_OUTCOMES,
+            "kind" => kind,
+            "name" => name,
+            "outcome" => outcome.as_str()
+        )
+        .increment(1);
+    }
+
     pub fn observe_command(kind: &'static str, name: impl Into<SharedString>, duration: Duration) {
         histogram!(COMMANDS_PROCESS_TIME, "kind" => kind, "name" => name).record(duration);
     }
@@ -89,10 +172,52 @@ impl BotMetrics {
         counter!(OSU_TRACKING_HIT, "mode" => mode.as_str()).increment(1);
     }
 
+    pub fn inc_osu_tracking_fetches_saved(mode: GameMode, saved: u64) {
+        counter!(OSU_TRACKING_FETCHES_SAVED, "mode" => mode.as_str()).increment(saved);
+    }
+
+    pub fn inc_orphaned_active_message(cleaned: bool) {
+        let result = if cleaned { "cleaned" } else { "failed" };
+        counter!(ORPHANED_ACTIVE_MESSAGES, "result" => result).increment(1);
+    }
+
+    pub fn inc_stats_refresh(result: &'static str) {
+        counter!(STATS_REFRESHED, "result" => result).increment(1);
+    }
+
+    pub fn set_stats_refresh_paused(paused: bool) {
+        gauge!(STATS_REFRESH_PAUSED).set(paused as u8 as f64);
+    }
+
+    pub fn observe_stats_refresh_staleness(seconds: f64) {
+        histogram!(STATS_REFRESH_STALENESS).record(seconds);
+    }
+
+    pub fn set_approx_curve_age(mode: GameMode, seconds: f64) {
+        gauge!(APPROX_CURVE_AGE, "mode" => mode.as_str()).set(seconds);
+    }
+
+    pub fn inc_loop_restart(name: &'static str) {
+        counter!(BACKGROUND_LOOP_RESTARTS, "name" => name).increment(1);
+    }
+
+    pub fn inc_respektive_error(kind: &'static str) {
+        counter!(RESPEKTIVE_ERRORS, "kind" => kind).increment(1);
+    }
+
+    pub fn inc_member_request_give_up() {
+        counter!(MEMBER_REQUESTS_ABANDONED).increment(1);
+    }
+
     pub fn inc_redis_hit(kind: impl Into<SharedString>) {
         counter!(REDIS_CACHE_HITS, "kind" => kind).increment(1);
     }
 
+    pub fn inc_difficulty_cache_hit(canonical: bool) {
+        let key = if canonical { "canonical" } else { "exact" };
+        counter!(DIFFICULTY_CACHE_HITS, "key" => key).increment(1);
+    }
+
     pub fn event(event: &Event, change: Option<CacheChange>) {
         if let Some(change) = change {
             gauge!(CACHE_ENTRIES, "kind" => "Guilds").increment(change.guilds as f64);
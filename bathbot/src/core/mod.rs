@@ -1,14 +1,26 @@
 pub use self::{
+    api_incident::{API_INCIDENT_BANNER, ApiIncident, IncidentChange},
+    command_health::{CommandHealth, ErrorRateAlert},
     config::BotConfig,
-    context::Context,
+    context::{Context, ConvertKey, ConvertValues, RecalcScope},
+    database_health::{DatabaseHealth, HealthChange},
     events::{EventKind, event_loop},
+    feature_flags::{FeatureFlags, Service},
+    maintenance::{Maintenance, MaintenanceChange},
     metrics::BotMetrics,
+    watchdog::{BackgroundLoop, Supervised, Watchdog, watchdog_loop},
 };
 
+mod api_incident;
+mod command_health;
 mod config;
 mod context;
+mod database_health;
 mod events;
+mod feature_flags;
+mod maintenance;
 mod metrics;
+mod watchdog;
 
 pub mod commands;
 pub mod logging;
@@ -0,0 +1,366 @@
+use std::{
+    sync::atomic::{AtomicU32, AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+use futures::FutureExt;
+use tokio::{task::JoinHandle, time::interval};
+
+#[cfg(feature = "matchlive")]
+use super::context::MATCH_LIVE_TICK;
+use super::{BotMetrics, Context};
+use crate::tracking;
+
+/// How often the watchdog checks the heartbeats.
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Multiple of a loop's interval without heartbeat after which the loop is
+/// considered stuck.
+const GRACE_MULTIPLIER: u32 = 3;
+
+/// Minimum time between two restarts of the same loop, doubled on each
+/// further restart.
+const BASE_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Upper bound for the time between two restarts of the same loop.
+const MAX_BACKOFF: Duration = Duration::from_secs(30 * 60);
+
+/// Long-running background loops that are supervised by the watchdog.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BackgroundLoop {
+    TwitchTracking,
+    StatsRefresh,
+    MatchLive,
+    MapsetWatch,
+    ApproxRefresh,
+    DatabaseProbe,
+    TrackingDigest,
+    MaintenanceWatch,
+    CacheReconcile,
+    PruneReport,
+    ErrorRateWatch,
+    ApiIncidentWatch,
+    AuditLogCleanup,
+}
+
+impl BackgroundLoop {
+    const COUNT: usize = 13;
+
+    const ALL: [Self; Self::COUNT] = [
+        Self::TwitchTracking,
+        Self::StatsRefresh,
+        Self::MatchLive,
+        Self::MapsetWatch,
+        Self::ApproxRefresh,
+        Self::DatabaseProbe,
+        Self::TrackingDigest,
+        Self::MaintenanceWatch,
+        Self::CacheReconcile,
+        Self::PruneReport,
+        Self::ErrorRateWatch,
+        Self::ApiIncidentWatch,
+        Self::AuditLogCleanup,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::TwitchTracking => "twitch_tracking",
+            Self::StatsRefresh => "stats_refresh",
+            Self::MatchLive => "match_live",
+            Self::MapsetWatch => "mapset_watch",
+            Self::ApproxRefresh => "approx_refresh",
+            Self::DatabaseProbe => "database_probe",
+            Self::TrackingDigest => "tracking_digest",
+            Self::MaintenanceWatch => "maintenance_watch",
+            Self::CacheReconcile => "cache_reconcile",
+            Self::PruneReport => "prune_report",
+            Self::ErrorRateWatch => "error_rate_watch",
+            Self::ApiIncidentWatch => "api_incident_watch",
+            Self::AuditLogCleanup => "audit_log_cleanup",
+        }
+    }
+
+    /// How often a healthy loop beats.
+    fn interval(self) -> Duration {
+        match self {
+            #[cfg(feature = "twitchtracking")]
+            Self::TwitchTracking => tracking::TWITCH_TRACKING_TICK,
+            #[cfg(feature = "statsrefresh")]
+            Self::StatsRefresh => tracking::STATS_REFRESH_TICK,
+            #[cfg(feature = "matchlive")]
+            Self::MatchLive => MATCH_LIVE_TICK,
+            Self::MapsetWatch => tracking::MAPSET_WATCH_TICK,
+            Self::ApproxRefresh => tracking::APPROX_REFRESH_TICK,
+            Self::DatabaseProbe => tracking::DATABASE_PROBE_TICK,
+            Self::TrackingDigest => tracking::TRACKING_DIGEST_TICK,
+            Self::MaintenanceWatch => tracking::MAINTENANCE_WATCH_TICK,
+            Self::CacheReconcile => tracking::CACHE_RECONCILE_TICK,
+            Self::PruneReport => tracking::PRUNE_REPORT_TICK,
+            Self::ErrorRateWatch => tracking::ERROR_RATE_WATCH_TICK,
+            Self::ApiIncidentWatch => tracking::API_INCIDENT_WATCH_TICK,
+            Self::AuditLogCleanup => tracking::AUDIT_LOG_CLEANUP_TICK,
+            // Loops of disabled features are never supervised
+            #[allow(unreachable_patterns)]
+            Self::TwitchTracking | Self::StatsRefresh | Self::MatchLive => CHECK_INTERVAL,
+        }
+    }
+}
+
+/// Heartbeats and restart counts of the background loops.
+pub struct Watchdog {
+    start: Instant,
+    /// Milliseconds since `start` of each loop's last heartbeat
+    heartbeats: [AtomicU64; BackgroundLoop::COUNT],
+    restarts: [AtomicU32; BackgroundLoop::COUNT],
+}
+
+impl Watchdog {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            heartbeats: Default::default(),
+            restarts: Default::default(),
+        }
+    }
+
+    /// Signal that the loop is still making progress.
+    pub fn beat(&self, kind: BackgroundLoop) {
+        let elapsed = self.start.elapsed().as_millis() as u64;
+        self.heartbeats[kind as usize].store(elapsed, Ordering::Relaxed);
+    }
+
+    /// Loops that were restarted since the bot started and how often.
+    pub fn restarts(&self) -> impl Iterator<Item = (BackgroundLoop, u32)> + '_ {
+        BackgroundLoop::ALL.into_iter().filter_map(|kind| {
+            let count = self.restarts[kind as usize].load(Ordering::Relaxed);
+
+            (count > 0).then_some((kind, count))
+        })
+    }
+
+    fn since_beat(&self, kind: BackgroundLoop, now: Instant) -> Duration {
+        let beat = self.heartbeats[kind as usize].load(Ordering::Relaxed);
+
+        now.saturating_duration_since(self.start + Duration::from_millis(beat))
+    }
+
+    fn record_restart(&self, kind: BackgroundLoop) {
+        self.restarts[kind as usize].fetch_add(1, Ordering::Relaxed);
+        BotMetrics::inc_loop_restart(kind.name());
+    }
+}
+
+/// A background loop and how to spawn it.
+pub struct Supervised {
+    kind: BackgroundLoop,
+    spawn: fn() -> JoinHandle<()>,
+}
+
+impl Supervised {
+    pub fn new(kind: BackgroundLoop, spawn: fn() -> JoinHandle<()>) -> Self {
+        Self { kind, spawn }
+    }
+}
+
+struct Entry {
+    supervised: Supervised,
+    /// `None` if the task ended but could not be restarted yet
+    handle: Option<JoinHandle<()>>,
+    state: RestartState,
+}
+
+impl Entry {
+    fn spawn(&mut self) {
+        Context::watchdog().beat(self.supervised.kind);
+        self.handle = Some((self.supervised.spawn)());
+    }
+}
+
+/// Spawns the given loops and restarts them whenever they panic or stop
+/// beating.
+#[cold]
+pub async fn watchdog_loop(loops: Vec<Supervised>) {
+    let watchdog = Context::watchdog();
+
+    let mut entries: Vec<_> = loops
+        .into_iter()
+        .map(|supervised| Entry {
+            supervised,
+            handle: None,
+            state: RestartState::default(),
+        })
+        .collect();
+
+    entries.iter_mut().for_each(Entry::spawn);
+
+    let mut interval = interval(CHECK_INTERVAL);
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+
+        let now = Instant::now();
+
+        entries.retain_mut(|entry| {
+            let kind = entry.supervised.kind;
+
+            let finished = entry.handle.as_ref().is_some_and(JoinHandle::is_finished);
+
+            if finished {
+                let res = entry.handle.take().and_then(FutureExt::now_or_never);
+
+                match res {
+                    // Loops may return on purpose e.g. if they're disabled
+                    Some(Ok(())) => {
+                        info!(name = kind.name(), "Background loop ended");
+
+                        return false;
+                    }
+                    Some(Err(err)) => {
+                        error!(name = kind.name(), ?err, "Background loop died");
+                    }
+                    None => {}
+                }
+            }
+
+            let since_beat = watchdog.since_beat(kind, now);
+            let ended = entry.handle.is_none();
+
+            match entry.state.decide(kind.interval(), since_beat, ended, now) {
+                Action::Keep => {}
+                Action::Wait => {
+                    debug!(
+                        name = kind.name(),
+                        "Waiting before restarting background loop"
+                    );
+                }
+                Action::Restart => {
+                    if let Some(handle) = entry.handle.take() {
+                        warn!(
+                            name = kind.name(),
+                            ?since_beat,
+                            "Background loop is stuck, restarting"
+                        );
+
+                        handle.abort();
+                    }
+
+                    entry.state.record(now);
+                    watchdog.record_restart(kind);
+                    entry.spawn();
+                }
+            }
+
+            true
+        });
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Action {
+    Keep,
+    Restart,
+    /// Needs a restart but the last one was too recent
+    Wait,
+}
+
+#[derive(Default)]
+struct RestartState {
+    restarts: u32,
+    last_restart: Option<Instant>,
+}
+
+impl RestartState {
+    fn decide(
+        &self,
+        interval: Duration,
+        since_beat: Duration,
+        ended: bool,
+        now: Instant,
+    ) -> Action {
+        if !ended && since_beat <= interval * GRACE_MULTIPLIER {
+            return Action::Keep;
+        }
+
+        match self.last_restart {
+            Some(last) if now.saturating_duration_since(last) < self.backoff() => Action::Wait,
+            _ => Action::Restart,
+        }
+    }
+
+    fn backoff(&self) -> Duration {
+        match self.restarts {
+            0 => Duration::ZERO,
+            n => BASE_BACKOFF
+                .saturating_mul(1 << (n - 1).min(16))
+                .min(MAX_BACKOFF),
+        }
+    }
+
+    fn record(&mut self, now: Instant) {
+        self.restarts += 1;
+        self.last_restart = Some(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INTERVAL: Duration = Duration::from_secs(10);
+
+    #[test]
+    fn healthy_within_grace() {
+        let state = RestartState::default();
+        let now = Instant::now();
+
+        let action = state.decide(INTERVAL, INTERVAL * GRACE_MULTIPLIER, false, now);
+        assert_eq!(action, Action::Keep);
+
+        let action = state.decide(INTERVAL, INTERVAL * 4, false, now);
+        assert_eq!(action, Action::Restart);
+    }
+
+    #[test]
+    fn ended_loops_restart() {
+        let state = RestartState::default();
+
+        let action = state.decide(INTERVAL, Duration::ZERO, true, Instant::now());
+        assert_eq!(action, Action::Restart);
+    }
+
+    #[test]
+    fn restarts_back_off() {
+        let mut state = RestartState::default();
+        let now = Instant::now();
+        let stuck = INTERVAL * 10;
+
+        state.record(now);
+        assert_eq!(state.backoff(), BASE_BACKOFF);
+        assert_eq!(state.decide(INTERVAL, stuck, false, now), Action::Wait);
+
+        let later = now + BASE_BACKOFF;
+        assert_eq!(state.decide(INTERVAL, stuck, false, later), Action::Restart);
+
+        state.record(later);
+        assert_eq!(state.backoff(), BASE_BACKOFF * 2);
+
+        let even_later = later + BASE_BACKOFF;
+        assert_eq!(
+            state.decide(INTERVAL, stuck, true, even_later),
+            Action::Wait
+        );
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let mut state = RestartState::default();
+        let now = Instant::now();
+
+        for _ in 0..40 {
+            state.record(now);
+        }
+
+        assert_eq!(state.backoff(), MAX_BACKOFF);
+    }
+}
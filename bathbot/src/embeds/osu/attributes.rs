@@ -1,11 +1,21 @@
 use std::fmt::Write;
 
 use bathbot_macros::EmbedData;
-use bathbot_util::{numbers::round, osu::AttributeKind};
-use rosu_pp::model::beatmap::BeatmapAttributesBuilder;
-use rosu_v2::prelude::GameModsIntermode;
+use bathbot_util::{
+    numbers::round,
+    osu::{
+        AttributeKind,
+        derived_attributes::{
+            ManiaHitWindows, OsuHitWindows, TaikoHitWindows, catcher_width, fade_in, preempt,
+        },
+    },
+};
+use rosu_pp::model::beatmap::{BeatmapAttributes, BeatmapAttributesBuilder};
+use rosu_v2::prelude::{GameMode, GameModsIntermode};
 use twilight_model::channel::message::embed::EmbedField;
 
+use crate::commands::osu::parsed_map::OsuFileMetadata;
+
 #[derive(EmbedData)]
 pub struct AttributesEmbed {
     fields: Vec<EmbedField>,
@@ -18,21 +28,29 @@ impl AttributesEmbed {
         value: f32,
         mods: GameModsIntermode,
         clock_rate: Option<f32>,
+        mode: GameMode,
+        map: Option<&OsuFileMetadata>,
     ) -> Self {
-        let mut builder = BeatmapAttributesBuilder::default().mods(&mods);
+        let new_builder = || {
+            let builder = BeatmapAttributesBuilder::default()
+                .mods(&mods)
+                .mode((mode as u8).into(), false);
 
-        builder = match kind {
-            AttributeKind::Ar => builder.ar(value, false),
-            AttributeKind::Cs => builder.cs(value, false),
-            AttributeKind::Hp => builder.hp(value, false),
-            AttributeKind::Od => builder.od(value, false),
+            match kind {
+                AttributeKind::Ar => builder.ar(value, false),
+                AttributeKind::Cs => builder.cs(value, false),
+                AttributeKind::Hp => builder.hp(value, false),
+                AttributeKind::Od => builder.od(value, false),
+            }
         };
 
+        let mut builder = new_builder();
+
         if let Some(clock_rate) = clock_rate {
             builder = builder.clock_rate(clock_rate as f64);
         }
 
-        let title = format!(
+        let mut title = format!(
             "Adjusting {}",
             match kind {
                 AttributeKind::Ar => "AR",
@@ -42,6 +60,10 @@ impl AttributesEmbed {
             }
         );
 
+        if let Some(map) = map {
+            let _ = write!(title, " of {map} (unsubmitted map)");
+        }
+
         let nm_field = EmbedField {
             inline: true,
             name: "NM".to_owned(),
@@ -79,8 +101,86 @@ impl AttributesEmbed {
             let _ = write!(mods_field.value, " ({}ms)", round(ms as f32));
         }
 
-        let fields = vec![nm_field, mods_field];
+        let mut fields = vec![nm_field, mods_field];
+
+        // Derived values use attributes without clock rate adjustments and
+        // apply the clock rate themselves
+        let unscaled = new_builder().clock_rate(1.0).build();
+
+        if let Some(field) = derived_field(kind, mode, &unscaled, attrs.clock_rate) {
+            fields.push(field);
+        }
 
         Self { title, fields }
     }
 }
+
+fn derived_field(
+    kind: AttributeKind,
+    mode: GameMode,
+    attrs: &BeatmapAttributes,
+    clock_rate: f64,
+) -> Option<EmbedField> {
+    let ms = |ms: f64| round(ms as f32);
+
+    let value = match (mode, kind) {
+        (GameMode::Osu, AttributeKind::Ar) => format!(
+            "Preempt: {}ms\nFade-in: {}ms",
+            ms(preempt(attrs.ar, clock_rate)),
+            ms(fade_in(attrs.ar, clock_rate)),
+        ),
+        (GameMode::Osu, AttributeKind::Od) => {
+            let windows = OsuHitWindows::new(attrs.od, clock_rate);
+
+            format!(
+                "300: ±{}ms\n100: ±{}ms\n50: ±{}ms",
+                ms(windows.great),
+                ms(windows.ok),
+                ms(windows.meh),
+            )
+        }
+        (GameMode::Taiko, AttributeKind::Od) => {
+            let windows = TaikoHitWindows::new(attrs.od, clock_rate);
+
+            format!(
+                "Great: ±{}ms\nOk: ±{}ms\nMiss: ±{}ms",
+                ms(windows.great),
+                ms(windows.ok),
+                ms(windows.miss),
+            )
+        }
+        (GameMode::Catch, AttributeKind::Ar) => {
+            format!("Fall time: {}ms", ms(preempt(attrs.ar, clock_rate)))
+        }
+        (GameMode::Catch, AttributeKind::Cs) => {
+            format!("Catcher width: {}px", round(catcher_width(attrs.cs) as f32))
+        }
+        (GameMode::Mania, AttributeKind::Od) => {
+            let windows = ManiaHitWindows::new(attrs.od, clock_rate);
+
+            format!(
+                "MAX: ±{}ms\n300: ±{}ms\n200: ±{}ms\n100: ±{}ms\n50: ±{}ms\nMiss: ±{}ms",
+                ms(windows.perfect),
+                ms(windows.great),
+                ms(windows.good),
+                ms(windows.ok),
+                ms(windows.meh),
+                ms(windows.miss),
+            )
+        }
+        _ => return None,
+    };
+
+    let name = match mode {
+        GameMode::Osu => "osu!standard",
+        GameMode::Taiko => "osu!taiko",
+        GameMode::Catch => "osu!catch",
+        GameMode::Mania => "osu!mania",
+    };
+
+    Some(EmbedField {
+        inline: false,
+        name: name.to_owned(),
+        value,
+    })
+}
@@ -20,7 +20,10 @@ use rosu_v2::prelude::{
 use smallvec::SmallVec;
 use twilight_model::channel::message::embed::Embed;
 
-use crate::util::{Emote, osu::grade_emote};
+use crate::{
+    matchlive::MatchProgress,
+    util::{Emote, osu::grade_emote},
+};
 
 const DESCRIPTION_BUFFER: usize = 45;
 
@@ -88,7 +91,7 @@ macro_rules! team {
 }
 
 impl MatchLiveEmbed {
-    pub fn new(lobby: &OsuMatch) -> MatchLiveEmbeds {
+    pub fn new(lobby: &OsuMatch, progress: &mut MatchProgress) -> MatchLiveEmbeds {
         let mut embeds = MatchLiveEmbeds::new();
 
         if lobby.events.is_empty() {
@@ -149,7 +152,7 @@ impl MatchLiveEmbed {
                         embeds.pop();
                     }
 
-                    let (description, image, footer) = game_content(lobby, game);
+                    let (description, image, footer) = game_content(lobby, game, progress);
                     state = Some(next_state);
 
                     let embed = Self {
@@ -209,7 +212,11 @@ impl MatchLiveEmbed {
         embeds
     }
 
-    pub fn update(&mut self, lobby: &OsuMatch) -> (bool, Option<MatchLiveEmbeds>) {
+    pub fn update(
+        &mut self,
+        lobby: &OsuMatch,
+        progress: &mut MatchProgress,
+    ) -> (bool, Option<MatchLiveEmbeds>) {
         if lobby.events.is_empty() {
             return (false, None);
         }
@@ -278,7 +285,7 @@ impl MatchLiveEmbed {
                             continue;
                         }
 
-                        let (description, image, footer) = game_content(lobby, game);
+                        let (description, image, footer) = game_content(lobby, game, progress);
 
                         // Previous game not yet finished but next one already there => override
                         if !state.finished {
@@ -368,7 +375,7 @@ impl MatchLiveEmbed {
                         embed.description.push_str("• **Lobby was closed**")
                     }
                     MatchEvent::Game { game, .. } => {
-                        let (description, image, footer) = game_content(lobby, game);
+                        let (description, image, footer) = game_content(lobby, game, progress);
 
                         let state = GameState {
                             game_id: game.game_id,
@@ -433,6 +440,18 @@ impl MatchLiveEmbed {
         )
     }
 
+    /// Final summary of all finished maps of the match
+    pub fn summary(lobby: &OsuMatch, progress: &MatchProgress) -> Self {
+        Self {
+            title: format!("Summary: {}", lobby.name.as_str().cow_escape_markdown()),
+            url: format!("{OSU_BASE}community/matches/{}", lobby.match_id),
+            description: progress.summary(),
+            image: None,
+            footer: None,
+            state: None,
+        }
+    }
+
     pub fn as_embed(&self) -> Embed {
         let mut builder = EmbedBuilder::new()
             .description(&self.description)
@@ -453,10 +472,13 @@ impl MatchLiveEmbed {
     }
 }
 
-/// Return the description and image for a either in-progress or finished games
+/// Return the description and image for a either in-progress or finished games.
+///
+/// Finished games are recorded in the match progress.
 fn game_content(
     lobby: &OsuMatch,
     game: &MatchGame,
+    progress: &mut MatchProgress,
 ) -> (String, Option<String>, Option<FooterBuilder>) {
     let mut description = String::with_capacity(128);
 
@@ -537,6 +559,18 @@ fn game_content(
                 );
             }
 
+            if progress.record_game(game, &lobby.users) {
+                description.push('\n');
+
+                if let Some(mvp) = progress.mvp_line(game.game_id) {
+                    let _ = writeln!(description, "{mvp}");
+                }
+
+                if let Some(score_line) = progress.score_line() {
+                    let _ = writeln!(description, "{score_line}");
+                }
+            }
+
             let footer = team_scores.map(|(blue, red)| {
                 let difference = (blue as i64 - red as i64).abs();
 
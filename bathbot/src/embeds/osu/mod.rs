@@ -8,6 +8,7 @@ mod player_snipe_stats;
 mod pp_missing;
 mod profile_compare;
 mod ratio;
+mod score_versus;
 mod sniped;
 mod whatif;
 
@@ -16,6 +17,7 @@ mod match_live;
 
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+use bathbot_util::osu::CatchHitresults;
 use rosu_v2::prelude::{GameModIntermode, GameMode, GameMods, ScoreStatistics};
 
 #[cfg(feature = "matchlive")]
@@ -23,7 +25,7 @@ pub use self::match_live::*;
 pub use self::{
     attributes::*, claim_name::*, country_snipe_stats::*, fix_score::*, medal_stats::*,
     osustats_counts::*, player_snipe_stats::*, pp_missing::*, profile_compare::*, ratio::*,
-    sniped::*, whatif::*,
+    score_versus::*, sniped::*, whatif::*,
 };
 
 pub struct ComboFormatter {
@@ -132,6 +134,16 @@ impl Display for HitResultFormatter<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.write_str("{")?;
 
+        if self.mode == GameMode::Catch {
+            let hitresults = CatchHitresults::new(self.stats);
+
+            return write!(
+                f,
+                "{}/{}/{}/{}}}",
+                hitresults.fruits, hitresults.droplets, hitresults.tiny_droplets, hitresults.misses
+            );
+        }
+
         if self.mode == GameMode::Mania {
             write!(f, "{}/", self.stats.perfect)?;
         }
@@ -142,21 +154,10 @@ impl Display for HitResultFormatter<'_> {
             write!(f, "{}/", self.stats.good)?;
         }
 
-        let n100 = match self.mode {
-            GameMode::Osu | GameMode::Taiko | GameMode::Mania => self.stats.ok,
-            GameMode::Catch => self.stats.ok.max(self.stats.large_tick_hit),
-        };
-
-        write!(f, "{n100}/")?;
+        write!(f, "{}/", self.stats.ok)?;
 
         if self.mode != GameMode::Taiko {
-            let n50 = match self.mode {
-                GameMode::Osu | GameMode::Mania => self.stats.meh,
-                GameMode::Catch => self.stats.meh.max(self.stats.small_tick_hit),
-                GameMode::Taiko => unreachable!(),
-            };
-
-            write!(f, "{n50}/")?;
+            write!(f, "{}/", self.stats.meh)?;
         }
 
         write!(f, "{}}}", self.stats.miss)
@@ -8,9 +8,9 @@ use std::{
 use bathbot_util::{
     AuthorBuilder, CowUtils, EmbedBuilder, FooterBuilder,
     numbers::WithComma,
-    osu::{ExtractablePp, PpListUtil, pp_missing},
+    osu::{ExtractablePp, PlaySuggestion, PpListUtil, pp_missing, suggest_play},
 };
-use rosu_v2::prelude::Score;
+use rosu_v2::prelude::{GameMods, Score};
 
 use crate::{embeds::EmbedData, manager::redis::osu::CachedUser, util::CachedUserExt};
 
@@ -38,6 +38,7 @@ impl PpMissingEmbed {
     pub fn new(
         user: &CachedUser,
         scores: &[Score],
+        plays: &[(f32, f32, &GameMods)],
         goal_pp: f64,
         rank: Option<u32>,
         each: Option<f32>,
@@ -52,6 +53,14 @@ impl PpMissingEmbed {
 
         let username = user.username.as_str();
 
+        let suggestion = |required: f32| {
+            suggest_play(required, plays.iter().copied()).map(|suggestion| {
+                let user = username.cow_escape_markdown();
+
+                format!("\n{}", PlaySuggestionFormatter::new(&suggestion, &user))
+            })
+        };
+
         let title = format!(
             "What scores is {name} missing to reach {goal_pp}pp?",
             name = username.cow_escape_markdown(),
@@ -73,7 +82,7 @@ impl PpMissingEmbed {
                 let (required, idx) = pp_missing(stats_pp, goal_pp, scores);
                 let suffix = idx_suffix(idx + 1);
 
-                format!(
+                let mut description = format!(
                     "To reach {pp}pp with one additional score, {user} needs to perform \
                     a **{required}pp** score which would be their {approx}{idx}{suffix} top play",
                     pp = WithComma::new(goal_pp),
@@ -81,7 +90,13 @@ impl PpMissingEmbed {
                     required = WithComma::new(required),
                     approx = if idx >= 200 { "~" } else { "" },
                     idx = idx + 1,
-                )
+                );
+
+                if let Some(suggestion) = suggestion(required as f32) {
+                    description.push_str(&suggestion);
+                }
+
+                description
             }
             // Given score pp is below last top 200 score pp
             (Some(last_pp), Some(each), _) if each < last_pp => {
@@ -217,7 +232,7 @@ impl PpMissingEmbed {
                     required = (*pps.last().unwrap() - 0.01).max(0.0);
                 }
 
-                format!(
+                let mut description = format!(
                     "To reach {pp}pp with {amount} additional score{plural} of \
                     the same pp, each of them would need to be **{required}pp**, \
                     placing them {pb_fmt} for {user}.",
@@ -225,7 +240,13 @@ impl PpMissingEmbed {
                     plural = if amount == 1 { "" } else { "s" },
                     required = WithComma::new(required),
                     user = username.cow_escape_markdown(),
-                )
+                );
+
+                if let Some(suggestion) = suggestion(required) {
+                    description.push_str(&suggestion);
+                }
+
+                description
             }
         };
 
@@ -289,4 +310,46 @@ impl Display for PersonalBestIndexFormatter {
     }
 }
 
+/// Describes a [`PlaySuggestion`] e.g. "That's roughly a 7.1★ HDDT play for
+/// peppy."
+pub struct PlaySuggestionFormatter<'a> {
+    suggestion: &'a PlaySuggestion,
+    username: &'a str,
+}
+
+impl<'a> PlaySuggestionFormatter<'a> {
+    pub fn new(suggestion: &'a PlaySuggestion, username: &'a str) -> Self {
+        Self {
+            suggestion,
+            username,
+        }
+    }
+}
+
+impl Display for PlaySuggestionFormatter<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let PlaySuggestion {
+            stars,
+            ref mods,
+            extrapolated,
+        } = *self.suggestion;
+
+        write!(f, "That's roughly a {stars:.1}★ ")?;
+
+        if mods.is_empty() {
+            f.write_str("NM")?;
+        } else {
+            write!(f, "{mods}")?;
+        }
+
+        write!(f, " play for {}", self.username)?;
+
+        if extrapolated {
+            f.write_str(" (extrapolated from their top plays)")?;
+        }
+
+        f.write_str(".")
+    }
+}
+
 const FACTOR: f64 = 0.95;
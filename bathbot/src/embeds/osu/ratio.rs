@@ -1,7 +1,13 @@
-use std::{collections::BTreeMap, fmt::Write};
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+};
 
 use bathbot_macros::EmbedData;
-use bathbot_util::AuthorBuilder;
+use bathbot_util::{
+    AuthorBuilder,
+    osu::{HitResultKind, hitresult_ratio},
+};
 use rosu_v2::{
     model::GameMode,
     prelude::{Grade, Score},
@@ -17,7 +23,13 @@ pub struct RatioEmbed {
 }
 
 impl RatioEmbed {
-    pub fn new(user: &CachedUser, scores: Vec<Score>) -> Self {
+    pub fn new(
+        user: &CachedUser,
+        scores: Vec<Score>,
+        mode: GameMode,
+        numerator: HitResultKind,
+        denominator: HitResultKind,
+    ) -> Self {
         let accs = [0, 90, 95, 97, 99];
         let mut categories: BTreeMap<u8, RatioCategory> = BTreeMap::new();
 
@@ -27,17 +39,23 @@ impl RatioEmbed {
 
         categories.insert(100, RatioCategory::default());
 
+        let ratio = ScoreRatio {
+            mode,
+            numerator,
+            denominator,
+        };
+
         for score in scores {
             let acc = score.accuracy;
 
             for &curr in accs.iter() {
                 if acc > curr as f32 {
-                    categories.get_mut(&curr).unwrap().add_score(&score);
+                    categories.get_mut(&curr).unwrap().add_score(&score, ratio);
                 }
             }
 
             if score.grade.eq_letter(Grade::X) {
-                categories.get_mut(&100).unwrap().add_score(&score);
+                categories.get_mut(&100).unwrap().add_score(&score, ratio);
             }
         }
 
@@ -47,29 +65,22 @@ impl RatioEmbed {
         let _ = writeln!(
             description,
             "```\n \
-        Acc: #Scores |  Ratio | % misses\n\
-        --------------+--------+---------"
+        Acc: #Scores |    Avg |  Total | % misses\n\
+        --------------+--------+--------+---------"
         );
 
-        let mut all_scores = Vec::with_capacity(6);
-        let mut all_ratios = Vec::with_capacity(6);
-        let mut all_misses = Vec::with_capacity(6);
-
         for (acc, c) in categories.into_iter() {
             if c.scores > 0 {
                 let scores = c.scores;
-                let ratio = c.ratio();
+                let average = RatioValue(c.average_ratio());
+                let total = RatioValue(c.total_ratio());
                 let misses = c.miss_percent();
 
                 let _ = writeln!(
                     description,
-                    "{}{acc:>2}%: {scores:>7} | {ratio:>6.3} | {misses:>7.3}%",
+                    "{}{acc:>2}%: {scores:>7} | {average:>6} | {total:>6} | {misses:>7.3}%",
                     if acc < 100 { ">" } else { "" },
                 );
-
-                all_scores.push(scores as i8);
-                all_ratios.push(ratio);
-                all_misses.push(misses);
             }
         }
 
@@ -83,41 +94,76 @@ impl RatioEmbed {
     }
 }
 
+#[derive(Copy, Clone)]
+struct ScoreRatio {
+    mode: GameMode,
+    numerator: HitResultKind,
+    denominator: HitResultKind,
+}
+
 #[derive(Default)]
 struct RatioCategory {
     pub scores: u8,
-    pub count_geki: u32,
-    pub count_300: u32,
+    pub count_numerator: u32,
+    pub count_denominator: u32,
     pub count_miss: u32,
     pub count_objects: u32,
+    /// Sum of all finite ratios of individual scores
+    pub ratio_sum: f32,
+    /// Amount of scores with a finite ratio
+    pub ratio_count: u8,
 }
 
 impl RatioCategory {
-    fn add_score(&mut self, s: &Score) {
-        let stats = s.statistics.as_legacy(GameMode::Mania);
+    fn add_score(&mut self, s: &Score, ratio: ScoreRatio) {
+        let stats = &s.statistics;
+        let numerator = ratio.numerator.value(stats);
+        let denominator = ratio.denominator.value(stats);
 
         self.scores += 1;
-        self.count_geki += stats.count_geki;
-        self.count_300 += stats.count_300;
-        self.count_miss += stats.count_miss;
-        self.count_objects += stats.count_geki
-            + stats.count_300
-            + stats.count_katu
-            + stats.count_100
-            + stats.count_50
-            + stats.count_miss;
+        self.count_numerator += numerator;
+        self.count_denominator += denominator;
+        self.count_miss += stats.miss;
+        self.count_objects += stats.total_hits(ratio.mode);
+
+        if let Some(ratio) = hitresult_ratio(numerator, denominator).filter(|r| r.is_finite()) {
+            self.ratio_sum += ratio;
+            self.ratio_count += 1;
+        }
     }
 
-    fn ratio(&self) -> f32 {
-        if self.count_300 == 0 {
-            self.count_geki as f32
+    fn average_ratio(&self) -> Option<f32> {
+        if self.ratio_count > 0 {
+            Some(self.ratio_sum / self.ratio_count as f32)
         } else {
-            self.count_geki as f32 / self.count_300 as f32
+            self.total_ratio()
         }
     }
 
+    fn total_ratio(&self) -> Option<f32> {
+        hitresult_ratio(self.count_numerator, self.count_denominator)
+    }
+
     fn miss_percent(&self) -> f32 {
         (self.count_objects > 0) as u8 as f32 * 100.0 * self.count_miss as f32
             / self.count_objects as f32
     }
 }
+
+/// Displays a ratio as `inf` if the denominator is zero and as `-` if there
+/// are no hitresults at all.
+struct RatioValue(Option<f32>);
+
+impl Display for RatioValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.0 {
+            Some(ratio) if ratio.is_finite() => {
+                let ratio = format!("{ratio:.3}");
+
+                f.pad(&ratio)
+            }
+            Some(_) => f.pad("inf"),
+            None => f.pad("-"),
+        }
+    }
+}
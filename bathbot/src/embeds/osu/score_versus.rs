@@ -0,0 +1,365 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    fmt::{Display, Write},
+};
+
+use bathbot_macros::EmbedData;
+use bathbot_util::{
+    CowUtils,
+    constants::OSU_BASE,
+    datetime::DATE_FORMAT,
+    numbers::{WithComma, round},
+};
+use rosu_v2::prelude::Grade;
+
+use crate::{
+    commands::utility::ScoreEmbedData,
+    manager::{OsuMap, redis::osu::CachedUser},
+};
+
+const TITLE_WIDTH: usize = 10;
+
+#[derive(EmbedData)]
+pub struct ScoreVersusEmbed {
+    description: String,
+    thumbnail: String,
+    title: String,
+    url: String,
+}
+
+impl ScoreVersusEmbed {
+    pub fn new(
+        map: &OsuMap,
+        user1: &CachedUser,
+        score1: Option<&ScoreEmbedData>,
+        user2: &CachedUser,
+        score2: Option<&ScoreEmbedData>,
+        filtered: bool,
+    ) -> Self {
+        let name1 = user1.username.as_str();
+        let name2 = user2.username.as_str();
+
+        let left = VersusStrings::new(score1);
+        let max_left = left.max().max(name1.chars().count());
+        let right = VersusStrings::new(score2);
+        let max_right = right.max().max(name2.chars().count());
+
+        let mut d = String::with_capacity(512);
+        d.push_str("```ansi\n");
+
+        let _ = writeln!(
+            d,
+            "{name1:>max_left$}  | {:^TITLE_WIDTH$} |  {name2:<max_right$}",
+            "vs"
+        );
+
+        let _ = writeln!(
+            d,
+            "{:->max_left$}--+-{:->TITLE_WIDTH$}-+--{:-<max_right$}",
+            "-", "-", "-",
+        );
+
+        let grade = |entry: &ScoreEmbedData| grade_value(entry.score.grade);
+        let acc = |entry: &ScoreEmbedData| entry.score.accuracy;
+        let combo = |entry: &ScoreEmbedData| entry.score.max_combo;
+        let misses = |entry: &ScoreEmbedData| Reverse(entry.score.statistics.miss);
+        let pp = |entry: &ScoreEmbedData| entry.score.pp;
+        let date = |entry: &ScoreEmbedData| Reverse(entry.score.ended_at);
+
+        let rows = [
+            (
+                "Grade",
+                &left.grade,
+                &right.grade,
+                cmp(score1, score2, grade),
+            ),
+            ("Accuracy", &left.acc, &right.acc, cmp(score1, score2, acc)),
+            (
+                "Combo",
+                &left.combo,
+                &right.combo,
+                cmp(score1, score2, combo),
+            ),
+            (
+                "Misses",
+                &left.misses,
+                &right.misses,
+                cmp(score1, score2, misses),
+            ),
+            ("PP", &left.pp, &right.pp, cmp(score1, score2, pp)),
+            ("Date", &left.date, &right.date, cmp(score1, score2, date)),
+        ];
+
+        for (title, left, right, winner) in rows {
+            write_row(&mut d, title, left, right, winner, max_left, max_right);
+        }
+
+        d.push_str("```");
+
+        let name1 = name1.cow_escape_markdown();
+        let name2 = name2.cow_escape_markdown();
+
+        let filter = if filtered {
+            " matching the filters"
+        } else {
+            ""
+        };
+
+        let _ = match (score1, score2) {
+            (Some(entry1), Some(entry2)) => {
+                let diff = entry1.score.pp - entry2.score.pp;
+                let abs = round(diff.abs());
+
+                if abs == 0.0 {
+                    write!(
+                        d,
+                        "Both scores are worth **{}pp**",
+                        WithComma::new(round(entry1.score.pp))
+                    )
+                } else {
+                    write!(
+                        d,
+                        "**{winner}**'s score is worth **{}pp** more",
+                        WithComma::new(abs),
+                        winner = if diff > 0.0 { &name1 } else { &name2 },
+                    )
+                }
+            }
+            (Some(_), None) => write!(d, "Only **{name1}** has a score{filter} on this map"),
+            (None, Some(_)) => write!(d, "Only **{name2}** has a score{filter} on this map"),
+            (None, None) => write!(d, "Neither user has a score{filter} on this map"),
+        };
+
+        let title = format!(
+            "{} - {} [{}]",
+            map.artist().cow_escape_markdown(),
+            map.title().cow_escape_markdown(),
+            map.version().cow_escape_markdown()
+        );
+
+        Self {
+            description: d,
+            thumbnail: map.thumbnail().to_owned(),
+            title,
+            url: format!("{OSU_BASE}b/{}", map.map_id()),
+        }
+    }
+}
+
+/// Compare the values of both sides. A missing score always loses.
+fn cmp<T: PartialOrd>(
+    left: Option<&ScoreEmbedData>,
+    right: Option<&ScoreEmbedData>,
+    f: impl Fn(&ScoreEmbedData) -> T,
+) -> Option<Ordering> {
+    left.map(&f).partial_cmp(&right.map(&f))
+}
+
+fn grade_value(grade: Grade) -> u8 {
+    match grade {
+        Grade::F => 0,
+        Grade::D => 1,
+        Grade::C => 2,
+        Grade::B => 3,
+        Grade::A => 4,
+        Grade::S | Grade::SH => 5,
+        Grade::X | Grade::XH => 6,
+    }
+}
+
+fn grade_str(grade: Grade) -> &'static str {
+    match grade {
+        Grade::F => "F",
+        Grade::D => "D",
+        Grade::C => "C",
+        Grade::B => "B",
+        Grade::A => "A",
+        Grade::S => "S",
+        Grade::SH => "SH",
+        Grade::X => "SS",
+        Grade::XH => "SSH",
+    }
+}
+
+/// Write a row of the comparison table with the values aligned around the
+/// title column.
+///
+/// `max_left` and `max_right` are the character counts of the widest value on
+/// each side; the ANSI color codes don't count towards the width.
+fn write_row<V: Display + ?Sized>(
+    content: &mut String,
+    title: &str,
+    left: &V,
+    right: &V,
+    winner: Option<Ordering>,
+    max_left: usize,
+    max_right: usize,
+) {
+    let green = "\u{001b}[0;32m";
+    let yellow = "\u{001b}[0;33m";
+    let red = "\u{001b}[0;31m";
+    let reset = "\u{001b}[0m";
+
+    let (ansi_left, winner_left, ansi_right, winner_right) = match winner {
+        Some(Ordering::Less) => (red, ' ', green, '>'),
+        Some(Ordering::Greater) => (green, '<', red, ' '),
+        Some(Ordering::Equal) | None => (yellow, ' ', yellow, ' '),
+    };
+
+    let _ = writeln!(
+        content,
+        "{ansi_left}{left:>max_left$}{reset} {winner_left}\
+        | {title:^TITLE_WIDTH$} |\
+        {winner_right} {ansi_right}{right:<max_right$}{reset}",
+    );
+}
+
+struct VersusStrings {
+    grade: Box<str>,
+    acc: Box<str>,
+    combo: Box<str>,
+    misses: Box<str>,
+    pp: Box<str>,
+    date: Box<str>,
+}
+
+impl VersusStrings {
+    fn new(entry: Option<&ScoreEmbedData>) -> Self {
+        let Some(entry) = entry else {
+            return Self {
+                grade: "no score".into(),
+                acc: "-".into(),
+                combo: "-".into(),
+                misses: "-".into(),
+                pp: "-".into(),
+                date: "-".into(),
+            };
+        };
+
+        let score = &entry.score;
+
+        Self {
+            grade: grade_str(score.grade).into(),
+            acc: format!("{}%", round(score.accuracy)).into_boxed_str(),
+            combo: format!("{}x/{}x", score.max_combo, entry.max_combo).into_boxed_str(),
+            misses: score.statistics.miss.to_string().into_boxed_str(),
+            pp: format!("{}pp", WithComma::new(round(score.pp))).into_boxed_str(),
+            date: score.ended_at.format(DATE_FORMAT).unwrap().into_boxed_str(),
+        }
+    }
+
+    fn max(&self) -> usize {
+        [
+            &self.grade,
+            &self.acc,
+            &self.combo,
+            &self.misses,
+            &self.pp,
+            &self.date,
+        ]
+        .into_iter()
+        .map(|s| s.chars().count())
+        .max()
+        .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The line without ANSI color codes, i.e. what is visible in discord.
+    fn visible(line: &str) -> String {
+        let mut visible = String::with_capacity(line.len());
+        let mut rest = line;
+
+        while let Some(idx) = rest.find('\u{001b}') {
+            visible.push_str(&rest[..idx]);
+            rest = &rest[idx..];
+            rest = rest.split_once('m').map_or("", |(_, suffix)| suffix);
+        }
+
+        visible.push_str(rest);
+
+        visible
+    }
+
+    fn separators(line: &str) -> Vec<usize> {
+        visible(line)
+            .chars()
+            .enumerate()
+            .filter_map(|(i, c)| (c == '|').then_some(i))
+            .collect()
+    }
+
+    #[test]
+    fn rows_are_aligned() {
+        let mut content = String::new();
+        let max_left = "no score".len();
+        let max_right = "1,234.56pp".len();
+
+        write_row(
+            &mut content,
+            "Grade",
+            "no score",
+            "SS",
+            Some(Ordering::Less),
+            max_left,
+            max_right,
+        );
+        write_row(
+            &mut content,
+            "PP",
+            "-",
+            "1,234.56pp",
+            Some(Ordering::Less),
+            max_left,
+            max_right,
+        );
+        write_row(
+            &mut content,
+            "Accuracy",
+            "98.5%",
+            "ü",
+            None,
+            max_left,
+            max_right,
+        );
+
+        let lines: Vec<_> = content.lines().collect();
+        assert_eq!(lines.len(), 3);
+
+        for line in lines.iter() {
+            assert_eq!(separators(line), separators(lines[0]));
+        }
+
+        let widths: Vec<_> = lines
+            .iter()
+            .map(|line| visible(line).chars().count())
+            .collect();
+        assert!(widths.iter().all(|&width| width == widths[0]));
+    }
+
+    #[test]
+    fn winner_markers() {
+        let mut content = String::new();
+
+        write_row(&mut content, "PP", "2", "1", Some(Ordering::Greater), 1, 1);
+        assert_eq!(visible(&content), "2 <|     PP     |  1\n");
+
+        content.clear();
+        write_row(&mut content, "PP", "1", "2", Some(Ordering::Less), 1, 1);
+        assert_eq!(visible(&content), "1  |     PP     |> 2\n");
+
+        content.clear();
+        write_row(&mut content, "PP", "1", "1", Some(Ordering::Equal), 1, 1);
+        assert_eq!(visible(&content), "1  |     PP     |  1\n");
+    }
+
+    #[test]
+    fn grades_are_ordered() {
+        assert!(grade_value(Grade::XH) == grade_value(Grade::X));
+        assert!(grade_value(Grade::X) > grade_value(Grade::SH));
+        assert!(grade_value(Grade::A) > grade_value(Grade::F));
+    }
+}
@@ -5,8 +5,14 @@ use bathbot_util::{
     AuthorBuilder, CowUtils,
     numbers::{WithComma, round},
 };
+use rosu_v2::prelude::GameModsIntermode;
 
-use crate::{commands::osu::WhatIfData, manager::redis::osu::CachedUser, util::CachedUserExt};
+use crate::{
+    commands::osu::{ProjectedRank, WhatIfData},
+    embeds::PlaySuggestionFormatter,
+    manager::redis::osu::CachedUser,
+    util::CachedUserExt,
+};
 
 #[derive(EmbedData)]
 pub struct WhatIfEmbed {
@@ -17,10 +23,16 @@ pub struct WhatIfEmbed {
 }
 
 impl WhatIfEmbed {
-    pub fn new(user: &CachedUser, pp: f32, data: WhatIfData) -> Self {
+    pub fn new(
+        user: &CachedUser,
+        pp: f32,
+        data: WhatIfData,
+        blacklisted: Option<GameModsIntermode>,
+    ) -> Self {
         let stats = user.statistics.as_ref().expect("missing stats");
         let stats_pp = stats.pp.to_native();
         let global_rank = stats.global_rank.to_native();
+        let country_rank = stats.country_rank.to_native();
 
         let username = user.username.as_str().cow_escape_markdown();
         let avatar_url = user.avatar_url.as_ref();
@@ -51,26 +63,19 @@ impl WhatIfEmbed {
                 let mut d = if count == 1 {
                     format!(
                         "A {pp}pp play would be {username}'s #1 best play.\n\
-                        Their pp would change by **+{pp}** to **{pp}pp**",
+                        Their pp would change by **+{pp}** to **{pp}pp**.",
                         pp = WithComma::new(pp),
                     )
                 } else {
                     format!(
                         "A {pp}pp play would be {username}'s #1 best play.\n\
-                        Adding {count} of them would change their pp by **{pp:+}** to **{pp}pp**",
+                        Adding {count} of them would change their pp by **{pp:+}** to **{pp}pp**.",
                         pp = WithComma::new(pp),
                     )
                 };
 
                 if let Some(rank) = rank {
-                    let _ = write!(
-                        d,
-                        "\nand they would reach approx. rank #{} (+{}).",
-                        WithComma::new(rank.min(global_rank)),
-                        WithComma::new(global_rank.saturating_sub(rank)),
-                    );
-                } else {
-                    d.push('.');
+                    write_projected_rank(&mut d, &rank, global_rank, country_rank);
                 }
 
                 d
@@ -82,34 +87,58 @@ impl WhatIfEmbed {
                 new_pos,
                 max_pp,
                 rank,
+                baseline,
+                suggestion,
             } => {
-                let mut d = if count == 1 {
-                    format!(
+                let mut d = String::new();
+
+                let stats_pp = match baseline {
+                    Some(baseline) => {
+                        let _ = writeln!(
+                            d,
+                            "Assuming {username}'s {excluded} weakest top play{plural} \
+                            {verb} replaced, their baseline is **{pp}pp**.",
+                            excluded = baseline.excluded,
+                            plural = if baseline.excluded == 1 { "" } else { "s" },
+                            verb = if baseline.excluded == 1 { "is" } else { "are" },
+                            pp = WithComma::new(baseline.pp),
+                        );
+
+                        baseline.pp
+                    }
+                    None => stats_pp,
+                };
+
+                if count == 1 {
+                    let _ = write!(
+                        d,
                         "A {pp}pp play would be {username}'s #{new_pos} best play.\n\
-                        Their pp would change by **{pp_change:+.2}** to **{new_pp}pp**",
+                        Their pp would change by **{pp_change:+.2}** to **{new_pp}pp**.",
                         pp = round(pp),
                         pp_change = (new_pp + bonus_pp - stats_pp).max(0.0),
                         new_pp = WithComma::new(new_pp + bonus_pp)
-                    )
+                    );
                 } else {
-                    format!(
+                    let _ = write!(
+                        d,
                         "A {pp}pp play would be {username}'s #{new_pos} best play.\n\
-                        Adding {count} of them would change their pp by **{pp_change:+.2}** to **{new_pp}pp**",
+                        Adding {count} of them would change their pp by **{pp_change:+.2}** to **{new_pp}pp**.",
                         pp = round(pp),
                         pp_change = (new_pp + bonus_pp - stats_pp).max(0.0),
                         new_pp = WithComma::new(new_pp + bonus_pp)
-                    )
-                };
+                    );
+                }
 
                 if let Some(rank) = rank {
+                    write_projected_rank(&mut d, &rank, global_rank, country_rank);
+                }
+
+                if let Some(ref suggestion) = suggestion {
                     let _ = write!(
                         d,
-                        " and they would reach approx. rank #{} (+{}).",
-                        WithComma::new(rank.min(global_rank)),
-                        WithComma::new(global_rank.saturating_sub(rank)),
+                        "\n{}",
+                        PlaySuggestionFormatter::new(suggestion, &username)
                     );
-                } else {
-                    d.push('.');
                 }
 
                 // Arbitrary value 30, surely everyone can get that without
@@ -118,6 +147,14 @@ impl WhatIfEmbed {
                     d.push_str("\nThey'd probably also get banned :^)");
                 }
 
+                if let Some(mods) = blacklisted {
+                    let _ = write!(
+                        d,
+                        "\nA {pp}pp play likely requires {mods}-level scores, which you've blacklisted.",
+                        pp = round(pp),
+                    );
+                }
+
                 d
             }
         };
@@ -130,3 +167,42 @@ impl WhatIfEmbed {
         }
     }
 }
+
+fn write_projected_rank(
+    description: &mut String,
+    rank: &ProjectedRank,
+    global_rank: u32,
+    country_rank: u32,
+) {
+    // Current rank of zero means the user is unranked
+    let delta = |projected: u32, current: u32| {
+        if current == 0 {
+            (projected, None)
+        } else {
+            let projected = projected.min(current);
+
+            (projected, Some(current - projected))
+        }
+    };
+
+    let approx = if rank.approx { "approx. " } else { "" };
+    let (global, global_delta) = delta(rank.global, global_rank);
+    let _ = write!(
+        description,
+        "\nProjected rank: {approx}#{}",
+        WithComma::new(global)
+    );
+
+    if let Some(delta) = global_delta {
+        let _ = write!(description, " (+{})", WithComma::new(delta));
+    }
+
+    if let Some(country) = rank.country {
+        let (country, country_delta) = delta(country, country_rank);
+        let _ = write!(description, " • Country rank: #{}", WithComma::new(country));
+
+        if let Some(delta) = country_delta {
+            let _ = write!(description, " (+{})", WithComma::new(delta));
+        }
+    }
+}
@@ -1,7 +1,11 @@
 use std::fmt::{Display, Write};
 
 use ::time::UtcOffset;
-use bathbot_psql::model::configs::{ListSize, OsuUsername, Retries, ScoreData, UserConfig};
+use bathbot_model::embed_builder::SettingsImage;
+use bathbot_psql::model::configs::{
+    GraphTheme, GuildConfig, ListSize, OsuUserId, OsuUsername, Retries, ScoreData, TimestampStyle,
+    UserConfig, resolve,
+};
 use bathbot_util::{AuthorBuilder, EmbedBuilder, FooterBuilder};
 use rosu_v2::prelude::GameMode;
 use twilight_model::{channel::message::embed::EmbedField, user::User};
@@ -22,22 +26,7 @@ impl ConfigEmbed {
         twitch: Option<Box<str>>,
         skin_url: Option<String>,
     ) -> Self {
-        let author_img = match author.avatar {
-            Some(ref hash) if hash.is_animated() => format!(
-                "https://cdn.discordapp.com/avatars/{}/{hash}.gif",
-                author.id
-            ),
-            Some(ref hash) => format!(
-                "https://cdn.discordapp.com/avatars/{}/{hash}.png",
-                author.id
-            ),
-            None => format!(
-                "https://cdn.discordapp.com/embed/avatars/{}.png",
-                author.discriminator()
-            ),
-        };
-
-        let author = AuthorBuilder::new(&author.name).icon_url(author_img);
+        let author = author_builder(author);
         let title = "Current user configuration:";
 
         let account_value = format!(
@@ -108,8 +97,55 @@ impl ConfigEmbed {
                     (Retries::IgnoreMods, "ignore mods"),
                 ],
             ),
+            create_field(
+                "Timestamps",
+                config.timestamp_style.unwrap_or_default(),
+                &[
+                    (TimestampStyle::Relative, "relative"),
+                    (TimestampStyle::LocalAbsolute, "local time"),
+                    (TimestampStyle::UtcText, "utc text"),
+                ],
+            ),
+            create_field(
+                "Recent fails",
+                config.recent_includes_fails.unwrap_or(true),
+                &[(true, "show"), (false, "hide")],
+            ),
+            create_field(
+                "Graphs",
+                config.graph_theme.unwrap_or_default(),
+                &[
+                    (GraphTheme::Default, "default"),
+                    (GraphTheme::Colorblind, "colorblind"),
+                    (GraphTheme::Light, "light"),
+                    (GraphTheme::Dark, "dark"),
+                ],
+            ),
+            create_field(
+                "Extended profile",
+                config.extended_profile.unwrap_or(false),
+                &[(true, "show"), (false, "hide")],
+            ),
+            create_field(
+                "Tracking DM digest",
+                config.tracking_dm_digest.unwrap_or(false),
+                &[(true, "enable"), (false, "disable")],
+            ),
+            create_field(
+                "osu!track updates",
+                config.osutrack_auto_update.unwrap_or(false),
+                &[(true, "enable"), (false, "disable")],
+            ),
         ];
 
+        if let Some(ref mods) = config.blacklisted_mods {
+            fields.push(EmbedField {
+                inline: true,
+                name: "Blacklisted mods".to_owned(),
+                value: format!("```\n{mods}\n```"),
+            });
+        }
+
         if let Some(skin_url) = skin_url {
             fields.push(EmbedField {
                 inline: false,
@@ -133,6 +169,274 @@ impl ConfigEmbed {
     }
 }
 
+pub struct ConfigListEmbed {
+    author: AuthorBuilder,
+    description: String,
+    footer: FooterBuilder,
+    title: &'static str,
+}
+
+impl ConfigListEmbed {
+    pub fn new(
+        author: &User,
+        config: &UserConfig<OsuUserId>,
+        guild_config: Option<&GuildConfig>,
+    ) -> Self {
+        let author = author_builder(author);
+        let title = "Current settings and their sources:";
+
+        let guild_score_data = guild_config.and_then(|config| config.score_data);
+        let guild_list_size = guild_config.and_then(|config| config.list_size);
+        let guild_retries = guild_config.and_then(|config| config.retries);
+        let guild_render_button = guild_config.and_then(|config| config.render_button);
+
+        let guild_value = |value: Option<&'static str>| match (guild_config, value) {
+            (Some(_), Some(value)) => value,
+            (Some(_), None) => "unset",
+            (None, _) => "-",
+        };
+
+        let blacklisted_mods = config.blacklisted_mods.as_ref().map(ToString::to_string);
+        let score_embed_image = config.score_embed.as_ref().map(|settings| settings.image);
+
+        let rows = [
+            [
+                "Score data",
+                config.score_data.map_or("unset", score_data_str),
+                guild_value(guild_score_data.map(score_data_str)),
+                score_data_str(resolve::score_data(
+                    None,
+                    config.score_data,
+                    guild_score_data,
+                )),
+            ],
+            [
+                "List embeds",
+                config.list_size.map_or("unset", list_size_str),
+                guild_value(guild_list_size.map(list_size_str)),
+                list_size_str(resolve::list_size(None, config.list_size, guild_list_size)),
+            ],
+            [
+                "Retries",
+                config.retries.map_or("unset", retries_str),
+                guild_value(guild_retries.map(retries_str)),
+                retries_str(resolve::retries(config.retries, guild_retries)),
+            ],
+            [
+                "Render button",
+                config.render_button.map_or("unset", show_hide_str),
+                guild_value(guild_render_button.map(show_hide_str)),
+                show_hide_str(resolve::render_button(
+                    config.render_button,
+                    guild_render_button,
+                )),
+            ],
+            [
+                "Mode",
+                config.mode.map_or("unset", mode_str),
+                "-",
+                mode_str(resolve::mode(None, config.mode)),
+            ],
+            [
+                "Timestamps",
+                config.timestamp_style.map_or("unset", timestamp_style_str),
+                "-",
+                timestamp_style_str(config.timestamp_style.unwrap_or_default()),
+            ],
+            [
+                "Recent fails",
+                config.recent_includes_fails.map_or("unset", show_hide_str),
+                "-",
+                show_hide_str(config.recent_includes_fails.unwrap_or(true)),
+            ],
+            [
+                "Graphs",
+                config.graph_theme.map_or("unset", graph_theme_str),
+                "-",
+                graph_theme_str(config.graph_theme.unwrap_or_default()),
+            ],
+            [
+                "Extended profile",
+                config.extended_profile.map_or("unset", show_hide_str),
+                "-",
+                show_hide_str(config.extended_profile.unwrap_or(false)),
+            ],
+            [
+                "Tracking DM digest",
+                config
+                    .tracking_dm_digest
+                    .map_or("unset", enable_disable_str),
+                "-",
+                enable_disable_str(config.tracking_dm_digest.unwrap_or(false)),
+            ],
+            [
+                "osu!track updates",
+                config
+                    .osutrack_auto_update
+                    .map_or("unset", enable_disable_str),
+                "-",
+                enable_disable_str(config.osutrack_auto_update.unwrap_or(false)),
+            ],
+            [
+                "Server lists",
+                config
+                    .hide_from_server_lists
+                    .map_or("unset", |hide| show_hide_str(!hide)),
+                "-",
+                show_hide_str(!config.hide_from_server_lists.unwrap_or(false)),
+            ],
+            [
+                "Blacklisted mods",
+                blacklisted_mods.as_deref().unwrap_or("unset"),
+                "-",
+                blacklisted_mods.as_deref().unwrap_or("none"),
+            ],
+            [
+                "Embed image",
+                score_embed_image.map_or("unset", settings_image_str),
+                "-",
+                settings_image_str(score_embed_image.unwrap_or_default()),
+            ],
+            [
+                "Score skin",
+                config.skin_display_name.as_deref().unwrap_or("unset"),
+                "-",
+                config.skin_display_name.as_deref().unwrap_or("none"),
+            ],
+        ];
+
+        let header = ["Setting", "User", "Server", "Effective"];
+        let mut widths = header.map(str::len);
+
+        for row in rows.iter() {
+            for (width, value) in widths.iter_mut().zip(row) {
+                *width = (*width).max(value.len());
+            }
+        }
+
+        let mut description = String::with_capacity(512);
+        description.push_str("```\n");
+
+        for row in [header].iter().chain(rows.iter()) {
+            let [name, user, guild, effective] = row;
+            let [w0, w1, w2, _] = widths;
+
+            let _ = writeln!(
+                description,
+                "{name:<w0$} | {user:<w1$} | {guild:<w2$} | {effective}"
+            );
+        }
+
+        description.push_str("```");
+
+        let footer = FooterBuilder::new("Options specified in commands take precedence");
+
+        Self {
+            author,
+            description,
+            footer,
+            title,
+        }
+    }
+}
+
+impl EmbedData for ConfigListEmbed {
+    #[inline]
+    fn build(self) -> EmbedBuilder {
+        EmbedBuilder::new()
+            .author(self.author)
+            .description(self.description)
+            .footer(self.footer)
+            .title(self.title)
+    }
+}
+
+fn author_builder(author: &User) -> AuthorBuilder {
+    let author_img = match author.avatar {
+        Some(ref hash) if hash.is_animated() => format!(
+            "https://cdn.discordapp.com/avatars/{}/{hash}.gif",
+            author.id
+        ),
+        Some(ref hash) => format!(
+            "https://cdn.discordapp.com/avatars/{}/{hash}.png",
+            author.id
+        ),
+        None => format!(
+            "https://cdn.discordapp.com/embed/avatars/{}.png",
+            author.discriminator()
+        ),
+    };
+
+    AuthorBuilder::new(&author.name).icon_url(author_img)
+}
+
+fn score_data_str(score_data: ScoreData) -> &'static str {
+    match score_data {
+        ScoreData::Stable => "stable",
+        ScoreData::Lazer => "lazer",
+        ScoreData::LazerWithClassicScoring => "lazer (classic)",
+    }
+}
+
+fn list_size_str(list_size: ListSize) -> &'static str {
+    match list_size {
+        ListSize::Condensed => "condensed",
+        ListSize::Detailed => "detailed",
+        ListSize::Single => "single",
+    }
+}
+
+fn retries_str(retries: Retries) -> &'static str {
+    match retries {
+        Retries::Hide => "hide",
+        Retries::ConsiderMods => "consider mods",
+        Retries::IgnoreMods => "ignore mods",
+    }
+}
+
+fn timestamp_style_str(style: TimestampStyle) -> &'static str {
+    match style {
+        TimestampStyle::Relative => "relative",
+        TimestampStyle::LocalAbsolute => "local time",
+        TimestampStyle::UtcText => "utc text",
+    }
+}
+
+fn graph_theme_str(theme: GraphTheme) -> &'static str {
+    match theme {
+        GraphTheme::Default => "default",
+        GraphTheme::Colorblind => "colorblind",
+        GraphTheme::Light => "light",
+        GraphTheme::Dark => "dark",
+    }
+}
+
+fn settings_image_str(image: SettingsImage) -> &'static str {
+    match image {
+        SettingsImage::Thumbnail => "thumbnail",
+        SettingsImage::Image => "image",
+        SettingsImage::ImageWithStrains => "image with strains",
+        SettingsImage::Hide => "hide",
+    }
+}
+
+fn show_hide_str(show: bool) -> &'static str {
+    if show { "show" } else { "hide" }
+}
+
+fn enable_disable_str(enable: bool) -> &'static str {
+    if enable { "enable" } else { "disable" }
+}
+
+fn mode_str(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "osu",
+        GameMode::Taiko => "taiko",
+        GameMode::Catch => "catch",
+        GameMode::Mania => "mania",
+    }
+}
+
 pub(super) fn create_field<T: Eq>(
     name: &'static str,
     val: T,
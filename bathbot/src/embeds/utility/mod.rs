@@ -1,4 +1,7 @@
 mod config;
 mod server_config;
 
-pub use self::{config::ConfigEmbed, server_config::ServerConfigEmbed};
+pub use self::{
+    config::{ConfigEmbed, ConfigListEmbed},
+    server_config::ServerConfigEmbed,
+};
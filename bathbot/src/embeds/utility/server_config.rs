@@ -4,7 +4,7 @@ use bathbot_cache::model::CachedArchive;
 use bathbot_macros::EmbedData;
 use bathbot_model::twilight::guild::ArchivedCachedGuild;
 use bathbot_psql::model::configs::{GuildConfig, HideSolutions, ListSize, Retries, ScoreData};
-use bathbot_util::AuthorBuilder;
+use bathbot_util::{AuthorBuilder, i18n::Lang};
 use twilight_model::channel::message::embed::EmbedField;
 
 use super::config::create_field;
@@ -67,6 +67,11 @@ impl ServerConfigEmbed {
 
         description.push_str("\n```");
 
+        let langs: Vec<_> = Lang::ALL
+            .into_iter()
+            .map(|lang| (lang, lang.name()))
+            .collect();
+
         let fields = vec![
             create_field(
                 "Song commands",
@@ -128,6 +133,17 @@ impl ServerConfigEmbed {
                     (Retries::IgnoreMods, "ignore mods"),
                 ],
             ),
+            create_field(
+                "Prefix invocations",
+                config.delete_invocation.unwrap_or(false),
+                &[(true, "delete"), (false, "keep")],
+            ),
+            create_field(
+                "Score anomalies",
+                config.score_anomalies.unwrap_or(true),
+                &[(true, "mark"), (false, "skip")],
+            ),
+            create_field("Language", config.lang.unwrap_or_default(), &langs),
         ];
 
         Self {
@@ -26,7 +26,10 @@ use tokio::{
 
 use crate::{
     commands::owner::RESHARD_TX,
-    core::{BotConfig, Context, commands::interaction::InteractionCommands, event_loop, logging},
+    core::{
+        BackgroundLoop, BotConfig, Context, Supervised, commands::interaction::InteractionCommands,
+        event_loop, logging, watchdog_loop,
+    },
 };
 
 fn main() {
@@ -68,34 +71,115 @@ async fn async_main() -> Result<()> {
     let slash_commands = InteractionCommands::get().collect();
     info!("Setting {} slash commands...", slash_commands.len());
 
+    let dev_guild = BotConfig::get().dev_guild;
+
     if cfg!(feature = "global_slash") {
         let cmds = Context::set_global_commands(slash_commands).await?;
         InteractionCommands::set_ids(&cmds);
 
-        if let Err(err) = Context::set_guild_commands(Vec::new()).await {
+        if let Err(err) = Context::set_guild_commands(dev_guild, Vec::new()).await {
             warn!(?err, "Failed to remove guild commands");
         }
     } else {
-        let cmds = Context::set_guild_commands(slash_commands).await?;
+        let cmds = Context::register_guild_commands(dev_guild, slash_commands).await?;
         InteractionCommands::set_ids(&cmds);
 
+        // Guilds that were joined while the bot was offline need commands too
+        tokio::spawn(Context::register_cached_guild_commands());
+
         if let Err(err) = Context::set_global_commands(Vec::new()).await {
             warn!(?err, "Failed to remove global commands");
         }
     }
 
+    // Background loops are spawned by the watchdog so that they're restarted
+    // if they panic or get stuck
+    let mut loops = Vec::new();
+
     #[cfg(feature = "twitchtracking")]
     {
-        // Spawn twitch worker
-        tokio::spawn(tracking::twitch_tracking_loop());
+        // Twitch worker
+        loops.push(Supervised::new(BackgroundLoop::TwitchTracking, || {
+            tokio::spawn(tracking::twitch_tracking_loop())
+        }));
+    }
+
+    #[cfg(feature = "statsrefresh")]
+    {
+        // Background refresher of user stats
+        loops.push(Supervised::new(BackgroundLoop::StatsRefresh, || {
+            tokio::spawn(tracking::stats_refresh_loop())
+        }));
     }
 
     #[cfg(feature = "matchlive")]
     {
-        // Spawn osu match ticker worker
-        tokio::spawn(Context::match_live_loop());
+        // osu match ticker worker
+        loops.push(Supervised::new(BackgroundLoop::MatchLive, || {
+            tokio::spawn(Context::match_live_loop())
+        }));
     }
 
+    // Mapset watch worker
+    loops.push(Supervised::new(BackgroundLoop::MapsetWatch, || {
+        tokio::spawn(tracking::mapset_watch_loop())
+    }));
+
+    // Refresher of the sampled rank-pp curves
+    loops.push(Supervised::new(BackgroundLoop::ApproxRefresh, || {
+        tokio::spawn(tracking::approx_refresh_loop())
+    }));
+
+    // Keep track of the database's availability
+    loops.push(Supervised::new(BackgroundLoop::DatabaseProbe, || {
+        tokio::spawn(tracking::database_probe_loop())
+    }));
+
+    // Daily DM digest of tracking notifications
+    loops.push(Supervised::new(BackgroundLoop::TrackingDigest, || {
+        tokio::spawn(tracking::tracking_digest_loop())
+    }));
+
+    // Keep maintenance mode in sync with redis
+    loops.push(Supervised::new(BackgroundLoop::MaintenanceWatch, || {
+        tokio::spawn(tracking::maintenance_watch_loop())
+    }));
+
+    // Slowly sweep dangling entries out of cached guilds
+    loops.push(Supervised::new(BackgroundLoop::CacheReconcile, || {
+        tokio::spawn(tracking::cache_reconcile_loop())
+    }));
+
+    // Alert about commands that suddenly fail a lot
+    loops.push(Supervised::new(BackgroundLoop::ErrorRateWatch, || {
+        tokio::spawn(tracking::error_rate_watch_loop())
+    }));
+
+    // Flag the osu!api as degraded while its errors pile up
+    loops.push(Supervised::new(BackgroundLoop::ApiIncidentWatch, || {
+        tokio::spawn(tracking::api_incident_watch_loop())
+    }));
+
+    // Keep the audit log from growing indefinitely
+    loops.push(Supervised::new(BackgroundLoop::AuditLogCleanup, || {
+        tokio::spawn(tracking::audit_log_cleanup_loop())
+    }));
+
+    // Monthly report of prunable data for the owner
+    if BotConfig::get().prune_reports {
+        loops.push(Supervised::new(BackgroundLoop::PruneReport, || {
+            tokio::spawn(tracking::prune_report_loop())
+        }));
+    }
+
+    tokio::spawn(watchdog_loop(loops));
+
+    // Fill hot redis entries once the shards are up
+    tokio::spawn(tracking::cache_prewarm());
+
+    // Deliver renders that were interrupted by the last shutdown
+    tokio::spawn(commands::osu::recover_ongoing_renders());
+
     // Request members
     tokio::spawn(Context::request_guild_members(member_rx));
 
@@ -0,0 +1,268 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    future::Future,
+    hash::Hash,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use bathbot_util::IntHasher;
+use eyre::Result;
+
+/// In-process cache in front of a slower source such as the database.
+///
+/// Values are served for [`FrontCache::TTL`] before the source is asked again.
+/// If the source fails, the last known value is served for up to
+/// [`FrontCache::MAX_AGE`] so that lookups keep working during short outages.
+///
+/// Holds at most [`FrontCache::CAPACITY`] entries, evicting the least recently
+/// used one.
+pub struct FrontCache<K, V> {
+    inner: Mutex<Inner<K, V>>,
+    capacity: usize,
+}
+
+struct Inner<K, V> {
+    entries: HashMap<K, Entry<V>, IntHasher>,
+    /// Keys by the tick of their last use, least recently used first
+    recency: BTreeMap<u64, K>,
+    tick: u64,
+}
+
+struct Entry<V> {
+    value: V,
+    fetched_at: Instant,
+    last_used: u64,
+}
+
+enum Lookup<V> {
+    Fresh(V),
+    Stale(V),
+    Missing,
+}
+
+impl<K, V> FrontCache<K, V>
+where
+    K: Copy + Eq + Hash,
+    V: Clone,
+{
+    pub const TTL: Duration = Duration::from_secs(30);
+    pub const MAX_AGE: Duration = Duration::from_secs(60 * 60);
+    pub const CAPACITY: usize = 10_000;
+
+    pub async fn get_or_fetch<F>(&self, key: K, fetch: F) -> Result<V>
+    where
+        F: Future<Output = Result<V>>,
+    {
+        self.get_or_fetch_at(key, fetch, Instant::now()).await
+    }
+
+    async fn get_or_fetch_at<F>(&self, key: K, fetch: F, now: Instant) -> Result<V>
+    where
+        F: Future<Output = Result<V>>,
+    {
+        let stale = match self.lookup(key, now) {
+            Lookup::Fresh(value) => return Ok(value),
+            Lookup::Stale(value) => Some(value),
+            Lookup::Missing => None,
+        };
+
+        match (fetch.await, stale) {
+            (Ok(value), _) => {
+                self.insert(key, value.clone(), now);
+
+                Ok(value)
+            }
+            (Err(err), Some(value)) => {
+                warn!(?err, "Serving last known value after failed fetch");
+
+                Ok(value)
+            }
+            (Err(err), None) => Err(err),
+        }
+    }
+
+    fn lookup(&self, key: K, now: Instant) -> Lookup<V> {
+        let mut inner = self.inner.lock().unwrap();
+        let Inner {
+            entries,
+            recency,
+            tick,
+        } = &mut *inner;
+
+        let Some(entry) = entries.get_mut(&key) else {
+            return Lookup::Missing;
+        };
+
+        let age = now.saturating_duration_since(entry.fetched_at);
+
+        if age >= Self::MAX_AGE {
+            recency.remove(&entry.last_used);
+            entries.remove(&key);
+
+            return Lookup::Missing;
+        }
+
+        *tick += 1;
+        recency.remove(&entry.last_used);
+        recency.insert(*tick, key);
+        entry.last_used = *tick;
+
+        if age < Self::TTL {
+            Lookup::Fresh(entry.value.clone())
+        } else {
+            Lookup::Stale(entry.value.clone())
+        }
+    }
+
+    fn insert(&self, key: K, value: V, now: Instant) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.tick += 1;
+
+        let entry = Entry {
+            value,
+            fetched_at: now,
+            last_used: inner.tick,
+        };
+
+        if let Some(prev) = inner.entries.insert(key, entry) {
+            inner.recency.remove(&prev.last_used);
+        }
+
+        let tick = inner.tick;
+        inner.recency.insert(tick, key);
+
+        while inner.entries.len() > self.capacity {
+            let Some((_, lru)) = inner.recency.pop_first() else {
+                break;
+            };
+
+            inner.entries.remove(&lru);
+        }
+    }
+
+    /// Remove the entry so that the next lookup asks the source again.
+    pub fn invalidate(&self, key: K) {
+        let mut inner = self.inner.lock().unwrap();
+
+        if let Some(entry) = inner.entries.remove(&key) {
+            inner.recency.remove(&entry.last_used);
+        }
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        let inner = Inner {
+            entries: HashMap::default(),
+            recency: BTreeMap::new(),
+            tick: 0,
+        };
+
+        Self {
+            inner: Mutex::new(inner),
+            capacity,
+        }
+    }
+}
+
+impl<K, V> Default for FrontCache<K, V>
+where
+    K: Copy + Eq + Hash,
+    V: Clone,
+{
+    fn default() -> Self {
+        Self::with_capacity(Self::CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use eyre::eyre;
+
+    use super::*;
+
+    async fn ok<V>(value: V) -> Result<V> {
+        Ok(value)
+    }
+
+    async fn fail<V>() -> Result<V> {
+        Err(eyre!("pool timed out"))
+    }
+
+    #[tokio::test]
+    async fn serves_fresh_value_without_fetching() {
+        let cache = FrontCache::<u64, u32>::default();
+        let now = Instant::now();
+
+        let value = cache.get_or_fetch_at(1, ok(2), now).await.unwrap();
+        assert_eq!(value, 2);
+
+        let value = cache.get_or_fetch_at(1, ok(3), now).await.unwrap();
+        assert_eq!(value, 2);
+    }
+
+    #[tokio::test]
+    async fn refetches_after_ttl() {
+        let cache = FrontCache::<u64, u32>::default();
+        let now = Instant::now();
+
+        let _ = cache.get_or_fetch_at(1, ok(2), now).await;
+
+        let later = now + FrontCache::<u64, u32>::TTL;
+        let value = cache.get_or_fetch_at(1, ok(3), later).await;
+        assert_eq!(value.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn serves_last_known_value_while_source_fails() {
+        let cache = FrontCache::<u64, Option<u32>>::default();
+        let now = Instant::now();
+
+        let _ = cache.get_or_fetch_at(1, ok(Some(2)), now).await;
+
+        let later = now + Duration::from_secs(600);
+        let value = cache.get_or_fetch_at(1, fail(), later).await;
+        assert_eq!(value.unwrap(), Some(2));
+
+        assert!(cache.get_or_fetch_at(2, fail(), later).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn forgets_values_older_than_max_age() {
+        let cache = FrontCache::<u64, u32>::default();
+        let now = Instant::now();
+
+        let _ = cache.get_or_fetch_at(1, ok(2), now).await;
+
+        let later = now + FrontCache::<u64, u32>::MAX_AGE;
+        assert!(cache.get_or_fetch_at(1, fail(), later).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn evicts_least_recently_used() {
+        let cache = FrontCache::<u64, u32>::with_capacity(2);
+        let now = Instant::now();
+
+        let _ = cache.get_or_fetch_at(1, ok(1), now).await;
+        let _ = cache.get_or_fetch_at(2, ok(2), now).await;
+
+        // Using the first entry makes the second one the least recently used
+        let _ = cache.get_or_fetch_at(1, fail(), now).await;
+        let _ = cache.get_or_fetch_at(3, ok(3), now).await;
+
+        assert_eq!(cache.get_or_fetch_at(1, fail(), now).await.unwrap(), 1);
+        assert_eq!(cache.get_or_fetch_at(3, fail(), now).await.unwrap(), 3);
+        assert!(cache.get_or_fetch_at(2, fail(), now).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn invalidated_entries_are_refetched() {
+        let cache = FrontCache::<u64, u32>::default();
+        let now = Instant::now();
+
+        let _ = cache.get_or_fetch_at(1, ok(2), now).await;
+        cache.invalidate(1);
+
+        let value = cache.get_or_fetch_at(1, ok(3), now).await;
+        assert_eq!(value.unwrap(), 3);
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use bathbot_model::{BgGameScore, HlGameScore, HlVersion};
+use bathbot_model::{BgGameScore, HlGameScore, HlVersion, MapsetTags};
 use bathbot_psql::{
     Database,
     model::games::{DbMapTagsParams, MapsetTagsEntries},
@@ -104,4 +104,24 @@ impl GameManager {
             .await
             .wrap_err("failed to upsert mapset")
     }
+
+    pub async fn bggame_mapset_tags(self, mapset_id: u32) -> Result<Option<MapsetTags>> {
+        self.psql
+            .select_mapset_tags(mapset_id)
+            .await
+            .wrap_err("failed to get mapset tags")
+    }
+
+    /// Returns the previous tags or `None` if the mapset is not stored.
+    pub async fn bggame_update_mapset_tags(
+        self,
+        mapset_id: u32,
+        tags: MapsetTags,
+        editor: Id<UserMarker>,
+    ) -> Result<Option<MapsetTags>> {
+        self.psql
+            .update_mapset_tags(mapset_id, tags, editor)
+            .await
+            .wrap_err("failed to update mapset tags")
+    }
 }
@@ -1,10 +1,25 @@
+use std::sync::atomic::{AtomicI64, Ordering};
+
 use bathbot_cache::{Cache, util::serialize::serialize_using_arena};
-use bathbot_model::{ArchivedPullRequests, PullRequests, PullRequestsAndTags};
+use bathbot_client::ClientError;
+use bathbot_model::{
+    ArchivedPullRequests, ArchivedReleases, PullRequests, PullRequestsAndTags, Releases,
+};
 use eyre::{Report, Result, WrapErr};
+use time::{Duration, OffsetDateTime};
 
 use super::redis::RedisError;
 use crate::core::{BotMetrics, Context};
 
+/// Amount of releases that are requested at once.
+const RELEASE_COUNT: usize = 30;
+
+/// How long to wait after a 429 response that didn't specify a quota reset.
+const RATELIMIT_BACKOFF: Duration = Duration::minutes(1);
+
+/// Unix timestamp at which github's REST quota resets after it was exhausted.
+static QUOTA_RESET: AtomicI64 = AtomicI64::new(0);
+
 #[derive(Copy, Clone)]
 pub struct GithubManager;
 
@@ -55,4 +70,101 @@ impl GithubManager {
 
         Ok(prs)
     }
+
+    /// Returns the most recent releases.
+    ///
+    /// Releases are cached for 30 minutes. While github's quota is exhausted,
+    /// stale releases are served instead. `None` if the quota is exhausted and
+    /// nothing has been cached.
+    pub async fn releases(self) -> Result<Option<GithubReleases>> {
+        const KEY: &str = "github_releases";
+        const FRESH: Duration = Duration::minutes(30);
+        const EXPIRE: u64 = 7 * 24 * 60 * 60; // 7 days as fallback
+
+        let now = OffsetDateTime::now_utc();
+
+        let cached = match Context::cache().fetch::<_, ArchivedReleases>(KEY).await {
+            Ok(Ok(releases)) => match releases.try_deserialize::<Releases>() {
+                Ok(releases) => Some(releases),
+                Err(err) => {
+                    warn!(?err, "Failed to deserialize github releases");
+
+                    None
+                }
+            },
+            Ok(Err(_)) => None,
+            Err(err) => {
+                warn!(?err, "Failed to fetch github releases");
+
+                None
+            }
+        };
+
+        let stale = match cached {
+            Some(releases) if now < releases.fetched_at + FRESH => {
+                BotMetrics::inc_redis_hit("github releases");
+
+                return Ok(Some(GithubReleases {
+                    releases,
+                    stale: false,
+                }));
+            }
+            stale => stale.map(|releases| GithubReleases {
+                releases,
+                stale: true,
+            }),
+        };
+
+        // Don't bother github until the quota resets
+        if now.unix_timestamp() < QUOTA_RESET.load(Ordering::Relaxed) {
+            return Ok(stale);
+        }
+
+        let (inner, quota) = match Context::client().github_releases(RELEASE_COUNT).await {
+            Ok(tuple) => tuple,
+            Err(ClientError::GithubQuotaExhausted { reset }) => {
+                warn!(%reset, "Github quota exhausted");
+                QUOTA_RESET.store(reset.unix_timestamp(), Ordering::Relaxed);
+
+                return Ok(stale);
+            }
+            Err(ClientError::Ratelimited) => {
+                warn!("Github ratelimited without quota headers");
+                let retry_at = now + RATELIMIT_BACKOFF;
+                QUOTA_RESET.store(retry_at.unix_timestamp(), Ordering::Relaxed);
+
+                return Ok(stale);
+            }
+            Err(err) => return Err(Report::new(err).wrap_err("Failed to get github releases")),
+        };
+
+        if let Some(quota) = quota.filter(|quota| quota.remaining == 0) {
+            QUOTA_RESET.store(quota.reset.unix_timestamp(), Ordering::Relaxed);
+        }
+
+        let releases = Releases {
+            inner,
+            fetched_at: now,
+        };
+
+        let bytes = serialize_using_arena(&releases).map_err(RedisError::Serialization)?;
+
+        if let Err(err) = Context::cache()
+            .store_new(KEY, bytes.as_slice(), EXPIRE)
+            .await
+        {
+            warn!(?err, "Failed to store github releases");
+        }
+
+        Ok(Some(GithubReleases {
+            releases,
+            stale: false,
+        }))
+    }
+}
+
+pub struct GithubReleases {
+    pub releases: Releases,
+    /// Whether the releases are outdated because github's quota is exhausted
+    pub stale: bool,
 }
@@ -1,5 +1,10 @@
-use bathbot_psql::{Database, model::configs::GuildConfig};
-use bathbot_util::IntHasher;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use bathbot_psql::{
+    Database,
+    model::configs::{GuildConfig, ScoreData, resolve},
+};
+use bathbot_util::{IntHasher, i18n::Lang};
 use eyre::{Result, WrapErr};
 use papaya::HashMap as PapayaMap;
 use twilight_model::id::{Id, marker::GuildMarker};
@@ -10,13 +15,21 @@ type GuildConfigs = PapayaMap<Id<GuildMarker>, GuildConfig, IntHasher>;
 pub struct GuildConfigManager {
     psql: &'static Database,
     guild_configs: &'static GuildConfigs,
+    /// Whether `guild_configs` was filled from the database, i.e. `false`
+    /// after starting without a database until it becomes reachable again
+    loaded: &'static AtomicBool,
 }
 
 impl GuildConfigManager {
-    pub fn new(psql: &'static Database, guild_configs: &'static GuildConfigs) -> Self {
+    pub fn new(
+        psql: &'static Database,
+        guild_configs: &'static GuildConfigs,
+        loaded: &'static AtomicBool,
+    ) -> Self {
         Self {
             psql,
             guild_configs,
+            loaded,
         }
     }
 
@@ -33,10 +46,23 @@ impl GuildConfigManager {
         let config = GuildConfig::default();
         let res = f(&config);
 
-        if let Err(err) = self.store(guild_id, config).await {
-            warn!("{err:?}");
+        // Without the configs from the database, the guild might very well
+        // have a config already so the default must not overwrite it
+        if !self.loaded.load(Ordering::Acquire) {
+            return res;
         }
 
+        // Storing in the database happens in the background so that peeks
+        // don't wait on it, e.g. while the database is unreachable
+        self.guild_configs.pin().insert(guild_id, config.clone());
+        let psql = self.psql;
+
+        tokio::spawn(async move {
+            if let Err(err) = psql.upsert_guild_config(guild_id, &config).await {
+                warn!(?err, "Failed to store default guild config");
+            }
+        });
+
         res
     }
 
@@ -52,10 +78,43 @@ impl GuildConfigManager {
         prefix_opt.unwrap_or_else(|| GuildConfig::DEFAULT_PREFIX.to_owned())
     }
 
+    /// The language of user-facing strings; English outside of guilds.
+    pub async fn lang(self, guild_id: Option<Id<GuildMarker>>) -> Lang {
+        match guild_id {
+            Some(guild_id) => self
+                .peek(guild_id, |config| config.lang)
+                .await
+                .unwrap_or_default(),
+            None => Lang::default(),
+        }
+    }
+
+    /// Resolve the [`ScoreData`] for a command.
+    ///
+    /// The guild config is only peeked if neither the arguments nor the user
+    /// config specify the score data.
+    pub async fn resolve_score_data(
+        self,
+        guild_id: Option<Id<GuildMarker>>,
+        args: Option<ScoreData>,
+        user: Option<ScoreData>,
+    ) -> ScoreData {
+        let guild = match (args.or(user), guild_id) {
+            (None, Some(guild_id)) => self.peek(guild_id, |config| config.score_data).await,
+            _ => None,
+        };
+
+        resolve::score_data(args, user, guild)
+    }
+
     pub async fn update<F, O>(self, guild_id: Id<GuildMarker>, f: F) -> Result<O>
     where
         F: FnOnce(&mut GuildConfig) -> O,
     {
+        if !self.loaded.load(Ordering::Acquire) {
+            bail!("Guild configs have not been loaded from the database yet");
+        }
+
         let mut config = match self.guild_configs.pin().get(&guild_id) {
             Some(config) => config.to_owned(),
             None => GuildConfig::default(),
@@ -67,6 +126,41 @@ impl GuildConfigManager {
         Ok(res)
     }
 
+    /// Replace the whole [`GuildConfig`] of a guild.
+    ///
+    /// Unlike [`GuildConfigManager::update`], the in-memory config is only
+    /// replaced once the database accepted the new config so that a failed
+    /// replacement leaves the previous config in place.
+    pub async fn replace(self, guild_id: Id<GuildMarker>, config: GuildConfig) -> Result<()> {
+        self.psql
+            .upsert_guild_config(guild_id, &config)
+            .await
+            .wrap_err("failed to store guild config")?;
+
+        self.guild_configs.pin().insert(guild_id, config);
+
+        Ok(())
+    }
+
+    /// Fill the configs from the database after having started without one.
+    pub async fn reload(self) -> Result<()> {
+        let configs = self
+            .psql
+            .select_guild_configs::<IntHasher>()
+            .await
+            .wrap_err("Failed to get guild configs")?;
+
+        let guild_configs = self.guild_configs.pin();
+
+        for (guild_id, config) in configs {
+            guild_configs.insert(guild_id, config);
+        }
+
+        self.loaded.store(true, Ordering::Release);
+
+        Ok(())
+    }
+
     async fn store(&self, guild_id: Id<GuildMarker>, config: GuildConfig) -> Result<()> {
         let res = self
             .psql
@@ -79,3 +173,54 @@ impl GuildConfigManager {
         res
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn peek_with_unreachable_database() {
+        // The pool connects lazily so nothing is listening on that port
+        let psql = Database::new("postgres://bathbot@127.0.0.1:1/bathbot").unwrap();
+        let psql = Box::leak(Box::new(psql));
+        let guild_configs = Box::leak(Box::new(GuildConfigs::default()));
+        let loaded = Box::leak(Box::new(AtomicBool::new(true)));
+        let manager = GuildConfigManager::new(psql, guild_configs, loaded);
+
+        let guild_id = Id::new(1);
+
+        let prefixes = tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            manager.peek(guild_id, |config| config.prefixes.clone()),
+        )
+        .await
+        .expect("peek must not wait on the database");
+
+        assert_eq!(prefixes, GuildConfig::default().prefixes);
+        assert!(guild_configs.pin().contains_key(&guild_id));
+    }
+
+    #[tokio::test]
+    async fn peek_before_configs_were_loaded() {
+        let psql = Database::new("postgres://bathbot@127.0.0.1:1/bathbot").unwrap();
+        let psql = Box::leak(Box::new(psql));
+        let guild_configs = Box::leak(Box::new(GuildConfigs::default()));
+        let loaded = Box::leak(Box::new(AtomicBool::new(false)));
+        let manager = GuildConfigManager::new(psql, guild_configs, loaded);
+
+        let guild_id = Id::new(1);
+        let prefixes = manager
+            .peek(guild_id, |config| config.prefixes.clone())
+            .await;
+
+        // Served from memory but neither cached nor stored so that the real
+        // config is loaded once the database is back
+        assert_eq!(prefixes, GuildConfig::default().prefixes);
+        assert!(!guild_configs.pin().contains_key(&guild_id));
+
+        let res = manager
+            .update(guild_id, |config| config.prefixes.clear())
+            .await;
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,75 @@
+use bathbot_psql::{Database, model::osu::MapsetWatch};
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::RankStatus;
+use time::OffsetDateTime;
+use twilight_model::id::{Id, marker::UserMarker};
+
+use crate::core::Context;
+
+#[derive(Copy, Clone)]
+pub struct MapsetWatchManager {
+    psql: &'static Database,
+}
+
+impl MapsetWatchManager {
+    pub fn new() -> Self {
+        Self {
+            psql: Context::psql(),
+        }
+    }
+
+    pub async fn all(self) -> Result<Vec<MapsetWatch>> {
+        self.psql
+            .select_mapset_watches()
+            .await
+            .wrap_err("Failed to get mapset watches")
+    }
+
+    pub async fn get(self, user: Id<UserMarker>) -> Result<Vec<MapsetWatch>> {
+        self.psql
+            .select_user_mapset_watches(user)
+            .await
+            .wrap_err("Failed to get user mapset watches")
+    }
+
+    pub async fn add(self, watch: &MapsetWatch) -> Result<()> {
+        self.psql
+            .upsert_mapset_watch(watch)
+            .await
+            .wrap_err("Failed to upsert mapset watch")
+    }
+
+    /// Returns whether the user was watching the mapset.
+    pub async fn remove(self, user: Id<UserMarker>, mapset_id: u32) -> Result<bool> {
+        self.psql
+            .delete_mapset_watch(user, mapset_id)
+            .await
+            .wrap_err("Failed to delete mapset watch")
+    }
+
+    pub async fn remove_mapset(self, mapset_id: u32) -> Result<()> {
+        self.psql
+            .delete_mapset_watches(mapset_id)
+            .await
+            .wrap_err("Failed to delete mapset watches")
+    }
+
+    pub async fn update_status(
+        self,
+        mapset_id: u32,
+        status: RankStatus,
+        expires_at: Option<OffsetDateTime>,
+    ) -> Result<()> {
+        self.psql
+            .update_mapset_watch_status(mapset_id, status, expires_at)
+            .await
+            .wrap_err("Failed to update mapset watch status")
+    }
+
+    pub async fn remove_expired(self, now: OffsetDateTime) -> Result<u64> {
+        self.psql
+            .delete_expired_mapset_watches(now)
+            .await
+            .wrap_err("Failed to delete expired mapset watches")
+    }
+}
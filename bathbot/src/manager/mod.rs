@@ -3,25 +3,28 @@ pub use self::twitch::TwitchManager;
 pub use self::{
     bookmarks::BookmarkManager,
     games::GameManager,
-    github::GithubManager,
+    github::{GithubManager, GithubReleases},
     guild_config::GuildConfigManager,
     huismetbenen_country::HuismetbenenCountryManager,
+    mapset_watch::MapsetWatchManager,
     osu_map::{MapError, MapManager, OsuMap, OsuMapSlim},
     osu_scores::ScoresManager,
     osu_user::OsuUserManager,
-    pp::{Mods, PpManager},
-    rank_pp_approx::ApproxManager,
+    pp::{CachedAttributes, Mods, PpManager, difficulty_mods, performance_mods},
+    rank_pp_approx::{ApproxManager, PpCurves},
     replay::{ReplayError, ReplayManager, ReplaySettings},
-    user_config::UserConfigManager,
+    user_config::{UserConfigManager, UserLinks},
 };
 
 pub mod redis;
 
 mod bookmarks;
+mod front_cache;
 mod games;
 mod github;
 mod guild_config;
 mod huismetbenen_country;
+mod mapset_watch;
 mod osu_map;
 mod osu_scores;
 mod osu_user;
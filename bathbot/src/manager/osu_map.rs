@@ -6,7 +6,9 @@ use std::{
 };
 
 use bathbot_client::ClientError;
-use bathbot_psql::model::osu::{ArtistTitle, DbBeatmap, DbBeatmapset, DbMapContent, MapVersion};
+use bathbot_psql::model::osu::{
+    ArtistTitle, DbBeatmap, DbBeatmapset, DbMapContent, DbMapMetadata, MapVersion, PartialMap,
+};
 use bathbot_util::{
     ExponentialBackoff, IntHasher,
     query::{FilterCriteria, RegularCriteria, Searchable},
@@ -18,10 +20,11 @@ use rosu_pp::{
     any::DifficultyAttributes,
     model::{beatmap::BeatmapAttributesBuilder, mode::GameMode as MapMode},
 };
-use rosu_v2::prelude::{BeatmapsetExtended, GameMode, OsuError, RankStatus};
+use rosu_v2::prelude::{BeatmapsetExtended, GameMode, OsuError, RankStatus, Score};
 use thiserror::Error;
 use time::OffsetDateTime;
 use tokio::time::sleep;
+use tracing::Instrument;
 
 use super::{PpManager, pp::Mods};
 use crate::{core::Context, util::NativeCriteria};
@@ -75,6 +78,27 @@ impl MapManager {
         Ok(pp_map)
     }
 
+    /// Hex-encoded MD5 hash of the stored .osu file, comparable to the
+    /// checksum provided by the osu!api.
+    pub async fn file_checksum(self, map_id: u32) -> Result<Option<String>> {
+        let checksum = Context::psql()
+            .select_beatmap_file_checksum(map_id)
+            .await
+            .wrap_err("Failed to get map file checksum")?;
+
+        Ok(checksum)
+    }
+
+    /// Re-download the map's file and metadata as if the stored checksum
+    /// was outdated.
+    pub async fn refresh_file(self, map_id: u32) -> Result<Beatmap> {
+        let (pp_map, _) = self
+            .prepare_map(map_id, DbMapContent::ChecksumMismatch)
+            .await?;
+
+        Ok(pp_map)
+    }
+
     /// Returns `Ok(None)` if the map is too suspicious.
     pub async fn difficulty(
         self,
@@ -168,6 +192,50 @@ impl MapManager {
         })
     }
 
+    /// Artist, title, version, mode, and thumbnail of a map.
+    ///
+    /// Unlike [`MapManager::map`], partially stored maps are used too.
+    pub async fn metadata(self, map_id: u32) -> Result<DbMapMetadata> {
+        let metadata_opt = Context::psql()
+            .select_map_metadata(map_id)
+            .await
+            .wrap_err("Failed to get map metadata")?;
+
+        if let Some(metadata) = metadata_opt {
+            return Ok(metadata);
+        }
+
+        let mapset = match Context::osu().beatmapset_from_map_id(map_id).await {
+            Ok(mapset) => mapset,
+            Err(OsuError::NotFound) => return Err(MapError::NotFound),
+            Err(err) => {
+                return Err(MapError::Report(
+                    Report::new(err).wrap_err("Failed to retrieve mapset"),
+                ));
+            }
+        };
+
+        let map = mapset
+            .maps
+            .as_ref()
+            .and_then(|maps| maps.iter().find(|map| map.map_id == map_id))
+            .wrap_err("missing map in mapset")?;
+
+        let metadata = DbMapMetadata {
+            map_id: map.map_id as i32,
+            mapset_id: mapset.mapset_id as i32,
+            artist: mapset.artist.clone(),
+            title: mapset.title.clone(),
+            version: map.version.clone(),
+            gamemode: map.mode as i16,
+            thumbnail: mapset.covers.list.clone(),
+        };
+
+        tokio::spawn(async move { self.store(&mapset).await }.in_current_span());
+
+        Ok(metadata)
+    }
+
     fn mapset_to_map_versions(mapset: &BeatmapsetExtended) -> Vec<MapVersion> {
         mapset
             .maps
@@ -205,7 +273,7 @@ impl MapManager {
 
         let versions = Self::mapset_to_map_versions(&mapset);
 
-        tokio::spawn(async move { self.store(&mapset).await });
+        tokio::spawn(async move { self.store(&mapset).await }.in_current_span());
 
         Ok(versions)
     }
@@ -232,7 +300,7 @@ impl MapManager {
 
         let versions = Self::mapset_to_map_versions(&mapset);
 
-        tokio::spawn(async move { self.store(&mapset).await });
+        tokio::spawn(async move { self.store(&mapset).await }.in_current_span());
 
         Ok(versions)
     }
@@ -243,13 +311,31 @@ impl MapManager {
         }
     }
 
+    /// Backfill the metadata of maps and mapsets embedded in scores.
+    ///
+    /// Maps that are not yet stored will still be fetched fully when needed.
+    pub async fn store_partial(self, scores: &[Score]) {
+        let maps: Vec<_> = scores
+            .iter()
+            .filter_map(|score| PartialMap::new(score.map.as_ref()?, score.mapset.as_deref()?))
+            .collect();
+
+        if maps.is_empty() {
+            return;
+        }
+
+        if let Err(err) = Context::psql().upsert_partial_maps(&maps).await {
+            warn!(?err, "Failed to store partial maps");
+        }
+    }
+
     /// Request a [`BeatmapsetExtended`] from a map id and turn it into a
     /// [`OsuMapSlim`]
     async fn retrieve_map(self, map_id: u32) -> Result<OsuMapSlim> {
         match Context::osu().beatmapset_from_map_id(map_id).await {
             Ok(mapset) => {
                 let mapset_clone = mapset.clone();
-                tokio::spawn(async move { self.store(&mapset_clone).await });
+                tokio::spawn(async move { self.store(&mapset_clone).await }.in_current_span());
 
                 OsuMapSlim::try_from_mapset(mapset, map_id)
             }
@@ -265,7 +351,7 @@ impl MapManager {
         match Context::osu().beatmapset(mapset_id).await {
             Ok(mapset) => {
                 let mapset_clone = mapset.clone();
-                tokio::spawn(async move { self.store(&mapset_clone).await });
+                tokio::spawn(async move { self.store(&mapset_clone).await }.in_current_span());
 
                 Ok(mapset)
             }
@@ -1,11 +1,13 @@
-use std::{cmp, slice};
+use std::{cmp, sync::Arc};
 
+use bathbot_psql::model::osu::MapFirstPlace;
 use eyre::{Result, WrapErr};
 use rosu_v2::{
     OsuResult,
     model::score::BeatmapUserScore,
     prelude::{GameMode, GameModsIntermode, OsuError, Score},
 };
+use tracing::Instrument;
 
 use super::redis::osu::{CachedUser, UserArgs, UserArgsError, UserArgsSlim};
 use crate::core::Context;
@@ -33,18 +35,34 @@ impl ScoresManager {
             .legacy_only(legacy_scores)
             .legacy_scores(legacy_scores);
 
+        // Only the unfiltered leaderboard's top score is the map's first place
+        let is_global = mods.is_none() && !legacy_scores;
+
         if let Some(mods) = mods {
             req = req.mods(mods);
         }
 
         let scores = req.await.wrap_err("Failed to get map leaderboard")?.scores;
 
-        let scores_clone = Box::from(scores.as_slice());
-        tokio::spawn(async move { self.store(&scores_clone).await });
+        if let Some(score) = scores.first().filter(|_| is_global) {
+            let fut = Self::store_first_place(map_id, mode, score.id, score.user_id, score.pp);
+            tokio::spawn(fut.in_current_span());
+        }
+
+        self.store(Arc::from(scores.as_slice()));
 
         Ok(scores)
     }
 
+    /// First places of a map that were observed through
+    /// [`ScoresManager::map_leaderboard`], oldest first.
+    pub async fn map_first_places(self, map_id: u32, mode: GameMode) -> Result<Vec<MapFirstPlace>> {
+        Context::psql()
+            .select_map_first_places(map_id, mode)
+            .await
+            .wrap_err("Failed to get map first places")
+    }
+
     pub async fn user_on_map_single(
         self,
         user_id: u32,
@@ -65,8 +83,7 @@ impl ScoresManager {
 
         let score = req.await?;
 
-        let score_inner = score.score.clone();
-        tokio::spawn(async move { self.store(slice::from_ref(&score_inner)).await });
+        self.store(Arc::from([score.score.clone()]));
 
         Ok(score)
     }
@@ -106,9 +123,33 @@ impl ScoresManager {
         }
     }
 
-    async fn store(self, scores: &[Score]) {
-        if let Err(err) = Context::psql().insert_scores_mapsets(scores).await {
-            warn!(?err, "Failed to store scores");
+    /// Store the scores and backfill their maps in the background.
+    fn store(self, scores: Arc<[Score]>) {
+        let scores_clone = Arc::clone(&scores);
+
+        let store_fut = async move {
+            if let Err(err) = Context::psql().insert_scores_mapsets(&scores).await {
+                warn!(?err, "Failed to store scores");
+            }
+        };
+
+        tokio::spawn(store_fut.in_current_span());
+
+        let backfill_fut = async move { Context::osu_map().store_partial(&scores_clone).await };
+        tokio::spawn(backfill_fut.in_current_span());
+    }
+
+    async fn store_first_place(
+        map_id: u32,
+        mode: GameMode,
+        score_id: u64,
+        user_id: u32,
+        pp: Option<f32>,
+    ) {
+        let store_fut = Context::psql().insert_map_first_place(map_id, mode, score_id, user_id, pp);
+
+        if let Err(err) = store_fut.await {
+            warn!(?err, map_id, "Failed to store map first place");
         }
     }
 }
@@ -250,8 +291,7 @@ impl ScoreArgs {
             }
         }
 
-        let scores_clone = Box::from(scores.as_slice());
-        tokio::spawn(async move { self.manager.store(&scores_clone).await });
+        self.manager.store(Arc::from(scores.as_slice()));
 
         Ok(scores)
     }
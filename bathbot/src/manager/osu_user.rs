@@ -97,4 +97,113 @@ impl OsuUserManager {
             .await
             .wrap_err("Failed to delete osu user data")
     }
+
+    /// Record a strike for a linked user that could not be found.
+    ///
+    /// After too many strikes, the link is flagged as possibly restricted.
+    pub async fn strike_link(self, user_id: u32) -> Result<()> {
+        let linked = self
+            .psql
+            .select_user_discord_id_by_osu_id(user_id)
+            .await
+            .wrap_err("Failed to check whether user is linked")?
+            .is_some();
+
+        if !linked {
+            return Ok(());
+        }
+
+        let strikes = Context::cache()
+            .add_link_strike(user_id)
+            .await
+            .wrap_err("Failed to add link strike")?;
+
+        if LinkStrike::new(strikes) == LinkStrike::Flag {
+            self.psql
+                .upsert_flagged_osu_link(user_id)
+                .await
+                .wrap_err("Failed to flag link")?;
+
+            // Strikes must stay around for the flag to be cleared
+            Context::cache()
+                .persist_link_strikes(user_id)
+                .await
+                .wrap_err("Failed to persist link strikes")?;
+        }
+
+        Ok(())
+    }
+
+    /// Clear strikes and the flag of a user that was found.
+    pub async fn clear_link_strikes(self, user_id: u32) -> Result<()> {
+        // Practically all found users have no strikes so only check for them
+        // instead of deleting on every request
+        let has_strikes = Context::cache()
+            .has_link_strikes(user_id)
+            .await
+            .wrap_err("Failed to check link strikes")?;
+
+        if !has_strikes {
+            return Ok(());
+        }
+
+        let had_strikes = Context::cache()
+            .clear_link_strikes(user_id)
+            .await
+            .wrap_err("Failed to clear link strikes")?;
+
+        // Flagged links always have strikes so there is no need to hit the
+        // database otherwise
+        if had_strikes {
+            self.psql
+                .delete_flagged_osu_link(user_id)
+                .await
+                .wrap_err("Failed to unflag link")?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn is_link_flagged(self, user_id: u32) -> Result<bool> {
+        self.psql
+            .select_osu_link_flagged(user_id)
+            .await
+            .wrap_err("Failed to check whether link is flagged")
+    }
+}
+
+/// Outcome of adding a strike to a linked user that could not be found.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum LinkStrike {
+    /// Not enough strikes yet
+    Counted,
+    /// The link should be flagged as possibly restricted
+    Flag,
+}
+
+impl LinkStrike {
+    const LIMIT: u32 = 3;
+
+    fn new(strikes: u32) -> Self {
+        if strikes >= Self::LIMIT {
+            Self::Flag
+        } else {
+            Self::Counted
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn link_strikes() {
+        assert_eq!(LinkStrike::new(1), LinkStrike::Counted);
+        assert_eq!(LinkStrike::new(2), LinkStrike::Counted);
+        assert_eq!(LinkStrike::new(3), LinkStrike::Flag);
+
+        // Strikes are persisted once flagged so they keep counting up
+        assert_eq!(LinkStrike::new(4), LinkStrike::Flag);
+    }
 }
@@ -1,18 +1,29 @@
 use std::borrow::Cow;
 
 use bathbot_model::{OsuStatsScore, ScoreSlim};
+use bathbot_util::osu::CatchHitresults;
 use rosu_pp::{
     Beatmap, Difficulty,
     any::{DifficultyAttributes, PerformanceAttributes, ScoreState},
     model::mode::GameMode as Mode,
 };
 use rosu_v2::{
-    model::mods::GameMods,
-    prelude::{GameMode, Grade, Score, ScoreStatistics},
+    model::mods::{
+        GameMod, GameMods,
+        generated_mods::{
+            DaycoreCatch, DaycoreMania, DaycoreOsu, DaycoreTaiko, DoubleTimeCatch, DoubleTimeMania,
+            DoubleTimeOsu, DoubleTimeTaiko, HalfTimeCatch, HalfTimeMania, HalfTimeOsu,
+            HalfTimeTaiko, NightcoreCatch, NightcoreMania, NightcoreOsu, NightcoreTaiko,
+        },
+    },
+    prelude::{GameModIntermode, GameMode, Grade, Score, ScoreStatistics},
 };
 
 use super::OsuMap;
-use crate::commands::{osu::LeaderboardScore, utility::ScoreEmbedDataRaw};
+use crate::{
+    commands::{osu::LeaderboardScore, utility::ScoreEmbedDataRaw},
+    core::BotMetrics,
+};
 
 #[derive(Clone)]
 pub struct PpManager<'m> {
@@ -170,6 +181,77 @@ impl<'m> PpManager<'m> {
     }
 }
 
+// It takes a long time to calculate attributes for maps like /b/5023039 with
+// 32k+ objects so we want to cache as much as possible in case users have
+// the same long map multiple times with equivalent mods in their recent plays.
+#[derive(Default)]
+pub struct CachedAttributes {
+    // `GameMods` implements neither `Hash` nor `Ord` so we cannot use
+    // `HashMap` or `BTreeMap` and need to use `Vec` instead.
+    entries: Vec<CachedEntry>,
+}
+
+impl CachedAttributes {
+    /// Returns `None` if the map is too suspicious.
+    pub async fn get(&mut self, map: &Beatmap, score: &Score) -> Option<DifficultyAttributes> {
+        self.get_blocking(map, score)
+    }
+
+    /// Same as [`CachedAttributes::get`] but for blocking contexts such as
+    /// [`tokio::task::spawn_blocking`].
+    pub fn get_blocking(&mut self, map: &Beatmap, score: &Score) -> Option<DifficultyAttributes> {
+        let map_id = score.map_id;
+        let lazer = score.set_on_lazer;
+        let mods = difficulty_mods(score.mode, &score.mods);
+
+        let res = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.map_id == map_id && entry.mods == mods && entry.lazer == lazer);
+
+        if let Some(entry) = res {
+            // Only hit thanks to the difficulty mods if these exact mods
+            // were not seen before
+            let canonical = !entry.seen.contains(&score.mods);
+
+            if canonical {
+                entry.seen.push(score.mods.clone());
+            }
+
+            BotMetrics::inc_difficulty_cache_hit(canonical);
+
+            return Some(entry.attrs.clone());
+        } else if map.check_suspicion().is_err() {
+            return None;
+        }
+
+        let attrs = Difficulty::new()
+            .mods(mods.clone())
+            .lazer(lazer)
+            .calculate(map);
+
+        self.entries.push(CachedEntry {
+            map_id,
+            lazer,
+            mods,
+            seen: vec![score.mods.clone()],
+            attrs: attrs.clone(),
+        });
+
+        Some(attrs)
+    }
+}
+
+struct CachedEntry {
+    map_id: u32,
+    lazer: bool,
+    /// Difficulty-relevant mods
+    mods: GameMods,
+    /// Original mods that were looked up for this entry
+    seen: Vec<GameMods>,
+    attrs: DifficultyAttributes,
+}
+
 pub struct ScoreData {
     state: ScoreState,
     mods: Mods,
@@ -254,6 +336,8 @@ impl<'s> From<&'s ScoreEmbedDataRaw> for ScoreData {
 }
 
 fn stats_to_state(max_combo: u32, mode: GameMode, stats: &ScoreStatistics) -> ScoreState {
+    let catch = CatchHitresults::new(stats);
+
     let n_geki = match mode {
         GameMode::Osu | GameMode::Taiko | GameMode::Catch => 0,
         GameMode::Mania => stats.good,
@@ -261,19 +345,19 @@ fn stats_to_state(max_combo: u32, mode: GameMode, stats: &ScoreStatistics) -> Sc
 
     let n_katu = match mode {
         GameMode::Osu | GameMode::Taiko => 0,
-        GameMode::Catch => stats.small_tick_miss.max(stats.good),
+        GameMode::Catch => catch.tiny_droplet_misses,
         GameMode::Mania => stats.good,
     };
 
     let n100 = match mode {
         GameMode::Osu | GameMode::Taiko | GameMode::Mania => stats.ok,
-        GameMode::Catch => stats.large_tick_hit.max(stats.ok),
+        GameMode::Catch => catch.droplets,
     };
 
     let n50 = match mode {
         GameMode::Osu | GameMode::Mania => stats.meh,
         GameMode::Taiko => 0,
-        GameMode::Catch => stats.small_tick_hit.max(stats.meh),
+        GameMode::Catch => catch.tiny_droplets,
     };
 
     let osu_large_tick_hits = match mode {
@@ -301,7 +385,10 @@ fn stats_to_state(max_combo: u32, mode: GameMode, stats: &ScoreStatistics) -> Sc
         n300: stats.great,
         n100,
         n50,
-        misses: stats.miss,
+        misses: match mode {
+            GameMode::Osu | GameMode::Taiko | GameMode::Mania => stats.miss,
+            GameMode::Catch => catch.misses,
+        },
     }
 }
 
@@ -327,3 +414,314 @@ impl From<GameMods> for Mods {
         Self::new(mods)
     }
 }
+
+/// Difficulty-relevant form of mods, used to key cached difficulty attributes.
+///
+/// Mods that don't change the difficulty attributes of the mode are removed,
+/// NC and DC are turned into DT and HT, and speed changes equal to the default
+/// rate are cleared so that equivalent combinations share a key.
+///
+/// Performance attributes still need the original mods.
+pub fn difficulty_mods(mode: GameMode, mods: &GameMods) -> GameMods {
+    mods.iter()
+        .filter(|gamemod| affects_difficulty(mode, gamemod.intermode()))
+        .map(canonical_rate)
+        .collect()
+}
+
+/// Performance-relevant form of mods, used to key stored values of converts.
+///
+/// Same as [`difficulty_mods`] except that no mods are removed.
+pub fn performance_mods(mods: &GameMods) -> GameMods {
+    mods.iter().map(canonical_rate).collect()
+}
+
+/// Whether the mod may change difficulty attributes of the mode.
+///
+/// Unlisted mods are assumed to affect difficulty so that a missing rule only
+/// costs a cache hit rather than producing wrong attributes.
+fn affects_difficulty(mode: GameMode, gamemod: GameModIntermode) -> bool {
+    match (mode, gamemod) {
+        // Failing conditions, autoplay, and purely visual or audible mods
+        (
+            _,
+            GameModIntermode::NoFail
+            | GameModIntermode::SuddenDeath
+            | GameModIntermode::Perfect
+            | GameModIntermode::Autoplay
+            | GameModIntermode::Cinema
+            | GameModIntermode::Muted
+            | GameModIntermode::Blinds
+            | GameModIntermode::NoScope,
+        ) => false,
+        // Only osu!standard considers visibility for its flashlight rating
+        (
+            GameMode::Taiko | GameMode::Catch | GameMode::Mania,
+            GameModIntermode::Hidden | GameModIntermode::Flashlight,
+        ) => false,
+        // Mania strains don't depend on column order or the visible area
+        (
+            GameMode::Mania,
+            GameModIntermode::FadeIn | GameModIntermode::Cover | GameModIntermode::Mirror,
+        ) => false,
+        _ => true,
+    }
+}
+
+/// Turn NC and DC into DT and HT and drop settings that don't affect the
+/// clock rate.
+fn canonical_rate(gamemod: &GameMod) -> GameMod {
+    const DT_RATE: f64 = 1.5;
+    const HT_RATE: f64 = 0.75;
+
+    macro_rules! canonical_rate {
+        ( $( $dt:ident, $nc:ident, $ht:ident, $dc:ident; )* ) => {
+            match gamemod {
+                $(
+                    GameMod::$dt($dt { speed_change, .. })
+                    | GameMod::$nc($nc { speed_change, .. }) => GameMod::$dt($dt {
+                        speed_change: speed_change.filter(|&rate| rate != DT_RATE),
+                        ..Default::default()
+                    }),
+                    GameMod::$ht($ht { speed_change, .. })
+                    | GameMod::$dc($dc { speed_change, .. }) => GameMod::$ht($ht {
+                        speed_change: speed_change.filter(|&rate| rate != HT_RATE),
+                        ..Default::default()
+                    }),
+                )*
+                _ => gamemod.to_owned(),
+            }
+        };
+    }
+
+    canonical_rate! {
+        DoubleTimeOsu, NightcoreOsu, HalfTimeOsu, DaycoreOsu;
+        DoubleTimeTaiko, NightcoreTaiko, HalfTimeTaiko, DaycoreTaiko;
+        DoubleTimeCatch, NightcoreCatch, HalfTimeCatch, DaycoreCatch;
+        DoubleTimeMania, NightcoreMania, HalfTimeMania, DaycoreMania;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODES: [GameMode; 4] = [
+        GameMode::Osu,
+        GameMode::Taiko,
+        GameMode::Catch,
+        GameMode::Mania,
+    ];
+
+    fn mods(mode: GameMode, acronyms: &[&str]) -> GameMods {
+        acronyms
+            .iter()
+            .map(|acronym| GameMod::new(acronym, mode))
+            .collect()
+    }
+
+    fn same_key(mode: GameMode, a: &GameMods, b: &GameMods) -> bool {
+        difficulty_mods(mode, a) == difficulty_mods(mode, b)
+    }
+
+    #[test]
+    fn irrelevant_in_every_mode() {
+        for mode in MODES {
+            for gamemod in [
+                GameModIntermode::NoFail,
+                GameModIntermode::SuddenDeath,
+                GameModIntermode::Perfect,
+                GameModIntermode::Autoplay,
+                GameModIntermode::Cinema,
+                GameModIntermode::Muted,
+                GameModIntermode::Blinds,
+                GameModIntermode::NoScope,
+            ] {
+                assert!(
+                    !affects_difficulty(mode, gamemod),
+                    "{gamemod:?} in {mode:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn relevant_in_every_mode() {
+        for mode in MODES {
+            for gamemod in [
+                GameModIntermode::Easy,
+                GameModIntermode::HardRock,
+                GameModIntermode::HalfTime,
+                GameModIntermode::Daycore,
+                GameModIntermode::DoubleTime,
+                GameModIntermode::Nightcore,
+                GameModIntermode::DifficultyAdjust,
+                GameModIntermode::Classic,
+            ] {
+                assert!(affects_difficulty(mode, gamemod), "{gamemod:?} in {mode:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn visibility_mods_per_mode() {
+        for gamemod in [
+            GameModIntermode::Hidden,
+            GameModIntermode::Flashlight,
+            GameModIntermode::Traceable,
+        ] {
+            assert!(affects_difficulty(GameMode::Osu, gamemod), "{gamemod:?}");
+        }
+
+        for mode in [GameMode::Taiko, GameMode::Catch, GameMode::Mania] {
+            assert!(!affects_difficulty(mode, GameModIntermode::Hidden));
+            assert!(!affects_difficulty(mode, GameModIntermode::Flashlight));
+        }
+    }
+
+    #[test]
+    fn osu_specific_mods() {
+        for gamemod in [
+            GameModIntermode::TouchDevice,
+            GameModIntermode::Relax,
+            GameModIntermode::Autopilot,
+            GameModIntermode::SpunOut,
+            GameModIntermode::Mirror,
+        ] {
+            assert!(affects_difficulty(GameMode::Osu, gamemod), "{gamemod:?}");
+        }
+    }
+
+    #[test]
+    fn mania_specific_mods() {
+        for gamemod in [
+            GameModIntermode::FadeIn,
+            GameModIntermode::Cover,
+            GameModIntermode::Mirror,
+        ] {
+            assert!(!affects_difficulty(GameMode::Mania, gamemod), "{gamemod:?}");
+        }
+
+        for gamemod in [
+            GameModIntermode::FourKeys,
+            GameModIntermode::SevenKeys,
+            GameModIntermode::Random,
+        ] {
+            assert!(affects_difficulty(GameMode::Mania, gamemod), "{gamemod:?}");
+        }
+    }
+
+    #[test]
+    fn rate_mods_are_normalized() {
+        for mode in MODES {
+            assert!(same_key(mode, &mods(mode, &["NC"]), &mods(mode, &["DT"])));
+            assert!(same_key(mode, &mods(mode, &["DC"]), &mods(mode, &["HT"])));
+            assert!(!same_key(mode, &mods(mode, &["DT"]), &mods(mode, &["HT"])));
+            assert!(!same_key(mode, &mods(mode, &["DT"]), &mods(mode, &[])));
+        }
+    }
+
+    #[test]
+    fn equal_rates_collapse() {
+        let default_dt = mods(GameMode::Osu, &["DT"]);
+
+        let explicit_nc: GameMods = [GameMod::NightcoreOsu(NightcoreOsu {
+            speed_change: Some(1.5),
+            ..Default::default()
+        })]
+        .into_iter()
+        .collect();
+
+        let pitched_dt: GameMods = [GameMod::DoubleTimeOsu(DoubleTimeOsu {
+            adjust_pitch: Some(true),
+            ..Default::default()
+        })]
+        .into_iter()
+        .collect();
+
+        let custom_nc: GameMods = [GameMod::NightcoreOsu(NightcoreOsu {
+            speed_change: Some(1.2),
+            ..Default::default()
+        })]
+        .into_iter()
+        .collect();
+
+        let custom_dt: GameMods = [GameMod::DoubleTimeOsu(DoubleTimeOsu {
+            speed_change: Some(1.2),
+            ..Default::default()
+        })]
+        .into_iter()
+        .collect();
+
+        let explicit_dc: GameMods = [GameMod::DaycoreTaiko(DaycoreTaiko {
+            speed_change: Some(0.75),
+            ..Default::default()
+        })]
+        .into_iter()
+        .collect();
+
+        assert!(same_key(GameMode::Osu, &explicit_nc, &default_dt));
+        assert!(same_key(GameMode::Osu, &pitched_dt, &default_dt));
+        assert!(same_key(GameMode::Osu, &custom_nc, &custom_dt));
+        assert!(!same_key(GameMode::Osu, &custom_dt, &default_dt));
+        assert!(same_key(
+            GameMode::Taiko,
+            &explicit_dc,
+            &mods(GameMode::Taiko, &["HT"])
+        ));
+    }
+
+    #[test]
+    fn irrelevant_mods_share_key() {
+        let osu = GameMode::Osu;
+
+        assert!(same_key(
+            osu,
+            &mods(osu, &["HD", "NC", "PF"]),
+            &mods(osu, &["HD", "DT"])
+        ));
+        assert!(same_key(
+            osu,
+            &mods(osu, &["BL", "HR"]),
+            &mods(osu, &["HR"])
+        ));
+        assert!(!same_key(
+            osu,
+            &mods(osu, &["HD", "DT"]),
+            &mods(osu, &["DT"])
+        ));
+        assert!(!same_key(osu, &mods(osu, &["TC"]), &mods(osu, &[])));
+
+        for mode in [GameMode::Taiko, GameMode::Catch] {
+            assert!(same_key(
+                mode,
+                &mods(mode, &["HD", "DT"]),
+                &mods(mode, &["NC"])
+            ));
+            assert!(!same_key(mode, &mods(mode, &["HR"]), &mods(mode, &[])));
+        }
+
+        let mania = GameMode::Mania;
+
+        assert!(same_key(
+            mania,
+            &mods(mania, &["FI", "MR", "NF"]),
+            &mods(mania, &[])
+        ));
+        assert!(!same_key(mania, &mods(mania, &["4K"]), &mods(mania, &[])));
+    }
+
+    #[test]
+    fn performance_mods_keep_every_mod() {
+        let taiko = GameMode::Taiko;
+
+        assert_eq!(
+            performance_mods(&mods(taiko, &["HD", "NC"])),
+            mods(taiko, &["HD", "DT"])
+        );
+        assert_ne!(
+            performance_mods(&mods(taiko, &["HD", "DT"])),
+            performance_mods(&mods(taiko, &["DT"]))
+        );
+    }
+}
@@ -1,18 +1,33 @@
+use std::{
+    sync::RwLock,
+    time::{Duration, Instant},
+};
+
 use bathbot_psql::Database;
-use eyre::{Result, WrapErr};
+use bathbot_util::MonotoneCurve;
+use eyre::{ContextCompat, Result, WrapErr};
 use rosu_v2::prelude::GameMode;
 
 use crate::core::Context;
 
+/// Smallest rank for which pp is approximated through the sampled curve.
+const CURVE_START_RANK: u32 = 10_000;
+
+/// Consecutive sampled ranks grow by `CURVE_RANK_FACTOR / CURVE_RANK_DIVISOR`.
+const CURVE_RANK_FACTOR: u32 = 3;
+const CURVE_RANK_DIVISOR: u32 = 2;
+
 #[derive(Copy, Clone)]
 pub struct ApproxManager {
     psql: &'static Database,
+    curves: &'static PpCurves,
 }
 
 impl ApproxManager {
-    pub fn new() -> Self {
+    pub fn new(curves: &'static PpCurves) -> Self {
         Self {
             psql: Context::psql(),
+            curves,
         }
     }
 
@@ -24,9 +39,179 @@ impl ApproxManager {
     }
 
     pub async fn pp(self, rank: u32, mode: GameMode) -> Result<f32> {
+        if rank >= CURVE_START_RANK {
+            if let Some(pp) = self.curves.pp(rank, mode) {
+                return Ok(pp);
+            }
+
+            // The curve was not sampled yet, e.g. right after startup
+            self.refresh_curve(mode).await?;
+
+            if let Some(pp) = self.curves.pp(rank, mode) {
+                return Ok(pp);
+            }
+        }
+
         self.psql
             .select_pp_approx_by_rank(rank, mode)
             .await
             .wrap_err("failed to approximate pp")
     }
+
+    /// Average pp values within exponentially spaced rank buckets and store
+    /// the curve through these buckets for the mode.
+    pub async fn refresh_curve(self, mode: GameMode) -> Result<()> {
+        let max_rank = self
+            .psql
+            .select_max_rank_approx(mode)
+            .await
+            .wrap_err("failed to get max rank")?;
+
+        let Some(max_rank) = max_rank.filter(|&rank| rank > CURVE_START_RANK) else {
+            return Ok(());
+        };
+
+        let thresholds = sample_ranks(max_rank);
+
+        let mut samples = self
+            .psql
+            .select_pp_approx_by_rank_buckets(&thresholds, mode)
+            .await
+            .wrap_err("failed to approximate pp of rank buckets")?;
+
+        // Averages of neighbouring buckets may round to the same rank
+        samples.dedup_by_key(|(rank, _)| *rank);
+
+        let curve = PpCurve::new(&samples).wrap_err("invalid pp samples")?;
+        *self.curves.get(mode).write().unwrap() = Some(curve);
+
+        Ok(())
+    }
+
+    /// How long ago the curve of the mode was sampled.
+    pub fn curve_age(self, mode: GameMode) -> Option<Duration> {
+        self.curves
+            .get(mode)
+            .read()
+            .unwrap()
+            .as_ref()
+            .map(|curve| curve.sampled_at.elapsed())
+    }
+}
+
+/// Sampled rank-pp curves for each mode.
+#[derive(Default)]
+pub struct PpCurves {
+    curves: [RwLock<Option<PpCurve>>; 4],
+}
+
+impl PpCurves {
+    fn get(&self, mode: GameMode) -> &RwLock<Option<PpCurve>> {
+        &self.curves[mode as usize]
+    }
+
+    fn pp(&self, rank: u32, mode: GameMode) -> Option<f32> {
+        self.get(mode)
+            .read()
+            .unwrap()
+            .as_ref()
+            .map(|curve| curve.pp(rank))
+    }
+}
+
+struct PpCurve {
+    /// Interpolates pp over the logarithm of the rank
+    curve: MonotoneCurve,
+    sampled_at: Instant,
+}
+
+impl PpCurve {
+    /// Samples must be sorted by rank.
+    fn new(samples: &[(u32, f32)]) -> Option<Self> {
+        let mut min_pp = f64::INFINITY;
+
+        // Outdated entries may cause a lower rank to have more pp so we
+        // make sure pp never increases.
+        let points: Vec<_> = samples
+            .iter()
+            .map(|&(rank, pp)| {
+                min_pp = min_pp.min(pp as f64);
+
+                ((rank as f64).ln(), min_pp)
+            })
+            .collect();
+
+        MonotoneCurve::new(&points).map(|curve| Self {
+            curve,
+            sampled_at: Instant::now(),
+        })
+    }
+
+    fn pp(&self, rank: u32) -> f32 {
+        self.curve.eval((rank.max(1) as f64).ln()).max(0.0) as f32
+    }
+}
+
+/// Exponentially spaced rank bucket thresholds starting at
+/// [`CURVE_START_RANK`] up to the given max rank.
+fn sample_ranks(max_rank: u32) -> Vec<u32> {
+    let mut ranks = Vec::new();
+    let mut rank = CURVE_START_RANK;
+
+    while rank < max_rank {
+        ranks.push(rank);
+        rank = (rank / CURVE_RANK_DIVISOR * CURVE_RANK_FACTOR) / 1000 * 1000;
+    }
+
+    ranks.push(max_rank);
+
+    ranks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sampled_ranks() {
+        assert_eq!(
+            sample_ranks(120_000),
+            [
+                10_000, 15_000, 22_000, 33_000, 49_000, 73_000, 109_000, 120_000
+            ]
+        );
+        assert_eq!(sample_ranks(15_000), [10_000, 15_000]);
+    }
+
+    #[test]
+    fn curve_is_non_increasing() {
+        // The sample at rank 33k is outdated and has more pp than rank 22k
+        let samples = [
+            (10_000, 6000.0),
+            (15_000, 5200.0),
+            (22_000, 4500.0),
+            (33_000, 4600.0),
+            (49_000, 3500.0),
+            (73_000, 2900.0),
+        ];
+
+        let curve = PpCurve::new(&samples).unwrap();
+        let pps: Vec<_> = (10_000..=73_000)
+            .step_by(100)
+            .map(|rank| curve.pp(rank))
+            .collect();
+
+        assert!(pps.windows(2).all(|w| w[1] <= w[0]));
+        assert!((curve.pp(33_000) - 4500.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn extrapolation_floored_at_zero() {
+        let samples = [(10_000, 3000.0), (100_000, 1000.0), (1_000_000, 100.0)];
+        let curve = PpCurve::new(&samples).unwrap();
+
+        assert!(curve.pp(1_200_000) < 100.0);
+        assert!(curve.pp(1_200_000) > 0.0);
+        assert_eq!(curve.pp(u32::MAX), 0.0);
+    }
 }
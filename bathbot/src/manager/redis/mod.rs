@@ -1,4 +1,10 @@
-use std::{borrow::Cow, collections::HashMap, fmt::Write};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fmt::Write,
+    num::NonZeroU32,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 use bathbot_cache::{
     Cache,
@@ -7,11 +13,13 @@ use bathbot_cache::{
 };
 use bathbot_model::{
     ArchivedOsekaiBadge, ArchivedOsekaiMedal, ArchivedOsuStatsBestScores,
-    ArchivedOsuTrackHistoryEntry, ArchivedScrapedMedal, ArchivedSnipeCountries, OsekaiRanking,
-    OsuStatsBestScores, OsuStatsBestTimeframe,
+    ArchivedOsuTrackHistoryEntry, ArchivedScrapedMedal, ArchivedSnipeCountries,
+    ArchivedSnipeCountryRankingPlayer, MapCompletionScore, OsekaiRanking, OsuStatsBestScores,
+    OsuStatsBestTimeframe, SnipeCountryPlayersOrder,
     rosu_v2::{
         multiplayer::{ArchivedRoom, RoomRkyv},
         ranking::{ArchivedRankings, RankingsRkyv},
+        score::{ArchivedMapLeaderboardScore, MapLeaderboardScore},
     },
 };
 use bathbot_psql::model::osu::MapVersion;
@@ -23,11 +31,11 @@ use rkyv::{
     primitive::ArchivedU16, rancor::BoxedError, vec::ArchivedVec,
 };
 use rosu_v2::{
-    prelude::{GameMode, RoomCategory},
+    prelude::{GameMode, GameModsIntermode, OsuError, RoomCategory},
     request::RoomsFilter,
 };
 use thiserror::Error as ThisError;
-use time::{Date, UtcDateTime};
+use time::{Date, OffsetDateTime, UtcDateTime};
 
 use crate::{
     core::{BotMetrics, Context},
@@ -36,6 +44,8 @@ use crate::{
 
 pub mod osu;
 
+const MEDALS_KEY: &str = "osekai_medals";
+
 // type RedisResult<T, A = T, E = Report> = Result<RedisData<T, A>, E>;
 type RedisResult<T> = Result<CachedArchive<T>, RedisError>;
 
@@ -52,6 +62,31 @@ pub enum RedisError {
 #[derive(Copy, Clone)]
 pub struct RedisManager;
 
+/// Key and expiration under which fetched data is cached.
+#[derive(Clone, Debug)]
+pub struct CacheMeta {
+    pub key: String,
+    pub expire_seconds: u64,
+}
+
+impl CacheMeta {
+    /// Keep the cached data alive while it's still in use.
+    pub async fn refresh(&self) {
+        if let Err(err) = Context::cache()
+            .refresh_ttl(&self.key, self.expire_seconds)
+            .await
+        {
+            warn!(key = %self.key, ?err, "Failed to refresh ttl");
+        }
+    }
+}
+
+/// Score ids of a user's top scores in the order they had when stored.
+pub struct TopSnapshot {
+    pub score_ids: Vec<u64>,
+    pub stored_at: OffsetDateTime,
+}
+
 impl RedisManager {
     pub fn new() -> Self {
         Self
@@ -89,14 +124,26 @@ impl RedisManager {
     }
 
     pub async fn medals(self) -> RedisResult<ArchivedVec<ArchivedOsekaiMedal>> {
+        self.medals_with_meta().await.map(|(medals, _)| medals)
+    }
+
+    /// Same as [`RedisManager::medals`] but also returns how the medals are
+    /// cached.
+    pub async fn medals_with_meta(
+        self,
+    ) -> Result<(CachedArchive<ArchivedVec<ArchivedOsekaiMedal>>, CacheMeta), RedisError> {
         const EXPIRE: u64 = 3600;
-        const KEY: &str = "osekai_medals";
 
-        let mut conn = match Context::cache().fetch(KEY).await {
+        let meta = CacheMeta {
+            key: MEDALS_KEY.to_owned(),
+            expire_seconds: EXPIRE,
+        };
+
+        let mut conn = match Context::cache().fetch(MEDALS_KEY).await {
             Ok(Ok(medals)) => {
                 BotMetrics::inc_redis_hit("Osekai medals");
 
-                return Ok(medals);
+                return Ok((medals, meta));
             }
             Ok(Err(conn)) => Some(conn),
             Err(err) => {
@@ -111,12 +158,57 @@ impl RedisManager {
         let bytes = serialize_using_arena(&medals).map_err(RedisError::Serialization)?;
 
         if let Some(ref mut conn) = conn
-            && let Err(err) = Cache::store(conn, KEY, bytes.as_slice(), EXPIRE).await
+            && let Err(err) = Cache::store(conn, MEDALS_KEY, bytes.as_slice(), EXPIRE).await
         {
             warn!(?err, "Failed to store medals");
         }
 
-        CachedArchive::new(bytes).map_err(RedisError::Validation)
+        let medals = CachedArchive::new(bytes).map_err(RedisError::Validation)?;
+
+        Ok((medals, meta))
+    }
+
+    /// Medals only if they're already cached.
+    ///
+    /// On a miss, the medals are fetched in the background so that they're
+    /// available for later calls, and `None` is returned right away.
+    pub async fn cached_medals(self) -> Option<CachedArchive<ArchivedVec<ArchivedOsekaiMedal>>> {
+        static FETCHING: AtomicBool = AtomicBool::new(false);
+
+        /// Clears the flag when dropped so that a panicking fetch doesn't
+        /// block later fetches.
+        struct FetchingGuard;
+
+        impl Drop for FetchingGuard {
+            fn drop(&mut self) {
+                FETCHING.store(false, Ordering::Release);
+            }
+        }
+
+        match Context::cache().fetch(MEDALS_KEY).await {
+            Ok(Ok(medals)) => {
+                BotMetrics::inc_redis_hit("Osekai medals");
+
+                return Some(medals);
+            }
+            Ok(Err(_)) => {}
+            Err(err) => warn!(?err, "Failed to fetch osekai medals"),
+        }
+
+        // Only one background fetch at a time
+        if !FETCHING.swap(true, Ordering::AcqRel) {
+            let guard = FetchingGuard;
+
+            tokio::spawn(async move {
+                let _guard = guard;
+
+                if let Err(err) = self.medals().await {
+                    warn!(?err, "Failed to fill medal cache");
+                }
+            });
+        }
+
+        None
     }
 
     pub async fn medal_icons(self, medal_ids: &[u32]) -> Result<Vec<(u32, Vec<u8>)>> {
@@ -379,6 +471,19 @@ impl RedisManager {
         page: u32,
         country: Option<&str>,
     ) -> RedisResult<ArchivedRankings> {
+        self.pp_ranking_with_meta(mode, page, country)
+            .await
+            .map(|(ranking, _)| ranking)
+    }
+
+    /// Same as [`RedisManager::pp_ranking`] but also returns how the ranking
+    /// is cached.
+    pub async fn pp_ranking_with_meta(
+        self,
+        mode: GameMode,
+        page: u32,
+        country: Option<&str>,
+    ) -> Result<(CachedArchive<ArchivedRankings>, CacheMeta), RedisError> {
         const EXPIRE: u64 = 1800;
         let mut key = format!("pp_ranking_{}_{page}", mode as u8);
 
@@ -386,11 +491,18 @@ impl RedisManager {
             let _ = write!(key, "_{country}");
         }
 
-        let mut conn = match Context::cache().fetch(&key).await {
+        let meta = CacheMeta {
+            key,
+            expire_seconds: EXPIRE,
+        };
+
+        let key = meta.key.as_str();
+
+        let mut conn = match Context::cache().fetch(key).await {
             Ok(Ok(ranking)) => {
                 BotMetrics::inc_redis_hit("PP ranking");
 
-                return Ok(ranking);
+                return Ok((ranking, meta));
             }
             Ok(Err(conn)) => Some(conn),
             Err(err) => {
@@ -412,12 +524,14 @@ impl RedisManager {
             .map_err(RedisError::Serialization)?;
 
         if let Some(ref mut conn) = conn
-            && let Err(err) = Cache::store(conn, &key, bytes.as_slice(), EXPIRE).await
+            && let Err(err) = Cache::store(conn, key, bytes.as_slice(), EXPIRE).await
         {
             warn!(?err, "Failed to store ranking");
         }
 
-        CachedArchive::new(bytes).map_err(RedisError::Validation)
+        let ranking = CachedArchive::new(bytes).map_err(RedisError::Validation)?;
+
+        Ok((ranking, meta))
     }
 
     pub async fn osustats_best(
@@ -461,6 +575,245 @@ impl RedisManager {
         Ok(scores)
     }
 
+    /// Global leaderboard of a map, cached briefly so that commands used on
+    /// the same map in quick succession share a single osu!api request.
+    ///
+    /// The full leaderboard is cached regardless of `limit` so that different
+    /// limits share the same entry.
+    pub async fn map_leaderboard(
+        self,
+        map_id: u32,
+        mode: GameMode,
+        mods: Option<GameModsIntermode>,
+        limit: u32,
+        legacy_scores: bool,
+    ) -> Result<Vec<MapLeaderboardScore>> {
+        const EXPIRE: u64 = 300;
+        const MAX_LIMIT: u32 = 100;
+
+        let mods_key = mods
+            .as_ref()
+            .map_or(Cow::Borrowed("all"), |mods| Cow::Owned(mods.to_string()));
+
+        let key = format!(
+            "map_lb_v3_{map_id}_{}_{mods_key}_{}",
+            mode as u8, legacy_scores as u8
+        );
+
+        let mut conn = match Context::cache()
+            .fetch::<_, ArchivedVec<ArchivedMapLeaderboardScore>>(&key)
+            .await
+        {
+            Ok(Ok(scores)) => {
+                BotMetrics::inc_redis_hit("Map leaderboard");
+
+                let mut scores: Vec<MapLeaderboardScore> =
+                    scores.try_deserialize().wrap_err("Failed to deserialize")?;
+                scores.truncate(limit as usize);
+
+                return Ok(scores);
+            }
+            Ok(Err(conn)) => Some(conn),
+            Err(err) => {
+                warn!(?err, "Failed to fetch map leaderboard");
+
+                None
+            }
+        };
+
+        let mut scores: Vec<_> = Context::osu_scores()
+            .map_leaderboard(map_id, mode, mods, MAX_LIMIT, legacy_scores)
+            .await?
+            .into_iter()
+            .map(MapLeaderboardScore::new)
+            .collect();
+
+        if let Some(ref mut conn) = conn {
+            match serialize_using_arena(&scores).map_err(RedisError::Serialization) {
+                Ok(bytes) => {
+                    if let Err(err) = Cache::store(conn, &key, &bytes, EXPIRE).await {
+                        warn!(?err, "Failed to store map leaderboard");
+                    }
+                }
+                Err(err) => warn!(err = ?Report::new(err), "Failed to serialize map leaderboard"),
+            }
+        }
+
+        scores.truncate(limit as usize);
+
+        Ok(scores)
+    }
+
+    /// Score rank of a user as provided by respektive.
+    pub async fn respektive_score_rank(self, user_id: u32, mode: GameMode) -> Result<Option<u32>> {
+        const EXPIRE: u64 = 1800;
+        let key = format!("respektive_rank_{user_id}_{}", mode as u8);
+
+        let mut conn = match Context::cache()
+            .fetch::<_, Archived<Option<u32>>>(&key)
+            .await
+        {
+            Ok(Ok(rank)) => {
+                BotMetrics::inc_redis_hit("Respektive score rank");
+
+                return rank.try_deserialize().wrap_err("Failed to deserialize");
+            }
+            Ok(Err(conn)) => Some(conn),
+            Err(err) => {
+                warn!(?err, "Failed to fetch respektive score rank");
+
+                None
+            }
+        };
+
+        let rank = Context::client()
+            .get_respektive_users([user_id], mode)
+            .await?
+            .next()
+            .flatten()
+            .and_then(|user| user.rank)
+            .map(NonZeroU32::get);
+
+        if let Some(ref mut conn) = conn {
+            match serialize_using_arena(&rank).map_err(RedisError::Serialization) {
+                Ok(bytes) => {
+                    if let Err(err) = Cache::store(conn, &key, &bytes, EXPIRE).await {
+                        warn!(?err, "Failed to store respektive score rank");
+                    }
+                }
+                Err(err) => warn!(
+                    err = ?Report::new(err),
+                    "Failed to serialize respektive score rank"
+                ),
+            }
+        }
+
+        Ok(rank)
+    }
+
+    /// Order of a user's top scores as it was when they were last listed.
+    pub async fn top_snapshot(
+        self,
+        user_id: u32,
+        mode: GameMode,
+        legacy_scores: bool,
+    ) -> Result<Option<TopSnapshot>> {
+        let key = Self::top_snapshot_key(user_id, mode, legacy_scores);
+
+        let snapshot = match Context::cache()
+            .fetch::<_, Archived<(i64, Vec<u64>)>>(&key)
+            .await
+        {
+            Ok(Ok(snapshot)) => snapshot,
+            Ok(Err(_)) => return Ok(None),
+            Err(err) => return Err(Report::new(err).wrap_err("Failed to fetch top snapshot")),
+        };
+
+        let (stored_at, score_ids) = snapshot
+            .try_deserialize::<(i64, Vec<u64>)>()
+            .wrap_err("Failed to deserialize")?;
+
+        let stored_at = OffsetDateTime::from_unix_timestamp(stored_at)
+            .wrap_err("Invalid top snapshot timestamp")?;
+
+        Ok(Some(TopSnapshot {
+            score_ids,
+            stored_at,
+        }))
+    }
+
+    /// Store the current order of a user's top scores so that the next
+    /// listing can show how they moved.
+    pub async fn store_top_snapshot(
+        self,
+        user_id: u32,
+        mode: GameMode,
+        legacy_scores: bool,
+        score_ids: Vec<u64>,
+    ) -> Result<()> {
+        const EXPIRE: u64 = 604_800; // 7 days
+        let key = Self::top_snapshot_key(user_id, mode, legacy_scores);
+
+        let snapshot = (OffsetDateTime::now_utc().unix_timestamp(), score_ids);
+        let bytes = serialize_using_arena(&snapshot).wrap_err("Failed to serialize")?;
+
+        Context::cache()
+            .store_new(&key, bytes.as_slice(), EXPIRE)
+            .await
+            .wrap_err("Failed to store top snapshot")
+    }
+
+    /// A user's best score on a map in the map's own mode.
+    ///
+    /// Returns `None` if the user has no score on the map.
+    pub async fn map_completion_score(
+        self,
+        user_id: u32,
+        map_id: u32,
+        mode: GameMode,
+        legacy_scores: bool,
+    ) -> Result<Option<MapCompletionScore>> {
+        const EXPIRE: u64 = 3600;
+        let key = format!(
+            "map_completion_{user_id}_{map_id}_{}",
+            u8::from(legacy_scores)
+        );
+
+        let mut conn = match Context::cache()
+            .fetch::<_, Archived<Option<MapCompletionScore>>>(&key)
+            .await
+        {
+            Ok(Ok(score)) => {
+                BotMetrics::inc_redis_hit("Map completion score");
+
+                return score.try_deserialize().wrap_err("Failed to deserialize");
+            }
+            Ok(Err(conn)) => Some(conn),
+            Err(err) => {
+                warn!(?err, "Failed to fetch map completion score");
+
+                None
+            }
+        };
+
+        let score_fut =
+            Context::osu_scores().user_on_map_single(user_id, map_id, mode, None, legacy_scores);
+
+        let score = match score_fut.await {
+            Ok(score) => Some(MapCompletionScore {
+                grade: score.score.grade,
+                accuracy: score.score.accuracy,
+                pp: score.score.pp,
+            }),
+            Err(OsuError::NotFound) => None,
+            Err(err) => return Err(Report::new(err).wrap_err("Failed to get user map score")),
+        };
+
+        if let Some(ref mut conn) = conn {
+            match serialize_using_arena(&score).map_err(RedisError::Serialization) {
+                Ok(bytes) => {
+                    if let Err(err) = Cache::store(conn, &key, &bytes, EXPIRE).await {
+                        warn!(?err, "Failed to store map completion score");
+                    }
+                }
+                Err(err) => warn!(
+                    err = ?Report::new(err),
+                    "Failed to serialize map completion score"
+                ),
+            }
+        }
+
+        Ok(score)
+    }
+
+    fn top_snapshot_key(user_id: u32, mode: GameMode, legacy_scores: bool) -> String {
+        format!(
+            "top_snapshot_{user_id}_{}_{}",
+            mode as u8,
+            u8::from(legacy_scores)
+        )
+    }
+
     pub async fn osutrack_history(
         self,
         user_id: u32,
@@ -529,6 +882,52 @@ impl RedisManager {
         CachedArchive::new(bytes).map_err(RedisError::Validation)
     }
 
+    /// osu!standard players of a country ranked by their national #1s.
+    ///
+    /// The players are in the order that huismetbenen provides for `sort`; see
+    /// [`SnipeCountryPlayersOrder::as_huismetbenen_str`].
+    pub async fn snipe_country_ranking(
+        self,
+        country_code: &str,
+        sort: SnipeCountryPlayersOrder,
+    ) -> RedisResult<ArchivedVec<ArchivedSnipeCountryRankingPlayer>> {
+        const EXPIRE: u64 = 7200; // 2 hours
+        let sort_str = sort.as_huismetbenen_str().unwrap_or("count");
+        let key = format!(
+            "snipe_country_ranking_{country}_{mode}_{sort_str}",
+            country = country_code.to_ascii_lowercase(),
+            mode = GameMode::Osu as u8,
+        );
+
+        let mut conn = match Context::cache().fetch(&key).await {
+            Ok(Ok(players)) => {
+                BotMetrics::inc_redis_hit("Snipe country ranking");
+
+                return Ok(players);
+            }
+            Ok(Err(conn)) => Some(conn),
+            Err(err) => {
+                warn!(?err, "Failed to fetch snipe country ranking");
+
+                None
+            }
+        };
+
+        let players = Context::client()
+            .get_snipe_country_ranking(country_code, sort)
+            .await?;
+
+        let bytes = serialize_using_arena(&players).map_err(RedisError::Serialization)?;
+
+        if let Some(ref mut conn) = conn
+            && let Err(err) = Cache::store(conn, &key, bytes.as_slice(), EXPIRE).await
+        {
+            warn!(?err, "Failed to store snipe country ranking");
+        }
+
+        CachedArchive::new(bytes).map_err(RedisError::Validation)
+    }
+
     // Mapset difficulty names for the autocomplete option of the compare command
     pub async fn cs_diffs(
         self,
@@ -8,6 +8,7 @@ use rosu_v2::{
     prelude::{GameMode, OsuError, UserExtended},
     request::UserId,
 };
+use tracing::Instrument;
 
 use super::RedisManager;
 use crate::core::{BotMetrics, Context};
@@ -95,12 +96,15 @@ impl UserArgs {
             }
         };
 
-        tokio::spawn(async move {
-            Context::osu_user().store(&user, mode).await;
-            Context::get()
-                .notify_osutrack_of_user_activity(user.user_id, mode)
-                .await;
-        });
+        tokio::spawn(
+            async move {
+                Context::osu_user().store(&user, mode).await;
+                Context::get()
+                    .notify_osutrack_of_user_activity(user.user_id, mode)
+                    .await;
+            }
+            .in_current_span(),
+        );
 
         Self::User {
             user: archived,
@@ -147,8 +151,10 @@ impl TryFrom<UserArgs> for UserArgsSlim {
 const EXPIRE: u64 = 600;
 
 impl RedisManager {
+    /// The version must be bumped whenever the archived [`User`] layout changes
+    /// so that previous entries expire instead of failing validation.
     fn osu_user_key(user_id: u32, mode: GameMode) -> String {
-        format!("osu_user_{user_id}_{}", mode as u8)
+        format!("osu_user_v2_{user_id}_{}", mode as u8)
     }
 
     pub async fn osu_user_from_args(self, args: UserArgsSlim) -> Result<CachedUser, UserArgsError> {
@@ -178,6 +184,10 @@ impl RedisManager {
                     warn!(?err, "Failed to remove stats of unknown user");
                 }
 
+                if let Err(err) = Context::osu_user().strike_link(user_id).await {
+                    warn!(?err, "Failed to strike link of unknown user");
+                }
+
                 return Err(UserArgsError::Osu(err));
             }
             Err(err) => return Err(UserArgsError::Osu(err)),
@@ -202,12 +212,20 @@ impl RedisManager {
             }
         }
 
-        tokio::spawn(async move {
-            Context::osu_user().store(&user, mode).await;
-            Context::get()
-                .notify_osutrack_of_user_activity(user.user_id, mode)
-                .await;
-        });
+        tokio::spawn(
+            async move {
+                Context::osu_user().store(&user, mode).await;
+
+                if let Err(err) = Context::osu_user().clear_link_strikes(user.user_id).await {
+                    warn!(?err, "Failed to clear link strikes");
+                }
+
+                Context::get()
+                    .notify_osutrack_of_user_activity(user.user_id, mode)
+                    .await;
+            }
+            .in_current_span(),
+        );
 
         CachedUser::new(bytes).map_err(UserArgsError::Validation)
     }
@@ -1,24 +1,37 @@
-use bathbot_model::embed_builder::ScoreEmbedSettings;
+use bathbot_model::embed_builder::ScoreEmbedPatch;
 use bathbot_psql::{
     Database,
-    model::configs::{OsuUserId, SkinEntry, UserConfig},
+    model::{
+        configs::{GraphTheme, OsuUserId, RevisedScoreEmbed, SkinEntry, UserConfig},
+        prune::PruneReport,
+    },
 };
 use bathbot_util::CowUtils;
-use eyre::{Result, WrapErr};
-use rosu_v2::prelude::{GameMode, Username};
-use twilight_model::id::{Id, marker::UserMarker};
+use eyre::{ContextCompat, Result, WrapErr};
+use rosu_v2::prelude::{GameMode, GameModsIntermode, Username};
+use time::OffsetDateTime;
+use twilight_model::id::{
+    Id,
+    marker::{GuildMarker, UserMarker},
+};
 
+use super::front_cache::FrontCache;
 use crate::core::Context;
 
+/// Linked osu! user ids of discord users.
+pub type UserLinks = FrontCache<Id<UserMarker>, Option<u32>>;
+
 #[derive(Copy, Clone)]
 pub struct UserConfigManager {
     psql: &'static Database,
+    links: &'static UserLinks,
 }
 
 impl UserConfigManager {
-    pub fn new() -> Self {
+    pub fn new(links: &'static UserLinks) -> Self {
         Self {
             psql: Context::psql(),
+            links,
         }
     }
 
@@ -48,11 +61,36 @@ impl UserConfigManager {
             .wrap_err("Failed to get user mode from DB")
     }
 
-    pub async fn osu_id(self, user_id: Id<UserMarker>) -> Result<Option<u32>> {
+    pub async fn graph_theme(self, user_id: Id<UserMarker>) -> Result<Option<GraphTheme>> {
         self.psql
-            .select_osu_id_by_discord_id(user_id)
+            .select_user_graph_theme(user_id)
             .await
-            .wrap_err("Failed to get user id from DB")
+            .wrap_err("Failed to get graph theme from DB")
+    }
+
+    pub async fn blacklisted_mods(
+        self,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<GameModsIntermode>> {
+        self.psql
+            .select_user_blacklisted_mods(user_id)
+            .await
+            .wrap_err("Failed to get blacklisted mods from DB")
+    }
+
+    /// Get the linked osu! user id.
+    ///
+    /// Served from an in-process cache for a short while and during database
+    /// outages.
+    pub async fn osu_id(self, user_id: Id<UserMarker>) -> Result<Option<u32>> {
+        let fetch_fut = async {
+            self.psql
+                .select_osu_id_by_discord_id(user_id)
+                .await
+                .wrap_err("Failed to get user id from DB")
+        };
+
+        self.links.get_or_fetch(user_id, fetch_fut).await
     }
 
     pub async fn osu_name(self, user_id: Id<UserMarker>) -> Result<Option<Username>> {
@@ -69,6 +107,40 @@ impl UserConfigManager {
             .wrap_err("failed to get discord id from osu id")
     }
 
+    /// Discord users linked to the osu! user that opted into the tracking DM
+    /// digest.
+    pub async fn tracking_dm_digest_recipients(self, user_id: u32) -> Result<Vec<Id<UserMarker>>> {
+        self.psql
+            .select_tracking_dm_digest_discord_ids(user_id)
+            .await
+            .wrap_err("Failed to get tracking DM digest recipients")
+    }
+
+    pub async fn disable_tracking_dm_digest(self, user_id: Id<UserMarker>) -> Result<()> {
+        self.psql
+            .update_tracking_dm_digest(user_id, false)
+            .await
+            .wrap_err("Failed to disable tracking DM digest")
+    }
+
+    /// Members of the guild that may appear in server leaderboards.
+    ///
+    /// Every guild-aggregate list should go through this so that users who hid
+    /// themselves from server lists are excluded.
+    pub async fn visible_linked_users(self, guild: Id<GuildMarker>) -> Result<Vec<i64>> {
+        let members: Vec<_> = Context::cache()
+            .members(guild)
+            .await?
+            .into_iter()
+            .map(|id| id as i64)
+            .collect();
+
+        self.psql
+            .select_visible_linked_users(&members)
+            .await
+            .wrap_err("Failed to get visible server members")
+    }
+
     pub async fn skin(self, user_id: Id<UserMarker>) -> Result<Option<String>> {
         self.psql
             .select_skin_url(user_id)
@@ -114,17 +186,61 @@ impl UserConfigManager {
         self.psql
             .upsert_user_config(user_id, config)
             .await
-            .wrap_err("failed to store user config")
+            .wrap_err("failed to store user config")?;
+
+        // Only invalidate once the new link is committed so that a lookup in
+        // the meantime can't cache the previous link again
+        self.links.invalidate(user_id);
+
+        Ok(())
+    }
+
+    /// Delete the report's user configs unless they were seen since `cutoff`
+    /// and mark the report as applied.
+    ///
+    /// Returns the amount of deleted configs or `None` if the report was
+    /// applied already.
+    pub async fn delete_inactive(
+        self,
+        report: &PruneReport,
+        cutoff: OffsetDateTime,
+    ) -> Result<Option<u64>> {
+        let deleted = self
+            .psql
+            .claim_prune_report(report.report_id, cutoff)
+            .await
+            .wrap_err("Failed to claim prune report")?;
+
+        if deleted.is_some() {
+            for &user_id in report.discord_ids.iter() {
+                self.links.invalidate(user_id);
+            }
+        }
+
+        Ok(deleted)
+    }
+
+    pub async fn score_embed_settings(self, user_id: Id<UserMarker>) -> Result<RevisedScoreEmbed> {
+        self.psql
+            .select_score_embed_settings(user_id)
+            .await
+            .wrap_err("Failed to get score embed settings")?
+            .wrap_err("Missing user config")
     }
 
-    pub async fn store_score_embed_settings(
+    /// Store the changed fields of the score embed settings.
+    ///
+    /// Changes since `revision` are reloaded and kept. Returns the merged
+    /// settings.
+    pub async fn patch_score_embed_settings(
         self,
         user_id: Id<UserMarker>,
-        settings: &ScoreEmbedSettings,
-    ) -> Result<()> {
+        patch: &ScoreEmbedPatch,
+        revision: i32,
+    ) -> Result<RevisedScoreEmbed> {
         self.psql
-            .update_score_embed_settings(user_id, settings)
+            .patch_score_embed_settings(user_id, patch, revision)
             .await
-            .wrap_err("Failed to store score embed settings")
+            .wrap_err("Failed to patch score embed settings")
     }
 }
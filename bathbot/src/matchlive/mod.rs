@@ -7,9 +7,10 @@ use twilight_model::id::{
     marker::{ChannelMarker, MessageMarker},
 };
 
-pub use self::types::*;
+pub use self::{progress::MatchProgress, types::*};
 use crate::{core::Context, embeds::MatchLiveEmbed};
 
+mod progress;
 mod types;
 
 const EMBED_LIMIT: usize = 10;
@@ -0,0 +1,521 @@
+use std::{borrow::Cow, cmp::Ordering, collections::HashMap, fmt::Write};
+
+use bathbot_util::{IntHasher, numbers::WithComma};
+use rosu_v2::prelude::{MatchGame, MatchTeam, ScoringType, TeamType, User, Username};
+
+/// Accumulated results of all finished maps of a tracked match
+#[derive(Default)]
+pub struct MatchProgress {
+    maps: Vec<MapResult>,
+    usernames: HashMap<u32, Username, IntHasher>,
+}
+
+impl MatchProgress {
+    /// Store the result of a finished game.
+    ///
+    /// Returns `false` if the game is not finished or has no scores.
+    pub fn record_game(&mut self, game: &MatchGame, users: &HashMap<u32, User>) -> bool {
+        let Some(map) = MapResult::from_game(game) else {
+            return false;
+        };
+
+        for score in map.scores.iter() {
+            if let Some(user) = users.get(&score.user_id) {
+                self.usernames
+                    .insert(score.user_id, user.username.as_str().into());
+            }
+        }
+
+        self.record(map);
+
+        true
+    }
+
+    /// Store a map result, overriding a previous result of the same game.
+    pub fn record(&mut self, map: MapResult) {
+        match self.maps.iter_mut().find(|m| m.game_id == map.game_id) {
+            Some(prev) => *prev = map,
+            None => self.maps.push(map),
+        }
+    }
+
+    /// Line mentioning the player with the highest score on the given game.
+    pub fn mvp_line(&self, game_id: u64) -> Option<String> {
+        let map = self.maps.iter().find(|map| map.game_id == game_id)?;
+        let mvp = map.mvp()?;
+
+        let line = format!(
+            "**MVP:** `{name}` with {score}",
+            name = self.username(mvp.user_id),
+            score = WithComma::new(mvp.score),
+        );
+
+        Some(line)
+    }
+
+    /// Running score across all finished maps.
+    ///
+    /// Counts team points if the most recent map was played in teams,
+    /// otherwise map wins per player.
+    pub fn score_line(&self) -> Option<String> {
+        let last = self.maps.last()?;
+
+        let line = if last.team_vs {
+            let (blue, red) = self.team_points(self.maps.len());
+
+            format!("**Score:** :blue_circle: {blue} - {red} :red_circle:")
+        } else {
+            let mut wins = self.player_wins();
+
+            if wins.is_empty() {
+                return None;
+            }
+
+            wins.sort_unstable_by(|(id1, w1), (id2, w2)| w2.cmp(w1).then_with(|| id1.cmp(id2)));
+
+            let mut line = "**Wins:** ".to_owned();
+
+            for (i, (user_id, wins)) in wins.into_iter().enumerate() {
+                if i > 0 {
+                    line.push_str(" • ");
+                }
+
+                let _ = write!(line, "`{}` {wins}", self.username(user_id));
+            }
+
+            line
+        };
+
+        Some(line)
+    }
+
+    /// Running score after each map, e.g. `1-0 → 1-1 → 2-1` for team matches
+    /// or the map winners for head-to-head matches.
+    pub fn progression(&self) -> String {
+        let mut progression = String::new();
+
+        for (i, map) in self.maps.iter().enumerate() {
+            if i > 0 {
+                progression.push_str(" → ");
+            }
+
+            match (map.team_vs, map.winner()) {
+                (true, _) => {
+                    let (blue, red) = self.team_points(i + 1);
+                    let _ = write!(progression, "{blue}-{red}");
+                }
+                (false, Some(MapWinner::Player(user_id))) => {
+                    let _ = write!(progression, "`{}`", self.username(user_id));
+                }
+                (false, _) => progression.push_str("tie"),
+            }
+        }
+
+        progression
+    }
+
+    /// Average score and accuracy of each player, sorted by average score.
+    pub fn averages(&self) -> Vec<PlayerAverage> {
+        let mut averages: Vec<PlayerAverage> = Vec::new();
+
+        for score in self.maps.iter().flat_map(|map| map.scores.iter()) {
+            match averages.iter_mut().find(|avg| avg.user_id == score.user_id) {
+                Some(avg) => {
+                    avg.score += score.score as f32;
+                    avg.accuracy += score.accuracy;
+                    avg.maps += 1;
+                }
+                None => averages.push(PlayerAverage {
+                    user_id: score.user_id,
+                    score: score.score as f32,
+                    accuracy: score.accuracy,
+                    maps: 1,
+                }),
+            }
+        }
+
+        for avg in averages.iter_mut() {
+            avg.score /= avg.maps as f32;
+            avg.accuracy /= avg.maps as f32;
+        }
+
+        averages.sort_unstable_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| a.user_id.cmp(&b.user_id))
+        });
+
+        averages
+    }
+
+    /// Description of the final summary of the match.
+    pub fn summary(&self) -> String {
+        if self.maps.is_empty() {
+            return "No map was finished in this match".to_owned();
+        }
+
+        let mut summary = String::with_capacity(256);
+
+        let plural = if self.maps.len() == 1 { "" } else { "s" };
+        let _ = writeln!(summary, "**{} map{plural} played**", self.maps.len());
+
+        if let Some(score_line) = self.score_line() {
+            summary.push_str(&score_line);
+            summary.push('\n');
+        }
+
+        let _ = writeln!(summary, "\n**Progression**\n{}", self.progression());
+
+        summary.push_str("\n**Player averages**\n");
+
+        let averages = self.averages();
+
+        let names: Vec<_> = averages
+            .iter()
+            .map(|avg| self.username(avg.user_id))
+            .collect();
+
+        let name_len = names.iter().map(|name| name.len()).max().unwrap_or(0);
+
+        let scores: Vec<_> = averages
+            .iter()
+            .map(|avg| WithComma::new(avg.score.round() as u64).to_string())
+            .collect();
+
+        let score_len = scores.iter().map(String::len).max().unwrap_or(0);
+
+        for ((avg, name), score) in averages.iter().zip(names).zip(scores) {
+            let plural = if avg.maps == 1 { "" } else { "s" };
+
+            let _ = writeln!(
+                summary,
+                "`{name:<name_len$}` `{score:>score_len$}` `{acc:>5.2}%` • {maps} map{plural}",
+                acc = avg.accuracy,
+                maps = avg.maps,
+            );
+        }
+
+        summary
+    }
+
+    fn username(&self, user_id: u32) -> Cow<'_, str> {
+        match self.usernames.get(&user_id) {
+            Some(name) => Cow::Borrowed(name.as_str()),
+            None => format!("User id {user_id}").into(),
+        }
+    }
+
+    /// Team points after the first `count` maps
+    fn team_points(&self, count: usize) -> (usize, usize) {
+        self.maps[..count]
+            .iter()
+            .filter(|map| map.team_vs)
+            .fold((0, 0), |(blue, red), map| match map.winner() {
+                Some(MapWinner::Team(MatchTeam::Blue)) => (blue + 1, red),
+                Some(MapWinner::Team(MatchTeam::Red)) => (blue, red + 1),
+                _ => (blue, red),
+            })
+    }
+
+    /// Map wins of each player across head-to-head maps
+    fn player_wins(&self) -> Vec<(u32, usize)> {
+        let mut wins: Vec<(u32, usize)> = Vec::new();
+
+        let winners = self
+            .maps
+            .iter()
+            .filter(|map| !map.team_vs)
+            .filter_map(MapResult::winner);
+
+        for winner in winners {
+            let MapWinner::Player(user_id) = winner else {
+                continue;
+            };
+
+            match wins.iter_mut().find(|(id, _)| *id == user_id) {
+                Some((_, count)) => *count += 1,
+                None => wins.push((user_id, 1)),
+            }
+        }
+
+        wins
+    }
+}
+
+/// Result of a single finished map
+pub struct MapResult {
+    game_id: u64,
+    team_vs: bool,
+    scoring: ScoringType,
+    scores: Vec<MapScore>,
+}
+
+impl MapResult {
+    pub fn new(game_id: u64, team_vs: bool, scoring: ScoringType, scores: Vec<MapScore>) -> Self {
+        Self {
+            game_id,
+            team_vs,
+            scoring,
+            scores,
+        }
+    }
+
+    fn from_game(game: &MatchGame) -> Option<Self> {
+        game.end_time?;
+
+        let scores: Vec<_> = game
+            .scores
+            .iter()
+            .filter(|score| score.score > 0)
+            .map(|score| MapScore {
+                user_id: score.user_id,
+                team: score.info.team,
+                score: score.score,
+                accuracy: score.accuracy,
+                combo: score.max_combo,
+            })
+            .collect();
+
+        if scores.is_empty() {
+            return None;
+        }
+
+        let team_vs = matches!(game.team_type, TeamType::TeamVS | TeamType::TagTeamVS);
+
+        Some(Self::new(game.game_id, team_vs, game.scoring_type, scores))
+    }
+
+    fn mvp(&self) -> Option<&MapScore> {
+        self.scores.iter().max_by_key(|score| score.score)
+    }
+
+    /// The winning team or player as determined by the scoring type.
+    /// Returns `None` on ties.
+    fn winner(&self) -> Option<MapWinner> {
+        if self.team_vs {
+            let value = |team: MatchTeam| {
+                let iter = self.scores.iter().filter(|score| score.team == team);
+
+                match self.scoring {
+                    ScoringType::Score | ScoringType::ScoreV2 => {
+                        iter.map(|s| s.value(self.scoring)).sum::<f64>()
+                    }
+                    ScoringType::Accuracy | ScoringType::Combo => {
+                        iter.map(|s| s.value(self.scoring)).fold(0.0, f64::max)
+                    }
+                }
+            };
+
+            match value(MatchTeam::Blue).partial_cmp(&value(MatchTeam::Red))? {
+                Ordering::Greater => Some(MapWinner::Team(MatchTeam::Blue)),
+                Ordering::Less => Some(MapWinner::Team(MatchTeam::Red)),
+                Ordering::Equal => None,
+            }
+        } else {
+            let mut best: Option<&MapScore> = None;
+            let mut tied = false;
+
+            for score in self.scores.iter() {
+                let Some(curr) = best else {
+                    best = Some(score);
+
+                    continue;
+                };
+
+                match score
+                    .value(self.scoring)
+                    .partial_cmp(&curr.value(self.scoring))
+                {
+                    Some(Ordering::Greater) => {
+                        best = Some(score);
+                        tied = false;
+                    }
+                    Some(Ordering::Equal) => tied = true,
+                    _ => {}
+                }
+            }
+
+            best.filter(|_| !tied)
+                .map(|score| MapWinner::Player(score.user_id))
+        }
+    }
+}
+
+pub struct MapScore {
+    pub user_id: u32,
+    pub team: MatchTeam,
+    pub score: u32,
+    pub accuracy: f32,
+    pub combo: u32,
+}
+
+impl MapScore {
+    fn value(&self, scoring: ScoringType) -> f64 {
+        match scoring {
+            ScoringType::Score | ScoringType::ScoreV2 => self.score as f64,
+            ScoringType::Accuracy => self.accuracy as f64,
+            ScoringType::Combo => self.combo as f64,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum MapWinner {
+    Team(MatchTeam),
+    Player(u32),
+}
+
+pub struct PlayerAverage {
+    pub user_id: u32,
+    pub score: f32,
+    pub accuracy: f32,
+    pub maps: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(user_id: u32, team: MatchTeam, score: u32, accuracy: f32) -> MapScore {
+        MapScore {
+            user_id,
+            team,
+            score,
+            accuracy,
+            combo: 100,
+        }
+    }
+
+    fn team_map(game_id: u64, blue: &[u32], red: &[u32]) -> MapResult {
+        let blue = blue
+            .iter()
+            .enumerate()
+            .map(|(i, &s)| score(i as u32 + 1, MatchTeam::Blue, s, 98.0));
+
+        let red = red
+            .iter()
+            .enumerate()
+            .map(|(i, &s)| score(i as u32 + 11, MatchTeam::Red, s, 96.0));
+
+        MapResult::new(
+            game_id,
+            true,
+            ScoringType::ScoreV2,
+            blue.chain(red).collect(),
+        )
+    }
+
+    fn team_progress() -> MatchProgress {
+        let mut progress = MatchProgress::default();
+
+        progress.usernames.insert(1, "blue1".into());
+        progress.usernames.insert(2, "blue2".into());
+        progress.usernames.insert(11, "red1".into());
+        progress.usernames.insert(12, "red2".into());
+
+        progress.record(team_map(1, &[500_000, 400_000], &[300_000, 350_000]));
+        progress.record(team_map(2, &[200_000, 250_000], &[600_000, 100_000]));
+        progress.record(team_map(3, &[700_000, 300_000], &[450_000, 500_000]));
+
+        progress
+    }
+
+    #[test]
+    fn team_score_line() {
+        let progress = team_progress();
+
+        assert_eq!(
+            progress.score_line().as_deref(),
+            Some("**Score:** :blue_circle: 2 - 1 :red_circle:")
+        );
+        assert_eq!(progress.progression(), "1-0 → 1-1 → 2-1");
+    }
+
+    #[test]
+    fn mvp_per_map() {
+        let progress = team_progress();
+
+        assert_eq!(
+            progress.mvp_line(2).as_deref(),
+            Some("**MVP:** `red1` with 600,000")
+        );
+        assert_eq!(
+            progress.mvp_line(3).as_deref(),
+            Some("**MVP:** `blue1` with 700,000")
+        );
+        assert!(progress.mvp_line(4).is_none());
+    }
+
+    #[test]
+    fn rerecording_overrides() {
+        let mut progress = team_progress();
+        progress.record(team_map(3, &[100_000, 100_000], &[450_000, 500_000]));
+
+        assert_eq!(progress.progression(), "1-0 → 1-1 → 1-2");
+    }
+
+    #[test]
+    fn head_to_head_wins() {
+        let mut progress = MatchProgress::default();
+        progress.usernames.insert(1, "alice".into());
+        progress.usernames.insert(2, "bob".into());
+
+        let map = |game_id, alice, bob| {
+            let scores = vec![
+                score(1, MatchTeam::None, alice, 97.0),
+                score(2, MatchTeam::None, bob, 99.0),
+            ];
+
+            MapResult::new(game_id, false, ScoringType::ScoreV2, scores)
+        };
+
+        progress.record(map(1, 800_000, 700_000));
+        progress.record(map(2, 600_000, 900_000));
+        progress.record(map(3, 850_000, 750_000));
+        progress.record(map(4, 500_000, 500_000));
+
+        assert_eq!(
+            progress.score_line().as_deref(),
+            Some("**Wins:** `alice` 2 • `bob` 1")
+        );
+        assert_eq!(progress.progression(), "`alice` → `bob` → `alice` → tie");
+    }
+
+    #[test]
+    fn accuracy_scoring_winner() {
+        let scores = vec![
+            score(1, MatchTeam::None, 900_000, 95.0),
+            score(2, MatchTeam::None, 800_000, 99.0),
+        ];
+
+        let map = MapResult::new(1, false, ScoringType::Accuracy, scores);
+
+        assert_eq!(map.winner(), Some(MapWinner::Player(2)));
+    }
+
+    #[test]
+    fn player_averages() {
+        let progress = team_progress();
+        let averages = progress.averages();
+
+        assert_eq!(averages.len(), 4);
+        assert_eq!(averages[0].user_id, 1);
+        assert_eq!(averages[0].maps, 3);
+        assert!((averages[0].score - 466_666.66).abs() < 1.0);
+        assert!((averages[0].accuracy - 98.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn summary_contains_sections() {
+        let summary = team_progress().summary();
+
+        assert!(summary.starts_with("**3 maps played**\n"));
+        assert!(summary.contains(":blue_circle: 2 - 1 :red_circle:"));
+        assert!(summary.contains("1-0 → 1-1 → 2-1"));
+        assert!(summary.contains("`blue1` `466,667` `98.00%` • 3 maps"));
+
+        let empty = MatchProgress::default().summary();
+        assert_eq!(empty, "No map was finished in this match");
+    }
+}
@@ -9,6 +9,7 @@ use twilight_model::id::{
     marker::{ChannelMarker, MessageMarker},
 };
 
+use super::MatchProgress;
 use crate::embeds::{MatchLiveEmbed, MatchLiveEmbeds};
 
 pub struct MatchLiveChannels {
@@ -51,13 +52,21 @@ impl MatchEntry {
 
 pub struct Channel {
     pub id: Id<ChannelMarker>,
-    /// Last msg in the channel
-    pub msg_id: Id<MessageMarker>,
+    /// Last msg in the channel; `None` if the channel only receives the
+    /// summary at the end of the match
+    pub msg_id: Option<Id<MessageMarker>>,
 }
 
 impl Channel {
     pub fn new(id: Id<ChannelMarker>, msg_id: Id<MessageMarker>) -> Self {
-        Self { id, msg_id }
+        Self {
+            id,
+            msg_id: Some(msg_id),
+        }
+    }
+
+    pub fn summary_only(id: Id<ChannelMarker>) -> Self {
+        Self { id, msg_id: None }
     }
 }
 
@@ -81,13 +90,16 @@ pub struct TrackedMatch {
     pub osu_match: OsuMatch,
     /// All embeds of the match
     pub embeds: Vec<MatchLiveEmbed>,
+    /// Results of all finished maps
+    pub progress: MatchProgress,
 }
 
 impl TrackedMatch {
-    pub fn new(osu_match: OsuMatch, embeds: MatchLiveEmbeds) -> Self {
+    pub fn new(osu_match: OsuMatch, embeds: MatchLiveEmbeds, progress: MatchProgress) -> Self {
         Self {
             osu_match,
             embeds: embeds.into_vec(),
+            progress,
         }
     }
 }
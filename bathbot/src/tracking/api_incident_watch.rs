@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use time::OffsetDateTime;
+use tokio::time::interval;
+
+use crate::core::{BackgroundLoop, Context, IncidentChange};
+
+/// How often the osu!api error window is evaluated.
+pub const TICK: Duration = Duration::from_secs(30);
+
+/// Flags the osu!api as degraded while bot-wide osu!api errors pile up.
+#[cold]
+pub async fn api_incident_watch_loop() {
+    let mut interval = interval(TICK);
+
+    loop {
+        interval.tick().await;
+        Context::watchdog().beat(BackgroundLoop::ApiIncidentWatch);
+
+        let incident = Context::api_incident();
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let change = incident.evaluate(now);
+
+        if change == IncidentChange::Unchanged {
+            continue;
+        }
+
+        let counts = incident.counts(now);
+
+        match change {
+            IncidentChange::Started => warn!(
+                timeouts = counts.timeout,
+                server_errors = counts.server_error,
+                rate_limited = counts.rate_limited,
+                "osu!api appears to be degraded"
+            ),
+            IncidentChange::Ended => info!(errors = counts.total(), "osu!api recovered"),
+            IncidentChange::Unchanged => {}
+        }
+
+        change.update_presence();
+    }
+}
@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use rosu_v2::prelude::GameMode;
+use tokio::time::interval;
+
+use crate::core::{BackgroundLoop, BotMetrics, Context};
+
+/// How often the age of the sampled curves is checked.
+pub const TICK: Duration = Duration::from_secs(60 * 60);
+
+/// Age after which a sampled curve is refreshed.
+const REFRESH_AFTER: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Periodically samples the rank-pp curve of each mode so that pp can be
+/// approximated for ranks beyond the leaderboards.
+#[cold]
+pub async fn approx_refresh_loop() {
+    const MODES: [GameMode; 4] = [
+        GameMode::Osu,
+        GameMode::Taiko,
+        GameMode::Catch,
+        GameMode::Mania,
+    ];
+
+    let mut interval = interval(TICK);
+
+    loop {
+        interval.tick().await;
+        Context::watchdog().beat(BackgroundLoop::ApproxRefresh);
+
+        let approx = Context::approx();
+
+        for mode in MODES {
+            let stale = approx
+                .curve_age(mode)
+                .is_none_or(|age| age >= REFRESH_AFTER);
+
+            if stale && let Err(err) = approx.refresh_curve(mode).await {
+                warn!(?err, ?mode, "Failed to refresh rank-pp curve");
+            }
+
+            if let Some(age) = approx.curve_age(mode) {
+                BotMetrics::set_approx_curve_age(mode, age.as_secs_f64());
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use time::OffsetDateTime;
+use tokio::time::interval;
+
+use crate::core::{BackgroundLoop, Context};
+
+/// How often outdated audit log entries are deleted.
+pub const TICK: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Audit log entries older than this are deleted.
+const RETENTION: time::Duration = time::Duration::days(90);
+
+/// Deletes audit log entries once they're older than [`RETENTION`].
+#[cold]
+pub async fn audit_log_cleanup_loop() {
+    let mut interval = interval(TICK);
+
+    loop {
+        interval.tick().await;
+        Context::watchdog().beat(BackgroundLoop::AuditLogCleanup);
+
+        let cutoff = OffsetDateTime::now_utc() - RETENTION;
+
+        match Context::psql()
+            .delete_audit_log_entries_before(cutoff)
+            .await
+        {
+            Ok(0) => {}
+            Ok(deleted) => info!(deleted, "Deleted outdated audit log entries"),
+            Err(err) => warn!(?err, "Failed to delete outdated audit log entries"),
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use futures::{StreamExt, stream};
+use rosu_v2::prelude::GameMode;
+use twilight_gateway::Event;
+
+use crate::{
+    core::{BotConfig, Context},
+    manager::redis::RedisError,
+};
+
+/// Amount of items that are warmed concurrently.
+const CONCURRENCY: usize = 2;
+
+/// Amount of pp ranking pages that are warmed per mode.
+const PP_RANKING_PAGES: u32 = 2;
+
+const MODES: [GameMode; 4] = [
+    GameMode::Osu,
+    GameMode::Taiko,
+    GameMode::Catch,
+    GameMode::Mania,
+];
+
+/// Resources whose redis entries can be warmed on startup.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WarmResource {
+    PpRankings,
+    Medals,
+    Badges,
+    SnipeCountries,
+}
+
+impl WarmResource {
+    pub const ALL: [Self; 4] = [
+        Self::PpRankings,
+        Self::Medals,
+        Self::Badges,
+        Self::SnipeCountries,
+    ];
+
+    fn items(self) -> Vec<WarmItem> {
+        match self {
+            Self::PpRankings => MODES
+                .into_iter()
+                .flat_map(|mode| {
+                    (1..=PP_RANKING_PAGES).map(move |page| WarmItem::PpRanking { mode, page })
+                })
+                .collect(),
+            Self::Medals => vec![WarmItem::Medals],
+            Self::Badges => vec![WarmItem::Badges],
+            Self::SnipeCountries => vec![WarmItem::SnipeCountries(GameMode::Osu)],
+        }
+    }
+}
+
+impl FromStr for WarmResource {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pp_rankings" => Ok(Self::PpRankings),
+            "medals" => Ok(Self::Medals),
+            "badges" => Ok(Self::Badges),
+            "snipe_countries" => Ok(Self::SnipeCountries),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A single redis entry to warm.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum WarmItem {
+    PpRanking { mode: GameMode, page: u32 },
+    Medals,
+    Badges,
+    SnipeCountries(GameMode),
+}
+
+impl WarmItem {
+    async fn warm(self) -> Result<(), RedisError> {
+        let redis = Context::redis();
+
+        match self {
+            Self::PpRanking { mode, page } => redis.pp_ranking(mode, page, None).await.map(drop),
+            Self::Medals => redis.medals().await.map(drop),
+            Self::Badges => redis.badges().await.map(drop),
+            Self::SnipeCountries(mode) => redis.snipe_countries(mode).await.map(drop),
+        }
+    }
+}
+
+impl Display for WarmItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::PpRanking { mode, page } => write!(f, "pp ranking {mode} page {page}"),
+            Self::Medals => f.write_str("osekai medals"),
+            Self::Badges => f.write_str("osekai badges"),
+            Self::SnipeCountries(mode) => write!(f, "snipe countries {mode}"),
+        }
+    }
+}
+
+fn warm_items(resources: &[WarmResource]) -> Vec<WarmItem> {
+    let mut items = Vec::new();
+
+    for item in resources.iter().flat_map(|resource| resource.items()) {
+        if !items.contains(&item) {
+            items.push(item);
+        }
+    }
+
+    items
+}
+
+/// Fills the configured redis entries once the first shard is ready so that
+/// the first users after a deploy don't have to wait for upstream requests.
+#[cold]
+pub async fn cache_prewarm() {
+    let Some(ref config) = BotConfig::get().cache_prewarm else {
+        return;
+    };
+
+    let ready_fut =
+        Context::standby().wait_for_event(|event: &Event| matches!(event, Event::Ready(_)));
+
+    if ready_fut.await.is_err() {
+        return warn!("Standby canceled before any shard was ready");
+    }
+
+    let items = warm_items(&config.resources);
+
+    if config.dry_run {
+        for item in items.iter() {
+            info!(%item, "Would warm cache entry");
+        }
+
+        return info!(count = items.len(), "Cache pre-warm dry run done");
+    }
+
+    let results: Vec<_> = stream::iter(items)
+        .map(|item| async move {
+            let res = item.warm().await;
+
+            // Low priority; let other tasks run in between
+            tokio::task::yield_now().await;
+
+            (item, res)
+        })
+        .buffer_unordered(CONCURRENCY)
+        .collect()
+        .await;
+
+    let mut warmed = 0;
+    let mut failed = 0;
+
+    for (item, res) in results {
+        match res {
+            Ok(()) => warmed += 1,
+            Err(err) => {
+                warn!(%item, ?err, "Failed to warm cache entry");
+                failed += 1;
+            }
+        }
+    }
+
+    info!(warmed, failed, "Cache pre-warm done");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_resources() {
+        assert_eq!("medals".parse(), Ok(WarmResource::Medals));
+        assert_eq!("pp_rankings".parse(), Ok(WarmResource::PpRankings));
+        assert_eq!("medal".parse::<WarmResource>(), Err(()));
+    }
+
+    #[test]
+    fn expand_all_resources() {
+        let items = warm_items(&WarmResource::ALL);
+
+        assert_eq!(items.len(), 4 * PP_RANKING_PAGES as usize + 3);
+        assert!(items.contains(&WarmItem::PpRanking {
+            mode: GameMode::Mania,
+            page: 2
+        }));
+        assert!(items.contains(&WarmItem::SnipeCountries(GameMode::Osu)));
+    }
+
+    #[test]
+    fn expand_selected_resources() {
+        let resources = [
+            WarmResource::Badges,
+            WarmResource::Medals,
+            WarmResource::Badges,
+        ];
+
+        assert_eq!(warm_items(&resources), [WarmItem::Badges, WarmItem::Medals]);
+    }
+}
@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use tokio::time::interval;
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use crate::core::{BackgroundLoop, Context};
+
+/// One guild is reconciled per tick to keep the load on redis negligible.
+pub const TICK: Duration = Duration::from_secs(60);
+
+/// Walks through all cached guilds, one per tick, and removes ids from their
+/// member, channel, and role sets whose entries are missing.
+#[cold]
+pub async fn cache_reconcile_loop() {
+    let mut interval = interval(TICK);
+    let mut queue: Vec<Id<GuildMarker>> = Vec::new();
+
+    loop {
+        interval.tick().await;
+        Context::watchdog().beat(BackgroundLoop::CacheReconcile);
+
+        if queue.is_empty() {
+            match Context::cache().guild_ids().await {
+                Ok(guilds) => queue = guilds,
+                Err(err) => {
+                    warn!(?err, "Failed to get guild ids for reconciliation");
+
+                    continue;
+                }
+            }
+        }
+
+        let Some(guild) = queue.pop() else { continue };
+
+        match Context::cache().reconcile_guild(guild).await {
+            Ok(report) if report.removed() > 0 => {
+                info!(%guild, %report, "Removed dangling cache entries");
+            }
+            Ok(_) => {}
+            Err(err) => warn!(?err, %guild, "Failed to reconcile guild"),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use tokio::time::interval;
+
+use crate::core::{BackgroundLoop, Context, HealthChange};
+
+/// How often the database connection is probed.
+pub const TICK: Duration = Duration::from_secs(30);
+
+/// Periodically checks whether the database is reachable so that commands
+/// can be turned away early while it is not.
+#[cold]
+pub async fn database_probe_loop() {
+    let mut interval = interval(TICK);
+
+    loop {
+        interval.tick().await;
+        Context::watchdog().beat(BackgroundLoop::DatabaseProbe);
+
+        let res = Context::psql().ping().await;
+        let available = res.is_ok();
+
+        match Context::database_health().update(res.is_ok()) {
+            HealthChange::Unchanged => {}
+            HealthChange::Lost => {
+                if let Err(err) = res {
+                    error!(?err, "Lost connection to the database");
+                }
+            }
+            HealthChange::Recovered => {
+                info!("Reconnected to the database");
+
+                if let Err(err) = Context::psql().migrate().await {
+                    error!(?err, "Failed to run migrations after reconnecting");
+                }
+            }
+        }
+
+        // After starting without a database, the data is loaded as soon as
+        // it's reachable; retried on every tick until it succeeds
+        if available && !Context::database_data_loaded() {
+            match Context::reload_database_data().await {
+                Ok(_) => info!("Loaded database data after starting without a database"),
+                Err(err) => error!(?err, "Failed to load database data"),
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::{fmt::Write, time::Duration};
+
+use bathbot_util::EmbedBuilder;
+use time::OffsetDateTime;
+use tokio::time::interval;
+
+use super::prune_report::send_to_owner;
+use crate::core::{BackgroundLoop, Context, ErrorRateAlert};
+
+/// How often the command error rates are evaluated.
+pub const TICK: Duration = Duration::from_secs(60);
+
+/// Alerts about commands whose share of internal errors within the last
+/// window is unusually high.
+#[cold]
+pub async fn error_rate_watch_loop() {
+    let mut interval = interval(TICK);
+
+    // The first tick completes immediately
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+        Context::watchdog().beat(BackgroundLoop::ErrorRateWatch);
+
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let alerts = Context::command_health().evaluate_window(now);
+
+        if alerts.is_empty() {
+            continue;
+        }
+
+        for alert in alerts.iter() {
+            let ErrorRateAlert { name, counts } = alert;
+
+            error!(
+                %name,
+                invocations = counts.total(),
+                internal_errors = counts.internal_error,
+                user_errors = counts.user_error,
+                rate = counts.internal_error_rate(),
+                "High command error rate"
+            );
+        }
+
+        let embed = alert_embed(&alerts).build();
+
+        if let Err(err) = send_to_owner(&embed).await {
+            warn!(?err, "Failed to send error rate alert to owner");
+        }
+    }
+}
+
+fn alert_embed(alerts: &[ErrorRateAlert]) -> EmbedBuilder {
+    let mut description = String::new();
+
+    for ErrorRateAlert { name, counts } in alerts {
+        let _ = writeln!(
+            description,
+            "`{name}`: {errors}/{total} internal errors ({rate:.1}%)",
+            errors = counts.internal_error,
+            total = counts.total(),
+            rate = counts.internal_error_rate() * 100.0,
+        );
+    }
+
+    EmbedBuilder::new()
+        .color_red()
+        .title("High command error rates over the last 5 minutes")
+        .description(description)
+}
@@ -0,0 +1,33 @@
+use std::time::Duration;
+
+use time::OffsetDateTime;
+use tokio::time::interval;
+
+use crate::core::{BackgroundLoop, Context};
+
+/// How often the maintenance mode is fetched from redis.
+pub const TICK: Duration = Duration::from_secs(10);
+
+/// Mirrors the maintenance mode from redis so that it survives restarts and
+/// ends on its own once the redis entry expires.
+#[cold]
+pub async fn maintenance_watch_loop() {
+    let mut interval = interval(TICK);
+
+    loop {
+        interval.tick().await;
+        Context::watchdog().beat(BackgroundLoop::MaintenanceWatch);
+
+        let state = match Context::cache().maintenance().await {
+            Ok(state) => state,
+            Err(err) => {
+                warn!(?err, "Failed to get maintenance");
+
+                continue;
+            }
+        };
+
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        Context::maintenance().update(state, now).update_presence();
+    }
+}
@@ -0,0 +1,320 @@
+use std::{collections::HashMap, fmt::Write, slice, time::Duration};
+
+use bathbot_psql::model::osu::MapsetWatch;
+use bathbot_util::{
+    EmbedBuilder, IntHasher,
+    constants::{OSU_BASE, UNKNOWN_CHANNEL},
+};
+use futures::future::join_all;
+use rosu_v2::prelude::{BeatmapsetExtended, OsuError, RankStatus};
+use time::OffsetDateTime;
+use tokio::time::interval;
+use twilight_http::{
+    api_error::{ApiError, GeneralApiError},
+    error::ErrorType as TwilightErrorType,
+};
+use twilight_model::{
+    channel::message::Embed,
+    id::{Id, marker::ChannelMarker},
+};
+
+use crate::core::{BackgroundLoop, Context};
+
+/// How often watched mapsets are checked for status changes.
+pub const TICK: Duration = Duration::from_secs(60 * 60);
+
+/// Amount of mapsets that are requested concurrently.
+const CHUNK_SIZE: usize = 10;
+
+/// How long watches are kept after their mapset ranked.
+const EXPIRE_AFTER: time::Duration = time::Duration::days(60);
+
+/// Periodically checks the status of all watched mapsets and notifies watchers
+/// about changes.
+#[cold]
+pub async fn mapset_watch_loop() {
+    let mut interval = interval(TICK);
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+        Context::watchdog().beat(BackgroundLoop::MapsetWatch);
+
+        let now = OffsetDateTime::now_utc();
+
+        match Context::mapset_watches().remove_expired(now).await {
+            Ok(0) => {}
+            Ok(count) => debug!(count, "Removed expired mapset watches"),
+            Err(err) => warn!(?err, "Failed to remove expired mapset watches"),
+        }
+
+        let watches = match Context::mapset_watches().all().await {
+            Ok(watches) => watches,
+            Err(err) => {
+                warn!(?err, "Failed to get mapset watches");
+
+                continue;
+            }
+        };
+
+        let mut by_mapset: HashMap<u32, Vec<MapsetWatch>, IntHasher> = HashMap::default();
+
+        for watch in watches {
+            by_mapset.entry(watch.mapset_id).or_default().push(watch);
+        }
+
+        let mapset_ids: Vec<_> = by_mapset.keys().copied().collect();
+
+        for chunk in mapset_ids.chunks(CHUNK_SIZE) {
+            let fetches = chunk.iter().map(|&mapset_id| async move {
+                (mapset_id, Context::osu().beatmapset(mapset_id).await)
+            });
+
+            for (mapset_id, res) in join_all(fetches).await {
+                let Some(watches) = by_mapset.remove(&mapset_id) else {
+                    continue;
+                };
+
+                let mapset = match res {
+                    Ok(mapset) => Some(mapset),
+                    Err(OsuError::NotFound) => None,
+                    Err(err) => {
+                        warn!(mapset_id, ?err, "Failed to request watched mapset");
+
+                        continue;
+                    }
+                };
+
+                process_mapset(mapset_id, mapset.as_ref(), &watches, now).await;
+            }
+        }
+    }
+}
+
+async fn process_mapset(
+    mapset_id: u32,
+    mapset: Option<&BeatmapsetExtended>,
+    watches: &[MapsetWatch],
+    now: OffsetDateTime,
+) {
+    // All watches of a mapset share the same stored status
+    let Some(stored) = watches.first().map(|watch| watch.status) else {
+        return;
+    };
+
+    match WatchUpdate::new(stored, mapset.map(|mapset| mapset.status)) {
+        WatchUpdate::Unchanged => {}
+        WatchUpdate::Changed { old, new } => {
+            let Some(mapset) = mapset else { return };
+
+            let expires_at = expiry(new, mapset.ranked_date, now);
+
+            let update_fut = Context::mapset_watches().update_status(mapset_id, new, expires_at);
+
+            if let Err(err) = update_fut.await {
+                return warn!(mapset_id, ?err, "Failed to update mapset watch status");
+            }
+
+            info!(mapset_id, ?old, ?new, "Watched mapset changed status");
+
+            let embed = changed_embed(mapset, old, new);
+            notify(watches, &embed).await;
+        }
+        WatchUpdate::Deleted => {
+            // Remove first so that watchers are notified only once
+            if let Err(err) = Context::mapset_watches().remove_mapset(mapset_id).await {
+                return warn!(
+                    mapset_id,
+                    ?err,
+                    "Failed to remove watches of deleted mapset"
+                );
+            }
+
+            info!(mapset_id, "Watched mapset was deleted");
+
+            let embed = deleted_embed(&watches[0]);
+            notify(watches, &embed).await;
+        }
+    }
+}
+
+fn changed_embed(mapset: &BeatmapsetExtended, old: RankStatus, new: RankStatus) -> Embed {
+    let mut description = format!("**{old:?}** → **{new:?}**");
+
+    if let Some(ranked_date) = mapset.ranked_date.filter(|_| is_ranked(new)) {
+        let _ = write!(
+            description,
+            "\n{new:?} <t:{}:f>",
+            ranked_date.unix_timestamp()
+        );
+    }
+
+    EmbedBuilder::new()
+        .title(format!("{} - {}", mapset.artist, mapset.title))
+        .url(format!("{OSU_BASE}beatmapsets/{}", mapset.mapset_id))
+        .thumbnail(mapset.covers.list.as_str())
+        .description(description)
+        .build()
+}
+
+fn deleted_embed(watch: &MapsetWatch) -> Embed {
+    EmbedBuilder::new()
+        .title(watch.name.as_ref())
+        .description(format!(
+            "Mapset {} was deleted, removed it from your watches",
+            watch.mapset_id
+        ))
+        .color_red()
+        .build()
+}
+
+async fn notify(watches: &[MapsetWatch], embed: &Embed) {
+    let http = Context::http();
+    let embeds = slice::from_ref(embed);
+
+    for watch in watches {
+        let channel = match watch.channel_id {
+            Some(channel) => channel,
+            None => match dm_channel(watch).await {
+                Some(channel) => channel,
+                None => continue,
+            },
+        };
+
+        let Err(err) = http.create_message(channel).embeds(embeds).await else {
+            continue;
+        };
+
+        if let TwilightErrorType::Response {
+            error:
+                ApiError::General(GeneralApiError {
+                    code: UNKNOWN_CHANNEL,
+                    ..
+                }),
+            ..
+        } = err.kind()
+        {
+            // The channel is gone so the watch can't be notified anymore
+            let _ = Context::mapset_watches()
+                .remove(watch.user_id, watch.mapset_id)
+                .await;
+        } else {
+            warn!(%channel, ?err, "Failed to send mapset watch notification");
+        }
+    }
+}
+
+async fn dm_channel(watch: &MapsetWatch) -> Option<Id<ChannelMarker>> {
+    let channel_res = match Context::http().create_private_channel(watch.user_id).await {
+        Ok(channel_res) => channel_res,
+        Err(err) => {
+            warn!(user = %watch.user_id, ?err, "Failed to create DM channel");
+
+            return None;
+        }
+    };
+
+    match channel_res.model().await {
+        Ok(channel) => Some(channel.id),
+        Err(err) => {
+            warn!(user = %watch.user_id, ?err, "Failed to deserialize DM channel");
+
+            None
+        }
+    }
+}
+
+fn is_ranked(status: RankStatus) -> bool {
+    matches!(
+        status,
+        RankStatus::Ranked | RankStatus::Approved | RankStatus::Loved
+    )
+}
+
+/// When watches should expire for the given status.
+///
+/// Watches are kept for a while after their mapset ranked so that watchers
+/// still notice if it gets unranked shortly after.
+fn expiry(
+    status: RankStatus,
+    ranked_date: Option<OffsetDateTime>,
+    now: OffsetDateTime,
+) -> Option<OffsetDateTime> {
+    is_ranked(status).then(|| ranked_date.unwrap_or(now) + EXPIRE_AFTER)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum WatchUpdate {
+    Unchanged,
+    Changed { old: RankStatus, new: RankStatus },
+    Deleted,
+}
+
+impl WatchUpdate {
+    /// Compare the stored status with the current one, `None` if the mapset
+    /// no longer exists.
+    fn new(stored: RankStatus, current: Option<RankStatus>) -> Self {
+        match current {
+            Some(new) if new == stored => Self::Unchanged,
+            Some(new) => Self::Changed { old: stored, new },
+            None => Self::Deleted,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month};
+
+    use super::*;
+
+    fn date() -> OffsetDateTime {
+        Date::from_calendar_date(2026, Month::March, 14)
+            .unwrap()
+            .midnight()
+            .assume_utc()
+    }
+
+    #[test]
+    fn status_diff() {
+        assert_eq!(
+            WatchUpdate::new(RankStatus::Qualified, Some(RankStatus::Qualified)),
+            WatchUpdate::Unchanged
+        );
+        assert_eq!(
+            WatchUpdate::new(RankStatus::Qualified, Some(RankStatus::Ranked)),
+            WatchUpdate::Changed {
+                old: RankStatus::Qualified,
+                new: RankStatus::Ranked
+            }
+        );
+        assert_eq!(
+            WatchUpdate::new(RankStatus::Qualified, Some(RankStatus::Pending)),
+            WatchUpdate::Changed {
+                old: RankStatus::Qualified,
+                new: RankStatus::Pending
+            }
+        );
+        assert_eq!(
+            WatchUpdate::new(RankStatus::Pending, None),
+            WatchUpdate::Deleted
+        );
+    }
+
+    #[test]
+    fn expiry_after_ranking() {
+        let ranked = date();
+        let now = ranked + time::Duration::hours(1);
+
+        assert_eq!(
+            expiry(RankStatus::Ranked, Some(ranked), now),
+            Some(ranked + EXPIRE_AFTER)
+        );
+        assert_eq!(
+            expiry(RankStatus::Loved, None, now),
+            Some(now + EXPIRE_AFTER)
+        );
+        assert_eq!(expiry(RankStatus::Qualified, Some(ranked), now), None);
+        assert_eq!(expiry(RankStatus::Pending, None, now), None);
+    }
+}
@@ -1,16 +1,43 @@
+#[cfg(feature = "statsrefresh")]
+pub use self::stats_refresh::{TICK as STATS_REFRESH_TICK, stats_refresh_loop};
 #[cfg(feature = "twitch")]
 pub use self::twitch::online_streams::OnlineTwitchStreams;
 #[cfg(feature = "twitchtracking")]
-pub use self::twitch::twitch_loop::twitch_tracking_loop;
+pub use self::twitch::twitch_loop::{TICK as TWITCH_TRACKING_TICK, twitch_tracking_loop};
 pub use self::{
+    api_incident_watch::{TICK as API_INCIDENT_WATCH_TICK, api_incident_watch_loop},
+    approx_refresh::{TICK as APPROX_REFRESH_TICK, approx_refresh_loop},
+    audit_log_cleanup::{TICK as AUDIT_LOG_CLEANUP_TICK, audit_log_cleanup_loop},
+    cache_prewarm::{WarmResource, cache_prewarm},
+    cache_reconcile::{TICK as CACHE_RECONCILE_TICK, cache_reconcile_loop},
+    database_probe::{TICK as DATABASE_PROBE_TICK, database_probe_loop},
+    error_rate_watch::{TICK as ERROR_RATE_WATCH_TICK, error_rate_watch_loop},
+    maintenance_watch::{TICK as MAINTENANCE_WATCH_TICK, maintenance_watch_loop},
+    mapset_watch::{TICK as MAPSET_WATCH_TICK, mapset_watch_loop},
     ordr::{Ordr, OrdrReceivers},
-    osu::{OsuTracking, TrackEntryParams},
+    osu::{OsuTracking, TRACKING_DIGEST_TICK, TrackEntryParams, tracking_digest_loop},
+    prune_report::{
+        TICK as PRUNE_REPORT_TICK, apply_prune_report, create_prune_report, prune_report_loop,
+    },
     scores_ws::{ScoresWebSocket, ScoresWebSocketDisconnect},
 };
 
+mod api_incident_watch;
+mod approx_refresh;
+mod audit_log_cleanup;
+mod cache_prewarm;
+mod cache_reconcile;
+mod database_probe;
+mod error_rate_watch;
+mod maintenance_watch;
+mod mapset_watch;
 mod ordr;
 mod osu;
+mod prune_report;
 mod scores_ws;
 
+#[cfg(feature = "statsrefresh")]
+mod stats_refresh;
+
 #[cfg(feature = "twitch")]
 mod twitch;
@@ -0,0 +1,186 @@
+use bathbot_psql::Database;
+use eyre::{Result, WrapErr};
+use futures::{StreamExt, stream};
+use rosu_v2::prelude::{GameMode, Score};
+
+use crate::{
+    core::Context,
+    manager::redis::osu::{CachedUser, UserArgsSlim},
+};
+
+/// How many top scores are requested concurrently while seeding a country.
+const SEED_CONCURRENCY: usize = 4;
+
+/// How many of a country's tracked users are considered while seeding it.
+///
+/// Only the users with the highest pp are considered because they're the
+/// most likely ones to hold the record.
+const SEED_USERS: usize = 16;
+
+/// Checks whether the score is the highest pp play of the user's country
+/// that the bot has seen and, if so, stores it as new record.
+///
+/// Countries are seeded the first time they're encountered through the best
+/// scores of the country's highest pp tracked users.
+pub async fn check_country_record(user: &CachedUser, score: &Score, tops: &[Score]) -> bool {
+    let Some(pp) = score.pp else { return false };
+
+    let psql = Context::psql();
+    let country_code = user.country_code.as_str();
+    let mode = score.mode;
+
+    match psql.select_country_record(country_code, mode).await {
+        Ok(Some(_)) => {}
+        Ok(None) => match seed(psql, country_code, score, pp, tops).await {
+            // Nobody else of the country had a score so this one is the record
+            Ok(true) => return true,
+            Ok(false) => {}
+            Err(err) => {
+                log!(warn: country_code, ?mode, ?err, "Failed to seed country record");
+
+                return false;
+            }
+        },
+        Err(err) => {
+            log!(warn: country_code, ?mode, ?err, "Failed to get country record");
+
+            return false;
+        }
+    }
+
+    let update_fut = psql.update_country_record(country_code, mode, pp, score.id, score.user_id);
+
+    match update_fut.await {
+        Ok(updated) => updated,
+        Err(err) => {
+            log!(warn: country_code, ?mode, ?err, "Failed to update country record");
+
+            false
+        }
+    }
+}
+
+/// Seeds the country with the best score among its [`SEED_USERS`] highest pp
+/// tracked users, excluding the given score so that it can still become the
+/// record.
+///
+/// If there is no other score, the given score is seeded instead. Returns
+/// whether that was the case.
+async fn seed(
+    psql: &Database,
+    country_code: &str,
+    score: &Score,
+    pp: f32,
+    tops: &[Score],
+) -> Result<bool> {
+    let mode = score.mode;
+
+    let user_ids = psql
+        .select_tracked_osu_user_ids_by_country(country_code, mode, SEED_USERS)
+        .await
+        .wrap_err("Failed to get tracked users of country")?;
+
+    let mut best = best_score(tops.iter(), score.id);
+
+    let mut top_scores = stream::iter(user_ids)
+        .filter(|user_id| std::future::ready(*user_id != score.user_id))
+        .map(|user_id| {
+            let user_args = UserArgsSlim::user_id(user_id).mode(mode);
+
+            Context::osu_scores().top(1, false).exec(user_args)
+        })
+        .buffer_unordered(SEED_CONCURRENCY);
+
+    while let Some(res) = top_scores.next().await {
+        match res {
+            Ok(scores) => {
+                if let Some(candidate) = best_score(scores.iter(), score.id) {
+                    best = Some(best.map_or(candidate, |best| max_by_pp(best, candidate)));
+                }
+            }
+            Err(err) => log!(warn: ?err, "Failed to get top score while seeding country record"),
+        }
+    }
+
+    let incoming = SeedCandidate {
+        pp,
+        score_id: score.id,
+        user_id: score.user_id,
+    };
+
+    let (record, is_incoming) = initial_record(best, incoming);
+
+    let inserted = psql
+        .insert_country_record(
+            country_code,
+            mode,
+            record.pp,
+            record.score_id,
+            record.user_id,
+        )
+        .await
+        .wrap_err("Failed to insert country record")?;
+
+    Ok(inserted && is_incoming)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct SeedCandidate {
+    pp: f32,
+    score_id: u64,
+    user_id: u32,
+}
+
+fn best_score<'s>(scores: impl Iterator<Item = &'s Score>, exclude: u64) -> Option<SeedCandidate> {
+    scores
+        .filter(|score| score.id != exclude)
+        .filter_map(|score| {
+            Some(SeedCandidate {
+                pp: score.pp?,
+                score_id: score.id,
+                user_id: score.user_id,
+            })
+        })
+        .reduce(max_by_pp)
+}
+
+fn max_by_pp(a: SeedCandidate, b: SeedCandidate) -> SeedCandidate {
+    if b.pp > a.pp { b } else { a }
+}
+
+/// The record to seed a country with and whether it's the incoming score.
+fn initial_record(best: Option<SeedCandidate>, incoming: SeedCandidate) -> (SeedCandidate, bool) {
+    match best {
+        Some(best) => (best, false),
+        None => (incoming, true),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(pp: f32, score_id: u64) -> SeedCandidate {
+        SeedCandidate {
+            pp,
+            score_id,
+            user_id: 2,
+        }
+    }
+
+    #[test]
+    fn empty_country_seeds_incoming_score() {
+        let incoming = candidate(300.0, 1);
+
+        assert_eq!(initial_record(None, incoming), (incoming, true));
+    }
+
+    #[test]
+    fn others_seed_before_incoming_score() {
+        let incoming = candidate(300.0, 1);
+        let best = candidate(200.0, 2);
+
+        // The incoming score becomes the record through the update instead
+        assert_eq!(initial_record(Some(best), incoming), (best, false));
+    }
+}
@@ -0,0 +1,376 @@
+use std::{fmt::Write, slice, time::Duration};
+
+use bathbot_util::EmbedBuilder;
+use eyre::{Result, WrapErr};
+use time::{OffsetDateTime, Time};
+use twilight_model::{
+    channel::message::Embed,
+    id::{Id, marker::UserMarker},
+};
+
+use crate::core::{BackgroundLoop, Context};
+
+/// Maximum amount of entries listed in a single digest.
+const MAX_ENTRIES: usize = 10;
+
+/// Amount of consecutive days with failed DMs after which the digest is
+/// disabled.
+const MAX_FAILURES: u32 = 3;
+
+/// Time of day (UTC) at which digests are sent.
+const FLUSH_TIME: Time = Time::MIDNIGHT;
+
+/// Longest time between two flushes.
+pub const TICK: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Queue a compact notification line for every discord user that is linked to
+/// the osu! user and opted into the tracking DM digest.
+pub(super) async fn push_digest(user_id: u32, line: &str) {
+    let recipients = match Context::user_config()
+        .tracking_dm_digest_recipients(user_id)
+        .await
+    {
+        Ok(recipients) => recipients,
+        Err(err) => {
+            log!(warn: user = user_id, ?err, "Failed to get digest recipients");
+
+            return;
+        }
+    };
+
+    for recipient in recipients {
+        if let Err(err) = Context::cache().push_tracking_digest(recipient, line).await {
+            log!(warn: user = user_id, %recipient, ?err, "Failed to push digest entry");
+        }
+    }
+}
+
+/// Sends the pending tracking digests once a day.
+#[cold]
+pub async fn tracking_digest_loop() {
+    loop {
+        tokio::time::sleep(until_next_flush(OffsetDateTime::now_utc())).await;
+        Context::watchdog().beat(BackgroundLoop::TrackingDigest);
+
+        flush_all(&DiscordDm).await;
+    }
+}
+
+/// Time until the next [`FLUSH_TIME`].
+fn until_next_flush(now: OffsetDateTime) -> Duration {
+    let mut next = now.replace_time(FLUSH_TIME);
+
+    if next <= now {
+        next += time::Duration::DAY;
+    }
+
+    (next - now).try_into().unwrap_or(Duration::ZERO)
+}
+
+async fn flush_all<S: DigestSender>(sender: &S) {
+    let cache = Context::cache();
+
+    let users = match cache.tracking_digest_users().await {
+        Ok(users) => users,
+        Err(err) => {
+            log!(warn: ?err, "Failed to get tracking digest users");
+
+            return;
+        }
+    };
+
+    for user in users {
+        // Users may have disabled the digest since entries were queued
+        let enabled = match Context::user_config().with_osu_id(user).await {
+            Ok(config) => config.tracking_dm_digest == Some(true),
+            Err(err) => {
+                log!(warn: %user, ?err, "Failed to get user config");
+
+                continue;
+            }
+        };
+
+        if !enabled {
+            if let Err(err) = cache.clear_tracking_digest(user).await {
+                log!(warn: %user, ?err, "Failed to clear tracking digest");
+            }
+
+            continue;
+        }
+
+        let lines_fut = cache.tracking_digest(user);
+        let failures_fut = cache.tracking_digest_failures(user);
+
+        let (lines, failures) = match tokio::try_join!(lines_fut, failures_fut) {
+            Ok(tuple) => tuple,
+            Err(err) => {
+                log!(warn: %user, ?err, "Failed to get tracking digest");
+
+                continue;
+            }
+        };
+
+        let res = match flush_user(sender, user, &lines, failures).await {
+            FlushOutcome::Sent => match cache.remove_tracking_digest(user, lines.len()).await {
+                Ok(_) => cache.clear_tracking_digest_failures(user).await,
+                Err(err) => Err(err),
+            },
+            FlushOutcome::Failed => cache.add_tracking_digest_failure(user).await.map(|_| ()),
+            FlushOutcome::Disabled => match Context::user_config()
+                .disable_tracking_dm_digest(user)
+                .await
+            {
+                Ok(_) => cache.clear_tracking_digest(user).await,
+                Err(err) => Err(err),
+            },
+        };
+
+        if let Err(err) = res {
+            log!(warn: %user, ?err, "Failed to update tracking digest");
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum FlushOutcome {
+    /// The digest was sent, or there was nothing to send.
+    Sent,
+    /// The digest could not be sent; it will be retried the next day.
+    Failed,
+    /// The digest failed too often and should be disabled.
+    Disabled,
+}
+
+/// Try to send the user's digest.
+///
+/// `failures` is the amount of consecutive failures before this attempt. If
+/// this attempt fails too, a final notice is sent that the digest is being
+/// disabled.
+async fn flush_user<S: DigestSender>(
+    sender: &S,
+    user: Id<UserMarker>,
+    lines: &[String],
+    failures: u32,
+) -> FlushOutcome {
+    if lines.is_empty() {
+        return FlushOutcome::Sent;
+    }
+
+    let Err(err) = sender.send(user, &digest_embed(lines)).await else {
+        return FlushOutcome::Sent;
+    };
+
+    if failures + 1 < MAX_FAILURES {
+        log!(debug: %user, ?err, "Failed to send tracking digest");
+
+        return FlushOutcome::Failed;
+    }
+
+    log!(info: %user, ?err, "Disabling tracking digest after repeated failures");
+
+    if let Err(err) = sender.send(user, &disabled_embed()).await {
+        log!(debug: %user, ?err, "Failed to send tracking digest notice");
+    }
+
+    FlushOutcome::Disabled
+}
+
+/// Embed listing the most recent [`MAX_ENTRIES`] lines and the amount of
+/// older ones.
+fn digest_embed(lines: &[String]) -> Embed {
+    let skip = lines.len().saturating_sub(MAX_ENTRIES);
+    let mut description = lines[skip..].join("\n");
+
+    match skip {
+        0 => {}
+        1 => description.push_str("\n…and 1 earlier top play"),
+        _ => {
+            let _ = write!(description, "\n…and {skip} earlier top plays");
+        }
+    }
+
+    EmbedBuilder::new()
+        .title("Your new top plays")
+        .description(description)
+        .footer("Disable this digest via /config edit")
+        .build()
+}
+
+fn disabled_embed() -> Embed {
+    EmbedBuilder::new()
+        .title("Tracking digest disabled")
+        .description(
+            "Your daily digest of tracked top plays could not be delivered for several days \
+            so it has been disabled.\n\
+            Make sure your DMs are open and re-enable it via `/config edit`.",
+        )
+        .color_red()
+        .build()
+}
+
+/// Delivers digests to users.
+trait DigestSender {
+    async fn send(&self, user: Id<UserMarker>, embed: &Embed) -> Result<()>;
+}
+
+struct DiscordDm;
+
+impl DigestSender for DiscordDm {
+    async fn send(&self, user: Id<UserMarker>, embed: &Embed) -> Result<()> {
+        let channel = Context::http()
+            .create_private_channel(user)
+            .await
+            .wrap_err("Failed to create DM channel")?
+            .model()
+            .await
+            .wrap_err("Failed to deserialize DM channel")?;
+
+        Context::http()
+            .create_message(channel.id)
+            .embeds(slice::from_ref(embed))
+            .await
+            .wrap_err("Failed to send DM")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use eyre::eyre;
+    use time::{Date, Month};
+
+    use super::*;
+
+    const USER: Id<UserMarker> = Id::new(1);
+
+    #[derive(Default)]
+    struct MockSender {
+        fail: bool,
+        sent: Mutex<Vec<Embed>>,
+    }
+
+    impl MockSender {
+        fn failing() -> Self {
+            Self {
+                fail: true,
+                ..Default::default()
+            }
+        }
+
+        fn sent(&self) -> Vec<Embed> {
+            self.sent.lock().unwrap().clone()
+        }
+    }
+
+    impl DigestSender for MockSender {
+        async fn send(&self, _: Id<UserMarker>, embed: &Embed) -> Result<()> {
+            self.sent.lock().unwrap().push(embed.clone());
+
+            if self.fail {
+                Err(eyre!("Cannot send messages to this user"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    fn lines(amount: usize) -> Vec<String> {
+        (1..=amount).map(|i| format!("line {i}")).collect()
+    }
+
+    #[test]
+    fn digest_lists_all_entries() {
+        let embed = digest_embed(&lines(3));
+
+        assert_eq!(embed.description.as_deref(), Some("line 1\nline 2\nline 3"));
+    }
+
+    #[test]
+    fn digest_summarizes_overflow() {
+        let embed = digest_embed(&lines(14));
+        let description = embed.description.unwrap();
+        let mut rows = description.lines();
+
+        assert_eq!(rows.next(), Some("line 5"));
+        assert_eq!(rows.by_ref().take(9).last(), Some("line 14"));
+        assert_eq!(rows.next(), Some("…and 4 earlier top plays"));
+        assert_eq!(rows.next(), None);
+
+        let embed = digest_embed(&lines(11));
+        assert!(
+            embed
+                .description
+                .unwrap()
+                .ends_with("…and 1 earlier top play")
+        );
+    }
+
+    #[tokio::test]
+    async fn send_single_embed() {
+        let sender = MockSender::default();
+
+        assert_eq!(
+            flush_user(&sender, USER, &lines(25), 0).await,
+            FlushOutcome::Sent
+        );
+        assert_eq!(sender.sent().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn skip_empty_digest() {
+        let sender = MockSender::default();
+
+        assert_eq!(flush_user(&sender, USER, &[], 0).await, FlushOutcome::Sent);
+        assert!(sender.sent().is_empty());
+    }
+
+    #[tokio::test]
+    async fn disable_after_consecutive_failures() {
+        let sender = MockSender::failing();
+        let lines = lines(2);
+
+        for failures in 0..MAX_FAILURES - 1 {
+            let outcome = flush_user(&sender, USER, &lines, failures).await;
+            assert_eq!(outcome, FlushOutcome::Failed);
+        }
+
+        assert_eq!(sender.sent().len(), 2);
+
+        let outcome = flush_user(&sender, USER, &lines, MAX_FAILURES - 1).await;
+        assert_eq!(outcome, FlushOutcome::Disabled);
+
+        // The third failed digest is followed by a final explanation attempt
+        let sent = sender.sent();
+        assert_eq!(sent.len(), 4);
+        assert_eq!(sent[3].title.as_deref(), Some("Tracking digest disabled"));
+    }
+
+    #[tokio::test]
+    async fn success_after_failures() {
+        let sender = MockSender::default();
+        let outcome = flush_user(&sender, USER, &lines(1), MAX_FAILURES - 1).await;
+
+        assert_eq!(outcome, FlushOutcome::Sent);
+        assert_eq!(sender.sent().len(), 1);
+    }
+
+    #[test]
+    fn next_flush() {
+        let date = Date::from_calendar_date(2026, Month::March, 14).unwrap();
+        let now = date.with_hms(18, 30, 0).unwrap().assume_utc();
+
+        assert_eq!(
+            until_next_flush(now),
+            Duration::from_secs(5 * 60 * 60 + 30 * 60)
+        );
+
+        let midnight = date.midnight().assume_utc();
+        assert_eq!(
+            until_next_flush(midnight),
+            Duration::from_secs(24 * 60 * 60)
+        );
+    }
+}
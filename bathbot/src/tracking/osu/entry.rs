@@ -3,7 +3,7 @@ use std::{
     num::NonZeroU64,
     ops::Not,
     sync::{
-        Arc, RwLock, RwLockReadGuard,
+        Arc, Mutex, RwLock, RwLockReadGuard,
         atomic::{AtomicI64, AtomicU32, Ordering},
     },
 };
@@ -13,7 +13,10 @@ use bathbot_util::IntHasher;
 use rosu_v2::{model::GameMode, prelude::Score};
 use time::OffsetDateTime;
 
-use super::TrackEntryParams;
+use super::{
+    TrackEntryParams,
+    milestones::{Milestone, MilestoneStats, Milestones},
+};
 use crate::core::Context;
 
 type Channels = HashMap<NonZeroU64, TrackEntryParams, IntHasher>;
@@ -24,6 +27,8 @@ pub struct TrackEntry {
     last_pp: AtomicU32,
     /// Unix timestamp of the last update
     last_ended_at: AtomicI64,
+    /// Last announced milestones
+    milestones: Mutex<Milestones>,
     channels: RwLock<Channels>,
 }
 
@@ -93,6 +98,42 @@ impl TrackEntry {
         }
     }
 
+    /// Detects crossed milestones and stores the new milestones both
+    /// in-memory and in the DB.
+    pub async fn check_milestones(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        stats: MilestoneStats,
+    ) -> Vec<Milestone> {
+        let (milestones, crossed) = {
+            let mut guard = self.milestones.lock().unwrap();
+            let (next, crossed) = guard.detect(stats);
+
+            if next == *guard {
+                return crossed;
+            }
+
+            *guard = next;
+
+            (next, crossed)
+        };
+
+        let update_fut = Context::psql().update_tracked_milestones(
+            user_id,
+            mode,
+            milestones.playcount,
+            milestones.ranked_score,
+            milestones.pp,
+        );
+
+        if let Err(err) = update_fut.await {
+            error!(user_id, ?mode, ?err, "Failed to update tracked milestones");
+        }
+
+        crossed
+    }
+
     fn store_last_pp(&self, pp: f32, ended_at: OffsetDateTime) {
         self.last_pp.store(pp.to_bits(), Ordering::SeqCst);
         self.last_ended_at
@@ -102,6 +143,12 @@ impl TrackEntry {
     fn insert(&self, user: DbTrackedOsuUser) {
         self.store_last_pp(user.last_pp, user.last_updated);
 
+        *self.milestones.lock().unwrap() = Milestones {
+            playcount: user.playcount_milestone.map(|n| n as u64),
+            ranked_score: user.ranked_score_milestone.map(|n| n as u64),
+            pp: user.pp_milestone.map(|n| n as u64),
+        };
+
         let Some(channel_id) = NonZeroU64::new(user.channel_id as u64) else {
             return;
         };
@@ -112,7 +159,8 @@ impl TrackEntry {
                 user.max_index.map(|n| n as u8),
             )
             .with_pp(user.min_pp, user.max_pp)
-            .with_combo_percent(user.min_combo_percent, user.max_combo_percent);
+            .with_combo_percent(user.min_combo_percent, user.max_combo_percent)
+            .with_compact(user.compact);
 
         self.channels.write().unwrap().insert(channel_id, params);
     }
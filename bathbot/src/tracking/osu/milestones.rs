@@ -0,0 +1,234 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use bathbot_util::numbers::WithComma;
+
+/// Metrics for which milestones are announced and their step size.
+const THRESHOLDS: [(MilestoneMetric, u64); 3] = [
+    (MilestoneMetric::Playcount, 1_000),
+    (MilestoneMetric::RankedScore, 10_000_000),
+    (MilestoneMetric::Pp, 500),
+];
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MilestoneMetric {
+    Playcount,
+    RankedScore,
+    Pp,
+}
+
+/// The current values of a user's metrics.
+#[derive(Copy, Clone, Debug)]
+pub struct MilestoneStats {
+    pub playcount: u32,
+    pub ranked_score: u64,
+    pub pp: f32,
+}
+
+impl MilestoneStats {
+    fn get(self, metric: MilestoneMetric) -> u64 {
+        match metric {
+            MilestoneMetric::Playcount => self.playcount as u64,
+            MilestoneMetric::RankedScore => self.ranked_score,
+            MilestoneMetric::Pp => self.pp.max(0.0) as u64,
+        }
+    }
+}
+
+/// The last announced milestone of each metric.
+///
+/// `None` means the metric has not been observed yet.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Milestones {
+    pub playcount: Option<u64>,
+    pub ranked_score: Option<u64>,
+    pub pp: Option<u64>,
+}
+
+impl Milestones {
+    fn get_mut(&mut self, metric: MilestoneMetric) -> &mut Option<u64> {
+        match metric {
+            MilestoneMetric::Playcount => &mut self.playcount,
+            MilestoneMetric::RankedScore => &mut self.ranked_score,
+            MilestoneMetric::Pp => &mut self.pp,
+        }
+    }
+
+    /// Detect which milestones were crossed since the last announcement.
+    ///
+    /// Returns the updated milestones and the crossed milestones. Metrics
+    /// that were not observed before only store their current milestone
+    /// without announcing it. Dropping below a milestone does not lower it
+    /// so that it won't be announced again.
+    pub fn detect(self, stats: MilestoneStats) -> (Self, Vec<Milestone>) {
+        let mut next = self;
+        let mut crossed = Vec::new();
+
+        for (metric, step) in THRESHOLDS {
+            let curr = stats.get(metric) / step * step;
+            let last = next.get_mut(metric);
+
+            match *last {
+                Some(prev) if curr > prev => {
+                    crossed.push(Milestone {
+                        metric,
+                        value: curr,
+                    });
+                    *last = Some(curr);
+                }
+                Some(_) => {}
+                None => *last = Some(curr),
+            }
+        }
+
+        (next, crossed)
+    }
+}
+
+/// A crossed milestone.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Milestone {
+    pub metric: MilestoneMetric,
+    pub value: u64,
+}
+
+impl Milestone {
+    pub fn display(self, username: &str) -> MilestoneFormatter<'_> {
+        MilestoneFormatter {
+            milestone: self,
+            username,
+        }
+    }
+}
+
+pub struct MilestoneFormatter<'n> {
+    milestone: Milestone,
+    username: &'n str,
+}
+
+impl Display for MilestoneFormatter<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let Self {
+            milestone,
+            username,
+        } = self;
+        let value = WithComma::new(milestone.value);
+
+        match milestone.metric {
+            MilestoneMetric::Playcount => {
+                write!(f, "🎉 {username} just reached {value} plays!")
+            }
+            MilestoneMetric::RankedScore => {
+                write!(f, "🎉 {username} just passed {value} ranked score!")
+            }
+            MilestoneMetric::Pp => write!(f, "🎉 {username} just passed {value}pp!"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(playcount: u32, ranked_score: u64, pp: f32) -> MilestoneStats {
+        MilestoneStats {
+            playcount,
+            ranked_score,
+            pp,
+        }
+    }
+
+    #[test]
+    fn first_observation_is_not_announced() {
+        let (next, crossed) = Milestones::default().detect(stats(4_321, 123_456_789, 7_999.9));
+
+        assert!(crossed.is_empty());
+        assert_eq!(
+            next,
+            Milestones {
+                playcount: Some(4_000),
+                ranked_score: Some(120_000_000),
+                pp: Some(7_500),
+            }
+        );
+    }
+
+    #[test]
+    fn crossing_is_announced_once() {
+        let last = Milestones {
+            playcount: Some(4_000),
+            ranked_score: Some(120_000_000),
+            pp: Some(7_500),
+        };
+
+        let (next, crossed) = last.detect(stats(5_000, 125_000_000, 8_000.2));
+
+        assert_eq!(
+            crossed,
+            [
+                Milestone {
+                    metric: MilestoneMetric::Playcount,
+                    value: 5_000,
+                },
+                Milestone {
+                    metric: MilestoneMetric::Pp,
+                    value: 8_000,
+                },
+            ]
+        );
+
+        // Same stats after e.g. a restart
+        let (again, crossed) = next.detect(stats(5_001, 125_000_000, 8_000.2));
+
+        assert!(crossed.is_empty());
+        assert_eq!(again, next);
+    }
+
+    #[test]
+    fn multiple_steps_announce_highest() {
+        let last = Milestones {
+            playcount: Some(1_000),
+            ranked_score: Some(0),
+            pp: Some(500),
+        };
+
+        let (next, crossed) = last.detect(stats(1_500, 35_000_000, 600.0));
+
+        assert_eq!(
+            crossed,
+            [Milestone {
+                metric: MilestoneMetric::RankedScore,
+                value: 30_000_000,
+            }]
+        );
+        assert_eq!(next.ranked_score, Some(30_000_000));
+    }
+
+    #[test]
+    fn dropping_does_not_reannounce() {
+        let last = Milestones {
+            playcount: Some(1_000),
+            ranked_score: Some(10_000_000),
+            pp: Some(8_000),
+        };
+
+        let (next, crossed) = last.detect(stats(1_000, 10_000_000, 7_990.0));
+        assert!(crossed.is_empty());
+        assert_eq!(next.pp, Some(8_000));
+
+        let (_, crossed) = next.detect(stats(1_000, 10_000_000, 8_010.0));
+        assert!(crossed.is_empty());
+    }
+
+    #[test]
+    fn formatting() {
+        let milestone = Milestone {
+            metric: MilestoneMetric::Playcount,
+            value: 5_000,
+        };
+
+        assert_eq!(
+            milestone.display("badewanne3").to_string(),
+            "🎉 badewanne3 just reached 5,000 plays!"
+        );
+    }
+}
@@ -7,17 +7,28 @@ macro_rules! log {
 
 use std::{collections::HashMap, sync::RwLock};
 
-use bathbot_psql::Database;
+use bathbot_psql::{
+    Database,
+    model::osu::{CountryRecord, DbTrackedOsuUser},
+};
 use bathbot_util::{IntHasher, datetime::NAIVE_DATETIME_FORMAT};
 use eyre::{Result, WrapErr};
 use rosu_v2::{model::GameMode, prelude::Score};
+use tracing::Instrument;
 use twilight_model::id::{Id, marker::ChannelMarker};
 
+pub use self::{
+    digest::{TICK as TRACKING_DIGEST_TICK, tracking_digest_loop},
+    params::TrackEntryParams,
+    stats::OsuTrackingStats,
+};
 use self::{entry::TrackedUser, require_top::RequireTopScores};
-pub use self::{params::TrackEntryParams, stats::OsuTrackingStats};
 use crate::core::Context;
 
+mod country_record;
+mod digest;
 mod entry;
+mod milestones;
 mod params;
 mod process_score;
 mod require_top;
@@ -25,6 +36,7 @@ mod stats;
 
 type TrackedUsers = RwLock<HashMap<u32, TrackedUser, IntHasher>>;
 
+#[derive(Default)]
 pub struct OsuTracking {
     users: TrackedUsers,
 }
@@ -38,17 +50,23 @@ impl OsuTracking {
             .await
             .wrap_err("Failed to fetch tracked users")?;
 
-        let mut users = HashMap::<u32, TrackedUser, IntHasher>::default();
-
-        for user in data {
-            users.entry(user.user_id as u32).or_default().insert(user);
-        }
-
         Ok(Self {
-            users: RwLock::new(users),
+            users: RwLock::new(group_tracked_users(data)),
         })
     }
 
+    /// Replace the tracked users with the ones stored in the database.
+    pub async fn reload(&self, psql: &Database) -> Result<()> {
+        let data = psql
+            .select_tracked_osu_users()
+            .await
+            .wrap_err("Failed to fetch tracked users")?;
+
+        *self.users.write().unwrap() = group_tracked_users(data);
+
+        Ok(())
+    }
+
     pub fn stats() -> OsuTrackingStats {
         OsuTrackingStats::new()
     }
@@ -85,7 +103,9 @@ impl OsuTracking {
             return;
         }
 
-        tokio::spawn(process_score::process_score(score, entry));
+        let span = info_span!("tracking", user_id = score.user_id, score_id = score.id);
+
+        tokio::spawn(process_score::process_score(score, entry).instrument(span));
     }
 
     pub async fn remove_channel(channel: Id<ChannelMarker>, mode: Option<GameMode>) {
@@ -169,4 +189,84 @@ impl OsuTracking {
 
         Ok(entries)
     }
+
+    /// Highest pp play of a country that tracking has seen.
+    pub async fn country_record(
+        country_code: &str,
+        mode: GameMode,
+    ) -> Result<Option<CountryRecord>> {
+        Context::psql()
+            .select_country_record(country_code, mode)
+            .await
+            .wrap_err("Failed to get country record")
+    }
+}
+
+/// Groups tracked rows by user and mode so that each score only needs to be
+/// processed once, regardless of how many channels track the user.
+fn group_tracked_users(data: Vec<DbTrackedOsuUser>) -> HashMap<u32, TrackedUser, IntHasher> {
+    let mut users = HashMap::<u32, TrackedUser, IntHasher>::default();
+
+    for user in data {
+        users.entry(user.user_id as u32).or_default().insert(user);
+    }
+
+    users
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+
+    use time::OffsetDateTime;
+
+    use super::*;
+
+    fn tracked(user_id: i32, mode: GameMode, channel_id: i64, compact: bool) -> DbTrackedOsuUser {
+        DbTrackedOsuUser {
+            user_id,
+            gamemode: mode as i16,
+            channel_id,
+            min_index: None,
+            max_index: None,
+            min_pp: None,
+            max_pp: None,
+            min_combo_percent: None,
+            max_combo_percent: None,
+            compact,
+            last_pp: 0.0,
+            last_updated: OffsetDateTime::UNIX_EPOCH,
+            playcount_milestone: None,
+            ranked_score_milestone: None,
+            pp_milestone: None,
+        }
+    }
+
+    #[test]
+    fn group_channels_per_user_and_mode() {
+        let users = group_tracked_users(vec![
+            tracked(2, GameMode::Osu, 10, false),
+            tracked(2, GameMode::Osu, 11, true),
+            tracked(2, GameMode::Taiko, 10, false),
+            tracked(3, GameMode::Osu, 10, false),
+        ]);
+
+        assert_eq!(users.len(), 2);
+
+        let osu = users[&2].try_get(GameMode::Osu).unwrap();
+        let channels = osu.channels();
+        assert_eq!(channels.len(), 2);
+
+        let compact = channels[&NonZeroU64::new(11).unwrap()].compact();
+        assert!(compact);
+
+        let taiko = users[&2].try_get(GameMode::Taiko).unwrap();
+        assert_eq!(taiko.channels().len(), 1);
+
+        assert!(users[&2].try_get(GameMode::Mania).is_none());
+        assert_eq!(
+            users[&3].try_get(GameMode::Osu).unwrap().channels().len(),
+            1
+        );
+    }
 }
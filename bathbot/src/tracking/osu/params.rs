@@ -11,6 +11,8 @@ pub struct TrackEntryParams {
     pp: Range<f32>,
     /// 0.0..=100.0
     combo_percent: Range<f32>,
+    /// Whether notifications are sent as a single line
+    compact: bool,
 }
 
 impl TrackEntryParams {
@@ -32,6 +34,7 @@ impl TrackEntryParams {
                 Self::DEFAULT_MIN_COMBO_PERCENT,
                 Self::DEFAULT_MAX_COMBO_PERCENT,
             ),
+            compact: false,
         }
     }
 
@@ -61,6 +64,10 @@ impl TrackEntryParams {
         }
     }
 
+    pub const fn with_compact(self, compact: bool) -> Self {
+        Self { compact, ..self }
+    }
+
     pub const fn index(&self) -> Range<u8> {
         self.index
     }
@@ -73,8 +80,22 @@ impl TrackEntryParams {
         self.combo_percent
     }
 
-    pub const fn matches(&self, idx: u8, pp: f32, combo_percent: Option<f32>) -> bool {
-        self.index.contains(idx)
+    pub const fn compact(&self) -> bool {
+        self.compact
+    }
+
+    /// `idx` is `None` for scores outside of the top scores, e.g. for
+    /// milestone notifications, which only match if the index is not limited.
+    pub const fn matches(&self, idx: Option<u8>, pp: f32, combo_percent: Option<f32>) -> bool {
+        let index_matches = match idx {
+            Some(idx) => self.index.contains(idx),
+            None => {
+                self.index.start == Self::DEFAULT_MIN_INDEX
+                    && self.index.end == Self::DEFAULT_MAX_INDEX
+            }
+        };
+
+        index_matches
             && self.pp.contains(pp)
             && match combo_percent {
                 // Manual `Option::is_some_and` to preserve const-ness
@@ -97,6 +118,7 @@ impl TrackEntryParams {
             max_pp: Some(self.pp.end),
             min_combo_percent: Some(self.combo_percent.start),
             max_combo_percent: Some(self.combo_percent.end),
+            compact: self.compact,
         }
     }
 }
@@ -114,6 +136,7 @@ impl From<DbTrackedOsuUserInChannel> for TrackEntryParams {
             .with_index(map_as_u8(entry.min_index), map_as_u8(entry.max_index))
             .with_pp(entry.min_pp, entry.max_pp)
             .with_combo_percent(entry.min_combo_percent, entry.max_combo_percent)
+            .with_compact(entry.compact)
     }
 }
 
@@ -1,33 +1,52 @@
-use std::{slice, sync::Arc, time::Duration};
+use std::{fmt::Write, num::NonZeroU64, slice, sync::Arc, time::Duration};
 
-use bathbot_model::embed_builder::{
-    ComboValue, HitresultsValue, ScoreEmbedSettings, SettingValue, SettingsButtons, SettingsImage,
-    Value,
+use bathbot_model::{
+    Countries,
+    embed_builder::{
+        ComboValue, HitresultsValue, ScoreEmbedSettings, SettingValue, SettingsButtons,
+        SettingsImage, Value,
+    },
 };
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{EmbedBuilder, constants::UNKNOWN_CHANNEL};
+use bathbot_util::{
+    EmbedBuilder, ModsFormatter,
+    constants::{OSU_BASE, UNKNOWN_CHANNEL},
+    datetime::HowLongAgoDynamic,
+};
+use futures::future::join_all;
 use rand::Rng;
 use rosu_v2::{model::GameMode, prelude::Score};
 use twilight_http::{
     api_error::{ApiError, GeneralApiError},
     error::ErrorType as TwilightErrorType,
 };
-use twilight_model::id::Id;
+use twilight_model::{
+    channel::message::Embed,
+    id::{Id, marker::ChannelMarker},
+};
 
-use super::{OsuTracking, entry::TrackEntry};
+use super::{
+    OsuTracking, TrackEntryParams, country_record::check_country_record, digest::push_digest,
+    entry::TrackEntry, milestones::MilestoneStats,
+};
 use crate::{
     active::{
         IActiveMessage,
-        impls::{SingleScoreContent, SingleScorePagination},
+        impls::{MapFormat, SingleScoreContent, SingleScorePagination},
     },
-    commands::utility::ScoreEmbedDataWrap,
+    commands::utility::{ScoreEmbedData, ScoreEmbedDataWrap},
     core::{BotMetrics, Context},
     manager::{
         OsuMap,
         redis::osu::{CachedUser, UserArgs, UserArgsSlim},
     },
+    util::{CachedUserExt, osu::GradeFormatter},
 };
 
+/// Amount of osu!api requests for a tracked score, namely the user and their
+/// top scores.
+const FETCHES_PER_SCORE: u64 = 2;
+
 pub async fn process_score(score: Score, entry: Arc<TrackEntry>) {
     let Some(pp) = score.pp else { return };
 
@@ -79,9 +98,35 @@ pub async fn process_score(score: Score, entry: Arc<TrackEntry>) {
         }
     };
 
+    // Entries are grouped per user and mode so all subscribed channels share
+    // the fetches instead of requesting the same data for each channel.
+    let subscribed = entry.channels().len() as u64;
+    BotMetrics::inc_osu_tracking_fetches_saved(
+        mode,
+        subscribed.saturating_sub(1) * FETCHES_PER_SCORE,
+    );
+
     entry.insert_last_pp(user_id, mode, &tops).await;
 
+    let milestones = milestones_content(&entry, &user, mode).await;
+
     let Some(idx) = tops.iter().position(|s| s.id == score_id) else {
+        if let Some(content) = milestones {
+            let max_combo = score.map.as_ref().and_then(|map| map.max_combo);
+            let combo_percent = max_combo.map(|max| 100.0 * score.max_combo as f32 / max as f32);
+            let recipients = Recipients::new(entry.channels().iter(), None, pp, combo_percent);
+
+            let embed = EmbedBuilder::new()
+                .author(user.author_builder(false))
+                .description(content)
+                .build();
+
+            // The milestone embed is small enough for compact channels too
+            let mut channels = recipients.full;
+            channels.extend(recipients.compact);
+            send_notifs(channels, slice::from_ref(&embed), None).await;
+        }
+
         log!(info:
             user = user_id,
             map = map_id,
@@ -95,11 +140,35 @@ pub async fn process_score(score: Score, entry: Arc<TrackEntry>) {
 
     BotMetrics::osu_tracking_hit(score.mode);
 
+    // Only a personal best can be a country's best play
+    let country_record = idx == 0 && check_country_record(&user, &score, &tops).await;
+
+    let content = match (country_record, milestones) {
+        (true, milestones) => {
+            let country_code = user.country_code.as_str();
+
+            let mut content = match Countries::code(country_code).to_name() {
+                Some(name) => format!("🏆 New top play for {name}!"),
+                None => format!("🏆 New top play for {country_code}!"),
+            };
+
+            if let Some(milestones) = milestones {
+                content.push('\n');
+                content.push_str(&milestones);
+            }
+
+            Some(content)
+        }
+        (false, milestones) => milestones,
+    };
+
     let combo = score.max_combo;
-    let (builder, max_combo) = embed_builder(&user, score, map, idx).await;
+    let TrackNotif {
+        full: builder,
+        compact: compact_line,
+        max_combo,
+    } = embed_builder(&user, score, map, idx).await;
     let idx = idx as u8 + 1;
-    let embed = builder.build();
-    let embeds = slice::from_ref(&embed);
     let combo_percent = max_combo.map(|max| 100.0 * combo as f32 / max as f32);
 
     log!(info:
@@ -112,43 +181,140 @@ pub async fn process_score(score: Score, entry: Arc<TrackEntry>) {
         "New top score",
     );
 
-    let http = Context::http();
+    let recipients = Recipients::new(entry.channels().iter(), Some(idx), pp, combo_percent);
 
-    let channels: Vec<_> = entry
-        .channels()
-        .iter()
-        .filter_map(|(channel_id, params)| {
-            params
-                .matches(idx, pp, combo_percent)
-                .then_some(*channel_id)
-        })
-        .collect();
+    if recipients.is_empty() {
+        return;
+    }
 
-    for channel_id in channels {
-        let channel = Id::new(channel_id.get());
+    let embed = builder.build();
 
-        let Err(err) = http.create_message(channel).embeds(embeds).await else {
-            continue;
-        };
+    let compact_embed = EmbedBuilder::new()
+        .author(user.author_builder(false))
+        .description(&compact_line)
+        .build();
 
-        let TwilightErrorType::Response { error, .. } = err.kind() else {
-            log!(warn: %channel, ?err, "Error while sending notif");
+    let content = content.as_deref();
 
-            continue;
-        };
+    tokio::join!(
+        send_notifs(recipients.full, slice::from_ref(&embed), content),
+        send_notifs(recipients.compact, slice::from_ref(&compact_embed), content),
+    );
 
-        let ApiError::General(GeneralApiError {
-            code: UNKNOWN_CHANNEL,
-            ..
-        }) = error
-        else {
-            log!(warn: %channel, ?error, "Error from API while sending notif");
+    push_digest(user_id, &compact_line).await;
+}
 
-            continue;
-        };
+/// Channels that should be notified about a score, split by their preferred
+/// notification format.
+#[derive(Debug, Default, PartialEq)]
+struct Recipients {
+    full: Vec<NonZeroU64>,
+    compact: Vec<NonZeroU64>,
+}
+
+impl Recipients {
+    /// Applies each channel's [`TrackEntryParams`] to the score.
+    fn new<'a, I>(channels: I, idx: Option<u8>, pp: f32, combo_percent: Option<f32>) -> Self
+    where
+        I: IntoIterator<Item = (&'a NonZeroU64, &'a TrackEntryParams)>,
+    {
+        let mut recipients = Self::default();
+
+        for (channel_id, params) in channels {
+            if !params.matches(idx, pp, combo_percent) {
+                continue;
+            }
+
+            if params.compact() {
+                recipients.compact.push(*channel_id);
+            } else {
+                recipients.full.push(*channel_id);
+            }
+        }
+
+        recipients
+    }
+
+    fn is_empty(&self) -> bool {
+        self.full.is_empty() && self.compact.is_empty()
+    }
+}
+
+/// Checks the user's stats for newly crossed milestones and returns the
+/// announcement lines, if any.
+async fn milestones_content(
+    entry: &TrackEntry,
+    user: &CachedUser,
+    mode: GameMode,
+) -> Option<String> {
+    let stats = user.statistics.as_ref()?;
+
+    let stats = MilestoneStats {
+        playcount: stats.playcount.to_native(),
+        ranked_score: stats.ranked_score.to_native(),
+        pp: stats.pp.to_native(),
+    };
+
+    let crossed = entry
+        .check_milestones(user.user_id.to_native(), mode, stats)
+        .await;
+
+    if crossed.is_empty() {
+        return None;
+    }
+
+    let username = user.username.as_str();
+    let mut content = String::new();
+
+    for milestone in crossed {
+        if !content.is_empty() {
+            content.push('\n');
+        }
+
+        let _ = write!(content, "{}", milestone.display(username));
+    }
+
+    Some(content)
+}
 
-        OsuTracking::remove_channel(channel, None).await;
+/// Sends the notification to all channels concurrently so that a failing or
+/// slow channel does not hold up the others.
+async fn send_notifs(channels: Vec<NonZeroU64>, embeds: &[Embed], content: Option<&str>) {
+    let sends = channels
+        .into_iter()
+        .map(|channel_id| send_notif(Id::new(channel_id.get()), embeds, content));
+
+    join_all(sends).await;
+}
+
+async fn send_notif(channel: Id<ChannelMarker>, embeds: &[Embed], content: Option<&str>) {
+    let mut req = Context::http().create_message(channel).embeds(embeds);
+
+    if let Some(content) = content {
+        req = req.content(content);
     }
+
+    let Err(err) = req.await else {
+        return;
+    };
+
+    let TwilightErrorType::Response { error, .. } = err.kind() else {
+        log!(warn: %channel, ?err, "Error while sending notif");
+
+        return;
+    };
+
+    let ApiError::General(GeneralApiError {
+        code: UNKNOWN_CHANNEL,
+        ..
+    }) = error
+    else {
+        log!(warn: %channel, ?error, "Error from API while sending notif");
+
+        return;
+    };
+
+    OsuTracking::remove_channel(channel, None).await;
 }
 
 /// Random [`Duration`] between 30s and 60s
@@ -156,12 +322,15 @@ fn jitter() -> Duration {
     rand::thread_rng().gen_range(Duration::from_secs(30)..Duration::from_secs(60))
 }
 
-async fn embed_builder(
-    user: &CachedUser,
-    score: Score,
-    map: OsuMap,
-    idx: usize,
-) -> (EmbedBuilder, Option<u32>) {
+/// A top score notification in both its full and compact format.
+struct TrackNotif {
+    full: EmbedBuilder,
+    /// Single-line variant for compact channels and the DM digest.
+    compact: String,
+    max_combo: Option<u32>,
+}
+
+async fn embed_builder(user: &CachedUser, score: Score, map: OsuMap, idx: usize) -> TrackNotif {
     let settings = match score.mode {
         GameMode::Mania => create_mania_settings(),
         _ => create_settings(),
@@ -177,20 +346,54 @@ async fn embed_builder(
     // creates *full* data but let's map regardless to be extra sure.
     let max_combo = embed_data.try_get().map(|data| data.max_combo);
 
+    let compact = embed_data
+        .try_get()
+        .map(|data| compact_line(data, idx))
+        .unwrap_or_default();
+
     let entries = Box::<[_]>::from([embed_data]);
 
+    // Notifications are seen by the whole channel so there is no single
+    // user config to consult; the default timestamp style is used instead,
+    // as documented in `TIMESTAMP_STYLE_HELP`.
     let mut pagination =
         SingleScorePagination::new(user, entries, settings, score_data, msg_owner, content);
 
-    match pagination.build_page().await {
-        Ok(data) => (data.into_embed(), max_combo),
+    let full = match pagination.build_page().await {
+        Ok(data) => data.into_embed(),
         // Unreachable because `async_build_page` can only fail while
         // converting to full score data but it already starts off as
         // full.
         Err(_) => Default::default(),
+    };
+
+    TrackNotif {
+        full,
+        compact,
+        max_combo,
     }
 }
 
+/// Formats the same data as the full notification into a single line.
+fn compact_line(data: &ScoreEmbedData, idx: usize) -> String {
+    let ScoreEmbedData {
+        score, map, stars, ..
+    } = data;
+
+    format!(
+        "**#{idx}** {grade} [{map}]({OSU_BASE}b/{map_id}) [{stars:.2}★] \
+        **+{mods}** • {pp:.2}pp • {acc:.2}% {ago}",
+        idx = idx + 1,
+        grade = GradeFormatter::new(score.grade, Some(score.score_id), score.is_legacy),
+        map = MapFormat::from(map),
+        map_id = map.map_id(),
+        mods = ModsFormatter::new(&score.mods, score.is_legacy),
+        pp = score.pp,
+        acc = score.accuracy,
+        ago = HowLongAgoDynamic::new(&score.ended_at),
+    )
+}
+
 fn create_settings() -> ScoreEmbedSettings {
     ScoreEmbedSettings {
         values: vec![
@@ -340,3 +543,92 @@ fn create_mania_settings() -> ScoreEmbedSettings {
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channel(id: u64) -> NonZeroU64 {
+        NonZeroU64::new(id).unwrap()
+    }
+
+    #[test]
+    fn split_by_format() {
+        let full = TrackEntryParams::new();
+        let compact = TrackEntryParams::new().with_compact(true);
+        let channels = [
+            (channel(1), full),
+            (channel(2), compact),
+            (channel(3), full),
+        ];
+
+        let recipients = Recipients::new(
+            channels.iter().map(|(id, params)| (id, params)),
+            Some(5),
+            300.0,
+            Some(100.0),
+        );
+
+        let expected = Recipients {
+            full: vec![channel(1), channel(3)],
+            compact: vec![channel(2)],
+        };
+
+        assert_eq!(recipients, expected);
+    }
+
+    #[test]
+    fn apply_params_per_channel() {
+        let min_pp = TrackEntryParams::new().with_pp(Some(400.0), None);
+        let top10 = TrackEntryParams::new()
+            .with_index(None, Some(10))
+            .with_compact(true);
+        let channels = [(channel(1), min_pp), (channel(2), top10)];
+
+        let recipients = Recipients::new(
+            channels.iter().map(|(id, params)| (id, params)),
+            Some(5),
+            300.0,
+            Some(100.0),
+        );
+
+        assert!(recipients.full.is_empty());
+        assert_eq!(recipients.compact, [channel(2)]);
+
+        let recipients = Recipients::new(
+            channels.iter().map(|(id, params)| (id, params)),
+            Some(50),
+            300.0,
+            Some(100.0),
+        );
+
+        assert!(recipients.is_empty());
+    }
+
+    #[test]
+    fn milestones_outside_top_scores() {
+        let min_pp = TrackEntryParams::new().with_pp(Some(400.0), None);
+        let top10 = TrackEntryParams::new().with_index(None, Some(10));
+        let compact = TrackEntryParams::new().with_compact(true);
+        let channels = [
+            (channel(1), min_pp),
+            (channel(2), top10),
+            (channel(3), compact),
+            (channel(4), TrackEntryParams::new()),
+        ];
+
+        let recipients = Recipients::new(
+            channels.iter().map(|(id, params)| (id, params)),
+            None,
+            300.0,
+            Some(100.0),
+        );
+
+        let expected = Recipients {
+            full: vec![channel(4)],
+            compact: vec![channel(3)],
+        };
+
+        assert_eq!(recipients, expected);
+    }
+}
@@ -0,0 +1,293 @@
+use std::{slice, time::Duration};
+
+use bathbot_util::{
+    EmbedBuilder,
+    constants::{MISSING_ACCESS, UNKNOWN_CHANNEL},
+};
+use eyre::{Result, WrapErr};
+use time::{Date, Month, OffsetDateTime, Time};
+use twilight_http::{
+    api_error::{ApiError, GeneralApiError},
+    error::ErrorType,
+};
+use twilight_model::{
+    channel::message::Embed,
+    id::{Id, marker::ChannelMarker},
+};
+
+use crate::{
+    core::{BackgroundLoop, BotConfig, Context},
+    tracking::OsuTracking,
+};
+
+/// User configs without any command usage for this long are pruning
+/// candidates.
+pub const INACTIVITY_CUTOFF: time::Duration = time::Duration::days(548);
+
+/// Longest time between two reports.
+pub const TICK: Duration = Duration::from_secs(31 * 24 * 60 * 60);
+
+/// Sends the owner a report of prunable data on the first of each month.
+#[cold]
+pub async fn prune_report_loop() {
+    loop {
+        tokio::time::sleep(until_next_report(OffsetDateTime::now_utc())).await;
+        Context::watchdog().beat(BackgroundLoop::PruneReport);
+
+        let embed = match create_prune_report().await {
+            Ok(Some((report_id, summary))) => summary.embed(report_id).build(),
+            Ok(None) => {
+                info!("Nothing to prune");
+
+                continue;
+            }
+            Err(err) => {
+                error!(?err, "Failed to create prune report");
+
+                continue;
+            }
+        };
+
+        if let Err(err) = send_to_owner(&embed).await {
+            warn!(?err, "Failed to send prune report to owner");
+        }
+    }
+}
+
+/// Time until midnight (UTC) of the next first day of a month.
+fn until_next_report(now: OffsetDateTime) -> Duration {
+    let date = now.date();
+
+    let (year, month) = match date.month() {
+        Month::December => (date.year() + 1, Month::January),
+        month => (date.year(), month.next()),
+    };
+
+    let next = Date::from_calendar_date(year, month, 1)
+        .map_or(now, |date| date.with_time(Time::MIDNIGHT).assume_utc());
+
+    (next - now).try_into().unwrap_or(Duration::ZERO)
+}
+
+/// Amounts of candidates in a prune report.
+pub struct PruneSummary {
+    pub inactive_configs: usize,
+    pub inactive_links: usize,
+    pub unreachable_channels: usize,
+}
+
+impl PruneSummary {
+    pub fn embed(&self, report_id: i32) -> EmbedBuilder {
+        let description = format!(
+            "User configs unused for {days} days: {configs} ({links} linked to osu!)\n\
+            Tracking channels the bot can no longer see: {channels}\n\n\
+            Nothing has been deleted yet. Confirm with \
+            `/owner prune confirm report:{report_id}`",
+            days = INACTIVITY_CUTOFF.whole_days(),
+            configs = self.inactive_configs,
+            links = self.inactive_links,
+            channels = self.unreachable_channels,
+        );
+
+        EmbedBuilder::new()
+            .title(format!("Prune report #{report_id}"))
+            .description(description)
+    }
+}
+
+/// Store the current pruning candidates as a report.
+///
+/// Returns `None` if there is nothing to prune.
+pub async fn create_prune_report() -> Result<Option<(i32, PruneSummary)>> {
+    let psql = Context::psql();
+    let cutoff = OffsetDateTime::now_utc() - INACTIVITY_CUTOFF;
+
+    let configs = psql
+        .select_inactive_user_configs(cutoff)
+        .await
+        .wrap_err("Failed to get inactive user configs")?;
+
+    let channels = psql
+        .select_tracked_channels()
+        .await
+        .wrap_err("Failed to get tracked channels")?;
+
+    let mut unreachable = Vec::new();
+
+    for channel in channels {
+        match is_unreachable(channel).await {
+            Ok(true) => unreachable.push(channel),
+            Ok(false) => {}
+            Err(err) => warn!(%channel, ?err, "Failed to check channel access"),
+        }
+    }
+
+    if configs.is_empty() && unreachable.is_empty() {
+        return Ok(None);
+    }
+
+    let discord_ids: Vec<_> = configs.iter().map(|config| config.discord_id).collect();
+
+    let report_id = psql
+        .insert_prune_report(&discord_ids, &unreachable)
+        .await
+        .wrap_err("Failed to insert prune report")?;
+
+    let summary = PruneSummary {
+        inactive_configs: configs.len(),
+        inactive_links: configs
+            .iter()
+            .filter(|config| config.osu_id.is_some())
+            .count(),
+        unreachable_channels: unreachable.len(),
+    };
+
+    Ok(Some((report_id, summary)))
+}
+
+/// Amounts of pruned entries.
+pub struct PruneOutcome {
+    pub deleted_configs: u64,
+    pub skipped_configs: usize,
+    pub untracked_channels: usize,
+    pub skipped_channels: usize,
+}
+
+/// Delete the candidates of a report.
+///
+/// Users that were seen since the report was created and channels that are
+/// reachable again are skipped. Returns `None` if the report does not exist or
+/// was applied already.
+pub async fn apply_prune_report(report_id: i32) -> Result<Option<PruneOutcome>> {
+    let report = Context::psql()
+        .select_pending_prune_report(report_id)
+        .await
+        .wrap_err("Failed to get prune report")?;
+
+    let Some(report) = report else {
+        return Ok(None);
+    };
+
+    // Access may have been granted again since the report was created
+    let mut unreachable = Vec::with_capacity(report.channel_ids.len());
+
+    for &channel in report.channel_ids.iter() {
+        match is_unreachable(channel).await {
+            Ok(true) => unreachable.push(channel),
+            Ok(false) => {}
+            Err(err) => warn!(%channel, ?err, "Failed to check channel access"),
+        }
+    }
+
+    let cutoff = OffsetDateTime::now_utc() - INACTIVITY_CUTOFF;
+
+    let deleted_configs = Context::user_config()
+        .delete_inactive(&report, cutoff)
+        .await?;
+
+    // Applied concurrently in the meantime
+    let Some(deleted_configs) = deleted_configs else {
+        return Ok(None);
+    };
+
+    for &channel in unreachable.iter() {
+        OsuTracking::remove_channel(channel, None).await;
+
+        #[cfg(feature = "twitch")]
+        if let Err(err) = Context::twitch().untrack_all(channel).await {
+            warn!(%channel, ?err, "Failed to remove stream tracks of pruned channel");
+        }
+
+        #[cfg(feature = "twitchtracking")]
+        for twitch_id in Context::tracked_users_in(channel) {
+            Context::remove_tracking(twitch_id, channel.get());
+        }
+    }
+
+    Ok(Some(PruneOutcome {
+        deleted_configs,
+        skipped_configs: report
+            .discord_ids
+            .len()
+            .saturating_sub(deleted_configs as usize),
+        untracked_channels: unreachable.len(),
+        skipped_channels: report.channel_ids.len() - unreachable.len(),
+    }))
+}
+
+/// Whether the bot lost access to the channel.
+async fn is_unreachable(channel: Id<ChannelMarker>) -> Result<bool> {
+    let Err(err) = Context::http().channel(channel).await else {
+        return Ok(false);
+    };
+
+    match err.kind() {
+        ErrorType::Response {
+            error:
+                ApiError::General(GeneralApiError {
+                    code: UNKNOWN_CHANNEL | MISSING_ACCESS,
+                    ..
+                }),
+            ..
+        } => Ok(true),
+        _ => Err(err.into()),
+    }
+}
+
+pub(super) async fn send_to_owner(embed: &Embed) -> Result<()> {
+    let channel = Context::http()
+        .create_private_channel(BotConfig::get().owner)
+        .await
+        .wrap_err("Failed to create DM channel")?
+        .model()
+        .await
+        .wrap_err("Failed to deserialize DM channel")?;
+
+    Context::http()
+        .create_message(channel.id)
+        .embeds(slice::from_ref(embed))
+        .await
+        .wrap_err("Failed to send DM")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime(year: i32, month: Month, day: u8, hour: u8) -> OffsetDateTime {
+        Date::from_calendar_date(year, month, day)
+            .unwrap()
+            .with_hms(hour, 0, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    #[test]
+    fn next_report_is_first_of_next_month() {
+        let now = datetime(2026, Month::October, 16, 12);
+
+        assert_eq!(
+            until_next_report(now),
+            Duration::from_secs((15 * 24 + 12) * 60 * 60)
+        );
+    }
+
+    #[test]
+    fn next_report_wraps_year() {
+        let now = datetime(2026, Month::December, 31, 23);
+
+        assert_eq!(until_next_report(now), Duration::from_secs(60 * 60));
+    }
+
+    #[test]
+    fn first_of_month_waits_full_month() {
+        let now = datetime(2026, Month::February, 1, 0);
+
+        assert_eq!(
+            until_next_report(now),
+            Duration::from_secs(28 * 24 * 60 * 60)
+        );
+    }
+}
@@ -0,0 +1,272 @@
+use std::time::Duration;
+
+use metrics::Key;
+use rosu_v2::prelude::OsuError;
+use time::{Date, OffsetDateTime};
+use tokio::time::interval;
+
+use crate::core::{BackgroundLoop, BotConfig, BotMetrics, Context};
+
+/// How often the refresher wakes up to check for pressure and refresh a batch.
+pub const TICK: Duration = Duration::from_secs(60);
+
+/// Maximum amount of users to refresh per tick.
+const BATCH_SIZE: u32 = 20;
+
+/// Amount of osu!api requests per tick that were *not* issued by the refresher
+/// above which the refresher pauses so that interactive commands are not
+/// slowed down.
+const PRESSURE_THRESHOLD: usize = 60;
+
+/// Periodically refreshes the stored stats of linked users in
+/// least-recently-refreshed order so that server leaderboards don't go stale.
+#[cold]
+pub async fn stats_refresh_loop() {
+    let limit = BotConfig::get().stats_refresh_budget;
+
+    if limit == 0 {
+        info!("Stats refresh budget is zero, not refreshing stats");
+
+        return;
+    }
+
+    let today = OffsetDateTime::now_utc().date();
+    let mut budget = DailyBudget::new(limit, today, fetch_spent(today).await);
+    let mut pressure = ApiPressure::new(PRESSURE_THRESHOLD, osu_requests());
+
+    let mut interval = interval(TICK);
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+        Context::watchdog().beat(BackgroundLoop::StatsRefresh);
+
+        let paused = pressure.observe(osu_requests());
+        BotMetrics::set_stats_refresh_paused(paused);
+
+        if paused {
+            continue;
+        }
+
+        let today = OffsetDateTime::now_utc().date();
+
+        if budget.roll_over(today) {
+            budget.set_spent(fetch_spent(today).await);
+        }
+
+        let amount = budget.remaining().min(BATCH_SIZE);
+
+        if amount == 0 {
+            continue;
+        }
+
+        let users = match Context::psql()
+            .select_stalest_linked_osu_users(amount as usize)
+            .await
+        {
+            Ok(users) => users,
+            Err(err) => {
+                warn!(?err, "Failed to get stale users");
+
+                continue;
+            }
+        };
+
+        let now = OffsetDateTime::now_utc();
+        let mut spent = 0;
+
+        for user in users {
+            let staleness = (now - user.last_update).whole_seconds().max(0) as f64;
+            BotMetrics::observe_stats_refresh_staleness(staleness);
+
+            spent += 1;
+
+            match Context::osu().user(user.user_id).mode(user.mode).await {
+                Ok(osu_user) => {
+                    Context::osu_user().store(&osu_user, user.mode).await;
+                    BotMetrics::inc_stats_refresh("updated");
+                }
+                Err(OsuError::NotFound) => {
+                    // Remove stats of unknown/restricted users so they don't
+                    // appear in the leaderboard
+                    let remove_fut = Context::osu_user().remove_stats_and_scores(user.user_id);
+
+                    if let Err(err) = remove_fut.await {
+                        warn!(?err, "Failed to remove stats of unknown user");
+                    }
+
+                    BotMetrics::inc_stats_refresh("not_found");
+                }
+                Err(err) => {
+                    warn!(?err, user_id = user.user_id, "Failed to refresh user stats");
+                    BotMetrics::inc_stats_refresh("failed");
+                }
+            }
+        }
+
+        pressure.add_own(spent as usize);
+        budget.spend(spent);
+
+        if let Err(err) = Context::cache()
+            .add_stats_refresh_spent(budget.day(), spent)
+            .await
+        {
+            warn!(?err, "Failed to store spent stats refresh requests");
+        }
+    }
+}
+
+/// Total amount of osu!api requests since startup.
+fn osu_requests() -> usize {
+    let key = Key::from_static_name("bathbot.osu_response_time");
+
+    Context::get().metrics.sum_histograms(&key)
+}
+
+async fn fetch_spent(today: Date) -> u32 {
+    match Context::cache()
+        .stats_refresh_spent(today.to_julian_day())
+        .await
+    {
+        Ok(spent) => spent,
+        Err(err) => {
+            warn!(?err, "Failed to get spent stats refresh requests");
+
+            0
+        }
+    }
+}
+
+/// Keeps track of how many requests were spent on the current day.
+struct DailyBudget {
+    limit: u32,
+    spent: u32,
+    day: Date,
+}
+
+impl DailyBudget {
+    fn new(limit: u32, day: Date, spent: u32) -> Self {
+        Self { limit, spent, day }
+    }
+
+    /// Julian day of the budget's current day.
+    fn day(&self) -> i32 {
+        self.day.to_julian_day()
+    }
+
+    fn remaining(&self) -> u32 {
+        self.limit.saturating_sub(self.spent)
+    }
+
+    fn spend(&mut self, amount: u32) {
+        self.spent = self.spent.saturating_add(amount);
+    }
+
+    fn set_spent(&mut self, spent: u32) {
+        self.spent = spent;
+    }
+
+    /// Reset the spent amount if the day changed.
+    ///
+    /// Returns whether the day changed.
+    fn roll_over(&mut self, today: Date) -> bool {
+        if today == self.day {
+            return false;
+        }
+
+        self.day = today;
+        self.spent = 0;
+
+        true
+    }
+}
+
+/// Detects whether interactive commands currently issue many osu!api requests
+/// by comparing the total request count between observations while ignoring
+/// requests of the refresher itself.
+struct ApiPressure {
+    threshold: usize,
+    last_total: usize,
+    own: usize,
+}
+
+impl ApiPressure {
+    fn new(threshold: usize, total: usize) -> Self {
+        Self {
+            threshold,
+            last_total: total,
+            own: 0,
+        }
+    }
+
+    /// Register requests issued by the refresher since the last observation.
+    fn add_own(&mut self, amount: usize) {
+        self.own += amount;
+    }
+
+    /// Observe the current total request count and return whether the
+    /// refresher should pause.
+    fn observe(&mut self, total: usize) -> bool {
+        let foreign = total
+            .saturating_sub(self.last_total)
+            .saturating_sub(self.own);
+
+        self.last_total = total;
+        self.own = 0;
+
+        foreign > self.threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    fn day(day: u8) -> Date {
+        Date::from_calendar_date(2024, Month::May, day).unwrap()
+    }
+
+    #[test]
+    fn budget_spends_until_limit() {
+        let mut budget = DailyBudget::new(50, day(1), 30);
+        assert_eq!(budget.remaining(), 20);
+
+        budget.spend(15);
+        assert_eq!(budget.remaining(), 5);
+
+        budget.spend(15);
+        assert_eq!(budget.remaining(), 0);
+    }
+
+    #[test]
+    fn budget_resets_on_new_day() {
+        let mut budget = DailyBudget::new(50, day(1), 50);
+        assert!(!budget.roll_over(day(1)));
+        assert_eq!(budget.remaining(), 0);
+
+        assert!(budget.roll_over(day(2)));
+        assert_eq!(budget.remaining(), 50);
+        assert_eq!(budget.day(), day(2).to_julian_day());
+    }
+
+    #[test]
+    fn pressure_ignores_own_requests() {
+        let mut pressure = ApiPressure::new(10, 100);
+
+        pressure.add_own(50);
+        assert!(!pressure.observe(155));
+
+        assert!(pressure.observe(166));
+    }
+
+    #[test]
+    fn pressure_resumes_when_idle() {
+        let mut pressure = ApiPressure::new(10, 0);
+
+        assert!(pressure.observe(100));
+        assert!(!pressure.observe(105));
+        assert!(!pressure.observe(105));
+    }
+}
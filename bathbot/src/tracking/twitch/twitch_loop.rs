@@ -17,12 +17,15 @@ use twilight_http::{
 };
 use twilight_model::id::{Id, marker::ChannelMarker};
 
-use crate::Context;
+use crate::core::{BackgroundLoop, Context};
+
+/// How often the tracked streams are checked.
+pub const TICK: Duration = Duration::from_secs(10 * 60);
 
 #[cold]
 pub async fn twitch_tracking_loop() {
     let mut online_streams = HashSet::with_hasher(IntHasher);
-    let mut interval = interval(Duration::from_secs(10 * 60));
+    let mut interval = interval(TICK);
     interval.tick().await;
 
     let client = Context::client();
@@ -30,6 +33,7 @@ pub async fn twitch_tracking_loop() {
 
     loop {
         interval.tick().await;
+        Context::watchdog().beat(BackgroundLoop::TwitchTracking);
 
         // Get data about what needs to be tracked for which channel
         let user_ids = Context::tracked_users();
@@ -0,0 +1,153 @@
+/// Characters of the sparkline from lowest to highest density.
+const LEVELS: [char; 5] = ['▁', '▂', '▃', '▅', '▇'];
+
+/// Maximum amount of characters in a sparkline.
+const MAX_LEN: usize = 40;
+
+/// Object density of a map as a sparkline of object counts per window.
+///
+/// Timestamps are in milliseconds of map time and the window size is in
+/// milliseconds of real time i.e. the clock rate scales the windows.
+/// Returns `None` if there are no timestamps or the arguments are invalid.
+pub fn density_sparkline(
+    timestamps: impl IntoIterator<Item = f64>,
+    window_ms: f64,
+    clock_rate: f64,
+) -> Option<String> {
+    let buckets = density_buckets(timestamps, window_ms, clock_rate);
+
+    (!buckets.is_empty()).then(|| sparkline(&buckets))
+}
+
+/// Count objects per window, starting at the beginning of the map.
+fn density_buckets(
+    timestamps: impl IntoIterator<Item = f64>,
+    window_ms: f64,
+    clock_rate: f64,
+) -> Vec<usize> {
+    let window = window_ms * clock_rate;
+
+    if !window.is_finite() || window <= 0.0 {
+        return Vec::new();
+    }
+
+    let mut buckets = Vec::new();
+
+    for timestamp in timestamps {
+        if !timestamp.is_finite() {
+            continue;
+        }
+
+        let idx = (timestamp.max(0.0) / window) as usize;
+
+        if idx >= buckets.len() {
+            buckets.resize(idx + 1, 0);
+        }
+
+        buckets[idx] += 1;
+    }
+
+    buckets
+}
+
+/// Render counts as sparkline, downsampled to at most 40 characters.
+fn sparkline(buckets: &[usize]) -> String {
+    let values: Vec<f64> = if buckets.len() <= MAX_LEN {
+        buckets.iter().map(|&count| count as f64).collect()
+    } else {
+        // Average each chunk so that unevenly sized chunks stay comparable
+        (0..MAX_LEN)
+            .map(|i| {
+                let start = i * buckets.len() / MAX_LEN;
+                let end = (i + 1) * buckets.len() / MAX_LEN;
+                let chunk = &buckets[start..end];
+
+                chunk.iter().sum::<usize>() as f64 / chunk.len() as f64
+            })
+            .collect()
+    };
+
+    let max = values.iter().copied().fold(0.0, f64::max);
+
+    values
+        .into_iter()
+        .map(|value| {
+            if max <= 0.0 {
+                return LEVELS[0];
+            }
+
+            let level = (value / max * (LEVELS.len() - 1) as f64).round() as usize;
+
+            LEVELS[level.min(LEVELS.len() - 1)]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rosu_pp::Beatmap;
+
+    use super::*;
+
+    const WINDOW: f64 = 10_000.0;
+
+    fn fixture_timestamps() -> Vec<f64> {
+        let bytes = include_bytes!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/density.osu"
+        ));
+
+        let map = Beatmap::from_bytes(bytes).unwrap();
+
+        map.hit_objects.iter().map(|h| h.start_time).collect()
+    }
+
+    #[test]
+    fn fixture_buckets() {
+        let timestamps = fixture_timestamps();
+
+        assert_eq!(
+            density_buckets(timestamps.iter().copied(), WINDOW, 1.0),
+            [8, 2, 0, 4, 16]
+        );
+        assert_eq!(
+            density_sparkline(timestamps.iter().copied(), WINDOW, 1.0).as_deref(),
+            Some("▃▂▁▂▇")
+        );
+    }
+
+    #[test]
+    fn clock_rate_rescales_windows() {
+        let timestamps = fixture_timestamps();
+
+        assert_eq!(
+            density_buckets(timestamps.iter().copied(), WINDOW, 1.5),
+            [10, 0, 14, 6]
+        );
+        assert_eq!(
+            density_buckets(timestamps.iter().copied(), WINDOW, 0.75),
+            [8, 2, 0, 0, 4, 10, 6]
+        );
+    }
+
+    #[test]
+    fn downsampled_to_max_len() {
+        let buckets: Vec<_> = (0..100).collect();
+        let sparkline = sparkline(&buckets);
+
+        assert_eq!(sparkline.chars().count(), MAX_LEN);
+        assert!(sparkline.starts_with('▁'));
+        assert!(sparkline.ends_with('▇'));
+    }
+
+    #[test]
+    fn empty_or_invalid() {
+        assert_eq!(density_sparkline([], WINDOW, 1.0), None);
+        assert_eq!(density_sparkline([1000.0], WINDOW, 0.0), None);
+        assert_eq!(
+            density_sparkline([1000.0], WINDOW, 1.0).as_deref(),
+            Some("▇")
+        );
+        assert_eq!(sparkline(&[0, 0]), "▁▁");
+    }
+}
@@ -8,7 +8,7 @@ use twilight_model::{
     id::{Id, marker::ChannelMarker},
 };
 
-use crate::core::Context;
+use crate::core::{Context, commands::outcome::note_error_response};
 
 pub trait ChannelExt {
     /// Create a message inside a green embed
@@ -31,6 +31,7 @@ impl ChannelExt for Id<ChannelMarker> {
         builder: MessageBuilder<'_>,
         permissions: Option<Permissions>,
     ) -> ResponseFuture<Message> {
+        let builder = builder.resolve_plain_fallback(permissions);
         let mut req = Context::http().create_message(*self);
 
         if let Some(ref content) = builder.content {
@@ -56,6 +57,8 @@ impl ChannelExt for Id<ChannelMarker> {
     }
 
     fn error(&self, content: impl Into<String>) -> ResponseFuture<Message> {
+        note_error_response();
+
         let embed = EmbedBuilder::new().color_red().description(content).build();
 
         Context::http()
@@ -28,6 +28,8 @@ pub trait ComponentExt {
 
 impl ComponentExt for InteractionComponent {
     fn callback(&self, builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody> {
+        let builder = builder.resolve_plain_fallback(self.permissions);
+
         let attachments = builder
             .attachment
             .filter(|_| {
@@ -66,6 +68,7 @@ impl ComponentExt for InteractionComponent {
     }
 
     fn update(&self, builder: MessageBuilder<'_>) -> ResponseFuture<Message> {
+        let builder = builder.resolve_plain_fallback(self.permissions);
         let client = Context::interaction();
 
         let mut req = client.update_response(&self.token);
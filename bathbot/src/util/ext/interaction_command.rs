@@ -1,7 +1,10 @@
 use std::{borrow::Cow, future::IntoFuture, mem, slice};
 
-use bathbot_util::{EmbedBuilder, MessageBuilder};
-use twilight_http::response::{ResponseFuture, marker::EmptyBody};
+use bathbot_util::{EmbedBuilder, MessageBuilder, i18n::ErrorKey};
+use twilight_http::{
+    Error as HttpError, Response,
+    response::{ResponseFuture, marker::EmptyBody},
+};
 use twilight_interactions::command::CommandInputData;
 use twilight_model::{
     application::{
@@ -14,7 +17,10 @@ use twilight_model::{
 };
 
 use crate::{
-    core::Context,
+    core::{
+        Context,
+        commands::{localize_error, outcome::note_error_response},
+    },
     util::{CheckPermissions, interaction::InteractionCommand},
 };
 
@@ -45,6 +51,8 @@ pub trait InteractionCommandExt {
     ///
     /// Be sure the command was deferred beforehand.
     fn error(&self, content: impl Into<String>) -> ResponseFuture<Message> {
+        note_error_response();
+
         let embed = EmbedBuilder::new().description(content).color_red();
         let builder = MessageBuilder::new().embed(embed);
 
@@ -55,6 +63,8 @@ pub trait InteractionCommandExt {
     ///
     /// Be sure the command was **not** deferred beforehand.
     fn error_callback(&self, content: impl Into<String>) -> ResponseFuture<EmptyBody> {
+        note_error_response();
+
         let embed = EmbedBuilder::new().description(content).color_red();
         let builder = MessageBuilder::new().embed(embed);
 
@@ -65,6 +75,24 @@ pub trait InteractionCommandExt {
     fn autocomplete(&self, choices: Vec<CommandOptionChoice>) -> ResponseFuture<EmptyBody>;
 }
 
+impl InteractionCommand {
+    /// Same as [`InteractionCommandExt::error`] but with a generic error in
+    /// the guild's language.
+    pub async fn error_tr(&self, key: ErrorKey) -> Result<Response<Message>, HttpError> {
+        let content = localize_error(self.guild_id, key).await;
+
+        self.error(content).await
+    }
+
+    /// Same as [`InteractionCommandExt::error_callback`] but with a generic
+    /// error in the guild's language.
+    pub async fn error_callback_tr(&self, key: ErrorKey) -> Result<Response<EmptyBody>, HttpError> {
+        let content = localize_error(self.guild_id, key).await;
+
+        self.error_callback(content).await
+    }
+}
+
 impl InteractionCommandExt for InteractionCommand {
     fn input_data(&mut self) -> CommandInputData<'static> {
         CommandInputData {
@@ -101,6 +129,8 @@ impl InteractionCommandExt for InteractionCommand {
     }
 
     fn callback(&self, builder: MessageBuilder<'_>, ephemeral: bool) -> ResponseFuture<EmptyBody> {
+        let builder = builder.resolve_plain_fallback(self.permissions);
+
         let attachments = builder
             .attachment
             .filter(|_| self.can_attach_file())
@@ -162,6 +192,7 @@ impl InteractionCommandExt for InteractionCommand {
     }
 }
 
+#[derive(Clone)]
 pub struct InteractionToken<'a>(pub Cow<'a, str>);
 
 impl InteractionToken<'_> {
@@ -174,6 +205,7 @@ impl InteractionToken<'_> {
         builder: MessageBuilder<'_>,
         permissions: Option<Permissions>,
     ) -> ResponseFuture<Message> {
+        let builder = builder.resolve_plain_fallback(permissions);
         let client = Context::interaction();
 
         let mut req = client.create_followup(self.0.as_ref());
@@ -206,6 +238,7 @@ impl InteractionToken<'_> {
         builder: MessageBuilder<'_>,
         permissions: Option<Permissions>,
     ) -> ResponseFuture<Message> {
+        let builder = builder.resolve_plain_fallback(permissions);
         let client = Context::interaction();
 
         let mut req = client.update_response(self.0.as_ref());
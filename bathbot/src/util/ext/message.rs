@@ -43,6 +43,7 @@ impl MessageExt for (Id<MessageMarker>, Id<ChannelMarker>) {
             return None;
         }
 
+        let builder = builder.resolve_plain_fallback(permissions);
         let mut req = Context::http().update_message(self.1, self.0);
 
         if let Some(ref content) = builder.content {
@@ -68,6 +69,7 @@ impl MessageExt for (Id<MessageMarker>, Id<ChannelMarker>) {
         builder: MessageBuilder<'_>,
         permissions: Option<Permissions>,
     ) -> ResponseFuture<Message> {
+        let builder = builder.resolve_plain_fallback(permissions);
         let mut req = Context::http().create_message(self.1).reply(self.0);
 
         if let Some(ref content) = builder.content {
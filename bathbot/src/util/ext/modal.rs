@@ -1,4 +1,4 @@
-use std::{future::IntoFuture, slice};
+use std::{borrow::Cow, future::IntoFuture, slice};
 
 use bathbot_util::MessageBuilder;
 use twilight_http::response::{ResponseFuture, marker::EmptyBody};
@@ -25,6 +25,8 @@ pub trait ModalExt {
 
 impl ModalExt for InteractionModal {
     fn callback(&self, builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody> {
+        let builder = builder.resolve_plain_fallback(self.permissions);
+
         let attachments = builder
             .attachment
             .filter(|_| {
@@ -36,6 +38,7 @@ impl ModalExt for InteractionModal {
         let data = InteractionResponseData {
             components: builder.components,
             embeds: builder.embed.into(),
+            content: builder.content.map(Cow::into_owned),
             attachments,
             ..Default::default()
         };
@@ -62,6 +65,7 @@ impl ModalExt for InteractionModal {
     }
 
     fn update(&self, builder: MessageBuilder<'_>) -> ResponseFuture<Message> {
+        let builder = builder.resolve_plain_fallback(self.permissions);
         let client = Context::interaction();
 
         let mut req = client.update_response(&self.token);
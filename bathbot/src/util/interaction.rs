@@ -5,7 +5,7 @@ use twilight_model::{
         application_command::CommandData, message_component::MessageComponentInteractionData,
         modal::ModalInteractionData,
     },
-    channel::Message,
+    channel::{Message, thread::AutoArchiveDuration},
     guild::{PartialMember, Permissions},
     id::{
         Id,
@@ -22,6 +22,8 @@ pub struct InteractionCommand {
     pub guild_id: Option<Id<GuildMarker>>,
     pub id: Id<InteractionMarker>,
     pub member: Option<PartialMember>,
+    /// Auto-archive duration of the thread if the command was used in one
+    pub thread_archive: Option<AutoArchiveDuration>,
     pub token: String,
     pub user: Option<User>,
 }
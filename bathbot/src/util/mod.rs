@@ -1,16 +1,24 @@
 pub use self::{
     check_permissions::CheckPermissions,
+    density::density_sparkline,
     emote::{CustomEmote, Emote},
     ext::*,
+    mode::EffectiveMode,
     monthly::Monthly,
     searchable::NativeCriteria,
+    timeout::within,
+    timestamp::{TimestampFormatter, TimestampPosition},
 };
 
 pub mod interaction;
 pub mod osu;
 
 mod check_permissions;
+mod density;
 mod emote;
 mod ext;
+mod mode;
 mod monthly;
 mod searchable;
+mod timeout;
+mod timestamp;
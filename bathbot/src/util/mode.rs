@@ -0,0 +1,182 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use rosu_v2::prelude::GameMode;
+
+/// The mode a map-based command runs in and how it was chosen.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EffectiveMode {
+    pub mode: GameMode,
+    pub source: ModeSource,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ModeSource {
+    /// The mode was specified in the command
+    Explicit,
+    /// The user's configured mode was used to convert an osu!standard map
+    Config,
+    /// The map's own mode
+    Map,
+    /// A mode was specified but the map can't be converted to it
+    Unconvertible,
+    /// The mode of a given score
+    Score,
+}
+
+impl EffectiveMode {
+    /// Pick the mode for a map.
+    ///
+    /// Only osu!standard maps can be converted so an explicit or configured
+    /// mode is only applied to those, other maps always keep their own mode.
+    pub fn infer(map_mode: GameMode, explicit: Option<GameMode>, config: Option<GameMode>) -> Self {
+        let convertible = |mode: GameMode| mode == map_mode || map_mode == GameMode::Osu;
+
+        let (mode, source) = match (explicit, config) {
+            (Some(mode), _) if convertible(mode) => (mode, ModeSource::Explicit),
+            (Some(_), _) => (map_mode, ModeSource::Unconvertible),
+            (None, Some(mode)) if mode != map_mode && convertible(mode) => {
+                (mode, ModeSource::Config)
+            }
+            (None, _) => (map_mode, ModeSource::Map),
+        };
+
+        Self { mode, source }
+    }
+
+    pub fn score(mode: GameMode) -> Self {
+        Self {
+            mode,
+            source: ModeSource::Score,
+        }
+    }
+}
+
+impl ModeSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Explicit => "specified",
+            Self::Config => "your default mode",
+            Self::Map => "map's mode",
+            Self::Unconvertible => "map's mode, can't be converted",
+            Self::Score => "score's mode",
+        }
+    }
+}
+
+impl Display for EffectiveMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mode = match self.mode {
+            GameMode::Osu => "osu!",
+            GameMode::Taiko => "Taiko",
+            GameMode::Catch => "Catch",
+            GameMode::Mania => "Mania",
+        };
+
+        write!(f, "{mode} ({})", self.source.label())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODES: [GameMode; 4] = [
+        GameMode::Osu,
+        GameMode::Taiko,
+        GameMode::Catch,
+        GameMode::Mania,
+    ];
+
+    fn infer(map: GameMode, explicit: Option<GameMode>, config: Option<GameMode>) -> EffectiveMode {
+        EffectiveMode::infer(map, explicit, config)
+    }
+
+    #[test]
+    fn explicit_mode_converts_std_maps() {
+        for explicit in MODES {
+            for config in [None, Some(GameMode::Mania)] {
+                let expected = EffectiveMode {
+                    mode: explicit,
+                    source: ModeSource::Explicit,
+                };
+
+                assert_eq!(infer(GameMode::Osu, Some(explicit), config), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn explicit_mode_on_non_std_maps() {
+        for map in [GameMode::Taiko, GameMode::Catch, GameMode::Mania] {
+            for explicit in MODES {
+                let expected = if explicit == map {
+                    EffectiveMode {
+                        mode: map,
+                        source: ModeSource::Explicit,
+                    }
+                } else {
+                    EffectiveMode {
+                        mode: map,
+                        source: ModeSource::Unconvertible,
+                    }
+                };
+
+                assert_eq!(infer(map, Some(explicit), Some(GameMode::Osu)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn config_mode_converts_std_maps() {
+        for config in [GameMode::Taiko, GameMode::Catch, GameMode::Mania] {
+            let expected = EffectiveMode {
+                mode: config,
+                source: ModeSource::Config,
+            };
+
+            assert_eq!(infer(GameMode::Osu, None, Some(config)), expected);
+        }
+
+        let expected = EffectiveMode {
+            mode: GameMode::Osu,
+            source: ModeSource::Map,
+        };
+
+        assert_eq!(infer(GameMode::Osu, None, Some(GameMode::Osu)), expected);
+    }
+
+    #[test]
+    fn config_mode_ignored_on_non_std_maps() {
+        for map in [GameMode::Taiko, GameMode::Catch, GameMode::Mania] {
+            for config in MODES.map(Some).into_iter().chain([None]) {
+                let expected = EffectiveMode {
+                    mode: map,
+                    source: ModeSource::Map,
+                };
+
+                assert_eq!(infer(map, None, config), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn no_mode_keeps_map_mode() {
+        for map in MODES {
+            let expected = EffectiveMode {
+                mode: map,
+                source: ModeSource::Map,
+            };
+
+            assert_eq!(infer(map, None, None), expected);
+        }
+    }
+
+    #[test]
+    fn display_mode_and_source() {
+        let mode = infer(GameMode::Osu, None, Some(GameMode::Catch));
+        assert_eq!(mode.to_string(), "Catch (your default mode)");
+
+        let mode = infer(GameMode::Mania, Some(GameMode::Taiko), None);
+        assert_eq!(mode.to_string(), "Mania (map's mode, can't be converted)");
+    }
+}
@@ -456,6 +456,18 @@ impl IfFc {
 
         let attrs = calc.difficulty().await?;
 
+        Self::with_difficulty(score, map, attrs)
+    }
+
+    /// Same as [`IfFc::new`] but re-uses already calculated difficulty
+    /// attributes.
+    ///
+    /// The attributes must match the score's mode, mods, and lazer-ness.
+    pub fn with_difficulty(
+        score: &ScoreSlim,
+        map: &OsuMap,
+        attrs: &DifficultyAttributes,
+    ) -> Option<Self> {
         if score.is_fc(score.mode, attrs.max_combo()) {
             return None;
         }
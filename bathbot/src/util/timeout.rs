@@ -0,0 +1,28 @@
+use std::{future::Future, time::Duration};
+
+/// Await the future but give up once the duration passed.
+///
+/// Returns `None` if the future did not complete in time.
+pub async fn within<F: Future>(fut: F, duration: Duration) -> Option<F::Output> {
+    tokio::time::timeout(duration, fut).await.ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn completes_in_time() {
+        let res = within(future::ready(42), Duration::from_millis(1500)).await;
+        assert_eq!(res, Some(42));
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_duration() {
+        let fut = tokio::time::sleep(Duration::from_secs(5));
+        let res = within(fut, Duration::from_millis(10)).await;
+        assert_eq!(res, None);
+    }
+}
@@ -0,0 +1,143 @@
+use std::{
+    fmt::{Display, Error as FmtError, Formatter, Result as FmtResult},
+    time::Duration,
+};
+
+use bathbot_psql::model::configs::TimestampStyle;
+use bathbot_util::datetime::{HowLongAgoDynamic, HowLongAgoText, SHORT_NAIVE_DATETIME_FORMAT};
+use time::OffsetDateTime;
+
+const DAY: Duration = Duration::from_secs(60 * 60 * 24);
+
+/// Where a timestamp is going to be displayed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TimestampPosition {
+    /// Description, fields, or message content
+    Body,
+    /// Embed footer which does not support discord's timestamp markup
+    Footer,
+}
+
+/// Formats a datetime based on the user's [`TimestampStyle`].
+///
+/// Since discord's timestamp markup is not rendered in footers, all styles
+/// fall back to UTC text there; only recent datetimes are shown as relative
+/// text if the style is [`TimestampStyle::Relative`].
+pub struct TimestampFormatter {
+    datetime: OffsetDateTime,
+    style: TimestampStyle,
+    position: TimestampPosition,
+}
+
+impl TimestampFormatter {
+    pub fn new(
+        datetime: OffsetDateTime,
+        style: TimestampStyle,
+        position: TimestampPosition,
+    ) -> Self {
+        Self {
+            datetime,
+            style,
+            position,
+        }
+    }
+
+    fn utc_text(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.datetime.format(&SHORT_NAIVE_DATETIME_FORMAT) {
+            Ok(datetime) => write!(f, "{datetime} UTC"),
+            Err(_) => Err(FmtError),
+        }
+    }
+}
+
+impl Display for TimestampFormatter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match (self.style, self.position) {
+            (TimestampStyle::Relative, TimestampPosition::Body) => {
+                HowLongAgoDynamic::new(&self.datetime).fmt(f)
+            }
+            (TimestampStyle::LocalAbsolute, TimestampPosition::Body) => {
+                // https://discord.com/developers/docs/reference#message-formatting-timestamp-styles
+                write!(f, "<t:{}:f>", self.datetime.unix_timestamp())
+            }
+            (TimestampStyle::Relative, TimestampPosition::Footer)
+                if OffsetDateTime::now_utc() < self.datetime + DAY =>
+            {
+                HowLongAgoText::new(&self.datetime).fmt(f)
+            }
+            (
+                TimestampStyle::Relative | TimestampStyle::LocalAbsolute,
+                TimestampPosition::Footer,
+            )
+            | (TimestampStyle::UtcText, _) => self.utc_text(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month};
+
+    use super::*;
+
+    fn old() -> OffsetDateTime {
+        Date::from_calendar_date(2024, Month::May, 1)
+            .unwrap()
+            .with_hms(13, 37, 42)
+            .unwrap()
+            .assume_utc()
+    }
+
+    fn format(style: TimestampStyle, position: TimestampPosition) -> String {
+        TimestampFormatter::new(old(), style, position).to_string()
+    }
+
+    #[test]
+    fn body_styles() {
+        let timestamp = old().unix_timestamp();
+
+        assert_eq!(
+            format(TimestampStyle::Relative, TimestampPosition::Body),
+            format!("<t:{timestamp}:R>")
+        );
+        assert_eq!(
+            format(TimestampStyle::LocalAbsolute, TimestampPosition::Body),
+            format!("<t:{timestamp}:f>")
+        );
+        assert_eq!(
+            format(TimestampStyle::UtcText, TimestampPosition::Body),
+            "2024-05-01 13:37 UTC"
+        );
+    }
+
+    #[test]
+    fn footer_degrades_to_utc_text() {
+        for style in [
+            TimestampStyle::Relative,
+            TimestampStyle::LocalAbsolute,
+            TimestampStyle::UtcText,
+        ] {
+            assert_eq!(
+                format(style, TimestampPosition::Footer),
+                "2024-05-01 13:37 UTC"
+            );
+        }
+    }
+
+    #[test]
+    fn recent_relative_footer() {
+        let recent = OffsetDateTime::now_utc() - Duration::from_secs(5 * 60);
+        let formatter =
+            TimestampFormatter::new(recent, TimestampStyle::Relative, TimestampPosition::Footer);
+
+        assert_eq!(formatter.to_string(), "5 minutes ago");
+
+        let formatter = TimestampFormatter::new(
+            recent,
+            TimestampStyle::LocalAbsolute,
+            TimestampPosition::Footer,
+        );
+
+        assert!(formatter.to_string().ends_with(" UTC"));
+    }
+}